@@ -0,0 +1,294 @@
+use arrow_buffer::{BooleanBuffer, Buffer as RsBuffer};
+use arrow_data::{ArrayData, ArrayDataBuilder};
+use polars_buffer::Buffer;
+use polars_error::{PolarsResult, polars_bail};
+
+use super::buffer::{
+    bitmap_from_arrow_rs, bitmap_to_arrow_rs, buffer_from_arrow_rs, buffer_to_arrow_rs,
+    bytes_from_arrow_rs, validity_from_arrow_rs, validity_to_arrow_rs,
+};
+use super::datatypes::{dtype_from_arrow_rs, dtype_to_arrow_rs};
+use crate::array::*;
+use crate::datatypes::{ArrowDataType, PhysicalType, PrimitiveType};
+use crate::offset::{Offset, OffsetsBuffer};
+use crate::types::{NativeType, days_ms, i256, months_days_ns};
+use crate::{match_integer_type, with_match_primitive_type_full};
+
+fn downcast<A: 'static>(array: &dyn Array) -> &A {
+    array.as_any().downcast_ref::<A>().unwrap()
+}
+
+fn primitive_to_data<T: NativeType>(
+    array: &dyn Array,
+    builder: ArrayDataBuilder,
+) -> ArrayDataBuilder {
+    builder.add_buffer(buffer_to_arrow_rs(
+        downcast::<PrimitiveArray<T>>(array).values(),
+    ))
+}
+
+fn binary_to_data<O: Offset>(array: &dyn Array, builder: ArrayDataBuilder) -> ArrayDataBuilder {
+    let array = downcast::<BinaryArray<O>>(array);
+    builder
+        .add_buffer(buffer_to_arrow_rs(array.offsets().buffer()))
+        .add_buffer(buffer_to_arrow_rs(array.values()))
+}
+
+fn utf8_to_data<O: Offset>(array: &dyn Array, builder: ArrayDataBuilder) -> ArrayDataBuilder {
+    let array = downcast::<Utf8Array<O>>(array);
+    builder
+        .add_buffer(buffer_to_arrow_rs(array.offsets().buffer()))
+        .add_buffer(buffer_to_arrow_rs(array.values()))
+}
+
+fn view_to_data<T: ViewType + ?Sized>(
+    array: &dyn Array,
+    builder: ArrayDataBuilder,
+) -> ArrayDataBuilder {
+    let array = downcast::<BinaryViewArrayGeneric<T>>(array);
+    // arrow-rs reads the views as `u128`, so they have to be aligned to 16 bytes.
+    let views = if array.views().as_ptr().align_offset(align_of::<i128>()) == 0 {
+        buffer_to_arrow_rs(array.views())
+    } else {
+        RsBuffer::from_vec(bytemuck::pod_collect_to_vec::<View, i128>(
+            array.views().as_slice(),
+        ))
+    };
+    array
+        .data_buffers()
+        .iter()
+        .fold(builder.add_buffer(views), |builder, buffer| {
+            builder.add_buffer(buffer_to_arrow_rs(buffer))
+        })
+}
+
+fn list_to_data<O: Offset>(
+    array: &dyn Array,
+    builder: ArrayDataBuilder,
+) -> PolarsResult<ArrayDataBuilder> {
+    let array = downcast::<ListArray<O>>(array);
+    Ok(builder
+        .add_buffer(buffer_to_arrow_rs(array.offsets().buffer()))
+        .add_child_data(to_data(array.values().as_ref())?))
+}
+
+fn dictionary_to_data<K: DictionaryKey>(
+    array: &dyn Array,
+    builder: ArrayDataBuilder,
+) -> PolarsResult<ArrayDataBuilder> {
+    let array = downcast::<DictionaryArray<K>>(array);
+    Ok(builder
+        .add_buffer(buffer_to_arrow_rs(array.keys().values()))
+        .add_child_data(to_data(array.values().as_ref())?))
+}
+
+/// Converts an [`Array`] to arrow-rs `ArrayData` that shares its buffers.
+pub(super) fn to_data(array: &dyn Array) -> PolarsResult<ArrayData> {
+    use PhysicalType::*;
+
+    let mut builder = ArrayData::builder(dtype_to_arrow_rs(array.dtype())?).len(array.len());
+    if array.dtype() != &ArrowDataType::Null {
+        builder = builder.nulls(array.validity().map(validity_to_arrow_rs));
+    }
+
+    let builder = match array.dtype().to_physical_type() {
+        Null => builder,
+        Boolean => {
+            let values = bitmap_to_arrow_rs(downcast::<BooleanArray>(array).values());
+            builder
+                .offset(values.offset())
+                .add_buffer(values.into_inner())
+        },
+        Primitive(PrimitiveType::Int256) => primitive_to_data::<i256>(array, builder),
+        Primitive(PrimitiveType::DaysMs) => primitive_to_data::<days_ms>(array, builder),
+        Primitive(PrimitiveType::MonthDayNano) => {
+            primitive_to_data::<months_days_ns>(array, builder)
+        },
+        Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+            primitive_to_data::<$T>(array, builder)
+        }),
+        Binary => binary_to_data::<i32>(array, builder),
+        LargeBinary => binary_to_data::<i64>(array, builder),
+        Utf8 => utf8_to_data::<i32>(array, builder),
+        LargeUtf8 => utf8_to_data::<i64>(array, builder),
+        BinaryView => view_to_data::<[u8]>(array, builder),
+        Utf8View => view_to_data::<str>(array, builder),
+        FixedSizeBinary => builder.add_buffer(buffer_to_arrow_rs(
+            downcast::<FixedSizeBinaryArray>(array).values(),
+        )),
+        List => list_to_data::<i32>(array, builder)?,
+        LargeList => list_to_data::<i64>(array, builder)?,
+        FixedSizeList => {
+            let array = downcast::<FixedSizeListArray>(array);
+            builder.add_child_data(to_data(array.values().as_ref())?)
+        },
+        Struct => builder.child_data(
+            downcast::<StructArray>(array)
+                .values()
+                .iter()
+                .map(|values| to_data(values.as_ref()))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        Map => {
+            let array = downcast::<MapArray>(array);
+            builder
+                .add_buffer(buffer_to_arrow_rs(array.offsets().buffer()))
+                .add_child_data(to_data(array.field().as_ref())?)
+        },
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            dictionary_to_data::<$T>(array, builder)?
+        }),
+        Union => polars_bail!(InvalidOperation: "arrow-rs conversion of unions is not supported"),
+    };
+
+    // SAFETY: the arrays of this crate uphold the invariants of the Arrow format.
+    Ok(unsafe { builder.build_unchecked() })
+}
+
+fn primitive_from_data<T: NativeType>(
+    data: &ArrayData,
+    dtype: ArrowDataType,
+    validity: Option<crate::bitmap::Bitmap>,
+) -> Box<dyn Array> {
+    let values = buffer_from_arrow_rs::<T>(&data.buffers()[0], data.offset(), data.len());
+    PrimitiveArray::<T>::new(dtype, values, validity).boxed()
+}
+
+fn offsets_from_data<O: Offset>(data: &ArrayData) -> OffsetsBuffer<O> {
+    let buffer = &data.buffers()[0];
+    // The offsets of empty arrays may be left out.
+    if buffer.len() < (data.offset() + data.len() + 1) * size_of::<O>() {
+        debug_assert_eq!(data.len(), 0);
+        return OffsetsBuffer::new();
+    }
+    let offsets = buffer_from_arrow_rs::<O>(buffer, data.offset(), data.len() + 1);
+    // SAFETY: the offsets of valid arrow-rs arrays are monotonically increasing.
+    unsafe { OffsetsBuffer::new_unchecked(offsets) }
+}
+
+fn values_from_data(data: &ArrayData) -> Buffer<u8> {
+    data.buffers()
+        .get(1)
+        .map(bytes_from_arrow_rs)
+        .unwrap_or_default()
+}
+
+fn view_from_data<T: ViewType + ?Sized>(
+    data: &ArrayData,
+    dtype: ArrowDataType,
+    validity: Option<crate::bitmap::Bitmap>,
+) -> Box<dyn Array> {
+    let views = buffer_from_arrow_rs(&data.buffers()[0], data.offset(), data.len());
+    let buffers = data.buffers()[1..]
+        .iter()
+        .map(bytes_from_arrow_rs)
+        .collect::<Buffer<_>>();
+    // SAFETY: the views of valid arrow-rs arrays point into their buffers.
+    unsafe {
+        BinaryViewArrayGeneric::<T>::new_unchecked_unknown_md(dtype, views, buffers, validity, None)
+    }
+    .boxed()
+}
+
+/// Converts arrow-rs `ArrayData` to an [`Array`] that shares its buffers.
+pub(super) fn from_data(data: &ArrayData) -> PolarsResult<Box<dyn Array>> {
+    use PhysicalType::*;
+
+    let dtype = dtype_from_arrow_rs(data.data_type())?;
+    let (offset, length) = (data.offset(), data.len());
+    let validity = data.nulls().map(validity_from_arrow_rs);
+
+    Ok(match dtype.to_physical_type() {
+        Null => NullArray::new(dtype, length).boxed(),
+        Boolean => {
+            let values = BooleanBuffer::new(data.buffers()[0].clone(), offset, length);
+            BooleanArray::new(dtype, bitmap_from_arrow_rs(&values, None), validity).boxed()
+        },
+        Primitive(PrimitiveType::Int256) => primitive_from_data::<i256>(data, dtype, validity),
+        Primitive(PrimitiveType::DaysMs) => primitive_from_data::<days_ms>(data, dtype, validity),
+        Primitive(PrimitiveType::MonthDayNano) => {
+            primitive_from_data::<months_days_ns>(data, dtype, validity)
+        },
+        Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+            primitive_from_data::<$T>(data, dtype, validity)
+        }),
+        // SAFETY: the offsets of valid arrow-rs arrays are in bounds of their values, which are
+        // valid UTF-8 for string arrays.
+        Binary => unsafe {
+            BinaryArray::<i32>::new_unchecked(
+                dtype,
+                offsets_from_data(data),
+                values_from_data(data),
+                validity,
+            )
+        }
+        .boxed(),
+        LargeBinary => unsafe {
+            BinaryArray::<i64>::new_unchecked(
+                dtype,
+                offsets_from_data(data),
+                values_from_data(data),
+                validity,
+            )
+        }
+        .boxed(),
+        Utf8 => unsafe {
+            Utf8Array::<i32>::new_unchecked(
+                dtype,
+                offsets_from_data(data),
+                values_from_data(data),
+                validity,
+            )
+        }
+        .boxed(),
+        LargeUtf8 => unsafe {
+            Utf8Array::<i64>::new_unchecked(
+                dtype,
+                offsets_from_data(data),
+                values_from_data(data),
+                validity,
+            )
+        }
+        .boxed(),
+        BinaryView => view_from_data::<[u8]>(data, dtype, validity),
+        Utf8View => view_from_data::<str>(data, dtype, validity),
+        FixedSizeBinary => {
+            let size = FixedSizeBinaryArray::get_size(&dtype);
+            let values = buffer_from_arrow_rs(&data.buffers()[0], offset * size, length * size);
+            FixedSizeBinaryArray::new(dtype, values, validity).boxed()
+        },
+        List => {
+            let values = from_data(&data.child_data()[0])?;
+            ListArray::<i32>::new(dtype, offsets_from_data(data), values, validity).boxed()
+        },
+        LargeList => {
+            let values = from_data(&data.child_data()[0])?;
+            ListArray::<i64>::new(dtype, offsets_from_data(data), values, validity).boxed()
+        },
+        FixedSizeList => {
+            let size = FixedSizeListArray::get_child_and_size(&dtype).1;
+            let values = from_data(&data.child_data()[0])?.sliced(offset * size, length * size);
+            FixedSizeListArray::new(dtype, length, values, validity).boxed()
+        },
+        Struct => {
+            let values = data
+                .child_data()
+                .iter()
+                .map(|child| Ok(from_data(child)?.sliced(offset, length)))
+                .collect::<PolarsResult<_>>()?;
+            StructArray::new(dtype, length, values, validity).boxed()
+        },
+        Map => {
+            let field = from_data(&data.child_data()[0])?;
+            MapArray::new(dtype, offsets_from_data(data), field, validity).boxed()
+        },
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let keys = buffer_from_arrow_rs::<$T>(&data.buffers()[0], offset, length);
+            let keys = PrimitiveArray::new($T::PRIMITIVE.into(), keys, validity);
+            let values = from_data(&data.child_data()[0])?;
+            // SAFETY: the keys of valid arrow-rs dictionaries are in bounds of the values.
+            unsafe { DictionaryArray::<$T>::try_new_unchecked(dtype, keys, values) }?.boxed()
+        }),
+        Union => polars_bail!(InvalidOperation: "arrow-rs conversion of unions is not supported"),
+    })
+}
@@ -0,0 +1,75 @@
+use std::panic::AssertUnwindSafe;
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use arrow_buffer::{BooleanBuffer, Buffer as RsBuffer, NullBuffer};
+use bytemuck::Pod;
+use polars_buffer::{Buffer, SharedStorage};
+
+use crate::bitmap::Bitmap;
+
+/// Creates an arrow-rs buffer over `bytes` that keeps `owner` alive.
+///
+/// # Safety
+/// `bytes` must stay valid for as long as `owner` lives.
+unsafe fn shared_buffer<O: Send + Sync + 'static>(bytes: &[u8], owner: O) -> RsBuffer {
+    let ptr = NonNull::new(bytes.as_ptr().cast_mut()).unwrap();
+    unsafe { RsBuffer::from_custom_allocation(ptr, bytes.len(), Arc::new(AssertUnwindSafe(owner))) }
+}
+
+/// Shares the memory of a [`Buffer`] with arrow-rs.
+pub(super) fn buffer_to_arrow_rs<T: Pod + Send + Sync>(buffer: &Buffer<T>) -> RsBuffer {
+    let bytes = bytemuck::cast_slice(buffer.as_slice());
+    // SAFETY: the clone keeps the memory of the buffer alive.
+    unsafe { shared_buffer(bytes, buffer.clone()) }
+}
+
+/// Shares the memory of a [`Bitmap`] with arrow-rs.
+pub(super) fn bitmap_to_arrow_rs(bitmap: &Bitmap) -> BooleanBuffer {
+    let (bytes, offset, length) = bitmap.as_slice();
+    // SAFETY: the clone keeps the memory of the bitmap alive.
+    let buffer = unsafe { shared_buffer(bytes, bitmap.clone()) };
+    BooleanBuffer::new(buffer, offset, length)
+}
+
+pub(super) fn validity_to_arrow_rs(validity: &Bitmap) -> NullBuffer {
+    // SAFETY: the number of unset bits is the number of nulls.
+    unsafe { NullBuffer::new_unchecked(bitmap_to_arrow_rs(validity), validity.unset_bits()) }
+}
+
+/// Shares the elements `offset..offset + length` of an arrow-rs buffer. The memory is copied if
+/// it is not aligned for `T`.
+pub(super) fn buffer_from_arrow_rs<T: Pod>(
+    buffer: &RsBuffer,
+    offset: usize,
+    length: usize,
+) -> Buffer<T> {
+    let bytes = buffer.as_slice();
+    let bytes = &bytes[..bytes.len() / size_of::<T>() * size_of::<T>()];
+    let values = match bytemuck::try_cast_slice::<u8, T>(bytes) {
+        // SAFETY: the clone keeps the memory of the buffer alive.
+        Ok(values) => Buffer::from_storage(unsafe {
+            SharedStorage::from_slice_with_owner(values, buffer.clone())
+        }),
+        Err(_) => Buffer::from(bytemuck::pod_collect_to_vec::<u8, T>(bytes)),
+    };
+    values.sliced(offset..offset + length)
+}
+
+/// Shares all bytes of an arrow-rs buffer.
+pub(super) fn bytes_from_arrow_rs(buffer: &RsBuffer) -> Buffer<u8> {
+    buffer_from_arrow_rs(buffer, 0, buffer.len())
+}
+
+pub(super) fn bitmap_from_arrow_rs(values: &BooleanBuffer, unset_bits: Option<usize>) -> Bitmap {
+    let buffer = values.inner();
+    // SAFETY: the clone keeps the memory of the buffer alive.
+    let storage =
+        unsafe { SharedStorage::from_slice_with_owner(buffer.as_slice(), buffer.clone()) };
+    // SAFETY: a `BooleanBuffer` has `offset + len` bits.
+    unsafe { Bitmap::from_inner_unchecked(storage, values.offset(), values.len(), unset_bits) }
+}
+
+pub(super) fn validity_from_arrow_rs(validity: &NullBuffer) -> Bitmap {
+    bitmap_from_arrow_rs(validity.inner(), Some(validity.null_count()))
+}
@@ -0,0 +1,320 @@
+// arrow-rs stores field and schema metadata in std hash maps.
+#![allow(clippy::disallowed_types)]
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_schema::{
+    DataType as RsDataType, Field as RsField, IntervalUnit as RsIntervalUnit, Schema as RsSchema,
+    TimeUnit as RsTimeUnit,
+};
+use polars_error::{PolarsResult, polars_bail, polars_err};
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::datatypes::{
+    ArrowDataType, ArrowSchema, Field, IntegerType, IntervalUnit, Metadata, TimeUnit,
+};
+
+const EXTENSION_NAME: &str = "ARROW:extension:name";
+const EXTENSION_METADATA: &str = "ARROW:extension:metadata";
+
+fn time_unit_to_arrow_rs(time_unit: TimeUnit) -> RsTimeUnit {
+    match time_unit {
+        TimeUnit::Second => RsTimeUnit::Second,
+        TimeUnit::Millisecond => RsTimeUnit::Millisecond,
+        TimeUnit::Microsecond => RsTimeUnit::Microsecond,
+        TimeUnit::Nanosecond => RsTimeUnit::Nanosecond,
+    }
+}
+
+fn time_unit_from_arrow_rs(time_unit: RsTimeUnit) -> TimeUnit {
+    match time_unit {
+        RsTimeUnit::Second => TimeUnit::Second,
+        RsTimeUnit::Millisecond => TimeUnit::Millisecond,
+        RsTimeUnit::Microsecond => TimeUnit::Microsecond,
+        RsTimeUnit::Nanosecond => TimeUnit::Nanosecond,
+    }
+}
+
+fn decimal_to_arrow_rs(precision: usize, scale: usize) -> PolarsResult<(u8, i8)> {
+    match (u8::try_from(precision), i8::try_from(scale)) {
+        (Ok(precision), Ok(scale)) => Ok((precision, scale)),
+        _ => polars_bail!(
+            InvalidOperation: "decimal precision {} and scale {} are out of range for arrow-rs",
+            precision, scale
+        ),
+    }
+}
+
+fn decimal_from_arrow_rs(precision: u8, scale: i8) -> PolarsResult<(usize, usize)> {
+    let scale = usize::try_from(scale).map_err(
+        |_| polars_err!(InvalidOperation: "negative decimal scale {} is not supported", scale),
+    )?;
+    Ok((precision as usize, scale))
+}
+
+fn key_type_to_arrow_rs(key_type: IntegerType) -> PolarsResult<RsDataType> {
+    Ok(match key_type {
+        IntegerType::Int8 => RsDataType::Int8,
+        IntegerType::Int16 => RsDataType::Int16,
+        IntegerType::Int32 => RsDataType::Int32,
+        IntegerType::Int64 => RsDataType::Int64,
+        IntegerType::UInt8 => RsDataType::UInt8,
+        IntegerType::UInt16 => RsDataType::UInt16,
+        IntegerType::UInt32 => RsDataType::UInt32,
+        IntegerType::UInt64 => RsDataType::UInt64,
+        IntegerType::Int128 | IntegerType::UInt128 => {
+            polars_bail!(InvalidOperation: "arrow-rs does not support {:?} dictionary keys", key_type)
+        },
+    })
+}
+
+fn key_type_from_arrow_rs(key_type: &RsDataType) -> PolarsResult<IntegerType> {
+    Ok(match key_type {
+        RsDataType::Int8 => IntegerType::Int8,
+        RsDataType::Int16 => IntegerType::Int16,
+        RsDataType::Int32 => IntegerType::Int32,
+        RsDataType::Int64 => IntegerType::Int64,
+        RsDataType::UInt8 => IntegerType::UInt8,
+        RsDataType::UInt16 => IntegerType::UInt16,
+        RsDataType::UInt32 => IntegerType::UInt32,
+        RsDataType::UInt64 => IntegerType::UInt64,
+        _ => polars_bail!(InvalidOperation: "invalid dictionary key type {}", key_type),
+    })
+}
+
+/// Converts an [`ArrowDataType`] to an arrow-rs `DataType`.
+///
+/// Extension types are converted to their storage type.
+pub fn dtype_to_arrow_rs(dtype: &ArrowDataType) -> PolarsResult<RsDataType> {
+    use ArrowDataType as D;
+    Ok(match dtype {
+        D::Null => RsDataType::Null,
+        D::Boolean => RsDataType::Boolean,
+        D::Int8 => RsDataType::Int8,
+        D::Int16 => RsDataType::Int16,
+        D::Int32 => RsDataType::Int32,
+        D::Int64 => RsDataType::Int64,
+        D::UInt8 => RsDataType::UInt8,
+        D::UInt16 => RsDataType::UInt16,
+        D::UInt32 => RsDataType::UInt32,
+        D::UInt64 => RsDataType::UInt64,
+        D::Float16 => RsDataType::Float16,
+        D::Float32 => RsDataType::Float32,
+        D::Float64 => RsDataType::Float64,
+        D::Timestamp(time_unit, tz) => RsDataType::Timestamp(
+            time_unit_to_arrow_rs(*time_unit),
+            tz.as_ref().map(|tz| Arc::from(tz.as_str())),
+        ),
+        D::Date32 => RsDataType::Date32,
+        D::Date64 => RsDataType::Date64,
+        D::Time32(time_unit) => RsDataType::Time32(time_unit_to_arrow_rs(*time_unit)),
+        D::Time64(time_unit) => RsDataType::Time64(time_unit_to_arrow_rs(*time_unit)),
+        D::Duration(time_unit) => RsDataType::Duration(time_unit_to_arrow_rs(*time_unit)),
+        D::Interval(IntervalUnit::YearMonth) => RsDataType::Interval(RsIntervalUnit::YearMonth),
+        D::Interval(IntervalUnit::DayTime) => RsDataType::Interval(RsIntervalUnit::DayTime),
+        D::Interval(IntervalUnit::MonthDayNano) => {
+            RsDataType::Interval(RsIntervalUnit::MonthDayNano)
+        },
+        D::Binary => RsDataType::Binary,
+        D::FixedSizeBinary(size) => RsDataType::FixedSizeBinary(i32::try_from(*size).unwrap()),
+        D::LargeBinary => RsDataType::LargeBinary,
+        D::Utf8 => RsDataType::Utf8,
+        D::LargeUtf8 => RsDataType::LargeUtf8,
+        D::BinaryView => RsDataType::BinaryView,
+        D::Utf8View => RsDataType::Utf8View,
+        D::List(field) => RsDataType::List(Arc::new(field_to_arrow_rs(field)?)),
+        D::FixedSizeList(field, size) => RsDataType::FixedSizeList(
+            Arc::new(field_to_arrow_rs(field)?),
+            i32::try_from(*size).unwrap(),
+        ),
+        D::LargeList(field) => RsDataType::LargeList(Arc::new(field_to_arrow_rs(field)?)),
+        D::Struct(fields) => RsDataType::Struct(
+            fields
+                .iter()
+                .map(field_to_arrow_rs)
+                .collect::<PolarsResult<Vec<_>>>()?
+                .into(),
+        ),
+        D::Map(field, is_sorted) => {
+            RsDataType::Map(Arc::new(field_to_arrow_rs(field)?), *is_sorted)
+        },
+        D::Dictionary(key_type, values, _) => RsDataType::Dictionary(
+            Box::new(key_type_to_arrow_rs(*key_type)?),
+            Box::new(dtype_to_arrow_rs(values)?),
+        ),
+        D::Decimal(precision, scale) => {
+            let (precision, scale) = decimal_to_arrow_rs(*precision, *scale)?;
+            RsDataType::Decimal128(precision, scale)
+        },
+        D::Decimal32(precision, scale) => {
+            let (precision, scale) = decimal_to_arrow_rs(*precision, *scale)?;
+            RsDataType::Decimal32(precision, scale)
+        },
+        D::Decimal64(precision, scale) => {
+            let (precision, scale) = decimal_to_arrow_rs(*precision, *scale)?;
+            RsDataType::Decimal64(precision, scale)
+        },
+        D::Decimal256(precision, scale) => {
+            let (precision, scale) = decimal_to_arrow_rs(*precision, *scale)?;
+            RsDataType::Decimal256(precision, scale)
+        },
+        D::Extension(ext) => dtype_to_arrow_rs(&ext.inner)?,
+        D::Int128
+        | D::UInt128
+        | D::Interval(IntervalUnit::MonthDayMillis)
+        | D::Union(_)
+        | D::Unknown => {
+            polars_bail!(InvalidOperation: "arrow-rs does not support the data type {:?}", dtype)
+        },
+    })
+}
+
+/// Converts an arrow-rs `DataType` to an [`ArrowDataType`].
+pub fn dtype_from_arrow_rs(dtype: &RsDataType) -> PolarsResult<ArrowDataType> {
+    use ArrowDataType as D;
+    Ok(match dtype {
+        RsDataType::Null => D::Null,
+        RsDataType::Boolean => D::Boolean,
+        RsDataType::Int8 => D::Int8,
+        RsDataType::Int16 => D::Int16,
+        RsDataType::Int32 => D::Int32,
+        RsDataType::Int64 => D::Int64,
+        RsDataType::UInt8 => D::UInt8,
+        RsDataType::UInt16 => D::UInt16,
+        RsDataType::UInt32 => D::UInt32,
+        RsDataType::UInt64 => D::UInt64,
+        RsDataType::Float16 => D::Float16,
+        RsDataType::Float32 => D::Float32,
+        RsDataType::Float64 => D::Float64,
+        RsDataType::Timestamp(time_unit, tz) => D::Timestamp(
+            time_unit_from_arrow_rs(*time_unit),
+            tz.as_deref().map(PlSmallStr::from_str),
+        ),
+        RsDataType::Date32 => D::Date32,
+        RsDataType::Date64 => D::Date64,
+        RsDataType::Time32(time_unit) => D::Time32(time_unit_from_arrow_rs(*time_unit)),
+        RsDataType::Time64(time_unit) => D::Time64(time_unit_from_arrow_rs(*time_unit)),
+        RsDataType::Duration(time_unit) => D::Duration(time_unit_from_arrow_rs(*time_unit)),
+        RsDataType::Interval(RsIntervalUnit::YearMonth) => D::Interval(IntervalUnit::YearMonth),
+        RsDataType::Interval(RsIntervalUnit::DayTime) => D::Interval(IntervalUnit::DayTime),
+        RsDataType::Interval(RsIntervalUnit::MonthDayNano) => {
+            D::Interval(IntervalUnit::MonthDayNano)
+        },
+        RsDataType::Binary => D::Binary,
+        RsDataType::FixedSizeBinary(size) => D::FixedSizeBinary(*size as usize),
+        RsDataType::LargeBinary => D::LargeBinary,
+        RsDataType::BinaryView => D::BinaryView,
+        RsDataType::Utf8 => D::Utf8,
+        RsDataType::LargeUtf8 => D::LargeUtf8,
+        RsDataType::Utf8View => D::Utf8View,
+        RsDataType::List(field) => D::List(Box::new(field_from_arrow_rs(field)?)),
+        RsDataType::FixedSizeList(field, size) => {
+            D::FixedSizeList(Box::new(field_from_arrow_rs(field)?), *size as usize)
+        },
+        RsDataType::LargeList(field) => D::LargeList(Box::new(field_from_arrow_rs(field)?)),
+        RsDataType::Struct(fields) => D::Struct(
+            fields
+                .iter()
+                .map(|field| field_from_arrow_rs(field))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        RsDataType::Map(field, is_sorted) => {
+            D::Map(Box::new(field_from_arrow_rs(field)?), *is_sorted)
+        },
+        RsDataType::Dictionary(key_type, values) => D::Dictionary(
+            key_type_from_arrow_rs(key_type)?,
+            Box::new(dtype_from_arrow_rs(values)?),
+            false,
+        ),
+        RsDataType::Decimal32(precision, scale) => {
+            let (precision, scale) = decimal_from_arrow_rs(*precision, *scale)?;
+            D::Decimal32(precision, scale)
+        },
+        RsDataType::Decimal64(precision, scale) => {
+            let (precision, scale) = decimal_from_arrow_rs(*precision, *scale)?;
+            D::Decimal64(precision, scale)
+        },
+        RsDataType::Decimal128(precision, scale) => {
+            let (precision, scale) = decimal_from_arrow_rs(*precision, *scale)?;
+            D::Decimal(precision, scale)
+        },
+        RsDataType::Decimal256(precision, scale) => {
+            let (precision, scale) = decimal_from_arrow_rs(*precision, *scale)?;
+            D::Decimal256(precision, scale)
+        },
+        dtype => {
+            polars_bail!(InvalidOperation: "the arrow-rs data type {} is not supported", dtype)
+        },
+    })
+}
+
+fn metadata_to_arrow_rs(metadata: &Metadata) -> HashMap<String, String> {
+    metadata
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+fn metadata_from_arrow_rs(metadata: &HashMap<String, String>) -> Metadata {
+    metadata
+        .iter()
+        .map(|(k, v)| (PlSmallStr::from_str(k), PlSmallStr::from_str(v)))
+        .collect()
+}
+
+/// Converts a [`Field`] to an arrow-rs `Field`.
+///
+/// The name and metadata of extension types are written to the `ARROW:extension:name` and
+/// `ARROW:extension:metadata` metadata keys, as in the IPC format.
+pub fn field_to_arrow_rs(field: &Field) -> PolarsResult<RsField> {
+    let mut metadata = field
+        .metadata
+        .as_deref()
+        .map(metadata_to_arrow_rs)
+        .unwrap_or_default();
+    if let ArrowDataType::Extension(ext) = &field.dtype {
+        metadata.insert(EXTENSION_NAME.to_string(), ext.name.to_string());
+        if let Some(ext_metadata) = &ext.metadata {
+            metadata.insert(EXTENSION_METADATA.to_string(), ext_metadata.to_string());
+        }
+    }
+
+    Ok(RsField::new(
+        field.name.as_str(),
+        dtype_to_arrow_rs(&field.dtype)?,
+        field.is_nullable,
+    )
+    .with_metadata(metadata))
+}
+
+/// Converts an arrow-rs `Field` to a [`Field`].
+///
+/// Extension metadata is kept as metadata of the field.
+pub fn field_from_arrow_rs(field: &RsField) -> PolarsResult<Field> {
+    Ok(Field::new(
+        PlSmallStr::from_str(field.name()),
+        dtype_from_arrow_rs(field.data_type())?,
+        field.is_nullable(),
+    )
+    .with_metadata(metadata_from_arrow_rs(field.metadata())))
+}
+
+/// Converts an [`ArrowSchema`] to an arrow-rs `Schema`.
+pub fn schema_to_arrow_rs(schema: &ArrowSchema) -> PolarsResult<RsSchema> {
+    let fields = schema
+        .iter_values()
+        .map(field_to_arrow_rs)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(RsSchema::new(fields).with_metadata(metadata_to_arrow_rs(schema.metadata())))
+}
+
+/// Converts an arrow-rs `Schema` to an [`ArrowSchema`].
+pub fn schema_from_arrow_rs(schema: &RsSchema) -> PolarsResult<ArrowSchema> {
+    let mut out = schema
+        .fields()
+        .iter()
+        .map(|field| field_from_arrow_rs(field))
+        .collect::<PolarsResult<ArrowSchema>>()?;
+    *out.metadata_mut() = metadata_from_arrow_rs(schema.metadata());
+    Ok(out)
+}
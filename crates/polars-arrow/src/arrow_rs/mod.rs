@@ -0,0 +1,60 @@
+//! Conversions between the arrays of this crate and those of the
+//! [arrow-rs](https://docs.rs/arrow) crates.
+//!
+//! The conversions share the memory of the buffers instead of copying them, like the
+//! [C Data Interface](crate::ffi) but without its indirection. Memory is only copied where the
+//! two layouts disagree on alignment:
+//! * exported views of [`BinaryViewArray`](crate::array::BinaryViewArray) and
+//!   [`Utf8ViewArray`](crate::array::Utf8ViewArray) must be aligned to 16 bytes;
+//! * imported buffers that are not aligned to their native type.
+//!
+//! Unions, 128-bit integers and `MonthDayMillis` intervals are not supported.
+mod array;
+mod buffer;
+mod datatypes;
+
+use std::sync::Arc;
+
+pub use datatypes::{
+    dtype_from_arrow_rs, dtype_to_arrow_rs, field_from_arrow_rs, field_to_arrow_rs,
+    schema_from_arrow_rs, schema_to_arrow_rs,
+};
+use polars_error::{PolarsResult, to_compute_err};
+pub use {arrow_array, arrow_buffer, arrow_data, arrow_schema};
+
+use crate::array::Array;
+use crate::record_batch::RecordBatch;
+
+/// Converts an [`Array`] to an arrow-rs array that shares its buffers.
+pub fn array_to_arrow_rs(array: &dyn Array) -> PolarsResult<arrow_array::ArrayRef> {
+    Ok(arrow_array::make_array(array::to_data(array)?))
+}
+
+/// Converts an arrow-rs array to an [`Array`] that shares its buffers.
+pub fn array_from_arrow_rs(array: &dyn arrow_array::Array) -> PolarsResult<Box<dyn Array>> {
+    array::from_data(&array.to_data())
+}
+
+/// Converts a [`RecordBatch`] to an arrow-rs `RecordBatch` that shares its buffers.
+pub fn record_batch_to_arrow_rs(batch: &RecordBatch) -> PolarsResult<arrow_array::RecordBatch> {
+    let schema = datatypes::schema_to_arrow_rs(batch.schema())?;
+    let columns = batch
+        .arrays()
+        .iter()
+        .map(|array| array_to_arrow_rs(array.as_ref()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let options = arrow_array::RecordBatchOptions::new().with_row_count(Some(batch.height()));
+    arrow_array::RecordBatch::try_new_with_options(Arc::new(schema), columns, &options)
+        .map_err(to_compute_err)
+}
+
+/// Converts an arrow-rs `RecordBatch` to a [`RecordBatch`] that shares its buffers.
+pub fn record_batch_from_arrow_rs(batch: &arrow_array::RecordBatch) -> PolarsResult<RecordBatch> {
+    let schema = datatypes::schema_from_arrow_rs(batch.schema_ref())?;
+    let arrays = batch
+        .columns()
+        .iter()
+        .map(|array| array_from_arrow_rs(array.as_ref()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    RecordBatch::try_new(batch.num_rows(), Arc::new(schema), arrays)
+}
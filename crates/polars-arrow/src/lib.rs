@@ -34,6 +34,9 @@ pub mod temporal_conversions;
 
 pub mod datatypes;
 
+#[cfg(feature = "arrow_rs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrow_rs")))]
+pub mod arrow_rs;
 pub mod ffi;
 pub mod legacy;
 pub mod pushable;
@@ -1,15 +1,20 @@
+use std::num::NonZeroU64;
 use std::sync::LazyLock;
 use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU64, Ordering};
 
 mod engine;
+mod morsel_sizing;
 mod parse;
 mod spill_format;
 mod spill_policy;
+mod tzdb_version;
 
 pub use engine::Engine;
+pub use morsel_sizing::MorselSizing;
 use polars_error::polars_warn;
 pub use spill_format::SpillFormat;
 pub use spill_policy::SpillPolicy;
+pub use tzdb_version::TzdbVersion;
 
 // Public.
 const VERBOSE: &str = "POLARS_VERBOSE";
@@ -26,9 +31,18 @@ const IDEAL_MORSEL_SIZE: &str = "POLARS_IDEAL_MORSEL_SIZE";
 const STREAMING_CHUNK_SIZE: &str = "POLARS_STREAMING_CHUNK_SIZE"; // Backwards compatibility.
 const DEFAULT_IDEAL_MORSEL_SIZE: u64 = 100_000;
 
+const ADAPTIVE_MORSEL_SIZE: &str = "POLARS_ADAPTIVE_MORSEL_SIZE";
+const DEFAULT_ADAPTIVE_MORSEL_SIZE: bool = false;
+
 const ENGINE_AFFINITY: &str = "POLARS_ENGINE_AFFINITY";
 const DEFAULT_ENGINE_AFFINITY: Engine = Engine::Auto;
 
+const TZDB_VERSION: &str = "POLARS_TZDB_VERSION";
+const DEFAULT_TZDB_VERSION: Option<TzdbVersion> = None;
+
+const JOIN_VALIDATION_MAX_REPORTED: &str = "POLARS_JOIN_VALIDATION_MAX_REPORTED";
+const DEFAULT_JOIN_VALIDATION_MAX_REPORTED: u64 = 10;
+
 // Private.
 const VERBOSE_SENSITIVE: &str = "POLARS_VERBOSE_SENSITIVE";
 const DEFAULT_VERBOSE_SENSITIVE: bool = false;
@@ -55,7 +69,10 @@ static KNOWN_OPTIONS: &[&str] = &[
     WARN_UNSTABLE,
     IDEAL_MORSEL_SIZE,
     STREAMING_CHUNK_SIZE,
+    ADAPTIVE_MORSEL_SIZE,
     ENGINE_AFFINITY,
+    TZDB_VERSION,
+    JOIN_VALIDATION_MAX_REPORTED,
     /*
     Not yet supported public options:
 
@@ -93,7 +110,10 @@ pub struct Config {
     warn_unknown_config: AtomicBool,
     warn_unstable: AtomicBool,
     ideal_morsel_size: AtomicU64,
+    adaptive_morsel_size: AtomicBool,
     engine_affinity: AtomicU8,
+    tzdb_version: AtomicU64,
+    join_validation_max_reported: AtomicU64,
 
     // Private.
     verbose_sensitive: AtomicBool,
@@ -112,7 +132,10 @@ impl Config {
             warn_unknown_config: AtomicBool::new(DEFAULT_WARN_UNKNOWN_CONFIG),
             warn_unstable: AtomicBool::new(DEFAULT_WARN_UNSTABLE),
             ideal_morsel_size: AtomicU64::new(DEFAULT_IDEAL_MORSEL_SIZE),
+            adaptive_morsel_size: AtomicBool::new(DEFAULT_ADAPTIVE_MORSEL_SIZE),
             engine_affinity: AtomicU8::new(DEFAULT_ENGINE_AFFINITY as u8),
+            tzdb_version: AtomicU64::new(tzdb_version_to_bits(DEFAULT_TZDB_VERSION)),
+            join_validation_max_reported: AtomicU64::new(DEFAULT_JOIN_VALIDATION_MAX_REPORTED),
 
             // Private.
             verbose_sensitive: AtomicBool::new(DEFAULT_VERBOSE_SENSITIVE),
@@ -164,11 +187,28 @@ impl Config {
                     .unwrap_or(DEFAULT_IDEAL_MORSEL_SIZE),
                 Ordering::Relaxed,
             ),
+            ADAPTIVE_MORSEL_SIZE => self.adaptive_morsel_size.store(
+                val.and_then(|x| parse::parse_bool(var, x))
+                    .unwrap_or(DEFAULT_ADAPTIVE_MORSEL_SIZE),
+                Ordering::Relaxed,
+            ),
             ENGINE_AFFINITY => self.engine_affinity.store(
                 val.and_then(|x| parse::parse_engine(var, x))
                     .unwrap_or(DEFAULT_ENGINE_AFFINITY) as u8,
                 Ordering::Relaxed,
             ),
+            TZDB_VERSION => self.tzdb_version.store(
+                tzdb_version_to_bits(
+                    val.and_then(|x| parse::parse_tzdb_version(var, x))
+                        .or(DEFAULT_TZDB_VERSION),
+                ),
+                Ordering::Relaxed,
+            ),
+            JOIN_VALIDATION_MAX_REPORTED => self.join_validation_max_reported.store(
+                val.and_then(|x| parse::parse_u64(var, x))
+                    .unwrap_or(DEFAULT_JOIN_VALIDATION_MAX_REPORTED),
+                Ordering::Relaxed,
+            ),
 
             // Private flags.
             VERBOSE_SENSITIVE => self.verbose_sensitive.store(
@@ -229,11 +269,27 @@ impl Config {
         self.ideal_morsel_size.load(Ordering::Relaxed)
     }
 
+    /// Whether the streaming engine adapts the size of its morsels to the throughput of the
+    /// query and the memory pressure, starting from the ideal morsel size.
+    pub fn adaptive_morsel_size(&self) -> bool {
+        self.adaptive_morsel_size.load(Ordering::Relaxed)
+    }
+
     /// Which engine to use by default.
     pub fn engine_affinity(&self) -> Engine {
         Engine::from_discriminant(self.engine_affinity.load(Ordering::Relaxed))
     }
 
+    /// The version of the time zone database that time zones must be resolved with, if pinned.
+    pub fn tzdb_version(&self) -> Option<TzdbVersion> {
+        NonZeroU64::new(self.tzdb_version.load(Ordering::Relaxed)).map(TzdbVersion::from_bits)
+    }
+
+    /// The maximum number of violating keys listed in the error of a failed join validation.
+    pub fn join_validation_max_reported(&self) -> u64 {
+        self.join_validation_max_reported.load(Ordering::Relaxed)
+    }
+
     /// Whether we should do verbose printing on sensitive information.
     pub fn verbose_sensitive(&self) -> bool {
         self.verbose_sensitive.load(Ordering::Relaxed)
@@ -260,6 +316,10 @@ impl Config {
     }
 }
 
+fn tzdb_version_to_bits(version: Option<TzdbVersion>) -> u64 {
+    version.map_or(0, |v| v.to_bits().get())
+}
+
 pub fn config() -> &'static Config {
     static CONFIG: LazyLock<Config> = LazyLock::new(Config::new);
     &CONFIG
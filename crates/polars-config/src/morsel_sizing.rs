@@ -0,0 +1,41 @@
+use crate::config;
+
+/// How the streaming engine sizes the morsels of a query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MorselSizing {
+    /// Morsels of a fixed number of rows.
+    Fixed(usize),
+    /// Morsels that start at a number of rows, and are grown while they are consumed quickly
+    /// and shrunk when they are consumed slowly, hold many bytes, or memory is running low.
+    Adaptive(usize),
+}
+
+impl MorselSizing {
+    /// The sizing set by the `POLARS_IDEAL_MORSEL_SIZE` and `POLARS_ADAPTIVE_MORSEL_SIZE`
+    /// environment variables.
+    pub fn from_config() -> Self {
+        Self::from_config_with(None, None)
+    }
+
+    /// The sizing from the config, with the number of rows and whether the sizing is adaptive
+    /// replaced where given.
+    pub fn from_config_with(size: Option<usize>, adaptive: Option<bool>) -> Self {
+        let size = size.unwrap_or(config().ideal_morsel_size() as usize);
+        if adaptive.unwrap_or(config().adaptive_morsel_size()) {
+            Self::Adaptive(size)
+        } else {
+            Self::Fixed(size)
+        }
+    }
+
+    /// The number of rows of the first morsels.
+    pub fn initial_size(&self) -> usize {
+        match self {
+            Self::Fixed(size) | Self::Adaptive(size) => (*size).max(1),
+        }
+    }
+
+    pub fn is_adaptive(&self) -> bool {
+        matches!(self, Self::Adaptive(_))
+    }
+}
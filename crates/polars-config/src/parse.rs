@@ -1,6 +1,6 @@
 use polars_error::polars_warn;
 
-use crate::{Engine, SpillFormat, SpillPolicy};
+use crate::{Engine, SpillFormat, SpillPolicy, TzdbVersion};
 
 pub fn parse_bool(var: &str, val: &str) -> Option<bool> {
     match val.trim_ascii() {
@@ -50,3 +50,18 @@ pub fn parse_spill_format(var: &str, val: &str) -> Option<SpillFormat> {
         },
     }
 }
+
+pub fn parse_tzdb_version(var: &str, val: &str) -> Option<TzdbVersion> {
+    let val = val.trim_ascii();
+    // An empty value doesn't pin a version.
+    if val.is_empty() {
+        return None;
+    }
+    match val.parse::<TzdbVersion>() {
+        Ok(x) => Some(x),
+        Err(e) => {
+            polars_warn!("illegal value '{val}' found while parsing option '{var}' ({e})");
+            None
+        },
+    }
+}
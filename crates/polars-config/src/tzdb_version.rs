@@ -0,0 +1,54 @@
+use std::fmt;
+use std::num::NonZeroU64;
+use std::str::FromStr;
+
+/// A version of the IANA time zone database, like `2025b`.
+///
+/// The version is at most 8 ASCII bytes, so that it fits in an atomic.
+#[derive(Clone, Debug, Copy, Eq, PartialEq, Hash)]
+pub struct TzdbVersion {
+    bytes: [u8; 8],
+}
+
+impl fmt::Display for TzdbVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for TzdbVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, release) = s.split_at_checked(4).unwrap_or((s, ""));
+        let valid = year.bytes().all(|b| b.is_ascii_digit())
+            && (1..=4).contains(&release.len())
+            && release.bytes().all(|b| b.is_ascii_lowercase());
+        if !valid {
+            return Err(format!(
+                "a time zone database version must be a year followed by a letter, like '2025b', got {s}"
+            ));
+        }
+        let mut bytes = [0; 8];
+        bytes[..s.len()].copy_from_slice(s.as_bytes());
+        Ok(Self { bytes })
+    }
+}
+
+impl TzdbVersion {
+    pub(crate) fn to_bits(self) -> NonZeroU64 {
+        NonZeroU64::new(u64::from_le_bytes(self.bytes)).unwrap()
+    }
+
+    pub(crate) fn from_bits(bits: NonZeroU64) -> Self {
+        Self {
+            bytes: bits.get().to_le_bytes(),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        let len = self.bytes.iter().position(|b| *b == 0).unwrap_or(8);
+        // SAFETY: the bytes were validated to be ASCII when parsed.
+        unsafe { std::str::from_utf8_unchecked(&self.bytes[..len]) }
+    }
+}
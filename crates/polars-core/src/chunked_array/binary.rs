@@ -1,10 +1,16 @@
 use std::hash::BuildHasher;
 
+use arrow::bitmap::utils::ZipValidity;
+#[cfg(feature = "approx_unique")]
+use polars_compute::hyperloglogplus::HyperLogLog;
+#[cfg(feature = "approx_unique")]
+use polars_utils::aliases::PlFixedStateQuality;
 use polars_utils::aliases::PlRandomState;
 use polars_utils::hashing::BytesHash;
 use rayon::prelude::*;
 
 use crate::POOL;
+use crate::hashing::ViewHash;
 use crate::prelude::*;
 use crate::utils::{_set_partition_size, _split_offsets};
 
@@ -35,6 +41,31 @@ where
     byte_hashes
 }
 
+#[inline]
+fn fill_view_hashes<'a, S: BuildHasher>(
+    ca: &'a BinaryChunked,
+    null_h: u64,
+    hb: S,
+) -> Vec<ViewHash<'a>> {
+    let mut view_hashes = Vec::with_capacity(ca.len());
+    for arr in ca.downcast_iter() {
+        let buffers = &arr.data_buffers()[..];
+        let views = ZipValidity::new_with_validity(arr.views().iter(), arr.validity());
+        view_hashes.extend(views.map(|opt_v| {
+            let hash = match opt_v {
+                // Inlined values are hashed by their view, which is zero padded.
+                Some(v) if v.is_inline() => hb.hash_one(v.as_u128()),
+                Some(v) => hb.hash_one(unsafe { v.get_external_slice_unchecked(buffers) }),
+                None => null_h,
+            };
+            // SAFETY:
+            // the views are valid for the buffers of their array.
+            unsafe { ViewHash::new(opt_v.copied(), buffers, hash) }
+        }));
+    }
+    view_hashes
+}
+
 impl<T> ChunkedArray<T>
 where
     T: PolarsDataType,
@@ -76,3 +107,53 @@ where
         }
     }
 }
+
+impl BinaryChunked {
+    /// Hash the views of the values, only hashing the buffers of values that are not inlined.
+    #[allow(clippy::needless_lifetimes)]
+    pub fn to_view_hashes<'a>(
+        &'a self,
+        mut multithreaded: bool,
+        hb: PlRandomState,
+    ) -> Vec<Vec<ViewHash<'a>>> {
+        multithreaded &= POOL.current_num_threads() > 1;
+        let null_h = hb.hash_one(0xde259df92c607d49_u64);
+
+        if multithreaded {
+            let n_partitions = _set_partition_size();
+
+            let split = _split_offsets(self.len(), n_partitions);
+
+            POOL.install(|| {
+                split
+                    .into_par_iter()
+                    .map(|(offset, len)| {
+                        let ca = self.slice(offset as i64, len);
+                        let view_hashes = fill_view_hashes(&ca, null_h, hb.clone());
+
+                        // SAFETY:
+                        // the underlying data is tied to self
+                        unsafe {
+                            std::mem::transmute::<Vec<ViewHash<'_>>, Vec<ViewHash<'a>>>(view_hashes)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+        } else {
+            vec![fill_view_hashes(self, null_h, hb)]
+        }
+    }
+
+    /// Estimate the number of distinct values, hashing the views of the values with a fixed
+    /// seed so that the estimate is deterministic.
+    #[cfg(feature = "approx_unique")]
+    pub(crate) fn approx_n_unique_views(&self) -> IdxSize {
+        let hb = PlFixedStateQuality::with_seed(0);
+        let null_h = hb.hash_one(0xde259df92c607d49_u64);
+        let mut hllp = HyperLogLog::new();
+        for vh in fill_view_hashes(self, null_h, hb) {
+            hllp.add(&vh);
+        }
+        hllp.count() as IdxSize
+    }
+}
@@ -1,3 +1,5 @@
+use arrow::datatypes::Metadata;
+
 use crate::chunked_array::cast::CastOptions;
 use crate::prelude::*;
 
@@ -25,8 +27,13 @@ impl ExtensionChunked {
         self.storage.rename(name);
     }
 
+    pub fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.storage.set_metadata(metadata);
+    }
+
     pub fn field(&self) -> Field {
         Field::new(self.storage.name().clone(), self.dtype.clone())
+            .with_metadata(self.storage.metadata())
     }
 
     pub fn dtype(&self) -> &DataType {
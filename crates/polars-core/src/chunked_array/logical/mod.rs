@@ -26,6 +26,7 @@ pub use extension::*;
 mod time;
 use std::marker::PhantomData;
 
+use arrow::datatypes::Metadata;
 #[cfg(feature = "dtype-time")]
 pub use time::*;
 
@@ -98,6 +99,11 @@ where
         self.phys.rename(name)
     }
 
+    #[inline(always)]
+    pub fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.phys.set_metadata(metadata)
+    }
+
     #[inline(always)]
     pub fn len(&self) -> usize {
         self.phys.len()
@@ -146,8 +152,9 @@ where
 
     #[inline(always)]
     pub fn field(&self) -> Field {
-        let name = self.phys.ref_field().name();
-        Field::new(name.clone(), LogicalType::dtype(self).clone())
+        let field = self.phys.ref_field();
+        Field::new(field.name().clone(), LogicalType::dtype(self).clone())
+            .with_metadata(field.metadata().cloned())
     }
 
     #[inline(always)]
@@ -6,6 +6,7 @@ use arrow::array::*;
 use arrow::bitmap::Bitmap;
 use arrow::compute::concatenate::concatenate_unchecked;
 use arrow::compute::utils::combine_validities_and;
+use arrow::datatypes::Metadata;
 use polars_compute::filter::filter_with_bitmap;
 
 use crate::prelude::{ChunkTakeUnchecked, *};
@@ -507,7 +508,12 @@ impl<T: PolarsDataType> ChunkedArray<T> {
 
     /// Rename this [`ChunkedArray`].
     pub fn rename(&mut self, name: PlSmallStr) {
-        self.field = Arc::new(Field::new(name, self.field.dtype().clone()));
+        Arc::make_mut(&mut self.field).set_name(name);
+    }
+
+    /// Set the user metadata of the field of this [`ChunkedArray`].
+    pub fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        Arc::make_mut(&mut self.field).set_metadata(metadata);
     }
 
     /// Return this [`ChunkedArray`] with a new name.
@@ -121,6 +121,25 @@ pub trait ChunkRollApply: AsRefDataType {
         Self: Sized;
 }
 
+/// A reduction over a rolling window that is updated as values enter and leave the window,
+/// instead of being recomputed for every window. See [`ChunkedArray::rolling_map_reducer`].
+#[cfg(feature = "rolling_window")]
+pub trait RollingReducer<T> {
+    type Output: PolarsNumericType;
+
+    /// Reset to the state of an empty window.
+    fn init(&mut self);
+
+    /// A non-null value enters the window.
+    fn update(&mut self, value: T);
+
+    /// A non-null value leaves the window. Values leave in the order in which they entered.
+    fn remove(&mut self, value: T);
+
+    /// The output for the current window.
+    fn emit(&mut self) -> Option<<Self::Output as PolarsNumericType>::Native>;
+}
+
 pub trait ChunkTake<Idx: ?Sized>: ChunkTakeUnchecked<Idx> {
     /// Gather values from ChunkedArray by index.
     fn take(&self, indices: &Idx) -> PolarsResult<Self>
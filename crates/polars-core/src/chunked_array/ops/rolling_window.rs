@@ -211,4 +211,63 @@ mod inner_mod {
             }
         }
     }
+
+    impl<T: PolarsNumericType> ChunkedArray<T> {
+        /// Apply a [`RollingReducer`] over a rolling window.
+        ///
+        /// Every value enters and leaves the reducer once, so unlike [`ChunkRollApply::rolling_map`]
+        /// this is `O(n)` in the length of the array, independent of the window size. Null values
+        /// are skipped and windows with fewer than `min_periods` non-null values are null.
+        /// Weights are not supported.
+        pub fn rolling_map_reducer<R: RollingReducer<T::Native>>(
+            &self,
+            mut reducer: R,
+            options: RollingOptionsFixedWindow,
+        ) -> PolarsResult<ChunkedArray<R::Output>> {
+            check_input(options.window_size, options.min_periods)?;
+            polars_ensure!(
+                options.window_size > 0,
+                ComputeError: "`window_size` should be positive"
+            );
+            polars_ensure!(
+                options.weights.is_none(),
+                InvalidOperation: "`rolling_map_reducer` does not support weights"
+            );
+
+            let ca = self.rechunk();
+            let arr = ca.downcast_as_array();
+            let get = |i: usize| arr.is_valid(i).then(|| arr.value(i));
+            let len = self.len();
+            let window_size = std::cmp::min(len, options.window_size);
+
+            let mut builder = PrimitiveChunkedBuilder::<R::Output>::new(self.name().clone(), len);
+            reducer.init();
+            // The reducer holds the values in `lo..hi`, of which `valid` are non-null.
+            let (mut lo, mut hi, mut valid) = (0, 0, 0);
+            for idx in 0..len {
+                let (start, size) = window_edges(idx, len, window_size, options.center);
+                while lo < start {
+                    if let Some(v) = get(lo) {
+                        reducer.remove(v);
+                        valid -= 1;
+                    }
+                    lo += 1;
+                }
+                while hi < start + size {
+                    if let Some(v) = get(hi) {
+                        reducer.update(v);
+                        valid += 1;
+                    }
+                    hi += 1;
+                }
+
+                if valid < options.min_periods {
+                    builder.append_null();
+                } else {
+                    builder.append_option(reducer.emit());
+                }
+            }
+            Ok(builder.finish())
+        }
+    }
 }
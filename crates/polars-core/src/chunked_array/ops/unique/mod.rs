@@ -3,12 +3,18 @@ use std::ops::Deref;
 
 use arrow::bitmap::MutableBitmap;
 use polars_compute::unique::BooleanUniqueKernelState;
+use polars_utils::hashing::{DirtyHash, hash_to_partition};
 use polars_utils::total_ord::{ToTotalOrd, TotalHash, TotalOrdWrap};
+use rayon::prelude::*;
 
-use crate::hashing::_HASHMAP_INIT_SIZE;
+use crate::POOL;
+use crate::hashing::{_HASHMAP_INIT_SIZE, ViewHash};
 use crate::prelude::*;
 use crate::series::IsSorted;
 
+/// The minimum length of a binary array for which its distinct values are found in parallel.
+const PARALLEL_UNIQUE_MIN_LEN: usize = 1 << 16;
+
 fn finish_is_unique_helper(
     unique_idx: Vec<IdxSize>,
     len: IdxSize,
@@ -213,32 +219,45 @@ impl ChunkUnique for StringChunked {
     }
 }
 
+/// Get the distinct values of `ca`, by hashing the views of the values. Large arrays are
+/// partitioned by hash, so that every thread builds the hash set of a single partition.
+fn unique_view_hashes(ca: &BinaryChunked) -> Vec<ViewHash<'_>> {
+    let multithreaded = ca.len() > PARALLEL_UNIQUE_MIN_LEN;
+    let vh = ca.to_view_hashes(multithreaded, Default::default());
+
+    if vh.len() == 1 {
+        let mut set = PlHashSet::with_capacity(std::cmp::min(_HASHMAP_INIT_SIZE, ca.len()));
+        set.extend(vh[0].iter().copied());
+        return set.into_iter().collect();
+    }
+
+    let n_partitions = vh.len();
+    POOL.install(|| {
+        (0..n_partitions)
+            .into_par_iter()
+            .flat_map_iter(|partition| {
+                let mut set = PlHashSet::new();
+                for keys in &vh {
+                    set.extend(
+                        keys.iter().copied().filter(|k| {
+                            hash_to_partition(k.dirty_hash(), n_partitions) == partition
+                        }),
+                    );
+                }
+                set.into_iter()
+            })
+            .collect()
+    })
+}
+
 impl ChunkUnique for BinaryChunked {
     fn unique(&self) -> PolarsResult<Self> {
-        match self.null_count() {
-            0 => {
-                let mut set =
-                    PlHashSet::with_capacity(std::cmp::min(_HASHMAP_INIT_SIZE, self.len()));
-                for arr in self.downcast_iter() {
-                    set.extend(arr.values_iter())
-                }
-                Ok(BinaryChunked::from_iter_values(
-                    self.name().clone(),
-                    set.iter().copied(),
-                ))
-            },
-            _ => {
-                let mut set =
-                    PlHashSet::with_capacity(std::cmp::min(_HASHMAP_INIT_SIZE, self.len()));
-                for arr in self.downcast_iter() {
-                    set.extend(arr.iter())
-                }
-                Ok(BinaryChunked::from_iter_options(
-                    self.name().clone(),
-                    set.iter().copied(),
-                ))
-            },
-        }
+        let keys = unique_view_hashes(self);
+        let values = keys.iter().map(|k| k.payload());
+        Ok(BinaryChunked::from_iter_options(
+            self.name().clone(),
+            values,
+        ))
     }
 
     fn arg_unique(&self) -> PolarsResult<IdxCa> {
@@ -246,18 +265,7 @@ impl ChunkUnique for BinaryChunked {
     }
 
     fn n_unique(&self) -> PolarsResult<usize> {
-        let mut set: PlHashSet<&[u8]> = PlHashSet::new();
-        if self.null_count() > 0 {
-            for arr in self.downcast_iter() {
-                set.extend(arr.into_iter().flatten())
-            }
-            Ok(set.len() + 1)
-        } else {
-            for arr in self.downcast_iter() {
-                set.extend(arr.values_iter())
-            }
-            Ok(set.len())
-        }
+        Ok(unique_view_hashes(self).len())
     }
 
     fn unique_id(&self) -> PolarsResult<(IdxSize, Vec<IdxSize>)> {
@@ -410,6 +418,43 @@ mod test {
         );
     }
 
+    #[test]
+    fn unique_string_views() {
+        // Long values with the same prefix and length only differ in their buffers.
+        let values = [
+            Some("a"),
+            Some("a long string value 1"),
+            None,
+            Some("a long string value 2"),
+            Some("a"),
+            Some("a long string value 1"),
+            Some("exactly12byt"),
+        ];
+        let mut ca = StringChunked::new(PlSmallStr::EMPTY, &values);
+        ca.append(&StringChunked::new(PlSmallStr::EMPTY, &values))
+            .unwrap();
+        assert_eq!(ca.n_unique().unwrap(), 5);
+        assert_eq!(
+            Vec::from(&ca.unique().unwrap().sort(false)),
+            &[
+                None,
+                Some("a"),
+                Some("a long string value 1"),
+                Some("a long string value 2"),
+                Some("exactly12byt"),
+            ]
+        );
+        let groups = ca.group_tuples(false, true).unwrap();
+        assert_eq!(groups.len(), 5);
+
+        // Large arrays are deduplicated in parallel.
+        let ca: StringChunked = (0..100_000)
+            .map(|i| format!("a long string value {}", i % 1000))
+            .collect();
+        assert_eq!(ca.n_unique().unwrap(), 1000);
+        assert_eq!(ca.group_tuples(true, false).unwrap().len(), 1000);
+    }
+
     #[test]
     fn arg_unique() {
         let ca =
@@ -238,6 +238,100 @@ impl DataFrame {
             None => Ok(self.clear()),
         }
     }
+
+    #[cfg(feature = "algorithm_group_by")]
+    /// Get the row indices of a split of this [`DataFrame`] into parts with the given
+    /// `fractions` of the rows, e.g. `[0.8, 0.2]` for a train/test split.
+    ///
+    /// With `stratify_by`, every distinct combination of values in those columns is split on its
+    /// own, so that every part holds the same fraction of every group (up to rounding). Unless
+    /// `shuffle` is set, every part is a contiguous slice of its group and keeps the original row
+    /// order.
+    pub fn split_indices(
+        &self,
+        fractions: &[f64],
+        shuffle: bool,
+        seed: Option<u64>,
+        stratify_by: Option<&[PlSmallStr]>,
+    ) -> PolarsResult<Vec<IdxCa>> {
+        polars_ensure!(
+            !fractions.is_empty() && fractions.iter().all(|f| *f > 0.0),
+            ComputeError: "split fractions must be positive"
+        );
+        let total = fractions.iter().sum::<f64>();
+        polars_ensure!(
+            (total - 1.0).abs() < 1e-9,
+            ComputeError: "split fractions must sum to 1, got {}", total
+        );
+
+        let groups = match stratify_by {
+            Some(by) if !by.is_empty() => self
+                .group_by_stable(by)?
+                .get_groups()
+                .iter()
+                .map(|group| match group {
+                    GroupsIndicator::Idx((_, idx)) => idx.to_vec(),
+                    GroupsIndicator::Slice([first, len]) => (first..first + len).collect(),
+                })
+                .collect::<Vec<_>>(),
+            _ => vec![(0..self.height() as IdxSize).collect()],
+        };
+
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+        let mut parts = vec![Vec::new(); fractions.len()];
+        for mut group in groups {
+            if shuffle {
+                group.shuffle(&mut rng);
+            }
+            let n = group.len();
+            let mut start = 0;
+            let mut cum_frac = 0.0;
+            for (i, frac) in fractions.iter().enumerate() {
+                cum_frac += frac;
+                // The last part takes the remainder, so that no row is lost to rounding.
+                let end = if i + 1 == fractions.len() {
+                    n
+                } else {
+                    ((cum_frac * n as f64).round() as usize).clamp(start, n)
+                };
+                parts[i].extend_from_slice(&group[start..end]);
+                start = end;
+            }
+        }
+
+        let stratified = stratify_by.is_some_and(|by| !by.is_empty());
+        Ok(parts
+            .into_iter()
+            .map(|mut idx| {
+                // Interleave the groups again.
+                if shuffle {
+                    idx.shuffle(&mut rng);
+                } else if stratified {
+                    idx.sort_unstable();
+                }
+                IdxCa::from_vec(PlSmallStr::EMPTY, idx)
+            })
+            .collect())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    /// Split this [`DataFrame`] into parts with the given `fractions` of the rows.
+    ///
+    /// See [`DataFrame::split_indices`].
+    pub fn split(
+        &self,
+        fractions: &[f64],
+        shuffle: bool,
+        seed: Option<u64>,
+        stratify_by: Option<&[PlSmallStr]>,
+    ) -> PolarsResult<Vec<Self>> {
+        let parts = self.split_indices(fractions, shuffle, seed, stratify_by)?;
+        // SAFETY: the indices are within bounds.
+        Ok(parts
+            .iter()
+            .map(|idx| unsafe { self.take_unchecked(idx) })
+            .collect())
+    }
 }
 
 impl<T> ChunkedArray<T>
@@ -391,4 +485,29 @@ mod test {
             .is_ok()
         );
     }
+
+    #[test]
+    #[cfg(feature = "algorithm_group_by")]
+    fn test_split() -> PolarsResult<()> {
+        let df = df![
+            "a" => (0..10).collect::<Vec<i32>>(),
+            "g" => ["x", "x", "x", "x", "x", "x", "y", "y", "y", "y"],
+        ]?;
+
+        let parts = df.split(&[0.8, 0.2], false, None, None)?;
+        assert_eq!(parts[0].height(), 8);
+        assert!(parts[1].equals(&df.slice(8, 2)));
+
+        let stratify_by = [PlSmallStr::from_static("g")];
+        let parts = df.split_indices(&[0.5, 0.5], true, Some(0), Some(&stratify_by))?;
+        let mut idx = parts[0].into_no_null_iter().collect::<Vec<_>>();
+        assert_eq!(idx.iter().filter(|i| **i < 6).count(), 3);
+        assert_eq!(idx.len(), 5);
+        idx.extend(parts[1].into_no_null_iter());
+        idx.sort_unstable();
+        assert_eq!(idx, (0..10).collect::<Vec<IdxSize>>());
+
+        assert!(df.split(&[0.5, 0.4], false, None, None).is_err());
+        Ok(())
+    }
 }
@@ -1,6 +1,9 @@
 use chrono::Duration as ChronoDuration;
 
-use crate::fmt::{fmt_duration_string, iso_duration_string};
+use crate::fmt::{
+    fmt_duration_string, human_duration_string, iso_duration_string, parse_duration_format,
+    strftime_duration_string,
+};
 use crate::prelude::DataType::Duration;
 use crate::prelude::*;
 
@@ -62,8 +65,8 @@ impl DurationChunked {
         self.dtype = Duration(tu);
     }
 
-    /// Convert from [`Duration`] to String; note that `strftime` format
-    /// strings are not supported, only the specifiers 'iso' and 'polars'.
+    /// Convert from [`Duration`] to String, using one of the specifiers 'iso', 'polars' and
+    /// 'human', or a strftime-like format string such as "%H:%M:%S%.3f".
     pub fn to_string(&self, format: &str) -> PolarsResult<StringChunked> {
         // the duration string functions below can reuse this string buffer
         let mut s = String::with_capacity(32);
@@ -90,9 +93,30 @@ impl DurationChunked {
                         });
                 Ok(out)
             },
+            "human" => {
+                let out: StringChunked =
+                    self.phys
+                        .apply_nonnull_values_generic(DataType::String, |v: i64| {
+                            s.clear();
+                            human_duration_string(&mut s, v, self.time_unit());
+                            s.clone()
+                        });
+                Ok(out)
+            },
+            _ if format.contains('%') => {
+                let items = parse_duration_format(format)?;
+                let out: StringChunked =
+                    self.phys
+                        .apply_nonnull_values_generic(DataType::String, |v: i64| {
+                            s.clear();
+                            strftime_duration_string(&mut s, v, self.time_unit(), &items);
+                            s.clone()
+                        });
+                Ok(out)
+            },
             _ => {
                 polars_bail!(
-                    InvalidOperation: "format {:?} not supported for Duration type (expected one of 'iso' or 'polars')",
+                    InvalidOperation: "format {:?} not supported for Duration type (expected one of 'iso', 'polars', 'human' or a format string with '%' specifiers)",
                     format
                 )
             },
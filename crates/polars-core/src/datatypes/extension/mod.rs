@@ -6,9 +6,15 @@ use std::hash::{Hash, Hasher};
 use crate::datatypes::DataType;
 
 mod generic;
+mod opaque;
 mod registry;
 
 use generic::GenericExtensionType;
+pub use opaque::{
+    OpaqueType, OpaqueVTable, OpaqueValue, PolarsOpaque, opaque_ids, opaque_series, opaque_values,
+    opaque_vtable, register_opaque_type,
+};
+pub(crate) use opaque::{opaque_ranks, opaque_vec_hash, opaque_vec_hash_combine};
 pub use registry::{
     UnknownExtensionTypeBehavior, get_extension_type_or_generic, get_extension_type_or_storage,
     register_extension_type, set_unknown_extension_type_behavior, unregister_extension_type,
@@ -0,0 +1,530 @@
+//! Opaque dtypes: user types stored in a column by their serialization, which are cloned, dropped,
+//! compared and hashed through a vtable.
+use std::any::Any;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use polars_error::{PolarsResult, polars_bail};
+use polars_utils::aliases::{PlFixedStateQuality, PlHashMap, PlSeedableRandomStateQuality};
+use polars_utils::hashing::_boost_hash_combine;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::{
+    ExtensionTypeFactory, ExtensionTypeImpl, ExtensionTypeInstance, register_extension_type,
+};
+use crate::hashing::vector_hasher::get_null_hash_value;
+use crate::prelude::*;
+
+/// A user type that can be stored in a column of an opaque dtype, see [`register_opaque_type`].
+///
+/// Unlike [`PolarsObject`], the values are not kept alive in the column: they are serialized
+/// when the column is built and deserialized when they are read. The column itself only holds
+/// the serializations, so it can be sent across threads without calling back into the user type,
+/// and it is written to IPC as an extension type over binary storage.
+///
+/// Group-bys, joins, uniques and sorts on the column compare and hash the values through the
+/// [`OpaqueVTable`] of the type, so values that are equal by [`Ord`] are equal in the column
+/// even if their serializations differ.
+pub trait PolarsOpaque: Clone + Ord + Hash + Send + Sync + 'static {
+    /// The name of the type, which is the name of its extension type.
+    fn type_name() -> &'static str;
+
+    /// Append the serialization of `self` to `buf`.
+    fn serialize(&self, buf: &mut Vec<u8>);
+
+    /// Deserialize a value.
+    fn deserialize(bytes: &[u8]) -> PolarsResult<Self>;
+}
+
+type OpaqueBox = Box<dyn Any + Send + Sync>;
+
+/// The operations on the values of a [`PolarsOpaque`] type, erased over the type.
+pub struct OpaqueVTable {
+    type_name: &'static str,
+    clone: fn(&dyn Any) -> OpaqueBox,
+    drop: fn(OpaqueBox),
+    serialize: fn(&dyn Any, &mut Vec<u8>),
+    deserialize: fn(&[u8]) -> PolarsResult<OpaqueBox>,
+    compare: fn(&dyn Any, &dyn Any) -> Ordering,
+    hash: fn(&dyn Any, &mut dyn Hasher),
+}
+
+fn downcast<T: 'static>(value: &dyn Any) -> &T {
+    value
+        .downcast_ref()
+        .expect("opaque value should have the type of its vtable")
+}
+
+impl OpaqueVTable {
+    /// The vtable of `T`.
+    pub fn of<T: PolarsOpaque>() -> Self {
+        Self {
+            type_name: T::type_name(),
+            clone: |value| Box::new(downcast::<T>(value).clone()),
+            drop: |value| drop(value.downcast::<T>()),
+            serialize: |value, buf| downcast::<T>(value).serialize(buf),
+            deserialize: |bytes| Ok(Box::new(T::deserialize(bytes)?)),
+            compare: |left, right| downcast::<T>(left).cmp(downcast::<T>(right)),
+            hash: |value, mut state| downcast::<T>(value).hash(&mut state),
+        }
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Deserialize a value of the type of this vtable.
+    pub fn deserialize(self: &Arc<Self>, bytes: &[u8]) -> PolarsResult<OpaqueValue> {
+        Ok(OpaqueValue {
+            value: Some((self.deserialize)(bytes)?),
+            vtable: self.clone(),
+        })
+    }
+}
+
+/// A value of an opaque dtype, which is cloned, dropped, compared and hashed through the
+/// [`OpaqueVTable`] of its type.
+pub struct OpaqueValue {
+    // Only taken when the value is dropped.
+    value: Option<OpaqueBox>,
+    vtable: Arc<OpaqueVTable>,
+}
+
+impl OpaqueValue {
+    fn inner(&self) -> &dyn Any {
+        self.value.as_deref().unwrap()
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        self.vtable.type_name
+    }
+
+    pub fn downcast_ref<T: PolarsOpaque>(&self) -> Option<&T> {
+        self.inner().downcast_ref()
+    }
+
+    /// Append the serialization of the value to `buf`.
+    pub fn serialize(&self, buf: &mut Vec<u8>) {
+        (self.vtable.serialize)(self.inner(), buf)
+    }
+}
+
+impl Clone for OpaqueValue {
+    fn clone(&self) -> Self {
+        Self {
+            value: Some((self.vtable.clone)(self.inner())),
+            vtable: self.vtable.clone(),
+        }
+    }
+}
+
+impl Drop for OpaqueValue {
+    fn drop(&mut self) {
+        if let Some(value) = self.value.take() {
+            (self.vtable.drop)(value)
+        }
+    }
+}
+
+impl PartialEq for OpaqueValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OpaqueValue {}
+
+impl PartialOrd for OpaqueValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OpaqueValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.type_name()
+            .cmp(other.type_name())
+            .then_with(|| (self.vtable.compare)(self.inner(), other.inner()))
+    }
+}
+
+impl Hash for OpaqueValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.vtable.hash)(self.inner(), state)
+    }
+}
+
+impl Debug for OpaqueValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "opaque[{}]", self.type_name())
+    }
+}
+
+/// The extension type of the columns of a [`PolarsOpaque`] type.
+pub struct OpaqueType {
+    vtable: Arc<OpaqueVTable>,
+}
+
+impl OpaqueType {
+    pub fn new(vtable: Arc<OpaqueVTable>) -> Self {
+        Self { vtable }
+    }
+
+    /// The dtype of the columns holding values of `T`.
+    pub fn dtype<T: PolarsOpaque>() -> DataType {
+        let typ = Self::new(Arc::new(OpaqueVTable::of::<T>()));
+        DataType::Extension(
+            ExtensionTypeInstance(Box::new(typ)),
+            Box::new(DataType::Binary),
+        )
+    }
+
+    pub fn vtable(&self) -> &Arc<OpaqueVTable> {
+        &self.vtable
+    }
+}
+
+impl ExtensionTypeImpl for OpaqueType {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.vtable.type_name)
+    }
+
+    fn serialize_metadata(&self) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    fn dyn_clone(&self) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(Self::new(self.vtable.clone()))
+    }
+
+    fn dyn_eq(&self, other: &dyn ExtensionTypeImpl) -> bool {
+        let Some(other) = (other as &dyn Any).downcast_ref::<OpaqueType>() else {
+            return false;
+        };
+
+        self.vtable.type_name == other.vtable.type_name
+    }
+
+    fn dyn_hash(&self) -> u64 {
+        PlFixedStateQuality::default().hash_one(self.vtable.type_name)
+    }
+
+    fn dyn_display(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("opaque[{}]", self.vtable.type_name))
+    }
+
+    fn dyn_debug(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("Opaque('{}')", self.vtable.type_name))
+    }
+}
+
+struct OpaqueTypeFactory<T>(PhantomData<fn() -> T>);
+
+impl<T: PolarsOpaque> ExtensionTypeFactory for OpaqueTypeFactory<T> {
+    fn create_type_instance(
+        &self,
+        _name: &str,
+        _storage: &DataType,
+        _metadata: Option<&str>,
+    ) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(OpaqueType::new(Arc::new(OpaqueVTable::of::<T>())))
+    }
+}
+
+/// Register the opaque type `T`, so that its columns are read back with their opaque dtype from
+/// IPC. Unregistered opaque types are read like other unknown extension types.
+pub fn register_opaque_type<T: PolarsOpaque>() -> PolarsResult<()> {
+    register_extension_type(
+        T::type_name(),
+        Some(Arc::new(OpaqueTypeFactory::<T>(PhantomData))),
+    )
+}
+
+/// Build a column of an opaque dtype from `values`.
+pub fn opaque_series<'a, T: PolarsOpaque>(
+    name: PlSmallStr,
+    values: impl IntoIterator<Item = Option<&'a T>>,
+) -> Series {
+    let mut buf = Vec::new();
+    let storage = values
+        .into_iter()
+        .map(|opt_v| {
+            opt_v.map(|v| {
+                buf.clear();
+                v.serialize(&mut buf);
+                buf.clone()
+            })
+        })
+        .collect::<BinaryChunked>()
+        .with_name(name);
+
+    let DataType::Extension(typ, _) = OpaqueType::dtype::<T>() else {
+        unreachable!()
+    };
+    storage.into_series().into_extension(typ)
+}
+
+/// Deserialize the values of a column of the opaque dtype of `T`.
+pub fn opaque_values<T: PolarsOpaque>(s: &Series) -> PolarsResult<Vec<Option<T>>> {
+    if opaque_vtable(s.dtype()).is_none_or(|vtable| vtable.type_name != T::type_name()) {
+        polars_bail!(
+            SchemaMismatch: "expected a column of dtype opaque[{}], got {}",
+            T::type_name(), s.dtype()
+        );
+    }
+
+    s.ext()?
+        .storage()
+        .binary()?
+        .iter()
+        .map(|opt_bytes| opt_bytes.map(T::deserialize).transpose())
+        .collect()
+}
+
+/// The vtable of `dtype` if it is an opaque dtype.
+pub fn opaque_vtable(dtype: &DataType) -> Option<&Arc<OpaqueVTable>> {
+    let DataType::Extension(typ, _) = dtype else {
+        return None;
+    };
+    (&*typ.0 as &dyn Any)
+        .downcast_ref::<OpaqueType>()
+        .map(|typ| &typ.vtable)
+}
+
+/// Deserialize the values of a column of an opaque dtype through its vtable.
+fn opaque_value_vec(ext: &ExtensionChunked) -> PolarsResult<Vec<Option<OpaqueValue>>> {
+    let Some(vtable) = opaque_vtable(ext.dtype()) else {
+        polars_bail!(SchemaMismatch: "expected a column of an opaque dtype, got {}", ext.dtype());
+    };
+    ext.storage()
+        .binary()?
+        .iter()
+        .map(|opt_bytes| opt_bytes.map(|bytes| vtable.deserialize(bytes)).transpose())
+        .collect()
+}
+
+/// Number the values of columns of the same opaque dtype, so that values that are equal by the
+/// vtable of the type get the same id in all the columns. Nulls stay null.
+///
+/// The ids replace the values where rows are matched by their values, like in group-bys and
+/// joins.
+pub fn opaque_ids(columns: &[&Series]) -> PolarsResult<Vec<IdxCa>> {
+    let mut ids = PlHashMap::<OpaqueValue, IdxSize>::default();
+    columns
+        .iter()
+        .map(|s| {
+            let values = opaque_value_vec(s.ext()?)?;
+            let out: IdxCa = values
+                .into_iter()
+                .map(|opt_v| {
+                    opt_v.map(|v| {
+                        let id = ids.len() as IdxSize;
+                        *ids.entry(v).or_insert(id)
+                    })
+                })
+                .collect();
+            Ok(out.with_name(s.name().clone()))
+        })
+        .collect()
+}
+
+/// The dense ranks of the values of a column of an opaque dtype in the order of the vtable of the
+/// type. Nulls stay null.
+pub(crate) fn opaque_ranks(ext: &ExtensionChunked) -> PolarsResult<IdxCa> {
+    let values = opaque_value_vec(ext)?;
+    let mut order = (0..values.len())
+        .filter(|i| values[*i].is_some())
+        .collect::<Vec<_>>();
+    order.sort_by(|a, b| values[*a].cmp(&values[*b]));
+
+    let mut ranks = vec![None; values.len()];
+    let mut rank: IdxSize = 0;
+    for (j, i) in order.iter().enumerate() {
+        if j > 0 && values[order[j - 1]] != values[*i] {
+            rank += 1;
+        }
+        ranks[*i] = Some(rank);
+    }
+    Ok(IdxCa::from_iter_options(
+        ext.name().clone(),
+        ranks.into_iter(),
+    ))
+}
+
+/// Hash the values of a column of an opaque dtype through the vtable of the type.
+pub(crate) fn opaque_vec_hash(
+    ext: &ExtensionChunked,
+    build_hasher: &PlSeedableRandomStateQuality,
+) -> PolarsResult<Vec<u64>> {
+    let null_h = get_null_hash_value(build_hasher);
+    Ok(opaque_value_vec(ext)?
+        .iter()
+        .map(|opt_v| opt_v.as_ref().map_or(null_h, |v| build_hasher.hash_one(v)))
+        .collect())
+}
+
+pub(crate) fn opaque_vec_hash_combine(
+    ext: &ExtensionChunked,
+    build_hasher: &PlSeedableRandomStateQuality,
+    hashes: &mut [u64],
+) -> PolarsResult<()> {
+    for (h, l) in hashes.iter_mut().zip(opaque_vec_hash(ext, build_hasher)?) {
+        *h = _boost_hash_combine(l, *h);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A point that is equal to the points with the same coordinates modulo 10, but keeps its
+    /// coordinates in its serialization.
+    #[derive(Debug, Clone)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    impl Point {
+        fn key(&self) -> (i32, i32) {
+            (self.x.rem_euclid(10), self.y.rem_euclid(10))
+        }
+    }
+
+    impl PartialEq for Point {
+        fn eq(&self, other: &Self) -> bool {
+            self.key() == other.key()
+        }
+    }
+
+    impl Eq for Point {}
+
+    impl PartialOrd for Point {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Point {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.key().cmp(&other.key())
+        }
+    }
+
+    impl Hash for Point {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.key().hash(state)
+        }
+    }
+
+    impl PolarsOpaque for Point {
+        fn type_name() -> &'static str {
+            "test.point"
+        }
+
+        fn serialize(&self, buf: &mut Vec<u8>) {
+            buf.extend_from_slice(&self.x.to_le_bytes());
+            buf.extend_from_slice(&self.y.to_le_bytes());
+        }
+
+        fn deserialize(bytes: &[u8]) -> PolarsResult<Self> {
+            polars_ensure!(bytes.len() == 8, ComputeError: "invalid point");
+            let [x, y] =
+                [&bytes[0..4], &bytes[4..8]].map(|b| i32::from_le_bytes(b.try_into().unwrap()));
+            Ok(Point { x, y })
+        }
+    }
+
+    fn points() -> Series {
+        let points = [
+            Point { x: 1, y: -2 },
+            Point { x: 3, y: 4 },
+            Point { x: 11, y: 8 },
+            Point { x: -7, y: 14 },
+        ];
+        let values = [
+            Some(&points[0]),
+            None,
+            Some(&points[1]),
+            Some(&points[2]),
+            Some(&points[3]),
+        ];
+        opaque_series(PlSmallStr::from_static("p"), values)
+    }
+
+    #[test]
+    fn test_opaque_roundtrip() -> PolarsResult<()> {
+        let s = points();
+        assert_eq!(s.dtype(), &OpaqueType::dtype::<Point>());
+        assert_eq!(s.dtype().to_string(), "ext[opaque[test.point]]");
+
+        let values = opaque_values::<Point>(&s)?;
+        assert_eq!(values[1], None);
+        // The serialization keeps the coordinates.
+        assert_eq!(values[2].as_ref().map(|p| (p.x, p.y)), Some((3, 4)));
+        assert_eq!(values[3].as_ref().map(|p| (p.x, p.y)), Some((11, 8)));
+
+        let value = opaque_value_vec(s.ext()?)?.swap_remove(0).unwrap();
+        let clone = value.clone();
+        drop(value);
+        assert_eq!(clone.downcast_ref::<Point>().map(|p| p.x), Some(1));
+        assert_eq!(format!("{clone:?}"), "opaque[test.point]");
+
+        assert!(opaque_values::<Point>(&s.ext()?.storage().clone()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_opaque_compare_and_hash() -> PolarsResult<()> {
+        let s = points();
+
+        // (1, -2) equals (11, 8), and (3, 4) equals (-7, 14).
+        assert_eq!(s.n_unique()?, 3);
+        assert_eq!(s.unique()?.len(), 3);
+
+        let mut hashes = vec![];
+        s.vec_hash(PlSeedableRandomStateQuality::default(), &mut hashes)?;
+        assert_eq!(hashes[0], hashes[3]);
+        assert_eq!(hashes[2], hashes[4]);
+        assert_ne!(hashes[0], hashes[2]);
+
+        let sorted = s.sort(SortOptions::default().with_nulls_last(true))?;
+        let keys = opaque_values::<Point>(&sorted)?
+            .into_iter()
+            .map(|p| p.map(|p| p.key()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            [Some((1, 8)), Some((1, 8)), Some((3, 4)), Some((3, 4)), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_opaque_group_by() -> PolarsResult<()> {
+        let df = DataFrame::new_infer_height(vec![
+            points().into(),
+            Column::new("a".into(), [1, 2, 3, 4, 5]),
+            Column::new("b".into(), [0, 0, 0, 0, 1]),
+        ])?;
+
+        #[allow(deprecated)]
+        let out = df.group_by(["p"])?.select(["a"]).sum()?;
+        let mut sums = out.column("a_sum")?.i32()?.to_vec();
+        sums.sort();
+        assert_eq!(sums, [Some(2), Some(5), Some(8)]);
+
+        // Multiple keys are grouped by their values too.
+        #[allow(deprecated)]
+        let out = df.group_by(["p", "b"])?.select(["a"]).sum()?;
+        let mut sums = out.column("a_sum")?.i32()?.to_vec();
+        sums.sort();
+        assert_eq!(sums, [Some(2), Some(3), Some(5), Some(5)]);
+        Ok(())
+    }
+}
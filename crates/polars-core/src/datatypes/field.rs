@@ -1,4 +1,4 @@
-use arrow::datatypes::{IntervalUnit, Metadata};
+use arrow::datatypes::{IntervalUnit, Metadata, PL_KEY};
 use polars_dtype::categorical::CategoricalPhysical;
 use polars_error::feature_gated;
 use polars_utils::pl_str::PlSmallStr;
@@ -9,7 +9,13 @@ pub static POLARS_OBJECT_EXTENSION_NAME: &str = "_POLARS_PYTHON_OBJECT";
 pub static ARROW_UUID_EXTENSION_NAME: &str = "arrow.uuid";
 
 /// Characterizes the name and the [`DataType`] of a column.
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+///
+/// A top-level column can additionally carry arbitrary key/value metadata, which is written to
+/// and read from the arrow field metadata of IPC and Parquet files. The metadata is not part of
+/// the [`Schema`] of a query, see `polars_plan::plans::field_metadata` for how it propagates
+/// through a query. It doesn't take part in comparisons and hashing, so that a field equals the
+/// field resolved for it in the schema.
+#[derive(Clone, Debug)]
 #[cfg_attr(
     any(feature = "serde", feature = "serde-lazy"),
     derive(Serialize, Deserialize)
@@ -18,6 +24,54 @@ pub static ARROW_UUID_EXTENSION_NAME: &str = "arrow.uuid";
 pub struct Field {
     pub name: PlSmallStr,
     pub dtype: DataType,
+    #[cfg_attr(any(feature = "serde", feature = "serde-lazy"), serde(default))]
+    metadata: Option<Arc<Metadata>>,
+}
+
+/// Fields are equal if they have the same name and data type, whatever their metadata.
+impl PartialEq for Field {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.dtype == other.dtype
+    }
+}
+
+impl Eq for Field {}
+
+/// Consistent with [`PartialEq`], the metadata is not hashed.
+impl std::hash::Hash for Field {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.dtype.hash(state);
+    }
+}
+
+/// The metadata of the columns of a frame or query, by column name.
+pub type FieldMetadataMap = PlHashMap<PlSmallStr, Arc<Metadata>>;
+
+/// Whether `key` is used by Polars or Arrow to encode the data type of a field, rather than
+/// being user metadata.
+fn is_reserved_metadata_key(key: &str) -> bool {
+    key == PL_KEY || key.starts_with("_PL_") || key.starts_with("ARROW:")
+}
+
+/// Add user metadata to the metadata of an arrow field. The metadata that encodes the data type
+/// takes precedence over user metadata with the same key.
+pub fn arrow_field_with_user_metadata(field: ArrowField, metadata: &Metadata) -> ArrowField {
+    let mut merged = metadata.clone();
+    if let Some(dtype_metadata) = &field.metadata {
+        merged.extend(dtype_metadata.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+    field.with_metadata(merged)
+}
+
+/// The user metadata in the metadata of an arrow field, if any.
+pub fn user_field_metadata(metadata: Option<&Metadata>) -> Option<Arc<Metadata>> {
+    let metadata: Metadata = metadata?
+        .iter()
+        .filter(|(k, _)| !is_reserved_metadata_key(k))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    (!metadata.is_empty()).then(|| Arc::new(metadata))
 }
 
 impl From<Field> for (PlSmallStr, DataType) {
@@ -41,7 +95,11 @@ impl Field {
     /// ```
     #[inline]
     pub fn new(name: PlSmallStr, dtype: DataType) -> Self {
-        Field { name, dtype }
+        Field {
+            name,
+            dtype,
+            metadata: None,
+        }
     }
 
     /// Returns a reference to the `Field` name.
@@ -116,6 +174,34 @@ impl Field {
         self
     }
 
+    /// Returns a reference to the user metadata of the `Field`.
+    #[inline]
+    pub fn metadata(&self) -> Option<&Arc<Metadata>> {
+        self.metadata.as_ref()
+    }
+
+    /// Sets the user metadata of the `Field`. Empty metadata is stored as `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # use polars_core::utils::arrow::datatypes::Metadata;
+    /// let mut f = Field::new("Speed".into(), DataType::Float64);
+    /// f.set_metadata(Some(Arc::new(Metadata::from([("unit".into(), "m/s".into())]))));
+    ///
+    /// assert_eq!(f.metadata().unwrap()["unit"], "m/s");
+    /// ```
+    pub fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.metadata = metadata.filter(|md| !md.is_empty());
+    }
+
+    /// Returns this `Field`, with different user metadata.
+    pub fn with_metadata(mut self, metadata: Option<Arc<Metadata>>) -> Self {
+        self.set_metadata(metadata);
+        self
+    }
+
     /// Converts the `Field` to an `arrow::datatypes::Field`.
     ///
     /// # Example
@@ -128,13 +214,18 @@ impl Field {
     /// assert_eq!(f.to_arrow(CompatLevel::newest()), af);
     /// ```
     pub fn to_arrow(&self, compat_level: CompatLevel) -> ArrowField {
-        self.dtype.to_arrow_field(self.name.clone(), compat_level)
+        let field = self.dtype.to_arrow_field(self.name.clone(), compat_level);
+        match &self.metadata {
+            Some(metadata) => arrow_field_with_user_metadata(field, metadata),
+            None => field,
+        }
     }
 
     pub fn to_physical(&self) -> Field {
         Self {
             name: self.name.clone(),
             dtype: self.dtype().to_physical(),
+            metadata: self.metadata.clone(),
         }
     }
 }
@@ -251,9 +342,13 @@ impl DataType {
             },
 
             #[cfg(feature = "dtype-struct")]
-            ArrowDataType::Struct(fields) => {
-                DataType::Struct(fields.iter().map(|fld| fld.into()).collect())
-            },
+            ArrowDataType::Struct(fields) => DataType::Struct(
+                fields
+                    .iter()
+                    // Only top-level columns carry user metadata.
+                    .map(|fld| Field::new(fld.name.clone(), DataType::from_arrow_field(fld)))
+                    .collect(),
+            ),
             #[cfg(not(feature = "dtype-struct"))]
             ArrowDataType::Struct(_) => {
                 panic!("activate the 'dtype-struct' feature to handle struct data types")
@@ -315,5 +410,6 @@ impl DataType {
 impl From<&ArrowField> for Field {
     fn from(f: &ArrowField) -> Self {
         Field::new(f.name.clone(), DataType::from_arrow_field(f))
+            .with_metadata(user_field_metadata(f.metadata.as_deref()))
     }
 }
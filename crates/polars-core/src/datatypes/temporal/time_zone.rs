@@ -1,4 +1,4 @@
-use polars_error::{PolarsResult, polars_bail};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::config;
@@ -56,6 +56,7 @@ impl TimeZone {
 
         #[cfg(feature = "timezones")]
         if let Some(tz) = canonical_tz.as_mut() {
+            check_tzdb_version()?;
             if let Err(err) = Self::validate_time_zone(tz) {
                 match parse_fixed_offset(tz) {
                     Ok(v) => *tz = v,
@@ -138,6 +139,26 @@ impl std::fmt::Display for TimeZone {
     }
 }
 
+/// Version of the IANA time zone database bundled with Polars, eg: "2025b".
+#[cfg(feature = "timezones")]
+pub const TZDB_VERSION: &str = chrono_tz::IANA_TZDB_VERSION;
+
+/// Check that the bundled time zone database has the version pinned with the
+/// `POLARS_TZDB_VERSION` config option, if any.
+#[cfg(feature = "timezones")]
+pub fn check_tzdb_version() -> PolarsResult<()> {
+    if let Some(pinned) = polars_config::config().tzdb_version() {
+        polars_ensure!(
+            pinned.as_str() == TZDB_VERSION,
+            ComputeError:
+            "time zone database version '{}' was pinned with POLARS_TZDB_VERSION, \
+            but Polars bundles version '{}'",
+            pinned, TZDB_VERSION
+        );
+    }
+    Ok(())
+}
+
 #[cfg(feature = "timezones")]
 static FIXED_OFFSET_PATTERN: &str = r#"(?x)
     ^
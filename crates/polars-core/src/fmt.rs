@@ -27,6 +27,7 @@ use polars_error::feature_gated;
 use polars_utils::relaxed_cell::RelaxedCell;
 
 use crate::config::*;
+use crate::frame::column::Column;
 use crate::prelude::*;
 
 // Note: see https://github.com/pola-rs/polars/pull/13699 for the rationale
@@ -37,7 +38,7 @@ const DEFAULT_COL_LIMIT: usize = 8;
 const DEFAULT_STR_LEN_LIMIT: usize = 30;
 const DEFAULT_LIST_LEN_LIMIT: usize = 3;
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug)]
 #[repr(u8)]
 pub enum FloatFmt {
     Mixed,
@@ -471,6 +472,305 @@ impl Debug for DataFrame {
         Display::fmt(self, f)
     }
 }
+/// The output mode of a formatted [`DataFrame`].
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TableOutput {
+    /// A table drawn with Unicode box characters.
+    #[default]
+    Unicode,
+    /// A table drawn with ASCII characters only.
+    Ascii,
+    /// A Markdown table.
+    Markdown,
+    /// An HTML `<table>`.
+    Html,
+}
+
+/// How the widths of the columns of a formatted [`DataFrame`] are chosen.
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColumnWidth {
+    /// Fit the contents of the columns, within the width of the table.
+    #[default]
+    Auto,
+    /// Every column has exactly this width.
+    Fixed(u16),
+    /// Fit the contents of the columns, but no column is wider than this.
+    Max(u16),
+}
+
+/// The alignment of the cells of a column of a formatted [`DataFrame`].
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TableCellAlignment {
+    Left,
+    Center,
+    Right,
+}
+
+/// Where the shape of a formatted [`DataFrame`] is shown.
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ShapePosition {
+    #[default]
+    Above,
+    Below,
+    Hidden,
+}
+
+/// The options of the formatting of a [`DataFrame`], see [`DataFrame::display_with`].
+///
+/// [`FmtOptions::from_env`] holds the options that the [`Display`] implementation of
+/// [`DataFrame`] uses, which are set by the `POLARS_FMT_*` environment variables and the global
+/// number formatting settings.
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+#[derive(Clone, Debug)]
+pub struct FmtOptions {
+    pub output: TableOutput,
+    /// The name of the table preset, e.g. `UTF8_FULL` or `ASCII_BORDERS_ONLY`, overriding the
+    /// default preset of [`FmtOptions::output`].
+    pub table_style: Option<PlSmallStr>,
+    pub rounded_corners: bool,
+    /// The width of the table, or `None` to use the width of the terminal.
+    pub table_width: Option<u16>,
+    pub column_width: ColumnWidth,
+    /// The maximum number of rows shown; `usize::MAX` shows all rows.
+    pub max_rows: usize,
+    /// The maximum number of columns shown; `usize::MAX` shows all columns.
+    pub max_cols: usize,
+    /// The maximum number of characters of the names and values shown.
+    pub str_len: usize,
+    /// The marker of truncated values, rows and columns, defaulting to `...` for ASCII tables
+    /// and `…` otherwise.
+    pub ellipsis: Option<PlSmallStr>,
+    pub float_fmt: FloatFmt,
+    /// The number of decimals of floats.
+    pub float_precision: Option<usize>,
+    /// The number of decimals of the floats of specific columns, overriding
+    /// [`FmtOptions::float_precision`].
+    pub column_float_precision: PlHashMap<PlSmallStr, usize>,
+    pub thousands_separator: Option<char>,
+    pub decimal_separator: char,
+    pub cell_alignment: Option<TableCellAlignment>,
+    /// The alignment of numeric columns, defaulting to [`FmtOptions::cell_alignment`].
+    pub numeric_cell_alignment: Option<TableCellAlignment>,
+    pub hide_column_names: bool,
+    pub hide_column_dtypes: bool,
+    pub hide_column_separator: bool,
+    pub inline_column_dtype: bool,
+    pub shape: ShapePosition,
+}
+
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+impl Default for FmtOptions {
+    fn default() -> Self {
+        Self {
+            output: TableOutput::default(),
+            table_style: None,
+            rounded_corners: false,
+            table_width: None,
+            column_width: ColumnWidth::default(),
+            max_rows: DEFAULT_ROW_LIMIT,
+            max_cols: DEFAULT_COL_LIMIT,
+            str_len: DEFAULT_STR_LEN_LIMIT,
+            ellipsis: None,
+            float_fmt: FloatFmt::Mixed,
+            float_precision: None,
+            column_float_precision: PlHashMap::new(),
+            thousands_separator: None,
+            decimal_separator: '.',
+            cell_alignment: None,
+            numeric_cell_alignment: None,
+            hide_column_names: false,
+            hide_column_dtypes: false,
+            hide_column_separator: false,
+            inline_column_dtype: false,
+            shape: ShapePosition::default(),
+        }
+    }
+}
+
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+impl FmtOptions {
+    /// The options set by the `POLARS_FMT_*` environment variables and the global number
+    /// formatting settings.
+    pub fn from_env() -> Self {
+        let table_style = std::env::var(FMT_TABLE_FORMATTING).ok();
+        let output = match table_style.as_deref() {
+            Some("ASCII_MARKDOWN" | "MARKDOWN") => TableOutput::Markdown,
+            Some(style) if style.starts_with("ASCII") => TableOutput::Ascii,
+            _ => TableOutput::Unicode,
+        };
+        let ellipsis = table_style
+            .as_deref()
+            .is_some_and(|style| style.starts_with("ASCII"))
+            .then(|| PlSmallStr::from_static("..."));
+
+        let table_width = std::env::var("POLARS_TABLE_WIDTH").ok().map(|s| {
+            let n = s
+                .parse::<i64>()
+                .expect("could not parse table width argument");
+            if n < 0 {
+                u16::MAX
+            } else {
+                u16::try_from(n).expect("table width argument does not fit in u16")
+            }
+        });
+
+        let parse_alignment = |name| match std::env::var(name).as_deref() {
+            Ok("LEFT") => Some(TableCellAlignment::Left),
+            Ok("CENTER") => Some(TableCellAlignment::Center),
+            Ok("RIGHT") => Some(TableCellAlignment::Right),
+            _ => None,
+        };
+        let cell_alignment = parse_alignment(FMT_TABLE_CELL_ALIGNMENT);
+        let numeric_cell_alignment = if std::env::var(FMT_TABLE_CELL_NUMERIC_ALIGNMENT).is_ok() {
+            parse_alignment(FMT_TABLE_CELL_NUMERIC_ALIGNMENT)
+        } else {
+            cell_alignment
+        };
+
+        let shape = if env_is_true(FMT_TABLE_HIDE_DATAFRAME_SHAPE_INFORMATION) {
+            ShapePosition::Hidden
+        } else if env_is_true(FMT_TABLE_DATAFRAME_SHAPE_BELOW) {
+            ShapePosition::Below
+        } else {
+            ShapePosition::Above
+        };
+
+        Self {
+            output,
+            table_style: table_style.map(PlSmallStr::from),
+            rounded_corners: env_is_true(FMT_TABLE_ROUNDED_CORNERS),
+            table_width,
+            column_width: ColumnWidth::Auto,
+            max_rows: get_row_limit(),
+            max_cols: get_col_limit(),
+            str_len: get_str_len_limit(),
+            ellipsis,
+            float_fmt: get_float_fmt(),
+            float_precision: get_float_precision(),
+            column_float_precision: PlHashMap::new(),
+            thousands_separator: get_thousands_separator().chars().next(),
+            decimal_separator: get_decimal_separator(),
+            cell_alignment,
+            numeric_cell_alignment,
+            hide_column_names: env_is_true(FMT_TABLE_HIDE_COLUMN_NAMES),
+            hide_column_dtypes: env_is_true(FMT_TABLE_HIDE_COLUMN_DATA_TYPES),
+            hide_column_separator: env_is_true(FMT_TABLE_HIDE_COLUMN_SEPARATOR),
+            inline_column_dtype: env_is_true(FMT_TABLE_INLINE_COLUMN_DATA_TYPE),
+            shape,
+        }
+    }
+
+    fn ellipsis(&self) -> String {
+        match (&self.ellipsis, self.output) {
+            (Some(ellipsis), _) => ellipsis.to_string(),
+            (None, TableOutput::Ascii) => "...".to_string(),
+            (None, _) => "…".to_string(),
+        }
+    }
+
+    fn preset(&self) -> &'static str {
+        let style = self.table_style.as_deref().unwrap_or("");
+        match style {
+            "ASCII_FULL" => ASCII_FULL,
+            "ASCII_FULL_CONDENSED" => ASCII_FULL_CONDENSED,
+            "ASCII_NO_BORDERS" => ASCII_NO_BORDERS,
+            "ASCII_BORDERS_ONLY" => ASCII_BORDERS_ONLY,
+            "ASCII_BORDERS_ONLY_CONDENSED" => ASCII_BORDERS_ONLY_CONDENSED,
+            "ASCII_HORIZONTAL_ONLY" => ASCII_HORIZONTAL_ONLY,
+            "ASCII_MARKDOWN" | "MARKDOWN" => ASCII_MARKDOWN,
+            "UTF8_FULL" => UTF8_FULL,
+            "UTF8_FULL_CONDENSED" => UTF8_FULL_CONDENSED,
+            "UTF8_NO_BORDERS" => UTF8_NO_BORDERS,
+            "UTF8_BORDERS_ONLY" => UTF8_BORDERS_ONLY,
+            "UTF8_HORIZONTAL_ONLY" => UTF8_HORIZONTAL_ONLY,
+            "NOTHING" => NOTHING,
+            _ => match self.output {
+                TableOutput::Ascii => ASCII_FULL_CONDENSED,
+                TableOutput::Markdown => ASCII_MARKDOWN,
+                TableOutput::Unicode | TableOutput::Html => UTF8_FULL_CONDENSED,
+            },
+        }
+    }
+
+    /// Format the value at `idx` of `column`.
+    fn cell_str<'a>(&self, column: &'a Column, idx: usize) -> Cow<'a, str> {
+        let thousands_separator = self
+            .thousands_separator
+            .map(String::from)
+            .unwrap_or_default();
+        let av = column.get(idx).unwrap();
+        let float = match av {
+            AnyValue::Float32(v) => Some(v as f64),
+            AnyValue::Float64(v) => Some(v),
+            _ => None,
+        };
+        if let Some(v) = float {
+            let precision = self
+                .column_float_precision
+                .get(column.name())
+                .copied()
+                .or(self.float_precision);
+            return Cow::Owned(fmt_float_custom(
+                v,
+                0,
+                self.float_fmt,
+                precision,
+                &thousands_separator,
+                self.decimal_separator,
+            ));
+        }
+        if column.dtype().is_integer() && !av.is_null() {
+            let num = match av {
+                AnyValue::UInt8(v) => v.to_string(),
+                AnyValue::UInt16(v) => v.to_string(),
+                AnyValue::UInt32(v) => v.to_string(),
+                AnyValue::UInt64(v) => v.to_string(),
+                AnyValue::Int8(v) => v.to_string(),
+                AnyValue::Int16(v) => v.to_string(),
+                AnyValue::Int32(v) => v.to_string(),
+                AnyValue::Int64(v) => v.to_string(),
+                _ => return column.str_value(idx).unwrap(),
+            };
+            return Cow::Owned(fmt_int_string_custom(&num, 3, &thousands_separator));
+        }
+        column.str_value(idx).unwrap()
+    }
+}
+
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+impl DataFrame {
+    /// Format the [`DataFrame`] with the given options, instead of the options set by the
+    /// environment variables.
+    pub fn display_with<'a>(&'a self, options: &'a FmtOptions) -> impl Display + 'a {
+        DataFrameDisplay { df: self, options }
+    }
+}
+
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+struct DataFrameDisplay<'a> {
+    df: &'a DataFrame,
+    options: &'a FmtOptions,
+}
+
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+impl Display for DataFrameDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let height = self.df.height();
+        assert!(
+            self.df.columns().iter().all(|s| s.len() == height),
+            "The column lengths in the DataFrame are not equal."
+        );
+        match self.options.output {
+            TableOutput::Html => fmt_df_html(f, self.df, self.options, None, None),
+            _ => fmt_df_table(f, self.df, self.options),
+        }
+    }
+}
+
 #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
 fn make_str_val(v: &str, truncate: usize, ellipsis: &String) -> String {
     let v_trunc = &v[..v
@@ -489,36 +789,29 @@ fn make_str_val(v: &str, truncate: usize, ellipsis: &String) -> String {
 #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
 fn field_to_str(
     f: &Field,
-    str_truncate: usize,
+    options: &FmtOptions,
     ellipsis: &String,
     padding: usize,
 ) -> (String, usize) {
-    let name = make_str_val(f.name(), str_truncate, ellipsis);
+    let name = make_str_val(f.name(), options.str_len, ellipsis);
     let name_length = estimate_string_width(name.as_str());
     let mut column_name = name;
-    if env_is_true(FMT_TABLE_HIDE_COLUMN_NAMES) {
+    if options.hide_column_names {
         column_name = "".to_string();
     }
-    let column_dtype = if env_is_true(FMT_TABLE_HIDE_COLUMN_DATA_TYPES) {
+    let column_dtype = if options.hide_column_dtypes {
         "".to_string()
-    } else if env_is_true(FMT_TABLE_INLINE_COLUMN_DATA_TYPE)
-        | env_is_true(FMT_TABLE_HIDE_COLUMN_NAMES)
-    {
+    } else if options.inline_column_dtype | options.hide_column_names {
         format!("{}", f.dtype())
     } else {
         format!("\n{}", f.dtype())
     };
     let mut dtype_length = column_dtype.trim_start().len();
     let mut separator = "\n---";
-    if env_is_true(FMT_TABLE_HIDE_COLUMN_SEPARATOR)
-        | env_is_true(FMT_TABLE_HIDE_COLUMN_NAMES)
-        | env_is_true(FMT_TABLE_HIDE_COLUMN_DATA_TYPES)
-    {
+    if options.hide_column_separator | options.hide_column_names | options.hide_column_dtypes {
         separator = ""
     }
-    let s = if env_is_true(FMT_TABLE_INLINE_COLUMN_DATA_TYPE)
-        & !env_is_true(FMT_TABLE_HIDE_COLUMN_DATA_TYPES)
-    {
+    let s = if options.inline_column_dtype & !options.hide_column_dtypes {
         let inline_name_dtype = format!("{column_name} ({column_dtype})");
         dtype_length = inline_name_dtype.len();
         inline_name_dtype
@@ -572,6 +865,37 @@ fn prepare_row(
     row_strings
 }
 
+/// The number of leading and trailing columns of `df` that are shown.
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+fn shown_columns(df: &DataFrame, options: &FmtOptions) -> (usize, usize) {
+    let max_n_cols = options.max_cols;
+    if df.width() > max_n_cols {
+        (max_n_cols.div_ceil(2), max_n_cols / 2)
+    } else {
+        (df.width(), 0)
+    }
+}
+
+/// The rows of `df` that are shown, with `None` for the row of truncation markers.
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+fn shown_rows(df: &DataFrame, options: &FmtOptions) -> Vec<Option<usize>> {
+    let height = df.height();
+    let max_n_rows = options.max_rows;
+    if max_n_rows == 0 {
+        return if height > 0 { vec![None] } else { vec![] };
+    }
+    if height <= max_n_rows {
+        return (0..height).map(Some).collect();
+    }
+    let half = max_n_rows / 2;
+    let rest = max_n_rows % 2;
+    (0..half + rest)
+        .map(Some)
+        .chain(std::iter::once(None))
+        .chain((height - half..height).map(Some))
+        .collect()
+}
+
 #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
 fn env_is_true(varname: &str) -> bool {
     std::env::var(varname).as_deref().unwrap_or("0") == "1"
@@ -587,249 +911,423 @@ fn fmt_df_shape((shape0, shape1): &(usize, usize)) -> String {
     )
 }
 
-impl Display for DataFrame {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
-        {
-            let height = self.height();
-            assert!(
-                self.columns().iter().all(|s| s.len() == height),
-                "The column lengths in the DataFrame are not equal."
-            );
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+fn fmt_df_table(f: &mut Formatter<'_>, df: &DataFrame, options: &FmtOptions) -> fmt::Result {
+    let height = df.height();
+    let is_utf8 = !matches!(options.output, TableOutput::Ascii);
+    let ellipsis = options.ellipsis();
+    let ellipsis_len = ellipsis.chars().count();
+    let max_n_rows = options.max_rows;
+    let str_truncate = options.str_len;
+    let padding = 2; // eg: one char either side of the value
+
+    let (n_first, n_last) = shown_columns(df, options);
+    let reduce_columns = n_first + n_last < df.width();
+    let n_tbl_cols = n_first + n_last + reduce_columns as usize;
+    let mut names = Vec::with_capacity(n_tbl_cols);
+    let mut name_lengths = Vec::with_capacity(n_tbl_cols);
+
+    let fields = df.fields();
+    for field in fields[0..n_first].iter() {
+        let (s, l) = field_to_str(field, options, &ellipsis, padding);
+        names.push(s);
+        name_lengths.push(l);
+    }
+    if reduce_columns {
+        names.push(ellipsis.clone());
+        name_lengths.push(ellipsis_len);
+    }
+    for field in fields[df.width() - n_last..].iter() {
+        let (s, l) = field_to_str(field, options, &ellipsis, padding);
+        names.push(s);
+        name_lengths.push(l);
+    }
 
-            let table_style = std::env::var(FMT_TABLE_FORMATTING).unwrap_or("DEFAULT".to_string());
-            let is_utf8 = !table_style.starts_with("ASCII");
-            let preset = match table_style.as_str() {
-                "ASCII_FULL" => ASCII_FULL,
-                "ASCII_FULL_CONDENSED" => ASCII_FULL_CONDENSED,
-                "ASCII_NO_BORDERS" => ASCII_NO_BORDERS,
-                "ASCII_BORDERS_ONLY" => ASCII_BORDERS_ONLY,
-                "ASCII_BORDERS_ONLY_CONDENSED" => ASCII_BORDERS_ONLY_CONDENSED,
-                "ASCII_HORIZONTAL_ONLY" => ASCII_HORIZONTAL_ONLY,
-                "ASCII_MARKDOWN" | "MARKDOWN" => ASCII_MARKDOWN,
-                "UTF8_FULL" => UTF8_FULL,
-                "UTF8_FULL_CONDENSED" => UTF8_FULL_CONDENSED,
-                "UTF8_NO_BORDERS" => UTF8_NO_BORDERS,
-                "UTF8_BORDERS_ONLY" => UTF8_BORDERS_ONLY,
-                "UTF8_HORIZONTAL_ONLY" => UTF8_HORIZONTAL_ONLY,
-                "NOTHING" => NOTHING,
-                _ => UTF8_FULL_CONDENSED,
+    let mut table = Table::new();
+    table
+        .load_preset(options.preset())
+        .set_content_arrangement(ContentArrangement::Dynamic);
+
+    if is_utf8 && options.rounded_corners {
+        table.apply_modifier(UTF8_ROUND_CORNERS);
+    }
+    let mut constraints = Vec::with_capacity(n_tbl_cols);
+    let mut max_elem_lengths: Vec<usize> = vec![0; n_tbl_cols];
+
+    if df.width() > 0 || max_n_rows == 0 {
+        for row_idx in shown_rows(df, options) {
+            let row_strings = match row_idx {
+                Some(i) => {
+                    let row = df
+                        .columns()
+                        .iter()
+                        .map(|c| options.cell_str(c, i))
+                        .collect();
+                    prepare_row(
+                        row,
+                        n_first,
+                        n_last,
+                        str_truncate,
+                        &mut max_elem_lengths,
+                        &ellipsis,
+                        padding,
+                    )
+                },
+                None if max_n_rows == 0 => vec![ellipsis.clone(); df.width()],
+                None => vec![ellipsis.clone(); n_tbl_cols],
             };
-            let ellipsis = get_ellipsis().to_string();
-            let ellipsis_len = ellipsis.chars().count();
-            let max_n_cols = get_col_limit();
-            let max_n_rows = get_row_limit();
-            let str_truncate = get_str_len_limit();
-            let padding = 2; // eg: one char either side of the value
-
-            let (n_first, n_last) = if self.width() > max_n_cols {
-                (max_n_cols.div_ceil(2), max_n_cols / 2)
+            table.add_row(row_strings);
+        }
+    }
+    let tbl_fallback_width = 100;
+
+    // column width constraints
+    let col_width_exact =
+        |w: usize| ColumnConstraint::Absolute(comfy_table::Width::Fixed(w as u16));
+    let col_width_bounds = |l: usize, u: usize| ColumnConstraint::Boundaries {
+        lower: Width::Fixed(l as u16),
+        upper: Width::Fixed(u as u16),
+    };
+    let min_col_width = std::cmp::max(5, 3 + padding);
+    for (idx, elem_len) in max_elem_lengths.iter().enumerate() {
+        let mut mx = std::cmp::min(
+            str_truncate + ellipsis_len + padding,
+            std::cmp::max(name_lengths[idx], *elem_len),
+        );
+        match options.column_width {
+            ColumnWidth::Auto => {},
+            ColumnWidth::Fixed(w) => {
+                constraints.push(col_width_exact(w as usize));
+                continue;
+            },
+            ColumnWidth::Max(w) => mx = mx.min(w as usize),
+        }
+        if (mx <= min_col_width) && !(max_n_rows > 0 && height > max_n_rows) {
+            // col width is less than min width + table is not truncated
+            constraints.push(col_width_exact(mx));
+        } else if mx <= min_col_width {
+            // col width is less than min width + table is truncated (w/ ellipsis)
+            constraints.push(col_width_bounds(mx, min_col_width));
+        } else {
+            constraints.push(col_width_bounds(min_col_width, mx));
+        }
+    }
+
+    // insert a header row, unless both column names and dtypes are hidden
+    if !(options.hide_column_names && options.hide_column_dtypes) {
+        table.set_header(names).set_constraints(constraints);
+    }
+
+    // if tbl_width is explicitly set, use it
+    if let Some(w) = options.table_width {
+        table.set_width(w);
+    } else {
+        // if no tbl_width (it's not tty && width not explicitly set), apply
+        // a default value; this is needed to support non-tty applications
+        #[cfg(feature = "fmt")]
+        if table.width().is_none() && !table.is_tty() {
+            table.set_width(tbl_fallback_width);
+        }
+        #[cfg(feature = "fmt_no_tty")]
+        if table.width().is_none() {
+            table.set_width(tbl_fallback_width);
+        }
+    }
+
+    // set alignment of cells, if defined
+    if options.cell_alignment.is_some() || options.numeric_cell_alignment.is_some() {
+        let shown_fields = fields[..n_first]
+            .iter()
+            .map(Some)
+            .chain(reduce_columns.then_some(None))
+            .chain(fields[df.width() - n_last..].iter().map(Some));
+        for (column, field) in table.column_iter_mut().zip(shown_fields) {
+            let is_numeric = field
+                .is_some_and(|fld| fld.dtype().is_primitive_numeric() || fld.dtype().is_decimal());
+            let alignment = if is_numeric {
+                options.numeric_cell_alignment
             } else {
-                (self.width(), 0)
+                options.cell_alignment
             };
-            let reduce_columns = n_first + n_last < self.width();
-            let n_tbl_cols = n_first + n_last + reduce_columns as usize;
-            let mut names = Vec::with_capacity(n_tbl_cols);
-            let mut name_lengths = Vec::with_capacity(n_tbl_cols);
-
-            let fields = self.fields();
-            for field in fields[0..n_first].iter() {
-                let (s, l) = field_to_str(field, str_truncate, &ellipsis, padding);
-                names.push(s);
-                name_lengths.push(l);
-            }
-            if reduce_columns {
-                names.push(ellipsis.clone());
-                name_lengths.push(ellipsis_len);
-            }
-            for field in fields[self.width() - n_last..].iter() {
-                let (s, l) = field_to_str(field, str_truncate, &ellipsis, padding);
-                names.push(s);
-                name_lengths.push(l);
+            match alignment {
+                Some(TableCellAlignment::Right) => column.set_cell_alignment(CellAlignment::Right),
+                Some(TableCellAlignment::Left) => column.set_cell_alignment(CellAlignment::Left),
+                Some(TableCellAlignment::Center) => {
+                    column.set_cell_alignment(CellAlignment::Center)
+                },
+                None => {},
             }
+        }
+    }
 
-            let mut table = Table::new();
-            table
-                .load_preset(preset)
-                .set_content_arrangement(ContentArrangement::Dynamic);
+    // establish 'shape' information (above/below/hidden)
+    let shape_str = fmt_df_shape(&df.shape());
+    match options.shape {
+        ShapePosition::Hidden => write!(f, "{table}"),
+        ShapePosition::Below => write!(f, "{table}\nshape: {shape_str}"),
+        ShapePosition::Above => write!(f, "shape: {shape_str}\n{table}"),
+    }
+}
+
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
 
-            if is_utf8 && env_is_true(FMT_TABLE_ROUNDED_CORNERS) {
-                table.apply_modifier(UTF8_ROUND_CORNERS);
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+fn fmt_df_html(
+    f: &mut Formatter<'_>,
+    df: &DataFrame,
+    options: &FmtOptions,
+    table_class: Option<&str>,
+    cell_style: Option<&HtmlCellStyleFn>,
+) -> fmt::Result {
+    let ellipsis = options.ellipsis();
+    let (n_first, n_last) = shown_columns(df, options);
+    let reduce_columns = n_first + n_last < df.width();
+    let columns = || {
+        df.columns()[..n_first]
+            .iter()
+            .map(Some)
+            .chain(reduce_columns.then_some(None))
+            .chain(df.columns()[df.width() - n_last..].iter().map(Some))
+    };
+
+    let shape_str = fmt_df_shape(&df.shape());
+    if options.shape == ShapePosition::Above {
+        writeln!(f, "<small>shape: {shape_str}</small>")?;
+    }
+    match table_class {
+        Some(class) => writeln!(f, "<table class=\"{}\">", escape_html(class))?,
+        None => writeln!(f, "<table>")?,
+    }
+
+    if !(options.hide_column_names && options.hide_column_dtypes) {
+        writeln!(f, "<thead>")?;
+        if !options.hide_column_names {
+            write!(f, "<tr>")?;
+            for column in columns() {
+                let name = column.map_or(ellipsis.clone(), |c| {
+                    make_str_val(c.name(), options.str_len, &ellipsis)
+                });
+                write!(f, "<th>{}</th>", escape_html(&name))?;
             }
-            let mut constraints = Vec::with_capacity(n_tbl_cols);
-            let mut max_elem_lengths: Vec<usize> = vec![0; n_tbl_cols];
-
-            if max_n_rows > 0 {
-                if height > max_n_rows {
-                    // Truncate the table if we have more rows than the
-                    // configured maximum number of rows
-                    let mut rows = Vec::with_capacity(std::cmp::max(max_n_rows, 2));
-                    let half = max_n_rows / 2;
-                    let rest = max_n_rows % 2;
-
-                    for i in 0..(half + rest) {
-                        let row = self
-                            .columns()
-                            .iter()
-                            .map(|c| c.str_value(i).unwrap())
-                            .collect();
-
-                        let row_strings = prepare_row(
-                            row,
-                            n_first,
-                            n_last,
-                            str_truncate,
-                            &mut max_elem_lengths,
-                            &ellipsis,
-                            padding,
-                        );
-                        rows.push(row_strings);
-                    }
-                    let dots = vec![ellipsis.clone(); rows[0].len()];
-                    rows.push(dots);
-
-                    for i in (height - half)..height {
-                        let row = self
-                            .columns()
-                            .iter()
-                            .map(|c| c.str_value(i).unwrap())
-                            .collect();
-
-                        let row_strings = prepare_row(
-                            row,
-                            n_first,
-                            n_last,
-                            str_truncate,
-                            &mut max_elem_lengths,
-                            &ellipsis,
-                            padding,
-                        );
-                        rows.push(row_strings);
-                    }
-                    table.add_rows(rows);
-                } else {
-                    for i in 0..height {
-                        if self.width() > 0 {
-                            let row = self
-                                .materialized_column_iter()
-                                .map(|s| s.str_value(i).unwrap())
-                                .collect();
-
-                            let row_strings = prepare_row(
-                                row,
-                                n_first,
-                                n_last,
-                                str_truncate,
-                                &mut max_elem_lengths,
-                                &ellipsis,
-                                padding,
-                            );
-                            table.add_row(row_strings);
-                        } else {
-                            break;
-                        }
-                    }
-                }
-            } else if height > 0 {
-                let dots: Vec<String> = vec![ellipsis; self.width()];
-                table.add_row(dots);
+            writeln!(f, "</tr>")?;
+        }
+        if !options.hide_column_dtypes {
+            write!(f, "<tr>")?;
+            for column in columns() {
+                let dtype = column.map_or(ellipsis.clone(), |c| c.dtype().to_string());
+                write!(f, "<td>{}</td>", escape_html(&dtype))?;
             }
-            let tbl_fallback_width = 100;
-            let tbl_width = std::env::var("POLARS_TABLE_WIDTH")
-                .map(|s| {
-                    let n = s
-                        .parse::<i64>()
-                        .expect("could not parse table width argument");
-                    let w = if n < 0 {
-                        u16::MAX
-                    } else {
-                        u16::try_from(n).expect("table width argument does not fit in u16")
-                    };
-                    Some(w)
-                })
-                .unwrap_or(None);
-
-            // column width constraints
-            let col_width_exact =
-                |w: usize| ColumnConstraint::Absolute(comfy_table::Width::Fixed(w as u16));
-            let col_width_bounds = |l: usize, u: usize| ColumnConstraint::Boundaries {
-                lower: Width::Fixed(l as u16),
-                upper: Width::Fixed(u as u16),
+            writeln!(f, "</tr>")?;
+        }
+        writeln!(f, "</thead>")?;
+    }
+
+    writeln!(f, "<tbody>")?;
+    for row_idx in shown_rows(df, options) {
+        write!(f, "<tr>")?;
+        for column in columns() {
+            let (value, style) = match (row_idx, column) {
+                (Some(i), Some(c)) => {
+                    let value = make_str_val(&options.cell_str(c, i), options.str_len, &ellipsis);
+                    let style =
+                        cell_style.and_then(|cell_style| cell_style(c, i, &c.get(i).unwrap()));
+                    (value, style)
+                },
+                _ => (ellipsis.clone(), None),
             };
-            let min_col_width = std::cmp::max(5, 3 + padding);
-            for (idx, elem_len) in max_elem_lengths.iter().enumerate() {
-                let mx = std::cmp::min(
-                    str_truncate + ellipsis_len + padding,
-                    std::cmp::max(name_lengths[idx], *elem_len),
-                );
-                if (mx <= min_col_width) && !(max_n_rows > 0 && height > max_n_rows) {
-                    // col width is less than min width + table is not truncated
-                    constraints.push(col_width_exact(mx));
-                } else if mx <= min_col_width {
-                    // col width is less than min width + table is truncated (w/ ellipsis)
-                    constraints.push(col_width_bounds(mx, min_col_width));
-                } else {
-                    constraints.push(col_width_bounds(min_col_width, mx));
+            write!(f, "<td")?;
+            if let Some(style) = style {
+                if let Some(class) = &style.class {
+                    write!(f, " class=\"{}\"", escape_html(class))?;
+                }
+                if let Some(css) = &style.style {
+                    write!(f, " style=\"{}\"", escape_html(css))?;
                 }
             }
+            write!(f, ">{}</td>", escape_html(&value))?;
+        }
+        writeln!(f, "</tr>")?;
+    }
+    writeln!(f, "</tbody>")?;
+    write!(f, "</table>")?;
 
-            // insert a header row, unless both column names and dtypes are hidden
-            if !(env_is_true(FMT_TABLE_HIDE_COLUMN_NAMES)
-                && env_is_true(FMT_TABLE_HIDE_COLUMN_DATA_TYPES))
-            {
-                table.set_header(names).set_constraints(constraints);
-            }
+    if options.shape == ShapePosition::Below {
+        write!(f, "\n<small>shape: {shape_str}</small>")?;
+    }
+    Ok(())
+}
 
-            // if tbl_width is explicitly set, use it
-            if let Some(w) = tbl_width {
-                table.set_width(w);
-            } else {
-                // if no tbl_width (it's not tty && width not explicitly set), apply
-                // a default value; this is needed to support non-tty applications
-                #[cfg(feature = "fmt")]
-                if table.width().is_none() && !table.is_tty() {
-                    table.set_width(tbl_fallback_width);
-                }
-                #[cfg(feature = "fmt_no_tty")]
-                if table.width().is_none() {
-                    table.set_width(tbl_fallback_width);
-                }
+/// The attributes of a cell of an HTML table, see [`HtmlOptions::with_cell_style`].
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HtmlCellStyle {
+    /// The CSS classes of the cell.
+    pub class: Option<PlSmallStr>,
+    /// The inline CSS of the cell, e.g. `background-color: yellow`.
+    pub style: Option<PlSmallStr>,
+}
+
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+impl HtmlCellStyle {
+    pub fn class(class: impl Into<PlSmallStr>) -> Self {
+        Self {
+            class: Some(class.into()),
+            style: None,
+        }
+    }
+
+    pub fn style(style: impl Into<PlSmallStr>) -> Self {
+        Self {
+            class: None,
+            style: Some(style.into()),
+        }
+    }
+}
+
+/// Gets the style of the cell of a column at a row, given its value.
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+pub type HtmlCellStyleFn =
+    Arc<dyn Fn(&Column, usize, &AnyValue<'_>) -> Option<HtmlCellStyle> + Send + Sync>;
+
+/// The options of [`DataFrame::to_html`].
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+#[derive(Clone)]
+pub struct HtmlOptions {
+    /// The options of the formatting of the values. By default, all rows and columns are
+    /// written, without truncating the values, and the shape is hidden.
+    pub fmt: FmtOptions,
+    /// The CSS classes of the `<table>`.
+    pub table_class: Option<PlSmallStr>,
+    pub cell_style: Option<HtmlCellStyleFn>,
+}
+
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+impl Default for HtmlOptions {
+    fn default() -> Self {
+        Self {
+            fmt: FmtOptions {
+                output: TableOutput::Html,
+                max_rows: usize::MAX,
+                max_cols: usize::MAX,
+                str_len: usize::MAX,
+                shape: ShapePosition::Hidden,
+                ..Default::default()
+            },
+            table_class: None,
+            cell_style: None,
+        }
+    }
+}
+
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+impl Debug for HtmlOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HtmlOptions")
+            .field("fmt", &self.fmt)
+            .field("table_class", &self.table_class)
+            .field(
+                "cell_style",
+                &self.cell_style.as_ref().map(|_| "<function>"),
+            )
+            .finish()
+    }
+}
+
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+impl HtmlOptions {
+    pub fn with_table_class(mut self, class: impl Into<PlSmallStr>) -> Self {
+        self.table_class = Some(class.into());
+        self
+    }
+
+    /// Style the cells with `f`, which is called with every written value, e.g. to highlight
+    /// values conditionally. The cells of truncated rows and columns are not styled.
+    pub fn with_cell_style(
+        mut self,
+        f: impl Fn(&Column, usize, &AnyValue<'_>) -> Option<HtmlCellStyle> + Send + Sync + 'static,
+    ) -> Self {
+        self.cell_style = Some(Arc::new(f));
+        self
+    }
+}
+
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+impl DataFrame {
+    /// Write the [`DataFrame`] as an HTML `<table>`.
+    pub fn to_html(&self, options: &HtmlOptions) -> String {
+        struct Html<'a>(&'a DataFrame, &'a HtmlOptions);
+
+        impl Display for Html<'_> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let Self(df, options) = self;
+                fmt_df_html(
+                    f,
+                    df,
+                    &options.fmt,
+                    options.table_class.as_deref(),
+                    options.cell_style.as_ref(),
+                )
             }
+        }
 
-            // set alignment of cells, if defined
-            if std::env::var(FMT_TABLE_CELL_ALIGNMENT).is_ok()
-                | std::env::var(FMT_TABLE_CELL_NUMERIC_ALIGNMENT).is_ok()
-            {
-                let str_preset = std::env::var(FMT_TABLE_CELL_ALIGNMENT)
-                    .unwrap_or_else(|_| "DEFAULT".to_string());
-                let num_preset = std::env::var(FMT_TABLE_CELL_NUMERIC_ALIGNMENT)
-                    .unwrap_or_else(|_| str_preset.to_string());
-                for (column_index, column) in table.column_iter_mut().enumerate() {
-                    let dtype = fields[column_index].dtype();
-                    let mut preset = str_preset.as_str();
-                    if dtype.is_primitive_numeric() || dtype.is_decimal() {
-                        preset = num_preset.as_str();
-                    }
-                    match preset {
-                        "RIGHT" => column.set_cell_alignment(CellAlignment::Right),
-                        "LEFT" => column.set_cell_alignment(CellAlignment::Left),
-                        "CENTER" => column.set_cell_alignment(CellAlignment::Center),
-                        _ => {},
-                    }
-                }
+        Html(self, options).to_string()
+    }
+
+    /// Write the [`DataFrame`] as a Markdown table, with all its rows and columns.
+    ///
+    /// Numeric columns are right-aligned. Pipes in the names and values are escaped, and line
+    /// breaks are written as `<br>`.
+    pub fn to_markdown(&self) -> String {
+        fn escape(s: &str) -> String {
+            s.replace('|', "\\|")
+                .replace("\r\n", "<br>")
+                .replace('\n', "<br>")
+        }
+
+        let options = FmtOptions::default();
+        let mut out = String::new();
+        let mut push_row = |cells: &mut dyn Iterator<Item = String>| {
+            out.push('|');
+            for cell in cells {
+                out.push(' ');
+                out.push_str(&cell);
+                out.push_str(" |");
             }
+            out.push('\n');
+        };
 
-            // establish 'shape' information (above/below/hidden)
-            if env_is_true(FMT_TABLE_HIDE_DATAFRAME_SHAPE_INFORMATION) {
-                write!(f, "{table}")?;
+        push_row(&mut self.columns().iter().map(|c| escape(c.name())));
+        push_row(&mut self.columns().iter().map(|c| {
+            let dtype = c.dtype();
+            if dtype.is_primitive_numeric() || dtype.is_decimal() {
+                "---:".to_string()
             } else {
-                let shape_str = fmt_df_shape(&self.shape());
-                if env_is_true(FMT_TABLE_DATAFRAME_SHAPE_BELOW) {
-                    write!(f, "{table}\nshape: {shape_str}")?;
-                } else {
-                    write!(f, "shape: {shape_str}\n{table}")?;
-                }
+                "---".to_string()
             }
+        }));
+        for i in 0..self.height() {
+            push_row(
+                &mut self
+                    .columns()
+                    .iter()
+                    .map(|c| escape(&options.cell_str(c, i))),
+            );
+        }
+        out
+    }
+}
+
+impl Display for DataFrame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+        {
+            Display::fmt(&self.display_with(&FmtOptions::from_env()), f)
         }
         #[cfg(not(any(feature = "fmt", feature = "fmt_no_tty")))]
         {
@@ -837,9 +1335,8 @@ impl Display for DataFrame {
                 f,
                 "shape: {:?}\nto see more, compile with the 'fmt' or 'fmt_no_tty' feature",
                 self.shape()
-            )?;
+            )
         }
-        Ok(())
     }
 }
 
@@ -918,34 +1415,53 @@ const SCIENTIFIC_BOUND: f64 = 999999.0;
 
 fn fmt_float<T: Num + NumCast>(f: &mut Formatter<'_>, width: usize, v: T) -> fmt::Result {
     let v: f64 = NumCast::from(v).unwrap();
+    let s = fmt_float_custom(
+        v,
+        width,
+        get_float_fmt(),
+        get_float_precision(),
+        &get_thousands_separator(),
+        get_decimal_separator(),
+    );
+    f.write_str(&s)
+}
 
-    let float_precision = get_float_precision();
+fn fmt_float_custom(
+    v: f64,
+    width: usize,
+    float_fmt: FloatFmt,
+    float_precision: Option<usize>,
+    thousands_separator: &str,
+    decimal: char,
+) -> String {
+    let fmt_float_string =
+        |num: &str| fmt_float_string_custom(num, 3, thousands_separator, decimal);
 
     if let Some(precision) = float_precision {
         if format!("{v:.precision$}").len() > 19 {
-            return write!(f, "{v:>width$.precision$e}");
+            return format!("{v:>width$.precision$e}");
         }
         let s = format!("{v:>width$.precision$}");
-        return write!(f, "{}", fmt_float_string(s.as_str()));
+        return fmt_float_string(s.as_str());
     }
 
-    if matches!(get_float_fmt(), FloatFmt::Full) {
+    if matches!(float_fmt, FloatFmt::Full) {
         let s = format!("{v:>width$}");
-        return write!(f, "{}", fmt_float_string(s.as_str()));
+        return fmt_float_string(s.as_str());
     }
 
     // show integers as 0.0, 1.0 ... 101.0
     if v.fract() == 0.0 && v.abs() < SCIENTIFIC_BOUND {
         let s = format!("{v:>width$.1}");
-        write!(f, "{}", fmt_float_string(s.as_str()))
+        fmt_float_string(s.as_str())
     } else if format!("{v}").len() > 9 {
         // large and small floats in scientific notation.
         // (note: scientific notation does not play well with digit grouping)
         if (!(0.000001..=SCIENTIFIC_BOUND).contains(&v.abs()) | (v.abs() > SCIENTIFIC_BOUND))
-            && get_thousands_separator().is_empty()
+            && thousands_separator.is_empty()
         {
             let s = format!("{v:>width$.4e}");
-            write!(f, "{}", fmt_float_string(s.as_str()))
+            fmt_float_string(s.as_str())
         } else {
             // this makes sure we don't write 12.00000 in case of a long flt that is 12.0000000001
             // instead we write 12.0
@@ -964,13 +1480,13 @@ fn fmt_float<T: Num + NumCast>(f: &mut Formatter<'_>, width: usize, v: T) -> fmt
                 } else {
                     s.to_string()
                 };
-                write!(f, "{}", fmt_float_string(s.as_str()))
+                fmt_float_string(s.as_str())
             } else {
                 // 12.0934509341243124
                 // written as
                 // 12.09345
                 let s = format!("{v:>width$.6}");
-                write!(f, "{}", fmt_float_string(s.as_str()))
+                fmt_float_string(s.as_str())
             }
         }
     } else {
@@ -979,7 +1495,7 @@ fn fmt_float<T: Num + NumCast>(f: &mut Formatter<'_>, width: usize, v: T) -> fmt
         } else {
             format!("{v:>width$}")
         };
-        write!(f, "{}", fmt_float_string(s.as_str()))
+        fmt_float_string(s.as_str())
     }
 }
 
@@ -1143,6 +1659,217 @@ pub fn iso_duration_string(s: &mut String, mut v: i64, unit: TimeUnit) {
     }
 }
 
+#[cfg(feature = "dtype-duration")]
+fn duration_sizes(unit: TimeUnit) -> &'static [i64] {
+    match unit {
+        TimeUnit::Nanoseconds => SIZES_NS.as_slice(),
+        TimeUnit::Microseconds => SIZES_US.as_slice(),
+        TimeUnit::Milliseconds => SIZES_MS.as_slice(),
+    }
+}
+
+#[cfg(feature = "dtype-duration")]
+fn duration_fraction_digits(unit: TimeUnit) -> usize {
+    match unit {
+        TimeUnit::Nanoseconds => 9,
+        TimeUnit::Microseconds => 6,
+        TimeUnit::Milliseconds => 3,
+    }
+}
+
+/// Write the fractional seconds `frac` (in `unit`) with exactly `digits` digits, truncating
+/// or zero-padding as needed.
+#[cfg(feature = "dtype-duration")]
+fn push_duration_fraction(s: &mut String, frac: u64, unit: TimeUnit, digits: usize) {
+    let unit_digits = duration_fraction_digits(unit);
+    let fraction = format!("{frac:0unit_digits$}");
+    if digits <= unit_digits {
+        s.push_str(&fraction[..digits]);
+    } else {
+        s.push_str(&fraction);
+        s.extend(std::iter::repeat_n('0', digits - unit_digits));
+    }
+}
+
+#[cfg(feature = "dtype-duration")]
+pub fn human_duration_string(s: &mut String, v: i64, unit: TimeUnit) {
+    // take the physical/integer duration value and return a human-readable
+    // duration string with a single leading sign, eg: "-2d 3h 4m 5.25s"
+    if v < 0 {
+        s.push('-');
+    }
+    let sizes = duration_sizes(unit);
+    let mut rem = v.unsigned_abs();
+    let mut buffer = itoa::Buffer::new();
+    let mut wrote_part = false;
+    for (i, &size) in sizes.iter().enumerate().take(3) {
+        let whole_num = rem / size as u64;
+        rem %= size as u64;
+        if whole_num != 0 {
+            if wrote_part {
+                s.push(' ');
+            }
+            s.push_str(buffer.format(whole_num));
+            s.push_str(DURATION_PARTS[i]);
+            wrote_part = true;
+        }
+    }
+    // fractional seconds are written as a decimal part of the seconds.
+    let (secs, frac) = (rem / sizes[3] as u64, rem % sizes[3] as u64);
+    if secs != 0 || frac != 0 || !wrote_part {
+        if wrote_part {
+            s.push(' ');
+        }
+        s.push_str(buffer.format(secs));
+        if frac != 0 {
+            s.push('.');
+            let start = s.len();
+            push_duration_fraction(s, frac, unit, duration_fraction_digits(unit));
+            s.truncate(start + s[start..].trim_end_matches('0').len());
+        }
+        s.push('s');
+    }
+}
+
+/// A parsed item of a strftime-like duration format string.
+#[cfg(feature = "dtype-duration")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DurationFormatItem {
+    Literal(String),
+    /// `%d`
+    Days,
+    /// `%H`
+    Hours,
+    /// `%M`
+    Minutes,
+    /// `%S`
+    Seconds,
+    /// `%f`, `%.f`, `%.3f`, `%.6f` and `%.9f`; without explicit digits the precision of the
+    /// time unit is used.
+    Fraction {
+        dot: bool,
+        digits: Option<usize>,
+    },
+}
+
+/// Parse a strftime-like duration format string, eg: "%H:%M:%S%.3f".
+#[cfg(feature = "dtype-duration")]
+pub fn parse_duration_format(format: &str) -> PolarsResult<Vec<DurationFormatItem>> {
+    let mut items = Vec::new();
+    let mut literal = String::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            literal.push(c);
+            continue;
+        }
+        let spec = chars.as_str();
+        let (item, len) = if spec.starts_with('%') {
+            literal.push('%');
+            chars.next();
+            continue;
+        } else if spec.starts_with('d') {
+            (DurationFormatItem::Days, 1)
+        } else if spec.starts_with('H') {
+            (DurationFormatItem::Hours, 1)
+        } else if spec.starts_with('M') {
+            (DurationFormatItem::Minutes, 1)
+        } else if spec.starts_with('S') {
+            (DurationFormatItem::Seconds, 1)
+        } else if spec.starts_with('f') {
+            let item = DurationFormatItem::Fraction {
+                dot: false,
+                digits: None,
+            };
+            (item, 1)
+        } else if spec.starts_with(".f") {
+            let item = DurationFormatItem::Fraction {
+                dot: true,
+                digits: None,
+            };
+            (item, 2)
+        } else if let Some(digits) = [".3f", ".6f", ".9f"]
+            .iter()
+            .find(|p| spec.starts_with(*p))
+            .map(|p| (p.as_bytes()[1] - b'0') as usize)
+        {
+            let item = DurationFormatItem::Fraction {
+                dot: true,
+                digits: Some(digits),
+            };
+            (item, 3)
+        } else {
+            polars_bail!(
+                InvalidOperation: "invalid specifier in duration format {:?}, expected one of \
+                '%d', '%H', '%M', '%S', '%f', '%.f', '%.3f', '%.6f', '%.9f' or '%%'",
+                format
+            )
+        };
+        if !literal.is_empty() {
+            items.push(DurationFormatItem::Literal(std::mem::take(&mut literal)));
+        }
+        items.push(item);
+        for _ in 0..len {
+            chars.next();
+        }
+    }
+    if !literal.is_empty() {
+        items.push(DurationFormatItem::Literal(literal));
+    }
+    Ok(items)
+}
+
+#[cfg(feature = "dtype-duration")]
+pub fn strftime_duration_string(
+    s: &mut String,
+    v: i64,
+    unit: TimeUnit,
+    items: &[DurationFormatItem],
+) {
+    // the largest unit in the format holds the total of that unit (eg: "%H:%M" writes
+    // 26 hours as "26:00"), the smaller units wrap around.
+    let part_index = |item: &DurationFormatItem| match item {
+        DurationFormatItem::Days => Some(0),
+        DurationFormatItem::Hours => Some(1),
+        DurationFormatItem::Minutes => Some(2),
+        DurationFormatItem::Seconds => Some(3),
+        _ => None,
+    };
+    let largest = items.iter().filter_map(part_index).min();
+
+    if v < 0 {
+        s.push('-');
+    }
+    let sizes = duration_sizes(unit);
+    let v = v.unsigned_abs();
+    let mut buffer = itoa::Buffer::new();
+    for item in items {
+        match item {
+            DurationFormatItem::Literal(literal) => s.push_str(literal),
+            DurationFormatItem::Fraction { dot, digits } => {
+                if *dot {
+                    s.push('.');
+                }
+                let digits = digits.unwrap_or_else(|| duration_fraction_digits(unit));
+                push_duration_fraction(s, v % sizes[3] as u64, unit, digits);
+            },
+            item => {
+                let i = part_index(item).unwrap();
+                let size = sizes[i] as u64;
+                let whole_num = if Some(i) == largest {
+                    v / size
+                } else {
+                    (v % sizes[i - 1] as u64) / size
+                };
+                if i > 0 && whole_num < 10 {
+                    s.push('0');
+                }
+                s.push_str(buffer.format(whole_num));
+            },
+        }
+    }
+}
+
 fn format_blob(f: &mut Formatter<'_>, bytes: &[u8]) -> fmt::Result {
     let ellipsis = get_ellipsis();
     let width = get_str_len_limit() * 2;
@@ -1517,6 +2244,115 @@ Series: '' [datetime[ns]]
         );
     }
 
+    #[test]
+    #[cfg(feature = "dtype-duration")]
+    fn test_fmt_duration_formats() {
+        let ca = Int64Chunked::new(
+            PlSmallStr::EMPTY,
+            &[Some(-86_442_000_000), Some(183_845_250_000), Some(0), None],
+        )
+        .into_duration(TimeUnit::Microseconds);
+
+        let out = ca.to_string("human").unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[Some("-1d 42s"), Some("2d 3h 4m 5.25s"), Some("0s"), None]
+        );
+
+        let out = ca.to_string("%H:%M:%S%.3f").unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some("-24:00:42.000"),
+                Some("51:04:05.250"),
+                Some("00:00:00.000"),
+                None
+            ]
+        );
+
+        let out = ca.to_string("%dd %Hh (%%)").unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some("-1d 00h (%)"),
+                Some("2d 03h (%)"),
+                Some("0d 00h (%)"),
+                None
+            ]
+        );
+
+        assert!(ca.to_string("%Y").is_err());
+    }
+
+    #[test]
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    fn test_fmt_options_html() -> PolarsResult<()> {
+        use crate::fmt::{FmtOptions, TableOutput};
+
+        let df = df![
+            "a" => [1000i64, 2_000_000, 3],
+            "b<" => [1.23456f64, 2.0, 3.5],
+        ]?;
+        let options = FmtOptions {
+            output: TableOutput::Html,
+            max_rows: 2,
+            thousands_separator: Some(','),
+            column_float_precision: PlHashMap::from_iter([("b<".into(), 2)]),
+            ..Default::default()
+        };
+        assert_eq!(
+            df.display_with(&options).to_string(),
+            r#"<small>shape: (3, 2)</small>
+<table>
+<thead>
+<tr><th>a</th><th>b&lt;</th></tr>
+<tr><td>i64</td><td>f64</td></tr>
+</thead>
+<tbody>
+<tr><td>1,000</td><td>1.23</td></tr>
+<tr><td>…</td><td>…</td></tr>
+<tr><td>3</td><td>3.50</td></tr>
+</tbody>
+</table>"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    fn test_to_html_and_markdown() -> PolarsResult<()> {
+        use crate::fmt::{HtmlCellStyle, HtmlOptions};
+
+        let df = df![
+            "name" => ["a|b", "c"],
+            "value" => [-1i32, 2],
+        ]?;
+        let options = HtmlOptions::default()
+            .with_table_class("report")
+            .with_cell_style(|column, _, value| {
+                (column.name() == "value" && value.extract::<i32>()? < 0)
+                    .then(|| HtmlCellStyle::style("color: red"))
+            });
+        assert_eq!(
+            df.to_html(&options),
+            r#"<table class="report">
+<thead>
+<tr><th>name</th><th>value</th></tr>
+<tr><td>str</td><td>i32</td></tr>
+</thead>
+<tbody>
+<tr><td>a|b</td><td style="color: red">-1</td></tr>
+<tr><td>c</td><td>2</td></tr>
+</tbody>
+</table>"#
+        );
+        assert_eq!(
+            df.to_markdown(),
+            "| name | value |\n| --- | ---: |\n| a\\|b | -1 |\n| c | 2 |\n"
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_fmt_chunkedarray() {
         let ca = Int32Chunked::new(PlSmallStr::from_static("Date"), &[Some(1), None, Some(3)]);
@@ -0,0 +1,226 @@
+use std::borrow::Cow;
+
+use polars_error::PolarsResult;
+
+use super::ensure_can_extend;
+use crate::frame::DataFrame;
+use crate::utils::try_get_supertype;
+
+/// When to rechunk a [`DataFrame`] after appending to it.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RechunkThreshold {
+    /// Keep the chunks as they are.
+    #[default]
+    Never,
+    /// Rechunk to a single chunk after every append.
+    Always,
+    /// Rechunk once any column has more than this number of chunks.
+    MaxChunks(usize),
+}
+
+impl RechunkThreshold {
+    fn apply(self, df: &mut DataFrame) {
+        let n_chunks = match self {
+            Self::Never => return,
+            Self::Always => 1,
+            Self::MaxChunks(n_chunks) => n_chunks.max(1),
+        };
+        if df.max_n_chunks() > n_chunks {
+            df.rechunk_mut();
+        }
+    }
+}
+
+/// Options for [`DataFrame::vstack_mut_with_options`] and [`DataFrame::extend_with_options`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AppendOptions {
+    /// Cast columns with mismatching dtypes on both sides to their supertype, instead of raising.
+    pub coerce: bool,
+    pub rechunk: RechunkThreshold,
+}
+
+impl DataFrame {
+    /// Concatenate a [`DataFrame`] to this [`DataFrame`], optionally coercing mismatching dtypes
+    /// to their supertype and bounding the number of chunks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let mut df1 = df!("a" => [1i32, 2])?;
+    /// let df2 = df!("a" => [3.5f64])?;
+    ///
+    /// let options = AppendOptions {
+    ///     coerce: true,
+    ///     rechunk: RechunkThreshold::MaxChunks(8),
+    /// };
+    /// df1.vstack_mut_with_options(&df2, options)?;
+    ///
+    /// assert_eq!(df1.column("a")?.dtype(), &DataType::Float64);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn vstack_mut_with_options(
+        &mut self,
+        other: &DataFrame,
+        options: AppendOptions,
+    ) -> PolarsResult<&mut Self> {
+        let other = self.coerce_for_append(other, options.coerce)?;
+        self.vstack_mut(&other)?;
+        options.rechunk.apply(self);
+        Ok(self)
+    }
+
+    /// Extend the memory backed by this [`DataFrame`] with the values from `other`, optionally
+    /// coercing mismatching dtypes to their supertype and bounding the number of chunks.
+    ///
+    /// See [`DataFrame::extend`].
+    pub fn extend_with_options(
+        &mut self,
+        other: &DataFrame,
+        options: AppendOptions,
+    ) -> PolarsResult<()> {
+        let other = self.coerce_for_append(other, options.coerce)?;
+        self.extend(&other)?;
+        options.rechunk.apply(self);
+        Ok(())
+    }
+
+    /// Cast the columns of `self` and `other` to their supertypes where their dtypes differ.
+    ///
+    /// Widths are not checked here, that is left to the append itself. Names are checked before
+    /// anything is cast, so that `self` is left untouched when the append would fail.
+    fn coerce_for_append<'a>(
+        &mut self,
+        other: &'a DataFrame,
+        coerce: bool,
+    ) -> PolarsResult<Cow<'a, DataFrame>> {
+        let mut other = Cow::Borrowed(other);
+        if !coerce || self.width() != other.width() {
+            return Ok(other);
+        }
+
+        for (left, right) in self.columns().iter().zip(other.columns()) {
+            ensure_can_extend(left, right)?;
+        }
+
+        // Compute every cast before assigning any, so that `self` is left untouched on error.
+        let mut left_casts = vec![];
+        let mut right_casts = vec![];
+        for (idx, (left, right)) in self.columns().iter().zip(other.columns()).enumerate() {
+            let left_dtype = left.dtype();
+            let right_dtype = right.dtype();
+            if left_dtype == right_dtype {
+                continue;
+            }
+
+            let supertype = try_get_supertype(left_dtype, right_dtype).map_err(|e| {
+                e.context(format!("failed to coerce column '{}' for append", left.name()).into())
+            })?;
+
+            if left_dtype != &supertype {
+                left_casts.push((idx, left.cast(&supertype)?));
+            }
+            if right_dtype != &supertype {
+                right_casts.push((idx, right.cast(&supertype)?));
+            }
+        }
+
+        if !left_casts.is_empty() {
+            // SAFETY: casting does not change the length or the name of the column.
+            let columns = unsafe { self.columns_mut() };
+            for (idx, casted) in left_casts {
+                columns[idx] = casted;
+            }
+        }
+        if !right_casts.is_empty() {
+            // SAFETY: casting does not change the length or the name of the column.
+            let columns = unsafe { other.to_mut().columns_mut() };
+            for (idx, casted) in right_casts {
+                columns[idx] = casted;
+            }
+        }
+
+        Ok(other)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_vstack_with_options() -> PolarsResult<()> {
+        let mut df = df!("a" => [1i32, 2], "b" => ["x", "y"])?;
+        let other = df!("a" => [3i64], "b" => ["z"])?;
+
+        // Mismatching dtypes raise without coercion.
+        assert!(
+            df.clone()
+                .vstack_mut_with_options(&other, AppendOptions::default())
+                .is_err()
+        );
+
+        let options = AppendOptions {
+            coerce: true,
+            rechunk: RechunkThreshold::MaxChunks(2),
+        };
+        df.vstack_mut_with_options(&other, options)?;
+        assert_eq!(df.column("a")?.dtype(), &DataType::Int64);
+        assert_eq!(df.max_n_chunks(), 2);
+
+        // The third chunk exceeds the threshold.
+        df.vstack_mut_with_options(&other, options)?;
+        assert_eq!(df.max_n_chunks(), 1);
+
+        let expected = df!("a" => [1i64, 2, 3, 3], "b" => ["x", "y", "z", "z"])?;
+        assert!(df.equals(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_extend_with_options() -> PolarsResult<()> {
+        let mut df = df!("a" => [Some(1.5f32), None])?;
+        let other = df!("a" => [1i64])?;
+        let options = AppendOptions {
+            coerce: true,
+            rechunk: RechunkThreshold::Always,
+        };
+        df.extend_with_options(&other, options)?;
+        assert_eq!(df.column("a")?.dtype(), &DataType::Float64);
+        assert_eq!(df.max_n_chunks(), 1);
+        assert_eq!(df.height(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_failed_coercion_leaves_frame_untouched() -> PolarsResult<()> {
+        let mut df = df!("a" => [1i32, 2], "b" => [1i64, 2])?;
+        let b = Column::new("b".into(), ["x", "y"]).cast(&DataType::Binary)?;
+        let other = DataFrame::new(2, vec![Column::new("a".into(), [3i64, 4]), b])?;
+        let options = AppendOptions {
+            coerce: true,
+            ..Default::default()
+        };
+
+        // "a" could be coerced, but "b" has no supertype.
+        assert!(df.vstack_mut_with_options(&other, options).is_err());
+        assert_eq!(df.column("a")?.dtype(), &DataType::Int32);
+        assert_eq!(df.height(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_name_mismatch_leaves_frame_untouched() -> PolarsResult<()> {
+        let mut df = df!("a" => [1i32, 2])?;
+        let other = df!("b" => [3i64])?;
+        let options = AppendOptions {
+            coerce: true,
+            ..Default::default()
+        };
+
+        assert!(df.vstack_mut_with_options(&other, options).is_err());
+        assert!(df.extend_with_options(&other, options).is_err());
+        assert_eq!(df.column("a")?.dtype(), &DataType::Int32);
+        Ok(())
+    }
+}
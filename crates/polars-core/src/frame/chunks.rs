@@ -13,19 +13,12 @@ impl From<RecordBatch> for DataFrame {
         let columns: Vec<Column> = arrays
             .into_iter()
             .zip(schema.iter())
-            .map(|(arr, (name, field))| {
+            .map(|(arr, (_, field))| {
                 // SAFETY: Record Batch has the invariant that the schema datatype matches the
                 // columns.
-                unsafe {
-                    Series::_try_from_arrow_unchecked_with_md(
-                        name.clone(),
-                        vec![arr],
-                        field.dtype(),
-                        field.metadata.as_deref(),
-                    )
-                }
-                .unwrap()
-                .into_column()
+                unsafe { Series::_try_from_arrow_field_unchecked(field, vec![arr]) }
+                    .unwrap()
+                    .into_column()
             })
             .collect();
 
@@ -78,6 +71,71 @@ impl DataFrame {
         }
     }
 
+    /// Iterate over zero-copy slices of at most `n_rows` rows, e.g. to export the [`DataFrame`]
+    /// in bounded batches without rechunking it.
+    ///
+    /// A slice ends at the last chunk boundary of the first chunked column that fits in it, so
+    /// that the slices do not split chunks unless a chunk is longer than `n_rows`. An empty
+    /// [`DataFrame`] has no slices.
+    ///
+    /// # Panics
+    /// Panics if `n_rows` is 0.
+    pub fn iter_slices(&self, n_rows: usize) -> impl ExactSizeIterator<Item = DataFrame> + '_ {
+        self.slice_offsets(n_rows)
+            .into_iter()
+            .map(|(offset, len)| self.slice(offset as i64, len))
+    }
+
+    /// Parallel version of [`DataFrame::iter_slices`].
+    pub fn par_iter_slices(
+        &self,
+        n_rows: usize,
+    ) -> impl IndexedParallelIterator<Item = DataFrame> + '_ {
+        self.slice_offsets(n_rows)
+            .into_par_iter()
+            .map(|(offset, len)| self.slice(offset as i64, len))
+    }
+
+    /// The `(offset, len)` of the slices of [`DataFrame::iter_slices`].
+    fn slice_offsets(&self, n_rows: usize) -> Vec<(usize, usize)> {
+        assert!(n_rows > 0, "slices must have a positive number of rows");
+
+        let height = self.height();
+        let chunk_ends = self
+            .columns()
+            .iter()
+            .find_map(|c| c.as_series())
+            .map(|s| {
+                s.chunk_lengths()
+                    .scan(0, |end, len| {
+                        *end += len;
+                        Some(*end)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let mut offsets = Vec::with_capacity(height.div_ceil(n_rows));
+        let mut start = 0;
+        let mut n_chunks_before_end = 0;
+        while start < height {
+            let max_end = usize::min(start + n_rows, height);
+            while chunk_ends
+                .get(n_chunks_before_end)
+                .is_some_and(|&end| end <= max_end)
+            {
+                n_chunks_before_end += 1;
+            }
+            let end = match n_chunks_before_end.checked_sub(1) {
+                Some(i) if chunk_ends[i] > start => chunk_ends[i],
+                _ => max_end,
+            };
+            offsets.push((start, end - start));
+            start = end;
+        }
+        offsets
+    }
+
     /// Convert the columns of this [DataFrame] to arrow arrays.
     pub fn rechunk_to_arrow(&self, compat_level: CompatLevel) -> Vec<ArrayRef> {
         self.columns()
@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 
 use arrow::bitmap::{Bitmap, BitmapBuilder};
+use arrow::datatypes::Metadata;
 use arrow::trusted_len::TrustMyLength;
 use num_traits::{Num, NumCast};
 use polars_compute::rolling::QuantileMethod;
@@ -237,6 +238,27 @@ impl Column {
         }
     }
 
+    /// Get the user metadata of the field of this column.
+    pub fn metadata(&self) -> Option<Arc<Metadata>> {
+        self.field().metadata().cloned()
+    }
+
+    /// Set the user metadata of the field of this column.
+    ///
+    /// Scalar columns don't carry metadata and are materialized when metadata is set.
+    pub fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        if metadata.is_none() && self.metadata().is_none() {
+            return;
+        }
+        self.into_materialized_series().set_metadata(metadata);
+    }
+
+    /// Return this column with different user metadata.
+    pub fn with_metadata(mut self, metadata: Option<Arc<Metadata>>) -> Column {
+        self.set_metadata(metadata);
+        self
+    }
+
     // # Downcasting
     #[inline]
     pub fn as_series(&self) -> Option<&Series> {
@@ -0,0 +1,395 @@
+//! Lightweight indexes on the columns of a [`DataFrame`].
+use std::cmp::Ordering;
+use std::sync::{LazyLock, RwLock, Weak};
+
+use crate::prelude::*;
+
+/// The kind of a [`ColumnIndex`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndexKind {
+    /// A sorted permutation of the rows, to find the rows within a range of values with a
+    /// binary search, or to get the rows in sorted order.
+    Sorted,
+    /// The minimum and maximum value of every block of `block_size` rows, to skip the blocks
+    /// that cannot contain a range of values.
+    ZoneMap { block_size: usize },
+}
+
+/// A range of values, with inclusive or exclusive bounds.
+#[derive(Clone, Debug, Default)]
+pub struct ValueBounds {
+    /// The lower bound, and whether it is inclusive.
+    pub lower: Option<(Scalar, bool)>,
+    /// The upper bound, and whether it is inclusive.
+    pub upper: Option<(Scalar, bool)>,
+}
+
+impl ValueBounds {
+    /// Restrict the lower bound to `value` if that is tighter than the current lower bound.
+    pub fn restrict_lower(&mut self, value: Scalar, inclusive: bool) {
+        let tighter = self.lower.as_ref().is_none_or(|(lower, lower_inclusive)| {
+            match value.value().partial_cmp(lower.value()) {
+                Some(Ordering::Greater) => true,
+                Some(Ordering::Equal) => *lower_inclusive && !inclusive,
+                _ => false,
+            }
+        });
+        if tighter {
+            self.lower = Some((value, inclusive));
+        }
+    }
+
+    /// Restrict the upper bound to `value` if that is tighter than the current upper bound.
+    pub fn restrict_upper(&mut self, value: Scalar, inclusive: bool) {
+        let tighter = self.upper.as_ref().is_none_or(|(upper, upper_inclusive)| {
+            match value.value().partial_cmp(upper.value()) {
+                Some(Ordering::Less) => true,
+                Some(Ordering::Equal) => *upper_inclusive && !inclusive,
+                _ => false,
+            }
+        });
+        if tighter {
+            self.upper = Some((value, inclusive));
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum IndexData {
+    Sorted {
+        /// The rows in ascending order of their values with the nulls first. Rows with equal
+        /// values keep their order.
+        perm: IdxCa,
+        /// The physical values in the order of `perm`, without the nulls.
+        values: Series,
+    },
+    ZoneMap {
+        block_size: usize,
+        /// The physical minimum and maximum of every block, or `None` if the block must always
+        /// be scanned (because it contains NaN).
+        zones: Vec<Option<(AnyValue<'static>, AnyValue<'static>)>>,
+    },
+}
+
+/// The indexes of all the frames, see [`DataFrame::create_index`].
+///
+/// The indexes are kept apart from the [`DataFrame`]s, so that frames without indexes don't pay
+/// for them. An index refers to the data of the column it was built on, so it is shared by all
+/// the frames that hold that same column. It doesn't keep that data alive: once no frame holds
+/// the column anymore, the index is dropped on the next access.
+static INDEXES: LazyLock<RwLock<Vec<Arc<ColumnIndex>>>> = LazyLock::new(Default::default);
+
+/// Add `index`, replacing the index of the same kind on the same column.
+pub(super) fn register_index(index: ColumnIndex) {
+    let mut indexes = INDEXES.write().unwrap();
+    indexes.retain(|existing| {
+        !existing.is_orphaned()
+            && (existing.kind() != index.kind() || !Weak::ptr_eq(&existing.data_ref, &index.data_ref))
+    });
+    indexes.push(Arc::new(index));
+}
+
+/// Get the indexes on `columns`.
+pub(super) fn indexes_on(columns: &[Column]) -> Vec<Arc<ColumnIndex>> {
+    let indexes = INDEXES.read().unwrap();
+    if indexes.is_empty() {
+        return vec![];
+    }
+    let out = indexes
+        .iter()
+        .filter(|index| columns.iter().any(|column| index.is_valid_for(column)))
+        .cloned()
+        .collect();
+    let has_orphans = indexes.iter().any(|index| index.is_orphaned());
+    drop(indexes);
+    if has_orphans {
+        INDEXES
+            .write()
+            .unwrap()
+            .retain(|index| !index.is_orphaned());
+    }
+    out
+}
+
+/// Drop the indexes on `columns`.
+pub(super) fn drop_indexes_on(columns: &[Column]) {
+    let mut indexes = INDEXES.write().unwrap();
+    indexes.retain(|index| {
+        !index.is_orphaned() && !columns.iter().any(|column| index.is_valid_for(column))
+    });
+}
+
+/// An index on a column of a [`DataFrame`], see [`DataFrame::create_index`].
+///
+/// The index keeps a weak reference to the data of the column it was built on, and is only used
+/// for frames that still hold that same data. Modifying the column gives it new data, which the
+/// index doesn't apply to.
+#[derive(Clone, Debug)]
+pub struct ColumnIndex {
+    data_ref: Weak<dyn SeriesTrait>,
+    name: PlSmallStr,
+    dtype: DataType,
+    len: usize,
+    data: IndexData,
+}
+
+impl ColumnIndex {
+    /// Build an index of `kind` on `column`.
+    pub fn new(column: &Column, kind: IndexKind) -> PolarsResult<Self> {
+        let dtype = column.dtype();
+        polars_ensure!(
+            dtype.to_physical().is_primitive() && !dtype.is_categorical() && !dtype.is_enum(),
+            InvalidOperation: "cannot index column '{}' of dtype {}", column.name(), dtype
+        );
+        let Column::Series(indexed) = column else {
+            polars_bail!(
+                InvalidOperation: "cannot index column '{}' as it is not materialized", column.name()
+            );
+        };
+        let s = column.as_materialized_series();
+        let phys = s.to_physical_repr();
+
+        let data = match kind {
+            IndexKind::Sorted => {
+                let perm = s.arg_sort(SortOptions::default().with_maintain_order(true));
+                let null_count = s.null_count();
+                // SAFETY: the permutation is in bounds.
+                let values = unsafe { phys.take_unchecked(&perm) }
+                    .slice(null_count as i64, s.len() - null_count);
+                IndexData::Sorted { perm, values }
+            },
+            IndexKind::ZoneMap { block_size } => {
+                polars_ensure!(
+                    block_size > 0,
+                    InvalidOperation: "the block size of a zone map must be positive"
+                );
+                let zones = (0..s.len())
+                    .step_by(block_size)
+                    .map(|offset| {
+                        let block = phys.slice(offset as i64, block_size);
+                        if block.dtype().is_float() && block.is_nan()?.any() {
+                            return Ok(None);
+                        }
+                        let min = block.min_reduce()?.into_value();
+                        let max = block.max_reduce()?.into_value();
+                        Ok(Some((min, max)))
+                    })
+                    .collect::<PolarsResult<_>>()?;
+                IndexData::ZoneMap { block_size, zones }
+            },
+        };
+
+        Ok(Self {
+            data_ref: Arc::downgrade(&indexed.0),
+            name: column.name().clone(),
+            dtype: dtype.clone(),
+            len: column.len(),
+            data,
+        })
+    }
+
+    pub fn name(&self) -> &PlSmallStr {
+        &self.name
+    }
+
+    pub fn kind(&self) -> IndexKind {
+        match &self.data {
+            IndexData::Sorted { .. } => IndexKind::Sorted,
+            IndexData::ZoneMap { block_size, .. } => IndexKind::ZoneMap {
+                block_size: *block_size,
+            },
+        }
+    }
+
+    /// Whether no column holds the data of this index anymore.
+    fn is_orphaned(&self) -> bool {
+        self.data_ref.strong_count() == 0
+    }
+
+    /// Whether `column` is the column this index was built on.
+    pub fn is_valid_for(&self, column: &Column) -> bool {
+        // The weak reference keeps the allocation, so no other data can take its address.
+        match column {
+            Column::Series(column) => {
+                std::ptr::addr_eq(self.data_ref.as_ptr(), Arc::as_ptr(&column.0))
+            },
+            _ => false,
+        }
+    }
+
+    /// The rows in ascending order of their values with the nulls first, if this is a
+    /// [`IndexKind::Sorted`] index. Rows with equal values keep their order.
+    pub fn sorted_permutation(&self) -> Option<&IdxCa> {
+        match &self.data {
+            IndexData::Sorted { perm, .. } => Some(perm),
+            IndexData::ZoneMap { .. } => None,
+        }
+    }
+
+    /// Get the rows, in ascending order, that may have a value within `bounds`.
+    ///
+    /// A sorted index gives exactly the matching rows, a zone map gives all the rows of the
+    /// blocks that may contain matching rows. Null values are never within the bounds.
+    pub fn candidates(&self, bounds: &ValueBounds) -> PolarsResult<IdxCa> {
+        let to_physical = |bound: &Option<(Scalar, bool)>| {
+            bound
+                .as_ref()
+                .map(|(value, inclusive)| {
+                    polars_ensure!(
+                        value.dtype() == &self.dtype,
+                        SchemaMismatch: "cannot search index of dtype {} for a value of dtype {}",
+                        self.dtype, value.dtype()
+                    );
+                    Ok((value.clone().to_physical().into_value(), *inclusive))
+                })
+                .transpose()
+        };
+        let lower = to_physical(&bounds.lower)?;
+        let upper = to_physical(&bounds.upper)?;
+        if lower.as_ref().is_some_and(|(v, _)| v.is_null())
+            || upper.as_ref().is_some_and(|(v, _)| v.is_null())
+        {
+            return Ok(IdxCa::from_vec(self.name().clone(), vec![]));
+        }
+
+        let idx = match &self.data {
+            IndexData::Sorted { perm, values } => {
+                let start = lower.map_or(0, |(lower, inclusive)| {
+                    partition_point(values, |v| {
+                        let ord = compare(v, &lower);
+                        ord.is_lt() || (ord.is_eq() && !inclusive)
+                    })
+                });
+                let end = upper.map_or(values.len(), |(upper, inclusive)| {
+                    partition_point(values, |v| {
+                        let ord = compare(v, &upper);
+                        ord.is_lt() || (ord.is_eq() && inclusive)
+                    })
+                });
+                let null_count = perm.len() - values.len();
+                let mut idx = perm
+                    .slice((null_count + start) as i64, end.saturating_sub(start))
+                    .into_no_null_iter()
+                    .collect::<Vec<_>>();
+                idx.sort_unstable();
+                idx
+            },
+            IndexData::ZoneMap { block_size, zones } => {
+                let len = self.len;
+                let mut idx = Vec::new();
+                for (i, zone) in zones.iter().enumerate() {
+                    let keep = zone.as_ref().is_none_or(|(min, max)| {
+                        let above_lower = lower.as_ref().is_none_or(|(lower, inclusive)| {
+                            let ord = compare(max, lower);
+                            ord.is_gt() || (ord.is_eq() && *inclusive)
+                        });
+                        let below_upper = upper.as_ref().is_none_or(|(upper, inclusive)| {
+                            let ord = compare(min, upper);
+                            ord.is_lt() || (ord.is_eq() && *inclusive)
+                        });
+                        above_lower && below_upper
+                    });
+                    if keep {
+                        let start = i * block_size;
+                        let end = (start + block_size).min(len);
+                        idx.extend(start as IdxSize..end as IdxSize);
+                    }
+                }
+                idx
+            },
+        };
+        Ok(IdxCa::from_vec(self.name().clone(), idx))
+    }
+}
+
+fn compare(l: &AnyValue, r: &AnyValue) -> Ordering {
+    l.partial_cmp(r)
+        .expect("index values and bounds have the same physical dtype")
+}
+
+/// Get the number of leading values of the sorted `values` for which `pred` holds.
+fn partition_point(values: &Series, pred: impl Fn(&AnyValue) -> bool) -> usize {
+    let (mut lo, mut hi) = (0, values.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        // SAFETY: `mid` is in bounds.
+        if pred(&unsafe { values.get_unchecked(mid) }) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_column_index_candidates() -> PolarsResult<()> {
+        let column = Column::new(
+            "a".into(),
+            [Some(5), None, Some(1), Some(3), Some(3), Some(9)],
+        );
+        let bounds = |lower: Option<(i32, bool)>, upper: Option<(i32, bool)>| {
+            let scalar = |v: i32| Scalar::new(DataType::Int32, v.into());
+            ValueBounds {
+                lower: lower.map(|(v, inclusive)| (scalar(v), inclusive)),
+                upper: upper.map(|(v, inclusive)| (scalar(v), inclusive)),
+            }
+        };
+        let to_vec = |idx: IdxCa| idx.into_no_null_iter().collect::<Vec<_>>();
+
+        let sorted = ColumnIndex::new(&column, IndexKind::Sorted)?;
+        assert_eq!(
+            to_vec(sorted.candidates(&bounds(Some((3, true)), Some((5, false))))?),
+            [3, 4]
+        );
+        assert_eq!(
+            to_vec(sorted.candidates(&bounds(Some((3, false)), None))?),
+            [0, 5]
+        );
+        assert_eq!(
+            to_vec(sorted.candidates(&bounds(None, Some((3, true))))?),
+            [2, 3, 4]
+        );
+        assert!(
+            sorted
+                .candidates(&bounds(Some((6, true)), Some((8, true))))?
+                .is_empty()
+        );
+        assert_eq!(
+            to_vec(sorted.sorted_permutation().unwrap().clone()),
+            [1, 2, 3, 4, 0, 5]
+        );
+
+        let zone_map = ColumnIndex::new(&column, IndexKind::ZoneMap { block_size: 2 })?;
+        assert_eq!(
+            to_vec(zone_map.candidates(&bounds(Some((6, true)), None))?),
+            [4, 5]
+        );
+        assert_eq!(
+            to_vec(zone_map.candidates(&bounds(Some((2, true)), Some((4, true))))?),
+            [2, 3, 4, 5]
+        );
+        assert!(
+            zone_map
+                .candidates(&bounds(Some((10, true)), None))?
+                .is_empty()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_column_index_does_not_keep_column_alive() -> PolarsResult<()> {
+        let column = Column::new("a".into(), [3, 1, 2]);
+        let index = ColumnIndex::new(&column, IndexKind::Sorted)?;
+        assert!(index.is_valid_for(&column));
+        assert!(!index.is_orphaned());
+
+        drop(column);
+        assert!(index.is_orphaned());
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@ use std::sync::{Arc, OnceLock};
 use polars_error::PolarsResult;
 
 use super::broadcast::{broadcast_columns, infer_broadcast_height};
+use super::column_index::{ColumnIndex, IndexKind, drop_indexes_on, indexes_on, register_index};
 use super::validation::validate_columns_slice;
 use crate::frame::column::Column;
 use crate::schema::{Schema, SchemaRef};
@@ -367,6 +368,47 @@ impl DataFrame {
         self
     }
 
+    /// Build an index of `kind` on the column `name`, replacing its existing index of that kind.
+    ///
+    /// Both engines use the indexes of an in-memory [`DataFrame`] to speed up filters on the
+    /// indexed columns, and the in-memory engine also uses them for asof joins. The indexes
+    /// are kept outside of the [`DataFrame`] and belong to the data of their column: frames that
+    /// share the column share its indexes, and an index is dropped when its column is replaced or
+    /// modified.
+    pub fn create_index(&mut self, name: &str, kind: IndexKind) -> PolarsResult<&mut Self> {
+        let idx = self.try_get_column_index(name)?;
+        let column = &mut self.columns[idx];
+        if !matches!(column, Column::Series(_)) {
+            *column = Column::from(column.as_materialized_series().clone());
+        }
+        register_index(ColumnIndex::new(column, kind)?);
+        Ok(self)
+    }
+
+    /// Get the index of `kind` on the column `name`, if there is one.
+    pub fn index(&self, name: &str, kind: IndexKind) -> Option<Arc<ColumnIndex>> {
+        let column = self.column(name).ok()?;
+        indexes_on(std::slice::from_ref(column))
+            .into_iter()
+            .find(|index| index.kind() == kind)
+    }
+
+    /// Get the sorted index on the column `name`, if there is one.
+    pub fn sorted_index(&self, name: &str) -> Option<Arc<ColumnIndex>> {
+        self.index(name, IndexKind::Sorted)
+    }
+
+    /// Get all the indexes on the columns.
+    pub fn indexes(&self) -> Vec<Arc<ColumnIndex>> {
+        indexes_on(&self.columns)
+    }
+
+    /// Drop all the indexes on the columns, also for the other frames that share these columns.
+    pub fn drop_indexes(&mut self) -> &mut Self {
+        drop_indexes_on(&self.columns);
+        self
+    }
+
     #[inline]
     fn clear_schema(&mut self) -> &mut Self {
         self.cached_schema = OnceLock::new();
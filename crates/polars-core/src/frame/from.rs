@@ -15,15 +15,7 @@ impl TryFrom<StructArray> for DataFrame {
             .map(|(fld, arr)| {
                 // SAFETY:
                 // reported data type is correct
-                unsafe {
-                    Series::_try_from_arrow_unchecked_with_md(
-                        fld.name.clone(),
-                        vec![arr],
-                        fld.dtype(),
-                        fld.metadata.as_deref(),
-                    )
-                }
-                .map(Column::from)
+                unsafe { Series::_try_from_arrow_field_unchecked(fld, vec![arr]) }.map(Column::from)
             })
             .collect::<PolarsResult<Vec<_>>>()?;
 
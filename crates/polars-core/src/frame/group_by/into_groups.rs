@@ -235,15 +235,15 @@ impl IntoGroupsType for BinaryChunked {
         }
 
         multithreaded &= POOL.current_num_threads() > 1;
-        let bh = self.to_bytes_hashes(multithreaded, Default::default());
+        let vh = self.to_view_hashes(multithreaded, Default::default());
 
         let out = if multithreaded {
-            let n_partitions = bh.len();
+            let n_partitions = vh.len();
             // Take slices so that the vecs are not cloned.
-            let bh = bh.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
-            group_by_threaded_slice(bh, n_partitions, sorted)
+            let vh = vh.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
+            group_by_threaded_slice(vh, n_partitions, sorted)
         } else {
-            group_by(bh[0].iter(), sorted)
+            group_by(vh[0].iter(), sorted)
         };
         try_raise_keyboard_interrupt();
         Ok(out)
@@ -88,6 +88,19 @@ impl DataFrame {
 
                 Ok(GroupsType::new_slice(groups, false, true))
             } else {
+                // The values of opaque dtypes are grouped by their vtable, not their encoding.
+                #[cfg(feature = "dtype-extension")]
+                let by = by
+                    .into_iter()
+                    .map(|c| {
+                        if crate::datatypes::extension::opaque_vtable(c.dtype()).is_none() {
+                            return Ok(c);
+                        }
+                        let s = c.as_materialized_series();
+                        let ids = crate::datatypes::extension::opaque_ids(&[s])?;
+                        Ok(ids.into_iter().next().unwrap().into_column())
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
                 let rows = if multithreaded {
                     encode_rows_vertical_par_unordered(&by)
                 } else {
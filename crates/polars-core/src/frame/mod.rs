@@ -16,11 +16,15 @@ use crate::utils::split_df;
 use crate::utils::{Container, NoNull, slice_offsets, try_get_supertype};
 use crate::{HEAD_DEFAULT_LENGTH, TAIL_DEFAULT_LENGTH};
 
+mod append;
+pub use append::{AppendOptions, RechunkThreshold};
 #[cfg(feature = "dataframe_arithmetic")]
 mod arithmetic;
 pub mod builder;
 mod chunks;
 pub use chunks::chunk_df_for_writing;
+mod column_index;
+pub use column_index::{ColumnIndex, IndexKind, ValueBounds};
 mod broadcast;
 pub mod column;
 mod dataframe;
@@ -390,6 +394,38 @@ impl DataFrame {
         self.columns().iter().map(|s| s.name().clone()).collect()
     }
 
+    /// Get the user metadata of the columns that have any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # use polars_core::utils::arrow::datatypes::Metadata;
+    /// let mut df: DataFrame = df!("Speed" => [1.0, 2.5], "Lap" => [1, 2])?;
+    /// let unit = Metadata::from([("unit".into(), "m/s".into())]);
+    /// df.set_field_metadata(&FieldMetadataMap::from_iter([("Speed".into(), Arc::new(unit))]));
+    ///
+    /// assert_eq!(df.field_metadata()["Speed"]["unit"], "m/s");
+    /// assert!(!df.field_metadata().contains_key("Lap"));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn field_metadata(&self) -> FieldMetadataMap {
+        self.columns()
+            .iter()
+            .filter_map(|c| Some((c.name().clone(), c.metadata()?)))
+            .collect()
+    }
+
+    /// Set the user metadata of every column to its entry in `metadata`, columns without an entry
+    /// lose their metadata.
+    pub fn set_field_metadata(&mut self, metadata: &FieldMetadataMap) {
+        // SAFETY: metadata doesn't change the names or lengths of the columns.
+        for c in unsafe { self.columns_mut_retain_schema() } {
+            let md = metadata.get(c.name()).cloned();
+            c.set_metadata(md);
+        }
+    }
+
     /// Set the column names.
     /// # Example
     ///
@@ -2909,6 +2945,33 @@ mod test {
         assert_eq!(sliced_df.shape(), (2, 2));
     }
 
+    #[test]
+    fn iter_slices() -> PolarsResult<()> {
+        let mut df = df!("a" => [1, 2, 3, 4], "b" => ["a", "b", "c", "d"])?;
+        let head = df.slice(0, 2);
+        df.vstack_mut(&head)?;
+        df.vstack_mut(&df!("a" => [1; 7], "b" => ["x"; 7])?)?;
+        assert_eq!(df.first_col_n_chunks(), 3);
+
+        let heights = |n_rows| {
+            df.iter_slices(n_rows)
+                .map(|df| df.height())
+                .collect::<Vec<_>>()
+        };
+        // Slices end at the chunk boundaries (4, 6 and 13) if they fit.
+        assert_eq!(heights(5), [4, 2, 5, 2]);
+        assert_eq!(heights(6), [6, 6, 1]);
+        assert_eq!(heights(13), [13]);
+        assert_eq!(heights(1), [1; 13]);
+
+        let slices = df.par_iter_slices(5).collect::<Vec<_>>();
+        assert!(slices.iter().all(|df| df.first_col_n_chunks() == 1));
+        assert!(crate::utils::accumulate_dataframes_vertical(slices)?.equals(&df));
+
+        assert_eq!(df.clear().iter_slices(5).count(), 0);
+        Ok(())
+    }
+
     #[test]
     fn rechunk_false() {
         let df = create_frame();
@@ -0,0 +1,304 @@
+//! Statically typed row and column access that avoids [`AnyValue`] boxing.
+//!
+//! The dtypes (and nullability) of the columns are checked once when the accessor is built.
+//! After that every access is a plain lookup into the underlying [`ChunkedArray`].
+use std::marker::PhantomData;
+
+use super::*;
+
+/// A value that can be read directly from a [`Column`] of a known dtype.
+///
+/// Implemented for the native numeric types, `bool` and `&str`. Wrapping any of those in an
+/// [`Option`] allows the column to contain nulls, the unwrapped types require the column to be
+/// free of nulls.
+pub trait TypedValue<'a>: Sized {
+    type Source: Copy;
+
+    /// Validate the dtype of `column` and return the source to read values from.
+    fn source(column: &'a Column) -> PolarsResult<Self::Source>;
+
+    /// # Safety
+    /// `idx` must be in bounds of the column the source was created from.
+    unsafe fn get_unchecked(source: Self::Source, idx: usize) -> Self;
+}
+
+/// Values that are backed by a single [`ChunkedArray`].
+pub trait TypedPhysical<'a>: Sized {
+    type Ca: PolarsDataType;
+
+    fn downcast(column: &'a Column) -> PolarsResult<&'a ChunkedArray<Self::Ca>>;
+
+    /// # Safety
+    /// `idx` must be in bounds.
+    unsafe fn get_unchecked(ca: &'a ChunkedArray<Self::Ca>, idx: usize) -> Option<Self>;
+}
+
+macro_rules! impl_typed_physical {
+    ($native:ty, $pl_type:ty, $downcast:ident) => {
+        impl<'a> TypedPhysical<'a> for $native {
+            type Ca = $pl_type;
+
+            fn downcast(column: &'a Column) -> PolarsResult<&'a ChunkedArray<Self::Ca>> {
+                column.$downcast()
+            }
+
+            #[inline]
+            unsafe fn get_unchecked(ca: &'a ChunkedArray<Self::Ca>, idx: usize) -> Option<Self> {
+                unsafe { ca.get_unchecked(idx) }
+            }
+        }
+    };
+}
+
+impl_typed_physical!(i8, Int8Type, i8);
+impl_typed_physical!(i16, Int16Type, i16);
+impl_typed_physical!(i32, Int32Type, i32);
+impl_typed_physical!(i64, Int64Type, i64);
+impl_typed_physical!(u8, UInt8Type, u8);
+impl_typed_physical!(u16, UInt16Type, u16);
+impl_typed_physical!(u32, UInt32Type, u32);
+impl_typed_physical!(u64, UInt64Type, u64);
+impl_typed_physical!(f32, Float32Type, f32);
+impl_typed_physical!(f64, Float64Type, f64);
+impl_typed_physical!(bool, BooleanType, bool);
+impl_typed_physical!(&'a str, StringType, str);
+impl_typed_physical!(&'a [u8], BinaryType, binary);
+
+impl<'a, T: TypedPhysical<'a>> TypedValue<'a> for Option<T> {
+    type Source = &'a ChunkedArray<T::Ca>;
+
+    fn source(column: &'a Column) -> PolarsResult<Self::Source> {
+        T::downcast(column)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(source: Self::Source, idx: usize) -> Self {
+        unsafe { T::get_unchecked(source, idx) }
+    }
+}
+
+macro_rules! impl_typed_value {
+    ($($native:ty),*) => {
+        $(
+        impl<'a> TypedValue<'a> for $native {
+            type Source = &'a ChunkedArray<<$native as TypedPhysical<'a>>::Ca>;
+
+            fn source(column: &'a Column) -> PolarsResult<Self::Source> {
+                let ca = <$native as TypedPhysical<'a>>::downcast(column)?;
+                polars_ensure!(
+                    ca.null_count() == 0,
+                    ComputeError: "column '{}' contains nulls, use an `Option<{}>` to read it",
+                    column.name(), stringify!($native)
+                );
+                Ok(ca)
+            }
+
+            #[inline]
+            unsafe fn get_unchecked(source: Self::Source, idx: usize) -> Self {
+                // SAFETY: the source was checked to not contain any nulls.
+                unsafe {
+                    <$native as TypedPhysical<'a>>::get_unchecked(source, idx).unwrap_unchecked()
+                }
+            }
+        }
+        )*
+    };
+}
+
+impl_typed_value!(i8, i16, i32, i64, u8, u16, u32, u64);
+impl_typed_value!(f32, f64, bool, &'a str, &'a [u8]);
+
+/// A typed view over a single [`Column`].
+pub struct TypedColumn<'a, T: TypedValue<'a>> {
+    source: T::Source,
+    len: usize,
+    _pd: PhantomData<(&'a Column, T)>,
+}
+
+impl<'a, T: TypedValue<'a>> TypedColumn<'a, T> {
+    pub fn new(column: &'a Column) -> PolarsResult<Self> {
+        Ok(Self {
+            source: T::source(column)?,
+            len: column.len(),
+            _pd: PhantomData,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the value at `idx`, returns `None` if `idx` is out of bounds.
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<T> {
+        (idx < self.len).then(|| unsafe { T::get_unchecked(self.source, idx) })
+    }
+
+    /// # Safety
+    /// `idx` must be in bounds.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, idx: usize) -> T {
+        unsafe { T::get_unchecked(self.source, idx) }
+    }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = T> + '_ {
+        (0..self.len).map(|idx| unsafe { T::get_unchecked(self.source, idx) })
+    }
+}
+
+/// A tuple of [`TypedValue`]s that can be read from the columns of a [`DataFrame`].
+pub trait TypedRow<'a>: Sized {
+    type Sources: Copy;
+
+    fn sources(columns: &'a [Column]) -> PolarsResult<Self::Sources>;
+
+    /// # Safety
+    /// `idx` must be in bounds of the columns the sources were created from.
+    unsafe fn get_unchecked(sources: Self::Sources, idx: usize) -> Self;
+}
+
+macro_rules! impl_typed_row {
+    ($n:expr; $($T:ident $i:tt),+) => {
+        impl<'a, $($T: TypedValue<'a>),+> TypedRow<'a> for ($($T,)+) {
+            type Sources = ($($T::Source,)+);
+
+            fn sources(columns: &'a [Column]) -> PolarsResult<Self::Sources> {
+                polars_ensure!(
+                    columns.len() == $n,
+                    ShapeMismatch: "cannot read rows of width {} into a tuple of {} values",
+                    columns.len(), $n
+                );
+                Ok(($($T::source(&columns[$i])?,)+))
+            }
+
+            #[inline]
+            unsafe fn get_unchecked(sources: Self::Sources, idx: usize) -> Self {
+                unsafe { ($($T::get_unchecked(sources.$i, idx),)+) }
+            }
+        }
+    };
+}
+
+impl_typed_row!(1; A 0);
+impl_typed_row!(2; A 0, B 1);
+impl_typed_row!(3; A 0, B 1, C 2);
+impl_typed_row!(4; A 0, B 1, C 2, D 3);
+impl_typed_row!(5; A 0, B 1, C 2, D 3, E 4);
+impl_typed_row!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+impl_typed_row!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_typed_row!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Typed row access over all columns of a [`DataFrame`].
+pub struct TypedRows<'a, R: TypedRow<'a>> {
+    sources: R::Sources,
+    height: usize,
+    _pd: PhantomData<(&'a DataFrame, R)>,
+}
+
+impl<'a, R: TypedRow<'a>> TypedRows<'a, R> {
+    pub fn len(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.height == 0
+    }
+
+    /// Get the row at `idx`, returns `None` if `idx` is out of bounds.
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<R> {
+        (idx < self.height).then(|| unsafe { R::get_unchecked(self.sources, idx) })
+    }
+
+    /// # Safety
+    /// `idx` must be in bounds.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, idx: usize) -> R {
+        unsafe { R::get_unchecked(self.sources, idx) }
+    }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = R> + '_ {
+        (0..self.height).map(|idx| unsafe { R::get_unchecked(self.sources, idx) })
+    }
+}
+
+impl DataFrame {
+    /// Create a typed row accessor. The dtypes of all columns are checked against `R` once, so
+    /// that the accessor can be used in hot loops without going through [`AnyValue`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => [1i64, 2], "b" => ["x", "y"], "c" => [Some(1.0), None])?;
+    /// let rows = df.typed_rows::<(i64, &str, Option<f64>)>()?;
+    /// assert_eq!(rows.get(1), Some((2, "y", None)));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn typed_rows<'a, R: TypedRow<'a>>(&'a self) -> PolarsResult<TypedRows<'a, R>> {
+        Ok(TypedRows {
+            sources: R::sources(self.columns())?,
+            height: self.height(),
+            _pd: PhantomData,
+        })
+    }
+
+    /// Get a single typed row. Prefer [`DataFrame::typed_rows`] when reading many rows.
+    pub fn get_typed<'a, R: TypedRow<'a>>(&'a self, idx: usize) -> PolarsResult<R> {
+        let rows = self.typed_rows::<R>()?;
+        rows.get(idx)
+            .ok_or_else(|| polars_err!(oob = idx, self.height()))
+    }
+
+    /// Create a typed accessor for the column `name`.
+    pub fn typed_column<'a, T: TypedValue<'a>>(
+        &'a self,
+        name: &str,
+    ) -> PolarsResult<TypedColumn<'a, T>> {
+        TypedColumn::new(self.column(name)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_typed_rows() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1i64, 2, 3],
+            "b" => ["x", "y", "z"],
+            "c" => [Some(1.0), None, Some(3.0)],
+        ]?;
+
+        let rows = df.typed_rows::<(i64, &str, Option<f64>)>()?;
+        assert_eq!(rows.get(0), Some((1, "x", Some(1.0))));
+        assert_eq!(rows.get(1), Some((2, "y", None)));
+        assert_eq!(rows.get(3), None);
+        assert_eq!(
+            df.get_typed::<(i64, &str, Option<f64>)>(2)?,
+            (3, "z", Some(3.0))
+        );
+
+        // Dtype mismatch.
+        assert!(df.typed_rows::<(i32, &str, Option<f64>)>().is_err());
+        // Nulls in a non-optional column.
+        assert!(df.typed_rows::<(i64, &str, f64)>().is_err());
+        // Width mismatch.
+        assert!(df.typed_rows::<(i64, &str)>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_typed_column() -> PolarsResult<()> {
+        let df = df!["a" => [Some(1i32), None, Some(3)]]?;
+        let col = df.typed_column::<Option<i32>>("a")?;
+        assert_eq!(col.iter().collect::<Vec<_>>(), [Some(1), None, Some(3)]);
+        assert!(df.typed_column::<i32>("a").is_err());
+        Ok(())
+    }
+}
@@ -1,10 +1,12 @@
 mod identity;
 pub(crate) mod vector_hasher;
+mod view_hash;
 
 use std::hash::{BuildHasherDefault, Hash, Hasher};
 
 pub use identity::*;
 pub use vector_hasher::*;
+pub use view_hash::ViewHash;
 
 // We must strike a balance between cache
 // Overallocation seems a lot more expensive than resizing so we start reasonable small.
@@ -0,0 +1,127 @@
+use std::hash::{Hash, Hasher};
+
+use arrow::array::View;
+use polars_buffer::Buffer;
+use polars_utils::hashing::DirtyHash;
+use polars_utils::nulls::IsNull;
+use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
+
+/// A [`View`] of a binary view array together with its hash.
+///
+/// Values of at most 12 bytes are inlined in the view, so they are hashed and compared by
+/// their view alone. Longer values are only compared in full if the length and the prefix of
+/// their views are equal.
+#[derive(Copy, Clone, Debug)]
+pub struct ViewHash<'a> {
+    view: Option<View>,
+    buffers: &'a [Buffer<u8>],
+    hash: u64,
+}
+
+impl<'a> ViewHash<'a> {
+    /// # Safety
+    /// The view must be valid for the given buffers.
+    #[inline]
+    pub unsafe fn new(view: Option<View>, buffers: &'a [Buffer<u8>], hash: u64) -> Self {
+        Self {
+            view,
+            buffers,
+            hash,
+        }
+    }
+
+    /// The bytes of the value, or `None` if it is null.
+    #[inline]
+    pub fn payload(&self) -> Option<&[u8]> {
+        self.view
+            .as_ref()
+            .map(|view| unsafe { view.get_slice_unchecked(self.buffers) })
+    }
+}
+
+impl<'a> IsNull for ViewHash<'a> {
+    const HAS_NULLS: bool = true;
+    type Inner = ViewHash<'a>;
+
+    #[inline(always)]
+    fn is_null(&self) -> bool {
+        self.view.is_none()
+    }
+
+    fn unwrap_inner(self) -> Self::Inner {
+        assert!(self.view.is_some());
+        self
+    }
+}
+
+impl Hash for ViewHash<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash)
+    }
+}
+
+impl PartialEq for ViewHash<'_> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        if self.hash != other.hash {
+            return false;
+        }
+        match (&self.view, &other.view) {
+            (Some(l), Some(r)) => {
+                if l.length != r.length || l.prefix != r.prefix {
+                    false
+                } else if l.is_inline() {
+                    l.as_u128() == r.as_u128()
+                } else {
+                    // SAFETY: the views are valid for their buffers.
+                    unsafe {
+                        l.get_external_slice_unchecked(self.buffers)
+                            == r.get_external_slice_unchecked(other.buffers)
+                    }
+                }
+            },
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ViewHash<'_> {}
+
+impl DirtyHash for ViewHash<'_> {
+    fn dirty_hash(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl TotalHash for ViewHash<'_> {
+    #[inline(always)]
+    fn tot_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.hash(state)
+    }
+}
+
+impl TotalEq for ViewHash<'_> {
+    #[inline(always)]
+    fn tot_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl<'a> ToTotalOrd for ViewHash<'a> {
+    type TotalOrdItem = ViewHash<'a>;
+    type SourceItem = ViewHash<'a>;
+
+    #[inline]
+    fn to_total_ord(&self) -> Self::TotalOrdItem {
+        *self
+    }
+
+    #[inline]
+    fn peel_total_ord(ord_item: Self::TotalOrdItem) -> Self::SourceItem {
+        ord_item
+    }
+}
@@ -168,6 +168,24 @@ impl Series {
         Self::_try_from_arrow_unchecked_with_md(name, chunks, dtype, None)
     }
 
+    /// Create a new Series for a top-level arrow `field`, keeping its user metadata.
+    ///
+    /// # Safety
+    /// The caller must ensure that the dtype of `field` matches all the `ArrayRef` dtypes.
+    pub unsafe fn _try_from_arrow_field_unchecked(
+        field: &ArrowField,
+        chunks: Vec<ArrayRef>,
+    ) -> PolarsResult<Self> {
+        let md = field.metadata.as_deref();
+        let mut s = unsafe {
+            Self::_try_from_arrow_unchecked_with_md(field.name.clone(), chunks, &field.dtype, md)
+        }?;
+        if let Some(metadata) = user_field_metadata(md) {
+            s.set_metadata(Some(metadata));
+        }
+        Ok(s)
+    }
+
     /// Create a new Series without checking if the inner dtype of the chunks is correct
     ///
     /// # Safety
@@ -941,14 +959,7 @@ impl TryFrom<(&ArrowField, Vec<ArrayRef>)> for Series {
 
         // SAFETY:
         // dtype is checked
-        unsafe {
-            Series::_try_from_arrow_unchecked_with_md(
-                field.name.clone(),
-                chunks,
-                &dtype,
-                field.metadata.as_deref(),
-            )
-        }
+        unsafe { Series::_try_from_arrow_field_unchecked(&field.with_dtype(dtype), chunks) }
     }
 }
 
@@ -104,6 +104,10 @@ impl SeriesTrait for SeriesWrap<BinaryChunked> {
         self.0.rename(name);
     }
 
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.0.set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkLenIter<'_> {
         self.0.chunk_lengths()
     }
@@ -228,7 +232,7 @@ impl SeriesTrait for SeriesWrap<BinaryChunked> {
 
     #[cfg(feature = "approx_unique")]
     fn approx_n_unique(&self) -> PolarsResult<IdxSize> {
-        Ok(ChunkApproxNUnique::approx_n_unique(&self.0))
+        Ok(self.0.approx_n_unique_views())
     }
 
     fn is_null(&self) -> BooleanChunked {
@@ -164,6 +164,10 @@ macro_rules! impl_cat_series {
                 self.0.physical_mut().rename(name);
             }
 
+            fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+                self.0.physical_mut().set_metadata(metadata);
+            }
+
             fn chunk_lengths(&self) -> ChunkLenIter<'_> {
                 self.0.physical().chunk_lengths()
             }
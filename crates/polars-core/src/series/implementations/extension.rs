@@ -1,4 +1,7 @@
 use super::*;
+use crate::datatypes::extension::{
+    opaque_ids, opaque_ranks, opaque_vec_hash, opaque_vec_hash_combine, opaque_vtable,
+};
 use crate::prelude::*;
 
 unsafe impl IntoSeries for ExtensionChunked {
@@ -21,6 +24,24 @@ impl SeriesWrap<ExtensionChunked> {
     {
         Ok(apply(self.0.storage())?.into_extension(self.0.extension_type().clone()))
     }
+
+    /// The values of opaque dtypes are hashed and compared through their vtable instead of by
+    /// their storage.
+    fn is_opaque(&self) -> bool {
+        opaque_vtable(self.0.dtype()).is_some()
+    }
+
+    /// Ids that are equal for the rows with equal values, see [`opaque_ids`].
+    fn opaque_ids(&self) -> PolarsResult<Series> {
+        let s = self.0.clone().into_series();
+        Ok(opaque_ids(&[&s])?.pop().unwrap().into_series())
+    }
+
+    fn opaque_ranks(&self) -> Series {
+        opaque_ranks(&self.0)
+            .expect("values of an opaque dtype should deserialize")
+            .into_series()
+    }
 }
 
 impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
@@ -57,6 +78,10 @@ impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
         build_hasher: PlSeedableRandomStateQuality,
         buf: &mut Vec<u64>,
     ) -> PolarsResult<()> {
+        if self.is_opaque() {
+            *buf = opaque_vec_hash(&self.0, &build_hasher)?;
+            return Ok(());
+        }
         self.0.storage().vec_hash(build_hasher, buf)
     }
 
@@ -65,10 +90,16 @@ impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
         build_hasher: PlSeedableRandomStateQuality,
         hashes: &mut [u64],
     ) -> PolarsResult<()> {
+        if self.is_opaque() {
+            return opaque_vec_hash_combine(&self.0, &build_hasher, hashes);
+        }
         self.0.storage().vec_hash_combine(build_hasher, hashes)
     }
 
     fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        if self.is_opaque() {
+            return self.opaque_ids()?.group_tuples(multithreaded, sorted);
+        }
         self.0.storage().group_tuples(multithreaded, sorted)
     }
 
@@ -90,6 +121,9 @@ impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
         by: &[Column],
         options: &SortMultipleOptions,
     ) -> PolarsResult<IdxCa> {
+        if self.is_opaque() {
+            return self.opaque_ranks().arg_sort_multiple(by, options);
+        }
         self.0.storage().arg_sort_multiple(by, options)
     }
 }
@@ -105,6 +139,10 @@ impl SeriesTrait for SeriesWrap<ExtensionChunked> {
         self.0.rename(name);
     }
 
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.0.set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkLenIter<'_> {
         self.0.storage().chunk_lengths()
     }
@@ -283,26 +321,46 @@ impl SeriesTrait for SeriesWrap<ExtensionChunked> {
     }
 
     fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        if self.is_opaque() {
+            let idx = self.arg_sort(options);
+            // SAFETY: the sort indices are in bounds.
+            return Ok(unsafe { self.take_unchecked(&idx) });
+        }
         self.try_apply_on_storage(|s| s.sort_with(options))
     }
 
     fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        if self.is_opaque() {
+            return self.opaque_ranks().arg_sort(options);
+        }
         self.0.storage().arg_sort(options)
     }
 
     fn unique(&self) -> PolarsResult<Series> {
+        if self.is_opaque() {
+            return self.take(&self.arg_unique()?);
+        }
         self.try_apply_on_storage(|s| s.unique())
     }
 
     fn n_unique(&self) -> PolarsResult<usize> {
+        if self.is_opaque() {
+            return self.opaque_ids()?.n_unique();
+        }
         self.0.storage().n_unique()
     }
 
     fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        if self.is_opaque() {
+            return self.opaque_ids()?.arg_unique();
+        }
         self.0.storage().arg_unique()
     }
 
     fn unique_id(&self) -> PolarsResult<(IdxSize, Vec<IdxSize>)> {
+        if self.is_opaque() {
+            return self.opaque_ids()?.unique_id();
+        }
         self.0.storage().unique_id()
     }
 
@@ -1,5 +1,6 @@
 use std::any::Any;
 
+use arrow::datatypes::Metadata;
 use polars_error::constants::LENGTH_LIMIT_MSG;
 
 use self::compare_inner::TotalOrdInner;
@@ -19,6 +20,7 @@ impl Series {
 #[derive(Clone)]
 pub struct NullChunked {
     pub(crate) name: PlSmallStr,
+    metadata: Option<Arc<Metadata>>,
     length: IdxSize,
     // we still need chunks as many series consumers expect
     // chunks to be there
@@ -29,6 +31,7 @@ impl NullChunked {
     pub(crate) fn new(name: PlSmallStr, len: usize) -> Self {
         Self {
             name,
+            metadata: None,
             length: len as IdxSize,
             chunks: vec![Box::new(arrow::array::NullArray::new(
                 ArrowDataType::Null,
@@ -66,7 +69,9 @@ impl PrivateSeries for NullChunked {
         self.length = IdxSize::try_from(inner(&self.chunks)).expect(LENGTH_LIMIT_MSG);
     }
     fn _field(&self) -> Cow<'_, Field> {
-        Cow::Owned(Field::new(self.name().clone(), DataType::Null))
+        Cow::Owned(
+            Field::new(self.name().clone(), DataType::Null).with_metadata(self.metadata.clone()),
+        )
     }
 
     #[allow(unused)]
@@ -182,6 +187,10 @@ impl SeriesTrait for NullChunked {
         self.name = name
     }
 
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.metadata = metadata.filter(|md| !md.is_empty());
+    }
+
     fn chunks(&self) -> &Vec<ArrayRef> {
         &self.chunks
     }
@@ -276,6 +285,7 @@ impl SeriesTrait for NullChunked {
         let (chunks, len) = chunkops::slice(&self.chunks, offset, length, self.len());
         NullChunked {
             name: self.name.clone(),
+            metadata: self.metadata.clone(),
             length: len as IdxSize,
             chunks,
         }
@@ -287,12 +297,14 @@ impl SeriesTrait for NullChunked {
         (
             NullChunked {
                 name: self.name.clone(),
+                metadata: self.metadata.clone(),
                 length: l.iter().map(|arr| arr.len() as IdxSize).sum(),
                 chunks: l,
             }
             .into_series(),
             NullChunked {
                 name: self.name.clone(),
+                metadata: self.metadata.clone(),
                 length: r.iter().map(|arr| arr.len() as IdxSize).sum(),
                 chunks: r,
             }
@@ -113,6 +113,10 @@ impl SeriesTrait for SeriesWrap<StringChunked> {
         self.0.rename(name);
     }
 
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.0.set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkLenIter<'_> {
         self.0.chunk_lengths()
     }
@@ -275,7 +279,7 @@ impl SeriesTrait for SeriesWrap<StringChunked> {
 
     #[cfg(feature = "approx_unique")]
     fn approx_n_unique(&self) -> PolarsResult<IdxSize> {
-        Ok(ChunkApproxNUnique::approx_n_unique(&self.0))
+        Ok(self.0.as_binary().approx_n_unique_views())
     }
 
     fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
@@ -35,6 +35,7 @@ use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
 use arrow::compute::aggregate::estimated_bytes_size;
+use arrow::datatypes::Metadata;
 use arrow::offset::Offsets;
 pub use from::*;
 pub use iterator::{SeriesIter, SeriesPhysIter};
@@ -296,6 +297,23 @@ impl Series {
         self
     }
 
+    /// Get the user metadata of the field of this Series.
+    pub fn metadata(&self) -> Option<Arc<Metadata>> {
+        self.field().metadata().cloned()
+    }
+
+    /// Set the user metadata of the field of this Series. Empty metadata is stored as `None`.
+    pub fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) -> &mut Series {
+        self._get_inner_mut().set_metadata(metadata);
+        self
+    }
+
+    /// Return this Series with different user metadata.
+    pub fn with_metadata(mut self, metadata: Option<Arc<Metadata>>) -> Series {
+        self.set_metadata(metadata);
+        self
+    }
+
     pub fn from_arrow_chunks(name: PlSmallStr, arrays: Vec<ArrayRef>) -> PolarsResult<Series> {
         Self::try_from((name, arrays))
     }
@@ -427,20 +445,14 @@ impl Series {
                         if let Some(dtype) = cast_dtype(&field.dtype) {
                             let mut new_fields = Vec::with_capacity(fields.len());
                             new_fields.extend(fields.iter().take(i).cloned());
-                            new_fields.push(Field {
-                                name: field.name.clone(),
-                                dtype,
-                            });
+                            new_fields.push(Field::new(field.name.clone(), dtype));
                             break new_fields;
                         }
                     };
 
                     new_fields.extend(fields.iter().skip(new_fields.len()).cloned().map(|field| {
                         let dtype = cast_dtype(&field.dtype).unwrap_or(field.dtype);
-                        Field {
-                            name: field.name,
-                            dtype,
-                        }
+                        Field::new(field.name, dtype)
                     }));
 
                     Some(D::Struct(new_fields))
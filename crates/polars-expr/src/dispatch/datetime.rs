@@ -264,8 +264,19 @@ pub(super) fn timestamp(s: &Column, tu: TimeUnit) -> PolarsResult<Column> {
         .timestamp(tu)
         .map(|ca| ca.into_column())
 }
-pub(super) fn to_string(s: &Column, format: &str) -> PolarsResult<Column> {
-    TemporalMethods::to_string(s.as_materialized_series(), format).map(Column::from)
+pub(super) fn to_string(s: &Column, format: &str, locale: Option<&str>) -> PolarsResult<Column> {
+    match locale {
+        None => TemporalMethods::to_string(s.as_materialized_series(), format).map(Column::from),
+        #[cfg(feature = "locales")]
+        Some(locale) => {
+            polars_time::to_string_localized(s.as_materialized_series(), format, locale)
+                .map(Column::from)
+        },
+        #[cfg(not(feature = "locales"))]
+        Some(_) => {
+            polars_bail!(InvalidOperation: "activate the 'locales' feature to format with a locale")
+        },
+    }
 }
 
 #[cfg(feature = "timezones")]
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::prelude::Column;
+use polars_ops::series::IntervalFunction;
+use polars_plan::dsl::{ColumnsUdf, SpecialEq};
+
+pub fn function_expr_to_udf(func: IntervalFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
+    use IntervalFunction::*;
+    match func {
+        New => map_as_slice!(interval),
+        Overlaps => map_as_slice!(overlaps),
+        Contains => map_as_slice!(contains),
+        Intersect => map_as_slice!(intersect),
+        UnionMerge => map!(union_merge),
+    }
+}
+
+pub(super) fn interval(s: &[Column]) -> PolarsResult<Column> {
+    let start = &s[0];
+    let end = &s[1];
+    polars_ops::series::interval_from_bounds(
+        start.as_materialized_series(),
+        end.as_materialized_series(),
+    )
+    .map(Column::from)
+}
+
+pub(super) fn overlaps(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::series::interval_overlaps(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+    )
+    .map(Column::from)
+}
+
+pub(super) fn contains(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::series::interval_contains(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+    )
+    .map(Column::from)
+}
+
+pub(super) fn intersect(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::series::interval_intersect(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+    )
+    .map(Column::from)
+}
+
+pub(super) fn union_merge(s: &Column) -> PolarsResult<Column> {
+    polars_ops::series::interval_union_merge(s.as_materialized_series()).map(Column::from)
+}
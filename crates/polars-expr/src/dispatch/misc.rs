@@ -1,3 +1,4 @@
+use arrow::datatypes::Metadata;
 use polars_core::error::{PolarsResult, polars_bail, polars_ensure, polars_err};
 use polars_core::prelude::row_encode::{_get_rows_encoded_ca, _get_rows_encoded_ca_unordered};
 use polars_core::prelude::*;
@@ -10,12 +11,15 @@ use polars_ops::series::InterpolationMethod;
 #[cfg(feature = "rank")]
 use polars_ops::series::RankOptions;
 use polars_ops::series::{ArgAgg, NullStrategy, SeriesMethods};
+#[cfg(feature = "convolve")]
+use polars_ops::series::{ConvolveMode, ConvolveNulls};
 #[cfg(feature = "dtype-array")]
 use polars_plan::dsl::ReshapeDimension;
 #[cfg(feature = "fused")]
 use polars_plan::plans::FusedOperator;
 #[cfg(feature = "cov")]
 use polars_plan::plans::IRCorrelationMethod;
+use polars_plan::plans::units::UNIT_KEY;
 use polars_plan::plans::{AExprSorted, DynamicPred, RowEncodingVariant};
 use polars_row::RowEncodingOptions;
 use polars_utils::IdxSize;
@@ -82,6 +86,47 @@ pub(super) fn set_sorted_flag(s: &Column, sorted: AExprSorted) -> PolarsResult<C
     Ok(s)
 }
 
+pub(super) fn with_field_metadata(s: &Column, metadata: &Arc<Metadata>) -> PolarsResult<Column> {
+    Ok(s.clone().with_metadata(Some(metadata.clone())))
+}
+
+fn with_unit(s: Column, unit: &PlSmallStr) -> Column {
+    let mut metadata = s.metadata().map(|m| m.as_ref().clone()).unwrap_or_default();
+    if unit.is_empty() {
+        metadata.remove(UNIT_KEY);
+    } else {
+        metadata.insert(PlSmallStr::from_static(UNIT_KEY), unit.clone());
+    }
+    let metadata = (!metadata.is_empty()).then(|| Arc::new(metadata));
+    s.with_metadata(metadata)
+}
+
+pub(super) fn set_unit(s: &Column, unit: &PlSmallStr) -> PolarsResult<Column> {
+    Ok(with_unit(s.clone(), unit))
+}
+
+pub(super) fn convert_unit(
+    s: &Column,
+    unit: &PlSmallStr,
+    factor: Option<f64>,
+) -> PolarsResult<Column> {
+    let factor = factor.ok_or_else(
+        || polars_err!(ComputeError: "the unit of `convert_unit` to `{}` was not resolved", unit),
+    )?;
+    polars_ensure!(
+        s.dtype().is_primitive_numeric(),
+        InvalidOperation: "`convert_unit` operation not supported for dtype `{}`", s.dtype()
+    );
+    let dtype = match s.dtype() {
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => DataType::Float16,
+        DataType::Float32 => DataType::Float32,
+        _ => DataType::Float64,
+    };
+    let out = s.as_materialized_series().cast(&dtype)? * factor;
+    Ok(with_unit(out.into_column(), unit))
+}
+
 #[cfg(feature = "timezones")]
 pub(super) fn replace_time_zone(
     s: &[Column],
@@ -501,6 +546,24 @@ pub(super) fn search_sorted_impl(
     .map(|ca| ca.into_column())
 }
 
+#[cfg(feature = "search_sorted")]
+pub(super) fn search_sorted_by_impl(
+    s: &mut [Column],
+    side: polars_ops::series::SearchSortedSide,
+    descending: &[bool],
+    nulls_last: &[bool],
+) -> PolarsResult<Column> {
+    let (by, search_values) = s.split_at(s.len() / 2);
+    let search_values = by
+        .iter()
+        .zip(search_values)
+        .map(|(by, v)| v.strict_cast(by.dtype()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    polars_ops::series::search_sorted_by(by, &search_values, side, descending, nulls_last)
+        .map(|ca| ca.into_column())
+}
+
 #[cfg(feature = "sign")]
 pub(super) fn sign(s: &Column) -> PolarsResult<Column> {
     use num_traits::{One, Zero};
@@ -885,6 +948,7 @@ pub(super) fn cut(
     labels: Option<Vec<PlSmallStr>>,
     left_closed: bool,
     include_breaks: bool,
+    include_bounds: bool,
 ) -> PolarsResult<Column> {
     polars_ops::prelude::cut(
         s.as_materialized_series(),
@@ -892,6 +956,7 @@ pub(super) fn cut(
         labels,
         left_closed,
         include_breaks,
+        include_bounds,
     )
     .map(Column::from)
 }
@@ -904,6 +969,7 @@ pub(super) fn qcut(
     left_closed: bool,
     allow_duplicates: bool,
     include_breaks: bool,
+    include_bounds: bool,
 ) -> PolarsResult<Column> {
     polars_ops::prelude::qcut(
         s.as_materialized_series(),
@@ -912,10 +978,93 @@ pub(super) fn qcut(
         left_closed,
         allow_duplicates,
         include_breaks,
+        include_bounds,
+    )
+    .map(Column::from)
+}
+
+#[cfg(feature = "to_dummies")]
+pub(super) fn to_dummies(
+    s: &Column,
+    categories: Option<&[PlSmallStr]>,
+    separator: &str,
+    drop_first: bool,
+) -> PolarsResult<Column> {
+    polars_ops::series::to_dummies_struct(
+        s.as_materialized_series(),
+        categories,
+        separator,
+        drop_first,
     )
     .map(Column::from)
 }
 
+#[cfg(feature = "fft")]
+pub(super) fn fft(s: &Column) -> PolarsResult<Column> {
+    polars_ops::series::fft(s.as_materialized_series()).map(Column::from)
+}
+
+#[cfg(feature = "fft")]
+pub(super) fn power_spectrum(s: &Column, window: usize) -> PolarsResult<Column> {
+    polars_ops::series::power_spectrum(s.as_materialized_series(), window).map(Column::from)
+}
+
+#[cfg(feature = "convolve")]
+pub(super) fn convolve(
+    s: &Column,
+    kernel: &[f64],
+    mode: ConvolveMode,
+    nulls: ConvolveNulls,
+) -> PolarsResult<Column> {
+    polars_ops::series::convolve(s.as_materialized_series(), kernel, mode, nulls).map(Column::from)
+}
+
+#[cfg(feature = "convolve")]
+pub(super) fn savgol(
+    s: &Column,
+    window: usize,
+    polyorder: usize,
+    nulls: ConvolveNulls,
+) -> PolarsResult<Column> {
+    polars_ops::series::savgol(s.as_materialized_series(), window, polyorder, nulls)
+        .map(Column::from)
+}
+
+#[cfg(feature = "anonymize")]
+pub(super) fn bucketize_numeric(s: &Column, k_anonymity: usize) -> PolarsResult<Column> {
+    polars_ops::series::bucketize_numeric(s.as_materialized_series(), k_anonymity).map(Column::from)
+}
+
+#[cfg(feature = "scan_fn")]
+pub(super) fn scan_fn(
+    s: &Column,
+    step: &polars_plan::dsl::ScanFnStep,
+    return_dtype: Option<&DataType>,
+) -> PolarsResult<Column> {
+    use polars_plan::dsl::ScanFnStep;
+
+    let s = s.as_materialized_series();
+    let dtype = return_dtype.unwrap_or(s.dtype());
+    let out = match step {
+        ScanFnStep::Registered { name, init } => polars_ops::series::scan_fn_registered(
+            s,
+            name,
+            init.value().clone().into_static(),
+            dtype,
+        )?,
+        ScanFnStep::Series(callback) => {
+            let out = callback.call(s.clone())?;
+            polars_ensure!(
+                out.len() == s.len() && out.dtype() == dtype,
+                ComputeError: "`scan_fn` expected {} values of dtype {dtype}, got {} of dtype {}",
+                s.len(), out.len(), out.dtype()
+            );
+            out
+        },
+    };
+    Ok(out.into_column())
+}
+
 #[cfg(feature = "ewma")]
 pub(super) fn ewm_mean(
     s: &Column,
@@ -110,6 +110,8 @@ mod datetime;
 mod extension;
 mod groups_dispatch;
 mod horizontal;
+#[cfg(feature = "interval")]
+mod interval;
 mod list;
 mod misc;
 mod pow;
@@ -160,6 +162,8 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::Boolean(func) => boolean::function_expr_to_udf(func),
         #[cfg(feature = "business")]
         F::Business(func) => business::function_expr_to_udf(func),
+        #[cfg(feature = "interval")]
+        F::Interval(func) => interval::function_expr_to_udf(func),
         #[cfg(feature = "abs")]
         F::Abs => map!(misc::abs),
         F::Negate => map!(misc::negate),
@@ -191,6 +195,14 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::SearchSorted { side, descending } => {
             map_as_slice!(misc::search_sorted_impl, side, descending)
         },
+        #[cfg(feature = "search_sorted")]
+        F::SearchSortedBy {
+            side,
+            descending,
+            nulls_last,
+        } => {
+            map_as_slice!(misc::search_sorted_by_impl, side, &descending, &nulls_last)
+        },
         #[cfg(feature = "range")]
         F::Range(func) => range::function_expr_to_udf(func),
 
@@ -388,12 +400,14 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
             labels,
             left_closed,
             include_breaks,
+            include_bounds,
         } => map!(
             misc::cut,
             breaks.clone(),
             labels.clone(),
             left_closed,
-            include_breaks
+            include_breaks,
+            include_bounds
         ),
         #[cfg(feature = "cutqcut")]
         F::QCut {
@@ -402,19 +416,53 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
             left_closed,
             allow_duplicates,
             include_breaks,
+            include_bounds,
         } => map!(
             misc::qcut,
             probs.clone(),
             labels.clone(),
             left_closed,
             allow_duplicates,
-            include_breaks
+            include_breaks,
+            include_bounds
         ),
         #[cfg(feature = "rle")]
         F::RLE => map!(polars_ops::series::rle),
         #[cfg(feature = "rle")]
         F::RLEID => map!(polars_ops::series::rle_id),
+        #[cfg(feature = "to_dummies")]
+        F::ToDummies {
+            categories,
+            separator,
+            drop_first,
+        } => map!(
+            misc::to_dummies,
+            categories.as_deref(),
+            &separator,
+            drop_first
+        ),
+        #[cfg(feature = "fft")]
+        F::Fft => map!(misc::fft),
+        #[cfg(feature = "fft")]
+        F::PowerSpectrum { window } => map!(misc::power_spectrum, window),
+        #[cfg(feature = "convolve")]
+        F::Convolve {
+            kernel,
+            mode,
+            nulls,
+        } => map!(misc::convolve, &kernel, mode, nulls),
+        #[cfg(feature = "convolve")]
+        F::Savgol {
+            window,
+            polyorder,
+            nulls,
+        } => map!(misc::savgol, window, polyorder, nulls),
+        #[cfg(feature = "anonymize")]
+        F::BucketizeNumeric { k_anonymity } => map!(misc::bucketize_numeric, k_anonymity),
         F::ToPhysical => map!(misc::to_physical),
+        F::WithFieldMetadata(metadata) => map!(misc::with_field_metadata, &metadata),
+        F::SetUnit(unit) => map!(misc::set_unit, &unit),
+        F::ConvertUnit { unit, factor } => map!(misc::convert_unit, &unit, factor),
         #[cfg(feature = "random")]
         F::Random { method, seed } => {
             use IRRandomMethod::*;
@@ -434,6 +482,11 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
                 },
             }
         },
+        #[cfg(feature = "random")]
+        F::DpNoise {
+            epsilon,
+            sensitivity,
+        } => map!(random::dp_noise, epsilon, sensitivity),
         F::SetSortedFlag(sortedness) => map!(misc::set_sorted_flag, sortedness),
         #[cfg(feature = "ffi_plugin")]
         F::FfiPlugin {
@@ -499,6 +552,8 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::MinHorizontal => wrap!(misc::min_horizontal),
         F::SumHorizontal { ignore_nulls } => wrap!(misc::sum_horizontal, ignore_nulls),
         F::MeanHorizontal { ignore_nulls } => wrap!(misc::mean_horizontal, ignore_nulls),
+        #[cfg(feature = "scan_fn")]
+        F::ScanFn { step, return_dtype } => map!(misc::scan_fn, &step, return_dtype.as_ref()),
         #[cfg(feature = "ewma")]
         F::EwmMean { options } => map!(misc::ewm_mean, options),
         #[cfg(feature = "ewma_by")]
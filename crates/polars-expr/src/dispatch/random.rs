@@ -1,6 +1,9 @@
 use polars_core::error::{PolarsResult, polars_ensure};
 use polars_core::prelude::DataType::Float64;
-use polars_core::prelude::{Column, IDX_DTYPE};
+use polars_core::prelude::{Column, Float64Chunked, IDX_DTYPE, IntoColumn};
+use polars_plan::plans::privacy::dp_noise_seed;
+use rand::distr::Open01;
+use rand::prelude::*;
 
 pub(super) fn shuffle(s: &Column, seed: Option<u64>) -> PolarsResult<Column> {
     Ok(s.shuffle(seed))
@@ -51,3 +54,69 @@ pub(super) fn sample_n(
         None => Ok(Column::new_empty(src.name().clone(), src.dtype())),
     }
 }
+
+pub(super) fn dp_noise(s: &Column, epsilon: f64, sensitivity: f64) -> PolarsResult<Column> {
+    polars_ensure!(
+        s.dtype().is_primitive_numeric() || s.dtype().is_null(),
+        InvalidOperation: "`dp_noise` operation not supported for dtype `{}`", s.dtype()
+    );
+
+    let s = s.cast(&Float64)?;
+    if sensitivity == 0.0 {
+        return Ok(s);
+    }
+    // The noise must not be predictable, so it is drawn from a generator that is seeded from
+    // the OS and can't be seeded by the user (see `set_random_seed`), unless a seed is set for
+    // testing.
+    let mut rng = match dp_noise_seed() {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_os_rng(),
+    };
+    let laplace = LatticeLaplace::new(epsilon, sensitivity);
+    let out: Float64Chunked = s
+        .f64()?
+        .iter()
+        .map(|v| v.map(|v| laplace.add_noise(v, &mut rng)))
+        .collect();
+    Ok(out.with_name(s.name().clone()).into_column())
+}
+
+/// The Laplace mechanism on the lattice of multiples of a power of two.
+///
+/// Adding continuous Laplace noise in floating point leaks the value through the low-order bits
+/// of the result (Mironov, "On Significance of the Least Significant Bits for Differential
+/// Privacy", 2012). Instead, the value is rounded to the lattice and noise from the discrete
+/// Laplace distribution is added in multiples of the lattice spacing, so the result is a lattice
+/// point whose low-order bits don't depend on the value.
+struct LatticeLaplace {
+    granularity: f64,
+    /// The scale of the noise in multiples of `granularity`.
+    scale: f64,
+}
+
+impl LatticeLaplace {
+    fn new(epsilon: f64, sensitivity: f64) -> Self {
+        // Fine enough not to noticeably change the noise of scale `sensitivity / epsilon`.
+        let granularity = ((sensitivity / epsilon).log2().ceil() - 40.0).exp2();
+        // Values that differ by at most `sensitivity` are rounded to lattice points that differ by
+        // at most this many steps.
+        let sensitivity = (sensitivity / granularity).floor() + 1.0;
+        Self {
+            granularity,
+            scale: sensitivity / epsilon,
+        }
+    }
+
+    fn add_noise<R: Rng>(&self, v: f64, rng: &mut R) -> f64 {
+        // Scaling by a power of two is exact.
+        let v = (v / self.granularity).round();
+        (v + self.geometric(rng) - self.geometric(rng)) * self.granularity
+    }
+
+    /// Sample the number of failures before the first success of Bernoulli trials with success
+    /// probability `1 - exp(-1 / scale)` by inverting its CDF. The difference of two samples
+    /// follows the discrete Laplace distribution.
+    fn geometric<R: Rng>(&self, rng: &mut R) -> f64 {
+        (-self.scale * rng.sample::<f64, _>(Open01).ln()).floor()
+    }
+}
@@ -57,6 +57,8 @@ pub fn function_expr_to_udf(func: IRStringFunction) -> SpecialEq<Arc<dyn Columns
         Strptime(dtype, options) => {
             map_as_slice!(strings::strptime, dtype.clone(), &options)
         },
+        #[cfg(feature = "dtype-duration")]
+        ToDuration { time_unit, strict } => map!(strings::to_duration, time_unit, strict),
         Split(inclusive) => {
             map_as_slice!(strings::split, inclusive)
         },
@@ -82,10 +84,21 @@ pub fn function_expr_to_udf(func: IRStringFunction) -> SpecialEq<Arc<dyn Columns
         Replace { n, literal } => map_as_slice!(strings::replace, literal, n),
         #[cfg(feature = "string_normalize")]
         Normalize { form } => map!(strings::normalize, form.clone()),
+        #[cfg(feature = "string_normalize")]
+        RemoveDiacritics => map!(strings::remove_diacritics),
+        IsAscii => map!(strings::is_ascii),
         #[cfg(feature = "string_reverse")]
         Reverse => map!(strings::reverse),
         Uppercase => map!(uppercase),
         Lowercase => map!(lowercase),
+        #[cfg(feature = "anonymize")]
+        MaskEmail => map!(strings::mask_email),
+        #[cfg(feature = "anonymize")]
+        MaskDigits { keep_last } => map!(strings::mask_digits, keep_last),
+        #[cfg(feature = "collation")]
+        CollationKey(options) => map!(strings::collation_key, &options),
+        #[cfg(feature = "natural_sort")]
+        NaturalSortKey => map!(strings::natural_sort_key),
         #[cfg(feature = "nightly")]
         Titlecase => map!(strings::titlecase),
         StripChars => map_as_slice!(strings::strip_chars),
@@ -225,6 +238,33 @@ fn lowercase(s: &Column) -> PolarsResult<Column> {
     Ok(ca.to_lowercase().into_column())
 }
 
+#[cfg(feature = "anonymize")]
+pub(super) fn mask_email(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(polars_ops::chunked_array::strings::mask_email(ca).into_column())
+}
+
+#[cfg(feature = "anonymize")]
+pub(super) fn mask_digits(s: &Column, keep_last: usize) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(polars_ops::chunked_array::strings::mask_digits(ca, keep_last).into_column())
+}
+
+#[cfg(feature = "collation")]
+pub(super) fn collation_key(
+    s: &Column,
+    options: &polars_ops::chunked_array::strings::CollationOptions,
+) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(polars_ops::chunked_array::strings::collation_key(ca, options)?.into_column())
+}
+
+#[cfg(feature = "natural_sort")]
+pub(super) fn natural_sort_key(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(polars_ops::chunked_array::strings::natural_sort_key(ca).into_column())
+}
+
 #[cfg(feature = "nightly")]
 pub(super) fn titlecase(s: &Column) -> PolarsResult<Column> {
     let ca = s.str()?;
@@ -412,6 +452,25 @@ pub(super) fn strptime(
     dtype: DataType,
     options: &StrptimeOptions,
 ) -> PolarsResult<Column> {
+    #[cfg(feature = "locales")]
+    if let Some(locale) = &options.locale {
+        // Translate the localized month and weekday names, so that they can be parsed as
+        // English names.
+        let ca = polars_time::delocalize_names(s[0].str()?, options.format.as_deref(), locale)?;
+        let mut s = s.to_vec();
+        s[0] = ca.into_column();
+        let options = StrptimeOptions {
+            locale: None,
+            ..options.clone()
+        };
+        return strptime(&s, dtype, &options);
+    }
+    #[cfg(not(feature = "locales"))]
+    polars_ensure!(
+        options.locale.is_none(),
+        InvalidOperation: "activate the 'locales' feature to parse with a locale"
+    );
+
     match dtype {
         #[cfg(feature = "dtype-date")]
         DataType::Date => to_date(&s[0], options),
@@ -484,6 +543,12 @@ fn to_date(s: &Column, options: &StrptimeOptions) -> PolarsResult<Column> {
     Ok(out.into_column())
 }
 
+#[cfg(feature = "dtype-duration")]
+pub(super) fn to_duration(s: &Column, time_unit: TimeUnit, strict: bool) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.as_duration(time_unit, strict)?.into_column())
+}
+
 #[cfg(feature = "dtype-datetime")]
 fn to_datetime(
     s: &[Column],
@@ -764,6 +829,17 @@ pub(super) fn normalize(
     Ok(ca.str_normalize(form).into_column())
 }
 
+#[cfg(feature = "string_normalize")]
+pub(super) fn remove_diacritics(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_remove_diacritics().into_column())
+}
+
+pub(super) fn is_ascii(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_is_ascii().into_column())
+}
+
 #[cfg(feature = "string_reverse")]
 pub(super) fn reverse(s: &Column) -> PolarsResult<Column> {
     let ca = s.str()?;
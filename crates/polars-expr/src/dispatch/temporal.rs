@@ -59,7 +59,7 @@ pub fn temporal_func_to_udf(func: IRTemporalFunction) -> SpecialEq<Arc<dyn Colum
         TotalNanoseconds { fractional: false } => map!(datetime::total_nanoseconds),
         #[cfg(feature = "dtype-duration")]
         TotalNanoseconds { fractional: true } => map!(datetime::total_nanoseconds_fractional),
-        ToString(format) => map!(datetime::to_string, &format),
+        ToString { format, locale } => map!(datetime::to_string, &format, locale.as_deref()),
         TimeStamp(tu) => map!(datetime::timestamp, tu),
         #[cfg(feature = "timezones")]
         ConvertTimeZone(tz) => map!(datetime::convert_time_zone, &tz),
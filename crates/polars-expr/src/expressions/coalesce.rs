@@ -0,0 +1,46 @@
+use polars_core::prelude::*;
+use polars_ops::series::coalesce_columns_lazy;
+
+use super::*;
+use crate::expressions::{AggregationContext, ApplyExpr, PhysicalExpr};
+
+/// `coalesce` that stops evaluating its inputs once no null values remain.
+pub struct CoalesceExpr {
+    inputs: Vec<Arc<dyn PhysicalExpr>>,
+    /// The regular function application, used in the group context.
+    apply: ApplyExpr,
+}
+
+impl CoalesceExpr {
+    pub(crate) fn new(inputs: Vec<Arc<dyn PhysicalExpr>>, apply: ApplyExpr) -> Self {
+        Self { inputs, apply }
+    }
+}
+
+impl PhysicalExpr for CoalesceExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        self.apply.as_expression()
+    }
+
+    fn evaluate_impl(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Column> {
+        coalesce_columns_lazy(self.inputs.iter().map(|e| e.evaluate(df, state)))
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups_impl<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupPositions,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        self.apply.evaluate_on_groups(df, groups, state)
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        self.apply.to_field(input_schema)
+    }
+
+    fn is_scalar(&self) -> bool {
+        self.apply.is_scalar()
+    }
+}
@@ -0,0 +1,76 @@
+use polars_core::prelude::*;
+
+use super::*;
+use crate::expressions::{AggregationContext, PhysicalExpr};
+
+/// A sub-expression that occurs more than once in a context, e.g. in different windows.
+///
+/// It is evaluated once per context and groups; the other occurrences read the output from
+/// [`ExecutionState::expr_memo`].
+pub struct MemoizedExpr {
+    input: Arc<dyn PhysicalExpr>,
+    id: usize,
+}
+
+impl MemoizedExpr {
+    pub fn new(input: Arc<dyn PhysicalExpr>, id: usize) -> Self {
+        Self { input, id }
+    }
+}
+
+impl PhysicalExpr for MemoizedExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        self.input.as_expression()
+    }
+
+    // The input already adds its expression to errors.
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Column> {
+        self.evaluate_impl(df, state)
+    }
+
+    fn evaluate_impl(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Column> {
+        if let Some(c) = state.expr_memo.get_column(self.id) {
+            return Ok(c);
+        }
+        let c = self.input.evaluate(df, state)?;
+        state.expr_memo.insert_column(self.id, c.clone());
+        Ok(c)
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupPositions,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        self.evaluate_on_groups_impl(df, groups, state)
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups_impl<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupPositions,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        if let Some(ac) = state.expr_memo.get_aggregation(self.id, groups) {
+            return Ok(ac);
+        }
+        let ac = self.input.evaluate_on_groups(df, groups, state)?;
+        state.expr_memo.insert_aggregation(self.id, groups, &ac);
+        Ok(ac)
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        self.input.to_field(input_schema)
+    }
+
+    fn is_literal(&self) -> bool {
+        self.input.is_literal()
+    }
+
+    fn is_scalar(&self) -> bool {
+        self.input.is_scalar()
+    }
+}
@@ -3,6 +3,7 @@ mod alias;
 mod apply;
 mod binary;
 mod cast;
+mod coalesce;
 mod column;
 mod count;
 mod element;
@@ -13,6 +14,7 @@ mod filter;
 mod gather;
 mod group_iter;
 mod literal;
+mod memoize;
 #[cfg(feature = "dynamic_group_by")]
 mod rolling;
 mod slice;
@@ -34,6 +36,7 @@ use arrow::bitmap::MutableBitmap;
 use arrow::legacy::utils::CustomIterTools;
 pub(crate) use binary::*;
 pub(crate) use cast::*;
+pub(crate) use coalesce::*;
 pub(crate) use column::*;
 pub(crate) use count::*;
 pub(crate) use element::*;
@@ -43,6 +46,7 @@ pub(crate) use field::*;
 pub(crate) use filter::*;
 pub(crate) use gather::*;
 pub(crate) use literal::*;
+pub(crate) use memoize::*;
 use polars_core::prelude::*;
 use polars_io::predicates::PhysicalIoExpr;
 use polars_plan::prelude::*;
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_plan::prelude::*;
@@ -13,9 +15,17 @@ pub struct TernaryExpr {
     // Can be expensive on small data to run literals in parallel.
     run_par: bool,
     returns_scalar: bool,
+    /// Set for the ternaries of `when_lazy`: the input columns of the branches, which are only
+    /// evaluated on the rows they are selected for, see [`evaluate_selected`].
+    truthy_columns: Option<Vec<PlSmallStr>>,
+    falsy_columns: Option<Vec<PlSmallStr>>,
+    /// Set for the ternaries of `when_lazy`: the input columns of the whole expression, which is
+    /// then evaluated on every group on its own.
+    group_columns: Option<Vec<PlSmallStr>>,
 }
 
 impl TernaryExpr {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         predicate: Arc<dyn PhysicalExpr>,
         truthy: Arc<dyn PhysicalExpr>,
@@ -23,6 +33,9 @@ impl TernaryExpr {
         expr: Expr,
         run_par: bool,
         returns_scalar: bool,
+        truthy_columns: Option<Vec<PlSmallStr>>,
+        falsy_columns: Option<Vec<PlSmallStr>>,
+        group_columns: Option<Vec<PlSmallStr>>,
     ) -> Self {
         Self {
             predicate,
@@ -31,8 +44,98 @@ impl TernaryExpr {
             expr,
             run_par,
             returns_scalar,
+            truthy_columns,
+            falsy_columns,
+            group_columns,
         }
     }
+
+    /// Evaluate the ternary on the rows of every group, so that the branches only see the
+    /// selected rows of their own group.
+    fn evaluate_per_group<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupPositions,
+        columns: &[PlSmallStr],
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let df = select_inputs(df, columns)?;
+        let name = self.to_field(df.schema())?.name;
+        let ca: ListChunked = groups
+            .iter()
+            .map(|indicator| {
+                let group = match indicator {
+                    // SAFETY: group tuples are always in bounds.
+                    GroupsIndicator::Idx((_, idx)) => unsafe {
+                        df._take_unchecked_slice(idx, false)
+                    },
+                    GroupsIndicator::Slice([first, len]) => df.slice(first as i64, len as usize),
+                };
+                let out = self.evaluate(&group, state)?;
+                Ok(Some(out.take_materialized_series()))
+            })
+            .collect::<PolarsResult<_>>()?;
+        let ca = ca.with_name(name);
+
+        let state = if self.returns_scalar {
+            let out = ca.explode(ExplodeOptions {
+                empty_as_null: true,
+                keep_nulls: true,
+            })?;
+            AggState::AggregatedScalar(out.into_column())
+        } else {
+            AggState::AggregatedList(ca.into_column())
+        };
+        let mut ac = AggregationContext::from_agg_state(state, Cow::Borrowed(groups));
+        if !self.returns_scalar {
+            ac.with_update_groups(UpdateGroups::WithSeriesLen);
+        }
+        Ok(ac)
+    }
+}
+
+/// Select the input `columns` of a lazy ternary from `df`.
+fn select_inputs(df: &DataFrame, columns: &[PlSmallStr]) -> PolarsResult<DataFrame> {
+    // The expression may read columns of an outer context, which are not in `df`.
+    let schema = df.schema();
+    df.select(columns.iter().filter(|name| schema.contains(name)))
+}
+
+/// Evaluate `branch` on the rows where `mask` is true only, and put the results back in place
+/// with nulls on the other rows. `columns` are the input columns of `branch`.
+fn evaluate_selected(
+    branch: &dyn PhysicalExpr,
+    columns: &[PlSmallStr],
+    mask: &BooleanChunked,
+    df: &DataFrame,
+    state: &ExecutionState,
+) -> PolarsResult<Column> {
+    let n_selected = mask.num_trues();
+    if mask.len() != df.height() || n_selected == df.height() {
+        return branch.evaluate(df, state);
+    }
+    if n_selected == 0 {
+        let field = branch.to_field(df.schema())?;
+        return Ok(Column::full_null(field.name, df.height(), &field.dtype));
+    }
+    let selected = select_inputs(df, columns)?.filter(mask)?;
+    let out = branch.evaluate(&selected, state)?;
+    if out.len() != n_selected {
+        // Scalars and literal series don't depend on the rows of `df`.
+        return Ok(out);
+    }
+
+    let mut position = 0 as IdxSize;
+    let idx: IdxCa = mask
+        .into_no_null_iter()
+        .map(|selected| {
+            selected.then(|| {
+                position += 1;
+                position - 1
+            })
+        })
+        .collect();
+    out.take(&idx)
 }
 
 fn finish_as_iters<'a>(
@@ -91,8 +194,18 @@ impl PhysicalExpr for TernaryExpr {
         let mask_series = self.predicate.evaluate(df, &state)?;
         let mask = mask_series.bool()?.clone();
 
-        let op_truthy = || self.truthy.evaluate(df, &state);
-        let op_falsy = || self.falsy.evaluate(df, &state);
+        // Null predicates select the falsy branch.
+        let truthy_mask = || mask.fill_null_with_values(false);
+        let op_truthy = || match &self.truthy_columns {
+            Some(columns) => evaluate_selected(&*self.truthy, columns, &truthy_mask()?, df, &state),
+            None => self.truthy.evaluate(df, &state),
+        };
+        let op_falsy = || match &self.falsy_columns {
+            Some(columns) => {
+                evaluate_selected(&*self.falsy, columns, &!&truthy_mask()?, df, &state)
+            },
+            None => self.falsy.evaluate(df, &state),
+        };
         let (truthy, falsy) = if self.run_par {
             POOL.install(|| rayon::join(op_truthy, op_falsy))
         } else {
@@ -115,6 +228,10 @@ impl PhysicalExpr for TernaryExpr {
         groups: &'a GroupPositions,
         state: &ExecutionState,
     ) -> PolarsResult<AggregationContext<'a>> {
+        if let Some(columns) = &self.group_columns {
+            return self.evaluate_per_group(df, groups, columns, state);
+        }
+
         let op_mask = || self.predicate.evaluate_on_groups(df, groups, state);
         let op_truthy = || self.truthy.evaluate_on_groups(df, groups, state);
         let op_falsy = || self.falsy.evaluate_on_groups(df, groups, state);
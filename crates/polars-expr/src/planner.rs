@@ -1,3 +1,5 @@
+use std::hash::{BuildHasher, Hash, Hasher};
+
 use polars_core::prelude::*;
 use polars_plan::constants::{get_literal_name, get_pl_element_name, get_pl_structfields_name};
 use polars_plan::prelude::expr_ir::ExprIR;
@@ -87,13 +89,16 @@ where
         .collect()
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct ExpressionConversionState {
     // settings per context
     // they remain activate between
     // expressions
     pub allow_threading: bool,
     pub has_windows: bool,
+    /// Sub-expressions that occur more than once in the context, with the id they are memoized
+    /// under.
+    memoized: PlHashMap<Node, usize>,
     // settings per expression
     // those are reset every expression
     local: LocalConversionState,
@@ -110,12 +115,92 @@ impl ExpressionConversionState {
         Self {
             allow_threading,
             has_windows: false,
+            memoized: Default::default(),
             local: LocalConversionState {
                 ..Default::default()
             },
         }
     }
 
+    /// Memoize the non-trivial sub-expressions that occur more than once in `exprs`, so that
+    /// they are evaluated once per context and groups, e.g. the same input of different windows.
+    ///
+    /// Only the outermost duplicates are memoized. Sub-expressions that are evaluated on other
+    /// frames, such as the branches of a ternary or the evaluation of `list.eval`, are not.
+    pub fn memoize_duplicates(&mut self, exprs: &[ExprIR], expr_arena: &Arena<AExpr>) {
+        let roots = exprs.iter().map(|e| e.node()).collect::<Vec<_>>();
+
+        let mut excluded = PlHashSet::new();
+        let mut classes: Vec<Vec<Node>> = vec![];
+        let mut class_of = PlHashMap::new();
+        let mut by_hash: PlHashMap<u64, Vec<usize>> = PlHashMap::new();
+        let mut stack = roots.clone();
+        while let Some(node) = stack.pop() {
+            let ae = expr_arena.get(node);
+            let evaluated_elsewhere: &[Node] = match ae {
+                AExpr::Ternary {
+                    predicate,
+                    truthy,
+                    falsy,
+                    ..
+                } => &[*predicate, *truthy, *falsy],
+                AExpr::Eval {
+                    expr, evaluation, ..
+                } => {
+                    stack.push(*expr);
+                    &[*evaluation]
+                },
+                #[cfg(feature = "dtype-struct")]
+                AExpr::StructEval { expr, evaluation } => {
+                    stack.push(*expr);
+                    for e in evaluation {
+                        excluded.extend(expr_arena.iter(e.node()).map(|(n, _)| n));
+                    }
+                    &[]
+                },
+                _ => {
+                    if is_memoizable(node, expr_arena) {
+                        let hash = expr_hash(node, expr_arena);
+                        let same = by_hash.entry(hash).or_default();
+                        let class = same
+                            .iter()
+                            .copied()
+                            .find(|&c| {
+                                let other = expr_arena.get(classes[c][0]);
+                                ae.is_expr_equal_to(other, expr_arena)
+                            })
+                            .unwrap_or_else(|| {
+                                same.push(classes.len());
+                                classes.push(vec![]);
+                                classes.len() - 1
+                            });
+                        classes[class].push(node);
+                        class_of.insert(node, class);
+                    }
+                    ae.children_rev(&mut stack);
+                    &[]
+                },
+            };
+            for &n in evaluated_elsewhere {
+                excluded.extend(expr_arena.iter(n).map(|(n, _)| n));
+            }
+        }
+
+        let mut stack = roots;
+        while let Some(node) = stack.pop() {
+            if excluded.contains(&node) {
+                continue;
+            }
+            match class_of.get(&node) {
+                Some(&class) if classes[class].len() > 1 => {
+                    self.memoized.insert(node, class);
+                },
+                _ => expr_arena.get(node).children_rev(&mut stack),
+            }
+        }
+        self.memoized.retain(|node, _| !excluded.contains(node));
+    }
+
     fn reset(&mut self) {
         self.local = LocalConversionState::default();
     }
@@ -145,6 +230,36 @@ pub fn create_physical_expr(
     }
 }
 
+/// Whether the output of `node` only depends on the frame it is evaluated on and is worth keeping.
+fn is_memoizable(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    if matches!(
+        expr_arena.get(node),
+        AExpr::Column(_) | AExpr::Literal(_) | AExpr::Len
+    ) {
+        return false;
+    }
+    !has_aexpr(node, expr_arena, |e| match e {
+        AExpr::Element | AExpr::AnonymousFunction { .. } | AExpr::AnonymousAgg { .. } => true,
+        #[cfg(feature = "dtype-struct")]
+        AExpr::StructField(_) => true,
+        #[cfg(feature = "random")]
+        AExpr::Function {
+            function: IRFunctionExpr::Random { .. } | IRFunctionExpr::DpNoise { .. },
+            ..
+        } => true,
+        _ => false,
+    })
+}
+
+/// Hash the whole expression, such that equal expressions have equal hashes.
+fn expr_hash(node: Node, expr_arena: &Arena<AExpr>) -> u64 {
+    let mut hasher = PlFixedStateQuality::default().build_hasher();
+    for (_, e) in expr_arena.iter(node) {
+        e.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
 #[recursive]
 fn create_physical_expr_inner(
     expression: Node,
@@ -154,6 +269,12 @@ fn create_physical_expr_inner(
 ) -> PolarsResult<Arc<dyn PhysicalExpr>> {
     use AExpr::*;
 
+    if let Some(id) = state.memoized.remove(&expression) {
+        let input = create_physical_expr_inner(expression, expr_arena, schema, state);
+        state.memoized.insert(expression, id);
+        return Ok(Arc::new(MemoizedExpr::new(input?, id)));
+    }
+
     let aexpr = expr_arena.get(expression);
     match aexpr.clone() {
         Len => Ok(Arc::new(phys_expr::CountExpr::new())),
@@ -464,7 +585,10 @@ fn create_physical_expr_inner(
             predicate,
             truthy,
             falsy,
+            lazy,
         } => {
+            let (truthy_node, falsy_node) = (truthy, falsy);
+            let group_columns = lazy.then(|| leaf_columns(expression, expr_arena));
             let is_scalar = is_scalar_ae(expression, expr_arena);
             let mut lit_count = 0u8;
             state.reset();
@@ -483,6 +607,9 @@ fn create_physical_expr_inner(
                 node_to_expr(expression, expr_arena),
                 state.allow_threading && lit_count < 2,
                 is_scalar,
+                lazy.then(|| leaf_columns(truthy_node, expr_arena)),
+                lazy.then(|| leaf_columns(falsy_node, expr_arena)),
+                group_columns,
             )))
         },
         AExpr::AnonymousAgg {
@@ -620,8 +747,11 @@ fn create_physical_expr_inner(
 
             let input = create_physical_expressions_from_irs(&input, expr_arena, schema, state)?;
             let is_fallible = expr_arena.get(expression).is_fallible_top_level(expr_arena);
+            // `coalesce` only evaluates the inputs it needs outside of the group context.
+            let coalesce_inputs =
+                matches!(function, IRFunctionExpr::Coalesce).then(|| input.clone());
 
-            Ok(Arc::new(ApplyExpr::new(
+            let apply = ApplyExpr::new(
                 input,
                 function_expr_to_udf(function.clone()),
                 function_expr_to_groups_udf(&function),
@@ -632,7 +762,11 @@ fn create_physical_expr_inner(
                 output_field,
                 is_scalar,
                 is_fallible,
-            )))
+            );
+            match coalesce_inputs {
+                Some(inputs) => Ok(Arc::new(CoalesceExpr::new(inputs, apply))),
+                None => Ok(Arc::new(apply)),
+            }
         },
 
         Slice {
@@ -675,3 +809,10 @@ fn create_physical_expr_inner(
         },
     }
 }
+
+fn leaf_columns(node: Node, expr_arena: &Arena<AExpr>) -> Vec<PlSmallStr> {
+    let mut columns = aexpr_to_leaf_names(node, expr_arena);
+    columns.sort_unstable();
+    columns.dedup();
+    columns
+}
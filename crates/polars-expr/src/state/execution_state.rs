@@ -12,6 +12,7 @@ use polars_utils::relaxed_cell::RelaxedCell;
 use polars_utils::unique_id::UniqueId;
 
 use super::NodeTimer;
+use crate::expressions::{AggState, UpdateGroups};
 use crate::prelude::AggregationContext;
 
 pub type JoinTuplesCache = Arc<Mutex<PlHashMap<String, ChunkJoinOptIds>>>;
@@ -66,6 +67,85 @@ impl WindowCache {
     }
 }
 
+struct MemoizedAggregation {
+    /// The groups the expression was evaluated on.
+    groups: GroupPositions,
+    state: AggState,
+    /// `None` if the output has the groups it was evaluated on.
+    output_groups: Option<GroupPositions>,
+    update_groups: UpdateGroups,
+    original_len: bool,
+}
+
+/// The outputs of sub-expressions that occur more than once in a context, by their id.
+///
+/// This must be cleared after every evaluation of the context, as the outputs are only valid for
+/// the `DataFrame` they were evaluated on.
+#[derive(Default)]
+pub struct ExprMemo {
+    columns: RwLock<PlHashMap<usize, Column>>,
+    aggregations: RwLock<PlHashMap<usize, Vec<MemoizedAggregation>>>,
+}
+
+impl ExprMemo {
+    pub(crate) fn clear(&self) {
+        let Self {
+            columns,
+            aggregations,
+        } = self;
+        columns.write().unwrap().clear();
+        aggregations.write().unwrap().clear();
+    }
+
+    pub(crate) fn get_column(&self, id: usize) -> Option<Column> {
+        let c = self.columns.read().unwrap();
+        c.get(&id).cloned()
+    }
+
+    pub(crate) fn insert_column(&self, id: usize, column: Column) {
+        let mut c = self.columns.write().unwrap();
+        c.insert(id, column);
+    }
+
+    pub(crate) fn get_aggregation<'a>(
+        &self,
+        id: usize,
+        groups: &'a GroupPositions,
+    ) -> Option<AggregationContext<'a>> {
+        let a = self.aggregations.read().unwrap();
+        let memoized = a.get(&id)?.iter().find(|m| m.groups.is_same(groups))?;
+        Some(AggregationContext {
+            state: memoized.state.clone(),
+            groups: match &memoized.output_groups {
+                None => Cow::Borrowed(groups),
+                Some(output_groups) => Cow::Owned(output_groups.clone()),
+            },
+            update_groups: memoized.update_groups,
+            original_len: memoized.original_len,
+        })
+    }
+
+    pub(crate) fn insert_aggregation(
+        &self,
+        id: usize,
+        groups: &GroupPositions,
+        ac: &AggregationContext<'_>,
+    ) {
+        let output_groups = match &ac.groups {
+            Cow::Borrowed(output_groups) if std::ptr::eq(*output_groups, groups) => None,
+            output_groups => Some(output_groups.as_ref().clone()),
+        };
+        let mut a = self.aggregations.write().unwrap();
+        a.entry(id).or_default().push(MemoizedAggregation {
+            groups: groups.clone(),
+            state: ac.state.clone(),
+            output_groups,
+            update_groups: ac.update_groups,
+            original_len: ac.original_len,
+        });
+    }
+}
+
 bitflags! {
     #[repr(transparent)]
     #[derive(Copy, Clone)]
@@ -120,6 +200,8 @@ pub struct ExecutionState {
     pub schema_cache: Arc<RwLock<Option<SchemaRef>>>,
     /// Used by Window Expressions to cache intermediate state
     pub window_cache: Arc<WindowCache>,
+    /// Used by memoized sub-expressions to evaluate only once per context.
+    pub expr_memo: Arc<ExprMemo>,
     // every join/union split gets an increment to distinguish between schema state
     pub branch_idx: usize,
     pub flags: RelaxedCell<u8>,
@@ -143,6 +225,7 @@ impl ExecutionState {
             df_cache: Default::default(),
             schema_cache: Default::default(),
             window_cache: Default::default(),
+            expr_memo: Default::default(),
             branch_idx: 0,
             flags: RelaxedCell::from(StateFlags::init().as_u8()),
             #[cfg(feature = "dtype-struct")]
@@ -212,6 +295,7 @@ impl ExecutionState {
             df_cache: self.df_cache.clone(),
             schema_cache: Default::default(),
             window_cache: Default::default(),
+            expr_memo: Default::default(),
             branch_idx: self.branch_idx,
             flags: self.flags.clone(),
             ext_contexts: self.ext_contexts.clone(),
@@ -284,6 +368,11 @@ impl ExecutionState {
         self.window_cache.clear();
     }
 
+    /// Clear the outputs of memoized sub-expressions. Typically at the end of a projection.
+    pub fn clear_expr_memo(&self) {
+        self.expr_memo.clear();
+    }
+
     fn set_flags(&self, f: &dyn Fn(StateFlags) -> StateFlags) {
         let flags: StateFlags = self.flags.load().into();
         let flags = f(flags);
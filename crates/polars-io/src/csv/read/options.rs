@@ -391,6 +391,9 @@ pub enum NullValues {
     AllColumns(Vec<PlSmallStr>),
     /// Tuples that map column names to null value of that column
     Named(Vec<(PlSmallStr, PlSmallStr)>),
+    /// Tuples that map column names to the null values of that column. Columns without a tuple
+    /// and columns mapped to no values do not have null values.
+    NamedMultiple(Vec<(PlSmallStr, Vec<PlSmallStr>)>),
 }
 
 impl NullValues {
@@ -406,6 +409,14 @@ impl NullValues {
                 }
                 NullValuesCompiled::Columns(null_values)
             },
+            NullValues::NamedMultiple(v) => {
+                let mut null_values = vec![vec![]; schema.len()];
+                for (name, column_null_values) in v {
+                    let i = schema.try_index_of(&name)?;
+                    null_values[i] = column_null_values;
+                }
+                NullValuesCompiled::ColumnsMultiple(null_values)
+            },
         })
     }
 }
@@ -418,6 +429,8 @@ pub enum NullValuesCompiled {
     AllColumns(Vec<PlSmallStr>),
     /// A different null value per column, computed from `NullValues::Named`
     Columns(Vec<PlSmallStr>),
+    /// Different null values per column, computed from `NullValues::NamedMultiple`
+    ColumnsMultiple(Vec<Vec<PlSmallStr>>),
 }
 
 impl NullValuesCompiled {
@@ -433,6 +446,10 @@ impl NullValuesCompiled {
                 debug_assert!(index < v.len());
                 v.get_unchecked(index).as_bytes() == field
             },
+            ColumnsMultiple(v) => {
+                debug_assert!(index < v.len());
+                v.get_unchecked(index).iter().any(|v| v.as_bytes() == field)
+            },
         }
     }
 }
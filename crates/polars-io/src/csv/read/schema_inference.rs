@@ -183,6 +183,25 @@ fn infer_types_from_line(
                         ))
                     }
                 },
+                Some(NullValues::NamedMultiple(names)) => {
+                    let current_name = &headers[i];
+                    let is_null = names
+                        .iter()
+                        .find(|name| name.0 == current_name)
+                        .is_some_and(|(_, null_values)| {
+                            null_values.iter().any(|nv| nv == s.as_ref())
+                        });
+
+                    if !is_null {
+                        Some(infer_field_schema(
+                            &s,
+                            parse_options.try_parse_dates,
+                            parse_options.decimal_comma,
+                        ))
+                    } else {
+                        None
+                    }
+                },
             };
             if let Some(dtype) = dtype {
                 column_types[i].insert(dtype);
@@ -58,12 +58,19 @@ pub struct SerializeOptions {
     pub separator: u8,
     /// Quoting character.
     pub quote_char: u8,
+    /// Character that escapes quotes and itself in quoted fields. Quotes are escaped by doubling
+    /// them if `None`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub escape_char: Option<u8>,
     /// Null value representation.
     pub null: PlSmallStr,
     /// String appended after every row.
     pub line_terminator: PlSmallStr,
     /// When to insert quotes.
     pub quote_style: QuoteStyle,
+    /// Overrides of the options above for specific columns.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub column_options: Vec<(PlSmallStr, CsvColumnOptions)>,
 }
 
 impl Default for SerializeOptions {
@@ -77,9 +84,59 @@ impl Default for SerializeOptions {
             decimal_comma: false,
             separator: b',',
             quote_char: b'"',
+            escape_char: None,
             null: PlSmallStr::EMPTY,
             line_terminator: "\n".into(),
             quote_style: Default::default(),
+            column_options: Vec::new(),
+        }
+    }
+}
+
+/// Options to serialize a single column to CSV.
+///
+/// Options that are not set fall back to those of the [`SerializeOptions`].
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct CsvColumnOptions {
+    /// When to insert quotes.
+    pub quote_style: Option<QuoteStyle>,
+    /// Whether to format floats in scientific notation.
+    pub float_scientific: Option<bool>,
+    /// The number of decimals of floats.
+    pub float_precision: Option<usize>,
+    /// Used for [`DataType::Date`](polars_core::datatypes::DataType::Date).
+    pub date_format: Option<PlSmallStr>,
+    /// Used for [`DataType::Time`](polars_core::datatypes::DataType::Time).
+    pub time_format: Option<PlSmallStr>,
+    /// Used for [`DataType::Datetime`](polars_core::datatypes::DataType::Datetime).
+    pub datetime_format: Option<PlSmallStr>,
+}
+
+impl SerializeOptions {
+    /// The options of a column with the given overrides applied.
+    pub fn with_column_options(&self, column_options: &CsvColumnOptions) -> Self {
+        let CsvColumnOptions {
+            quote_style,
+            float_scientific,
+            float_precision,
+            date_format,
+            time_format,
+            datetime_format,
+        } = column_options;
+
+        Self {
+            quote_style: quote_style.unwrap_or(self.quote_style),
+            float_scientific: float_scientific.or(self.float_scientific),
+            float_precision: float_precision.or(self.float_precision),
+            date_format: date_format.clone().or_else(|| self.date_format.clone()),
+            time_format: time_format.clone().or_else(|| self.time_format.clone()),
+            datetime_format: datetime_format
+                .clone()
+                .or_else(|| self.datetime_format.clone()),
+            column_options: Vec::new(),
+            ..self.clone()
         }
     }
 }
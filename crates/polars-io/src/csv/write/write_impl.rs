@@ -9,7 +9,7 @@ use polars_utils::reuse_vec::reuse_vec;
 use rayon::prelude::*;
 use serializer::{serializer_for, string_serializer};
 
-use crate::csv::write::SerializeOptions;
+use crate::csv::write::{CsvColumnOptions, SerializeOptions};
 
 type ColumnSerializer<'a> =
     dyn crate::csv::write::write_impl::serializer::Serializer<'a> + Send + 'a;
@@ -18,6 +18,7 @@ type ColumnSerializer<'a> =
 pub struct CsvSerializer {
     serializers: Vec<Box<ColumnSerializer<'static>>>,
     options: Arc<SerializeOptions>,
+    column_options: Arc<[SerializeOptions]>,
     datetime_formats: Arc<[PlSmallStr]>,
     time_zones: Arc<[Option<Tz>]>,
 }
@@ -27,6 +28,7 @@ impl Clone for CsvSerializer {
         Self {
             serializers: vec![],
             options: self.options.clone(),
+            column_options: self.column_options.clone(),
             datetime_formats: self.datetime_formats.clone(),
             time_zones: self.time_zones.clone(),
         }
@@ -59,10 +61,35 @@ impl CsvSerializer {
             std::str::from_utf8(&[options.quote_char, options.quote_char]).is_ok(),
             ComputeError: "quote char results in invalid utf-8",
         );
+        if let Some(escape_char) = options.escape_char {
+            polars_ensure!(
+                std::str::from_utf8(&[escape_char, options.quote_char]).is_ok(),
+                ComputeError: "escape char results in invalid utf-8",
+            );
+        }
+
+        // Resolve the options of every column, so that the serializers don't need to look up the
+        // overrides.
+        let mut column_overrides = vec![None; schema.len()];
+        for (name, overrides) in &options.column_options {
+            let Some(i) = schema.index_of(name) else {
+                polars_bail!(
+                    ColumnNotFound: "cannot set the CSV options of column '{}': not found in the schema",
+                    name
+                );
+            };
+            column_overrides[i] = Some(overrides);
+        }
+        let no_overrides = CsvColumnOptions::default();
+        let column_options: Arc<[SerializeOptions]> = column_overrides
+            .into_iter()
+            .map(|overrides| options.with_column_options(overrides.unwrap_or(&no_overrides)))
+            .collect();
 
         let (datetime_formats, time_zones): (Vec<PlSmallStr>, Vec<Option<Tz>>) = schema
             .iter_values()
-            .map(|dtype| {
+            .zip(column_options.iter())
+            .map(|(dtype, options)| {
                 let (datetime_format_str, time_zone) = match dtype {
                     DataType::Datetime(TimeUnit::Milliseconds, tz) => {
                         let (format, tz_parsed) = match tz {
@@ -134,6 +161,7 @@ impl CsvSerializer {
         Ok(Self {
             serializers: vec![],
             options,
+            column_options,
             datetime_formats: Arc::from_iter(datetime_formats),
             time_zones: Arc::from_iter(time_zones),
         })
@@ -152,15 +180,19 @@ impl CsvSerializer {
 
         let options = Arc::clone(&self.options);
         let options = options.as_ref();
+        let column_options = Arc::clone(&self.column_options);
+        let column_options = column_options.as_ref();
 
         let mut serializers_vec = reuse_vec(std::mem::take(&mut self.serializers));
         let serializers = self.build_serializers(df.columns(), &mut serializers_vec)?;
 
         for _ in 0..df.height() {
-            serializers[0].serialize(buffer, options);
-            for serializer in &mut serializers[1..] {
+            serializers[0].serialize(buffer, &column_options[0]);
+            for (serializer, column_options) in
+                serializers[1..].iter_mut().zip(&column_options[1..])
+            {
                 buffer.push(options.separator);
-                serializer.serialize(buffer, options);
+                serializer.serialize(buffer, column_options);
             }
 
             buffer.extend_from_slice(options.line_terminator.as_bytes());
@@ -186,7 +218,7 @@ impl CsvSerializer {
 
             serializers.push(serializer_for(
                 c.as_materialized_series().chunks()[0].as_ref(),
-                Arc::as_ref(&self.options),
+                &self.column_options[i],
                 c.dtype(),
                 self.datetime_formats[i].as_str(),
                 self.time_zones[i],
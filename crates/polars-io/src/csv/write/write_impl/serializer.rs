@@ -38,7 +38,7 @@ use arrow::legacy::time_zone::Tz;
 use arrow::types::NativeType;
 #[cfg(feature = "timezones")]
 use chrono::TimeZone;
-use memchr::{memchr_iter, memchr3};
+use memchr::{memchr2_iter, memchr3};
 use num_traits::NumCast;
 use polars_core::prelude::*;
 use polars_utils::float16::pf16;
@@ -451,26 +451,32 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
         }
     }
 
-    fn serialize_str_escaped(buf: &mut Vec<u8>, s: &[u8], quote_char: u8, quoted: bool) {
-        let mut iter = memchr_iter(quote_char, s);
-        let first_quote = iter.next();
-        match first_quote {
+    fn serialize_str_escaped(
+        buf: &mut Vec<u8>,
+        s: &[u8],
+        quote_char: u8,
+        escape_char: u8,
+        quoted: bool,
+    ) {
+        let mut iter = memchr2_iter(quote_char, escape_char, s);
+        let first_escaped = iter.next();
+        match first_escaped {
             None => buf.extend_from_slice(s),
-            Some(mut quote_pos) => {
+            Some(mut escaped_pos) => {
                 if !quoted {
                     buf.push(quote_char);
                 }
                 let mut start_pos = 0;
                 loop {
-                    buf.extend_from_slice(&s[start_pos..quote_pos]);
-                    buf.extend_from_slice(&[quote_char, quote_char]);
+                    buf.extend_from_slice(&s[start_pos..escaped_pos]);
+                    buf.extend_from_slice(&[escape_char, s[escaped_pos]]);
                     match iter.next() {
-                        Some(quote) => {
-                            start_pos = quote_pos + 1;
-                            quote_pos = quote;
+                        Some(escaped) => {
+                            start_pos = escaped_pos + 1;
+                            escaped_pos = escaped;
                         },
                         None => {
-                            buf.extend_from_slice(&s[quote_pos + 1..]);
+                            buf.extend_from_slice(&s[escaped_pos + 1..]);
                             break;
                         },
                     }
@@ -488,13 +494,14 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
             let serialize =
                 move |iter: &mut Iter, buf: &mut Vec<u8>, options: &SerializeOptions| {
                     let quote_char = options.quote_char;
+                    let escape_char = options.escape_char.unwrap_or(quote_char);
                     buf.push(quote_char);
                     let Some(s) = f(iter) else {
                         buf.extend_from_slice(options.null.as_bytes());
                         buf.push(quote_char);
                         return;
                     };
-                    serialize_str_escaped(buf, s.as_bytes(), quote_char, true);
+                    serialize_str_escaped(buf, s.as_bytes(), quote_char, escape_char, true);
                     buf.push(quote_char);
                 };
             Box::new(StringSerializer { serialize, iter })
@@ -507,8 +514,9 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                         return;
                     };
                     let quote_char = options.quote_char;
+                    let escape_char = options.escape_char.unwrap_or(quote_char);
                     buf.push(quote_char);
-                    serialize_str_escaped(buf, s.as_bytes(), quote_char, true);
+                    serialize_str_escaped(buf, s.as_bytes(), quote_char, escape_char, true);
                     buf.push(quote_char);
                 };
             Box::new(StringSerializer { serialize, iter })
@@ -521,6 +529,7 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                         return;
                     };
                     let quote_char = options.quote_char;
+                    let escape_char = options.escape_char.unwrap_or(quote_char);
                     // An empty string conflicts with null, so it is necessary to quote.
                     if s.is_empty() {
                         buf.extend_from_slice(&[quote_char, quote_char]);
@@ -530,7 +539,7 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                     if needs_quote {
                         buf.push(quote_char);
                     }
-                    serialize_str_escaped(buf, s.as_bytes(), quote_char, needs_quote);
+                    serialize_str_escaped(buf, s.as_bytes(), quote_char, escape_char, needs_quote);
                     if needs_quote {
                         buf.push(quote_char);
                     }
@@ -979,5 +988,17 @@ mod test {
         check_string_serialization(&non_numeric_quote, Some("a,b"), r#""a,b""#);
         check_string_serialization(&non_numeric_quote, Some("a\nb"), "\"a\nb\"");
         check_string_serialization(&non_numeric_quote, Some("a\rb"), "\"a\rb\"");
+
+        let backslash_escape = SerializeOptions {
+            quote_style: QuoteStyle::Necessary,
+            escape_char: Some(b'\\'),
+            ..SerializeOptions::default()
+        };
+        check_string_serialization(&backslash_escape, Some("a"), r#"a"#);
+        check_string_serialization(&backslash_escape, Some("\""), r#""\"""#);
+        check_string_serialization(&backslash_escape, Some("a\"\"b"), r#""a\"\"b""#);
+        check_string_serialization(&backslash_escape, Some(r"a\b"), r#""a\\b""#);
+        check_string_serialization(&backslash_escape, Some("a,b"), r#""a,b""#);
+        check_string_serialization(&backslash_escape, Some(r#"a\",b"#), r#""a\\\",b""#);
     }
 }
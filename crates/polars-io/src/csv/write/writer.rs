@@ -9,7 +9,7 @@ use polars_error::PolarsResult;
 use polars_utils::pl_str::PlSmallStr;
 
 use super::write_impl::{UTF8_BOM, csv_header, write};
-use super::{QuoteStyle, SerializeOptions};
+use super::{CsvColumnOptions, QuoteStyle, SerializeOptions};
 use crate::shared::SerWriter;
 
 /// Write a DataFrame to csv.
@@ -150,6 +150,14 @@ where
         self
     }
 
+    /// Set the single byte character used to escape quotes in quoted fields, e.g. `\` to write
+    /// `"a\"b"` instead of `"a""b"`. The escape character itself is escaped as well. Quotes are
+    /// escaped by doubling them if `None`.
+    pub fn with_escape_char(mut self, escape_char: Option<u8>) -> Self {
+        self.options_mut().escape_char = escape_char;
+        self
+    }
+
     /// Set the CSV file's null value representation.
     pub fn with_null_value(mut self, null_value: PlSmallStr) -> Self {
         self.options_mut().null = null_value;
@@ -169,6 +177,16 @@ where
         self
     }
 
+    /// Override the quoting behavior, float formatting and temporal formats of specific columns.
+    /// See more on [`CsvColumnOptions`].
+    pub fn with_column_options(
+        mut self,
+        column_options: Vec<(PlSmallStr, CsvColumnOptions)>,
+    ) -> Self {
+        self.options_mut().column_options = column_options;
+        self
+    }
+
     pub fn n_threads(mut self, n_threads: usize) -> Self {
         self.n_threads = n_threads;
         self
@@ -1,6 +1,6 @@
 use std::io::Write;
 
-use arrow::datatypes::Metadata;
+use arrow::datatypes::{ArrowSchemaRef, Metadata};
 use arrow::io::ipc::IpcField;
 use arrow::io::ipc::write::{self, EncodedData, WriteOptions};
 use polars_core::prelude::*;
@@ -8,9 +8,9 @@ use polars_core::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
-use crate::shared::schema_to_arrow_checked;
+use crate::shared::{attach_field_metadata, schema_to_arrow_checked};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub struct IpcWriterOptions {
@@ -23,6 +23,10 @@ pub struct IpcWriterOptions {
     /// Write record batch StatisticsFlags as custom metadata into the record batch header.
     #[cfg_attr(feature = "serde", serde(default))]
     pub record_batch_statistics: bool,
+    /// Arrow schema to write instead of the one derived from the input, e.g. to attach field
+    /// metadata.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub arrow_schema: Option<ArrowSchemaRef>,
 }
 
 impl Default for IpcWriterOptions {
@@ -32,6 +36,7 @@ impl Default for IpcWriterOptions {
             compat_level: CompatLevel::newest(),
             record_batch_size: None,
             record_batch_statistics: false,
+            arrow_schema: None,
         }
     }
 }
@@ -116,9 +121,18 @@ impl<W: Write> IpcWriter<W> {
         ipc_fields: Vec<IpcField>,
     ) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, self.compat_level, "ipc")?;
+        self.batched_arrow(Arc::new(schema), ipc_fields)
+    }
+
+    /// Same as [`IpcWriter::batched`], but with the arrow schema to write given directly.
+    pub fn batched_arrow(
+        self,
+        schema: ArrowSchemaRef,
+        ipc_fields: Vec<IpcField>,
+    ) -> PolarsResult<BatchedWriter<W>> {
         let mut writer = write::FileWriter::new(
             self.writer,
-            Arc::new(schema),
+            schema,
             Some(ipc_fields),
             WriteOptions {
                 compression: self.compression.map(|c| c.into()),
@@ -155,7 +169,8 @@ where
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(df.schema(), self.compat_level, "ipc")?;
+        let mut schema = schema_to_arrow_checked(df.schema(), self.compat_level, "ipc")?;
+        attach_field_metadata(&mut schema, &df.field_metadata());
         let mut ipc_writer = write::FileWriter::try_new(
             &mut self.writer,
             Arc::new(schema),
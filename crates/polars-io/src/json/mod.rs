@@ -75,6 +75,7 @@ use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
 use polars_error::{PolarsResult, polars_bail};
 use polars_json::json::write::FallibleStreamingIterator;
+pub use polars_json::json::write::{JsonSerializeOptions, JsonTemporalFormat, NonFiniteFloats};
 use simd_json::BorrowedValue;
 
 use crate::mmap::{MmapBytesReader, ReaderBytes};
@@ -113,6 +114,8 @@ pub struct JsonWriter<W: Write> {
     /// File or Stream handler
     buffer: W,
     json_format: JsonFormat,
+    serialize_options: JsonSerializeOptions,
+    pretty: bool,
 }
 
 impl<W: Write> JsonWriter<W> {
@@ -120,6 +123,25 @@ impl<W: Write> JsonWriter<W> {
         self.json_format = format;
         self
     }
+
+    /// Set how temporal values are written. See more on [`JsonTemporalFormat`].
+    pub fn with_temporal_format(mut self, temporal_format: JsonTemporalFormat) -> Self {
+        self.serialize_options.temporal_format = temporal_format;
+        self
+    }
+
+    /// Set how NaN and infinite floats are written. See more on [`NonFiniteFloats`].
+    pub fn with_non_finite_floats(mut self, non_finite_floats: NonFiniteFloats) -> Self {
+        self.serialize_options.non_finite_floats = non_finite_floats;
+        self
+    }
+
+    /// Set whether to indent the output, with every value of an object or array on its own line.
+    /// Only supported for [`JsonFormat::Json`].
+    pub fn with_pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
 }
 
 impl<W> SerWriter<W> for JsonWriter<W>
@@ -132,6 +154,8 @@ where
         JsonWriter {
             buffer,
             json_format: JsonFormat::JsonLines,
+            serialize_options: JsonSerializeOptions::default(),
+            pretty: false,
         }
     }
 
@@ -151,14 +175,26 @@ where
 
         match self.json_format {
             JsonFormat::JsonLines => {
-                let serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
+                polars_ensure!(
+                    !self.pretty,
+                    InvalidOperation: "pretty-printing is not supported for JSON Lines"
+                );
+                let serializer = polars_json::ndjson::write::Serializer::new(batches, vec![])
+                    .with_options(self.serialize_options);
                 let writer =
                     polars_json::ndjson::write::FileWriter::new(&mut self.buffer, serializer);
                 writer.collect::<PolarsResult<()>>()?;
             },
             JsonFormat::Json => {
-                let serializer = polars_json::json::write::Serializer::new(batches, vec![]);
-                polars_json::json::write::write(&mut self.buffer, serializer)?;
+                let serializer = polars_json::json::write::Serializer::new(batches, vec![])
+                    .with_options(self.serialize_options);
+                if self.pretty {
+                    let mut json = vec![];
+                    polars_json::json::write::write(&mut json, serializer)?;
+                    polars_json::json::write::write_pretty(&mut self.buffer, &json)?;
+                } else {
+                    polars_json::json::write::write(&mut self.buffer, serializer)?;
+                }
             },
         }
 
@@ -7,6 +7,7 @@ use polars_core::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::ExternalCompression;
+use crate::json::JsonSerializeOptions;
 
 pub(crate) mod buffer;
 pub mod core;
@@ -18,6 +19,8 @@ pub struct NDJsonWriterOptions {
     pub compression: ExternalCompression,
     #[cfg_attr(feature = "serde", serde(default))]
     pub check_extension: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub serialize_options: JsonSerializeOptions,
 }
 
 pub fn infer_schema<R: std::io::BufRead>(
@@ -1,8 +1,148 @@
 //! Apache Parquet file metadata.
 
+use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
+use polars_core::error::feature_gated;
+use polars_core::prelude::{PlHashMap, PlIndexMap};
+use polars_error::{PolarsResult, polars_err};
 pub use polars_parquet::parquet::metadata::FileMetadata;
+use polars_parquet::parquet::read::deserialize_footer;
+use polars_parquet::read::FileDecryptionProperties;
 pub use polars_parquet::read::statistics::{Statistics as ParquetStatistics, deserialize};
+use polars_utils::pl_path::PlRefPath;
+
+use crate::SerReader;
+use crate::cloud::CloudOptions;
+#[cfg(feature = "cloud")]
+use crate::parquet::read::ParquetObjectStore;
+use crate::parquet::read::ParquetReader;
 
 pub type FileMetadataRef = Arc<FileMetadata>;
+
+/// The footers of Parquet files that were read beforehand, e.g. from a `_metadata` summary file
+/// or a metadata cache, by the path of the file. Scans use these instead of fetching the footers
+/// of these files.
+///
+/// Equality and hashing are by identity, as comparing the footers is expensive.
+#[derive(Clone, Default)]
+pub struct ParquetFooters(Arc<PlHashMap<PlRefPath, FileMetadataRef>>);
+
+impl ParquetFooters {
+    pub fn new(footers: PlHashMap<PlRefPath, FileMetadataRef>) -> Self {
+        Self(Arc::new(footers))
+    }
+
+    /// Deserialize footers from the end of the files, i.e. the metadata followed by its length
+    /// and the magic bytes. Leading bytes, e.g. a larger tail of the file, are ignored.
+    pub fn from_bytes<'a>(
+        footers: impl IntoIterator<Item = (PlRefPath, &'a [u8])>,
+        decryption: Option<&Arc<FileDecryptionProperties>>,
+    ) -> PolarsResult<Self> {
+        footers
+            .into_iter()
+            .map(|(path, bytes)| {
+                let metadata = deserialize_footer(bytes, decryption).map_err(
+                    |e| polars_err!(ComputeError: "invalid Parquet footer for '{}': {}", path, e),
+                )?;
+                Ok((path, Arc::new(metadata)))
+            })
+            .collect::<PolarsResult<_>>()
+            .map(Self::new)
+    }
+
+    /// Split the metadata of a `_metadata` summary file into the footers of the files that it
+    /// summarizes. The file paths of the row groups are relative to `base_path`, usually the
+    /// directory of the summary file.
+    pub fn from_summary(summary: &FileMetadata, base_path: &PlRefPath) -> PolarsResult<Self> {
+        let mut files = PlIndexMap::<&str, Vec<_>>::default();
+        for rg in &summary.row_groups {
+            let path = rg
+                .parquet_columns()
+                .first()
+                .and_then(|c| c.file_path().as_deref())
+                .ok_or_else(|| {
+                    polars_err!(ComputeError: "row group of Parquet summary file has no file path")
+                })?;
+            files.entry(path).or_default().push(rg.clone());
+        }
+
+        let footers = files
+            .into_iter()
+            .map(|(path, row_groups)| {
+                let footer = FileMetadata {
+                    num_rows: row_groups.iter().map(|rg| rg.num_rows()).sum(),
+                    max_row_group_height: row_groups
+                        .iter()
+                        .map(|rg| rg.num_rows())
+                        .max()
+                        .unwrap_or(0),
+                    row_groups,
+                    ..summary.clone()
+                };
+                (base_path.join(path), Arc::new(footer))
+            })
+            .collect();
+        Ok(Self::new(footers))
+    }
+
+    /// Read the footers of the files that a `_metadata` summary file summarizes. The file paths
+    /// in the summary are relative to the directory of the summary file.
+    pub fn read_summary(
+        path: &PlRefPath,
+        #[allow(unused)] cloud_options: Option<&CloudOptions>,
+        decryption: Option<&Arc<FileDecryptionProperties>>,
+    ) -> PolarsResult<Self> {
+        let summary = if path.has_scheme() {
+            feature_gated!("cloud", {
+                crate::pl_async::get_runtime().block_in_place_on(async {
+                    let mut reader =
+                        ParquetObjectStore::from_uri(path.clone(), cloud_options, None)
+                            .await?
+                            .with_decryption(decryption.cloned());
+                    reader.get_metadata().await.cloned()
+                })?
+            })
+        } else {
+            let file = polars_utils::open_file(path.as_std_path())?;
+            let mut reader = ParquetReader::new(file).with_decryption(decryption.cloned());
+            reader.get_metadata()?.clone()
+        };
+
+        let base_path = &path.as_str()[..path.as_str().rfind('/').map_or(0, |i| i + 1)];
+        Self::from_summary(&summary, &PlRefPath::new(base_path))
+    }
+
+    pub fn get(&self, path: &PlRefPath) -> Option<&FileMetadataRef> {
+        self.0.get(path)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl PartialEq for ParquetFooters {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for ParquetFooters {}
+
+impl Hash for ParquetFooters {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).hash(state)
+    }
+}
+
+impl fmt::Debug for ParquetFooters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ParquetFooters({} files)", self.len())
+    }
+}
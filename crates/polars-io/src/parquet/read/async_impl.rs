@@ -1,8 +1,10 @@
 //! Read parquet files in parallel from the Object Store without a third party crate.
 
-use arrow::datatypes::ArrowSchemaRef;
+use arrow::datatypes::{ArrowSchemaRef, Metadata};
 use object_store::path::Path as ObjectPath;
 use polars_core::prelude::*;
+use polars_parquet::read::FileDecryptionProperties;
+use polars_parquet::read::schema::read_custom_key_value_metadata;
 use polars_parquet::write::FileMetadata;
 use polars_utils::pl_path::PlRefPath;
 
@@ -10,6 +12,7 @@ use crate::cloud::{
     CloudLocation, CloudOptions, PolarsObjectStore, build_object_store, object_path_from_str,
 };
 use crate::parquet::metadata::FileMetadataRef;
+use crate::parquet::read::{Int96Options, infer_parquet_schema};
 
 pub struct ParquetObjectStore {
     store: PolarsObjectStore,
@@ -17,6 +20,9 @@ pub struct ParquetObjectStore {
     length: Option<usize>,
     metadata: Option<FileMetadataRef>,
     schema: Option<ArrowSchemaRef>,
+    decryption: Option<Arc<FileDecryptionProperties>>,
+    int96: Int96Options,
+    uuid_as_string: bool,
 }
 
 impl ParquetObjectStore {
@@ -34,9 +40,31 @@ impl ParquetObjectStore {
             length: None,
             metadata,
             schema: None,
+            decryption: None,
+            int96: Int96Options::default(),
+            uuid_as_string: false,
         })
     }
 
+    /// Set the keys to read a file written with Parquet modular encryption.
+    pub fn with_decryption(mut self, decryption: Option<Arc<FileDecryptionProperties>>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
+    /// Set how INT96 timestamps are read.
+    pub fn with_int96_options(mut self, int96: Int96Options) -> Self {
+        self.int96 = int96;
+        self
+    }
+
+    /// Read columns with the Parquet UUID logical type as strings instead of as 16-byte binary
+    /// values.
+    pub fn with_uuid_as_string(mut self, uuid_as_string: bool) -> Self {
+        self.uuid_as_string = uuid_as_string;
+        self
+    }
+
     /// Initialize the length property of the object, unless it has already been fetched.
     async fn length(&mut self) -> PolarsResult<usize> {
         if self.length.is_none() {
@@ -51,10 +79,18 @@ impl ParquetObjectStore {
         Ok(metadata.num_rows)
     }
 
+    /// The key-value metadata in the footer of the parquet file.
+    pub async fn key_value_metadata(&mut self) -> PolarsResult<Metadata> {
+        let metadata = self.get_metadata().await?;
+        Ok(read_custom_key_value_metadata(
+            metadata.key_value_metadata(),
+        ))
+    }
+
     /// Fetch the metadata of the parquet file, do not memoize it.
     async fn fetch_metadata(&mut self) -> PolarsResult<FileMetadata> {
         let length = self.length().await?;
-        fetch_metadata(&self.store, &self.path, length).await
+        fetch_metadata(&self.store, &self.path, length, self.decryption.as_ref()).await
     }
 
     /// Fetch and memoize the metadata of the parquet file.
@@ -69,8 +105,10 @@ impl ParquetObjectStore {
         self.schema = Some(match self.schema.as_ref() {
             Some(schema) => Arc::clone(schema),
             None => {
+                let int96 = self.int96.clone();
+                let uuid_as_string = self.uuid_as_string;
                 let metadata = self.get_metadata().await?;
-                let arrow_schema = polars_parquet::arrow::read::infer_schema(metadata)?;
+                let arrow_schema = infer_parquet_schema(metadata, &int96, uuid_as_string)?;
                 Arc::new(arrow_schema)
             },
         });
@@ -98,6 +136,7 @@ pub async fn fetch_metadata(
     store: &PolarsObjectStore,
     path: &ObjectPath,
     file_byte_length: usize,
+    decryption: Option<&Arc<FileDecryptionProperties>>,
 ) -> PolarsResult<FileMetadata> {
     let footer_header_bytes = store
         .get_range(
@@ -117,7 +156,9 @@ pub async fn fetch_metadata(
         let footer_byte_size = read_i32le(reader).unwrap();
         let magic = read_n(reader).unwrap();
         debug_assert!(reader.is_empty());
-        if magic != polars_parquet::parquet::PARQUET_MAGIC {
+        if magic != polars_parquet::parquet::PARQUET_MAGIC
+            && magic != polars_parquet::parquet::encryption::PARQUET_ENCRYPTED_MAGIC
+        {
             return Err(polars_parquet::parquet::error::ParquetError::OutOfSpec(
                 "incorrect magic in parquet footer".to_string(),
             )
@@ -143,11 +184,8 @@ pub async fn fetch_metadata(
         )
         .await?;
 
-    Ok(polars_parquet::parquet::read::deserialize_metadata(
-        std::io::Cursor::new(footer_bytes.as_ref()),
-        // TODO: Describe why this makes sense. Taken from the previous
-        // implementation which said "a highly nested but sparse struct could
-        // result in many allocations".
-        footer_bytes.as_ref().len() * 2 + 1024,
+    Ok(polars_parquet::parquet::read::deserialize_footer(
+        footer_bytes.as_ref(),
+        decryption,
     )?)
 }
@@ -6,7 +6,8 @@ use arrow::datatypes::Field;
 use polars_buffer::Buffer;
 use polars_error::PolarsResult;
 use polars_parquet::read::{
-    BasicDecompressor, ColumnChunkMetadata, Filter, PageReader, column_iter_to_arrays,
+    BasicDecompressor, ColumnChunkMetadata, Filter, PageReader, TimestampOverflowOptions,
+    column_iter_to_arrays,
 };
 use polars_utils::mem::prefetch::prefetch_l2;
 
@@ -56,6 +57,7 @@ pub fn to_deserializer(
     columns: Vec<(&ColumnChunkMetadata, Buffer<u8>)>,
     field: Field,
     filter: Option<Filter>,
+    timestamp_overflow: TimestampOverflowOptions,
 ) -> PolarsResult<(Vec<Box<dyn Array>>, Bitmap)> {
     let (columns, types): (Vec<_>, Vec<_>) = columns
         .into_iter()
@@ -71,5 +73,5 @@ pub fn to_deserializer(
         })
         .unzip();
 
-    column_iter_to_arrays(columns, types, field, filter)
+    column_iter_to_arrays(columns, types, field, filter, timestamp_overflow)
 }
@@ -20,6 +20,7 @@ mod mmap;
 mod options;
 mod read_impl;
 mod reader;
+mod statistics;
 mod utils;
 
 const ROW_COUNT_OVERFLOW_ERR: PolarsError = PolarsError::ComputeError(ErrString::new_static(
@@ -31,13 +32,17 @@ or set 'streaming'",
 
 #[cfg(feature = "cloud")]
 pub use async_impl::ParquetObjectStore;
-pub use options::{ParallelStrategy, ParquetOptions};
+pub use options::{Int96Options, ParallelStrategy, ParquetOptions, StructFieldSelection};
 use polars_error::{ErrString, PolarsError};
 pub use polars_parquet::arrow::read::infer_schema;
-pub use polars_parquet::read::FileMetadata;
+pub use polars_parquet::read::schema::read_custom_key_value_metadata;
+pub use polars_parquet::read::{
+    FileDecryptionProperties, FileMetadata, TimestampOverflow, TimestampOverflowOptions,
+};
 pub use read_impl::{create_sorting_map, try_set_sorted_flag};
 pub use reader::ParquetReader;
-pub use utils::materialize_empty_df;
+pub use statistics::{column_chunk_statistics_df, column_distinct_count, row_group_statistics_df};
+pub use utils::{infer_parquet_schema, materialize_empty_df, read_strings_as_categorical};
 
 pub mod _internal {
     pub use super::mmap::to_deserializer;
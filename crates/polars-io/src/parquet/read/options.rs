@@ -1,7 +1,18 @@
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+use polars_core::prelude::{TimeUnit, TimeZone};
 use polars_core::schema::SchemaRef;
+use polars_parquet::read::{FileDecryptionProperties, TimestampOverflow, TimestampOverflowOptions};
+use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::parquet::metadata::ParquetFooters;
+
+/// The fields that are read of struct columns, per struct column name.
+pub type StructFieldSelection = Arc<[(PlSmallStr, Arc<[PlSmallStr]>)]>;
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -10,6 +21,38 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// The keys to read files written with Parquet modular encryption.
+    pub decryption: Option<Arc<FileDecryptionProperties>>,
+    /// The number of row groups to fetch ahead of the one that is being decoded. Defaults to a
+    /// value derived from the number of threads, or `POLARS_ROW_GROUP_PREFETCH_SIZE` if set.
+    pub readahead: Option<NonZeroUsize>,
+    /// The maximum total number of bytes of the row groups that are fetched ahead. A row group
+    /// that is larger than the budget is still fetched, but only on its own.
+    pub readahead_bytes: Option<NonZeroUsize>,
+    /// The fields that are read of struct columns, set by the optimizer when a query only uses
+    /// some fields of a struct. Only the leaf columns of these fields are decoded; the other
+    /// fields of the struct are null. Struct columns that are not listed are read in full.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub struct_fields: Option<StructFieldSelection>,
+    /// How INT96 timestamps are read.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub int96: Int96Options,
+    /// What to do with INT64 timestamps that are out of range for the time unit they are read as,
+    /// e.g. millisecond timestamps far in the future that are read as nanoseconds. By default
+    /// the range is not checked.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub int64_timestamp_overflow: Option<TimestampOverflow>,
+    /// Read columns with the Parquet UUID logical type as strings in the canonical hyphenated
+    /// form, e.g. `"123e4567-e89b-12d3-a456-426614174000"`, instead of as 16-byte binary values.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub uuid_as_string: bool,
+    /// String columns that are read as categoricals. Their dictionary-encoded pages are decoded
+    /// into categorical keys directly, without materializing every string.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub categorical_columns: Option<Arc<[PlSmallStr]>>,
+    /// Footers that were read beforehand, used instead of fetching the footers of these files.
+    #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+    pub footers: Option<ParquetFooters>,
 }
 
 impl Default for ParquetOptions {
@@ -19,10 +62,44 @@ impl Default for ParquetOptions {
             parallel: ParallelStrategy::default(),
             low_memory: false,
             use_statistics: true,
+            decryption: None,
+            readahead: None,
+            readahead_bytes: None,
+            struct_fields: None,
+            int96: Int96Options::default(),
+            int64_timestamp_overflow: None,
+            uuid_as_string: false,
+            categorical_columns: None,
+            footers: None,
         }
     }
 }
 
+impl ParquetOptions {
+    /// The overflow policies of the timestamps, by physical type.
+    pub fn timestamp_overflow(&self) -> TimestampOverflowOptions {
+        TimestampOverflowOptions {
+            int96: self.int96.overflow,
+            int64: self.int64_timestamp_overflow,
+        }
+    }
+}
+
+/// How INT96 timestamps, as written by legacy Spark and Hive writers, are read.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct Int96Options {
+    /// The time unit that the timestamps are read as. Defaults to nanoseconds.
+    pub time_unit: Option<TimeUnit>,
+    /// The time zone that the timestamps are read in. INT96 timestamps are instants in UTC, so
+    /// this only sets the zone they are displayed in. Defaults to no time zone.
+    pub time_zone: Option<TimeZone>,
+    /// What to do with timestamps that are out of range for `time_unit`. By default the range is
+    /// not checked.
+    pub overflow: Option<TimestampOverflow>,
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
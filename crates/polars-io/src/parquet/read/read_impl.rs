@@ -8,7 +8,9 @@ use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_core::{POOL, config};
-use polars_parquet::read::{self, ColumnChunkMetadata, FileMetadata, Filter, RowGroupMetadata};
+use polars_parquet::read::{
+    self, ColumnChunkMetadata, FileMetadata, Filter, RowGroupMetadata, TimestampOverflowOptions,
+};
 use rayon::prelude::*;
 
 use super::mmap::mmap_columns;
@@ -32,6 +34,7 @@ fn assert_dtypes(dtype: &ArrowDataType) {
         D::Utf8 | D::Binary | D::LargeUtf8 | D::LargeBinary => unreachable!(),
 
         // These should be cast to Float32
+        #[cfg(not(feature = "dtype-f16"))]
         D::Float16 => unreachable!(),
 
         // This should have been converted to a LargeList
@@ -52,20 +55,36 @@ fn assert_dtypes(dtype: &ArrowDataType) {
 }
 
 fn should_copy_sortedness(dtype: &DataType) -> bool {
-    // @NOTE: For now, we are a bit conservative with this.
+    // @NOTE: For now, we are a bit conservative with this. These are the types for which Parquet
+    // and Polars agree on the order of the values.
     use DataType as D;
 
     matches!(
         dtype,
-        D::Int8 | D::Int16 | D::Int32 | D::Int64 | D::UInt8 | D::UInt16 | D::UInt32 | D::UInt64
-    )
+        D::Int8
+            | D::Int16
+            | D::Int32
+            | D::Int64
+            | D::UInt8
+            | D::UInt16
+            | D::UInt32
+            | D::UInt64
+            | D::Boolean
+            | D::String
+            | D::Binary
+    ) || dtype.is_temporal()
 }
 
-pub fn try_set_sorted_flag(series: &mut Series, col_idx: usize, sorting_map: &[(usize, IsSorted)]) {
+/// Set the sorted flag of `series` if the row group is sorted by the leaf column `leaf_idx`.
+pub fn try_set_sorted_flag(
+    series: &mut Series,
+    leaf_idx: usize,
+    sorting_map: &[(usize, IsSorted)],
+) {
     let Some((sorted_col, is_sorted)) = sorting_map.first() else {
         return;
     };
-    if *sorted_col != col_idx || !should_copy_sortedness(series.dtype()) {
+    if *sorted_col != leaf_idx || !should_copy_sortedness(series.dtype()) {
         return;
     }
     if config::verbose() {
@@ -105,6 +124,7 @@ fn column_idx_to_series(
     filter: Option<Filter>,
     file_schema: &ArrowSchema,
     store: &mmap::ColumnStore,
+    timestamp_overflow: TimestampOverflowOptions,
 ) -> PolarsResult<(Series, Bitmap)> {
     let field = file_schema.get_at_index(column_i).unwrap().1;
 
@@ -113,7 +133,8 @@ fn column_idx_to_series(
         assert_dtypes(field.dtype())
     }
     let columns = mmap_columns(store, field_md);
-    let (arrays, pred_true_mask) = mmap::to_deserializer(columns, field.clone(), filter)?;
+    let (arrays, pred_true_mask) =
+        mmap::to_deserializer(columns, field.clone(), filter, timestamp_overflow)?;
     let series = Series::try_from((field, arrays))?;
 
     Ok((series, pred_true_mask))
@@ -132,6 +153,7 @@ fn rg_to_dfs(
     parallel: ParallelStrategy,
     projection: &[usize],
     hive_partition_columns: Option<&[Series]>,
+    timestamp_overflow: TimestampOverflowOptions,
 ) -> PolarsResult<Vec<DataFrame>> {
     if config::verbose() {
         eprintln!("parquet scan with parallel = {parallel:?}");
@@ -168,6 +190,7 @@ fn rg_to_dfs(
             parallel,
             projection,
             hive_partition_columns,
+            timestamp_overflow,
         ),
         _ => rg_to_dfs_par_over_rg(
             store,
@@ -180,6 +203,7 @@ fn rg_to_dfs(
             row_index,
             projection,
             hive_partition_columns,
+            timestamp_overflow,
         ),
     }
 }
@@ -198,6 +222,7 @@ fn rg_to_dfs_optionally_par_over_columns(
     parallel: ParallelStrategy,
     projection: &[usize],
     hive_partition_columns: Option<&[Series]>,
+    timestamp_overflow: TimestampOverflowOptions,
 ) -> PolarsResult<Vec<DataFrame>> {
     let mut dfs = Vec::with_capacity(row_group_end - row_group_start);
 
@@ -234,9 +259,12 @@ fn rg_to_dfs_optionally_par_over_columns(
                 Some(Filter::new_ranged(rg_slice.0, rg_slice.0 + rg_slice.1)),
                 schema,
                 store,
+                timestamp_overflow,
             )?;
 
-            try_set_sorted_flag(&mut series, *column_i, &sorting_map);
+            if let Some(&[leaf_idx]) = md.columns_idxs_under_root_iter(name) {
+                try_set_sorted_flag(&mut series, leaf_idx, &sorting_map);
+            }
             Ok(series.into_column())
         };
 
@@ -295,6 +323,7 @@ fn rg_to_dfs_par_over_rg(
     row_index: Option<RowIndex>,
     projection: &[usize],
     hive_partition_columns: Option<&[Series]>,
+    timestamp_overflow: TimestampOverflowOptions,
 ) -> PolarsResult<Vec<DataFrame>> {
     // compute the limits per row group and the row count offsets
     let mut row_groups = Vec::with_capacity(row_group_end - row_group_start);
@@ -377,9 +406,12 @@ fn rg_to_dfs_par_over_rg(
                             Some(Filter::new_ranged(slice.0, slice.0 + slice.1)),
                             schema,
                             store,
+                            timestamp_overflow,
                         )?;
 
-                        try_set_sorted_flag(&mut series, *column_i, &sorting_map);
+                        if let Some(&[leaf_idx]) = md.columns_idxs_under_root_iter(name) {
+                            try_set_sorted_flag(&mut series, leaf_idx, &sorting_map);
+                        }
                         Ok(series.into_column())
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
@@ -414,6 +446,7 @@ pub fn read_parquet<R: MmapBytesReader>(
     mut parallel: ParallelStrategy,
     row_index: Option<RowIndex>,
     hive_partition_columns: Option<&[Series]>,
+    timestamp_overflow: TimestampOverflowOptions,
 ) -> PolarsResult<DataFrame> {
     // Fast path.
     if pre_slice.1 == 0 {
@@ -462,6 +495,7 @@ pub fn read_parquet<R: MmapBytesReader>(
             parallel,
             &materialized_projection,
             hive_partition_columns,
+            timestamp_overflow,
         )?;
 
         if dfs.is_empty() {
@@ -1,13 +1,18 @@
 use std::io::{Read, Seek};
 use std::sync::Arc;
 
-use arrow::datatypes::ArrowSchemaRef;
+use arrow::datatypes::{ArrowSchemaRef, Metadata};
 use polars_core::prelude::*;
-use polars_parquet::read;
+use polars_parquet::read::{
+    self, FileDecryptionProperties, TimestampOverflow, TimestampOverflowOptions,
+};
 use polars_utils::pl_str::PlRefStr;
 
 use super::read_impl::read_parquet;
-use super::utils::{ensure_matching_dtypes_if_found, projected_arrow_schema_to_projection_indices};
+use super::utils::{
+    ensure_matching_dtypes_if_found, infer_parquet_schema,
+    projected_arrow_schema_to_projection_indices,
+};
 use crate::RowIndex;
 use crate::mmap::MmapBytesReader;
 use crate::parquet::metadata::FileMetadataRef;
@@ -28,6 +33,10 @@ pub struct ParquetReader<R: Read + Seek> {
     metadata: Option<FileMetadataRef>,
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, PlRefStr)>,
+    decryption: Option<Arc<FileDecryptionProperties>>,
+    int96: Int96Options,
+    int64_timestamp_overflow: Option<TimestampOverflow>,
+    uuid_as_string: bool,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -137,8 +146,10 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self.schema = Some(match &self.schema {
             Some(schema) => schema.clone(),
             None => {
+                let int96 = self.int96.clone();
+                let uuid_as_string = self.uuid_as_string;
                 let metadata = self.get_metadata()?;
-                Arc::new(read::infer_schema(metadata)?)
+                Arc::new(infer_parquet_schema(metadata, &int96, uuid_as_string)?)
             },
         });
 
@@ -151,6 +162,14 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         Ok(metadata.num_rows)
     }
 
+    /// The key-value metadata in the footer of the parquet file.
+    pub fn key_value_metadata(&mut self) -> PolarsResult<Metadata> {
+        let metadata = self.get_metadata()?;
+        Ok(read::schema::read_custom_key_value_metadata(
+            metadata.key_value_metadata(),
+        ))
+    }
+
     pub fn with_hive_partition_columns(mut self, columns: Option<Vec<Series>>) -> Self {
         self.hive_partition_columns = columns;
         self
@@ -164,13 +183,46 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Set the keys to read a file written with Parquet modular encryption.
+    pub fn with_decryption(mut self, decryption: Option<Arc<FileDecryptionProperties>>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
+    /// Set how INT96 timestamps are read.
+    ///
+    /// This must be set before the schema is read.
+    pub fn with_int96_options(mut self, int96: Int96Options) -> Self {
+        self.int96 = int96;
+        self
+    }
+
+    /// Set what to do with INT64 timestamps that are out of range for the time unit they are read
+    /// as. By default the range is not checked.
+    pub fn with_int64_timestamp_overflow(mut self, overflow: Option<TimestampOverflow>) -> Self {
+        self.int64_timestamp_overflow = overflow;
+        self
+    }
+
+    /// Read columns with the Parquet UUID logical type as strings in the canonical hyphenated
+    /// form instead of as 16-byte binary values.
+    ///
+    /// This must be set before the schema is read.
+    pub fn with_uuid_as_string(mut self, uuid_as_string: bool) -> Self {
+        self.uuid_as_string = uuid_as_string;
+        self
+    }
+
     pub fn set_metadata(&mut self, metadata: FileMetadataRef) {
         self.metadata = Some(metadata);
     }
 
     pub fn get_metadata(&mut self) -> PolarsResult<&FileMetadataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(Arc::new(read::read_metadata(&mut self.reader)?));
+            self.metadata = Some(Arc::new(read::read_metadata_with_decryption(
+                &mut self.reader,
+                self.decryption.as_ref(),
+            )?));
         }
         Ok(self.metadata.as_ref().unwrap())
     }
@@ -192,6 +244,10 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             schema: None,
             hive_partition_columns: None,
             include_file_path: None,
+            decryption: None,
+            int96: Int96Options::default(),
+            int64_timestamp_overflow: None,
+            uuid_as_string: false,
         }
     }
 
@@ -218,6 +274,10 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.parallel,
             self.row_index,
             self.hive_partition_columns.as_deref(),
+            TimestampOverflowOptions {
+                int96: self.int96.overflow,
+                int64: self.int64_timestamp_overflow,
+            },
         )?;
 
         if self.rechunk {
@@ -0,0 +1,222 @@
+use polars_core::chunked_array::cast::CastOptions;
+use polars_core::prelude::*;
+use polars_parquet::read::statistics::{Statistics, deserialize, deserialize_all};
+use polars_utils::format_pl_smallstr;
+
+use super::{FileMetadata, Int96Options, infer_parquet_schema};
+
+/// Builds a [`DataFrame`] holding the statistics of every row group in `metadata`, in the layout
+/// that is expected by skip batch predicates. That is, a `len` column followed by a `{col}_min`,
+/// `{col}_max`, `{col}_nc` and `{col}_ndv` (distinct count) column for every column in
+/// `live_columns`.
+///
+/// Statistics are cast to their dtype in `schema`. They are set to NULL for columns that are
+/// not in the file or do not have statistics. The min and max of a struct column hold the min and
+/// max of each of its fields.
+pub fn row_group_statistics_df(
+    metadata: &FileMetadata,
+    schema: &Schema,
+    live_columns: &PlIndexSet<PlSmallStr>,
+) -> PolarsResult<DataFrame> {
+    let row_groups = metadata.row_groups.as_slice();
+    let height = row_groups.len();
+    let file_schema = infer_parquet_schema(metadata, &Int96Options::default(), false)?;
+
+    let mut columns = Vec::with_capacity(1 + live_columns.len() * 4);
+
+    columns.push(Column::new(
+        PlSmallStr::from_static("len"),
+        row_groups
+            .iter()
+            .map(|rg| IdxSize::try_from(rg.num_rows()).unwrap_or(IdxSize::MAX))
+            .collect::<Vec<_>>(),
+    ));
+
+    for (name, dtype) in schema.iter() {
+        if !live_columns.contains(name) {
+            continue;
+        }
+
+        let statistics = file_schema
+            .get(name)
+            .filter(|_| height > 0)
+            .and_then(|field| {
+                // The leaves of a column are stored next to each other.
+                let idx = *row_groups[0].columns_idxs_under_root_iter(name)?.first()?;
+                Some((field, idx))
+            })
+            .map(|(field, idx)| {
+                let Some(statistics) = deserialize_all(field, row_groups, idx)? else {
+                    return PolarsResult::Ok(None);
+                };
+
+                let to_column = |array: ArrayRef| {
+                    unsafe {
+                        Series::_try_from_arrow_unchecked_with_md(
+                            PlSmallStr::EMPTY,
+                            vec![array],
+                            field.dtype(),
+                            field.metadata.as_deref(),
+                        )
+                    }?
+                    .cast(dtype)
+                    .map(Column::from)
+                };
+
+                Ok(Some((
+                    to_column(statistics.min_value)?,
+                    to_column(statistics.max_value)?,
+                    Series::from_arrow(PlSmallStr::EMPTY, statistics.null_count.boxed())?
+                        .into_column(),
+                    Series::from_arrow(PlSmallStr::EMPTY, statistics.distinct_count.boxed())?
+                        .into_column(),
+                )))
+            })
+            .transpose()?
+            .flatten();
+
+        let (min, max, null_count, distinct_count) = statistics.unwrap_or_else(|| {
+            (
+                Column::full_null(PlSmallStr::EMPTY, height, dtype),
+                Column::full_null(PlSmallStr::EMPTY, height, dtype),
+                Column::full_null(PlSmallStr::EMPTY, height, &IDX_DTYPE),
+                Column::full_null(PlSmallStr::EMPTY, height, &IDX_DTYPE),
+            )
+        });
+
+        columns.extend([
+            min.with_name(format_pl_smallstr!("{name}_min")),
+            max.with_name(format_pl_smallstr!("{name}_max")),
+            null_count.with_name(format_pl_smallstr!("{name}_nc")),
+            distinct_count.with_name(format_pl_smallstr!("{name}_ndv")),
+        ]);
+    }
+
+    DataFrame::new(height, columns)
+}
+
+/// Builds a [`DataFrame`] with a row for every column chunk of every row group in `metadata`,
+/// e.g. to inspect how the data of a file is laid out.
+///
+/// The rows hold the `row_group` index, the dot-separated path of the leaf `column`, the
+/// `num_rows` of the row group, the `null_count`, `distinct_count`, `min` and `max` statistics
+/// and the `compressed_size` and `uncompressed_size` of the column chunk in bytes. The `min`
+/// and `max` are formatted as strings, as the columns have different dtypes. Statistics that
+/// are not in the file are NULL.
+pub fn column_chunk_statistics_df(metadata: &FileMetadata) -> PolarsResult<DataFrame> {
+    let file_schema = infer_parquet_schema(metadata, &Int96Options::default(), false)?;
+    let mut leaf_fields = Vec::new();
+    for field in file_schema.iter_values() {
+        push_leaf_fields(field, &mut leaf_fields);
+    }
+
+    let height = metadata.row_groups.len() * leaf_fields.len();
+    let mut row_group_idxs = Vec::<IdxSize>::with_capacity(height);
+    let mut paths = Vec::with_capacity(height);
+    let mut num_rows = Vec::with_capacity(height);
+    let mut null_counts = Vec::with_capacity(height);
+    let mut distinct_counts = Vec::with_capacity(height);
+    let mut min_values = Vec::with_capacity(height);
+    let mut max_values = Vec::with_capacity(height);
+    let mut compressed_sizes = Vec::with_capacity(height);
+    let mut uncompressed_sizes = Vec::with_capacity(height);
+
+    let to_string = |array: Option<ArrayRef>| -> PolarsResult<Option<String>> {
+        let Some(array) = array else {
+            return Ok(None);
+        };
+        let s = Series::from_arrow(PlSmallStr::EMPTY, array)?
+            .cast_with_options(&DataType::String, CastOptions::NonStrict)?;
+        Ok(s.str()?.get(0).map(String::from))
+    };
+
+    for (i, row_group) in metadata.row_groups.iter().enumerate() {
+        for (field, column) in leaf_fields.iter().zip(row_group.parquet_columns()) {
+            let statistics = match deserialize(field, &mut std::iter::once(column))? {
+                Some(Statistics::Column(statistics)) => Some(statistics.into_arrow()?),
+                _ => None,
+            };
+            let (null_count, distinct_count, min_value, max_value) = match statistics {
+                Some(s) => (s.null_count, s.distinct_count, s.min_value, s.max_value),
+                None => (None, None, None, None),
+            };
+
+            row_group_idxs.push(i as IdxSize);
+            paths.push(column.descriptor().path_in_schema.join("."));
+            num_rows.push(row_group.num_rows() as u64);
+            null_counts.push(null_count);
+            distinct_counts.push(distinct_count);
+            min_values.push(to_string(min_value)?);
+            max_values.push(to_string(max_value)?);
+            compressed_sizes.push(column.compressed_size());
+            uncompressed_sizes.push(column.uncompressed_size());
+        }
+    }
+
+    let height = row_group_idxs.len();
+    DataFrame::new(
+        height,
+        vec![
+            Column::new(PlSmallStr::from_static("row_group"), row_group_idxs),
+            Column::new(PlSmallStr::from_static("column"), paths),
+            Column::new(PlSmallStr::from_static("num_rows"), num_rows),
+            Column::new(PlSmallStr::from_static("null_count"), null_counts),
+            Column::new(PlSmallStr::from_static("distinct_count"), distinct_counts),
+            Column::new(PlSmallStr::from_static("min"), min_values),
+            Column::new(PlSmallStr::from_static("max"), max_values),
+            Column::new(PlSmallStr::from_static("compressed_size"), compressed_sizes),
+            Column::new(
+                PlSmallStr::from_static("uncompressed_size"),
+                uncompressed_sizes,
+            ),
+        ],
+    )
+}
+
+/// Returns an upper bound of the number of distinct non-null values of the top-level column `name`
+/// in the file, from the distinct counts in the statistics of its column chunks.
+///
+/// Distinct counts of row groups can't be merged, so this is their sum, capped by the number of
+/// rows. Returns `None` if the column is nested or not in the file, or if any column chunk has no
+/// distinct count.
+pub fn column_distinct_count(metadata: &FileMetadata, name: &str) -> Option<usize> {
+    let mut distinct_count = 0usize;
+    for row_group in &metadata.row_groups {
+        let mut columns = row_group.columns_under_root_iter(name)?;
+        if columns.len() != 1 {
+            return None;
+        }
+        let count = columns.next()?.metadata().statistics.as_ref()?.distinct_count?;
+        distinct_count = distinct_count.saturating_add(usize::try_from(count).ok()?);
+    }
+    Some(distinct_count.min(metadata.num_rows))
+}
+
+/// Pushes the fields of the leaf columns of `field` in the order of the Parquet columns.
+fn push_leaf_fields(field: &ArrowField, leaf_fields: &mut Vec<ArrowField>) {
+    match field.dtype.to_storage() {
+        ArrowDataType::List(inner)
+        | ArrowDataType::LargeList(inner)
+        | ArrowDataType::FixedSizeList(inner, _)
+        | ArrowDataType::Map(inner, _) => push_leaf_fields(inner, leaf_fields),
+        // A struct without fields is written as a boolean column.
+        ArrowDataType::Struct(fields) if fields.is_empty() => {
+            leaf_fields.push(ArrowField::new(
+                field.name.clone(),
+                ArrowDataType::Boolean,
+                true,
+            ));
+        },
+        ArrowDataType::Struct(fields) => {
+            for field in fields {
+                push_leaf_fields(field, leaf_fields);
+            }
+        },
+        ArrowDataType::Dictionary(_, values, _) => leaf_fields.push(ArrowField::new(
+            field.name.clone(),
+            values.as_ref().clone(),
+            true,
+        )),
+        dtype => leaf_fields.push(ArrowField::new(field.name.clone(), dtype.clone(), true)),
+    }
+}
@@ -1,14 +1,76 @@
 use std::borrow::Cow;
 
-use polars_core::prelude::{ArrowSchema, Column, DataFrame, DataType, IDX_DTYPE, Series};
+use arrow::datatypes::IntegerType;
+use polars_core::prelude::{
+    ArrowDataType, ArrowSchema, ArrowTimeUnit, Column, DataFrame, DataType, IDX_DTYPE, Series,
+};
 use polars_core::schema::{SchemaExt, SchemaNamesAndDtypes};
 use polars_error::{PolarsResult, polars_bail};
+use polars_parquet::read::FileMetadata;
+use polars_parquet::read::schema::{SchemaInferenceOptions, infer_schema_with_options};
 use polars_schema::Schema;
+use polars_utils::pl_str::PlSmallStr;
 
 use crate::RowIndex;
 use crate::hive::materialize_hive_partitions;
+use crate::parquet::read::Int96Options;
 use crate::utils::apply_projection;
 
+/// Infers the Arrow schema of a Parquet file, reading INT96 timestamps with the time unit and zone
+/// of `int96`. UUIDs are read as strings if `uuid_as_string` is set.
+///
+/// Half-precision floats are read as `Float32` if the `dtype-f16` feature is not enabled.
+pub fn infer_parquet_schema(
+    metadata: &FileMetadata,
+    int96: &Int96Options,
+    uuid_as_string: bool,
+) -> PolarsResult<ArrowSchema> {
+    let options = SchemaInferenceOptions {
+        int96_coerce_to_timeunit: int96
+            .time_unit
+            .map_or(ArrowTimeUnit::Nanosecond, |time_unit| time_unit.to_arrow()),
+        int96_coerce_to_timezone: int96.time_zone.as_ref().map(|tz| PlSmallStr::clone(tz)),
+        float16_coerce_to_float32: !cfg!(feature = "dtype-f16"),
+        uuid_as_string,
+    };
+    infer_schema_with_options(metadata, &Some(options))
+}
+
+/// Read the string columns in `columns` as [`Categorical`](DataType::Categorical) columns.
+///
+/// Their dictionary-encoded pages are then decoded into categorical keys directly, without
+/// materializing every string. Columns that are not in `schema` are ignored, as they might only
+/// be missing from this file.
+pub fn read_strings_as_categorical(
+    schema: &mut ArrowSchema,
+    columns: &[PlSmallStr],
+) -> PolarsResult<()> {
+    for name in columns {
+        let Some(field) = schema.get_mut(name) else {
+            continue;
+        };
+
+        match &field.dtype {
+            ArrowDataType::Utf8View | ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 => {
+                field.dtype = ArrowDataType::Dictionary(
+                    IntegerType::UInt32,
+                    Box::new(ArrowDataType::Utf8View),
+                    false,
+                );
+            },
+            // Already read as a categorical or enum.
+            ArrowDataType::Dictionary(..) => {},
+            dtype => polars_bail!(
+                InvalidOperation:
+                "cannot read column '{}' of type {:?} as categorical, only string columns can be",
+                name, dtype
+            ),
+        }
+    }
+
+    Ok(())
+}
+
 pub fn materialize_empty_df(
     projection: Option<&[usize]>,
     reader_schema: &ArrowSchema,
@@ -1,19 +1,22 @@
+use std::collections::VecDeque;
 use std::io::Write;
 use std::sync::Mutex;
 
+use arrow::compute::aggregate::estimated_bytes_size;
 use arrow::record_batch::RecordBatch;
 use polars_buffer::Buffer;
 use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_parquet::read::{ParquetError, fallible_streaming_iterator};
 use polars_parquet::write::{
-    CompressedPage, Compressor, DynIter, DynStreamingIterator, Encoding, FallibleStreamingIterator,
-    FileWriter, Page, ParquetType, RowGroupIterColumns, SchemaDescriptor, WriteOptions,
-    array_to_columns, schema_to_metadata_key,
+    BloomFilterBitset, CompressedPage, CompressionOptions, Compressor, DynIter,
+    DynStreamingIterator, Encoding, FallibleStreamingIterator, FileWriter, Page, ParquetType,
+    RowGroupIterColumns, SchemaDescriptor, SortingColumn, WriteOptions, array_to_columns,
+    schema_to_metadata_key,
 };
 use rayon::prelude::*;
 
-use super::{KeyValueMetadata, ParquetMetadataContext};
+use super::{KeyValueMetadata, ParquetBloomFilters, ParquetMetadataContext, sorting_columns};
 
 pub struct BatchedWriter<W: Write> {
     // A mutex so that streaming engine can get concurrent read access to
@@ -24,26 +27,37 @@ pub struct BatchedWriter<W: Write> {
     // @TODO: Remove when old streaming engine is removed
     pub(super) parquet_schema: SchemaDescriptor,
     pub(super) encodings: Buffer<Vec<Encoding>>,
+    /// The compression of every top-level column, which overrides `options.compression`.
+    pub(super) compressions: Buffer<CompressionOptions>,
     pub(super) options: WriteOptions,
     pub(super) parallel: bool,
+    /// Bounds the memory of the columns that are encoded in parallel, see
+    /// [`ParquetWriter::with_memory_budget`](super::ParquetWriter::with_memory_budget).
+    pub(super) memory_budget: Option<usize>,
     pub(super) key_value_metadata: Option<KeyValueMetadata>,
+    pub(super) bloom_filters: Option<ParquetBloomFilters>,
 }
 
 impl<W: Write> BatchedWriter<W> {
     pub fn new(
         writer: Mutex<FileWriter<W>>,
         encodings: Buffer<Vec<Encoding>>,
+        compressions: Buffer<CompressionOptions>,
         options: WriteOptions,
         parallel: bool,
         key_value_metadata: Option<KeyValueMetadata>,
+        bloom_filters: Option<ParquetBloomFilters>,
     ) -> Self {
         Self {
             writer,
             parquet_schema: SchemaDescriptor::new(PlSmallStr::EMPTY, vec![]),
             encodings,
+            compressions,
             options,
             parallel,
+            memory_budget: None,
             key_value_metadata,
+            bloom_filters,
         }
     }
 
@@ -59,6 +73,7 @@ impl<W: Write> BatchedWriter<W> {
                     batch,
                     self.parquet_schema.fields(),
                     self.encodings.as_ref(),
+                    self.compressions.as_ref(),
                     self.options,
                 );
 
@@ -76,13 +91,23 @@ impl<W: Write> BatchedWriter<W> {
             df,
             &self.parquet_schema,
             &self.encodings,
+            &self.compressions,
             self.options,
             self.parallel,
+            self.memory_budget,
+            self.bloom_filters.as_ref(),
         );
+        // Every row group is a slice of `df`, so it is sorted by the same columns.
+        let sorting_columns = sorting_columns(df.columns(), self.parquet_schema.fields());
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
-        for (num_rows, group) in row_group_iter {
-            writer.write(num_rows as u64, group?)?;
+        for (num_rows, group, bloom_filters) in row_group_iter {
+            writer.write_with_metadata(
+                num_rows as u64,
+                group?,
+                bloom_filters,
+                sorting_columns.clone(),
+            )?;
         }
         Ok(())
     }
@@ -93,10 +118,15 @@ impl<W: Write> BatchedWriter<W> {
     }
 
     /// Note: `num_rows` can be passed as `u64::MAX` to infer `num_rows` from the encoded data.
+    ///
+    /// `bloom_filters` holds the bloom filter of every leaf column, or is empty.
+    /// `sorting_columns` holds the columns the rows of the row group are sorted by, if any.
     pub fn write_row_group(
         &mut self,
         num_rows: u64,
         rg: &[Vec<CompressedPage>],
+        bloom_filters: Vec<BloomFilterBitset>,
+        sorting_columns: Option<Vec<SortingColumn>>,
     ) -> PolarsResult<()> {
         let writer = self.writer.get_mut().unwrap();
         let rg = DynIter::new(rg.iter().map(|col_pages| {
@@ -104,7 +134,7 @@ impl<W: Write> BatchedWriter<W> {
                 fallible_streaming_iterator::convert(col_pages.iter().map(PolarsResult::Ok)),
             ))
         }));
-        writer.write(num_rows, rg)?;
+        writer.write_with_metadata(num_rows, rg, bloom_filters, sorting_columns)?;
         Ok(())
     }
 
@@ -150,26 +180,50 @@ impl<W: Write> BatchedWriter<W> {
 }
 
 // Note that the df should be rechunked
+#[allow(clippy::too_many_arguments)]
 fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
     parquet_schema: &'a SchemaDescriptor,
     encodings: &'a [Vec<Encoding>],
+    compressions: &'a [CompressionOptions],
     options: WriteOptions,
     parallel: bool,
+    memory_budget: Option<usize>,
+    bloom_filters: Option<&'a ParquetBloomFilters>,
 ) -> impl Iterator<
     Item = (
         usize,
         PolarsResult<RowGroupIterColumns<'static, PolarsError>>,
+        Vec<BloomFilterBitset>,
     ),
 > + 'a {
     let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         num_rows => {
-            let row_group =
-                create_serializer(batch, parquet_schema.fields(), encodings, options, parallel);
+            let bloom_filters = bloom_filters.map_or_else(Vec::new, |bloom_filters| {
+                bloom_filters.row_group_bloom_filters(batch.columns(), parquet_schema.fields())
+            });
+            let row_group = match memory_budget {
+                Some(memory_budget) => Ok(create_pipelined_serializer(
+                    batch,
+                    parquet_schema.fields(),
+                    encodings,
+                    compressions,
+                    options,
+                    memory_budget,
+                )),
+                None => create_serializer(
+                    batch,
+                    parquet_schema.fields(),
+                    encodings,
+                    compressions,
+                    options,
+                    parallel,
+                ),
+            };
 
-            Some((num_rows, row_group))
+            Some((num_rows, row_group, bloom_filters))
         },
     })
 }
@@ -214,10 +268,18 @@ fn create_serializer(
     batch: RecordBatch,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
+    compressions: &[CompressionOptions],
     options: WriteOptions,
     parallel: bool,
 ) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
+    let func = move |(((array, type_), encoding), compression): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &CompressionOptions,
+    )| {
+        let options = WriteOptions {
+            compression: *compression,
+            ..options
+        };
         array_to_pages_iter(array, type_, encoding, options)
     };
 
@@ -228,6 +290,7 @@ fn create_serializer(
                 .par_iter()
                 .zip(fields)
                 .zip(encodings)
+                .zip(compressions)
                 .flat_map(func)
                 .collect::<Vec<_>>()
         })
@@ -237,6 +300,7 @@ fn create_serializer(
             .iter()
             .zip(fields)
             .zip(encodings)
+            .zip(compressions)
             .flat_map(func)
             .collect::<Vec<_>>()
     };
@@ -246,15 +310,129 @@ fn create_serializer(
     Ok(row_group)
 }
 
+/// This serializer encodes and compresses the columns in parallel, in consecutive waves of columns
+/// whose estimated size in memory fits in `memory_budget` bytes. A wave always holds at least one
+/// column. The next wave is only encoded once the pages of the previous wave have been written, so
+/// at most one wave of compressed pages is held in memory.
+fn create_pipelined_serializer(
+    batch: RecordBatch,
+    fields: &[ParquetType],
+    encodings: &[Vec<Encoding>],
+    compressions: &[CompressionOptions],
+    options: WriteOptions,
+    memory_budget: usize,
+) -> RowGroupIterColumns<'static, PolarsError> {
+    let mut columns = batch
+        .into_arrays()
+        .into_iter()
+        .zip(fields.to_vec())
+        .zip(encodings.to_vec())
+        .zip(compressions.to_vec())
+        .peekable();
+    let mut wave = VecDeque::new();
+
+    let iter = std::iter::from_fn(move || {
+        if wave.is_empty() {
+            let mut wave_columns = Vec::new();
+            let mut wave_size = 0;
+            while let Some((((array, _), _), _)) = columns.peek() {
+                let size = estimated_bytes_size(array.as_ref());
+                if !wave_columns.is_empty() && wave_size + size > memory_budget {
+                    break;
+                }
+                wave_size += size;
+                wave_columns.push(columns.next().unwrap());
+            }
+
+            wave = POOL.install(|| {
+                wave_columns
+                    .into_par_iter()
+                    .flat_map(|(((array, type_), encoding), compression)| {
+                        let options = WriteOptions {
+                            compression,
+                            ..options
+                        };
+                        array_to_compressed_pages(&array, &type_, &encoding, options)
+                    })
+                    .collect::<VecDeque<_>>()
+            });
+        }
+        wave.pop_front()
+    });
+
+    DynIter::new(iter)
+}
+
+/// Encodes and compresses all pages of the leaf columns of `array` in memory.
+fn array_to_compressed_pages(
+    array: &ArrayRef,
+    type_: &ParquetType,
+    encoding: &[Encoding],
+    options: WriteOptions,
+) -> Vec<PolarsResult<DynStreamingIterator<'static, CompressedPage, PolarsError>>> {
+    let encoded_columns = match array_to_columns(array, type_.clone(), options, encoding) {
+        Ok(encoded_columns) => encoded_columns,
+        Err(e) => return vec![Err(e)],
+    };
+    encoded_columns
+        .into_iter()
+        .map(|encoded_pages| {
+            let compressor = Compressor::new_from_vec(
+                encoded_pages.map(|result| {
+                    result.map_err(|e| {
+                        ParquetError::FeatureNotSupported(format!("reraised in polars: {e}",))
+                    })
+                }),
+                options.compression,
+                vec![],
+            );
+            let pages = Iterator::collect::<Result<Vec<_>, ParquetError>>(compressor)?;
+
+            Ok(DynStreamingIterator::new(CompressedPages {
+                pages: pages.into_iter(),
+                current: None,
+            }))
+        })
+        .collect()
+}
+
+/// Streams the pages of a column that were already compressed.
+struct CompressedPages {
+    pages: std::vec::IntoIter<CompressedPage>,
+    current: Option<CompressedPage>,
+}
+
+impl FallibleStreamingIterator for CompressedPages {
+    type Item = CompressedPage;
+    type Error = PolarsError;
+
+    fn advance(&mut self) -> PolarsResult<()> {
+        self.current = self.pages.next();
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&CompressedPage> {
+        self.current.as_ref()
+    }
+}
+
 /// This serializer encodes and compresses all eagerly in memory.
 /// Used for separating compute from IO.
 fn create_eager_serializer(
     batch: RecordBatch,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
+    compressions: &[CompressionOptions],
     options: WriteOptions,
 ) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
+    let func = move |(((array, type_), encoding), compression): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &CompressionOptions,
+    )| {
+        let options = WriteOptions {
+            compression: *compression,
+            ..options
+        };
         array_to_pages_iter(array, type_, encoding, options)
     };
 
@@ -263,6 +441,7 @@ fn create_eager_serializer(
         .iter()
         .zip(fields)
         .zip(encodings)
+        .zip(compressions)
         .flat_map(func)
         .collect::<Vec<_>>();
 
@@ -0,0 +1,102 @@
+use arrow::array::{Array, ArrayRef};
+use arrow::datatypes::ArrowSchema;
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+use polars_parquet::write::{
+    BloomFilterBitset, ParquetType, PrimitiveLogicalType, to_parquet_leaves,
+};
+#[cfg(feature = "parquet_bloom_filter")]
+use polars_parquet::write::{BloomFilterOptions, array_to_bloom_filter};
+
+use super::ParquetBloomFilterOptions;
+
+/// The bloom filters to write for the columns of a schema, see [`ParquetBloomFilterOptions`].
+#[derive(Clone, Debug)]
+pub struct ParquetBloomFilters {
+    /// Whether to write a bloom filter, for every top-level column.
+    columns: Vec<bool>,
+    #[cfg(feature = "parquet_bloom_filter")]
+    options: BloomFilterOptions,
+}
+
+impl ParquetBloomFilters {
+    pub fn try_new(
+        options: &ParquetBloomFilterOptions,
+        schema: &ArrowSchema,
+    ) -> PolarsResult<Self> {
+        let fpp = options.fpp.0;
+        polars_ensure!(
+            fpp > 0.0 && fpp < 1.0,
+            InvalidOperation: "the false positive probability of bloom filters must be between 0 and 1, got {}", fpp
+        );
+        polars_ensure!(
+            options.ndv > 0,
+            InvalidOperation: "the number of distinct values of bloom filters must be positive"
+        );
+        let mut columns = vec![false; schema.len()];
+        for name in &options.columns {
+            let Some(idx) = schema.index_of(name) else {
+                polars_bail!(ColumnNotFound: "cannot write a bloom filter for column '{}'", name);
+            };
+            columns[idx] = true;
+        }
+        #[cfg(not(feature = "parquet_bloom_filter"))]
+        polars_bail!(ComputeError: "'parquet_bloom_filter' feature is not enabled");
+        #[cfg(feature = "parquet_bloom_filter")]
+        Ok(Self {
+            columns,
+            options: BloomFilterOptions {
+                ndv: options.ndv,
+                fpp,
+            },
+        })
+    }
+
+    /// Build the bloom filters of the leaf columns of the `i`-th top-level column. Nested
+    /// columns and UUID columns, whose values are written as bytes rather than as strings, have
+    /// no bloom filters.
+    pub fn column_bloom_filters(
+        &self,
+        i: usize,
+        array: &dyn Array,
+        type_: &ParquetType,
+    ) -> Vec<BloomFilterBitset> {
+        let num_leaves = match type_ {
+            ParquetType::PrimitiveType(type_)
+                if type_.logical_type == Some(PrimitiveLogicalType::Uuid) =>
+            {
+                return vec![None];
+            },
+            ParquetType::PrimitiveType(_) => 1,
+            ParquetType::GroupType { .. } => to_parquet_leaves(type_.clone()).len(),
+        };
+        let mut bloom_filters = vec![None; num_leaves];
+        if self.columns[i] && num_leaves == 1 {
+            bloom_filters[0] = self.bloom_filter(array);
+        }
+        bloom_filters
+    }
+
+    #[cfg(feature = "parquet_bloom_filter")]
+    fn bloom_filter(&self, array: &dyn Array) -> BloomFilterBitset {
+        array_to_bloom_filter(array, &self.options)
+    }
+
+    #[cfg(not(feature = "parquet_bloom_filter"))]
+    fn bloom_filter(&self, _array: &dyn Array) -> BloomFilterBitset {
+        unreachable!("bloom filters are only built with the 'parquet_bloom_filter' feature")
+    }
+
+    /// Build the bloom filters of the leaf columns of a row group.
+    pub fn row_group_bloom_filters(
+        &self,
+        columns: &[ArrayRef],
+        fields: &[ParquetType],
+    ) -> Vec<BloomFilterBitset> {
+        columns
+            .iter()
+            .zip(fields)
+            .enumerate()
+            .flat_map(|(i, (array, type_))| self.column_bloom_filters(i, array.as_ref(), type_))
+            .collect()
+    }
+}
@@ -1,12 +1,18 @@
 //! Functionality for reading and writing Apache Parquet files.
 
 mod batched_writer;
+mod bloom_filter;
 mod key_value_metadata;
 mod options;
+mod sorting_columns;
 mod writer;
 
 pub use batched_writer::BatchedWriter;
+pub use bloom_filter::ParquetBloomFilters;
 pub use key_value_metadata::{KeyValueMetadata, ParquetMetadataContext};
-pub use options::{ParquetCompression, ParquetWriteOptions};
-pub use polars_parquet::write::{RowGroupIterColumns, StatisticsOptions};
-pub use writer::{ParquetWriter, get_encodings};
+pub use options::{
+    ParquetBloomFilterOptions, ParquetColumnOptions, ParquetCompression, ParquetWriteOptions,
+};
+pub use polars_parquet::write::{FileEncryptionProperties, RowGroupIterColumns, StatisticsOptions};
+pub use sorting_columns::sorting_columns;
+pub use writer::{ParquetWriter, get_compressions, get_encodings, get_parquet_schema};
@@ -1,8 +1,13 @@
+use std::sync::Arc;
+
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::CompatLevel;
 use polars_parquet::write::{
-    BrotliLevel, CompressionOptions, GzipLevel, StatisticsOptions, ZstdLevel,
+    BrotliLevel, CompressionOptions, FileEncryptionProperties, GzipLevel, StatisticsOptions,
+    ZstdLevel,
 };
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::total_ord::TotalOrdWrap;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -20,11 +25,31 @@ pub struct ParquetWriteOptions {
     pub row_group_size: Option<usize>,
     /// if `None` will be 1024^2 bytes
     pub data_page_size: Option<usize>,
+    /// if `None` the number of rows in a data page is not limited
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub data_page_row_limit: Option<usize>,
     /// Custom file-level key value metadata
     pub key_value_metadata: Option<KeyValueMetadata>,
     pub arrow_schema: Option<ArrowSchemaRef>,
     #[cfg_attr(feature = "serde", serde(default))]
     pub compat_level: Option<CompatLevel>,
+    /// Write split-block bloom filters for some columns.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bloom_filter: Option<ParquetBloomFilterOptions>,
+    /// Encrypt the files with Parquet modular encryption.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub encryption: Option<Arc<FileEncryptionProperties>>,
+    /// The top-level float columns to write with `BYTE_STREAM_SPLIT` encoding instead of
+    /// dictionary or plain encoding. This compresses much better for floats that vary a lot.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub byte_stream_split: Vec<PlSmallStr>,
+    /// The top-level string columns holding UUIDs in their canonical string form, to write as
+    /// 16-byte values with the Parquet `UUID` logical type.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub uuid_columns: Vec<PlSmallStr>,
+    /// Options that override the file-level options for some top-level columns.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub column_options: Vec<(PlSmallStr, ParquetColumnOptions)>,
 }
 
 impl ParquetWriteOptions {
@@ -33,6 +58,57 @@ impl ParquetWriteOptions {
     }
 }
 
+/// The bloom filters to write to Parquet files.
+///
+/// A bloom filter is written for every row group of the selected columns, to let readers skip
+/// the row groups that cannot contain a value. They are written for columns of integer, float,
+/// temporal, string and binary dtypes; other columns are ignored.
+#[derive(Clone, Debug, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ParquetBloomFilterOptions {
+    /// The top-level columns to write bloom filters for.
+    pub columns: Vec<PlSmallStr>,
+    /// The number of distinct values expected in a row group. This sets the size of the bloom
+    /// filters.
+    pub ndv: u64,
+    /// The false positive probability at `ndv` distinct values, between 0 and 1.
+    pub fpp: TotalOrdWrap<f64>,
+}
+
+impl ParquetBloomFilterOptions {
+    pub fn new(columns: Vec<PlSmallStr>) -> Self {
+        Self {
+            columns,
+            ndv: 1_000_000,
+            fpp: TotalOrdWrap(0.05),
+        }
+    }
+}
+
+/// The options to write a single top-level column of a Parquet file with.
+#[derive(Clone, Copy, Debug, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ParquetColumnOptions {
+    /// The compression of the data pages of the column. If `None`, the compression of the file
+    /// is used.
+    pub compression: Option<ParquetCompression>,
+    /// Write the column with dictionary encoding if it is worth it. If `false`, the values are
+    /// written without a dictionary page, which avoids the cost of building the dictionary for
+    /// columns with mostly unique values.
+    pub dictionary: bool,
+}
+
+impl Default for ParquetColumnOptions {
+    fn default() -> Self {
+        Self {
+            compression: None,
+            dictionary: true,
+        }
+    }
+}
+
 /// The compression strategy to use for writing Parquet files.
 #[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -0,0 +1,57 @@
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_parquet::write::{ParquetType, PrimitiveLogicalType, SortingColumn, to_parquet_leaves};
+
+/// Whether Parquet orders the values of `dtype` the same way Polars does, so that the sorted flag
+/// of such a column is meaningful to other readers.
+fn has_portable_sort_order(dtype: &DataType) -> bool {
+    dtype.is_integer()
+        || dtype.is_temporal()
+        || matches!(
+            dtype,
+            DataType::Boolean | DataType::String | DataType::Binary
+        )
+}
+
+/// The [`SortingColumn`]s of a row group holding (a slice of) `columns`, derived from their
+/// sorted flags. Returns `None` if no column is known to be sorted.
+///
+/// Every listed column is sorted on its own, so the rows are also sorted by all of them in the
+/// listed order, as the Parquet format requires.
+pub fn sorting_columns(columns: &[Column], fields: &[ParquetType]) -> Option<Vec<SortingColumn>> {
+    let mut sorting_columns = Vec::new();
+    let mut leaf_idx = 0;
+
+    for (column, field) in columns.iter().zip(fields) {
+        let ParquetType::PrimitiveType(type_) = field else {
+            leaf_idx += to_parquet_leaves(field.clone()).len();
+            continue;
+        };
+        // UUIDs written from strings do not keep the order of the strings.
+        if type_.logical_type == Some(PrimitiveLogicalType::Uuid) {
+            leaf_idx += 1;
+            continue;
+        }
+
+        let descending = match column.is_sorted_flag() {
+            IsSorted::Ascending => Some(false),
+            IsSorted::Descending => Some(true),
+            IsSorted::Not => None,
+        };
+        if let Some(descending) = descending
+            && has_portable_sort_order(column.dtype())
+        {
+            // A sorted column has all its nulls either at the start or at the end.
+            let nulls_first =
+                column.null_count() > 0 && column.get(0).is_ok_and(|value| value.is_null());
+            sorting_columns.push(SortingColumn {
+                column_idx: leaf_idx as i32,
+                descending,
+                nulls_first,
+            });
+        }
+        leaf_idx += 1;
+    }
+
+    (!sorting_columns.is_empty()).then_some(sorting_columns)
+}
@@ -5,14 +5,18 @@ use polars_buffer::Buffer;
 use polars_core::frame::chunk_df_for_writing;
 use polars_core::prelude::*;
 use polars_parquet::write::{
-    CompressionOptions, Encoding, FileWriter, StatisticsOptions, Version, WriteOptions,
-    get_dtype_encoding, to_parquet_schema,
+    CompressionOptions, Encoding, FileEncryptionProperties, FileWriter, SchemaDescriptor,
+    StatisticsOptions, Version, WriteOptions, get_dtype_encoding, to_parquet_schema,
+    to_parquet_uuid_type,
 };
 
 use super::batched_writer::BatchedWriter;
 use super::options::ParquetCompression;
-use super::{KeyValueMetadata, ParquetWriteOptions};
-use crate::shared::schema_to_arrow_checked;
+use super::{
+    KeyValueMetadata, ParquetBloomFilterOptions, ParquetBloomFilters, ParquetColumnOptions,
+    ParquetWriteOptions,
+};
+use crate::shared::{attach_field_metadata, schema_to_arrow_checked};
 
 impl ParquetWriteOptions {
     pub fn to_writer<F>(&self, f: F) -> ParquetWriter<F>
@@ -24,7 +28,13 @@ impl ParquetWriteOptions {
             .with_statistics(self.statistics)
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
+            .with_data_page_row_limit(self.data_page_row_limit)
             .with_key_value_metadata(self.key_value_metadata.clone())
+            .with_bloom_filter(self.bloom_filter.clone())
+            .with_encryption(self.encryption.clone())
+            .with_byte_stream_split(self.byte_stream_split.clone())
+            .with_uuid_columns(self.uuid_columns.clone())
+            .with_column_options(self.column_options.clone())
     }
 }
 
@@ -40,12 +50,26 @@ pub struct ParquetWriter<W> {
     row_group_size: Option<usize>,
     /// if `None` will be 1024^2 bytes
     data_page_size: Option<usize>,
+    /// if `None` the number of rows in a data page is not limited
+    data_page_row_limit: Option<usize>,
     /// Serialize columns in parallel
     parallel: bool,
+    /// Bound the memory of the columns that are serialized in parallel.
+    memory_budget: Option<usize>,
     /// Custom file-level key value metadata
     key_value_metadata: Option<KeyValueMetadata>,
     /// Context info for the Parquet file being written.
     context_info: Option<PlHashMap<String, String>>,
+    /// Bloom filters to write for some columns.
+    bloom_filter: Option<ParquetBloomFilterOptions>,
+    /// Encrypt the file with Parquet modular encryption.
+    encryption: Option<Arc<FileEncryptionProperties>>,
+    /// Float columns to write with `BYTE_STREAM_SPLIT` encoding.
+    byte_stream_split: Vec<PlSmallStr>,
+    /// String columns to write as Parquet UUIDs.
+    uuid_columns: Vec<PlSmallStr>,
+    /// Options that override the file-level options for some columns.
+    column_options: Vec<(PlSmallStr, ParquetColumnOptions)>,
 }
 
 impl<W> ParquetWriter<W>
@@ -63,9 +87,16 @@ where
             statistics: StatisticsOptions::default(),
             row_group_size: None,
             data_page_size: None,
+            data_page_row_limit: None,
             parallel: true,
+            memory_budget: None,
             key_value_metadata: None,
             context_info: None,
+            bloom_filter: None,
+            encryption: None,
+            byte_stream_split: Vec::new(),
+            uuid_columns: Vec::new(),
+            column_options: Vec::new(),
         }
     }
 
@@ -97,12 +128,30 @@ where
         self
     }
 
+    /// Sets the maximum number of rows in a data page, on top of the limit on its size. Smaller
+    /// pages let readers that use the page index skip more rows. If `None` it is not limited.
+    pub fn with_data_page_row_limit(mut self, limit: Option<usize>) -> Self {
+        self.data_page_row_limit = limit;
+        self
+    }
+
     /// Serialize columns in parallel
     pub fn set_parallel(mut self, parallel: bool) -> Self {
         self.parallel = parallel;
         self
     }
 
+    /// Encode the columns of a row group in parallel while bounding the memory in flight to
+    /// roughly `memory_budget` bytes. Columns are encoded in waves whose in-memory size fits in
+    /// the budget, and the pages of a wave are written out before the next wave is encoded. This
+    /// allows writing very wide frames without holding a whole encoded row group in memory.
+    ///
+    /// If `None`, the whole row group is encoded before it is written.
+    pub fn with_memory_budget(mut self, memory_budget: Option<usize>) -> Self {
+        self.memory_budget = memory_budget;
+        self
+    }
+
     /// Set custom file-level key value metadata for the Parquet file
     pub fn with_key_value_metadata(mut self, key_value_metadata: Option<KeyValueMetadata>) -> Self {
         self.key_value_metadata = key_value_metadata;
@@ -115,20 +164,85 @@ where
         self
     }
 
+    /// Write split-block bloom filters for some columns, see [`ParquetBloomFilterOptions`].
+    pub fn with_bloom_filter(mut self, bloom_filter: Option<ParquetBloomFilterOptions>) -> Self {
+        self.bloom_filter = bloom_filter;
+        self
+    }
+
+    /// Encrypt the file with Parquet modular encryption, see [`FileEncryptionProperties`].
+    pub fn with_encryption(mut self, encryption: Option<Arc<FileEncryptionProperties>>) -> Self {
+        self.encryption = encryption;
+        self
+    }
+
+    /// Write the top-level float columns with these names with `BYTE_STREAM_SPLIT` encoding,
+    /// which compresses much better than plain encoding for floats that vary a lot.
+    pub fn with_byte_stream_split(mut self, columns: Vec<PlSmallStr>) -> Self {
+        self.byte_stream_split = columns;
+        self
+    }
+
+    /// Write the top-level string columns with these names as 16-byte values with the Parquet
+    /// `UUID` logical type. Every non-null value must be a UUID in its canonical string form, e.g.
+    /// `"123e4567-e89b-12d3-a456-426614174000"`.
+    pub fn with_uuid_columns(mut self, columns: Vec<PlSmallStr>) -> Self {
+        self.uuid_columns = columns;
+        self
+    }
+
+    /// Override the compression and the dictionary encoding of the top-level columns with these
+    /// names, e.g. to compress string columns with `Zstd` while writing float columns
+    /// uncompressed.
+    pub fn with_column_options(
+        mut self,
+        column_options: Vec<(PlSmallStr, ParquetColumnOptions)>,
+    ) -> Self {
+        self.column_options = column_options;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
-        let parquet_schema = to_parquet_schema(&schema)?;
-        let encodings = get_encodings(&schema);
+        self.batched_arrow(schema)
+    }
+
+    fn batched_arrow(self, schema: ArrowSchema) -> PolarsResult<BatchedWriter<W>> {
+        let parquet_schema = get_parquet_schema(&schema, &self.uuid_columns)?;
+        let encodings = get_encodings(
+            &schema,
+            &self.byte_stream_split,
+            &self.uuid_columns,
+            &self.column_options,
+        )?;
         let options = self.materialize_options();
-        let writer = Mutex::new(FileWriter::try_new(self.writer, schema, options)?);
+        let compressions = get_compressions(&schema, options.compression, &self.column_options)?;
+        let bloom_filters = self
+            .bloom_filter
+            .as_ref()
+            .map(|bloom_filter| ParquetBloomFilters::try_new(bloom_filter, &schema))
+            .transpose()?;
+        let mut writer = FileWriter::new_with_parquet_schema(
+            self.writer,
+            schema,
+            parquet_schema.clone(),
+            options,
+        );
+        if let Some(encryption) = self.encryption {
+            writer = writer.with_encryption(encryption)?;
+        }
+        let writer = Mutex::new(writer);
 
         Ok(BatchedWriter {
             writer,
             parquet_schema,
             encodings,
+            compressions,
             options,
             parallel: self.parallel,
+            memory_budget: self.memory_budget,
             key_value_metadata: self.key_value_metadata,
+            bloom_filters,
         })
     }
 
@@ -138,6 +252,7 @@ where
             compression: self.compression,
             version: Version::V1,
             data_page_size: self.data_page_size,
+            data_page_row_limit: self.data_page_row_limit,
         }
     }
 
@@ -145,15 +260,94 @@ where
     /// Returns the total size of the file.
     pub fn finish(self, df: &mut DataFrame) -> PolarsResult<u64> {
         let chunked_df = chunk_df_for_writing(df, self.row_group_size.unwrap_or(512 * 512))?;
-        let mut batched = self.batched(chunked_df.schema())?;
+        let mut schema =
+            schema_to_arrow_checked(chunked_df.schema(), CompatLevel::newest(), "parquet")?;
+        attach_field_metadata(&mut schema, &chunked_df.field_metadata());
+        let mut batched = self.batched_arrow(schema)?;
         batched.write_batch(&chunked_df)?;
         batched.finish()
     }
 }
 
-pub fn get_encodings(schema: &ArrowSchema) -> Buffer<Vec<Encoding>> {
-    schema
+/// Get the Parquet schema of `schema`, writing the string columns in `uuid_columns` as UUIDs.
+pub fn get_parquet_schema(
+    schema: &ArrowSchema,
+    uuid_columns: &[PlSmallStr],
+) -> PolarsResult<SchemaDescriptor> {
+    let parquet_schema = to_parquet_schema(schema)?;
+    if uuid_columns.is_empty() {
+        return Ok(parquet_schema);
+    }
+
+    let mut fields = parquet_schema.fields().to_vec();
+    for name in uuid_columns {
+        let Some((idx, _, field)) = schema.get_full(name) else {
+            polars_bail!(ColumnNotFound: "cannot write column '{}' as Parquet UUIDs", name);
+        };
+        fields[idx] = to_parquet_uuid_type(field)?;
+    }
+    Ok(SchemaDescriptor::new(parquet_schema.name().into(), fields))
+}
+
+/// Get the encodings of the leaf columns of every top-level column of `schema`, writing the
+/// float columns in `byte_stream_split` with `BYTE_STREAM_SPLIT` encoding, the UUID columns in
+/// `uuid_columns` and the columns with dictionary encoding disabled in `column_options` with
+/// plain encoding.
+pub fn get_encodings(
+    schema: &ArrowSchema,
+    byte_stream_split: &[PlSmallStr],
+    uuid_columns: &[PlSmallStr],
+    column_options: &[(PlSmallStr, ParquetColumnOptions)],
+) -> PolarsResult<Buffer<Vec<Encoding>>> {
+    let mut encodings: Vec<_> = schema
         .iter_values()
         .map(|f| get_dtype_encoding(&f.dtype))
-        .collect()
+        .collect();
+    for name in byte_stream_split {
+        let Some((idx, _, field)) = schema.get_full(name) else {
+            polars_bail!(ColumnNotFound: "cannot write column '{}' with byte stream split encoding", name);
+        };
+        polars_ensure!(
+            matches!(field.dtype, ArrowDataType::Float32 | ArrowDataType::Float64),
+            InvalidOperation: "byte stream split encoding is only supported for Float32 and Float64 columns, got column '{}' of type {:?}", name, field.dtype
+        );
+        encodings[idx] = vec![Encoding::ByteStreamSplit];
+    }
+    for name in uuid_columns {
+        if let Some(idx) = schema.index_of(name) {
+            encodings[idx] = vec![Encoding::Plain];
+        }
+    }
+    for (name, options) in column_options {
+        let Some(idx) = schema.index_of(name) else {
+            polars_bail!(ColumnNotFound: "cannot set the Parquet options of column '{}'", name);
+        };
+        if !options.dictionary {
+            for encoding in &mut encodings[idx] {
+                if *encoding == Encoding::RleDictionary {
+                    *encoding = Encoding::Plain;
+                }
+            }
+        }
+    }
+    Ok(encodings.into())
+}
+
+/// Get the compression of every top-level column of `schema`, which is `compression` unless it is
+/// overridden in `column_options`.
+pub fn get_compressions(
+    schema: &ArrowSchema,
+    compression: CompressionOptions,
+    column_options: &[(PlSmallStr, ParquetColumnOptions)],
+) -> PolarsResult<Buffer<CompressionOptions>> {
+    let mut compressions = vec![compression; schema.len()];
+    for (name, options) in column_options {
+        let Some(idx) = schema.index_of(name) else {
+            polars_bail!(ColumnNotFound: "cannot set the Parquet options of column '{}'", name);
+        };
+        if let Some(compression) = options.compression {
+            compressions[idx] = compression.into();
+        }
+    }
+    Ok(compressions.into())
 }
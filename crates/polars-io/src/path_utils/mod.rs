@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 use std::collections::VecDeque;
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 
@@ -167,16 +168,37 @@ pub fn expanded_from_single_directory(paths: &[PlRefPath], expanded_paths: &[PlR
     }
 }
 
+/// Returns whether a file is kept, given its file name.
+pub type FileNameFilter<'a> = &'a (dyn Fn(&str) -> PolarsResult<bool> + Send + Sync);
+
+/// Additional controls for [`expand_paths`] and [`expand_paths_hive`].
+#[derive(Clone, Copy, Default)]
+pub struct ListingControls<'a> {
+    /// Maximum number of cloud paths that are listed concurrently. Paths are listed one at a time
+    /// if this is `None`.
+    pub listing_concurrency: Option<NonZeroUsize>,
+    /// Files are skipped if this returns `false` for their file name.
+    pub file_name_filter: Option<FileNameFilter<'a>>,
+}
+
 /// Recursively traverses directories and expands globs if `glob` is `true`.
 pub async fn expand_paths(
     paths: &[PlRefPath],
     glob: bool,
     hidden_file_prefix: &[PlSmallStr],
     #[allow(unused_variables)] cloud_options: &mut Option<CloudOptions>,
+    listing_controls: ListingControls<'_>,
 ) -> PolarsResult<Buffer<PlRefPath>> {
-    expand_paths_hive(paths, glob, hidden_file_prefix, cloud_options, false)
-        .await
-        .map(|x| x.0)
+    expand_paths_hive(
+        paths,
+        glob,
+        hidden_file_prefix,
+        cloud_options,
+        false,
+        listing_controls,
+    )
+    .await
+    .map(|x| x.0)
 }
 
 struct HiveIdxTracker<'a> {
@@ -319,6 +341,7 @@ pub async fn expand_paths_hive(
     hidden_file_prefix: &[PlSmallStr],
     #[allow(unused_variables)] cloud_options: &mut Option<CloudOptions>,
     check_directory_level: bool,
+    listing_controls: ListingControls<'_>,
 ) -> PolarsResult<(Buffer<PlRefPath>, usize)> {
     let Some(first_path) = paths.first() else {
         return Ok((vec![].into(), 0));
@@ -326,20 +349,28 @@ pub async fn expand_paths_hive(
 
     let first_path_has_scheme = first_path.has_scheme();
 
-    let is_hidden_file = move |path: &PlRefPath| {
-        path.file_name()
-            .and_then(|x| x.to_str())
-            .is_some_and(|file_name| {
-                hidden_file_prefix
-                    .iter()
-                    .any(|x| file_name.starts_with(x.as_str()))
-            })
+    let is_skipped_file = move |path: &PlRefPath| {
+        let Some(file_name) = path.file_name().and_then(|x| x.to_str()) else {
+            return Ok(false);
+        };
+
+        if hidden_file_prefix
+            .iter()
+            .any(|x| file_name.starts_with(x.as_str()))
+        {
+            return Ok(true);
+        }
+
+        match listing_controls.file_name_filter {
+            Some(f) => f(file_name).map(|keep| !keep),
+            None => Ok(false),
+        }
     };
 
     let mut out_paths = OutPaths {
         paths: vec![],
         exts: [None, None],
-        is_hidden_file: &is_hidden_file,
+        is_skipped_file: &is_skipped_file,
     };
 
     let mut hive_idx_tracker = HiveIdxTracker {
@@ -362,10 +393,35 @@ pub async fn expand_paths_hive(
                 )
                 .await?;
 
+                let paths = if listing_controls.file_name_filter.is_some() {
+                    let mut kept = Vec::with_capacity(paths.len());
+
+                    for path in paths {
+                        if !is_skipped_file(&path)? {
+                            kept.push(path)
+                        }
+                    }
+
+                    kept
+                } else {
+                    paths
+                };
+
                 return Ok((paths.into(), expand_start_idx));
             }
 
-            for (path_idx, path) in paths.iter().enumerate() {
+            use futures::StreamExt;
+
+            /// How a single input path is expanded.
+            enum CloudExpansion {
+                Passthrough(PlRefPath),
+                Glob(PlRefPath),
+                List(PlRefPath),
+            }
+
+            let mut expansions = Vec::with_capacity(paths.len());
+
+            for path in paths.iter() {
                 use std::borrow::Cow;
 
                 let mut path = Cow::Borrowed(path);
@@ -414,43 +470,56 @@ pub async fn expand_paths_hive(
                     }
 
                     if !rewrite_aws {
-                        out_paths.push(path.into_owned());
-                        hive_idx_tracker.update(0, path_idx)?;
+                        expansions.push(CloudExpansion::Passthrough(path.into_owned()));
                         continue;
                     }
                 }
 
-                let sort_start_idx = out_paths.paths.len();
+                expansions.push(if glob && has_glob(path.as_bytes()) {
+                    CloudExpansion::Glob(path.into_owned())
+                } else {
+                    CloudExpansion::List(path.into_owned())
+                });
+            }
 
-                if glob && has_glob(path.as_bytes()) {
-                    hive_idx_tracker.update(0, path_idx)?;
+            let cloud_options = cloud_options.as_ref();
+
+            let mut expanded =
+                futures::stream::iter(expansions.into_iter().map(|expansion| async move {
+                    match expansion {
+                        CloudExpansion::Passthrough(path) => Ok((0, vec![path])),
+                        CloudExpansion::Glob(path) => {
+                            let iter = crate::pl_async::get_runtime()
+                                .block_in_place_on(crate::async_glob(path, cloud_options))?;
+
+                            let paths = if first_path_has_scheme {
+                                iter.into_iter().map(PlRefPath::new).collect()
+                            } else {
+                                // FORCE_ASYNC, remove leading file:// as the caller may not be
+                                // expecting a URI result.
+                                iter.iter().map(|x| &x[7..]).map(PlRefPath::new).collect()
+                            };
+
+                            Ok((0, paths))
+                        },
+                        CloudExpansion::List(path) => {
+                            expand_path_cloud(path, cloud_options, glob, first_path_has_scheme)
+                                .await
+                        },
+                    }
+                }))
+                .buffered(listing_controls.listing_concurrency.map_or(1, |x| x.get()));
 
-                    let iter = crate::pl_async::get_runtime().block_in_place_on(
-                        crate::async_glob(path.into_owned(), cloud_options.as_ref()),
-                    )?;
+            let mut path_idx = 0;
 
-                    if first_path_has_scheme {
-                        out_paths.extend(iter.into_iter().map(PlRefPath::new))
-                    } else {
-                        // FORCE_ASYNC, remove leading file:// as the caller may not be expecting a
-                        // URI result.
-                        out_paths.extend(iter.iter().map(|x| &x[7..]).map(PlRefPath::new))
-                    };
-                } else {
-                    let (expand_start_idx, paths) = expand_path_cloud(
-                        path.into_owned(),
-                        cloud_options.as_ref(),
-                        glob,
-                        first_path_has_scheme,
-                    )
-                    .await?;
-                    out_paths.extend_from_slice(&paths);
-                    hive_idx_tracker.update(expand_start_idx, path_idx)?;
-                };
+            while let Some(v) = expanded.next().await {
+                let (expand_start_idx, mut paths) = v?;
 
-                if let Some(mut_slice) = out_paths.paths.get_mut(sort_start_idx..) {
-                    <[PlRefPath]>::sort_unstable(mut_slice);
-                }
+                paths.sort_unstable();
+                out_paths.extend(paths)?;
+                hive_idx_tracker.update(expand_start_idx, path_idx)?;
+
+                path_idx += 1;
             }
         }
         #[cfg(not(feature = "cloud"))]
@@ -492,7 +561,7 @@ pub async fn expand_paths_hive(
                         if md.is_dir() {
                             stack.push_back(Cow::Owned(path));
                         } else if md.len() > 0 {
-                            out_paths.push(PlRefPath::try_from_path(&path)?);
+                            out_paths.push(PlRefPath::try_from_path(&path)?)?;
                         }
                     }
                 }
@@ -507,12 +576,12 @@ pub async fn expand_paths_hive(
                     let path = path.map_err(to_compute_err)?;
                     let md = path.metadata()?;
                     if !md.is_dir() && md.len() > 0 {
-                        out_paths.push(PlRefPath::try_from_path(&path)?);
+                        out_paths.push(PlRefPath::try_from_path(&path)?)?;
                     }
                 }
             } else {
                 hive_idx_tracker.update(0, path_idx)?;
-                out_paths.push(path.clone());
+                out_paths.push(path.clone())?;
             };
 
             if let Some(mut_slice) = out_paths.paths.get_mut(sort_start_idx..) {
@@ -536,42 +605,35 @@ pub async fn expand_paths_hive(
 
     /// Wrapper around `Vec<PathBuf>` that also tracks file extensions, so that
     /// we don't have to traverse the entire list again to validate extensions.
-    struct OutPaths<'a, F: Fn(&PlRefPath) -> bool> {
+    struct OutPaths<'a, F: Fn(&PlRefPath) -> PolarsResult<bool>> {
         paths: Vec<PlRefPath>,
         exts: [Option<(PlSmallStr, PlRefPath)>; 2],
-        is_hidden_file: &'a F,
+        is_skipped_file: &'a F,
     }
 
     impl<F> OutPaths<'_, F>
     where
-        F: Fn(&PlRefPath) -> bool,
+        F: Fn(&PlRefPath) -> PolarsResult<bool>,
     {
-        fn push(&mut self, value: PlRefPath) {
-            if (self.is_hidden_file)(&value) {
-                return;
+        fn push(&mut self, value: PlRefPath) -> PolarsResult<()> {
+            if (self.is_skipped_file)(&value)? {
+                return Ok(());
             }
 
             let exts = &mut self.exts;
             Self::update_ext_status(exts, &value);
 
-            self.paths.push(value)
-        }
+            self.paths.push(value);
 
-        fn extend(&mut self, values: impl IntoIterator<Item = PlRefPath>) {
-            let exts = &mut self.exts;
-
-            self.paths.extend(
-                values
-                    .into_iter()
-                    .filter(|x| !(self.is_hidden_file)(x))
-                    .inspect(|x| {
-                        Self::update_ext_status(exts, x);
-                    }),
-            )
+            Ok(())
         }
 
-        fn extend_from_slice(&mut self, values: &[PlRefPath]) {
-            self.extend(values.iter().cloned())
+        fn extend(&mut self, values: impl IntoIterator<Item = PlRefPath>) -> PolarsResult<()> {
+            for value in values {
+                self.push(value)?;
+            }
+
+            Ok(())
         }
 
         fn update_ext_status(exts: &mut [Option<(PlSmallStr, PlRefPath)>; 2], value: &PlRefPath) {
@@ -661,7 +723,13 @@ mod tests {
         let path = "https://pola.rs/test.csv?token=bear";
         let paths = &[PlRefPath::new(path)];
         let out = get_runtime()
-            .block_on(expand_paths(paths, true, &[], &mut None))
+            .block_on(expand_paths(
+                paths,
+                true,
+                &[],
+                &mut None,
+                Default::default(),
+            ))
             .unwrap();
         assert_eq!(out.as_ref(), paths);
     }
@@ -253,6 +253,45 @@ where
     callable().await
 }
 
+/// Serializes the [`ConcurrencyBudgetLimit`]s, as holding back the budget of two limits at the same
+/// time could starve the requests of both.
+static CONCURRENCY_LIMIT: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Holds back part of the concurrency budget until dropped, see [`limit_concurrency_budget`].
+pub struct ConcurrencyBudgetLimit {
+    _held_back: Option<tokio::sync::SemaphorePermit<'static>>,
+    _lock: std::sync::MutexGuard<'static, ()>,
+}
+
+/// Limit the concurrent object store requests of the process to `max_budget`, for as long as the
+/// returned guard is alive. Blocks until the requests in flight leave enough budget.
+///
+/// The limit is at least the budget of a single request. Budget that the concurrency tuner adds
+/// afterwards is not held back.
+pub fn limit_concurrency_budget(max_budget: u32) -> ConcurrencyBudgetLimit {
+    let lock = CONCURRENCY_LIMIT
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let (semaphore, initial_budget) = get_semaphore();
+    let held_back = initial_budget.saturating_sub(max_budget.max(MAX_BUDGET_PER_REQUEST as u32));
+
+    let held_back = (held_back > 0).then(|| {
+        get_runtime()
+            .block_in_place_on(semaphore.acquire_many(held_back))
+            .unwrap()
+    });
+    if verbose() {
+        eprintln!(
+            "concurrency budget limited to {}",
+            initial_budget - held_back.as_ref().map_or(0, |p| p.num_permits() as u32)
+        );
+    }
+    ConcurrencyBudgetLimit {
+        _held_back: held_back,
+        _lock: lock,
+    }
+}
+
 pub struct RuntimeManager {
     rt: Runtime,
 }
@@ -402,6 +402,9 @@ pub trait SkipBatchPredicate: Send + Sync {
 pub struct ColumnPredicates {
     pub predicates:
         PlHashMap<PlSmallStr, (Arc<dyn PhysicalIoExpr>, Option<SpecializedColumnPredicate>)>,
+    /// The paths of the struct fields that the predicates of struct columns apply to. These
+    /// predicates are evaluated on the values of the field, under the name of the column.
+    pub struct_field_paths: PlHashMap<PlSmallStr, Vec<PlSmallStr>>,
     pub is_sumwise_complete: bool,
 }
 
@@ -411,6 +414,7 @@ impl Default for ColumnPredicates {
     fn default() -> Self {
         Self {
             predicates: PlHashMap::default(),
+            struct_field_paths: PlHashMap::default(),
             is_sumwise_complete: false,
         }
     }
@@ -507,6 +511,7 @@ impl ScanIOPredicate {
         let mut column_predicates = self.column_predicates.as_ref().clone();
         for (c, _) in constant_columns.iter() {
             column_predicates.predicates.remove(c);
+            column_predicates.struct_field_paths.remove(c);
         }
         self.column_predicates = Arc::new(column_predicates);
 
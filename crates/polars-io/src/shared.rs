@@ -106,6 +106,15 @@ pub(crate) fn finish_reader<R: ArrowReader>(
     Ok(df)
 }
 
+/// Add the user metadata of the columns to the fields of an arrow schema.
+pub fn attach_field_metadata(schema: &mut ArrowSchema, metadata: &FieldMetadataMap) {
+    for (name, field) in schema.iter_mut() {
+        if let Some(md) = metadata.get(name) {
+            *field = arrow_field_with_user_metadata(std::mem::take(field), md);
+        }
+    }
+}
+
 pub fn schema_to_arrow_checked(
     schema: &Schema,
     compat_level: CompatLevel,
@@ -14,6 +14,42 @@ use polars_error::{PolarsError, PolarsResult};
 use serialize::serialize;
 pub use utf8::serialize_to_utf8;
 
+/// How temporal values are written to JSON.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum JsonTemporalFormat {
+    /// As strings, e.g. `"2024-01-31"`, `"2024-01-31 12:30:00"`, `"12:30:00"` or `"PT1S"`.
+    #[default]
+    Iso,
+    /// As their integer values: days since the UNIX epoch for dates, time units since the UNIX
+    /// epoch for datetimes, nanoseconds since midnight for times and time units for durations.
+    Epoch,
+}
+
+/// How NaN and infinite floats are written to JSON, which has no representation for them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum NonFiniteFloats {
+    /// As `null`.
+    #[default]
+    Null,
+    /// As the strings `"NaN"`, `"Infinity"` and `"-Infinity"`.
+    String,
+}
+
+/// Options to serialize values to JSON.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct JsonSerializeOptions {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub temporal_format: JsonTemporalFormat,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub non_finite_floats: NonFiniteFloats,
+}
+
 /// [`FallibleStreamingIterator`] that serializes an [`Array`] to bytes of valid JSON
 /// # Implementation
 /// Advancing this iterator CPU-bounded
@@ -25,6 +61,7 @@ where
 {
     arrays: I,
     buffer: Vec<u8>,
+    options: JsonSerializeOptions,
 }
 
 impl<A, I> Serializer<A, I>
@@ -34,7 +71,17 @@ where
 {
     /// Creates a new [`Serializer`].
     pub fn new(arrays: I, buffer: Vec<u8>) -> Self {
-        Self { arrays, buffer }
+        Self {
+            arrays,
+            buffer,
+            options: JsonSerializeOptions::default(),
+        }
+    }
+
+    /// Sets the [`JsonSerializeOptions`] of the values.
+    pub fn with_options(mut self, options: JsonSerializeOptions) -> Self {
+        self.options = options;
+        self
     }
 }
 
@@ -51,7 +98,9 @@ where
         self.buffer.clear();
         self.arrays
             .next()
-            .map(|maybe_array| maybe_array.map(|array| serialize(array.as_ref(), &mut self.buffer)))
+            .map(|maybe_array| {
+                maybe_array.map(|array| serialize(array.as_ref(), &mut self.buffer, self.options))
+            })
             .transpose()?;
         Ok(())
     }
@@ -88,7 +137,9 @@ impl<'a> RecordSerializer<'a> {
         let iterators = chunk
             .arrays()
             .iter()
-            .map(|arr| new_serializer(arr.as_ref(), 0, usize::MAX) as _)
+            .map(|arr| {
+                new_serializer(arr.as_ref(), 0, usize::MAX, JsonSerializeOptions::default()) as _
+            })
             .collect();
 
         Self {
@@ -156,3 +207,57 @@ where
     writer.write_all(b"]")?;
     Ok(())
 }
+
+/// Writes `json`, which must be JSON without whitespace as written by [`write`], to `writer`
+/// with every value of an object or array on its own line, indented by two spaces per level.
+pub fn write_pretty<W: std::io::Write>(writer: &mut W, json: &[u8]) -> PolarsResult<()> {
+    let mut out = Vec::with_capacity(json.len() * 2);
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    let newline = |out: &mut Vec<u8>, depth: usize| {
+        out.push(b'\n');
+        out.resize(out.len() + 2 * depth, b' ');
+    };
+
+    let mut iter = json.iter().copied().peekable();
+    while let Some(byte) = iter.next() {
+        out.push(byte);
+
+        if in_string {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {},
+            }
+            continue;
+        }
+
+        match byte {
+            b'"' => in_string = true,
+            b'{' | b'[' => {
+                if matches!(iter.peek(), Some(b'}' | b']')) {
+                    // Keep empty objects and arrays on a single line.
+                    out.push(iter.next().unwrap());
+                } else {
+                    depth += 1;
+                    newline(&mut out, depth);
+                }
+            },
+            b'}' | b']' => {
+                out.pop();
+                depth = depth.saturating_sub(1);
+                newline(&mut out, depth);
+                out.push(byte);
+            },
+            b',' => newline(&mut out, depth),
+            b':' => out.push(b' '),
+            _ => {},
+        }
+    }
+
+    writer.write_all(&out)?;
+    Ok(())
+}
@@ -20,7 +20,7 @@ use num_traits::{Float, ToPrimitive};
 use polars_utils::float16::pf16;
 use streaming_iterator::StreamingIterator;
 
-use super::utf8;
+use super::{JsonSerializeOptions, JsonTemporalFormat, NonFiniteFloats, utf8};
 
 fn write_integer<I: itoa::Integer>(buf: &mut Vec<u8>, val: I) {
     let mut buffer = itoa::Buffer::new();
@@ -34,6 +34,15 @@ fn write_float<I: zmij::Float>(f: &mut Vec<u8>, val: I) {
     f.extend_from_slice(value.as_bytes())
 }
 
+fn write_non_finite_float<T: Float>(buf: &mut Vec<u8>, val: T, non_finite_floats: NonFiniteFloats) {
+    match non_finite_floats {
+        NonFiniteFloats::Null => buf.extend_from_slice(b"null"),
+        NonFiniteFloats::String if val.is_nan() => buf.extend_from_slice(b"\"NaN\""),
+        NonFiniteFloats::String if val.is_sign_positive() => buf.extend_from_slice(b"\"Infinity\""),
+        NonFiniteFloats::String => buf.extend_from_slice(b"\"-Infinity\""),
+    }
+}
+
 pub trait JsonSerializer: StreamingIterator<Item = [u8]> {
     /// Serializes all rows directly into `to`, bypassing the `BufStreamingIterator` buffer.
     fn serialize_json_lines_to_vec(self: Box<Self>, to: &mut Vec<u8>, n: usize);
@@ -119,14 +128,15 @@ fn float_serializer<'a, T>(
     array: &'a PrimitiveArray<T>,
     offset: usize,
     take: usize,
+    non_finite_floats: NonFiniteFloats,
 ) -> Box<dyn JsonSerializer<Item = [u8]> + 'a + Send + Sync>
 where
     T: num_traits::Float + NativeType + zmij::Float,
 {
-    let f = |x: Option<&T>, buf: &mut Vec<u8>| {
+    let f = move |x: Option<&T>, buf: &mut Vec<u8>| {
         if let Some(x) = x {
             if T::is_nan(*x) || T::is_infinite(*x) {
-                buf.extend(b"null")
+                write_non_finite_float(buf, *x, non_finite_floats)
             } else {
                 write_float(buf, *x)
             }
@@ -142,11 +152,12 @@ fn float16_serializer<'a>(
     array: &'a PrimitiveArray<pf16>,
     offset: usize,
     take: usize,
+    non_finite_floats: NonFiniteFloats,
 ) -> Box<dyn JsonSerializer<Item = [u8]> + 'a + Send + Sync> {
-    let f = |x: Option<&pf16>, buf: &mut Vec<u8>| {
+    let f = move |x: Option<&pf16>, buf: &mut Vec<u8>| {
         if let Some(x) = x {
             if pf16::is_nan(*x) || pf16::is_infinite(*x) {
-                buf.extend(b"null")
+                write_non_finite_float(buf, x.to_f32().unwrap(), non_finite_floats)
             } else {
                 write_float(buf, x.to_f32().unwrap())
             }
@@ -228,6 +239,7 @@ fn struct_serializer<'a>(
     array: &'a StructArray,
     offset: usize,
     take: usize,
+    options: JsonSerializeOptions,
 ) -> Box<dyn JsonSerializer<Item = [u8]> + 'a + Send + Sync> {
     // {"a": [1, 2, 3], "b": [a, b, c], "c": {"a": [1, 2, 3]}}
     // [
@@ -240,7 +252,7 @@ fn struct_serializer<'a>(
         .values()
         .iter()
         .map(|x| x.as_ref())
-        .map(|arr| new_serializer(arr, offset, take))
+        .map(|arr| new_serializer(arr, offset, take, options))
         .collect::<Vec<_>>();
 
     Box::new(BufStreamingIterator::new(
@@ -272,6 +284,7 @@ fn list_serializer<'a, O: Offset>(
     array: &'a ListArray<O>,
     offset: usize,
     take: usize,
+    options: JsonSerializeOptions,
 ) -> Box<dyn JsonSerializer<Item = [u8]> + 'a + Send + Sync> {
     // [[1, 2], [3]]
     // [
@@ -282,7 +295,7 @@ fn list_serializer<'a, O: Offset>(
     let offsets = array.offsets().as_slice();
     let start = offsets[0].to_usize();
     let end = offsets.last().unwrap().to_usize();
-    let mut serializer = new_serializer(array.values().as_ref(), start, end - start);
+    let mut serializer = new_serializer(array.values().as_ref(), start, end - start, options);
 
     let mut prev_offset = start;
     let f = move |offset: Option<&[O]>, buf: &mut Vec<u8>| {
@@ -319,11 +332,13 @@ fn fixed_size_list_serializer<'a>(
     array: &'a FixedSizeListArray,
     offset: usize,
     take: usize,
+    options: JsonSerializeOptions,
 ) -> Box<dyn JsonSerializer<Item = [u8]> + 'a + Send + Sync> {
     let mut serializer = new_serializer(
         array.values().as_ref(),
         offset * array.size(),
         take * array.size(),
+        options,
     );
 
     Box::new(BufStreamingIterator::new(
@@ -484,8 +499,17 @@ pub fn new_serializer<'a>(
     array: &'a dyn Array,
     offset: usize,
     take: usize,
+    options: JsonSerializeOptions,
 ) -> Box<dyn JsonSerializer<Item = [u8]> + 'a + Send + Sync> {
     match array.dtype().to_storage() {
+        ArrowDataType::Date32 if options.temporal_format == JsonTemporalFormat::Epoch => {
+            primitive_serializer::<i32>(array.as_any().downcast_ref().unwrap(), offset, take)
+        },
+        ArrowDataType::Timestamp(_, _) | ArrowDataType::Duration(_) | ArrowDataType::Time64(_)
+            if options.temporal_format == JsonTemporalFormat::Epoch =>
+        {
+            primitive_serializer::<i64>(array.as_any().downcast_ref().unwrap(), offset, take)
+        },
         ArrowDataType::Boolean => {
             boolean_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
         },
@@ -519,15 +543,24 @@ pub fn new_serializer<'a>(
         ArrowDataType::UInt128 => {
             primitive_serializer::<u128>(array.as_any().downcast_ref().unwrap(), offset, take)
         },
-        ArrowDataType::Float16 => {
-            float16_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
-        ArrowDataType::Float32 => {
-            float_serializer::<f32>(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
-        ArrowDataType::Float64 => {
-            float_serializer::<f64>(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
+        ArrowDataType::Float16 => float16_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options.non_finite_floats,
+        ),
+        ArrowDataType::Float32 => float_serializer::<f32>(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options.non_finite_floats,
+        ),
+        ArrowDataType::Float64 => float_serializer::<f64>(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options.non_finite_floats,
+        ),
         #[cfg(feature = "dtype-decimal")]
         ArrowDataType::Decimal(_, scale) => {
             decimal_serializer(array.as_any().downcast_ref().unwrap(), *scale, offset, take)
@@ -538,15 +571,24 @@ pub fn new_serializer<'a>(
         ArrowDataType::Utf8View => {
             utf8view_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
         },
-        ArrowDataType::Struct(_) => {
-            struct_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
-        ArrowDataType::FixedSizeList(_, _) => {
-            fixed_size_list_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
-        ArrowDataType::LargeList(_) => {
-            list_serializer::<i64>(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
+        ArrowDataType::Struct(_) => struct_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
+        ArrowDataType::FixedSizeList(_, _) => fixed_size_list_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
+        ArrowDataType::LargeList(_) => list_serializer::<i64>(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
         ArrowDataType::Dictionary(k, v, _) => match (k, &**v) {
             (IntegerType::UInt8, ArrowDataType::Utf8View) => {
                 let array = array
@@ -657,8 +699,8 @@ fn serialize_item<'a>(
 /// Serializes `array` to a valid JSON to `buffer`
 /// # Implementation
 /// This operation is CPU-bounded
-pub(crate) fn serialize(array: &dyn Array, buffer: &mut Vec<u8>) {
-    let mut serializer = new_serializer(array, 0, usize::MAX);
+pub(crate) fn serialize(array: &dyn Array, buffer: &mut Vec<u8>, options: JsonSerializeOptions) {
+    let mut serializer = new_serializer(array, 0, usize::MAX, options);
 
     (0..array.len()).for_each(|i| {
         if i != 0 {
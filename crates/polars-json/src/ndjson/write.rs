@@ -5,10 +5,10 @@ use arrow::array::Array;
 pub use fallible_streaming_iterator::FallibleStreamingIterator;
 use polars_error::{PolarsError, PolarsResult};
 
-use super::super::json::write::new_serializer;
+use super::super::json::write::{JsonSerializeOptions, new_serializer};
 
-fn serialize(array: &dyn Array, buffer: &mut Vec<u8>) {
-    let mut serializer = new_serializer(array, 0, usize::MAX);
+fn serialize(array: &dyn Array, buffer: &mut Vec<u8>, options: JsonSerializeOptions) {
+    let mut serializer = new_serializer(array, 0, usize::MAX, options);
     (0..array.len()).for_each(|_| {
         buffer.extend_from_slice(serializer.next().unwrap());
         buffer.push(b'\n');
@@ -27,6 +27,7 @@ where
 {
     arrays: I,
     buffer: Vec<u8>,
+    options: JsonSerializeOptions,
 }
 
 impl<A, I> Serializer<A, I>
@@ -36,7 +37,17 @@ where
 {
     /// Creates a new [`Serializer`].
     pub fn new(arrays: I, buffer: Vec<u8>) -> Self {
-        Self { arrays, buffer }
+        Self {
+            arrays,
+            buffer,
+            options: JsonSerializeOptions::default(),
+        }
+    }
+
+    /// Sets the [`JsonSerializeOptions`] of the values.
+    pub fn with_options(mut self, options: JsonSerializeOptions) -> Self {
+        self.options = options;
+        self
     }
 }
 
@@ -53,7 +64,9 @@ where
         self.buffer.clear();
         self.arrays
             .next()
-            .map(|maybe_array| maybe_array.map(|array| serialize(array.as_ref(), &mut self.buffer)))
+            .map(|maybe_array| {
+                maybe_array.map(|array| serialize(array.as_ref(), &mut self.buffer, self.options))
+            })
             .transpose()?;
         Ok(())
     }
@@ -32,7 +32,11 @@ pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
         lps.push(lp)
     }
 
-    let lp = DslPlan::Union { inputs: lps, args };
+    let lp = DslPlan::Union {
+        inputs: lps,
+        args,
+        partitioning: None,
+    };
     Ok(LazyFrame::from_inner(lp, opt_state, cached_arenas))
 }
 
@@ -72,6 +76,14 @@ pub fn concat<L: AsRef<[LazyFrame]>>(inputs: L, args: UnionArgs) -> PolarsResult
     concat_impl(inputs, args)
 }
 
+/// Resolve the schema of concatenating `inputs` with `args`, without executing the inputs.
+///
+/// For diagonal concatenation with `to_supertypes` this is the unified schema: every column name
+/// of the inputs in order of first appearance, with the supertype of all its input dtypes.
+pub fn concat_schema<L: AsRef<[LazyFrame]>>(inputs: L, args: UnionArgs) -> PolarsResult<SchemaRef> {
+    concat_impl(inputs, args)?.collect_schema()
+}
+
 #[cfg(test)]
 mod test {
     // used only if feature="diagonal_concat"
@@ -118,4 +130,46 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "diagonal_concat")]
+    fn test_diag_concat_relaxed_by_name() -> PolarsResult<()> {
+        let a = df![
+            "a" => [1i32, 2],
+            "b" => ["a", "b"]
+        ]?;
+        // Same columns in a different order and with a wider dtype.
+        let b = df![
+            "b" => ["c"],
+            "a" => [3.5f64]
+        ]?;
+        let c = df![
+            "c" => [1u8]
+        ]?;
+        let inputs = [a.lazy(), b.lazy(), c.lazy()];
+        let args = UnionArgs {
+            diagonal: true,
+            to_supertypes: true,
+            ..Default::default()
+        };
+
+        let schema = concat_schema(&inputs, args.clone())?;
+        assert_eq!(
+            schema.as_ref(),
+            &Schema::from_iter([
+                Field::new("a".into(), DataType::Float64),
+                Field::new("b".into(), DataType::String),
+                Field::new("c".into(), DataType::UInt8),
+            ])
+        );
+
+        let out = concat(&inputs, args)?.collect()?;
+        let expected = df![
+            "a" => [Some(1.0), Some(2.0), Some(3.5), None],
+            "b" => [Some("a"), Some("b"), Some("c"), None],
+            "c" => [None, None, None, Some(1u8)]
+        ]?;
+        assert!(out.equals_missing(&expected));
+        Ok(())
+    }
 }
@@ -0,0 +1,71 @@
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+impl LazyFrame {
+    /// Create `n_folds` expanding-window train/test splits over the values of `time_col`.
+    ///
+    /// The distinct non-null values of `time_col` are cut into `n_folds + 1` consecutive blocks.
+    /// Fold `i` tests on block `i + 1` and trains on all earlier blocks, except for the last `gap`
+    /// distinct values before the test block. Every split is a filter on this [`LazyFrame`], so
+    /// the folds can be collected together and share the work on the input.
+    pub fn time_series_folds(
+        self,
+        time_col: &str,
+        n_folds: usize,
+        gap: usize,
+    ) -> PolarsResult<Vec<(LazyFrame, LazyFrame)>> {
+        polars_ensure!(n_folds > 0, ComputeError: "`n_folds` must be positive");
+
+        // 0-based position of the value among the distinct values.
+        let position = col(time_col)
+            .rank(Default::default(), None)
+            .cast(DataType::Int64)
+            - lit(1i64);
+        let n_unique = col(time_col).drop_nulls().n_unique().cast(DataType::Int64);
+        let n_blocks = lit((n_folds + 1) as i64);
+        let block_start =
+            |block: usize| (lit(block as i64) * n_unique.clone()).floor_div(n_blocks.clone());
+
+        Ok((1..=n_folds)
+            .map(|test_block| {
+                let test_start = block_start(test_block);
+                let train = position.clone().lt(test_start.clone() - lit(gap as i64));
+                let test = position
+                    .clone()
+                    .gt_eq(test_start)
+                    .and(position.clone().lt(block_start(test_block + 1)));
+                (self.clone().filter(train), self.clone().filter(test))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_time_series_folds() -> PolarsResult<()> {
+        let df = df![
+            "t" => [Some(1i32), Some(1), Some(2), Some(3), Some(4), Some(5), None, Some(6)],
+            "v" => [0i32, 1, 2, 3, 4, 5, 6, 7],
+        ]?;
+
+        let folds = df.lazy().time_series_folds("t", 2, 1)?;
+        assert_eq!(folds.len(), 2);
+
+        let values = |lf: &LazyFrame| -> PolarsResult<Vec<i32>> {
+            let df = lf.clone().collect()?;
+            Ok(df.column("v")?.i32()?.into_no_null_iter().collect())
+        };
+        // Blocks of distinct values: [1, 2], [3, 4], [5, 6].
+        assert_eq!(values(&folds[0].0)?, [0, 1]);
+        assert_eq!(values(&folds[0].1)?, [3, 4]);
+        assert_eq!(values(&folds[1].0)?, [0, 1, 2, 3]);
+        assert_eq!(values(&folds[1].1)?, [5, 7]);
+        Ok(())
+    }
+}
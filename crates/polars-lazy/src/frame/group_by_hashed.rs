@@ -0,0 +1,109 @@
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+const LEFT_HASH: &str = "__POLARS_JOIN_HASH_LEFT";
+const RIGHT_HASH: &str = "__POLARS_JOIN_HASH_RIGHT";
+
+/// Check that `hash` is a `UInt64` expression on `lf`.
+fn ensure_hash_dtype(lf: &LazyFrame, hash: &Expr) -> PolarsResult<()> {
+    let schema = lf.clone().select([hash.clone()]).collect_schema()?;
+    let (name, dtype) = schema.get_at_index(0).unwrap();
+    polars_ensure!(
+        dtype == &DataType::UInt64,
+        SchemaMismatch: "the hash '{}' must be of dtype UInt64, got {}", name, dtype
+    );
+    Ok(())
+}
+
+impl LazyFrame {
+    /// Group on a precomputed `UInt64` hash of the keys `by`, instead of on the keys themselves.
+    ///
+    /// The hash is trusted: rows with the same hash are put in the same group without comparing
+    /// their keys, and the keys of a group are taken from its first row. This avoids encoding and
+    /// hashing the keys of every row when grouping repeatedly on the same expensive composite
+    /// key. The hash itself is not part of the output, so it must not have the name of a key.
+    pub fn group_by_hashed<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        hash: Expr,
+        by: E,
+        maintain_order: bool,
+    ) -> PolarsResult<LazyGroupBy> {
+        ensure_hash_dtype(&self, &hash)?;
+
+        let hashed_keys = by
+            .as_ref()
+            .iter()
+            .map(|e| e.clone().into())
+            .collect::<Vec<_>>();
+        polars_ensure!(
+            !hashed_keys.is_empty(),
+            InvalidOperation: "`group_by_hashed` needs at least one key"
+        );
+
+        let mut lgb = if maintain_order {
+            self.group_by_stable([hash])
+        } else {
+            self.group_by([hash])
+        };
+        lgb.hashed_keys = hashed_keys;
+        Ok(lgb)
+    }
+
+    /// Join with `other` on precomputed `UInt64` hashes of the join keys, instead of on the keys
+    /// themselves.
+    ///
+    /// The hashes are trusted: rows with equal hashes are joined without comparing their keys.
+    /// The hashes are only used for matching, the columns of both inputs are joined as with
+    /// [`LazyFrame::join`] with no key columns, so the keys of both sides are kept.
+    pub fn join_hashed(
+        self,
+        other: LazyFrame,
+        left_hash: Expr,
+        right_hash: Expr,
+        args: JoinArgs,
+    ) -> PolarsResult<LazyFrame> {
+        ensure_hash_dtype(&self, &left_hash)?;
+        ensure_hash_dtype(&other, &right_hash)?;
+
+        let left = self.with_column(left_hash.alias(LEFT_HASH));
+        let right = other.with_column(right_hash.alias(RIGHT_HASH));
+        Ok(left
+            .join(right, [col(LEFT_HASH)], [col(RIGHT_HASH)], args)
+            .drop(by_name([LEFT_HASH, RIGHT_HASH], false, false)))
+    }
+}
+
+impl LazyGroupBy {
+    pub(super) fn hashed_key_names(&self) -> Vec<PlSmallStr> {
+        self.hashed_keys
+            .iter()
+            .filter_map(|expr| expr_output_name(expr).ok())
+            .collect()
+    }
+
+    /// All the columns that are aggregated by [`head`](Self::head) and [`tail`](Self::tail).
+    pub(super) fn value_columns(&self) -> Expr {
+        if self.hashed_keys.is_empty() {
+            all().as_expr()
+        } else {
+            (all() - by_name(self.hashed_key_names(), false, false)).as_expr()
+        }
+    }
+
+    /// Aggregate the groups of [`LazyFrame::group_by_hashed`], taking the keys from the first
+    /// row of every group and dropping the hash.
+    pub(super) fn agg_hashed(mut self, aggs: &[Expr]) -> LazyFrame {
+        let hash_names = self
+            .keys
+            .iter()
+            .filter_map(|expr| expr_output_name(expr).ok())
+            .collect::<Vec<_>>();
+        let aggs = std::mem::take(&mut self.hashed_keys)
+            .into_iter()
+            .map(|key| key.first())
+            .chain(aggs.iter().cloned())
+            .collect::<Vec<_>>();
+        self.agg(aggs).drop(by_name(hash_names, true, false))
+    }
+}
@@ -0,0 +1,72 @@
+use polars_core::prelude::*;
+use polars_ops::series::{
+    INTERVAL_END_FIELD_NAME, INTERVAL_START_FIELD_NAME, interval_bound_dtype,
+};
+
+use crate::prelude::*;
+
+const LEFT_START: &str = "__POLARS_INTERVAL_LEFT_START";
+const LEFT_END: &str = "__POLARS_INTERVAL_LEFT_END";
+const RIGHT_START: &str = "__POLARS_INTERVAL_RIGHT_START";
+const RIGHT_END: &str = "__POLARS_INTERVAL_RIGHT_END";
+
+/// Add the bounds of the intervals `on` to `lf` as the `start` and `end` columns.
+///
+/// The intervals are rebuilt from their bounds, which raises if a `start` is after its `end`.
+fn with_bounds(
+    lf: LazyFrame,
+    on: Expr,
+    start: &'static str,
+    end: &'static str,
+) -> PolarsResult<LazyFrame> {
+    let mut intervals = lf.clone().select([on.clone()]);
+    let schema = intervals.collect_schema()?;
+    interval_bound_dtype(schema.get_at_index(0).unwrap().1)?;
+
+    let on = polars_plan::dsl::functions::interval(
+        on.clone()
+            .struct_()
+            .field_by_name(INTERVAL_START_FIELD_NAME),
+        on.struct_().field_by_name(INTERVAL_END_FIELD_NAME),
+    );
+    Ok(lf.with_columns([
+        on.clone()
+            .struct_()
+            .field_by_name(INTERVAL_START_FIELD_NAME)
+            .alias(start),
+        on.struct_()
+            .field_by_name(INTERVAL_END_FIELD_NAME)
+            .alias(end),
+    ]))
+}
+
+impl LazyFrame {
+    /// Join the rows of `other` of which the interval `right_on` overlaps the interval `left_on`
+    /// of the rows of this frame.
+    ///
+    /// Both `left_on` and `right_on` evaluate to intervals, see
+    /// [`interval`](polars_plan::dsl::functions::interval). Rows with a null interval are never
+    /// joined. The columns of `other` that already exist in this frame get the `suffix`.
+    pub fn interval_join(
+        self,
+        other: LazyFrame,
+        left_on: Expr,
+        right_on: Expr,
+        suffix: Option<PlSmallStr>,
+    ) -> PolarsResult<LazyFrame> {
+        let left = with_bounds(self, left_on, LEFT_START, LEFT_END)?;
+        let right = with_bounds(other, right_on, RIGHT_START, RIGHT_END)?;
+
+        let mut builder = left.join_builder().with(right);
+        if let Some(suffix) = suffix {
+            builder = builder.suffix(suffix);
+        }
+
+        Ok(builder
+            .join_where(vec![
+                col(LEFT_START).lt(col(RIGHT_END)),
+                col(RIGHT_START).lt(col(LEFT_END)),
+            ])
+            .drop(cols([LEFT_START, LEFT_END, RIGHT_START, RIGHT_END])))
+    }
+}
@@ -6,6 +6,17 @@ mod cached_arenas;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+#[cfg(feature = "rank")]
+mod folds;
+mod group_by_hashed;
+#[cfg(feature = "interval")]
+mod interval_join;
+mod partitioned;
+#[cfg(feature = "plan_cache")]
+mod plan_cache;
+mod scd2;
+mod schedule;
+mod update;
 
 use std::num::NonZeroUsize;
 use std::sync::mpsc::{Receiver, sync_channel};
@@ -21,6 +32,9 @@ pub use file_list_reader::*;
 pub use ndjson::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
+pub use partitioned::PartitionedFrame;
+#[cfg(feature = "plan_cache")]
+pub use plan_cache::{PlanCacheStats, clear_plan_cache, plan_cache_stats, set_plan_cache_capacity};
 use polars_compute::rolling::QuantileMethod;
 use polars_core::error::feature_gated;
 #[cfg(feature = "pivot")]
@@ -29,12 +43,21 @@ use polars_core::prelude::*;
 use polars_core::query_result::QueryResult;
 use polars_io::RowIndex;
 use polars_mem_engine::scan_predicate::functions::apply_scan_predicate_to_scan_ir;
-use polars_mem_engine::{Executor, create_multiple_physical_plans, create_physical_plan};
+use polars_mem_engine::{
+    Executor, NodeRowCounts, ReplayRecorder, create_multiple_physical_plans, create_physical_plan,
+    create_physical_plan_with_recorder, create_physical_plan_with_row_counts,
+};
 use polars_ops::frame::{JoinBuildSide, JoinCoalesce, MaintainOrderJoin};
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
 pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
+use polars_plan::plans::audit::{AuditRecord, get_audit_sink};
+#[cfg(feature = "random")]
+use polars_plan::plans::privacy::spend_privacy_budget;
 use polars_utils::pl_str::PlSmallStr;
+pub use scd2::Scd2Options;
+pub use schedule::CollectAllOptions;
+pub use update::{UpdateOptions, UpdateStrategy};
 
 use crate::frame::cached_arenas::CachedArena;
 use crate::prelude::*;
@@ -83,6 +106,42 @@ impl From<DslPlan> for LazyFrame {
     }
 }
 
+impl From<LazyFrame> for DslPlan {
+    fn from(lf: LazyFrame) -> Self {
+        lf.logical_plan
+    }
+}
+
+/// Set the resolved user field metadata on the collected frames.
+fn with_field_metadata(result: QueryResult, field_metadata: &[FieldMetadataMap]) -> QueryResult {
+    match result {
+        QueryResult::Single(mut df) => {
+            if let Some(metadata) = field_metadata.first() {
+                df.set_field_metadata(metadata);
+            }
+            QueryResult::Single(df)
+        },
+        QueryResult::Multiple(mut dfs) => {
+            for (df, metadata) in dfs.iter_mut().zip(field_metadata) {
+                df.set_field_metadata(metadata);
+            }
+            QueryResult::Multiple(dfs)
+        },
+    }
+}
+
+/// Spend the privacy cost of the optimized plan of a query, unless the query already spent it on
+/// an engine it fell back from.
+#[cfg_attr(not(feature = "random"), allow(unused_variables))]
+fn spend_privacy_budget_once(ir_plan: &IRPlan, spent: &mut bool) -> PolarsResult<()> {
+    #[cfg(feature = "random")]
+    if !*spent {
+        spend_privacy_budget(ir_plan.lp_top, &ir_plan.lp_arena, &ir_plan.expr_arena)?;
+        *spent = true;
+    }
+    Ok(())
+}
+
 impl LazyFrame {
     pub(crate) fn from_inner(
         logical_plan: DslPlan,
@@ -461,6 +520,32 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Bind the [`param`]s of the plan to literal values.
+    ///
+    /// The plan can be built once and bound to different values for every run. Parameters that
+    /// are not in `params` stay unbound. With the plan cache enabled (see
+    /// `set_plan_cache_capacity`), the bound plans reuse the same optimized plan.
+    pub fn bind_parameters(self, params: &PlHashMap<PlSmallStr, LiteralValue>) -> LazyFrame {
+        let opt_state = self.get_opt_state();
+        let lp = self.logical_plan.bind_parameters(params);
+        Self::from_logical_plan(lp, opt_state)
+    }
+
+    /// Restrict every scan of the plan to the rows and values that `principal` may see under
+    /// `policy`, see [`AccessPolicy`].
+    ///
+    /// This is applied before optimization, so the row filters and column masks are optimized
+    /// with the rest of the query.
+    pub fn with_access_policy(
+        self,
+        policy: &dyn AccessPolicy,
+        principal: &Principal,
+    ) -> PolarsResult<LazyFrame> {
+        let opt_state = self.get_opt_state();
+        let lp = self.logical_plan.apply_access_policy(policy, principal)?;
+        Ok(Self::from_logical_plan(lp, opt_state))
+    }
+
     /// Cast named frame columns, resulting in a new LazyFrame with updated dtypes
     pub fn cast(self, dtypes: PlHashMap<&str, DataType>, strict: bool) -> Self {
         let cast_cols: Vec<Expr> = dtypes
@@ -525,6 +610,11 @@ impl LazyFrame {
         expr_arena: &mut Arena<AExpr>,
         scratch: &mut Vec<Node>,
     ) -> PolarsResult<Node> {
+        #[cfg(feature = "plan_cache")]
+        if plan_cache::plan_cache_enabled() {
+            return Ok(self.optimize_cached(lp_arena, expr_arena, scratch)?.0);
+        }
+
         let lp_top = optimize(
             self.logical_plan,
             self.opt_state,
@@ -564,6 +654,8 @@ impl LazyFrame {
             // query was started as its "base" timepoint.
             query_start.map(|s| s.elapsed()),
         )?;
+        #[cfg(feature = "random")]
+        spend_privacy_budget(lp_top, &lp_arena, &expr_arena)?;
 
         // sink should be replaced
         let no_file_sink = if check_sink {
@@ -616,7 +708,29 @@ impl LazyFrame {
     /// `engine`.
     ///
     /// The query is optimized prior to execution.
-    pub fn collect_with_engine(mut self, engine: Engine) -> PolarsResult<QueryResult> {
+    pub fn collect_with_engine(self, engine: Engine) -> PolarsResult<QueryResult> {
+        self.collect_with_engine_impl(engine, None)
+    }
+
+    /// Execute all the lazy operations and collect them into a [`DataFrame`] using a specified
+    /// `engine`, with the streaming engine sizing the morsels by `morsel_sizing` instead of the
+    /// config.
+    ///
+    /// The query is optimized prior to execution.
+    pub fn collect_with_morsel_sizing(
+        self,
+        engine: Engine,
+        morsel_sizing: MorselSizing,
+    ) -> PolarsResult<QueryResult> {
+        self.collect_with_engine_impl(engine, Some(morsel_sizing))
+    }
+
+    #[cfg_attr(not(feature = "new_streaming"), allow(unused_variables))]
+    fn collect_with_engine_impl(
+        mut self,
+        engine: Engine,
+        morsel_sizing: Option<MorselSizing>,
+    ) -> PolarsResult<QueryResult> {
         let engine = match engine {
             Engine::Streaming => Engine::Streaming,
             _ if std::env::var("POLARS_FORCE_NEW_STREAMING").as_deref() == Ok("1") => {
@@ -626,11 +740,17 @@ impl LazyFrame {
             v => v,
         };
 
+        // The privacy budget is spent once per query, also if the streaming engine falls back to
+        // the in-memory engine.
+        let mut privacy_spent = false;
         if engine != Engine::Streaming
             && std::env::var("POLARS_AUTO_NEW_STREAMING").as_deref() == Ok("1")
         {
             feature_gated!("new_streaming", {
-                if let Some(r) = self.clone()._collect_with_streaming_suppress_todo_panic() {
+                if let Some(r) = self
+                    .clone()
+                    ._collect_with_streaming_suppress_todo_panic(&mut privacy_spent)
+                {
                     return r;
                 }
             })
@@ -640,17 +760,35 @@ impl LazyFrame {
             feature_gated!("new_streaming", self = self.with_new_streaming(true))
         }
 
+        #[cfg(feature = "plan_cache")]
+        let (mut ir_plan, _cached_plan) = self.to_alp_optimized_cached()?;
+        #[cfg(not(feature = "plan_cache"))]
         let mut ir_plan = self.to_alp_optimized()?;
 
         ir_plan.ensure_root_node_is_sink();
+        let field_metadata = ir_plan.resolve_sink_field_metadata()?;
+        spend_privacy_budget_once(&ir_plan, &mut privacy_spent)?;
+        let audit = get_audit_sink().map(|sink| {
+            (
+                sink,
+                AuditRecord::from_plan(ir_plan.lp_top, &ir_plan.lp_arena),
+            )
+        });
 
-        match engine {
+        let result = match engine {
             Engine::Streaming => feature_gated!("new_streaming", {
-                polars_stream::run_query(
+                #[cfg(feature = "plan_cache")]
+                let query = plan_cache::build_streaming_query(&mut ir_plan, _cached_plan);
+                #[cfg(not(feature = "plan_cache"))]
+                let query = polars_stream::StreamingQuery::build(
                     ir_plan.lp_top,
                     &mut ir_plan.lp_arena,
                     &mut ir_plan.expr_arena,
-                )
+                );
+                query.and_then(|query| match morsel_sizing {
+                    Some(morsel_sizing) => query.with_morsel_sizing(morsel_sizing).execute(),
+                    None => query.execute(),
+                })
             }),
             Engine::InMemory | Engine::Gpu => {
                 if let IR::SinkMultiple { inputs } = ir_plan.root() {
@@ -660,27 +798,32 @@ impl LazyFrame {
                         "collect_all is not supported for the gpu engine"
                     );
 
-                    return create_multiple_physical_plans(
+                    create_multiple_physical_plans(
                         inputs.clone().as_slice(),
                         &mut ir_plan.lp_arena,
                         &mut ir_plan.expr_arena,
                         BUILD_STREAMING_EXECUTOR,
                     )?
                     .execute()
-                    .map(QueryResult::Multiple);
+                    .map(QueryResult::Multiple)
+                } else {
+                    let mut physical_plan = create_physical_plan(
+                        ir_plan.lp_top,
+                        &mut ir_plan.lp_arena,
+                        &mut ir_plan.expr_arena,
+                        BUILD_STREAMING_EXECUTOR,
+                    )?;
+                    let mut state = ExecutionState::new();
+                    physical_plan.execute(&mut state).map(QueryResult::Single)
                 }
-
-                let mut physical_plan = create_physical_plan(
-                    ir_plan.lp_top,
-                    &mut ir_plan.lp_arena,
-                    &mut ir_plan.expr_arena,
-                    BUILD_STREAMING_EXECUTOR,
-                )?;
-                let mut state = ExecutionState::new();
-                physical_plan.execute(&mut state).map(QueryResult::Single)
             },
             Engine::Auto => unreachable!(),
+        };
+        if let Some((sink, mut record)) = audit {
+            record.error = result.as_ref().err().map(|e| e.to_string().into());
+            sink.record(record);
         }
+        result.map(|result| with_field_metadata(result, &field_metadata))
     }
 
     pub fn explain_all(plans: Vec<DslPlan>, opt_state: OptFlags) -> PolarsResult<String> {
@@ -735,6 +878,74 @@ impl LazyFrame {
         })
     }
 
+    /// Resolve the user metadata of the output columns without running the query.
+    ///
+    /// See [`polars_plan::plans::field_metadata`] for how the metadata propagates through the
+    /// query.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> PolarsResult<()> {
+    ///     let metadata = [("unit".into(), "m/s".into())].into_iter().collect();
+    ///     let field_metadata = df
+    ///         .lazy()
+    ///         .select([col("speed").with_field_metadata(metadata).alias("v")])
+    ///         .collect_field_metadata()?;
+    ///     assert_eq!(field_metadata["v"]["unit"].as_str(), "m/s");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn collect_field_metadata(self) -> PolarsResult<FieldMetadataMap> {
+        let ir_plan = self.to_alp_optimized()?;
+        polars_plan::plans::field_metadata::resolve_field_metadata(
+            ir_plan.lp_top,
+            &ir_plan.lp_arena,
+            &ir_plan.expr_arena,
+        )
+    }
+
+    /// Report which files and row groups the scans of the query skip based on its predicates,
+    /// without running the query.
+    ///
+    /// The report holds a row per file of every scan, with whether the file is skipped and why,
+    /// and for Parquet files the row groups that are skipped based on their statistics. See
+    /// [`scan_pruning_report`](polars_mem_engine::scan_predicate::pruning_report::scan_pruning_report)
+    /// for the columns of the report.
+    pub fn scan_pruning_report(mut self) -> PolarsResult<DataFrame> {
+        let (mut lp_arena, mut expr_arena) = self.get_arenas();
+        let lp_top = optimize(
+            self.logical_plan,
+            self.opt_state,
+            &mut lp_arena,
+            &mut expr_arena,
+            &mut vec![],
+            // Keep all files in the scans, the report applies the scan predicates itself.
+            |_, _, _| Ok(()),
+        )?;
+
+        polars_mem_engine::scan_predicate::pruning_report::scan_pruning_report(
+            lp_top,
+            &lp_arena,
+            &mut expr_arena,
+        )
+    }
+
+    /// The total `epsilon` of the differential privacy budget that running the query spends,
+    /// without running the query, see [`polars_plan::plans::privacy`].
+    #[cfg(feature = "random")]
+    pub fn privacy_cost(self) -> PolarsResult<f64> {
+        let ir_plan = self.to_alp_optimized()?;
+        polars_plan::plans::privacy::privacy_cost(
+            ir_plan.lp_top,
+            &ir_plan.lp_arena,
+            &ir_plan.expr_arena,
+        )
+    }
+
     /// Collect the query in batches.
     ///
     /// If lazy is true the query will not start until the first poll (or until
@@ -809,6 +1020,85 @@ impl LazyFrame {
         self._profile_post_opt(|_, _, _, _| Ok(()))
     }
 
+    /// Run the query and describe the optimized plan in tree format, with the actual number of
+    /// rows of every node next to the estimate of [`LazyFrame::describe_optimized_plan_tree`].
+    ///
+    /// Nodes whose actual number of rows differs from the estimate by more than a factor 10 are
+    /// flagged with `(!)`. The query is run on the in-memory engine.
+    pub fn explain_analyze(mut self) -> PolarsResult<String> {
+        let (mut lp_arena, mut expr_arena) = self.get_arenas();
+        let lp_top = self.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut vec![])?;
+        #[cfg(feature = "random")]
+        spend_privacy_budget(lp_top, &lp_arena, &expr_arena)?;
+
+        // Creating the physical plan takes the nodes out of the arena.
+        let plan = IRPlan::new(lp_top, lp_arena.clone(), expr_arena.clone());
+        let row_counts = NodeRowCounts::default();
+        let mut physical_plan = create_physical_plan_with_row_counts(
+            lp_top,
+            &mut lp_arena,
+            &mut expr_arena,
+            BUILD_STREAMING_EXECUTOR,
+            row_counts.clone(),
+        )?;
+        physical_plan.execute(&mut ExecutionState::new())?;
+
+        let row_counts = row_counts.lock().unwrap();
+        Ok(plan.as_ref().describe_tree_format_with_rows(&row_counts))
+    }
+
+    /// Run the query and, if a node fails, record it with the outputs of its inputs in a
+    /// [`ReplayBundle`] that can be run in isolation with [`LazyFrame::replay`].
+    ///
+    /// The outputs of the nodes are kept until the node that consumes them succeeds, up to
+    /// `max_bytes` in total. Returns `None` if the query succeeds or if the outputs of the inputs
+    /// of the failing node exceeded `max_bytes`. The query is run on the in-memory engine.
+    pub fn record_failure(mut self, max_bytes: usize) -> PolarsResult<Option<ReplayBundle>> {
+        let (mut lp_arena, mut expr_arena) = self.get_arenas();
+        let lp_top = self.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut vec![])?;
+        #[cfg(feature = "random")]
+        spend_privacy_budget(lp_top, &lp_arena, &expr_arena)?;
+
+        // Creating the physical plan takes the nodes out of the arena.
+        let plan = IRPlan::new(lp_top, lp_arena.clone(), expr_arena.clone());
+        let recorder = Arc::new(ReplayRecorder::new(max_bytes));
+        let mut physical_plan = create_physical_plan_with_recorder(
+            lp_top,
+            &mut lp_arena,
+            &mut expr_arena,
+            BUILD_STREAMING_EXECUTOR,
+            recorder.clone(),
+        )?;
+        let Err(err) = physical_plan.execute(&mut ExecutionState::new()) else {
+            return Ok(None);
+        };
+
+        Ok(recorder.take_failure().map(|failure| {
+            ReplayBundle::new(
+                failure.node,
+                &plan.lp_arena,
+                &plan.expr_arena,
+                failure.inputs,
+                &err,
+            )
+        }))
+    }
+
+    /// Run the failing node of a [`ReplayBundle`] on the recorded outputs of its inputs.
+    pub fn replay(bundle: &ReplayBundle) -> PolarsResult<DataFrame> {
+        let mut lp_arena = bundle.plan.lp_arena.clone();
+        let mut expr_arena = bundle.plan.expr_arena.clone();
+        #[cfg(feature = "random")]
+        spend_privacy_budget(bundle.plan.lp_top, &lp_arena, &expr_arena)?;
+        let mut physical_plan = create_physical_plan(
+            bundle.plan.lp_top,
+            &mut lp_arena,
+            &mut expr_arena,
+            BUILD_STREAMING_EXECUTOR,
+        )?;
+        physical_plan.execute(&mut ExecutionState::new())
+    }
+
     pub fn sink_batches(
         mut self,
         function: PlanCallback<DataFrame, bool>,
@@ -838,6 +1128,7 @@ impl LazyFrame {
     #[cfg(feature = "new_streaming")]
     fn _collect_with_streaming_suppress_todo_panic(
         mut self,
+        privacy_spent: &mut bool,
     ) -> Option<PolarsResult<polars_core::query_result::QueryResult>> {
         self.opt_state |= OptFlags::NEW_STREAMING;
         let mut ir_plan = match self.to_alp_optimized() {
@@ -846,6 +1137,13 @@ impl LazyFrame {
         };
 
         ir_plan.ensure_root_node_is_sink();
+        let field_metadata = match ir_plan.resolve_sink_field_metadata() {
+            Ok(v) => v,
+            Err(e) => return Some(Err(e)),
+        };
+        if let Err(e) = spend_privacy_budget_once(&ir_plan, privacy_spent) {
+            return Some(Err(e));
+        }
 
         let f = || {
             polars_stream::run_query(
@@ -856,7 +1154,7 @@ impl LazyFrame {
         };
 
         match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
-            Ok(v) => Some(v),
+            Ok(v) => Some(v.map(|result| with_field_metadata(result, &field_metadata))),
             Err(e) => {
                 // Fallback to normal engine if error is due to not being implemented
                 // and auto_new_streaming is set, otherwise propagate error.
@@ -1049,6 +1347,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 predicates: vec![],
+                hashed_keys: vec![],
                 maintain_order: false,
                 dynamic_options: None,
                 rolling_options: None,
@@ -1062,6 +1361,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 predicates: vec![],
+                hashed_keys: vec![],
                 maintain_order: false,
             }
         }
@@ -1098,6 +1398,7 @@ impl LazyFrame {
             logical_plan: self.logical_plan,
             opt_state,
             predicates: vec![],
+            hashed_keys: vec![],
             keys: group_by.as_ref().to_vec(),
             maintain_order: true,
             dynamic_options: None,
@@ -1144,6 +1445,7 @@ impl LazyFrame {
             logical_plan: self.logical_plan,
             opt_state,
             predicates: vec![],
+            hashed_keys: vec![],
             keys: group_by.as_ref().to_vec(),
             maintain_order: true,
             dynamic_options: Some(options),
@@ -1167,6 +1469,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 predicates: vec![],
+                hashed_keys: vec![],
                 maintain_order: true,
                 dynamic_options: None,
                 rolling_options: None,
@@ -1180,6 +1483,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 predicates: vec![],
+                hashed_keys: vec![],
                 maintain_order: true,
             }
         }
@@ -1964,6 +2268,9 @@ pub struct LazyGroupBy {
     opt_state: OptFlags,
     keys: Vec<Expr>,
     predicates: Vec<Expr>,
+    /// Keys that are taken from the first row of every group, see
+    /// [`LazyFrame::group_by_hashed`].
+    hashed_keys: Vec<Expr>,
     maintain_order: bool,
     #[cfg(feature = "dynamic_group_by")]
     dynamic_options: Option<DynamicGroupOptions>,
@@ -2029,6 +2336,10 @@ impl LazyGroupBy {
     /// }
     /// ```
     pub fn agg<E: AsRef<[Expr]>>(self, aggs: E) -> LazyFrame {
+        if !self.hashed_keys.is_empty() {
+            return self.agg_hashed(aggs.as_ref());
+        }
+
         #[cfg(feature = "dynamic_group_by")]
         let lp = DslBuilder::from(self.logical_plan)
             .group_by(
@@ -2054,10 +2365,12 @@ impl LazyGroupBy {
         let keys = self
             .keys
             .iter()
+            .chain(&self.hashed_keys)
             .filter_map(|expr| expr_output_name(expr).ok())
             .collect::<Vec<_>>();
+        let values = self.value_columns();
 
-        self.agg([all().as_expr().head(n)]).explode_impl(
+        self.agg([values.head(n)]).explode_impl(
             all() - by_name(keys.iter().cloned(), false, false),
             ExplodeOptions {
                 empty_as_null: true,
@@ -2072,10 +2385,12 @@ impl LazyGroupBy {
         let keys = self
             .keys
             .iter()
+            .chain(&self.hashed_keys)
             .filter_map(|expr| expr_output_name(expr).ok())
             .collect::<Vec<_>>();
+        let values = self.value_columns();
 
-        self.agg([all().as_expr().tail(n)]).explode_impl(
+        self.agg([values.tail(n)]).explode_impl(
             all() - by_name(keys.iter().cloned(), false, false),
             ExplodeOptions {
                 empty_as_null: true,
@@ -0,0 +1,213 @@
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_ops::frame::JoinCoalesce;
+use polars_plan::dsl::HashPartitioning;
+use rayon::prelude::*;
+
+use crate::prelude::*;
+
+/// A [`DataFrame`] that is kept physically partitioned by the hash of its key columns.
+///
+/// All the rows with the same keys are in the same partition, so a group by on the keys, or a
+/// join on the keys with a frame that is partitioned in the same way, is done per partition and
+/// in parallel, without shuffling the rows again. The keys are hashed with a fixed seed, so two
+/// frames with the same number of partitions and keys of the same dtypes are partitioned in the
+/// same way.
+///
+/// The [`LazyFrame`] of a partitioned frame is a union that carries its partitioning (see
+/// [`HashPartitioning`]), so the optimizer only reads the partition selected by an equality filter
+/// on the keys, and runs group bys on the keys and joins with a frame partitioned in the same way
+/// per partition.
+#[derive(Clone, Debug)]
+pub struct PartitionedFrame {
+    keys: Vec<PlSmallStr>,
+    partitions: Vec<DataFrame>,
+}
+
+impl PartitionedFrame {
+    /// Partition `df` by the hash of the `keys` columns, with one partition per thread.
+    pub fn new(df: &DataFrame, keys: Vec<PlSmallStr>) -> PolarsResult<Self> {
+        Self::with_n_partitions(df, keys, POOL.current_num_threads())
+    }
+
+    /// Partition `df` by the hash of the `keys` columns into `n_partitions` partitions. The
+    /// partitions keep the order of the rows of `df`.
+    pub fn with_n_partitions(
+        df: &DataFrame,
+        keys: Vec<PlSmallStr>,
+        n_partitions: usize,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            !keys.is_empty(),
+            InvalidOperation: "a partitioned frame needs at least one key"
+        );
+        polars_ensure!(
+            n_partitions > 0,
+            InvalidOperation: "a partitioned frame needs at least one partition"
+        );
+
+        let partitions =
+            HashPartitioning::partition_idxs(&df.select(keys.iter().cloned())?, n_partitions)?;
+        let mut partition_idx = vec![Vec::new(); n_partitions];
+        for (idx, partition) in partitions.into_iter().enumerate() {
+            partition_idx[partition].push(idx as IdxSize);
+        }
+        let partitions = POOL.install(|| {
+            partition_idx
+                .into_par_iter()
+                .map(|idx| {
+                    let idx = IdxCa::from_vec(PlSmallStr::EMPTY, idx);
+                    // SAFETY: the indices are in bounds.
+                    unsafe { df.take_unchecked(&idx) }
+                })
+                .collect()
+        });
+
+        Ok(Self { keys, partitions })
+    }
+
+    pub fn keys(&self) -> &[PlSmallStr] {
+        &self.keys
+    }
+
+    pub fn partitions(&self) -> &[DataFrame] {
+        &self.partitions
+    }
+
+    pub fn n_partitions(&self) -> usize {
+        self.partitions.len()
+    }
+
+    pub fn schema(&self) -> &SchemaRef {
+        self.partitions[0].schema()
+    }
+
+    pub fn height(&self) -> usize {
+        self.partitions.iter().map(|df| df.height()).sum()
+    }
+
+    /// Concatenate the partitions into a single [`DataFrame`], ordered by partition.
+    pub fn into_df(self) -> DataFrame {
+        accumulate_dataframes_vertical_unchecked(self.partitions)
+    }
+
+    /// Get a [`LazyFrame`] of the union of the partitions, which are processed in parallel.
+    ///
+    /// The union is partitioned by the keys, which the optimizer uses to prune partitions and to
+    /// run group bys and joins on the keys per partition.
+    pub fn lazy(&self) -> PolarsResult<LazyFrame> {
+        let inputs = self
+            .partitions
+            .iter()
+            .map(|df| df.clone().lazy())
+            .collect::<Vec<_>>();
+        let mut lf = concat(inputs, UnionArgs::default())?;
+        let DslPlan::Union { partitioning, .. } = &mut lf.logical_plan else {
+            unreachable!()
+        };
+        *partitioning = Some(HashPartitioning::new(self.keys.as_slice()));
+        Ok(lf)
+    }
+
+    /// Apply `f` to the [`LazyFrame`] of every partition, and get the union of the results.
+    ///
+    /// As the partitions are processed separately, `f` must only combine rows with the same
+    /// keys, like a group by on (a superset of) the keys does. The result is not partitioned, as
+    /// `f` may change the keys; use [`try_map_partitions`](Self::try_map_partitions) to keep the
+    /// partitioning.
+    pub fn map_partitions<F>(&self, f: F) -> PolarsResult<LazyFrame>
+    where
+        F: Fn(LazyFrame) -> LazyFrame,
+    {
+        let inputs = self
+            .partitions
+            .iter()
+            .map(|df| f(df.clone().lazy()))
+            .collect::<Vec<_>>();
+        concat(inputs, UnionArgs::default())
+    }
+
+    /// Apply `f` to the [`LazyFrame`] of every partition and collect the results in parallel,
+    /// keeping them partitioned by the same keys.
+    ///
+    /// `f` must keep the key columns and only combine rows with the same keys.
+    pub fn try_map_partitions<F>(&self, f: F) -> PolarsResult<Self>
+    where
+        F: Fn(LazyFrame) -> LazyFrame + Send + Sync,
+    {
+        let partitions = POOL.install(|| {
+            self.partitions
+                .par_iter()
+                .map(|df| f(df.clone().lazy()).collect())
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        // The partitioning only holds if every partition keeps its keys unchanged.
+        for df in &partitions {
+            for key in &self.keys {
+                let Some(dtype) = df.schema().get(key) else {
+                    polars_bail!(ColumnNotFound: "key '{}' of the partitioned frame was removed", key);
+                };
+                let expected = self.schema().try_get(key)?;
+                polars_ensure!(
+                    dtype == expected,
+                    SchemaMismatch: "key '{}' of the partitioned frame changed dtype from {} to {}",
+                    key, expected, dtype
+                );
+            }
+        }
+
+        Ok(Self {
+            keys: self.keys.clone(),
+            partitions,
+        })
+    }
+
+    /// Group by the keys and aggregate, per partition.
+    pub fn group_by_agg<E: AsRef<[Expr]>>(&self, aggs: E) -> PolarsResult<LazyFrame> {
+        Ok(self.lazy()?.group_by(self.key_exprs()).agg(aggs))
+    }
+
+    /// Join with `other` on the keys of both frames, per partition. The frames must have the
+    /// same number of partitions and keys of the same dtypes. The key columns are coalesced.
+    pub fn join(&self, other: &PartitionedFrame, how: JoinType) -> PolarsResult<LazyFrame> {
+        polars_ensure!(
+            how.is_equi() || how.is_semi_anti(),
+            InvalidOperation: "cannot do a {} join on partitioned frames", how
+        );
+        polars_ensure!(
+            self.n_partitions() == other.n_partitions(),
+            InvalidOperation: "cannot join partitioned frames with {} and {} partitions",
+            self.n_partitions(), other.n_partitions()
+        );
+        polars_ensure!(
+            self.keys.len() == other.keys.len(),
+            InvalidOperation: "cannot join partitioned frames with {} and {} keys",
+            self.keys.len(), other.keys.len()
+        );
+        for (left, right) in self.keys.iter().zip(&other.keys) {
+            let left_dtype = self.schema().try_get(left)?;
+            let right_dtype = other.schema().try_get(right)?;
+            polars_ensure!(
+                left_dtype == right_dtype,
+                SchemaMismatch: "cannot join partitioned frames on keys '{}' of dtype {} and '{}' of dtype {}",
+                left, left_dtype, right, right_dtype
+            );
+        }
+
+        let left_on = self.key_exprs();
+        let right_on = other.key_exprs();
+        let args = JoinArgs::new(how).with_coalesce(JoinCoalesce::CoalesceColumns);
+        Ok(self.lazy()?.join(other.lazy()?, left_on, right_on, args))
+    }
+
+    fn key_exprs(&self) -> Vec<Expr> {
+        self.keys.iter().map(|key| col(key.clone())).collect()
+    }
+}
+
+impl From<PartitionedFrame> for DataFrame {
+    fn from(frame: PartitionedFrame) -> Self {
+        frame.into_df()
+    }
+}
@@ -0,0 +1,415 @@
+//! A global cache of optimized plans, keyed by the shape of the plan before optimization.
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{LazyLock, Mutex};
+
+use polars_plan::plans::{PlanShape, literal_nodes, plan_shape};
+
+use super::*;
+
+static PLAN_CACHE: LazyLock<Mutex<PlanCache>> = LazyLock::new(Default::default);
+// Kept outside of the mutex so that a disabled cache doesn't take the lock.
+static PLAN_CACHE_CAPACITY: AtomicUsize = AtomicUsize::new(0);
+
+/// Statistics of the plan cache, see [`plan_cache_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PlanCacheStats {
+    /// The number of queries that reused an optimized plan.
+    pub hits: u64,
+    /// The number of queries that had to be optimized.
+    pub misses: u64,
+    /// The number of cached plans.
+    pub len: usize,
+    /// The maximum number of cached plans.
+    pub capacity: usize,
+}
+
+/// Set the maximum number of optimized plans to cache. The cache is disabled with a capacity
+/// of 0, which is the default.
+///
+/// With the cache enabled, a query whose plan has the same shape as a cached plan (it only
+/// differs in the values of its literals) skips the optimizer: the cached plan is reused, with
+/// the new values of the literals substituted in where that is safe. This is useful when the
+/// same query is run many times with different parameters.
+///
+/// The streaming engine also reuses the physical plan of a query whose literals are the same as
+/// those of the cached plan. The executors of the in-memory engine hold state, so they are still
+/// built for every run. Cached plans keep the in-memory frames they scan alive until they are
+/// evicted, or the cache is cleared.
+pub fn set_plan_cache_capacity(capacity: usize) {
+    let mut cache = PLAN_CACHE.lock().unwrap();
+    PLAN_CACHE_CAPACITY.store(capacity, Ordering::Relaxed);
+    cache.capacity = capacity;
+    cache.evict();
+}
+
+/// Get the statistics of the plan cache.
+pub fn plan_cache_stats() -> PlanCacheStats {
+    let cache = PLAN_CACHE.lock().unwrap();
+    PlanCacheStats {
+        hits: cache.hits,
+        misses: cache.misses,
+        len: cache.len,
+        capacity: cache.capacity,
+    }
+}
+
+/// Remove all the plans from the plan cache and reset its statistics.
+pub fn clear_plan_cache() {
+    let mut cache = PLAN_CACHE.lock().unwrap();
+    *cache = PlanCache {
+        capacity: cache.capacity,
+        ..Default::default()
+    };
+}
+
+pub(super) fn plan_cache_enabled() -> bool {
+    PLAN_CACHE_CAPACITY.load(Ordering::Relaxed) > 0
+}
+
+/// Identifies a plan in the plan cache.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) struct CachedPlanId(u64);
+
+#[derive(Default)]
+struct PlanCache {
+    capacity: usize,
+    plans: PlHashMap<u64, Vec<CachedPlan>>,
+    len: usize,
+    clock: u64,
+    next_id: u64,
+    hits: u64,
+    misses: u64,
+}
+
+struct CachedPlan {
+    id: CachedPlanId,
+    /// The normalized shape of the plan before optimization, see [`PlanShape::normalized`].
+    shape: Vec<u8>,
+    opt_flags: OptFlags,
+    /// The literals of the plan before optimization.
+    literals: Vec<LiteralValue>,
+    lp_top: Node,
+    lp_arena: Arena<IR>,
+    expr_arena: Arena<AExpr>,
+    /// For every distinct literal value, the nodes that hold it in the optimized plan. `None`
+    /// if the optimized plan is only valid for these exact literals.
+    slots: Option<Vec<(LiteralValue, Vec<Node>)>>,
+    /// The physical plan of the streaming engine, with the expressions it was lowered into.
+    #[cfg(feature = "new_streaming")]
+    lowered: Option<(polars_stream::LoweredQuery, Arena<AExpr>)>,
+    last_used: u64,
+}
+
+impl PlanCache {
+    /// Get the cached plan for a query of the given `shape`. The id is only returned if the
+    /// plan is reused with the literals it was optimized with.
+    fn get(
+        &mut self,
+        key: u64,
+        shape: &PlanShape,
+        opt_flags: OptFlags,
+    ) -> Option<(IRPlan, Option<CachedPlanId>)> {
+        self.clock += 1;
+        let clock = self.clock;
+        let plan = self.plans.get_mut(&key).and_then(|plans| {
+            // Plans with equal keys may still differ in their shapes, if their hashes collide.
+            let same_shape = plans
+                .iter_mut()
+                .filter(|p| p.opt_flags.bits() == opt_flags.bits() && p.shape == shape.normalized);
+            let mut candidates = vec![];
+            for cached in same_shape {
+                if cached.literals == shape.literals {
+                    cached.last_used = clock;
+                    let plan = IRPlan::new(
+                        cached.lp_top,
+                        cached.lp_arena.clone(),
+                        cached.expr_arena.clone(),
+                    );
+                    return Some((plan, Some(cached.id)));
+                }
+                candidates.push(cached);
+            }
+            candidates.into_iter().find_map(|cached| {
+                let plan = cached.substitute(&shape.literals)?;
+                cached.last_used = clock;
+                Some((plan, None))
+            })
+        });
+        match plan {
+            Some(_) => self.hits += 1,
+            None => self.misses += 1,
+        }
+        plan
+    }
+
+    fn insert(&mut self, key: u64, mut plan: CachedPlan) -> CachedPlanId {
+        let plans = self.plans.entry(key).or_default();
+        // Another thread may have optimized the same query in the meantime.
+        if let Some(cached) = plans.iter().find(|p| {
+            p.opt_flags.bits() == plan.opt_flags.bits()
+                && p.shape == plan.shape
+                && p.literals == plan.literals
+        }) {
+            return cached.id;
+        }
+        plan.id = CachedPlanId(self.next_id);
+        self.next_id += 1;
+        let id = plan.id;
+        plans.push(plan);
+        self.len += 1;
+        self.evict();
+        id
+    }
+
+    fn get_by_id(&mut self, id: CachedPlanId) -> Option<&mut CachedPlan> {
+        self.plans.values_mut().flatten().find(|p| p.id == id)
+    }
+
+    /// Evict the least recently used plans until the cache is within its capacity.
+    fn evict(&mut self) {
+        while self.len > self.capacity {
+            let (key, idx) = self
+                .plans
+                .iter()
+                .flat_map(|(key, plans)| {
+                    plans
+                        .iter()
+                        .enumerate()
+                        .map(move |(idx, p)| (p.last_used, *key, idx))
+                })
+                .min()
+                .map(|(_, key, idx)| (key, idx))
+                .unwrap();
+            let plans = self.plans.get_mut(&key).unwrap();
+            plans.swap_remove(idx);
+            if plans.is_empty() {
+                self.plans.remove(&key);
+            }
+            self.len -= 1;
+        }
+    }
+}
+
+impl CachedPlan {
+    fn new(
+        shape: PlanShape,
+        opt_flags: OptFlags,
+        lp_top: Node,
+        lp_arena: Arena<IR>,
+        expr_arena: Arena<AExpr>,
+        last_used: u64,
+    ) -> Self {
+        let slots = literal_slots(&shape.literals, lp_top, &lp_arena, &expr_arena);
+        Self {
+            // Assigned on insertion.
+            id: CachedPlanId(0),
+            shape: shape.normalized,
+            opt_flags,
+            literals: shape.literals,
+            lp_top,
+            lp_arena,
+            expr_arena,
+            slots,
+            #[cfg(feature = "new_streaming")]
+            lowered: None,
+            last_used,
+        }
+    }
+
+    /// Get this plan with the values of `literals` in place of the values it was optimized
+    /// with, if the optimizer cannot have depended on the values that differ.
+    fn substitute(&self, literals: &[LiteralValue]) -> Option<IRPlan> {
+        let slots = self.slots.as_ref()?;
+        if literals.len() != self.literals.len() {
+            return None;
+        }
+
+        // Every distinct value must be replaced by a single new value.
+        let mut replacements: Vec<Option<&LiteralValue>> = vec![None; slots.len()];
+        for (old, new) in self.literals.iter().zip(literals) {
+            let slot = slots.iter().position(|(value, _)| value == old)?;
+            match replacements[slot] {
+                Some(prev) if prev != new => return None,
+                _ => replacements[slot] = Some(new),
+            }
+            if old != new && !(is_parameter(old) && is_parameter(new)) {
+                return None;
+            }
+        }
+
+        let mut expr_arena = self.expr_arena.clone();
+        for ((_, nodes), new) in slots.iter().zip(replacements) {
+            for node in nodes {
+                expr_arena.replace(*node, AExpr::Literal(new.unwrap().clone()));
+            }
+        }
+        Some(IRPlan::new(self.lp_top, self.lp_arena.clone(), expr_arena))
+    }
+}
+
+/// Whether the value of a literal can be changed in an optimized plan. The optimizer may rely on
+/// nulls, NaNs and booleans, and literals of other kinds are not simple values.
+fn is_parameter(lv: &LiteralValue) -> bool {
+    match lv {
+        LiteralValue::Scalar(sc) => {
+            let dtype = sc.dtype();
+            !(sc.is_null()
+                || dtype.is_bool()
+                || (dtype.is_float() && sc.value().extract::<f64>().is_none_or(f64::is_nan)))
+        },
+        _ => false,
+    }
+}
+
+/// Find the nodes of the optimized plan that hold each of the distinct `literals` of the plan
+/// before optimization.
+///
+/// The optimizer may have used the value of a literal that it folded, removed or duplicated, so
+/// every value must be held by as many nodes as before optimization. Scans may have been pruned
+/// with the values of their predicates, so plans with scans are never substituted.
+fn literal_slots(
+    literals: &[LiteralValue],
+    lp_top: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<Vec<(LiteralValue, Vec<Node>)>> {
+    let has_scan = lp_arena.iter(lp_top).any(|(_, ir)| match ir {
+        IR::Scan { .. } => true,
+        #[cfg(feature = "python")]
+        IR::PythonScan { .. } => true,
+        _ => false,
+    });
+    if has_scan {
+        return None;
+    }
+
+    let mut slots: Vec<(LiteralValue, usize, Vec<Node>)> = vec![];
+    for lv in literals {
+        match slots.iter_mut().find(|(value, _, _)| value == lv) {
+            Some((_, count, _)) => *count += 1,
+            None => slots.push((lv.clone(), 1, vec![])),
+        }
+    }
+    for node in literal_nodes(lp_top, lp_arena, expr_arena) {
+        let AExpr::Literal(lv) = expr_arena.get(node) else {
+            unreachable!()
+        };
+        if let Some((_, _, nodes)) = slots.iter_mut().find(|(value, _, _)| value == lv) {
+            nodes.push(node);
+        }
+    }
+
+    slots
+        .into_iter()
+        .map(|(value, count, nodes)| (nodes.len() == count).then_some((value, nodes)))
+        .collect()
+}
+
+impl LazyFrame {
+    /// Optimize the plan, or take the optimized plan from the plan cache.
+    ///
+    /// Also returns the id of the cached plan if it is used with the literals it was optimized
+    /// with, see [`build_streaming_query`].
+    pub(super) fn optimize_cached(
+        self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        scratch: &mut Vec<Node>,
+    ) -> PolarsResult<(Node, Option<CachedPlanId>)> {
+        let mut opt_flags = self.opt_state;
+        let dsl = self.logical_plan;
+        let root = to_alp(dsl.clone(), expr_arena, lp_arena, &mut opt_flags)?;
+        let shape = plan_shape(root, lp_arena, expr_arena);
+
+        let mut state = DefaultHasher::new();
+        shape.hash.hash(&mut state);
+        opt_flags.bits().hash(&mut state);
+        let key = state.finish();
+
+        if let Some((plan, id)) = PLAN_CACHE.lock().unwrap().get(key, &shape, opt_flags) {
+            *lp_arena = plan.lp_arena;
+            *expr_arena = plan.expr_arena;
+            return Ok((plan.lp_top, id));
+        }
+
+        let dsl = DslPlan::IR {
+            node: Some(root),
+            dsl: Arc::new(dsl),
+            version: lp_arena.version(),
+        };
+        let lp_top = optimize(
+            dsl,
+            opt_flags,
+            lp_arena,
+            expr_arena,
+            scratch,
+            apply_scan_predicate_to_scan_ir,
+        )?;
+
+        let mut cache = PLAN_CACHE.lock().unwrap();
+        let clock = cache.clock;
+        let plan = CachedPlan::new(
+            shape,
+            opt_flags,
+            lp_top,
+            lp_arena.clone(),
+            expr_arena.clone(),
+            clock,
+        );
+        let id = cache.insert(key, plan);
+        Ok((lp_top, Some(id)))
+    }
+
+    /// Like [`LazyFrame::to_alp_optimized`], but also returns the id of the cached plan if the
+    /// plan cache is enabled, see [`LazyFrame::optimize_cached`].
+    #[allow(clippy::wrong_self_convention)]
+    pub(super) fn to_alp_optimized_cached(
+        mut self,
+    ) -> PolarsResult<(IRPlan, Option<CachedPlanId>)> {
+        if !plan_cache_enabled() {
+            return Ok((self.to_alp_optimized()?, None));
+        }
+        let (mut lp_arena, mut expr_arena) = self.get_arenas();
+        let (node, id) = self.optimize_cached(&mut lp_arena, &mut expr_arena, &mut vec![])?;
+        Ok((IRPlan::new(node, lp_arena, expr_arena), id))
+    }
+}
+
+/// Build the streaming query of `ir_plan`, reusing the physical plan of the cached plan `id`.
+///
+/// The physical plan is lowered and cached on the first run of the cached plan.
+#[cfg(feature = "new_streaming")]
+pub(super) fn build_streaming_query(
+    ir_plan: &mut IRPlan,
+    id: Option<CachedPlanId>,
+) -> PolarsResult<polars_stream::StreamingQuery> {
+    use polars_stream::{LoweredQuery, StreamingQuery};
+
+    let Some(id) = id else {
+        return StreamingQuery::build(
+            ir_plan.lp_top,
+            &mut ir_plan.lp_arena,
+            &mut ir_plan.expr_arena,
+        );
+    };
+
+    let lowered = PLAN_CACHE
+        .lock()
+        .unwrap()
+        .get_by_id(id)
+        .and_then(|cached| cached.lowered.clone());
+    if let Some((lowered, mut expr_arena)) = lowered {
+        return StreamingQuery::from_lowered(lowered, &mut expr_arena);
+    }
+
+    let lowered = LoweredQuery::build(
+        ir_plan.lp_top,
+        &mut ir_plan.lp_arena,
+        &mut ir_plan.expr_arena,
+    )?;
+    if let Some(cached) = PLAN_CACHE.lock().unwrap().get_by_id(id) {
+        cached.lowered = Some((lowered.clone(), ir_plan.expr_arena.clone()));
+    }
+    StreamingQuery::from_lowered(lowered, &mut ir_plan.expr_arena)
+}
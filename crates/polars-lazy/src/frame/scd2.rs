@@ -0,0 +1,194 @@
+use polars_core::prelude::*;
+use polars_ops::frame::{JoinCoalesce, MaintainOrderJoin};
+use polars_utils::format_pl_smallstr;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::prelude::*;
+
+const SCD2_IS_CURRENT: &str = "__POLARS_SCD2_IS_CURRENT";
+const SCD2_IN_HISTORY: &str = "__POLARS_SCD2_IN_HISTORY";
+const SCD2_IN_SNAPSHOT: &str = "__POLARS_SCD2_IN_SNAPSHOT";
+const SCD2_SUFFIX: &str = "__POLARS_SCD2_SNAPSHOT";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scd2Options {
+    /// Business key columns, identifying an entity in both the history and the snapshot.
+    pub keys: Vec<PlSmallStr>,
+    /// Column of the history holding the start of the validity interval of a version.
+    pub valid_from: PlSmallStr,
+    /// Column of the history holding the end of the validity interval of a version. It is null
+    /// for the current version of an entity.
+    pub valid_to: PlSmallStr,
+    /// Moment from which the snapshot is valid. Cast to the dtypes of `valid_from` / `valid_to`.
+    pub effective_at: Expr,
+    /// Close the current version of the entities that are missing from the snapshot.
+    pub close_missing: bool,
+}
+
+impl Scd2Options {
+    pub fn new(keys: Vec<PlSmallStr>, effective_at: Expr) -> Self {
+        Self {
+            keys,
+            valid_from: PlSmallStr::from_static("valid_from"),
+            valid_to: PlSmallStr::from_static("valid_to"),
+            effective_at,
+            close_missing: true,
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Merge a `snapshot` of the current state of the entities into this slowly changing
+    /// dimension (type 2) history.
+    ///
+    /// The snapshot has the columns of the history, except for the `valid_from` / `valid_to`
+    /// columns. For every entity:
+    /// - whose attributes changed, the current version is closed at `effective_at` and a new
+    ///   version is opened from `effective_at`.
+    /// - that is new, a version is opened from `effective_at`.
+    /// - that is missing from the snapshot, the current version is closed at `effective_at` if
+    ///   `close_missing` is set.
+    ///
+    /// The history keeps its order, the new versions are appended to it. This is compiled to a
+    /// single full join of the history and the snapshot, so it can be optimized and run in the
+    /// streaming engine like any other join.
+    pub fn scd2_merge(
+        mut self,
+        mut snapshot: LazyFrame,
+        options: Scd2Options,
+    ) -> PolarsResult<Self> {
+        let Scd2Options {
+            keys,
+            valid_from,
+            valid_to,
+            effective_at,
+            close_missing,
+        } = options;
+
+        polars_ensure!(!keys.is_empty(), InvalidOperation: "`scd2_merge` needs at least one key");
+
+        let history_schema = self.collect_schema()?;
+        let snapshot_schema = snapshot.collect_schema()?;
+
+        let valid_from_dtype = history_schema.try_get(&valid_from)?.clone();
+        let valid_to_dtype = history_schema.try_get(&valid_to)?.clone();
+
+        let columns = history_schema
+            .iter_names()
+            .filter(|name| **name != valid_from && **name != valid_to)
+            .cloned()
+            .collect::<Vec<_>>();
+        polars_ensure!(
+            snapshot_schema.len() == columns.len()
+                && columns.iter().all(|name| snapshot_schema.contains(name)),
+            SchemaMismatch: "the snapshot must have the columns of the history other than '{}' and '{}'",
+            valid_from, valid_to
+        );
+        for name in &keys {
+            polars_ensure!(
+                columns.contains(name),
+                ColumnNotFound: "key column '{}' not found in the history", name
+            );
+        }
+        let attributes = columns
+            .iter()
+            .filter(|name| !keys.contains(name))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // Only the current versions can match the snapshot, the closed versions are kept as is.
+        self = self.with_columns([
+            col(valid_to.clone()).is_null().alias(SCD2_IS_CURRENT),
+            lit(true).alias(SCD2_IN_HISTORY),
+        ]);
+        snapshot = snapshot.with_columns([
+            lit(true).alias(SCD2_IS_CURRENT),
+            lit(true).alias(SCD2_IN_SNAPSHOT),
+        ]);
+
+        let join_keys = keys
+            .iter()
+            .cloned()
+            .chain([PlSmallStr::from_static(SCD2_IS_CURRENT)])
+            .map(col)
+            .collect::<Vec<_>>();
+
+        let joined = self
+            .join_builder()
+            .with(snapshot)
+            .left_on(join_keys.clone())
+            .right_on(join_keys)
+            .how(JoinType::Full)
+            .validate(JoinValidation::ManyToOne)
+            .join_nulls(true)
+            .suffix(SCD2_SUFFIX)
+            .coalesce(JoinCoalesce::CoalesceColumns)
+            .maintain_order(MaintainOrderJoin::LeftRight)
+            .finish();
+
+        let in_history = col(SCD2_IN_HISTORY).is_not_null();
+        let in_snapshot = col(SCD2_IN_SNAPSHOT).is_not_null();
+        let changed = attributes
+            .iter()
+            .map(|name| {
+                col(name.clone())
+                    .eq_missing(col(format_pl_smallstr!("{name}{SCD2_SUFFIX}")))
+                    .not()
+            })
+            .reduce(|acc, e| acc.or(e))
+            .unwrap_or(lit(false));
+        let changed = in_history.clone().and(in_snapshot.clone()).and(changed);
+
+        let close = col(SCD2_IS_CURRENT).and(
+            in_snapshot
+                .clone()
+                .not()
+                .and(lit(close_missing))
+                .or(changed.clone()),
+        );
+
+        // The versions of the history, of which the changed and missing ones are closed.
+        let history = joined.clone().filter(in_history.clone()).select(
+            history_schema
+                .iter_names()
+                .map(|name| {
+                    if *name == valid_to {
+                        when(close.clone())
+                            .then(effective_at.clone().strict_cast(valid_to_dtype.clone()))
+                            .otherwise(col(valid_to.clone()))
+                            .alias(valid_to.clone())
+                    } else {
+                        col(name.clone())
+                    }
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        // The new versions of the changed and new entities.
+        let opened = joined
+            .filter(in_snapshot.and(in_history.not().or(changed)))
+            .select(
+                history_schema
+                    .iter_names()
+                    .map(|name| {
+                        if *name == valid_from {
+                            effective_at
+                                .clone()
+                                .strict_cast(valid_from_dtype.clone())
+                                .alias(valid_from.clone())
+                        } else if *name == valid_to {
+                            lit(NULL)
+                                .cast(valid_to_dtype.clone())
+                                .alias(valid_to.clone())
+                        } else if attributes.contains(name) {
+                            col(format_pl_smallstr!("{name}{SCD2_SUFFIX}")).alias(name.clone())
+                        } else {
+                            col(name.clone())
+                        }
+                    })
+                    .collect::<Vec<_>>(),
+            );
+
+        concat([history, opened], UnionArgs::default())
+    }
+}
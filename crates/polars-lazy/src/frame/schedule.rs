@@ -0,0 +1,132 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use polars_core::prelude::*;
+use polars_core::query_result::QueryResult;
+use polars_utils::relaxed_cell::RelaxedCell;
+use polars_utils::sys::{MEMINFO, total_memory};
+
+use crate::prelude::*;
+
+/// How often a query that waits for memory checks again.
+const MEMORY_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How [`LazyFrame::collect_all_scheduled`] shares the machine between the queries.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CollectAllOptions {
+    /// The priority of every query. Queries with a higher priority are started first, queries
+    /// with the same priority in order. Defaults to the same priority for all queries.
+    pub priorities: Option<Vec<i64>>,
+    /// The maximum number of queries that run at the same time. Defaults to all queries.
+    pub max_concurrent_queries: Option<usize>,
+    /// The memory in bytes that the running queries may use together. A query is only started
+    /// while the memory used since the start is below the limit, or if no other query runs.
+    pub memory_limit: Option<u64>,
+    /// The maximum number of concurrent object store requests of all queries together.
+    pub max_concurrent_requests: Option<u32>,
+}
+
+impl CollectAllOptions {
+    /// Whether the queries are collected independently, as opposed to as a single plan.
+    pub fn is_scheduled(&self) -> bool {
+        self != &Self::default()
+    }
+}
+
+fn used_memory() -> u64 {
+    total_memory().saturating_sub(MEMINFO.free())
+}
+
+impl LazyFrame {
+    /// Collect multiple queries under a shared budget of concurrent queries, memory and object
+    /// store requests, starting the queries with the highest priority first.
+    ///
+    /// Without any limits, the queries are collected as a single plan like
+    /// [`collect_all_with_engine`](LazyFrame::collect_all_with_engine). Otherwise every query is
+    /// collected on its own, so subplans that the queries share are not run only once. The
+    /// results are returned in the order of `plans`.
+    pub fn collect_all_scheduled(
+        plans: Vec<DslPlan>,
+        engine: Engine,
+        opt_state: OptFlags,
+        options: CollectAllOptions,
+    ) -> PolarsResult<Vec<DataFrame>> {
+        if !options.is_scheduled() || plans.is_empty() {
+            return Self::collect_all_with_engine(plans, engine, opt_state);
+        }
+
+        let CollectAllOptions {
+            priorities,
+            max_concurrent_queries,
+            memory_limit,
+            max_concurrent_requests,
+        } = options;
+
+        let n_queries = plans.len();
+        let mut queue = plans.into_iter().enumerate().collect::<Vec<_>>();
+        if let Some(priorities) = priorities {
+            polars_ensure!(
+                priorities.len() == n_queries,
+                InvalidOperation: "got {} priorities for {} queries", priorities.len(), n_queries
+            );
+            // Stable, so that queries with the same priority keep their order.
+            queue.sort_by_key(|(i, _)| std::cmp::Reverse(priorities[*i]));
+        }
+        let n_workers = match max_concurrent_queries {
+            Some(0) => polars_bail!(InvalidOperation: "'max_concurrent_queries' must be positive"),
+            Some(n) => n.min(n_queries),
+            None => n_queries,
+        };
+
+        let _limit = max_concurrent_requests.map(polars_io::pl_async::limit_concurrency_budget);
+
+        let memory_at_start = memory_limit.map(|_| used_memory());
+        let may_start = |running: &RelaxedCell<usize>| match (memory_limit, memory_at_start) {
+            (Some(limit), Some(at_start)) => {
+                running.load() == 0 || used_memory().saturating_sub(at_start) < limit
+            },
+            _ => true,
+        };
+
+        let queue = Mutex::new(queue.into_iter());
+        let running = RelaxedCell::new_usize(0);
+        let failed = RelaxedCell::new_bool(false);
+        let results = Mutex::new((0..n_queries).map(|_| None).collect::<Vec<_>>());
+
+        std::thread::scope(|s| {
+            for _ in 0..n_workers {
+                s.spawn(|| {
+                    loop {
+                        // Only one query waits for memory at a time, the others wait for the
+                        // queue.
+                        let mut queue = queue.lock().unwrap();
+                        while !failed.load() && !may_start(&running) {
+                            std::thread::sleep(MEMORY_POLL_INTERVAL);
+                        }
+                        let Some((i, plan)) = queue.next().filter(|_| !failed.load()) else {
+                            return;
+                        };
+                        running.fetch_add(1);
+                        drop(queue);
+
+                        let lf = LazyFrame::from_logical_plan(plan, opt_state);
+                        let result = lf.collect_with_engine(engine).map(|r| match r {
+                            QueryResult::Single(df) => df,
+                            QueryResult::Multiple(_) => DataFrame::empty(),
+                        });
+                        running.fetch_sub(1);
+                        failed.fetch_or(result.is_err());
+                        results.lock().unwrap()[i] = Some(result);
+                    }
+                });
+            }
+        });
+
+        results
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect::<PolarsResult<Vec<_>>>()
+    }
+}
@@ -0,0 +1,162 @@
+use polars_core::prelude::*;
+use polars_ops::frame::{JoinCoalesce, MaintainOrderJoin};
+use polars_utils::format_pl_smallstr;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::prelude::*;
+
+const UPDATE_ROW_INDEX: &str = "__POLARS_UPDATE_ROW_INDEX";
+const UPDATE_VALIDITY: &str = "__POLARS_UPDATE_VALIDITY";
+const UPDATE_SUFFIX: &str = "__POLARS_UPDATE_RIGHT";
+
+/// Which values of the other frame are used by [`LazyFrame::update`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum UpdateStrategy {
+    /// Overwrite with the non-null values of the other frame.
+    #[default]
+    OverwriteNonNull,
+    /// Overwrite with all matched values of the other frame, including nulls.
+    Overwrite,
+    /// Only fill the null values of this frame with values of the other frame.
+    OnlyNulls,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateOptions {
+    /// One of [`JoinType::Left`], [`JoinType::Inner`] or [`JoinType::Full`].
+    pub how: JoinType,
+    pub strategy: UpdateStrategy,
+    /// Key columns of this frame. If empty, the implicit row index is used as key.
+    pub left_on: Vec<PlSmallStr>,
+    /// Key columns of the other frame. If empty, the implicit row index is used as key.
+    pub right_on: Vec<PlSmallStr>,
+    pub maintain_order: MaintainOrderJoin,
+}
+
+impl Default for UpdateOptions {
+    fn default() -> Self {
+        Self {
+            how: JoinType::Left,
+            strategy: UpdateStrategy::default(),
+            left_on: Vec::new(),
+            right_on: Vec::new(),
+            maintain_order: MaintainOrderJoin::Left,
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Update the values in this [`LazyFrame`] with the values in `other`.
+    ///
+    /// This is compiled to a join followed by a coalesce of the shared columns, so it can be
+    /// optimized and run in the streaming engine like any other join.
+    ///
+    /// The updated columns keep the dtype of this frame. Values of `other` that can't be cast to
+    /// it raise an error.
+    pub fn update(mut self, mut other: LazyFrame, options: UpdateOptions) -> PolarsResult<Self> {
+        let UpdateOptions {
+            how,
+            strategy,
+            mut left_on,
+            mut right_on,
+            maintain_order,
+        } = options;
+
+        polars_ensure!(
+            matches!(how, JoinType::Left | JoinType::Inner | JoinType::Full),
+            InvalidOperation: "`update` does not support a {} join", how
+        );
+        polars_ensure!(
+            left_on.len() == right_on.len(),
+            InvalidOperation: "`update` needs the same number of left and right keys, got {} and {}",
+            left_on.len(), right_on.len()
+        );
+
+        let use_row_index = left_on.is_empty();
+        if use_row_index {
+            self = self.with_row_index(UPDATE_ROW_INDEX, None);
+            other = other.with_row_index(UPDATE_ROW_INDEX, None);
+            left_on = vec![PlSmallStr::from_static(UPDATE_ROW_INDEX)];
+            right_on = left_on.clone();
+        }
+        let drop_row_index = |lf: LazyFrame| {
+            if use_row_index {
+                lf.drop(by_name([UPDATE_ROW_INDEX], true, false))
+            } else {
+                lf
+            }
+        };
+
+        let left_schema = self.collect_schema()?;
+        let right_schema = other.collect_schema()?;
+        for name in &left_on {
+            left_schema.try_get(name)?;
+        }
+        for name in &right_on {
+            right_schema.try_get(name)?;
+        }
+
+        // Nothing to update, and no rows to add or drop.
+        if how == JoinType::Left && right_schema.len() == right_on.len() {
+            return Ok(drop_row_index(self));
+        }
+
+        let update_columns = right_schema
+            .iter_names()
+            .filter(|name| left_schema.contains(name) && !right_on.contains(name))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut right_select = right_on
+            .iter()
+            .chain(&update_columns)
+            .map(|name| col(name.clone()))
+            .collect::<Vec<_>>();
+        // Distinguishes right nulls from rows that failed to join.
+        if strategy == UpdateStrategy::Overwrite {
+            other = other.with_column(lit(true).alias(UPDATE_VALIDITY));
+            right_select.push(col(UPDATE_VALIDITY));
+        }
+
+        let joined = self
+            .join_builder()
+            .with(other.select(right_select))
+            .left_on(left_on.into_iter().map(col).collect::<Vec<_>>())
+            .right_on(right_on.into_iter().map(col).collect::<Vec<_>>())
+            .how(how)
+            .suffix(UPDATE_SUFFIX)
+            .coalesce(JoinCoalesce::CoalesceColumns)
+            .maintain_order(maintain_order)
+            .finish();
+
+        let mut drop_columns = Vec::with_capacity(update_columns.len() + 1);
+        let updated = update_columns
+            .iter()
+            .map(|name| {
+                let right_name = format_pl_smallstr!("{name}{UPDATE_SUFFIX}");
+                let dtype = left_schema.get(name).unwrap().clone();
+                let left = col(name.clone());
+                let right = col(right_name.clone());
+                drop_columns.push(right_name);
+                match strategy {
+                    UpdateStrategy::OverwriteNonNull => coalesce(&[right, left]),
+                    UpdateStrategy::OnlyNulls => coalesce(&[left, right]),
+                    UpdateStrategy::Overwrite => when(col(UPDATE_VALIDITY).is_null())
+                        .then(left)
+                        .otherwise(right),
+                }
+                // Coalescing casts to the supertype of both sides.
+                .strict_cast(dtype)
+                .alias(name.clone())
+            })
+            .collect::<Vec<_>>();
+        if strategy == UpdateStrategy::Overwrite {
+            drop_columns.push(PlSmallStr::from_static(UPDATE_VALIDITY));
+        }
+
+        let out = joined
+            .with_columns(updated)
+            .drop(by_name(drop_columns, true, false));
+        Ok(drop_row_index(out))
+    }
+}
@@ -13,7 +13,19 @@ pub use polars_ops::prelude::{RankMethod, RankOptions};
 #[cfg(feature = "polars_cloud_client")]
 pub use polars_plan::client::prepare_cloud_plan;
 pub use polars_plan::dsl::AnonymousScanOptions;
-pub use polars_plan::plans::{AnonymousScan, AnonymousScanArgs, Literal, LiteralValue, NULL, Null};
+pub use polars_plan::plans::audit::{
+    AuditAccess, AuditRecord, AuditSink, get_audit_sink, set_audit_sink,
+};
+#[cfg(feature = "random")]
+pub use polars_plan::plans::privacy::{
+    PrivacyBudget, get_privacy_budget, set_dp_noise_seed, set_privacy_budget,
+};
+pub use polars_plan::plans::replay::ReplayBundle;
+pub use polars_plan::plans::{
+    AnonymousScan, AnonymousScanArgs, CustomOptimizationRule, Literal, LiteralValue, NULL, Null,
+    OptimizationPhase, PredicateSupport, Sorted, register_optimization_rule,
+    unregister_optimization_rule,
+};
 pub(crate) use polars_plan::prelude::*;
 pub use polars_plan::prelude::{PlanCallback, UnionArgs};
 #[cfg(feature = "rolling_window_by")]
@@ -24,4 +36,7 @@ pub(crate) use polars_utils::arena::{Arena, Node};
 
 pub use crate::dsl::*;
 pub use crate::frame::*;
+pub use crate::scan::table::{
+    Catalog, get_catalog, register_catalog, resolve_catalog_table, unregister_catalog,
+};
 pub(crate) use crate::scan::*;
@@ -50,6 +50,7 @@ impl LazyFrame {
                 cache: false,
                 glob: false,
                 hidden_file_prefix: None,
+                file_listing: Default::default(),
                 projection: None,
                 column_mapping: None,
                 default_values: None,
@@ -59,8 +60,11 @@ impl LazyFrame {
                 missing_columns_policy: MissingColumnsPolicy::Raise,
                 extra_columns_policy: ExtraColumnsPolicy::Raise,
                 include_file_paths: None,
+                provenance: Default::default(),
+                schema_evolution: None,
                 deletion_files: None,
                 table_statistics: None,
+                statistics_sidecar: None,
                 row_count: None,
             },
         )?
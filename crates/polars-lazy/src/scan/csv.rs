@@ -5,8 +5,9 @@ use polars_io::cloud::CloudOptions;
 use polars_io::csv::read::{
     CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues,
 };
-use polars_io::path_utils::expand_paths;
 use polars_io::{HiveOptions, RowIndex};
+use polars_plan::dsl::listing::FileListingOptions;
+use polars_plan::dsl::schema_evolution::SchemaEvolutionPolicy;
 use polars_utils::mmap::MMapSemaphore;
 use polars_utils::pl_path::PlRefPath;
 use polars_utils::slice_enum::Slice;
@@ -22,7 +23,11 @@ pub struct LazyCsvReader {
     read_options: CsvReadOptions,
     cloud_options: Option<CloudOptions>,
     include_file_paths: Option<PlSmallStr>,
+    file_listing: FileListingOptions,
+    provenance: ProvenanceColumns,
     missing_columns_policy: Option<MissingColumnsPolicy>,
+    schema_evolution: Option<Arc<SchemaEvolutionPolicy>>,
+    statistics_sidecar: Option<PlRefPath>,
 }
 
 #[cfg(feature = "csv")]
@@ -48,7 +53,11 @@ impl LazyCsvReader {
             read_options: Default::default(),
             cloud_options: Default::default(),
             include_file_paths: None,
+            file_listing: Default::default(),
+            provenance: Default::default(),
             missing_columns_policy: None,
+            schema_evolution: None,
+            statistics_sidecar: None,
         }
     }
 
@@ -287,11 +296,12 @@ impl LazyCsvReader {
 
                 use polars_io::pl_async::get_runtime;
 
-                let paths = get_runtime().block_on(expand_paths(
+                let (paths, _) = get_runtime().block_on(self.file_listing.expand_paths(
                     &paths[..],
                     self.glob(),
                     &[], // hidden_file_prefix
                     &mut self.cloud_options,
+                    false,
                 ))?;
 
                 let Some(path) = paths.first() else {
@@ -337,11 +347,39 @@ impl LazyCsvReader {
         self
     }
 
+    /// Set the options that control how the paths are expanded into files.
+    #[must_use]
+    pub fn with_file_listing_options(mut self, file_listing: FileListingOptions) -> Self {
+        self.file_listing = file_listing;
+        self
+    }
+
+    /// Set the provenance columns that are added to the output.
+    #[must_use]
+    pub fn with_provenance_columns(mut self, provenance: ProvenanceColumns) -> Self {
+        self.provenance = provenance;
+        self
+    }
+
     #[must_use]
     pub fn with_missing_columns_policy(mut self, policy: Option<MissingColumnsPolicy>) -> Self {
         self.missing_columns_policy = policy;
         self
     }
+
+    /// Set the policy used to reconcile files written under older schemas.
+    #[must_use]
+    pub fn with_schema_evolution(mut self, policy: Option<Arc<SchemaEvolutionPolicy>>) -> Self {
+        self.schema_evolution = policy;
+        self
+    }
+
+    /// Set the statistics sidecar file that is consulted for skipping files.
+    #[must_use]
+    pub fn with_statistics_sidecar(mut self, path: Option<PlRefPath>) -> Self {
+        self.statistics_sidecar = path;
+        self
+    }
 }
 
 impl LazyFileListReader for LazyCsvReader {
@@ -364,6 +402,7 @@ impl LazyFileListReader for LazyCsvReader {
                 cache: self.cache,
                 glob: self.glob,
                 hidden_file_prefix: None,
+                file_listing: self.file_listing,
                 projection: None,
                 column_mapping: None,
                 default_values: None,
@@ -373,8 +412,11 @@ impl LazyFileListReader for LazyCsvReader {
                 missing_columns_policy,
                 extra_columns_policy: ExtraColumnsPolicy::Raise,
                 include_file_paths: self.include_file_paths,
+                provenance: self.provenance,
+                schema_evolution: self.schema_evolution,
                 deletion_files: None,
                 table_statistics: None,
+                statistics_sidecar: self.statistics_sidecar,
                 row_count: None,
             },
         )?
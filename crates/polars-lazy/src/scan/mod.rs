@@ -8,6 +8,9 @@ pub(super) mod ipc;
 pub(super) mod ndjson;
 #[cfg(feature = "parquet")]
 pub(super) mod parquet;
+#[cfg(feature = "ipc")]
+mod statistics_sidecar;
+pub(super) mod table;
 
 #[cfg(feature = "catalog")]
 mod catalog;
@@ -4,8 +4,11 @@ use polars_buffer::Buffer;
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::{HiveOptions, RowIndex};
+use polars_plan::dsl::listing::FileListingOptions;
+use polars_plan::dsl::schema_evolution::SchemaEvolutionPolicy;
 use polars_plan::dsl::{
-    CastColumnsPolicy, DslPlan, ExtraColumnsPolicy, FileScanDsl, MissingColumnsPolicy, ScanSources,
+    CastColumnsPolicy, DslPlan, ExtraColumnsPolicy, FileScanDsl, MissingColumnsPolicy,
+    ProvenanceColumns, ScanSources,
 };
 use polars_plan::prelude::{NDJsonReadOptions, UnifiedScanArgs};
 use polars_utils::pl_path::PlRefPath;
@@ -27,7 +30,11 @@ pub struct LazyJsonLineReader {
     pub(crate) n_rows: Option<usize>,
     pub(crate) ignore_errors: bool,
     pub(crate) include_file_paths: Option<PlSmallStr>,
+    pub(crate) file_listing: FileListingOptions,
+    pub(crate) provenance: ProvenanceColumns,
     pub(crate) cloud_options: Option<CloudOptions>,
+    pub(crate) schema_evolution: Option<Arc<SchemaEvolutionPolicy>>,
+    pub(crate) statistics_sidecar: Option<PlRefPath>,
 }
 
 impl LazyJsonLineReader {
@@ -48,7 +55,11 @@ impl LazyJsonLineReader {
             ignore_errors: false,
             n_rows: None,
             include_file_paths: None,
+            file_listing: Default::default(),
+            provenance: Default::default(),
             cloud_options: None,
+            schema_evolution: None,
+            statistics_sidecar: None,
         }
     }
 
@@ -121,6 +132,34 @@ impl LazyJsonLineReader {
         self.include_file_paths = include_file_paths;
         self
     }
+
+    /// Set the options that control how the paths are expanded into files.
+    #[must_use]
+    pub fn with_file_listing_options(mut self, file_listing: FileListingOptions) -> Self {
+        self.file_listing = file_listing;
+        self
+    }
+
+    /// Set the provenance columns that are added to the output.
+    #[must_use]
+    pub fn with_provenance_columns(mut self, provenance: ProvenanceColumns) -> Self {
+        self.provenance = provenance;
+        self
+    }
+
+    /// Set the policy used to reconcile files written under older schemas.
+    #[must_use]
+    pub fn with_schema_evolution(mut self, policy: Option<Arc<SchemaEvolutionPolicy>>) -> Self {
+        self.schema_evolution = policy;
+        self
+    }
+
+    /// Set the statistics sidecar file that is consulted for skipping files.
+    #[must_use]
+    pub fn with_statistics_sidecar(mut self, path: Option<PlRefPath>) -> Self {
+        self.statistics_sidecar = path;
+        self
+    }
 }
 
 impl LazyFileListReader for LazyJsonLineReader {
@@ -133,6 +172,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             cache: false,
             glob: true,
             hidden_file_prefix: None,
+            file_listing: self.file_listing,
             projection: None,
             column_mapping: None,
             default_values: None,
@@ -142,8 +182,11 @@ impl LazyFileListReader for LazyJsonLineReader {
             missing_columns_policy: MissingColumnsPolicy::Raise,
             extra_columns_policy: ExtraColumnsPolicy::Raise,
             include_file_paths: self.include_file_paths,
+            provenance: self.provenance,
+            schema_evolution: self.schema_evolution,
             deletion_files: None,
             table_statistics: None,
+            statistics_sidecar: self.statistics_sidecar,
             row_count: None,
         };
 
@@ -1,9 +1,21 @@
+use std::num::NonZeroUsize;
+
+use arrow::datatypes::Metadata;
 use polars_buffer::Buffer;
+use polars_core::error::feature_gated;
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
-use polars_io::parquet::read::ParallelStrategy;
-use polars_io::prelude::ParquetOptions;
-use polars_io::{HiveOptions, RowIndex};
+use polars_io::parquet::metadata::{FileMetadataRef, ParquetFooters};
+#[cfg(feature = "cloud")]
+use polars_io::parquet::read::ParquetObjectStore;
+use polars_io::parquet::read::{ParallelStrategy, ParquetReader, read_custom_key_value_metadata};
+use polars_io::prelude::{
+    FileDecryptionProperties, Int96Options, ParquetOptions, TimestampOverflow,
+};
+use polars_io::{HiveOptions, RowIndex, SerReader};
+use polars_plan::dsl::deletion::DeletionFilesList;
+use polars_plan::dsl::listing::FileListingOptions;
+use polars_plan::dsl::schema_evolution::SchemaEvolutionPolicy;
 use polars_utils::pl_path::PlRefPath;
 use polars_utils::slice_enum::Slice;
 
@@ -23,8 +35,36 @@ pub struct ScanArgsParquet {
     pub cache: bool,
     /// Expand path given via globbing rules.
     pub glob: bool,
+    pub file_listing: FileListingOptions,
     pub include_file_paths: Option<PlSmallStr>,
+    pub provenance: ProvenanceColumns,
     pub allow_missing_columns: bool,
+    pub schema_evolution: Option<Arc<SchemaEvolutionPolicy>>,
+    /// Statistics sidecar file that is consulted for skipping files.
+    pub statistics_sidecar: Option<PlRefPath>,
+    /// The keys to read files written with Parquet modular encryption.
+    pub decryption: Option<Arc<FileDecryptionProperties>>,
+    /// The number of row groups to fetch ahead of decoding.
+    pub readahead: Option<NonZeroUsize>,
+    /// The maximum total size in bytes of the row groups that are fetched ahead of decoding.
+    pub readahead_bytes: Option<NonZeroUsize>,
+    /// How INT96 timestamps are read.
+    pub int96: Int96Options,
+    /// What to do with INT64 timestamps that are out of range for the time unit they are read as.
+    /// By default the range is not checked.
+    pub int64_timestamp_overflow: Option<TimestampOverflow>,
+    /// Read columns with the Parquet UUID logical type as strings instead of as 16-byte binary
+    /// values.
+    pub uuid_as_string: bool,
+    /// String columns that are read as categoricals, decoding their dictionary-encoded pages into
+    /// categorical keys directly.
+    pub categorical_columns: Option<Arc<[PlSmallStr]>>,
+    /// Rows to delete from the files, keyed by the index of the file in the sources. Deleted rows
+    /// are filtered out while decoding.
+    pub deletion_files: Option<DeletionFilesList>,
+    /// Footers of the files that were read beforehand, e.g. from a `_metadata` summary file. The
+    /// footers of these files are not fetched.
+    pub footers: Option<ParquetFooters>,
 }
 
 impl Default for ScanArgsParquet {
@@ -41,8 +81,21 @@ impl Default for ScanArgsParquet {
             low_memory: false,
             cache: true,
             glob: true,
+            file_listing: Default::default(),
             include_file_paths: None,
+            provenance: Default::default(),
             allow_missing_columns: false,
+            schema_evolution: None,
+            statistics_sidecar: None,
+            decryption: None,
+            readahead: None,
+            readahead_bytes: None,
+            int96: Int96Options::default(),
+            int64_timestamp_overflow: None,
+            uuid_as_string: false,
+            categorical_columns: None,
+            deletion_files: None,
+            footers: None,
         }
     }
 }
@@ -70,6 +123,15 @@ impl LazyFileListReader for LazyParquetReader {
             parallel: self.args.parallel,
             low_memory: self.args.low_memory,
             use_statistics: self.args.use_statistics,
+            decryption: self.args.decryption,
+            readahead: self.args.readahead,
+            readahead_bytes: self.args.readahead_bytes,
+            struct_fields: None,
+            int96: self.args.int96,
+            int64_timestamp_overflow: self.args.int64_timestamp_overflow,
+            uuid_as_string: self.args.uuid_as_string,
+            categorical_columns: self.args.categorical_columns,
+            footers: self.args.footers,
         };
 
         let unified_scan_args = UnifiedScanArgs {
@@ -80,6 +142,7 @@ impl LazyFileListReader for LazyParquetReader {
             cache: self.args.cache,
             glob: self.args.glob,
             hidden_file_prefix: None,
+            file_listing: self.args.file_listing,
             projection: None,
             column_mapping: None,
             default_values: None,
@@ -97,8 +160,11 @@ impl LazyFileListReader for LazyParquetReader {
             },
             extra_columns_policy: ExtraColumnsPolicy::Raise,
             include_file_paths: self.args.include_file_paths,
-            deletion_files: None,
+            provenance: self.args.provenance,
+            schema_evolution: self.args.schema_evolution,
+            deletion_files: DeletionFilesList::filter_empty(self.args.deletion_files),
             table_statistics: None,
+            statistics_sidecar: self.args.statistics_sidecar,
             row_count: None,
         };
 
@@ -182,4 +248,75 @@ impl LazyFrame {
     ) -> PolarsResult<Self> {
         Self::scan_parquet_sources(ScanSources::Paths(paths), args)
     }
+
+    /// Read the key-value metadata in the footer of the Parquet files the query scans, without
+    /// running the query.
+    ///
+    /// The metadata is read from the first file of the first Parquet scan of the query. It holds
+    /// all pairs of the footer, including the Arrow and pandas schemas that writers store in it.
+    pub fn collect_metadata(self) -> PolarsResult<Metadata> {
+        let IRPlan {
+            lp_top, lp_arena, ..
+        } = self.to_alp()?;
+
+        let mut stack = vec![lp_top];
+        while let Some(node) = stack.pop() {
+            let ir = lp_arena.get(node);
+            if let IR::Scan {
+                sources,
+                scan_type,
+                unified_scan_args,
+                ..
+            } = ir
+                && let FileScanIR::Parquet { options, metadata } = scan_type.as_ref()
+            {
+                let metadata = match metadata {
+                    Some(metadata) => metadata.clone(),
+                    None => read_first_metadata(
+                        sources,
+                        unified_scan_args.cloud_options.as_ref(),
+                        options.decryption.as_ref(),
+                    )?,
+                };
+                return Ok(read_custom_key_value_metadata(
+                    metadata.key_value_metadata(),
+                ));
+            }
+            // Visit the inputs from left to right.
+            let n = stack.len();
+            ir.copy_inputs(&mut stack);
+            stack[n..].reverse();
+        }
+
+        polars_bail!(InvalidOperation: "query does not scan Parquet files")
+    }
+}
+
+/// Read the metadata of the first file of a scan whose schema was passed, so that the metadata
+/// wasn't read when the plan was resolved.
+fn read_first_metadata(
+    sources: &ScanSources,
+    #[allow(unused)] cloud_options: Option<&CloudOptions>,
+    decryption: Option<&Arc<FileDecryptionProperties>>,
+) -> PolarsResult<FileMetadataRef> {
+    let Some(source) = sources.first() else {
+        polars_bail!(ComputeError: "cannot read the Parquet metadata of a scan without files")
+    };
+
+    if source.is_cloud_url() {
+        let path = source.as_path().unwrap().clone();
+        feature_gated!("cloud", {
+            polars_io::pl_async::get_runtime().block_on(async {
+                let mut reader = ParquetObjectStore::from_uri(path, cloud_options, None)
+                    .await?
+                    .with_decryption(decryption.cloned());
+                Ok(reader.get_metadata().await?.clone())
+            })
+        })
+    } else {
+        let memslice = source.to_memslice()?;
+        let mut reader =
+            ParquetReader::new(std::io::Cursor::new(memslice)).with_decryption(decryption.cloned());
+        Ok(reader.get_metadata()?.clone())
+    }
 }
@@ -0,0 +1,101 @@
+use polars_core::prelude::*;
+use polars_plan::dsl::statistics_sidecar::{
+    STATISTICS_SIDECAR_PATH_COLUMN, statistics_sidecar_key,
+};
+use polars_utils::format_pl_smallstr;
+use polars_utils::pl_path::PlRefPath;
+
+use crate::prelude::*;
+
+impl LazyFrame {
+    /// Collect the statistics sidecar of a file scan, to be written to `sidecar_path`.
+    ///
+    /// The sidecar holds a row for every non-empty file of the scan, with its number of rows and
+    /// the minimum, maximum, null count and number of distinct values of every column. See
+    /// [`statistics_sidecar`](polars_plan::dsl::statistics_sidecar) for the layout.
+    pub fn collect_statistics_sidecar(self, sidecar_path: &PlRefPath) -> PolarsResult<DataFrame> {
+        let DslPlan::Scan {
+            sources,
+            mut unified_scan_args,
+            scan_type,
+            cached_ir: _,
+        } = self.logical_plan
+        else {
+            polars_bail!(
+                InvalidOperation:
+                "statistics sidecar can only be collected for a file scan"
+            )
+        };
+
+        const PATH_COLUMN: &str = "__POLARS_STATISTICS_SIDECAR_PATH";
+
+        // Statistics apply to whole files, regardless of the statistics that are already known.
+        unified_scan_args.row_index = None;
+        unified_scan_args.pre_slice = None;
+        unified_scan_args.table_statistics = None;
+        unified_scan_args.statistics_sidecar = None;
+        unified_scan_args.include_file_paths = Some(PlSmallStr::from_static(PATH_COLUMN));
+
+        let mut lf = LazyFrame::from(DslPlan::Scan {
+            sources,
+            unified_scan_args,
+            scan_type,
+            cached_ir: Default::default(),
+        })
+        .with_optimizations(self.opt_state);
+
+        let schema = lf.collect_schema()?;
+
+        let mut aggs = vec![len().alias("len")];
+
+        for (name, dtype) in schema.iter() {
+            if name.as_str() == PATH_COLUMN || dtype.is_nested() || dtype.is_object() {
+                continue;
+            }
+
+            if dtype.is_primitive_numeric()
+                || dtype.is_bool()
+                || dtype.is_string()
+                || dtype.is_temporal()
+                || dtype.is_decimal()
+            {
+                aggs.extend([
+                    col(name.clone())
+                        .min()
+                        .alias(format_pl_smallstr!("{name}_min")),
+                    col(name.clone())
+                        .max()
+                        .alias(format_pl_smallstr!("{name}_max")),
+                ]);
+            }
+
+            aggs.extend([
+                col(name.clone())
+                    .null_count()
+                    .alias(format_pl_smallstr!("{name}_nc")),
+                col(name.clone())
+                    .drop_nulls()
+                    .n_unique()
+                    .alias(format_pl_smallstr!("{name}_ndv")),
+            ]);
+        }
+
+        let mut df = lf.group_by_stable([col(PATH_COLUMN)]).agg(aggs).collect()?;
+
+        let paths = df
+            .column(PATH_COLUMN)?
+            .str()?
+            .iter()
+            .map(|path| {
+                path.map(|path| statistics_sidecar_key(&PlRefPath::new(path), sidecar_path))
+                    .transpose()
+            })
+            .collect::<PolarsResult<StringChunked>>()?
+            .with_name(PlSmallStr::from_static(STATISTICS_SIDECAR_PATH_COLUMN));
+
+        df.drop_in_place(PATH_COLUMN)?;
+        df.insert_column(0, paths.into_column())?;
+
+        Ok(df)
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::{Arc, LazyLock, RwLock};
+
+use polars_core::prelude::*;
+
+use crate::frame::LazyFrame;
+
+/// A source of named tables, e.g. an Iceberg or Delta catalog or a directory of files.
+///
+/// Tables are identified by a namespace followed by the table name, e.g. `["schema", "table"]`.
+pub trait Catalog: Send + Sync {
+    /// The names of the tables in `namespace`.
+    fn list_tables(&self, namespace: &[PlSmallStr]) -> PolarsResult<Vec<PlSmallStr>>;
+
+    /// Resolve the table `name` to a [`LazyFrame`], or `None` if the catalog has no such table.
+    fn resolve_table(&self, name: &[PlSmallStr]) -> PolarsResult<Option<LazyFrame>>;
+
+    /// The schema of the table `name`, or `None` if the catalog has no such table.
+    fn table_schema(&self, name: &[PlSmallStr]) -> PolarsResult<Option<SchemaRef>> {
+        self.resolve_table(name)?
+            .map(|mut lf| lf.collect_schema())
+            .transpose()
+    }
+}
+
+static CATALOGS: LazyLock<RwLock<PlHashMap<PlSmallStr, Arc<dyn Catalog>>>> =
+    LazyLock::new(Default::default);
+
+/// Register `catalog` under `name`, so that its tables can be scanned as `{name}.{table}` with
+/// [`LazyFrame::scan_table`] and from SQL.
+pub fn register_catalog(name: &str, catalog: Arc<dyn Catalog>) -> PolarsResult<()> {
+    let mut catalogs = CATALOGS.write().unwrap();
+    polars_ensure!(
+        !catalogs.contains_key(name),
+        ComputeError: "attempted to register duplicate catalog with name '{name}'"
+    );
+    catalogs.insert(name.into(), catalog);
+    Ok(())
+}
+
+/// Unregister the catalog registered under `name`, returning it.
+pub fn unregister_catalog(name: &str) -> Option<Arc<dyn Catalog>> {
+    CATALOGS.write().unwrap().remove(name)
+}
+
+/// Get the catalog registered under `name`.
+pub fn get_catalog(name: &str) -> Option<Arc<dyn Catalog>> {
+    CATALOGS.read().unwrap().get(name).cloned()
+}
+
+/// Resolve a table name whose first part is the name of a catalog and whose other parts name the
+/// table in that catalog.
+pub fn resolve_catalog_table(
+    name: &[PlSmallStr],
+    catalogs: &PlHashMap<PlSmallStr, Arc<dyn Catalog>>,
+) -> PolarsResult<Option<LazyFrame>> {
+    let [catalog_name, table_name @ ..] = name else {
+        return Ok(None);
+    };
+    if table_name.is_empty() {
+        return Ok(None);
+    }
+    let catalog = match catalogs.get(catalog_name) {
+        Some(catalog) => catalog.clone(),
+        None => match get_catalog(catalog_name) {
+            Some(catalog) => catalog,
+            None => return Ok(None),
+        },
+    };
+    catalog.resolve_table(table_name)
+}
+
+impl LazyFrame {
+    /// Scan the table `name` of a registered [`Catalog`], given as `catalog.namespace.table`.
+    pub fn scan_table(name: &str) -> PolarsResult<Self> {
+        let parts = name.split('.').map(PlSmallStr::from).collect::<Vec<_>>();
+        polars_ensure!(
+            parts.len() >= 2,
+            InvalidOperation: "table name '{name}' must be prefixed by the name of its catalog"
+        );
+        match resolve_catalog_table(&parts, &PlHashMap::default())? {
+            Some(lf) => Ok(lf),
+            None if get_catalog(&parts[0]).is_none() => {
+                polars_bail!(ComputeError: "no catalog registered with name '{}'", parts[0])
+            },
+            None => polars_bail!(ComputeError: "table '{name}' was not found"),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::num::NonZeroUsize;
+
 use polars_io::RowIndex;
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
@@ -358,6 +360,79 @@ fn test_parquet_statistics() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "is_in", feature = "parquet_bloom_filter"))]
+fn test_parquet_bloom_filter_pruning() -> PolarsResult<()> {
+    // Even ids, so that the min/max statistics of every row group include the odd ids.
+    let mut df = df![
+        "id" => (0..2048i64).map(|i| i * 2).collect::<Vec<_>>(),
+        "name" => (0..2048).map(|i| format!("n{i}")).collect::<Vec<_>>(),
+    ]?;
+    let mut buf = vec![];
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(512))
+        .with_bloom_filter(Some(ParquetBloomFilterOptions::new(vec![
+            "id".into(),
+            "name".into(),
+        ])))
+        .finish(&mut df)?;
+
+    let sources = ScanSources::Buffers([polars_buffer::Buffer::from(buf)].into());
+    let scan = || LazyFrame::scan_parquet_sources(sources.clone(), Default::default()).unwrap();
+
+    let out = scan().filter(col("id").eq(lit(1001i64))).collect()?;
+    assert_eq!(out.height(), 0);
+
+    let out = scan().filter(col("id").eq(lit(1000i64))).collect()?;
+    assert_eq!(out.height(), 1);
+
+    let out = scan()
+        .filter(col("id").is_in(lit(Series::new("".into(), [3i64, 1000, 2001])), false))
+        .collect()?;
+    assert_eq!(out.height(), 1);
+
+    let out = scan()
+        .filter(col("name").eq(lit("n7")).and(col("id").eq(lit(14i64))))
+        .collect()?;
+    assert_eq!(out.height(), 1);
+
+    let out = scan().filter(col("name").eq(lit("x"))).collect()?;
+    assert_eq!(out.height(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_parquet_key_value_metadata() -> PolarsResult<()> {
+    let mut df = df!["a" => [1i64, 2, 3]]?;
+    let mut buf = vec![];
+    ParquetWriter::new(&mut buf)
+        .with_key_value_metadata(Some(KeyValueMetadata::from_static(vec![(
+            "lineage".to_string(),
+            "job-42".to_string(),
+        )])))
+        .finish(&mut df)?;
+
+    let sources = ScanSources::Buffers([polars_buffer::Buffer::from(buf)].into());
+    let metadata = LazyFrame::scan_parquet_sources(sources.clone(), Default::default())?
+        .filter(col("a").gt(lit(1i64)))
+        .collect_metadata()?;
+    assert_eq!(metadata["lineage"].as_str(), "job-42");
+    assert!(metadata.contains_key("ARROW:schema"));
+
+    // The metadata is also read if the schema of the scan is passed.
+    let args = ScanArgsParquet {
+        schema: Some(df.schema().clone()),
+        ..Default::default()
+    };
+    let metadata = LazyFrame::scan_parquet_sources(sources, args)?.collect_metadata()?;
+    assert_eq!(metadata["lineage"].as_str(), "job-42");
+
+    let err = df.lazy().collect_metadata().unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    Ok(())
+}
+
 #[test]
 #[cfg(not(target_os = "windows"))]
 fn test_parquet_globbing() -> PolarsResult<()> {
@@ -383,6 +458,57 @@ fn test_parquet_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn test_parquet_readahead() -> PolarsResult<()> {
+    init_files();
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let glob = "../../examples/datasets/foods*.parquet";
+    let scan = |readahead, readahead_bytes| {
+        LazyFrame::scan_parquet(
+            PlRefPath::new(glob),
+            ScanArgsParquet {
+                readahead,
+                readahead_bytes,
+                ..Default::default()
+            },
+        )?
+        .collect()
+    };
+
+    let expected = scan(None, None)?;
+    // A budget smaller than any row group still makes progress, one row group at a time.
+    for (readahead, readahead_bytes) in [(1, 1), (1, usize::MAX), (64, 1024)] {
+        let out = scan(
+            NonZeroUsize::new(readahead),
+            NonZeroUsize::new(readahead_bytes),
+        )?;
+        assert!(out.equals_missing(&expected));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_audit_record() -> PolarsResult<()> {
+    let lf = scan_foods_parquet(false)
+        .filter(col("category").eq(lit("seafood")))
+        .select([col("calories")]);
+    let plan = lf.to_alp_optimized()?;
+    let record = AuditRecord::from_plan(plan.lp_top, &plan.lp_arena);
+
+    // Only the columns that survive projection pushdown are read.
+    assert_eq!(
+        record.reads,
+        [AuditAccess {
+            target: FOODS_PARQUET.into(),
+            columns: vec!["category".into(), "calories".into()],
+        }]
+    );
+    assert!(record.writes.is_empty());
+    Ok(())
+}
+
 #[test]
 fn test_scan_parquet_limit_9001() {
     init_files();
@@ -738,6 +864,71 @@ fn scan_anonymous_fn_with_options() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn scan_anonymous_fn_negotiation() -> PolarsResult<()> {
+    struct MyScan {}
+
+    impl AnonymousScan for MyScan {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn predicate_support(&self, predicate: &Expr) -> PredicateSupport {
+            let predicate = predicate.to_string();
+            if predicate.contains("fruits") {
+                PredicateSupport::Unsupported
+            } else if predicate.contains(r#"col("A")"#) {
+                PredicateSupport::Exact
+            } else {
+                PredicateSupport::Inexact
+            }
+        }
+
+        fn allows_slice_pushdown(&self) -> bool {
+            false
+        }
+
+        fn sorted_by(&self) -> Vec<Sorted> {
+            vec![Sorted {
+                column: "A".into(),
+                descending: Some(false),
+                nulls_last: None,
+            }]
+        }
+
+        fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+            assert_eq!(scan_opts.n_rows, None);
+            let predicate = scan_opts.predicate.unwrap().to_string();
+            assert!(predicate.contains(r#"col("A")"#));
+            assert!(predicate.contains(r#"col("B")"#));
+            assert!(!predicate.contains("fruits"));
+
+            // The predicate on `B` is inexact, so it doesn't need to be applied.
+            let df = fruits_cars();
+            let mask = df.column("A")?.as_materialized_series().gt(1)?;
+            df.filter(&mask)
+        }
+    }
+
+    let args = ScanArgsAnonymous {
+        schema: Some(fruits_cars().schema().clone()),
+        ..ScanArgsAnonymous::default()
+    };
+    let predicate = col("A")
+        .gt(lit(1))
+        .and(col("B").lt(lit(4)))
+        .and(col("fruits").eq(lit("banana")));
+
+    let df = LazyFrame::anonymous_scan(Arc::new(MyScan {}), args)?
+        .filter(predicate.clone())
+        .limit(1)
+        .collect()?;
+    let expected = fruits_cars().lazy().filter(predicate).limit(1).collect()?;
+    assert!(df.equals(&expected));
+    assert_eq!(df.column("A")?.i32()?.get(0), Some(5));
+    Ok(())
+}
+
 #[test]
 fn scan_anonymous_fn_count() -> PolarsResult<()> {
     struct MyScan {}
@@ -649,3 +649,88 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_custom_optimization_rule() -> PolarsResult<()> {
+    /// Removes the filters on top of frames with a `custom_rule_marker` column.
+    struct RemoveMarkedFilters;
+
+    impl CustomOptimizationRule for RemoveMarkedFilters {
+        fn name(&self) -> &str {
+            "remove_marked_filters"
+        }
+
+        fn phase(&self) -> OptimizationPhase {
+            OptimizationPhase::Early
+        }
+
+        fn optimize_plan(
+            &self,
+            lp_arena: &mut Arena<IR>,
+            _expr_arena: &mut Arena<AExpr>,
+            node: Node,
+        ) -> PolarsResult<Option<IR>> {
+            let IR::Filter { input, .. } = lp_arena.get(node) else {
+                return Ok(None);
+            };
+            let input = lp_arena.get(*input);
+            Ok(input
+                .schema(lp_arena)
+                .contains("custom_rule_marker")
+                .then(|| input.clone()))
+        }
+    }
+
+    let lf = df![
+        "custom_rule_marker" => [1, 2, 3],
+    ]?
+    .lazy()
+    .filter(col("custom_rule_marker").gt(lit(1)));
+
+    register_optimization_rule(Arc::new(RemoveMarkedFilters))?;
+    assert!(register_optimization_rule(Arc::new(RemoveMarkedFilters)).is_err());
+    let out = lf.clone().collect();
+    unregister_optimization_rule("remove_marked_filters")?;
+    assert_eq!(out?.height(), 3);
+
+    assert_eq!(lf.collect()?.height(), 2);
+    assert!(unregister_optimization_rule("remove_marked_filters").is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_join_build_side_from_row_estimates() -> PolarsResult<()> {
+    use polars_ops::frame::JoinBuildSide;
+
+    let small = df!["a" => [1, 2]]?.lazy();
+    let large = df!["a" => [1, 2, 3, 4], "b" => [5, 6, 7, 8]]?.lazy();
+
+    let build_side = |lf: LazyFrame| -> PolarsResult<Option<JoinBuildSide>> {
+        let plan = lf.to_alp_optimized()?;
+        Ok(plan
+            .lp_arena
+            .iter(plan.lp_top)
+            .find_map(|(_, ir)| match ir {
+                IR::Join { options, .. } => options.args.build_side.clone(),
+                _ => None,
+            }))
+    };
+
+    let q = large
+        .clone()
+        .inner_join(small.clone(), col("a"), col("a"))
+        .with_new_streaming(true);
+    assert_eq!(build_side(q)?, Some(JoinBuildSide::PreferRight));
+
+    let q = small
+        .clone()
+        .inner_join(large.clone(), col("a"), col("a"))
+        .with_new_streaming(true);
+    assert_eq!(build_side(q)?, Some(JoinBuildSide::PreferLeft));
+
+    // The in-memory engine chooses the build side itself.
+    let q = small.inner_join(large, col("a"), col("a"));
+    assert_eq!(build_side(q)?, None);
+    Ok(())
+}
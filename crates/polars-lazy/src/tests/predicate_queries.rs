@@ -314,3 +314,126 @@ fn test_push_join_col_predicates_to_both_sides_semi_12565() -> PolarsResult<()>
     assert_eq!(out, expected);
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "is_in", feature = "semi_anti_join"))]
+fn test_is_in_lazy() -> PolarsResult<()> {
+    let df = df![
+        "id" => [1i64, 2, 3, 4, 5],
+        "value" => ["a", "b", "c", "d", "e"]
+    ]?;
+    let other = df![
+        "key" => [4i64, 2, 2, 9],
+        "other" => [true, false, true, false]
+    ]?;
+
+    // The other query is small, so it is probed as a set.
+    let out = df
+        .clone()
+        .lazy()
+        .filter(col("id").is_in_lazy(other.clone().lazy().select([col("key")]), false))
+        .collect()?;
+    let expected = df![
+        "id" => [2i64, 4],
+        "value" => ["b", "d"]
+    ]?;
+    assert!(out.equals(&expected));
+
+    // Negated.
+    let out = df
+        .clone()
+        .lazy()
+        .filter(
+            col("id")
+                .is_in_lazy(other.clone().lazy().select([col("key")]), false)
+                .not(),
+        )
+        .collect()?;
+    let expected = df![
+        "id" => [1i64, 3, 5],
+        "value" => ["a", "c", "e"]
+    ]?;
+    assert!(out.equals(&expected));
+
+    // A null is dropped by the negated filter unless nulls are equal.
+    let with_null = df![
+        "id" => [Some(1i64), None, Some(2)]
+    ]?;
+    let keys = df![
+        "key" => [Some(2i64), None]
+    ]?;
+    let out = with_null
+        .clone()
+        .lazy()
+        .filter(
+            col("id")
+                .is_in_lazy(df!["key" => [2i64]]?.lazy(), false)
+                .not(),
+        )
+        .collect()?;
+    assert!(out.equals_missing(&df!["id" => [Some(1i64)]]?));
+    let out = with_null
+        .clone()
+        .lazy()
+        .filter(
+            col("id")
+                .is_in_lazy(df!["key" => [2i64]]?.lazy(), true)
+                .not(),
+        )
+        .collect()?;
+    assert!(out.equals_missing(&df!["id" => [Some(1i64), None]]?));
+    let out = with_null
+        .lazy()
+        .filter(col("id").is_in_lazy(keys.lazy(), true).not())
+        .collect()?;
+    assert!(out.equals_missing(&df!["id" => [Some(1i64)]]?));
+
+    // Not a filter, so it is probed as a set.
+    let out = df
+        .lazy()
+        .with_column(
+            col("id")
+                .is_in_lazy(other.lazy().select([col("key")]), false)
+                .alias("found"),
+        )
+        .collect()?;
+    let expected = df![
+        "id" => [1i64, 2, 3, 4, 5],
+        "value" => ["a", "b", "c", "d", "e"],
+        "found" => [false, true, false, true, false]
+    ]?;
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "is_in", feature = "semi_anti_join"))]
+fn test_is_in_lazy_strategy_by_size() -> PolarsResult<()> {
+    let df = df!["id" => [1i64, 2, 3]]?;
+    let has_join = |other: DataFrame, negated: bool| -> PolarsResult<bool> {
+        let mut predicate = col("id").is_in_lazy(other.lazy(), false);
+        if negated {
+            predicate = predicate.not();
+        }
+        let plan = df.clone().lazy().filter(predicate).to_alp_optimized()?;
+        Ok(plan
+            .lp_arena
+            .iter(plan.lp_top)
+            .any(|(_, ir)| matches!(ir, IR::Join { .. })))
+    };
+
+    let small = df!["key" => [2i64, 3]]?;
+    let large = df!["key" => (0i64..20_000).collect::<Vec<_>>()]?;
+    assert!(!has_join(small.clone(), false)?);
+    assert!(!has_join(small, true)?);
+    assert!(has_join(large.clone(), false)?);
+    assert!(has_join(large.clone(), true)?);
+
+    let out = df
+        .lazy()
+        .filter(col("id").is_in_lazy(large.lazy(), false).not())
+        .collect()?;
+    assert_eq!(out.height(), 0);
+    Ok(())
+}
@@ -1221,6 +1221,386 @@ fn test_cross_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_update() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [Some(10), None, Some(30)],
+    ]?;
+    let other = df![
+        "a" => [2, 3, 4],
+        "b" => [Some(20), None, Some(40)],
+    ]?;
+    let update = |strategy, how| {
+        let options = UpdateOptions {
+            how,
+            strategy,
+            left_on: vec!["a".into()],
+            right_on: vec!["a".into()],
+            ..Default::default()
+        };
+        df.clone()
+            .lazy()
+            .update(other.clone().lazy(), options)?
+            .collect()
+    };
+
+    let out = update(UpdateStrategy::OverwriteNonNull, JoinType::Left)?;
+    let expected = df!["a" => [1, 2, 3], "b" => [Some(10), Some(20), Some(30)]]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = update(UpdateStrategy::Overwrite, JoinType::Left)?;
+    let expected = df!["a" => [1, 2, 3], "b" => [Some(10), Some(20), None]]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = update(UpdateStrategy::OnlyNulls, JoinType::Full)?;
+    let expected = df!["a" => [1, 2, 3, 4], "b" => [Some(10), Some(20), Some(30), Some(40)]]?;
+    assert!(out.equals_missing(&expected));
+
+    // An inner update still drops the unmatched rows if the right frame only has keys.
+    let options = UpdateOptions {
+        how: JoinType::Inner,
+        left_on: vec!["a".into()],
+        right_on: vec!["a".into()],
+        ..Default::default()
+    };
+    let out = df
+        .clone()
+        .lazy()
+        .update(other.clone().lazy().select([col("a")]), options)?
+        .collect()?;
+    let expected = df!["a" => [2, 3], "b" => [None, Some(30)]]?;
+    assert!(out.equals_missing(&expected));
+
+    // Update by row index.
+    let out = df
+        .clone()
+        .lazy()
+        .update(other.clone().lazy().select([col("b")]), Default::default())?
+        .collect()?;
+    let expected = df!["a" => [1, 2, 3], "b" => [Some(20), None, Some(40)]]?;
+    assert!(out.equals_missing(&expected));
+
+    // The updated columns keep the left dtype.
+    let wide = df![
+        "a" => [2, 3, 4],
+        "b" => [Some(20i64), None, Some(40)],
+    ]?;
+    for (strategy, how) in [
+        (UpdateStrategy::OverwriteNonNull, JoinType::Left),
+        (UpdateStrategy::Overwrite, JoinType::Left),
+        (UpdateStrategy::OnlyNulls, JoinType::Full),
+    ] {
+        let options = UpdateOptions {
+            how,
+            strategy,
+            left_on: vec!["a".into()],
+            right_on: vec!["a".into()],
+            ..Default::default()
+        };
+        let out = df
+            .clone()
+            .lazy()
+            .update(wide.clone().lazy(), options)?
+            .collect()?;
+        assert_eq!(out.schema(), df.schema());
+    }
+
+    // Values that don't fit the left dtype raise.
+    let too_wide = df!["a" => [2], "b" => [i64::MAX]]?;
+    let options = UpdateOptions {
+        left_on: vec!["a".into()],
+        right_on: vec!["a".into()],
+        ..Default::default()
+    };
+    assert!(
+        df.clone()
+            .lazy()
+            .update(too_wide.lazy(), options)?
+            .collect()
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_scd2_merge() -> PolarsResult<()> {
+    let history = df![
+        "id" => [1, 1, 2, 3],
+        "v" => ["a", "b", "c", "d"],
+        "valid_from" => [0, 5, 0, 0],
+        "valid_to" => [Some(5), None, None, None],
+    ]?;
+    let snapshot = df![
+        "id" => [1, 2, 4],
+        "v" => ["b", "x", "e"],
+    ]?;
+    let merge = |close_missing| {
+        let options = Scd2Options {
+            close_missing,
+            ..Scd2Options::new(vec!["id".into()], lit(10))
+        };
+        history
+            .clone()
+            .lazy()
+            .scd2_merge(snapshot.clone().lazy(), options)?
+            .collect()
+    };
+
+    let out = merge(true)?;
+    let expected = df![
+        "id" => [1, 1, 2, 3, 2, 4],
+        "v" => ["a", "b", "c", "d", "x", "e"],
+        "valid_from" => [0, 5, 0, 0, 10, 10],
+        "valid_to" => [Some(5), None, Some(10), Some(10), None, None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = merge(false)?;
+    let expected = df![
+        "id" => [1, 1, 2, 3, 2, 4],
+        "v" => ["a", "b", "c", "d", "x", "e"],
+        "valid_from" => [0, 5, 0, 0, 10, 10],
+        "valid_to" => [Some(5), None, Some(10), None, None, None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    // The snapshot must not have duplicate keys.
+    let snapshot = df!["id" => [1, 1], "v" => ["a", "b"]]?;
+    let out = history
+        .clone()
+        .lazy()
+        .scd2_merge(
+            snapshot.lazy(),
+            Scd2Options::new(vec!["id".into()], lit(10)),
+        )?
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "interval")]
+fn test_interval_join() -> PolarsResult<()> {
+    let left = df![
+        "id" => [1, 2, 3],
+        "start" => [Some(0), Some(10), None],
+        "end" => [5, 20, 30],
+    ]?;
+    let right = df![
+        "start" => [4, 5, 15],
+        "end" => [6, 10, 25],
+        "v" => ["a", "b", "c"],
+    ]?;
+
+    let out = left
+        .lazy()
+        .interval_join(
+            right.lazy(),
+            interval(col("start"), col("end")),
+            interval(col("start"), col("end")),
+            Some("_right".into()),
+        )?
+        .sort(["id"], Default::default())
+        .select([col("id"), col("v")])
+        .collect()?;
+
+    let expected = df![
+        "id" => [1, 2],
+        "v" => ["a", "c"],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_group_by_hashed() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["x", "y", "x", "z"],
+        "b" => [1, 2, 3, 4],
+        "h" => [7u64, 8, 7, 9],
+    ]?;
+
+    // Rows with the same hash are one group, the keys are taken from the first row.
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_hashed(col("h"), [col("a")], true)?
+        .agg([col("b").sum()])
+        .collect()?;
+    let expected = df![
+        "a" => ["x", "y", "z"],
+        "b" => [4, 2, 4],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let out = df.lazy().group_by_hashed(col("b"), [col("a")], true);
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_join_hashed() -> PolarsResult<()> {
+    let left = df![
+        "a" => ["x", "y", "z"],
+        "h" => [7u64, 8, 9],
+    ]?;
+    let right = df![
+        "b" => [1, 2],
+        "h" => [9u64, 7],
+    ]?;
+
+    // The rows are matched on the hashes only, the hashes are not added to the output.
+    let out = left
+        .clone()
+        .lazy()
+        .join_hashed(
+            right.clone().lazy().select([col("b"), col("h").alias("g")]),
+            col("h"),
+            col("g"),
+            JoinArgs::new(JoinType::Left),
+        )?
+        .sort(["a"], Default::default())
+        .collect()?;
+    let expected = df![
+        "a" => ["x", "y", "z"],
+        "h" => [7u64, 8, 9],
+        "b" => [Some(2), None, Some(1)],
+        "g" => [Some(7u64), None, Some(9)],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = left.lazy().join_hashed(
+        right.lazy(),
+        col("a"),
+        col("h"),
+        JoinArgs::new(JoinType::Inner),
+    );
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_partitioned_frame() -> PolarsResult<()> {
+    let df = df![
+        "k" => [1, 2, 3, 1, 2, 1],
+        "v" => [1, 2, 3, 4, 5, 6],
+    ]?;
+    let other = df![
+        "k" => [1, 3, 4],
+        "w" => ["a", "c", "d"],
+    ]?;
+
+    let left = PartitionedFrame::with_n_partitions(&df, vec!["k".into()], 3)?;
+    let right = PartitionedFrame::with_n_partitions(&other, vec!["k".into()], 3)?;
+    assert_eq!(left.n_partitions(), 3);
+    assert_eq!(left.height(), 6);
+
+    // Rows with the same key are in the same partition.
+    for partition in left.partitions() {
+        let keys = partition.column("k")?.i32()?;
+        for key in keys.unique()?.into_no_null_iter() {
+            let n = left
+                .partitions()
+                .iter()
+                .filter(|p| p.column("k").unwrap().i32().unwrap().equal(key).any())
+                .count();
+            assert_eq!(n, 1);
+        }
+    }
+
+    let out = left
+        .group_by_agg([col("v").sum()])?
+        .sort(["k"], Default::default())
+        .collect()?;
+    let expected = df![
+        "k" => [1, 2, 3],
+        "v" => [11, 7, 3],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let out = left
+        .join(&right, JoinType::Inner)?
+        .sort(["v"], Default::default())
+        .collect()?;
+    let expected = df![
+        "k" => [1, 3, 1, 1],
+        "v" => [1, 3, 4, 6],
+        "w" => ["a", "c", "a", "a"],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let sorted = |df: DataFrame| df.sort(["v"], Default::default());
+    assert!(sorted(left.clone().into_df())?.equals(&df));
+
+    // The partitions stay partitioned after a partition-wise operation.
+    let mapped = left.try_map_partitions(|lf| lf.filter(col("v").gt(lit(1))))?;
+    assert_eq!(mapped.height(), 5);
+    assert!(
+        left.try_map_partitions(|lf| lf.drop(by_name(["k"], true, false)))
+            .is_err()
+    );
+
+    let other = PartitionedFrame::with_n_partitions(&other, vec!["k".into()], 2)?;
+    assert!(left.join(&other, JoinType::Inner).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_partitioned_frame_plan() -> PolarsResult<()> {
+    let df = df![
+        "k" => [1, 2, 3, 1, 2, 1],
+        "v" => [1, 2, 3, 4, 5, 6],
+    ]?;
+    let other = df![
+        "k" => [1, 3, 4],
+        "w" => ["a", "c", "d"],
+    ]?;
+    let left = PartitionedFrame::with_n_partitions(&df, vec!["k".into()], 3)?;
+    let right = PartitionedFrame::with_n_partitions(&other, vec!["k".into()], 3)?;
+
+    // The group by and the join run per partition.
+    let plan = left
+        .group_by_agg([col("v").sum()])?
+        .describe_optimized_plan()?;
+    assert!(plan.contains("PARTITIONED UNION"), "{plan}");
+    assert_eq!(plan.matches("AGGREGATE").count(), 3, "{plan}");
+    let plan = left
+        .join(&right, JoinType::Inner)?
+        .describe_optimized_plan()?;
+    assert!(plan.contains("PARTITIONED UNION"), "{plan}");
+    assert_eq!(plan.matches("INNER JOIN:").count(), 3, "{plan}");
+
+    // A group by on other columns than the keys is not split.
+    let plan = left
+        .lazy()?
+        .group_by([col("v")])
+        .agg([col("k").sum()])
+        .describe_optimized_plan()?;
+    assert_eq!(plan.matches("AGGREGATE").count(), 1, "{plan}");
+
+    // An equality filter on the keys only reads the partition holding them.
+    let q = left.lazy()?.filter(col("k").eq(lit(1)));
+    let plan = q.clone().describe_optimized_plan()?;
+    assert_eq!(plan.matches("DF [").count(), 1, "{plan}");
+    let out = q.sort(["v"], Default::default()).collect()?;
+    let expected = df![
+        "k" => [1, 1, 1],
+        "v" => [1, 4, 6],
+    ]?;
+    assert!(out.equals(&expected));
+
+    // The key of a filter may be in any partition.
+    for key in 1..=3 {
+        let out = left.lazy()?.filter(lit(key).eq(col("k"))).collect()?;
+        assert_eq!(
+            out.height(),
+            df.filter(&df.column("k")?.i32()?.equal(key))?.height()
+        );
+    }
+    Ok(())
+}
+
 #[test]
 fn test_select_empty_df() -> PolarsResult<()> {
     // https://github.com/pola-rs/polars/issues/1056
@@ -2046,3 +2426,416 @@ fn test_named_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_field_metadata_propagation() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1.0, 2.0, 3.0],
+        "b" => [1, 2, 3],
+    ]?;
+    let lf = df.lazy().with_column(
+        col("a").with_field_metadata([("unit".into(), "m".into())].into_iter().collect()),
+    );
+
+    let out = lf
+        .clone()
+        .select([
+            col("a").alias("x"),
+            col("a").max(),
+            (col("a") * lit(2.0)).alias("y"),
+            (col("a") * col("b")).alias("z"),
+            col("b"),
+        ])
+        .collect()?;
+    let field_metadata = out.field_metadata();
+    assert_eq!(field_metadata.len(), 2);
+    assert_eq!(field_metadata["x"]["unit"].as_str(), "m");
+    assert_eq!(field_metadata["a"]["unit"].as_str(), "m");
+    assert!(out.column("b")?.metadata().is_none());
+
+    let field_metadata = lf
+        .rename(["a"], ["length"], true)
+        .filter(col("b").gt(lit(1)))
+        .collect_field_metadata()?;
+    assert_eq!(field_metadata.len(), 1);
+    assert_eq!(field_metadata["length"]["unit"].as_str(), "m");
+    Ok(())
+}
+
+#[test]
+fn test_units() -> PolarsResult<()> {
+    let df = df![
+        "distance" => [1000, 2000, 3000],
+        "time" => [100.0, 200.0, 400.0],
+        "offset" => [1.0, 2.0, 3.0],
+    ]?;
+    let lf = df.lazy().with_columns([
+        col("distance").set_unit("m"),
+        col("time").set_unit("s"),
+        col("offset").set_unit("km"),
+    ]);
+
+    let out = lf
+        .clone()
+        .select([
+            (col("distance") / col("time")).alias("speed"),
+            (col("distance") / col("time"))
+                .convert_unit("km/h")
+                .alias("speed_kmh"),
+            (col("distance") + col("offset").convert_unit("m")).alias("total"),
+            (col("distance") * lit(2)).alias("double"),
+        ])
+        .collect()?;
+    let field_metadata = out.field_metadata();
+    assert_eq!(field_metadata["speed"]["polars:unit"].as_str(), "m/s");
+    assert_eq!(field_metadata["speed_kmh"]["polars:unit"].as_str(), "km/h");
+    assert_eq!(field_metadata["total"]["polars:unit"].as_str(), "m");
+    assert_eq!(field_metadata["double"]["polars:unit"].as_str(), "m");
+    let speed_kmh = out.column("speed_kmh")?.f64()?;
+    assert!((speed_kmh.get(2).unwrap() - 27.0).abs() < 1e-9);
+    let total = out.column("total")?.f64()?;
+    assert_eq!(total.get(0), Some(2000.0));
+
+    // Different dimensions and different scales are both errors.
+    let err = lf
+        .clone()
+        .select([col("distance") + col("time")])
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("different dimensions"));
+    let err = lf
+        .clone()
+        .filter(col("distance").gt(col("offset")))
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("convert_unit"));
+    assert!(
+        lf.select([col("time").convert_unit("m")])
+            .collect()
+            .is_err()
+    );
+
+    // A plain "unit" entry is user metadata without unit semantics.
+    let plain = |unit: &str| [("unit".into(), unit.into())].into_iter().collect();
+    let out = df![
+        "distance" => [1.0, 2.0],
+        "time" => [3.0, 4.0],
+    ]?
+    .lazy()
+    .with_columns([
+        col("distance").with_field_metadata(plain("m")),
+        col("time").with_field_metadata(plain("s")),
+    ])
+    .select([(col("distance") + col("time")).alias("sum")])
+    .collect()?;
+    assert!(out.field_metadata().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_column_index_filter() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [5, 1, 4, 2, 3],
+        "b" => [0, 1, 2, 3, 4],
+    ]?;
+    df.create_index("a", IndexKind::Sorted)?;
+    assert!(df.sorted_index("a").is_some());
+
+    let out = df
+        .clone()
+        .lazy()
+        .filter(col("a").gt_eq(lit(3)).and(col("a").lt(lit(5))))
+        .collect()?;
+    assert_eq!(out.column("b")?.i32()?.to_vec(), &[Some(2), Some(4)]);
+
+    // The index is dropped when its column is replaced.
+    df.with_column(Column::new("a".into(), [1, 2, 3, 4, 5]))?;
+    assert!(df.sorted_index("a").is_none());
+    let out = df.lazy().filter(col("a").gt_eq(lit(3))).collect()?;
+    assert_eq!(
+        out.column("b")?.i32()?.to_vec(),
+        &[Some(2), Some(3), Some(4)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "plan_cache")]
+fn test_plan_cache() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3, 4, 5],
+        "b" => [0, 1, 2, 3, 4],
+    ]?;
+    let query = |threshold: i32| {
+        df.clone()
+            .lazy()
+            .filter(col("a").gt(lit(threshold)))
+            .select([col("b").sum()])
+            .collect()
+    };
+
+    set_plan_cache_capacity(16);
+    let before = plan_cache_stats();
+    // The first query is optimized, the next ones reuse its plan with other literals.
+    for (threshold, expected) in [(2, 9), (2, 9), (0, 10), (3, 7)] {
+        let out = query(threshold)?;
+        assert_eq!(out.column("b")?.i32()?.get(0), Some(expected));
+    }
+    let after = plan_cache_stats();
+    set_plan_cache_capacity(0);
+
+    assert!(after.hits >= before.hits + 3);
+    assert_eq!(after.capacity, 16);
+    assert_eq!(plan_cache_stats().len, 0);
+    Ok(())
+}
+
+#[test]
+fn test_bind_parameters() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3, 4, 5],
+        "b" => [0, 1, 2, 3, 4],
+    ]?;
+    let template = df
+        .lazy()
+        .filter(col("a").gt(param("min")))
+        .select([col("b").sum() * param("scale")]);
+
+    for (min, scale, expected) in [(2, 1, 9), (0, 2, 20)] {
+        let params = PlHashMap::from_iter([
+            ("min".into(), LiteralValue::from(Scalar::from(min))),
+            ("scale".into(), LiteralValue::from(Scalar::from(scale))),
+        ]);
+        let out = template.clone().bind_parameters(&params).collect()?;
+        assert_eq!(out.column("b")?.i32()?.get(0), Some(expected));
+    }
+
+    // Parameters that are not bound are an error.
+    let params = PlHashMap::from_iter([("min".into(), LiteralValue::from(Scalar::from(0)))]);
+    let err = template.bind_parameters(&params).collect().unwrap_err();
+    assert!(err.to_string().contains("parameter 'scale' is not bound"));
+
+    // Parameters in the expressions of functions on the frame are bound too.
+    let df = df![
+        "a" => [1.0, f64::NAN, 3.0],
+    ]?;
+    let params = PlHashMap::from_iter([("fill".into(), LiteralValue::from(Scalar::from(2.0)))]);
+    let out = df
+        .lazy()
+        .fill_nan(param("fill"))
+        .sum()
+        .bind_parameters(&params)
+        .collect()?;
+    assert_eq!(out.column("a")?.f64()?.get(0), Some(6.0));
+    Ok(())
+}
+
+#[test]
+fn test_access_policy() -> PolarsResult<()> {
+    struct TenantPolicy;
+
+    impl AccessPolicy for TenantPolicy {
+        fn row_filter(&self, principal: &Principal, _scan: &DslPlan) -> PolarsResult<Option<Expr>> {
+            if principal.has_role("admin") {
+                return Ok(None);
+            }
+            Ok(Some(col("tenant").eq(lit(principal.name.clone()))))
+        }
+
+        fn column_masks(&self, principal: &Principal, _scan: &DslPlan) -> PolarsResult<Vec<Expr>> {
+            if principal.has_role("admin") {
+                return Ok(vec![]);
+            }
+            Ok(vec![lit(NULL).cast(DataType::Int32).alias("secret")])
+        }
+    }
+
+    let df = df![
+        "tenant" => ["x", "y", "x"],
+        "secret" => [1, 2, 3],
+    ]?;
+    // The filter is applied before the user's query, and can use the masked column.
+    let lf = df
+        .lazy()
+        .filter(col("secret").is_null().or(col("secret").gt(lit(1))));
+
+    let user = Principal::new("x");
+    let out = lf
+        .clone()
+        .with_access_policy(&TenantPolicy, &user)?
+        .collect()?;
+    assert_eq!(out.height(), 2);
+    assert_eq!(out.column("secret")?.null_count(), 2);
+
+    let admin = Principal::new("root").with_roles(["admin"]);
+    let out = lf.with_access_policy(&TenantPolicy, &admin)?.collect()?;
+    assert_eq!(out.height(), 2);
+    assert_eq!(out.column("secret")?.null_count(), 0);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "random", feature = "round_series"))]
+fn test_dp_aggregations() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b"],
+        "x" => [1i64, 5, 1000],
+    ]?;
+    let lf = df.clone().lazy().select([
+        col("x").dp_sum(1.0, (0.0, 10.0))?,
+        col("x").dp_count(0.5).alias("n"),
+    ]);
+    assert_eq!(lf.clone().privacy_cost()?, 1.5);
+
+    // Queries with noisy expressions don't run without a budget.
+    let previous = set_privacy_budget(None);
+    let err = lf.clone().collect().unwrap_err();
+    assert!(err.to_string().contains("requires a privacy budget"));
+
+    let budget = Arc::new(PrivacyBudget::new(2.0));
+    set_privacy_budget(Some(budget.clone()));
+    let out = lf.clone().collect()?;
+    assert_eq!(out.column("x")?.dtype(), &DataType::Float64);
+    assert_eq!(budget.remaining(), 0.5);
+
+    let err = lf.collect().unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("exceeds the remaining privacy budget")
+    );
+    assert_eq!(budget.remaining(), 0.5);
+
+    // The groups would be released without noise.
+    let err = df
+        .clone()
+        .lazy()
+        .group_by([col("g")])
+        .agg([col("x").dp_count(0.1)])
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("group-by aggregations"));
+    assert_eq!(budget.remaining(), 0.5);
+
+    // A seed makes the noise reproducible for testing.
+    let noisy = df.clone().lazy().select([col("x").dp_noise(0.125, 1.0)]);
+    set_dp_noise_seed(Some(0));
+    let a = noisy.clone().collect()?;
+    let b = noisy.collect()?;
+    set_dp_noise_seed(None);
+    assert!(a.equals(&b));
+    assert_ne!(a.column("x")?.f64()?.get(0), Some(1.0));
+    assert_eq!(budget.remaining(), 0.25);
+
+    // Falling back from the streaming engine to the in-memory engine spends the budget once.
+    #[cfg(feature = "new_streaming")]
+    {
+        // Anonymous scans are not supported by the streaming engine.
+        struct Scan(DataFrame);
+
+        impl AnonymousScan for Scan {
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+
+            fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+                Ok(self.0.clone())
+            }
+        }
+
+        let args = ScanArgsAnonymous {
+            schema: Some(df.schema().clone()),
+            ..ScanArgsAnonymous::default()
+        };
+        let lf =
+            LazyFrame::anonymous_scan(Arc::new(Scan(df)), args)?.select([col("x").dp_count(0.25)]);
+        unsafe { std::env::set_var("POLARS_AUTO_NEW_STREAMING", "1") };
+        let out = lf.collect();
+        unsafe { std::env::remove_var("POLARS_AUTO_NEW_STREAMING") };
+        assert_eq!(out?.height(), 1);
+        assert_eq!(budget.remaining(), 0.0);
+    }
+
+    set_privacy_budget(previous);
+    Ok(())
+}
+
+#[test]
+fn test_map_batch_udf() -> PolarsResult<()> {
+    use arrow::array::{Array, ArrayRef, PrimitiveArray};
+
+    struct AddI32;
+
+    impl BatchUdf for AddI32 {
+        fn name(&self) -> &str {
+            "add_i32"
+        }
+
+        fn properties(&self) -> BatchUdfProperties {
+            BatchUdfProperties {
+                elementwise: true,
+                propagates_nulls: true,
+            }
+        }
+
+        fn output_field(&self, fields: &[Field]) -> PolarsResult<Field> {
+            Ok(Field::new(fields[0].name().clone(), DataType::Int32))
+        }
+
+        fn call(&self, arrays: &[&dyn Array]) -> PolarsResult<ArrayRef> {
+            let values = |array: &dyn Array| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<i32>>()
+                    .unwrap();
+                array.values().clone()
+            };
+            let (l, r) = (values(arrays[0]), values(arrays[1]));
+            let out = l.iter().zip(r.iter()).map(|(l, r)| l.wrapping_add(*r));
+            Ok(PrimitiveArray::from_vec(out.collect()).boxed())
+        }
+    }
+
+    let df = df![
+        "a" => [Some(1), None, Some(3)],
+        "b" => [Some(10), Some(20), None],
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            map_batch_udf(Arc::new(AddI32), [col("a"), col("b")]),
+            map_batch_udf(Arc::new(AddI32), [col("a"), lit(5)]).alias("c"),
+        ])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("a")?.i32()?), [Some(11), None, None]);
+    assert_eq!(Vec::from(out.column("c")?.i32()?), [Some(6), None, Some(8)]);
+    Ok(())
+}
+
+#[test]
+fn test_record_failure() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 1000],
+        "b" => [1, 2, 3],
+    ]?;
+    let lf = df
+        .lazy()
+        .filter(col("b").gt(lit(1)))
+        .select([col("a").strict_cast(DataType::Int8)]);
+
+    let bundle = lf.record_failure(usize::MAX)?.unwrap();
+    let plan = bundle.plan.as_ref();
+    let IR::DataFrameScan { df, .. } = plan.lp_arena.get(plan.root().get_inputs()[0]) else {
+        panic!("expected a scan of the recorded input");
+    };
+    assert_eq!(df.height(), 2);
+    let err = LazyFrame::replay(&bundle).unwrap_err();
+    assert_eq!(err.to_string(), bundle.error);
+
+    let lf = df![
+        "a" => [1, 2, 3],
+    ]?
+    .lazy()
+    .select([col("a").strict_cast(DataType::Int8)]);
+    assert!(lf.record_failure(usize::MAX)?.is_none());
+    Ok(())
+}
@@ -13,6 +13,8 @@ mod merge_sorted;
 mod projection;
 mod projection_simple;
 mod projection_utils;
+mod record;
+mod row_count;
 mod scan;
 mod slice;
 mod sort;
@@ -29,6 +31,8 @@ use polars_core::POOL;
 use polars_plan::utils::*;
 use projection_utils::*;
 use rayon::prelude::*;
+pub use record::{RecordedFailure, ReplayRecorder};
+pub use row_count::NodeRowCounts;
 
 pub(super) use self::cache::*;
 pub(super) use self::ext_context::*;
@@ -45,6 +49,8 @@ pub(super) use self::join::*;
 pub(super) use self::merge_sorted::*;
 pub(super) use self::projection::*;
 pub(super) use self::projection_simple::*;
+pub(super) use self::record::*;
+pub(super) use self::row_count::*;
 pub(super) use self::scan::*;
 pub(super) use self::slice::*;
 pub(super) use self::sort::*;
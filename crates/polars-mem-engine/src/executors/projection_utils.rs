@@ -286,7 +286,9 @@ pub(super) fn evaluate_physical_expressions(
         run_exprs_seq
     };
 
-    let selected_columns = expr_runner(df, exprs, state)?;
+    let selected_columns = expr_runner(df, exprs, state);
+    state.clear_expr_memo();
+    let selected_columns = selected_columns?;
 
     if has_windows {
         state.clear_window_expr_cache();
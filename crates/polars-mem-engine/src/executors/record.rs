@@ -0,0 +1,104 @@
+use std::sync::Mutex;
+
+use polars_utils::UnitVec;
+
+use super::*;
+
+/// The failing node of a plan, with the outputs of its inputs.
+pub struct RecordedFailure {
+    pub node: Node,
+    /// The outputs of the inputs of the node, in the order of its inputs.
+    pub inputs: Vec<DataFrame>,
+}
+
+#[derive(Default)]
+struct RecorderState {
+    /// The outputs of the nodes whose consumers have not succeeded yet.
+    outputs: PlHashMap<Node, DataFrame>,
+    recorded_bytes: usize,
+    failed: bool,
+    failure: Option<RecordedFailure>,
+}
+
+/// Records the outputs of the executors of the nodes of a plan to replay the first node that
+/// fails, see [`create_physical_plan_with_recorder`](crate::create_physical_plan_with_recorder).
+///
+/// The output of a node is kept until the node that consumes it succeeds, and is only kept if
+/// the estimated size of the kept outputs stays below `max_bytes`.
+pub struct ReplayRecorder {
+    max_bytes: usize,
+    state: Mutex<RecorderState>,
+}
+
+impl ReplayRecorder {
+    pub fn new(max_bytes: usize) -> Self {
+        Self {
+            max_bytes,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Takes the node that failed first, or `None` if no node failed or if the outputs of its
+    /// inputs could not all be kept.
+    pub fn take_failure(&self) -> Option<RecordedFailure> {
+        self.state.lock().unwrap().failure.take()
+    }
+
+    fn record(&self, node: Node, inputs: &[Node], df: &DataFrame) {
+        let mut state = self.state.lock().unwrap();
+        for input in inputs {
+            if let Some(output) = state.outputs.remove(input) {
+                state.recorded_bytes -= output.estimated_size();
+            }
+        }
+
+        let size = df.estimated_size();
+        if state.recorded_bytes + size <= self.max_bytes {
+            state.recorded_bytes += size;
+            state.outputs.insert(node, df.clone());
+        }
+    }
+
+    fn fail(&self, node: Node, inputs: &[Node]) {
+        let mut state = self.state.lock().unwrap();
+        // The consumers of the node fail with the same error.
+        if std::mem::replace(&mut state.failed, true) {
+            return;
+        }
+
+        let inputs = inputs
+            .iter()
+            .map(|input| state.outputs.get(input).cloned())
+            .collect::<Option<Vec<_>>>();
+        state.failure = inputs.map(|inputs| RecordedFailure { node, inputs });
+        state.outputs.clear();
+    }
+}
+
+/// Records the output of the executor of a node, or the outputs of its inputs if it fails.
+pub struct RecordExec {
+    pub input: Box<dyn Executor>,
+    pub node: Node,
+    /// The inputs of the node in the plan.
+    pub inputs: UnitVec<Node>,
+    pub recorder: Arc<ReplayRecorder>,
+}
+
+impl Executor for RecordExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        match self.input.execute(state) {
+            Ok(df) => {
+                self.recorder.record(self.node, &self.inputs, &df);
+                Ok(df)
+            },
+            Err(err) => {
+                self.recorder.fail(self.node, &self.inputs);
+                Err(err)
+            },
+        }
+    }
+
+    fn is_cache_prefiller(&self) -> bool {
+        self.input.is_cache_prefiller()
+    }
+}
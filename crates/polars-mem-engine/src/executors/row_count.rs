@@ -0,0 +1,29 @@
+use std::sync::Mutex;
+
+use super::*;
+
+/// The number of rows that the executors of the nodes of a plan output, see
+/// [`create_physical_plan_with_row_counts`](crate::create_physical_plan_with_row_counts).
+pub type NodeRowCounts = Arc<Mutex<PlHashMap<Node, usize>>>;
+
+/// Records the number of rows that the executor of a node outputs.
+pub struct RowCountExec {
+    pub input: Box<dyn Executor>,
+    pub node: Node,
+    pub row_counts: NodeRowCounts,
+}
+
+impl Executor for RowCountExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let df = self.input.execute(state)?;
+        self.row_counts
+            .lock()
+            .unwrap()
+            .insert(self.node, df.height());
+        Ok(df)
+    }
+
+    fn is_cache_prefiller(&self) -> bool {
+        self.input.is_cache_prefiller()
+    }
+}
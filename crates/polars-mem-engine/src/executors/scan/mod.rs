@@ -3,6 +3,8 @@ mod python_scan;
 
 use std::mem;
 
+use polars_core::series::IsSorted;
+use polars_plan::plans::column_index::index_candidates;
 use polars_utils::slice_enum::Slice;
 
 #[cfg(feature = "python")]
@@ -15,10 +17,33 @@ use crate::scan_predicate::ScanPredicate;
 pub struct DataFrameExec {
     pub(crate) df: Arc<DataFrame>,
     pub(crate) projection: Option<Vec<PlSmallStr>>,
+    /// Only produce the rows that the indexes on these columns give for these bounds. This may
+    /// be a superset of the rows within the bounds, so a filter must still be applied.
+    pub(crate) index_bounds: Vec<(PlSmallStr, ValueBounds)>,
+    /// Produce the rows in the order of the sorted index on this column.
+    pub(crate) sorted_by_index: Option<PlSmallStr>,
+}
+
+impl DataFrameExec {
+    pub(crate) fn new(df: Arc<DataFrame>, output_schema: Option<&SchemaRef>) -> Self {
+        Self {
+            df,
+            projection: output_schema.map(|s| s.iter_names_cloned().collect()),
+            index_bounds: vec![],
+            sorted_by_index: None,
+        }
+    }
 }
 
 impl Executor for DataFrameExec {
     fn execute(&mut self, _state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        // The indexes are consulted before the projection, which drops them.
+        let candidates = index_candidates(&self.df, &self.index_bounds)?;
+        let sorted_perm = self
+            .sorted_by_index
+            .as_ref()
+            .and_then(|name| self.df.sorted_index(name)?.sorted_permutation().cloned());
+
         let df = mem::take(&mut self.df);
         let mut df = Arc::try_unwrap(df).unwrap_or_else(|df| (*df).clone());
 
@@ -28,6 +53,21 @@ impl Executor for DataFrameExec {
             df = df.select(projection.iter().cloned())?;
         }
 
+        if let Some(idx) = candidates {
+            // SAFETY: the indexes give rows in bounds.
+            df = unsafe { df.take_unchecked(&idx) };
+        }
+        if let Some(perm) = sorted_perm {
+            // SAFETY: the permutation is in bounds.
+            df = unsafe { df.take_unchecked(&perm) };
+            let name = self.sorted_by_index.as_ref().unwrap();
+            if let Some(idx) = df.get_column_index(name) {
+                // SAFETY: setting the sorted flag doesn't change the schema.
+                let columns = unsafe { df.columns_mut_retain_schema() };
+                columns[idx].set_sorted_flag(IsSorted::Ascending);
+            }
+        }
+
         Ok(df)
     }
 }
@@ -38,7 +78,6 @@ pub(crate) struct AnonymousScanExec {
     pub(crate) file_info: FileInfo,
     pub(crate) predicate: Option<ScanPredicate>,
     pub(crate) output_schema: Option<SchemaRef>,
-    pub(crate) predicate_has_windows: bool,
 }
 
 impl Executor for AnonymousScanExec {
@@ -58,31 +97,11 @@ impl Executor for AnonymousScanExec {
             state.insert_has_window_function_flag()
         }
 
-        match (self.function.allows_predicate_pushdown(), &self.predicate) {
-            (true, Some(predicate)) => state.record(
-                || {
-                    args.predicate = predicate.predicate.as_expression().cloned();
-                    self.function.scan(args)
-                },
-                "anonymous_scan".into(),
-            ),
-            (false, Some(predicate)) => state.record(
-                || {
-                    let mut df = self.function.scan(args)?;
-                    let s = predicate.predicate.evaluate(&df, state)?;
-                    if self.predicate_has_windows {
-                        state.clear_window_expr_cache()
-                    }
-                    let mask = s.bool().map_err(
-                        |_| polars_err!(ComputeError: "filter predicate was not of type boolean"),
-                    )?;
-                    df = df.filter(mask)?;
-
-                    Ok(df)
-                },
-                "anonymous_scan".into(),
-            ),
-            _ => state.record(|| self.function.scan(args), "anonymous_scan".into()),
-        }
+        // The predicate pushdown only leaves the predicates that the scan supports.
+        args.predicate = self
+            .predicate
+            .as_ref()
+            .and_then(|predicate| predicate.predicate.as_expression().cloned());
+        state.record(|| self.function.scan(args), "anonymous_scan".into())
     }
 }
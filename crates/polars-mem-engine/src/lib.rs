@@ -7,7 +7,10 @@ mod planner;
 mod prelude;
 pub mod scan_predicate;
 
-pub use executors::{Executor, column_to_mask};
+pub use executors::{Executor, NodeRowCounts, RecordedFailure, ReplayRecorder, column_to_mask};
 #[cfg(feature = "python")]
 pub use planner::python_scan_predicate;
-pub use planner::{StreamingExecutorBuilder, create_multiple_physical_plans, create_physical_plan};
+pub use planner::{
+    StreamingExecutorBuilder, create_multiple_physical_plans, create_physical_plan,
+    create_physical_plan_with_recorder, create_physical_plan_with_row_counts,
+};
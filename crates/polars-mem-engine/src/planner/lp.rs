@@ -1,6 +1,8 @@
 use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_expr::state::ExecutionState;
+use polars_plan::plans::MintermIter;
+use polars_plan::plans::column_index::index_bounds;
 use polars_plan::plans::expr_ir::ExprIR;
 use polars_plan::prelude::sink::CallbackSinkType;
 use polars_utils::unique_id::UniqueId;
@@ -10,7 +12,10 @@ use recursive::recursive;
 #[cfg(feature = "python")]
 use self::python_dsl::PythonScanSource;
 use super::*;
-use crate::executors::{self, CachePrefiller, Executor, GroupByStreamingExec, SinkExecutor};
+use crate::executors::{
+    self, CachePrefiller, Executor, GroupByStreamingExec, NodeRowCounts, ReplayRecorder,
+    SinkExecutor,
+};
 use crate::scan_predicate::functions::create_scan_predicate;
 
 pub type StreamingExecutorBuilder =
@@ -53,6 +58,8 @@ fn partitionable_gb(
 struct ConversionState {
     has_cache_child: bool,
     has_cache_parent: bool,
+    row_counts: Option<NodeRowCounts>,
+    recorder: Option<Arc<ReplayRecorder>>,
 }
 
 impl ConversionState {
@@ -60,6 +67,8 @@ impl ConversionState {
         Ok(ConversionState {
             has_cache_child: false,
             has_cache_parent: false,
+            row_counts: None,
+            recorder: None,
         })
     }
 
@@ -77,8 +86,46 @@ pub fn create_physical_plan(
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
     build_streaming_executor: Option<StreamingExecutorBuilder>,
+) -> PolarsResult<Box<dyn Executor>> {
+    let state = ConversionState::new()?;
+    create_physical_plan_with_state(root, lp_arena, expr_arena, state, build_streaming_executor)
+}
+
+/// Like [`create_physical_plan`], but the executor of every node records the number of rows that
+/// it outputs in `row_counts`, keyed by the node in `lp_arena`.
+pub fn create_physical_plan_with_row_counts(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    build_streaming_executor: Option<StreamingExecutorBuilder>,
+    row_counts: NodeRowCounts,
 ) -> PolarsResult<Box<dyn Executor>> {
     let mut state = ConversionState::new()?;
+    state.row_counts = Some(row_counts);
+    create_physical_plan_with_state(root, lp_arena, expr_arena, state, build_streaming_executor)
+}
+
+/// Like [`create_physical_plan`], but the executor of every node records its output or, if it
+/// fails, the outputs of its inputs in `recorder`, keyed by the node in `lp_arena`.
+pub fn create_physical_plan_with_recorder(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    build_streaming_executor: Option<StreamingExecutorBuilder>,
+    recorder: Arc<ReplayRecorder>,
+) -> PolarsResult<Box<dyn Executor>> {
+    let mut state = ConversionState::new()?;
+    state.recorder = Some(recorder);
+    create_physical_plan_with_state(root, lp_arena, expr_arena, state, build_streaming_executor)
+}
+
+fn create_physical_plan_with_state(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    mut state: ConversionState,
+    build_streaming_executor: Option<StreamingExecutorBuilder>,
+) -> PolarsResult<Box<dyn Executor>> {
     let mut cache_nodes = Default::default();
     let plan = create_physical_plan_impl(
         root,
@@ -196,7 +243,6 @@ pub fn python_scan_predicate(
         // Convert to a pyarrow eval string.
         if matches!(options.python_source, PythonScanSource::Pyarrow) {
             use polars_core::config::verbose_print_sensitive;
-            use polars_plan::plans::MintermIter;
 
             // Split into AND-minterms and convert each independently.
             let mut residual_predicate_nodes: Vec<Node> = vec![];
@@ -281,7 +327,6 @@ pub fn python_scan_predicate(
     Ok((predicate, predicate_serialized))
 }
 
-#[recursive]
 fn create_physical_plan_impl(
     root: Node,
     lp_arena: &mut Arena<IR>,
@@ -290,6 +335,46 @@ fn create_physical_plan_impl(
     // Cache nodes in order of discovery
     cache_nodes: &mut PlIndexMap<UniqueId, executors::CachePrefill>,
     build_streaming_executor: Option<StreamingExecutorBuilder>,
+) -> PolarsResult<Box<dyn Executor>> {
+    let row_counts = state.row_counts.clone();
+    let recorder = state.recorder.clone();
+    let inputs = lp_arena.get(root).get_inputs();
+    let exec = create_physical_plan_node(
+        root,
+        lp_arena,
+        expr_arena,
+        state,
+        cache_nodes,
+        build_streaming_executor,
+    )?;
+    let exec: Box<dyn Executor> = match row_counts {
+        Some(row_counts) => Box::new(executors::RowCountExec {
+            input: exec,
+            node: root,
+            row_counts,
+        }),
+        None => exec,
+    };
+    Ok(match recorder {
+        Some(recorder) => Box::new(executors::RecordExec {
+            input: exec,
+            node: root,
+            inputs,
+            recorder,
+        }),
+        None => exec,
+    })
+}
+
+#[recursive]
+fn create_physical_plan_node(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    state: &mut ConversionState,
+    // Cache nodes in order of discovery
+    cache_nodes: &mut PlIndexMap<UniqueId, executors::CachePrefill>,
+    build_streaming_executor: Option<StreamingExecutorBuilder>,
 ) -> PolarsResult<Box<dyn Executor>> {
     use IR::*;
 
@@ -408,7 +493,23 @@ fn create_physical_plan_impl(
         Filter { input, predicate } => {
             let streamable = is_elementwise_rec(predicate.node(), expr_arena);
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
-            let input = recurse!(input, state)?;
+            // Let the indexes of an in-memory frame select the rows to filter.
+            let indexed_scan = match lp_arena.get(input) {
+                DataFrameScan {
+                    df, output_schema, ..
+                } => {
+                    let index_bounds = index_bounds(df, predicate.node(), expr_arena);
+                    (!index_bounds.is_empty()).then(|| executors::DataFrameExec {
+                        index_bounds,
+                        ..executors::DataFrameExec::new(df.clone(), output_schema.as_ref())
+                    })
+                },
+                _ => None,
+            };
+            let input: Box<dyn Executor> = match indexed_scan {
+                Some(scan) => Box::new(scan),
+                None => recurse!(input, state)?,
+            };
             let mut state = ExpressionConversionState::new(true);
             let predicate =
                 create_physical_expr(&predicate, expr_arena, &input_schema, &mut state)?;
@@ -462,7 +563,6 @@ fn create_physical_plan_impl(
                         unified_scan_args,
                         file_info,
                         output_schema,
-                        predicate_has_windows: expr_conversion_state.has_windows,
                     }))
                 },
                 #[cfg_attr(
@@ -488,11 +588,18 @@ fn create_physical_plan_impl(
         } => {
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
             let input = recurse!(input, state)?;
+            let all_elementwise = expr
+                .iter()
+                .all(|e| is_elementwise_rec(e.node(), expr_arena));
             let mut state = ExpressionConversionState::new(POOL.current_num_threads() > expr.len());
+            // Vertically parallel projections evaluate the expressions on every chunk.
+            if !all_elementwise {
+                state.memoize_duplicates(&expr, expr_arena);
+            }
             let phys_expr =
                 create_physical_expressions_from_irs(&expr, expr_arena, &input_schema, &mut state)?;
 
-            let allow_vertical_parallelism = options.should_broadcast && expr.iter().all(|e| is_elementwise_rec(e.node(), expr_arena))
+            let allow_vertical_parallelism = options.should_broadcast && all_elementwise
                 // If all columns are literal we would get a 1 row per thread.
                 && !phys_expr.iter().all(|p| {
                     p.is_literal()
@@ -511,10 +618,10 @@ fn create_physical_plan_impl(
         },
         DataFrameScan {
             df, output_schema, ..
-        } => Ok(Box::new(executors::DataFrameExec {
+        } => Ok(Box::new(executors::DataFrameExec::new(
             df,
-            projection: output_schema.map(|s| s.iter_names_cloned().collect()),
-        })),
+            output_schema.as_ref(),
+        ))),
         Sort {
             input,
             by_column,
@@ -674,10 +781,31 @@ fn create_physical_plan_impl(
             let schema_left = lp_arena.get(input_left).schema(lp_arena).into_owned();
             let schema_right = lp_arena.get(input_right).schema(lp_arena).into_owned();
 
+            // An asof join needs the right frame sorted by its key, which the sorted index of an
+            // in-memory frame gives without sorting.
+            let sorted_right_scan = if options.args.how.is_asof()
+                && let [right_key] = right_on.as_slice()
+                && let AExpr::Column(name) = expr_arena.get(right_key.node())
+                && let DataFrameScan {
+                    df, output_schema, ..
+                } = lp_arena.get(input_right)
+                && df.sorted_index(name).is_some()
+            {
+                Some(executors::DataFrameExec {
+                    sorted_by_index: Some(name.clone()),
+                    ..executors::DataFrameExec::new(df.clone(), output_schema.as_ref())
+                })
+            } else {
+                None
+            };
+
             let (input_left, input_right) = state.with_new_branch(|new_state| {
                 (
                     recurse!(input_left, new_state),
-                    recurse!(input_right, new_state),
+                    match sorted_right_scan {
+                        Some(scan) => Ok(Box::new(scan) as Box<dyn Executor>),
+                        None => recurse!(input_right, new_state),
+                    },
                 )
             });
             let input_left = input_left?;
@@ -748,13 +876,17 @@ fn create_physical_plan_impl(
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
             let input = recurse!(input, state)?;
 
-            let allow_vertical_parallelism = options.should_broadcast
-                && exprs
-                    .iter()
-                    .all(|e| is_elementwise_rec(e.node(), expr_arena));
+            let all_elementwise = exprs
+                .iter()
+                .all(|e| is_elementwise_rec(e.node(), expr_arena));
+            let allow_vertical_parallelism = options.should_broadcast && all_elementwise;
 
             let mut state =
                 ExpressionConversionState::new(POOL.current_num_threads() > exprs.len());
+            // Vertically parallel projections evaluate the expressions on every chunk.
+            if !all_elementwise {
+                state.memoize_duplicates(&exprs, expr_arena);
+            }
 
             let phys_exprs = create_physical_expressions_from_irs(
                 &exprs,
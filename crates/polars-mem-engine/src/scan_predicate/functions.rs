@@ -17,6 +17,8 @@ use polars_plan::dsl::{
 };
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
 use polars_plan::plans::hive::HivePartitionsDf;
+#[cfg(feature = "dtype-struct")]
+use polars_plan::plans::predicates::struct_field_dtype;
 use polars_plan::plans::predicates::{aexpr_to_column_predicates, aexpr_to_skip_batch_predicate};
 use polars_plan::plans::{AExpr, ExprIRDisplay, FileInfo, IR, MintermIter};
 use polars_plan::utils::aexpr_to_leaf_names_iter;
@@ -173,13 +175,22 @@ pub fn create_scan_predicate(
                 .predicates
                 .into_iter()
                 .map(|(n, (p, s))| {
+                    // Predicates on struct fields refer to the field by the name of the column.
+                    #[cfg(feature = "dtype-struct")]
+                    let field_schema = column_predicates.struct_field_paths.get(&n).map(|path| {
+                        let dtype = struct_field_dtype(schema.get(&n).unwrap(), path).unwrap();
+                        Arc::new(Schema::from_iter([(n.clone(), dtype.clone())]))
+                    });
+                    #[cfg(not(feature = "dtype-struct"))]
+                    let field_schema = None;
+
                     PolarsResult::Ok((
                         n,
                         (
                             create_physical_expr(
                                 &ExprIR::new(p, OutputName::Alias(PlSmallStr::EMPTY)),
                                 expr_arena,
-                                schema,
+                                field_schema.as_ref().unwrap_or(schema),
                                 state,
                             )?,
                             s,
@@ -187,11 +198,13 @@ pub fn create_scan_predicate(
                     ))
                 })
                 .collect::<PolarsResult<PlHashMap<_, _>>>()?,
+            struct_field_paths: column_predicates.struct_field_paths,
             is_sumwise_complete: column_predicates.is_sumwise_complete,
         }
     } else {
         PhysicalColumnPredicates {
             predicates: PlHashMap::default(),
+            struct_field_paths: PlHashMap::default(),
             is_sumwise_complete: false,
         }
     };
@@ -435,6 +448,7 @@ where
         cache: _,
         glob: _,
         hidden_file_prefix: _,
+        file_listing: _,
         projection: _,
         column_mapping: _,
         default_values,
@@ -445,7 +459,10 @@ where
         missing_columns_policy: _,
         extra_columns_policy: _,
         include_file_paths: _,
+        provenance: _,
+        schema_evolution: _,
         table_statistics,
+        statistics_sidecar: _,
         deletion_files,
         row_count,
     } = unified_scan_args.as_mut()
@@ -504,20 +521,31 @@ where
             .collect::<Vec<_>>()
     });
 
-    *deletion_files = deletion_files.as_ref().and_then(|x| match x {
-        DeletionFilesList::IcebergPositionDelete(deletions) => {
-            let mut out = None;
+    fn select_sources<V: Clone>(
+        deletions: &PlIndexMap<usize, V>,
+        selected_path_indices: impl Iterator<Item = usize>,
+    ) -> Option<Arc<PlIndexMap<usize, V>>> {
+        let mut out = None;
+        let n = selected_path_indices.size_hint().0;
 
-            for (out_idx, source_idx) in selected_path_indices.clone().enumerate() {
-                if let Some(v) = deletions.get(&source_idx) {
-                    out.get_or_insert_with(|| {
-                        PlIndexMap::with_capacity(selected_path_indices.size_hint().0 - out_idx)
-                    })
+        for (out_idx, source_idx) in selected_path_indices.enumerate() {
+            if let Some(v) = deletions.get(&source_idx) {
+                out.get_or_insert_with(|| PlIndexMap::with_capacity(n.saturating_sub(out_idx)))
                     .insert(out_idx, v.clone());
-                }
             }
+        }
 
-            out.map(|x| DeletionFilesList::IcebergPositionDelete(Arc::new(x)))
+        out.map(Arc::new)
+    }
+
+    *deletion_files = deletion_files.as_ref().and_then(|x| match x {
+        DeletionFilesList::IcebergPositionDelete(deletions) => {
+            select_sources(deletions, selected_path_indices.clone())
+                .map(DeletionFilesList::IcebergPositionDelete)
+        },
+        DeletionFilesList::PositionDelete(deletions) => {
+            select_sources(deletions, selected_path_indices.clone())
+                .map(DeletionFilesList::PositionDelete)
         },
     });
 
@@ -1,4 +1,5 @@
 pub mod functions;
+pub mod pruning_report;
 pub mod skip_files_mask;
 use core::fmt;
 use std::sync::Arc;
@@ -52,6 +53,7 @@ impl fmt::Debug for ScanPredicate {
 pub struct PhysicalColumnPredicates {
     pub predicates:
         PlHashMap<PlSmallStr, (Arc<dyn PhysicalExpr>, Option<SpecializedColumnPredicate>)>,
+    pub struct_field_paths: PlHashMap<PlSmallStr, Vec<PlSmallStr>>,
     pub is_sumwise_complete: bool,
 }
 
@@ -200,6 +202,7 @@ impl ScanPredicate {
                     .iter()
                     .map(|(n, (p, s))| (n.clone(), (phys_expr_to_io_expr(p.clone()), s.clone())))
                     .collect(),
+                struct_field_paths: self.column_predicates.struct_field_paths.clone(),
                 is_sumwise_complete: self.column_predicates.is_sumwise_complete,
             }),
             hive_predicate: self.hive_predicate.clone().map(phys_expr_to_io_expr),
@@ -0,0 +1,284 @@
+use polars_core::prelude::*;
+use polars_expr::ExpressionConversionState;
+#[cfg(feature = "parquet")]
+use polars_io::predicates::ScanIOPredicate;
+use polars_plan::dsl::ScanSources;
+#[cfg(feature = "parquet")]
+use polars_plan::dsl::{FileScanIR, ScanSourceRef, UnifiedScanArgs};
+use polars_plan::plans::{AExpr, ArenaLpIter, IR};
+use polars_utils::arena::{Arena, Node};
+use polars_utils::format_pl_smallstr;
+
+use crate::scan_predicate::functions::{create_scan_predicate, initialize_scan_predicate};
+
+/// Reports the files and row groups of every scan in the plan that are skipped because of the
+/// predicate pushed down into the scan.
+///
+/// The plan should be optimized without applying the scan predicates to the scans (see
+/// [`apply_scan_predicate_to_scan_ir`]), as that removes the skipped files from the scans.
+///
+/// The report holds a row per file with the columns:
+/// * `scan`: Index of the scan in the plan.
+/// * `path`: Path of the file.
+/// * `pruned`: Whether the whole file is skipped.
+/// * `reason`: What allowed skipping the file, `"hive partitions"` or `"table statistics"`.
+/// * `row_groups`: Number of row groups in the file, for Parquet files that are not skipped.
+/// * `pruned_row_groups`: Indices of the row groups that are skipped based on their statistics.
+/// * `predicate`: The predicate of the scan.
+///
+/// [`apply_scan_predicate_to_scan_ir`]: crate::scan_predicate::functions::apply_scan_predicate_to_scan_ir
+pub fn scan_pruning_report(
+    root: Node,
+    ir_arena: &Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<DataFrame> {
+    let mut visited = PlHashSet::new();
+    let scan_nodes: Vec<Node> = ir_arena
+        .iter(root)
+        .filter(|(node, ir)| matches!(ir, IR::Scan { .. }) && visited.insert(*node))
+        .map(|(node, _)| node)
+        .collect();
+
+    let mut report = PruningReportBuilder::default();
+
+    for (scan_idx, node) in scan_nodes.into_iter().enumerate() {
+        report_scan(
+            IdxSize::try_from(scan_idx).unwrap(),
+            node,
+            ir_arena,
+            expr_arena,
+            &mut report,
+        )?;
+    }
+
+    report.finish()
+}
+
+fn report_scan(
+    scan_idx: IdxSize,
+    scan_ir_node: Node,
+    ir_arena: &Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    report: &mut PruningReportBuilder,
+) -> PolarsResult<()> {
+    let scan_ir_schema = IR::schema(ir_arena.get(scan_ir_node), ir_arena).into_owned();
+
+    let IR::Scan {
+        sources,
+        hive_parts,
+        predicate,
+        unified_scan_args,
+        file_info,
+        scan_type,
+        ..
+    } = ir_arena.get(scan_ir_node)
+    else {
+        unreachable!()
+    };
+
+    let predicate_str = predicate
+        .as_ref()
+        .map(|p| format_pl_smallstr!("{}", p.display(expr_arena)));
+
+    let scan_predicate = predicate
+        .as_ref()
+        .map(|predicate| {
+            PolarsResult::Ok(
+                create_scan_predicate(
+                    predicate,
+                    expr_arena,
+                    &scan_ir_schema,
+                    hive_parts.as_ref().map(|hp| hp.df().schema().as_ref()),
+                    &mut ExpressionConversionState::new(true),
+                    true,  // create_skip_batch_predicate
+                    false, // create_column_predicates
+                )?
+                .to_io(None, file_info.schema.clone()),
+            )
+        })
+        .transpose()?;
+
+    // Files are skipped under the same conditions as in `apply_scan_predicate_to_scan_ir`.
+    let (skip_files_mask, predicate_to_readers) =
+        if unified_scan_args.has_row_index_or_slice() || matches!(sources, ScanSources::Files(_)) {
+            (None, scan_predicate.as_ref())
+        } else {
+            initialize_scan_predicate(
+                scan_predicate.as_ref(),
+                hive_parts.as_ref(),
+                unified_scan_args.table_statistics.as_ref(),
+                false,
+            )?
+        };
+
+    let file_skip_reason = if hive_parts.is_some()
+        && scan_predicate
+            .as_ref()
+            .is_some_and(|p| p.hive_predicate.is_some())
+    {
+        "hive partitions"
+    } else {
+        "table statistics"
+    };
+
+    for (source_idx, source) in sources.iter().enumerate() {
+        let path = PlSmallStr::from_str(source.to_include_path_name());
+
+        if skip_files_mask
+            .as_ref()
+            .is_some_and(|mask| mask.is_skipped_file(source_idx))
+        {
+            report.push(
+                scan_idx,
+                path,
+                Some(file_skip_reason),
+                None,
+                predicate_str.clone(),
+            );
+            continue;
+        }
+
+        #[cfg(feature = "parquet")]
+        let row_groups = if let FileScanIR::Parquet { options, .. } = scan_type.as_ref()
+            && options.use_statistics
+        {
+            parquet_row_group_pruning(
+                source,
+                options.footers.as_ref(),
+                predicate_to_readers,
+                &scan_ir_schema,
+                unified_scan_args,
+            )?
+        } else {
+            None
+        };
+        #[cfg(not(feature = "parquet"))]
+        let row_groups = {
+            let _ = (scan_type, predicate_to_readers);
+            None
+        };
+
+        report.push(scan_idx, path, None, row_groups, predicate_str.clone());
+    }
+
+    Ok(())
+}
+
+/// # Returns
+/// (number of row groups, skipped row groups)
+#[cfg(feature = "parquet")]
+fn parquet_row_group_pruning(
+    source: ScanSourceRef<'_>,
+    footers: Option<&polars_io::parquet::metadata::ParquetFooters>,
+    predicate: Option<&ScanIOPredicate>,
+    schema: &Schema,
+    unified_scan_args: &UnifiedScanArgs,
+) -> PolarsResult<Option<(IdxSize, Vec<IdxSize>)>> {
+    use polars_core::error::feature_gated;
+    use polars_io::SerReader;
+    use polars_io::parquet::read::{ParquetReader, row_group_statistics_df};
+
+    let footer = footers.zip(source.as_path()).and_then(|(f, p)| f.get(p));
+
+    let metadata = if let Some(footer) = footer {
+        footer.clone()
+    } else if source.is_cloud_url() {
+        feature_gated!("cloud", {
+            let path = source.as_path().unwrap().clone();
+            let cloud_options = unified_scan_args.cloud_options.as_ref();
+
+            polars_io::pl_async::get_runtime().block_in_place_on(async {
+                polars_io::parquet::read::ParquetObjectStore::from_uri(path, cloud_options, None)
+                    .await?
+                    .get_metadata()
+                    .await
+                    .cloned()
+            })?
+        })
+    } else {
+        ParquetReader::new(std::io::Cursor::new(source.to_memslice()?))
+            .get_metadata()?
+            .clone()
+    };
+
+    let num_row_groups = IdxSize::try_from(metadata.row_groups.len()).unwrap();
+
+    let Some(skip_batch_predicate) = predicate.and_then(|p| p.skip_batch_predicate.as_ref()) else {
+        return Ok(Some((num_row_groups, vec![])));
+    };
+
+    let statistics_df =
+        row_group_statistics_df(&metadata, schema, &predicate.unwrap().live_columns)?;
+    let skip_mask = skip_batch_predicate.evaluate_with_stat_df(&statistics_df)?;
+
+    let skipped_row_groups = skip_mask
+        .true_idx_iter()
+        .map(|i| IdxSize::try_from(i).unwrap())
+        .collect();
+
+    Ok(Some((num_row_groups, skipped_row_groups)))
+}
+
+#[derive(Default)]
+struct PruningReportBuilder {
+    scan: Vec<IdxSize>,
+    path: Vec<PlSmallStr>,
+    reason: Vec<Option<&'static str>>,
+    row_groups: Vec<Option<IdxSize>>,
+    pruned_row_groups: Vec<Option<Series>>,
+    predicate: Vec<Option<PlSmallStr>>,
+}
+
+impl PruningReportBuilder {
+    fn push(
+        &mut self,
+        scan: IdxSize,
+        path: PlSmallStr,
+        reason: Option<&'static str>,
+        row_groups: Option<(IdxSize, Vec<IdxSize>)>,
+        predicate: Option<PlSmallStr>,
+    ) {
+        let (row_groups, pruned_row_groups) = row_groups.unzip();
+
+        self.scan.push(scan);
+        self.path.push(path);
+        self.reason.push(reason);
+        self.row_groups.push(row_groups);
+        self.pruned_row_groups
+            .push(pruned_row_groups.map(|x| Series::new(PlSmallStr::EMPTY, x)));
+        self.predicate.push(predicate);
+    }
+
+    fn finish(self) -> PolarsResult<DataFrame> {
+        let height = self.scan.len();
+
+        let pruned = self.reason.iter().map(Option::is_some).collect::<Vec<_>>();
+        let pruned_row_groups = Series::new(
+            PlSmallStr::from_static("pruned_row_groups"),
+            self.pruned_row_groups,
+        )
+        .cast(&DataType::List(Box::new(IDX_DTYPE)))?;
+
+        DataFrame::new(
+            height,
+            vec![
+                Column::new(PlSmallStr::from_static("scan"), self.scan),
+                Column::new(
+                    PlSmallStr::from_static("path"),
+                    self.path.iter().map(|x| x.as_str()).collect::<Vec<_>>(),
+                ),
+                Column::new(PlSmallStr::from_static("pruned"), pruned),
+                Column::new(PlSmallStr::from_static("reason"), self.reason),
+                Column::new(PlSmallStr::from_static("row_groups"), self.row_groups),
+                pruned_row_groups.into_column(),
+                Column::new(
+                    PlSmallStr::from_static("predicate"),
+                    self.predicate
+                        .iter()
+                        .map(|x| x.as_deref())
+                        .collect::<Vec<_>>(),
+                ),
+            ],
+        )
+    }
+}
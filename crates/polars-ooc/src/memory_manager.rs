@@ -318,6 +318,13 @@ impl MemoryManager {
     pub fn total_bytes(&self) -> usize {
         self.total_bytes.load(Ordering::Relaxed)
     }
+
+    /// The fraction of the memory budget in use, see [`total_bytes`](Self::total_bytes).
+    ///
+    /// This is above 1.0 when the budget is exceeded.
+    pub fn memory_pressure(&self) -> f64 {
+        self.total_bytes() as f64 / self.budget.max(1) as f64
+    }
 }
 
 impl std::fmt::Debug for MemoryManager {
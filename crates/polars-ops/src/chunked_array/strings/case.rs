@@ -131,6 +131,45 @@ pub(super) fn to_uppercase<'a>(ca: &'a StringChunked) -> StringChunked {
     ca.apply_mut(f)
 }
 
+/// Push the titlecase mapping of `c`, which differs from its uppercase mapping for digraphs,
+/// ligatures and Greek letters with a subscript iota, e.g. `ǆ` becomes `ǅ` and `ﬁ` becomes `Fi`.
+#[cfg(feature = "nightly")]
+fn push_titlecase(c: char, s: &mut String) {
+    let title = match c {
+        'Ǆ' | 'ǅ' | 'ǆ' => "ǅ",
+        'Ǉ' | 'ǈ' | 'ǉ' => "ǈ",
+        'Ǌ' | 'ǋ' | 'ǌ' => "ǋ",
+        'Ǳ' | 'ǲ' | 'ǳ' => "ǲ",
+        'ß' => "Ss",
+        'ﬀ' => "Ff",
+        'ﬁ' => "Fi",
+        'ﬂ' => "Fl",
+        'ﬃ' => "Ffi",
+        'ﬄ' => "Ffl",
+        'ﬅ' | 'ﬆ' => "St",
+        'և' => "Եւ",
+        'ﬓ' => "Մն",
+        'ﬔ' => "Մե",
+        'ﬕ' => "Մի",
+        'ﬖ' => "Վն",
+        'ﬗ' => "Մխ",
+        'ᾳ' => "ᾼ",
+        'ῃ' => "ῌ",
+        'ῳ' => "ῼ",
+        // Greek letters with a subscript iota (ypogegrammeni) map to the letters with an
+        // adscript iota (prosgegrammeni), 8 code points further.
+        '\u{1F80}'..='\u{1F87}' | '\u{1F90}'..='\u{1F97}' | '\u{1FA0}'..='\u{1FA7}' => {
+            s.push(char::from_u32(c as u32 + 8).unwrap());
+            return;
+        },
+        _ => {
+            s.extend(c.to_uppercase());
+            return;
+        },
+    };
+    s.push_str(title);
+}
+
 #[cfg(feature = "nightly")]
 pub(super) fn to_titlecase<'a>(ca: &'a StringChunked) -> StringChunked {
     // Amortize allocation.
@@ -150,7 +189,7 @@ pub(super) fn to_titlecase<'a>(ca: &'a StringChunked) -> StringChunked {
         let mut next_is_upper = true;
         for c in lowercased.chars() {
             if next_is_upper {
-                s.extend(c.to_uppercase());
+                push_titlecase(c, &mut s);
             } else {
                 s.push(c);
             }
@@ -0,0 +1,134 @@
+use icu_collator::options::{CollatorOptions, Strength};
+use icu_collator::{Collator, CollatorBorrowed, CollatorPreferences};
+use icu_locale_core::Locale;
+use polars_core::prelude::*;
+
+/// Which differences between strings are significant when comparing them with a collation.
+///
+/// Every level also takes the differences of the levels before it into account.
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum CollationStrength {
+    /// Only base letters are significant, e.g. `"a" == "A" == "á"`.
+    Primary,
+    /// Accents are significant, e.g. `"a" == "A" < "á"`.
+    Secondary,
+    /// Case is significant, e.g. `"a" < "A" < "á"`.
+    #[default]
+    Tertiary,
+    /// Punctuation that is ignored at the other levels is significant.
+    Quaternary,
+    /// Any remaining ties are broken by code point.
+    Identical,
+}
+
+/// A locale-aware ordering of strings, following the Unicode Collation Algorithm.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct CollationOptions {
+    /// BCP 47 language tag, e.g. `"de"`, `"sv"` or `"de-u-co-phonebk"`. Unicode extension
+    /// keywords such as `-u-kn` (numeric ordering) are respected.
+    pub locale: PlSmallStr,
+    pub strength: CollationStrength,
+}
+
+impl CollationOptions {
+    pub fn new(locale: PlSmallStr) -> Self {
+        Self {
+            locale,
+            strength: CollationStrength::default(),
+        }
+    }
+
+    pub fn with_strength(mut self, strength: CollationStrength) -> Self {
+        self.strength = strength;
+        self
+    }
+
+    fn collator(&self) -> PolarsResult<CollatorBorrowed<'static>> {
+        let locale = Locale::try_from_str(&self.locale).map_err(
+            |e| polars_err!(InvalidOperation: "invalid collation locale '{}': {}", self.locale, e),
+        )?;
+
+        let mut options = CollatorOptions::default();
+        options.strength = Some(match self.strength {
+            CollationStrength::Primary => Strength::Primary,
+            CollationStrength::Secondary => Strength::Secondary,
+            CollationStrength::Tertiary => Strength::Tertiary,
+            CollationStrength::Quaternary => Strength::Quaternary,
+            CollationStrength::Identical => Strength::Identical,
+        });
+
+        Collator::try_new(CollatorPreferences::from(&locale), options).map_err(
+            |e| polars_err!(ComputeError: "no collation data for locale '{}': {}", self.locale, e),
+        )
+    }
+}
+
+/// Compute the collation sort keys of the strings in `ca`.
+///
+/// Comparing the keys byte-wise gives the same result as comparing the strings with the
+/// collation, so the keys can be used to sort, compare and group strings in a locale-aware way.
+pub fn collation_key(
+    ca: &StringChunked,
+    options: &CollationOptions,
+) -> PolarsResult<BinaryChunked> {
+    let collator = options.collator()?;
+
+    let mut buffer = Vec::new();
+    let mut builder = BinaryChunkedBuilder::new(ca.name().clone(), ca.len());
+    for opt_s in ca.iter() {
+        if let Some(s) = opt_s {
+            buffer.clear();
+            let Ok(()) = collator.write_sort_key_to(s, &mut buffer);
+            builder.append_value(&buffer);
+        } else {
+            builder.append_null();
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sorted(values: &[&str], options: &CollationOptions) -> Vec<String> {
+        let ca = StringChunked::new("a".into(), values);
+        let keys = collation_key(&ca, options).unwrap();
+        let mut pairs = values.iter().zip(keys.iter()).collect::<Vec<_>>();
+        pairs.sort_by(|a, b| a.1.cmp(&b.1));
+        pairs.into_iter().map(|(v, _)| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_collation_key() {
+        let values = ["zebra", "Äpfel", "apfel", "Zucker", "Ober", "Öl"];
+        let de = CollationOptions::new("de".into());
+        assert_eq!(
+            sorted(&values, &de),
+            ["apfel", "Äpfel", "Ober", "Öl", "zebra", "Zucker"]
+        );
+
+        // Swedish sorts "ä" and "ö" after "z".
+        let sv = CollationOptions::new("sv".into());
+        assert_eq!(
+            sorted(&values, &sv),
+            ["apfel", "Ober", "zebra", "Zucker", "Äpfel", "Öl"]
+        );
+
+        let ca = StringChunked::new("a".into(), [Some("Straße"), Some("strasse"), None]);
+        let keys = collation_key(&ca, &de.with_strength(CollationStrength::Primary)).unwrap();
+        assert_eq!(keys.get(0), keys.get(1));
+        assert_eq!(keys.null_count(), 1);
+    }
+
+    #[test]
+    fn test_collation_invalid_locale() {
+        let ca = StringChunked::new("a".into(), ["a"]);
+        let options = CollationOptions::new("not a locale".into());
+        assert!(collation_key(&ca, &options).is_err());
+    }
+}
@@ -0,0 +1,70 @@
+use polars_core::prelude::arity::unary_elementwise;
+use polars_core::prelude::*;
+
+const MASK_CHAR: char = '*';
+
+/// Masks the local part of an email address, e.g. `john.doe@example.com` becomes
+/// `j***@example.com`. Values without an `@` are masked completely.
+fn mask_email_helper(s: &str) -> String {
+    match s.rsplit_once('@') {
+        Some((local, domain)) => {
+            let mut out = String::with_capacity(domain.len() + 8);
+            out.extend(local.chars().next());
+            out.push_str("***@");
+            out.push_str(domain);
+            out
+        },
+        None => "***".to_string(),
+    }
+}
+
+/// Masks all ASCII digits except the last `keep_last` ones, e.g. `4111-1111-1111-1234` becomes
+/// `****-****-****-1234` with `keep_last = 4`. Other characters are kept.
+fn mask_digits_helper(s: &str, keep_last: usize) -> String {
+    let n_digits = s.bytes().filter(u8::is_ascii_digit).count();
+    let mut n_masked = n_digits.saturating_sub(keep_last);
+    s.chars()
+        .map(|c| {
+            if n_masked > 0 && c.is_ascii_digit() {
+                n_masked -= 1;
+                MASK_CHAR
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+pub fn mask_email(ca: &StringChunked) -> StringChunked {
+    unary_elementwise(ca, |s| s.map(mask_email_helper))
+}
+
+pub fn mask_digits(ca: &StringChunked, keep_last: usize) -> StringChunked {
+    unary_elementwise(ca, |s| s.map(|s| mask_digits_helper(s, keep_last)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mask_email() {
+        assert_eq!(
+            mask_email_helper("john.doe@example.com"),
+            "j***@example.com"
+        );
+        assert_eq!(mask_email_helper("@example.com"), "***@example.com");
+        assert_eq!(mask_email_helper("a\"@b\"@example.com"), "a***@example.com");
+        assert_eq!(mask_email_helper("not an email"), "***");
+    }
+
+    #[test]
+    fn test_mask_digits() {
+        assert_eq!(
+            mask_digits_helper("4111-1111-1111-1234", 4),
+            "****-****-****-1234"
+        );
+        assert_eq!(mask_digits_helper("+1 (555) 010", 0), "+* (***) ***");
+        assert_eq!(mask_digits_helper("12", 4), "12");
+    }
+}
@@ -1,5 +1,7 @@
 #[cfg(feature = "strings")]
 mod case;
+#[cfg(feature = "collation")]
+mod collation;
 #[cfg(feature = "strings")]
 mod concat;
 #[cfg(feature = "strings")]
@@ -10,8 +12,12 @@ mod extract;
 mod find_many;
 #[cfg(feature = "extract_jsonpath")]
 mod json_path;
+#[cfg(feature = "anonymize")]
+mod mask;
 #[cfg(feature = "strings")]
 mod namespace;
+#[cfg(feature = "natural_sort")]
+mod natural_sort;
 #[cfg(feature = "string_normalize")]
 mod normalize;
 #[cfg(feature = "string_pad")]
@@ -27,6 +33,8 @@ mod substring;
 #[cfg(all(not(feature = "nightly"), feature = "strings"))]
 mod unicode_internals;
 
+#[cfg(feature = "collation")]
+pub use collation::*;
 #[cfg(feature = "strings")]
 pub use concat::*;
 #[cfg(feature = "strings")]
@@ -35,8 +43,12 @@ pub use escape_regex::*;
 pub use find_many::*;
 #[cfg(feature = "extract_jsonpath")]
 pub use json_path::*;
+#[cfg(feature = "anonymize")]
+pub use mask::*;
 #[cfg(feature = "strings")]
 pub use namespace::*;
+#[cfg(feature = "natural_sort")]
+pub use natural_sort::*;
 #[cfg(feature = "string_normalize")]
 pub use normalize::*;
 use polars_core::prelude::*;
@@ -281,6 +281,12 @@ pub trait StringNameSpaceImpl: AsString {
         ca.apply_kernel_cast(&utf8view_len_bytes)
     }
 
+    /// Check whether the string values only consist of ASCII characters.
+    fn str_is_ascii(&self) -> BooleanChunked {
+        let ca = self.as_string();
+        unary_elementwise_values(ca, |s: &str| s.is_ascii())
+    }
+
     /// Pad the start of the string until it reaches the given length.
     ///
     /// Padding is done using the specified `fill_char`.
@@ -658,6 +664,14 @@ pub trait StringNameSpaceImpl: AsString {
         normalize::normalize(ca, form)
     }
 
+    /// Removes the diacritics of the string values.
+    #[must_use]
+    #[cfg(feature = "string_normalize")]
+    fn str_remove_diacritics(&self) -> StringChunked {
+        let ca = self.as_string();
+        normalize::remove_diacritics(ca)
+    }
+
     /// Reverses the string values
     #[must_use]
     #[cfg(feature = "string_reverse")]
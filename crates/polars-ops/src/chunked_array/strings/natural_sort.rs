@@ -0,0 +1,95 @@
+use polars_core::prelude::*;
+
+/// Marks the start of a run of digits in a sort key. This is the byte of `'0'`, so numbers sort
+/// relative to the other characters as digits would. Digits never appear as a literal byte in a
+/// key, so the marker is unambiguous.
+const NUMBER_MARKER: u8 = b'0';
+
+fn write_natural_sort_key(s: &str, key: &mut Vec<u8>) {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            key.push(bytes[i]);
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let leading_zeros = bytes[start..i].iter().take_while(|&&b| b == b'0').count();
+        let digits = &bytes[start + leading_zeros..i];
+
+        // A longer number is a larger number, so the number of digits goes first. Numbers of
+        // the same length compare digit by digit.
+        key.push(NUMBER_MARKER);
+        key.extend_from_slice(&(digits.len() as u32).to_be_bytes());
+        key.extend_from_slice(digits);
+    }
+}
+
+/// Compute sort keys that order the strings in `ca` naturally, i.e. runs of digits are compared
+/// by their numeric value, so that `"file2"` sorts before `"file10"`.
+///
+/// Comparing the keys byte-wise gives the natural order of the strings. Leading zeros are
+/// ignored, so `"a01"` and `"a1"` have the same key.
+pub fn natural_sort_key(ca: &StringChunked) -> BinaryChunked {
+    let mut key = Vec::new();
+    let mut builder = BinaryChunkedBuilder::new(ca.name().clone(), ca.len());
+    for opt_s in ca.iter() {
+        if let Some(s) = opt_s {
+            key.clear();
+            write_natural_sort_key(s, &mut key);
+            builder.append_value(&key);
+        } else {
+            builder.append_null();
+        }
+    }
+    builder.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_natural_sort_key() {
+        let values = [
+            "file10",
+            "file2",
+            "file1",
+            "file",
+            "file1a",
+            "file02",
+            "a-1",
+            "a10b2",
+            "a10b10",
+            "file1.txt",
+            "",
+        ];
+        let ca = StringChunked::new("a".into(), values);
+        let keys = natural_sort_key(&ca);
+
+        let mut pairs = values.iter().zip(keys.iter()).collect::<Vec<_>>();
+        pairs.sort_by(|a, b| a.1.cmp(&b.1));
+        let sorted = pairs.into_iter().map(|(v, _)| *v).collect::<Vec<_>>();
+        assert_eq!(
+            sorted,
+            [
+                "",
+                "a-1",
+                "a10b2",
+                "a10b10",
+                "file",
+                "file1",
+                "file1.txt",
+                "file1a",
+                "file2",
+                "file02",
+                "file10"
+            ]
+        );
+    }
+}
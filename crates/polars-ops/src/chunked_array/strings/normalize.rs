@@ -1,5 +1,6 @@
 use polars_core::prelude::{StringChunked, StringChunkedBuilder};
 use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -37,3 +38,78 @@ pub fn normalize(ca: &StringChunked, form: UnicodeForm) -> StringChunked {
         UnicodeForm::NFKD => normalize_with(ca, |s, b| b.extend(s.nfkd())),
     }
 }
+
+/// Letters with a stroke or bar, which have no canonical decomposition into a base letter and a
+/// combining mark.
+fn strip_stroke(c: char) -> char {
+    match c {
+        'Ø' => 'O',
+        'ø' => 'o',
+        'Ł' => 'L',
+        'ł' => 'l',
+        'Đ' => 'D',
+        'đ' => 'd',
+        'Ħ' => 'H',
+        'ħ' => 'h',
+        'Ŧ' => 'T',
+        'ŧ' => 't',
+        'Ƀ' => 'B',
+        'ƀ' => 'b',
+        'Ɨ' => 'I',
+        'ɨ' => 'i',
+        c => c,
+    }
+}
+
+/// Removes the diacritics of the string values, e.g. `"Crème Brûlée"` becomes `"Creme Brulee"`.
+///
+/// The strings are decomposed (NFD), the combining marks are dropped and the result is composed
+/// again (NFC). Letters with a stroke, such as `ł` and `ø`, are replaced by their base letter.
+pub fn remove_diacritics(ca: &StringChunked) -> StringChunked {
+    normalize_with(ca, |s, b| {
+        if s.is_ascii() {
+            b.push_str(s);
+        } else {
+            b.extend(
+                s.nfd()
+                    .filter(|c| !is_combining_mark(*c))
+                    .map(strip_stroke)
+                    .nfc(),
+            )
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_remove_diacritics() {
+        let ca = StringChunked::new(
+            "a".into(),
+            [
+                Some("Crème Brûlée"),
+                Some("Łódź"),
+                Some("Ørsted Ångström"),
+                Some("ﬁancée"),
+                Some("日本"),
+                None,
+            ],
+        );
+        let out = remove_diacritics(&ca);
+        assert_eq!(
+            Vec::from(&out),
+            [
+                Some("Creme Brulee"),
+                Some("Lodz"),
+                Some("Orsted Angstrom"),
+                Some("ﬁancee"),
+                Some("日本"),
+                None,
+            ]
+        );
+    }
+}
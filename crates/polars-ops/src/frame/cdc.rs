@@ -0,0 +1,148 @@
+use polars_core::prelude::*;
+
+use super::join::*;
+
+pub const CHANGE_TYPE_COLUMN: &str = "__change_type";
+
+const LEFT_INDEX: &str = "__POLARS_CDC_LEFT_INDEX";
+const RIGHT_INDEX: &str = "__POLARS_CDC_RIGHT_INDEX";
+
+#[derive(Clone, Copy)]
+enum ChangeType {
+    Insert,
+    UpdatePre,
+    UpdatePost,
+    Delete,
+}
+
+impl ChangeType {
+    fn as_str(self) -> &'static str {
+        match self {
+            ChangeType::Insert => "insert",
+            ChangeType::UpdatePre => "update_pre",
+            ChangeType::UpdatePost => "update_post",
+            ChangeType::Delete => "delete",
+        }
+    }
+}
+
+/// Get the row indices of `old` and `new` that belong to the same key, with a full outer join.
+fn match_rows(
+    old: &DataFrame,
+    new: &DataFrame,
+    keys: &[PlSmallStr],
+) -> PolarsResult<(IdxCa, IdxCa)> {
+    let left = old
+        .select(keys.iter().cloned())?
+        .with_row_index(PlSmallStr::from_static(LEFT_INDEX), None)?;
+    let right = new
+        .select(keys.iter().cloned())?
+        .with_row_index(PlSmallStr::from_static(RIGHT_INDEX), None)?;
+
+    let mut args = JoinArgs::new(JoinType::Full).with_coalesce(JoinCoalesce::KeepColumns);
+    args.validation = JoinValidation::OneToOne;
+    args.nulls_equal = true;
+    args.maintain_order = MaintainOrderJoin::LeftRight;
+
+    let joined = left.join(&right, keys, keys, args, None)?;
+
+    Ok((
+        joined.column(LEFT_INDEX)?.idx()?.clone(),
+        joined.column(RIGHT_INDEX)?.idx()?.clone(),
+    ))
+}
+
+/// Get whether the value columns differ between the `old` rows and the `new` rows.
+fn changed_rows(
+    old: &DataFrame,
+    new: &DataFrame,
+    old_idx: &IdxCa,
+    new_idx: &IdxCa,
+    keys: &[PlSmallStr],
+) -> PolarsResult<BooleanChunked> {
+    let mut changed = BooleanChunked::full(PlSmallStr::EMPTY, false, old_idx.len());
+
+    for old_column in old.columns() {
+        if keys.contains(old_column.name()) {
+            continue;
+        }
+
+        let new_column = new.column(old_column.name())?;
+        let equal = old_column
+            .take(old_idx)?
+            .equal_missing(&new_column.take(new_idx)?)?;
+        changed = changed | !equal;
+    }
+
+    Ok(changed)
+}
+
+pub(super) fn cdc(
+    old: &DataFrame,
+    new: &DataFrame,
+    keys: &[PlSmallStr],
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(!keys.is_empty(), InvalidOperation: "expected at least one key column for cdc");
+    polars_ensure!(
+        !old.schema().contains(CHANGE_TYPE_COLUMN),
+        Duplicate: "column '{}' is reserved for the change type", CHANGE_TYPE_COLUMN
+    );
+    polars_ensure!(
+        old.width() == new.width(),
+        SchemaMismatch: "cdc requires both frames to have the same columns, got {} and {} columns",
+        old.width(), new.width()
+    );
+    for (name, dtype) in old.schema().iter() {
+        let new_dtype = new.schema().try_get(name)?;
+        polars_ensure!(
+            dtype == new_dtype,
+            SchemaMismatch: "dtypes of column '{}' don't match, got {} and {}", name, dtype, new_dtype
+        );
+    }
+    let new = new.select(old.get_column_names_owned())?;
+
+    let (left, right) = match_rows(old, &new, keys)?;
+
+    // The rows that exist in both versions are compared at once.
+    let both = left.is_not_null() & right.is_not_null();
+    let changed = changed_rows(old, &new, &left.filter(&both)?, &right.filter(&both)?, keys)?;
+    let mut changed = changed.into_no_null_iter();
+
+    // Gather the output rows from `old` and `new` stacked on top of each other, so that
+    // `update_pre` and `update_post` rows are adjacent.
+    let offset = old.height() as IdxSize;
+    let mut take_idx = Vec::with_capacity(left.len());
+    let mut change_types = Vec::with_capacity(left.len());
+
+    for (l, r) in left.iter().zip(right.iter()) {
+        match (l, r) {
+            (None, Some(r)) => {
+                take_idx.push(offset + r);
+                change_types.push(ChangeType::Insert);
+            },
+            (Some(l), None) => {
+                take_idx.push(l);
+                change_types.push(ChangeType::Delete);
+            },
+            (Some(l), Some(r)) => {
+                if changed.next().unwrap() {
+                    take_idx.extend([l, offset + r]);
+                    change_types.extend([ChangeType::UpdatePre, ChangeType::UpdatePost]);
+                }
+            },
+            (None, None) => unreachable!(),
+        }
+    }
+
+    let mut stacked = old.clone();
+    stacked.vstack_mut(&new)?;
+
+    let mut out = stacked.take(&IdxCa::from_vec(PlSmallStr::EMPTY, take_idx))?;
+    out.with_column(StringChunked::from_iter_values(
+        PlSmallStr::from_static(CHANGE_TYPE_COLUMN),
+        change_types.into_iter().map(ChangeType::as_str),
+    )
+    .into_column())?;
+
+    Ok(out)
+}
@@ -0,0 +1,251 @@
+use std::collections::VecDeque;
+
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::random::get_global_random_u64;
+use rand::prelude::*;
+use rayon::prelude::*;
+
+/// Get the given columns as `f64` vectors, one per column.
+fn feature_columns(df: &DataFrame, columns: &[PlSmallStr]) -> PolarsResult<Vec<Vec<f64>>> {
+    polars_ensure!(!columns.is_empty(), ComputeError: "expected at least one column to cluster on");
+    POOL.install(|| {
+        columns
+            .par_iter()
+            .map(|name| {
+                let c = df.column(name)?;
+                polars_ensure!(
+                    c.dtype().is_primitive_numeric() || c.dtype().is_bool(),
+                    InvalidOperation: "clustering requires numeric columns, got {} of type {}", name, c.dtype()
+                );
+                polars_ensure!(
+                    c.null_count() == 0,
+                    InvalidOperation: "clustering does not support null values, found them in {}", name
+                );
+                let s = c.as_materialized_series().cast(&DataType::Float64)?;
+                Ok(s.f64()?.into_no_null_iter().collect())
+            })
+            .collect()
+    })
+}
+
+fn squared_distance(x: &[Vec<f64>], row: usize, point: &[f64]) -> f64 {
+    x.iter()
+        .zip(point)
+        .map(|(col, p)| (col[row] - p) * (col[row] - p))
+        .sum()
+}
+
+fn row(x: &[Vec<f64>], row: usize) -> Vec<f64> {
+    x.iter().map(|col| col[row]).collect()
+}
+
+/// Index and squared distance of the nearest centroid.
+fn nearest(x: &[Vec<f64>], row: usize, centroids: &[Vec<f64>]) -> (usize, f64) {
+    centroids
+        .iter()
+        .map(|c| squared_distance(x, row, c))
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap()
+}
+
+pub(super) fn kmeans(
+    df: &DataFrame,
+    columns: &[PlSmallStr],
+    k: usize,
+    max_iter: usize,
+    seed: Option<u64>,
+) -> PolarsResult<IdxCa> {
+    let n = df.height();
+    polars_ensure!(
+        k > 0 && k <= n,
+        ComputeError: "`k` must be between 1 and the number of rows ({}), got {}", n, k
+    );
+    let x = feature_columns(df, columns)?;
+    let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+
+    // k-means++ initialization: every next centroid is drawn with a probability proportional
+    // to its squared distance to the nearest centroid so far.
+    let mut centroids = vec![row(&x, rng.random_range(0..n))];
+    let mut min_distance = vec![f64::INFINITY; n];
+    while centroids.len() < k {
+        let last = centroids.last().unwrap();
+        POOL.install(|| {
+            min_distance
+                .par_iter_mut()
+                .enumerate()
+                .for_each(|(i, d)| *d = d.min(squared_distance(&x, i, last)))
+        });
+        let total = min_distance.iter().sum::<f64>();
+        let next = if total > 0.0 {
+            let mut target = rng.random::<f64>() * total;
+            min_distance
+                .iter()
+                .position(|d| {
+                    target -= d;
+                    target < 0.0
+                })
+                .unwrap_or(n - 1)
+        } else {
+            // All rows coincide with a centroid.
+            rng.random_range(0..n)
+        };
+        centroids.push(row(&x, next));
+    }
+
+    let mut labels = vec![0 as IdxSize; n];
+    for iteration in 0..max_iter.max(1) {
+        let new_labels = POOL.install(|| {
+            (0..n)
+                .into_par_iter()
+                .map(|i| nearest(&x, i, &centroids).0 as IdxSize)
+                .collect::<Vec<_>>()
+        });
+        let converged = iteration > 0 && new_labels == labels;
+        labels = new_labels;
+        if converged {
+            break;
+        }
+
+        let mut counts = vec![0usize; k];
+        labels.iter().for_each(|l| counts[*l as usize] += 1);
+        centroids = POOL
+            .install(|| {
+                x.par_iter()
+                    .map(|col| {
+                        let mut sums = vec![0.0; k];
+                        col.iter()
+                            .zip(&labels)
+                            .for_each(|(v, l)| sums[*l as usize] += v);
+                        sums
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .into_iter()
+            .fold(vec![Vec::with_capacity(x.len()); k], |mut acc, sums| {
+                acc.iter_mut()
+                    .zip(sums)
+                    .for_each(|(centroid, sum)| centroid.push(sum));
+                acc
+            })
+            .into_iter()
+            .zip(&counts)
+            .enumerate()
+            .map(|(c, (sums, count))| {
+                if *count == 0 {
+                    // Keep an empty cluster where it is.
+                    centroids[c].clone()
+                } else {
+                    sums.into_iter().map(|s| s / *count as f64).collect()
+                }
+            })
+            .collect();
+    }
+
+    Ok(IdxCa::from_vec(PlSmallStr::from_static("cluster"), labels))
+}
+
+pub(super) fn dbscan(
+    df: &DataFrame,
+    columns: &[PlSmallStr],
+    eps: f64,
+    min_pts: usize,
+) -> PolarsResult<IdxCa> {
+    polars_ensure!(eps > 0.0, ComputeError: "`eps` must be positive, got {}", eps);
+    polars_ensure!(min_pts > 0, ComputeError: "`min_pts` must be positive");
+    let n = df.height();
+    let x = feature_columns(df, columns)?;
+    let eps2 = eps * eps;
+
+    let neighbors = |i: usize| -> Vec<usize> {
+        POOL.install(|| {
+            (0..n)
+                .into_par_iter()
+                .filter(|j| {
+                    x.iter()
+                        .map(|c| (c[i] - c[*j]) * (c[i] - c[*j]))
+                        .sum::<f64>()
+                        <= eps2
+                })
+                .collect()
+        })
+    };
+
+    let mut labels: Vec<Option<IdxSize>> = vec![None; n];
+    let mut visited = vec![false; n];
+    let mut cluster: IdxSize = 0;
+    for i in 0..n {
+        if visited[i] {
+            continue;
+        }
+        visited[i] = true;
+        let seeds = neighbors(i);
+        // Points that are not part of a cluster (yet) are noise.
+        if seeds.len() < min_pts {
+            continue;
+        }
+
+        labels[i] = Some(cluster);
+        let mut queue = VecDeque::from(seeds);
+        while let Some(j) = queue.pop_front() {
+            if labels[j].is_none() {
+                labels[j] = Some(cluster);
+            }
+            if visited[j] {
+                continue;
+            }
+            visited[j] = true;
+            let reachable = neighbors(j);
+            // Only core points expand the cluster.
+            if reachable.len() >= min_pts {
+                queue.extend(reachable.into_iter().filter(|r| !visited[*r]));
+            }
+        }
+        cluster += 1;
+    }
+
+    Ok(IdxCa::from_iter_options(
+        PlSmallStr::from_static("cluster"),
+        labels.into_iter(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_kmeans() -> PolarsResult<()> {
+        let df = df![
+            "x" => [0.0, 0.1, 0.2, 10.0, 10.1, 10.2],
+            "y" => [0i32, 1, 0, 10, 11, 10],
+        ]?;
+        let columns = [PlSmallStr::from_static("x"), PlSmallStr::from_static("y")];
+        let labels = kmeans(&df, &columns, 2, 100, Some(0))?;
+        let labels = labels.into_no_null_iter().collect::<Vec<_>>();
+        assert_eq!(labels[0], labels[1]);
+        assert_eq!(labels[0], labels[2]);
+        assert_eq!(labels[3], labels[4]);
+        assert_eq!(labels[3], labels[5]);
+        assert_ne!(labels[0], labels[3]);
+
+        assert!(kmeans(&df, &columns, 7, 100, Some(0)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_dbscan() -> PolarsResult<()> {
+        let df = df![
+            "x" => [0.0, 0.5, 1.0, 10.0, 10.5, 11.0, 50.0],
+        ]?;
+        let labels = dbscan(&df, &[PlSmallStr::from_static("x")], 0.6, 2)?;
+        assert_eq!(
+            labels.into_iter().collect::<Vec<_>>(),
+            [Some(0), Some(0), Some(0), Some(1), Some(1), Some(1), None]
+        );
+        Ok(())
+    }
+}
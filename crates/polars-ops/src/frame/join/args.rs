@@ -386,6 +386,62 @@ impl JoinValidation {
         Ok(())
     }
 
+    /// Check the join keys against this validation.
+    ///
+    /// If the validation fails, the error lists (up to
+    /// [`Config::join_validation_max_reported`](polars_config::Config::join_validation_max_reported))
+    /// the keys that violate it, together with their number of occurrences.
+    pub fn validate_keys(
+        &self,
+        left: &[Series],
+        right: &[Series],
+        nulls_equal: bool,
+    ) -> PolarsResult<()> {
+        match self.violating_keys(left, right, nulls_equal)? {
+            Some(violation) => Err(self.violation_error(violation)),
+            None => Ok(()),
+        }
+    }
+
+    /// Find the keys that violate this validation, together with the side of the join they are
+    /// on. Returns `None` if the keys fulfill the validation.
+    pub(super) fn violating_keys(
+        &self,
+        left: &[Series],
+        right: &[Series],
+        nulls_equal: bool,
+    ) -> PolarsResult<Option<(&'static str, DataFrame)>> {
+        use JoinValidation::*;
+        let (check_left, check_right) = match self {
+            ManyToMany => return Ok(None),
+            ManyToOne => (false, true),
+            OneToMany => (true, false),
+            OneToOne => (true, true),
+        };
+
+        for (side, keys, check) in [("left", left, check_left), ("right", right, check_right)] {
+            if !check {
+                continue;
+            }
+            let duplicates = duplicate_join_keys(
+                keys,
+                nulls_equal,
+                Some(polars_config::config().join_validation_max_reported() as usize),
+            )?;
+            if duplicates.height() > 0 {
+                return Ok(Some((side, duplicates)));
+            }
+        }
+        Ok(None)
+    }
+
+    pub(super) fn violation_error(&self, (side, duplicates): (&str, DataFrame)) -> PolarsError {
+        polars_err!(
+            ComputeError: "join keys did not fulfill {} validation; duplicate keys in the {} dataset:\n{}",
+            self, side, duplicates
+        )
+    }
+
     pub(super) fn validate_probe(
         &self,
         s_left: &Series,
@@ -440,6 +496,49 @@ impl JoinValidation {
     }
 }
 
+/// Find the join keys that occur more than once, together with their number of occurrences in a
+/// `count` column.
+///
+/// Null keys are ignored unless `nulls_equal` is set. If `limit` is given, at most that many keys
+/// are returned.
+pub fn duplicate_join_keys(
+    keys: &[Series],
+    nulls_equal: bool,
+    limit: Option<usize>,
+) -> PolarsResult<DataFrame> {
+    let mut keys = keys
+        .iter()
+        .map(|s| s.clone().into_column())
+        .collect::<Vec<_>>();
+
+    if !nulls_equal && keys.iter().any(|c| c.has_nulls()) {
+        let mut mask = keys[0].is_not_null();
+        for c in &keys[1..] {
+            mask = &mask & &c.is_not_null();
+        }
+        for c in keys.iter_mut() {
+            *c = c.filter(&mask)?;
+        }
+    }
+
+    let df = DataFrame::empty();
+    let gb = df.group_by_with_series(keys, true, false)?;
+    let counts = gb.get_groups().group_count();
+    let mask = counts.gt(1);
+
+    let mut columns = gb.keys();
+    columns.push(
+        counts
+            .with_name(PlSmallStr::from_static("count"))
+            .into_column(),
+    );
+    let out = DataFrame::new_infer_height(columns)?.filter(&mask)?;
+    Ok(match limit {
+        Some(limit) => out.head(Some(limit)),
+        None => out,
+    })
+}
+
 impl Display for JoinValidation {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let s = match self {
@@ -522,6 +522,8 @@ pub trait AsofJoinBy: IntoDf {
         coalesce: bool,
         allow_eq: bool,
         check_sortedness: bool,
+        distance_column: Option<PlSmallStr>,
+        indicator_column: Option<PlSmallStr>,
     ) -> PolarsResult<DataFrame> {
         let (self_sliced_slot, left_slice_s); // Keeps temporaries alive.
         let (self_df, other_df, left_key, right_key);
@@ -586,13 +588,24 @@ pub trait AsofJoinBy: IntoDf {
         let proj_other_df = unsafe { DataFrame::new_unchecked(other_df.height(), cols) };
 
         let left = self_df.clone();
+        let take_idx = IdxCa::with_chunk(PlSmallStr::EMPTY, right_join_tuples);
+
+        let match_columns = asof_match_columns(
+            left_key,
+            right_key,
+            &take_idx,
+            distance_column,
+            indicator_column,
+        )?;
 
         // SAFETY: join tuples are in bounds.
-        let right_df = unsafe {
-            proj_other_df.take_unchecked(&IdxCa::with_chunk(PlSmallStr::EMPTY, right_join_tuples))
-        };
+        let right_df = unsafe { proj_other_df.take_unchecked(&take_idx) };
 
-        _finish_join(left, right_df, suffix)
+        let mut out = _finish_join(left, right_df, suffix)?;
+        if !match_columns.is_empty() {
+            out.hstack_mut(&match_columns)?;
+        }
+        Ok(out)
     }
 
     /// This is similar to a left-join except that we match on nearest key
@@ -634,6 +647,8 @@ pub trait AsofJoinBy: IntoDf {
             true,
             allow_eq,
             check_sortedness,
+            None,
+            None,
         )
     }
 }
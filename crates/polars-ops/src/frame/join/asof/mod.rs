@@ -216,6 +216,54 @@ pub struct AsOfOptions {
     /// Allow equal matches
     pub allow_eq: bool,
     pub check_sortedness: bool,
+    /// Name of an output column holding the distance `left_key - right_key` to the matched row.
+    pub distance_column: Option<PlSmallStr>,
+    /// Name of an output boolean column indicating whether a match within the tolerance was found.
+    pub indicator_column: Option<PlSmallStr>,
+}
+
+/// The dtype of the distance column of an asof join on keys of `key_dtype`.
+pub fn asof_distance_dtype(key_dtype: &DataType) -> PolarsResult<DataType> {
+    use DataType as D;
+    Ok(match key_dtype {
+        D::Date => D::Duration(TimeUnit::Microseconds),
+        D::Datetime(tu, _) | D::Duration(tu) => D::Duration(*tu),
+        D::Time => D::Duration(TimeUnit::Nanoseconds),
+        D::Int128 | D::UInt128 => D::Int128,
+        dt if dt.is_integer() => D::Int64,
+        dt if dt.is_float() => dt.clone(),
+        dt => polars_bail!(
+            InvalidOperation: "asof join distance is only supported on numeric/temporal keys, got {}", dt
+        ),
+    })
+}
+
+/// Compute the optional distance and indicator columns of an asof join.
+fn asof_match_columns(
+    left_key: &Series,
+    right_key: &Series,
+    take_idx: &IdxCa,
+    distance_column: Option<PlSmallStr>,
+    indicator_column: Option<PlSmallStr>,
+) -> PolarsResult<Vec<Column>> {
+    let mut out = Vec::with_capacity(2);
+    if let Some(name) = distance_column {
+        let dtype = asof_distance_dtype(left_key.dtype())?;
+        // SAFETY: join tuples are in bounds.
+        let matched = unsafe { right_key.take_unchecked(take_idx) };
+        let distance = if left_key.dtype().is_temporal() {
+            (left_key - &matched)?.cast(&dtype)?
+        } else {
+            // Cast first, so unsigned keys don't wrap around. The cast is strict, as `UInt64`
+            // keys beyond `i64::MAX` would otherwise silently get a null distance.
+            (&left_key.strict_cast(&dtype)? - &matched.strict_cast(&dtype)?)?
+        };
+        out.push(distance.with_name(name).into_column());
+    }
+    if let Some(name) = indicator_column {
+        out.push(take_idx.is_not_null().with_name(name).into_column());
+    }
+    Ok(out)
 }
 
 fn check_asof_columns(
@@ -284,6 +332,8 @@ pub trait AsofJoin: IntoDf {
         coalesce: bool,
         allow_eq: bool,
         check_sortedness: bool,
+        distance_column: Option<PlSmallStr>,
+        indicator_column: Option<PlSmallStr>,
     ) -> PolarsResult<DataFrame> {
         let self_df = self.to_df();
 
@@ -294,6 +344,7 @@ pub trait AsofJoin: IntoDf {
             check_sortedness,
             false,
         )?;
+        let (left_key_logical, right_key_logical) = (left_key, right_key);
         let left_key = left_key.to_physical_repr();
         let right_key = right_key.to_physical_repr();
 
@@ -368,15 +419,29 @@ pub trait AsofJoin: IntoDf {
         };
 
         let mut left = self_df.clone();
+        let mut left_key_logical = Cow::Borrowed(left_key_logical);
         if let Some((offset, len)) = slice {
             left = left.slice(offset, len);
             take_idx = take_idx.slice(offset, len);
+            left_key_logical = Cow::Owned(left_key_logical.slice(offset, len));
         }
 
+        let match_columns = asof_match_columns(
+            &left_key_logical,
+            right_key_logical,
+            &take_idx,
+            distance_column,
+            indicator_column,
+        )?;
+
         // SAFETY: join tuples are in bounds.
         let right_df = unsafe { other.take_unchecked(&take_idx) };
 
-        _finish_join(left, right_df, suffix)
+        let mut out = _finish_join(left, right_df, suffix)?;
+        if !match_columns.is_empty() {
+            out.hstack_mut(&match_columns)?;
+        }
+        Ok(out)
     }
 }
 
@@ -10,6 +10,8 @@ mod iejoin;
 pub mod merge_join;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
+#[cfg(feature = "nearest_join")]
+mod nearest;
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
@@ -18,7 +20,7 @@ use std::hash::Hash;
 pub use args::*;
 use arrow::trusted_len::TrustedLen;
 #[cfg(feature = "asof_join")]
-pub use asof::{AsOfOptions, AsofJoin, AsofJoinBy, AsofStrategy};
+pub use asof::{AsOfOptions, AsofJoin, AsofJoinBy, AsofStrategy, asof_distance_dtype};
 pub use cross_join::CrossJoin;
 #[cfg(feature = "chunked_ids")]
 use either::Either;
@@ -31,6 +33,8 @@ use hashbrown::hash_map::{Entry, RawEntryMut};
 pub use iejoin::{IEJoinOptions, InequalityOperator};
 #[cfg(feature = "merge_sorted")]
 pub use merge_sorted::_merge_sorted_dfs;
+#[cfg(feature = "nearest_join")]
+pub use nearest::{NearestJoin, NearestJoinOptions, NearestMetric};
 use polars_core::POOL;
 #[allow(unused_imports)]
 use polars_core::chunked_array::ops::row_encode::{
@@ -119,8 +123,49 @@ pub trait DataFrameJoinOps: IntoDf {
 
     #[doc(hidden)]
     #[allow(clippy::too_many_arguments)]
-    #[allow(unused_mut)]
     fn _join_impl(
+        &self,
+        other: &DataFrame,
+        selected_left: Vec<Series>,
+        selected_right: Vec<Series>,
+        args: JoinArgs,
+        options: Option<JoinTypeOptions>,
+        _check_rechunk: bool,
+        _verbose: bool,
+    ) -> PolarsResult<DataFrame> {
+        // The violating keys are only collected once the join failed, so that valid joins don't
+        // pay for the extra group by.
+        let report_keys = (args.validation.needs_checks()
+            && matches!(args.how, JoinType::Inner | JoinType::Left | JoinType::Full))
+        .then(|| (selected_left.clone(), selected_right.clone()));
+        let (validation, nulls_equal) = (args.validation, args.nulls_equal);
+
+        let out = self._join_impl_unreported(
+            other,
+            selected_left,
+            selected_right,
+            args,
+            options,
+            _check_rechunk,
+            _verbose,
+        );
+        match (out, report_keys) {
+            // Only a failed validation leaves violating keys behind; any other error is returned
+            // as is.
+            (Err(err), Some((left, right))) => {
+                match validation.violating_keys(&left, &right, nulls_equal) {
+                    Ok(Some(violation)) => Err(validation.violation_error(violation)),
+                    _ => Err(err),
+                }
+            },
+            (out, _) => out,
+        }
+    }
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    #[allow(unused_mut)]
+    fn _join_impl_unreported(
         &self,
         other: &DataFrame,
         mut selected_left: Vec<Series>,
@@ -201,7 +246,7 @@ pub trait DataFrameJoinOps: IntoDf {
                     tmp_right.rechunk_mut_par();
                     right = Cow::Owned(tmp_right);
                 }
-                return left._join_impl(
+                return left._join_impl_unreported(
                     &right,
                     selected_left,
                     selected_right,
@@ -225,6 +270,19 @@ pub trait DataFrameJoinOps: IntoDf {
             );
         };
 
+        // The values of opaque dtypes are matched by their vtable, not their encoding.
+        #[cfg(feature = "dtype-extension")]
+        for (l, r) in selected_left.iter_mut().zip(selected_right.iter_mut()) {
+            if polars_core::datatypes::extension::opaque_vtable(l.dtype()).is_some() {
+                let [l_ids, r_ids]: [IdxCa; 2] =
+                    polars_core::datatypes::extension::opaque_ids(&[&*l, &*r])?
+                        .try_into()
+                        .unwrap();
+                *l = l_ids.into_series();
+                *r = r_ids.into_series();
+            }
+        }
+
         #[cfg(feature = "iejoin")]
         if let JoinType::IEJoin = args.how {
             let Some(JoinTypeOptions::IEJoin(options)) = options else {
@@ -308,6 +366,8 @@ pub trait DataFrameJoinOps: IntoDf {
                         should_coalesce,
                         options.allow_eq,
                         options.check_sortedness,
+                        options.distance_column,
+                        options.indicator_column,
                     ),
                     (None, None) => left_df._join_asof(
                         other,
@@ -320,6 +380,8 @@ pub trait DataFrameJoinOps: IntoDf {
                         should_coalesce,
                         options.allow_eq,
                         options.check_sortedness,
+                        options.distance_column,
+                        options.indicator_column,
                     ),
                     _ => {
                         panic!("expected by arguments on both sides")
@@ -426,7 +488,7 @@ pub trait DataFrameJoinOps: IntoDf {
                 Some(drop_names),
             ),
             #[cfg(feature = "semi_anti_join")]
-            JoinType::Anti | JoinType::Semi => self._join_impl(
+            JoinType::Anti | JoinType::Semi => self._join_impl_unreported(
                 other,
                 vec![lhs_keys],
                 vec![rhs_keys],
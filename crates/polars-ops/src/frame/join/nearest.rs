@@ -0,0 +1,347 @@
+use std::collections::BinaryHeap;
+
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::utils::NoNull;
+use polars_utils::total_ord::TotalOrdWrap;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::_finish_join;
+use crate::frame::IntoDf;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum NearestMetric {
+    /// The euclidean distance between the vectors.
+    #[default]
+    Euclidean,
+    /// One minus the cosine similarity of the vectors.
+    Cosine,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NearestJoinOptions {
+    /// The number of right rows to attach to every left row.
+    pub k: usize,
+    pub metric: NearestMetric,
+    /// Right rows further away than this are never matched.
+    pub max_distance: Option<f64>,
+    /// Name of the output column holding the distance of the match.
+    pub distance_column: PlSmallStr,
+}
+
+impl Default for NearestJoinOptions {
+    fn default() -> Self {
+        Self {
+            k: 1,
+            metric: NearestMetric::default(),
+            max_distance: None,
+            distance_column: PlSmallStr::from_static("distance"),
+        }
+    }
+}
+
+/// Points stored row-major, `dim` values per point.
+struct Points {
+    values: Vec<f64>,
+    dim: usize,
+}
+
+impl Points {
+    fn new(df: &DataFrame, on: &[PlSmallStr], metric: NearestMetric) -> PolarsResult<Self> {
+        let dim = on.len();
+        let mut values = vec![0.0; df.height() * dim];
+        for (j, name) in on.iter().enumerate() {
+            let c = df.column(name)?;
+            polars_ensure!(
+                c.dtype().is_primitive_numeric(),
+                InvalidOperation: "join_nearest requires numeric columns, got {} of type {}", name, c.dtype()
+            );
+            polars_ensure!(
+                c.null_count() == 0,
+                InvalidOperation: "join_nearest does not support null values, found them in {}", name
+            );
+            let s = c.as_materialized_series().cast(&DataType::Float64)?;
+            for (i, v) in s.f64()?.into_no_null_iter().enumerate() {
+                values[i * dim + j] = v;
+            }
+        }
+
+        // On the unit sphere the euclidean distance is monotonic in the cosine similarity.
+        if metric == NearestMetric::Cosine {
+            for point in values.chunks_exact_mut(dim) {
+                let norm = point.iter().map(|v| v * v).sum::<f64>().sqrt();
+                if norm > 0.0 {
+                    point.iter_mut().for_each(|v| *v /= norm);
+                }
+            }
+        }
+        Ok(Self { values, dim })
+    }
+
+    fn get(&self, i: usize) -> &[f64] {
+        &self.values[i * self.dim..(i + 1) * self.dim]
+    }
+
+    fn len(&self) -> usize {
+        self.values.len() / self.dim
+    }
+}
+
+fn squared_distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| (a - b) * (a - b)).sum()
+}
+
+/// A KD-tree stored implicitly in a permutation of the point indices: the median of every
+/// range splits it on the axis of its depth.
+struct KdTree<'a> {
+    points: &'a Points,
+    idx: Vec<usize>,
+}
+
+impl<'a> KdTree<'a> {
+    fn new(points: &'a Points) -> Self {
+        let mut idx = (0..points.len()).collect::<Vec<_>>();
+        Self::build(points, &mut idx, 0);
+        Self { points, idx }
+    }
+
+    fn build(points: &Points, idx: &mut [usize], depth: usize) {
+        if idx.len() <= 1 {
+            return;
+        }
+        let axis = depth % points.dim;
+        let mid = idx.len() / 2;
+        idx.select_nth_unstable_by(mid, |a, b| {
+            points.get(*a)[axis].total_cmp(&points.get(*b)[axis])
+        });
+        let (left, right) = idx.split_at_mut(mid);
+        Self::build(points, left, depth + 1);
+        Self::build(points, &mut right[1..], depth + 1);
+    }
+
+    /// Find the `k` nearest points within squared distance `bound`, closest first.
+    fn query(&self, point: &[f64], k: usize, bound: f64) -> Vec<(f64, usize)> {
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        self.search(&self.idx, 0, point, k, bound, &mut heap);
+        let mut out = heap
+            .into_vec()
+            .into_iter()
+            .map(|(d, i): (TotalOrdWrap<f64>, usize)| (d.0, i))
+            .collect::<Vec<_>>();
+        out.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        out
+    }
+
+    fn search(
+        &self,
+        idx: &[usize],
+        depth: usize,
+        point: &[f64],
+        k: usize,
+        bound: f64,
+        heap: &mut BinaryHeap<(TotalOrdWrap<f64>, usize)>,
+    ) {
+        if idx.is_empty() {
+            return;
+        }
+        let mid = idx.len() / 2;
+        let node = idx[mid];
+        let dist = squared_distance(point, self.points.get(node));
+        if dist <= bound {
+            heap.push((TotalOrdWrap(dist), node));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+
+        let axis = depth % self.points.dim;
+        let diff = point[axis] - self.points.get(node)[axis];
+        let (near, far) = if diff < 0.0 {
+            (&idx[..mid], &idx[mid + 1..])
+        } else {
+            (&idx[mid + 1..], &idx[..mid])
+        };
+        self.search(near, depth + 1, point, k, bound, heap);
+        let worst = if heap.len() == k {
+            heap.peek().map_or(bound, |(d, _)| d.0)
+        } else {
+            bound
+        };
+        if diff * diff <= worst {
+            self.search(far, depth + 1, point, k, bound, heap);
+        }
+    }
+}
+
+pub trait NearestJoin: IntoDf {
+    /// Attach to every left row the `k` nearest right rows, where every row is a vector with
+    /// the values of the `left_on` and `right_on` columns as coordinates.
+    ///
+    /// A KD-tree is built on the right rows and queried for all left rows in parallel. The
+    /// output holds a row per match, closest first, with its distance in
+    /// `options.distance_column`, which must not be the name of a column of either frame. Left
+    /// rows without any right row within `options.max_distance` are dropped.
+    fn join_nearest(
+        &self,
+        other: &DataFrame,
+        left_on: &[PlSmallStr],
+        right_on: &[PlSmallStr],
+        options: NearestJoinOptions,
+        suffix: Option<PlSmallStr>,
+    ) -> PolarsResult<DataFrame> {
+        let df = self.to_df();
+        polars_ensure!(
+            !left_on.is_empty() && left_on.len() == right_on.len(),
+            ComputeError: "join_nearest expects the same non-zero number of left and right columns"
+        );
+        polars_ensure!(options.k > 0, ComputeError: "`k` must be positive");
+        let distance_column = &options.distance_column;
+        polars_ensure!(
+            df.get_column_index(distance_column).is_none()
+                && other.get_column_index(distance_column).is_none(),
+            Duplicate: "column '{}' already exists; choose another name for the distance column",
+            distance_column
+        );
+
+        let left = Points::new(df, left_on, options.metric)?;
+        let right = Points::new(other, right_on, options.metric)?;
+        let tree = KdTree::new(&right);
+
+        // Cosine distances are `1 - cos = |a - b|^2 / 2` for normalized vectors.
+        let to_squared = |d: f64| match options.metric {
+            NearestMetric::Euclidean => d * d,
+            NearestMetric::Cosine => 2.0 * d,
+        };
+        let from_squared = |d: f64| match options.metric {
+            NearestMetric::Euclidean => d.sqrt(),
+            NearestMetric::Cosine => d / 2.0,
+        };
+        let bound = options.max_distance.map_or(f64::INFINITY, to_squared);
+
+        let matches = POOL.install(|| {
+            (0..left.len())
+                .into_par_iter()
+                .map(|i| tree.query(left.get(i), options.k, bound))
+                .collect::<Vec<_>>()
+        });
+
+        let mut left_idx = Vec::new();
+        let mut right_idx = Vec::new();
+        let mut distance = Vec::new();
+        for (i, matches) in matches.into_iter().enumerate() {
+            for (d, j) in matches {
+                left_idx.push(i as IdxSize);
+                right_idx.push(j as IdxSize);
+                distance.push(from_squared(d));
+            }
+        }
+        let left_idx = IdxCa::from_vec(PlSmallStr::EMPTY, left_idx);
+        let right_idx = IdxCa::from_vec(PlSmallStr::EMPTY, right_idx);
+
+        // SAFETY: the indices are within bounds.
+        let (df_left, mut df_right) = POOL.join(
+            || unsafe { df.take_unchecked(&left_idx) },
+            || unsafe { other.take_unchecked(&right_idx) },
+        );
+        let distance: NoNull<Float64Chunked> = distance.into_iter().collect();
+        let mut distance = distance.into_inner();
+        distance.rename(options.distance_column);
+        df_right.with_column(distance.into_column())?;
+
+        _finish_join(df_left, df_right, suffix)
+    }
+}
+
+impl NearestJoin for DataFrame {}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_join_nearest() -> PolarsResult<()> {
+        let left = df![
+            "x" => [0.0, 5.0, 100.0],
+            "y" => [0.0, 5.0, 100.0],
+        ]?;
+        let right = df![
+            "x" => [1.0, 4.0, 6.0, 0.0],
+            "y" => [0i32, 5, 5, 2],
+            "name" => ["a", "b", "c", "d"],
+        ]?;
+        let on = [PlSmallStr::from_static("x"), PlSmallStr::from_static("y")];
+
+        let options = NearestJoinOptions {
+            k: 2,
+            max_distance: Some(3.0),
+            ..Default::default()
+        };
+        let out = left.join_nearest(&right, &on, &on, options, None)?;
+        let names = out
+            .column("name")?
+            .str()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["a", "d", "b", "c"]);
+        let distance = out
+            .column("distance")?
+            .f64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(distance, [1.0, 2.0, 1.0, 1.0]);
+        assert_eq!(
+            out.get_column_names(),
+            ["x", "y", "x_right", "y_right", "name", "distance"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_nearest_cosine() -> PolarsResult<()> {
+        let left = df!["x" => [1.0], "y" => [0.0]]?;
+        let right = df!["x" => [10.0, 1.0, -1.0], "y" => [1.0, 1.0, 0.0]]?;
+        let on = [PlSmallStr::from_static("x"), PlSmallStr::from_static("y")];
+
+        let options = NearestJoinOptions {
+            k: 3,
+            metric: NearestMetric::Cosine,
+            ..Default::default()
+        };
+        let out = left.join_nearest(&right, &on, &on, options, None)?;
+        let x = out
+            .column("x_right")?
+            .f64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(x, [10.0, 1.0, -1.0]);
+        let distance = out.column("distance")?.f64()?;
+        assert!((distance.get(2).unwrap() - 2.0).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_nearest_distance_column_exists() -> PolarsResult<()> {
+        let left = df!["x" => [0.0]]?;
+        let right = df!["x" => [1.0], "distance" => [5.0]]?;
+        let on = [PlSmallStr::from_static("x")];
+
+        let err = left
+            .join_nearest(&right, &on, &on, NearestJoinOptions::default(), None)
+            .unwrap_err();
+        assert!(matches!(err, PolarsError::Duplicate(_)));
+
+        let options = NearestJoinOptions {
+            distance_column: PlSmallStr::from_static("dist"),
+            ..Default::default()
+        };
+        let out = left.join_nearest(&right, &on, &on, options, None)?;
+        assert_eq!(out.get_column_names(), ["x", "x_right", "distance", "dist"]);
+        Ok(())
+    }
+}
@@ -1,7 +1,15 @@
+#[cfg(feature = "cdc")]
+mod cdc;
+#[cfg(feature = "cluster")]
+mod cluster;
 pub mod join;
+#[cfg(feature = "linalg")]
+mod pca;
 #[cfg(feature = "pivot")]
 pub mod unpivot;
 
+#[cfg(feature = "cdc")]
+pub use cdc::CHANGE_TYPE_COLUMN;
 pub use join::*;
 #[cfg(feature = "to_dummies")]
 use polars_core::POOL;
@@ -122,4 +130,63 @@ pub trait DataFrameOps: IntoDf {
 
         accumulate_dataframes_horizontal(cols)
     }
+
+    /// Principal component analysis over the columns of this [`DataFrame`].
+    ///
+    /// The columns are centered and decomposed with a randomized SVD, which works on the
+    /// columnar data directly and parallelizes over the columns. All columns must be numeric
+    /// and free of nulls.
+    ///
+    /// Returns two frames:
+    /// - the loadings, with one row per component: its name (`pc1`, `pc2`, ...), the
+    ///   `explained_variance`, the `explained_variance_ratio` and the weight of every input
+    ///   column.
+    /// - the data projected on the first `k` components, with one column per component.
+    #[cfg(feature = "linalg")]
+    fn pca(&self, k: usize) -> PolarsResult<(DataFrame, DataFrame)> {
+        pca::pca(self.to_df(), k)
+    }
+
+    /// Assign every row to one of `k` clusters with k-means over the given `columns`.
+    ///
+    /// The centroids are initialized with k-means++ and refined until the assignment no longer
+    /// changes, or for at most `max_iter` iterations. Returns the `cluster` label of every row.
+    #[cfg(feature = "cluster")]
+    fn kmeans(
+        &self,
+        columns: &[PlSmallStr],
+        k: usize,
+        max_iter: usize,
+        seed: Option<u64>,
+    ) -> PolarsResult<IdxCa> {
+        cluster::kmeans(self.to_df(), columns, k, max_iter, seed)
+    }
+
+    /// Cluster the rows with DBSCAN over the given `columns`.
+    ///
+    /// Rows with at least `min_pts` rows (including themselves) within euclidean distance `eps`
+    /// are core points; clusters are formed by the core points that reach each other and the
+    /// rows they reach. Returns the `cluster` label of every row, which is null for noise.
+    ///
+    /// The neighbours are found by brute force, so this is quadratic in the number of rows.
+    #[cfg(feature = "cluster")]
+    fn dbscan(&self, columns: &[PlSmallStr], eps: f64, min_pts: usize) -> PolarsResult<IdxCa> {
+        cluster::dbscan(self.to_df(), columns, eps, min_pts)
+    }
+
+    /// Row-level change data capture from this (old) version of a frame to the `other` (new)
+    /// version, matching rows on the `keys` columns.
+    ///
+    /// Both frames must have the same columns and the keys must be unique in both of them.
+    /// Returns the changed rows with an additional `__change_type` column:
+    /// - `insert`: the row from `other` whose key is not in this frame.
+    /// - `delete`: the row from this frame whose key is not in `other`.
+    /// - `update_pre` / `update_post`: the row from this frame and the row from `other` for a key
+    ///   whose values differ, directly after each other.
+    ///
+    /// Rows are matched with a single full outer join and compared column by column.
+    #[cfg(feature = "cdc")]
+    fn cdc(&self, other: &DataFrame, keys: &[PlSmallStr]) -> PolarsResult<DataFrame> {
+        cdc::cdc(self.to_df(), other, keys)
+    }
 }
@@ -0,0 +1,265 @@
+use polars_core::POOL;
+use polars_core::prelude::*;
+use rand::prelude::*;
+use rand_distr::StandardNormal;
+use rayon::prelude::*;
+
+/// Extra dimensions of the random projection, on top of `k`.
+const OVERSAMPLING: usize = 10;
+/// Number of power iterations, which sharpen the spectrum of the projection.
+const POWER_ITERATIONS: usize = 4;
+/// The projection is seeded, so that the same input always gives the same components.
+const SEED: u64 = 0;
+
+/// A dense matrix stored as one `Vec` per column.
+type Columns = Vec<Vec<f64>>;
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// `x * m`, where `m` is a `p x l` matrix given as `l` columns of length `p`.
+fn mul(x: &Columns, m: &Columns) -> Columns {
+    let n = x.first().map_or(0, |c| c.len());
+    POOL.install(|| {
+        m.par_iter()
+            .map(|weights| {
+                let mut out = vec![0.0; n];
+                for (col, w) in x.iter().zip(weights) {
+                    if *w != 0.0 {
+                        out.iter_mut().zip(col).for_each(|(o, v)| *o += w * v);
+                    }
+                }
+                out
+            })
+            .collect()
+    })
+}
+
+/// `x^T * q`, as `l` columns of length `p`.
+fn mul_transposed(x: &Columns, q: &Columns) -> Columns {
+    POOL.install(|| {
+        q.par_iter()
+            .map(|q| x.iter().map(|col| dot(col, q)).collect())
+            .collect()
+    })
+}
+
+/// Orthonormalize the columns in place with modified Gram-Schmidt. Columns that are linearly
+/// dependent on the previous ones are zeroed.
+fn orthonormalize(cols: &mut Columns) {
+    for i in 0..cols.len() {
+        let (done, rest) = cols.split_at_mut(i);
+        let col = &mut rest[0];
+        for q in done.iter() {
+            let proj = dot(col, q);
+            col.iter_mut().zip(q).for_each(|(c, q)| *c -= proj * q);
+        }
+        let norm = dot(col, col).sqrt();
+        if norm > 1e-10 {
+            col.iter_mut().for_each(|c| *c /= norm);
+        } else {
+            col.iter_mut().for_each(|c| *c = 0.0);
+        }
+    }
+}
+
+/// Eigen decomposition of a small symmetric matrix with the cyclic Jacobi method. Returns the
+/// eigenvalues and the eigenvectors as columns, sorted by descending eigenvalue.
+fn symmetric_eigen(mut a: Vec<Vec<f64>>) -> (Vec<f64>, Columns) {
+    let l = a.len();
+    let mut v: Columns = (0..l)
+        .map(|i| (0..l).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+
+    for _ in 0..100 {
+        let off_diagonal = (0..l)
+            .flat_map(|i| (i + 1..l).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j] * a[i][j])
+            .sum::<f64>();
+        if off_diagonal < 1e-22 {
+            break;
+        }
+        for p in 0..l {
+            for q in p + 1..l {
+                if a[p][q].abs() < 1e-300 {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
+                let (head, tail) = a.split_at_mut(q);
+                for (apk, aqk) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+                    (*apk, *aqk) = (c * *apk - s * *aqk, s * *apk + c * *aqk);
+                }
+                // `v` holds the eigenvectors as columns, `v[i]` being column `i`.
+                let (vp, vq) = (v[p].clone(), v[q].clone());
+                for k in 0..l {
+                    v[p][k] = c * vp[k] - s * vq[k];
+                    v[q][k] = s * vp[k] + c * vq[k];
+                }
+            }
+        }
+    }
+
+    let mut order = (0..l).collect::<Vec<_>>();
+    order.sort_by(|i, j| a[*j][*j].total_cmp(&a[*i][*i]));
+    let values = order.iter().map(|i| a[*i][*i]).collect();
+    let vectors = order.iter().map(|i| v[*i].clone()).collect();
+    (values, vectors)
+}
+
+pub(super) fn pca(df: &DataFrame, k: usize) -> PolarsResult<(DataFrame, DataFrame)> {
+    let p = df.width();
+    let n = df.height();
+    polars_ensure!(
+        k > 0 && k <= p.min(n),
+        ComputeError: "`k` must be between 1 and min(n_rows, n_columns) = {}, got {}", p.min(n), k
+    );
+
+    let x = POOL.install(|| {
+        df.columns()
+            .par_iter()
+            .map(|c| {
+                polars_ensure!(
+                    c.dtype().is_primitive_numeric() || c.dtype().is_bool(),
+                    InvalidOperation: "pca requires numeric columns, got {} of type {}", c.name(), c.dtype()
+                );
+                polars_ensure!(
+                    c.null_count() == 0,
+                    InvalidOperation: "pca does not support null values, found them in {}", c.name()
+                );
+                let s = c.as_materialized_series().cast(&DataType::Float64)?;
+                let ca = s.f64()?;
+                let mean = ca.mean().unwrap_or(0.0);
+                Ok(ca.into_no_null_iter().map(|v| v - mean).collect())
+            })
+            .collect::<PolarsResult<Columns>>()
+    })?;
+
+    // Randomized SVD of the centered data: find an orthonormal basis `q` of the range of `x`,
+    // then decompose the small matrix `b = q^T x`.
+    let l = (k + OVERSAMPLING).min(p).min(n);
+    let mut rng = SmallRng::seed_from_u64(SEED);
+    let omega: Columns = (0..l)
+        .map(|_| (0..p).map(|_| rng.sample(StandardNormal)).collect())
+        .collect();
+    let mut q = mul(&x, &omega);
+    orthonormalize(&mut q);
+    for _ in 0..POWER_ITERATIONS {
+        let mut z = mul_transposed(&x, &q);
+        orthonormalize(&mut z);
+        q = mul(&x, &z);
+        orthonormalize(&mut q);
+    }
+    // Rows of `b`, each of length `p`.
+    let b = mul_transposed(&x, &q);
+
+    // With `b b^T = u s^2 u^T`, the right singular vectors are `v = b^T u / s`.
+    let bbt = b
+        .iter()
+        .map(|bi| b.iter().map(|bj| dot(bi, bj)).collect())
+        .collect();
+    let (eigenvalues, u) = symmetric_eigen(bbt);
+    let components: Columns = u
+        .iter()
+        .zip(&eigenvalues)
+        .take(k)
+        .map(|(u, ev)| {
+            let s = ev.max(0.0).sqrt();
+            let mut v = (0..p)
+                .map(|j| {
+                    let vj = u.iter().zip(&b).map(|(ui, bi)| ui * bi[j]).sum::<f64>();
+                    if s > 1e-10 { vj / s } else { 0.0 }
+                })
+                .collect::<Vec<_>>();
+            // Deterministic signs: the largest loading of every component is positive.
+            let max = v.iter().copied().max_by(|a, b| a.abs().total_cmp(&b.abs()));
+            if max.is_some_and(|m| m < 0.0) {
+                v.iter_mut().for_each(|vj| *vj = -*vj);
+            }
+            v
+        })
+        .collect();
+
+    let ddof = (n.max(2) - 1) as f64;
+    let explained_variance = eigenvalues
+        .iter()
+        .take(k)
+        .map(|ev| ev.max(0.0) / ddof)
+        .collect::<Vec<_>>();
+    let total_variance = x.iter().map(|c| dot(c, c)).sum::<f64>() / ddof;
+    let explained_variance_ratio = explained_variance
+        .iter()
+        .map(|ev| {
+            if total_variance > 0.0 {
+                ev / total_variance
+            } else {
+                0.0
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let names = (1..=k).map(|i| format!("pc{i}")).collect::<Vec<_>>();
+
+    let mut loadings = vec![
+        Column::new("component".into(), &names),
+        Column::new("explained_variance".into(), explained_variance),
+        Column::new("explained_variance_ratio".into(), explained_variance_ratio),
+    ];
+    loadings.extend(df.columns().iter().enumerate().map(|(j, c)| {
+        let values = components.iter().map(|v| v[j]).collect::<Vec<_>>();
+        Column::new(c.name().clone(), values)
+    }));
+    let loadings = DataFrame::new(k, loadings)?;
+
+    let transformed = mul(&x, &components)
+        .into_iter()
+        .zip(names)
+        .map(|(values, name)| Column::new(name.into(), values))
+        .collect();
+    let transformed = DataFrame::new(n, transformed)?;
+
+    Ok((loadings, transformed))
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_pca() -> PolarsResult<()> {
+        // `b` is exactly `2 * a`, so a single component explains all variance.
+        let df = df![
+            "a" => [1.0, 2.0, 3.0, 4.0, 5.0],
+            "b" => [2i32, 4, 6, 8, 10],
+        ]?;
+        let (loadings, transformed) = pca(&df, 2)?;
+
+        let ratio = loadings.column("explained_variance_ratio")?.f64()?;
+        assert!((ratio.get(0).unwrap() - 1.0).abs() < 1e-9);
+        assert!(ratio.get(1).unwrap().abs() < 1e-9);
+
+        let a = loadings.column("a")?.f64()?.get(0).unwrap();
+        let b = loadings.column("b")?.f64()?.get(0).unwrap();
+        assert!((a - 1.0 / 5f64.sqrt()).abs() < 1e-9);
+        assert!((b - 2.0 / 5f64.sqrt()).abs() < 1e-9);
+
+        let pc1 = transformed.column("pc1")?.f64()?;
+        let expected = [-2.0, -1.0, 0.0, 1.0, 2.0].map(|v| v * 5f64.sqrt());
+        for (out, exp) in pc1.into_no_null_iter().zip(expected) {
+            assert!((out - exp).abs() < 1e-9);
+        }
+
+        assert!(pca(&df, 3).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::total_ord::{TotalEq, TotalOrd};
+
+/// The `(lower, upper)` bounds of the buckets of the values of `ca`, in the order of `ca`.
+fn bucket_bounds<T>(ca: &ChunkedArray<T>, k: usize) -> (ChunkedArray<T>, ChunkedArray<T>)
+where
+    T: PolarsNumericType,
+{
+    let mut sorted = ca
+        .iter()
+        .enumerate()
+        .filter_map(|(i, v)| Some((i, v?)))
+        .collect::<Vec<_>>();
+    sorted.sort_unstable_by(|a, b| a.1.tot_cmp(&b.1));
+    let n = sorted.len();
+
+    let mut lower = vec![None; ca.len()];
+    let mut upper = vec![None; ca.len()];
+    let mut start = 0;
+    while start < n {
+        let mut end = (start + k).min(n);
+        // Equal values are never split over buckets.
+        while end < n && sorted[end].1.tot_eq(&sorted[end - 1].1) {
+            end += 1;
+        }
+        // A remainder that is too small is added to the last bucket.
+        if n - end < k {
+            end = n;
+        }
+
+        let (lo, hi) = (sorted[start].1, sorted[end - 1].1);
+        for &(i, _) in &sorted[start..end] {
+            lower[i] = Some(lo);
+            upper[i] = Some(hi);
+        }
+        start = end;
+    }
+
+    (
+        ChunkedArray::from_iter_options(PlSmallStr::from_static("lower"), lower.into_iter()),
+        ChunkedArray::from_iter_options(PlSmallStr::from_static("upper"), upper.into_iter()),
+    )
+}
+
+/// Generalize numeric values into ranges that each contain at least `k` values, so that every
+/// value is shared by at least `k` rows (k-anonymity).
+///
+/// The values are sorted and split into consecutive buckets of at least `k` non-null values.
+/// Equal values are always put in the same bucket, and a remainder of less than `k` values is
+/// added to the last bucket. Every value is replaced by a struct with the `lower` and `upper`
+/// bound of its bucket. Null values stay null.
+pub fn bucketize_numeric(s: &Series, k: usize) -> PolarsResult<Series> {
+    polars_ensure!(k > 0, InvalidOperation: "`k_anonymity` must be positive");
+    polars_ensure!(
+        s.dtype().is_primitive_numeric(),
+        opq = bucketize_numeric,
+        s.dtype()
+    );
+
+    let physical = s.to_physical_repr();
+    let (lower, upper) = with_match_physical_numeric_polars_type!(physical.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = physical.as_ref().as_ref().as_ref();
+        let (lower, upper) = bucket_bounds(ca, k);
+        (lower.into_series(), upper.into_series())
+    });
+    let lower = lower.cast(s.dtype())?;
+    let upper = upper.cast(s.dtype())?;
+
+    let out = StructChunked::from_series(s.name().clone(), s.len(), [lower, upper].iter())?;
+    Ok(out.with_outer_validity(s.rechunk_validity()).into_series())
+}
+
+/// The output type of [`bucketize_numeric`].
+pub fn bucketize_numeric_dtype(dtype: &DataType) -> DataType {
+    DataType::Struct(vec![
+        Field::new(PlSmallStr::from_static("lower"), dtype.clone()),
+        Field::new(PlSmallStr::from_static("upper"), dtype.clone()),
+    ])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bucketize_numeric() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            [Some(5), Some(1), None, Some(3), Some(3), Some(9), Some(2)],
+        );
+        let out = bucketize_numeric(&s, 2)?;
+        let out = out.struct_()?;
+
+        let lower = out.field_by_name("lower")?;
+        let upper = out.field_by_name("upper")?;
+        // Sorted: 1 2 | 3 3 | 5 9
+        assert_eq!(
+            Vec::from(lower.i32()?),
+            [Some(5), Some(1), None, Some(3), Some(3), Some(5), Some(1)]
+        );
+        assert_eq!(
+            Vec::from(upper.i32()?),
+            [Some(9), Some(2), None, Some(3), Some(3), Some(9), Some(2)]
+        );
+        assert_eq!(out.null_count(), 1);
+
+        // Ties are kept together.
+        let s = Series::new("a".into(), [1, 1, 1, 2, 3]);
+        let out = bucketize_numeric(&s, 2)?;
+        let lower = out.struct_()?.field_by_name("lower")?;
+        assert_eq!(Vec::from(lower.i32()?), [1, 1, 1, 2, 2].map(Some));
+
+        // A remainder that is too small is merged into the last bucket.
+        let s = Series::new("a".into(), [1, 2, 3, 4, 5]);
+        let out = bucketize_numeric(&s, 2)?;
+        let lower = out.struct_()?.field_by_name("lower")?;
+        assert_eq!(Vec::from(lower.i32()?), [1, 1, 3, 3, 3].map(Some));
+        Ok(())
+    }
+}
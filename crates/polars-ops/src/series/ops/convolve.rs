@@ -0,0 +1,270 @@
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Which part of the full convolution to return.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ConvolveMode {
+    /// Every position where the kernel overlaps the input, `n + m - 1` values.
+    Full,
+    /// The center of the full convolution, with the same length as the input.
+    #[default]
+    Same,
+    /// Only the positions where the kernel fully overlaps the input, `n - m + 1` values.
+    Valid,
+}
+
+/// How null values in the input affect the output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ConvolveNulls {
+    /// An output value is null if any of the values it is computed from is null.
+    #[default]
+    Propagate,
+    /// Null values count as zero.
+    Zero,
+    /// Null values are skipped and the remaining weights are rescaled to the sum of all weights.
+    /// An output value is null if all of its values are null.
+    Renormalize,
+}
+
+/// The weighted sum of `(value, weight)` pairs, where null values are `None`.
+fn apply_weights(
+    pairs: impl Iterator<Item = (Option<f64>, f64)>,
+    nulls: ConvolveNulls,
+) -> Option<f64> {
+    let mut acc = 0.0;
+    let mut weight_total = 0.0;
+    let mut weight_valid = 0.0;
+    for (v, w) in pairs {
+        weight_total += w;
+        match v {
+            Some(v) => {
+                acc += v * w;
+                weight_valid += w;
+            },
+            None => match nulls {
+                ConvolveNulls::Propagate => return None,
+                ConvolveNulls::Zero | ConvolveNulls::Renormalize => {},
+            },
+        }
+    }
+    match nulls {
+        ConvolveNulls::Renormalize if weight_valid == 0.0 => None,
+        ConvolveNulls::Renormalize => Some(acc * weight_total / weight_valid),
+        _ => Some(acc),
+    }
+}
+
+fn to_f64(s: &Series, op: &str) -> PolarsResult<Vec<Option<f64>>> {
+    polars_ensure!(
+        s.dtype().is_primitive_numeric(),
+        InvalidOperation: "`{}` operation not supported for dtype `{}`", op, s.dtype()
+    );
+    let s = s.cast(&DataType::Float64)?;
+    Ok(s.f64()?.iter().collect())
+}
+
+/// Convolve `s` with `kernel`.
+///
+/// As in the mathematical definition, the kernel is flipped: `out[k] = sum_j s[k - j] *
+/// kernel[j]`. Positions outside of the input count as zero in the [`ConvolveMode::Full`] and
+/// [`ConvolveMode::Same`] modes.
+pub fn convolve(
+    s: &Series,
+    kernel: &[f64],
+    mode: ConvolveMode,
+    nulls: ConvolveNulls,
+) -> PolarsResult<Series> {
+    polars_ensure!(!kernel.is_empty(), ComputeError: "`convolve` requires a non-empty kernel");
+    let values = to_f64(s, "convolve")?;
+    let n = values.len() as i64;
+    let m = kernel.len() as i64;
+
+    let (start, len) = match mode {
+        ConvolveMode::Full => (0, n + m - 1),
+        ConvolveMode::Same => ((m - 1) / 2, n),
+        ConvolveMode::Valid => (m - 1, (n - m + 1).max(0)),
+    };
+    let len = if n == 0 { 0 } else { len };
+
+    let out: Float64Chunked = (start..start + len)
+        .map(|k| {
+            let pairs = kernel.iter().enumerate().filter_map(|(j, w)| {
+                let i = k - j as i64;
+                // Padding outside of the input is zero, not null.
+                if i < 0 || i >= n {
+                    None
+                } else {
+                    Some((values[i as usize], *w))
+                }
+            });
+            apply_weights(pairs, nulls)
+        })
+        .collect();
+    Ok(out.with_name(s.name().clone()).into_series())
+}
+
+/// Solve `a x = b` for a small dense system with Gaussian elimination and partial pivoting.
+fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Vec<f64> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|i, j| a[*i][col].abs().total_cmp(&a[*j][col].abs()))
+            .unwrap();
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        for row in col + 1..n {
+            let factor = a[row][col] / a[col][col];
+            let (upper, lower) = a.split_at_mut(row);
+            for (x, y) in lower[0][col..].iter_mut().zip(&upper[col][col..]) {
+                *x -= factor * y;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum = (row + 1..n).map(|k| a[row][k] * x[k]).sum::<f64>();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    x
+}
+
+/// The weights of a least squares polynomial fit of degree `polyorder` over the positions
+/// `0..window`, evaluated at position `at`.
+fn savgol_weights(window: usize, polyorder: usize, at: usize) -> Vec<f64> {
+    let half = (window / 2) as f64;
+    let z = (0..window).map(|i| i as f64 - half).collect::<Vec<_>>();
+    let t = at as f64 - half;
+
+    let powers = |x: f64| (0..=polyorder).map(move |p| x.powi(p as i32));
+    let gram = (0..=polyorder)
+        .map(|p| {
+            (0..=polyorder)
+                .map(|q| z.iter().map(|z| z.powi((p + q) as i32)).sum())
+                .collect()
+        })
+        .collect();
+    let coef = solve(gram, powers(t).collect());
+    z.iter()
+        .map(|z| powers(*z).zip(&coef).map(|(zp, c)| zp * c).sum())
+        .collect()
+}
+
+/// Smooth `s` with a Savitzky-Golay filter.
+///
+/// Every value is replaced by the value of a polynomial of degree `polyorder` fitted with least
+/// squares to the `window` values centered on it. At the edges, the polynomial fitted to the
+/// first or last full window is used. Inputs shorter than `window` are all null.
+pub fn savgol(
+    s: &Series,
+    window: usize,
+    polyorder: usize,
+    nulls: ConvolveNulls,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        window % 2 == 1,
+        ComputeError: "`window` of `savgol` must be odd, got {}", window
+    );
+    polars_ensure!(
+        polyorder < window,
+        ComputeError: "`polyorder` of `savgol` must be less than `window`, got {} and {}", polyorder, window
+    );
+    let values = to_f64(s, "savgol")?;
+    let n = values.len();
+    if n < window {
+        return Ok(Series::full_null(s.name().clone(), n, &DataType::Float64));
+    }
+
+    let half = window / 2;
+    let center = savgol_weights(window, polyorder, half);
+    let out: Float64Chunked = (0..n)
+        .map(|i| {
+            // The window the polynomial is fitted to, and the position in it to evaluate.
+            let (offset, at) = if i < half {
+                (0, i)
+            } else if i + half >= n {
+                (n - window, i + window - n)
+            } else {
+                (i - half, half)
+            };
+            let edge;
+            let weights = if at == half {
+                &center
+            } else {
+                edge = savgol_weights(window, polyorder, at);
+                &edge
+            };
+            let pairs = values[offset..offset + window]
+                .iter()
+                .copied()
+                .zip(weights.iter().copied());
+            apply_weights(pairs, nulls)
+        })
+        .collect();
+    Ok(out.with_name(s.name().clone()).into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn values(s: &Series) -> Vec<Option<f64>> {
+        s.f64().unwrap().iter().collect()
+    }
+
+    #[test]
+    fn test_convolve() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [1.0, 2.0, 3.0]);
+        let kernel = [0.0, 1.0, 0.5];
+
+        let full = convolve(&s, &kernel, ConvolveMode::Full, ConvolveNulls::Propagate)?;
+        assert_eq!(
+            values(&full),
+            [Some(0.0), Some(1.0), Some(2.5), Some(4.0), Some(1.5)]
+        );
+        let same = convolve(&s, &kernel, ConvolveMode::Same, ConvolveNulls::Propagate)?;
+        assert_eq!(values(&same), [Some(1.0), Some(2.5), Some(4.0)]);
+        let valid = convolve(&s, &kernel, ConvolveMode::Valid, ConvolveNulls::Propagate)?;
+        assert_eq!(values(&valid), [Some(2.5)]);
+
+        let s = Series::new("a".into(), [Some(1.0), None, Some(3.0)]);
+        let kernel = [1.0, 1.0];
+        let out = convolve(&s, &kernel, ConvolveMode::Valid, ConvolveNulls::Propagate)?;
+        assert_eq!(values(&out), [None, None]);
+        let out = convolve(&s, &kernel, ConvolveMode::Valid, ConvolveNulls::Zero)?;
+        assert_eq!(values(&out), [Some(1.0), Some(3.0)]);
+        let out = convolve(&s, &kernel, ConvolveMode::Valid, ConvolveNulls::Renormalize)?;
+        assert_eq!(values(&out), [Some(2.0), Some(6.0)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_savgol() -> PolarsResult<()> {
+        // A polynomial of the fitted degree is reproduced exactly, also at the edges.
+        let s = Series::new(
+            "a".into(),
+            (0..8)
+                .map(|x| (x * x) as f64 - 3.0 * x as f64)
+                .collect::<Vec<_>>(),
+        );
+        let out = savgol(&s, 5, 2, ConvolveNulls::Propagate)?;
+        for (out, exp) in values(&out).into_iter().zip(values(&s)) {
+            assert!((out.unwrap() - exp.unwrap()).abs() < 1e-9);
+        }
+
+        // The classic 5-point quadratic smoothing weights.
+        let weights = savgol_weights(5, 2, 2);
+        let expected = [-3.0, 12.0, 17.0, 12.0, -3.0].map(|w| w / 35.0);
+        for (w, exp) in weights.iter().zip(expected) {
+            assert!((w - exp).abs() < 1e-12);
+        }
+
+        assert!(savgol(&s, 4, 2, ConvolveNulls::Propagate).is_err());
+        Ok(())
+    }
+}
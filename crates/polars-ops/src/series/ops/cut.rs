@@ -3,12 +3,43 @@ use polars_core::chunked_array::builder::CategoricalChunkedBuilder;
 use polars_core::prelude::*;
 use polars_utils::format_pl_smallstr;
 
+/// The output dtype of [`cut`] and [`qcut`].
+///
+/// At most one of `include_breaks` and `include_bounds` may be set, see [`check_cut_outputs`].
+pub fn cut_dtype(include_breaks: bool, include_bounds: bool) -> DataType {
+    let category = DataType::from_categories(Categories::global());
+    if include_bounds {
+        DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("category"), category),
+            Field::new(PlSmallStr::from_static("lower"), DataType::Float64),
+            Field::new(PlSmallStr::from_static("upper"), DataType::Float64),
+        ])
+    } else if include_breaks {
+        DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("breakpoint"), DataType::Float64),
+            Field::new(PlSmallStr::from_static("category"), category),
+        ])
+    } else {
+        category
+    }
+}
+
+/// Check that at most one of `include_breaks` and `include_bounds` is set.
+pub fn check_cut_outputs(include_breaks: bool, include_bounds: bool) -> PolarsResult<()> {
+    polars_ensure!(
+        !(include_breaks && include_bounds),
+        InvalidOperation: "`include_breaks` and `include_bounds` cannot both be set"
+    );
+    Ok(())
+}
+
 fn map_cats(
     s: &Series,
     labels: &[PlSmallStr],
     sorted_breaks: &[f64],
     left_closed: bool,
     include_breaks: bool,
+    include_bounds: bool,
 ) -> PolarsResult<Series> {
     let out_name = PlSmallStr::from_static("category");
 
@@ -22,7 +53,42 @@ fn map_cats(
         PartialOrd::gt
     };
 
-    if include_breaks {
+    if include_bounds {
+        let left_ends = [&[f64::NEG_INFINITY], sorted_breaks].concat();
+        let right_ends = [sorted_breaks, &[f64::INFINITY]].concat();
+        let mut bld = CategoricalChunkedBuilder::<Categorical32Type>::new(
+            out_name.clone(),
+            DataType::from_categories(Categories::global()),
+        );
+        let mut lower =
+            PrimitiveChunkedBuilder::<Float64Type>::new(PlSmallStr::from_static("lower"), s.len());
+        let mut upper =
+            PrimitiveChunkedBuilder::<Float64Type>::new(PlSmallStr::from_static("upper"), s.len());
+        s_iter
+            .map(|opt| {
+                opt.filter(|x| !x.is_nan())
+                    .map(|x| sorted_breaks.partition_point(|v| op(&x, v)))
+            })
+            .for_each(|idx| match idx {
+                None => {
+                    bld.append_null();
+                    lower.append_null();
+                    upper.append_null();
+                },
+                Some(idx) => unsafe {
+                    bld.append_str(labels.get_unchecked(idx)).unwrap();
+                    lower.append_value(*left_ends.get_unchecked(idx));
+                    upper.append_value(*right_ends.get_unchecked(idx));
+                },
+            });
+
+        let outvals = [
+            bld.finish().into_series(),
+            lower.finish().into_series(),
+            upper.finish().into_series(),
+        ];
+        Ok(StructChunked::from_series(out_name, outvals[0].len(), outvals.iter())?.into_series())
+    } else if include_breaks {
         // This is to replicate the behavior of the old buggy version that only worked on series and
         // returned a dataframe. That included a column of the right endpoint of the interval. So we
         // return a struct series instead which can be turned into a dataframe later.
@@ -91,7 +157,10 @@ pub fn cut(
     labels: Option<Vec<PlSmallStr>>,
     left_closed: bool,
     include_breaks: bool,
+    include_bounds: bool,
 ) -> PolarsResult<Series> {
+    check_cut_outputs(include_breaks, include_bounds)?;
+
     // Breaks must be sorted to cut inputs properly.
     polars_ensure!(!breaks.iter().any(|x| x.is_nan()), ComputeError: "breaks cannot be NaN");
     breaks.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
@@ -108,7 +177,14 @@ pub fn cut(
     } else {
         compute_labels(&breaks, left_closed)?
     };
-    map_cats(s, &cut_labels, &breaks, left_closed, include_breaks)
+    map_cats(
+        s,
+        &cut_labels,
+        &breaks,
+        left_closed,
+        include_breaks,
+        include_bounds,
+    )
 }
 
 pub fn qcut(
@@ -118,7 +194,9 @@ pub fn qcut(
     left_closed: bool,
     allow_duplicates: bool,
     include_breaks: bool,
+    include_bounds: bool,
 ) -> PolarsResult<Series> {
+    check_cut_outputs(include_breaks, include_bounds)?;
     polars_ensure!(!probs.iter().any(|x| x.is_nan()), ComputeError: "quantiles cannot be NaN");
 
     if s.null_count() == s.len() {
@@ -126,7 +204,7 @@ pub fn qcut(
         return Ok(Series::full_null(
             s.name().clone(),
             s.len(),
-            &DataType::from_categories(Categories::global()),
+            &cut_dtype(include_breaks, include_bounds),
         ));
     }
 
@@ -153,7 +231,14 @@ pub fn qcut(
         compute_labels(&qbreaks, left_closed)?
     };
 
-    map_cats(&s, &cut_labels, &qbreaks, left_closed, include_breaks)
+    map_cats(
+        &s,
+        &cut_labels,
+        &qbreaks,
+        left_closed,
+        include_breaks,
+        include_bounds,
+    )
 }
 
 mod test {
@@ -174,12 +259,29 @@ mod test {
         let left_closed = false;
 
         let include_breaks = false;
-        let out = map_cats(&s, labels, breaks, left_closed, include_breaks).unwrap();
+        let out = map_cats(&s, labels, breaks, left_closed, include_breaks, false).unwrap();
         out.cat32().unwrap();
 
         let include_breaks = true;
-        let out = map_cats(&s, labels, breaks, left_closed, include_breaks).unwrap();
+        let out = map_cats(&s, labels, breaks, left_closed, include_breaks, false).unwrap();
         let out = out.struct_().unwrap().fields_as_series()[1].clone();
         out.cat32().unwrap();
     }
+
+    #[test]
+    fn test_cut_breaks_and_bounds() {
+        use polars_core::prelude::*;
+
+        use super::{cut, qcut};
+
+        let s = Series::new("x".into(), &[1, 2, 3, 4, 5]);
+
+        let err = cut(&s, vec![2.0, 4.0], None, false, true, true).unwrap_err();
+        assert!(matches!(err, PolarsError::InvalidOperation(_)));
+
+        // Also for only nulls, where no breaks are computed.
+        let s = Series::full_null("x".into(), 3, &DataType::Float64);
+        let err = qcut(&s, vec![0.5], None, false, false, true, true).unwrap_err();
+        assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    }
 }
@@ -0,0 +1,150 @@
+use polars_core::prelude::*;
+use rustfft::num_complex::Complex;
+use rustfft::{Fft, FftPlanner};
+
+pub static FFT_REAL_FIELD_NAME: &str = "re";
+pub static FFT_IMAG_FIELD_NAME: &str = "im";
+
+fn to_f64(s: &Series, op: &str) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(
+        s.dtype().is_primitive_numeric(),
+        InvalidOperation: "`{}` operation not supported for dtype `{}`", op, s.dtype()
+    );
+    let s = s.cast(&DataType::Float64)?;
+    Ok(s.f64()?.clone())
+}
+
+/// Discrete Fourier transform of the whole series.
+///
+/// Returns a struct with the real part in field `re` and the imaginary part in field `im`.
+/// Nulls are not supported, as they have no meaningful place in the spectrum.
+pub fn fft(s: &Series) -> PolarsResult<Series> {
+    let ca = to_f64(s, "fft")?;
+    polars_ensure!(
+        ca.null_count() == 0,
+        ComputeError: "`fft` does not support null values; fill or drop them first"
+    );
+
+    let mut buf = ca
+        .into_no_null_iter()
+        .map(|v| Complex::new(v, 0.0))
+        .collect::<Vec<_>>();
+    FftPlanner::new()
+        .plan_fft_forward(buf.len())
+        .process(&mut buf);
+
+    let re = Float64Chunked::from_vec(
+        PlSmallStr::from_static(FFT_REAL_FIELD_NAME),
+        buf.iter().map(|c| c.re).collect(),
+    );
+    let im = Float64Chunked::from_vec(
+        PlSmallStr::from_static(FFT_IMAG_FIELD_NAME),
+        buf.iter().map(|c| c.im).collect(),
+    );
+    let fields = [re.into_series(), im.into_series()];
+    Ok(StructChunked::from_series(s.name().clone(), s.len(), fields.iter())?.into_series())
+}
+
+/// The output dtype of [`fft`].
+pub fn fft_dtype() -> DataType {
+    DataType::Struct(vec![
+        Field::new(
+            PlSmallStr::from_static(FFT_REAL_FIELD_NAME),
+            DataType::Float64,
+        ),
+        Field::new(
+            PlSmallStr::from_static(FFT_IMAG_FIELD_NAME),
+            DataType::Float64,
+        ),
+    ])
+}
+
+/// Power spectrum of the trailing window of `window` values at every position.
+///
+/// Every value is a list with the `window / 2 + 1` one-sided periodogram bins `|X_k|^2 / window`
+/// of the window ending at that position. Positions without a full window, or whose window
+/// contains a null, are null.
+pub fn power_spectrum(s: &Series, window: usize) -> PolarsResult<Series> {
+    polars_ensure!(window > 0, ComputeError: "`window` of `power_spectrum` must be positive");
+    let ca = to_f64(s, "power_spectrum")?;
+    let ca = ca.rechunk();
+    let arr = ca.downcast_as_array();
+    let values = arr.values().as_slice();
+    let validity = arr.validity();
+
+    let n_bins = window / 2 + 1;
+    let mut builder = ListPrimitiveChunkedBuilder::<Float64Type>::new(
+        s.name().clone(),
+        s.len(),
+        s.len().saturating_sub(window - 1) * n_bins,
+        DataType::Float64,
+    );
+    let plan = FftPlanner::new().plan_fft_forward(window);
+    let mut buf = vec![Complex::default(); window];
+    let mut scratch = vec![Complex::default(); plan.get_inplace_scratch_len()];
+    let mut bins = vec![0.0; n_bins];
+    // Number of nulls in the current window.
+    let mut nulls_in_window = 0;
+    for i in 0..values.len() {
+        let is_null = |i: usize| validity.is_some_and(|v| !v.get_bit(i));
+        nulls_in_window += is_null(i) as usize;
+        if i >= window {
+            nulls_in_window -= is_null(i - window) as usize;
+        }
+        if i + 1 < window || nulls_in_window > 0 {
+            builder.append_null();
+            continue;
+        }
+
+        for (b, v) in buf.iter_mut().zip(&values[i + 1 - window..=i]) {
+            *b = Complex::new(*v, 0.0);
+        }
+        plan.process_with_scratch(&mut buf, &mut scratch);
+        for (bin, c) in bins.iter_mut().zip(&buf) {
+            *bin = c.norm_sqr() / window as f64;
+        }
+        builder.append_slice(&bins);
+    }
+    Ok(builder.finish().into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fft() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [1.0, 2.0, 0.0, -1.0, 3.0]);
+        let out = fft(&s)?;
+        let out = out.struct_()?;
+        let re = out.field_by_name("re")?;
+        let im = out.field_by_name("im")?;
+        let (re, im) = (re.f64()?, im.f64()?);
+
+        let x = [1.0, 2.0, 0.0, -1.0, 3.0];
+        let n = x.len();
+        for k in 0..n {
+            let angle = |t: usize| -2.0 * std::f64::consts::PI * (k * t) as f64 / n as f64;
+            let exp_re = x.iter().enumerate().map(|(t, v)| v * angle(t).cos()).sum::<f64>();
+            let exp_im = x.iter().enumerate().map(|(t, v)| v * angle(t).sin()).sum::<f64>();
+            assert!((re.get(k).unwrap() - exp_re).abs() < 1e-9);
+            assert!((im.get(k).unwrap() - exp_im).abs() < 1e-9);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_power_spectrum() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            [Some(1.0), Some(-1.0), Some(1.0), None, Some(1.0)],
+        );
+        let out = power_spectrum(&s, 2)?;
+        let out = out.list()?;
+        assert_eq!(out.null_count(), 3);
+        let first = out.get_as_series(1).unwrap();
+        let first = first.f64()?.into_no_null_iter().collect::<Vec<_>>();
+        assert_eq!(first, [0.0, 2.0]);
+        Ok(())
+    }
+}
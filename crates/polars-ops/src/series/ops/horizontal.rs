@@ -346,19 +346,32 @@ pub fn mean_horizontal(
 }
 
 pub fn coalesce_columns(s: &[Column]) -> PolarsResult<Column> {
-    // TODO! this can be faster if we have more than two inputs.
-    polars_ensure!(!s.is_empty(), NoData: "cannot coalesce empty list");
-    let mut out = s[0].clone();
-    for s in s {
-        if !out.null_count() == 0 {
-            return Ok(out);
-        } else {
-            let mask = out.is_not_null();
-            out = out
-                .as_materialized_series()
-                .zip_with_same_type(&mask, s.as_materialized_series())?
-                .into();
-        }
+    coalesce_columns_lazy(s.iter().cloned().map(Ok))
+}
+
+/// Fold the columns from left to right, keeping the first non-null value of every row.
+///
+/// The columns are only pulled from `columns` while rows remain that are null in all columns so
+/// far, so the caller can skip computing the columns that are not needed.
+pub fn coalesce_columns_lazy(
+    columns: impl IntoIterator<Item = PolarsResult<Column>>,
+) -> PolarsResult<Column> {
+    let mut columns = columns.into_iter();
+    let mut out = columns
+        .next()
+        .ok_or_else(|| polars_err!(NoData: "cannot coalesce empty list"))??;
+    // A unit length column may still have to be broadcast to the length of the next columns.
+    while out.null_count() > 0 || out.len() == 1 {
+        let Some(next) = columns.next() else {
+            break;
+        };
+        let next = next?;
+        // The validity of `out` tracks the rows that are filled so far.
+        let filled = out.is_not_null();
+        out = out
+            .as_materialized_series()
+            .zip_with_same_type(&filled, next.as_materialized_series())?
+            .into();
     }
     Ok(out)
 }
@@ -404,4 +417,28 @@ mod tests {
             &[Some(4), Some(2), Some(6)]
         );
     }
+
+    #[test]
+    fn test_coalesce_columns_lazy() -> PolarsResult<()> {
+        let a = Column::new("a".into(), [Some(1), None, None]);
+        let b = Column::new("b".into(), [None, Some(2), None]);
+        let c = Column::new("c".into(), [Some(4), Some(5), Some(6)]);
+
+        let mut pulled = 0;
+        let columns = [a, b, c.clone(), c].into_iter().map(|c| {
+            pulled += 1;
+            Ok(c)
+        });
+        let out = coalesce_columns_lazy(columns)?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(Vec::from(out.i32().unwrap()), &[Some(1), Some(2), Some(6)]);
+        // No nulls remain after the third column, so the last one is never pulled.
+        assert_eq!(pulled, 3);
+
+        let scalar = Column::new("lit".into(), [0]);
+        let b = Column::new("b".into(), [None, Some(2), None]);
+        let out = coalesce_columns(&[scalar, b])?;
+        assert_eq!(Vec::from(out.i32().unwrap()), &[Some(0), Some(0), Some(0)]);
+        Ok(())
+    }
 }
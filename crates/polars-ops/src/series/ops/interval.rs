@@ -0,0 +1,328 @@
+//! Intervals are structs with a `start` and an `end` field of the same numeric or temporal
+//! dtype, covering the half-open range `[start, end)`. Intervals are always closed on the left
+//! and open on the right. Intervals created with [`interval_from_bounds`] are null if either of
+//! their bounds is null.
+//!
+//! Any struct of this shape is accepted as an interval, so every interval operation checks that
+//! `start <= end` when it reads the bounds, not only [`interval_from_bounds`].
+use std::fmt::{Display, Formatter};
+
+use arrow::bitmap::Bitmap;
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+use polars_core::with_match_physical_numeric_polars_type;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub const INTERVAL_START_FIELD_NAME: &str = "start";
+pub const INTERVAL_END_FIELD_NAME: &str = "end";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum IntervalFunction {
+    /// Create intervals from their `start` and `end` bounds.
+    New,
+    /// Whether two intervals overlap.
+    Overlaps,
+    /// Whether an interval contains a value or another interval.
+    Contains,
+    /// The overlapping part of two intervals, null if they don't overlap.
+    Intersect,
+    /// Merge the overlapping and adjacent intervals into sorted, disjoint intervals.
+    UnionMerge,
+}
+
+impl Display for IntervalFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IntervalFunction::*;
+        let s = match self {
+            New => "interval",
+            Overlaps => "interval.overlaps",
+            Contains => "interval.contains",
+            Intersect => "interval.intersect",
+            UnionMerge => "interval.union_merge",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Get the dtype of intervals with bounds of `dtype`.
+pub fn interval_dtype(dtype: DataType) -> DataType {
+    DataType::Struct(vec![
+        Field::new(
+            PlSmallStr::from_static(INTERVAL_START_FIELD_NAME),
+            dtype.clone(),
+        ),
+        Field::new(PlSmallStr::from_static(INTERVAL_END_FIELD_NAME), dtype),
+    ])
+}
+
+/// Get the dtype of the bounds of the interval dtype `dtype`.
+pub fn interval_bound_dtype(dtype: &DataType) -> PolarsResult<&DataType> {
+    match dtype {
+        DataType::Struct(fields)
+            if fields.len() == 2
+                && fields[0].name() == INTERVAL_START_FIELD_NAME
+                && fields[1].name() == INTERVAL_END_FIELD_NAME
+                && fields[0].dtype() == fields[1].dtype() =>
+        {
+            Ok(fields[0].dtype())
+        },
+        dt => polars_bail!(
+            InvalidOperation:
+            "expected an interval, a struct with a 'start' and an 'end' field of the same dtype, got {}",
+            dt
+        ),
+    }
+}
+
+fn check_bound_dtype(dtype: &DataType) -> PolarsResult<()> {
+    polars_ensure!(
+        dtype.is_primitive_numeric() || dtype.is_temporal(),
+        InvalidOperation: "interval bounds must be numeric or temporal, got {}", dtype
+    );
+    Ok(())
+}
+
+fn broadcast_len(operation: &str, lhs: &Series, rhs: &Series) -> PolarsResult<usize> {
+    match (lhs.len(), rhs.len()) {
+        (1, len) | (len, 1) => Ok(len),
+        (l, r) if l == r => Ok(l),
+        (l, r) => polars_bail!(length_mismatch = operation, l, r),
+    }
+}
+
+fn broadcast(s: Series, len: usize) -> Series {
+    if s.len() == 1 && len != 1 {
+        s.new_from_index(0, len)
+    } else {
+        s
+    }
+}
+
+fn check_bounds(start: &Series, end: &Series) -> PolarsResult<()> {
+    polars_ensure!(
+        !start.gt(end)?.any(),
+        ComputeError: "the start of an interval must not be after its end"
+    );
+    Ok(())
+}
+
+/// Get the `start` and `end` bounds of the intervals, where null intervals have null bounds.
+///
+/// Raises if the `start` of an interval is after its `end`.
+fn bounds(s: &Series) -> PolarsResult<(Series, Series)> {
+    interval_bound_dtype(s.dtype())?;
+    let ca = s.struct_()?;
+    let fields = ca.fields_as_series();
+    let (mut start, mut end) = (fields[0].clone(), fields[1].clone());
+
+    if ca.null_count() > 0 {
+        let valid = ca.is_not_null();
+        let null = Series::full_null(PlSmallStr::EMPTY, s.len(), start.dtype());
+        start = start.zip_with(&valid, &null)?;
+        end = end.zip_with(&valid, &null)?;
+    }
+
+    check_bounds(&start, &end)?;
+    Ok((start, end))
+}
+
+fn build_interval(name: PlSmallStr, start: Series, end: Series) -> PolarsResult<Series> {
+    let len = start.len();
+    let valid = start.is_not_null() & end.is_not_null();
+    let fields = [
+        start.with_name(PlSmallStr::from_static(INTERVAL_START_FIELD_NAME)),
+        end.with_name(PlSmallStr::from_static(INTERVAL_END_FIELD_NAME)),
+    ];
+    let out = StructChunked::from_series(name, len, fields.iter())?;
+
+    if valid.all() {
+        return Ok(out.into_series());
+    }
+
+    let validity: Bitmap = valid.rechunk().downcast_as_array().values().clone();
+    Ok(out
+        .rechunk()
+        .into_owned()
+        .with_outer_validity(Some(validity))
+        .into_series())
+}
+
+/// Create intervals from the `start` and `end` bounds, which are cast to their supertype.
+pub fn interval_from_bounds(start: &Series, end: &Series) -> PolarsResult<Series> {
+    let dtype = try_get_supertype(start.dtype(), end.dtype())?;
+    check_bound_dtype(&dtype)?;
+
+    let len = broadcast_len("interval", start, end)?;
+    let start = broadcast(start.cast(&dtype)?, len);
+    let end = broadcast(end.cast(&dtype)?, len);
+
+    check_bounds(&start, &end)?;
+
+    build_interval(start.name().clone(), start, end)
+}
+
+/// Whether the intervals `a` and `b` overlap.
+pub fn interval_overlaps(a: &Series, b: &Series) -> PolarsResult<Series> {
+    broadcast_len("interval.overlaps", a, b)?;
+    let (a_start, a_end) = bounds(a)?;
+    let (b_start, b_end) = bounds(b)?;
+
+    let out = a_start.lt(&b_end)? & b_start.lt(&a_end)?;
+    Ok(out.with_name(a.name().clone()).into_series())
+}
+
+/// Whether the intervals `a` contain `other`, which holds values or intervals.
+pub fn interval_contains(a: &Series, other: &Series) -> PolarsResult<Series> {
+    broadcast_len("interval.contains", a, other)?;
+    let (start, end) = bounds(a)?;
+
+    let out = if matches!(other.dtype(), DataType::Struct(_)) {
+        let (other_start, other_end) = bounds(other)?;
+        start.lt_eq(&other_start)? & other_end.lt_eq(&end)?
+    } else {
+        start.lt_eq(other)? & other.lt(&end)?
+    };
+    Ok(out.with_name(a.name().clone()).into_series())
+}
+
+/// The overlapping part of the intervals `a` and `b`, null if they don't overlap. The bounds of
+/// `b` are cast to the dtype of the bounds of `a`.
+pub fn interval_intersect(a: &Series, b: &Series) -> PolarsResult<Series> {
+    let len = broadcast_len("interval.intersect", a, b)?;
+    let (a_start, a_end) = bounds(a)?;
+    let (b_start, b_end) = bounds(b)?;
+
+    let dtype = a_start.dtype().clone();
+    let [a_start, a_end, b_start, b_end] =
+        [a_start, a_end, b_start, b_end].map(|s| s.cast(&dtype).map(|s| broadcast(s, len)));
+    let (a_start, a_end, b_start, b_end) = (a_start?, a_end?, b_start?, b_end?);
+
+    // Intervals that don't overlap have no intersection.
+    let overlap = a_start.lt(&b_end)? & b_start.lt(&a_end)?;
+
+    let start = a_start.zip_with(&a_start.gt_eq(&b_start)?, &b_start)?;
+    let end = a_end.zip_with(&a_end.lt_eq(&b_end)?, &b_end)?;
+    let null = Series::full_null(PlSmallStr::EMPTY, len, &dtype);
+    let start = start.zip_with(&overlap, &null)?;
+    let end = end.zip_with(&overlap, &null)?;
+
+    build_interval(a.name().clone(), start, end)
+}
+
+/// Merge the overlapping and adjacent intervals into sorted, disjoint intervals. Null intervals
+/// are ignored.
+pub fn interval_union_merge(s: &Series) -> PolarsResult<Series> {
+    let (start, end) = bounds(s)?;
+    let dtype = start.dtype().clone();
+
+    let valid = start.is_not_null();
+    let start = start.filter(&valid)?;
+    let idx = start.arg_sort(SortOptions::default().with_maintain_order(true));
+    let start = start.take(&idx)?.to_physical_repr().into_owned();
+    let end = end
+        .filter(&valid)?
+        .take(&idx)?
+        .to_physical_repr()
+        .into_owned();
+
+    let (start, end) = with_match_physical_numeric_polars_type!(start.dtype(), |$T| {
+        let start: &ChunkedArray<$T> = start.as_ref().as_ref().as_ref();
+        let end: &ChunkedArray<$T> = end.as_ref().as_ref().as_ref();
+
+        let mut starts = Vec::new();
+        let mut ends: Vec<<$T as PolarsNumericType>::Native> = Vec::new();
+        for (s, e) in start.into_no_null_iter().zip(end.into_no_null_iter()) {
+            match ends.last_mut() {
+                Some(last) if s <= *last => {
+                    if e > *last {
+                        *last = e;
+                    }
+                },
+                _ => {
+                    starts.push(s);
+                    ends.push(e);
+                },
+            }
+        }
+
+        (
+            ChunkedArray::<$T>::from_vec(PlSmallStr::EMPTY, starts).into_series(),
+            ChunkedArray::<$T>::from_vec(PlSmallStr::EMPTY, ends).into_series(),
+        )
+    });
+
+    build_interval(s.name().clone(), start.cast(&dtype)?, end.cast(&dtype)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn intervals(start: &[i64], end: &[i64]) -> Series {
+        interval_from_bounds(
+            &Series::new("a".into(), start),
+            &Series::new("b".into(), end),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_interval_algebra() {
+        let a = intervals(&[0, 0, 5], &[5, 10, 5]);
+        let b = intervals(&[5, 2, 0], &[8, 4, 10]);
+
+        let out = interval_overlaps(&a, &b).unwrap();
+        assert_eq!(
+            Vec::from(out.bool().unwrap()),
+            &[Some(false), Some(true), Some(true)]
+        );
+
+        let out = interval_contains(&a, &b).unwrap();
+        assert_eq!(
+            Vec::from(out.bool().unwrap()),
+            &[Some(false), Some(true), Some(false)]
+        );
+
+        let out = interval_intersect(&a, &b).unwrap();
+        let (start, end) = bounds(&out).unwrap();
+        assert_eq!(Vec::from(start.i64().unwrap()), &[None, Some(2), Some(5)]);
+        assert_eq!(Vec::from(end.i64().unwrap()), &[None, Some(4), Some(5)]);
+    }
+
+    #[test]
+    fn test_interval_union_merge() {
+        let s = intervals(&[7, 0, 3, 12], &[10, 3, 5, 12]);
+
+        let out = interval_union_merge(&s).unwrap();
+        let (start, end) = bounds(&out).unwrap();
+        assert_eq!(
+            Vec::from(start.i64().unwrap()),
+            &[Some(0), Some(7), Some(12)]
+        );
+        assert_eq!(
+            Vec::from(end.i64().unwrap()),
+            &[Some(5), Some(10), Some(12)]
+        );
+    }
+
+    #[test]
+    fn test_interval_invalid() {
+        let start = Series::new("a".into(), &[1i64]);
+        let end = Series::new("b".into(), &[0i64]);
+        assert!(interval_from_bounds(&start, &end).is_err());
+
+        // Structs that were not created with `interval_from_bounds` are checked when used.
+        let s = StructChunked::from_series(
+            "a".into(),
+            1,
+            [start.with_name("start".into()), end.with_name("end".into())].iter(),
+        )
+        .unwrap()
+        .into_series();
+        assert!(interval_overlaps(&s, &s).is_err());
+        assert!(interval_union_merge(&s).is_err());
+    }
+}
@@ -58,6 +58,9 @@ where
     for<'b> T::Physical<'b>: TotalHash + TotalEq + ToTotalOrd + Copy,
     for<'b> <T::Physical<'b> as ToTotalOrd>::TotalOrdItem: Hash + Eq + Copy,
 {
+    // The offsets and the inner values have to come from the same chunk. A list column that was
+    // e.g. filtered or concatenated has multiple (possibly sliced) chunks.
+    let other = other.rechunk();
     let offsets = other.offsets()?;
     let inner = other.get_inner();
     let inner: &ChunkedArray<T> = inner.as_ref().as_ref();
@@ -72,10 +75,10 @@ where
                 let mut builder = BitmapBuilder::with_capacity(other.len());
 
                 for (start, length) in offsets.offset_and_length_iter() {
-                    let mut is_in = false;
-                    for i in 0..length {
-                        is_in |= value.to_total_ord() == inner.get(start + i).to_total_ord();
-                    }
+                    // SAFETY: the offsets are in bounds of the inner values.
+                    let is_in = (start..start + length).any(|i| {
+                        value.to_total_ord() == unsafe { inner.get_unchecked(i) }.to_total_ord()
+                    });
                     builder.push(is_in);
                 }
 
@@ -92,10 +95,10 @@ where
                 let mut builder = BitmapBuilder::with_capacity(ca_in.len());
 
                 for (value, (start, length)) in ca_in.iter().zip(offsets.offset_and_length_iter()) {
-                    let mut is_in = false;
-                    for i in 0..length {
-                        is_in |= value.to_total_ord() == inner.get(start + i).to_total_ord();
-                    }
+                    // SAFETY: the offsets are in bounds of the inner values.
+                    let is_in = (start..start + length).any(|i| {
+                        value.to_total_ord() == unsafe { inner.get_unchecked(i) }.to_total_ord()
+                    });
                     builder.push(is_in);
                 }
 
@@ -107,12 +110,11 @@ where
                 let mut builder = BitmapBuilder::with_capacity(ca_in.len());
 
                 for (value, (start, length)) in ca_in.iter().zip(offsets.offset_and_length_iter()) {
-                    let mut is_in = false;
-                    if value.is_some() {
-                        for i in 0..length {
-                            is_in |= value.to_total_ord() == inner.get(start + i).to_total_ord();
-                        }
-                    }
+                    // SAFETY: the offsets are in bounds of the inner values.
+                    let is_in = value.is_some()
+                        && (start..start + length).any(|i| {
+                            value.to_total_ord() == unsafe { inner.get_unchecked(i) }.to_total_ord()
+                        });
                     builder.push(is_in);
                 }
 
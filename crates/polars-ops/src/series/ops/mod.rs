@@ -2,9 +2,13 @@
 mod abs;
 pub mod arg_min_max;
 mod bitwise;
+#[cfg(feature = "anonymize")]
+mod bucketize;
 #[cfg(feature = "business")]
 mod business;
 mod clip;
+#[cfg(feature = "convolve")]
+mod convolve;
 #[cfg(feature = "cum_agg")]
 mod cum_agg;
 #[cfg(feature = "cutqcut")]
@@ -16,6 +20,8 @@ mod eager;
 mod ewm;
 #[cfg(feature = "ewma_by")]
 mod ewm_by;
+#[cfg(feature = "fft")]
+mod fft;
 #[cfg(feature = "round_series")]
 mod floor_divide;
 #[cfg(feature = "fused")]
@@ -27,6 +33,8 @@ mod index_of;
 mod int_range;
 #[cfg(any(feature = "interpolate_by", feature = "interpolate"))]
 mod interpolation;
+#[cfg(feature = "interval")]
+mod interval;
 #[cfg(feature = "is_between")]
 mod is_between;
 #[cfg(feature = "is_close")]
@@ -57,6 +65,8 @@ mod rle;
 mod rolling;
 #[cfg(feature = "round_series")]
 pub mod round;
+#[cfg(feature = "scan_fn")]
+mod scan;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
 mod strings;
@@ -70,9 +80,13 @@ mod various;
 pub use abs::*;
 pub use arg_min_max::ArgAgg;
 pub use bitwise::*;
+#[cfg(feature = "anonymize")]
+pub use bucketize::*;
 #[cfg(feature = "business")]
 pub use business::*;
 pub use clip::*;
+#[cfg(feature = "convolve")]
+pub use convolve::*;
 #[cfg(feature = "cum_agg")]
 pub use cum_agg::*;
 #[cfg(feature = "cutqcut")]
@@ -84,6 +98,8 @@ pub use eager::*;
 pub use ewm::*;
 #[cfg(feature = "ewma_by")]
 pub use ewm_by::*;
+#[cfg(feature = "fft")]
+pub use fft::*;
 #[cfg(feature = "round_series")]
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
@@ -99,6 +115,8 @@ pub use interpolation::interpolate::*;
 pub use interpolation::interpolate_by::*;
 #[cfg(any(feature = "interpolate", feature = "interpolate_by"))]
 pub use interpolation::*;
+#[cfg(feature = "interval")]
+pub use interval::*;
 #[cfg(feature = "is_between")]
 pub use is_between::*;
 #[cfg(feature = "is_close")]
@@ -131,6 +149,8 @@ pub use rle::*;
 pub use rolling::*;
 #[cfg(feature = "round_series")]
 pub use round::*;
+#[cfg(feature = "scan_fn")]
+pub use scan::*;
 #[cfg(feature = "search_sorted")]
 pub use search_sorted::*;
 pub use strings::*;
@@ -0,0 +1,140 @@
+use std::sync::{Arc, LazyLock, RwLock};
+
+use polars_core::prelude::*;
+use polars_utils::aliases::{InitHashMaps, PlHashMap};
+use polars_utils::pl_str::PlSmallStr;
+
+/// A step of a scan registered under a name with [`register_scan_fn`].
+///
+/// It takes the current state and a value, and returns the next state and the output value.
+pub type ScanStepFn = Arc<
+    dyn Fn(AnyValue<'static>, AnyValue<'_>) -> PolarsResult<(AnyValue<'static>, AnyValue<'static>)>
+        + Send
+        + Sync,
+>;
+
+static SCAN_FNS: LazyLock<RwLock<PlHashMap<PlSmallStr, ScanStepFn>>> =
+    LazyLock::new(|| RwLock::new(PlHashMap::new()));
+
+/// Register a step function under `name`, so that it can be used in a serializable `scan_fn`
+/// expression.
+pub fn register_scan_fn(name: PlSmallStr, step: ScanStepFn) -> PolarsResult<()> {
+    let mut registry = SCAN_FNS.write().unwrap();
+    polars_ensure!(
+        !registry.contains_key(&name),
+        Duplicate: "a scan function named '{name}' is already registered"
+    );
+    registry.insert(name, step);
+    Ok(())
+}
+
+/// Remove the step function registered under `name`, returning whether it was registered.
+pub fn unregister_scan_fn(name: &str) -> bool {
+    SCAN_FNS.write().unwrap().remove(name).is_some()
+}
+
+pub fn get_scan_fn(name: &str) -> PolarsResult<ScanStepFn> {
+    SCAN_FNS
+        .read()
+        .unwrap()
+        .get(name)
+        .cloned()
+        .ok_or_else(|| polars_err!(ComputeError: "no scan function named '{name}' is registered"))
+}
+
+/// Run a state machine over the values of `s` in order.
+///
+/// For every value, `f` is called with the current state and the value, and returns the next
+/// state and the output value. The state starts at `init`, and the output values must be of
+/// `dtype`. This is `O(n)`, where expressing such a recurrence with `cumulative_eval`
+/// recomputes every prefix.
+pub fn scan_fn<S, F>(s: &Series, init: S, dtype: &DataType, mut f: F) -> PolarsResult<Series>
+where
+    F: FnMut(S, AnyValue<'_>) -> PolarsResult<(S, AnyValue<'static>)>,
+{
+    let mut state = init;
+    let mut out = Vec::with_capacity(s.len());
+    for v in s.iter() {
+        let (next, value) = f(state, v)?;
+        state = next;
+        out.push(value);
+    }
+    Series::from_any_values_and_dtype(s.name().clone(), &out, dtype, true)
+}
+
+/// Run the step function registered under `name` over the values of `s` in order.
+pub fn scan_fn_registered(
+    s: &Series,
+    name: &str,
+    init: AnyValue<'static>,
+    dtype: &DataType,
+) -> PolarsResult<Series> {
+    let step = get_scan_fn(name)?;
+    scan_fn(s, init, dtype, |state, v| step(state, v))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scan_fn() -> PolarsResult<()> {
+        // Positions from entry and exit signals, holding the position over nulls.
+        let signals = Series::new("a".into(), [Some(1i32), None, Some(0), Some(-1), None]);
+        let out = scan_fn(&signals, 0i32, signals.dtype(), |position, signal| {
+            let position = match signal.extract::<i32>() {
+                Some(s) if s != 0 => s,
+                _ => position,
+            };
+            Ok((position, AnyValue::Int32(position)))
+        })?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(out.dtype(), &DataType::Int32);
+        assert_eq!(
+            out.i32()?.into_no_null_iter().collect::<Vec<_>>(),
+            [1, 1, 1, -1, -1]
+        );
+
+        // An exponentially weighted mean that skips nulls.
+        let s = Series::new("a".into(), [Some(1.0), Some(3.0), None]);
+        let out = scan_fn(&s, None, s.dtype(), |mean: Option<f64>, v| {
+            Ok(match (mean, v.extract::<f64>()) {
+                (Some(m), Some(v)) => (Some(0.5 * m + 0.5 * v), (0.5 * m + 0.5 * v).into()),
+                (None, Some(v)) => (Some(v), v.into()),
+                (m, None) => (m, AnyValue::Null),
+            })
+        })?;
+        assert_eq!(
+            out.f64()?.iter().collect::<Vec<_>>(),
+            [Some(1.0), Some(2.0), None]
+        );
+
+        // Output values of another type are rejected.
+        assert!(scan_fn(&s, (), s.dtype(), |_, _| Ok(((), AnyValue::Boolean(true)))).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_fn_registered() -> PolarsResult<()> {
+        let name = "test_running_count";
+        register_scan_fn(
+            name.into(),
+            Arc::new(|count, v| {
+                let count = count.extract::<u32>().unwrap() + u32::from(!v.is_null());
+                Ok((AnyValue::UInt32(count), AnyValue::UInt32(count)))
+            }),
+        )?;
+        assert!(register_scan_fn(name.into(), Arc::new(|s, v| Ok((s, v.into_static())))).is_err());
+
+        let s = Series::new("a".into(), [Some("x"), None, Some("y")]);
+        let out = scan_fn_registered(&s, name, AnyValue::UInt32(0), &DataType::UInt32)?;
+        assert_eq!(
+            out.u32()?.into_no_null_iter().collect::<Vec<_>>(),
+            [1, 1, 2]
+        );
+
+        assert!(unregister_scan_fn(name));
+        assert!(scan_fn_registered(&s, name, AnyValue::UInt32(0), &DataType::UInt32).is_err());
+        Ok(())
+    }
+}
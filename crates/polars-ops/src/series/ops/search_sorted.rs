@@ -114,3 +114,110 @@ pub fn search_sorted(
         _ => polars_bail!(opq = search_sorted, original_dtype),
     }
 }
+
+/// Find the indices where the rows of `search_values` have to be inserted into the rows of `by`
+/// to maintain the order, for a frame that is sorted by all columns of `by`.
+///
+/// `descending` and `nulls_last` hold one value per column of `by`, or a single value that is used
+/// for all columns, and have to match how `by` was sorted.
+pub fn search_sorted_by(
+    by: &[Column],
+    search_values: &[Column],
+    side: SearchSortedSide,
+    descending: &[bool],
+    nulls_last: &[bool],
+) -> PolarsResult<IdxCa> {
+    polars_ensure!(
+        !by.is_empty() && by.len() == search_values.len(),
+        InvalidOperation: "`search_sorted_by` expected the same (non-zero) number of sorted and search columns, got {} and {}",
+        by.len(), search_values.len()
+    );
+    for (s, v) in by.iter().zip(search_values) {
+        polars_ensure!(
+            s.dtype() == v.dtype(),
+            op = "search_sorted_by",
+            s.dtype(),
+            v.dtype()
+        );
+    }
+    let broadcast = |flags: &[bool], name: &str| -> PolarsResult<Vec<bool>> {
+        match flags.len() {
+            1 => Ok(vec![flags[0]; by.len()]),
+            n if n == by.len() => Ok(flags.to_vec()),
+            n => polars_bail!(
+                InvalidOperation: "`search_sorted_by` expected 1 or {} values for `{}`, got {}",
+                by.len(), name, n
+            ),
+        }
+    };
+    let descending = broadcast(descending, "descending")?;
+    let nulls_last = broadcast(nulls_last, "nulls_last")?;
+
+    // The row encoding preserves the (multi-column) sort order, so a single ascending binary search
+    // over the encoded rows suffices.
+    let ca = _get_rows_encoded_ca(PlSmallStr::EMPTY, by, &descending, &nulls_last, false)?;
+    let search_values = _get_rows_encoded_ca(
+        PlSmallStr::EMPTY,
+        search_values,
+        &descending,
+        &nulls_last,
+        false,
+    )?;
+    let idx = binary_search_ca(&ca, search_values.iter(), side, false);
+    Ok(IdxCa::new_vec(by[0].name().clone(), idx))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_search_sorted_by() -> PolarsResult<()> {
+        let by = [
+            Column::new("a".into(), [1i32, 1, 1, 2, 2]),
+            Column::new("b".into(), ["z", "y", "x", "b", "a"]),
+        ];
+        let search_values = [
+            Column::new("a".into(), [0i32, 1, 1, 2, 3]),
+            Column::new("b".into(), ["a", "y", "w", "b", "a"]),
+        ];
+        let descending = [false, true];
+        let nulls_last = [false];
+
+        let out = search_sorted_by(
+            &by,
+            &search_values,
+            SearchSortedSide::Left,
+            &descending,
+            &nulls_last,
+        )?;
+        assert_eq!(
+            Vec::from(&out),
+            [Some(0), Some(1), Some(3), Some(3), Some(5)]
+        );
+
+        let out = search_sorted_by(
+            &by,
+            &search_values,
+            SearchSortedSide::Right,
+            &descending,
+            &nulls_last,
+        )?;
+        assert_eq!(
+            Vec::from(&out),
+            [Some(0), Some(2), Some(3), Some(4), Some(5)]
+        );
+
+        assert!(
+            search_sorted_by(
+                &by,
+                &search_values[..1],
+                SearchSortedSide::Left,
+                &[false],
+                &[false]
+            )
+            .is_err()
+        );
+        Ok(())
+    }
+}
@@ -12,6 +12,11 @@ type DummyType = i32;
 #[cfg(not(feature = "dtype-u8"))]
 type DummyCa = Int32Chunked;
 
+#[cfg(feature = "dtype-u8")]
+const DUMMY_DTYPE: DataType = DataType::UInt8;
+#[cfg(not(feature = "dtype-u8"))]
+const DUMMY_DTYPE: DataType = DataType::Int32;
+
 pub trait ToDummies {
     fn to_dummies(
         &self,
@@ -98,7 +103,130 @@ fn dummies_helper_slice(
     ChunkedArray::from_vec(name, av)
 }
 
+/// Resolve the categories a column of `dtype` is one-hot encoded with.
+///
+/// These are the given `categories`, or the categories of an `Enum` if none are given. Other
+/// dtypes need explicit categories, as their output schema would depend on the data.
+pub fn dummy_categories(
+    dtype: &DataType,
+    categories: Option<&[PlSmallStr]>,
+    drop_first: bool,
+) -> PolarsResult<Vec<PlSmallStr>> {
+    let mut categories = match (categories, dtype) {
+        (Some(categories), _) => {
+            let mut seen = PlHashSet::with_capacity(categories.len());
+            for cat in categories {
+                polars_ensure!(
+                    seen.insert(cat.as_str()),
+                    Duplicate: "category '{}' is given more than once to `to_dummies`", cat
+                );
+            }
+            categories.to_vec()
+        },
+        #[cfg(feature = "dtype-categorical")]
+        (None, DataType::Enum(fcats, _)) => fcats
+            .categories()
+            .values_iter()
+            .map(PlSmallStr::from_str)
+            .collect(),
+        (None, dt) => polars_bail!(
+            InvalidOperation: "`to_dummies` on a column of dtype {} requires the `categories` to be given", dt
+        ),
+    };
+    if drop_first && !categories.is_empty() {
+        categories.remove(0);
+    }
+    Ok(categories)
+}
+
+/// The output dtype of [`to_dummies_struct`].
+#[cfg(feature = "dtype-struct")]
+pub fn to_dummies_dtype(
+    name: &str,
+    dtype: &DataType,
+    categories: Option<&[PlSmallStr]>,
+    separator: &str,
+    drop_first: bool,
+) -> PolarsResult<DataType> {
+    let fields = dummy_categories(dtype, categories, drop_first)?
+        .into_iter()
+        .map(|cat| Field::new(format_pl_smallstr!("{name}{separator}{cat}"), DUMMY_DTYPE))
+        .collect();
+    Ok(DataType::Struct(fields))
+}
+
+/// One-hot encode `s` against a fixed set of categories, returning a struct with one indicator
+/// field per category.
+///
+/// Unlike [`ToDummies::to_dummies`], the output fields do not depend on the data. Values that
+/// are null or not one of the categories are `0` in every field.
+#[cfg(feature = "dtype-struct")]
+pub fn to_dummies_struct(
+    s: &Series,
+    categories: Option<&[PlSmallStr]>,
+    separator: &str,
+    drop_first: bool,
+) -> PolarsResult<Series> {
+    let col_name = s.name();
+    let categories = dummy_categories(s.dtype(), categories, drop_first)?;
+    let positions = categories
+        .iter()
+        .enumerate()
+        .map(|(i, cat)| (cat.as_str(), i))
+        .collect::<PlHashMap<_, _>>();
+
+    let values = s.cast(&DataType::String)?;
+    let values = values.str()?;
+    let mut dummies = vec![vec![0 as DummyType; s.len()]; categories.len()];
+    for (idx, opt_v) in values.iter().enumerate() {
+        if let Some(&i) = opt_v.and_then(|v| positions.get(v)) {
+            dummies[i][idx] = 1;
+        }
+    }
+
+    let fields = categories
+        .iter()
+        .zip(dummies)
+        .map(|(cat, values)| {
+            DummyCa::from_vec(format_pl_smallstr!("{col_name}{separator}{cat}"), values)
+                .into_series()
+        })
+        .collect::<Vec<_>>();
+    Ok(StructChunked::from_series(col_name.clone(), s.len(), fields.iter())?.into_series())
+}
+
 fn sort_columns(mut columns: Vec<Column>) -> Vec<Column> {
     columns.sort_by(|a, b| a.name().partial_cmp(b.name()).unwrap());
     columns
 }
+
+#[cfg(all(test, feature = "dtype-struct"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_to_dummies_struct() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some("x"), Some("z"), None, Some("y")]);
+        let categories = ["x".into(), "y".into()];
+
+        let out = to_dummies_struct(&s, Some(&categories), "_", false)?;
+        assert_eq!(
+            out.dtype(),
+            &to_dummies_dtype("a", s.dtype(), Some(&categories), "_", false)?
+        );
+        let fields = out.struct_()?.fields_as_series();
+        assert_eq!(fields[0].name(), "a_x");
+        assert_eq!(fields[1].name(), "a_y");
+        let expected = Series::new("a_x".into(), [1u8, 0, 0, 0]).cast(&DUMMY_DTYPE)?;
+        assert!(fields[0].equals(&expected));
+        let expected = Series::new("a_y".into(), [0u8, 0, 0, 1]).cast(&DUMMY_DTYPE)?;
+        assert!(fields[1].equals(&expected));
+
+        let out = to_dummies_struct(&s, Some(&categories), "_", true)?;
+        assert_eq!(out.struct_()?.fields_as_series().len(), 1);
+
+        // Categories must be given for non-enum input.
+        assert!(to_dummies_struct(&s, None, "_", false).is_err());
+        Ok(())
+    }
+}
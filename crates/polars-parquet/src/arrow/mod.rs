@@ -1,4 +1,5 @@
 pub mod read;
+mod uuid;
 pub mod write;
 
 #[cfg(feature = "bloom_filter")]
@@ -0,0 +1,34 @@
+use arrow::array::Array;
+use arrow::datatypes::ArrowDataType;
+
+use crate::parquet::bloom_filter::is_in_set;
+use crate::parquet::schema::types::PhysicalType;
+use crate::write::hash_values;
+
+/// Whether any of the values of `array` may be in the split-block bloom filter `bitset` of a
+/// column chunk of `physical_type`. `Some(false)` proves that none of the values are in the
+/// column chunk.
+///
+/// Returns `None` if the values cannot be looked up in the bloom filter: bloom filters are not
+/// supported for the dtype of `array`, or its values are not stored as `physical_type`. Floats
+/// are not supported either, as values that compare equal (like `0.0` and `-0.0`) have
+/// different hashes.
+pub fn bloom_filter_may_contain(
+    bitset: &[u8],
+    physical_type: PhysicalType,
+    array: &dyn Array,
+) -> Option<bool> {
+    if bitset.len() < 32
+        || !bitset.len().is_multiple_of(32)
+        || matches!(
+            array.dtype().to_storage(),
+            ArrowDataType::Float32 | ArrowDataType::Float64
+        )
+    {
+        return None;
+    }
+
+    let mut may_contain = false;
+    let hashed_as = hash_values(array, |hash| may_contain |= is_in_set(bitset, hash))?;
+    (hashed_as == physical_type).then_some(may_contain)
+}
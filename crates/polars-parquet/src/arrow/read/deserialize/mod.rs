@@ -15,11 +15,12 @@ mod utils;
 
 use std::io::Cursor;
 
-use arrow::array::{Array, FixedSizeListArray, ListArray, MapArray};
+use arrow::array::{Array, FixedSizeListArray, ListArray, MapArray, StructArray, new_null_array};
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::{ArrowDataType, Field};
 use arrow::offset::Offsets;
 use polars_buffer::Buffer;
+use polars_utils::pl_str::PlSmallStr;
 use simple::page_iter_to_array;
 
 pub use self::nested_utils::{InitNested, NestedState, init_nested};
@@ -137,6 +138,7 @@ fn columns_to_iter_recursive(
     field: Field,
     init: Vec<InitNested>,
     filter: Option<Filter>,
+    timestamp_overflow: TimestampOverflowOptions,
 ) -> ParquetResult<(NestedState, Vec<Box<dyn Array>>, Bitmap)> {
     if init.is_empty() && is_primitive(&field.dtype) {
         let (_, array, pred_true_mask) = page_iter_to_array(
@@ -145,12 +147,13 @@ fn columns_to_iter_recursive(
             field,
             filter,
             None,
+            timestamp_overflow,
         )?;
 
         return Ok((NestedState::default(), array, pred_true_mask));
     }
 
-    nested::columns_to_iter_recursive(columns, types, field, init, filter)
+    nested::columns_to_iter_recursive(columns, types, field, init, filter, timestamp_overflow)
 }
 
 /// Returns the number of (parquet) columns that a [`ArrowDataType`] contains.
@@ -190,19 +193,83 @@ pub fn n_columns(dtype: &ArrowDataType) -> usize {
     }
 }
 
+/// Restricts the struct `field` to its fields named in `names`, so that only their leaf columns
+/// have to be decoded.
+///
+/// Returns the restricted field along with the indices of the leaf columns of `field` that it is
+/// decoded from, or `None` if `field` is not a struct or none of its fields are in `names`.
+pub fn project_struct_fields(field: &Field, names: &[PlSmallStr]) -> Option<(Field, Vec<usize>)> {
+    let ArrowDataType::Struct(fields) = field.dtype() else {
+        return None;
+    };
+
+    let mut projected_fields = Vec::with_capacity(names.len());
+    let mut leaf_idxs = Vec::new();
+    let mut leaf_offset = 0;
+    for f in fields {
+        let n_leaves = n_columns(f.dtype());
+        if names.contains(&f.name) {
+            projected_fields.push(f.clone());
+            leaf_idxs.extend(leaf_offset..leaf_offset + n_leaves);
+        }
+        leaf_offset += n_leaves;
+    }
+
+    if projected_fields.is_empty() {
+        return None;
+    }
+
+    let projected = Field {
+        dtype: ArrowDataType::Struct(projected_fields),
+        ..field.clone()
+    };
+    Some((projected, leaf_idxs))
+}
+
+/// Turns an array that was decoded with a field of [`project_struct_fields`] into an array of the
+/// full struct `field`. The fields that were not decoded are null.
+pub fn fill_projected_struct_fields(array: Box<dyn Array>, field: &Field) -> Box<dyn Array> {
+    let ArrowDataType::Struct(fields) = field.dtype() else {
+        unreachable!()
+    };
+    let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+    let length = array.len();
+
+    let mut decoded = array.fields().iter().zip(array.values()).peekable();
+    let values = fields
+        .iter()
+        .map(
+            |f| match decoded.next_if(|(decoded_f, _)| decoded_f.name == f.name) {
+                Some((_, values)) => values.clone(),
+                None => new_null_array(f.dtype().clone(), length),
+            },
+        )
+        .collect();
+
+    StructArray::new(
+        field.dtype().clone(),
+        length,
+        values,
+        array.validity().cloned(),
+    )
+    .boxed()
+}
+
 /// An iterator adapter that maps multiple iterators of [`PagesIter`] into an iterator of [`Array`]s.
 ///
 /// For a non-nested datatypes such as [`ArrowDataType::Int32`], this function requires a single element in `columns` and `types`.
 /// For nested types, `columns` must be composed by all parquet columns with associated types `types`.
 ///
 /// The arrays are guaranteed to be at most of size `chunk_size` and data type `field.dtype`.
+/// Timestamps that do not fit in `field.dtype` are handled according to `timestamp_overflow`.
 pub fn column_iter_to_arrays(
     columns: Vec<BasicDecompressor>,
     types: Vec<&PrimitiveType>,
     field: Field,
     filter: Option<Filter>,
+    timestamp_overflow: TimestampOverflowOptions,
 ) -> PolarsResult<(Vec<Box<dyn Array>>, Bitmap)> {
     let (_, array, pred_true_mask) =
-        columns_to_iter_recursive(columns, types, field, vec![], filter)?;
+        columns_to_iter_recursive(columns, types, field, vec![], filter, timestamp_overflow)?;
     Ok((array, pred_true_mask))
 }
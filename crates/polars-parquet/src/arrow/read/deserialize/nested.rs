@@ -1,4 +1,6 @@
 use arrow::array::StructArray;
+use arrow::bitmap::BitmapBuilder;
+use arrow::compute::utils::combine_validities_and;
 use arrow::datatypes::{
     DTYPE_CATEGORICAL_LEGACY, DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY,
     DTYPE_ENUM_VALUES_NEW, IntegerType,
@@ -18,12 +20,14 @@ pub fn columns_to_iter_recursive(
     field: Field,
     mut init: Vec<InitNested>,
     filter: Option<Filter>,
+    timestamp_overflow: TimestampOverflowOptions,
 ) -> ParquetResult<(NestedState, Vec<Box<dyn Array>>, Bitmap)> {
     if !field.dtype().is_nested() || field.is_pl_pq_empty_struct() {
         let pages = columns.pop().unwrap();
         init.push(InitNested::Primitive(field.is_nullable));
         let type_ = types.pop().unwrap();
-        let (nested, arr, pdm) = page_iter_to_array(pages, type_, field, filter, Some(init))?;
+        let (nested, arr, pdm) =
+            page_iter_to_array(pages, type_, field, filter, Some(init), timestamp_overflow)?;
         Ok((nested.unwrap(), arr, pdm))
     } else {
         match field.dtype() {
@@ -35,6 +39,7 @@ pub fn columns_to_iter_recursive(
                     inner.as_ref().clone(),
                     init,
                     filter,
+                    timestamp_overflow,
                 )?;
                 let array = array
                     .into_iter()
@@ -50,6 +55,7 @@ pub fn columns_to_iter_recursive(
                     inner.as_ref().clone(),
                     init,
                     filter,
+                    timestamp_overflow,
                 )?;
                 let array = array
                     .into_iter()
@@ -58,8 +64,25 @@ pub fn columns_to_iter_recursive(
                 Ok((nested, array, ptm))
             },
             ArrowDataType::Struct(fields) => {
-                // This definitely does not support Filter predicate yet.
-                assert!(!matches!(&filter, Some(Filter::Predicate(_))));
+                if let Some(Filter::Predicate(predicate)) = &filter {
+                    // The predicate applies to one of the fields, so the struct is decoded in
+                    // full and then filtered on the values of that field.
+                    let (nested, mut array, _) = columns_to_iter_recursive(
+                        columns,
+                        types,
+                        field.clone(),
+                        init,
+                        None,
+                        timestamp_overflow,
+                    )?;
+                    assert_eq!(array.len(), 1);
+                    let array = array.pop().unwrap();
+                    let pred_true_mask =
+                        evaluate_struct_field_predicate(array.as_ref(), predicate)?;
+                    let array =
+                        polars_compute::filter::filter_with_bitmap(array.as_ref(), &pred_true_mask);
+                    return Ok((nested, vec![array], pred_true_mask));
+                }
 
                 // @NOTE:
                 // We go back to front here, because we constantly split off the end of the array
@@ -87,6 +110,7 @@ pub fn columns_to_iter_recursive(
                             struct_field.clone(),
                             init,
                             filter.clone(),
+                            timestamp_overflow,
                         )
                     };
 
@@ -141,6 +165,7 @@ pub fn columns_to_iter_recursive(
                     inner.as_ref().clone(),
                     init,
                     filter,
+                    timestamp_overflow,
                 )?;
                 let array = array
                     .into_iter()
@@ -226,6 +251,7 @@ pub fn columns_to_iter_recursive(
                     field.with_dtype(ext.inner.clone()),
                     init,
                     filter,
+                    timestamp_overflow,
                 )?;
 
                 // Restore the extension type.
@@ -243,3 +269,48 @@ pub fn columns_to_iter_recursive(
         }
     }
 }
+
+/// Evaluates `predicate` on the values of the struct field it applies to, returning which rows of
+/// `array` match.
+fn evaluate_struct_field_predicate(
+    array: &dyn Array,
+    predicate: &PredicateFilter,
+) -> ParquetResult<Bitmap> {
+    if predicate.struct_field_path.is_empty() {
+        return Err(ParquetError::not_supported(
+            "predicate on a struct column without a field",
+        ));
+    }
+
+    // A field is null wherever one of the structs containing it is null.
+    let mut values = array;
+    let mut validity = array.validity().cloned();
+    for name in &predicate.struct_field_path {
+        let Some(struct_array) = values.as_any().downcast_ref::<StructArray>() else {
+            return Err(ParquetError::oos(format!(
+                "predicate on field '{name}' of a non-struct column"
+            )));
+        };
+        let Some(idx) = struct_array.fields().iter().position(|f| f.name == *name) else {
+            return Err(ParquetError::oos(format!(
+                "predicate on non-existent struct field '{name}'"
+            )));
+        };
+        values = struct_array.values()[idx].as_ref();
+        validity = combine_validities_and(validity.as_ref(), values.validity());
+    }
+
+    // The predicate must not be given nulls, so the values behind the nulls are evaluated as well
+    // and their results are replaced by the result for null.
+    let mut mask = BitmapBuilder::with_capacity(values.len());
+    predicate
+        .predicate
+        .evaluate_mut(values.with_validity(None).as_ref(), &mut mask);
+    let mask = mask.freeze();
+
+    Ok(match validity {
+        None => mask,
+        Some(validity) if predicate.predicate.evaluate_null() => &mask | &!&validity,
+        Some(validity) => &mask & &validity,
+    })
+}
@@ -1,5 +1,6 @@
 use arrow::array::{Array, BinaryViewArray, FixedSizeBinaryArray, PrimitiveArray, StructArray};
 use arrow::bitmap::Bitmap;
+use arrow::compute::utils::combine_validities_and;
 use arrow::datatypes::{
     ArrowDataType, DTYPE_CATEGORICAL_LEGACY, DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY,
     DTYPE_ENUM_VALUES_NEW, Field, IntegerType, IntervalUnit, TimeUnit,
@@ -10,19 +11,21 @@ use polars_compute::cast::CastOptionsImpl;
 use polars_utils::float16::pf16;
 use polars_utils::pl_str::PlSmallStr;
 
+use super::utils::Decoder;
 use super::utils::filter::Filter;
 use super::{
     BasicDecompressor, InitNested, NestedState, boolean, fixed_size_binary, null, primitive,
 };
+use crate::arrow::uuid::uuids_to_strings;
 use crate::parquet::error::ParquetResult;
 use crate::parquet::schema::types::{
     PhysicalType, PrimitiveLogicalType, PrimitiveType, TimeUnit as ParquetTimeUnit,
 };
 use crate::parquet::types::int96_to_i64_ns;
-use crate::read::ParquetError;
 use crate::read::deserialize::categorical::CategoricalDecoder;
 use crate::read::deserialize::utils::PageDecoder;
 use crate::read::deserialize::{binary, binview};
+use crate::read::{ParquetError, TimestampOverflow, TimestampOverflowOptions};
 
 /// An iterator adapter that maps an iterator of Pages a boxed [`Array`] of [`ArrowDataType`]
 /// `dtype` with a maximum of `num_rows` elements.
@@ -32,6 +35,7 @@ pub fn page_iter_to_array(
     field: Field,
     filter: Option<Filter>,
     init_nested: Option<Vec<InitNested>>,
+    timestamp_overflow: TimestampOverflowOptions,
 ) -> ParquetResult<(Option<NestedState>, Vec<Box<dyn Array>>, Bitmap)> {
     use ArrowDataType::*;
 
@@ -145,6 +149,7 @@ pub fn page_iter_to_array(
                 filter,
                 time_unit,
                 init_nested,
+                timestamp_overflow,
             );
         },
         (PhysicalType::FixedLenByteArray(_), FixedSizeBinary(_)) => {
@@ -295,6 +300,25 @@ pub fn page_iter_to_array(
 
             (nested, array, ptm)
         },
+        (PhysicalType::FixedLenByteArray(16), Utf8View) => {
+            // UUIDs that are read as their canonical string form.
+            let n = 16;
+            let (nested, array, ptm) = PageDecoder::new(
+                &field.name,
+                pages,
+                ArrowDataType::FixedSizeBinary(n),
+                fixed_size_binary::BinaryDecoder { size: n },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let array = array
+                .iter()
+                .map(|array| uuids_to_strings(array).to_boxed())
+                .collect();
+
+            (nested, array, ptm)
+        },
         (PhysicalType::Int32, Decimal(_, _)) => PageDecoder::new(
             &field.name,
             pages,
@@ -513,6 +537,14 @@ pub fn page_iter_to_array(
             init_nested,
         )?
         .collect_boxed(filter)?,
+        (PhysicalType::FixedLenByteArray(2), Float32) => PageDecoder::new(
+            &field.name,
+            pages,
+            dtype,
+            primitive::FloatDecoder::closure(|x: pf16| f32::from(x)),
+            init_nested,
+        )?
+        .collect_boxed(filter)?,
         (PhysicalType::Float, Float32) => PageDecoder::new(
             &field.name,
             pages,
@@ -563,10 +595,12 @@ pub fn page_iter_to_array(
             .collect_boxed(filter)?
         },
         (_, Dictionary(key_type, value_type, _)) => {
-            // @NOTE: This should only hit in two cases:
+            // @NOTE: This should only hit in three cases:
             // - Polars enum's and categorical's
+            // - String columns that are read as categoricals
             // - Int -> String which can be turned into categoricals
             assert_eq!(value_type.as_ref(), &ArrowDataType::Utf8View);
+            let key_type = *key_type;
 
             if field.metadata.is_some_and(|md| {
                 md.contains_key(DTYPE_ENUM_VALUES_LEGACY)
@@ -604,29 +638,35 @@ pub fn page_iter_to_array(
                     .collect_boxed(filter)?,
                     _ => unreachable!(),
                 }
-            } else {
-                let (nested, array, ptm) = PageDecoder::new(
+            } else if key_type == IntegerType::UInt32
+                && matches!(physical_type, PhysicalType::ByteArray)
+            {
+                // Decode the keys of the dictionary-encoded pages directly, so that only the
+                // dictionary is materialized as strings. Writers other than Polars fall back to
+                // plain-encoded pages if the dictionary grows too large, which the categorical
+                // decoder does not support. Such column chunks are decoded as strings instead.
+                let fallback_pages = pages.fork();
+                let decoded = PageDecoder::new(
                     &field.name,
                     pages,
-                    ArrowDataType::Utf8View,
-                    binview::BinViewDecoder::new_string(),
-                    init_nested,
-                )?
-                .collect(filter)?;
-
-                let array = array
-                    .into_iter()
-                    .map(|array| {
-                        polars_compute::cast::cast(
-                            array.as_ref(),
-                            &dtype,
-                            CastOptionsImpl::default(),
-                        )
-                        .unwrap()
-                    })
-                    .collect();
-
-                (nested, array, ptm)
+                    dtype.clone(),
+                    CategoricalDecoder::<u32>::new(),
+                    init_nested.clone(),
+                )
+                .and_then(|decoder| decoder.collect_boxed(filter.clone()));
+
+                match decoded {
+                    Ok(decoded) => decoded,
+                    Err(_) => strings_to_dictionary(
+                        fallback_pages,
+                        &field.name,
+                        dtype,
+                        filter,
+                        init_nested,
+                    )?,
+                }
+            } else {
+                strings_to_dictionary(pages, &field.name, dtype, filter, init_nested)?
             }
         },
         (from, to) => {
@@ -637,6 +677,33 @@ pub fn page_iter_to_array(
     })
 }
 
+/// Decode string pages and cast them to the dictionary type `dtype`.
+fn strings_to_dictionary(
+    pages: BasicDecompressor,
+    field_name: &str,
+    dtype: ArrowDataType,
+    filter: Option<Filter>,
+    init_nested: Option<Vec<InitNested>>,
+) -> ParquetResult<(Option<NestedState>, Vec<Box<dyn Array>>, Bitmap)> {
+    let (nested, array, ptm) = PageDecoder::new(
+        field_name,
+        pages,
+        ArrowDataType::Utf8View,
+        binview::BinViewDecoder::new_string(),
+        init_nested,
+    )?
+    .collect(filter)?;
+
+    let array = array
+        .into_iter()
+        .map(|array| {
+            polars_compute::cast::cast(array.as_ref(), &dtype, CastOptionsImpl::default()).unwrap()
+        })
+        .collect();
+
+    Ok((nested, array, ptm))
+}
+
 /// Unify the timestamp unit from parquet TimeUnit into arrow's TimeUnit
 /// Returns (a int64 factor, is_multiplier)
 fn unify_timestamp_unit(
@@ -706,6 +773,98 @@ pub fn int96_to_i64_s(value: [u32; 3]) -> i64 {
     day_seconds + seconds
 }
 
+/// Converts an INT96 timestamp to the number of `time_unit`s since the epoch.
+///
+/// This cannot overflow, as every INT96 timestamp fits in an `i128` with a nanosecond resolution.
+#[inline]
+fn int96_to_i128(value: [u32; 3], time_unit: TimeUnit) -> i128 {
+    const JULIAN_DAY_OF_EPOCH: i128 = 2_440_588;
+    const NANOS_PER_DAY: i128 = 86_400 * 1_000_000_000;
+
+    let nanos_per_unit = match time_unit {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    };
+
+    let day = value[2] as i128;
+    let nanoseconds = ((value[1] as i128) << 32) + value[0] as i128;
+
+    (day - JULIAN_DAY_OF_EPOCH) * (NANOS_PER_DAY / nanos_per_unit) + nanoseconds / nanos_per_unit
+}
+
+/// Converts timestamps that were decoded to `i128` into an `i64` array of `dtype`. Values that
+/// do not fit are handled according to `overflow`.
+fn i128_to_timestamp_array(
+    field_name: &str,
+    physical_type: &str,
+    array: PrimitiveArray<i128>,
+    dtype: ArrowDataType,
+    time_unit: TimeUnit,
+    overflow: TimestampOverflow,
+) -> ParquetResult<Box<dyn Array>> {
+    let fits = |v: &i128| i64::try_from(*v).is_ok();
+
+    let validity = if array.values().iter().all(fits) {
+        array.validity().cloned()
+    } else {
+        match overflow {
+            TimestampOverflow::Raise => {
+                if array.iter().any(|v| v.is_some_and(|v| !fits(v))) {
+                    return Err(ParquetError::InvalidParameter(format!(
+                        "{physical_type} timestamp in column '{field_name}' is out of range for \
+                        Timestamp({time_unit:?}); read it with a coarser time unit or with \
+                        another {physical_type} overflow policy"
+                    )));
+                }
+                array.validity().cloned()
+            },
+            TimestampOverflow::Null => {
+                let in_range = array.values().iter().map(fits).collect::<Bitmap>();
+                combine_validities_and(array.validity(), Some(&in_range))
+            },
+            TimestampOverflow::Saturate => unreachable!("saturated while decoding"),
+        }
+    };
+
+    let values = array.values().iter().map(|v| *v as i64).collect::<Vec<_>>();
+    Ok(PrimitiveArray::new(dtype, values.into(), validity).to_boxed())
+}
+
+/// Decodes timestamps with `decoder`, which decodes them to `i128` so that values that overflow
+/// can be detected, and handles those values according to `overflow`.
+#[expect(clippy::too_many_arguments)]
+fn overflowing_timestamp<D: Decoder<Output = PrimitiveArray<i128>>>(
+    field_name: &str,
+    physical_type: &str,
+    pages: BasicDecompressor,
+    dtype: ArrowDataType,
+    filter: Option<Filter>,
+    time_unit: TimeUnit,
+    nested: Option<Vec<InitNested>>,
+    decoder: D,
+    overflow: TimestampOverflow,
+) -> ParquetResult<(Option<NestedState>, Vec<Box<dyn Array>>, Bitmap)> {
+    let (nested, arrays, pred_true_mask) =
+        PageDecoder::new(field_name, pages, ArrowDataType::Int128, decoder, nested)?
+            .collect(filter)?;
+    let arrays = arrays
+        .into_iter()
+        .map(|array| {
+            i128_to_timestamp_array(
+                field_name,
+                physical_type,
+                array,
+                dtype.clone(),
+                time_unit,
+                overflow,
+            )
+        })
+        .collect::<ParquetResult<Vec<_>>>()?;
+    Ok((nested, arrays, pred_true_mask))
+}
+
 #[expect(clippy::too_many_arguments)]
 fn timestamp(
     field_name: &str,
@@ -716,41 +875,65 @@ fn timestamp(
     filter: Option<Filter>,
     time_unit: TimeUnit,
     nested: Option<Vec<InitNested>>,
+    timestamp_overflow: TimestampOverflowOptions,
 ) -> ParquetResult<(Option<NestedState>, Vec<Box<dyn Array>>, Bitmap)> {
     if physical_type == &PhysicalType::Int96 {
-        return match time_unit {
-            TimeUnit::Nanosecond => PageDecoder::new(
-                field_name,
-                pages,
-                dtype,
-                primitive::FloatDecoder::closure(|x: [u32; 3]| int96_to_i64_ns(x)),
-                nested,
-            )?
-            .collect_boxed(filter),
-            TimeUnit::Microsecond => PageDecoder::new(
-                field_name,
-                pages,
-                dtype,
-                primitive::FloatDecoder::closure(|x: [u32; 3]| int96_to_i64_us(x)),
-                nested,
-            )?
-            .collect_boxed(filter),
-            TimeUnit::Millisecond => PageDecoder::new(
+        return match timestamp_overflow.int96 {
+            None => match time_unit {
+                TimeUnit::Nanosecond => PageDecoder::new(
+                    field_name,
+                    pages,
+                    dtype,
+                    primitive::FloatDecoder::closure(|x: [u32; 3]| int96_to_i64_ns(x)),
+                    nested,
+                )?
+                .collect_boxed(filter),
+                TimeUnit::Microsecond => PageDecoder::new(
+                    field_name,
+                    pages,
+                    dtype,
+                    primitive::FloatDecoder::closure(|x: [u32; 3]| int96_to_i64_us(x)),
+                    nested,
+                )?
+                .collect_boxed(filter),
+                TimeUnit::Millisecond => PageDecoder::new(
+                    field_name,
+                    pages,
+                    dtype,
+                    primitive::FloatDecoder::closure(|x: [u32; 3]| int96_to_i64_ms(x)),
+                    nested,
+                )?
+                .collect_boxed(filter),
+                TimeUnit::Second => PageDecoder::new(
+                    field_name,
+                    pages,
+                    dtype,
+                    primitive::FloatDecoder::closure(|x: [u32; 3]| int96_to_i64_s(x)),
+                    nested,
+                )?
+                .collect_boxed(filter),
+            },
+            Some(TimestampOverflow::Saturate) => PageDecoder::new(
                 field_name,
                 pages,
                 dtype,
-                primitive::FloatDecoder::closure(|x: [u32; 3]| int96_to_i64_ms(x)),
+                primitive::FloatDecoder::closure(move |x: [u32; 3]| {
+                    int96_to_i128(x, time_unit).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+                }),
                 nested,
             )?
             .collect_boxed(filter),
-            TimeUnit::Second => PageDecoder::new(
+            Some(overflow) => overflowing_timestamp(
                 field_name,
+                "INT96",
                 pages,
                 dtype,
-                primitive::FloatDecoder::closure(|x: [u32; 3]| int96_to_i64_s(x)),
+                filter,
+                time_unit,
                 nested,
-            )?
-            .collect_boxed(filter),
+                primitive::FloatDecoder::closure(move |x: [u32; 3]| int96_to_i128(x, time_unit)),
+                overflow,
+            ),
         };
     };
 
@@ -761,8 +944,8 @@ fn timestamp(
     }
 
     let (factor, is_multiplier) = unify_timestamp_unit(logical_type, time_unit);
-    match (factor, is_multiplier) {
-        (1, _) => PageDecoder::new(
+    match (factor, is_multiplier, timestamp_overflow.int64) {
+        (1, _, _) => PageDecoder::new(
             field_name,
             pages,
             dtype,
@@ -770,7 +953,7 @@ fn timestamp(
             nested,
         )?
         .collect_boxed(filter),
-        (a, true) => PageDecoder::new(
+        (a, true, None) => PageDecoder::new(
             field_name,
             pages,
             dtype,
@@ -778,7 +961,27 @@ fn timestamp(
             nested,
         )?
         .collect_boxed(filter),
-        (a, false) => PageDecoder::new(
+        (a, true, Some(TimestampOverflow::Saturate)) => PageDecoder::new(
+            field_name,
+            pages,
+            dtype,
+            primitive::IntDecoder::closure(move |x: i64| x.saturating_mul(a)),
+            nested,
+        )?
+        .collect_boxed(filter),
+        // Only reading with a finer time unit can overflow.
+        (a, true, Some(overflow)) => overflowing_timestamp(
+            field_name,
+            "INT64",
+            pages,
+            dtype,
+            filter,
+            time_unit,
+            nested,
+            primitive::IntDecoder::closure(move |x: i64| x as i128 * a as i128),
+            overflow,
+        ),
+        (a, false, _) => PageDecoder::new(
             field_name,
             pages,
             dtype,
@@ -2,6 +2,7 @@ use std::ops::Range;
 
 use arrow::array::Splitable;
 use arrow::bitmap::Bitmap;
+use polars_utils::pl_str::PlSmallStr;
 
 use crate::read::expr::ParquetColumnExprRef;
 
@@ -9,6 +10,10 @@ use crate::read::expr::ParquetColumnExprRef;
 pub struct PredicateFilter {
     pub predicate: ParquetColumnExprRef,
     pub include_values: bool,
+    /// The path to the field of a struct column that the predicate applies to, e.g. `["a", "b"]`
+    /// for `col.struct.field("a").struct.field("b")`. Empty if the predicate applies to the
+    /// column itself.
+    pub struct_field_path: Vec<PlSmallStr>,
 }
 
 #[derive(Clone)]
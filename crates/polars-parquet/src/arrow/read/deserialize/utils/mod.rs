@@ -19,6 +19,7 @@ use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::page::{DataPage, DictPage, split_buffer};
 use crate::parquet::schema::Repetition;
 use crate::read::expr::{ParquetScalar, SpecializedParquetColumnExpr};
+use crate::read::page_index::can_skip_page as can_skip_page_by_index;
 
 #[derive(Debug)]
 pub(crate) struct State<'a, D: Decoder> {
@@ -554,12 +555,29 @@ impl<D: Decoder> PageDecoder<D> {
             metrics.decode_type = DecodeType::Predicate;
         }
 
+        // Pages whose statistics in the page index rule out a match are skipped.
+        let column_index = self
+            .iter
+            .page_index()
+            .cloned()
+            .filter(|_| !pred_tracks_nulls && specialized_pred.is_some())
+            .filter(|page_index| page_index.column_index.is_some());
+
         const MINIMUM_CHUNK_SIZE: usize = 256;
         let mut chunks = Vec::new();
+        let mut page_idx = 0;
         while let Some(page) = self.iter.next() {
             let page = page?;
-
-            let mut can_skip_page = false;
+            page_idx += 1;
+
+            let mut can_skip_page = column_index.as_ref().is_some_and(|page_index| {
+                can_skip_page_by_index(
+                    page_index.column_index.as_ref().unwrap(),
+                    page_idx - 1,
+                    specialized_pred.unwrap(),
+                    &page.page().descriptor.primitive_type,
+                )
+            });
 
             // Skip a dictionary encoded page if none of the dictionary values match the predicate.
             // This is essentially a slower version of statistics skipping.
@@ -1,28 +1,36 @@
 //! APIs to read from Parquet format.
 #![allow(clippy::type_complexity)]
 
+#[cfg(feature = "bloom_filter")]
+mod bloom_filter;
 mod deserialize;
 pub mod expr;
+mod page_index;
 pub mod schema;
 pub mod statistics;
 
 use std::io::{Read, Seek};
+use std::sync::Arc;
 
 use arrow::types::{NativeType, i256};
+#[cfg(feature = "bloom_filter")]
+pub use bloom_filter::bloom_filter_may_contain;
 pub use deserialize::{
     Filter, InitNested, NestedState, PredicateFilter, column_iter_to_arrays, create_list,
-    create_map, get_page_iterator, init_nested, n_columns,
+    create_map, fill_projected_struct_fields, get_page_iterator, init_nested, n_columns,
+    project_struct_fields,
 };
 #[cfg(feature = "async")]
 use futures::{AsyncRead, AsyncSeek};
 use polars_error::PolarsResult;
-pub use schema::{FileMetadata, infer_schema};
+pub use schema::{FileMetadata, TimestampOverflow, TimestampOverflowOptions, infer_schema};
 
 #[cfg(feature = "async")]
 pub use crate::parquet::read::{get_page_stream, read_metadata_async as _read_metadata_async};
 // re-exports of crate::parquet's relevant APIs
 pub use crate::parquet::{
     FallibleStreamingIterator,
+    encryption::FileDecryptionProperties,
     error::ParquetError,
     fallible_streaming_iterator,
     metadata::{ColumnChunkMetadata, ColumnDescriptor, RowGroupMetadata},
@@ -30,6 +38,7 @@ pub use crate::parquet::{
     read::{
         BasicDecompressor, MutStreamingIterator, PageReader, ReadColumnIterator, State, decompress,
         get_column_iterator, read_metadata as _read_metadata,
+        read_metadata_with_decryption as _read_metadata_with_decryption,
     },
     schema::types::{
         GroupLogicalType, ParquetType, PhysicalType, PrimitiveConvertedType, PrimitiveLogicalType,
@@ -58,6 +67,15 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> PolarsResult<FileMetadat
     Ok(_read_metadata(reader)?)
 }
 
+/// Reads parquets' metadata synchronously, decrypting it with `decryption` if the file is
+/// encrypted.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: Option<&Arc<FileDecryptionProperties>>,
+) -> PolarsResult<FileMetadata> {
+    Ok(_read_metadata_with_decryption(reader, decryption)?)
+}
+
 /// Reads parquets' metadata asynchronously.
 #[cfg(feature = "async")]
 pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
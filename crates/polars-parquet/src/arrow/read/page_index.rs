@@ -0,0 +1,92 @@
+use std::cmp::Ordering;
+
+use polars_parquet_format::ColumnIndex;
+
+use crate::parquet::schema::types::{
+    IntegerType, PhysicalType, PrimitiveConvertedType, PrimitiveLogicalType, PrimitiveType,
+};
+use crate::read::expr::{ParquetScalar, SpecializedParquetColumnExpr};
+
+/// Returns whether none of the values of data page `page_idx` can match `predicate`, according
+/// to the statistics of the page in `column_index`.
+///
+/// This assumes that the predicate does not match nulls.
+pub(crate) fn can_skip_page(
+    column_index: &ColumnIndex,
+    page_idx: usize,
+    predicate: &SpecializedParquetColumnExpr,
+    primitive_type: &PrimitiveType,
+) -> bool {
+    use SpecializedParquetColumnExpr as P;
+
+    let Some(&is_null_page) = column_index.null_pages.get(page_idx) else {
+        return false;
+    };
+    if is_null_page {
+        return true;
+    }
+
+    let min = &column_index.min_values[page_idx];
+    let max = &column_index.max_values[page_idx];
+    let cmp = |scalar: &ParquetScalar, value: &[u8]| compare(scalar, value, primitive_type);
+    let is_out_of_range = |scalar: &ParquetScalar| {
+        cmp(scalar, min) == Some(Ordering::Less) || cmp(scalar, max) == Some(Ordering::Greater)
+    };
+
+    match predicate {
+        P::Equal(scalar) => is_out_of_range(scalar),
+        P::EqualOneOf(scalars) => scalars.iter().all(is_out_of_range),
+        P::Between(low, high) => {
+            cmp(high, min) == Some(Ordering::Less) || cmp(low, max) == Some(Ordering::Greater)
+        },
+        _ => false,
+    }
+}
+
+/// Compares `scalar` to a plain-encoded statistics value of a column of `primitive_type`.
+///
+/// Returns `None` if the statistics are not ordered like the scalar, which is the case for
+/// unsigned integers and decimals.
+fn compare(
+    scalar: &ParquetScalar,
+    value: &[u8],
+    primitive_type: &PrimitiveType,
+) -> Option<Ordering> {
+    let is_unsigned = matches!(
+        primitive_type.logical_type,
+        Some(PrimitiveLogicalType::Integer(
+            IntegerType::UInt8 | IntegerType::UInt16 | IntegerType::UInt32 | IntegerType::UInt64
+        ))
+    ) || matches!(
+        primitive_type.converted_type,
+        Some(
+            PrimitiveConvertedType::Uint8
+                | PrimitiveConvertedType::Uint16
+                | PrimitiveConvertedType::Uint32
+                | PrimitiveConvertedType::Uint64
+        )
+    );
+    let is_decimal = matches!(
+        primitive_type.logical_type,
+        Some(PrimitiveLogicalType::Decimal(_, _))
+    ) || matches!(
+        primitive_type.converted_type,
+        Some(PrimitiveConvertedType::Decimal(_, _))
+    );
+    if is_unsigned || is_decimal {
+        return None;
+    }
+
+    let int32 = || Some(i32::from_le_bytes(value.try_into().ok()?));
+    let int64 = || Some(i64::from_le_bytes(value.try_into().ok()?));
+
+    match (scalar, primitive_type.physical_type) {
+        (ParquetScalar::Int8(v), PhysicalType::Int32) => Some((*v as i32).cmp(&int32()?)),
+        (ParquetScalar::Int16(v), PhysicalType::Int32) => Some((*v as i32).cmp(&int32()?)),
+        (ParquetScalar::Int32(v), PhysicalType::Int32) => Some(v.cmp(&int32()?)),
+        (ParquetScalar::Int64(v), PhysicalType::Int64) => Some(v.cmp(&int64()?)),
+        (ParquetScalar::String(v), PhysicalType::ByteArray) => Some(v.as_bytes().cmp(value)),
+        (ParquetScalar::Binary(v), PhysicalType::ByteArray) => Some(v.as_ref().cmp(value)),
+        _ => None,
+    }
+}
@@ -182,6 +182,7 @@ fn from_fixed_len_byte_array(
         (Some(PrimitiveLogicalType::Decimal(precision, scale)), _) => {
             ArrowDataType::Decimal(precision, scale)
         },
+        (Some(PrimitiveLogicalType::Float16), _) if length == 2 => ArrowDataType::Float16,
         (None, Some(PrimitiveConvertedType::Decimal(precision, scale))) => {
             ArrowDataType::Decimal(precision, scale)
         },
@@ -205,12 +206,21 @@ fn to_primitive_type_inner(
         PhysicalType::Int64 => {
             from_int64(primitive_type.logical_type, primitive_type.converted_type)
         },
-        PhysicalType::Int96 => ArrowDataType::Timestamp(options.int96_coerce_to_timeunit, None),
+        PhysicalType::Int96 => ArrowDataType::Timestamp(
+            options.int96_coerce_to_timeunit,
+            options.int96_coerce_to_timezone.clone(),
+        ),
         PhysicalType::Float => ArrowDataType::Float32,
         PhysicalType::Double => ArrowDataType::Float64,
         PhysicalType::ByteArray => {
             from_byte_array(&primitive_type.logical_type, &primitive_type.converted_type)
         },
+        PhysicalType::FixedLenByteArray(16)
+            if options.uuid_as_string
+                && primitive_type.logical_type == Some(PrimitiveLogicalType::Uuid) =>
+        {
+            ArrowDataType::Utf8View
+        },
         PhysicalType::FixedLenByteArray(length) => from_fixed_len_byte_array(
             length,
             primitive_type.logical_type,
@@ -504,6 +514,54 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_float16_field() -> PolarsResult<()> {
+        let message = "
+        message test_schema {
+            REQUIRED FIXED_LEN_BYTE_ARRAY (2) float16 (FLOAT16);
+        }
+        ";
+        let expected = vec![Field::new("float16".into(), ArrowDataType::Float16, false)];
+
+        let parquet_schema = SchemaDescriptor::try_from_message(message)?;
+        let fields = parquet_to_arrow_schema(parquet_schema.fields());
+        let fields = fields.iter_values().cloned().collect::<Vec<_>>();
+
+        assert_eq!(fields, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_uuid_field() -> PolarsResult<()> {
+        let message = "
+        message test_schema {
+            REQUIRED FIXED_LEN_BYTE_ARRAY (16) id (UUID);
+        }
+        ";
+        let parquet_schema = SchemaDescriptor::try_from_message(message)?;
+
+        let fields = parquet_to_arrow_schema(parquet_schema.fields());
+        let fields = fields.iter_values().cloned().collect::<Vec<_>>();
+        let expected = vec![Field::new(
+            "id".into(),
+            ArrowDataType::FixedSizeBinary(16),
+            false,
+        )];
+        assert_eq!(fields, expected);
+
+        let fields = parquet_to_arrow_schema_with_options(
+            parquet_schema.fields(),
+            &Some(SchemaInferenceOptions {
+                uuid_as_string: true,
+                ..Default::default()
+            }),
+        );
+        let fields = fields.iter_values().cloned().collect::<Vec<_>>();
+        let expected = vec![Field::new("id".into(), ArrowDataType::Utf8View, false)];
+        assert_eq!(fields, expected);
+        Ok(())
+    }
+
     #[test]
     fn test_duplicate_fields() -> PolarsResult<()> {
         let message = "
@@ -1233,6 +1291,7 @@ mod tests {
                 parquet_schema.fields(),
                 &Some(SchemaInferenceOptions {
                     int96_coerce_to_timeunit: tu,
+                    ..Default::default()
                 }),
             );
             let fields = fields.iter_values().cloned().collect::<Vec<_>>();
@@ -1,5 +1,5 @@
 //! APIs to handle Parquet <-> Arrow schemas.
-use arrow::datatypes::{ArrowSchema, TimeUnit};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, TimeUnit};
 
 mod convert;
 mod metadata;
@@ -8,11 +8,15 @@ pub(crate) use convert::*;
 pub use convert::{parquet_to_arrow_schema, parquet_to_arrow_schema_with_options};
 pub use metadata::{read_custom_key_value_metadata, read_schema_from_metadata};
 use polars_error::PolarsResult;
+use polars_utils::pl_str::PlSmallStr;
 
 use self::metadata::parse_key_value_metadata;
 pub use crate::parquet::metadata::{FileMetadata, KeyValue, SchemaDescriptor};
 pub use crate::parquet::schema::types::ParquetType;
 
+/// The name of the canonical Arrow extension type for UUIDs.
+const ARROW_UUID_EXTENSION_NAME: &str = "arrow.uuid";
+
 /// Options when inferring schemas from Parquet
 pub struct SchemaInferenceOptions {
     /// When inferring schemas from the Parquet INT96 timestamp type, this is the corresponding TimeUnit
@@ -23,16 +27,65 @@ pub struct SchemaInferenceOptions {
     /// (e.g. TimeUnit::Milliseconds) will result in loss of precision, but support a larger range of dates
     /// without overflowing when parsing the data.
     pub int96_coerce_to_timeunit: TimeUnit,
+    /// The time zone of the inferred Arrow Timestamp type of INT96 timestamps.
+    ///
+    /// INT96 timestamps are instants in UTC, so this only changes the zone they are displayed in.
+    pub int96_coerce_to_timezone: Option<PlSmallStr>,
+    /// Whether to infer Parquet FLOAT16 columns, and Arrow `Float16` fields of a stored Arrow
+    /// schema, as `Float32` instead of `Float16`.
+    ///
+    /// This is useful for consumers that can not represent half-precision floats. The values are
+    /// widened to `Float32` while decoding, which is lossless.
+    pub float16_coerce_to_float32: bool,
+    /// Whether to infer Parquet UUID columns, and `arrow.uuid` extension fields of a stored Arrow
+    /// schema, as `Utf8View` instead of `FixedSizeBinary(16)`.
+    ///
+    /// The UUIDs are then decoded to their canonical hyphenated string form, e.g.
+    /// `"123e4567-e89b-12d3-a456-426614174000"`.
+    pub uuid_as_string: bool,
 }
 
 impl Default for SchemaInferenceOptions {
     fn default() -> Self {
         SchemaInferenceOptions {
             int96_coerce_to_timeunit: TimeUnit::Nanosecond,
+            int96_coerce_to_timezone: None,
+            float16_coerce_to_float32: false,
+            uuid_as_string: false,
         }
     }
 }
 
+/// What to do with Parquet timestamps that do not fit in the `i64` of the Arrow `Timestamp` they
+/// are read as.
+///
+/// INT96 timestamps are written by legacy Spark and Hive writers and can hold dates far outside
+/// of the range that can be represented with a nanosecond resolution. INT64 timestamps overflow
+/// when they are read with a finer time unit than they were written with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum TimestampOverflow {
+    /// Raise an error.
+    Raise,
+    /// Clamp the timestamp to the smallest or largest representable timestamp.
+    Saturate,
+    /// Replace the timestamp by a null value.
+    Null,
+}
+
+/// The [`TimestampOverflow`] policies of the physical types that timestamps are read from.
+///
+/// Without a policy the range of the timestamps is not checked, and timestamps that are out of
+/// range overflow.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TimestampOverflowOptions {
+    /// The policy for INT96 timestamps.
+    pub int96: Option<TimestampOverflow>,
+    /// The policy for INT64 timestamps.
+    pub int64: Option<TimestampOverflow>,
+}
+
 /// Infers a [`ArrowSchema`] from parquet's [`FileMetadata`].
 ///
 /// This first looks for the metadata key `"ARROW:schema"`; if it does not exist, it converts the
@@ -53,7 +106,39 @@ pub fn infer_schema_with_options(
     let mut metadata = parse_key_value_metadata(file_metadata.key_value_metadata());
 
     let schema = read_schema_from_metadata(&mut metadata)?;
-    Ok(schema.unwrap_or_else(|| {
+    let mut schema = schema.unwrap_or_else(|| {
         parquet_to_arrow_schema_with_options(file_metadata.schema().fields(), options)
-    }))
+    });
+
+    if let Some(options) = options {
+        schema
+            .iter_values_mut()
+            .for_each(|field| coerce_field(field, options));
+    }
+
+    Ok(schema)
+}
+
+/// Applies the coercions of `options` that also apply to the fields of a stored Arrow schema.
+fn coerce_field(field: &mut Field, options: &SchemaInferenceOptions) {
+    match &mut field.dtype {
+        ArrowDataType::Float16 if options.float16_coerce_to_float32 => {
+            field.dtype = ArrowDataType::Float32
+        },
+        ArrowDataType::Extension(ext)
+            if options.uuid_as_string
+                && ext.name == ARROW_UUID_EXTENSION_NAME
+                && ext.inner == ArrowDataType::FixedSizeBinary(16) =>
+        {
+            field.dtype = ArrowDataType::Utf8View
+        },
+        ArrowDataType::List(inner)
+        | ArrowDataType::LargeList(inner)
+        | ArrowDataType::FixedSizeList(inner, _)
+        | ArrowDataType::Map(inner, _) => coerce_field(inner, options),
+        ArrowDataType::Struct(fields) => fields
+            .iter_mut()
+            .for_each(|field| coerce_field(field, options)),
+        _ => {},
+    }
 }
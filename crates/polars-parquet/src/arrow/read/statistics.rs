@@ -2,7 +2,7 @@
 use arrow::array::{
     Array, BinaryViewArray, BooleanArray, FixedSizeBinaryArray, MutableBinaryViewArray,
     MutableBooleanArray, MutableFixedSizeBinaryArray, MutablePrimitiveArray, NullArray,
-    PrimitiveArray, Utf8ViewArray,
+    PrimitiveArray, StructArray, Utf8ViewArray, new_null_array,
 };
 use arrow::datatypes::{ArrowDataType, Field, IntegerType, IntervalUnit, TimeUnit};
 use arrow::types::{days_ms, i256};
@@ -12,7 +12,8 @@ use polars_utils::IdxSize;
 use polars_utils::float16::pf16;
 use polars_utils::pl_str::PlSmallStr;
 
-use super::{ParquetTimeUnit, RowGroupMetadata};
+use super::{ParquetTimeUnit, RowGroupMetadata, n_columns};
+use crate::arrow::uuid::format_uuid;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::schema::types::PhysicalType as ParquetPhysicalType;
 use crate::parquet::statistics::Statistics as ParquetStatistics;
@@ -227,6 +228,11 @@ impl ColumnStatistics {
             {
                 rmap!(expect_fixedlen, @prim Vec<u8>, |v| pf16::from_le_bytes(&[v[0], v[1]]))
             },
+            (D::Float32, PPT::FixedLenByteArray(2)) => {
+                rmap!(expect_fixedlen, @prim Vec<u8>, |v| {
+                    f32::from(pf16::from_le_bytes(&[v[0], v[1]]))
+                })
+            },
             (D::Float32, _) => rmap!(expect_float, @prim f32),
             (D::Float64, _) => rmap!(expect_double, @prim f64),
 
@@ -264,6 +270,11 @@ impl ColumnStatistics {
             (D::LargeUtf8, _) => rmap!(@string),
 
             (D::BinaryView, _) => rmap!(@binary),
+            (D::Utf8View, PPT::FixedLenByteArray(16)) => {
+                rmap!(expect_fixedlen, |x: Option<Vec<u8>>| ParquetResult::Ok(
+                    x.map(|x| Utf8ViewArray::from_slice([Some(format_uuid(&x))]))
+                ))
+            },
             (D::Utf8View, _) => rmap!(@string),
 
             (D::FixedSizeBinary(_), _) => {
@@ -289,8 +300,11 @@ impl ColumnStatistics {
     }
 }
 
-/// Deserializes the statistics in the column chunks from a single `row_group`
-/// into [`Statistics`] associated from `field`'s name.
+/// Deserializes the statistics in the column chunks of all `row_groups` for `field`, whose first
+/// leaf column is at `field_idx`.
+///
+/// For a struct, the min and max values are structs of the min and max values of its fields.
+/// These only bound every field separately and are not bounds of the struct values themselves.
 ///
 /// # Errors
 /// This function errors if the deserialization of the statistics fails (e.g. invalid utf8)
@@ -306,7 +320,48 @@ pub fn deserialize_all(
         D::List(..) | D::LargeList(..) => Ok(None),
         D::Dictionary(..) => Ok(None),
         D::FixedSizeList(..) => Ok(None),
-        D::Struct(..) => Ok(None),
+        D::Struct(fields) if fields.is_empty() => Ok(None),
+        D::Struct(fields) => {
+            let height = row_groups.len();
+
+            let mut leaf_idx = field_idx;
+            let mut min_values = Vec::with_capacity(fields.len());
+            let mut max_values = Vec::with_capacity(fields.len());
+            for f in fields {
+                let (min_value, max_value) = match deserialize_all(f, row_groups, leaf_idx)? {
+                    Some(statistics) => (statistics.min_value, statistics.max_value),
+                    None => (
+                        new_null_array(f.dtype().clone(), height),
+                        new_null_array(f.dtype().clone(), height),
+                    ),
+                };
+                min_values.push(min_value);
+                max_values.push(max_value);
+                leaf_idx += n_columns(f.dtype());
+            }
+
+            let struct_array = |values: Vec<Box<dyn Array>>| {
+                let dtype = ArrowDataType::Struct(
+                    fields
+                        .iter()
+                        .zip(&values)
+                        .map(|(f, v)| Field::new(f.name.clone(), v.dtype().clone(), true))
+                        .collect(),
+                );
+                StructArray::new(dtype, height, values, None).to_boxed()
+            };
+
+            // The null count of a struct is not known from the null counts of its fields.
+            let unknown_count =
+                || PrimitiveArray::<IdxSize>::new_null(ArrowDataType::IDX_DTYPE, height);
+
+            Ok(Some(ArrowColumnStatisticsArrays {
+                null_count: unknown_count(),
+                distinct_count: unknown_count(),
+                min_value: struct_array(min_values),
+                max_value: struct_array(max_values),
+            }))
+        },
 
         _ => {
             let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
@@ -475,6 +530,12 @@ pub fn deserialize_all(
                         pf16::from_le_bytes(&le_bytes)
                     })
                 },
+                (D::Float32, PPT::FixedLenByteArray(2)) => {
+                    rmap!(expect_fixedlen, MutablePrimitiveArray::<f32>, @prim Vec<u8>, |v| {
+                        let le_bytes: [u8; 2] = [v[0], v[1]];
+                        f32::from(pf16::from_le_bytes(&le_bytes))
+                    })
+                },
                 (D::Float32, _) => rmap!(expect_float, MutablePrimitiveArray::<f32>, @prim f32),
                 (D::Float64, _) => rmap!(expect_double, MutablePrimitiveArray::<f64>, @prim f64),
 
@@ -518,6 +579,11 @@ pub fn deserialize_all(
                 (D::LargeUtf8, _) => rmap!(@string),
 
                 (D::BinaryView, _) => rmap!(@binary),
+                (D::Utf8View, PPT::FixedLenByteArray(16)) => rmap!(
+                    expect_fixedlen,
+                    |x: Option<Vec<u8>>| ParquetResult::Ok(x.map(|x| format_uuid(&x))),
+                    MutableBinaryViewArray<str>
+                ),
                 (D::Utf8View, _) => rmap!(@string),
 
                 (D::FixedSizeBinary(width), _) => {
@@ -0,0 +1,100 @@
+//! Conversions between the 16 bytes of the Parquet `UUID` logical type and the canonical
+//! hyphenated string form of UUIDs, e.g. `"123e4567-e89b-12d3-a456-426614174000"`.
+use arrow::array::{Array, FixedSizeBinaryArray, MutableBinaryViewArray, Utf8ViewArray};
+use arrow::datatypes::ArrowDataType;
+use polars_error::{PolarsResult, polars_bail};
+
+/// The length of the canonical string form of a UUID.
+const UUID_STR_LEN: usize = 36;
+
+/// The positions of the hyphens in the canonical string form of a UUID.
+const HYPHENS: [usize; 4] = [8, 13, 18, 23];
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Formats the 16 bytes of a UUID in its lowercase canonical string form.
+///
+/// The strings compare in the same order as the bytes, so statistics remain valid.
+pub(crate) fn format_uuid(bytes: &[u8]) -> String {
+    debug_assert_eq!(bytes.len(), 16);
+    let mut out = String::with_capacity(UUID_STR_LEN);
+    for (i, byte) in bytes.iter().enumerate() {
+        if matches!(i, 4 | 6 | 8 | 10) {
+            out.push('-');
+        }
+        out.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+        out.push(HEX_DIGITS[(byte & 0xf) as usize] as char);
+    }
+    out
+}
+
+/// Parses a UUID from its canonical string form. Both lowercase and uppercase hex digits are
+/// accepted.
+pub(crate) fn parse_uuid(s: &str) -> Option<[u8; 16]> {
+    let s = s.as_bytes();
+    if s.len() != UUID_STR_LEN || HYPHENS.iter().any(|&i| s[i] != b'-') {
+        return None;
+    }
+
+    let mut digits = s
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !HYPHENS.contains(i))
+        .map(|(_, c)| (*c as char).to_digit(16).map(|d| d as u8));
+    let mut out = [0u8; 16];
+    for byte in &mut out {
+        *byte = (digits.next()?? << 4) | digits.next()??;
+    }
+    Some(out)
+}
+
+/// Formats every UUID in `array` in its canonical string form.
+pub(crate) fn uuids_to_strings(array: &FixedSizeBinaryArray) -> Utf8ViewArray {
+    let mut out = MutableBinaryViewArray::<str>::with_capacity(array.len());
+    for value in array.iter() {
+        out.push(value.map(format_uuid));
+    }
+    out.freeze()
+}
+
+/// Parses every string in `array` as a UUID in its canonical string form.
+pub(crate) fn strings_to_uuids(array: &Utf8ViewArray) -> PolarsResult<FixedSizeBinaryArray> {
+    let mut values = Vec::with_capacity(array.len() * 16);
+    for (i, value) in array.values_iter().enumerate() {
+        match parse_uuid(value) {
+            Some(bytes) => values.extend_from_slice(&bytes),
+            // Null slots are written as zeroes.
+            None if !array.is_valid(i) => values.extend_from_slice(&[0; 16]),
+            None => polars_bail!(
+                InvalidOperation: "cannot write '{}' as a Parquet UUID, expected a UUID of the form 'xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx'",
+                value
+            ),
+        }
+    }
+    Ok(FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(16),
+        values.into(),
+        array.validity().cloned(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uuid_round_trip() {
+        let s = "123e4567-e89b-12d3-a456-426614174000";
+        let bytes = parse_uuid(s).unwrap();
+        assert_eq!(bytes[..4], [0x12, 0x3e, 0x45, 0x67]);
+        assert_eq!(format_uuid(&bytes), s);
+
+        assert_eq!(
+            parse_uuid("123E4567-E89B-12D3-A456-426614174000"),
+            Some(bytes)
+        );
+        assert_eq!(parse_uuid("123e4567e89b12d3a456426614174000"), None);
+        assert_eq!(parse_uuid("123e4567-e89b-12d3-a456-42661417400g"), None);
+        assert_eq!(parse_uuid("123e4567-e89b-12d3-a456-4266141740000"), None);
+    }
+}
@@ -0,0 +1,78 @@
+use arrow::array::{Array, BinaryViewArray, PrimitiveArray, Utf8ViewArray};
+use arrow::datatypes::ArrowDataType;
+use arrow::types::NativeType;
+
+use crate::parquet::bloom_filter::{hash_byte, hash_native, insert, optimal_num_bytes};
+use crate::parquet::schema::types::PhysicalType;
+use crate::parquet::types::NativeType as ParquetNativeType;
+
+/// The options of the split-block bloom filters of a column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BloomFilterOptions {
+    /// The number of distinct values expected in a column chunk.
+    pub ndv: u64,
+    /// The false positive probability at `ndv` distinct values.
+    pub fpp: f64,
+}
+
+/// Builds the bloom filter of `array`, hashing its values as they are written to Parquet.
+///
+/// Returns `None` if bloom filters are not supported for the dtype of `array`, which is the
+/// case for booleans, float16, decimals, dictionaries and nested dtypes.
+pub fn array_to_bloom_filter(array: &dyn Array, options: &BloomFilterOptions) -> Option<Vec<u8>> {
+    let mut bitset = vec![0; optimal_num_bytes(options.ndv, options.fpp)];
+    hash_values(array, |hash| insert(&mut bitset, hash))?;
+    Some(bitset)
+}
+
+/// Calls `f` with the bloom filter hash of every non-null value of `array`.
+///
+/// Returns the physical type that the values are hashed as, or `None` (without calling `f`) if
+/// bloom filters are not supported for the dtype of `array`.
+pub(crate) fn hash_values(array: &dyn Array, mut f: impl FnMut(u64)) -> Option<PhysicalType> {
+    use PhysicalType as P;
+
+    // casts below MUST match the casts done when writing the pages.
+    let physical_type = match array.dtype().to_storage() {
+        ArrowDataType::UInt8 => hash_primitive(array, &mut f, |x: u8| x as i32),
+        ArrowDataType::UInt16 => hash_primitive(array, &mut f, |x: u16| x as i32),
+        ArrowDataType::UInt32 => hash_primitive(array, &mut f, |x: u32| x as i32),
+        ArrowDataType::UInt64 => hash_primitive(array, &mut f, |x: u64| x as i64),
+        ArrowDataType::Int8 => hash_primitive(array, &mut f, |x: i8| x as i32),
+        ArrowDataType::Int16 => hash_primitive(array, &mut f, |x: i16| x as i32),
+        ArrowDataType::Int32 | ArrowDataType::Date32 | ArrowDataType::Time32(_) => {
+            hash_primitive(array, &mut f, |x: i32| x)
+        },
+        ArrowDataType::Int64
+        | ArrowDataType::Date64
+        | ArrowDataType::Time64(_)
+        | ArrowDataType::Timestamp(_, _)
+        | ArrowDataType::Duration(_) => hash_primitive(array, &mut f, |x: i64| x),
+        ArrowDataType::Float32 => hash_primitive(array, &mut f, |x: f32| x),
+        ArrowDataType::Float64 => hash_primitive(array, &mut f, |x: f64| x),
+        ArrowDataType::BinaryView => {
+            let array: &BinaryViewArray = array.as_any().downcast_ref().unwrap();
+            array.iter().flatten().for_each(|value| f(hash_byte(value)));
+            P::ByteArray
+        },
+        ArrowDataType::Utf8View => {
+            let array: &Utf8ViewArray = array.as_any().downcast_ref().unwrap();
+            array.iter().flatten().for_each(|value| f(hash_byte(value)));
+            P::ByteArray
+        },
+        _ => return None,
+    };
+    Some(physical_type)
+}
+
+fn hash_primitive<T: NativeType, P: ParquetNativeType>(
+    array: &dyn Array,
+    f: &mut impl FnMut(u64),
+    cast: impl Fn(T) -> P,
+) -> PhysicalType {
+    let array: &PrimitiveArray<T> = array.as_any().downcast_ref().unwrap();
+    for value in array.iter().flatten() {
+        f(hash_native(cast(*value)));
+    }
+    P::TYPE
+}
@@ -427,6 +427,24 @@ fn serialize_keys_range<K: DictionaryKey>(
     .map(Page::Data)
 }
 
+/// Returns the exact number of distinct non-null values that are referenced by the keys of
+/// `array`.
+fn distinct_count<K: DictionaryKey>(array: &DictionaryArray<K>) -> usize {
+    let values = array.values();
+    let mut seen = MutableBitmap::from_len_zeroed(values.len());
+
+    for key in array.keys().non_null_values_iter() {
+        // SAFETY: The keys of a DictionaryArray are in bounds of its values.
+        seen.set(unsafe { key.as_usize() }, true);
+    }
+
+    let seen = Bitmap::from(seen);
+    match values.validity() {
+        None => seen.set_bits(),
+        Some(validity) => (&seen & validity).set_bits(),
+    }
+}
+
 macro_rules! dyn_prim {
     ($from:ty, $to:ty, $array:expr, $options:expr, $type_:expr) => {{
         let values = $array.values().as_any().downcast_ref().unwrap();
@@ -605,9 +623,21 @@ pub fn array_to_pages<K: DictionaryKey>(
             if let Some(stats) = &mut statistics {
                 stats.null_count = Some(array.null_count() as i64)
             }
+            let chunk_distinct_count = options
+                .statistics
+                .distinct_count
+                .then(|| distinct_count(array) as i64);
 
             // write DataPages pointing to DictPage
-            let data_pages = serialize_keys(array, type_, nested, statistics, options);
+            let data_pages = serialize_keys(array, type_, nested, statistics, options).map(
+                move |page| {
+                    let mut page = page?;
+                    if let Page::Data(page) = &mut page {
+                        page.chunk_distinct_count = chunk_distinct_count;
+                    }
+                    Ok(page)
+                },
+            );
 
             Ok(DynIter::new(
                 std::iter::once(Ok(Page::Dict(dict_page))).chain(data_pages),
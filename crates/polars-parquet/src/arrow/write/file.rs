@@ -1,12 +1,16 @@
 use std::io::Write;
+use std::sync::Arc;
 
 use arrow::datatypes::ArrowSchema;
 use polars_error::{PolarsError, PolarsResult};
+use polars_parquet_format::SortingColumn;
 
 use super::schema::schema_to_metadata_key;
-use super::{ThriftFileMetadata, WriteOptions, to_parquet_schema};
+use super::{FileEncryptionProperties, ThriftFileMetadata, WriteOptions, to_parquet_schema};
 use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
-use crate::parquet::write::{RowGroupIterColumns, WriteOptions as FileWriteOptions};
+use crate::parquet::write::{
+    BloomFilterBitset, RowGroupIterColumns, WriteOptions as FileWriteOptions,
+};
 
 /// An interface to write a parquet to a [`Write`]
 pub struct FileWriter<W: Write> {
@@ -73,6 +77,15 @@ impl<W: Write> FileWriter<W> {
         ))
     }
 
+    /// Encrypts the file with Parquet modular encryption, see [`FileEncryptionProperties`].
+    pub fn with_encryption(
+        mut self,
+        properties: Arc<FileEncryptionProperties>,
+    ) -> PolarsResult<Self> {
+        self.writer = self.writer.with_encryption(properties)?;
+        Ok(self)
+    }
+
     /// Writes a row group to the file.
     pub fn write(
         &mut self,
@@ -82,6 +95,20 @@ impl<W: Write> FileWriter<W> {
         Ok(self.writer.write(num_rows, row_group)?)
     }
 
+    /// Writes a row group to the file, with the bloom filters of its leaf columns and the
+    /// columns its rows are sorted by.
+    pub fn write_with_metadata(
+        &mut self,
+        num_rows: u64,
+        row_group: RowGroupIterColumns<'_, PolarsError>,
+        bloom_filters: Vec<BloomFilterBitset>,
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> PolarsResult<()> {
+        Ok(self
+            .writer
+            .write_with_metadata(num_rows, row_group, bloom_filters, sorting_columns)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     /// If `key_value_metadata` is provided, the value is taken as-is. If it is not provided,
     /// the Arrow schema is added to the metadata.
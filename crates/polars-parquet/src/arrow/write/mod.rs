@@ -14,6 +14,8 @@
 
 mod binary;
 mod binview;
+#[cfg(feature = "bloom_filter")]
+mod bloom_filter;
 mod boolean;
 mod dictionary;
 mod file;
@@ -29,14 +31,21 @@ use arrow::array::*;
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::*;
 use arrow::types::{NativeType, days_ms, i256};
+#[cfg(feature = "bloom_filter")]
+pub(crate) use bloom_filter::hash_values;
+#[cfg(feature = "bloom_filter")]
+pub use bloom_filter::{BloomFilterOptions, array_to_bloom_filter};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
+pub use polars_parquet_format::SortingColumn;
 use polars_utils::float16::pf16;
 use polars_utils::pl_str::PlSmallStr;
 pub use utils::write_def_levels;
 
+use crate::arrow::uuid::strings_to_uuids;
 pub use crate::parquet::compression::{BrotliLevel, CompressionOptions, GzipLevel, ZstdLevel};
 pub use crate::parquet::encoding::Encoding;
+pub use crate::parquet::encryption::FileEncryptionProperties;
 pub use crate::parquet::metadata::{
     Descriptor, FileMetadata, KeyValue, SchemaDescriptor, ThriftFileMetadata,
 };
@@ -44,11 +53,11 @@ pub use crate::parquet::page::{CompressedDataPage, CompressedPage, Page};
 use crate::parquet::schema::Repetition;
 use crate::parquet::schema::types::PrimitiveType as ParquetPrimitiveType;
 pub use crate::parquet::schema::types::{
-    FieldInfo, ParquetType, PhysicalType as ParquetPhysicalType,
+    FieldInfo, ParquetType, PhysicalType as ParquetPhysicalType, PrimitiveLogicalType,
 };
 pub use crate::parquet::write::{
-    Compressor, DynIter, DynStreamingIterator, RowGroupIterColumns, Version, compress,
-    write_metadata_sidecar,
+    BloomFilterBitset, Compressor, DynIter, DynStreamingIterator, RowGroupIterColumns, Version,
+    compress, write_metadata_sidecar,
 };
 pub use crate::parquet::{FallibleStreamingIterator, fallible_streaming_iterator};
 use crate::write::fixed_size_binary::build_statistics_float16;
@@ -93,6 +102,8 @@ pub struct WriteOptions {
     pub compression: CompressionOptions,
     /// The size to flush a page, defaults to 1024 * 1024 if None
     pub data_page_size: Option<usize>,
+    /// The maximum number of rows in a data page, not limited if None
+    pub data_page_row_limit: Option<usize>,
 }
 
 use arrow::compute::aggregate::estimated_bytes_size;
@@ -101,7 +112,7 @@ pub use file::FileWriter;
 pub use pages::{Nested, array_to_columns, arrays_to_columns};
 use polars_error::{PolarsResult, polars_bail};
 pub use row_group::{RowGroupIterator, row_group_iter};
-pub use schema::{schema_to_metadata_key, to_parquet_type};
+pub use schema::{schema_to_metadata_key, to_parquet_type, to_parquet_uuid_type};
 
 use self::pages::{FixedSizeListNested, PrimitiveNested, StructNested};
 use crate::write::dictionary::encode_as_dictionary_optional;
@@ -157,6 +168,8 @@ impl EncodeNullability {
 /// `data_page_size`: Set a target threshold for the approximate encoded size of data
 /// pages within a column chunk (in bytes). If None, use the default data page size of 1MByte.
 /// See: https://arrow.apache.org/docs/python/generated/pyarrow.parquet.write_table.html
+///
+/// `data_page_row_limit`: Additionally limit the number of (top-level) rows in a data page.
 pub(crate) fn row_slice_ranges(
     number_of_rows: usize,
     byte_size: usize,
@@ -172,6 +185,9 @@ pub(crate) fn row_slice_ranges(
         ((byte_size as f64) / (number_of_rows as f64)) as usize
     };
     let rows_per_page = (max_page_size / (bytes_per_row + 1)).max(1);
+    let rows_per_page = options
+        .data_page_row_limit
+        .map_or(rows_per_page, |limit| rows_per_page.min(limit.max(1)));
 
     (0..number_of_rows)
         .step_by(rows_per_page)
@@ -307,6 +323,21 @@ pub fn get_max_length(nested: &[Nested]) -> usize {
     length
 }
 
+/// Gathers the values of the keys of `array`.
+fn dictionary_to_values<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+) -> PolarsResult<Box<dyn Array>> {
+    let keys =
+        polars_compute::cast::cast(array.keys(), &ArrowDataType::IDX_DTYPE, Default::default())?;
+    // SAFETY: the keys of a dictionary array are in bounds of its values.
+    Ok(unsafe {
+        polars_compute::gather::take_unchecked(
+            array.values().as_ref(),
+            keys.as_any().downcast_ref().unwrap(),
+        )
+    })
+}
+
 /// Returns an iterator of [`Page`].
 pub fn array_to_pages(
     primitive_array: &dyn Array,
@@ -315,7 +346,24 @@ pub fn array_to_pages(
     options: WriteOptions,
     mut encoding: Encoding,
 ) -> PolarsResult<DynIter<'static, PolarsResult<Page>>> {
+    // Strings are written to UUID columns as the 16 bytes of the UUIDs they hold.
+    if type_.logical_type == Some(PrimitiveLogicalType::Uuid)
+        && primitive_array.dtype() == &ArrowDataType::Utf8View
+    {
+        let array = strings_to_uuids(primitive_array.as_any().downcast_ref().unwrap())?;
+        return array_to_pages(&array, type_, nested, options, Encoding::Plain);
+    }
     if let ArrowDataType::Dictionary(key_type, _, _) = primitive_array.dtype().to_storage() {
+        // Without dictionary encoding, the values of the keys are written.
+        if !matches!(
+            encoding,
+            Encoding::PlainDictionary | Encoding::RleDictionary
+        ) {
+            let array = match_integer_type!(key_type, |$T| {
+                dictionary_to_values::<$T>(primitive_array.as_any().downcast_ref().unwrap())
+            })?;
+            return array_to_pages(array.as_ref(), type_, nested, options, encoding);
+        }
         return match_integer_type!(key_type, |$T| {
             dictionary::array_to_pages::<$T>(
                 primitive_array.as_any().downcast_ref().unwrap(),
@@ -336,8 +384,12 @@ pub fn array_to_pages(
             }
         }
 
-        // We didn't succeed, fallback to plain
-        encoding = Encoding::Plain;
+        // We didn't succeed, fallback to plain or to delta encoding of binary values
+        encoding = if matches!(nested, [Nested::Primitive(_)]) {
+            binary_fallback_encoding(primitive_array)
+        } else {
+            Encoding::Plain
+        };
     }
 
     let nested = nested.to_vec();
@@ -345,6 +397,13 @@ pub fn array_to_pages(
     // note: this is not correct if the array is sliced - the estimation should happen on the
     // primitive after sliced for parquet
     let byte_size = estimated_bytes_size(primitive_array);
+    // The distinct count is only meaningful for the whole column chunk, so it is passed along with
+    // the pages to the statistics of the column chunk.
+    let distinct_count = options
+        .statistics
+        .distinct_count
+        .then(|| estimate_distinct_count(primitive_array))
+        .flatten();
     let primitive_array = primitive_array.to_boxed();
 
     let pages =
@@ -353,17 +412,136 @@ pub fn array_to_pages(
             let mut right_nested = nested.clone();
             slice_parquet_array(right_array.as_mut(), &mut right_nested, offset, length);
 
-            array_to_page(
+            let mut page = array_to_page(
                 right_array.as_ref(),
                 type_.clone(),
                 &right_nested,
                 options,
                 encoding,
-            )
+            )?;
+
+            if let Page::Data(page) = &mut page {
+                page.chunk_distinct_count = distinct_count;
+            }
+
+            Ok(page)
         });
     Ok(DynIter::new(pages))
 }
 
+/// The number of values sampled to choose the encoding of a binary column.
+const BINARY_ENCODING_SAMPLE_SIZE: usize = 1024;
+
+/// The minimum fraction of the bytes of a binary column that are shared with the preceding value
+/// for it to be written with `DELTA_BYTE_ARRAY`.
+const DELTA_BYTE_ARRAY_MIN_SHARED_FRACTION: f64 = 0.25;
+
+/// Chooses the encoding of a column that could not be dictionary encoded.
+///
+/// Binary and string columns whose values share long prefixes with the preceding value, such as
+/// URLs or paths, are written with `DELTA_BYTE_ARRAY`, which only stores the suffixes. Other
+/// columns are written with `PLAIN`.
+fn binary_fallback_encoding(array: &dyn Array) -> Encoding {
+    fn shared_fraction<'a>(values: impl Iterator<Item = &'a [u8]>) -> f64 {
+        let mut previous: &[u8] = &[];
+        let (mut shared, mut total) = (0usize, 0usize);
+        for value in values.take(BINARY_ENCODING_SAMPLE_SIZE) {
+            shared += value
+                .iter()
+                .zip(previous)
+                .take_while(|(lhs, rhs)| lhs == rhs)
+                .count();
+            total += value.len();
+            previous = value;
+        }
+        if total == 0 {
+            0.0
+        } else {
+            shared as f64 / total as f64
+        }
+    }
+
+    let any = array.as_any();
+    let shared = match array.dtype().to_physical_type() {
+        PhysicalType::BinaryView => {
+            let array: &BinaryViewArray = any.downcast_ref().unwrap();
+            shared_fraction(array.non_null_values_iter())
+        },
+        PhysicalType::Utf8View => {
+            let array: &Utf8ViewArray = any.downcast_ref().unwrap();
+            shared_fraction(array.non_null_values_iter().map(str::as_bytes))
+        },
+        PhysicalType::LargeBinary => {
+            let array: &BinaryArray<i64> = any.downcast_ref().unwrap();
+            shared_fraction(array.non_null_values_iter())
+        },
+        PhysicalType::LargeUtf8 => {
+            let array: &Utf8Array<i64> = any.downcast_ref().unwrap();
+            shared_fraction(array.non_null_values_iter().map(str::as_bytes))
+        },
+        _ => return Encoding::Plain,
+    };
+
+    if shared >= DELTA_BYTE_ARRAY_MIN_SHARED_FRACTION {
+        Encoding::DeltaByteArray
+    } else {
+        Encoding::Plain
+    }
+}
+
+/// Estimates the number of distinct non-null values in `array` with HyperLogLog. Booleans are
+/// counted exactly.
+///
+/// Returns `None` for types that have no distinct count.
+fn estimate_distinct_count(array: &dyn Array) -> Option<i64> {
+    use arrow::types::PrimitiveType as PT;
+    use polars_compute::unique::GenericUniqueKernel;
+
+    match array.dtype().to_physical_type() {
+        PhysicalType::Boolean => {
+            let array: &BooleanArray = array.as_any().downcast_ref().unwrap();
+            return array.n_unique_non_null().try_into().ok();
+        },
+        PhysicalType::Primitive(
+            PT::Int8
+            | PT::Int16
+            | PT::Int32
+            | PT::Int64
+            | PT::Int128
+            | PT::UInt8
+            | PT::UInt16
+            | PT::UInt32
+            | PT::UInt64
+            | PT::UInt128
+            | PT::Float16
+            | PT::Float32
+            | PT::Float64,
+        )
+        | PhysicalType::Binary
+        | PhysicalType::LargeBinary
+        | PhysicalType::Utf8
+        | PhysicalType::LargeUtf8
+        | PhysicalType::BinaryView
+        | PhysicalType::Utf8View
+        | PhysicalType::FixedSizeBinary => {},
+        _ => return None,
+    }
+
+    let count = if array.null_count() == array.len() {
+        0
+    } else if let Some(validity) = array.validity().filter(|_| array.has_nulls()) {
+        // HyperLogLog counts nulls as a value, so they are filtered out first.
+        let mask = BooleanArray::new(ArrowDataType::Boolean, validity.clone(), None);
+        polars_compute::cardinality::estimate_cardinality(
+            polars_compute::filter::filter(array, &mask).as_ref(),
+        )
+    } else {
+        polars_compute::cardinality::estimate_cardinality(array)
+    };
+
+    count.try_into().ok()
+}
+
 /// Converts an [`Array`] to a [`CompressedPage`] based on options, descriptor and `encoding`.
 pub fn array_to_page(
     array: &dyn Array,
@@ -492,16 +670,22 @@ pub fn array_to_page_simple(
             );
             fixed_size_binary::array_to_page(&array, options, type_, statistics)
         },
-        ArrowDataType::Float32 => primitive::array_to_page_plain::<f32, f32>(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            type_,
-        ),
-        ArrowDataType::Float64 => primitive::array_to_page_plain::<f64, f64>(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            type_,
-        ),
+        ArrowDataType::Float32 => {
+            return primitive::array_to_page_float::<f32, f32>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+                encoding,
+            );
+        },
+        ArrowDataType::Float64 => {
+            return primitive::array_to_page_float::<f64, f64>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+                encoding,
+            );
+        },
         ArrowDataType::LargeUtf8 => {
             let array =
                 polars_compute::cast::cast(array, &ArrowDataType::LargeBinary, Default::default())
@@ -91,6 +91,30 @@ pub fn schema_to_metadata_key(schema: &ArrowSchema) -> KeyValue {
     }
 }
 
+/// Creates the [`ParquetType`] of a string [`Field`] holding UUIDs in their canonical string form,
+/// which are written as 16-byte values with the `UUID` logical type.
+pub fn to_parquet_uuid_type(field: &Field) -> PolarsResult<ParquetType> {
+    if field.dtype().to_storage() != &ArrowDataType::Utf8View {
+        polars_bail!(
+            InvalidOperation: "only string columns can be written as Parquet UUIDs, got column '{}' of type {:?}",
+            field.name, field.dtype()
+        );
+    }
+    let repetition = if field.is_nullable {
+        Repetition::Optional
+    } else {
+        Repetition::Required
+    };
+    Ok(ParquetType::try_from_primitive(
+        field.name.clone(),
+        PhysicalType::FixedLenByteArray(16),
+        repetition,
+        None,
+        Some(PrimitiveLogicalType::Uuid),
+        None,
+    )?)
+}
+
 /// Creates a [`ParquetType`] from a [`Field`].
 pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
     let name = field.name.clone();
@@ -49,3 +49,25 @@ pub fn read<R: Read + Seek>(
 
     Ok(())
 }
+
+/// Deserializes the header of a bloom filter from the start of `bytes`, which were read from the
+/// bloom filter offset of a column chunk.
+///
+/// Returns the length of the header and the length of the bitset that follows it, or `None` if
+/// the algorithm or the compression of the bloom filter is not supported.
+/// # Error
+/// Errors if the header can't be deserialized.
+pub fn read_header(bytes: &[u8]) -> ParquetResult<Option<(usize, usize)>> {
+    let mut reader = bytes;
+    let mut prot = TCompactInputProtocol::new(&mut reader, usize::MAX); // max is ok since `BloomFilterHeader` never allocates
+    let header = BloomFilterHeader::read_from_in_protocol(&mut prot)?;
+    let header_len = bytes.len() - reader.len();
+
+    if header.algorithm != BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {})
+        || header.compression != BloomFilterCompression::UNCOMPRESSED(Uncompressed {})
+    {
+        return Ok(None);
+    }
+
+    Ok(Some((header_len, header.num_bytes.try_into()?)))
+}
@@ -0,0 +1,19 @@
+use crate::parquet::types::NativeType;
+
+/// Encodes `values` using the [Byte Stream Split](https://github.com/apache/parquet-format/blob/master/Encodings.md#byte-stream-split-byte_stream_split--9) encoding.
+///
+/// The `n`-th bytes of all values are written contiguously, so that the similar sign and exponent
+/// bytes of floats end up next to each other, which compresses much better.
+pub fn encode<T: NativeType, I: ExactSizeIterator<Item = T>>(values: I, buffer: &mut Vec<u8>) {
+    let element_size = size_of::<T>();
+    let num_elements = values.len();
+    let start = buffer.len();
+    buffer.resize(start + num_elements * element_size, 0);
+    let streams = &mut buffer[start..];
+
+    for (i, value) in values.enumerate() {
+        for (n, byte) in value.to_le_bytes().as_ref().iter().enumerate() {
+            streams[(num_elements * n) + i] = *byte;
+        }
+    }
+}
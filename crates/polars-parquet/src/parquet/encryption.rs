@@ -0,0 +1,836 @@
+//! Encryption and decryption of files with Parquet [modular encryption].
+//!
+//! Only the `AES_GCM_V1` algorithm is supported, in which the footer, the column metadata, and
+//! the page headers and pages of encrypted columns are each encrypted as a separate module with
+//! AES-GCM. A module is stored as `length (4 bytes) | nonce (12 bytes) | ciphertext | tag`, and
+//! its additional authenticated data (AAD) binds it to its position in the file.
+//!
+//! [modular encryption]: https://github.com/apache/parquet-format/blob/master/Encryption.md
+use std::collections::BTreeMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::Arc;
+
+use polars_parquet_format::thrift::protocol::{TCompactInputProtocol, TCompactOutputProtocol};
+use polars_parquet_format::{
+    AesGcmV1, ColumnChunk, ColumnCryptoMetaData, ColumnMetaData, EncryptionAlgorithm,
+    EncryptionWithColumnKey, EncryptionWithFooterKey, FileCryptoMetaData,
+};
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::metadata::SchemaDescriptor;
+
+/// The magic bytes at the end of a file with an encrypted footer.
+pub const PARQUET_ENCRYPTED_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'E'];
+
+const MODULE_LENGTH_SIZE: usize = 4;
+const NONCE_SIZE: usize = 12;
+const TAG_SIZE: usize = 16;
+/// The size of the random identifier of a file, which is part of the AAD of its modules.
+const AAD_FILE_UNIQUE_SIZE: usize = 8;
+
+/// An AES key of the encryption or decryption properties.
+///
+/// Keys are secrets, so plans that hold them can't be serialized. They are compared by value in
+/// constant time, but only their length is hashed, so that plan hashes don't depend on them.
+#[derive(Clone)]
+struct EncryptionKey(Arc<[u8]>);
+
+impl EncryptionKey {
+    fn new(key: Vec<u8>) -> Self {
+        Self(key.into())
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Deref for EncryptionKey {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Eq for EncryptionKey {}
+
+impl PartialEq for EncryptionKey {
+    fn eq(&self, other: &Self) -> bool {
+        // Don't return early on the first differing byte, which would leak the position of the
+        // difference through the timing.
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(other.0.iter())
+                .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+                == 0
+    }
+}
+
+impl Hash for EncryptionKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.0.len())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for EncryptionKey {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::Error;
+        Err(S::Error::custom(
+            "cannot serialize Parquet encryption keys, the query must be run where the keys are given",
+        ))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EncryptionKey {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        Err(D::Error::custom(
+            "cannot deserialize Parquet encryption keys",
+        ))
+    }
+}
+
+#[cfg(feature = "dsl-schema")]
+impl schemars::JsonSchema for EncryptionKey {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "EncryptionKey".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        Vec::<u8>::json_schema(generator)
+    }
+}
+
+/// The keys to decrypt a file written with Parquet modular encryption.
+///
+/// The footer key decrypts the file metadata and the columns encrypted with the footer key.
+/// Columns encrypted with their own key can only be read if that key is given with
+/// [`FileDecryptionProperties::with_column_key`]. Keys must be 16, 24 or 32 bytes long.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FileDecryptionProperties {
+    footer_key: EncryptionKey,
+    /// Keys by dot-separated column path.
+    column_keys: BTreeMap<String, EncryptionKey>,
+    aad_prefix: Option<Vec<u8>>,
+}
+
+impl FileDecryptionProperties {
+    pub fn new(footer_key: Vec<u8>) -> Self {
+        Self {
+            footer_key: EncryptionKey::new(footer_key),
+            column_keys: BTreeMap::new(),
+            aad_prefix: None,
+        }
+    }
+
+    /// Set the key of the column at the dot-separated `path`, e.g. `"a.b"` for the field `b` of
+    /// the struct column `a`.
+    pub fn with_column_key(mut self, path: impl Into<String>, key: Vec<u8>) -> Self {
+        self.column_keys
+            .insert(path.into(), EncryptionKey::new(key));
+        self
+    }
+
+    /// Set the AAD prefix, which is required for files written without storing it.
+    pub fn with_aad_prefix(mut self, aad_prefix: Vec<u8>) -> Self {
+        self.aad_prefix = Some(aad_prefix);
+        self
+    }
+
+    fn column_key(&self, path: &str) -> Option<&[u8]> {
+        self.column_keys.get(path).map(EncryptionKey::as_slice)
+    }
+}
+
+impl fmt::Debug for FileDecryptionProperties {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The keys are secrets.
+        f.debug_struct("FileDecryptionProperties")
+            .field("column_keys", &self.column_keys.keys().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The keys to encrypt a file with Parquet modular encryption, using `AES_GCM_V1` and an
+/// encrypted footer.
+///
+/// Without column keys, all columns are encrypted with the footer key. Otherwise only the
+/// columns given with [`FileEncryptionProperties::with_column_key`] are encrypted, each with its
+/// own key, and the other columns are written in plaintext. Keys must be 16, 24 or 32 bytes long.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FileEncryptionProperties {
+    footer_key: EncryptionKey,
+    /// Keys by dot-separated column path.
+    column_keys: BTreeMap<String, EncryptionKey>,
+    aad_prefix: Option<Vec<u8>>,
+    store_aad_prefix: bool,
+}
+
+impl FileEncryptionProperties {
+    pub fn new(footer_key: Vec<u8>) -> Self {
+        Self {
+            footer_key: EncryptionKey::new(footer_key),
+            column_keys: BTreeMap::new(),
+            aad_prefix: None,
+            store_aad_prefix: true,
+        }
+    }
+
+    /// Encrypt the column at the dot-separated `path` with `key`, e.g. `"a.b"` for the field `b`
+    /// of the struct column `a`.
+    pub fn with_column_key(mut self, path: impl Into<String>, key: Vec<u8>) -> Self {
+        self.column_keys
+            .insert(path.into(), EncryptionKey::new(key));
+        self
+    }
+
+    /// Set the AAD prefix, which binds the file to an identifier such as its path. It is stored
+    /// in the file, unless [`FileEncryptionProperties::without_aad_prefix_storage`] is used.
+    pub fn with_aad_prefix(mut self, aad_prefix: Vec<u8>) -> Self {
+        self.aad_prefix = Some(aad_prefix);
+        self
+    }
+
+    /// Don't store the AAD prefix in the file, readers must then supply it to decrypt the file.
+    pub fn without_aad_prefix_storage(mut self) -> Self {
+        self.store_aad_prefix = false;
+        self
+    }
+}
+
+impl fmt::Debug for FileEncryptionProperties {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The keys are secrets.
+        f.debug_struct("FileEncryptionProperties")
+            .field("column_keys", &self.column_keys.keys().collect::<Vec<_>>())
+            .field("store_aad_prefix", &self.store_aad_prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The type of an encrypted module, which is part of its AAD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+}
+
+/// Decrypts the footer and the column metadata of a file.
+pub(crate) struct FileDecryptor {
+    properties: Option<Arc<FileDecryptionProperties>>,
+    /// The AAD prefix followed by the unique file identifier.
+    file_aad: Arc<[u8]>,
+}
+
+impl FileDecryptor {
+    /// Returns the decryptor of a file encrypted with `algorithm`. Without `properties`, only the
+    /// plaintext columns of a file with a plaintext footer can be read.
+    pub(crate) fn try_new(
+        algorithm: &EncryptionAlgorithm,
+        properties: Option<&Arc<FileDecryptionProperties>>,
+    ) -> ParquetResult<Self> {
+        let EncryptionAlgorithm::AESGCMV1(algorithm) = algorithm else {
+            return Err(ParquetError::not_supported(
+                "decrypting files encrypted with AES_GCM_CTR_V1",
+            ));
+        };
+
+        let aad_prefix = match (&algorithm.aad_prefix, properties) {
+            (_, Some(properties)) if properties.aad_prefix.is_some() => {
+                properties.aad_prefix.as_deref()
+            },
+            (Some(aad_prefix), _) => Some(aad_prefix.as_slice()),
+            (None, Some(_)) if algorithm.supply_aad_prefix == Some(true) => {
+                return Err(ParquetError::InvalidParameter(
+                    "the file was encrypted with an AAD prefix that is not stored in the file, \
+                    it must be given in the decryption properties"
+                        .to_string(),
+                ));
+            },
+            (None, _) => None,
+        };
+        let file_aad = aad_prefix
+            .unwrap_or_default()
+            .iter()
+            .chain(algorithm.aad_file_unique.as_deref().unwrap_or_default())
+            .copied()
+            .collect();
+
+        Ok(Self {
+            properties: properties.cloned(),
+            file_aad,
+        })
+    }
+
+    fn properties(&self) -> ParquetResult<&FileDecryptionProperties> {
+        self.properties.as_deref().ok_or_else(|| {
+            ParquetError::InvalidParameter(
+                "the file is encrypted, decryption properties are required to read it".to_string(),
+            )
+        })
+    }
+
+    /// Decrypts the encrypted footer `module`, returning the serialized file metadata.
+    pub(crate) fn decrypt_footer(&self, module: &[u8]) -> ParquetResult<Vec<u8>> {
+        let key = &self.properties()?.footer_key;
+        let aad = module_aad(&self.file_aad, ModuleType::Footer, None)?;
+        decrypt_module(key, &aad, module)
+    }
+
+    /// Decrypts the metadata of `column_chunk` in place if it is encrypted, and returns the
+    /// decryptor of its pages.
+    ///
+    /// An encrypted column without a key can still be used if its metadata is in plaintext, as
+    /// is the case in files with a plaintext footer, but reading its pages fails.
+    pub(crate) fn decrypt_column_chunk(
+        &self,
+        column_chunk: &mut ColumnChunk,
+        path: &[PlSmallStr],
+        row_group: usize,
+        column: usize,
+    ) -> ParquetResult<Option<Arc<ColumnDecryptor>>> {
+        let path = column_path(path);
+        let key = match &column_chunk.crypto_metadata {
+            None => return Ok(None),
+            Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_)) => self
+                .properties
+                .as_deref()
+                .map(|properties| properties.footer_key.as_slice()),
+            Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(_)) => self
+                .properties
+                .as_deref()
+                .and_then(|properties| properties.column_key(&path)),
+        };
+
+        if let (Some(key), Some(module)) = (key, &column_chunk.encrypted_column_metadata) {
+            let position = ModulePosition { row_group, column };
+            let aad = module_aad(&self.file_aad, ModuleType::ColumnMetaData, Some(&position))?;
+            let bytes = decrypt_module(key, &aad, module)?;
+            let mut prot = TCompactInputProtocol::new(bytes.as_slice(), bytes.len() * 2 + 1024);
+            column_chunk.meta_data = Some(ColumnMetaData::read_from_in_protocol(&mut prot)?);
+        } else if column_chunk.meta_data.is_none() {
+            return Err(ParquetError::InvalidParameter(format!(
+                "no decryption key for the encrypted column '{path}'"
+            )));
+        }
+
+        let has_dictionary_page = column_chunk
+            .meta_data
+            .as_ref()
+            .is_some_and(|meta| meta.dictionary_page_offset.is_some());
+
+        Ok(Some(Arc::new(ColumnDecryptor {
+            key: key.map(<[u8]>::to_vec),
+            path,
+            file_aad: self.file_aad.clone(),
+            position: ModulePosition { row_group, column },
+            has_dictionary_page,
+        })))
+    }
+}
+
+#[derive(Clone)]
+struct ModulePosition {
+    row_group: usize,
+    column: usize,
+}
+
+/// Decrypts the page headers and pages of an encrypted column chunk.
+pub struct ColumnDecryptor {
+    key: Option<Vec<u8>>,
+    path: String,
+    file_aad: Arc<[u8]>,
+    position: ModulePosition,
+    /// The first page of the column chunk is a dictionary page. This can't be determined from
+    /// the encrypted page header without knowing its module type.
+    pub(crate) has_dictionary_page: bool,
+}
+
+impl ColumnDecryptor {
+    /// Decrypts a page header or page `module`. `page_ordinal` is the index of the data page in
+    /// the column chunk, and is ignored for dictionary pages.
+    pub(crate) fn decrypt(
+        &self,
+        module: &[u8],
+        module_type: ModuleType,
+        page_ordinal: usize,
+    ) -> ParquetResult<Vec<u8>> {
+        let Some(key) = &self.key else {
+            return Err(ParquetError::InvalidParameter(format!(
+                "no decryption key for the encrypted column '{}'",
+                self.path
+            )));
+        };
+        let aad = page_module_aad(&self.file_aad, module_type, &self.position, page_ordinal)?;
+        decrypt_module(key, &aad, module)
+    }
+}
+
+impl fmt::Debug for ColumnDecryptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ColumnDecryptor")
+            .field("path", &self.path)
+            .field("has_key", &self.key.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Encrypts the footer of a file, and creates the encryptors of its columns.
+pub(crate) struct FileEncryptor {
+    properties: Arc<FileEncryptionProperties>,
+    aad_file_unique: [u8; AAD_FILE_UNIQUE_SIZE],
+    /// The AAD prefix followed by the unique file identifier.
+    file_aad: Vec<u8>,
+}
+
+impl FileEncryptor {
+    /// Returns the encryptor of a new file with `schema`.
+    ///
+    /// # Errors
+    /// Returns an error if a column key is given for a path that is not a column of `schema`.
+    pub(crate) fn try_new(
+        properties: Arc<FileEncryptionProperties>,
+        schema: &SchemaDescriptor,
+    ) -> ParquetResult<Self> {
+        if let Some(path) = properties.column_keys.keys().find(|path| {
+            !schema
+                .columns()
+                .iter()
+                .any(|column| column_path(&column.path_in_schema) == **path)
+        }) {
+            return Err(ParquetError::InvalidParameter(format!(
+                "cannot encrypt the column '{path}', it is not in the schema"
+            )));
+        }
+
+        let aad_file_unique = random_bytes::<AAD_FILE_UNIQUE_SIZE>()?;
+        let file_aad = properties
+            .aad_prefix
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .chain(&aad_file_unique)
+            .copied()
+            .collect();
+
+        Ok(Self {
+            properties,
+            aad_file_unique,
+            file_aad,
+        })
+    }
+
+    /// The metadata written in plaintext before the encrypted footer.
+    pub(crate) fn crypto_metadata(&self) -> FileCryptoMetaData {
+        let aad_prefix = &self.properties.aad_prefix;
+        let store_aad_prefix = self.properties.store_aad_prefix;
+        let algorithm = AesGcmV1::new(
+            aad_prefix.clone().filter(|_| store_aad_prefix),
+            Some(self.aad_file_unique.to_vec()),
+            (aad_prefix.is_some() && !store_aad_prefix).then_some(true),
+        );
+        FileCryptoMetaData::new(EncryptionAlgorithm::AESGCMV1(algorithm), None)
+    }
+
+    /// Encrypts the serialized file metadata into the footer module.
+    pub(crate) fn encrypt_footer(&self, metadata: &[u8]) -> ParquetResult<Vec<u8>> {
+        let aad = module_aad(&self.file_aad, ModuleType::Footer, None)?;
+        encrypt_module(&self.properties.footer_key, &aad, metadata)
+    }
+
+    /// Returns the encryptor of the column at `path`, or `None` if the column is written in
+    /// plaintext.
+    pub(crate) fn column_encryptor(
+        &self,
+        path: &[PlSmallStr],
+        row_group: usize,
+        column: usize,
+    ) -> Option<ColumnEncryptor<'_>> {
+        let (key, crypto_metadata) = if self.properties.column_keys.is_empty() {
+            let crypto_metadata =
+                ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(EncryptionWithFooterKey {});
+            (self.properties.footer_key.as_slice(), crypto_metadata)
+        } else {
+            let key = self.properties.column_keys.get(&column_path(path))?;
+            let crypto_metadata =
+                ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(EncryptionWithColumnKey::new(
+                    path.iter().map(PlSmallStr::to_string).collect::<Vec<_>>(),
+                    None,
+                ));
+            (key.as_slice(), crypto_metadata)
+        };
+
+        Some(ColumnEncryptor {
+            key,
+            file_aad: &self.file_aad,
+            position: ModulePosition { row_group, column },
+            crypto_metadata,
+        })
+    }
+}
+
+/// Encrypts the page headers, pages and metadata of an encrypted column chunk.
+pub(crate) struct ColumnEncryptor<'a> {
+    key: &'a [u8],
+    file_aad: &'a [u8],
+    position: ModulePosition,
+    crypto_metadata: ColumnCryptoMetaData,
+}
+
+impl ColumnEncryptor<'_> {
+    /// Encrypts a page header or page into a module. `page_ordinal` is the index of the data
+    /// page in the column chunk, and is ignored for dictionary pages.
+    pub(crate) fn encrypt(
+        &self,
+        plaintext: &[u8],
+        module_type: ModuleType,
+        page_ordinal: usize,
+    ) -> ParquetResult<Vec<u8>> {
+        let aad = page_module_aad(self.file_aad, module_type, &self.position, page_ordinal)?;
+        encrypt_module(self.key, &aad, plaintext)
+    }
+
+    pub(crate) fn crypto_metadata(&self) -> ColumnCryptoMetaData {
+        self.crypto_metadata.clone()
+    }
+
+    /// Replaces the metadata of `column_chunk` with its encrypted module.
+    pub(crate) fn encrypt_column_metadata(
+        &self,
+        column_chunk: &mut ColumnChunk,
+    ) -> ParquetResult<()> {
+        let Some(metadata) = column_chunk.meta_data.take() else {
+            return Ok(());
+        };
+        let mut bytes = vec![];
+        metadata.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut bytes))?;
+        let aad = module_aad(
+            self.file_aad,
+            ModuleType::ColumnMetaData,
+            Some(&self.position),
+        )?;
+        column_chunk.encrypted_column_metadata = Some(encrypt_module(self.key, &aad, &bytes)?);
+        column_chunk.crypto_metadata = Some(self.crypto_metadata());
+        Ok(())
+    }
+}
+
+/// Returns the length of the module at the start of `bytes`, including its length prefix.
+pub(crate) fn module_len(bytes: &[u8]) -> ParquetResult<usize> {
+    let len = bytes
+        .first_chunk::<MODULE_LENGTH_SIZE>()
+        .ok_or_else(|| ParquetError::oos("encrypted module is too short"))?;
+    Ok(MODULE_LENGTH_SIZE + u32::from_le_bytes(*len) as usize)
+}
+
+fn ordinal_bytes(ordinal: usize, name: &str) -> ParquetResult<[u8; 2]> {
+    i16::try_from(ordinal)
+        .map(i16::to_le_bytes)
+        .map_err(|_| ParquetError::oos(format!("{name} ordinal {ordinal} is too large")))
+}
+
+fn module_aad(
+    file_aad: &[u8],
+    module_type: ModuleType,
+    position: Option<&ModulePosition>,
+) -> ParquetResult<Vec<u8>> {
+    let mut aad = Vec::with_capacity(file_aad.len() + 7);
+    aad.extend_from_slice(file_aad);
+    aad.push(module_type as u8);
+    if let Some(position) = position {
+        aad.extend_from_slice(&ordinal_bytes(position.row_group, "row group")?);
+        aad.extend_from_slice(&ordinal_bytes(position.column, "column")?);
+    }
+    Ok(aad)
+}
+
+/// The AAD of a page header or page module of the column at `position`.
+fn page_module_aad(
+    file_aad: &[u8],
+    module_type: ModuleType,
+    position: &ModulePosition,
+    page_ordinal: usize,
+) -> ParquetResult<Vec<u8>> {
+    let mut aad = module_aad(file_aad, module_type, Some(position))?;
+    if matches!(
+        module_type,
+        ModuleType::DataPage | ModuleType::DataPageHeader
+    ) {
+        aad.extend_from_slice(&ordinal_bytes(page_ordinal, "page")?);
+    }
+    Ok(aad)
+}
+
+fn column_path(path: &[PlSmallStr]) -> String {
+    path.iter()
+        .map(PlSmallStr::as_str)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Encrypts `plaintext` into a module with a random nonce.
+fn encrypt_module(key: &[u8], aad: &[u8], plaintext: &[u8]) -> ParquetResult<Vec<u8>> {
+    let nonce = random_bytes::<NONCE_SIZE>()?;
+    let ciphertext = encrypt_aes_gcm(key, &nonce, plaintext, aad)?;
+    let len = u32::try_from(NONCE_SIZE + ciphertext.len())
+        .map_err(|_| ParquetError::oos("encrypted module is too large"))?;
+
+    let mut module = Vec::with_capacity(MODULE_LENGTH_SIZE + len as usize);
+    module.extend_from_slice(&len.to_le_bytes());
+    module.extend_from_slice(&nonce);
+    module.extend_from_slice(&ciphertext);
+    Ok(module)
+}
+
+/// Decrypts the module at the start of `bytes`.
+fn decrypt_module(key: &[u8], aad: &[u8], bytes: &[u8]) -> ParquetResult<Vec<u8>> {
+    let len = module_len(bytes)?;
+    let module = bytes
+        .get(MODULE_LENGTH_SIZE..len)
+        .filter(|module| module.len() >= NONCE_SIZE + TAG_SIZE)
+        .ok_or_else(|| ParquetError::oos("encrypted module is too short"))?;
+    let (nonce, ciphertext) = module.split_at(NONCE_SIZE);
+    decrypt_aes_gcm(key, nonce, ciphertext, aad)
+}
+
+#[cfg(feature = "encryption")]
+fn decrypt_aes_gcm(
+    key: &[u8],
+    nonce: &[u8],
+    ciphertext: &[u8],
+    aad: &[u8],
+) -> ParquetResult<Vec<u8>> {
+    use aes_gcm::aead::consts::U12;
+    use aes_gcm::aead::{Aead, Payload};
+    use aes_gcm::aes::Aes192;
+    use aes_gcm::{Aes128Gcm, Aes256Gcm, AesGcm, KeyInit, Nonce};
+
+    let nonce = Nonce::from_slice(nonce);
+    let payload = Payload {
+        msg: ciphertext,
+        aad,
+    };
+    let result = match key.len() {
+        16 => Aes128Gcm::new_from_slice(key)
+            .unwrap()
+            .decrypt(nonce, payload),
+        24 => AesGcm::<Aes192, U12>::new_from_slice(key)
+            .unwrap()
+            .decrypt(nonce, payload),
+        32 => Aes256Gcm::new_from_slice(key)
+            .unwrap()
+            .decrypt(nonce, payload),
+        len => {
+            return Err(ParquetError::InvalidParameter(format!(
+                "AES keys must be 16, 24 or 32 bytes long, got {len} bytes"
+            )));
+        },
+    };
+    result.map_err(|_| {
+        ParquetError::InvalidParameter(
+            "failed to decrypt the file, the key is wrong or the file is corrupted".to_string(),
+        )
+    })
+}
+
+#[cfg(not(feature = "encryption"))]
+fn decrypt_aes_gcm(_: &[u8], _: &[u8], _: &[u8], _: &[u8]) -> ParquetResult<Vec<u8>> {
+    Err(ParquetError::FeatureNotActive(
+        crate::parquet::error::Feature::Encryption,
+        "decrypt Parquet files".to_string(),
+    ))
+}
+
+#[cfg(feature = "encryption")]
+fn encrypt_aes_gcm(
+    key: &[u8],
+    nonce: &[u8],
+    plaintext: &[u8],
+    aad: &[u8],
+) -> ParquetResult<Vec<u8>> {
+    use aes_gcm::aead::consts::U12;
+    use aes_gcm::aead::{Aead, Payload};
+    use aes_gcm::aes::Aes192;
+    use aes_gcm::{Aes128Gcm, Aes256Gcm, AesGcm, KeyInit, Nonce};
+
+    let nonce = Nonce::from_slice(nonce);
+    let payload = Payload {
+        msg: plaintext,
+        aad,
+    };
+    let result = match key.len() {
+        16 => Aes128Gcm::new_from_slice(key)
+            .unwrap()
+            .encrypt(nonce, payload),
+        24 => AesGcm::<Aes192, U12>::new_from_slice(key)
+            .unwrap()
+            .encrypt(nonce, payload),
+        32 => Aes256Gcm::new_from_slice(key)
+            .unwrap()
+            .encrypt(nonce, payload),
+        len => {
+            return Err(ParquetError::InvalidParameter(format!(
+                "AES keys must be 16, 24 or 32 bytes long, got {len} bytes"
+            )));
+        },
+    };
+    result.map_err(|_| ParquetError::oos("failed to encrypt a module"))
+}
+
+#[cfg(not(feature = "encryption"))]
+fn encrypt_aes_gcm(_: &[u8], _: &[u8], _: &[u8], _: &[u8]) -> ParquetResult<Vec<u8>> {
+    Err(ParquetError::FeatureNotActive(
+        crate::parquet::error::Feature::Encryption,
+        "encrypt Parquet files".to_string(),
+    ))
+}
+
+/// Random bytes from the operating system, for nonces and file identifiers.
+#[cfg(feature = "encryption")]
+fn random_bytes<const N: usize>() -> ParquetResult<[u8; N]> {
+    use aes_gcm::aead::OsRng;
+    use aes_gcm::aead::rand_core::RngCore;
+
+    let mut bytes = [0u8; N];
+    OsRng
+        .try_fill_bytes(&mut bytes)
+        .map_err(|e| ParquetError::oos(format!("failed to generate random bytes: {e}")))?;
+    Ok(bytes)
+}
+
+#[cfg(not(feature = "encryption"))]
+fn random_bytes<const N: usize>() -> ParquetResult<[u8; N]> {
+    Err(ParquetError::FeatureNotActive(
+        crate::parquet::error::Feature::Encryption,
+        "encrypt Parquet files".to_string(),
+    ))
+}
+
+#[cfg(all(test, feature = "encryption"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decrypt_page_module() {
+        let key = vec![1u8; 16];
+        let decryptor = ColumnDecryptor {
+            key: Some(key.clone()),
+            path: "a".to_string(),
+            file_aad: Arc::from(&b"file"[..]),
+            position: ModulePosition {
+                row_group: 1,
+                column: 2,
+            },
+            has_dictionary_page: false,
+        };
+
+        let position = ModulePosition {
+            row_group: 1,
+            column: 2,
+        };
+        let mut aad = module_aad(b"file", ModuleType::DataPage, Some(&position)).unwrap();
+        assert_eq!(aad, b"file\x02\x01\x00\x02\x00");
+        aad.extend_from_slice(&3i16.to_le_bytes());
+        let mut module = encrypt_module(&key, &aad, b"page").unwrap();
+        assert_eq!(module_len(&module).unwrap(), module.len());
+
+        let decrypt = |module: &[u8], page_ordinal| {
+            decryptor.decrypt(module, ModuleType::DataPage, page_ordinal)
+        };
+        assert_eq!(decrypt(&module, 3).unwrap(), b"page");
+        // The AAD binds the module to its position.
+        assert!(decrypt(&module, 4).is_err());
+        *module.last_mut().unwrap() ^= 1;
+        assert!(decrypt(&module, 3).is_err());
+    }
+
+    #[test]
+    fn test_keys_compare_by_value() {
+        let properties = FileDecryptionProperties::new(vec![1u8; 16]);
+        assert_eq!(FileDecryptionProperties::new(vec![1u8; 16]), properties);
+        assert_ne!(FileDecryptionProperties::new(vec![2u8; 16]), properties);
+        assert_ne!(FileDecryptionProperties::new(vec![1u8; 24]), properties);
+    }
+
+    #[test]
+    fn test_encrypt_roundtrip() {
+        let footer_key = vec![1u8; 16];
+        let column_key = vec![2u8; 32];
+        let schema = SchemaDescriptor::try_from_message(
+            "message schema { REQUIRED INT32 a; REQUIRED INT32 b; }",
+        )
+        .unwrap();
+        let properties = FileEncryptionProperties::new(footer_key.clone())
+            .with_column_key("b", column_key.clone())
+            .with_aad_prefix(b"file".to_vec())
+            .without_aad_prefix_storage();
+        let encryptor = FileEncryptor::try_new(Arc::new(properties), &schema).unwrap();
+
+        // Only the column with a key is encrypted.
+        assert!(encryptor.column_encryptor(&["a".into()], 0, 0).is_none());
+        let column_encryptor = encryptor.column_encryptor(&["b".into()], 0, 1).unwrap();
+        let module = column_encryptor
+            .encrypt(b"page", ModuleType::DataPage, 3)
+            .unwrap();
+
+        let algorithm = encryptor.crypto_metadata().encryption_algorithm;
+        let decryptor = |properties: FileDecryptionProperties| {
+            FileDecryptor::try_new(&algorithm, Some(&Arc::new(properties)))
+        };
+        // The AAD prefix is not stored in the file.
+        assert!(decryptor(FileDecryptionProperties::new(footer_key.clone())).is_err());
+        let decryptor = decryptor(
+            FileDecryptionProperties::new(footer_key)
+                .with_column_key("b", column_key.clone())
+                .with_aad_prefix(b"file".to_vec()),
+        )
+        .unwrap();
+
+        let footer = encryptor.encrypt_footer(b"footer").unwrap();
+        assert_eq!(decryptor.decrypt_footer(&footer).unwrap(), b"footer");
+
+        let column_decryptor = ColumnDecryptor {
+            key: Some(column_key),
+            path: "b".to_string(),
+            file_aad: decryptor.file_aad.clone(),
+            position: ModulePosition {
+                row_group: 0,
+                column: 1,
+            },
+            has_dictionary_page: false,
+        };
+        assert_eq!(
+            column_decryptor
+                .decrypt(&module, ModuleType::DataPage, 3)
+                .unwrap(),
+            b"page"
+        );
+    }
+}
@@ -15,6 +15,8 @@ pub enum Feature {
     Lz4,
     /// Zstd compression and decompression
     Zstd,
+    /// Decryption of encrypted files
+    Encryption,
 }
 
 /// Errors generated by this crate
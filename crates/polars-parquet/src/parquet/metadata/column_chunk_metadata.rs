@@ -1,7 +1,11 @@
+use std::sync::{Arc, OnceLock};
+
 use polars_parquet_format::{ColumnChunk, ColumnMetaData, Encoding};
 
 use super::column_descriptor::ColumnDescriptor;
+use super::page_index::ColumnPageIndex;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnDecryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::schema::types::PhysicalType;
 use crate::parquet::statistics::Statistics;
@@ -36,6 +40,10 @@ pub struct ColumnChunkMetadata {
     )]
     column_chunk: ColumnChunk,
     column_descr: ColumnDescriptor,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    page_index: OnceLock<Arc<ColumnPageIndex>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    decryptor: Option<Arc<ColumnDecryptor>>,
 }
 
 #[cfg(feature = "serde")]
@@ -76,6 +84,8 @@ impl ColumnChunkMetadata {
         Self {
             column_chunk,
             column_descr,
+            page_index: OnceLock::new(),
+            decryptor: None,
         }
     }
 
@@ -172,10 +182,54 @@ impl ColumnChunkMetadata {
         column_metadata_byte_range(self.metadata())
     }
 
+    /// Returns the byte range of the column index (the statistics of the data pages) of this
+    /// column chunk, if it has one.
+    pub fn column_index_range(&self) -> Option<core::ops::Range<u64>> {
+        let offset = u64::try_from(self.column_chunk.column_index_offset?).ok()?;
+        let length = u64::try_from(self.column_chunk.column_index_length?).ok()?;
+        Some(offset..offset.checked_add(length)?)
+    }
+
+    /// Returns the byte range of the offset index (the locations of the data pages) of this
+    /// column chunk, if it has one.
+    pub fn offset_index_range(&self) -> Option<core::ops::Range<u64>> {
+        let offset = u64::try_from(self.column_chunk.offset_index_offset?).ok()?;
+        let length = u64::try_from(self.column_chunk.offset_index_length?).ok()?;
+        Some(offset..offset.checked_add(length)?)
+    }
+
+    /// The page index of this column chunk, if it was loaded with
+    /// [`ColumnChunkMetadata::set_page_index`].
+    pub fn page_index(&self) -> Option<&Arc<ColumnPageIndex>> {
+        self.page_index.get()
+    }
+
+    /// Sets the page index of this column chunk. This does nothing if it was already set.
+    ///
+    /// The page index is not part of the file metadata, so it is loaded separately, see
+    /// [`read_page_indexes`](crate::parquet::read::read_page_indexes).
+    pub fn set_page_index(&self, page_index: Arc<ColumnPageIndex>) {
+        let _ = self.page_index.set(page_index);
+    }
+
+    /// Whether the column chunk is encrypted, see [`crate::parquet::encryption`].
+    pub fn is_encrypted(&self) -> bool {
+        self.column_chunk.crypto_metadata.is_some()
+    }
+
+    /// The decryptor of the pages of this column chunk, if it is encrypted.
+    pub(crate) fn decryptor(&self) -> Option<&Arc<ColumnDecryptor>> {
+        self.decryptor.as_ref()
+    }
+
     /// Method to convert from Thrift.
+    ///
+    /// The metadata of an encrypted column chunk must already have been decrypted, see
+    /// [`FileDecryptor::decrypt_column_chunk`](crate::parquet::encryption::FileDecryptor).
     pub(crate) fn try_from_thrift(
         column_descr: ColumnDescriptor,
         column_chunk: ColumnChunk,
+        decryptor: Option<Arc<ColumnDecryptor>>,
     ) -> ParquetResult<Self> {
         // validate metadata
         if let Some(meta) = &column_chunk.meta_data {
@@ -194,6 +248,8 @@ impl ColumnChunkMetadata {
         Ok(Self {
             column_chunk,
             column_descr,
+            page_index: OnceLock::new(),
+            decryptor,
         })
     }
 
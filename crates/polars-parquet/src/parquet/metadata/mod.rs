@@ -2,6 +2,7 @@ mod column_chunk_metadata;
 mod column_descriptor;
 mod column_order;
 mod file_metadata;
+mod page_index;
 mod row_metadata;
 mod schema_descriptor;
 mod sort;
@@ -10,6 +11,7 @@ pub use column_chunk_metadata::ColumnChunkMetadata;
 pub use column_descriptor::{ColumnDescriptor, Descriptor};
 pub use column_order::ColumnOrder;
 pub use file_metadata::{FileMetadata, KeyValue};
+pub use page_index::ColumnPageIndex;
 pub use row_metadata::RowGroupMetadata;
 pub use schema_descriptor::SchemaDescriptor;
 pub use sort::*;
@@ -0,0 +1,50 @@
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+use polars_parquet_format::{ColumnIndex, OffsetIndex};
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+
+/// The page index of a column chunk: the statistics and the locations of its data pages.
+#[derive(Debug, Clone)]
+pub struct ColumnPageIndex {
+    /// The statistics of the data pages, if they were written.
+    pub column_index: Option<ColumnIndex>,
+    /// The locations of the data pages.
+    pub offset_index: OffsetIndex,
+}
+
+impl ColumnPageIndex {
+    /// Deserialize the page index of a column chunk from the bytes of its column index and
+    /// offset index.
+    pub fn try_from_bytes(column_index: Option<&[u8]>, offset_index: &[u8]) -> ParquetResult<Self> {
+        let column_index = column_index
+            .map(|bytes| {
+                let mut prot = TCompactInputProtocol::new(bytes, bytes.len() * 2 + 1024);
+                ColumnIndex::read_from_in_protocol(&mut prot)
+            })
+            .transpose()?;
+        let mut prot = TCompactInputProtocol::new(offset_index, offset_index.len() * 2 + 1024);
+        let offset_index = OffsetIndex::read_from_in_protocol(&mut prot)?;
+
+        let num_pages = offset_index.page_locations.len();
+        if let Some(column_index) = &column_index {
+            if column_index.null_pages.len() != num_pages
+                || column_index.min_values.len() != num_pages
+                || column_index.max_values.len() != num_pages
+            {
+                return Err(ParquetError::oos(
+                    "The column index and offset index have a different number of pages",
+                ));
+            }
+        }
+
+        Ok(Self {
+            column_index,
+            offset_index,
+        })
+    }
+
+    /// The number of data pages of the column chunk.
+    pub fn num_pages(&self) -> usize {
+        self.offset_index.page_locations.len()
+    }
+}
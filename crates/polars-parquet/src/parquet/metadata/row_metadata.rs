@@ -9,6 +9,7 @@ use polars_utils::unitvec;
 
 use super::column_chunk_metadata::{ColumnChunkMetadata, column_metadata_byte_range};
 use super::schema_descriptor::SchemaDescriptor;
+use crate::parquet::encryption::FileDecryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 
 type ColumnLookup = PlHashMap<PlSmallStr, UnitVec<usize>>;
@@ -103,9 +104,14 @@ impl RowGroupMetadata {
     }
 
     /// Method to convert from Thrift.
+    ///
+    /// The column chunks of files with encrypted columns are decrypted with `decryptor`, where
+    /// `ordinal` is the index of the row group in the file.
     pub(crate) fn try_from_thrift(
         schema_descr: &SchemaDescriptor,
-        rg: RowGroup,
+        mut rg: RowGroup,
+        ordinal: usize,
+        decryptor: Option<&FileDecryptor>,
     ) -> ParquetResult<RowGroupMetadata> {
         if schema_descr.columns().len() != rg.columns.len() {
             return Err(ParquetError::oos(format!(
@@ -117,6 +123,24 @@ impl RowGroupMetadata {
         let total_byte_size = rg.total_byte_size.try_into()?;
         let num_rows = rg.num_rows.try_into()?;
 
+        let column_decryptors = match decryptor {
+            Some(decryptor) => rg
+                .columns
+                .iter_mut()
+                .zip(schema_descr.columns())
+                .enumerate()
+                .map(|(i, (column_chunk, descriptor))| {
+                    decryptor.decrypt_column_chunk(
+                        column_chunk,
+                        &descriptor.path_in_schema,
+                        ordinal,
+                        i,
+                    )
+                })
+                .collect::<ParquetResult<Vec<_>>>()?,
+            None => vec![None; rg.columns.len()],
+        };
+
         let mut column_lookup = ColumnLookup::with_capacity(rg.columns.len());
         let mut full_byte_range = if let Some(first_column_chunk) = rg.columns.first() {
             let Some(metadata) = &first_column_chunk.meta_data else {
@@ -133,10 +157,14 @@ impl RowGroupMetadata {
             .columns
             .into_iter()
             .zip(schema_descr.columns())
+            .zip(column_decryptors)
             .enumerate()
-            .map(|(i, (column_chunk, descriptor))| {
-                let column =
-                    ColumnChunkMetadata::try_from_thrift(descriptor.clone(), column_chunk)?;
+            .map(|(i, ((column_chunk, descriptor), column_decryptor))| {
+                let column = ColumnChunkMetadata::try_from_thrift(
+                    descriptor.clone(),
+                    column_chunk,
+                    column_decryptor,
+                )?;
 
                 column_lookup.add_column(i, &column);
 
@@ -25,6 +25,9 @@ pub struct CompressedDataPage {
     uncompressed_page_size: usize,
     pub(crate) descriptor: Descriptor,
     pub num_rows: Option<usize>,
+    /// The number of distinct values of the column chunk this page belongs to. It is written in
+    /// the statistics of the column chunk, not in the page header.
+    pub chunk_distinct_count: Option<i64>,
 }
 
 impl CompressedDataPage {
@@ -44,6 +47,7 @@ impl CompressedDataPage {
             uncompressed_page_size,
             descriptor,
             num_rows: Some(num_rows),
+            chunk_distinct_count: None,
         }
     }
 
@@ -62,6 +66,7 @@ impl CompressedDataPage {
             uncompressed_page_size,
             descriptor,
             num_rows: None,
+            chunk_distinct_count: None,
         }
     }
 
@@ -156,6 +161,9 @@ pub struct DataPage {
     pub(super) buffer: CowBuffer,
     pub descriptor: Descriptor,
     pub num_rows: Option<usize>,
+    /// The number of distinct values of the column chunk this page belongs to. It is written in
+    /// the statistics of the column chunk, not in the page header.
+    pub chunk_distinct_count: Option<i64>,
 }
 
 impl DataPage {
@@ -170,6 +178,7 @@ impl DataPage {
             buffer,
             descriptor,
             num_rows: Some(num_rows),
+            chunk_distinct_count: None,
         }
     }
 
@@ -183,6 +192,7 @@ impl DataPage {
             buffer,
             descriptor,
             num_rows: None,
+            chunk_distinct_count: None,
         }
     }
 
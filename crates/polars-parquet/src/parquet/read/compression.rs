@@ -1,9 +1,12 @@
+use std::sync::Arc;
+
 use polars_parquet_format::DataPageHeaderV2;
 
 use super::PageReader;
 use crate::parquet::CowBuffer;
 use crate::parquet::compression::{self, Compression, DecompressionContext};
 use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::metadata::ColumnPageIndex;
 use crate::parquet::page::{
     CompressedDataPage, CompressedPage, DataPage, DataPageHeader, DictPage, Page,
 };
@@ -178,6 +181,17 @@ impl BasicDecompressor {
         self.reader.total_num_values()
     }
 
+    /// The page index of the column chunk, if it was loaded.
+    pub fn page_index(&self) -> Option<&Arc<ColumnPageIndex>> {
+        self.reader.page_index()
+    }
+
+    /// A decompressor over the pages that this one has not read yet, e.g. to decode them again
+    /// in another way.
+    pub fn fork(&self) -> Self {
+        Self::new(self.reader.clone(), Vec::new())
+    }
+
     /// Returns its internal buffer, consuming itself.
     pub fn into_inner(self) -> Vec<u8> {
         self.buffer
@@ -0,0 +1,70 @@
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
+
+use crate::parquet::error::ParquetResult;
+use crate::parquet::metadata::{ColumnChunkMetadata, ColumnPageIndex, FileMetadata};
+
+/// Reads the page indexes of all column chunks of `metadata` from `reader`, and sets them on the
+/// column chunks, see [`ColumnChunkMetadata::page_index`].
+///
+/// Column chunks without an offset index have no page index. The page indexes of encrypted
+/// column chunks are not read.
+pub fn read_page_indexes<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetadata,
+) -> ParquetResult<()> {
+    let mut column_index_buf = vec![];
+    let mut offset_index_buf = vec![];
+    for row_group in &metadata.row_groups {
+        for column in row_group.parquet_columns() {
+            if column.page_index().is_some() || column.is_encrypted() {
+                continue;
+            }
+            let Some(offset_index_range) = column.offset_index_range() else {
+                continue;
+            };
+            read_range(reader, offset_index_range, &mut offset_index_buf)?;
+            let column_index = match column.column_index_range() {
+                Some(range) => {
+                    read_range(reader, range, &mut column_index_buf)?;
+                    Some(column_index_buf.as_slice())
+                },
+                None => None,
+            };
+            let page_index = ColumnPageIndex::try_from_bytes(column_index, &offset_index_buf)?;
+            column.set_page_index(Arc::new(page_index));
+        }
+    }
+    Ok(())
+}
+
+/// Reads the page index of `column` from the bytes of the file, and sets it on the column chunk.
+pub fn read_column_page_index(column: &ColumnChunkMetadata, file: &[u8]) -> ParquetResult<()> {
+    if column.page_index().is_some() || column.is_encrypted() {
+        return Ok(());
+    }
+    let Some(offset_index_range) = column.offset_index_range() else {
+        return Ok(());
+    };
+    let get = |range: std::ops::Range<u64>| file.get(range.start as usize..range.end as usize);
+    let (Some(offset_index), column_index) = (
+        get(offset_index_range),
+        column.column_index_range().map(get),
+    ) else {
+        return Ok(());
+    };
+    let page_index = ColumnPageIndex::try_from_bytes(column_index.flatten(), offset_index)?;
+    column.set_page_index(Arc::new(page_index));
+    Ok(())
+}
+
+fn read_range<R: Read + Seek>(
+    reader: &mut R,
+    range: std::ops::Range<u64>,
+    buf: &mut Vec<u8>,
+) -> ParquetResult<()> {
+    reader.seek(SeekFrom::Start(range.start))?;
+    buf.clear();
+    reader.take(range.end - range.start).read_to_end(buf)?;
+    Ok(())
+}
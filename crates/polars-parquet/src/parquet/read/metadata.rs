@@ -1,11 +1,15 @@
 use std::cmp::min;
 use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
 
-use polars_parquet_format::FileMetaData as TFileMetadata;
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+use polars_parquet_format::{FileCryptoMetaData, FileMetaData as TFileMetadata};
 
 use super::super::metadata::FileMetadata;
 use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC};
+use crate::parquet::encryption::{
+    FileDecryptionProperties, FileDecryptor, PARQUET_ENCRYPTED_MAGIC,
+};
 use crate::parquet::error::{ParquetError, ParquetResult};
 
 pub(super) fn metadata_len(buffer: &[u8], len: usize) -> u32 {
@@ -28,15 +32,32 @@ fn stream_len(seek: &mut impl Seek) -> std::result::Result<u64, std::io::Error>
 
 /// Reads a [`FileMetadata`] from the reader, located at the end of the file.
 pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> ParquetResult<FileMetadata> {
+    read_metadata_with_decryption(reader, None)
+}
+
+/// Reads a [`FileMetadata`] from the reader, located at the end of the file, decrypting it with
+/// `decryption` if the file is encrypted.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: Option<&Arc<FileDecryptionProperties>>,
+) -> ParquetResult<FileMetadata> {
     // check file is large enough to hold footer
     let file_size = stream_len(reader)?;
-    read_metadata_with_size(reader, file_size)
+    read_footer(reader, file_size, decryption)
 }
 
 /// Reads a [`FileMetadata`] from the reader, located at the end of the file, with known file size.
 pub fn read_metadata_with_size<R: Read + Seek>(
     reader: &mut R,
     file_size: u64,
+) -> ParquetResult<FileMetadata> {
+    read_footer(reader, file_size, None)
+}
+
+fn read_footer<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    decryption: Option<&Arc<FileDecryptionProperties>>,
 ) -> ParquetResult<FileMetadata> {
     if file_size < HEADER_SIZE + FOOTER_SIZE {
         return Err(ParquetError::oos(
@@ -55,8 +76,9 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         .read_to_end(&mut buffer)?;
 
     // check this is indeed a parquet file
-    if buffer[default_end_len - 4..] != PARQUET_MAGIC {
-        return Err(ParquetError::oos("The file must end with PAR1"));
+    let magic = &buffer[default_end_len - 4..];
+    if magic != PARQUET_MAGIC && magic != PARQUET_ENCRYPTED_MAGIC {
+        return Err(ParquetError::oos("The file must end with PAR1 or PARE"));
     }
 
     let metadata_len: u32 = metadata_len(&buffer, default_end_len);
@@ -84,10 +106,49 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         &buffer
     };
 
+    deserialize_footer(reader, decryption)
+}
+
+/// Parses the bytes at the end of a file, which hold the metadata followed by its length and the
+/// magic bytes, into a [`FileMetadata`]. Encrypted metadata is decrypted with `decryption`.
+pub fn deserialize_footer(
+    bytes: &[u8],
+    decryption: Option<&Arc<FileDecryptionProperties>>,
+) -> ParquetResult<FileMetadata> {
+    let len = bytes.len();
+    if len < FOOTER_SIZE as usize {
+        return Err(ParquetError::oos(
+            "The footer must be at least 8 bytes long",
+        ));
+    }
+    let metadata_len = metadata_len(bytes, len) as usize;
+    let metadata = len
+        .checked_sub(FOOTER_SIZE as usize + metadata_len)
+        .map(|start| &bytes[start..len - FOOTER_SIZE as usize])
+        .ok_or_else(|| ParquetError::oos("The footer is shorter than the metadata length"))?;
     // a highly nested but sparse struct could result in many allocations
-    let max_size = reader.len() * 2 + 1024;
+    let max_size = metadata.len() * 2 + 1024;
+
+    if bytes[len - 4..] != PARQUET_ENCRYPTED_MAGIC {
+        let mut prot = TCompactInputProtocol::new(metadata, max_size);
+        let metadata = TFileMetadata::read_from_in_protocol(&mut prot)?;
+        let decryptor = metadata
+            .encryption_algorithm
+            .as_ref()
+            .map(|algorithm| FileDecryptor::try_new(algorithm, decryption))
+            .transpose()?;
+        return FileMetadata::try_from_thrift_with_decryptor(metadata, decryptor.as_ref());
+    }
 
-    deserialize_metadata(reader, max_size)
+    // An encrypted footer is the plaintext crypto metadata followed by the encrypted metadata.
+    let mut remaining = metadata;
+    let mut prot = TCompactInputProtocol::new(&mut remaining, max_size);
+    let crypto_metadata = FileCryptoMetaData::read_from_in_protocol(&mut prot)?;
+    let decryptor = FileDecryptor::try_new(&crypto_metadata.encryption_algorithm, decryption)?;
+    let metadata = decryptor.decrypt_footer(remaining)?;
+    let mut prot = TCompactInputProtocol::new(metadata.as_slice(), max_size);
+    let metadata = TFileMetadata::read_from_in_protocol(&mut prot)?;
+    FileMetadata::try_from_thrift_with_decryptor(metadata, Some(&decryptor))
 }
 
 /// Parse loaded metadata bytes
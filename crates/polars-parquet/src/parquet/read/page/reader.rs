@@ -1,5 +1,5 @@
 use std::io::{Cursor, Seek};
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
 use polars_buffer::Buffer;
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
@@ -7,8 +7,9 @@ use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
 use super::PageIterator;
 use crate::parquet::CowBuffer;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{ColumnDecryptor, ModuleType, module_len};
 use crate::parquet::error::{ParquetError, ParquetResult};
-use crate::parquet::metadata::{ColumnChunkMetadata, Descriptor};
+use crate::parquet::metadata::{ColumnChunkMetadata, ColumnPageIndex, Descriptor};
 use crate::parquet::page::{
     CompressedDataPage, CompressedDictPage, CompressedPage, DataPageHeader, PageType,
     ParquetPageHeader,
@@ -62,6 +63,7 @@ impl From<&ColumnChunkMetadata> for PageMetaData {
 /// The pages from this iterator always have [`None`] [`crate::parquet::page::CompressedDataPage::selected_rows()`] since
 /// filter pushdown is not supported without a
 /// pre-computed [page index](https://github.com/apache/parquet-format/blob/master/PageIndex.md).
+#[derive(Clone)]
 pub struct PageReader {
     // The source
     reader: Cursor<Buffer<u8>>,
@@ -81,6 +83,15 @@ pub struct PageReader {
 
     // Maximum page size (compressed or uncompressed) to limit allocations
     max_page_size: usize,
+
+    // The page index of the column chunk, if it was loaded.
+    page_index: Option<Arc<ColumnPageIndex>>,
+
+    // The decryptor of the pages, if the column chunk is encrypted.
+    decryptor: Option<Arc<ColumnDecryptor>>,
+
+    // The number of data pages we have seen so far, which is part of the AAD of encrypted pages.
+    data_page_ordinal: usize,
 }
 
 impl PageReader {
@@ -94,7 +105,11 @@ impl PageReader {
         scratch: Vec<u8>,
         max_page_size: usize,
     ) -> Self {
-        Self::new_with_page_meta(reader, column.into(), scratch, max_page_size)
+        let mut page_reader =
+            Self::new_with_page_meta(reader, column.into(), scratch, max_page_size);
+        page_reader.page_index = column.page_index().cloned();
+        page_reader.decryptor = column.decryptor().cloned();
+        page_reader
     }
 
     /// Create a new [`PageReader`] with [`PageMetaData`].
@@ -114,6 +129,9 @@ impl PageReader {
             descriptor: reader_meta.descriptor,
             scratch,
             max_page_size,
+            page_index: None,
+            decryptor: None,
+            data_page_ordinal: 0,
         }
     }
 
@@ -127,6 +145,11 @@ impl PageReader {
         self.total_num_values as usize
     }
 
+    /// The page index of the column chunk, if it was loaded.
+    pub fn page_index(&self) -> Option<&Arc<ColumnPageIndex>> {
+        self.page_index.as_ref()
+    }
+
     pub fn read_dict(&mut self) -> ParquetResult<Option<CompressedDictPage>> {
         // If there are no pages, we cannot check if the first page is a dictionary page. Just
         // return the fact there is no dictionary page.
@@ -134,10 +157,19 @@ impl PageReader {
             return Ok(None);
         }
 
+        // The header of an encrypted page can only be decrypted if its type is known.
+        if self
+            .decryptor
+            .as_ref()
+            .is_some_and(|decryptor| !decryptor.has_dictionary_page)
+        {
+            return Ok(None);
+        }
+
         // a dictionary page exists iff the first data page is not at the start of
         // the column
         let seek_offset = self.reader.position();
-        let page_header = read_page_header(&mut self.reader, self.max_page_size)?;
+        let page_header = self.next_page_header(ModuleType::DictionaryPageHeader)?;
         let page_type = page_header.type_.try_into()?;
 
         if !matches!(page_type, PageType::DictionaryPage) {
@@ -163,6 +195,7 @@ impl PageReader {
                 "The page header reported the wrong page size",
             ));
         }
+        let buffer = self.decrypt_page(buffer, ModuleType::DictionaryPage)?;
 
         finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
             if let CompressedPage::Dict(d) = p {
@@ -172,6 +205,37 @@ impl PageReader {
             }
         })
     }
+
+    /// Reads the next page header, decrypting it if the column chunk is encrypted.
+    fn next_page_header(&mut self, module_type: ModuleType) -> ParquetResult<ParquetPageHeader> {
+        let Some(decryptor) = &self.decryptor else {
+            return read_page_header(&mut self.reader, self.max_page_size);
+        };
+
+        let pos = self.reader.position() as usize;
+        let module = self.reader.get_ref().get(pos..).unwrap_or_default();
+        let len = module_len(module)?;
+        let header = decryptor.decrypt(module, module_type, self.data_page_ordinal)?;
+        self.reader.set_position((pos + len) as u64);
+
+        let mut prot = TCompactInputProtocol::new(header.as_slice(), self.max_page_size);
+        Ok(ParquetPageHeader::read_from_in_protocol(&mut prot)?)
+    }
+
+    /// Decrypts the `buffer` of a page if the column chunk is encrypted.
+    fn decrypt_page(
+        &self,
+        buffer: Buffer<u8>,
+        module_type: ModuleType,
+    ) -> ParquetResult<Buffer<u8>> {
+        match &self.decryptor {
+            None => Ok(buffer),
+            Some(decryptor) => {
+                let page = decryptor.decrypt(&buffer, module_type, self.data_page_ordinal)?;
+                Ok(Buffer::from_vec(page))
+            },
+        }
+    }
 }
 
 impl PageIterator for PageReader {
@@ -214,7 +278,7 @@ fn next_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
 }
 
 pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
-    let page_header = read_page_header(&mut reader.reader, reader.max_page_size)?;
+    let page_header = reader.next_page_header(ModuleType::DataPageHeader)?;
 
     reader.seen_num_values += get_page_num_values(&page_header)? as i64;
 
@@ -236,6 +300,8 @@ pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<Compre
             "The page header reported the wrong page size",
         ));
     }
+    let buffer = reader.decrypt_page(buffer, ModuleType::DataPage)?;
+    reader.data_page_ordinal += 1;
 
     finish_page(page_header, buffer, reader.compression, &reader.descriptor).map(Some)
 }
@@ -66,6 +66,7 @@ fn is_logical_type(s: &str) -> bool {
             | "JSON"
             | "BSON"
             | "UUID"
+            | "FLOAT16"
             | "UNKNOWN"
             | "INTERVAL"
     )
@@ -623,6 +624,7 @@ impl Parser<'_> {
             "JSON" => PrimitiveLogicalType::Json,
             "BSON" => PrimitiveLogicalType::Bson,
             "UUID" => PrimitiveLogicalType::Uuid,
+            "FLOAT16" => PrimitiveLogicalType::Float16,
             "UNKNOWN" => PrimitiveLogicalType::Unknown,
             "INTERVAL" => return Err(ParquetError::oos("Interval logical type not yet supported")),
             _ => unreachable!(),
@@ -16,15 +16,21 @@ use super::statistics::reduce;
 use crate::parquet::FallibleStreamingIterator;
 use crate::parquet::compression::Compression;
 use crate::parquet::encoding::Encoding;
+use crate::parquet::encryption::ColumnEncryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::ColumnDescriptor;
 use crate::parquet::page::{CompressedPage, PageType};
 
+/// Writes the pages of a column chunk, encrypting them if `encryptor` is given.
+///
+/// The metadata of an encrypted column chunk is not written after its pages. It is only
+/// written, encrypted, in the footer.
 pub fn write_column_chunk<W, E>(
     writer: &mut W,
     mut offset: u64,
     descriptor: &ColumnDescriptor,
     mut compressed_pages: DynStreamingIterator<'_, CompressedPage, E>,
+    encryptor: Option<&ColumnEncryptor<'_>>,
 ) -> ParquetResult<(ColumnChunk, Vec<PageWriteSpec>, u64)>
 where
     W: Write,
@@ -36,14 +42,29 @@ where
     let initial = offset;
 
     let mut specs = vec![];
+    let mut data_page_ordinal = 0;
     while let Some(compressed_page) = compressed_pages.next()? {
-        let spec = write_page(writer, offset, compressed_page)?;
+        let spec = write_page(
+            writer,
+            offset,
+            compressed_page,
+            encryptor,
+            data_page_ordinal,
+        )?;
+        if matches!(compressed_page, CompressedPage::Data(_)) {
+            data_page_ordinal += 1;
+        }
         offset += spec.bytes_written;
         specs.push(spec);
     }
     let mut bytes_written = offset - initial;
 
-    let column_chunk = build_column_chunk(&specs, descriptor)?;
+    let mut column_chunk = build_column_chunk(&specs, descriptor)?;
+
+    if let Some(encryptor) = encryptor {
+        column_chunk.crypto_metadata = Some(encryptor.crypto_metadata());
+        return Ok((column_chunk, specs, bytes_written));
+    }
 
     // write metadata
     let mut protocol = TCompactOutputProtocol::new(writer);
@@ -93,6 +114,17 @@ where
     Ok((column_chunk, specs, bytes_written))
 }
 
+/// The distinct count of the column chunk, which is only known if all its data pages carry the
+/// same count.
+fn chunk_distinct_count(specs: &[PageWriteSpec]) -> Option<i64> {
+    let mut counts = specs
+        .iter()
+        .filter(|spec| !is_dict_page(spec))
+        .map(|spec| spec.chunk_distinct_count);
+    let first = counts.next()??;
+    counts.all(|count| count == Some(first)).then_some(first)
+}
+
 fn build_column_chunk(
     specs: &[PageWriteSpec],
     descriptor: &ColumnDescriptor,
@@ -185,7 +217,10 @@ fn build_column_chunk(
 
     let statistics = specs.iter().map(|x| &x.statistics).collect::<Vec<_>>();
     let statistics = reduce(&statistics)?;
-    let statistics = statistics.map(|x| x.serialize());
+    let mut statistics = statistics.map(|x| x.serialize());
+    if let Some(statistics) = &mut statistics {
+        statistics.distinct_count = chunk_distinct_count(specs);
+    }
 
     let (type_, _): (Type, Option<i32>) = descriptor.descriptor.primitive_type.physical_type.into();
 
@@ -17,6 +17,7 @@ fn compress_data(
         header,
         descriptor,
         num_rows,
+        chunk_distinct_count,
     } = page;
     let uncompressed_page_size = buffer.len();
     let num_rows = num_rows.expect("We should have num_rows when we are writing");
@@ -41,14 +42,16 @@ fn compress_data(
         std::mem::swap(buffer.to_mut(), &mut compressed_buffer);
     }
 
-    Ok(CompressedDataPage::new(
+    let mut page = CompressedDataPage::new(
         header,
         CowBuffer::Owned(compressed_buffer),
         compression.into(),
         uncompressed_page_size,
         descriptor,
         num_rows,
-    ))
+    );
+    page.chunk_distinct_count = chunk_distinct_count;
+    Ok(page)
 }
 
 fn compress_dict(
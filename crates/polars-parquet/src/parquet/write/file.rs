@@ -1,12 +1,17 @@
-use std::io::Write;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::sync::Arc;
 
-use polars_parquet_format::RowGroup;
-use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+use polars_parquet_format::thrift::protocol::{TCompactInputProtocol, TCompactOutputProtocol};
+use polars_parquet_format::{RowGroup, SortingColumn};
 
+use super::bloom_filter::{BloomFilterBitset, write_bloom_filter};
 use super::indexes::{write_column_index, write_offset_index};
 use super::page::PageWriteSpec;
 use super::row_group::write_row_group;
 use super::{RowGroupIterColumns, WriteOptions};
+use crate::parquet::encryption::{
+    FileEncryptionProperties, FileEncryptor, PARQUET_ENCRYPTED_MAGIC,
+};
 use crate::parquet::error::{ParquetError, ParquetResult};
 pub use crate::parquet::metadata::KeyValue;
 use crate::parquet::metadata::{SchemaDescriptor, ThriftFileMetadata};
@@ -39,6 +44,32 @@ pub(super) fn end_file<W: Write>(
     Ok(metadata_len as u64 + FOOTER_SIZE)
 }
 
+/// Writes the footer of a file with an encrypted footer: the plaintext crypto metadata, followed
+/// by the encrypted file metadata.
+fn end_encrypted_file<W: Write>(
+    mut writer: &mut W,
+    metadata: &ThriftFileMetadata,
+    encryptor: &FileEncryptor,
+) -> ParquetResult<u64> {
+    let mut protocol = TCompactOutputProtocol::new(&mut writer);
+    let crypto_metadata_len = encryptor
+        .crypto_metadata()
+        .write_to_out_protocol(&mut protocol)?;
+
+    let mut metadata_bytes = vec![];
+    metadata.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut metadata_bytes))?;
+    let footer_module = encryptor.encrypt_footer(&metadata_bytes)?;
+    writer.write_all(&footer_module)?;
+
+    let footer_len: u32 = (crypto_metadata_len + footer_module.len())
+        .try_into()
+        .map_err(|_| ParquetError::oos("the encrypted footer is too large"))?;
+    writer.write_all(&footer_len.to_le_bytes())?;
+    writer.write_all(&PARQUET_ENCRYPTED_MAGIC)?;
+    writer.flush()?;
+    Ok(footer_len as u64 + FOOTER_SIZE)
+}
+
 fn create_column_orders(schema_desc: &SchemaDescriptor) -> Vec<polars_parquet_format::ColumnOrder> {
     // We only include ColumnOrder for leaf nodes.
     // Currently only supported ColumnOrder is TypeDefinedOrder so we set this
@@ -66,10 +97,17 @@ pub struct FileWriter<W: Write> {
     offset: u64,
     row_groups: Vec<RowGroup>,
     page_specs: Vec<Vec<Vec<PageWriteSpec>>>,
+    /// The bloom filters of every row group, written with the indexes.
+    bloom_filters: Vec<Vec<BloomFilterBitset>>,
+    /// Encrypts the columns and the footer, if the file is encrypted.
+    encryptor: Option<FileEncryptor>,
     /// Used to store the current state for writing the file
     state: State,
     // when the file is written, metadata becomes available
     metadata: Option<ThriftFileMetadata>,
+    /// The key-value metadata of the file that is appended to, written if [`Self::end`] is not
+    /// given any.
+    key_value_metadata: Option<Vec<KeyValue>>,
 }
 
 /// Writes a parquet file containing only the header and footer
@@ -88,6 +126,87 @@ pub fn write_metadata_sidecar<W: Write>(
     Ok(len)
 }
 
+impl<W: Write + Read + Seek> FileWriter<W> {
+    /// Opens the existing file `writer` to append row groups to it.
+    ///
+    /// The row groups of the file are kept as they are. New row groups are written over the
+    /// footer of the file, and [`Self::end`] writes a new footer with both the existing and the
+    /// new row groups. The new row groups must have the schema of the file, see
+    /// [`Self::schema`]. Without `created_by`, the one of the file is kept.
+    ///
+    /// The file is not a valid Parquet file until [`Self::end`] is called. As the new footer may
+    /// be shorter than the existing one, e.g. when the key-value metadata is replaced, the file
+    /// must be truncated to the length returned by [`Self::end`].
+    ///
+    /// # Errors
+    /// Returns an error if the file is not a Parquet file or if it is encrypted.
+    pub fn try_append(
+        mut writer: W,
+        options: WriteOptions,
+        created_by: Option<String>,
+    ) -> ParquetResult<Self> {
+        let file_size = writer.seek(SeekFrom::End(0))?;
+        if file_size < PARQUET_MAGIC.len() as u64 + FOOTER_SIZE {
+            return Err(ParquetError::oos(
+                "A parquet file must contain a header and footer with at least 12 bytes",
+            ));
+        }
+
+        let mut footer = [0u8; FOOTER_SIZE as usize];
+        writer.seek(SeekFrom::End(-(FOOTER_SIZE as i64)))?;
+        writer.read_exact(&mut footer)?;
+        if footer[4..] != PARQUET_MAGIC {
+            return Err(ParquetError::not_supported(
+                "appending to files with an encrypted footer",
+            ));
+        }
+        let metadata_len = u32::from_le_bytes(footer[..4].try_into().unwrap()) as u64;
+        let footer_start = file_size
+            .checked_sub(FOOTER_SIZE + metadata_len)
+            .filter(|start| *start >= PARQUET_MAGIC.len() as u64)
+            .ok_or_else(|| {
+                ParquetError::oos("The footer size must be smaller or equal to the file's size")
+            })?;
+
+        writer.seek(SeekFrom::Start(footer_start))?;
+        let mut metadata_bytes = vec![];
+        metadata_bytes.try_reserve(metadata_len as usize)?;
+        (&mut writer)
+            .take(metadata_len)
+            .read_to_end(&mut metadata_bytes)?;
+        // a highly nested but sparse struct could result in many allocations
+        let max_size = metadata_bytes.len() * 2 + 1024;
+        let mut protocol = TCompactInputProtocol::new(metadata_bytes.as_slice(), max_size);
+        let metadata = ThriftFileMetadata::read_from_in_protocol(&mut protocol)?;
+        if metadata.encryption_algorithm.is_some() {
+            return Err(ParquetError::not_supported(
+                "appending to files with encrypted columns",
+            ));
+        }
+
+        // The new row groups overwrite the existing footer.
+        writer.seek(SeekFrom::Start(footer_start))?;
+        let num_row_groups = metadata.row_groups.len();
+        Ok(Self {
+            writer,
+            schema: SchemaDescriptor::try_from_thrift(&metadata.schema)?,
+            options,
+            created_by: created_by.or(metadata.created_by),
+            offset: footer_start,
+            row_groups: metadata.row_groups,
+            // The indexes and bloom filters of the existing row groups are already written.
+            page_specs: std::iter::repeat_with(Vec::new)
+                .take(num_row_groups)
+                .collect(),
+            bloom_filters: vec![vec![]; num_row_groups],
+            encryptor: None,
+            state: State::Started,
+            metadata: None,
+            key_value_metadata: metadata.key_value_metadata,
+        })
+    }
+}
+
 // Accessors
 impl<W: Write> FileWriter<W> {
     /// The options assigned to the file
@@ -125,11 +244,29 @@ impl<W: Write> FileWriter<W> {
             offset: 0,
             row_groups: vec![],
             page_specs: vec![],
+            bloom_filters: vec![],
+            encryptor: None,
             state: State::Initialised,
             metadata: None,
+            key_value_metadata: None,
         }
     }
 
+    /// Encrypts the file with Parquet modular encryption, see [`FileEncryptionProperties`].
+    ///
+    /// The column indexes, offset indexes and bloom filters of encrypted columns are not
+    /// written.
+    ///
+    /// # Errors
+    /// Returns an error if a column key is given for a column that is not in the schema.
+    pub fn with_encryption(
+        mut self,
+        properties: Arc<FileEncryptionProperties>,
+    ) -> ParquetResult<Self> {
+        self.encryptor = Some(FileEncryptor::try_new(properties, &self.schema)?);
+        Ok(self)
+    }
+
     /// Writes the header of the file.
     ///
     /// This is automatically called by [`Self::write`] if not called following [`Self::new`].
@@ -156,6 +293,25 @@ impl<W: Write> FileWriter<W> {
         num_rows: u64,
         row_group: RowGroupIterColumns<'_, E>,
     ) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        self.write_with_metadata(num_rows, row_group, vec![], None)
+    }
+
+    /// Writes a row group to the file, with the bloom filters of its leaf columns and the
+    /// columns its rows are sorted by.
+    ///
+    /// `bloom_filters` holds the bitset of every leaf column that has a bloom filter, and may
+    /// be empty if no column has one. The bloom filters are written when the file is ended.
+    pub fn write_with_metadata<E>(
+        &mut self,
+        num_rows: u64,
+        row_group: RowGroupIterColumns<'_, E>,
+        bloom_filters: Vec<BloomFilterBitset>,
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> ParquetResult<()>
     where
         ParquetError: From<E>,
         E: std::error::Error,
@@ -164,17 +320,20 @@ impl<W: Write> FileWriter<W> {
             self.start()?;
         }
         let ordinal = self.row_groups.len();
-        let (group, specs, size) = write_row_group(
+        let (mut group, specs, size) = write_row_group(
             &mut self.writer,
             num_rows,
             self.offset,
             self.schema.columns(),
             row_group,
             ordinal,
+            self.encryptor.as_ref(),
         )?;
+        group.sorting_columns = sorting_columns;
         self.offset += size;
         self.row_groups.push(group);
         self.page_specs.push(specs);
+        self.bloom_filters.push(bloom_filters);
         Ok(())
     }
 
@@ -193,6 +352,22 @@ impl<W: Write> FileWriter<W> {
         // compute file stats
         let num_rows = self.row_groups.iter().map(|group| group.num_rows).sum();
 
+        // write bloom filters
+        for (group, bloom_filters) in self.row_groups.iter_mut().zip(&self.bloom_filters) {
+            for (column, bitset) in group.columns.iter_mut().zip(bloom_filters) {
+                let (Some(bitset), Some(metadata)) = (bitset, column.meta_data.as_mut()) else {
+                    continue;
+                };
+                if column.crypto_metadata.is_some() {
+                    continue;
+                }
+                let offset = self.offset;
+                self.offset += write_bloom_filter(&mut self.writer, bitset)?;
+                metadata.bloom_filter_offset = Some(offset as i64);
+                metadata.bloom_filter_length = Some((self.offset - offset).try_into()?);
+            }
+        }
+
         if self.options.write_statistics {
             // write column indexes (require page statistics)
             self.row_groups
@@ -201,6 +376,9 @@ impl<W: Write> FileWriter<W> {
                 .try_for_each(|(group, pages)| {
                     group.columns.iter_mut().zip(pages.iter()).try_for_each(
                         |(column, pages)| {
+                            if column.crypto_metadata.is_some() {
+                                return Ok(());
+                            }
                             let offset = self.offset;
                             column.column_index_offset = Some(offset as i64);
                             self.offset += write_column_index(&mut self.writer, pages)?;
@@ -223,6 +401,9 @@ impl<W: Write> FileWriter<W> {
                     .iter_mut()
                     .zip(pages.iter())
                     .try_for_each(|(column, pages)| {
+                        if column.crypto_metadata.is_some() {
+                            return Ok(());
+                        }
                         let offset = self.offset;
                         column.offset_index_offset = Some(offset as i64);
                         self.offset += write_offset_index(&mut self.writer, pages)?;
@@ -232,6 +413,21 @@ impl<W: Write> FileWriter<W> {
                 ParquetResult::Ok(())
             })?;
 
+        // encrypt the metadata of encrypted columns
+        if let Some(encryptor) = &self.encryptor {
+            for (row_group_idx, group) in self.row_groups.iter_mut().enumerate() {
+                for (column_idx, column) in group.columns.iter_mut().enumerate() {
+                    let path = &self.schema.columns()[column_idx].path_in_schema;
+                    if let Some(column_encryptor) =
+                        encryptor.column_encryptor(path, row_group_idx, column_idx)
+                    {
+                        column_encryptor.encrypt_column_metadata(column)?;
+                    }
+                }
+            }
+        }
+
+        let key_value_metadata = key_value_metadata.or_else(|| self.key_value_metadata.take());
         let metadata = ThriftFileMetadata::new(
             self.options.version.into(),
             self.schema.clone().into_thrift(),
@@ -244,7 +440,10 @@ impl<W: Write> FileWriter<W> {
             None,
         );
 
-        let len = end_file(&mut self.writer, &metadata)?;
+        let len = match &self.encryptor {
+            Some(encryptor) => end_encrypted_file(&mut self.writer, &metadata, encryptor)?,
+            None => end_file(&mut self.writer, &metadata)?,
+        };
         self.state = State::Finished;
         self.metadata = Some(metadata);
         Ok(self.offset + len)
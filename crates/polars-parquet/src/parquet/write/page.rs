@@ -8,6 +8,7 @@ use polars_parquet_format::thrift::protocol::TCompactOutputStreamProtocol;
 use polars_parquet_format::{DictionaryPageHeader, Encoding, PageType};
 
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{ColumnEncryptor, ModuleType};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::page::{
     CompressedDataPage, CompressedDictPage, CompressedPage, DataPageHeader, ParquetPageHeader,
@@ -50,40 +51,66 @@ pub struct PageWriteSpec {
     pub bytes_written: u64,
     pub compression: Compression,
     pub statistics: Option<Statistics>,
+    /// The number of distinct values of the column chunk, if the page carries it.
+    pub chunk_distinct_count: Option<i64>,
 }
 
+/// Writes `compressed_page` with its header. If the column is encrypted, the header and the
+/// page are written as modules encrypted with `encryptor`, where `page_ordinal` is the index of
+/// the data page in the column chunk.
 pub fn write_page<W: Write>(
     writer: &mut W,
     offset: u64,
     compressed_page: &CompressedPage,
+    encryptor: Option<&ColumnEncryptor<'_>>,
+    page_ordinal: usize,
 ) -> ParquetResult<PageWriteSpec> {
     let num_values = compressed_page.num_values();
     let num_rows = compressed_page
         .num_rows()
         .expect("We should have num_rows when we are writing");
 
-    let header = match &compressed_page {
+    let mut header = match &compressed_page {
         CompressedPage::Data(compressed_page) => assemble_data_page_header(compressed_page),
         CompressedPage::Dict(compressed_page) => assemble_dict_page_header(compressed_page),
     }?;
 
-    let header_size = write_page_header(writer, &header)?;
-    let mut bytes_written = header_size;
+    let buffer: &[u8] = match &compressed_page {
+        CompressedPage::Data(compressed_page) => &compressed_page.buffer,
+        CompressedPage::Dict(compressed_page) => &compressed_page.buffer,
+    };
 
-    bytes_written += match &compressed_page {
-        CompressedPage::Data(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
-        },
-        CompressedPage::Dict(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
-        },
+    let (header_size, bytes_written) = if let Some(encryptor) = encryptor {
+        let (page_type, header_type) = match &compressed_page {
+            CompressedPage::Data(_) => (ModuleType::DataPage, ModuleType::DataPageHeader),
+            CompressedPage::Dict(_) => {
+                (ModuleType::DictionaryPage, ModuleType::DictionaryPageHeader)
+            },
+        };
+        let page = encryptor.encrypt(buffer, page_type, page_ordinal)?;
+        // SPEC: the compressed size of an encrypted page is the size of its module.
+        (_, header.compressed_page_size) = maybe_bytes(0, page.len())?;
+
+        let mut header_bytes = vec![];
+        write_page_header(&mut header_bytes, &header)?;
+        let header_module = encryptor.encrypt(&header_bytes, header_type, page_ordinal)?;
+
+        writer.write_all(&header_module)?;
+        writer.write_all(&page)?;
+        let header_size = header_module.len() as u64;
+        (header_size, header_size + page.len() as u64)
+    } else {
+        let header_size = write_page_header(writer, &header)?;
+        writer.write_all(buffer)?;
+        (header_size, header_size + buffer.len() as u64)
     };
 
-    let statistics = match &compressed_page {
-        CompressedPage::Data(compressed_page) => compressed_page.statistics().transpose()?,
-        CompressedPage::Dict(_) => None,
+    let (statistics, chunk_distinct_count) = match &compressed_page {
+        CompressedPage::Data(compressed_page) => (
+            compressed_page.statistics().transpose()?,
+            compressed_page.chunk_distinct_count,
+        ),
+        CompressedPage::Dict(_) => (None, None),
     };
 
     Ok(PageWriteSpec {
@@ -93,6 +120,7 @@ pub fn write_page<W: Write>(
         bytes_written,
         compression: compressed_page.compression(),
         statistics,
+        chunk_distinct_count,
         num_values,
         num_rows,
     })
@@ -129,9 +157,12 @@ pub async fn write_page_async<W: AsyncWrite + Unpin + Send>(
         },
     };
 
-    let statistics = match &compressed_page {
-        CompressedPage::Data(compressed_page) => compressed_page.statistics().transpose()?,
-        CompressedPage::Dict(_) => None,
+    let (statistics, chunk_distinct_count) = match &compressed_page {
+        CompressedPage::Data(compressed_page) => (
+            compressed_page.statistics().transpose()?,
+            compressed_page.chunk_distinct_count,
+        ),
+        CompressedPage::Dict(_) => (None, None),
     };
 
     Ok(PageWriteSpec {
@@ -141,6 +172,7 @@ pub async fn write_page_async<W: AsyncWrite + Unpin + Send>(
         bytes_written,
         compression: compressed_page.compression(),
         statistics,
+        chunk_distinct_count,
         num_rows,
         num_values,
     })
@@ -269,6 +269,16 @@ mod _python {
             T::from_pyany(pyany, py).map(Arc::from)
         }
     }
+
+    impl<T: super::PlanCallbackOut> super::PlanCallbackOut for Vec<T> {
+        fn from_pyany<'py>(pyany: Py<PyAny>, py: Python<'py>) -> PyResult<Self> {
+            pyany
+                .bind(py)
+                .try_iter()?
+                .map(|v| T::from_pyany(v?.unbind(), py))
+                .collect()
+        }
+    }
 }
 
 #[cfg(not(feature = "python"))]
@@ -0,0 +1,225 @@
+//! Access policies: row filters and column masks that are applied to the scans of a plan, based
+//! on the principal that runs the query.
+use super::*;
+
+/// The user or service on whose behalf a query runs.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Principal {
+    pub name: PlSmallStr,
+    pub roles: Vec<PlSmallStr>,
+}
+
+impl Principal {
+    pub fn new<S: Into<PlSmallStr>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            roles: vec![],
+        }
+    }
+
+    pub fn with_roles<I, S>(mut self, roles: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        self.roles.extend(roles.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+}
+
+/// Decides which rows and values of the scanned data a [`Principal`] may see.
+///
+/// The policy is asked about every `Scan`, `DataFrameScan` and `PythonScan` node of a plan.
+pub trait AccessPolicy: Send + Sync {
+    /// A predicate on the rows of `scan` that `principal` may see, or `None` if all rows are
+    /// visible.
+    fn row_filter(&self, principal: &Principal, scan: &DslPlan) -> PolarsResult<Option<Expr>>;
+
+    /// Expressions that replace the columns of `scan` that are masked for `principal`, e.g.
+    /// `lit(NULL).cast(DataType::String).alias("ssn")`. Every expression must be named after the
+    /// column it replaces.
+    fn column_masks(&self, principal: &Principal, scan: &DslPlan) -> PolarsResult<Vec<Expr>> {
+        let _ = (principal, scan);
+        Ok(vec![])
+    }
+}
+
+impl Expr {
+    /// Apply `policy` to the scans of the subqueries in the expression.
+    pub fn apply_access_policy(
+        self,
+        policy: &dyn AccessPolicy,
+        principal: &Principal,
+    ) -> PolarsResult<Expr> {
+        self.try_map_expr(|e| match e {
+            Expr::SubPlan(plan, names) => {
+                let plan = plan
+                    .as_ref()
+                    .clone()
+                    .apply_access_policy(policy, principal)?;
+                Ok(Expr::SubPlan(SpecialEq::new(Arc::new(plan)), names))
+            },
+            e => Ok(e),
+        })
+    }
+}
+
+impl DslPlan {
+    /// Apply `policy` for `principal` to every scan of the plan, including the scans of
+    /// subqueries.
+    ///
+    /// The row filter is applied directly on top of the scan, before the column masks, so it can
+    /// refer to the unmasked values. As this runs before optimization, the filters and masks are
+    /// optimized together with the rest of the query, e.g. pushed into the scan.
+    pub fn apply_access_policy(
+        mut self,
+        policy: &dyn AccessPolicy,
+        principal: &Principal,
+    ) -> PolarsResult<DslPlan> {
+        self.apply_access_policy_mut(policy, principal)?;
+        Ok(self)
+    }
+
+    fn apply_access_policy_mut(
+        &mut self,
+        policy: &dyn AccessPolicy,
+        principal: &Principal,
+    ) -> PolarsResult<()> {
+        use DslPlan::*;
+        let apply = |exprs: &mut [Expr]| -> PolarsResult<()> {
+            for e in exprs {
+                *e = std::mem::take(e).apply_access_policy(policy, principal)?;
+            }
+            Ok(())
+        };
+        let apply_input = |input: &mut Arc<DslPlan>| {
+            Arc::make_mut(input).apply_access_policy_mut(policy, principal)
+        };
+
+        match self {
+            Filter { input, predicate } => {
+                apply(std::slice::from_mut(predicate))?;
+                apply_input(input)?;
+            },
+            Select { input, expr, .. } => {
+                apply(expr)?;
+                apply_input(input)?;
+            },
+            GroupBy {
+                input,
+                keys,
+                predicates,
+                aggs,
+                ..
+            } => {
+                apply(keys)?;
+                apply(predicates)?;
+                apply(aggs)?;
+                apply_input(input)?;
+            },
+            Join {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                predicates,
+                ..
+            } => {
+                apply(left_on)?;
+                apply(right_on)?;
+                apply(predicates)?;
+                apply_input(input_left)?;
+                apply_input(input_right)?;
+            },
+            HStack { input, exprs, .. } => {
+                apply(exprs)?;
+                apply_input(input)?;
+            },
+            Sort {
+                input, by_column, ..
+            } => {
+                apply(by_column)?;
+                apply_input(input)?;
+            },
+            #[cfg(feature = "pivot")]
+            Pivot { input, agg, .. } => {
+                apply(std::slice::from_mut(agg))?;
+                apply_input(input)?;
+            },
+            Cache { input, .. }
+            | MatchToSchema { input, .. }
+            | Distinct { input, .. }
+            | Slice { input, .. }
+            | MapFunction { input, .. }
+            | Sink { input, .. } => apply_input(input)?,
+            Union { inputs, .. } | HConcat { inputs, .. } | SinkMultiple { inputs } => {
+                for input in inputs {
+                    input.apply_access_policy_mut(policy, principal)?;
+                }
+            },
+            ExtContext { input, contexts } => {
+                apply_input(input)?;
+                for context in contexts {
+                    context.apply_access_policy_mut(policy, principal)?;
+                }
+            },
+            PipeWithSchema { input, .. } => {
+                *input = input
+                    .iter()
+                    .map(|plan| plan.clone().apply_access_policy(policy, principal))
+                    .collect::<PolarsResult<_>>()?;
+            },
+            #[cfg(feature = "merge_sorted")]
+            MergeSorted {
+                input_left,
+                input_right,
+                ..
+            } => {
+                apply_input(input_left)?;
+                apply_input(input_right)?;
+            },
+            IR { dsl, node, .. } => {
+                // The converted plan doesn't contain the filters and masks.
+                apply_input(dsl)?;
+                *node = None;
+            },
+            Scan { .. } | DataFrameScan { .. } => {
+                *self = restrict_scan(self.clone(), policy, principal)?;
+            },
+            #[cfg(feature = "python")]
+            PythonScan { .. } => {
+                *self = restrict_scan(self.clone(), policy, principal)?;
+            },
+        }
+        Ok(())
+    }
+}
+
+fn restrict_scan(
+    scan: DslPlan,
+    policy: &dyn AccessPolicy,
+    principal: &Principal,
+) -> PolarsResult<DslPlan> {
+    let row_filter = policy.row_filter(principal, &scan)?;
+    let masks = policy.column_masks(principal, &scan)?;
+
+    let mut plan = scan;
+    if let Some(predicate) = row_filter {
+        plan = DslPlan::Filter {
+            input: Arc::new(plan),
+            predicate,
+        };
+    }
+    if !masks.is_empty() {
+        plan = DslPlan::HStack {
+            input: Arc::new(plan),
+            exprs: masks,
+            options: ProjectionOptions::default(),
+        };
+    }
+    Ok(plan)
+}
@@ -8,6 +8,7 @@ use super::*;
 #[derive(Clone)]
 pub struct When {
     condition: Expr,
+    lazy: bool,
 }
 
 /// Utility struct for the `when-then-otherwise` expression.
@@ -17,6 +18,7 @@ pub struct When {
 pub struct Then {
     condition: Expr,
     statement: Expr,
+    lazy: bool,
 }
 
 /// Utility struct for the `when-then-otherwise` expression.
@@ -28,6 +30,7 @@ pub struct Then {
 pub struct ChainedWhen {
     conditions: Vec<Expr>,
     statements: Vec<Expr>,
+    lazy: bool,
 }
 
 /// Utility struct for the `when-then-otherwise` expression.
@@ -37,6 +40,7 @@ pub struct ChainedWhen {
 pub struct ChainedThen {
     conditions: Vec<Expr>,
     statements: Vec<Expr>,
+    lazy: bool,
 }
 
 impl When {
@@ -45,6 +49,7 @@ impl When {
         Then {
             condition: self.condition,
             statement: expr.into(),
+            lazy: self.lazy,
         }
     }
 }
@@ -55,12 +60,13 @@ impl Then {
         ChainedWhen {
             conditions: vec![self.condition, condition.into()],
             statements: vec![self.statement],
+            lazy: self.lazy,
         }
     }
 
     /// Define a default for the `when-then-otherwise` expression.
     pub fn otherwise<E: Into<Expr>>(self, statement: E) -> Expr {
-        ternary_expr(self.condition, self.statement, statement.into())
+        finish_ternary(self.lazy, self.condition, self.statement, statement.into())
     }
 }
 
@@ -70,6 +76,7 @@ impl ChainedWhen {
         ChainedThen {
             conditions: self.conditions,
             statements: self.statements,
+            lazy: self.lazy,
         }
     }
 }
@@ -82,6 +89,7 @@ impl ChainedThen {
         ChainedWhen {
             conditions: self.conditions,
             statements: self.statements,
+            lazy: self.lazy,
         }
     }
 
@@ -117,7 +125,8 @@ impl ChainedThen {
         let mut otherwise = expr.into();
 
         for e in conditions_iter {
-            otherwise = ternary_expr(
+            otherwise = finish_ternary(
+                self.lazy,
                 e,
                 statements_iter
                     .next()
@@ -134,17 +143,36 @@ impl ChainedThen {
 pub fn when<E: Into<Expr>>(condition: E) -> When {
     When {
         condition: condition.into(),
+        lazy: false,
     }
 }
 
-pub fn ternary_expr(predicate: Expr, truthy: Expr, falsy: Expr) -> Expr {
+/// Start a `when-then-otherwise` expression of which every statement is only evaluated on the
+/// rows it is selected for, as if these rows were the whole column.
+///
+/// Unlike with [`when`], statements that would fail on the unselected rows are fine here.
+/// Statements that depend on other rows, such as `col("a").cum_sum()`, are computed over the
+/// selected rows only.
+pub fn when_lazy<E: Into<Expr>>(condition: E) -> When {
+    When {
+        condition: condition.into(),
+        lazy: true,
+    }
+}
+
+fn finish_ternary(lazy: bool, predicate: Expr, truthy: Expr, falsy: Expr) -> Expr {
     Expr::Ternary {
         predicate: Arc::new(predicate),
         truthy: Arc::new(truthy),
         falsy: Arc::new(falsy),
+        lazy,
     }
 }
 
+pub fn ternary_expr(predicate: Expr, truthy: Expr, falsy: Expr) -> Expr {
+    finish_ternary(false, predicate, truthy, falsy)
+}
+
 /// Compute `op(l, r)` (or equivalently `l op r`). `l` and `r` must have types compatible with the Operator.
 pub fn binary_expr(l: Expr, op: Operator, r: Expr) -> Expr {
     Expr::BinaryExpr {
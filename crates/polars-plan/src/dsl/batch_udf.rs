@@ -0,0 +1,152 @@
+//! User-defined functions on the Arrow arrays of columns.
+use arrow::array::{Array, ArrayRef};
+use arrow::compute::utils::combine_validities_and;
+use polars_core::chunked_array::cast::CastOptions;
+
+use super::*;
+
+/// The properties of a [`BatchUdf`], which the optimizer and the engines rely on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BatchUdfProperties {
+    /// Every output row only depends on the input rows at the same position.
+    ///
+    /// An elementwise function is called once per batch of rows, and may be called on any
+    /// batching of the rows. Inputs of length 1 are broadcast to the length of the other inputs.
+    /// Otherwise the function is called once, on all the rows (or per group in a group-by).
+    pub elementwise: bool,
+    /// An output row is null if any of its input rows is null.
+    ///
+    /// The validity of the output is then computed from the validities of the inputs, so the
+    /// function may ignore nulls: the values in the null slots of its output are ignored.
+    pub propagates_nulls: bool,
+}
+
+/// A user-defined function on Arrow arrays, see [`map_batch_udf`].
+///
+/// This is a lower-level alternative to [`Expr::map`] for library authors: it works on the
+/// physical Arrow arrays of the inputs instead of [`Column`]s, and declares its properties so
+/// that the optimizer doesn't have to assume the worst.
+pub trait BatchUdf: Send + Sync {
+    /// The name of the function, shown in plans.
+    fn name(&self) -> &str;
+
+    /// The properties of the function.
+    fn properties(&self) -> BatchUdfProperties {
+        BatchUdfProperties::default()
+    }
+
+    /// The output field, given the fields of the inputs.
+    fn output_field(&self, fields: &[Field]) -> PolarsResult<Field>;
+
+    /// Call the function on a batch of rows.
+    ///
+    /// `arrays` holds the physical array of every input, all of the same length. The output
+    /// must be in the physical representation of the dtype of [`BatchUdf::output_field`], and
+    /// have the length of the inputs if the function is elementwise.
+    fn call(&self, arrays: &[&dyn Array]) -> PolarsResult<ArrayRef>;
+}
+
+/// Apply a [`BatchUdf`] to the outputs of `exprs`.
+pub fn map_batch_udf<E: AsRef<[Expr]>>(udf: Arc<dyn BatchUdf>, exprs: E) -> Expr {
+    let input = exprs.as_ref().to_vec();
+    let properties = udf.properties();
+    let fmt_str = Box::new(PlSmallStr::from_str(udf.name()));
+
+    let output_udf = udf.clone();
+    let function = BaseColumnUdf::new(
+        move |columns: &mut [Column]| call_batch_udf(udf.as_ref(), columns),
+        move |_: &Schema, fields: &[Field]| output_udf.output_field(fields),
+    );
+
+    let mut options = if properties.elementwise {
+        FunctionOptions::elementwise()
+    } else {
+        FunctionOptions::groupwise()
+    };
+    if properties.propagates_nulls {
+        options = options.with_flags(|f| f | FunctionFlags::PRESERVES_NULL_ALL_INPUTS);
+    }
+    Expr::AnonymousFunction {
+        input,
+        function: new_column_udf(function),
+        options,
+        fmt_str,
+    }
+}
+
+fn call_batch_udf(udf: &dyn BatchUdf, columns: &mut [Column]) -> PolarsResult<Column> {
+    polars_ensure!(
+        !columns.is_empty(),
+        InvalidOperation: "'{}' needs at least one input", udf.name()
+    );
+    let properties = udf.properties();
+    let fields = columns
+        .iter()
+        .map(|c| c.field().into_owned())
+        .collect::<Vec<_>>();
+    let output_field = udf.output_field(&fields)?;
+
+    let len = columns.iter().map(|c| c.len()).max().unwrap();
+    let mut inputs = columns
+        .iter()
+        .map(|c| {
+            polars_ensure!(
+                c.len() == len || (properties.elementwise && c.len() == 1),
+                ShapeMismatch: "inputs of '{}' have different lengths: {} and {}",
+                udf.name(), c.len(), len
+            );
+            let c = if c.len() == len {
+                c.clone()
+            } else {
+                c.new_from_index(0, len)
+            };
+            Ok(c.as_materialized_series().to_physical_repr().into_owned())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    // Elementwise functions are called per chunk if the inputs are chunked alike.
+    let chunk_lengths = |s: &Series| s.chunk_lengths().collect::<Vec<_>>();
+    let aligned = properties.elementwise
+        && inputs
+            .iter()
+            .all(|s| chunk_lengths(s) == chunk_lengths(&inputs[0]));
+    if !aligned {
+        for s in &mut inputs {
+            *s = s.rechunk();
+        }
+    }
+
+    let chunks = (0..inputs[0].n_chunks())
+        .map(|i| {
+            let arrays = inputs
+                .iter()
+                .map(|s| s.chunks()[i].as_ref())
+                .collect::<Vec<_>>();
+            let out = udf.call(&arrays)?;
+            if properties.elementwise {
+                polars_ensure!(
+                    out.len() == arrays[0].len(),
+                    ComputeError: "elementwise '{}' returned {} rows for {} input rows",
+                    udf.name(), out.len(), arrays[0].len()
+                );
+            }
+            if !properties.propagates_nulls || out.len() != arrays[0].len() {
+                return Ok(out);
+            }
+            let validity = arrays
+                .iter()
+                .fold(out.validity().cloned(), |validity, array| {
+                    combine_validities_and(validity.as_ref(), array.validity())
+                });
+            Ok(out.with_validity(validity))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let out = Series::try_from((output_field.name().clone(), chunks))?;
+    let out = if out.dtype() == output_field.dtype() {
+        out
+    } else {
+        out.cast_with_options(output_field.dtype(), CastOptions::Strict)?
+    };
+    Ok(out.into_column())
+}
@@ -62,7 +62,9 @@ impl DslBuilder {
         Ok(DslPlan::Scan {
             sources,
             unified_scan_args: Box::new(unified_scan_args),
-            scan_type: Box::new(FileScanDsl::Parquet { options }),
+            scan_type: Box::new(FileScanDsl::Parquet {
+                options: Arc::new(options),
+            }),
             cached_ir: Default::default(),
         }
         .into())
@@ -25,9 +25,21 @@ impl DateLikeNameSpace {
     pub fn to_string(self, format: &str) -> Expr {
         let format = format.to_string();
         self.0
-            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::ToString(
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::ToString {
                 format,
-            )))
+                locale: None,
+            }))
+    }
+
+    /// Convert from Date/Time/Datetime into String with the given format, writing month and
+    /// weekday names in the language of `locale` (e.g. "fr_FR").
+    pub fn to_string_localized(self, format: &str, locale: &str) -> Expr {
+        let format = format.to_string();
+        self.0
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::ToString {
+                format,
+                locale: Some(locale.into()),
+            }))
     }
 
     /// Convert from Date/Time/Datetime into String with the given format.
@@ -0,0 +1,62 @@
+//! Category encodings that are expressed with window aggregations over the category.
+use super::*;
+
+impl Expr {
+    /// Encode every category by the mean of `target` over the rows of that category.
+    ///
+    /// With `smoothing > 0` the category mean is shrunk towards the mean of `target` over all
+    /// rows, as if `smoothing` extra rows with that mean were added to every category. With
+    /// `leave_one_out` the target value of the row itself is excluded from its encoding, which
+    /// avoids leaking the target into the feature when encoding the training data.
+    ///
+    /// Null values of `target` are ignored. Rows whose encoding would be based on zero values
+    /// get the overall mean, which with `leave_one_out` excludes the row itself as well.
+    ///
+    /// Errors if `smoothing` is negative or not finite.
+    pub fn target_encode(
+        self,
+        target: Expr,
+        smoothing: f64,
+        leave_one_out: bool,
+    ) -> PolarsResult<Expr> {
+        polars_ensure!(
+            smoothing.is_finite() && smoothing >= 0.0,
+            InvalidOperation: "`smoothing` must be non-negative and finite, got {}", smoothing
+        );
+        let target = target.cast(DataType::Float64);
+        let is_valid = target.clone().is_not_null().cast(DataType::Float64);
+        let value = target.clone().fill_null(lit(0.0));
+
+        // Counted on `self`, so that the output is named after `self`.
+        let mut count = self
+            .clone()
+            .filter(target.clone().is_not_null())
+            .len()
+            .over([self.clone()])
+            .cast(DataType::Float64);
+        let mut sum = target.clone().sum().over([self]);
+        let mut prior_count = target.clone().count().cast(DataType::Float64);
+        let mut prior_sum = target.sum();
+        if leave_one_out {
+            count = count - is_valid.clone();
+            sum = sum - value.clone();
+            prior_count = prior_count - is_valid;
+            prior_sum = prior_sum - value;
+        }
+        let prior = when(prior_count.clone().gt(lit(0.0)))
+            .then(prior_sum / prior_count)
+            .otherwise(lit(NULL).cast(DataType::Float64));
+
+        let weight = count.clone() / (count.clone() + lit(smoothing));
+        let mean = sum / count.clone();
+        Ok(when(count.gt(lit(0.0)))
+            .then(weight.clone() * mean + (lit(1.0) - weight) * prior.clone())
+            .otherwise(prior))
+    }
+
+    /// Encode every category by the fraction of rows that belong to it.
+    pub fn freq_encode(self) -> Expr {
+        let count = self.clone().len().over([self.clone()]);
+        count.cast(DataType::Float64) / self.len().cast(DataType::Float64)
+    }
+}
@@ -103,6 +103,8 @@ pub enum Expr {
     Column(PlSmallStr),
     Selector(Selector),
     Literal(LiteralValue),
+    /// A literal that is bound later, see [`param`](crate::dsl::param).
+    Parameter(PlSmallStr),
     DataTypeFunction(DataTypeFunction),
     BinaryExpr {
         left: Arc<Expr>,
@@ -136,6 +138,10 @@ pub enum Expr {
         predicate: Arc<Expr>,
         truthy: Arc<Expr>,
         falsy: Arc<Expr>,
+        /// Evaluate the branches only on the rows they are selected for, see
+        /// [`when_lazy`](crate::dsl::when_lazy).
+        #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(default))]
+        lazy: bool,
     },
     Function {
         /// function arguments
@@ -336,10 +342,12 @@ impl Hash for Expr {
                 predicate,
                 truthy,
                 falsy,
+                lazy,
             } => {
                 predicate.hash(state);
                 truthy.hash(state);
                 falsy.hash(state);
+                lazy.hash(state);
             },
             Expr::Function { input, function } => {
                 input.hash(state);
@@ -442,6 +450,7 @@ impl Hash for Expr {
                 evaluation.hash(state);
             },
             Expr::SubPlan(_, names) => names.hash(state),
+            Expr::Parameter(name) => name.hash(state),
             #[cfg(feature = "dtype-struct")]
             Expr::Field(names) => names.hash(state),
         }
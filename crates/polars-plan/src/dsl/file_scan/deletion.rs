@@ -20,6 +20,9 @@ pub enum DeletionFilesList {
     //
     /// Iceberg positional deletes
     IcebergPositionDelete(Arc<PlIndexMap<usize, Arc<[String]>>>),
+    /// Positions of deleted rows that are already loaded, e.g. from Iceberg positional deletes
+    /// or Delta deletion vectors.
+    PositionDelete(Arc<PlIndexMap<usize, Arc<[u64]>>>),
 }
 
 impl DeletionFilesList {
@@ -31,6 +34,9 @@ impl DeletionFilesList {
             Some(IcebergPositionDelete(paths)) => {
                 (!paths.is_empty()).then_some(IcebergPositionDelete(paths))
             },
+            Some(PositionDelete(positions)) => {
+                (!positions.is_empty()).then_some(PositionDelete(positions))
+            },
             None => None,
         }
     }
@@ -40,6 +46,7 @@ impl DeletionFilesList {
 
         match self {
             IcebergPositionDelete(paths) => paths.len(),
+            PositionDelete(positions) => positions.len(),
         }
     }
 }
@@ -56,6 +63,13 @@ impl std::hash::Hash for DeletionFilesList {
                     .first()
                     .map_or(0, |(_, paths)| Arc::as_ptr(paths) as *const () as usize);
 
+                addr.hash(state)
+            },
+            PositionDelete(positions) => {
+                let addr = positions.first().map_or(0, |(_, positions)| {
+                    Arc::as_ptr(positions) as *const () as usize
+                });
+
                 addr.hash(state)
             },
         }
@@ -71,6 +85,10 @@ impl std::fmt::Display for DeletionFilesList {
                 let s = if paths.len() == 1 { "" } else { "s" };
                 write!(f, "iceberg-position-delete: {} source{s}", paths.len())?;
             },
+            PositionDelete(positions) => {
+                let s = if positions.len() == 1 { "" } else { "s" };
+                write!(f, "position-delete: {} source{s}", positions.len())?;
+            },
         }
 
         Ok(())
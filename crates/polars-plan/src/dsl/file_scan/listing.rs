@@ -0,0 +1,161 @@
+use std::num::NonZeroUsize;
+
+use polars_buffer::Buffer;
+use polars_error::{PolarsResult, polars_bail};
+use polars_io::cloud::CloudOptions;
+use polars_io::path_utils::{FileNameFilter, ListingControls, expand_paths_hive};
+use polars_utils::pl_path::PlRefPath;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::prelude::PlanCallback;
+
+/// Called with the file name of every listed file. Files for which this returns `false` are not
+/// scanned.
+pub type FileNameFilterFunction = PlanCallback<PlSmallStr, bool>;
+
+/// Called with the paths given to the scan, returns the files to scan.
+pub type FileListerFunction = PlanCallback<Vec<PlSmallStr>, Vec<PlSmallStr>>;
+
+/// Lists the files of a scan in place of expanding its paths against the file system or object
+/// store. This allows supplying the files from a manifest (e.g. from a catalog) instead of
+/// performing a LIST request on every run.
+pub trait FileLister: Send + Sync {
+    /// Returns the files to scan for the paths given to the scan.
+    fn list_files(&self, paths: &[PlRefPath]) -> PolarsResult<Vec<PlRefPath>>;
+}
+
+impl FileLister for FileListerFunction {
+    fn list_files(&self, paths: &[PlRefPath]) -> PolarsResult<Vec<PlRefPath>> {
+        let files = self.call(
+            paths
+                .iter()
+                .map(|x| PlSmallStr::from_str(x.as_str()))
+                .collect(),
+        )?;
+
+        Ok(files.iter().map(|x| PlRefPath::new(x.as_str())).collect())
+    }
+}
+
+impl FileListerFunction {
+    /// Wraps a [`FileLister`] so that it can be set on [`FileListingOptions`].
+    pub fn from_lister(lister: impl FileLister + 'static) -> Self {
+        Self::new(move |paths: Vec<PlSmallStr>| {
+            let paths: Vec<PlRefPath> = paths.iter().map(|x| PlRefPath::new(x.as_str())).collect();
+
+            Ok(lister
+                .list_files(&paths)?
+                .iter()
+                .map(|x| PlSmallStr::from_str(x.as_str()))
+                .collect())
+        })
+    }
+}
+
+/// Controls how the paths of a scan are expanded into the files to scan.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FileListingOptions {
+    /// Raise an error if the paths expand to more files than this.
+    pub max_files: Option<usize>,
+    /// Maximum number of paths that are listed concurrently on an object store.
+    pub listing_concurrency: Option<NonZeroUsize>,
+    pub file_name_filter: Option<FileNameFilterFunction>,
+    /// Used in place of expanding the paths. Globs are not expanded and the hidden file prefixes
+    /// are not applied to the listed files.
+    pub lister: Option<FileListerFunction>,
+}
+
+impl FileListingOptions {
+    /// Expands `paths` into the files to scan. Returns the files and the index at which to start
+    /// parsing hive partitions from the paths.
+    pub async fn expand_paths(
+        &self,
+        paths: &[PlRefPath],
+        glob: bool,
+        hidden_file_prefix: &[PlSmallStr],
+        cloud_options: &mut Option<CloudOptions>,
+        check_directory_level: bool,
+    ) -> PolarsResult<(Buffer<PlRefPath>, usize)> {
+        let (files, hive_start_idx) = if let Some(lister) = &self.lister {
+            let files = self.filter_file_names(lister.list_files(paths)?)?;
+            let hive_start_idx = listed_directory_len(paths, &files);
+
+            (files.into(), hive_start_idx)
+        } else {
+            let file_name_filter = self
+                .file_name_filter
+                .as_ref()
+                .map(|f| move |file_name: &str| f.call(file_name.into()));
+
+            expand_paths_hive(
+                paths,
+                glob,
+                hidden_file_prefix,
+                cloud_options,
+                check_directory_level,
+                ListingControls {
+                    listing_concurrency: self.listing_concurrency,
+                    file_name_filter: file_name_filter
+                        .as_ref()
+                        .map(|f| f as FileNameFilter),
+                },
+            )
+            .await?
+        };
+
+        if let Some(max_files) = self.max_files
+            && files.len() > max_files
+        {
+            polars_bail!(
+                ComputeError:
+                "scan paths expanded to {} files, which exceeds the maximum of {}",
+                files.len(), max_files
+            )
+        }
+
+        Ok((files, hive_start_idx))
+    }
+
+    fn filter_file_names(&self, files: Vec<PlRefPath>) -> PolarsResult<Vec<PlRefPath>> {
+        let Some(file_name_filter) = &self.file_name_filter else {
+            return Ok(files);
+        };
+
+        let mut out = Vec::with_capacity(files.len());
+
+        for file in files {
+            let keep = match file.file_name().and_then(|x| x.to_str()) {
+                Some(file_name) => file_name_filter.call(file_name.into())?,
+                None => true,
+            };
+
+            if keep {
+                out.push(file)
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+/// If a single directory was listed, returns the length of its path so that hive partitions are
+/// parsed after it. Otherwise returns 0.
+fn listed_directory_len(paths: &[PlRefPath], files: &[PlRefPath]) -> usize {
+    let [path] = paths else {
+        return 0;
+    };
+
+    let dir = path.as_str().trim_end_matches('/');
+
+    if files.iter().all(|x| {
+        x.as_str()
+            .strip_prefix(dir)
+            .is_some_and(|rest| rest.starts_with('/'))
+    }) {
+        dir.len()
+    } else {
+        0
+    }
+}
@@ -14,6 +14,7 @@ use polars_io::parquet::metadata::FileMetadataRef;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetOptions;
 use polars_io::{HiveOptions, RowIndex};
+use polars_utils::pl_path::PlRefPath;
 use polars_utils::slice_enum::Slice;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -21,8 +22,14 @@ use strum_macros::IntoStaticStr;
 
 use super::*;
 use crate::dsl::default_values::DefaultFieldValues;
+use crate::dsl::listing::FileListingOptions;
+use crate::dsl::schema_evolution::SchemaEvolutionPolicy;
 pub mod default_values;
 pub mod deletion;
+pub mod listing;
+pub mod schema_evolution;
+#[cfg(feature = "ipc")]
+pub mod statistics_sidecar;
 
 #[cfg(feature = "python")]
 pub mod python_dataset;
@@ -57,7 +64,7 @@ pub enum FileScanDsl {
 
     #[cfg(feature = "parquet")]
     Parquet {
-        options: ParquetOptions,
+        options: Arc<ParquetOptions>,
     },
 
     #[cfg(feature = "ipc")]
@@ -108,7 +115,7 @@ pub enum FileScanIR {
 
     #[cfg(feature = "parquet")]
     Parquet {
-        options: ParquetOptions,
+        options: Arc<ParquetOptions>,
         #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
         metadata: Option<FileMetadataRef>,
     },
@@ -266,6 +273,67 @@ pub enum ExtraColumnsPolicy {
     Ignore,
 }
 
+/// Names of columns describing where each row of a scan originates from. The file path column is
+/// configured separately through `include_file_paths`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ProvenanceColumns {
+    /// Position of the row within its file, excluding deleted rows.
+    pub row_index_in_file: Option<PlSmallStr>,
+    /// Index of the row group containing the row. Only supported for Parquet.
+    pub row_group_index: Option<PlSmallStr>,
+    /// Last modification time of the file.
+    pub file_modification_time: Option<PlSmallStr>,
+}
+
+impl ProvenanceColumns {
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Returns `true` if any of the columns depend on the position of the row in the file.
+    pub fn needs_row_position(&self) -> bool {
+        self.row_index_in_file.is_some() || self.row_group_index.is_some()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.iter().any(|(n, _)| n == name)
+    }
+
+    /// Iterates over the configured `(name, dtype)` pairs, in the order they are appended to the
+    /// schema.
+    pub fn iter(&self) -> impl Iterator<Item = (&PlSmallStr, DataType)> {
+        [
+            (self.row_index_in_file.as_ref(), IDX_DTYPE),
+            (self.row_group_index.as_ref(), IDX_DTYPE),
+            (
+                self.file_modification_time.as_ref(),
+                Self::file_modification_time_dtype(),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(name, dtype)| Some((name?, dtype)))
+    }
+
+    /// Removes the columns that are not in `schema`.
+    pub fn retain_in(&mut self, schema: &Schema) {
+        for name in [
+            &mut self.row_index_in_file,
+            &mut self.row_group_index,
+            &mut self.file_modification_time,
+        ] {
+            if name.as_ref().is_some_and(|x| !schema.contains(x)) {
+                *name = None;
+            }
+        }
+    }
+
+    pub fn file_modification_time_dtype() -> DataType {
+        DataType::Datetime(TimeUnit::Milliseconds, Some(TimeZone::UTC))
+    }
+}
+
 #[derive(Debug, Clone, Eq, Hash, PartialEq, strum_macros::IntoStaticStr)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -316,6 +384,9 @@ pub struct UnifiedScanArgs {
     pub glob: bool,
     /// Files with these prefixes will not be read.
     pub hidden_file_prefix: Option<Arc<[PlSmallStr]>>,
+    /// Controls for expanding the paths into the files to scan.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub file_listing: FileListingOptions,
 
     pub projection: Option<Arc<[PlSmallStr]>>,
     pub column_mapping: Option<ColumnMapping>,
@@ -329,9 +400,17 @@ pub struct UnifiedScanArgs {
     pub missing_columns_policy: MissingColumnsPolicy,
     pub extra_columns_policy: ExtraColumnsPolicy,
     pub include_file_paths: Option<PlSmallStr>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub provenance: ProvenanceColumns,
+    /// Renames, type widening and default values for files written under older schemas.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub schema_evolution: Option<Arc<SchemaEvolutionPolicy>>,
 
     pub deletion_files: Option<DeletionFilesList>,
     pub table_statistics: Option<TableStatistics>,
+    /// Statistics sidecar file to load the `table_statistics` from if they are not given.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub statistics_sidecar: Option<PlRefPath>,
     /// Stores (physical, deleted) row counts of the table if known upfront (e.g. for Iceberg).
     /// This allows for row-count queries to succeed without scanning all files.
     ///
@@ -366,6 +445,7 @@ impl Default for UnifiedScanArgs {
             cache: false,
             glob: true,
             hidden_file_prefix: None,
+            file_listing: FileListingOptions::default(),
             projection: None,
             column_mapping: None,
             default_values: None,
@@ -375,8 +455,11 @@ impl Default for UnifiedScanArgs {
             missing_columns_policy: MissingColumnsPolicy::default(),
             extra_columns_policy: ExtraColumnsPolicy::default(),
             include_file_paths: None,
+            provenance: ProvenanceColumns::default(),
+            schema_evolution: None,
             deletion_files: None,
             table_statistics: None,
+            statistics_sidecar: None,
             row_count: None,
         }
     }
@@ -0,0 +1,187 @@
+use polars_core::chunked_array::cast::CastOptions;
+use polars_core::prelude::*;
+use polars_core::scalar::Scalar;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::CastColumnsPolicy;
+
+/// Describes how the schema of a collection of files may have changed over time, so that files
+/// written under older schemas can be scanned together with newer ones.
+///
+/// The policy is resolved against the target schema (either given or inferred from the first
+/// file) during IR conversion, and applied per-file when the file schema is known.
+#[derive(Debug, Clone, Default, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct SchemaEvolutionPolicy {
+    /// `(former_name, current_name)`. A file column under a former name is read as the current
+    /// name if the file does not contain the current name.
+    pub renames: Vec<(PlSmallStr, PlSmallStr)>,
+    pub type_widening: TypeWidening,
+    /// Target dtypes that override the dtypes of the given / inferred schema.
+    pub dtypes: Vec<(PlSmallStr, DataType)>,
+    /// Values used for columns that are missing from a file. Columns that are not in the schema
+    /// are appended to it.
+    pub missing_defaults: Vec<(PlSmallStr, Scalar)>,
+}
+
+impl Eq for SchemaEvolutionPolicy {}
+
+/// Lossless casts that are allowed from the file dtype to the target dtype.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct TypeWidening {
+    pub integer: bool,
+    pub integer_to_float: bool,
+    pub float: bool,
+    pub categorical_to_string: bool,
+}
+
+impl TypeWidening {
+    pub const ALL: Self = Self {
+        integer: true,
+        integer_to_float: true,
+        float: true,
+        categorical_to_string: true,
+    };
+
+    /// Enables the widening casts on `policy`. Casts that are already allowed stay allowed.
+    pub fn apply_to(&self, policy: &mut CastColumnsPolicy) {
+        policy.integer_upcast |= self.integer;
+        policy.integer_to_float_cast |= self.integer_to_float;
+        policy.float_upcast |= self.float;
+        policy.categorical_to_string |= self.categorical_to_string;
+    }
+}
+
+impl SchemaEvolutionPolicy {
+    pub fn is_empty(&self) -> bool {
+        self.renames.is_empty()
+            && self.type_widening == TypeWidening::default()
+            && self.dtypes.is_empty()
+            && self.missing_defaults.is_empty()
+    }
+
+    /// Former names of `current_name`, in the order they were given.
+    pub fn former_names<'a>(
+        &'a self,
+        current_name: &'a str,
+    ) -> impl Iterator<Item = &'a PlSmallStr> + 'a {
+        self.renames
+            .iter()
+            .filter(move |(_, current)| current == current_name)
+            .map(|(former, _)| former)
+    }
+
+    /// Returns the current name of `name` if it is a former name.
+    pub fn current_name(&self, name: &str) -> Option<&PlSmallStr> {
+        self.renames
+            .iter()
+            .find_map(|(former, current)| (former == name).then_some(current))
+    }
+
+    pub fn missing_default(&self, name: &str) -> Option<&Scalar> {
+        self.missing_defaults
+            .iter()
+            .find_map(|(n, v)| (n == name).then_some(v))
+    }
+
+    /// Renames former column names in `schema` to their current names. Former names whose
+    /// current name is already present are left as-is.
+    pub fn rename_schema(&self, schema: &mut Schema) {
+        for (former, current) in self.renames.iter() {
+            if schema.contains(current) {
+                continue;
+            }
+
+            schema.rename(former, current.clone());
+        }
+    }
+
+    /// Resolves the policy against the target `schema`:
+    /// * Applies the dtype overrides.
+    /// * Appends columns with a default value that are not in the schema.
+    /// * Casts the default values to the dtype of their column.
+    pub fn resolve(&mut self, schema: &mut Schema) -> PolarsResult<()> {
+        for (name, dtype) in self.dtypes.iter() {
+            if let Some(existing) = schema.get_mut(name) {
+                *existing = dtype.clone();
+            } else {
+                schema.insert_at_index(schema.len(), name.clone(), dtype.clone())?;
+            }
+        }
+
+        for (name, value) in self.missing_defaults.iter_mut() {
+            match schema.get(name) {
+                Some(dtype) if dtype != value.dtype() => {
+                    *value = std::mem::replace(value, Scalar::null(DataType::Null))
+                        .cast_with_options(dtype, CastOptions::Strict)
+                        .map_err(|e| {
+                            e.wrap_msg(|msg| {
+                                format!("invalid default value for column '{name}': {msg}")
+                            })
+                        })?;
+                },
+                Some(_) => {},
+                None => {
+                    schema.insert_at_index(schema.len(), name.clone(), value.dtype().clone())?;
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_schema_evolution() {
+        let mut policy = SchemaEvolutionPolicy {
+            renames: vec![("a_old".into(), "a".into()), ("b_old".into(), "b".into())],
+            type_widening: TypeWidening::ALL,
+            dtypes: vec![("c".into(), DataType::Int64)],
+            missing_defaults: vec![
+                ("c".into(), Scalar::from(1i32)),
+                ("d".into(), Scalar::from(true)),
+            ],
+        };
+
+        let mut schema = Schema::from_iter([
+            Field::new("a_old".into(), DataType::Int32),
+            Field::new("b".into(), DataType::String),
+            Field::new("b_old".into(), DataType::String),
+            Field::new("c".into(), DataType::Int32),
+        ]);
+
+        policy.rename_schema(&mut schema);
+        policy.resolve(&mut schema).unwrap();
+
+        assert_eq!(
+            schema,
+            Schema::from_iter([
+                Field::new("a".into(), DataType::Int32),
+                Field::new("b".into(), DataType::String),
+                Field::new("b_old".into(), DataType::String),
+                Field::new("c".into(), DataType::Int64),
+                Field::new("d".into(), DataType::Boolean),
+            ])
+        );
+        assert_eq!(
+            policy.missing_default("c").unwrap().dtype(),
+            &DataType::Int64
+        );
+        assert_eq!(
+            policy.former_names("a").collect::<Vec<_>>(),
+            [&PlSmallStr::from("a_old")]
+        );
+        assert_eq!(policy.current_name("b_old").unwrap(), "b");
+
+        let mut cast_policy = CastColumnsPolicy::ERROR_ON_MISMATCH;
+        policy.type_widening.apply_to(&mut cast_policy);
+        assert!(cast_policy.integer_upcast && !cast_policy.float_downcast);
+    }
+}
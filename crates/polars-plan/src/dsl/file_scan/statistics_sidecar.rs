@@ -0,0 +1,130 @@
+//! Statistics sidecar files.
+//!
+//! A statistics sidecar is an IPC file holding a row of statistics for every file of a dataset.
+//! Multi-file scans consult it to skip files based on the predicate and to estimate the number of
+//! rows they produce, which is useful for formats that do not store statistics themselves (e.g.
+//! CSV / NDJSON). The streaming engine uses these estimates to choose the build side of joins.
+//!
+//! The sidecar has the columns:
+//! * `path`: Path of the file, relative to the directory of the sidecar if the file is under it.
+//! * `len`: Number of rows in the file.
+//! * `{col}_min` / `{col}_max`: Minimum / maximum value of the column.
+//! * `{col}_nc`: Null count of the column.
+//! * `{col}_ndv`: Number of distinct non-null values of the column.
+use std::io::Cursor;
+
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::prelude::{IpcReader, SerReader};
+use polars_io::utils::byte_source::{ByteSource, DynByteSourceBuilder};
+use polars_utils::format_pl_smallstr;
+use polars_utils::pl_path::PlRefPath;
+
+use super::TableStatistics;
+
+/// Conventional file name of a statistics sidecar, placed in the directory of the dataset.
+pub const STATISTICS_SIDECAR_FILE_NAME: &str = ".polars_stats";
+
+/// Name of the column holding the file paths in a statistics sidecar.
+pub const STATISTICS_SIDECAR_PATH_COLUMN: &str = "path";
+
+/// Returns the key under which the statistics of `path` are stored in the sidecar at
+/// `sidecar_path`. This is the path relative to the directory of the sidecar if `path` is under
+/// it, otherwise the absolute path.
+pub fn statistics_sidecar_key(
+    path: &PlRefPath,
+    sidecar_path: &PlRefPath,
+) -> PolarsResult<PlSmallStr> {
+    let sidecar_path = sidecar_path.to_absolute_path()?;
+    let path = path.to_absolute_path()?;
+
+    let key = sidecar_path
+        .as_str()
+        .rsplit_once('/')
+        .and_then(|(dir, _)| path.as_str().strip_prefix(dir)?.strip_prefix('/'))
+        .unwrap_or(path.as_str());
+
+    Ok(PlSmallStr::from_str(key))
+}
+
+/// Reads the statistics sidecar at `sidecar_path` into [`TableStatistics`] for the files in
+/// `paths`.
+///
+/// Statistics are cast to their dtype in `schema`. They are set to NULL for files or columns that
+/// are not in the sidecar, such files are never skipped.
+pub async fn load_statistics_sidecar(
+    sidecar_path: &PlRefPath,
+    paths: &[PlRefPath],
+    schema: &Schema,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<TableStatistics> {
+    let builder = if sidecar_path.has_scheme() {
+        DynByteSourceBuilder::ObjectStore
+    } else {
+        DynByteSourceBuilder::Mmap
+    };
+
+    let byte_source = builder
+        .try_build_from_path(sidecar_path.clone(), cloud_options, None)
+        .await?;
+    let bytes = byte_source
+        .get_range(0..byte_source.get_size().await?)
+        .await?;
+
+    let sidecar = IpcReader::new(Cursor::new(bytes)).finish()?;
+
+    let row_idx: PlHashMap<&str, IdxSize> = sidecar
+        .column(STATISTICS_SIDECAR_PATH_COLUMN)?
+        .str()?
+        .iter()
+        .enumerate()
+        .filter_map(|(i, path)| Some((path?, IdxSize::try_from(i).unwrap())))
+        .collect();
+
+    let take_idx = paths
+        .iter()
+        .map(|path| {
+            let key = statistics_sidecar_key(path, sidecar_path)?;
+            Ok(row_idx.get(key.as_str()).copied())
+        })
+        .collect::<PolarsResult<IdxCa>>()?;
+
+    let sidecar = if take_idx.null_count() == take_idx.len() {
+        DataFrame::full_null(sidecar.schema(), take_idx.len())
+    } else {
+        sidecar.take(&take_idx)?
+    };
+    let height = sidecar.height();
+
+    let get_column = |name: &str, dtype: &DataType| -> PolarsResult<Column> {
+        let column = match sidecar.column(name) {
+            Ok(column) => column.cast(dtype)?,
+            Err(_) => Column::full_null(PlSmallStr::EMPTY, height, dtype),
+        };
+
+        Ok(column.with_name(PlSmallStr::from_str(name)))
+    };
+
+    let mut columns = Vec::with_capacity(1 + schema.len() * 4);
+    columns.push(get_column("len", &IDX_DTYPE)?);
+
+    for (name, dtype) in schema.iter() {
+        columns.extend([
+            get_column(&format_pl_smallstr!("{name}_min"), dtype)?,
+            get_column(&format_pl_smallstr!("{name}_max"), dtype)?,
+            get_column(&format_pl_smallstr!("{name}_nc"), &IDX_DTYPE)?,
+            get_column(&format_pl_smallstr!("{name}_ndv"), &IDX_DTYPE)?,
+        ]);
+    }
+
+    Ok(TableStatistics(Arc::new(DataFrame::new(height, columns)?)))
+}
+
+impl TableStatistics {
+    /// Total number of rows of the files, if known for all of them.
+    pub fn num_rows(&self) -> Option<usize> {
+        let len = self.0.column("len").ok()?.idx().ok()?;
+
+        (len.null_count() == 0).then(|| len.iter().map(|x| x.unwrap() as usize).sum())
+    }
+}
@@ -65,6 +65,7 @@ impl fmt::Debug for Expr {
             },
             DataTypeFunction(dtype_fn) => fmt::Debug::fmt(dtype_fn, f),
             Len => write!(f, "len()"),
+            Parameter(name) => write!(f, "param(\"{name}\")"),
             Explode {
                 input: expr,
                 options,
@@ -203,10 +204,14 @@ impl fmt::Debug for Expr {
                 predicate,
                 truthy,
                 falsy,
-            } => write!(
-                f,
-                ".when({predicate:?}).then({truthy:?}).otherwise({falsy:?})",
-            ),
+                lazy,
+            } => {
+                let when = if *lazy { "when_lazy" } else { "when" };
+                write!(
+                    f,
+                    ".{when}({predicate:?}).then({truthy:?}).otherwise({falsy:?})",
+                )
+            },
             Function { input, function } => {
                 #[cfg(feature = "dtype-struct")]
                 if matches!(function, FunctionExpr::AsStruct) {
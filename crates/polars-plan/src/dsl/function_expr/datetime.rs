@@ -55,7 +55,10 @@ pub enum TemporalFunction {
     TotalNanoseconds {
         fractional: bool,
     },
-    ToString(String),
+    ToString {
+        format: String,
+        locale: Option<PlSmallStr>,
+    },
     CastTimeUnit(TimeUnit),
     WithTimeUnit(TimeUnit),
     #[cfg(feature = "timezones")]
@@ -124,7 +127,7 @@ impl Display for TemporalFunction {
             TotalMicroseconds { .. } => "total_microseconds",
             #[cfg(feature = "dtype-duration")]
             TotalNanoseconds { .. } => "total_nanoseconds",
-            ToString(_) => "to_string",
+            ToString { .. } => "to_string",
             #[cfg(feature = "timezones")]
             ConvertTimeZone(_) => "convert_time_zone",
             CastTimeUnit(_) => "cast_time_unit",
@@ -24,6 +24,8 @@ mod range;
 mod rolling;
 #[cfg(feature = "rolling_window_by")]
 mod rolling_by;
+#[cfg(feature = "scan_fn")]
+mod scan;
 #[cfg(feature = "strings")]
 mod strings;
 #[cfg(feature = "dtype-struct")]
@@ -36,6 +38,7 @@ use std::hash::{Hash, Hasher};
 
 #[cfg(feature = "dtype-array")]
 pub use array::ArrayFunction;
+use arrow::datatypes::Metadata;
 #[cfg(feature = "cov")]
 pub use correlation::CorrelationMethod;
 pub use list::ListFunction;
@@ -65,6 +68,8 @@ pub use self::range::{DateRangeArgs, RangeFunction};
 pub use self::rolling::RollingFunction;
 #[cfg(feature = "rolling_window_by")]
 pub use self::rolling_by::RollingFunctionBy;
+#[cfg(feature = "scan_fn")]
+pub use self::scan::ScanFnStep;
 #[cfg(feature = "strings")]
 pub use self::strings::StringFunction;
 #[cfg(feature = "dtype-struct")]
@@ -99,6 +104,8 @@ pub enum FunctionExpr {
     Boolean(BooleanFunction),
     #[cfg(feature = "business")]
     Business(BusinessFunction),
+    #[cfg(feature = "interval")]
+    Interval(IntervalFunction),
     #[cfg(feature = "abs")]
     Abs,
     Negate,
@@ -121,6 +128,12 @@ pub enum FunctionExpr {
         side: SearchSortedSide,
         descending: bool,
     },
+    #[cfg(feature = "search_sorted")]
+    SearchSortedBy {
+        side: SearchSortedSide,
+        descending: Vec<bool>,
+        nulls_last: Vec<bool>,
+    },
     #[cfg(feature = "range")]
     Range(RangeFunction),
     #[cfg(feature = "trigonometry")]
@@ -279,6 +292,7 @@ pub enum FunctionExpr {
         labels: Option<Vec<PlSmallStr>>,
         left_closed: bool,
         include_breaks: bool,
+        include_bounds: bool,
     },
     #[cfg(feature = "cutqcut")]
     QCut {
@@ -287,18 +301,59 @@ pub enum FunctionExpr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        include_bounds: bool,
     },
     #[cfg(feature = "rle")]
     RLE,
     #[cfg(feature = "rle")]
     RLEID,
+    #[cfg(feature = "to_dummies")]
+    ToDummies {
+        categories: Option<Vec<PlSmallStr>>,
+        separator: PlSmallStr,
+        drop_first: bool,
+    },
+    #[cfg(feature = "fft")]
+    Fft,
+    #[cfg(feature = "fft")]
+    PowerSpectrum {
+        window: usize,
+    },
+    #[cfg(feature = "convolve")]
+    Convolve {
+        kernel: Vec<f64>,
+        mode: ConvolveMode,
+        nulls: ConvolveNulls,
+    },
+    #[cfg(feature = "convolve")]
+    Savgol {
+        window: usize,
+        polyorder: usize,
+        nulls: ConvolveNulls,
+    },
+    #[cfg(feature = "anonymize")]
+    BucketizeNumeric {
+        k_anonymity: usize,
+    },
     ToPhysical,
     #[cfg(feature = "random")]
     Random {
         method: random::RandomMethod,
         seed: Option<u64>,
     },
+    /// Add Laplace noise with scale `sensitivity / epsilon`, see [`Expr::dp_noise`].
+    #[cfg(feature = "random")]
+    DpNoise {
+        epsilon: f64,
+        sensitivity: f64,
+    },
     SetSortedFlag(AExprSorted),
+    /// Replace the user metadata of the output field.
+    WithFieldMetadata(Arc<Metadata>),
+    /// Set the unit in the user metadata of the output field.
+    SetUnit(PlSmallStr),
+    /// Convert the values to another unit, see [`crate::plans::units`].
+    ConvertUnit(PlSmallStr),
     #[cfg(feature = "ffi_plugin")]
     /// Creating this node is unsafe
     /// This will lead to calls over FFI.
@@ -344,6 +399,11 @@ pub enum FunctionExpr {
     MeanHorizontal {
         ignore_nulls: bool,
     },
+    #[cfg(feature = "scan_fn")]
+    ScanFn {
+        step: ScanFnStep,
+        return_dtype: Option<DataTypeExpr>,
+    },
     #[cfg(feature = "ewma")]
     EwmMean {
         options: EWMOptions,
@@ -406,6 +466,8 @@ impl Hash for FunctionExpr {
             Boolean(f) => f.hash(state),
             #[cfg(feature = "business")]
             Business(f) => f.hash(state),
+            #[cfg(feature = "interval")]
+            Interval(f) => f.hash(state),
             Pow(f) => f.hash(state),
             #[cfg(feature = "index_of")]
             IndexOf => {},
@@ -414,8 +476,26 @@ impl Hash for FunctionExpr {
                 side.hash(state);
                 descending.hash(state);
             },
+            #[cfg(feature = "search_sorted")]
+            SearchSortedBy {
+                side,
+                descending,
+                nulls_last,
+            } => {
+                side.hash(state);
+                descending.hash(state);
+                nulls_last.hash(state);
+            },
             #[cfg(feature = "random")]
             Random { method, .. } => method.hash(state),
+            #[cfg(feature = "random")]
+            DpNoise {
+                epsilon,
+                sensitivity,
+            } => {
+                epsilon.to_bits().hash(state);
+                sensitivity.to_bits().hash(state);
+            },
             #[cfg(feature = "cov")]
             Correlation { method, .. } => method.hash(state),
             #[cfg(feature = "range")]
@@ -606,12 +686,14 @@ impl Hash for FunctionExpr {
                 labels,
                 left_closed,
                 include_breaks,
+                include_bounds,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(breaks);
                 slice.hash(state);
                 labels.hash(state);
                 left_closed.hash(state);
                 include_breaks.hash(state);
+                include_bounds.hash(state);
             },
             #[cfg(feature = "dtype-array")]
             Reshape(dims) => dims.hash(state),
@@ -624,6 +706,7 @@ impl Hash for FunctionExpr {
                 left_closed,
                 allow_duplicates,
                 include_breaks,
+                include_bounds,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(probs);
                 slice.hash(state);
@@ -631,13 +714,58 @@ impl Hash for FunctionExpr {
                 left_closed.hash(state);
                 allow_duplicates.hash(state);
                 include_breaks.hash(state);
+                include_bounds.hash(state);
             },
             #[cfg(feature = "rle")]
             RLE => {},
             #[cfg(feature = "rle")]
             RLEID => {},
+            #[cfg(feature = "to_dummies")]
+            ToDummies {
+                categories,
+                separator,
+                drop_first,
+            } => {
+                categories.hash(state);
+                separator.hash(state);
+                drop_first.hash(state);
+            },
+            #[cfg(feature = "fft")]
+            Fft => {},
+            #[cfg(feature = "fft")]
+            PowerSpectrum { window } => window.hash(state),
+            #[cfg(feature = "convolve")]
+            Convolve {
+                kernel,
+                mode,
+                nulls,
+            } => {
+                let slice = bytemuck::cast_slice::<_, u64>(kernel);
+                slice.hash(state);
+                mode.hash(state);
+                nulls.hash(state);
+            },
+            #[cfg(feature = "convolve")]
+            Savgol {
+                window,
+                polyorder,
+                nulls,
+            } => {
+                window.hash(state);
+                polyorder.hash(state);
+                nulls.hash(state);
+            },
+            #[cfg(feature = "anonymize")]
+            BucketizeNumeric { k_anonymity } => k_anonymity.hash(state),
             ToPhysical => {},
             SetSortedFlag(is_sorted) => is_sorted.hash(state),
+            WithFieldMetadata(metadata) => metadata.hash(state),
+            SetUnit(unit) | ConvertUnit(unit) => unit.hash(state),
+            #[cfg(feature = "scan_fn")]
+            ScanFn { step, return_dtype } => {
+                step.hash(state);
+                return_dtype.hash(state);
+            },
             #[cfg(feature = "ewma")]
             EwmMean { options } => options.hash(state),
             #[cfg(feature = "ewma_by")]
@@ -707,6 +835,8 @@ impl Display for FunctionExpr {
             Boolean(func) => return write!(f, "{func}"),
             #[cfg(feature = "business")]
             Business(func) => return write!(f, "{func}"),
+            #[cfg(feature = "interval")]
+            Interval(func) => return write!(f, "{func}"),
             #[cfg(feature = "abs")]
             Abs => "abs",
             Negate => "negate",
@@ -720,6 +850,8 @@ impl Display for FunctionExpr {
             IndexOf => "index_of",
             #[cfg(feature = "search_sorted")]
             SearchSorted { .. } => "search_sorted",
+            #[cfg(feature = "search_sorted")]
+            SearchSortedBy { .. } => "search_sorted_by",
             #[cfg(feature = "range")]
             Range(func) => return write!(f, "{func}"),
             #[cfg(feature = "trigonometry")]
@@ -852,10 +984,27 @@ impl Display for FunctionExpr {
             RLE => "rle",
             #[cfg(feature = "rle")]
             RLEID => "rle_id",
+            #[cfg(feature = "to_dummies")]
+            ToDummies { .. } => "to_dummies",
+            #[cfg(feature = "fft")]
+            Fft => "fft",
+            #[cfg(feature = "fft")]
+            PowerSpectrum { .. } => "power_spectrum",
+            #[cfg(feature = "convolve")]
+            Convolve { .. } => "convolve",
+            #[cfg(feature = "convolve")]
+            Savgol { .. } => "savgol",
+            #[cfg(feature = "anonymize")]
+            BucketizeNumeric { .. } => "bucketize_numeric",
             ToPhysical => "to_physical",
             #[cfg(feature = "random")]
             Random { method, .. } => method.into(),
+            #[cfg(feature = "random")]
+            DpNoise { .. } => "dp_noise",
             SetSortedFlag(_) => "set_sorted",
+            WithFieldMetadata(_) => "with_field_metadata",
+            SetUnit(_) => "set_unit",
+            ConvertUnit(_) => "convert_unit",
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin { lib, symbol, .. } => return write!(f, "{lib}:{symbol}"),
             FoldHorizontal { .. } => "fold",
@@ -868,6 +1017,8 @@ impl Display for FunctionExpr {
             MinHorizontal => "min_horizontal",
             SumHorizontal { .. } => "sum_horizontal",
             MeanHorizontal { .. } => "mean_horizontal",
+            #[cfg(feature = "scan_fn")]
+            ScanFn { .. } => "scan_fn",
             #[cfg(feature = "ewma")]
             EwmMean { .. } => "ewm_mean",
             #[cfg(feature = "ewma_by")]
@@ -0,0 +1,15 @@
+use super::*;
+
+/// The step function of a `scan_fn` expression.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Debug, Hash)]
+pub enum ScanFnStep {
+    /// A step registered with [`polars_ops::series::register_scan_fn`] under `name`, with the
+    /// state starting at `init`.
+    ///
+    /// Only the name is serialized, so the step must be registered wherever the plan runs.
+    Registered { name: PlSmallStr, init: Box<Scalar> },
+    /// Scans a whole series at once. Steps defined in Python run the kernel through this.
+    Series(PlanCallback<Series, Series>),
+}
@@ -48,6 +48,16 @@ pub enum StringFunction {
     LenBytes,
     LenChars,
     Lowercase,
+    #[cfg(feature = "anonymize")]
+    MaskEmail,
+    #[cfg(feature = "anonymize")]
+    MaskDigits {
+        keep_last: usize,
+    },
+    #[cfg(feature = "collation")]
+    CollationKey(CollationOptions),
+    #[cfg(feature = "natural_sort")]
+    NaturalSortKey,
     #[cfg(feature = "extract_jsonpath")]
     JsonDecode(DataTypeExpr),
     #[cfg(feature = "extract_jsonpath")]
@@ -63,6 +73,9 @@ pub enum StringFunction {
     Normalize {
         form: UnicodeForm,
     },
+    #[cfg(feature = "string_normalize")]
+    RemoveDiacritics,
+    IsAscii,
     #[cfg(feature = "string_reverse")]
     Reverse,
     #[cfg(feature = "string_pad")]
@@ -99,6 +112,11 @@ pub enum StringFunction {
     SplitN(usize),
     #[cfg(feature = "temporal")]
     Strptime(DataTypeExpr, StrptimeOptions),
+    #[cfg(feature = "dtype-duration")]
+    ToDuration {
+        time_unit: TimeUnit,
+        strict: bool,
+    },
     Split(bool),
     #[cfg(feature = "regex")]
     SplitRegex {
@@ -168,6 +186,14 @@ impl Display for StringFunction {
             JsonPathMatch => "json_path_match",
             LenBytes => "len_bytes",
             Lowercase => "to_lowercase",
+            #[cfg(feature = "anonymize")]
+            MaskEmail => "mask_email",
+            #[cfg(feature = "anonymize")]
+            MaskDigits { .. } => "mask_digits",
+            #[cfg(feature = "collation")]
+            CollationKey(_) => "collation_key",
+            #[cfg(feature = "natural_sort")]
+            NaturalSortKey => "natural_sort_key",
             LenChars => "len_chars",
             #[cfg(feature = "string_pad")]
             PadEnd { .. } => "pad_end",
@@ -177,6 +203,9 @@ impl Display for StringFunction {
             Replace { .. } => "replace",
             #[cfg(feature = "string_normalize")]
             Normalize { .. } => "normalize",
+            #[cfg(feature = "string_normalize")]
+            RemoveDiacritics => "remove_diacritics",
+            IsAscii => "is_ascii",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
             #[cfg(feature = "string_encoding")]
@@ -206,6 +235,8 @@ impl Display for StringFunction {
             SplitN(_) => "splitn",
             #[cfg(feature = "temporal")]
             Strptime(_, _) => "strptime",
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { .. } => "to_duration",
             Split(inclusive) => {
                 if *inclusive {
                     "split_inclusive"
@@ -175,3 +175,11 @@ pub fn mean_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> Polars
 pub fn coalesce(exprs: &[Expr]) -> Expr {
     Expr::n_ary(FunctionExpr::Coalesce, exprs.to_vec())
 }
+
+/// Keep the first non-null value of every row, over the columns matched by `selector`.
+///
+/// The columns are coalesced in the order in which `selector` matches them. Later columns are
+/// only evaluated as long as null values remain.
+pub fn first_non_null_horizontal(selector: Selector) -> Expr {
+    coalesce(&[Expr::Selector(selector)])
+}
@@ -14,6 +14,37 @@ pub fn arg_sort_by<E: AsRef<[Expr]>>(by: E, sort_options: SortMultipleOptions) -
         .alias(name)
 }
 
+#[cfg(feature = "search_sorted")]
+/// Find the indices where the rows of `search_values` have to be inserted into the rows of `by` to
+/// maintain the order, for a frame that is sorted by all expressions in `by`.
+///
+/// `descending` and `nulls_last` hold one value per expression in `by`, or a single value that is
+/// used for all of them, and have to match how the frame was sorted.
+pub fn search_sorted_by<E: AsRef<[Expr]>, V: AsRef<[Expr]>>(
+    by: E,
+    search_values: V,
+    side: SearchSortedSide,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+) -> PolarsResult<Expr> {
+    let by = by.as_ref();
+    let search_values = search_values.as_ref();
+    polars_ensure!(
+        !by.is_empty() && by.len() == search_values.len(),
+        InvalidOperation: "`search_sorted_by` expected the same (non-zero) number of sorted and search expressions, got {} and {}",
+        by.len(), search_values.len()
+    );
+
+    Ok(Expr::Function {
+        input: by.iter().chain(search_values).cloned().collect(),
+        function: FunctionExpr::SearchSortedBy {
+            side,
+            descending,
+            nulls_last,
+        },
+    })
+}
+
 #[cfg(feature = "arg_where")]
 /// Get the indices where `condition` evaluates `true`.
 pub fn arg_where<E: Into<Expr>>(condition: E) -> Expr {
@@ -0,0 +1,13 @@
+use super::*;
+
+/// Create half-open intervals `[start, end)` from the `start` and `end` bounds.
+///
+/// The intervals are structs with a `start` and an `end` field, cast to the supertype of the
+/// bounds. Raises if a `start` is after its `end`, and the interval is null if either bound is
+/// null.
+pub fn interval(start: Expr, end: Expr) -> Expr {
+    Expr::Function {
+        input: vec![start, end],
+        function: FunctionExpr::Interval(IntervalFunction::New),
+    }
+}
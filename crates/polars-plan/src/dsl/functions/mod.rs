@@ -11,6 +11,8 @@ mod correlation;
 pub(crate) mod horizontal;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 mod index;
+#[cfg(feature = "interval")]
+mod interval;
 #[cfg(feature = "range")]
 mod range;
 mod repeat;
@@ -28,13 +30,15 @@ pub use concat::*;
 #[cfg(feature = "cov")]
 pub use correlation::*;
 pub use horizontal::{
-    all_horizontal, any_horizontal, coalesce, fold_exprs, max_horizontal, mean_horizontal,
-    min_horizontal, reduce_exprs, sum_horizontal,
+    all_horizontal, any_horizontal, coalesce, first_non_null_horizontal, fold_exprs,
+    max_horizontal, mean_horizontal, min_horizontal, reduce_exprs, sum_horizontal,
 };
 #[cfg(feature = "dtype-struct")]
 pub use horizontal::{cum_fold_exprs, cum_reduce_exprs};
 #[cfg(any(feature = "range", feature = "arg_where"))]
 pub use index::*;
+#[cfg(feature = "interval")]
+pub use interval::interval;
 #[cfg(all(
     feature = "range",
     any(feature = "dtype-date", feature = "dtype-datetime")
@@ -59,6 +59,15 @@ where
     by_name(names, true, true)
 }
 
+/// Select the columns matched by `selector`, e.g. by dtype with [`Selector::by_dtype`] or by
+/// name with [`Selector::by_name_regex`].
+///
+/// The selector is resolved against the schema when the query is planned, so this can be used
+/// wherever a column can, such as in `select`, `with_columns` and `group_by`.
+pub fn cols_matching(selector: impl Into<Selector>) -> Expr {
+    Expr::Selector(selector.into())
+}
+
 /// Select multiple columns by dtype.
 pub fn dtype_col(dtype: &DataType) -> DataTypeSelector {
     DataTypeSelector::AnyOf([dtype.clone()].into())
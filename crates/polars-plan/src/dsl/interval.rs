@@ -0,0 +1,31 @@
+use super::*;
+
+/// Specialized expressions for intervals, see [`interval`](functions::interval).
+pub struct IntervalNameSpace(pub(super) Expr);
+
+impl IntervalNameSpace {
+    /// Whether the intervals overlap the intervals of `other`.
+    pub fn overlaps(self, other: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::Interval(IntervalFunction::Overlaps), other)
+    }
+
+    /// Whether the intervals contain `other`, which holds values or intervals.
+    pub fn contains(self, other: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::Interval(IntervalFunction::Contains), other)
+    }
+
+    /// The overlapping part of the intervals and the intervals of `other`, null if they don't
+    /// overlap.
+    pub fn intersect(self, other: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::Interval(IntervalFunction::Intersect), other)
+    }
+
+    /// Merge the overlapping and adjacent intervals into sorted, disjoint intervals.
+    pub fn union_merge(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::Interval(IntervalFunction::UnionMerge))
+    }
+}
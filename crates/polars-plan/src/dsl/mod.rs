@@ -7,10 +7,12 @@ pub use cat::*;
 #[cfg(feature = "rolling_window_by")]
 pub(crate) use polars_time::prelude::*;
 
+mod access_policy;
 mod arithmetic;
 mod arity;
 #[cfg(feature = "dtype-array")]
 mod array;
+mod batch_udf;
 pub mod binary;
 #[cfg(feature = "bitwise")]
 mod bitwise;
@@ -19,6 +21,7 @@ pub use builder_dsl::*;
 mod datatype_expr;
 #[cfg(feature = "temporal")]
 pub mod dt;
+mod encode;
 mod expr;
 #[cfg(feature = "dtype-extension")]
 mod extension;
@@ -26,16 +29,22 @@ mod format;
 mod from;
 pub mod function_expr;
 pub mod functions;
+#[cfg(feature = "interval")]
+pub mod interval;
 mod list;
 mod match_to_schema;
 #[cfg(feature = "meta")]
 mod meta;
 mod name;
 mod options;
+mod parameters;
+#[cfg(feature = "random")]
+mod privacy;
 #[cfg(feature = "python")]
 pub mod python_dsl;
 #[cfg(feature = "random")]
 mod random;
+mod scale;
 mod scan_sources;
 mod selector;
 #[cfg(feature = "serde")]
@@ -52,9 +61,12 @@ use std::sync::Arc;
 
 mod iter;
 mod plan;
+pub use access_policy::{AccessPolicy, Principal};
 pub use arity::*;
 #[cfg(feature = "dtype-array")]
 pub use array::*;
+use arrow::datatypes::Metadata;
+pub use batch_udf::{BatchUdf, BatchUdfProperties, map_batch_udf};
 pub use datatype_expr::DataTypeExpr;
 pub use expr::*;
 #[cfg(feature = "dtype-extension")]
@@ -66,6 +78,7 @@ pub use match_to_schema::*;
 pub use meta::*;
 pub use name::*;
 pub use options::*;
+pub use parameters::param;
 pub use plan::*;
 use polars_compute::rolling::QuantileMethod;
 use polars_core::chunked_array::cast::CastOptions;
@@ -1032,6 +1045,26 @@ impl Expr {
         }
     }
 
+    /// Check if the values of this expression are in the first column of another query, e.g. a
+    /// `LazyFrame`.
+    ///
+    /// When used as a filter predicate on a column, the query is planned as a semi join, or as an
+    /// anti join if the predicate is negated, unless it is estimated to have few rows. Otherwise
+    /// the other query is evaluated once and probed as a set.
+    #[allow(clippy::wrong_self_convention)]
+    #[cfg(feature = "is_in")]
+    pub fn is_in_lazy<P: Into<DslPlan>>(self, other: P, nulls_equal: bool) -> Self {
+        let name = polars_utils::pl_str::unique_column_name();
+        let other = Expr::SubPlan(
+            SpecialEq::new(Arc::new(other.into())),
+            vec![(
+                name.clone(),
+                functions::first().as_expr().implode(true).alias(name),
+            )],
+        );
+        self.is_in(other, nulls_equal)
+    }
+
     /// Sort this column by the ordering of another column evaluated from given expr.
     /// Can also be used in a group_by context to sort the groups.
     ///
@@ -1319,6 +1352,55 @@ impl Expr {
         self.finish_rolling(options, RollingFunction::Map(f))
     }
 
+    #[cfg(feature = "rolling_window")]
+    /// Apply a [`RollingReducer`] over a rolling/ moving window of the values cast to `Float64`.
+    /// Unlike [`Expr::rolling_map`], the reducer is updated as values enter and leave the window
+    /// instead of being called on every window.
+    pub fn rolling_map_reducer<R>(self, reducer: R, options: RollingOptionsFixedWindow) -> Expr
+    where
+        R: RollingReducer<f64> + Clone + Send + Sync + 'static,
+    {
+        self.apply_with_fmt_str(
+            move |c: Column| {
+                let s = c.as_materialized_series().cast(&DataType::Float64)?;
+                let out = s
+                    .f64()?
+                    .rolling_map_reducer(reducer.clone(), options.clone())?;
+                Ok(out.into_column())
+            },
+            |_, field| {
+                Ok(Field::new(
+                    field.name().clone(),
+                    R::Output::get_static_dtype(),
+                ))
+            },
+            "rolling_map_reducer",
+        )
+    }
+
+    #[cfg(feature = "scan_fn")]
+    /// Run the step function registered under `name` over the values, in order.
+    ///
+    /// For every value, the step gets the current state and the value and returns the next state
+    /// and the output value. The state starts at `init` for every group. The output keeps the
+    /// input data type unless `return_dtype` is set.
+    ///
+    /// Steps are registered with [`polars_ops::series::register_scan_fn`].
+    pub fn scan_fn(
+        self,
+        name: impl Into<PlSmallStr>,
+        init: Scalar,
+        return_dtype: Option<DataTypeExpr>,
+    ) -> Expr {
+        self.map_unary(FunctionExpr::ScanFn {
+            step: ScanFnStep::Registered {
+                name: name.into(),
+                init: Box::new(init),
+            },
+            return_dtype,
+        })
+    }
+
     #[cfg(feature = "peaks")]
     pub fn peak_min(self) -> Expr {
         self.map_unary(FunctionExpr::PeakMin)
@@ -1378,6 +1460,25 @@ impl Expr {
             labels: labels.map(|x| x.into_vec()),
             left_closed,
             include_breaks,
+            include_bounds: false,
+        })
+    }
+
+    #[cfg(feature = "cutqcut")]
+    /// Bin continuous values into discrete categories, returning a struct with the `category` and
+    /// the `lower` and `upper` bounds of its interval.
+    pub fn cut_bounds(
+        self,
+        breaks: Vec<f64>,
+        labels: Option<impl IntoVec<PlSmallStr>>,
+        left_closed: bool,
+    ) -> Expr {
+        self.map_unary(FunctionExpr::Cut {
+            breaks,
+            labels: labels.map(|x| x.into_vec()),
+            left_closed,
+            include_breaks: false,
+            include_bounds: true,
         })
     }
 
@@ -1397,6 +1498,30 @@ impl Expr {
             left_closed,
             allow_duplicates,
             include_breaks,
+            include_bounds: false,
+        })
+    }
+
+    #[cfg(feature = "cutqcut")]
+    /// Bin continuous values into discrete categories based on their quantiles, returning a struct
+    /// with the `category` and the `lower` and `upper` bounds of its interval.
+    ///
+    /// Like [`Expr::qcut`], the quantiles are computed per group when used in a group-by or
+    /// window context.
+    pub fn qcut_bounds(
+        self,
+        probs: Vec<f64>,
+        labels: Option<impl IntoVec<PlSmallStr>>,
+        left_closed: bool,
+        allow_duplicates: bool,
+    ) -> Expr {
+        self.map_unary(FunctionExpr::QCut {
+            probs,
+            labels: labels.map(|x| x.into_vec()),
+            left_closed,
+            allow_duplicates,
+            include_breaks: false,
+            include_bounds: true,
         })
     }
 
@@ -1417,6 +1542,7 @@ impl Expr {
             left_closed,
             allow_duplicates,
             include_breaks,
+            include_bounds: false,
         })
     }
 
@@ -1432,6 +1558,72 @@ impl Expr {
         self.map_unary(FunctionExpr::RLEID)
     }
 
+    #[cfg(feature = "to_dummies")]
+    /// One-hot encode the values into a struct with an indicator field per category.
+    ///
+    /// The fields are named `{name}{separator}{category}`. If no `categories` are given, the
+    /// input must be an `Enum`, so that the output schema is known before running the query.
+    pub fn to_dummies(
+        self,
+        categories: Option<impl IntoVec<PlSmallStr>>,
+        separator: Option<&str>,
+        drop_first: bool,
+    ) -> Expr {
+        self.map_unary(FunctionExpr::ToDummies {
+            categories: categories.map(|x| x.into_vec()),
+            separator: separator.unwrap_or("_").into(),
+            drop_first,
+        })
+    }
+
+    #[cfg(feature = "fft")]
+    /// Compute the discrete Fourier transform of the whole column.
+    ///
+    /// Returns a struct with the real part in field `re` and the imaginary part in field `im`.
+    pub fn fft(self) -> Expr {
+        self.map_unary(FunctionExpr::Fft)
+    }
+
+    #[cfg(feature = "fft")]
+    /// Compute the power spectrum of the trailing `window` values at every row.
+    ///
+    /// Every row holds a list with the `window / 2 + 1` one-sided periodogram bins. Rows
+    /// without a full window, or with a null in it, are null.
+    pub fn power_spectrum(self, window: usize) -> Expr {
+        self.map_unary(FunctionExpr::PowerSpectrum { window })
+    }
+
+    #[cfg(feature = "convolve")]
+    /// Convolve the values with `kernel`.
+    ///
+    /// The `mode` determines the part of the full convolution that is returned, and `nulls` how
+    /// null values are handled.
+    pub fn convolve(self, kernel: Vec<f64>, mode: ConvolveMode, nulls: ConvolveNulls) -> Expr {
+        self.map_unary(FunctionExpr::Convolve {
+            kernel,
+            mode,
+            nulls,
+        })
+    }
+
+    #[cfg(feature = "convolve")]
+    /// Smooth the values with a Savitzky-Golay filter of odd length `window` and polynomial
+    /// degree `polyorder`.
+    pub fn savgol(self, window: usize, polyorder: usize, nulls: ConvolveNulls) -> Expr {
+        self.map_unary(FunctionExpr::Savgol {
+            window,
+            polyorder,
+            nulls,
+        })
+    }
+
+    #[cfg(feature = "anonymize")]
+    /// Replace the values by the `lower` and `upper` bounds of ranges that each contain at least
+    /// `k_anonymity` values.
+    pub fn bucketize_numeric(self, k_anonymity: usize) -> Expr {
+        self.map_unary(FunctionExpr::BucketizeNumeric { k_anonymity })
+    }
+
     #[cfg(feature = "diff")]
     /// Calculate the n-th discrete difference between values.
     pub fn diff(self, n: Expr, null_behavior: NullBehavior) -> Expr {
@@ -1605,6 +1797,28 @@ impl Expr {
         self.map_unary(FunctionExpr::ToPhysical)
     }
 
+    /// Replace the user metadata of the output field with `metadata`.
+    ///
+    /// An empty map removes the metadata. See [`crate::plans::field_metadata`] for how the
+    /// metadata propagates through later expressions.
+    pub fn with_field_metadata(self, metadata: Metadata) -> Expr {
+        self.map_unary(FunctionExpr::WithFieldMetadata(Arc::new(metadata)))
+    }
+
+    /// Set the physical unit of the output, like `"m/s"`, keeping the rest of its user metadata.
+    ///
+    /// Arithmetic between columns with a unit is checked and derives the unit of the result
+    /// when the query is optimized, see [`crate::plans::units`].
+    pub fn set_unit(self, unit: &str) -> Expr {
+        self.map_unary(FunctionExpr::SetUnit(unit.into()))
+    }
+
+    /// Convert the values to `unit`, which must have the same dimension as the unit of the
+    /// input. The result is a float column with unit `unit`.
+    pub fn convert_unit(self, unit: &str) -> Expr {
+        self.map_unary(FunctionExpr::ConvertUnit(unit.into()))
+    }
+
     pub fn gather_every(self, n: usize, offset: usize) -> Expr {
         self.map_unary(FunctionExpr::GatherEvery { n, offset })
     }
@@ -1669,6 +1883,12 @@ impl Expr {
         struct_::StructNameSpace(self)
     }
 
+    /// Get the [`interval::IntervalNameSpace`].
+    #[cfg(feature = "interval")]
+    pub fn interval(self) -> interval::IntervalNameSpace {
+        interval::IntervalNameSpace(self)
+    }
+
     /// Get the [`meta::MetaNameSpace`]
     #[cfg(feature = "meta")]
     pub fn meta(self) -> meta::MetaNameSpace {
@@ -5,7 +5,7 @@ use std::sync::Arc;
 
 pub mod file_provider;
 pub mod sink;
-pub use polars_config::Engine;
+pub use polars_config::{Engine, MorselSizing};
 use polars_core::error::PolarsResult;
 use polars_core::prelude::*;
 #[cfg(feature = "csv")]
@@ -25,6 +25,8 @@ use polars_time::DynamicGroupOptions;
 #[cfg(feature = "dynamic_group_by")]
 use polars_time::RollingGroupOptions;
 use polars_utils::IdxSize;
+use polars_utils::aliases::{PlSeedableRandomStateQuality, SeedableFromU64SeedExt};
+use polars_utils::hashing::hash_to_partition;
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -61,6 +63,9 @@ pub struct StrptimeOptions {
     pub exact: bool,
     /// use a cache of unique, converted dates to apply the datetime conversion.
     pub cache: bool,
+    /// Locale of the month and weekday names, e.g. "fr_FR"
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub locale: Option<PlSmallStr>,
 }
 
 impl Default for StrptimeOptions {
@@ -70,6 +75,7 @@ impl Default for StrptimeOptions {
             strict: true,
             exact: true,
             cache: true,
+            locale: None,
         }
     }
 }
@@ -193,7 +199,43 @@ pub struct UnpivotArgsDSL {
     pub value_name: Option<PlSmallStr>,
 }
 
-#[derive(Clone, Debug, Copy, Eq, PartialEq, Hash)]
+/// How the inputs of a union partition its rows by the hash of key columns: the `i`-th input
+/// holds exactly the rows whose keys hash to partition `i` out of as many partitions as there
+/// are inputs.
+///
+/// The optimizer uses this to only read the partition that an equality filter on the keys
+/// selects, and to run group-bys on the keys and joins of two unions partitioned in the same
+/// way per partition.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct HashPartitioning {
+    pub keys: Arc<[PlSmallStr]>,
+}
+
+impl HashPartitioning {
+    pub fn new(keys: impl Into<Arc<[PlSmallStr]>>) -> Self {
+        Self { keys: keys.into() }
+    }
+
+    /// The partition of every row of the key columns `keys` out of `n_partitions` partitions.
+    /// The keys are hashed with a fixed seed, so keys of the same dtypes always go to the same
+    /// partition.
+    pub fn partition_idxs(keys: &DataFrame, n_partitions: usize) -> PolarsResult<Vec<usize>> {
+        let mut hashes = Vec::with_capacity(keys.height());
+        polars_core::hashing::columns_to_hashes(
+            keys,
+            Some(PlSeedableRandomStateQuality::seed_from_u64(0)),
+            &mut hashes,
+        )?;
+        Ok(hashes
+            .into_iter()
+            .map(|h| hash_to_partition(h, n_partitions))
+            .collect())
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UnionOptions {
     pub slice: Option<(i64, usize)>,
@@ -204,6 +246,9 @@ pub struct UnionOptions {
     pub flattened_by_opt: bool,
     pub rechunk: bool,
     pub maintain_order: bool,
+    /// Set if the inputs partition the rows by the hash of key columns.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub partitioning: Option<HashPartitioning>,
 }
 
 impl Default for UnionOptions {
@@ -216,6 +261,7 @@ impl Default for UnionOptions {
             flattened_by_opt: false,
             rechunk: false,
             maintain_order: true,
+            partitioning: None,
         }
     }
 }
@@ -386,6 +432,7 @@ impl From<UnionArgs> for UnionOptions {
             flattened_by_opt: false,
             rechunk: args.rechunk,
             maintain_order: args.maintain_order,
+            partitioning: None,
         }
     }
 }
@@ -0,0 +1,150 @@
+//! Parameters: literals whose values are bound after the plan is built.
+use polars_utils::aliases::PlHashMap;
+
+use super::*;
+
+/// A literal whose value is bound later with [`DslPlan::bind_parameters`].
+///
+/// This allows a plan to be built once, and run many times with different values. A plan with
+/// unbound parameters cannot be run, and its schema cannot be resolved.
+pub fn param<S: Into<PlSmallStr>>(name: S) -> Expr {
+    Expr::Parameter(name.into())
+}
+
+impl Expr {
+    /// Replace the parameters in `params` by their values. Other parameters stay unbound.
+    pub fn bind_parameters(self, params: &PlHashMap<PlSmallStr, LiteralValue>) -> Expr {
+        self.map_expr(|e| match e {
+            Expr::Parameter(name) => match params.get(&name) {
+                Some(value) => Expr::Literal(value.clone()),
+                None => Expr::Parameter(name),
+            },
+            Expr::SubPlan(plan, names) => {
+                let plan = plan.as_ref().clone().bind_parameters(params);
+                Expr::SubPlan(SpecialEq::new(Arc::new(plan)), names)
+            },
+            e => e,
+        })
+    }
+}
+
+impl DslPlan {
+    /// Replace the parameters in `params` by their values, in all the expressions of the plan.
+    /// Other parameters stay unbound.
+    pub fn bind_parameters(mut self, params: &PlHashMap<PlSmallStr, LiteralValue>) -> DslPlan {
+        self.bind_parameters_mut(params);
+        self
+    }
+
+    fn bind_parameters_mut(&mut self, params: &PlHashMap<PlSmallStr, LiteralValue>) {
+        use DslPlan::*;
+        let bind = |exprs: &mut [Expr]| {
+            for e in exprs {
+                *e = std::mem::take(e).bind_parameters(params);
+            }
+        };
+        let bind_input =
+            |input: &mut Arc<DslPlan>| Arc::make_mut(input).bind_parameters_mut(params);
+
+        match self {
+            Filter { input, predicate } => {
+                bind(std::slice::from_mut(predicate));
+                bind_input(input);
+            },
+            Select { input, expr, .. } => {
+                bind(expr);
+                bind_input(input);
+            },
+            GroupBy {
+                input,
+                keys,
+                predicates,
+                aggs,
+                ..
+            } => {
+                bind(keys);
+                bind(predicates);
+                bind(aggs);
+                bind_input(input);
+            },
+            Join {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                predicates,
+                ..
+            } => {
+                bind(left_on);
+                bind(right_on);
+                bind(predicates);
+                bind_input(input_left);
+                bind_input(input_right);
+            },
+            HStack { input, exprs, .. } => {
+                bind(exprs);
+                bind_input(input);
+            },
+            Sort {
+                input, by_column, ..
+            } => {
+                bind(by_column);
+                bind_input(input);
+            },
+            #[cfg(feature = "pivot")]
+            Pivot { input, agg, .. } => {
+                bind(std::slice::from_mut(agg));
+                bind_input(input);
+            },
+            Cache { input, .. }
+            | MatchToSchema { input, .. }
+            | Distinct { input, .. }
+            | Slice { input, .. }
+            | Sink { input, .. } => bind_input(input),
+            MapFunction { input, function } => {
+                match function {
+                    DslFunction::FillNan(e)
+                    | DslFunction::Stats(StatsFunction::Quantile { quantile: e, .. }) => {
+                        bind(std::slice::from_mut(e))
+                    },
+                    _ => {},
+                }
+                bind_input(input);
+            },
+            Union { inputs, .. } | HConcat { inputs, .. } | SinkMultiple { inputs } => {
+                for input in inputs {
+                    input.bind_parameters_mut(params);
+                }
+            },
+            ExtContext { input, contexts } => {
+                bind_input(input);
+                for context in contexts {
+                    context.bind_parameters_mut(params);
+                }
+            },
+            PipeWithSchema { input, .. } => {
+                *input = input
+                    .iter()
+                    .map(|plan| plan.clone().bind_parameters(params))
+                    .collect();
+            },
+            #[cfg(feature = "merge_sorted")]
+            MergeSorted {
+                input_left,
+                input_right,
+                ..
+            } => {
+                bind_input(input_left);
+                bind_input(input_right);
+            },
+            IR { dsl, node, .. } => {
+                // The converted plan is stale once the parameters are bound.
+                bind_input(dsl);
+                *node = None;
+            },
+            Scan { .. } | DataFrameScan { .. } => {},
+            #[cfg(feature = "python")]
+            PythonScan { .. } => {},
+        }
+    }
+}
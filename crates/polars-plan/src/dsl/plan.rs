@@ -146,6 +146,10 @@ pub enum DslPlan {
     Union {
         inputs: Vec<DslPlan>,
         args: UnionArgs,
+        /// Set if the inputs partition the rows by the hash of key columns, see
+        /// [`HashPartitioning`].
+        #[cfg_attr(feature = "serde", serde(default))]
+        partitioning: Option<HashPartitioning>,
     },
     /// Horizontal concatenation of multiple plans
     HConcat {
@@ -203,7 +207,7 @@ impl Clone for DslPlan {
             Self::Sort {input,by_column, slice, sort_options } => Self::Sort { input: input.clone(), by_column: by_column.clone(), slice: slice.clone(), sort_options: sort_options.clone() },
             Self::Slice { input, offset, len } => Self::Slice { input: input.clone(), offset: offset.clone(), len: len.clone() },
             Self::MapFunction { input, function } => Self::MapFunction { input: input.clone(), function: function.clone() },
-            Self::Union { inputs, args} => Self::Union { inputs: inputs.clone(), args: args.clone() },
+            Self::Union { inputs, args, partitioning } => Self::Union { inputs: inputs.clone(), args: args.clone(), partitioning: partitioning.clone() },
             Self::HConcat { inputs, options } => Self::HConcat { inputs: inputs.clone(), options: options.clone() },
             Self::ExtContext { input, contexts, } => Self::ExtContext { input: input.clone(), contexts: contexts.clone() },
             Self::Sink { input, payload } => Self::Sink { input: input.clone(), payload: payload.clone() },
@@ -0,0 +1,64 @@
+//! Differentially private aggregations.
+//!
+//! The aggregations clip the values to known bounds, aggregate them with the regular kernels and
+//! add Laplace noise calibrated to the sensitivity of the aggregate. Each of them spends its
+//! `epsilon` of the privacy budget of the query, see [`crate::plans::privacy`].
+use super::*;
+
+impl Expr {
+    /// Add Laplace noise with scale `sensitivity / epsilon` to every value, which makes a query
+    /// whose result changes by at most `sensitivity` when a single row is added or removed
+    /// `epsilon`-differentially private.
+    ///
+    /// The values are rounded to a lattice with a spacing of about `2^-40` times the scale and the
+    /// noise is drawn from the discrete Laplace distribution on it, as floating point noise would
+    /// leak the values through the low-order bits of the result. The result is a float column.
+    pub fn dp_noise(self, epsilon: f64, sensitivity: f64) -> Expr {
+        self.map_unary(FunctionExpr::DpNoise {
+            epsilon,
+            sensitivity,
+        })
+    }
+
+    /// The `epsilon`-differentially private sum of the values, which are clipped to
+    /// `(lower, upper)`.
+    #[cfg(feature = "round_series")]
+    pub fn dp_sum(self, epsilon: f64, bounds: (f64, f64)) -> PolarsResult<Expr> {
+        let (lower, upper) = check_bounds(bounds)?;
+        let sensitivity = lower.abs().max(upper.abs());
+        Ok(self
+            .cast(DataType::Float64)
+            .clip(lit(lower), lit(upper))
+            .sum()
+            .dp_noise(epsilon, sensitivity))
+    }
+
+    /// The `epsilon`-differentially private number of non-null values.
+    pub fn dp_count(self, epsilon: f64) -> Expr {
+        self.count().dp_noise(epsilon, 1.0)
+    }
+
+    /// The `epsilon`-differentially private mean of the values, which are clipped to
+    /// `(lower, upper)`.
+    ///
+    /// The budget is split evenly between the sum and the count of the values.
+    #[cfg(feature = "round_series")]
+    pub fn dp_mean(self, epsilon: f64, bounds: (f64, f64)) -> PolarsResult<Expr> {
+        let (lower, upper) = check_bounds(bounds)?;
+        let sum = self.clone().dp_sum(epsilon / 2.0, bounds)?;
+        let count = self.dp_count(epsilon / 2.0);
+        Ok((sum / count.clip_min(lit(1.0))).clip(lit(lower), lit(upper)))
+    }
+}
+
+/// The sensitivity of the clipped aggregations is derived from the bounds, so they must be a
+/// finite, non-empty range.
+#[cfg(feature = "round_series")]
+fn check_bounds(bounds: (f64, f64)) -> PolarsResult<(f64, f64)> {
+    let (lower, upper) = bounds;
+    polars_ensure!(
+        lower <= upper && lower.is_finite() && upper.is_finite(),
+        InvalidOperation: "the bounds of a differentially private aggregation must be finite with `lower <= upper`, got ({}, {})", lower, upper
+    );
+    Ok(bounds)
+}
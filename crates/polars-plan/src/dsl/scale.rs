@@ -0,0 +1,78 @@
+//! Feature scalers built from aggregations, so that they are fitted per group within `over`.
+//!
+//! The `*_params` variants return the fitted parameters as a struct named after the input.
+use super::*;
+#[cfg(feature = "dtype-struct")]
+use crate::dsl::functions::as_struct;
+
+/// `(x - center) / scale`, where a `scale` of zero leaves the centered values as is.
+fn center_and_scale(x: Expr, center: Expr, scale: Expr) -> Expr {
+    let scale = when(scale.clone().eq(lit(0.0)))
+        .then(lit(1.0))
+        .otherwise(scale);
+    (x.cast(DataType::Float64) - center) / scale
+}
+
+fn iqr(x: Expr) -> Expr {
+    let q = |p: f64| x.clone().quantile(lit(p), QuantileMethod::Linear);
+    q(0.75) - q(0.25)
+}
+
+impl Expr {
+    /// Standardize the values by subtracting the mean and dividing by the standard deviation.
+    pub fn zscore(self, ddof: u8) -> Expr {
+        let mean = self.clone().mean();
+        let std = self.clone().std(ddof);
+        center_and_scale(self, mean, std)
+    }
+
+    /// The parameters fitted by [`Expr::zscore`], as a struct with the fields `mean` and `std`.
+    #[cfg(feature = "dtype-struct")]
+    pub fn zscore_params(self, ddof: u8) -> Expr {
+        as_struct(vec![
+            self.clone().mean().alias("mean"),
+            self.std(ddof).alias("std"),
+        ])
+        .name()
+        .keep()
+    }
+
+    /// Scale the values linearly, so that the minimum maps to `min` and the maximum to `max`.
+    pub fn min_max_scale(self, min: f64, max: f64) -> Expr {
+        let lower = self.clone().min().cast(DataType::Float64);
+        let range = self.clone().max().cast(DataType::Float64) - lower.clone();
+        center_and_scale(self, lower, range) * lit(max - min) + lit(min)
+    }
+
+    /// The parameters fitted by [`Expr::min_max_scale`], as a struct with the fields `min` and
+    /// `max`.
+    #[cfg(feature = "dtype-struct")]
+    pub fn min_max_scale_params(self) -> Expr {
+        as_struct(vec![
+            self.clone().min().cast(DataType::Float64).alias("min"),
+            self.max().cast(DataType::Float64).alias("max"),
+        ])
+        .name()
+        .keep()
+    }
+
+    /// Scale the values by subtracting the median and dividing by the interquartile range,
+    /// which makes the result robust to outliers.
+    pub fn robust_scale(self) -> Expr {
+        let median = self.clone().median();
+        let iqr = iqr(self.clone());
+        center_and_scale(self, median, iqr)
+    }
+
+    /// The parameters fitted by [`Expr::robust_scale`], as a struct with the fields `median`
+    /// and `iqr`.
+    #[cfg(feature = "dtype-struct")]
+    pub fn robust_scale_params(self) -> Expr {
+        as_struct(vec![
+            self.clone().median().alias("median"),
+            iqr(self).alias("iqr"),
+        ])
+        .name()
+        .keep()
+    }
+}
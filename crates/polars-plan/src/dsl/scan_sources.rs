@@ -6,11 +6,11 @@ use polars_buffer::Buffer;
 use polars_core::error::{PolarsResult, feature_gated};
 use polars_error::polars_err;
 use polars_io::cloud::CloudOptions;
+use polars_io::expanded_from_single_directory;
 #[cfg(feature = "cloud")]
 use polars_io::file_cache::FileCacheEntry;
 use polars_io::metrics::IOMetrics;
 use polars_io::utils::byte_source::{DynByteSource, DynByteSourceBuilder};
-use polars_io::{expand_paths, expand_paths_hive, expanded_from_single_directory};
 use polars_utils::mmap::MMapSemaphore;
 use polars_utils::pl_path::PlRefPath;
 use polars_utils::pl_str::PlSmallStr;
@@ -183,13 +183,17 @@ impl ScanSources {
     pub async fn expand_paths(&self, scan_args: &mut UnifiedScanArgs) -> PolarsResult<Self> {
         match self {
             Self::Paths(paths) => Ok(Self::Paths(
-                expand_paths(
-                    paths,
-                    scan_args.glob,
-                    scan_args.hidden_file_prefix.as_deref().unwrap_or_default(),
-                    &mut scan_args.cloud_options,
-                )
-                .await?,
+                scan_args
+                    .file_listing
+                    .expand_paths(
+                        paths,
+                        scan_args.glob,
+                        scan_args.hidden_file_prefix.as_deref().unwrap_or_default(),
+                        &mut scan_args.cloud_options,
+                        false,
+                    )
+                    .await?
+                    .0,
             )),
             v => Ok(v.clone()),
         }
@@ -204,14 +208,16 @@ impl ScanSources {
     ) -> PolarsResult<Self> {
         match self {
             Self::Paths(paths) => {
-                let (expanded_paths, hive_start_idx) = expand_paths_hive(
-                    paths,
-                    scan_args.glob,
-                    scan_args.hidden_file_prefix.as_deref().unwrap_or_default(),
-                    &mut scan_args.cloud_options,
-                    scan_args.hive_options.enabled.unwrap_or(false),
-                )
-                .await?;
+                let (expanded_paths, hive_start_idx) = scan_args
+                    .file_listing
+                    .expand_paths(
+                        paths,
+                        scan_args.glob,
+                        scan_args.hidden_file_prefix.as_deref().unwrap_or_default(),
+                        &mut scan_args.cloud_options,
+                        scan_args.hive_options.enabled.unwrap_or(false),
+                    )
+                    .await?;
 
                 if scan_args.hive_options.enabled.is_none()
                     && expanded_from_single_directory(paths, expanded_paths.as_ref())
@@ -165,6 +165,9 @@ pub enum Selector {
 
     Matches(PlSmallStr),
     ByDType(DataTypeSelector),
+    /// The columns that have a value for a key in their user metadata, see
+    /// [`Selector::by_metadata`].
+    ByMetadata(PlSmallStr, PlSmallStr),
 
     Wildcard,
     Empty,
@@ -184,6 +187,65 @@ fn dtype_selector(
 }
 
 impl Selector {
+    /// Select the columns of which the dtype is matched by `dtypes`.
+    pub fn by_dtype(dtypes: DataTypeSelector) -> Self {
+        Self::ByDType(dtypes)
+    }
+
+    /// Select the columns of which the name matches the regular expression `pattern`.
+    ///
+    /// The pattern is not anchored, use `^` and `$` to match the whole name.
+    pub fn by_name_regex(pattern: impl Into<PlSmallStr>) -> Self {
+        Self::Matches(pattern.into())
+    }
+
+    /// Select the columns of which the user metadata has `value` for `key`.
+    ///
+    /// The metadata is resolved from the input when the plan is built, which is supported in
+    /// `select`, `with_columns` and `group_by`.
+    pub fn by_metadata(key: impl Into<PlSmallStr>, value: impl Into<PlSmallStr>) -> Self {
+        Self::ByMetadata(key.into(), value.into())
+    }
+
+    /// Whether the selector selects columns by their user metadata.
+    pub fn selects_by_metadata(&self) -> bool {
+        match self {
+            Self::Union(lhs, rhs)
+            | Self::Difference(lhs, rhs)
+            | Self::ExclusiveOr(lhs, rhs)
+            | Self::Intersect(lhs, rhs) => lhs.selects_by_metadata() || rhs.selects_by_metadata(),
+            Self::ByMetadata(..) => true,
+            _ => false,
+        }
+    }
+
+    /// Replace the metadata selectors by the columns of `schema` of which the metadata in
+    /// `metadata` matches.
+    pub fn resolve_metadata(self, schema: &Schema, metadata: &FieldMetadataMap) -> Self {
+        let resolve =
+            |s: Arc<Selector>| Arc::new(Arc::unwrap_or_clone(s).resolve_metadata(schema, metadata));
+        match self {
+            Self::Union(lhs, rhs) => Self::Union(resolve(lhs), resolve(rhs)),
+            Self::Difference(lhs, rhs) => Self::Difference(resolve(lhs), resolve(rhs)),
+            Self::ExclusiveOr(lhs, rhs) => Self::ExclusiveOr(resolve(lhs), resolve(rhs)),
+            Self::Intersect(lhs, rhs) => Self::Intersect(resolve(lhs), resolve(rhs)),
+            Self::ByMetadata(key, value) => {
+                let names = schema
+                    .iter_names()
+                    .filter(|name| metadata.get(*name).and_then(|md| md.get(&key)) == Some(&value))
+                    .cloned()
+                    .collect();
+                // Intersect with the wildcard, so that the ignored columns are not selected.
+                Self::Wildcard
+                    & Self::ByName {
+                        names,
+                        strict: false,
+                    }
+            },
+            s => s,
+        }
+    }
+
     /// Turns the selector into an ordered set of selected columns from the schema.
     ///
     /// - The order of the columns corresponds to the order in the schema.
@@ -272,6 +334,9 @@ impl Selector {
                     .cloned(),
             ),
             Self::Empty => Default::default(),
+            Self::ByMetadata(..) => polars_bail!(
+                InvalidOperation: "selecting {self} is only supported in `select`, `with_columns` and `group_by`"
+            ),
         };
         Ok(out)
     }
@@ -304,7 +369,10 @@ impl Selector {
 
             Self::ByDType(dts) => Some(dts.clone()),
 
-            Self::ByName { .. } | Self::ByIndex { .. } | Self::Matches(_) => None,
+            Self::ByName { .. }
+            | Self::ByIndex { .. }
+            | Self::Matches(_)
+            | Self::ByMetadata(..) => None,
         }
     }
 
@@ -441,7 +509,7 @@ impl DataTypeSelector {
             },
             Self::Wildcard => schema
                 .iter_names()
-                .filter(|n| ignored_columns.contains(*n))
+                .filter(|n| !ignored_columns.contains(*n))
                 .cloned()
                 .collect(),
             Self::Empty => Default::default(),
@@ -629,6 +697,12 @@ impl Not for DataTypeSelector {
     }
 }
 
+impl From<DataTypeSelector> for Selector {
+    fn from(value: DataTypeSelector) -> Self {
+        Selector::ByDType(value)
+    }
+}
+
 impl From<Selector> for Expr {
     fn from(value: Selector) -> Self {
         Expr::Selector(value)
@@ -670,6 +744,7 @@ impl fmt::Display for Selector {
                 )
             },
             Self::Matches(s) => write!(f, "cs.matches(\"{s}\")"),
+            Self::ByMetadata(key, value) => write!(f, "cs.by_metadata('{key}', '{value}')"),
             Self::Wildcard => f.write_str("cs.all()"),
             Self::Empty => f.write_str("cs.empty()"),
         }
@@ -125,6 +125,8 @@ pub(crate) enum SerializableDslPlanNode {
     Union {
         inputs: Vec<SerializableDslPlanNode>,
         args: UnionArgs,
+        #[cfg_attr(feature = "serde", serde(default))]
+        partitioning: Option<HashPartitioning>,
     },
     HConcat {
         inputs: Vec<SerializableDslPlanNode>,
@@ -323,12 +325,17 @@ fn convert_dsl_plan_to_serializable_plan(
             input: dsl_plan_key(input, arenas),
             function: function.clone(),
         },
-        DP::Union { inputs, args } => SP::Union {
+        DP::Union {
+            inputs,
+            args,
+            partitioning,
+        } => SP::Union {
             inputs: inputs
                 .iter()
                 .map(|p| convert_dsl_plan_to_serializable_plan(p, arenas))
                 .collect(),
             args: *args,
+            partitioning: partitioning.clone(),
         },
         DP::HConcat { inputs, options } => SP::HConcat {
             inputs: inputs
@@ -570,12 +577,17 @@ fn try_convert_serializable_plan_to_dsl_plan(
             input: get_dsl_plan(*input, ser_dsl_plan, arenas)?,
             function: function.clone(),
         }),
-        SP::Union { inputs, args } => Ok(DP::Union {
+        SP::Union {
+            inputs,
+            args,
+            partitioning,
+        } => Ok(DP::Union {
             inputs: inputs
                 .iter()
                 .map(|node| try_convert_serializable_plan_to_dsl_plan(node, ser_dsl_plan, arenas))
                 .collect::<Result<Vec<_>, _>>()?,
             args: *args,
+            partitioning: partitioning.clone(),
         }),
         SP::HConcat { inputs, options } => Ok(DP::HConcat {
             inputs: inputs
@@ -307,6 +307,16 @@ impl StringNameSpace {
         self.strptime(DataType::Time, options, lit("raise"))
     }
 
+    /// Convert a String column into a Duration column.
+    ///
+    /// Parses ISO 8601 durations ("P1DT2H"), human-readable durations ("2d 3h 4m 5.25s") and
+    /// clock durations ("26:03:04.5").
+    #[cfg(feature = "dtype-duration")]
+    pub fn to_duration(self, time_unit: TimeUnit, strict: bool) -> Expr {
+        self.0
+            .map_unary(StringFunction::ToDuration { time_unit, strict })
+    }
+
     /// Convert a String column into a Decimal column.
     #[cfg(feature = "dtype-decimal")]
     pub fn to_decimal(self, scale: usize) -> Expr {
@@ -414,6 +424,17 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Normalize { form })
     }
 
+    #[cfg(feature = "string_normalize")]
+    /// Remove the diacritics of each string
+    pub fn remove_diacritics(self) -> Expr {
+        self.0.map_unary(StringFunction::RemoveDiacritics)
+    }
+
+    /// Check whether each string only consists of ASCII characters
+    pub fn is_ascii(self) -> Expr {
+        self.0.map_unary(StringFunction::IsAscii)
+    }
+
     #[cfg(feature = "string_reverse")]
     /// Reverse each string
     pub fn reverse(self) -> Expr {
@@ -450,6 +471,35 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Lowercase)
     }
 
+    /// Mask the local part of email addresses, keeping its first character and the domain.
+    #[cfg(feature = "anonymize")]
+    pub fn mask_email(self) -> Expr {
+        self.0.map_unary(StringFunction::MaskEmail)
+    }
+
+    /// Mask all digits except the last `keep_last` ones.
+    #[cfg(feature = "anonymize")]
+    pub fn mask_digits(self, keep_last: usize) -> Expr {
+        self.0.map_unary(StringFunction::MaskDigits { keep_last })
+    }
+
+    /// Compute binary sort keys that order the strings according to a locale-aware collation.
+    ///
+    /// Sorting, comparing or grouping by these keys treats the strings as the collation does,
+    /// e.g. `"ä"` sorts next to `"a"` for German and after `"z"` for Swedish.
+    #[cfg(feature = "collation")]
+    pub fn collation_key(self, options: CollationOptions) -> Expr {
+        self.0.map_unary(StringFunction::CollationKey(options))
+    }
+
+    /// Compute binary sort keys that order the strings naturally.
+    ///
+    /// Runs of digits are compared by their numeric value, so `"file2"` sorts before `"file10"`.
+    #[cfg(feature = "natural_sort")]
+    pub fn natural_sort_key(self) -> Expr {
+        self.0.map_unary(StringFunction::NaturalSortKey)
+    }
+
     /// Convert all characters to uppercase.
     pub fn to_uppercase(self) -> Expr {
         self.0.map_unary(StringFunction::Uppercase)
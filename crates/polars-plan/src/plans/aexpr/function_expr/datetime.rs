@@ -54,7 +54,10 @@ pub enum IRTemporalFunction {
     TotalNanoseconds {
         fractional: bool,
     },
-    ToString(String),
+    ToString {
+        format: String,
+        locale: Option<PlSmallStr>,
+    },
     CastTimeUnit(TimeUnit),
     WithTimeUnit(TimeUnit),
     #[cfg(feature = "timezones")]
@@ -106,7 +109,7 @@ impl IRTemporalFunction {
                     mapper.with_dtype(DataType::Int64)
                 }
             },
-            ToString(_) => mapper.with_dtype(DataType::String),
+            ToString { .. } => mapper.with_dtype(DataType::String),
             WithTimeUnit(tu) | CastTimeUnit(tu) => mapper.try_map_dtype(|dt| match dt {
                 DataType::Duration(_) => Ok(DataType::Duration(*tu)),
                 DataType::Datetime(_, tz) => Ok(DataType::Datetime(*tu, tz.clone())),
@@ -183,7 +186,7 @@ impl IRTemporalFunction {
             | T::Millisecond
             | T::Microsecond
             | T::Nanosecond
-            | T::ToString(_)
+            | T::ToString { .. }
             | T::TimeStamp(_)
             | T::CastTimeUnit(_)
             | T::WithTimeUnit(_) => FunctionOptions::elementwise(),
@@ -261,7 +264,7 @@ impl Display for IRTemporalFunction {
             TotalMicroseconds { .. } => "total_microseconds",
             #[cfg(feature = "dtype-duration")]
             TotalNanoseconds { .. } => "total_nanoseconds",
-            ToString(_) => "to_string",
+            ToString { .. } => "to_string",
             #[cfg(feature = "timezones")]
             ConvertTimeZone(_) => "convert_time_zone",
             CastTimeUnit(_) => "cast_time_unit",
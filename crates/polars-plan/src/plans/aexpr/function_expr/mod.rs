@@ -44,6 +44,7 @@ use std::hash::{Hash, Hasher};
 
 #[cfg(feature = "dtype-array")]
 pub use array::IRArrayFunction;
+use arrow::datatypes::Metadata;
 #[cfg(feature = "cov")]
 pub use correlation::IRCorrelationMethod;
 #[cfg(feature = "fused")]
@@ -113,6 +114,8 @@ pub enum IRFunctionExpr {
     Boolean(IRBooleanFunction),
     #[cfg(feature = "business")]
     Business(IRBusinessFunction),
+    #[cfg(feature = "interval")]
+    Interval(IntervalFunction),
     #[cfg(feature = "abs")]
     Abs,
     Negate,
@@ -135,6 +138,12 @@ pub enum IRFunctionExpr {
         side: SearchSortedSide,
         descending: bool,
     },
+    #[cfg(feature = "search_sorted")]
+    SearchSortedBy {
+        side: SearchSortedSide,
+        descending: Vec<bool>,
+        nulls_last: Vec<bool>,
+    },
     #[cfg(feature = "range")]
     Range(IRRangeFunction),
     #[cfg(feature = "trigonometry")]
@@ -293,6 +302,7 @@ pub enum IRFunctionExpr {
         labels: Option<Vec<PlSmallStr>>,
         left_closed: bool,
         include_breaks: bool,
+        include_bounds: bool,
     },
     #[cfg(feature = "cutqcut")]
     QCut {
@@ -301,18 +311,64 @@ pub enum IRFunctionExpr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        include_bounds: bool,
     },
     #[cfg(feature = "rle")]
     RLE,
     #[cfg(feature = "rle")]
     RLEID,
+    #[cfg(feature = "to_dummies")]
+    ToDummies {
+        categories: Option<Vec<PlSmallStr>>,
+        separator: PlSmallStr,
+        drop_first: bool,
+    },
+    #[cfg(feature = "fft")]
+    Fft,
+    #[cfg(feature = "fft")]
+    PowerSpectrum {
+        window: usize,
+    },
+    #[cfg(feature = "convolve")]
+    Convolve {
+        kernel: Vec<f64>,
+        mode: ConvolveMode,
+        nulls: ConvolveNulls,
+    },
+    #[cfg(feature = "convolve")]
+    Savgol {
+        window: usize,
+        polyorder: usize,
+        nulls: ConvolveNulls,
+    },
+    #[cfg(feature = "anonymize")]
+    BucketizeNumeric {
+        k_anonymity: usize,
+    },
     ToPhysical,
     #[cfg(feature = "random")]
     Random {
         method: IRRandomMethod,
         seed: Option<u64>,
     },
+    /// Add Laplace noise with scale `sensitivity / epsilon`. Every evaluation spends `epsilon`
+    /// of the privacy budget, see [`crate::plans::privacy`].
+    #[cfg(feature = "random")]
+    DpNoise {
+        epsilon: f64,
+        sensitivity: f64,
+    },
     SetSortedFlag(AExprSorted),
+    /// Replace the user metadata of the output field.
+    WithFieldMetadata(Arc<Metadata>),
+    /// Set the unit in the user metadata of the output field.
+    SetUnit(PlSmallStr),
+    /// Multiply the values with the factor converting their unit to `unit`. The factor is
+    /// resolved from the unit of the input during optimization.
+    ConvertUnit {
+        unit: PlSmallStr,
+        factor: Option<f64>,
+    },
     #[cfg(feature = "ffi_plugin")]
     /// Creating this node is unsafe
     /// This will lead to calls over FFI.
@@ -358,6 +414,11 @@ pub enum IRFunctionExpr {
     MeanHorizontal {
         ignore_nulls: bool,
     },
+    #[cfg(feature = "scan_fn")]
+    ScanFn {
+        step: ScanFnStep,
+        return_dtype: Option<DataType>,
+    },
     #[cfg(feature = "ewma")]
     EwmMean {
         options: EWMOptions,
@@ -423,6 +484,8 @@ impl Hash for IRFunctionExpr {
             Boolean(f) => f.hash(state),
             #[cfg(feature = "business")]
             Business(f) => f.hash(state),
+            #[cfg(feature = "interval")]
+            Interval(f) => f.hash(state),
             Pow(f) => f.hash(state),
             #[cfg(feature = "index_of")]
             IndexOf => {},
@@ -431,8 +494,26 @@ impl Hash for IRFunctionExpr {
                 side.hash(state);
                 descending.hash(state);
             },
+            #[cfg(feature = "search_sorted")]
+            SearchSortedBy {
+                side,
+                descending,
+                nulls_last,
+            } => {
+                side.hash(state);
+                descending.hash(state);
+                nulls_last.hash(state);
+            },
             #[cfg(feature = "random")]
             Random { method, .. } => method.hash(state),
+            #[cfg(feature = "random")]
+            DpNoise {
+                epsilon,
+                sensitivity,
+            } => {
+                epsilon.to_bits().hash(state);
+                sensitivity.to_bits().hash(state);
+            },
             #[cfg(feature = "cov")]
             Correlation { method, .. } => method.hash(state),
             #[cfg(feature = "range")]
@@ -628,12 +709,14 @@ impl Hash for IRFunctionExpr {
                 labels,
                 left_closed,
                 include_breaks,
+                include_bounds,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(breaks);
                 slice.hash(state);
                 labels.hash(state);
                 left_closed.hash(state);
                 include_breaks.hash(state);
+                include_bounds.hash(state);
             },
             #[cfg(feature = "dtype-array")]
             Reshape(dims) => dims.hash(state),
@@ -646,6 +729,7 @@ impl Hash for IRFunctionExpr {
                 left_closed,
                 allow_duplicates,
                 include_breaks,
+                include_bounds,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(probs);
                 slice.hash(state);
@@ -653,13 +737,62 @@ impl Hash for IRFunctionExpr {
                 left_closed.hash(state);
                 allow_duplicates.hash(state);
                 include_breaks.hash(state);
+                include_bounds.hash(state);
             },
             #[cfg(feature = "rle")]
             RLE => {},
             #[cfg(feature = "rle")]
             RLEID => {},
+            #[cfg(feature = "to_dummies")]
+            ToDummies {
+                categories,
+                separator,
+                drop_first,
+            } => {
+                categories.hash(state);
+                separator.hash(state);
+                drop_first.hash(state);
+            },
+            #[cfg(feature = "fft")]
+            Fft => {},
+            #[cfg(feature = "fft")]
+            PowerSpectrum { window } => window.hash(state),
+            #[cfg(feature = "convolve")]
+            Convolve {
+                kernel,
+                mode,
+                nulls,
+            } => {
+                let slice = bytemuck::cast_slice::<_, u64>(kernel);
+                slice.hash(state);
+                mode.hash(state);
+                nulls.hash(state);
+            },
+            #[cfg(feature = "convolve")]
+            Savgol {
+                window,
+                polyorder,
+                nulls,
+            } => {
+                window.hash(state);
+                polyorder.hash(state);
+                nulls.hash(state);
+            },
+            #[cfg(feature = "anonymize")]
+            BucketizeNumeric { k_anonymity } => k_anonymity.hash(state),
             ToPhysical => {},
             SetSortedFlag(is_sorted) => is_sorted.hash(state),
+            WithFieldMetadata(metadata) => metadata.hash(state),
+            SetUnit(unit) => unit.hash(state),
+            ConvertUnit { unit, factor } => {
+                unit.hash(state);
+                factor.map(f64::to_bits).hash(state);
+            },
+            #[cfg(feature = "scan_fn")]
+            ScanFn { step, return_dtype } => {
+                step.hash(state);
+                return_dtype.hash(state);
+            },
             #[cfg(feature = "ewma")]
             EwmMean { options } => options.hash(state),
             #[cfg(feature = "ewma_by")]
@@ -732,6 +865,8 @@ impl Display for IRFunctionExpr {
             Boolean(func) => return write!(f, "{func}"),
             #[cfg(feature = "business")]
             Business(func) => return write!(f, "{func}"),
+            #[cfg(feature = "interval")]
+            Interval(func) => return write!(f, "{func}"),
             #[cfg(feature = "abs")]
             Abs => "abs",
             Negate => "negate",
@@ -745,6 +880,8 @@ impl Display for IRFunctionExpr {
             IndexOf => "index_of",
             #[cfg(feature = "search_sorted")]
             SearchSorted { .. } => "search_sorted",
+            #[cfg(feature = "search_sorted")]
+            SearchSortedBy { .. } => "search_sorted_by",
             #[cfg(feature = "range")]
             Range(func) => return write!(f, "{func}"),
             #[cfg(feature = "trigonometry")]
@@ -877,10 +1014,27 @@ impl Display for IRFunctionExpr {
             RLE => "rle",
             #[cfg(feature = "rle")]
             RLEID => "rle_id",
+            #[cfg(feature = "to_dummies")]
+            ToDummies { .. } => "to_dummies",
+            #[cfg(feature = "fft")]
+            Fft => "fft",
+            #[cfg(feature = "fft")]
+            PowerSpectrum { .. } => "power_spectrum",
+            #[cfg(feature = "convolve")]
+            Convolve { .. } => "convolve",
+            #[cfg(feature = "convolve")]
+            Savgol { .. } => "savgol",
+            #[cfg(feature = "anonymize")]
+            BucketizeNumeric { .. } => "bucketize_numeric",
             ToPhysical => "to_physical",
             #[cfg(feature = "random")]
             Random { method, .. } => method.into(),
+            #[cfg(feature = "random")]
+            DpNoise { .. } => "dp_noise",
             SetSortedFlag(_) => "set_sorted",
+            WithFieldMetadata(_) => "with_field_metadata",
+            SetUnit(_) => "set_unit",
+            ConvertUnit { .. } => "convert_unit",
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin { lib, symbol, .. } => return write!(f, "{lib}:{symbol}"),
 
@@ -895,6 +1049,8 @@ impl Display for IRFunctionExpr {
             MinHorizontal => "min_horizontal",
             SumHorizontal { .. } => "sum_horizontal",
             MeanHorizontal { .. } => "mean_horizontal",
+            #[cfg(feature = "scan_fn")]
+            ScanFn { .. } => "scan_fn",
             #[cfg(feature = "ewma")]
             EwmMean { .. } => "ewm_mean",
             #[cfg(feature = "ewma_by")]
@@ -1029,6 +1185,14 @@ impl IRFunctionExpr {
             F::Boolean(e) => e.function_options(),
             #[cfg(feature = "business")]
             F::Business(e) => e.function_options(),
+            #[cfg(feature = "interval")]
+            F::Interval(f) => match f {
+                IntervalFunction::UnionMerge => FunctionOptions::groupwise(),
+                IntervalFunction::New
+                | IntervalFunction::Overlaps
+                | IntervalFunction::Contains
+                | IntervalFunction::Intersect => FunctionOptions::elementwise(),
+            },
             F::Pow(e) => e.function_options(),
             #[cfg(feature = "range")]
             F::Range(e) => e.function_options(),
@@ -1050,6 +1214,8 @@ impl IRFunctionExpr {
             F::SearchSorted { .. } => FunctionOptions::groupwise().with_supertyping(
                 (SuperTypeFlags::default() & !SuperTypeFlags::ALLOW_PRIMITIVE_TO_STRING).into(),
             ),
+            #[cfg(feature = "search_sorted")]
+            F::SearchSortedBy { .. } => FunctionOptions::groupwise(),
             #[cfg(feature = "trigonometry")]
             F::Trigonometry(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "trigonometry")]
@@ -1192,7 +1358,25 @@ impl IRFunctionExpr {
             F::RLE => FunctionOptions::groupwise(),
             #[cfg(feature = "rle")]
             F::RLEID => FunctionOptions::length_preserving(),
+            #[cfg(feature = "to_dummies")]
+            F::ToDummies { .. } => {
+                FunctionOptions::elementwise().with_flags(|f| f | FunctionFlags::PASS_NAME_TO_APPLY)
+            },
+            #[cfg(feature = "fft")]
+            F::Fft | F::PowerSpectrum { .. } => FunctionOptions::length_preserving(),
+            #[cfg(feature = "convolve")]
+            F::Convolve { mode, .. } => match mode {
+                ConvolveMode::Same => FunctionOptions::length_preserving(),
+                ConvolveMode::Full | ConvolveMode::Valid => FunctionOptions::groupwise(),
+            },
+            #[cfg(feature = "convolve")]
+            F::Savgol { .. } => FunctionOptions::length_preserving(),
+            #[cfg(feature = "anonymize")]
+            F::BucketizeNumeric { .. } => FunctionOptions::length_preserving(),
             F::ToPhysical => FunctionOptions::elementwise(),
+            F::WithFieldMetadata(_) | F::SetUnit(_) | F::ConvertUnit { .. } => {
+                FunctionOptions::elementwise()
+            },
             #[cfg(feature = "random")]
             F::Random {
                 method: IRRandomMethod::Sample { .. },
@@ -1203,6 +1387,8 @@ impl IRFunctionExpr {
                 method: IRRandomMethod::Shuffle,
                 ..
             } => FunctionOptions::length_preserving(),
+            #[cfg(feature = "random")]
+            F::DpNoise { .. } => FunctionOptions::elementwise(),
             F::SetSortedFlag(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "ffi_plugin")]
             F::FfiPlugin { flags, .. } => *flags,
@@ -1231,6 +1417,8 @@ impl IRFunctionExpr {
                     }
                     f
                 }),
+            #[cfg(feature = "scan_fn")]
+            F::ScanFn { .. } => FunctionOptions::length_preserving(),
             #[cfg(feature = "ewma")]
             F::EwmMean { .. } | F::EwmStd { .. } | F::EwmVar { .. } => {
                 FunctionOptions::length_preserving()
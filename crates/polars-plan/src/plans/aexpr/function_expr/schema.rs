@@ -36,6 +36,22 @@ impl IRFunctionExpr {
             Boolean(func) => func.get_field(mapper),
             #[cfg(feature = "business")]
             Business(func) => func.get_field(mapper),
+            #[cfg(feature = "interval")]
+            Interval(func) => match func {
+                IntervalFunction::New => {
+                    let field = mapper.map_to_supertype()?;
+                    Ok(Field::new(
+                        field.name,
+                        polars_ops::series::interval_dtype(field.dtype),
+                    ))
+                },
+                IntervalFunction::Overlaps | IntervalFunction::Contains => {
+                    mapper.with_dtype(DataType::Boolean)
+                },
+                IntervalFunction::Intersect | IntervalFunction::UnionMerge => {
+                    mapper.with_same_dtype()
+                },
+            },
             #[cfg(feature = "abs")]
             Abs => mapper.with_same_dtype(),
             Negate => mapper.with_same_dtype(),
@@ -52,7 +68,7 @@ impl IRFunctionExpr {
             #[cfg(feature = "index_of")]
             IndexOf => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "search_sorted")]
-            SearchSorted { .. } => mapper.with_dtype(IDX_DTYPE),
+            SearchSorted { .. } | SearchSortedBy { .. } => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "range")]
             Range(func) => func.get_field(mapper),
             #[cfg(feature = "trigonometry")]
@@ -298,23 +314,13 @@ impl IRFunctionExpr {
             PeakMin | PeakMax => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "cutqcut")]
             Cut {
-                include_breaks: false,
+                include_breaks,
+                include_bounds,
                 ..
-            } => mapper.with_dtype(DataType::from_categories(Categories::global())),
-            #[cfg(feature = "cutqcut")]
-            Cut {
-                include_breaks: true,
-                ..
-            } => {
-                let struct_dt = DataType::Struct(vec![
-                    Field::new(PlSmallStr::from_static("breakpoint"), DataType::Float64),
-                    Field::new(
-                        PlSmallStr::from_static("category"),
-                        DataType::from_categories(Categories::global()),
-                    ),
-                ]);
-                mapper.with_dtype(struct_dt)
-            },
+            } => mapper.with_dtype(polars_ops::series::cut_dtype(
+                *include_breaks,
+                *include_bounds,
+            )),
             #[cfg(feature = "repeat_by")]
             RepeatBy => mapper.map_dtype(|dt| DataType::List(dt.clone().into())),
             #[cfg(feature = "dtype-array")]
@@ -330,23 +336,13 @@ impl IRFunctionExpr {
             }),
             #[cfg(feature = "cutqcut")]
             QCut {
-                include_breaks: false,
+                include_breaks,
+                include_bounds,
                 ..
-            } => mapper.with_dtype(DataType::from_categories(Categories::global())),
-            #[cfg(feature = "cutqcut")]
-            QCut {
-                include_breaks: true,
-                ..
-            } => {
-                let struct_dt = DataType::Struct(vec![
-                    Field::new(PlSmallStr::from_static("breakpoint"), DataType::Float64),
-                    Field::new(
-                        PlSmallStr::from_static("category"),
-                        DataType::from_categories(Categories::global()),
-                    ),
-                ]);
-                mapper.with_dtype(struct_dt)
-            },
+            } => mapper.with_dtype(polars_ops::series::cut_dtype(
+                *include_breaks,
+                *include_bounds,
+            )),
             #[cfg(feature = "rle")]
             RLE => mapper.map_dtype(|dt| {
                 DataType::Struct(vec![
@@ -356,9 +352,38 @@ impl IRFunctionExpr {
             }),
             #[cfg(feature = "rle")]
             RLEID => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "to_dummies")]
+            ToDummies {
+                categories,
+                separator,
+                drop_first,
+            } => mapper.try_map_field(|field| {
+                let dtype = polars_ops::series::to_dummies_dtype(
+                    field.name(),
+                    field.dtype(),
+                    categories.as_deref(),
+                    separator,
+                    *drop_first,
+                )?;
+                Ok(Field::new(field.name().clone(), dtype))
+            }),
+            #[cfg(feature = "fft")]
+            Fft => mapper.with_dtype(polars_ops::series::fft_dtype()),
+            #[cfg(feature = "fft")]
+            PowerSpectrum { .. } => mapper.with_dtype(DataType::List(Box::new(DataType::Float64))),
+            #[cfg(feature = "convolve")]
+            Convolve { .. } | Savgol { .. } => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "anonymize")]
+            BucketizeNumeric { .. } => {
+                mapper.map_dtype(polars_ops::series::bucketize_numeric_dtype)
+            },
             ToPhysical => mapper.to_physical_type(),
+            WithFieldMetadata(_) | SetUnit(_) => mapper.with_same_dtype(),
+            ConvertUnit { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "random")]
             Random { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "random")]
+            DpNoise { .. } => mapper.with_dtype(DataType::Float64),
             SetSortedFlag(_) => mapper.with_same_dtype(),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
@@ -427,6 +452,11 @@ impl IRFunctionExpr {
                 }
                 f
             }),
+            #[cfg(feature = "scan_fn")]
+            ScanFn { return_dtype, .. } => match return_dtype {
+                None => mapper.with_same_dtype(),
+                Some(dtype) => mapper.with_dtype(dtype.clone()),
+            },
             #[cfg(feature = "ewma")]
             EwmMean { .. } => mapper.map_numeric_to_float_dtype(true),
             #[cfg(feature = "ewma_by")]
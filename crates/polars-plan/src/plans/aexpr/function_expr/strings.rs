@@ -54,6 +54,16 @@ pub enum IRStringFunction {
     LenBytes,
     LenChars,
     Lowercase,
+    #[cfg(feature = "anonymize")]
+    MaskEmail,
+    #[cfg(feature = "anonymize")]
+    MaskDigits {
+        keep_last: usize,
+    },
+    #[cfg(feature = "collation")]
+    CollationKey(CollationOptions),
+    #[cfg(feature = "natural_sort")]
+    NaturalSortKey,
     #[cfg(feature = "extract_jsonpath")]
     JsonDecode(DataType),
     #[cfg(feature = "extract_jsonpath")]
@@ -69,6 +79,9 @@ pub enum IRStringFunction {
     Normalize {
         form: UnicodeForm,
     },
+    #[cfg(feature = "string_normalize")]
+    RemoveDiacritics,
+    IsAscii,
     #[cfg(feature = "string_reverse")]
     Reverse,
     #[cfg(feature = "string_pad")]
@@ -106,6 +119,11 @@ pub enum IRStringFunction {
     #[cfg(feature = "temporal")]
     // DataType can only be Date/Datetime/Time
     Strptime(DataType, StrptimeOptions),
+    #[cfg(feature = "dtype-duration")]
+    ToDuration {
+        time_unit: TimeUnit,
+        strict: bool,
+    },
     Split(bool),
     #[cfg(feature = "regex")]
     SplitRegex {
@@ -175,8 +193,13 @@ impl IRStringFunction {
             Replace { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_normalize")]
             Normalize { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "string_normalize")]
+            RemoveDiacritics => mapper.with_same_dtype(),
+            IsAscii => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { time_unit, .. } => mapper.with_dtype(DataType::Duration(*time_unit)),
             #[cfg(feature = "temporal")]
             Strptime(dtype, options) => match dtype {
                 #[cfg(feature = "dtype-datetime")]
@@ -212,6 +235,12 @@ impl IRStringFunction {
             Base64Decode(_) => mapper.with_dtype(DataType::Binary),
             Uppercase | Lowercase | StripChars | StripCharsStart | StripCharsEnd | StripPrefix
             | StripSuffix | Slice | Head | Tail => mapper.with_same_dtype(),
+            #[cfg(feature = "anonymize")]
+            MaskEmail | MaskDigits { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "collation")]
+            CollationKey(_) => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "natural_sort")]
+            NaturalSortKey => mapper.with_dtype(DataType::Binary),
             #[cfg(feature = "string_pad")]
             PadStart { .. } | PadEnd { .. } | ZFill => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-struct")]
@@ -274,12 +303,17 @@ impl IRStringFunction {
             },
             #[cfg(feature = "string_normalize")]
             S::Normalize { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_normalize")]
+            S::RemoveDiacritics => FunctionOptions::elementwise(),
+            S::IsAscii => FunctionOptions::elementwise(),
             #[cfg(feature = "string_reverse")]
             S::Reverse => FunctionOptions::elementwise(),
             #[cfg(feature = "temporal")]
             S::Strptime(_, options) if options.format.is_some() => FunctionOptions::elementwise(),
             #[cfg(feature = "temporal")]
             S::Strptime(_, _) => FunctionOptions::elementwise_with_infer(),
+            #[cfg(feature = "dtype-duration")]
+            S::ToDuration { .. } => FunctionOptions::elementwise(),
             S::Split(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "nightly")]
             S::Titlecase => FunctionOptions::elementwise(),
@@ -290,6 +324,12 @@ impl IRStringFunction {
             #[cfg(feature = "binary_encoding")]
             S::HexDecode(_) | S::Base64Decode(_) => FunctionOptions::elementwise(),
             S::Uppercase | S::Lowercase => FunctionOptions::elementwise(),
+            #[cfg(feature = "anonymize")]
+            S::MaskEmail | S::MaskDigits { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "collation")]
+            S::CollationKey(_) => FunctionOptions::elementwise(),
+            #[cfg(feature = "natural_sort")]
+            S::NaturalSortKey => FunctionOptions::elementwise(),
             S::StripChars
             | S::StripCharsStart
             | S::StripCharsEnd
@@ -349,6 +389,14 @@ impl Display for IRStringFunction {
             JsonPathMatch => "json_path_match",
             LenBytes => "len_bytes",
             Lowercase => "to_lowercase",
+            #[cfg(feature = "anonymize")]
+            MaskEmail => "mask_email",
+            #[cfg(feature = "anonymize")]
+            MaskDigits { .. } => "mask_digits",
+            #[cfg(feature = "collation")]
+            CollationKey(_) => "collation_key",
+            #[cfg(feature = "natural_sort")]
+            NaturalSortKey => "natural_sort_key",
             LenChars => "len_chars",
             #[cfg(feature = "string_pad")]
             PadEnd { .. } => "pad_end",
@@ -358,6 +406,9 @@ impl Display for IRStringFunction {
             Replace { .. } => "replace",
             #[cfg(feature = "string_normalize")]
             Normalize { .. } => "normalize",
+            #[cfg(feature = "string_normalize")]
+            RemoveDiacritics => "remove_diacritics",
+            IsAscii => "is_ascii",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
             #[cfg(feature = "string_encoding")]
@@ -387,6 +438,8 @@ impl Display for IRStringFunction {
             SplitN(_) => "splitn",
             #[cfg(feature = "temporal")]
             Strptime(_, _) => "strptime",
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { .. } => "to_duration",
             Split(inclusive) => {
                 if *inclusive {
                     "split_inclusive"
@@ -14,6 +14,7 @@ impl Hash for AExpr {
 
         match self {
             AExpr::Column(name) => name.hash(state),
+            #[cfg(feature = "dtype-struct")]
             AExpr::StructField(name) => name.hash(state),
             AExpr::Literal(lv) => lv.hash(state),
             AExpr::Function {
@@ -86,7 +87,8 @@ impl Hash for AExpr {
                 predicate: _,
                 truthy: _,
                 falsy: _,
-            } => {},
+                lazy,
+            } => lazy.hash(state),
             AExpr::AnonymousAgg {
                 input: _,
                 fmt_str,
@@ -100,6 +102,7 @@ impl Hash for AExpr {
                 evaluation: _,
                 variant,
             } => variant.hash(state),
+            #[cfg(feature = "dtype-struct")]
             AExpr::StructEval {
                 expr: _,
                 evaluation: _,
@@ -114,6 +117,7 @@ impl Hash for AExpr {
     }
 }
 
+#[cfg(feature = "cse")]
 pub(crate) fn traverse_and_hash_aexpr<H: Hasher>(
     node: Node,
     expr_arena: &Arena<AExpr>,
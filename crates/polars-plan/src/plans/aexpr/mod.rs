@@ -2,7 +2,6 @@ mod builder;
 mod equality;
 mod evaluate;
 mod function_expr;
-#[cfg(feature = "cse")]
 mod hash;
 mod minterm_iter;
 pub mod predicates;
@@ -229,6 +228,7 @@ pub enum AExpr {
         predicate: Node,
         truthy: Node,
         falsy: Node,
+        lazy: bool,
     },
     AnonymousAgg {
         input: Vec<ExprIR>,
@@ -321,6 +321,7 @@ impl AExpr {
                 predicate,
                 truthy,
                 falsy,
+                ..
             } => {
                 is_scalar_ae(*predicate, arena)
                     && is_scalar_ae(*truthy, arena)
@@ -405,6 +406,7 @@ impl AExpr {
                 predicate,
                 truthy,
                 falsy,
+                ..
             } => broadcasting_input_length_preserving([*predicate, *truthy, *falsy], arena),
             AExpr::Cast { expr, .. } => is_length_preserving_ae(*expr, arena),
             AExpr::Eval { expr, variant, .. } => {
@@ -11,6 +11,8 @@ use polars_utils::arena::{Arena, Node};
 use polars_utils::pl_str::PlSmallStr;
 
 use super::get_binary_expr_col_and_lv;
+#[cfg(feature = "dtype-struct")]
+use super::into_struct_field;
 use crate::dsl::Operator;
 use crate::plans::aexpr::evaluate::{constant_evaluate, into_column};
 use crate::plans::{
@@ -19,6 +21,9 @@ use crate::plans::{
 
 pub struct ColumnPredicates {
     pub predicates: PlHashMap<PlSmallStr, (Node, Option<SpecializedColumnPredicate>)>,
+    /// The paths of the struct fields that the predicates of struct columns apply to. These
+    /// predicates refer to the field by the name of the column.
+    pub struct_field_paths: PlHashMap<PlSmallStr, Vec<PlSmallStr>>,
 
     /// Are all column predicates AND-ed together the original predicate.
     pub is_sumwise_complete: bool,
@@ -31,6 +36,7 @@ pub fn aexpr_to_column_predicates(
 ) -> ColumnPredicates {
     let mut predicates =
         PlHashMap::<PlSmallStr, (Node, Option<SpecializedColumnPredicate>)>::default();
+    let mut struct_field_paths = PlHashMap::<PlSmallStr, Vec<PlSmallStr>>::default();
     let mut is_sumwise_complete = true;
 
     let minterms = MintermIter::new(root, expr_arena).collect::<Vec<_>>();
@@ -46,14 +52,40 @@ pub fn aexpr_to_column_predicates(
         }
 
         let column = leaf_names.pop().unwrap();
-        let Some(dtype) = schema.get(&column) else {
+        let Some(dtype) = schema.get(&column).cloned() else {
             is_sumwise_complete = false;
             continue;
         };
 
+        #[cfg(feature = "dtype-struct")]
+        let (minterm, dtype, struct_field_path) =
+            match struct_field_minterm(minterm, &dtype, expr_arena) {
+                Some((minterm, path, field_dtype)) => (minterm, field_dtype, path),
+                None => (minterm, dtype, Vec::new()),
+            };
+        #[cfg(not(feature = "dtype-struct"))]
+        let struct_field_path = Vec::<PlSmallStr>::new();
+        // Predicates on struct fields are evaluated on the values of the field under the name of
+        // the column.
+        let field_schema;
+        let schema = if struct_field_path.is_empty() {
+            schema
+        } else {
+            field_schema = Schema::from_iter([(column.clone(), dtype.clone())]);
+            &field_schema
+        };
+
+        let existing_path = struct_field_paths
+            .get(&column)
+            .map_or(&[][..], |p| p.as_slice());
+        if predicates.contains_key(&column) && existing_path != struct_field_path.as_slice() {
+            is_sumwise_complete = false;
+            continue;
+        }
+
         // We really don't want to deal with these types.
         use DataType as D;
-        match dtype {
+        match &dtype {
             #[cfg(feature = "dtype-categorical")]
             D::Enum(_, _) | D::Categorical(_, _) => {
                 is_sumwise_complete = false;
@@ -85,7 +117,9 @@ pub fn aexpr_to_column_predicates(
             _ => {},
         }
 
-        let dtype = dtype.clone();
+        if !struct_field_path.is_empty() {
+            struct_field_paths.insert(column.clone(), struct_field_path);
+        }
         let entry = predicates.entry(column);
 
         entry
@@ -302,10 +336,52 @@ pub fn aexpr_to_column_predicates(
 
     ColumnPredicates {
         predicates,
+        struct_field_paths,
         is_sumwise_complete,
     }
 }
 
+/// The data type of the (nested) field at `path` of a struct of type `dtype`.
+#[cfg(feature = "dtype-struct")]
+pub fn struct_field_dtype<'a>(dtype: &'a DataType, path: &[PlSmallStr]) -> Option<&'a DataType> {
+    path.iter().try_fold(dtype, |dtype, name| match dtype {
+        DataType::Struct(fields) => fields.iter().find(|f| f.name() == name).map(|f| f.dtype()),
+        _ => None,
+    })
+}
+
+/// If `minterm` is a predicate on a single (nested) field of a struct column of type `dtype`, e.g.
+/// `col("a").struct.field("x") > 5`, rewrites it to the same predicate on `col("a")`. Returns the
+/// rewritten predicate, along with the path and the data type of the field.
+#[cfg(feature = "dtype-struct")]
+fn struct_field_minterm(
+    minterm: Node,
+    dtype: &DataType,
+    expr_arena: &mut Arena<AExpr>,
+) -> Option<(Node, Vec<PlSmallStr>, DataType)> {
+    let aexpr = expr_arena.get(minterm).clone();
+    let mut inputs = Vec::new();
+    aexpr.inputs_rev(&mut inputs);
+    inputs.reverse();
+
+    let mut field = None;
+    for (i, &input) in inputs.iter().enumerate() {
+        if let Some((column, path)) = into_struct_field(input, expr_arena) {
+            if field.replace((i, column, path)).is_some() {
+                return None;
+            }
+        } else if aexpr_to_leaf_names_iter(input, expr_arena).next().is_some() {
+            return None;
+        }
+    }
+    let (i, column, path) = field?;
+    let field_dtype = struct_field_dtype(dtype, &path)?.clone();
+
+    inputs[i] = expr_arena.add(AExpr::Column(column));
+    let minterm = expr_arena.add(aexpr.replace_inputs(&inputs));
+    Some((minterm, path, field_dtype))
+}
+
 fn is_between(
     dtype: &DataType,
     low: Option<Scalar>,
@@ -366,7 +442,7 @@ mod tests {
 
     use super::*;
     use crate::dsl::Expr;
-    use crate::dsl::functions::col;
+    use crate::dsl::functions::{col, lit};
     use crate::plans::{ExprToIRContext, to_expr_ir, typed_lit};
 
     /// Given a single-column `Expr`, call `aexpr_to_column_predicates()` and
@@ -446,4 +522,55 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn column_predicate_on_struct_field() -> PolarsResult<()> {
+        use polars_core::prelude::Field;
+
+        let dtype = DataType::Struct(vec![
+            Field::new("x".into(), DataType::Int8),
+            Field::new("y".into(), DataType::String),
+        ]);
+        let schema = Schema::from_iter([("s".into(), dtype)]);
+        let column_predicates = |expr: Expr| -> PolarsResult<ColumnPredicates> {
+            let mut arena = Arena::new();
+            let mut ctx = ExprToIRContext::new(&mut arena, &schema);
+            let expr_ir = to_expr_ir(expr, &mut ctx)?;
+            Ok(aexpr_to_column_predicates(
+                expr_ir.node(),
+                &mut arena,
+                &schema,
+            ))
+        };
+        let x = || col("s").struct_().field_by_name("x");
+        let y = || col("s").struct_().field_by_name("y");
+
+        let cp = column_predicates(x().gt(typed_lit(5i8)))?;
+        assert!(cp.is_sumwise_complete);
+        assert_eq!(cp.struct_field_paths["s"], ["x"]);
+        let (_, specialized) = &cp.predicates["s"];
+        assert!(matches!(
+            specialized,
+            Some(SpecializedColumnPredicate::Between(low, high))
+                if *low == 6i8.into() && *high == i8::MAX.into()
+        ));
+
+        // Predicates on the same field are combined.
+        let cp = column_predicates(x().gt(typed_lit(5i8)).and(x().lt(typed_lit(9i8))))?;
+        assert!(cp.is_sumwise_complete);
+        assert_eq!(cp.predicates.len(), 1);
+
+        // Only one field per column is supported.
+        let cp = column_predicates(x().gt(typed_lit(5i8)).and(y().eq(lit("a"))))?;
+        assert!(!cp.is_sumwise_complete);
+        assert_eq!(cp.predicates.len(), 1);
+
+        // Neither are predicates that compare fields.
+        let cp = column_predicates(x().cast(DataType::String).eq(y()))?;
+        assert!(!cp.is_sumwise_complete);
+        assert!(cp.predicates.is_empty());
+
+        Ok(())
+    }
 }
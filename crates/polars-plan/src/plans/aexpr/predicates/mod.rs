@@ -33,3 +33,32 @@ fn get_binary_expr_col_and_lv<'a>(
         _ => None,
     }
 }
+
+/// Returns the column and the path of fields if `e` is a (nested) field of a struct column.
+#[cfg(feature = "dtype-struct")]
+pub(crate) fn into_struct_field(
+    e: Node,
+    arena: &Arena<AExpr>,
+) -> Option<(PlSmallStr, Vec<PlSmallStr>)> {
+    use crate::plans::{IRFunctionExpr, IRStructFunction};
+
+    let mut path = Vec::new();
+    let mut e = e;
+    loop {
+        match arena.get(e) {
+            AExpr::Column(c) if !path.is_empty() => {
+                path.reverse();
+                return Some((c.clone(), path));
+            },
+            AExpr::Function {
+                input,
+                function: IRFunctionExpr::StructExpr(IRStructFunction::FieldByName(name)),
+                ..
+            } if input.len() == 1 => {
+                path.push(name.clone());
+                e = input[0].node();
+            },
+            _ => return None,
+        }
+    }
+}
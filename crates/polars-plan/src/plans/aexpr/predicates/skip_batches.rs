@@ -8,11 +8,15 @@ use polars_utils::arena::{Arena, Node};
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
 
+#[cfg(feature = "dtype-struct")]
+use super::super::IRStructFunction;
 use super::super::evaluate::{constant_evaluate, into_column};
 use super::super::{AExpr, IRBooleanFunction, IRFunctionExpr, LiteralValue, Operator};
 use crate::plans::aexpr::builder::IntoAExprBuilder;
 use crate::plans::predicates::get_binary_expr_col_and_lv;
-use crate::plans::{AExprBuilder, aexpr_to_leaf_names_iter, is_scalar_ae, rename_columns};
+#[cfg(feature = "dtype-struct")]
+use crate::plans::predicates::into_struct_field;
+use crate::plans::{AExprBuilder, ExprIR, aexpr_to_leaf_names_iter, is_scalar_ae, rename_columns};
 
 /// Return a new boolean expression determines whether a batch can be skipped based on min, max and
 /// null count statistics.
@@ -73,6 +77,82 @@ fn is_stat_defined(
     result
 }
 
+/// Skip batch predicate for comparing a field of a struct column with a literal.
+///
+/// The `{col}_min` and `{col}_max` statistics of a struct column are structs of the min and max
+/// of its fields, so the field is taken from those. As the null count of the field is not known,
+/// only the non-null literal cases are supported.
+#[cfg(feature = "dtype-struct")]
+fn struct_field_skip_batch_predicate(
+    left: Node,
+    op: Operator,
+    right: Node,
+    arena: &mut Arena<AExpr>,
+    schema: &Schema,
+) -> Option<Node> {
+    use Operator as O;
+
+    let (field_node, lv_node, op) = match into_struct_field(left, arena) {
+        Some(_) => (left, right, op),
+        None => (right, left, op.swap_operands()),
+    };
+    let (col, path) = into_struct_field(field_node, arena)?;
+
+    let mut dtype = schema.get(&col)?;
+    for name in &path {
+        let DataType::Struct(fields) = dtype else {
+            return None;
+        };
+        dtype = &fields.iter().find(|f| f.name() == name)?.dtype;
+    }
+
+    let lv = constant_evaluate(lv_node, arena, schema, 0)??;
+    if lv.is_null() || !can_use_min_max_stats(dtype, Some(&op), Some(lv.as_ref())) {
+        return None;
+    }
+
+    let field_stat = |suffix: &str, arena: &mut Arena<AExpr>| {
+        let mut expr = AExprBuilder::col(format_pl_smallstr!("{col}_{suffix}"), arena);
+        for name in &path {
+            expr = AExprBuilder::function(
+                vec![ExprIR::from_node(expr.node(), arena)],
+                IRFunctionExpr::StructExpr(IRStructFunction::FieldByName(name.clone())),
+                arena,
+            );
+        }
+        let is_defined = is_stat_defined(expr, dtype, arena);
+        (expr, is_defined)
+    };
+
+    // field(A) == B -> min(A) > B || max(A) < B
+    // field(A) < B  -> min(A) >= B
+    // field(A) <= B -> min(A) > B
+    // field(A) > B  -> max(A) <= B
+    // field(A) >= B -> max(A) < B
+    let expr = match op {
+        O::Eq | O::EqValidity => {
+            let (col_min, min_is_defined) = field_stat("min", arena);
+            let (col_max, max_is_defined) = field_stat("max", arena);
+
+            let min_gt = col_min.gt(lv_node, arena).and(min_is_defined, arena);
+            let max_lt = col_max.lt(lv_node, arena).and(max_is_defined, arena);
+            min_gt.or(max_lt, arena)
+        },
+        O::Lt | O::LtEq | O::Gt | O::GtEq => {
+            let ((stat, is_defined), cmp_op) = match op {
+                O::Lt => (field_stat("min", arena), O::GtEq),
+                O::LtEq => (field_stat("min", arena), O::Gt),
+                O::Gt => (field_stat("max", arena), O::LtEq),
+                O::GtEq => (field_stat("max", arena), O::Lt),
+                _ => unreachable!(),
+            };
+            is_defined.and(stat.binary_op(lv_node, cmp_op, arena), arena)
+        },
+        _ => return None,
+    };
+    Some(expr.node())
+}
+
 #[recursive::recursive]
 fn aexpr_to_skip_batch_predicate_rec(
     e: Node,
@@ -133,6 +213,14 @@ fn aexpr_to_skip_batch_predicate_rec(
             }
         }
 
+        #[cfg(feature = "dtype-struct")]
+        if let AExpr::BinaryExpr { left, op, right } = arena.get(e) {
+            let (left, op, right) = (*left, *op, *right);
+            if let Some(expr) = struct_field_skip_batch_predicate(left, op, right, arena, schema) {
+                return Some(expr);
+            }
+        }
+
         match arena.get(e) {
             AExpr::Element => None,
             AExpr::Explode { .. } => None,
@@ -510,11 +598,12 @@ fn aexpr_to_skip_batch_predicate_rec(
     }));
 
     // We cannot do proper equalities for these. For floats, min/max stats exclude
-    // NaN, so substituting col=min doesn't account for hidden NaN values.
+    // NaN, so substituting col=min doesn't account for hidden NaN values. The min/max of nested
+    // columns only bound their fields separately.
     if live_columns.iter().any(|(c, _)| {
         schema
             .get(c)
-            .is_none_or(|dt| dt.is_categorical() || dt.is_float())
+            .is_none_or(|dt| dt.is_categorical() || dt.is_float() || dt.is_nested())
     }) {
         return None;
     }
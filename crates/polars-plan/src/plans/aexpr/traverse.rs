@@ -40,6 +40,7 @@ impl AExpr {
                 truthy,
                 falsy,
                 predicate,
+                ..
             } => {
                 container.extend([*predicate, *falsy, *truthy]);
             },
@@ -156,6 +157,7 @@ impl AExpr {
                 truthy,
                 falsy,
                 predicate,
+                ..
             } => {
                 container.extend([*predicate, *falsy, *truthy]);
             },
@@ -255,6 +257,7 @@ impl AExpr {
                 truthy,
                 falsy,
                 predicate,
+                ..
             } => {
                 *truthy = inputs[0];
                 *falsy = inputs[1];
@@ -375,6 +378,7 @@ impl AExpr {
                 truthy,
                 falsy,
                 predicate,
+                ..
             } => {
                 *truthy = inputs[0];
                 *falsy = inputs[1];
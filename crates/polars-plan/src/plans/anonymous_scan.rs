@@ -4,6 +4,19 @@ use std::fmt::{Debug, Formatter};
 use polars_core::prelude::*;
 
 use crate::dsl::Expr;
+use crate::plans::Sorted;
+
+/// How an [`AnonymousScan`] applies a predicate that the planner offers to it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PredicateSupport {
+    /// The scan only produces the rows for which the predicate holds.
+    Exact,
+    /// The scan uses the predicate to skip data, but may produce rows for which it doesn't hold.
+    /// The planner still filters the output of the scan.
+    Inexact,
+    /// The scan ignores the predicate. The planner filters the output of the scan.
+    Unsupported,
+}
 
 pub struct AnonymousScanArgs {
     pub n_rows: Option<usize>,
@@ -35,6 +48,37 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_projection_pushdown(&self) -> bool {
         false
     }
+
+    /// How the scan applies `predicate`, one of the conjunctions of the filters above the scan.
+    /// The predicates the scan supports are passed combined in [`AnonymousScanArgs::predicate`],
+    /// the others and the [`PredicateSupport::Inexact`] ones are kept in a filter after the scan.
+    ///
+    /// Defaults to [`PredicateSupport::Exact`] if
+    /// [`allows_predicate_pushdown`](Self::allows_predicate_pushdown) and to
+    /// [`PredicateSupport::Unsupported`] otherwise.
+    fn predicate_support(&self, _predicate: &Expr) -> PredicateSupport {
+        if self.allows_predicate_pushdown() {
+            PredicateSupport::Exact
+        } else {
+            PredicateSupport::Unsupported
+        }
+    }
+
+    /// Specify if the scan provider should allow pushing down a slice from the start of the scan,
+    /// passed as [`AnonymousScanArgs::n_rows`]. If not, the slice is applied after the scan.
+    ///
+    /// Defaults to `true`
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+
+    /// The columns by which the output of the scan is sorted, in order of precedence. The planner
+    /// uses this to avoid sorting the output again.
+    ///
+    /// Defaults to no columns.
+    fn sorted_by(&self) -> Vec<Sorted> {
+        vec![]
+    }
 }
 
 impl Debug for dyn AnonymousScan {
@@ -0,0 +1,145 @@
+//! Audit records of the data that queries read and write.
+//!
+//! When an [`AuditSink`] is set with [`set_audit_sink`], every query that is collected or sunk
+//! reports the sources and columns it read and the targets it wrote. The record is taken from the
+//! optimized plan, so files that were skipped based on the predicates and columns that were
+//! pruned by projection pushdown are not part of it.
+use std::sync::{Arc, LazyLock, RwLock};
+
+use polars_core::prelude::*;
+use polars_utils::arena::{Arena, Node};
+
+use crate::prelude::*;
+
+/// Columns of a source that were read, or of a target that was written.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditAccess {
+    /// The path of the file or directory. In-memory sources are reported as `in-mem`, opened
+    /// files as `open-file` and Python scans as `python`.
+    pub target: PlSmallStr,
+    pub columns: Vec<PlSmallStr>,
+}
+
+/// The data a query accessed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AuditRecord {
+    pub reads: Vec<AuditAccess>,
+    pub writes: Vec<AuditAccess>,
+    /// The error the query failed with. A failed query may have read or written only part of
+    /// the data.
+    pub error: Option<PlSmallStr>,
+}
+
+/// Receives the [`AuditRecord`] of every query after it ran.
+pub trait AuditSink: Send + Sync {
+    fn record(&self, record: AuditRecord);
+}
+
+static AUDIT_SINK: LazyLock<RwLock<Option<Arc<dyn AuditSink>>>> = LazyLock::new(Default::default);
+
+/// Set the sink that receives the audit records of all queries, returning the previous sink.
+/// `None` disables auditing.
+pub fn set_audit_sink(sink: Option<Arc<dyn AuditSink>>) -> Option<Arc<dyn AuditSink>> {
+    std::mem::replace(&mut *AUDIT_SINK.write().unwrap(), sink)
+}
+
+pub fn get_audit_sink() -> Option<Arc<dyn AuditSink>> {
+    AUDIT_SINK.read().unwrap().clone()
+}
+
+impl AuditRecord {
+    /// Collect the reads and writes of the optimized plan rooted at `root`.
+    pub fn from_plan(root: Node, lp_arena: &Arena<IR>) -> Self {
+        let mut record = Self::default();
+        let mut visited = PlHashSet::new();
+        let mut stack = vec![root];
+
+        while let Some(node) = stack.pop() {
+            // Shared subplans are only read once.
+            if !visited.insert(node) {
+                continue;
+            }
+            let ir = lp_arena.get(node);
+            match ir {
+                IR::Scan {
+                    sources,
+                    file_info,
+                    hive_parts,
+                    output_schema,
+                    unified_scan_args,
+                    ..
+                } => {
+                    let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
+                    // Columns that are not stored in the files.
+                    let is_virtual = |name: &str| {
+                        hive_parts
+                            .as_ref()
+                            .is_some_and(|hive| hive.schema().contains(name))
+                            || unified_scan_args
+                                .row_index
+                                .as_ref()
+                                .is_some_and(|ri| ri.name == name)
+                            || unified_scan_args.include_file_paths.as_deref() == Some(name)
+                            || unified_scan_args.provenance.contains(name)
+                    };
+                    let columns = schema
+                        .iter_names()
+                        .filter(|name| !is_virtual(name))
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    record
+                        .reads
+                        .extend(sources.iter().map(|source| AuditAccess {
+                            target: source.to_include_path_name().into(),
+                            columns: columns.clone(),
+                        }));
+                },
+                IR::DataFrameScan {
+                    schema,
+                    output_schema,
+                    ..
+                } => record.reads.push(AuditAccess {
+                    target: PlSmallStr::from_static("in-mem"),
+                    columns: output_schema
+                        .as_ref()
+                        .unwrap_or(schema)
+                        .iter_names_cloned()
+                        .collect(),
+                }),
+                #[cfg(feature = "python")]
+                IR::PythonScan { options } => record.reads.push(AuditAccess {
+                    target: PlSmallStr::from_static("python"),
+                    columns: options
+                        .output_schema
+                        .as_ref()
+                        .unwrap_or(&options.schema)
+                        .iter_names_cloned()
+                        .collect(),
+                }),
+                IR::Sink { input, payload } => {
+                    let target = match payload {
+                        SinkTypeIR::File(options) => match &options.target {
+                            SinkTarget::Path(path) => Some(PlSmallStr::from_str(path.as_str())),
+                            SinkTarget::Dyn(_) => Some(PlSmallStr::from_static("in-mem")),
+                        },
+                        SinkTypeIR::Partitioned(options) => {
+                            Some(PlSmallStr::from_str(options.base_path.as_str()))
+                        },
+                        SinkTypeIR::Memory | SinkTypeIR::Callback(_) => None,
+                    };
+                    if let Some(target) = target {
+                        let schema = lp_arena.get(*input).schema(lp_arena);
+                        record.writes.push(AuditAccess {
+                            target,
+                            columns: schema.iter_names_cloned().collect(),
+                        });
+                    }
+                },
+                _ => {},
+            }
+            ir.copy_inputs(&mut stack);
+        }
+
+        record
+    }
+}
@@ -0,0 +1,91 @@
+//! Use of the indexes of in-memory frames (see [`DataFrame::create_index`]) by the engines.
+use polars_core::frame::ValueBounds;
+use polars_core::prelude::*;
+use polars_utils::arena::{Arena, Node};
+
+use crate::prelude::*;
+
+/// Get the bounds that the comparisons with literals in `predicate` put on the indexed columns
+/// of `df`.
+pub fn index_bounds(
+    df: &DataFrame,
+    predicate: Node,
+    expr_arena: &Arena<AExpr>,
+) -> Vec<(PlSmallStr, ValueBounds)> {
+    let mut out: Vec<(PlSmallStr, ValueBounds)> = vec![];
+    let indexes = df.indexes();
+    if indexes.is_empty() {
+        return out;
+    }
+
+    for node in MintermIter::new(predicate, expr_arena) {
+        let AExpr::BinaryExpr { left, op, right } = expr_arena.get(node) else {
+            continue;
+        };
+        if !matches!(
+            op,
+            Operator::Eq | Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq
+        ) {
+            continue;
+        }
+        let (name, op, value) = match (expr_arena.get(*left), expr_arena.get(*right)) {
+            (AExpr::Column(name), AExpr::Literal(LiteralValue::Scalar(value))) => {
+                (name, *op, value)
+            },
+            (AExpr::Literal(LiteralValue::Scalar(value)), AExpr::Column(name)) => {
+                (name, op.swap_operands(), value)
+            },
+            _ => continue,
+        };
+        let indexed = indexes.iter().any(|index| index.name() == name);
+        if !indexed || df.column(name).map(|c| c.dtype()).ok() != Some(value.dtype()) {
+            continue;
+        }
+
+        let idx = match out.iter().position(|(indexed, _)| indexed == name) {
+            Some(idx) => idx,
+            None => {
+                out.push((name.clone(), ValueBounds::default()));
+                out.len() - 1
+            },
+        };
+        let bounds = &mut out[idx].1;
+        match op {
+            Operator::Eq => {
+                bounds.restrict_lower(value.clone(), true);
+                bounds.restrict_upper(value.clone(), true);
+            },
+            Operator::Gt => bounds.restrict_lower(value.clone(), false),
+            Operator::GtEq => bounds.restrict_lower(value.clone(), true),
+            Operator::Lt => bounds.restrict_upper(value.clone(), false),
+            Operator::LtEq => bounds.restrict_upper(value.clone(), true),
+            _ => unreachable!(),
+        }
+    }
+    out
+}
+
+/// Get the rows of `df` given by the index that selects the fewest rows for its bounds, or
+/// `None` if none of the columns is indexed.
+///
+/// This may be a superset of the rows within the bounds, so a filter must still be applied.
+pub fn index_candidates(
+    df: &DataFrame,
+    bounds: &[(PlSmallStr, ValueBounds)],
+) -> PolarsResult<Option<IdxCa>> {
+    let mut out: Option<IdxCa> = None;
+    for (name, bounds) in bounds {
+        let Some(index) = df.sorted_index(name).or_else(|| {
+            df.indexes()
+                .into_iter()
+                .find(|index| index.name() == name)
+        }) else {
+            continue;
+        };
+        let idx = index.candidates(bounds)?;
+        if out.as_ref().is_none_or(|out| idx.len() < out.len()) {
+            out = Some(idx);
+        }
+    }
+    Ok(out)
+}
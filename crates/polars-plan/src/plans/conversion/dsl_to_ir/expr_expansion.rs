@@ -263,7 +263,7 @@ fn expand_expression_rec(
 ) -> PolarsResult<usize> {
     let start_len = out.len();
     match &expr {
-        Expr::Element => out.push(expr.clone()),
+        Expr::Element | Expr::Parameter(_) => out.push(expr.clone()),
         Expr::Alias(subexpr, name) => {
             _ = expand_single(
                 subexpr.as_ref(),
@@ -600,6 +600,7 @@ fn expand_expression_rec(
             predicate,
             truthy,
             falsy,
+            lazy,
         } => {
             _ = expand_expression_by_combination(
                 &[
@@ -615,6 +616,7 @@ fn expand_expression_rec(
                     predicate: Arc::new(e[0].clone()),
                     truthy: Arc::new(e[1].clone()),
                     falsy: Arc::new(e[2].clone()),
+                    lazy: *lazy,
                 },
             )?
         },
@@ -360,6 +360,7 @@ pub(super) fn to_aexpr_impl(
             predicate,
             truthy,
             falsy,
+            lazy,
         } => {
             let (p, _) = to_aexpr_mat_lit_arc!(predicate)?;
             let (t, output_name) = recurse_arc!(truthy)?;
@@ -369,6 +370,7 @@ pub(super) fn to_aexpr_impl(
                     predicate: p,
                     truthy: t,
                     falsy: f,
+                    lazy,
                 },
                 output_name,
             )
@@ -616,6 +618,9 @@ pub(super) fn to_aexpr_impl(
             (AExpr::StructField(name.clone()), name.clone())
         },
 
+        Expr::Parameter(name) => {
+            polars_bail!(InvalidOperation: "parameter '{}' is not bound, bind it with `bind_parameters`", name)
+        },
         e @ Expr::SubPlan { .. } | e @ Expr::Selector(_) => {
             polars_bail!(InvalidOperation: "'Expr: {}' not allowed in this context/location", e)
         },
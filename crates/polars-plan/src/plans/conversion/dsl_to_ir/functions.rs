@@ -238,6 +238,14 @@ pub(super) fn convert_functions(
                 S::LenBytes => IS::LenBytes,
                 S::LenChars => IS::LenChars,
                 S::Lowercase => IS::Lowercase,
+                #[cfg(feature = "anonymize")]
+                S::MaskEmail => IS::MaskEmail,
+                #[cfg(feature = "anonymize")]
+                S::MaskDigits { keep_last } => IS::MaskDigits { keep_last },
+                #[cfg(feature = "collation")]
+                S::CollationKey(options) => IS::CollationKey(options),
+                #[cfg(feature = "natural_sort")]
+                S::NaturalSortKey => IS::NaturalSortKey,
                 #[cfg(feature = "extract_jsonpath")]
                 S::JsonDecode(dtype) => IS::JsonDecode(dtype.into_datatype(ctx.schema)?),
                 #[cfg(feature = "extract_jsonpath")]
@@ -246,6 +254,9 @@ pub(super) fn convert_functions(
                 S::Replace { n, literal } => IS::Replace { n, literal },
                 #[cfg(feature = "string_normalize")]
                 S::Normalize { form } => IS::Normalize { form },
+                #[cfg(feature = "string_normalize")]
+                S::RemoveDiacritics => IS::RemoveDiacritics,
+                S::IsAscii => IS::IsAscii,
                 #[cfg(feature = "string_reverse")]
                 S::Reverse => IS::Reverse,
                 #[cfg(feature = "string_pad")]
@@ -290,6 +301,8 @@ pub(super) fn convert_functions(
                     );
                     IS::Strptime(dtype, strptime_options)
                 },
+                #[cfg(feature = "dtype-duration")]
+                S::ToDuration { time_unit, strict } => IS::ToDuration { time_unit, strict },
                 S::Split(v) => IS::Split(v),
                 #[cfg(feature = "dtype-decimal")]
                 S::ToDecimal { scale } => IS::ToDecimal { scale },
@@ -391,7 +404,7 @@ pub(super) fn convert_functions(
                 T::TotalMicroseconds { fractional } => IT::TotalMicroseconds { fractional },
                 #[cfg(feature = "dtype-duration")]
                 T::TotalNanoseconds { fractional } => IT::TotalNanoseconds { fractional },
-                T::ToString(v) => IT::ToString(v),
+                T::ToString { format, locale } => IT::ToString { format, locale },
                 T::CastTimeUnit(time_unit) => IT::CastTimeUnit(time_unit),
                 T::WithTimeUnit(time_unit) => IT::WithTimeUnit(time_unit),
                 #[cfg(feature = "timezones")]
@@ -543,6 +556,8 @@ pub(super) fn convert_functions(
                 IRBusinessFunction::IsBusinessDay { week_mask }
             },
         }),
+        #[cfg(feature = "interval")]
+        F::Interval(f) => I::Interval(f),
         #[cfg(feature = "abs")]
         F::Abs => I::Abs,
         F::Negate => I::Negate,
@@ -570,6 +585,16 @@ pub(super) fn convert_functions(
         F::IndexOf => I::IndexOf,
         #[cfg(feature = "search_sorted")]
         F::SearchSorted { side, descending } => I::SearchSorted { side, descending },
+        #[cfg(feature = "search_sorted")]
+        F::SearchSortedBy {
+            side,
+            descending,
+            nulls_last,
+        } => I::SearchSortedBy {
+            side,
+            descending,
+            nulls_last,
+        },
         #[cfg(feature = "range")]
         F::Range(range_function) => I::Range(match range_function {
             RangeFunction::IntRange { step, dtype } => {
@@ -909,11 +934,16 @@ pub(super) fn convert_functions(
             labels,
             left_closed,
             include_breaks,
-        } => I::Cut {
-            breaks,
-            labels,
-            left_closed,
-            include_breaks,
+            include_bounds,
+        } => {
+            polars_ops::series::check_cut_outputs(include_breaks, include_bounds)?;
+            I::Cut {
+                breaks,
+                labels,
+                left_closed,
+                include_breaks,
+                include_bounds,
+            }
         },
         #[cfg(feature = "cutqcut")]
         F::QCut {
@@ -922,18 +952,62 @@ pub(super) fn convert_functions(
             left_closed,
             allow_duplicates,
             include_breaks,
-        } => I::QCut {
-            probs,
-            labels,
-            left_closed,
-            allow_duplicates,
-            include_breaks,
+            include_bounds,
+        } => {
+            polars_ops::series::check_cut_outputs(include_breaks, include_bounds)?;
+            I::QCut {
+                probs,
+                labels,
+                left_closed,
+                allow_duplicates,
+                include_breaks,
+                include_bounds,
+            }
         },
         #[cfg(feature = "rle")]
         F::RLE => I::RLE,
         #[cfg(feature = "rle")]
         F::RLEID => I::RLEID,
+        #[cfg(feature = "to_dummies")]
+        F::ToDummies {
+            categories,
+            separator,
+            drop_first,
+        } => I::ToDummies {
+            categories,
+            separator,
+            drop_first,
+        },
+        #[cfg(feature = "fft")]
+        F::Fft => I::Fft,
+        #[cfg(feature = "fft")]
+        F::PowerSpectrum { window } => I::PowerSpectrum { window },
+        #[cfg(feature = "convolve")]
+        F::Convolve {
+            kernel,
+            mode,
+            nulls,
+        } => I::Convolve {
+            kernel,
+            mode,
+            nulls,
+        },
+        #[cfg(feature = "convolve")]
+        F::Savgol {
+            window,
+            polyorder,
+            nulls,
+        } => I::Savgol {
+            window,
+            polyorder,
+            nulls,
+        },
+        #[cfg(feature = "anonymize")]
+        F::BucketizeNumeric { k_anonymity } => I::BucketizeNumeric { k_anonymity },
         F::ToPhysical => I::ToPhysical,
+        F::WithFieldMetadata(metadata) => I::WithFieldMetadata(metadata),
+        F::SetUnit(unit) => I::SetUnit(unit),
+        F::ConvertUnit(unit) => I::ConvertUnit { unit, factor: None },
         #[cfg(feature = "random")]
         F::Random { method, seed } => {
             use {IRRandomMethod as IR, RandomMethod as R};
@@ -953,6 +1027,24 @@ pub(super) fn convert_functions(
                 seed,
             }
         },
+        #[cfg(feature = "random")]
+        F::DpNoise {
+            epsilon,
+            sensitivity,
+        } => {
+            polars_ensure!(
+                epsilon > 0.0 && epsilon.is_finite(),
+                InvalidOperation: "the epsilon of `dp_noise` must be positive and finite, got {}", epsilon
+            );
+            polars_ensure!(
+                sensitivity >= 0.0 && sensitivity.is_finite(),
+                InvalidOperation: "the sensitivity of `dp_noise` must be non-negative and finite, got {}", sensitivity
+            );
+            I::DpNoise {
+                epsilon,
+                sensitivity,
+            }
+        },
         F::SetSortedFlag(sorted) => I::SetSortedFlag(sorted),
         #[cfg(feature = "ffi_plugin")]
         F::FfiPlugin {
@@ -1012,6 +1104,11 @@ pub(super) fn convert_functions(
         F::MinHorizontal => I::MinHorizontal,
         F::SumHorizontal { ignore_nulls } => I::SumHorizontal { ignore_nulls },
         F::MeanHorizontal { ignore_nulls } => I::MeanHorizontal { ignore_nulls },
+        #[cfg(feature = "scan_fn")]
+        F::ScanFn { step, return_dtype } => I::ScanFn {
+            step,
+            return_dtype: return_dtype.try_map(|dtype| dtype.into_datatype(ctx.schema))?,
+        },
         #[cfg(feature = "ewma")]
         F::EwmMean { options } => I::EwmMean { options },
         #[cfg(feature = "ewma_by")]
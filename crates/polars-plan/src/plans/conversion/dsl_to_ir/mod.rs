@@ -20,6 +20,7 @@ use crate::dsl::PartitionedSinkOptions;
 use crate::dsl::file_provider::{FileProviderType, HivePathProvider};
 use crate::dsl::functions::{all_horizontal, col};
 use crate::plans::conversion::dsl_to_ir::scans::SourcesToFileInfo;
+use crate::plans::field_metadata::resolve_metadata_selectors;
 
 mod concat;
 mod datatype_fn_to_ir;
@@ -28,6 +29,7 @@ mod expr_to_ir;
 mod functions;
 mod join;
 mod scans;
+mod subplan;
 mod utils;
 pub use expr_expansion::{expand_expression, is_regex_projection, prepare_projection};
 pub use expr_to_ir::{ExprToIRContext, to_expr_ir};
@@ -195,7 +197,11 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 },
             }
         },
-        DslPlan::Union { inputs, args } => {
+        DslPlan::Union {
+            inputs,
+            args,
+            partitioning,
+        } => {
             let mut inputs = inputs
                 .into_iter()
                 .map(|lp| to_alp_impl(lp, ctxt))
@@ -227,8 +233,19 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     got\n{:?} and \n{:?}", schema, schema_i)
                 )?;
             }
+            if let Some(partitioning) = &partitioning {
+                for key in partitioning.keys.iter() {
+                    polars_ensure!(
+                        schema.contains(key),
+                        ColumnNotFound: "partition key '{}' is not in the 'union'/'concat' inputs", key
+                    );
+                }
+            }
 
-            let options = args.into();
+            let options = UnionOptions {
+                partitioning,
+                ..args.into()
+            };
             IR::Union { inputs, options }
         },
         DslPlan::HConcat { inputs, options } => {
@@ -247,6 +264,10 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             }
         },
         DslPlan::Filter { input, predicate } => {
+            if subplan::has_subplan(std::slice::from_ref(&predicate)) {
+                let lp = subplan::rewrite_filter(input, predicate, ctxt)?;
+                return to_alp_impl(lp, ctxt).map_err(|e| e.context(failed_here!(filter)));
+            }
             let mut input =
                 to_alp_impl(owned(input), ctxt).map_err(|e| e.context(failed_here!(filter)))?;
             let input_schema = ctxt.lp_arena.get(input).schema(ctxt.lp_arena);
@@ -380,8 +401,14 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             input,
             options,
         } => {
+            if subplan::has_subplan(&expr) {
+                let lp = subplan::rewrite_select(input, expr, options)?;
+                return to_alp_impl(lp, ctxt).map_err(|e| e.context(failed_here!(select)));
+            }
             let input =
                 to_alp_impl(owned(input), ctxt).map_err(|e| e.context(failed_here!(select)))?;
+            let expr = resolve_metadata_selectors(expr, input, ctxt.lp_arena, ctxt.expr_arena)
+                .map_err(|e| e.context(failed_here!(select)))?;
             let input_schema = ctxt.lp_arena.get(input).schema(ctxt.lp_arena);
             let (exprs, schema) = prepare_projection(expr, &input_schema, ctxt.opt_flags)
                 .map_err(|e| e.context(failed_here!(select)))?;
@@ -647,6 +674,10 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             exprs,
             options,
         } => {
+            if subplan::has_subplan(&exprs) {
+                let lp = subplan::rewrite_with_columns(input, exprs, options)?;
+                return to_alp_impl(lp, ctxt).map_err(|e| e.context(failed_here!(with_columns)));
+            }
             let input = to_alp_impl(owned(input), ctxt)
                 .map_err(|e| e.context(failed_here!(with_columns)))?;
             let (exprs, schema) =
@@ -1522,6 +1553,7 @@ fn resolve_with_columns(
     expr_arena: &mut Arena<AExpr>,
     opt_flags: &mut OptFlags,
 ) -> PolarsResult<(Vec<ExprIR>, SchemaRef)> {
+    let exprs = resolve_metadata_selectors(exprs, input, lp_arena, expr_arena)?;
     let input_schema = lp_arena.get(input).schema(lp_arena);
     let mut output_schema = (**input_schema).clone();
     let exprs = rewrite_projections(exprs, &PlHashSet::new(), &input_schema, opt_flags)?;
@@ -1559,6 +1591,8 @@ fn resolve_group_by(
     expr_arena: &mut Arena<AExpr>,
     opt_flags: &mut OptFlags,
 ) -> PolarsResult<(Vec<ExprIR>, Vec<ExprIR>, SchemaRef)> {
+    let keys = resolve_metadata_selectors(keys, input, lp_arena, expr_arena)?;
+    let aggs = resolve_metadata_selectors(aggs, input, lp_arena, expr_arena)?;
     let input_schema = lp_arena.get(input).schema(lp_arena);
     let input_schema = input_schema.as_ref();
     let mut keys = rewrite_projections(keys, &PlHashSet::default(), input_schema, opt_flags)?;
@@ -11,6 +11,7 @@ use polars_io::utils::stream_buf_reader::ReaderSource;
 use polars_io::{RowIndex, pl_async};
 
 use super::*;
+use crate::dsl::schema_evolution::SchemaEvolutionPolicy;
 
 pub(super) async fn dsl_to_ir(
     sources: ScanSources,
@@ -91,6 +92,28 @@ pub(super) async fn dsl_to_ir(
                 .await?
         };
 
+        if let Some(policy) = unified_scan_args.schema_evolution.as_mut() {
+            polars_ensure!(
+                unified_scan_args.column_mapping.is_none(),
+                InvalidOperation: "schema evolution cannot be combined with a column mapping"
+            );
+
+            // The NDJSON reader fills absent fields with NULLs, so a missing or renamed column
+            // cannot be detected per file.
+            #[cfg(feature = "json")]
+            polars_ensure!(
+                !matches!(&*scan_type, FileScanDsl::NDJson { .. })
+                    || (policy.renames.is_empty() && policy.missing_defaults.is_empty()),
+                nyi = "schema evolution renames or missing column defaults for NDJSON scans"
+            );
+
+            let policy = Arc::make_mut(policy);
+            apply_schema_evolution(&mut file_info, policy)?;
+            policy
+                .type_widening
+                .apply_to(&mut unified_scan_args.cast_columns_policy);
+        }
+
         if unified_scan_args.hive_options.enabled.is_none() {
             // We expect this to be `Some(_)` after this point. If it hasn't been auto-enabled
             // we explicitly set it to disabled.
@@ -147,6 +170,31 @@ pub(super) async fn dsl_to_ir(
             schema.insert_at_index(schema.len(), file_path_col.clone(), DataType::String)?;
         }
 
+        if unified_scan_args.provenance.row_group_index.is_some() {
+            #[cfg(feature = "parquet")]
+            let is_parquet = matches!(&*scan_type, FileScanDsl::Parquet { .. });
+            #[cfg(not(feature = "parquet"))]
+            let is_parquet = false;
+
+            polars_ensure!(
+                is_parquet,
+                InvalidOperation: "row group index column is only supported for Parquet scans"
+            );
+        }
+
+        for (name, dtype) in unified_scan_args.provenance.iter() {
+            let schema: &mut Schema = Arc::make_mut(&mut file_info.schema);
+
+            if schema.contains(name) {
+                polars_bail!(
+                    Duplicate: r#"column name for provenance "{}" conflicts with column name from file"#,
+                    name
+                );
+            }
+
+            schema.insert_at_index(schema.len(), name.clone(), dtype)?;
+        }
+
         unified_scan_args.projection = if let Some(file_schema) = file_info.reader_schema.as_ref() {
             maybe_init_projection_excluding_hive(
                 file_schema,
@@ -163,6 +211,37 @@ pub(super) async fn dsl_to_ir(
                 .unwrap();
         }
 
+        if let Some(sidecar_path) = unified_scan_args.statistics_sidecar.as_ref()
+            && unified_scan_args.table_statistics.is_none()
+            && let Some(paths) = sources.as_paths()
+            && !paths.is_empty()
+        {
+            use polars_core::error::feature_gated;
+
+            feature_gated!("ipc", {
+                let table_statistics = crate::dsl::statistics_sidecar::load_statistics_sidecar(
+                    sidecar_path,
+                    paths,
+                    &file_info.schema,
+                    unified_scan_args.cloud_options.as_ref(),
+                )
+                .await?;
+
+                if verbose {
+                    eprintln!(
+                        "dsl_to_ir: loaded statistics sidecar {sidecar_path} for {} files",
+                        paths.len()
+                    );
+                }
+
+                if let Some(num_rows) = table_statistics.num_rows() {
+                    file_info.row_estimation = (None, num_rows);
+                }
+
+                unified_scan_args.table_statistics = Some(table_statistics);
+            })
+        }
+
         let ir = if sources.is_empty() && !matches!(&(*scan_type), FileScanDsl::Anonymous { .. }) {
             IR::DataFrameScan {
                 df: Arc::new(DataFrame::empty_with_schema(&file_info.schema)),
@@ -191,6 +270,55 @@ pub(super) async fn dsl_to_ir(
     Ok(())
 }
 
+/// Renames former column names in the inferred schemas, and resolves the policy against the
+/// resulting schema.
+fn apply_schema_evolution(
+    file_info: &mut FileInfo,
+    policy: &mut SchemaEvolutionPolicy,
+) -> PolarsResult<()> {
+    let schema = Arc::make_mut(&mut file_info.schema);
+    policy.rename_schema(schema);
+    policy.resolve(schema)?;
+
+    // Columns added by the policy must also be in the reader schema, as it is used to initialize
+    // the projection.
+    match file_info.reader_schema.as_mut() {
+        Some(Either::Left(reader_schema)) => {
+            let reader_schema = Arc::make_mut(reader_schema);
+
+            for (former, current) in policy.renames.iter() {
+                if !reader_schema.contains(current)
+                    && reader_schema.rename(former, current.clone()).is_some()
+                {
+                    reader_schema.get_mut(current).unwrap().name = current.clone();
+                }
+            }
+
+            for (name, dtype) in schema.iter() {
+                if !reader_schema.contains(name) {
+                    reader_schema.insert(
+                        name.clone(),
+                        dtype.to_arrow_field(name.clone(), CompatLevel::newest()),
+                    );
+                }
+            }
+        },
+        Some(Either::Right(reader_schema)) => {
+            let reader_schema = Arc::make_mut(reader_schema);
+            policy.rename_schema(reader_schema);
+
+            for (name, dtype) in schema.iter() {
+                if !reader_schema.contains(name) {
+                    reader_schema.insert(name.clone(), dtype.clone());
+                }
+            }
+        },
+        None => {},
+    }
+
+    Ok(())
+}
+
 pub(super) fn insert_row_index_to_schema(
     schema: &mut Schema,
     name: PlSmallStr,
@@ -236,20 +364,34 @@ fn prepare_schemas(
 }
 
 #[cfg(feature = "parquet")]
+#[allow(clippy::too_many_arguments)]
 pub(super) async fn parquet_file_info(
     first_scan_source: ScanSourceRef<'_>,
     row_index: Option<&RowIndex>,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    decryption: Option<&Arc<FileDecryptionProperties>>,
+    int96: &Int96Options,
+    uuid_as_string: bool,
+    categorical_columns: Option<&[PlSmallStr]>,
+    footers: Option<&ParquetFooters>,
     n_sources: usize,
 ) -> PolarsResult<(FileInfo, Option<FileMetadataRef>)> {
     use polars_core::error::feature_gated;
 
+    let footer = footers
+        .zip(first_scan_source.as_path())
+        .and_then(|(f, p)| f.get(p).cloned());
+
     let (reader_schema, num_rows, metadata) = {
         if first_scan_source.is_cloud_url() {
             let first_path = first_scan_source.as_path().unwrap();
             feature_gated!("cloud", {
                 let mut reader =
-                    ParquetObjectStore::from_uri(first_path.clone(), cloud_options, None).await?;
+                    ParquetObjectStore::from_uri(first_path.clone(), cloud_options, footer)
+                        .await?
+                        .with_decryption(decryption.cloned())
+                        .with_int96_options(int96.clone())
+                        .with_uuid_as_string(uuid_as_string);
 
                 (
                     reader.schema().await?,
@@ -259,7 +401,13 @@ pub(super) async fn parquet_file_info(
             })
         } else {
             let memslice = first_scan_source.to_memslice()?;
-            let mut reader = ParquetReader::new(std::io::Cursor::new(memslice));
+            let mut reader = ParquetReader::new(std::io::Cursor::new(memslice))
+                .with_decryption(decryption.cloned())
+                .with_int96_options(int96.clone())
+                .with_uuid_as_string(uuid_as_string);
+            if let Some(footer) = footer {
+                reader.set_metadata(footer);
+            }
             (
                 reader.schema()?,
                 reader.num_rows()?,
@@ -268,6 +416,15 @@ pub(super) async fn parquet_file_info(
         }
     };
 
+    let reader_schema = match categorical_columns {
+        Some(columns) => {
+            let mut reader_schema = Arc::unwrap_or_clone(reader_schema);
+            read_strings_as_categorical(&mut reader_schema, columns)?;
+            Arc::new(reader_schema)
+        },
+        None => reader_schema,
+    };
+
     let schema =
         prepare_output_schema(Schema::from_arrow_schema(reader_schema.as_ref()), row_index)?;
 
@@ -864,6 +1021,11 @@ this scan to succeed with an empty DataFrame.",
                             first_scan_source,
                             unified_scan_args.row_index.as_ref(),
                             cloud_options,
+                            options.decryption.as_ref(),
+                            &options.int96,
+                            options.uuid_as_string,
+                            options.categorical_columns.as_deref(),
+                            options.footers.as_ref(),
                             n_sources,
                         )
                         .await?;
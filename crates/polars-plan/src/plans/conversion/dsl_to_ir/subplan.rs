@@ -0,0 +1,187 @@
+//! Resolution of [`Expr::SubPlan`]: expressions that depend on the output of another query.
+use polars_utils::pl_str::unique_column_name;
+
+use super::*;
+use crate::dsl::functions::{by_name, col, first};
+#[cfg(all(feature = "is_in", feature = "semi_anti_join"))]
+use crate::plans::row_estimate::estimate_rows;
+
+/// Sub-plans of an `is_in` filter that are estimated to have at most this number of rows are
+/// collected and probed as a set. Larger sub-plans, or sub-plans of unknown size, are joined.
+#[cfg(all(feature = "is_in", feature = "semi_anti_join"))]
+const IS_IN_SET_MAX_ROWS: usize = 10_000;
+
+pub(super) fn has_subplan(exprs: &[Expr]) -> bool {
+    exprs
+        .iter()
+        .any(|e| e.into_iter().any(|e| matches!(e, Expr::SubPlan(..))))
+}
+
+/// Matches `col(name).is_in(subplan)` and its negation.
+///
+/// Returns the column, the sub-plan, `nulls_equal` and whether the predicate is negated.
+#[cfg(all(feature = "is_in", feature = "semi_anti_join"))]
+fn is_in_subplan(predicate: &Expr) -> Option<(PlSmallStr, Arc<DslPlan>, bool, bool)> {
+    if let Expr::Function {
+        input,
+        function: FunctionExpr::Boolean(BooleanFunction::Not),
+    } = predicate
+    {
+        let [input] = input.as_slice() else {
+            return None;
+        };
+        return is_in_subplan(input)
+            .filter(|(.., negated)| !negated)
+            .map(|(name, plan, nulls_equal, _)| (name, plan, nulls_equal, true));
+    }
+
+    let Expr::Function {
+        input,
+        function: FunctionExpr::Boolean(BooleanFunction::IsIn { nulls_equal }),
+    } = predicate
+    else {
+        return None;
+    };
+    match input.as_slice() {
+        [Expr::Column(name), Expr::SubPlan(plan, _)] => {
+            Some((name.clone(), (**plan).clone(), *nulls_equal, false))
+        },
+        _ => None,
+    }
+}
+
+/// Evaluate every [`Expr::SubPlan`] in `exprs` once and broadcast its result as a column onto
+/// `input`. The sub-plans are replaced by the scalar value of that column.
+///
+/// Returns the new input and the names of the added columns.
+fn broadcast_subplans(
+    input: Arc<DslPlan>,
+    exprs: &mut [Expr],
+) -> PolarsResult<(DslPlan, Vec<PlSmallStr>)> {
+    let mut inputs = vec![Arc::unwrap_or_clone(input)];
+    let mut names = vec![];
+
+    for e in exprs.iter_mut() {
+        *e = std::mem::take(e).try_map_expr(|e| {
+            let Expr::SubPlan(plan, subplan_exprs) = e else {
+                return Ok(e);
+            };
+            polars_ensure!(
+                subplan_exprs.len() == 1,
+                InvalidOperation: "sub-plans that produce multiple columns are not supported"
+            );
+            let (name, expr) = subplan_exprs.into_iter().next().unwrap();
+            inputs.push(
+                DslBuilder(Arc::unwrap_or_clone(plan.into_inner()))
+                    .project(vec![expr], Default::default())
+                    .build(),
+            );
+            names.push(name.clone());
+            Ok(col(name).first())
+        })?;
+    }
+
+    let input = DslPlan::HConcat {
+        inputs,
+        options: HConcatOptions {
+            broadcast_unit_length: true,
+            ..Default::default()
+        },
+    };
+    Ok((input, names))
+}
+
+/// Rewrite a filter whose predicate depends on another query.
+///
+/// A predicate of the form `col(name).is_in(subplan)` becomes a semi join if the other query is
+/// not estimated to be small, so that it is streamed into the join's hash table and never
+/// materialized as a list. Its negation becomes an anti join. Any other predicate, or a predicate
+/// on a small query, is evaluated against the imploded result of the other query, broadcast onto
+/// the input.
+#[cfg_attr(
+    not(all(feature = "is_in", feature = "semi_anti_join")),
+    allow(unused_variables)
+)]
+pub(super) fn rewrite_filter(
+    input: Arc<DslPlan>,
+    mut predicate: Expr,
+    ctxt: &mut DslConversionContext,
+) -> PolarsResult<DslPlan> {
+    #[cfg(all(feature = "is_in", feature = "semi_anti_join"))]
+    if let Some((left_on, other, nulls_equal, negated)) = is_in_subplan(&predicate) {
+        let other_node = to_alp_impl(other.as_ref().clone(), ctxt)?;
+        let other_rows = estimate_rows(other_node, ctxt.lp_arena, ctxt.expr_arena)
+            .get(&other_node)
+            .copied();
+        if other_rows.is_none_or(|n| n > IS_IN_SET_MAX_ROWS) {
+            // Reuse the converted sub-plan for the join.
+            let other = Arc::new(DslPlan::IR {
+                dsl: other,
+                version: ctxt.lp_arena.version(),
+                node: Some(other_node),
+            });
+
+            let mut input = DslBuilder(Arc::unwrap_or_clone(input));
+            // Unless nulls are equal, `is_in` is null for a null value, so the negated filter drops
+            // it. The anti join would keep it as it never matches.
+            if negated && !nulls_equal {
+                input = input.filter(col(left_on.clone()).is_not_null());
+            }
+            let right_on = unique_column_name();
+            let other = DslBuilder(Arc::unwrap_or_clone(other))
+                .project(
+                    vec![first().as_expr().alias(right_on.clone())],
+                    Default::default(),
+                )
+                .build();
+            let mut args = JoinArgs::new(if negated {
+                JoinType::Anti
+            } else {
+                JoinType::Semi
+            });
+            args.nulls_equal = nulls_equal;
+            args.maintain_order = MaintainOrderJoin::Left;
+            let options = JoinOptions {
+                args,
+                ..Default::default()
+            };
+            return Ok(input
+                .join(
+                    other,
+                    vec![col(left_on)],
+                    vec![col(right_on)],
+                    Arc::new(options),
+                )
+                .build());
+        }
+    }
+
+    let (input, names) = broadcast_subplans(input, std::slice::from_mut(&mut predicate))?;
+    Ok(DslBuilder(input)
+        .filter(predicate)
+        .drop(by_name(names, true, false))
+        .build())
+}
+
+/// Rewrite a `select` whose expressions depend on another query.
+pub(super) fn rewrite_select(
+    input: Arc<DslPlan>,
+    mut exprs: Vec<Expr>,
+    options: ProjectionOptions,
+) -> PolarsResult<DslPlan> {
+    let (input, _) = broadcast_subplans(input, &mut exprs)?;
+    Ok(DslBuilder(input).project(exprs, options).build())
+}
+
+/// Rewrite a `with_columns` whose expressions depend on another query.
+pub(super) fn rewrite_with_columns(
+    input: Arc<DslPlan>,
+    mut exprs: Vec<Expr>,
+    options: ProjectionOptions,
+) -> PolarsResult<DslPlan> {
+    let (input, names) = broadcast_subplans(input, &mut exprs)?;
+    Ok(DslBuilder(input)
+        .with_columns(exprs, options)
+        .drop(by_name(names, true, false))
+        .build())
+}
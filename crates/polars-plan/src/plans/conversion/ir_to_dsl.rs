@@ -201,6 +201,7 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
             predicate,
             truthy,
             falsy,
+            lazy,
         } => {
             let p = node_to_expr(predicate, expr_arena);
             let t = node_to_expr(truthy, expr_arena);
@@ -210,6 +211,7 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 predicate: Arc::new(p),
                 truthy: Arc::new(t),
                 falsy: Arc::new(f),
+                lazy,
             }
         },
         AExpr::AnonymousAgg {
@@ -491,6 +493,14 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::LenBytes => B::LenBytes,
                 IB::LenChars => B::LenChars,
                 IB::Lowercase => B::Lowercase,
+                #[cfg(feature = "anonymize")]
+                IB::MaskEmail => B::MaskEmail,
+                #[cfg(feature = "anonymize")]
+                IB::MaskDigits { keep_last } => B::MaskDigits { keep_last },
+                #[cfg(feature = "collation")]
+                IB::CollationKey(options) => B::CollationKey(options),
+                #[cfg(feature = "natural_sort")]
+                IB::NaturalSortKey => B::NaturalSortKey,
                 #[cfg(feature = "extract_jsonpath")]
                 IB::JsonDecode(dtype) => B::JsonDecode(dtype.into()),
                 #[cfg(feature = "extract_jsonpath")]
@@ -499,6 +509,9 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Replace { n, literal } => B::Replace { n, literal },
                 #[cfg(feature = "string_normalize")]
                 IB::Normalize { form } => B::Normalize { form },
+                #[cfg(feature = "string_normalize")]
+                IB::RemoveDiacritics => B::RemoveDiacritics,
+                IB::IsAscii => B::IsAscii,
                 #[cfg(feature = "string_reverse")]
                 IB::Reverse => B::Reverse,
                 #[cfg(feature = "string_pad")]
@@ -530,6 +543,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Strptime(dtype, strptime_options) => {
                     B::Strptime(dtype.into(), strptime_options)
                 },
+                #[cfg(feature = "dtype-duration")]
+                IB::ToDuration { time_unit, strict } => B::ToDuration { time_unit, strict },
                 IB::Split(v) => B::Split(v),
                 #[cfg(feature = "regex")]
                 IB::SplitRegex { inclusive, strict } => B::SplitRegex { inclusive, strict },
@@ -632,7 +647,7 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::TotalMicroseconds { fractional } => B::TotalMicroseconds { fractional },
                 #[cfg(feature = "dtype-duration")]
                 IB::TotalNanoseconds { fractional } => B::TotalNanoseconds { fractional },
-                IB::ToString(v) => B::ToString(v),
+                IB::ToString { format, locale } => B::ToString { format, locale },
                 IB::CastTimeUnit(time_unit) => B::CastTimeUnit(time_unit),
                 IB::WithTimeUnit(time_unit) => B::WithTimeUnit(time_unit),
                 #[cfg(feature = "timezones")]
@@ -727,6 +742,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::IsBusinessDay { week_mask } => B::IsBusinessDay { week_mask },
             })
         },
+        #[cfg(feature = "interval")]
+        IF::Interval(f) => F::Interval(f),
         #[cfg(feature = "abs")]
         IF::Abs => F::Abs,
         IF::Negate => F::Negate,
@@ -757,6 +774,16 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::IndexOf => F::IndexOf,
         #[cfg(feature = "search_sorted")]
         IF::SearchSorted { side, descending } => F::SearchSorted { side, descending },
+        #[cfg(feature = "search_sorted")]
+        IF::SearchSortedBy {
+            side,
+            descending,
+            nulls_last,
+        } => F::SearchSortedBy {
+            side,
+            descending,
+            nulls_last,
+        },
         #[cfg(feature = "range")]
         IF::Range(f) => {
             use {IRRangeFunction as IR, RangeFunction as R};
@@ -1038,11 +1065,13 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
             labels,
             left_closed,
             include_breaks,
+            include_bounds,
         } => F::Cut {
             breaks,
             labels,
             left_closed,
             include_breaks,
+            include_bounds,
         },
         #[cfg(feature = "cutqcut")]
         IF::QCut {
@@ -1051,18 +1080,59 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
             left_closed,
             allow_duplicates,
             include_breaks,
+            include_bounds,
         } => F::QCut {
             probs,
             labels,
             left_closed,
             allow_duplicates,
             include_breaks,
+            include_bounds,
         },
         #[cfg(feature = "rle")]
         IF::RLE => F::RLE,
         #[cfg(feature = "rle")]
         IF::RLEID => F::RLEID,
+        #[cfg(feature = "to_dummies")]
+        IF::ToDummies {
+            categories,
+            separator,
+            drop_first,
+        } => F::ToDummies {
+            categories,
+            separator,
+            drop_first,
+        },
+        #[cfg(feature = "fft")]
+        IF::Fft => F::Fft,
+        #[cfg(feature = "fft")]
+        IF::PowerSpectrum { window } => F::PowerSpectrum { window },
+        #[cfg(feature = "convolve")]
+        IF::Convolve {
+            kernel,
+            mode,
+            nulls,
+        } => F::Convolve {
+            kernel,
+            mode,
+            nulls,
+        },
+        #[cfg(feature = "convolve")]
+        IF::Savgol {
+            window,
+            polyorder,
+            nulls,
+        } => F::Savgol {
+            window,
+            polyorder,
+            nulls,
+        },
+        #[cfg(feature = "anonymize")]
+        IF::BucketizeNumeric { k_anonymity } => F::BucketizeNumeric { k_anonymity },
         IF::ToPhysical => F::ToPhysical,
+        IF::WithFieldMetadata(metadata) => F::WithFieldMetadata(metadata),
+        IF::SetUnit(unit) => F::SetUnit(unit),
+        IF::ConvertUnit { unit, .. } => F::ConvertUnit(unit),
         #[cfg(feature = "random")]
         IF::Random { method, seed } => {
             use {IRRandomMethod as IR, RandomMethod as R};
@@ -1082,6 +1152,14 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 seed,
             }
         },
+        #[cfg(feature = "random")]
+        IF::DpNoise {
+            epsilon,
+            sensitivity,
+        } => F::DpNoise {
+            epsilon,
+            sensitivity,
+        },
         IF::SetSortedFlag(s) => F::SetSortedFlag(s),
         #[cfg(feature = "ffi_plugin")]
         IF::FfiPlugin {
@@ -1141,6 +1219,11 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::MinHorizontal => F::MinHorizontal,
         IF::SumHorizontal { ignore_nulls } => F::SumHorizontal { ignore_nulls },
         IF::MeanHorizontal { ignore_nulls } => F::MeanHorizontal { ignore_nulls },
+        #[cfg(feature = "scan_fn")]
+        IF::ScanFn { step, return_dtype } => F::ScanFn {
+            step,
+            return_dtype: return_dtype.map(DataTypeExpr::Literal),
+        },
         #[cfg(feature = "ewma")]
         IF::EwmMean { options } => F::EwmMean { options },
         #[cfg(feature = "ewma_by")]
@@ -678,6 +678,7 @@ pub(super) fn coerce_comparison_literal(
                 DataType::Boolean,
                 AnyValue::Null,
             )))),
+            lazy: false,
         },
         output_constraint: Some(if nonnull_rows_value {
             BoolValueAlways::TrueOrNull
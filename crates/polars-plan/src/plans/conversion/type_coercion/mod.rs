@@ -190,6 +190,7 @@ impl OptimizationRule for TypeCoercionRule {
                 truthy: truthy_node,
                 falsy: falsy_node,
                 predicate,
+                lazy,
             } => {
                 let (truthy, type_true) =
                     unpack!(get_aexpr_and_type(expr_arena, truthy_node, schema));
@@ -230,6 +231,7 @@ impl OptimizationRule for TypeCoercionRule {
                     truthy: new_node_truthy,
                     falsy: new_node_falsy,
                     predicate,
+                    lazy,
                 })
             },
             AExpr::BinaryExpr {
@@ -0,0 +1,703 @@
+//! Propagation of user field metadata through a query plan.
+//!
+//! Top-level columns can carry arbitrary key/value metadata (see [`Field::metadata`]), for
+//! instance a unit annotation. The plan schema only holds data types, so the metadata of the
+//! output columns is resolved from the optimized plan and attached to the result when the query
+//! is collected or sunk to a file.
+//!
+//! # Rules
+//!
+//! Expressions:
+//! - A column reference has the metadata of the input column, whatever its output name is.
+//! - `with_field_metadata` replaces the metadata, `set_unit` only replaces its unit.
+//! - Casts and operations that only select or reorder values (`filter`, `sort`, `sort_by`,
+//!   `gather`, `slice`, `over`, `explode`, `set_sorted`) keep the metadata of their input.
+//! - `abs`, `neg`, `round`, `floor`, `ceil` and `clip` keep the metadata of their input.
+//! - Aggregations that stay in the domain of their input (`min`, `max`, `median`, `mean`,
+//!   `sum`, `std`, `quantile`, `first`, `last` and `item`) keep the metadata of their input.
+//! - `when/then/otherwise` keeps the metadata if both branches have the same metadata.
+//! - Arithmetic only keeps a unit, see below.
+//! - Every other expression drops the metadata.
+//!
+//! Units (see [`crate::plans::units`]):
+//! - Adding, subtracting or comparing values requires the same unit on both sides, the result
+//!   of `+` and `-` has that unit. Units with the same dimension but a different scale, like `m`
+//!   and `km`, must be converted first with `convert_unit`.
+//! - Multiplication, division and `pow` with an integer literal derive the unit of the result,
+//!   `var` squares the unit and `sqrt` and `cbrt` take its root.
+//! - Arithmetic with a literal keeps the unit of the other side.
+//! - Arithmetic with a column without a unit gives a result without a unit.
+//! - `convert_unit` resolves its scaling factor from the unit of its input.
+//!
+//! Errors for incompatible units are raised when the query is optimized.
+//!
+//! Plans:
+//! - In-memory frames and file scans provide the metadata of their columns.
+//! - Projections, renames, `with_columns` and group-bys apply the expression rules.
+//! - Operations that don't change the values of a column keep its metadata.
+//! - Joins keep the metadata of both sides, also for columns renamed with the suffix.
+//! - Vertical concatenations keep the metadata that is equal in all inputs.
+//! - Opaque user functions drop all metadata.
+
+use arrow::datatypes::Metadata;
+use either::Either;
+use polars_core::prelude::*;
+use polars_utils::arena::{Arena, Node};
+use polars_utils::format_pl_smallstr;
+use recursive::recursive;
+
+use crate::plans::units::{UNIT_KEY, Unit};
+use crate::prelude::*;
+
+/// An operand of an arithmetic expression.
+enum Operand {
+    /// A value with a unit, and the unit as it was written.
+    WithUnit(Unit, PlSmallStr),
+    Literal,
+    Unknown,
+}
+
+impl Operand {
+    fn derived(unit: Unit) -> Self {
+        if unit.is_dimensionless() {
+            Self::Unknown
+        } else {
+            let name = format_pl_smallstr!("{unit}");
+            Self::WithUnit(unit, name)
+        }
+    }
+
+    fn into_metadata(self) -> Option<Arc<Metadata>> {
+        match self {
+            Self::WithUnit(_, name) => Some(Arc::new(Metadata::from([(
+                PlSmallStr::from_static(UNIT_KEY),
+                name,
+            )]))),
+            Self::Literal | Self::Unknown => None,
+        }
+    }
+}
+
+fn unit_of(metadata: Option<&Arc<Metadata>>) -> Option<(Unit, PlSmallStr)> {
+    let name = metadata?.get(UNIT_KEY)?;
+    Some((Unit::parse(name), name.clone()))
+}
+
+fn is_literal(mut node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    loop {
+        match expr_arena.get(node) {
+            AExpr::Literal(_) => return true,
+            AExpr::Cast { expr, .. } => node = *expr,
+            _ => return false,
+        }
+    }
+}
+
+/// The unit of `left <op> right`.
+fn combine_units(left: Operand, op: Operator, right: Operand) -> PolarsResult<Operand> {
+    use Operand::*;
+    use Operator::*;
+    Ok(match op {
+        Plus | Minus | Modulus => match (left, right) {
+            (WithUnit(l, name), WithUnit(r, _)) => {
+                l.ensure_same(&r, op)?;
+                WithUnit(l, name)
+            },
+            (WithUnit(u, name), Literal) | (Literal, WithUnit(u, name)) => WithUnit(u, name),
+            (Literal, Literal) => Literal,
+            _ => Unknown,
+        },
+        Multiply => match (left, right) {
+            (WithUnit(l, _), WithUnit(r, _)) => Operand::derived(l.times(&r)),
+            (WithUnit(u, name), Literal) | (Literal, WithUnit(u, name)) => WithUnit(u, name),
+            (Literal, Literal) => Literal,
+            _ => Unknown,
+        },
+        RustDivide | TrueDivide | FloorDivide => match (left, right) {
+            (WithUnit(l, _), WithUnit(r, _)) => Operand::derived(l.per(&r)),
+            (WithUnit(u, name), Literal) => WithUnit(u, name),
+            (Literal, WithUnit(u, _)) => Operand::derived(u.powi(-1)),
+            (Literal, Literal) => Literal,
+            _ => Unknown,
+        },
+        Eq | EqValidity | NotEq | NotEqValidity | Lt | LtEq | Gt | GtEq => {
+            if let (WithUnit(l, _), WithUnit(r, _)) = (&left, &right) {
+                l.ensure_same(r, op)?;
+            }
+            Unknown
+        },
+        And | Or | Xor | LogicalAnd | LogicalOr => Unknown,
+    })
+}
+
+/// Resolves user metadata, reusing the metadata of plan nodes that are reached more than once.
+struct Resolver<'a> {
+    lp_arena: &'a Arena<IR>,
+    expr_arena: &'a Arena<AExpr>,
+    cache: PlHashMap<Node, FieldMetadataMap>,
+    /// The `convert_unit` expressions and the factors resolved for them.
+    conversions: Vec<(Node, f64)>,
+}
+
+impl<'a> Resolver<'a> {
+    fn new(lp_arena: &'a Arena<IR>, expr_arena: &'a Arena<AExpr>) -> Self {
+        Self {
+            lp_arena,
+            expr_arena,
+            cache: Default::default(),
+            conversions: vec![],
+        }
+    }
+
+    fn operand(
+        &mut self,
+        node: Node,
+        input: &FieldMetadataMap,
+    ) -> PolarsResult<(Operand, Option<Arc<Metadata>>)> {
+        let metadata = self.expr(node, input)?;
+        let operand = match unit_of(metadata.as_ref()) {
+            Some((unit, name)) => Operand::WithUnit(unit, name),
+            None if is_literal(node, self.expr_arena) => Operand::Literal,
+            None => Operand::Unknown,
+        };
+        Ok((operand, metadata))
+    }
+
+    fn function(
+        &mut self,
+        node: Node,
+        args: &[ExprIR],
+        function: &IRFunctionExpr,
+        input: &FieldMetadataMap,
+    ) -> PolarsResult<Option<Arc<Metadata>>> {
+        let mut operands = args
+            .iter()
+            .map(|e| self.operand(e.node(), input))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let with_unit = |metadata: Option<&Arc<Metadata>>, unit: &PlSmallStr| {
+            let mut metadata = metadata.map(|m| m.as_ref().clone()).unwrap_or_default();
+            if unit.is_empty() {
+                metadata.remove(UNIT_KEY);
+            } else {
+                metadata.insert(PlSmallStr::from_static(UNIT_KEY), unit.clone());
+            }
+            (!metadata.is_empty()).then(|| Arc::new(metadata))
+        };
+        Ok(match function {
+            IRFunctionExpr::WithFieldMetadata(metadata) => {
+                (!metadata.is_empty()).then(|| metadata.clone())
+            },
+            IRFunctionExpr::SetUnit(unit) => with_unit(operands[0].1.as_ref(), unit),
+            IRFunctionExpr::ConvertUnit { unit, .. } => {
+                let Operand::WithUnit(from, from_name) = &operands[0].0 else {
+                    polars_bail!(
+                        InvalidOperation: "cannot convert to unit `{}`, the input has no unit; set it with `set_unit`",
+                        unit
+                    );
+                };
+                let factor = from.conversion_factor(&Unit::parse(unit)).map_err(|e| {
+                    e.wrap_msg(|msg| format!("{msg} (converting from `{from_name}`)"))
+                })?;
+                self.conversions.push((node, factor));
+                with_unit(operands[0].1.as_ref(), unit)
+            },
+            IRFunctionExpr::SetSortedFlag(_) | IRFunctionExpr::Negate => operands.swap_remove(0).1,
+            #[cfg(feature = "abs")]
+            IRFunctionExpr::Abs => operands.swap_remove(0).1,
+            #[cfg(feature = "round_series")]
+            IRFunctionExpr::Round { .. }
+            | IRFunctionExpr::Floor
+            | IRFunctionExpr::Ceil
+            | IRFunctionExpr::Clip { .. } => operands.swap_remove(0).1,
+            IRFunctionExpr::Pow(pow) => {
+                let Operand::WithUnit(base, _) = &operands[0].0 else {
+                    return Ok(None);
+                };
+                let unit = match pow {
+                    IRPowFunction::Generic => {
+                        let mut exponent = args[1].node();
+                        while let AExpr::Cast { expr, .. } = self.expr_arena.get(exponent) {
+                            exponent = *expr;
+                        }
+                        let exponent = match self.expr_arena.get(exponent) {
+                            AExpr::Literal(lv) => lv
+                                .to_any_value()
+                                .and_then(|av| av.extract::<f64>())
+                                .filter(|e| e.fract() == 0.0 && e.abs() <= i32::MAX as f64),
+                            _ => None,
+                        };
+                        exponent.map(|e| base.powi(e as i32))
+                    },
+                    IRPowFunction::Sqrt => base.root(2),
+                    IRPowFunction::Cbrt => base.root(3),
+                };
+                unit.map_or(Operand::Unknown, Operand::derived)
+                    .into_metadata()
+            },
+            #[cfg(feature = "fused")]
+            IRFunctionExpr::Fused(op) => {
+                let mut operands = operands.into_iter().map(|(operand, _)| operand);
+                let (a, b, c) = (
+                    operands.next().unwrap(),
+                    operands.next().unwrap(),
+                    operands.next().unwrap(),
+                );
+                match op {
+                    FusedOperator::MultiplyAdd => {
+                        let bc = combine_units(b, Operator::Multiply, c)?;
+                        combine_units(a, Operator::Plus, bc)?
+                    },
+                    FusedOperator::SubMultiply => {
+                        let bc = combine_units(b, Operator::Multiply, c)?;
+                        combine_units(a, Operator::Minus, bc)?
+                    },
+                    FusedOperator::MultiplySub => {
+                        let ab = combine_units(a, Operator::Multiply, b)?;
+                        combine_units(ab, Operator::Minus, c)?
+                    },
+                }
+                .into_metadata()
+            },
+            _ => None,
+        })
+    }
+
+    /// Get the user metadata of the output of an expression, given the metadata of its input.
+    #[recursive]
+    fn expr(
+        &mut self,
+        node: Node,
+        input: &FieldMetadataMap,
+    ) -> PolarsResult<Option<Arc<Metadata>>> {
+        let expr_arena = self.expr_arena;
+        Ok(match expr_arena.get(node) {
+            AExpr::Column(name) => input.get(name).cloned(),
+            AExpr::Cast { expr, .. }
+            | AExpr::Sort { expr, .. }
+            | AExpr::Slice { input: expr, .. }
+            | AExpr::Explode { expr, .. } => self.expr(*expr, input)?,
+            #[cfg(feature = "dynamic_group_by")]
+            AExpr::Rolling { function: expr, .. } => self.expr(*expr, input)?,
+            AExpr::SortBy { expr, by, .. } => {
+                for by in by {
+                    self.expr(*by, input)?;
+                }
+                self.expr(*expr, input)?
+            },
+            AExpr::Gather { expr, idx: by, .. } | AExpr::Filter { input: expr, by } => {
+                self.expr(*by, input)?;
+                self.expr(*expr, input)?
+            },
+            AExpr::Over {
+                function,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                for by in partition_by.iter().chain(order_by.iter().map(|(by, _)| by)) {
+                    self.expr(*by, input)?;
+                }
+                self.expr(*function, input)?
+            },
+            AExpr::Agg(agg) => match agg {
+                IRAggExpr::Min { input: e, .. }
+                | IRAggExpr::Max { input: e, .. }
+                | IRAggExpr::Item { input: e, .. }
+                | IRAggExpr::Quantile { expr: e, .. }
+                | IRAggExpr::Median(e)
+                | IRAggExpr::First(e)
+                | IRAggExpr::FirstNonNull(e)
+                | IRAggExpr::Last(e)
+                | IRAggExpr::LastNonNull(e)
+                | IRAggExpr::Mean(e)
+                | IRAggExpr::Sum(e)
+                | IRAggExpr::Std(e, _) => self.expr(*e, input)?,
+                IRAggExpr::Var(e, _) => match unit_of(self.expr(*e, input)?.as_ref()) {
+                    Some((unit, _)) => Operand::derived(unit.powi(2)).into_metadata(),
+                    None => None,
+                },
+                _ => {
+                    self.validate_inputs(node, input)?;
+                    None
+                },
+            },
+            AExpr::Ternary {
+                predicate,
+                truthy,
+                falsy,
+                ..
+            } => {
+                self.expr(*predicate, input)?;
+                let (truthy, truthy_metadata) = self.operand(*truthy, input)?;
+                let (falsy, falsy_metadata) = self.operand(*falsy, input)?;
+                if let (Operand::WithUnit(t, _), Operand::WithUnit(f, _)) = (&truthy, &falsy) {
+                    t.ensure_same(f, "when/then/otherwise")?;
+                }
+                (truthy_metadata == falsy_metadata)
+                    .then_some(truthy_metadata)
+                    .flatten()
+            },
+            AExpr::BinaryExpr { left, op, right } => {
+                let (left, _) = self.operand(*left, input)?;
+                let (right, _) = self.operand(*right, input)?;
+                combine_units(left, *op, right)?.into_metadata()
+            },
+            AExpr::Function {
+                input: args,
+                function,
+                ..
+            } => self.function(node, args, function, input)?,
+            _ => {
+                self.validate_inputs(node, input)?;
+                None
+            },
+        })
+    }
+
+    /// Check the units in the inputs of an expression whose metadata is dropped.
+    fn validate_inputs(&mut self, node: Node, input: &FieldMetadataMap) -> PolarsResult<()> {
+        let mut inputs = vec![];
+        self.expr_arena.get(node).inputs_rev(&mut inputs);
+        for node in inputs {
+            self.expr(node, input)?;
+        }
+        Ok(())
+    }
+
+    fn exprs(
+        &mut self,
+        exprs: &[ExprIR],
+        input: &FieldMetadataMap,
+        out: &mut FieldMetadataMap,
+    ) -> PolarsResult<()> {
+        for e in exprs {
+            match self.expr(e.node(), input)? {
+                Some(metadata) => out.insert(e.output_name().clone(), metadata),
+                None => out.remove(e.output_name()),
+            };
+        }
+        Ok(())
+    }
+
+    fn resolve(&mut self, node: Node) -> PolarsResult<FieldMetadataMap> {
+        if let Some(out) = self.cache.get(&node) {
+            return Ok(out.clone());
+        }
+        let out = self.resolve_uncached(node)?;
+        self.cache.insert(node, out.clone());
+        Ok(out)
+    }
+
+    /// Resolve the user metadata of the output columns of a plan node.
+    #[recursive]
+    fn resolve_uncached(&mut self, node: Node) -> PolarsResult<FieldMetadataMap> {
+        let lp_arena = self.lp_arena;
+        let ir = lp_arena.get(node);
+        let mut out = match ir {
+            IR::DataFrameScan { df, .. } => df.field_metadata(),
+            IR::Scan { file_info, .. } => match &file_info.reader_schema {
+                Some(Either::Left(arrow_schema)) => arrow_schema
+                    .iter()
+                    .filter_map(|(name, field)| {
+                        let metadata = user_field_metadata(field.metadata.as_deref())?;
+                        Some((name.clone(), metadata))
+                    })
+                    .collect(),
+                _ => FieldMetadataMap::default(),
+            },
+            IR::Select { input, expr, .. } => {
+                let input = self.resolve(*input)?;
+                let mut out = FieldMetadataMap::default();
+                self.exprs(expr, &input, &mut out)?;
+                out
+            },
+            IR::HStack { input, exprs, .. } => {
+                let input = self.resolve(*input)?;
+                let mut out = input.clone();
+                self.exprs(exprs, &input, &mut out)?;
+                out
+            },
+            IR::GroupBy {
+                input,
+                keys,
+                aggs,
+                apply,
+                ..
+            } => {
+                if apply.is_some() {
+                    return Ok(FieldMetadataMap::default());
+                }
+                let input = self.resolve(*input)?;
+                let mut out = FieldMetadataMap::default();
+                self.exprs(keys, &input, &mut out)?;
+                self.exprs(aggs, &input, &mut out)?;
+                out
+            },
+            IR::Join {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                options,
+                ..
+            } => {
+                let mut out = self.resolve(*input_left)?;
+                let right = self.resolve(*input_right)?;
+                for (l, r) in left_on.iter().zip(right_on) {
+                    let (l, _) = self.operand(l.node(), &out)?;
+                    let (r, _) = self.operand(r.node(), &right)?;
+                    if let (Operand::WithUnit(l, _), Operand::WithUnit(r, _)) = (l, r) {
+                        l.ensure_same(&r, "join")?;
+                    }
+                }
+                let left_schema = lp_arena.get(*input_left).schema(lp_arena);
+                let suffix = options.args.suffix();
+                for (name, metadata) in right {
+                    let name = if left_schema.contains(&name) {
+                        format_pl_smallstr!("{name}{suffix}")
+                    } else {
+                        name
+                    };
+                    out.entry(name).or_insert(metadata);
+                }
+                out
+            },
+            IR::Union { inputs, .. } => {
+                let mut out: Option<FieldMetadataMap> = None;
+                for input in inputs {
+                    let other = self.resolve(*input)?;
+                    match &mut out {
+                        None => out = Some(other),
+                        Some(out) => out.retain(|name, metadata| other.get(name) == Some(metadata)),
+                    }
+                }
+                out.unwrap_or_default()
+            },
+            IR::HConcat { inputs, .. } => {
+                let mut out = FieldMetadataMap::default();
+                for input in inputs {
+                    out.extend(self.resolve(*input)?);
+                }
+                out
+            },
+            IR::MapFunction {
+                function: FunctionIR::Opaque { .. },
+                ..
+            } => FieldMetadataMap::default(),
+            #[cfg(feature = "python")]
+            IR::MapFunction {
+                function: FunctionIR::OpaquePython(_),
+                ..
+            } => FieldMetadataMap::default(),
+            // File sinks have an empty output schema, but write the columns of their input.
+            IR::Sink { input, .. } => return self.resolve(*input),
+            IR::SinkMultiple { inputs } => {
+                for input in inputs {
+                    self.resolve(*input)?;
+                }
+                FieldMetadataMap::default()
+            },
+            #[cfg(feature = "python")]
+            IR::PythonScan { .. } => FieldMetadataMap::default(),
+            IR::Invalid => FieldMetadataMap::default(),
+            IR::Filter { input, predicate } => {
+                let out = self.resolve(*input)?;
+                self.expr(predicate.node(), &out)?;
+                out
+            },
+            IR::Sort {
+                input, by_column, ..
+            } => {
+                let out = self.resolve(*input)?;
+                for e in by_column {
+                    self.expr(e.node(), &out)?;
+                }
+                out
+            },
+            IR::Slice { input, .. }
+            | IR::SimpleProjection { input, .. }
+            | IR::Cache { input, .. }
+            | IR::Distinct { input, .. }
+            | IR::MapFunction { input, .. }
+            | IR::ExtContext { input, .. } => self.resolve(*input)?,
+            #[cfg(feature = "merge_sorted")]
+            IR::MergeSorted { input_left, .. } => self.resolve(*input_left)?,
+        };
+        let schema = ir.schema(lp_arena);
+        out.retain(|name, _| schema.contains(name));
+        Ok(out)
+    }
+}
+
+/// Get the user metadata of the output of an expression, given the metadata of its input.
+pub fn expr_field_metadata(
+    node: Node,
+    expr_arena: &Arena<AExpr>,
+    input: &FieldMetadataMap,
+) -> PolarsResult<Option<Arc<Metadata>>> {
+    let lp_arena = Arena::new();
+    Resolver::new(&lp_arena, expr_arena).expr(node, input)
+}
+
+/// Resolve the user metadata of the output columns of a plan node.
+pub fn resolve_field_metadata(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<FieldMetadataMap> {
+    Resolver::new(lp_arena, expr_arena).resolve(node)
+}
+
+/// Replace the selectors on user metadata in `exprs` by the columns of `input` that match them.
+pub(crate) fn resolve_metadata_selectors(
+    exprs: Vec<Expr>,
+    input: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<Vec<Expr>> {
+    let selects_by_metadata = |e: &Expr| {
+        e.into_iter()
+            .any(|e| matches!(e, Expr::Selector(s) if s.selects_by_metadata()))
+    };
+    if !exprs.iter().any(selects_by_metadata) {
+        return Ok(exprs);
+    }
+    let metadata = resolve_field_metadata(input, lp_arena, expr_arena)?;
+    let schema = lp_arena.get(input).schema(lp_arena);
+    Ok(exprs
+        .into_iter()
+        .map(|e| {
+            e.map_expr(|e| match e {
+                Expr::Selector(s) => Expr::Selector(s.resolve_metadata(&schema, &metadata)),
+                e => e,
+            })
+        })
+        .collect())
+}
+
+/// Check the units in the plan and resolve the scaling factors of `convert_unit`.
+pub fn resolve_units(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let mut resolver = Resolver::new(lp_arena, expr_arena);
+    resolver.resolve(root)?;
+    let conversions = resolver.conversions;
+    for (node, resolved) in conversions {
+        if let AExpr::Function {
+            function: IRFunctionExpr::ConvertUnit { factor, .. },
+            ..
+        } = expr_arena.get_mut(node)
+        {
+            *factor = Some(resolved);
+        }
+    }
+    Ok(())
+}
+
+/// Attach the resolved user metadata to the arrow schema of the single-file Parquet and IPC
+/// sinks in the plan, unless an arrow schema was already given.
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+pub fn attach_sink_field_metadata(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<()> {
+    let sinks = match lp_arena.get(root) {
+        IR::SinkMultiple { inputs } => inputs.clone(),
+        _ => vec![root],
+    };
+    for sink in sinks {
+        let IR::Sink {
+            input,
+            payload: SinkTypeIR::File(_),
+        } = lp_arena.get(sink)
+        else {
+            continue;
+        };
+        let input = *input;
+        let metadata = resolve_field_metadata(input, lp_arena, expr_arena)?;
+        if metadata.is_empty() {
+            continue;
+        }
+        let schema = lp_arena.get(input).schema(lp_arena).into_owned();
+
+        let IR::Sink {
+            payload: SinkTypeIR::File(file_sink),
+            ..
+        } = lp_arena.get_mut(sink)
+        else {
+            unreachable!()
+        };
+        match &mut file_sink.file_format {
+            #[cfg(feature = "parquet")]
+            FileWriteFormat::Parquet(options) if options.arrow_schema.is_none() => {
+                let mut arrow_schema =
+                    polars_io::schema_to_arrow_checked(&schema, options.compat_level(), "parquet")?;
+                polars_io::attach_field_metadata(&mut arrow_schema, &metadata);
+                Arc::make_mut(options).arrow_schema = Some(Arc::new(arrow_schema));
+            },
+            #[cfg(feature = "ipc")]
+            FileWriteFormat::Ipc(options) if options.arrow_schema.is_none() => {
+                let mut arrow_schema =
+                    polars_io::schema_to_arrow_checked(&schema, options.compat_level, "ipc")?;
+                polars_io::attach_field_metadata(&mut arrow_schema, &metadata);
+                options.arrow_schema = Some(Arc::new(arrow_schema));
+            },
+            _ => {},
+        }
+    }
+    Ok(())
+}
+
+/// Whether a source or an expression of the plan can give user metadata to a column.
+fn has_field_metadata(root: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> bool {
+    lp_arena.iter(root).any(|(_, ir)| match ir {
+        IR::DataFrameScan { df, .. } => df.columns().iter().any(|c| c.metadata().is_some()),
+        IR::Scan { file_info, .. } => match &file_info.reader_schema {
+            Some(Either::Left(arrow_schema)) => arrow_schema
+                .iter_values()
+                .any(|field| user_field_metadata(field.metadata.as_deref()).is_some()),
+            _ => false,
+        },
+        _ => ir.exprs().any(|e| {
+            expr_arena.iter(e.node()).any(|(_, ae)| {
+                matches!(
+                    ae,
+                    AExpr::Function {
+                        function: IRFunctionExpr::WithFieldMetadata(_)
+                            | IRFunctionExpr::SetUnit(_)
+                            | IRFunctionExpr::ConvertUnit { .. },
+                        ..
+                    }
+                )
+            })
+        }),
+    })
+}
+
+impl IRPlan {
+    /// Resolve the user field metadata of every sink of the plan, in the order of the query
+    /// results, and attach it to the file sinks.
+    ///
+    /// Returns no metadata at all if the plan doesn't carry any, without resolving it.
+    ///
+    /// The root of the plan must be a sink.
+    pub fn resolve_sink_field_metadata(&mut self) -> PolarsResult<Vec<FieldMetadataMap>> {
+        if !has_field_metadata(self.lp_top, &self.lp_arena, &self.expr_arena) {
+            return Ok(vec![]);
+        }
+        #[cfg(any(feature = "parquet", feature = "ipc"))]
+        attach_sink_field_metadata(self.lp_top, &mut self.lp_arena, &self.expr_arena)?;
+        let sinks = match self.root() {
+            IR::SinkMultiple { inputs } => inputs.clone(),
+            _ => vec![self.lp_top],
+        };
+        let mut resolver = Resolver::new(&self.lp_arena, &self.expr_arena);
+        sinks
+            .into_iter()
+            .map(|sink| resolver.resolve(sink))
+            .collect()
+    }
+}
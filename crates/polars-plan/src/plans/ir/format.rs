@@ -162,6 +162,8 @@ impl<'a> IRDisplay<'a> {
                 write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)?;
                 let name = if let Some(slice) = options.slice {
                     format!("SLICED UNION: {slice:?}")
+                } else if let Some(partitioning) = &options.partitioning {
+                    format!("PARTITIONED UNION BY {:?}", partitioning.keys)
                 } else {
                     "UNION".to_string()
                 };
@@ -527,11 +529,13 @@ impl Display for ExprIRDisplay<'_> {
                 predicate,
                 truthy,
                 falsy,
+                lazy,
             } => {
                 let predicate = self.with_root(predicate);
                 let truthy = self.with_root(truthy);
                 let falsy = self.with_root(falsy);
-                write!(f, "when({predicate}).then({truthy}).otherwise({falsy})",)
+                let when = if *lazy { "when_lazy" } else { "when" };
+                write!(f, "{when}({predicate}).then({truthy}).otherwise({falsy})",)
             },
             Function {
                 input, function, ..
@@ -972,6 +976,8 @@ pub fn write_ir_non_recursive(
         IR::Union { inputs: _, options } => {
             let name = if let Some(slice) = options.slice {
                 format!("SLICED UNION: {slice:?}")
+            } else if let Some(partitioning) = &options.partitioning {
+                format!("PARTITIONED UNION BY {:?}", partitioning.keys)
             } else {
                 "UNION".to_string()
             };
@@ -17,6 +17,7 @@ use serde::{Deserialize, Serialize};
 use strum_macros::IntoStaticStr;
 
 use self::hive::HivePartitionsDf;
+use crate::plans::row_estimate::estimate_rows;
 use crate::prelude::*;
 
 #[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
@@ -239,9 +240,24 @@ impl<'a> IRPlanRef<'a> {
         self.display().to_string()
     }
 
+    /// Describe the plan as a tree, with the estimated number of rows of every node.
     pub fn describe_tree_format(self) -> String {
+        self.describe_tree_format_impl(None)
+    }
+
+    /// Describe an executed plan as a tree, with the actual number of rows of every node next to
+    /// the estimate. Misestimates are flagged with `(!)`.
+    pub fn describe_tree_format_with_rows(self, actual_rows: &PlHashMap<Node, usize>) -> String {
+        self.describe_tree_format_impl(Some(actual_rows))
+    }
+
+    fn describe_tree_format_impl(self, actual_rows: Option<&PlHashMap<Node, usize>>) -> String {
+        let rows = tree_format::TreeFmtRows {
+            estimated: estimate_rows(self.lp_top, self.lp_arena, self.expr_arena),
+            actual: actual_rows,
+        };
         let mut visitor = tree_format::TreeFmtVisitor::default();
-        tree_format::TreeFmtNode::root_logical_plan(self).traverse(&mut visitor);
+        tree_format::TreeFmtNode::root_logical_plan(self, &rows).traverse(&mut visitor);
         format!("{visitor:#?}")
     }
 }
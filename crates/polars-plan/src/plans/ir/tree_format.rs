@@ -1,6 +1,7 @@
 use std::fmt::{self, Write};
 
 use polars_core::error::*;
+use polars_core::prelude::PlHashMap;
 use polars_utils::format_list_truncated;
 
 use crate::constants;
@@ -15,6 +16,38 @@ pub struct TreeFmtNode<'a> {
     content: TreeFmtNodeContent<'a>,
 
     lp: IRPlanRef<'a>,
+    rows: &'a TreeFmtRows<'a>,
+}
+
+/// Actual numbers of rows that differ from the estimates by more than this factor are flagged.
+const MISESTIMATE_FACTOR: usize = 10;
+
+/// The numbers of rows of the nodes of a plan, shown below the nodes of the tree.
+pub(crate) struct TreeFmtRows<'a> {
+    /// The estimated numbers of rows.
+    pub(crate) estimated: PlHashMap<Node, usize>,
+    /// The actual numbers of rows, if the plan was executed.
+    pub(crate) actual: Option<&'a PlHashMap<Node, usize>>,
+}
+
+impl TreeFmtRows<'_> {
+    fn describe(&self, node: Node) -> Option<String> {
+        let estimated = self.estimated.get(&node).copied();
+        let Some(actual) = self.actual.and_then(|actual| actual.get(&node).copied()) else {
+            return estimated.map(|n| format!("est. rows: {n}"));
+        };
+
+        let Some(estimated) = estimated else {
+            return Some(format!("rows: {actual}, est. ?"));
+        };
+        let (lo, hi) = (actual.min(estimated), actual.max(estimated));
+        let flag = if hi > lo.max(1).saturating_mul(MISESTIMATE_FACTOR) {
+            " (!)"
+        } else {
+            ""
+        };
+        Some(format!("rows: {actual}, est. {estimated}{flag}"))
+    }
 }
 
 pub struct TreeFmtAExpr<'a>(&'a AExpr);
@@ -117,12 +150,13 @@ fn multiline_expression(expr: &str) -> std::borrow::Cow<'_, str> {
 }
 
 impl<'a> TreeFmtNode<'a> {
-    pub fn root_logical_plan(lp: IRPlanRef<'a>) -> Self {
+    pub(crate) fn root_logical_plan(lp: IRPlanRef<'a>, rows: &'a TreeFmtRows<'a>) -> Self {
         Self {
             h: None,
             content: TreeFmtNodeContent::LogicalPlan(lp.lp_top),
 
             lp,
+            rows,
         }
     }
 
@@ -132,6 +166,7 @@ impl<'a> TreeFmtNode<'a> {
             content: TreeFmtNodeContent::LogicalPlan(root),
 
             lp: self.lp,
+            rows: self.rows,
         }
     }
 
@@ -141,6 +176,7 @@ impl<'a> TreeFmtNode<'a> {
             content: TreeFmtNodeContent::Expression(expr),
 
             lp: self.lp,
+            rows: self.rows,
         }
     }
 
@@ -189,7 +225,7 @@ impl<'a> TreeFmtNode<'a> {
             #[cfg(not(feature = "regex"))]
             C::Expression(expr) => ND(wh(h, &expr.display(self.lp.expr_arena).to_string()), vec![]),
             C::LogicalPlan(lp_top) => {
-                match self.lp.with_root(lp_top).root() {
+                let ND(title, child_nodes) = match self.lp.with_root(lp_top).root() {
                     #[cfg(feature = "python")]
                     PythonScan { .. } => ND(wh(h, &lp.describe()), vec![]),
                     Scan { .. } => ND(wh(h, &lp.describe()), vec![]),
@@ -392,6 +428,10 @@ impl<'a> TreeFmtNode<'a> {
                             .collect(),
                     ),
                     Invalid => ND(wh(h, "INVALID"), vec![]),
+                };
+                match self.rows.describe(lp_top) {
+                    Some(rows) => ND(format!("{title}\n{rows}"), child_nodes),
+                    None => ND(title, child_nodes),
                 }
             },
         }
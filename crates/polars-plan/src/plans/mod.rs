@@ -9,11 +9,14 @@ pub(crate) mod anonymous_scan;
 pub(crate) mod ir;
 
 mod apply;
+pub mod audit;
 mod builder_ir;
+pub mod column_index;
 pub(crate) mod conversion;
 #[cfg(feature = "debugging")]
 pub(crate) mod debug;
 pub mod expr_ir;
+pub mod field_metadata;
 mod functions;
 pub mod hive;
 pub(crate) mod iterator;
@@ -24,8 +27,16 @@ pub(crate) mod options;
 pub mod python;
 #[cfg(feature = "python")]
 pub use python::*;
+#[cfg(feature = "random")]
+pub mod privacy;
 pub mod prune;
+pub mod replay;
+pub mod row_estimate;
 mod schema;
+#[cfg(feature = "plan_cache")]
+mod shape;
+pub mod units;
+pub mod validate;
 pub mod visitor;
 
 pub use aexpr::*;
@@ -40,3 +51,5 @@ pub use iterator::*;
 pub use lit::*;
 pub use optimizer::*;
 pub use schema::*;
+#[cfg(feature = "plan_cache")]
+pub use shape::{PlanShape, literal_nodes, plan_shape};
@@ -252,6 +252,8 @@ fn skip_pre_visit(ae: &AExpr, is_groupby: bool, element_wise_select_only: bool)
         #[cfg(feature = "dynamic_group_by")]
         AExpr::Rolling { .. } => true,
         AExpr::Over { .. } => true,
+        // The branches of a lazy ternary only see the rows they are selected for.
+        AExpr::Ternary { lazy: true, .. } => true,
         #[cfg(feature = "dtype-struct")]
         AExpr::Ternary { .. } => is_groupby,
         ae => {
@@ -419,7 +421,7 @@ impl ExprIdentifierVisitor<'_> {
             },
             #[cfg(feature = "random")]
             AExpr::Function {
-                function: IRFunctionExpr::Random { .. },
+                function: IRFunctionExpr::Random { .. } | IRFunctionExpr::DpNoise { .. },
                 ..
             } => REFUSE_NO_MEMBER,
             #[cfg(feature = "rolling_window")]
@@ -0,0 +1,116 @@
+//! Optimization rules registered from outside of polars.
+//!
+//! Domain-specific rewrites, such as pushing predicates into a custom scan, can be registered
+//! with [`register_optimization_rule`] and are then run by [`optimize`](super::optimize) on every
+//! plan in the phase they declare.
+use std::sync::{Arc, LazyLock, RwLock};
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// When a [`CustomOptimizationRule`] runs during optimization.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OptimizationPhase {
+    /// Right after conversion to IR, before any built-in optimization.
+    Early,
+    /// After predicate and projection pushdown, before the expression optimizations.
+    AfterPushdown,
+    /// After all built-in optimizations.
+    Late,
+}
+
+/// A rewrite of IR nodes that matches a pattern.
+pub trait CustomOptimizationRule: Send + Sync {
+    /// The unique name of the rule.
+    fn name(&self) -> &str;
+
+    fn phase(&self) -> OptimizationPhase;
+
+    /// Whether the rule is applied until the plan no longer changes. Otherwise, the rule is
+    /// applied at most once to every node.
+    fn fixpoint(&self) -> bool {
+        true
+    }
+
+    /// Rewrite `node`, returning the IR to replace it with, or `None` if the rule doesn't apply.
+    ///
+    /// New nodes, e.g. inputs of the replacement, can be added to the arenas. The inputs of the
+    /// replacement are visited after it.
+    fn optimize_plan(
+        &self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> PolarsResult<Option<IR>>;
+}
+
+static RULES: LazyLock<RwLock<Vec<Arc<dyn CustomOptimizationRule>>>> =
+    LazyLock::new(Default::default);
+
+/// Register a rule to run on every optimized plan. Rules of the same phase run in the order in
+/// which they are registered.
+///
+/// Plans that were optimized before, such as those in a plan cache, are not affected.
+pub fn register_optimization_rule(rule: Arc<dyn CustomOptimizationRule>) -> PolarsResult<()> {
+    let mut rules = RULES.write().unwrap();
+    let name = rule.name();
+    polars_ensure!(
+        rules.iter().all(|r| r.name() != name),
+        ComputeError: "attempted to register duplicate optimization rule with name '{name}'"
+    );
+    rules.push(rule);
+    Ok(())
+}
+
+pub fn unregister_optimization_rule(name: &str) -> PolarsResult<Arc<dyn CustomOptimizationRule>> {
+    let mut rules = RULES.write().unwrap();
+    let Some(idx) = rules.iter().position(|r| r.name() == name) else {
+        polars_bail!(ComputeError: "attempted to unregister unknown optimization rule with name '{name}'")
+    };
+    Ok(rules.remove(idx))
+}
+
+/// Run the registered rules of `phase` on the plan rooted at `root`.
+pub(super) fn run_custom_rules(
+    phase: OptimizationPhase,
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let rules = RULES
+        .read()
+        .unwrap()
+        .iter()
+        .filter(|rule| rule.phase() == phase)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    for rule in rules {
+        let mut changed = true;
+        while changed {
+            changed = false;
+            let mut visited = PlHashSet::new();
+            let mut stack = vec![root];
+            while let Some(node) = stack.pop() {
+                // Shared subplans are only rewritten once per pass.
+                if !visited.insert(node) {
+                    continue;
+                }
+                while let Some(ir) = rule
+                    .optimize_plan(lp_arena, expr_arena, node)
+                    .map_err(|e| e.context(format!("optimization rule '{}'", rule.name()).into()))?
+                {
+                    lp_arena.replace(node, ir);
+                    changed = true;
+                    if !rule.fixpoint() {
+                        break;
+                    }
+                }
+                lp_arena.get(node).copy_inputs(&mut stack);
+            }
+            changed &= rule.fixpoint();
+        }
+    }
+    Ok(())
+}
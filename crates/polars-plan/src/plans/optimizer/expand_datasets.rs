@@ -208,6 +208,7 @@ pub(super) fn expand_datasets(
                             cache,
                             glob: _,
                             hidden_file_prefix: _hidden_file_prefix @ None,
+                            file_listing: _,
                             projection: _projection @ None,
                             column_mapping,
                             default_values,
@@ -217,8 +218,16 @@ pub(super) fn expand_datasets(
                             missing_columns_policy,
                             extra_columns_policy,
                             include_file_paths: _include_file_paths @ None,
+                            provenance:
+                                crate::dsl::ProvenanceColumns {
+                                    row_index_in_file: None,
+                                    row_group_index: None,
+                                    file_modification_time: None,
+                                },
+                            schema_evolution: _schema_evolution @ None,
                             deletion_files,
                             table_statistics,
+                            statistics_sidecar: _statistics_sidecar @ None,
                             row_count,
                         } = resolved_unified_scan_args.as_ref()
                         else {
@@ -24,14 +24,16 @@ impl OptimizationRule for FlattenUnionRule {
         let lp = lp_arena.get(node);
 
         match lp {
+            // The inputs of a partitioned union are its partitions.
             Union { inputs, options }
-                if inputs.iter().any(|node| match lp_arena.get(*node) {
-                    Union { options, .. } => !options.flattened_by_opt,
-                    _ => false,
-                }) =>
+                if options.partitioning.is_none()
+                    && inputs.iter().any(|node| match lp_arena.get(*node) {
+                        Union { options, .. } => !options.flattened_by_opt,
+                        _ => false,
+                    }) =>
             {
                 let mut new_inputs = Vec::with_capacity(inputs.len() * 2);
-                let mut options = *options;
+                let mut options = options.clone();
 
                 for node in inputs {
                     match get_union_inputs(*node, lp_arena) {
@@ -0,0 +1,58 @@
+//! Choose the build side of joins from the estimated number of rows of their inputs.
+use std::sync::Arc;
+
+use polars_utils::arena::{Arena, Node};
+
+use crate::plans::row_estimate::estimate_rows;
+use crate::prelude::*;
+
+/// Prefer the input with the fewest estimated rows as the build side of the inner joins that
+/// don't choose one themselves.
+///
+/// The estimates start from the number of rows of the sources, which is known for multi-file
+/// scans with a statistics sidecar, see [`crate::plans::row_estimate`].
+pub(super) fn set_join_build_sides(
+    root: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) {
+    let joins = ir_arena
+        .iter(root)
+        .filter_map(|(node, ir)| match ir {
+            IR::Join { options, .. }
+                if options.args.how == JoinType::Inner
+                    && options.args.build_side.is_none()
+                    && options.args.maintain_order == MaintainOrderJoin::None =>
+            {
+                Some(node)
+            },
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if joins.is_empty() {
+        return;
+    }
+
+    let estimates = estimate_rows(root, ir_arena, expr_arena);
+    for node in joins {
+        let IR::Join {
+            input_left,
+            input_right,
+            options,
+            ..
+        } = ir_arena.get_mut(node)
+        else {
+            unreachable!()
+        };
+        let (Some(left), Some(right)) = (estimates.get(input_left), estimates.get(input_right))
+        else {
+            continue;
+        };
+        let build_side = match left.cmp(right) {
+            std::cmp::Ordering::Less => JoinBuildSide::PreferLeft,
+            std::cmp::Ordering::Greater => JoinBuildSide::PreferRight,
+            std::cmp::Ordering::Equal => continue,
+        };
+        Arc::make_mut(options).args.build_side = Some(build_side);
+    }
+}
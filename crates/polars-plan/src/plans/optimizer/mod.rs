@@ -11,26 +11,38 @@ mod collect_members;
 mod count_star;
 #[cfg(feature = "cse")]
 mod cse;
+mod custom_rules;
 mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
+mod join_build_side;
 mod join_utils;
+mod partitioned_union;
 pub(crate) use join_utils::ExprOrigin;
 mod expand_datasets;
 #[cfg(feature = "python")]
 pub use expand_datasets::ExpandedPythonScan;
 mod predicate_pushdown;
 mod projection_pushdown;
+#[cfg(feature = "replace")]
+mod replace_to_join;
 pub mod set_order;
 mod simplify_expr;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
 mod sortedness;
 mod stack_opt;
+#[cfg(all(feature = "parquet", feature = "dtype-struct"))]
+mod struct_field_pushdown;
 
 use collapse_and_project::SimpleProjectionAndCollapse;
 #[cfg(feature = "cse")]
 pub use cse::NaiveExprMerger;
+use custom_rules::run_custom_rules;
+pub use custom_rules::{
+    CustomOptimizationRule, OptimizationPhase, register_optimization_rule,
+    unregister_optimization_rule,
+};
 use delay_rechunk::DelayRechunk;
 pub use expand_datasets::ExpandedDataset;
 use polars_core::config::verbose;
@@ -42,11 +54,13 @@ pub use sortedness::{AExprSorted, IRSorted, are_keys_sorted_any, expr_is_sorted,
 pub use stack_opt::{OptimizationRule, OptimizeExprContext, StackOptimizer};
 
 use self::flatten_union::FlattenUnionRule;
+use self::partitioned_union::PartitionedUnionRule;
 pub use crate::frame::{AllowedOptimizations, OptFlags};
 pub use crate::plans::conversion::type_coercion::TypeCoercionRule;
 use crate::plans::optimizer::count_star::CountStar;
 #[cfg(feature = "cse")]
 use crate::plans::optimizer::cse::CommonSubExprOptimizer;
+use crate::plans::validate::validate_plan;
 #[cfg(feature = "cse")]
 use crate::plans::visitor::*;
 use crate::prelude::optimizer::collect_members::MemberCollector;
@@ -66,6 +80,11 @@ pub(crate) fn pushdown_maintain_errors() -> bool {
     std::env::var("POLARS_PUSHDOWN_OPT_MAINTAIN_ERRORS").as_deref() == Ok("1")
 }
 
+/// Validate the plan after every optimizer pass, see [`validate_plan`].
+pub(crate) fn validate_optimizer_passes() -> bool {
+    std::env::var("POLARS_VALIDATE_IR").as_deref() == Ok("1")
+}
+
 pub(super) fn run_projection_predicate_pushdown(
     root: Node,
     ir_arena: &mut Arena<IR>,
@@ -126,6 +145,23 @@ pub fn optimize(
     }
     let mut root = to_alp(logical_plan, expr_arena, ir_arena, &mut opt_flags)?;
 
+    let validate = validate_optimizer_passes();
+    macro_rules! validate {
+        ($pass:literal) => {
+            if validate {
+                validate_plan(root, ir_arena, expr_arena)
+                    .map_err(|e| e.context(concat!("validating the plan after ", $pass).into()))?;
+            }
+        };
+    }
+    validate!("conversion");
+
+    // Check units before the expressions are rewritten.
+    crate::plans::field_metadata::resolve_units(root, ir_arena, expr_arena)?;
+
+    run_custom_rules(OptimizationPhase::Early, root, ir_arena, expr_arena)?;
+    validate!("early custom optimization rules");
+
     #[allow(unused_assignments)]
     let mut comm_subplan_elim = false;
     // Don't run optimizations that don't make sense on a single node.
@@ -186,6 +222,7 @@ pub fn optimize(
                     verbose,
                     scratch,
                 )?;
+                validate!("common subplan elimination");
                 false
             } else {
                 true
@@ -206,6 +243,7 @@ pub fn optimize(
         ir_arena.replace(root, ir);
 
         repeat_slice_pd_after_filter_pd = slice_pushdown_opt.slice_node_in_optimized_plan;
+        validate!("slice pushdown");
 
         // Expressions use the stack optimizer.
         rules.push(Box::new(slice_pushdown_opt));
@@ -219,6 +257,16 @@ pub fn optimize(
             pushdown_maintain_errors,
             &opt_flags,
         )?;
+        validate!("predicate and projection pushdown");
+    }
+
+    run_custom_rules(OptimizationPhase::AfterPushdown, root, ir_arena, expr_arena)?;
+    validate!("custom optimization rules after pushdown");
+
+    // Needs the whole plan, so it can't be part of the pushdowns of the subplans.
+    #[cfg(all(feature = "parquet", feature = "dtype-struct"))]
+    if opt_flags.projection_pushdown() {
+        struct_field_pushdown::optimize(root, ir_arena, expr_arena);
     }
 
     if opt_flags.fast_projection() {
@@ -239,19 +287,29 @@ pub fn optimize(
 
     if !opt_flags.eager() {
         rules.push(Box::new(FlattenUnionRule {}));
+        rules.push(Box::new(PartitionedUnionRule {}));
     }
 
     root = opt.optimize_loop(&mut rules, expr_arena, ir_arena, root)?;
+    validate!("expression optimizations");
+
+    #[cfg(feature = "replace")]
+    if !opt_flags.eager() {
+        replace_to_join::optimize(root, ir_arena, expr_arena)?;
+        validate!("replace to join");
+    }
 
     if repeat_slice_pd_after_filter_pd {
         let mut slice_pushdown_opt = SlicePushDown::new();
         let ir = slice_pushdown_opt.optimize(root, ir_arena, expr_arena)?;
 
         ir_arena.replace(root, ir);
+        validate!("repeated slice pushdown");
     }
 
     if opt_flags.cluster_with_columns() && get_or_init_members!().with_columns_count > 1 {
-        cluster_with_columns::optimize(root, ir_arena, expr_arena)
+        cluster_with_columns::optimize(root, ir_arena, expr_arena);
+        validate!("clustering with_columns");
     }
 
     // This one should run (nearly) last as this modifies the projections
@@ -265,6 +323,7 @@ pub fn optimize(
             let rewritten = ir_node.rewrite(&mut optimizer, arena)?;
             Ok(rewritten.node())
         })?;
+        validate!("common subexpression elimination");
     }
 
     if opt_flags.contains(OptFlags::CHECK_ORDER_OBSERVE) {
@@ -302,6 +361,15 @@ pub fn optimize(
     }
 
     expand_datasets::expand_datasets(root, ir_arena, expr_arena, apply_scan_predicate_to_scan_ir)?;
+    validate!("dataset expansion");
+
+    // Only the streaming engine takes the build side into account.
+    if opt_flags.contains(OptFlags::NEW_STREAMING | OptFlags::ROW_ESTIMATE) {
+        join_build_side::set_join_build_sides(root, ir_arena, expr_arena);
+    }
+
+    run_custom_rules(OptimizationPhase::Late, root, ir_arena, expr_arena)?;
+    validate!("late custom optimization rules");
 
     // During debug we check if the optimizations have not modified the final schema.
     #[cfg(debug_assertions)]
@@ -0,0 +1,242 @@
+//! Optimizations of unions whose inputs partition the rows by the hash of key columns, see
+//! [`HashPartitioning`].
+use polars_core::prelude::*;
+use polars_utils::arena::{Arena, Node};
+
+use super::OptimizationRule;
+use crate::prelude::*;
+
+/// Runs group-bys on (a superset of) the partition keys, and joins on the partition keys of two
+/// unions partitioned in the same way, per partition. The result is again a partitioned union.
+pub(super) struct PartitionedUnionRule {}
+
+impl OptimizationRule for PartitionedUnionRule {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> PolarsResult<Option<IR>> {
+        match lp_arena.get(node) {
+            IR::GroupBy {
+                input,
+                keys,
+                maintain_order: false,
+                options,
+                apply: None,
+                ..
+            } if options.slice.is_none() && !options.is_rolling() && !options.is_dynamic() => {
+                let Some((inputs, partitioning)) = partitioned_union(*input, lp_arena) else {
+                    return Ok(None);
+                };
+                let covers_partition_keys = partitioning.keys.iter().all(|key| {
+                    keys.iter()
+                        .any(|e| column_name(e, expr_arena) == Some(key) && e.output_name() == key)
+                });
+                if !covers_partition_keys {
+                    return Ok(None);
+                }
+
+                let partitioning = partitioning.clone();
+                let partitions = inputs.to_vec();
+                let group_by = lp_arena.get(node).clone();
+                let inputs = partitions
+                    .into_iter()
+                    .map(|partition| {
+                        let mut group_by = group_by.clone();
+                        let IR::GroupBy { input, .. } = &mut group_by else {
+                            unreachable!()
+                        };
+                        *input = partition;
+                        lp_arena.add(group_by)
+                    })
+                    .collect();
+
+                Ok(Some(partitioned_union_of(inputs, Some(partitioning))))
+            },
+            IR::Join {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                options,
+                ..
+            } if (options.args.how.is_equi() || options.args.how.is_semi_anti())
+                && options.args.slice.is_none()
+                && options.args.maintain_order == MaintainOrderJoin::None =>
+            {
+                let Some((left_inputs, left_partitioning)) =
+                    partitioned_union(*input_left, lp_arena)
+                else {
+                    return Ok(None);
+                };
+                let Some((right_inputs, right_partitioning)) =
+                    partitioned_union(*input_right, lp_arena)
+                else {
+                    return Ok(None);
+                };
+                if left_inputs.len() != right_inputs.len()
+                    || left_partitioning.keys.len() != right_partitioning.keys.len()
+                {
+                    return Ok(None);
+                }
+
+                // Rows with equal join keys must have equal partition keys, which are hashed to
+                // the same partition on both sides.
+                let left_schema = lp_arena.get(left_inputs[0]).schema(lp_arena);
+                let right_schema = lp_arena.get(right_inputs[0]).schema(lp_arena);
+                let is_co_partitioned = left_partitioning
+                    .keys
+                    .iter()
+                    .zip(right_partitioning.keys.iter())
+                    .all(|(left_key, right_key)| {
+                        left_schema.get(left_key).is_some()
+                            && left_schema.get(left_key) == right_schema.get(right_key)
+                            && left_on.iter().zip(right_on).any(|(l, r)| {
+                                column_name(l, expr_arena) == Some(left_key)
+                                    && column_name(r, expr_arena) == Some(right_key)
+                            })
+                    });
+                if !is_co_partitioned {
+                    return Ok(None);
+                }
+
+                // The left keys are kept in the output of these joins.
+                let partitioning = match options.args.how {
+                    JoinType::Inner | JoinType::Left => Some(left_partitioning.clone()),
+                    #[cfg(feature = "semi_anti_join")]
+                    JoinType::Semi | JoinType::Anti => Some(left_partitioning.clone()),
+                    _ => None,
+                };
+                let partitions = left_inputs
+                    .iter()
+                    .copied()
+                    .zip(right_inputs.iter().copied())
+                    .collect::<Vec<_>>();
+                let join = lp_arena.get(node).clone();
+                let inputs = partitions
+                    .into_iter()
+                    .map(|(left, right)| {
+                        let mut join = join.clone();
+                        let IR::Join {
+                            input_left,
+                            input_right,
+                            ..
+                        } = &mut join
+                        else {
+                            unreachable!()
+                        };
+                        *input_left = left;
+                        *input_right = right;
+                        lp_arena.add(join)
+                    })
+                    .collect();
+
+                Ok(Some(partitioned_union_of(inputs, partitioning)))
+            },
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Removes the inputs of a partitioned union that the equality predicates on all the partition
+/// keys rule out. The predicates are still applied to the remaining input.
+pub(super) fn prune_partitions(
+    inputs: &mut Vec<Node>,
+    options: &mut UnionOptions,
+    acc_predicates: &PlHashMap<PlSmallStr, ExprIR>,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<()> {
+    let Some(partitioning) = &options.partitioning else {
+        return Ok(());
+    };
+    if options.slice.is_some() || inputs.len() < 2 {
+        return Ok(());
+    }
+
+    let schema = lp_arena.get(inputs[0]).schema(lp_arena);
+    let mut key_columns = Vec::with_capacity(partitioning.keys.len());
+    for key in partitioning.keys.iter() {
+        let Some(dtype) = schema.get(key) else {
+            return Ok(());
+        };
+        // The hash of a categorical depends on its categories.
+        if !(dtype.is_primitive_numeric()
+            || dtype.is_bool()
+            || dtype.is_string()
+            || dtype.is_temporal())
+        {
+            return Ok(());
+        }
+        let Some(value) = acc_predicates
+            .values()
+            .find_map(|e| equality_literal(e.node(), key, expr_arena))
+        else {
+            return Ok(());
+        };
+        let Ok(column) = Series::from_any_values_and_dtype(key.clone(), &[value], dtype, true)
+        else {
+            return Ok(());
+        };
+        key_columns.push(column.into_column());
+    }
+
+    let keys = DataFrame::new(1, key_columns)?;
+    let partition = HashPartitioning::partition_idxs(&keys, inputs.len())?[0];
+    *inputs = vec![inputs[partition]];
+    options.partitioning = None;
+    Ok(())
+}
+
+/// The inputs and partitioning of `node` if it is a partitioned union.
+fn partitioned_union(node: Node, lp_arena: &Arena<IR>) -> Option<(&[Node], &HashPartitioning)> {
+    match lp_arena.get(node) {
+        IR::Union { inputs, options } if options.slice.is_none() => {
+            Some((inputs.as_slice(), options.partitioning.as_ref()?))
+        },
+        _ => None,
+    }
+}
+
+fn partitioned_union_of(inputs: Vec<Node>, partitioning: Option<HashPartitioning>) -> IR {
+    IR::Union {
+        inputs,
+        options: UnionOptions {
+            maintain_order: false,
+            partitioning,
+            ..Default::default()
+        },
+    }
+}
+
+fn column_name<'a>(e: &ExprIR, expr_arena: &'a Arena<AExpr>) -> Option<&'a PlSmallStr> {
+    match expr_arena.get(e.node()) {
+        AExpr::Column(name) => Some(name),
+        _ => None,
+    }
+}
+
+/// The literal that `column` is compared to if `predicate` is `col(column) == lit(value)`.
+fn equality_literal<'a>(
+    predicate: Node,
+    column: &str,
+    expr_arena: &'a Arena<AExpr>,
+) -> Option<AnyValue<'a>> {
+    let AExpr::BinaryExpr {
+        left,
+        op: Operator::Eq,
+        right,
+    } = expr_arena.get(predicate)
+    else {
+        return None;
+    };
+    match (expr_arena.get(*left), expr_arena.get(*right)) {
+        (AExpr::Column(name), AExpr::Literal(lv)) | (AExpr::Literal(lv), AExpr::Column(name))
+            if name == column && lv.is_scalar() =>
+        {
+            lv.to_any_value()
+        },
+        _ => None,
+    }
+}
@@ -357,13 +357,27 @@ impl PredicatePushDown {
                     blocked_names.push(col);
                 }
 
-                let local_predicates = if blocked_names.is_empty() {
+                blocked_names.extend(
+                    unified_scan_args
+                        .provenance
+                        .iter()
+                        .map(|(name, _)| name.as_str()),
+                );
+
+                let mut local_predicates = if blocked_names.is_empty() {
                     vec![]
                 } else {
                     transfer_to_local_by_name(expr_arena, &mut acc_predicates, |name| {
                         blocked_names.contains(&name.as_ref())
                     })
                 };
+                if let FileScanIR::Anonymous { function, .. } = &*scan_type {
+                    local_predicates.extend(negotiate_anonymous_scan_predicates(
+                        function.as_ref(),
+                        &mut acc_predicates,
+                        expr_arena,
+                    ));
+                }
                 let predicate = predicate_at_scan(acc_predicates, predicate.clone(), expr_arena);
 
                 let do_optimization = predicate.is_some()
@@ -371,9 +385,8 @@ impl PredicatePushDown {
                         #[cfg(feature = "csv")]
                         FileScanIR::Csv { .. } => unified_scan_args.pre_slice.is_none(),
                         FileScanIR::ExpandedPaths { .. } => false,
-                        FileScanIR::Anonymous { function, .. } => {
-                            function.allows_predicate_pushdown()
-                        },
+                        // Only the predicates the scan supports are left.
+                        FileScanIR::Anonymous { .. } => true,
                         #[allow(unreachable_patterns)]
                         _ => true,
                     };
@@ -580,11 +593,21 @@ impl PredicatePushDown {
                 options,
                 acc_predicates,
             ),
-            Union { inputs, options } => {
+            Union {
+                mut inputs,
+                mut options,
+            } => {
                 if options.slice.is_some() {
                     let lp = Union { inputs, options };
                     self.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena)
                 } else {
+                    super::partitioned_union::prune_partitions(
+                        &mut inputs,
+                        &mut options,
+                        &acc_predicates,
+                        lp_arena,
+                        expr_arena,
+                    )?;
                     let lp = Union { inputs, options };
                     self.pushdown_and_continue(lp, acc_predicates, lp_arena, expr_arena, false)
                 }
@@ -113,6 +113,31 @@ pub(super) fn predicate_at_scan(
     }
 }
 
+/// Negotiates the predicates in `acc_predicates` with an anonymous scan. The predicates that the
+/// scan doesn't support are removed and returned, and the inexact ones are also returned, to be
+/// applied after the scan.
+pub(super) fn negotiate_anonymous_scan_predicates(
+    function: &dyn AnonymousScan,
+    acc_predicates: &mut PlHashMap<PlSmallStr, ExprIR>,
+    expr_arena: &Arena<AExpr>,
+) -> Vec<ExprIR> {
+    let mut local_predicates = vec![];
+    acc_predicates.retain(|_, predicate| {
+        match function.predicate_support(&node_to_expr(predicate.node(), expr_arena)) {
+            PredicateSupport::Exact => true,
+            PredicateSupport::Inexact => {
+                local_predicates.push(predicate.clone());
+                true
+            },
+            PredicateSupport::Unsupported => {
+                local_predicates.push(predicate.clone());
+                false
+            },
+        }
+    });
+    local_predicates
+}
+
 /// Evaluates a condition on the column name inputs of every predicate, where if
 /// the condition evaluates to true on any column name the predicate is
 /// transferred to local.
@@ -90,6 +90,7 @@ fn get_scan_columns(
     expr_arena: &Arena<AExpr>,
     row_index: Option<&RowIndex>,
     file_path_col: Option<&str>,
+    provenance: Option<&ProvenanceColumns>,
     // When set, the column order will match the order from the provided schema
     normalize_order_schema: Option<&Schema>,
 ) -> Option<Arc<[PlSmallStr]>> {
@@ -114,6 +115,10 @@ fn get_scan_columns(
                 }
             }
 
+            if provenance.is_some_and(|x| x.contains(name)) {
+                return None;
+            }
+
             Some(name.clone())
         })
         .collect::<Vec<_>>();
@@ -440,6 +445,7 @@ impl ProjectionPushDown {
                             expr_arena,
                             None,
                             None,
+                            None,
                             normalize_order_schema,
                         );
 
@@ -552,6 +558,7 @@ impl ProjectionPushDown {
                                 expr_arena,
                                 unified_scan_args.row_index.as_ref(),
                                 unified_scan_args.include_file_paths.as_deref(),
+                                Some(&unified_scan_args.provenance),
                                 None,
                             );
 
@@ -573,6 +580,13 @@ impl ProjectionPushDown {
                                     }
                                 }
 
+                                for (name, _) in unified_scan_args.provenance.iter() {
+                                    if let Some(i) = schema.index_of(name) {
+                                        let (name, dtype) = schema.shift_remove_index(i).unwrap();
+                                        schema.insert_at_index(schema.len(), name, dtype)?;
+                                    }
+                                }
+
                                 // If the projected schema is identical to the full file schema,
                                 // the projection is a no-op — clear it.
                                 if schema == *file_info.schema {
@@ -618,6 +632,21 @@ impl ProjectionPushDown {
                             }
                         };
 
+                        if let Some(output_schema) = output_schema.as_deref()
+                            && !unified_scan_args.provenance.is_empty()
+                        {
+                            let mut file_schema = Arc::unwrap_or_clone(file_info.schema);
+
+                            for (name, _) in unified_scan_args.provenance.iter() {
+                                if !output_schema.contains(name) {
+                                    file_schema.shift_remove(name);
+                                }
+                            }
+
+                            file_info.schema = Arc::new(file_schema);
+                            unified_scan_args.provenance.retain_in(output_schema);
+                        }
+
                         Ok(Scan {
                             sources,
                             file_info,
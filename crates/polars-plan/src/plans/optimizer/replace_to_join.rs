@@ -0,0 +1,297 @@
+//! Rewrites `replace` and `replace_strict` with a large literal mapping into a left join against
+//! that mapping.
+//!
+//! As an expression, the hash table of the mapping is rebuilt for every chunk or morsel that is
+//! replaced; as a join, it is built once. Only replacements at the root of a `select` or
+//! `with_columns` expression are rewritten. Mappings that the expression would reject, e.g. with
+//! duplicate old values, are left to the expression so that it raises the same error.
+use std::sync::Arc;
+
+use polars_core::chunked_array::cast::CastOptions;
+use polars_core::prelude::*;
+use polars_utils::arena::{Arena, Node};
+use polars_utils::pl_str::unique_column_name;
+
+use crate::prelude::*;
+
+/// The minimum number of old values for a replacement to become a join.
+const MIN_MAPPING_LEN: usize = 10_000;
+
+struct Replacement {
+    /// Index of the replacement in the expressions of the projection.
+    idx: usize,
+    key: ExprIR,
+    default: Option<ExprIR>,
+    strict: bool,
+    /// The old values, the new values and a mask of `true` values.
+    mapping: DataFrame,
+}
+
+/// Get the values of an imploded literal `Series`, as produced by type coercion.
+fn literal_values(node: Node, expr_arena: &Arena<AExpr>) -> Option<&Series> {
+    let AExpr::Agg(IRAggExpr::Implode { input, .. }) = expr_arena.get(node) else {
+        return None;
+    };
+    match expr_arena.get(*input) {
+        AExpr::Literal(LiteralValue::Series(s)) => Some(&**s),
+        _ => None,
+    }
+}
+
+fn to_replacement(
+    idx: usize,
+    e: &ExprIR,
+    dtype: &DataType,
+    input_schema: &Schema,
+    expr_arena: &Arena<AExpr>,
+) -> Option<Replacement> {
+    let AExpr::Function {
+        input, function, ..
+    } = expr_arena.get(e.node())
+    else {
+        return None;
+    };
+    let (strict, default) = match function {
+        IRFunctionExpr::Replace => (false, None),
+        IRFunctionExpr::ReplaceStrict { .. } => (true, input.get(3).cloned()),
+        _ => return None,
+    };
+
+    let key = &input[0];
+    if key.is_scalar(expr_arena) || !key.is_length_preserving(expr_arena) {
+        return None;
+    }
+    let old = literal_values(input[1].node(), expr_arena)?;
+    let new = literal_values(input[2].node(), expr_arena)?;
+    if old.len() < MIN_MAPPING_LEN || (new.len() != old.len() && new.len() != 1) {
+        return None;
+    }
+    if old.n_unique().ok()? != old.len() {
+        return None;
+    }
+
+    let key_dtype = key.dtype(input_schema, expr_arena).ok()?;
+    if strict && old.dtype().can_cast_to(key_dtype) != Some(true) {
+        return None;
+    }
+    let old = old.strict_cast(key_dtype).ok()?;
+    let new = if default.is_some() {
+        new.cast(dtype).ok()?
+    } else {
+        new.strict_cast(dtype).ok()?
+    };
+    let new = if new.len() == 1 {
+        new.new_from_index(0, old.len())
+    } else {
+        new
+    };
+
+    let len = old.len();
+    let mapping = DataFrame::new(
+        len,
+        vec![
+            old.with_name(unique_column_name()).into(),
+            new.with_name(unique_column_name()).into(),
+            Column::new_scalar(unique_column_name(), true.into(), len),
+        ],
+    )
+    .ok()?;
+
+    Some(Replacement {
+        idx,
+        key: key.clone(),
+        default,
+        strict,
+        mapping,
+    })
+}
+
+fn null_check(input: Node, function: IRBooleanFunction, expr_arena: &mut Arena<AExpr>) -> Node {
+    let function = IRFunctionExpr::Boolean(function);
+    let options = function.function_options();
+    expr_arena.add(AExpr::Function {
+        input: vec![ExprIR::from_node(input, expr_arena)],
+        function,
+        options,
+    })
+}
+
+/// Raise the error of `replace_strict` if `predicate` is not `true` everywhere, otherwise return
+/// `predicate`.
+fn ensure_all_replaced(predicate: Node, expr_arena: &mut Arena<AExpr>) -> Node {
+    let mut input = vec![ExprIR::from_node(predicate, expr_arena)];
+    for _ in 0..2 {
+        let values = Series::new(PlSmallStr::EMPTY, [true]);
+        let values = expr_arena.add(AExpr::Literal(LiteralValue::Series(SpecialEq::new(values))));
+        let values = expr_arena.add(AExpr::Agg(IRAggExpr::Implode {
+            input: values,
+            maintain_order: true,
+        }));
+        input.push(ExprIR::from_node(values, expr_arena));
+    }
+    let function = IRFunctionExpr::ReplaceStrict {
+        return_dtype: Some(DataType::Boolean),
+    };
+    let options = function.function_options();
+    expr_arena.add(AExpr::Function {
+        input,
+        function,
+        options,
+    })
+}
+
+/// Build the expression that takes the place of the replacement on the joined frame.
+fn replaced(
+    r: &Replacement,
+    key: PlSmallStr,
+    dtype: &DataType,
+    expr_arena: &mut Arena<AExpr>,
+) -> Node {
+    let [_, new, mask]: [PlSmallStr; 3] = r.mapping.get_column_names_owned().try_into().unwrap();
+    let key = expr_arena.add(AExpr::Column(key));
+    let new = expr_arena.add(AExpr::Column(new));
+    let mask = expr_arena.add(AExpr::Column(mask));
+    let matched = null_check(mask, IRBooleanFunction::IsNotNull, expr_arena);
+
+    let (predicate, falsy) = match (r.strict, &r.default) {
+        (false, _) => (matched, key),
+        (true, Some(default)) => {
+            let default = expr_arena.add(AExpr::Cast {
+                expr: default.node(),
+                dtype: dtype.clone(),
+                options: CastOptions::NonStrict,
+            });
+            (matched, default)
+        },
+        // Every value must be replaced, except nulls if null is not an old value. A null that is
+        // an old value is joined with its new value.
+        (true, None) => {
+            let key_is_null = null_check(key, IRBooleanFunction::IsNull, expr_arena);
+            let all_replaced = expr_arena.add(AExpr::BinaryExpr {
+                left: matched,
+                op: Operator::Or,
+                right: key_is_null,
+            });
+            let null = expr_arena.add(AExpr::Literal(LiteralValue::Scalar(Scalar::null(
+                dtype.clone(),
+            ))));
+            (ensure_all_replaced(all_replaced, expr_arena), null)
+        },
+    };
+    expr_arena.add(AExpr::Ternary {
+        predicate,
+        truthy: new,
+        falsy,
+        lazy: false,
+    })
+}
+
+fn rewrite(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let (input, exprs, schema, options, is_select) = match lp_arena.get(node) {
+        IR::Select {
+            input,
+            expr,
+            schema,
+            options,
+        } => (*input, expr, schema, *options, true),
+        IR::HStack {
+            input,
+            exprs,
+            schema,
+            options,
+        } => (*input, exprs, schema, *options, false),
+        _ => return Ok(()),
+    };
+    let input_schema = lp_arena.get(input).schema(lp_arena);
+    let replacements = exprs
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, e)| {
+            let dtype = schema.get(e.output_name())?;
+            to_replacement(idx, e, dtype, &input_schema, expr_arena)
+        })
+        .collect::<Vec<_>>();
+    if replacements.is_empty() {
+        return Ok(());
+    }
+    let mut exprs = exprs.clone();
+    let schema = schema.clone();
+
+    let keys = replacements
+        .iter()
+        .map(|_| unique_column_name())
+        .collect::<Vec<_>>();
+    let key_exprs = replacements
+        .iter()
+        .zip(&keys)
+        .map(|(r, key)| r.key.with_alias(key.clone()))
+        .collect();
+    let mut root = IRBuilder::new(input, expr_arena, lp_arena)
+        .with_columns(key_exprs, options)
+        .node();
+
+    for (r, key) in replacements.iter().zip(&keys) {
+        let df = Arc::new(r.mapping.clone());
+        let other = lp_arena.add(IR::DataFrameScan {
+            schema: df.schema().clone(),
+            df,
+            output_schema: None,
+        });
+        let left_on = vec![ExprIR::from_column_name(key.clone(), expr_arena)];
+        let old = r.mapping.columns()[0].name().clone();
+        let right_on = vec![ExprIR::from_column_name(old, expr_arena)];
+
+        let mut args = JoinArgs::new(JoinType::Left);
+        args.nulls_equal = true;
+        args.coalesce = JoinCoalesce::CoalesceColumns;
+        args.maintain_order = MaintainOrderJoin::Left;
+        args.build_side = Some(JoinBuildSide::PreferRight);
+        let options = Arc::new(JoinOptionsIR {
+            allow_parallel: true,
+            force_parallel: false,
+            args,
+            options: None,
+        });
+        root = IRBuilder::new(root, expr_arena, lp_arena)
+            .join(other, left_on, right_on, options)
+            .node();
+
+        let e = &mut exprs[r.idx];
+        let dtype = schema.get(e.output_name()).unwrap();
+        e.set_node(replaced(r, key.clone(), dtype, expr_arena));
+    }
+
+    let builder = IRBuilder::new(root, expr_arena, lp_arena);
+    let ir = if is_select {
+        builder.project(exprs, options).build()
+    } else {
+        builder
+            .with_columns(exprs, options)
+            .project_simple(schema.iter_names_cloned())?
+            .build()
+    };
+    lp_arena.replace(node, ir);
+    Ok(())
+}
+
+pub(super) fn optimize(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let mut visited = PlHashSet::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node) {
+            continue;
+        }
+        lp_arena.get(node).copy_inputs(&mut stack);
+        rewrite(node, lp_arena, expr_arena)?;
+    }
+    Ok(())
+}
@@ -177,6 +177,7 @@ impl<'a> ObservableOrdersResolver<'a> {
                 predicate,
                 truthy,
                 falsy,
+                ..
             } => zip!(*predicate, *truthy, *falsy),
 
             // Filter has to check whether zipping observes order, otherwise it propagates expr order.
@@ -162,6 +162,7 @@ impl OptimizationRule for SimplifyBooleanRule {
                 predicate,
                 truthy,
                 falsy,
+                ..
             } => {
                 return Ok(arity::simplify_ternary(
                     *predicate, *truthy, *falsy, expr_arena,
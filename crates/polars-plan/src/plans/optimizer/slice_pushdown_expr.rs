@@ -102,10 +102,13 @@ impl OptimizationRule for SlicePushDown {
                         right: inputs[1],
                     })
                 },
+                // The branches of a lazy ternary are evaluated on a subset of the rows, which a
+                // slice of the inputs would change.
                 Ternary {
                     truthy,
                     falsy,
                     predicate,
+                    lazy: false,
                 } => {
                     let mut inputs = [*truthy, *falsy, *predicate];
 
@@ -113,6 +116,7 @@ impl OptimizationRule for SlicePushDown {
                         truthy: inputs[0],
                         falsy: inputs[1],
                         predicate: inputs[2],
+                        lazy: false,
                     })
                 },
                 m @ AnonymousFunction { options, .. } if options.is_elementwise() => {
@@ -293,7 +293,9 @@ impl SlicePushDown {
                     FileScanIR::ExpandedPaths { .. } => false,
 
                     // TODO: This can be `true` after Anonymous scan dispatches to new-streaming.
-                    FileScanIR::Anonymous { .. } => state.offset == 0,
+                    FileScanIR::Anonymous { function, .. } => {
+                        state.offset == 0 && function.allows_slice_pushdown()
+                    },
                 } =>
             {
                 if let Some(existing) = &mut unified_scan_args.pre_slice {
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[cfg(all(feature = "strings", feature = "concat_str"))]
 use crate::plans::IRStringFunction;
 use crate::plans::{
-    AExpr, ExprIR, FunctionIR, HintIR, IR, IRFunctionExpr, Sorted, ToFieldContext,
+    AExpr, ExprIR, FileScanIR, FunctionIR, HintIR, IR, IRFunctionExpr, Sorted, ToFieldContext,
     constant_evaluate, into_column,
 };
 
@@ -175,7 +175,24 @@ fn is_sorted_rec(
             input,
             predicate: _,
         } => rec!(*input),
-        IR::Scan { .. } => None,
+        IR::Scan {
+            scan_type,
+            file_info,
+            output_schema,
+            ..
+        } => match scan_type.as_ref() {
+            FileScanIR::Anonymous { function, .. } => {
+                // The sort keys are only kept up to the first key that is projected out.
+                let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
+                let sorted_cols = function
+                    .sorted_by()
+                    .into_iter()
+                    .take_while(|s| schema.contains(&s.column))
+                    .collect_vec();
+                (!sorted_cols.is_empty()).then(|| IRSorted(sorted_cols.into()))
+            },
+            _ => None,
+        },
         IR::DataFrameScan { df, .. } => {
             let sorted_cols = df
                 .columns()
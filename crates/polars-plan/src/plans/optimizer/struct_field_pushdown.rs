@@ -0,0 +1,149 @@
+//! Pushes down the fields of struct columns that a query uses into Parquet scans, so that the
+//! leaf columns of the other fields don't have to be decoded.
+//!
+//! A struct column of which only fields are used, e.g. `col("s").struct.field("a")`, is read with
+//! only those fields; its other fields are read as nulls. Columns are matched by name across the
+//! whole plan, so this only runs if the plan consists of nodes that don't rename columns without
+//! an expression.
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_utils::arena::{Arena, Node};
+
+use crate::plans::predicates::into_struct_field;
+use crate::prelude::*;
+
+#[derive(Default)]
+struct StructFieldUses {
+    /// The first-level fields that are used of a column.
+    fields: PlHashMap<PlSmallStr, PlHashSet<PlSmallStr>>,
+    /// Columns that are used as a whole.
+    columns: PlHashSet<PlSmallStr>,
+}
+
+impl StructFieldUses {
+    fn collect_expr(&mut self, root: Node, expr_arena: &Arena<AExpr>) {
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            if let Some((column, path)) = into_struct_field(node, expr_arena) {
+                self.fields
+                    .entry(column)
+                    .or_default()
+                    .insert(path[0].clone());
+                continue;
+            }
+            let ae = expr_arena.get(node);
+            if let AExpr::Column(name) = ae {
+                self.columns.insert(name.clone());
+            }
+            ae.children_rev(&mut stack);
+        }
+    }
+
+    fn collect_schema(&mut self, schema: &Schema) {
+        self.columns.extend(schema.iter_names_cloned());
+    }
+}
+
+pub(super) fn optimize(root: Node, lp_arena: &mut Arena<IR>, expr_arena: &Arena<AExpr>) {
+    let mut uses = StructFieldUses::default();
+    let mut scans = vec![];
+    let mut visited = PlHashSet::new();
+    let mut stack = vec![root];
+
+    uses.collect_schema(&lp_arena.get(root).schema(lp_arena));
+
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node) {
+            continue;
+        }
+        let ir = lp_arena.get(node);
+        match ir {
+            IR::Scan {
+                scan_type,
+                unified_scan_args,
+                ..
+            } => {
+                // The fields of the files may be named differently than those of the scan.
+                if matches!(scan_type.as_ref(), FileScanIR::Parquet { .. })
+                    && unified_scan_args.column_mapping.is_none()
+                    && unified_scan_args.schema_evolution.is_none()
+                {
+                    scans.push(node);
+                }
+            },
+            IR::GroupBy { apply: None, .. }
+            | IR::DataFrameScan { .. }
+            | IR::Filter { .. }
+            | IR::Select { .. }
+            | IR::HStack { .. }
+            | IR::Sort { .. }
+            | IR::Slice { .. }
+            | IR::SimpleProjection { .. }
+            | IR::Cache { .. }
+            | IR::Union { .. }
+            | IR::HConcat { .. }
+            | IR::SinkMultiple { .. } => {},
+            IR::Sink { input, .. } => {
+                uses.collect_schema(&lp_arena.get(*input).schema(lp_arena));
+            },
+            IR::Join {
+                input_left,
+                input_right,
+                ..
+            } => {
+                // Columns of the right side that are also on the left side are renamed.
+                let left_schema = lp_arena.get(*input_left).schema(lp_arena);
+                let right_schema = lp_arena.get(*input_right).schema(lp_arena);
+                uses.columns.extend(
+                    right_schema
+                        .iter_names()
+                        .filter(|name| left_schema.contains(name))
+                        .cloned(),
+                );
+            },
+            _ => return,
+        }
+        for e in ir.exprs() {
+            uses.collect_expr(e.node(), expr_arena);
+        }
+        ir.copy_inputs(&mut stack);
+    }
+
+    for node in scans {
+        let IR::Scan {
+            file_info,
+            output_schema,
+            scan_type,
+            ..
+        } = lp_arena.get_mut(node)
+        else {
+            unreachable!()
+        };
+        let FileScanIR::Parquet { options, .. } = scan_type.as_mut() else {
+            unreachable!()
+        };
+        let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
+
+        let struct_fields = schema
+            .iter()
+            .filter_map(|(name, dtype)| {
+                let DataType::Struct(fields) = dtype else {
+                    return None;
+                };
+                if uses.columns.contains(name) {
+                    return None;
+                }
+                let used = uses.fields.get(name)?;
+                let used = fields
+                    .iter()
+                    .filter(|f| used.contains(f.name()))
+                    .map(|f| f.name().clone())
+                    .collect::<Arc<[_]>>();
+                (!used.is_empty() && used.len() < fields.len()).then(|| (name.clone(), used))
+            })
+            .collect::<Arc<[_]>>();
+
+        Arc::make_mut(options).struct_fields = (!struct_fields.is_empty()).then_some(struct_fields);
+    }
+}
@@ -0,0 +1,175 @@
+//! Accounting of the differential privacy budget of queries.
+//!
+//! Every evaluation of a noisy expression (see [`Expr::dp_noise`]) in a plan spends its
+//! `epsilon`, and the costs of the expressions in a plan add up (sequential composition).
+//!
+//! Noisy expressions are rejected in group-by aggregations, window expressions and list
+//! evaluations. The groups of these are derived from the data and are released without noise, so
+//! the output alone would reveal which keys exist in the data.
+//!
+//! The budget is enforced for every query that is collected or sunk: the cost of a plan with
+//! noisy expressions is spent from the budget set with [`set_privacy_budget`] before the plan
+//! runs, and the query fails if no budget is set or if the remaining budget is too small.
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
+
+use polars_core::prelude::*;
+use polars_utils::arena::{Arena, Node};
+
+use crate::prelude::*;
+
+/// The total `epsilon` spent by the noisy expressions of the optimized plan rooted at `root`.
+///
+/// Returns an error if a noisy expression is evaluated in groups that are derived from the data.
+pub fn privacy_cost(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<f64> {
+    let mut cost = 0.0;
+    let mut visited = PlHashSet::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        // Shared subplans are only evaluated once.
+        if !visited.insert(node) {
+            continue;
+        }
+        let ir = lp_arena.get(node);
+        let is_group_by = matches!(ir, IR::GroupBy { .. });
+        for e in ir.exprs() {
+            if is_group_by {
+                ensure_no_noise(e.node(), expr_arena, "group-by aggregations")?;
+            }
+            for (_, ae) in expr_arena.iter(e.node()) {
+                match ae {
+                    AExpr::Function {
+                        function: IRFunctionExpr::DpNoise { epsilon, .. },
+                        ..
+                    } => cost += epsilon,
+                    AExpr::Over { function, .. } => {
+                        ensure_no_noise(*function, expr_arena, "window expressions")?
+                    },
+                    #[cfg(feature = "dynamic_group_by")]
+                    AExpr::Rolling { function, .. } => {
+                        ensure_no_noise(*function, expr_arena, "rolling expressions")?
+                    },
+                    AExpr::Eval { evaluation, .. } => {
+                        ensure_no_noise(*evaluation, expr_arena, "list and array evaluations")?
+                    },
+                    _ => {},
+                }
+            }
+        }
+        ir.copy_inputs(&mut stack);
+    }
+    Ok(cost)
+}
+
+fn contains_noise(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    expr_arena.iter(node).any(|(_, ae)| match ae {
+        AExpr::Function {
+            function: IRFunctionExpr::DpNoise { .. },
+            ..
+        } => true,
+        // The evaluation is not an input, so it is not part of the iteration.
+        AExpr::Eval { evaluation, .. } => contains_noise(*evaluation, expr_arena),
+        _ => false,
+    })
+}
+
+fn ensure_no_noise(node: Node, expr_arena: &Arena<AExpr>, context: &str) -> PolarsResult<()> {
+    polars_ensure!(
+        !contains_noise(node, expr_arena),
+        InvalidOperation: "differentially private aggregations are not supported in {}, as their groups are derived from the data and released without noise",
+        context
+    );
+    Ok(())
+}
+
+/// A privacy budget that is spent by the queries run against it.
+#[derive(Debug)]
+pub struct PrivacyBudget {
+    epsilon: f64,
+    spent: Mutex<f64>,
+}
+
+impl PrivacyBudget {
+    pub fn new(epsilon: f64) -> Self {
+        Self {
+            epsilon,
+            spent: Mutex::new(0.0),
+        }
+    }
+
+    /// The total budget.
+    pub fn epsilon(&self) -> f64 {
+        self.epsilon
+    }
+
+    pub fn spent(&self) -> f64 {
+        *self.spent.lock().unwrap()
+    }
+
+    pub fn remaining(&self) -> f64 {
+        self.epsilon - self.spent()
+    }
+
+    /// Spend `epsilon` of the budget. Nothing is spent if this would exceed the budget.
+    pub fn spend(&self, epsilon: f64) -> PolarsResult<()> {
+        let mut spent = self.spent.lock().unwrap();
+        polars_ensure!(
+            *spent + epsilon <= self.epsilon,
+            InvalidOperation: "query with a privacy cost of {} exceeds the remaining privacy budget of {}",
+            epsilon, self.epsilon - *spent
+        );
+        *spent += epsilon;
+        Ok(())
+    }
+}
+
+static PRIVACY_BUDGET: LazyLock<RwLock<Option<Arc<PrivacyBudget>>>> =
+    LazyLock::new(Default::default);
+
+/// Set the budget that is spent by all queries with noisy expressions, returning the previous
+/// budget. With `None`, such queries fail.
+pub fn set_privacy_budget(budget: Option<Arc<PrivacyBudget>>) -> Option<Arc<PrivacyBudget>> {
+    std::mem::replace(&mut *PRIVACY_BUDGET.write().unwrap(), budget)
+}
+
+pub fn get_privacy_budget() -> Option<Arc<PrivacyBudget>> {
+    PRIVACY_BUDGET.read().unwrap().clone()
+}
+
+static DP_NOISE_SEED: RwLock<Option<u64>> = RwLock::new(None);
+
+/// Seed the generator of the noise of [`Expr::dp_noise`], returning the previous seed.
+///
+/// This is meant for testing only: with a seed the noise is predictable, so the queries are no
+/// longer differentially private.
+pub fn set_dp_noise_seed(seed: Option<u64>) -> Option<u64> {
+    std::mem::replace(&mut *DP_NOISE_SEED.write().unwrap(), seed)
+}
+
+pub fn dp_noise_seed() -> Option<u64> {
+    *DP_NOISE_SEED.read().unwrap()
+}
+
+/// Spend the privacy cost of the optimized plan rooted at `root` from the budget set with
+/// [`set_privacy_budget`]. Must be called before the plan runs.
+pub fn spend_privacy_budget(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<()> {
+    let cost = privacy_cost(root, lp_arena, expr_arena)?;
+    if cost == 0.0 {
+        return Ok(());
+    }
+    let Some(budget) = get_privacy_budget() else {
+        polars_bail!(
+            InvalidOperation: "query with a privacy cost of {} requires a privacy budget, see `set_privacy_budget`",
+            cost
+        );
+    };
+    budget.spend(cost)
+}
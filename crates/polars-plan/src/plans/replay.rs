@@ -0,0 +1,67 @@
+//! Bundles to replay a failing node of a query in isolation.
+//!
+//! When a node fails, the outputs of its inputs can be recorded by the engine. The node is then
+//! extracted into a plan of its own that scans these outputs instead of its inputs, so that the
+//! failure can be reproduced without the rest of the query or its sources.
+#[cfg(feature = "ir_serde")]
+use std::io::{Read, Write};
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_utils::arena::{Arena, Node};
+#[cfg(feature = "ir_serde")]
+use polars_utils::pl_serialize;
+
+use crate::prelude::*;
+
+/// The plan of a failing node, with the recorded outputs of its inputs.
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReplayBundle {
+    /// The failing node, whose inputs are in-memory scans of the recorded outputs.
+    pub plan: IRPlan,
+    /// The error of the failing node when it was recorded.
+    pub error: String,
+}
+
+impl ReplayBundle {
+    /// Extracts `node` from `lp_arena`, replacing its inputs by scans of `inputs`.
+    ///
+    /// # Panics
+    /// Panics if the number of `inputs` is not the number of inputs of `node`.
+    pub fn new(
+        node: Node,
+        lp_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+        inputs: Vec<DataFrame>,
+        error: &PolarsError,
+    ) -> Self {
+        let mut replay_arena = Arena::with_capacity(inputs.len() + 1);
+        let scans = inputs
+            .into_iter()
+            .map(|df| {
+                let schema = df.schema().clone();
+                replay_arena.add(IR::DataFrameScan {
+                    df: Arc::new(df),
+                    schema,
+                    output_schema: None,
+                })
+            })
+            .collect::<Vec<_>>();
+        let lp_top = replay_arena.add(lp_arena.get(node).clone().with_inputs(scans));
+
+        Self {
+            plan: IRPlan::new(lp_top, replay_arena, expr_arena.clone()),
+            error: error.to_string(),
+        }
+    }
+
+    #[cfg(feature = "ir_serde")]
+    pub fn serialize<W: Write>(&self, writer: W) -> PolarsResult<()> {
+        pl_serialize::serialize_into_writer::<_, _, true>(writer, self)
+    }
+
+    #[cfg(feature = "ir_serde")]
+    pub fn deserialize<R: Read>(reader: R) -> PolarsResult<Self> {
+        pl_serialize::deserialize_from_reader::<_, _, true>(reader)
+    }
+}
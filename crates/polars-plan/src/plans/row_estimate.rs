@@ -0,0 +1,330 @@
+//! Estimation of the number of rows that the nodes of a plan output.
+//!
+//! The estimates start from the known or estimated number of rows of the sources, and are
+//! propagated with simple heuristics: a filter keeps [`FILTER_SELECTIVITY`] of its input, and
+//! the number of groups of a group-by or of unique rows is bounded by the number of input rows.
+//!
+//! The number of distinct values of the columns of a scan, from a statistics sidecar or the
+//! column chunk statistics of a Parquet file, bounds the number of groups of a group-by on them,
+//! and gives the number of rows of an inner join on them.
+use polars_core::prelude::{InitHashMaps as _, PlHashMap};
+use polars_core::utils::slice_offsets;
+use polars_utils::format_pl_smallstr;
+use polars_utils::arena::{Arena, Node};
+
+use crate::prelude::*;
+
+/// The fraction of the rows that a filter is assumed to keep.
+pub const FILTER_SELECTIVITY: f64 = 0.5;
+
+/// Estimate the number of rows of every node of the plan under `root`. Nodes whose number of
+/// rows can't be estimated are not in the map.
+pub fn estimate_rows(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PlHashMap<Node, usize> {
+    let nodes = lp_arena
+        .iter(root)
+        .map(|(node, _)| node)
+        .collect::<Vec<_>>();
+
+    // The inputs of a node come after it, so they are estimated first.
+    let mut estimates = PlHashMap::with_capacity(nodes.len());
+    for node in nodes.into_iter().rev() {
+        if estimates.contains_key(&node) {
+            continue;
+        }
+        if let Some(n) = estimate_node(lp_arena.get(node), lp_arena, expr_arena, &estimates) {
+            estimates.insert(node, n);
+        }
+    }
+    estimates
+}
+
+/// Estimate the number of distinct non-null values of `column` in the output of `node`, from the
+/// distinct counts of the scan it comes from. Nodes that only remove rows or keep the column as
+/// is pass on the count of their input, bounded by their own estimated number of rows.
+fn estimate_distinct(
+    node: Node,
+    column: &str,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    estimates: &PlHashMap<Node, usize>,
+) -> Option<usize> {
+    use IR::*;
+
+    let keeps_column = |exprs: &[ExprIR]| {
+        exprs.iter().all(|e| {
+            e.output_name() != column
+                || matches!(expr_arena.get(e.node()), AExpr::Column(name) if name == column)
+        })
+    };
+
+    let n = match lp_arena.get(node) {
+        Scan {
+            sources,
+            scan_type,
+            unified_scan_args,
+            ..
+        } => {
+            if let Some(table_statistics) = &unified_scan_args.table_statistics {
+                let ndv = table_statistics
+                    .column(&format_pl_smallstr!("{column}_ndv"))
+                    .ok()?
+                    .idx()
+                    .ok()?;
+                (ndv.null_count() == 0).then(|| ndv.iter().map(|x| x.unwrap() as usize).sum())?
+            } else {
+                match scan_type.as_ref() {
+                    #[cfg(feature = "parquet")]
+                    FileScanIR::Parquet {
+                        metadata: Some(metadata),
+                        ..
+                    } if sources.len() == 1 => {
+                        polars_io::parquet::read::column_distinct_count(metadata, column)?
+                    },
+                    _ => return None,
+                }
+            }
+        },
+        Select {
+            input, expr: exprs, ..
+        }
+        | HStack { input, exprs, .. } => {
+            if !keeps_column(exprs) {
+                return None;
+            }
+            estimate_distinct(*input, column, lp_arena, expr_arena, estimates)?
+        },
+        Filter { input, .. }
+        | Slice { input, .. }
+        | Sort { input, .. }
+        | SimpleProjection { input, .. }
+        | Cache { input, .. } => estimate_distinct(*input, column, lp_arena, expr_arena, estimates)?,
+        _ => return None,
+    };
+
+    Some(match estimates.get(&node) {
+        Some(rows) => n.min(*rows),
+        None => n,
+    })
+}
+
+fn estimate_node(
+    ir: &IR,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    estimates: &PlHashMap<Node, usize>,
+) -> Option<usize> {
+    use IR::*;
+
+    let input = |node: &Node| estimates.get(node).copied();
+    // The distinct values of the keys, if they are all columns.
+    let distinct = |node: Node, keys: &[ExprIR]| -> Option<Vec<usize>> {
+        keys.iter()
+            .map(|e| match expr_arena.get(e.node()) {
+                AExpr::Column(name) => {
+                    estimate_distinct(node, name, lp_arena, expr_arena, estimates)
+                },
+                _ => None,
+            })
+            .collect()
+    };
+    let filtered = |n: usize| (n as f64 * FILTER_SELECTIVITY).ceil() as usize;
+    let sliced = |n: usize, offset: i64, len: usize| slice_offsets(offset, len, n).1;
+
+    match ir {
+        DataFrameScan { df, .. } => Some(df.height()),
+        Scan {
+            file_info,
+            predicate,
+            unified_scan_args,
+            ..
+        } => {
+            let mut n = match file_info.row_estimation {
+                (Some(n), _) => n,
+                (None, usize::MAX) => return None,
+                (None, n) => n,
+            };
+            if let Some(pre_slice) = &unified_scan_args.pre_slice {
+                n = n.min(pre_slice.len());
+            }
+            if predicate.is_some() {
+                n = filtered(n);
+            }
+            Some(n)
+        },
+        Filter { input: node, .. } => input(node).map(filtered),
+        Slice {
+            input: node,
+            offset,
+            len,
+        } => input(node).map(|n| sliced(n, *offset, *len as usize)),
+        Select {
+            input: node, expr, ..
+        } => {
+            if !expr.is_empty() && expr.iter().all(|e| e.is_scalar(expr_arena)) {
+                Some(1)
+            } else {
+                input(node)
+            }
+        },
+        Sort {
+            input: node, slice, ..
+        } => input(node).map(|n| match slice {
+            Some((offset, len, _)) => sliced(n, *offset, *len),
+            None => n,
+        }),
+        GroupBy {
+            input: node, keys, ..
+        } => {
+            if keys.is_empty() {
+                return Some(1);
+            }
+            let n = input(node)?;
+            // Every combination of the distinct values of the keys, and the null group.
+            Some(match distinct(*node, keys) {
+                Some(ndvs) => ndvs
+                    .iter()
+                    .try_fold(1usize, |acc, ndv| acc.checked_mul(ndv + 1))
+                    .map_or(n, |groups| groups.min(n)),
+                None => n,
+            })
+        },
+        Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options,
+            ..
+        } => {
+            let (left, right) = (input(input_left)?, input(input_right)?);
+            let n = match &options.args.how {
+                // Every row matches `rows / ndv` rows of the other side for the key with the
+                // most distinct values.
+                JoinType::Inner => {
+                    match (distinct(*input_left, left_on), distinct(*input_right, right_on)) {
+                        (Some(l), Some(r)) => l
+                            .iter()
+                            .zip(&r)
+                            .map(|(l, r)| *l.max(r))
+                            .max()
+                            .filter(|ndv| *ndv > 0)
+                            .map_or(left.max(right), |ndv| {
+                                (left as f64 * right as f64 / ndv as f64).ceil() as usize
+                            }),
+                        _ => left.max(right),
+                    }
+                },
+                JoinType::Left => left,
+                JoinType::Right => right,
+                JoinType::Full => left.saturating_add(right),
+                JoinType::Cross => left.saturating_mul(right),
+                #[cfg(feature = "asof_join")]
+                JoinType::AsOf(_) => left,
+                #[cfg(feature = "semi_anti_join")]
+                JoinType::Semi | JoinType::Anti => filtered(left),
+                #[cfg(feature = "iejoin")]
+                JoinType::IEJoin | JoinType::Range => return None,
+            };
+            Some(match options.args.slice {
+                Some((offset, len)) => sliced(n, offset, len),
+                None => n,
+            })
+        },
+        Union { inputs, options } => {
+            let n = inputs
+                .iter()
+                .map(input)
+                .try_fold(0usize, |acc, n| Some(acc.saturating_add(n?)))?;
+            Some(match options.slice {
+                Some((offset, len)) => sliced(n, offset, len),
+                None => n,
+            })
+        },
+        HConcat { inputs, .. } => inputs
+            .iter()
+            .map(input)
+            .try_fold(0usize, |acc, n| Some(acc.max(n?))),
+        #[cfg(feature = "merge_sorted")]
+        MergeSorted {
+            input_left,
+            input_right,
+            ..
+        } => Some(input(input_left)?.saturating_add(input(input_right)?)),
+        MapFunction {
+            input: node,
+            function,
+        } => match function {
+            FunctionIR::FastCount { .. } => Some(1),
+            FunctionIR::RowIndex { .. }
+            | FunctionIR::Unnest { .. }
+            | FunctionIR::Rechunk
+            | FunctionIR::Hint(_) => input(node),
+            _ => None,
+        },
+        Distinct { input: node, .. }
+        | HStack { input: node, .. }
+        | SimpleProjection { input: node, .. }
+        | Cache { input: node, .. }
+        | ExtContext { input: node, .. }
+        | Sink { input: node, .. } => input(node),
+        #[cfg(feature = "python")]
+        PythonScan { .. } => None,
+        SinkMultiple { .. } | Invalid => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use polars_core::df;
+    use polars_core::prelude::*;
+
+    use super::*;
+    use crate::dsl::functions::{col, lit};
+    use crate::plans::{ExprToIRContext, to_expr_ir};
+
+    #[test]
+    fn test_estimate_rows() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1, 2, 3, 4, 5, 6, 7, 8],
+        ]?;
+        let schema = df.schema().clone();
+        let mut lp_arena = Arena::new();
+        let mut expr_arena = Arena::new();
+
+        let scan = lp_arena.add(IR::DataFrameScan {
+            df: Arc::new(df),
+            schema: schema.clone(),
+            output_schema: None,
+        });
+        let mut ctx = ExprToIRContext::new(&mut expr_arena, &schema);
+        let filter = lp_arena.add(IR::Filter {
+            input: scan,
+            predicate: to_expr_ir(col("a").gt(lit(2)), &mut ctx)?,
+        });
+        let slice = lp_arena.add(IR::Slice {
+            input: filter,
+            offset: -3,
+            len: 10,
+        });
+        let mut ctx = ExprToIRContext::new(&mut expr_arena, &schema);
+        let select = lp_arena.add(IR::Select {
+            input: slice,
+            expr: vec![to_expr_ir(col("a").sum(), &mut ctx)?],
+            schema: schema.clone(),
+            options: Default::default(),
+        });
+
+        let estimates = estimate_rows(select, &lp_arena, &expr_arena);
+        assert_eq!(estimates[&scan], 8);
+        assert_eq!(estimates[&filter], 4);
+        assert_eq!(estimates[&slice], 3);
+        assert_eq!(estimates[&select], 1);
+        Ok(())
+    }
+}
@@ -245,6 +245,18 @@ pub(crate) fn det_join_schema(
                 })?;
             }
 
+            #[cfg(feature = "asof_join")]
+            if let JoinType::AsOf(asof_options) = &options.args.how {
+                if let Some(name) = &asof_options.distance_column {
+                    let key_dtype = left_on[0].dtype(schema_left, expr_arena)?;
+                    let dtype = polars_ops::frame::asof_distance_dtype(key_dtype)?;
+                    new_schema.try_insert(name.clone(), dtype)?;
+                }
+                if let Some(name) = &asof_options.indicator_column {
+                    new_schema.try_insert(name.clone(), DataType::Boolean)?;
+                }
+            }
+
             Ok(Arc::new(new_schema))
         },
     }
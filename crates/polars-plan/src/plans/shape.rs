@@ -0,0 +1,150 @@
+//! The shape of a plan: its structure without the values of its literals.
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_utils::arena::{Arena, Node};
+
+use crate::plans::visitor::IRNode;
+use crate::prelude::*;
+
+/// A plan with the values of its literals taken out, see [`plan_shape`].
+pub struct PlanShape {
+    /// The hash of the plan without the values of its literals.
+    pub hash: u64,
+    /// Everything that was hashed into [`PlanShape::hash`]. Plans have the same shape if these
+    /// are equal, their hashes may collide.
+    pub normalized: Vec<u8>,
+    /// The literals of the plan, in the order of [`literal_nodes`].
+    pub literals: Vec<LiteralValue>,
+}
+
+/// Get the shape of the plan under `root`.
+///
+/// Plans with the same shape only differ in the values of their literals. The dtypes of the
+/// literals are part of the shape. In-memory frames are identified by their columns, so clones
+/// of a [`DataFrame`] give the same shape.
+pub fn plan_shape(root: Node, lp_arena: &Arena<IR>, expr_arena: &mut Arena<AExpr>) -> PlanShape {
+    // Hash the plan with placeholders of the same dtype in place of the literals.
+    let nodes = literal_nodes(root, lp_arena, expr_arena);
+    let literals = nodes
+        .iter()
+        .map(|node| {
+            let AExpr::Literal(lv) = expr_arena.get(*node) else {
+                unreachable!()
+            };
+            let placeholder = AExpr::Literal(literal_placeholder(lv));
+            match expr_arena.replace(*node, placeholder) {
+                AExpr::Literal(lv) => lv,
+                _ => unreachable!(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut state = ShapeWriter::default();
+    for (node, ir) in lp_arena.iter(root) {
+        ir.inputs().count().hash(&mut state);
+        match ir {
+            IR::DataFrameScan {
+                df,
+                schema,
+                output_schema,
+            } => {
+                std::mem::discriminant(ir).hash(&mut state);
+                df.height().hash(&mut state);
+                for column in df.columns() {
+                    match column {
+                        Column::Series(s) => {
+                            (Arc::as_ptr(&s.0) as *const () as usize).hash(&mut state)
+                        },
+                        Column::Scalar(s) => {
+                            s.scalar().hash(&mut state);
+                            s.len().hash(&mut state);
+                        },
+                    }
+                }
+                schema.hash(&mut state);
+                output_schema.hash(&mut state);
+            },
+            _ => {
+                // The sources of a scan are hashed by their paths, so also hash the size of the
+                // files in case they were overwritten.
+                if let IR::Scan { file_info, .. } = ir {
+                    file_info.row_estimation.hash(&mut state);
+                }
+                IRNode::new(node)
+                    .hashable_and_cmp(lp_arena, expr_arena)
+                    .hash(&mut state)
+            },
+        }
+        ir.schema(lp_arena).hash(&mut state);
+    }
+
+    for (node, lv) in nodes.iter().zip(&literals) {
+        expr_arena.replace(*node, AExpr::Literal(lv.clone()));
+    }
+
+    PlanShape {
+        hash: state.finish(),
+        normalized: state.bytes,
+        literals,
+    }
+}
+
+/// A [`Hasher`] that keeps the bytes written to it, so that equal hashes can be checked.
+#[derive(Default)]
+struct ShapeWriter {
+    bytes: Vec<u8>,
+}
+
+impl Hasher for ShapeWriter {
+    fn write(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let mut state = DefaultHasher::new();
+        state.write(&self.bytes);
+        state.finish()
+    }
+}
+
+/// Get the literal nodes in the expressions of the plan under `root`, in the order in which
+/// they are visited.
+pub fn literal_nodes(root: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> Vec<Node> {
+    let mut seen = PlHashSet::new();
+    let mut out = vec![];
+    for (_, ir) in lp_arena.iter(root) {
+        for e in ir.exprs() {
+            for (node, ae) in expr_arena.iter(e.node()) {
+                if matches!(ae, AExpr::Literal(_)) && seen.insert(node) {
+                    out.push(node);
+                }
+            }
+        }
+    }
+    out
+}
+
+fn literal_placeholder(lv: &LiteralValue) -> LiteralValue {
+    match lv {
+        LiteralValue::Scalar(sc) => LiteralValue::Scalar(Scalar::null(sc.dtype().clone())),
+        LiteralValue::Dyn(DynLiteralValue::Str(_)) => {
+            LiteralValue::Dyn(DynLiteralValue::Str(PlSmallStr::EMPTY))
+        },
+        LiteralValue::Dyn(DynLiteralValue::Int(_)) => LiteralValue::Dyn(DynLiteralValue::Int(0)),
+        LiteralValue::Dyn(DynLiteralValue::Float(_)) => {
+            LiteralValue::Dyn(DynLiteralValue::Float(0.0))
+        },
+        LiteralValue::Dyn(DynLiteralValue::List(_)) => lv.clone(),
+        LiteralValue::Series(s) => LiteralValue::Series(SpecialEq::new(Series::new_empty(
+            s.name().clone(),
+            s.dtype(),
+        ))),
+        LiteralValue::Range(range) => LiteralValue::Range(RangeLiteralValue {
+            low: 0,
+            high: 0,
+            dtype: range.dtype.clone(),
+        }),
+    }
+}
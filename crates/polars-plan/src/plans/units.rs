@@ -0,0 +1,408 @@
+//! Physical units of columns, stored in the `"polars:unit"` key of the user field metadata.
+//!
+//! A unit is a product of symbols with integer exponents, written like `m/s`, `kg*m/s^2` or
+//! `1/s`. Every `/` only divides by the factor directly after it. Known symbols, optionally with
+//! an SI prefix (`km`, `ms`, `kWh`), resolve to a scale and a dimension in the SI base units,
+//! so that `N*m` and `J` are the same unit and `km/h` converts to `m/s`. Unknown symbols are
+//! their own dimension, a unit that doesn't parse is a single unknown symbol.
+//!
+//! Only multiplicative units are supported; temperatures with an offset such as degrees Celsius
+//! can't be converted.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use polars_core::prelude::*;
+
+/// The field metadata key holding the unit of a column. It is namespaced, so that a plain
+/// `"unit"` entry set by the user or another tool is kept as opaque metadata.
+pub const UNIT_KEY: &str = "polars:unit";
+
+type Exponents = BTreeMap<PlSmallStr, i32>;
+
+/// A unit as a product of symbols with non-zero exponents.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Unit {
+    symbols: Exponents,
+}
+
+/// SI prefixes and their powers of ten.
+const PREFIXES: &[(&str, i32)] = &[
+    ("da", 1),
+    ("Y", 24),
+    ("Z", 21),
+    ("E", 18),
+    ("P", 15),
+    ("T", 12),
+    ("G", 9),
+    ("M", 6),
+    ("k", 3),
+    ("h", 2),
+    ("d", -1),
+    ("c", -2),
+    ("m", -3),
+    ("µ", -6),
+    ("u", -6),
+    ("n", -9),
+    ("p", -12),
+    ("f", -15),
+    ("a", -18),
+];
+
+/// A scale relative to the SI base units, `num / den * 10^pow10`. Keeping the parts apart
+/// makes conversions like `m/s` to `km/h` exact.
+#[derive(Clone, Copy)]
+struct Scale {
+    num: f64,
+    den: f64,
+    pow10: i32,
+}
+
+impl Scale {
+    const ONE: Self = Self {
+        num: 1.0,
+        den: 1.0,
+        pow10: 0,
+    };
+
+    fn mul_powi(mut self, other: Self, exponent: i32) -> Self {
+        if exponent > 0 {
+            self.num *= other.num.powi(exponent);
+            self.den *= other.den.powi(exponent);
+        } else {
+            self.num *= other.den.powi(-exponent);
+            self.den *= other.num.powi(-exponent);
+        }
+        self.pow10 += other.pow10 * exponent;
+        self
+    }
+
+    /// `self / other` as a float.
+    fn ratio(self, other: Self) -> f64 {
+        let out = (self.num * other.den) / (self.den * other.num);
+        let pow10 = self.pow10 - other.pow10;
+        if pow10 >= 0 {
+            out * 10f64.powi(pow10)
+        } else {
+            out / 10f64.powi(-pow10)
+        }
+    }
+}
+
+/// A known symbol: its scale and exponents in SI base units, and whether it takes a prefix.
+struct SymbolDef {
+    scale: Scale,
+    base: &'static [(&'static str, i32)],
+    prefixable: bool,
+}
+
+/// A symbol of `num * 10^pow10` base units.
+const fn def(
+    num: f64,
+    pow10: i32,
+    base: &'static [(&'static str, i32)],
+    prefixable: bool,
+) -> SymbolDef {
+    SymbolDef {
+        scale: Scale {
+            num,
+            den: 1.0,
+            pow10,
+        },
+        base,
+        prefixable,
+    }
+}
+
+const ENERGY: &[(&str, i32)] = &[("kg", 1), ("m", 2), ("s", -2)];
+const PRESSURE: &[(&str, i32)] = &[("kg", 1), ("m", -1), ("s", -2)];
+
+fn symbol_def(symbol: &str) -> Option<SymbolDef> {
+    let def = match symbol {
+        // SI base units, the kilogram is prefixed from the gram.
+        "m" => def(1.0, 0, &[("m", 1)], true),
+        "g" => def(1.0, -3, &[("kg", 1)], true),
+        "s" => def(1.0, 0, &[("s", 1)], true),
+        "A" => def(1.0, 0, &[("A", 1)], true),
+        "K" => def(1.0, 0, &[("K", 1)], true),
+        "mol" => def(1.0, 0, &[("mol", 1)], true),
+        "cd" => def(1.0, 0, &[("cd", 1)], true),
+        // Derived SI units.
+        "Hz" => def(1.0, 0, &[("s", -1)], true),
+        "N" => def(1.0, 0, &[("kg", 1), ("m", 1), ("s", -2)], true),
+        "Pa" => def(1.0, 0, PRESSURE, true),
+        "J" => def(1.0, 0, ENERGY, true),
+        "W" => def(1.0, 0, &[("kg", 1), ("m", 2), ("s", -3)], true),
+        "C" => def(1.0, 0, &[("A", 1), ("s", 1)], true),
+        "V" => def(1.0, 0, &[("kg", 1), ("m", 2), ("s", -3), ("A", -1)], true),
+        "ohm" | "Ω" => def(1.0, 0, &[("kg", 1), ("m", 2), ("s", -3), ("A", -2)], true),
+        "L" | "l" => def(1.0, -3, &[("m", 3)], true),
+        "Wh" => def(3600.0, 0, ENERGY, true),
+        "eV" => def(1.602176634, -19, ENERGY, true),
+        "bar" => def(1.0, 5, PRESSURE, true),
+        // Other units.
+        "min" => def(60.0, 0, &[("s", 1)], false),
+        "h" => def(3600.0, 0, &[("s", 1)], false),
+        "day" => def(86400.0, 0, &[("s", 1)], false),
+        "t" => def(1.0, 3, &[("kg", 1)], false),
+        "in" => def(254.0, -4, &[("m", 1)], false),
+        "ft" => def(3048.0, -4, &[("m", 1)], false),
+        "yd" => def(9144.0, -4, &[("m", 1)], false),
+        "mi" => def(1609344.0, -3, &[("m", 1)], false),
+        "nmi" => def(1852.0, 0, &[("m", 1)], false),
+        "lb" => def(45359237.0, -8, &[("kg", 1)], false),
+        "oz" => def(28349523125.0, -12, &[("kg", 1)], false),
+        "rad" => def(1.0, 0, &[], false),
+        "deg" => def(std::f64::consts::PI / 180.0, 0, &[], false),
+        "%" => def(1.0, -2, &[], false),
+        _ => return None,
+    };
+    Some(def)
+}
+
+/// The scale of `symbol` relative to the SI base units, and its dimension.
+fn resolve_symbol(symbol: &str) -> Option<(Scale, &'static [(&'static str, i32)])> {
+    if let Some(def) = symbol_def(symbol) {
+        return Some((def.scale, def.base));
+    }
+    PREFIXES.iter().find_map(|(prefix, pow10)| {
+        let mut def = symbol_def(symbol.strip_prefix(prefix)?)?;
+        def.scale.pow10 += pow10;
+        def.prefixable.then_some((def.scale, def.base))
+    })
+}
+
+fn parse_factor(factor: &str) -> Option<(&str, i32)> {
+    let (symbol, exponent) = match factor.split_once('^') {
+        Some((symbol, exponent)) => (symbol, exponent.parse().ok()?),
+        None => (factor, 1),
+    };
+    let valid = !symbol.is_empty()
+        && symbol
+            .chars()
+            .all(|c| c.is_alphabetic() || matches!(c, '_' | '%' | '°'));
+    (valid || symbol == "1").then_some((symbol, exponent))
+}
+
+impl Unit {
+    /// Parse a unit. A unit that doesn't parse is a single unknown symbol.
+    pub fn parse(unit: &str) -> Self {
+        Self::try_parse(unit.trim()).unwrap_or_else(|| Self {
+            symbols: [(PlSmallStr::from_str(unit.trim()), 1)]
+                .into_iter()
+                .collect(),
+        })
+    }
+
+    fn try_parse(unit: &str) -> Option<Self> {
+        let mut out = Self::default();
+        if unit.is_empty() {
+            return Some(out);
+        }
+        let unit = unit.replace("**", "^");
+        let mut sign = 1;
+        let mut rest = unit.as_str();
+        loop {
+            let end = rest.find(['*', '/', '·']).unwrap_or(rest.len());
+            let factor = rest[..end].trim();
+            let (symbol, exponent) = parse_factor(factor)?;
+            if symbol != "1" {
+                out.add(symbol.into(), sign * exponent);
+            }
+            let Some(op) = rest[end..].chars().next() else {
+                return Some(out);
+            };
+            sign = if op == '/' { -1 } else { 1 };
+            rest = &rest[end + op.len_utf8()..];
+        }
+    }
+
+    fn add(&mut self, symbol: PlSmallStr, exponent: i32) {
+        let e = self.symbols.entry(symbol).or_default();
+        *e += exponent;
+        if *e == 0 {
+            self.symbols.retain(|_, e| *e != 0);
+        }
+    }
+
+    /// Whether this unit has no symbols, for instance the result of `m/m`.
+    pub fn is_dimensionless(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    pub fn times(&self, other: &Self) -> Self {
+        let mut out = self.clone();
+        for (symbol, exponent) in &other.symbols {
+            out.add(symbol.clone(), *exponent);
+        }
+        out
+    }
+
+    pub fn per(&self, other: &Self) -> Self {
+        self.times(&other.powi(-1))
+    }
+
+    pub fn powi(&self, n: i32) -> Self {
+        Self {
+            symbols: self
+                .symbols
+                .iter()
+                .filter(|_| n != 0)
+                .map(|(symbol, exponent)| (symbol.clone(), exponent * n))
+                .collect(),
+        }
+    }
+
+    /// The `n`-th root of this unit, if all exponents are multiples of `n`.
+    pub fn root(&self, n: i32) -> Option<Self> {
+        self.symbols
+            .values()
+            .all(|exponent| exponent % n == 0)
+            .then(|| Self {
+                symbols: self
+                    .symbols
+                    .iter()
+                    .map(|(symbol, exponent)| (symbol.clone(), exponent / n))
+                    .collect(),
+            })
+    }
+
+    /// The scale of this unit relative to the SI base units, and its dimension.
+    fn resolve(&self) -> (Scale, Exponents) {
+        let mut scale = Scale::ONE;
+        let mut dimension = Exponents::new();
+        for (symbol, exponent) in &self.symbols {
+            match resolve_symbol(symbol) {
+                Some((symbol_scale, base)) => {
+                    scale = scale.mul_powi(symbol_scale, *exponent);
+                    for (base, base_exponent) in base {
+                        *dimension.entry(PlSmallStr::from_static(base)).or_default() +=
+                            base_exponent * exponent;
+                    }
+                },
+                None => *dimension.entry(symbol.clone()).or_default() += exponent,
+            }
+        }
+        dimension.retain(|_, e| *e != 0);
+        (scale, dimension)
+    }
+
+    /// The factor to multiply values in this unit with to express them in `to`.
+    pub fn conversion_factor(&self, to: &Self) -> PolarsResult<f64> {
+        let (from_scale, from_dim) = self.resolve();
+        let (to_scale, to_dim) = to.resolve();
+        polars_ensure!(
+            from_dim == to_dim,
+            InvalidOperation: "cannot convert unit `{}` to `{}`, they have different dimensions",
+            self, to
+        );
+        Ok(from_scale.ratio(to_scale))
+    }
+
+    /// Check that values in this unit and in `other` can be combined with `op` (like `+` or
+    /// `<`), which requires the same unit.
+    pub fn ensure_same(&self, other: &Self, op: impl Display) -> PolarsResult<()> {
+        if self == other {
+            return Ok(());
+        }
+        let (scale, dimension) = self.resolve();
+        let (other_scale, other_dimension) = other.resolve();
+        polars_ensure!(
+            dimension == other_dimension,
+            InvalidOperation: "incompatible units in `{}`: `{}` and `{}` have different dimensions",
+            op, self, other
+        );
+        // Allow for rounding in the scales of equivalent units like `N*m` and `J`.
+        polars_ensure!(
+            (scale.ratio(other_scale) - 1.0).abs() < 1e-12,
+            InvalidOperation: "different units in `{}`: `{}` and `{}`; use `convert_unit` to convert one of them first",
+            op, self, other
+        );
+        Ok(())
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let write_factor = |f: &mut Formatter<'_>, symbol: &str, exponent: i32| {
+            if exponent == 1 {
+                write!(f, "{symbol}")
+            } else {
+                write!(f, "{symbol}^{exponent}")
+            }
+        };
+        let mut numerator = self.symbols.iter().filter(|(_, e)| **e > 0).peekable();
+        if numerator.peek().is_none() {
+            write!(f, "1")?;
+        }
+        for (i, (symbol, exponent)) in numerator.enumerate() {
+            if i > 0 {
+                write!(f, "*")?;
+            }
+            write_factor(f, symbol, *exponent)?;
+        }
+        for (symbol, exponent) in self.symbols.iter().filter(|(_, e)| **e < 0) {
+            write!(f, "/")?;
+            write_factor(f, symbol, -exponent)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unit_parse_display() {
+        for (unit, expected) in [
+            ("m/s", "m/s"),
+            ("kg*m/s^2", "kg*m/s^2"),
+            ("m/s/s", "m/s^2"),
+            ("1/s", "1/s"),
+            ("m**2", "m^2"),
+            ("m/m", "1"),
+            ("degrees Celsius", "degrees Celsius"),
+        ] {
+            assert_eq!(Unit::parse(unit).to_string(), expected);
+        }
+        let speed = Unit::parse("m/s");
+        assert_eq!(speed.times(&Unit::parse("s")).to_string(), "m");
+        assert_eq!(speed.per(&Unit::parse("s")).to_string(), "m/s^2");
+        assert_eq!(speed.powi(2).to_string(), "m^2/s^2");
+        assert!(speed.per(&speed).is_dimensionless());
+        assert_eq!(Unit::parse("m^2/s^4").root(2), Some(Unit::parse("m/s^2")));
+        assert_eq!(Unit::parse("m/s").root(2), None);
+    }
+
+    #[test]
+    fn test_unit_conversion() -> PolarsResult<()> {
+        let factor = Unit::parse("m/s").conversion_factor(&Unit::parse("km/h"))?;
+        assert_eq!(factor, 3.6);
+        let factor = Unit::parse("kWh").conversion_factor(&Unit::parse("MJ"))?;
+        assert!((factor - 3.6).abs() < 1e-12);
+        assert!(
+            Unit::parse("m")
+                .conversion_factor(&Unit::parse("s"))
+                .is_err()
+        );
+
+        Unit::parse("N*m").ensure_same(&Unit::parse("J"), "+")?;
+        assert!(
+            Unit::parse("m")
+                .ensure_same(&Unit::parse("km"), "+")
+                .is_err()
+        );
+        assert!(
+            Unit::parse("m")
+                .ensure_same(&Unit::parse("s"), "+")
+                .is_err()
+        );
+        assert!(
+            Unit::parse("apple")
+                .ensure_same(&Unit::parse("pear"), "+")
+                .is_err()
+        );
+        Ok(())
+    }
+}
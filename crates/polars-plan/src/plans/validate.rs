@@ -0,0 +1,475 @@
+//! Validation of the invariants of an [`IRPlan`].
+//!
+//! Optimizer passes and plugins rewrite the plan in place. A broken rewrite typically only
+//! surfaces much later, during execution, with an error that is hard to relate to the rewrite.
+//! The validator checks the plan right away and reports every violated invariant with the node
+//! it was found at.
+use polars_core::prelude::*;
+use polars_utils::arena::{Arena, Node};
+use recursive::recursive;
+
+use crate::constants::CSE_REPLACED;
+use crate::prelude::*;
+
+impl IRPlan {
+    /// Check the schemas, expressions and node invariants of the plan, see [`validate_plan`].
+    pub fn validate(&self) -> PolarsResult<()> {
+        self.as_ref().validate()
+    }
+}
+
+impl IRPlanRef<'_> {
+    /// Check the schemas, expressions and node invariants of the plan, see [`validate_plan`].
+    pub fn validate(&self) -> PolarsResult<()> {
+        validate_plan(self.lp_top, self.lp_arena, self.expr_arena)
+    }
+}
+
+/// Check the plan rooted at `root`.
+///
+/// This checks that:
+/// - the plan only refers to nodes that exist, and has no cycles or taken-out nodes;
+/// - every expression resolves against the schema of the input it is evaluated on;
+/// - the stored schemas of nodes agree with their expressions and inputs;
+/// - node specific invariants hold, e.g. join keys come in pairs and filters are boolean.
+///
+/// Returns an error listing every violation, each with the node it was found at.
+pub fn validate_plan(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<()> {
+    let mut validator = Validator {
+        lp_arena,
+        expr_arena,
+        visited: PlHashMap::new(),
+        has_cycle: false,
+        diagnostics: vec![],
+    };
+    validator.visit(root);
+
+    if validator.diagnostics.is_empty() {
+        Ok(())
+    } else {
+        polars_bail!(ComputeError: "invalid plan:\n{}", validator.diagnostics.join("\n"))
+    }
+}
+
+struct Validator<'a> {
+    lp_arena: &'a Arena<IR>,
+    expr_arena: &'a Arena<AExpr>,
+    /// Whether the validation of a node is finished. Nodes that are being validated are on the
+    /// current path, so reaching them again means the plan has a cycle.
+    visited: PlHashMap<Node, bool>,
+    /// Schemas cannot be resolved once a cycle is found.
+    has_cycle: bool,
+    diagnostics: Vec<String>,
+}
+
+impl Validator<'_> {
+    fn report(&mut self, node: Node, msg: impl std::fmt::Display) {
+        let name = self.lp_arena.get(node).name();
+        self.diagnostics
+            .push(format!("- node {} ({name}): {msg}", node.0));
+    }
+
+    #[recursive]
+    fn visit(&mut self, node: Node) {
+        match self.visited.get(&node) {
+            Some(true) => return,
+            Some(false) => {
+                self.report(node, "the plan has a cycle through this node");
+                self.has_cycle = true;
+                return;
+            },
+            None => {},
+        }
+        self.visited.insert(node, false);
+
+        let ir = self.lp_arena.get(node);
+        if matches!(ir, IR::Invalid) {
+            self.diagnostics.push(format!(
+                "- node {}: the node was taken out of the arena and not replaced",
+                node.0
+            ));
+            self.visited.insert(node, true);
+            return;
+        }
+
+        let mut inputs_valid = true;
+        for input in ir.inputs() {
+            if input.0 >= self.lp_arena.len() {
+                self.report(node, format!("input node {} does not exist", input.0));
+                inputs_valid = false;
+                continue;
+            }
+            self.visit(input);
+            inputs_valid &= !matches!(self.lp_arena.get(input), IR::Invalid);
+        }
+
+        let mut exprs_valid = true;
+        for e in ir.exprs() {
+            exprs_valid &= self.check_expr_nodes(node, e.node());
+        }
+
+        // Schemas and dtypes can only be resolved on a structurally sound plan.
+        if inputs_valid && exprs_valid && !self.has_cycle {
+            self.check_node(node);
+        }
+        self.visited.insert(node, true);
+    }
+
+    /// Check that all nodes of the expression exist and that their inputs have a valid arity.
+    fn check_expr_nodes(&mut self, node: Node, root: Node) -> bool {
+        let mut stack = vec![root];
+        while let Some(e) = stack.pop() {
+            if e.0 >= self.expr_arena.len() {
+                self.report(node, format!("expression node {} does not exist", e.0));
+                return false;
+            }
+            let ae = self.expr_arena.get(e);
+            if let AExpr::SortBy {
+                by, sort_options, ..
+            } = ae
+            {
+                if let Some(msg) = sort_arity(by.len(), sort_options) {
+                    self.report(node, format!("`sort_by` expression {}: {msg}", e.0));
+                }
+            }
+            ae.children_rev(&mut stack);
+        }
+        true
+    }
+
+    fn schema(&self, node: Node) -> SchemaRef {
+        self.lp_arena.get(node).schema(self.lp_arena).into_owned()
+    }
+
+    /// Resolve the dtype of `e` against `schema`, reporting the expression if it fails.
+    fn expr_dtype(&mut self, node: Node, e: &ExprIR, schema: &Schema) -> Option<DataType> {
+        let ctx = ToFieldContext::new(self.expr_arena, schema);
+        match self.expr_arena.get(e.node()).to_dtype(&ctx) {
+            Ok(dtype) => Some(dtype),
+            Err(err) => {
+                let display = e.display(self.expr_arena).to_string();
+                self.report(node, format!("expression `{display}`: {err}"));
+                None
+            },
+        }
+    }
+
+    /// Check projection expressions, which can refer to the temporary columns of common
+    /// subexpressions that are evaluated first.
+    fn check_projection(&mut self, node: Node, exprs: &[ExprIR], input_schema: &Schema) {
+        let mut schema = input_schema.clone();
+        let (cse, exprs): (Vec<_>, Vec<_>) = exprs
+            .iter()
+            .partition(|e| e.output_name().starts_with(CSE_REPLACED));
+        for e in cse {
+            if let Some(dtype) = self.expr_dtype(node, e, input_schema) {
+                schema.with_column(e.output_name().clone(), dtype);
+            }
+        }
+        for e in exprs {
+            self.expr_dtype(node, e, &schema);
+        }
+    }
+
+    fn check_node(&mut self, node: Node) {
+        use IR::*;
+        match self.lp_arena.get(node) {
+            Filter { input, predicate } => {
+                let schema = self.schema(*input);
+                if let Some(dtype) = self.expr_dtype(node, predicate, &schema) {
+                    if !matches!(dtype, DataType::Boolean | DataType::Null) {
+                        self.report(
+                            node,
+                            format!("the predicate has dtype {dtype}, not Boolean"),
+                        );
+                    }
+                }
+            },
+            Select {
+                input,
+                expr,
+                schema,
+                ..
+            } => {
+                let input_schema = self.schema(*input);
+                self.check_projection(node, expr, &input_schema);
+                let names = expr
+                    .iter()
+                    .map(|e| e.output_name())
+                    .filter(|name| !name.starts_with(CSE_REPLACED))
+                    .collect::<Vec<_>>();
+                if !names.iter().copied().eq(schema.iter_names()) {
+                    self.report(
+                        node,
+                        format!(
+                            "the schema {:?} does not match the expression names {names:?}",
+                            schema.iter_names().collect::<Vec<_>>()
+                        ),
+                    );
+                }
+                self.check_unique_names(node, names.iter().copied());
+            },
+            HStack {
+                input,
+                exprs,
+                schema,
+                ..
+            } => {
+                let input_schema = self.schema(*input);
+                self.check_projection(node, exprs, &input_schema);
+                let names = exprs
+                    .iter()
+                    .map(|e| e.output_name())
+                    .filter(|name| !name.starts_with(CSE_REPLACED));
+                self.check_unique_names(node, names.clone());
+                for name in input_schema.iter_names().chain(names) {
+                    if !schema.contains(name) {
+                        self.report(node, format!("column '{name}' is missing from the schema"));
+                    }
+                }
+            },
+            Sort {
+                input,
+                by_column,
+                sort_options,
+                ..
+            } => {
+                let schema = self.schema(*input);
+                for e in by_column {
+                    self.expr_dtype(node, e, &schema);
+                }
+                if let Some(msg) = sort_arity(by_column.len(), sort_options) {
+                    self.report(node, msg);
+                }
+            },
+            GroupBy {
+                input,
+                keys,
+                aggs,
+                schema,
+                apply,
+                ..
+            } => {
+                let input_schema = self.schema(*input);
+                for e in keys.iter().chain(aggs) {
+                    self.expr_dtype(node, e, &input_schema);
+                }
+                if apply.is_none() {
+                    for e in keys.iter().chain(aggs) {
+                        if !schema.contains(e.output_name()) {
+                            self.report(
+                                node,
+                                format!("column '{}' is missing from the schema", e.output_name()),
+                            );
+                        }
+                    }
+                }
+            },
+            Join {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                ..
+            } => {
+                if left_on.len() != right_on.len() {
+                    self.report(
+                        node,
+                        format!(
+                            "{} left join keys do not pair up with {} right join keys",
+                            left_on.len(),
+                            right_on.len()
+                        ),
+                    );
+                }
+                let left_schema = self.schema(*input_left);
+                let right_schema = self.schema(*input_right);
+                for e in left_on {
+                    self.expr_dtype(node, e, &left_schema);
+                }
+                for e in right_on {
+                    self.expr_dtype(node, e, &right_schema);
+                }
+            },
+            SimpleProjection { input, columns } => {
+                let input_schema = self.schema(*input);
+                self.check_subset(node, columns.iter_names(), &input_schema, "input schema");
+            },
+            DataFrameScan {
+                schema,
+                output_schema: Some(output_schema),
+                ..
+            } => {
+                self.check_subset(node, output_schema.iter_names(), schema, "scanned schema");
+            },
+            Union { inputs, .. } => {
+                let Some((first, rest)) = inputs.split_first() else {
+                    self.report(node, "the union has no inputs");
+                    return;
+                };
+                let schema = self.schema(*first);
+                for input in rest {
+                    let other = self.schema(*input);
+                    if !schema.iter_names().eq(other.iter_names()) {
+                        self.report(
+                            node,
+                            format!(
+                                "the columns of input {} differ from those of input {}",
+                                input.0, first.0
+                            ),
+                        );
+                    }
+                }
+            },
+            HConcat { inputs, schema, .. } => {
+                if inputs.is_empty() {
+                    self.report(node, "the horizontal concatenation has no inputs");
+                }
+                self.check_unique_names(node, schema.iter_names());
+            },
+            #[cfg(feature = "merge_sorted")]
+            MergeSorted {
+                input_left,
+                input_right,
+                key,
+            } => {
+                for input in [input_left, input_right] {
+                    if !self.schema(*input).contains(key) {
+                        self.report(node, format!("the key '{key}' is not in input {}", input.0));
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn check_subset<'b>(
+        &mut self,
+        node: Node,
+        names: impl Iterator<Item = &'b PlSmallStr>,
+        schema: &Schema,
+        what: &str,
+    ) {
+        for name in names {
+            if !schema.contains(name) {
+                self.report(node, format!("column '{name}' is not in the {what}"));
+            }
+        }
+    }
+
+    fn check_unique_names<'b>(&mut self, node: Node, names: impl Iterator<Item = &'b PlSmallStr>) {
+        let mut seen = PlHashSet::new();
+        for name in names {
+            if !seen.insert(name) {
+                self.report(node, format!("column '{name}' is output more than once"));
+            }
+        }
+    }
+}
+
+/// Check that the sort options have an entry per sort key, or a single one for all of them.
+fn sort_arity(n_by: usize, sort_options: &SortMultipleOptions) -> Option<String> {
+    let n_desc = sort_options.descending.len();
+    let n_nulls_last = sort_options.nulls_last.len();
+    if n_desc != n_by && n_desc != 1 {
+        Some(format!(
+            "the length of `descending` ({n_desc}) does not match the number of sort keys ({n_by})"
+        ))
+    } else if n_nulls_last != n_by && n_nulls_last != 1 {
+        Some(format!(
+            "the length of `nulls_last` ({n_nulls_last}) does not match the number of sort keys ({n_by})"
+        ))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_core::df;
+
+    use super::*;
+
+    fn df_scan(lp_arena: &mut Arena<IR>) -> Node {
+        let df = df![
+            "a" => [1i32, 2, 3],
+            "b" => [true, false, true],
+        ]
+        .unwrap();
+        let schema = df.schema().clone();
+        lp_arena.add(IR::DataFrameScan {
+            df: Arc::new(df),
+            schema,
+            output_schema: None,
+        })
+    }
+
+    #[test]
+    fn test_validate_filter() {
+        let mut lp_arena = Arena::new();
+        let mut expr_arena = Arena::new();
+        let input = df_scan(&mut lp_arena);
+
+        let b = expr_arena.add(AExpr::Column("b".into()));
+        let filter = lp_arena.add(IR::Filter {
+            input,
+            predicate: ExprIR::from_node(b, &expr_arena),
+        });
+        assert!(validate_plan(filter, &lp_arena, &expr_arena).is_ok());
+
+        let a = expr_arena.add(AExpr::Column("a".into()));
+        let c = expr_arena.add(AExpr::Column("c".into()));
+        lp_arena.replace(
+            filter,
+            IR::Filter {
+                input,
+                predicate: ExprIR::from_node(a, &expr_arena),
+            },
+        );
+        let filter_c = lp_arena.add(IR::Filter {
+            input: filter,
+            predicate: ExprIR::from_node(c, &expr_arena),
+        });
+        let err = validate_plan(filter_c, &lp_arena, &expr_arena)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(&format!("node {} (filter): the predicate", filter.0)));
+        assert!(err.contains(&format!("node {} (filter): expression", filter_c.0)));
+    }
+
+    #[test]
+    fn test_validate_structure() {
+        use polars_utils::unique_id::UniqueId;
+
+        let mut lp_arena = Arena::new();
+        let expr_arena = Arena::new();
+        let input = df_scan(&mut lp_arena);
+        let slice = lp_arena.add(IR::Slice {
+            input,
+            offset: 0,
+            len: 1,
+        });
+        // Point the scan at the slice to create a cycle.
+        lp_arena.replace(
+            input,
+            IR::Cache {
+                input: slice,
+                id: UniqueId::new(),
+            },
+        );
+        let err = validate_plan(slice, &lp_arena, &expr_arena)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("cycle"));
+
+        lp_arena.take(input);
+        let err = validate_plan(slice, &lp_arena, &expr_arena)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("taken out of the arena"));
+    }
+}
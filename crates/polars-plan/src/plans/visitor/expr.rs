@@ -43,6 +43,7 @@ impl TreeWalker for Expr {
             Alias(l, r) => Alias(am(l, f)?, r),
             Column(_) => self,
             Literal(_) => self,
+            Parameter(_) => self,
             DataTypeFunction(_) => self,
             #[cfg(feature = "dtype-struct")]
             Field(_) => self,
@@ -78,7 +79,7 @@ impl TreeWalker for Expr {
                 Var(x, ddf) => Var(am(x, f)?, ddf),
 
             }),
-            Ternary { predicate, truthy, falsy } => Ternary { predicate: am(predicate, &mut f)?, truthy: am(truthy, &mut f)?, falsy: am(falsy, f)? },
+            Ternary { predicate, truthy, falsy, lazy } => Ternary { predicate: am(predicate, &mut f)?, truthy: am(truthy, &mut f)?, falsy: am(falsy, f)?, lazy },
             Function { input, function } => Function { input: input.into_iter().map(f).collect::<Result<_, _>>()?, function },
             Explode { input, options } => Explode { input: am(input, f)?, options },
             Filter { input, by } => Filter { input: am(input, &mut f)?, by: am(by, f)? },
@@ -191,6 +192,7 @@ impl AExpr {
                 },
             ) => l_period == r_period && l_offset == r_offset && l_closed_window == r_closed_window,
             (Over { mapping: l, .. }, Over { mapping: r, .. }) => l == r,
+            (Ternary { lazy: l, .. }, Ternary { lazy: r, .. }) => l == r,
             (
                 Cast {
                     options: strict_l,
@@ -206,7 +208,6 @@ impl AExpr {
             (Sort { options: l, .. }, Sort { options: r, .. }) => l == r,
             (Gather { .. }, Gather { .. })
             | (Filter { .. }, Filter { .. })
-            | (Ternary { .. }, Ternary { .. })
             | (Len, Len)
             | (Slice { .. }, Slice { .. }) => true,
             (
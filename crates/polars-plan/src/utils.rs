@@ -5,7 +5,7 @@ use polars_core::prelude::*;
 use polars_utils::format_pl_smallstr;
 
 use self::visitor::{AexprNode, RewritingVisitor, TreeWalker};
-use crate::constants::get_len_name;
+use crate::constants::{get_len_name, get_literal_name};
 use crate::prelude::*;
 
 /// Utility to write comma delimited strings
@@ -119,6 +119,7 @@ pub fn expr_output_name(expr: &Expr) -> PolarsResult<PlSmallStr> {
             Expr::RenameAlias { expr, function } => return function.call(&expr_output_name(expr)?),
             Expr::Len => return Ok(get_len_name()),
             Expr::Literal(val) => return Ok(val.output_column_name()),
+            Expr::Parameter(_) => return Ok(get_literal_name()),
 
             #[cfg(feature = "dtype-struct")]
             Expr::Function {
@@ -31,7 +31,7 @@ use crate::cloud_client;
 #[cfg(feature = "polars_cloud_server")]
 use crate::cloud_server;
 use crate::conversion::PyCategories;
-use crate::dataframe::PyDataFrame;
+use crate::dataframe::{PyDataFrame, PyPartitionedFrame};
 use crate::expr::PyExpr;
 use crate::expr::datatype::PyDataTypeExpr;
 use crate::expr::selector::PySelector;
@@ -115,6 +115,7 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     #[cfg(not(target_arch = "wasm32"))]
     m.add_class::<PyInProcessQuery>().unwrap();
     m.add_class::<PyLazyGroupBy>().unwrap();
+    m.add_class::<PyPartitionedFrame>().unwrap();
     m.add_class::<PyExpr>().unwrap();
     m.add_class::<PyDataTypeExpr>().unwrap();
     m.add_class::<PySelector>().unwrap();
@@ -167,6 +168,10 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(functions::business_day_count))
         .unwrap();
 
+    // Functions - interval
+    m.add_wrapped(wrap_pyfunction!(functions::interval))
+        .unwrap();
+
     // Functions - aggregation
     m.add_wrapped(wrap_pyfunction!(functions::all_horizontal))
         .unwrap();
@@ -180,10 +185,15 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::mean_horizontal))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::first_non_null_horizontal))
+        .unwrap();
 
     // Functions - lazy
     m.add_wrapped(wrap_pyfunction!(functions::arg_sort_by))
         .unwrap();
+    #[cfg(feature = "search_sorted")]
+    m.add_wrapped(wrap_pyfunction!(functions::search_sorted_by))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::arg_where))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::as_struct))
@@ -191,6 +201,7 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(functions::coalesce))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::field)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::param)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::col)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::collect_all))
         .unwrap();
@@ -242,6 +253,8 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(functions::spearman_rank_corr))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::when)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::when_lazy))
+        .unwrap();
 
     // Functions: schema
     m.add_wrapped(wrap_pyfunction!(
@@ -278,6 +291,9 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     #[cfg(feature = "parquet")]
     m.add_wrapped(wrap_pyfunction!(functions::read_parquet_metadata))
         .unwrap();
+    #[cfg(feature = "parquet")]
+    m.add_wrapped(wrap_pyfunction!(functions::read_parquet_statistics))
+        .unwrap();
     #[cfg(feature = "clipboard")]
     m.add_wrapped(wrap_pyfunction!(functions::read_clipboard_string))
         .unwrap();
@@ -298,6 +314,15 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::using_string_cache))
         .unwrap();
+    #[cfg(feature = "plan_cache")]
+    m.add_wrapped(wrap_pyfunction!(functions::set_plan_cache_capacity))
+        .unwrap();
+    #[cfg(feature = "plan_cache")]
+    m.add_wrapped(wrap_pyfunction!(functions::plan_cache_stats))
+        .unwrap();
+    #[cfg(feature = "plan_cache")]
+    m.add_wrapped(wrap_pyfunction!(functions::clear_plan_cache))
+        .unwrap();
 
     // Numeric formatting
     m.add_wrapped(wrap_pyfunction!(functions::get_thousands_separator))
@@ -332,6 +357,12 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(functions::set_random_seed))
         .unwrap();
 
+    // Functions - privacy
+    m.add_wrapped(wrap_pyfunction!(functions::set_privacy_budget))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::privacy_budget_remaining))
+        .unwrap();
+
     // Functions - escape_regex
     m.add_wrapped(wrap_pyfunction!(functions::escape_regex))
         .unwrap();
@@ -343,6 +374,8 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(datatypes::_known_timezones))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(datatypes::_tzdb_version))
+        .unwrap();
 
     // Extension type registry.
     m.add_wrapped(wrap_pyfunction!(extension::_register_extension_type))
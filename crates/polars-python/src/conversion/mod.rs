@@ -3,11 +3,13 @@ mod categorical;
 pub(crate) mod chunked_array;
 mod datetime;
 
+use std::collections::{BTreeMap, HashMap};
 use std::convert::Infallible;
 use std::fmt::{Display, Formatter};
 use std::fs::File;
 use std::hash::{Hash, Hasher};
 
+use arrow::datatypes::Metadata;
 pub use categorical::PyCategories;
 #[cfg(feature = "object")]
 use polars::chunked_array::object::PolarsObjectSafe;
@@ -21,6 +23,8 @@ use polars::prelude::default_values::{
     DefaultFieldValues, IcebergIdentityTransformedPartitionFields,
 };
 use polars::prelude::deletion::DeletionFilesList;
+use polars::prelude::listing::FileListingOptions;
+use polars::prelude::schema_evolution::{SchemaEvolutionPolicy, TypeWidening};
 use polars::series::ops::NullBehavior;
 use polars_buffer::Buffer;
 use polars_compute::decimal::dec128_verify_prec_scale;
@@ -34,6 +38,7 @@ use polars_parquet::write::StatisticsOptions;
 use polars_plan::dsl::ScanSources;
 use polars_utils::compression::{BrotliLevel, GzipLevel, ZstdLevel};
 use polars_utils::pl_str::PlSmallStr;
+use polars_utils::python_function::PythonObject;
 use polars_utils::total_ord::{TotalEq, TotalHash};
 use pyo3::basic::CompareOp;
 use pyo3::exceptions::{PyTypeError, PyValueError};
@@ -152,6 +157,12 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<NullValues> {
                     .map(|(a, b)| ((&*a).into(), (&*b).into()))
                     .collect(),
             )))
+        } else if let Ok(s) = ob.extract::<Vec<(PyBackedStr, Vec<PyBackedStr>)>>() {
+            Ok(Wrap(NullValues::NamedMultiple(
+                s.into_iter()
+                    .map(|(a, b)| ((&*a).into(), b.iter().map(|x| (&**x).into()).collect()))
+                    .collect(),
+            )))
         } else {
             Err(
                 PyPolarsErr::Other("could not extract value from null_values argument".into())
@@ -851,6 +862,59 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<AsofStrategy> {
     }
 }
 
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<NearestMetric> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*(ob.extract::<PyBackedStr>()?) {
+            "euclidean" => NearestMetric::Euclidean,
+            "cosine" => NearestMetric::Cosine,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`metric` must be one of {{'euclidean', 'cosine'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<ConvolveMode> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*(ob.extract::<PyBackedStr>()?) {
+            "full" => ConvolveMode::Full,
+            "same" => ConvolveMode::Same,
+            "valid" => ConvolveMode::Valid,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`mode` must be one of {{'full', 'same', 'valid'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<ConvolveNulls> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*(ob.extract::<PyBackedStr>()?) {
+            "propagate" => ConvolveNulls::Propagate,
+            "zero" => ConvolveNulls::Zero,
+            "renormalize" => ConvolveNulls::Renormalize,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`null_behavior` must be one of {{'propagate', 'zero', 'renormalize'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<InterpolationMethod> {
     type Error = PyErr;
 
@@ -1381,6 +1445,42 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<QuoteStyle> {
     }
 }
 
+#[cfg(feature = "json")]
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<JsonTemporalFormat> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "iso" => JsonTemporalFormat::Iso,
+            "epoch" => JsonTemporalFormat::Epoch,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`temporal_format` must be one of {{'iso', 'epoch'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<NonFiniteFloats> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "null" => NonFiniteFloats::Null,
+            "string" => NonFiniteFloats::String,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`non_finite_floats` must be one of {{'null', 'string'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "list_sets")]
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<SetOperation> {
     type Error = PyErr;
@@ -1566,6 +1666,109 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<CastColumnsPolicy> {
     }
 }
 
+// Conversion from SchemaEvolutionPolicy class from the Python side.
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<SchemaEvolutionPolicy> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let py = ob.py();
+
+        let renames = ob
+            .getattr(intern!(py, "renames"))?
+            .cast::<PyDict>()?
+            .iter()
+            .map(|(former, current)| {
+                Ok((
+                    former.extract::<Wrap<PlSmallStr>>()?.0,
+                    current.extract::<Wrap<PlSmallStr>>()?.0,
+                ))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let type_widening_object = ob.getattr(intern!(py, "type_widening"))?;
+        let type_widening_options: Vec<PyBackedStr> =
+            if let Ok(v) = type_widening_object.extract::<PyBackedStr>() {
+                vec![v]
+            } else {
+                type_widening_object.extract()?
+            };
+
+        let mut type_widening = TypeWidening::default();
+
+        for v in type_widening_options.iter() {
+            match &**v {
+                "integer" => type_widening.integer = true,
+                "integer-to-float" => type_widening.integer_to_float = true,
+                "float" => type_widening.float = true,
+                "categorical-to-string" => type_widening.categorical_to_string = true,
+                "all" => type_widening = TypeWidening::ALL,
+                "forbid" => {},
+                v => {
+                    return Err(PyValueError::new_err(format!(
+                        "unknown option for type_widening: {v}"
+                    )));
+                },
+            }
+        }
+
+        let dtypes = ob
+            .getattr(intern!(py, "dtypes"))?
+            .extract::<Wrap<Schema>>()?
+            .0
+            .into_iter()
+            .collect();
+
+        let defaults = ob
+            .call_method0(intern!(py, "_defaults_frame"))?
+            .extract::<PyDataFrame>()?
+            .df
+            .into_inner();
+
+        let missing_defaults = defaults
+            .columns()
+            .iter()
+            .map(|c| {
+                let value = c.get(0).map_err(PyPolarsErr::from)?.into_static();
+                Ok((c.name().clone(), Scalar::new(c.dtype().clone(), value)))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        Ok(Wrap(SchemaEvolutionPolicy {
+            renames,
+            type_widening,
+            dtypes,
+            missing_defaults,
+        }))
+    }
+}
+
+// Conversion from FileListingOptions class from the Python side.
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<FileListingOptions> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let py = ob.py();
+
+        let max_files = ob.getattr(intern!(py, "max_files"))?.extract()?;
+        let listing_concurrency = ob.getattr(intern!(py, "listing_concurrency"))?.extract()?;
+        let file_name_filter = ob
+            .getattr(intern!(py, "file_name_filter"))?
+            .extract::<Option<Py<PyAny>>>()?
+            .map(|f| PlanCallback::new_python(PythonObject(f)));
+        let lister = ob
+            .getattr(intern!(py, "lister"))?
+            .extract::<Option<Py<PyAny>>>()?
+            .map(|f| PlanCallback::new_python(PythonObject(f)));
+
+        Ok(Wrap(FileListingOptions {
+            max_files,
+            listing_concurrency,
+            file_name_filter,
+            lister,
+        }))
+    }
+}
+
 pub(crate) fn parse_fill_null_strategy(
     strategy: &str,
     limit: FillNullLimit,
@@ -1587,6 +1790,24 @@ pub(crate) fn parse_fill_null_strategy(
     Ok(parsed)
 }
 
+#[cfg(feature = "parquet")]
+pub(crate) fn parse_timestamp_overflow(
+    overflow: &str,
+    parameter: &str,
+) -> PyResult<TimestampOverflow> {
+    let parsed = match overflow {
+        "raise" => TimestampOverflow::Raise,
+        "saturate" => TimestampOverflow::Saturate,
+        "null" => TimestampOverflow::Null,
+        e => {
+            return Err(PyValueError::new_err(format!(
+                "`{parameter}` must be one of {{'raise', 'saturate', 'null'}}, got {e}",
+            )));
+        },
+    };
+    Ok(parsed)
+}
+
 #[cfg(feature = "parquet")]
 pub(crate) fn parse_parquet_compression(
     compression: &str,
@@ -1639,6 +1860,29 @@ where
         .collect()
 }
 
+pub(crate) fn metadata_to_strings(metadata: &Metadata) -> BTreeMap<String, String> {
+    metadata
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+pub(crate) fn metadata_from_strings(metadata: BTreeMap<String, String>) -> Metadata {
+    metadata
+        .into_iter()
+        .map(|(k, v)| (k.into(), v.into()))
+        .collect()
+}
+
+pub(crate) fn field_metadata_to_strings(
+    field_metadata: &FieldMetadataMap,
+) -> HashMap<String, BTreeMap<String, String>> {
+    field_metadata
+        .iter()
+        .map(|(name, metadata)| (name.to_string(), metadata_to_strings(metadata)))
+        .collect()
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct PyCompatLevel(pub CompatLevel);
 
@@ -1686,6 +1930,26 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<UnicodeForm> {
     }
 }
 
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<CollationStrength> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "primary" => CollationStrength::Primary,
+            "secondary" => CollationStrength::Secondary,
+            "tertiary" => CollationStrength::Tertiary,
+            "quaternary" => CollationStrength::Quaternary,
+            "identical" => CollationStrength::Identical,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`strength` must be one of {{'primary', 'secondary', 'tertiary', 'quaternary', 'identical'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "parquet")]
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<Option<KeyValueMetadata>> {
     type Error = PyErr;
@@ -1850,6 +2114,31 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<DeletionFilesList> {
                 DeletionFilesList::IcebergPositionDelete(Arc::new(out))
             },
 
+            "position-delete" => {
+                let dict: Bound<'_, PyDict> = ob.extract()?;
+
+                let mut out = PlIndexMap::new();
+
+                for (k, v) in dict
+                    .try_iter()?
+                    .zip(dict.call_method0("values")?.try_iter()?)
+                {
+                    let k: usize = k?.extract()?;
+                    let v: Bound<'_, PyAny> = v?.extract()?;
+
+                    let positions = v
+                        .try_iter()?
+                        .map(|x| x.and_then(|x| x.extract::<u64>()))
+                        .collect::<PyResult<Arc<[u64]>>>()?;
+
+                    if !positions.is_empty() {
+                        out.insert(k, positions);
+                    }
+                }
+
+                DeletionFilesList::PositionDelete(Arc::new(out))
+            },
+
             v => {
                 return Err(PyValueError::new_err(format!(
                     "unknown deletion file type: {v}"
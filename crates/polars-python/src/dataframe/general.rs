@@ -1,3 +1,4 @@
+use std::collections::{BTreeMap, HashMap};
 use std::hash::BuildHasher;
 
 use arrow::bitmap::MutableBitmap;
@@ -5,7 +6,7 @@ use either::Either;
 use parking_lot::RwLock;
 use polars::prelude::*;
 use polars_ffi::version_0::SeriesExport;
-use pyo3::exceptions::PyIndexError;
+use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
 use pyo3::types::{PyList, PyType};
@@ -13,7 +14,7 @@ use pyo3::types::{PyList, PyType};
 use self::row_encode::{_get_rows_encoded_ca, _get_rows_encoded_ca_unordered};
 use super::PyDataFrame;
 use crate::PyLazyFrame;
-use crate::conversion::Wrap;
+use crate::conversion::{Wrap, field_metadata_to_strings};
 use crate::error::PyPolarsErr;
 use crate::prelude::strings_to_pl_smallstr;
 use crate::py_modules::polars;
@@ -125,6 +126,24 @@ impl PyDataFrame {
         })
     }
 
+    #[pyo3(signature = (fractions, shuffle, seed, stratify_by))]
+    pub fn split(
+        &self,
+        py: Python<'_>,
+        fractions: Vec<f64>,
+        shuffle: bool,
+        seed: Option<u64>,
+        stratify_by: Option<Vec<PyBackedStr>>,
+    ) -> PyResult<Vec<Self>> {
+        let stratify_by = stratify_by.map(strings_to_pl_smallstr);
+        let out = py.enter_polars(|| {
+            self.df
+                .read()
+                .split(&fractions, shuffle, seed, stratify_by.as_deref())
+        })?;
+        Ok(out.into_iter().map(PyDataFrame::from).collect())
+    }
+
     pub fn rechunk(&self, py: Python) -> PyResult<Self> {
         py.enter_polars_df(|| {
             let mut df = self.df.read().clone();
@@ -144,6 +163,10 @@ impl PyDataFrame {
     }
 
     /// Get column names
+    pub fn field_metadata(&self) -> HashMap<String, BTreeMap<String, String>> {
+        field_metadata_to_strings(&self.df.read().field_metadata())
+    }
+
     pub fn columns(&self) -> Vec<String> {
         self.df
             .read()
@@ -484,6 +507,79 @@ impl PyDataFrame {
         })
     }
 
+    pub fn pca(&self, py: Python<'_>, k: usize) -> PyResult<(Self, Self)> {
+        let (loadings, transformed) = py.enter_polars(|| self.df.read().pca(k))?;
+        Ok((loadings.into(), transformed.into()))
+    }
+
+    #[pyo3(signature = (columns, k, max_iter, seed))]
+    pub fn kmeans(
+        &self,
+        py: Python<'_>,
+        columns: Vec<PyBackedStr>,
+        k: usize,
+        max_iter: usize,
+        seed: Option<u64>,
+    ) -> PyResult<PySeries> {
+        let columns = strings_to_pl_smallstr(columns);
+        let labels = py.enter_polars(|| self.df.read().kmeans(&columns, k, max_iter, seed))?;
+        Ok(labels.into_series().into())
+    }
+
+    pub fn dbscan(
+        &self,
+        py: Python<'_>,
+        columns: Vec<PyBackedStr>,
+        eps: f64,
+        min_pts: usize,
+    ) -> PyResult<PySeries> {
+        let columns = strings_to_pl_smallstr(columns);
+        let labels = py.enter_polars(|| self.df.read().dbscan(&columns, eps, min_pts))?;
+        Ok(labels.into_series().into())
+    }
+
+    pub fn cdc(
+        &self,
+        py: Python<'_>,
+        other: &PyDataFrame,
+        keys: Vec<PyBackedStr>,
+    ) -> PyResult<Self> {
+        let keys = strings_to_pl_smallstr(keys);
+        py.enter_polars_df(|| self.df.read().cdc(&other.df.read(), &keys))
+    }
+
+    #[pyo3(signature = (other, left_on, right_on, k, metric, max_distance, suffix, distance_column))]
+    pub fn join_nearest(
+        &self,
+        py: Python<'_>,
+        other: &PyDataFrame,
+        left_on: Vec<PyBackedStr>,
+        right_on: Vec<PyBackedStr>,
+        k: usize,
+        metric: Wrap<NearestMetric>,
+        max_distance: Option<f64>,
+        suffix: String,
+        distance_column: String,
+    ) -> PyResult<Self> {
+        let left_on = strings_to_pl_smallstr(left_on);
+        let right_on = strings_to_pl_smallstr(right_on);
+        let options = NearestJoinOptions {
+            k,
+            metric: metric.0,
+            max_distance,
+            distance_column: distance_column.into(),
+        };
+        py.enter_polars_df(|| {
+            self.df.read().join_nearest(
+                &other.df.read(),
+                &left_on,
+                &right_on,
+                options,
+                Some(suffix.into()),
+            )
+        })
+    }
+
     pub fn null_count(&self, py: Python) -> PyResult<Self> {
         py.enter_polars_df(|| Ok(self.df.read().null_count()))
     }
@@ -492,6 +588,29 @@ impl PyDataFrame {
         py.enter_polars_ok(|| self.df.write().shrink_to_fit())
     }
 
+    pub fn create_index(
+        &self,
+        py: Python,
+        column: &str,
+        kind: &str,
+        block_size: usize,
+    ) -> PyResult<()> {
+        let kind = match kind {
+            "sorted" => IndexKind::Sorted,
+            "zone_map" => IndexKind::ZoneMap { block_size },
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`kind` must be one of {{'sorted', 'zone_map'}}, got {v}",
+                )));
+            },
+        };
+        py.enter_polars(|| self.df.write().create_index(column, kind).map(|_| ()))
+    }
+
+    pub fn drop_indexes(&self) {
+        self.df.write().drop_indexes();
+    }
+
     pub fn hash_rows(
         &self,
         py: Python<'_>,
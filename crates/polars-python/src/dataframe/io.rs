@@ -235,13 +235,24 @@ impl PyDataFrame {
     }
 
     #[cfg(feature = "json")]
-    pub fn write_json(&self, py: Python<'_>, py_f: Py<PyAny>) -> PyResult<()> {
+    #[pyo3(signature = (py_f, temporal_format, non_finite_floats, pretty))]
+    pub fn write_json(
+        &self,
+        py: Python<'_>,
+        py_f: Py<PyAny>,
+        temporal_format: Wrap<JsonTemporalFormat>,
+        non_finite_floats: Wrap<NonFiniteFloats>,
+        pretty: bool,
+    ) -> PyResult<()> {
         let file = BufWriter::new(get_file_like(py_f, true)?);
         py.enter_polars(|| {
             // TODO: Cloud support
 
             JsonWriter::new(file)
                 .with_json_format(JsonFormat::Json)
+                .with_temporal_format(temporal_format.0)
+                .with_non_finite_floats(non_finite_floats.0)
+                .with_pretty(pretty)
                 .finish(&mut self.df.write())
         })
     }
@@ -8,10 +8,12 @@ mod general;
 mod io;
 #[cfg(feature = "pymethods")]
 mod map;
+mod partitioned;
 #[cfg(feature = "pymethods")]
 mod serde;
 
 use parking_lot::RwLock;
+pub use partitioned::PyPartitionedFrame;
 use polars::prelude::DataFrame;
 use pyo3::pyclass;
 
@@ -0,0 +1,78 @@
+use polars::prelude::*;
+use pyo3::prelude::*;
+
+use crate::conversion::Wrap;
+use crate::error::PyPolarsErr;
+use crate::expr::ToExprs;
+use crate::utils::EnterPolarsExt;
+use crate::{PyDataFrame, PyExpr, PyLazyFrame};
+
+#[pyclass(frozen)]
+#[repr(transparent)]
+pub struct PyPartitionedFrame {
+    pub pf: PartitionedFrame,
+}
+
+#[pymethods]
+impl PyPartitionedFrame {
+    #[new]
+    #[pyo3(signature = (df, keys, n_partitions))]
+    fn new(
+        py: Python<'_>,
+        df: &PyDataFrame,
+        keys: Vec<String>,
+        n_partitions: Option<usize>,
+    ) -> PyResult<Self> {
+        let keys = keys.into_iter().map(PlSmallStr::from).collect();
+        let pf = py.enter_polars(|| {
+            let df = df.df.read();
+            match n_partitions {
+                Some(n_partitions) => PartitionedFrame::with_n_partitions(&df, keys, n_partitions),
+                None => PartitionedFrame::new(&df, keys),
+            }
+        })?;
+        Ok(Self { pf })
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.pf.keys().iter().map(|key| key.to_string()).collect()
+    }
+
+    fn n_partitions(&self) -> usize {
+        self.pf.n_partitions()
+    }
+
+    fn height(&self) -> usize {
+        self.pf.height()
+    }
+
+    fn partitions(&self) -> Vec<PyDataFrame> {
+        self.pf
+            .partitions()
+            .iter()
+            .map(|df| df.clone().into())
+            .collect()
+    }
+
+    fn to_df(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        py.enter_polars_df(|| Ok(self.pf.clone().into_df()))
+    }
+
+    fn lazy(&self) -> PyResult<PyLazyFrame> {
+        let lf = self.pf.lazy().map_err(PyPolarsErr::from)?;
+        Ok(lf.into())
+    }
+
+    fn group_by_agg(&self, aggs: Vec<PyExpr>) -> PyResult<PyLazyFrame> {
+        let lf = self
+            .pf
+            .group_by_agg(aggs.to_exprs())
+            .map_err(PyPolarsErr::from)?;
+        Ok(lf.into())
+    }
+
+    fn join(&self, other: &Self, how: Wrap<JoinType>) -> PyResult<PyLazyFrame> {
+        let lf = self.pf.join(&other.pf, how.0).map_err(PyPolarsErr::from)?;
+        Ok(lf.into())
+    }
+}
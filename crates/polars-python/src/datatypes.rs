@@ -25,3 +25,8 @@ pub fn _known_timezones() -> PyResult<Vec<String>> {
         .map(|tz| tz.to_string())
         .collect::<Vec<_>>())
 }
+
+#[pyfunction]
+pub fn _tzdb_version() -> &'static str {
+    polars_core::datatypes::time_zone::TZDB_VERSION
+}
@@ -20,8 +20,13 @@ impl PyExpr {
             .into()
     }
 
-    fn dt_to_string(&self, format: &str) -> Self {
-        self.inner.clone().dt().to_string(format).into()
+    #[pyo3(signature = (format, locale=None))]
+    fn dt_to_string(&self, format: &str, locale: Option<&str>) -> Self {
+        match locale {
+            Some(locale) => self.inner.clone().dt().to_string_localized(format, locale),
+            None => self.inner.clone().dt().to_string(format),
+        }
+        .into()
     }
 
     fn dt_offset_by(&self, by: PyExpr) -> Self {
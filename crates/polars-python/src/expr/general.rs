@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::hash::{BuildHasher, Hash, Hasher};
 use std::ops::Neg;
 
@@ -10,12 +11,14 @@ use polars_plan::plans::{
     AExprSorted, ExprToIRContext, RowEncodingVariant, node_to_expr, to_expr_ir,
 };
 use polars_utils::arena::Arena;
+#[cfg(feature = "scan_fn")]
+use polars_utils::python_function::PythonObject;
 use pyo3::class::basic::CompareOp;
 use pyo3::prelude::*;
 
 use super::datatype::PyDataTypeExpr;
 use super::selector::PySelector;
-use crate::conversion::{Wrap, parse_fill_null_strategy};
+use crate::conversion::{Wrap, metadata_from_strings, parse_fill_null_strategy};
 use crate::error::PyPolarsErr;
 use crate::utils::EnterPolarsExt;
 use crate::{PyDataType, PyExpr};
@@ -205,6 +208,14 @@ impl PyExpr {
             .cut(breaks, labels, left_closed, include_breaks)
             .into()
     }
+    #[pyo3(signature = (breaks, labels, left_closed))]
+    #[cfg(feature = "cutqcut")]
+    fn cut_bounds(&self, breaks: Vec<f64>, labels: Option<Vec<String>>, left_closed: bool) -> Self {
+        self.inner
+            .clone()
+            .cut_bounds(breaks, labels, left_closed)
+            .into()
+    }
     #[pyo3(signature = (probs, labels, left_closed, allow_duplicates, include_breaks))]
     #[cfg(feature = "cutqcut")]
     fn qcut(
@@ -220,6 +231,20 @@ impl PyExpr {
             .qcut(probs, labels, left_closed, allow_duplicates, include_breaks)
             .into()
     }
+    #[pyo3(signature = (probs, labels, left_closed, allow_duplicates))]
+    #[cfg(feature = "cutqcut")]
+    fn qcut_bounds(
+        &self,
+        probs: Vec<f64>,
+        labels: Option<Vec<String>>,
+        left_closed: bool,
+        allow_duplicates: bool,
+    ) -> Self {
+        self.inner
+            .clone()
+            .qcut_bounds(probs, labels, left_closed, allow_duplicates)
+            .into()
+    }
     #[pyo3(signature = (n_bins, labels, left_closed, allow_duplicates, include_breaks))]
     #[cfg(feature = "cutqcut")]
     fn qcut_uniform(
@@ -250,6 +275,81 @@ impl PyExpr {
     fn rle_id(&self) -> Self {
         self.inner.clone().rle_id().into()
     }
+    #[pyo3(signature = (categories, separator, drop_first))]
+    fn to_dummies(
+        &self,
+        categories: Option<Vec<String>>,
+        separator: Option<&str>,
+        drop_first: bool,
+    ) -> Self {
+        self.inner
+            .clone()
+            .to_dummies(categories, separator, drop_first)
+            .into()
+    }
+    fn target_encode(&self, target: PyExpr, smoothing: f64, leave_one_out: bool) -> PyResult<Self> {
+        Ok(self
+            .inner
+            .clone()
+            .target_encode(target.inner, smoothing, leave_one_out)
+            .map_err(PyPolarsErr::from)?
+            .into())
+    }
+    fn freq_encode(&self) -> Self {
+        self.inner.clone().freq_encode().into()
+    }
+    fn zscore(&self, ddof: u8, return_params: bool) -> Self {
+        let expr = self.inner.clone();
+        if return_params {
+            expr.zscore_params(ddof).into()
+        } else {
+            expr.zscore(ddof).into()
+        }
+    }
+    fn min_max_scale(&self, min: f64, max: f64, return_params: bool) -> Self {
+        let expr = self.inner.clone();
+        if return_params {
+            expr.min_max_scale_params().into()
+        } else {
+            expr.min_max_scale(min, max).into()
+        }
+    }
+    fn robust_scale(&self, return_params: bool) -> Self {
+        let expr = self.inner.clone();
+        if return_params {
+            expr.robust_scale_params().into()
+        } else {
+            expr.robust_scale().into()
+        }
+    }
+
+    fn fft(&self) -> Self {
+        self.inner.clone().fft().into()
+    }
+    fn power_spectrum(&self, window: usize) -> Self {
+        self.inner.clone().power_spectrum(window).into()
+    }
+
+    fn convolve(
+        &self,
+        kernel: Vec<f64>,
+        mode: Wrap<ConvolveMode>,
+        null_behavior: Wrap<ConvolveNulls>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .convolve(kernel, mode.0, null_behavior.0)
+            .into()
+    }
+    fn savgol(&self, window: usize, polyorder: usize, null_behavior: Wrap<ConvolveNulls>) -> Self {
+        self.inner
+            .clone()
+            .savgol(window, polyorder, null_behavior.0)
+            .into()
+    }
+    fn bucketize_numeric(&self, k_anonymity: usize) -> Self {
+        self.inner.clone().bucketize_numeric(k_anonymity).into()
+    }
 
     fn agg_groups(&self) -> Self {
         self.inner.clone().agg_groups().into()
@@ -728,6 +828,37 @@ impl PyExpr {
             .into()
     }
 
+    #[cfg(feature = "scan_fn")]
+    #[pyo3(signature = (function, return_dtype))]
+    fn scan_fn(&self, function: Py<PyAny>, return_dtype: Option<PyDataTypeExpr>) -> Self {
+        self.inner
+            .clone()
+            .map_unary(FunctionExpr::ScanFn {
+                step: ScanFnStep::Series(PlanCallback::new_python(PythonObject(function))),
+                return_dtype: return_dtype.map(|dt| dt.inner),
+            })
+            .into()
+    }
+
+    #[cfg(feature = "scan_fn")]
+    #[pyo3(signature = (name, init, return_dtype))]
+    fn scan_fn_registered(
+        &self,
+        name: String,
+        init: Wrap<AnyValue<'_>>,
+        return_dtype: Option<PyDataTypeExpr>,
+    ) -> Self {
+        let init = init.0.into_static();
+        self.inner
+            .clone()
+            .scan_fn(
+                name,
+                Scalar::new(init.dtype(), init),
+                return_dtype.map(|dt| dt.inner),
+            )
+            .into()
+    }
+
     fn product(&self) -> Self {
         self.inner.clone().product().into()
     }
@@ -821,6 +952,28 @@ impl PyExpr {
             .into()
     }
 
+    fn dp_sum(&self, epsilon: f64, lower: f64, upper: f64) -> PyResult<Self> {
+        Ok(self
+            .inner
+            .clone()
+            .dp_sum(epsilon, (lower, upper))
+            .map_err(PyPolarsErr::from)?
+            .into())
+    }
+
+    fn dp_count(&self, epsilon: f64) -> Self {
+        self.inner.clone().dp_count(epsilon).into()
+    }
+
+    fn dp_mean(&self, epsilon: f64, lower: f64, upper: f64) -> PyResult<Self> {
+        Ok(self
+            .inner
+            .clone()
+            .dp_mean(epsilon, (lower, upper))
+            .map_err(PyPolarsErr::from)?
+            .into())
+    }
+
     fn ewm_mean(&self, alpha: f64, adjust: bool, min_periods: usize, ignore_nulls: bool) -> Self {
         let options = EWMOptions {
             alpha,
@@ -913,6 +1066,21 @@ impl PyExpr {
         self.inner.clone().set_sorted_flag(sortedness).into()
     }
 
+    fn with_field_metadata(&self, metadata: BTreeMap<String, String>) -> Self {
+        self.inner
+            .clone()
+            .with_field_metadata(metadata_from_strings(metadata))
+            .into()
+    }
+
+    fn set_unit(&self, unit: &str) -> Self {
+        self.inner.clone().set_unit(unit).into()
+    }
+
+    fn convert_unit(&self, unit: &str) -> Self {
+        self.inner.clone().convert_unit(unit).into()
+    }
+
     fn replace(&self, old: PyExpr, new: PyExpr) -> Self {
         self.inner.clone().replace(old.inner, new.inner).into()
     }
@@ -0,0 +1,22 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn interval_overlaps(&self, other: PyExpr) -> Self {
+        self.inner.clone().interval().overlaps(other.inner).into()
+    }
+
+    fn interval_contains(&self, other: PyExpr) -> Self {
+        self.inner.clone().interval().contains(other.inner).into()
+    }
+
+    fn interval_intersect(&self, other: PyExpr) -> Self {
+        self.inner.clone().interval().intersect(other.inner).into()
+    }
+
+    fn interval_union_merge(&self) -> Self {
+        self.inner.clone().interval().union_merge().into()
+    }
+}
@@ -14,6 +14,8 @@ mod extension;
 #[cfg(feature = "pymethods")]
 mod general;
 #[cfg(feature = "pymethods")]
+mod interval;
+#[cfg(feature = "pymethods")]
 mod list;
 #[cfg(all(feature = "meta", feature = "pymethods"))]
 mod meta;
@@ -114,6 +114,11 @@ impl PySelector {
         Selector::Matches(pattern.into()).into()
     }
 
+    #[staticmethod]
+    fn by_metadata(key: String, value: String) -> Self {
+        Selector::by_metadata(key, value).into()
+    }
+
     #[staticmethod]
     fn enum_() -> Self {
         DataTypeSelector::Enum.as_selector().into()
@@ -16,8 +16,15 @@ impl PyExpr {
             .into()
     }
 
-    #[pyo3(signature = (format, strict, exact, cache))]
-    fn str_to_date(&self, format: Option<String>, strict: bool, exact: bool, cache: bool) -> Self {
+    #[pyo3(signature = (format, strict, exact, cache, locale=None))]
+    fn str_to_date(
+        &self,
+        format: Option<String>,
+        strict: bool,
+        exact: bool,
+        cache: bool,
+        locale: Option<String>,
+    ) -> Self {
         let format = format.map(|x| x.into());
 
         let options = StrptimeOptions {
@@ -25,11 +32,14 @@ impl PyExpr {
             strict,
             exact,
             cache,
+            locale: locale.map(|x| x.into()),
         };
         self.inner.clone().str().to_date(options).into()
     }
 
-    #[pyo3(signature = (format, time_unit, time_zone, strict, exact, cache, ambiguous))]
+    #[pyo3(signature = (
+        format, time_unit, time_zone, strict, exact, cache, ambiguous, locale=None
+    ))]
     fn str_to_datetime(
         &self,
         format: Option<String>,
@@ -39,6 +49,7 @@ impl PyExpr {
         exact: bool,
         cache: bool,
         ambiguous: Self,
+        locale: Option<String>,
     ) -> Self {
         let format = format.map(|x| x.into());
         let time_zone = time_zone.0;
@@ -48,6 +59,7 @@ impl PyExpr {
             strict,
             exact,
             cache,
+            locale: locale.map(|x| x.into()),
         };
         self.inner
             .clone()
@@ -61,8 +73,14 @@ impl PyExpr {
             .into()
     }
 
-    #[pyo3(signature = (format, strict, cache))]
-    fn str_to_time(&self, format: Option<String>, strict: bool, cache: bool) -> Self {
+    #[pyo3(signature = (format, strict, cache, locale=None))]
+    fn str_to_time(
+        &self,
+        format: Option<String>,
+        strict: bool,
+        cache: bool,
+        locale: Option<String>,
+    ) -> Self {
         let format = format.map(|x| x.into());
 
         let options = StrptimeOptions {
@@ -70,10 +88,19 @@ impl PyExpr {
             strict,
             cache,
             exact: true,
+            locale: locale.map(|x| x.into()),
         };
         self.inner.clone().str().to_time(options).into()
     }
 
+    fn str_to_duration(&self, time_unit: Wrap<TimeUnit>, strict: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .to_duration(time_unit.0, strict)
+            .into()
+    }
+
     fn str_strip_chars(&self, matches: Self) -> Self {
         self.inner.clone().str().strip_chars(matches.inner).into()
     }
@@ -126,6 +153,23 @@ impl PyExpr {
         self.inner.clone().str().to_lowercase().into()
     }
 
+    fn str_mask_email(&self) -> Self {
+        self.inner.clone().str().mask_email().into()
+    }
+
+    fn str_mask_digits(&self, keep_last: usize) -> Self {
+        self.inner.clone().str().mask_digits(keep_last).into()
+    }
+
+    fn str_collation_key(&self, locale: &str, strength: Wrap<CollationStrength>) -> Self {
+        let options = CollationOptions::new(locale.into()).with_strength(strength.0);
+        self.inner.clone().str().collation_key(options).into()
+    }
+
+    fn str_natural_sort_key(&self) -> Self {
+        self.inner.clone().str().natural_sort_key().into()
+    }
+
     #[cfg(feature = "nightly")]
     fn str_to_titlecase(&self) -> Self {
         self.inner.clone().str().to_titlecase().into()
@@ -161,6 +205,14 @@ impl PyExpr {
         self.inner.clone().str().normalize(form.0).into()
     }
 
+    fn str_remove_diacritics(&self) -> Self {
+        self.inner.clone().str().remove_diacritics().into()
+    }
+
+    fn str_is_ascii(&self) -> Self {
+        self.inner.clone().str().is_ascii().into()
+    }
+
     fn str_reverse(&self) -> Self {
         self.inner.clone().str().reverse().into()
     }
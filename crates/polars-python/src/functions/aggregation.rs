@@ -4,6 +4,7 @@ use pyo3::prelude::*;
 use crate::PyExpr;
 use crate::error::PyPolarsErr;
 use crate::expr::ToExprs;
+use crate::expr::selector::PySelector;
 
 #[pyfunction]
 pub fn all_horizontal(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
@@ -46,3 +47,8 @@ pub fn mean_horizontal(exprs: Vec<PyExpr>, ignore_nulls: bool) -> PyResult<PyExp
     let e = dsl::mean_horizontal(exprs, ignore_nulls).map_err(PyPolarsErr::from)?;
     Ok(e.into())
 }
+
+#[pyfunction]
+pub fn first_non_null_horizontal(selector: PySelector) -> PyExpr {
+    dsl::first_non_null_horizontal(selector.inner).into()
+}
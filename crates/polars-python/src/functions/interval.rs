@@ -0,0 +1,8 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pyfunction]
+pub fn interval(start: PyExpr, end: PyExpr) -> PyExpr {
+    polars_plan::dsl::functions::interval(start.inner, end.inner).into()
+}
@@ -6,10 +6,14 @@ use polars::prelude::CloudScheme;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
+#[cfg(feature = "parquet")]
+use crate::PyDataFrame;
 use crate::conversion::Wrap;
 use crate::error::PyPolarsErr;
 use crate::file::{EitherRustPythonFile, get_either_file};
 use crate::io::cloud_options::OptPyCloudOptions;
+#[cfg(feature = "parquet")]
+use crate::utils::EnterPolarsExt;
 
 #[cfg(feature = "ipc")]
 #[pyfunction]
@@ -27,20 +31,19 @@ pub fn read_ipc_schema(py: Python<'_>, py_f: Py<PyAny>) -> PyResult<Bound<'_, Py
     Ok(dict)
 }
 
+/// Reads the metadata of the Parquet file `py_f`.
 #[cfg(feature = "parquet")]
-#[pyfunction]
-pub fn read_parquet_metadata(
+fn read_parquet_file_metadata(
     py: Python,
     py_f: Py<PyAny>,
     storage_options: OptPyCloudOptions,
     credential_provider: Option<Py<PyAny>>,
-) -> PyResult<Py<PyDict>> {
+) -> PyResult<polars_parquet::read::FileMetadata> {
     use std::io::Cursor;
 
     use polars_error::feature_gated;
     use polars_io::pl_async::get_runtime;
     use polars_parquet::read::read_metadata;
-    use polars_parquet::read::schema::read_custom_key_value_metadata;
 
     use crate::file::{PythonScanSourceInput, get_python_scan_source_input};
 
@@ -79,7 +82,20 @@ pub fn read_parquet_metadata(
             read_metadata(&mut BufReader::new(f)).map_err(PyPolarsErr::from)?
         },
     };
+    Ok(metadata)
+}
+
+#[cfg(feature = "parquet")]
+#[pyfunction]
+pub fn read_parquet_metadata(
+    py: Python,
+    py_f: Py<PyAny>,
+    storage_options: OptPyCloudOptions,
+    credential_provider: Option<Py<PyAny>>,
+) -> PyResult<Py<PyDict>> {
+    use polars_parquet::read::schema::read_custom_key_value_metadata;
 
+    let metadata = read_parquet_file_metadata(py, py_f, storage_options, credential_provider)?;
     let key_value_metadata = read_custom_key_value_metadata(metadata.key_value_metadata());
     let dict = PyDict::new(py);
     for (key, value) in key_value_metadata.into_iter() {
@@ -88,6 +104,21 @@ pub fn read_parquet_metadata(
     Ok(dict.unbind())
 }
 
+#[cfg(feature = "parquet")]
+#[pyfunction]
+pub fn read_parquet_statistics(
+    py: Python,
+    py_f: Py<PyAny>,
+    storage_options: OptPyCloudOptions,
+    credential_provider: Option<Py<PyAny>>,
+) -> PyResult<PyDataFrame> {
+    use polars_io::parquet::read::column_chunk_statistics_df;
+
+    let metadata = read_parquet_file_metadata(py, py_f, storage_options, credential_provider)?;
+    let df = py.enter_polars(|| column_chunk_statistics_df(&metadata))?;
+    Ok(df.into())
+}
+
 #[cfg(any(feature = "ipc", feature = "parquet"))]
 fn fields_to_pydict(schema: &ArrowSchema, dict: &Bound<'_, PyDict>) -> PyResult<()> {
     for field in schema.iter_values() {
@@ -83,6 +83,26 @@ pub fn arg_sort_by(
     )
     .into()
 }
+#[cfg(feature = "search_sorted")]
+#[pyfunction]
+pub fn search_sorted_by(
+    by: Vec<PyExpr>,
+    search_values: Vec<PyExpr>,
+    side: Wrap<SearchSortedSide>,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+) -> PyResult<PyExpr> {
+    let expr = dsl::search_sorted_by(
+        by.to_exprs(),
+        search_values.to_exprs(),
+        side.0,
+        descending,
+        nulls_last,
+    )
+    .map_err(PyPolarsErr::from)?;
+    Ok(expr.into())
+}
+
 #[pyfunction]
 pub fn arg_where(condition: PyExpr) -> PyExpr {
     dsl::arg_where(condition.inner).into()
@@ -104,6 +124,11 @@ pub fn field(names: Vec<String>) -> PyExpr {
     dsl::Expr::Field(names.into_iter().map(|x| x.into()).collect()).into()
 }
 
+#[pyfunction]
+pub fn param(name: &str) -> PyExpr {
+    dsl::param(name).into()
+}
+
 #[pyfunction]
 pub fn coalesce(exprs: Vec<PyExpr>) -> PyExpr {
     let exprs = exprs.to_exprs();
@@ -127,15 +152,29 @@ fn lfs_to_plans(lfs: Vec<PyLazyFrame>) -> Vec<DslPlan> {
 }
 
 #[pyfunction]
+#[pyo3(signature = (
+    lfs, engine, optflags, priorities=None, max_concurrent_queries=None, memory_limit=None,
+    max_concurrent_requests=None
+))]
 pub fn collect_all(
     lfs: Vec<PyLazyFrame>,
     engine: Wrap<Engine>,
     optflags: PyOptFlags,
+    priorities: Option<Vec<i64>>,
+    max_concurrent_queries: Option<usize>,
+    memory_limit: Option<u64>,
+    max_concurrent_requests: Option<u32>,
     py: Python<'_>,
 ) -> PyResult<Vec<PyDataFrame>> {
     let plans = lfs_to_plans(lfs);
+    let options = CollectAllOptions {
+        priorities,
+        max_concurrent_queries,
+        memory_limit,
+        max_concurrent_requests,
+    };
     let dfs = py.enter_polars(|| {
-        LazyFrame::collect_all_with_engine(plans, engine.0, optflags.inner.into_inner())
+        LazyFrame::collect_all_scheduled(plans, engine.0, optflags.inner.into_inner(), options)
     })?;
     Ok(dfs.into_iter().map(Into::into).collect())
 }
@@ -1,10 +1,14 @@
 mod aggregation;
 mod business;
 mod eager;
+mod interval;
 mod io;
 mod lazy;
 mod meta;
 mod misc;
+#[cfg(feature = "plan_cache")]
+mod plan_cache;
+mod privacy;
 mod random;
 mod range;
 mod string_cache;
@@ -15,10 +19,14 @@ mod whenthen;
 pub use aggregation::*;
 pub use business::*;
 pub use eager::*;
+pub use interval::*;
 pub use io::*;
 pub use lazy::*;
 pub use meta::*;
 pub use misc::*;
+#[cfg(feature = "plan_cache")]
+pub use plan_cache::*;
+pub use privacy::*;
 pub use random::*;
 pub use range::*;
 pub use string_cache::*;
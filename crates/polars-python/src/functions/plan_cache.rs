@@ -0,0 +1,23 @@
+use polars::prelude::PlanCacheStats;
+use pyo3::prelude::*;
+
+#[pyfunction]
+pub fn set_plan_cache_capacity(capacity: usize) {
+    polars::prelude::set_plan_cache_capacity(capacity)
+}
+
+#[pyfunction]
+pub fn plan_cache_stats() -> (u64, u64, usize, usize) {
+    let PlanCacheStats {
+        hits,
+        misses,
+        len,
+        capacity,
+    } = polars::prelude::plan_cache_stats();
+    (hits, misses, len, capacity)
+}
+
+#[pyfunction]
+pub fn clear_plan_cache() {
+    polars::prelude::clear_plan_cache()
+}
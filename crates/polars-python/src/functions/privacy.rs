@@ -0,0 +1,16 @@
+use std::sync::Arc;
+
+use polars::prelude::PrivacyBudget;
+use pyo3::prelude::*;
+
+#[pyfunction]
+pub fn set_privacy_budget(epsilon: Option<f64>) {
+    polars::prelude::set_privacy_budget(
+        epsilon.map(|epsilon| Arc::new(PrivacyBudget::new(epsilon))),
+    );
+}
+
+#[pyfunction]
+pub fn privacy_budget_remaining() -> Option<f64> {
+    polars::prelude::get_privacy_budget().map(|budget| budget.remaining())
+}
@@ -10,6 +10,13 @@ pub fn when(condition: PyExpr) -> PyWhen {
     }
 }
 
+#[pyfunction]
+pub fn when_lazy(condition: PyExpr) -> PyWhen {
+    PyWhen {
+        inner: dsl::when_lazy(condition.inner),
+    }
+}
+
 #[pyclass(frozen)]
 #[derive(Clone)]
 pub struct PyWhen {
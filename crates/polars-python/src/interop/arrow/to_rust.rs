@@ -138,12 +138,7 @@ pub fn to_rust_df(
                             .map(|(i, arr)| {
                                 let (_, field) = schema.get_at_index(i).unwrap();
                                 let s = unsafe {
-                                    Series::_try_from_arrow_unchecked_with_md(
-                                        field.name.clone(),
-                                        vec![arr],
-                                        field.dtype(),
-                                        field.metadata.as_deref(),
-                                    )
+                                    Series::_try_from_arrow_field_unchecked(field, vec![arr])
                                 }
                                 .map_err(PyPolarsErr::from)?
                                 .into_column();
@@ -158,16 +153,10 @@ pub fn to_rust_df(
                     .enumerate()
                     .map(|(i, arr)| {
                         let (_, field) = schema.get_at_index(i).unwrap();
-                        let s = unsafe {
-                            Series::_try_from_arrow_unchecked_with_md(
-                                field.name.clone(),
-                                vec![arr],
-                                field.dtype(),
-                                field.metadata.as_deref(),
-                            )
-                        }
-                        .map_err(PyPolarsErr::from)?
-                        .into_column();
+                        let s =
+                            unsafe { Series::_try_from_arrow_field_unchecked(field, vec![arr]) }
+                                .map_err(PyPolarsErr::from)?
+                                .into_column();
                         Ok(s)
                     })
                     .collect::<PyResult<Vec<_>>>()
@@ -2,12 +2,15 @@ use std::sync::Arc;
 
 use polars::prelude::default_values::DefaultFieldValues;
 use polars::prelude::deletion::DeletionFilesList;
+use polars::prelude::listing::FileListingOptions;
+use polars::prelude::schema_evolution::SchemaEvolutionPolicy;
 use polars::prelude::{
     CastColumnsPolicy, CloudScheme, ColumnMapping, ExtraColumnsPolicy, MissingColumnsPolicy,
-    PlSmallStr, Schema, TableStatistics, UnifiedScanArgs,
+    PlSmallStr, ProvenanceColumns, Schema, TableStatistics, UnifiedScanArgs,
 };
 use polars_io::{HiveOptions, RowIndex};
 use polars_utils::IdxSize;
+use polars_utils::pl_path::PlRefPath;
 use polars_utils::slice_enum::Slice;
 use pyo3::intern;
 use pyo3::prelude::*;
@@ -53,8 +56,13 @@ impl PyScanOptions<'_> {
             extra_columns: Wrap<ExtraColumnsPolicy>,
             missing_columns: Wrap<MissingColumnsPolicy>,
             include_file_paths: Option<Wrap<PlSmallStr>>,
+            include_row_index_in_file: Option<Wrap<PlSmallStr>>,
+            include_row_group_index: Option<Wrap<PlSmallStr>>,
+            include_file_modification_time: Option<Wrap<PlSmallStr>>,
+            schema_evolution: Option<Wrap<SchemaEvolutionPolicy>>,
             glob: bool,
             hidden_file_prefix: Option<Vec<PyBackedStr>>,
+            listing_options: Option<Wrap<FileListingOptions>>,
             column_mapping: Option<Wrap<ColumnMapping>>,
             default_values: Option<Wrap<DefaultFieldValues>>,
             hive_partitioning: Option<bool>,
@@ -66,6 +74,7 @@ impl PyScanOptions<'_> {
             credential_provider: Option<Py<PyAny>>,
             deletion_files: Option<Wrap<DeletionFilesList>>,
             table_statistics: Option<Wrap<TableStatistics>>,
+            statistics_sidecar: Option<Wrap<PlRefPath>>,
             row_count: Option<(u64, u64)>,
         }
 
@@ -76,10 +85,15 @@ impl PyScanOptions<'_> {
             extra_columns,
             missing_columns,
             include_file_paths,
+            include_row_index_in_file,
+            include_row_group_index,
+            include_file_modification_time,
+            schema_evolution,
             column_mapping,
             default_values,
             glob,
             hidden_file_prefix,
+            listing_options,
             hive_partitioning,
             hive_schema,
             try_parse_hive_dates,
@@ -89,6 +103,7 @@ impl PyScanOptions<'_> {
             credential_provider,
             deletion_files,
             table_statistics,
+            statistics_sidecar,
             row_count,
         } = self.0.extract()?;
 
@@ -120,6 +135,7 @@ impl PyScanOptions<'_> {
             glob,
             hidden_file_prefix: hidden_file_prefix
                 .map(|x| x.into_iter().map(|x| (*x).into()).collect()),
+            file_listing: listing_options.map(|x| x.0).unwrap_or_default(),
             projection: None,
             column_mapping: column_mapping.map(|x| x.0),
             default_values: default_values
@@ -131,8 +147,15 @@ impl PyScanOptions<'_> {
             missing_columns_policy: missing_columns.0,
             extra_columns_policy: extra_columns.0,
             include_file_paths: include_file_paths.map(|x| x.0),
+            provenance: ProvenanceColumns {
+                row_index_in_file: include_row_index_in_file.map(|x| x.0),
+                row_group_index: include_row_group_index.map(|x| x.0),
+                file_modification_time: include_file_modification_time.map(|x| x.0),
+            },
+            schema_evolution: schema_evolution.map(|x| Arc::new(x.0)),
             deletion_files: DeletionFilesList::filter_empty(deletion_files.map(|x| x.0)),
             table_statistics: table_statistics.map(|x| x.0),
+            statistics_sidecar: statistics_sidecar.map(|x| x.0),
             row_count,
         };
 
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::ffi::CString;
 use std::num::NonZeroUsize;
 
@@ -13,16 +13,19 @@ use polars_core::prelude::*;
 use polars_core::query_result::QueryResult;
 #[cfg(feature = "parquet")]
 use polars_parquet::arrow::write::StatisticsOptions;
-use polars_plan::dsl::ScanSources;
+use polars_plan::dsl::listing::FileListingOptions;
+use polars_plan::dsl::schema_evolution::SchemaEvolutionPolicy;
+use polars_plan::dsl::{MorselSizing, ProvenanceColumns, ScanSources};
 use polars_plan::plans::{AExpr, HintIR, IR, Sorted};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::python_function::PythonObject;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::pybacked::PyBackedStr;
+use pyo3::pybacked::{PyBackedBytes, PyBackedStr};
 use pyo3::types::{PyCapsule, PyDict, PyDictMethods, PyList};
 
 use super::{PyLazyFrame, PyOptFlags};
+use crate::conversion::{field_metadata_to_strings, metadata_to_strings};
 use crate::error::PyPolarsErr;
 use crate::expr::ToExprs;
 use crate::expr::datatype::PyDataTypeExpr;
@@ -89,7 +92,9 @@ impl PyLazyFrame {
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (
         source, sources, infer_schema_length, schema, schema_overrides, batch_size, n_rows, low_memory, rechunk,
-        row_index, ignore_errors, include_file_paths, cloud_options, credential_provider
+        row_index, ignore_errors, include_file_paths, cloud_options, credential_provider,
+        schema_evolution, include_row_index_in_file, include_file_modification_time,
+        listing_options, statistics_sidecar
     ))]
     fn new_from_ndjson(
         source: Option<Py<PyAny>>,
@@ -106,6 +111,11 @@ impl PyLazyFrame {
         include_file_paths: Option<String>,
         cloud_options: OptPyCloudOptions,
         credential_provider: Option<Py<PyAny>>,
+        schema_evolution: Option<Wrap<SchemaEvolutionPolicy>>,
+        include_row_index_in_file: Option<String>,
+        include_file_modification_time: Option<String>,
+        listing_options: Option<Wrap<FileListingOptions>>,
+        statistics_sidecar: Option<Wrap<PlRefPath>>,
     ) -> PyResult<Self> {
         let row_index = row_index.map(|(name, offset)| RowIndex {
             name: name.into(),
@@ -142,6 +152,14 @@ impl PyLazyFrame {
             .with_row_index(row_index)
             .with_ignore_errors(ignore_errors)
             .with_include_file_paths(include_file_paths.map(|x| x.into()))
+            .with_schema_evolution(schema_evolution.map(|x| Arc::new(x.0)))
+            .with_provenance_columns(ProvenanceColumns {
+                row_index_in_file: include_row_index_in_file.map(|x| x.into()),
+                row_group_index: None,
+                file_modification_time: include_file_modification_time.map(|x| x.into()),
+            })
+            .with_file_listing_options(listing_options.map(|x| x.0).unwrap_or_default())
+            .with_statistics_sidecar(statistics_sidecar.map(|x| x.0))
             .finish()
             .map_err(PyPolarsErr::from)?;
 
@@ -154,7 +172,9 @@ impl PyLazyFrame {
         low_memory, comment_prefix, quote_char, null_values, missing_utf8_is_empty_string,
         infer_schema_length, with_schema_modify, rechunk, skip_rows_after_header,
         encoding, row_index, try_parse_dates, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, glob, schema,
-        cloud_options, credential_provider, include_file_paths, missing_columns
+        cloud_options, credential_provider, include_file_paths, missing_columns, schema_evolution,
+        include_row_index_in_file, include_file_modification_time, listing_options,
+        statistics_sidecar
     )
     )]
     fn new_from_csv(
@@ -190,6 +210,11 @@ impl PyLazyFrame {
         credential_provider: Option<Py<PyAny>>,
         include_file_paths: Option<String>,
         missing_columns: Option<Wrap<MissingColumnsPolicy>>,
+        schema_evolution: Option<Wrap<SchemaEvolutionPolicy>>,
+        include_row_index_in_file: Option<String>,
+        include_file_modification_time: Option<String>,
+        listing_options: Option<Wrap<FileListingOptions>>,
+        statistics_sidecar: Option<Wrap<PlRefPath>>,
     ) -> PyResult<Self> {
         let null_values = null_values.map(|w| w.0);
         let quote_char = quote_char.and_then(|s| s.as_bytes().first()).copied();
@@ -261,7 +286,15 @@ impl PyLazyFrame {
             .with_glob(glob)
             .with_raise_if_empty(raise_if_empty)
             .with_include_file_paths(include_file_paths.map(|x| x.into()))
-            .with_missing_columns_policy(missing_columns.map(|x| x.0));
+            .with_missing_columns_policy(missing_columns.map(|x| x.0))
+            .with_schema_evolution(schema_evolution.map(|x| Arc::new(x.0)))
+            .with_provenance_columns(ProvenanceColumns {
+                row_index_in_file: include_row_index_in_file.map(|x| x.into()),
+                row_group_index: None,
+                file_modification_time: include_file_modification_time.map(|x| x.into()),
+            })
+            .with_file_listing_options(listing_options.map(|x| x.0).unwrap_or_default())
+            .with_statistics_sidecar(statistics_sidecar.map(|x| x.0));
 
         if let Some(lambda) = with_schema_modify {
             let f = |schema: Schema| {
@@ -291,8 +324,11 @@ impl PyLazyFrame {
 
     #[cfg(feature = "parquet")]
     #[staticmethod]
+    #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (
-        sources, schema, scan_options, parallel, low_memory, use_statistics
+        sources, schema, scan_options, parallel, low_memory, use_statistics, int96_time_unit,
+        int96_time_zone, int96_overflow, int64_timestamp_overflow, uuid_as_string,
+        footer_summary=None, footers=None, categorical_columns=None
     ))]
     fn new_from_parquet(
         sources: Wrap<ScanSources>,
@@ -301,16 +337,44 @@ impl PyLazyFrame {
         parallel: Wrap<ParallelStrategy>,
         low_memory: bool,
         use_statistics: bool,
+        int96_time_unit: Option<Wrap<TimeUnit>>,
+        int96_time_zone: Wrap<Option<TimeZone>>,
+        int96_overflow: Option<PyBackedStr>,
+        int64_timestamp_overflow: Option<PyBackedStr>,
+        uuid_as_string: bool,
+        footer_summary: Option<Wrap<PlRefPath>>,
+        footers: Option<Vec<(Wrap<PlRefPath>, PyBackedBytes)>>,
+        categorical_columns: Option<Vec<PyBackedStr>>,
     ) -> PyResult<Self> {
+        use polars_io::parquet::metadata::ParquetFooters;
+
         use crate::utils::to_py_err;
 
         let parallel = parallel.0;
 
-        let options = ParquetOptions {
+        let mut options = ParquetOptions {
             schema: schema.map(|x| Arc::new(x.0)),
             parallel,
             low_memory,
             use_statistics,
+            decryption: None,
+            readahead: None,
+            readahead_bytes: None,
+            struct_fields: None,
+            int96: Int96Options {
+                time_unit: int96_time_unit.map(|tu| tu.0),
+                time_zone: int96_time_zone.0,
+                overflow: int96_overflow
+                    .map(|overflow| parse_timestamp_overflow(&overflow, "int96_overflow"))
+                    .transpose()?,
+            },
+            int64_timestamp_overflow: int64_timestamp_overflow
+                .map(|overflow| parse_timestamp_overflow(&overflow, "int64_timestamp_overflow"))
+                .transpose()?,
+            uuid_as_string,
+            categorical_columns: categorical_columns
+                .map(|columns| columns.iter().map(|s| PlSmallStr::from_str(s)).collect()),
+            footers: None,
         };
 
         let sources = sources.0;
@@ -319,6 +383,27 @@ impl PyLazyFrame {
         let unified_scan_args =
             scan_options.extract_unified_scan_args(first_path.and_then(|x| x.scheme()))?;
 
+        options.footers = match (footer_summary, footers) {
+            (Some(path), _) => Some(
+                ParquetFooters::read_summary(
+                    &path.0,
+                    unified_scan_args.cloud_options.as_ref(),
+                    None,
+                )
+                .map_err(PyPolarsErr::from)?,
+            ),
+            (None, Some(footers)) => Some(
+                ParquetFooters::from_bytes(
+                    footers
+                        .iter()
+                        .map(|(path, bytes)| (path.0.clone(), &**bytes)),
+                    None,
+                )
+                .map_err(PyPolarsErr::from)?,
+            ),
+            (None, None) => None,
+        };
+
         let lf: LazyFrame = DslBuilder::scan_parquet(sources, options, unified_scan_args)
             .map_err(to_py_err)?
             .build()
@@ -483,6 +568,10 @@ impl PyLazyFrame {
         py.enter_polars(|| self.ldf.read().describe_optimized_plan_tree())
     }
 
+    fn explain_analyze(&self, py: Python) -> PyResult<String> {
+        py.enter_polars(|| self.ldf.read().clone().explain_analyze())
+    }
+
     fn to_dot(&self, py: Python<'_>, optimized: bool) -> PyResult<String> {
         py.enter_polars(|| self.ldf.read().to_dot(optimized))
     }
@@ -564,6 +653,21 @@ impl PyLazyFrame {
         ldf.cache().into()
     }
 
+    fn bind_parameters(&self, params: Vec<(PyBackedStr, PyExpr)>) -> PyResult<Self> {
+        let params = params
+            .into_iter()
+            .map(|(name, value)| match value.inner {
+                polars_plan::dsl::Expr::Literal(lv) => Ok((PlSmallStr::from_str(&name), lv)),
+                e => Err(PyValueError::new_err(format!(
+                    "parameter '{}' must be bound to a literal, got {e}",
+                    &*name
+                ))),
+            })
+            .collect::<PyResult<PlHashMap<_, _>>>()?;
+        let ldf = self.ldf.read().clone();
+        Ok(ldf.bind_parameters(&params).into())
+    }
+
     #[pyo3(signature = (optflags))]
     fn with_optimizations(&self, optflags: PyOptFlags) -> Self {
         let ldf = self.ldf.read().clone();
@@ -589,12 +693,14 @@ impl PyLazyFrame {
         Ok((df.into(), time_df.into()))
     }
 
-    #[pyo3(signature = (engine, lambda_post_opt))]
+    #[pyo3(signature = (engine, lambda_post_opt, morsel_size=None, adaptive_morsel_size=None))]
     fn collect(
         &self,
         py: Python<'_>,
         engine: Wrap<Engine>,
         lambda_post_opt: Option<Py<PyAny>>,
+        morsel_size: Option<usize>,
+        adaptive_morsel_size: Option<bool>,
     ) -> PyResult<PyDataFrame> {
         py.enter_polars_df(|| {
             let ldf = self.ldf.read().clone();
@@ -603,7 +709,14 @@ impl PyLazyFrame {
                     post_opt_callback(&lambda, root, lp_arena, expr_arena, None)
                 })
             } else {
-                ldf.collect_with_engine(engine.0).map(|r| match r {
+                let result = if morsel_size.is_some() || adaptive_morsel_size.is_some() {
+                    let morsel_sizing =
+                        MorselSizing::from_config_with(morsel_size, adaptive_morsel_size);
+                    ldf.collect_with_morsel_sizing(engine.0, morsel_sizing)
+                } else {
+                    ldf.collect_with_engine(engine.0)
+                };
+                result.map(|r| match r {
                     QueryResult::Single(df) => df,
                     // TODO: Should return query results
                     QueryResult::Multiple(_) => DataFrame::empty(),
@@ -678,7 +791,7 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[pyo3(signature = (
         target, sink_options, compression, compression_level, statistics, row_group_size, data_page_size,
-        metadata, arrow_schema
+        data_page_row_limit, metadata, arrow_schema, uuid_columns, column_options
     ))]
     fn sink_parquet(
         &self,
@@ -690,9 +803,31 @@ impl PyLazyFrame {
         statistics: Wrap<StatisticsOptions>,
         row_group_size: Option<usize>,
         data_page_size: Option<usize>,
+        data_page_row_limit: Option<usize>,
         metadata: Wrap<Option<KeyValueMetadata>>,
         arrow_schema: Option<Wrap<ArrowSchema>>,
+        uuid_columns: Vec<PyBackedStr>,
+        column_options: Vec<(PyBackedStr, Option<PyBackedStr>, Option<i32>, bool)>,
     ) -> PyResult<PyLazyFrame> {
+        let column_options = column_options
+            .iter()
+            .map(|(name, column_compression, level, dictionary)| {
+                // A column with only a compression level uses the compression of the file.
+                let column_compression = (column_compression.is_some() || level.is_some())
+                    .then(|| {
+                        parse_parquet_compression(
+                            column_compression.as_deref().unwrap_or(compression),
+                            *level,
+                        )
+                    })
+                    .transpose()?;
+                let options = ParquetColumnOptions {
+                    compression: column_compression,
+                    dictionary: *dictionary,
+                };
+                PyResult::Ok((PlSmallStr::from_str(name), options))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
         let compression = parse_parquet_compression(compression, compression_level)?;
 
         let options = ParquetWriteOptions {
@@ -700,9 +835,18 @@ impl PyLazyFrame {
             statistics: statistics.0,
             row_group_size,
             data_page_size,
+            data_page_row_limit,
             key_value_metadata: metadata.0,
             arrow_schema: arrow_schema.map(|x| Arc::new(x.0)),
+            bloom_filter: None,
             compat_level: None,
+            encryption: None,
+            byte_stream_split: Vec::new(),
+            uuid_columns: uuid_columns
+                .iter()
+                .map(|s| PlSmallStr::from_str(s))
+                .collect(),
+            column_options,
         };
 
         let target = target.extract_file_sink_destination()?;
@@ -742,6 +886,7 @@ impl PyLazyFrame {
             compat_level: compat_level.0,
             record_batch_size,
             record_batch_statistics,
+            arrow_schema: None,
         };
 
         let target = target.extract_file_sink_destination()?;
@@ -761,9 +906,9 @@ impl PyLazyFrame {
     #[cfg(feature = "csv")]
     #[pyo3(signature = (
         target, sink_options, include_bom, compression, compression_level, check_extension,
-        include_header, separator, line_terminator, quote_char, batch_size, datetime_format,
-        date_format, time_format, float_scientific, float_precision, decimal_comma, null_value,
-        quote_style
+        include_header, separator, line_terminator, quote_char, escape_char, batch_size,
+        datetime_format, date_format, time_format, float_scientific, float_precision,
+        decimal_comma, null_value, quote_style, column_options
     ))]
     fn sink_csv(
         &self,
@@ -778,6 +923,7 @@ impl PyLazyFrame {
         separator: u8,
         line_terminator: Wrap<PlSmallStr>,
         quote_char: u8,
+        escape_char: Option<u8>,
         batch_size: NonZeroUsize,
         datetime_format: Option<Wrap<PlSmallStr>>,
         date_format: Option<Wrap<PlSmallStr>>,
@@ -787,8 +933,41 @@ impl PyLazyFrame {
         decimal_comma: bool,
         null_value: Option<Wrap<PlSmallStr>>,
         quote_style: Option<Wrap<QuoteStyle>>,
+        column_options: Vec<(
+            PyBackedStr,
+            Option<Wrap<QuoteStyle>>,
+            Option<bool>,
+            Option<usize>,
+            Option<Wrap<PlSmallStr>>,
+            Option<Wrap<PlSmallStr>>,
+            Option<Wrap<PlSmallStr>>,
+        )>,
     ) -> PyResult<PyLazyFrame> {
         let quote_style = quote_style.map_or(QuoteStyle::default(), |wrap| wrap.0);
+        let column_options = column_options
+            .into_iter()
+            .map(
+                |(
+                    name,
+                    quote_style,
+                    float_scientific,
+                    float_precision,
+                    date_format,
+                    time_format,
+                    datetime_format,
+                )| {
+                    let options = CsvColumnOptions {
+                        quote_style: quote_style.map(|x| x.0),
+                        float_scientific,
+                        float_precision,
+                        date_format: date_format.map(|x| x.0),
+                        time_format: time_format.map(|x| x.0),
+                        datetime_format: datetime_format.map(|x| x.0),
+                    };
+                    (PlSmallStr::from_str(&name), options)
+                },
+            )
+            .collect();
         let null_value = null_value
             .map(|x| x.0)
             .unwrap_or(SerializeOptions::default().null);
@@ -802,9 +981,11 @@ impl PyLazyFrame {
             decimal_comma,
             separator,
             quote_char,
+            escape_char,
             null: null_value,
             line_terminator: line_terminator.0,
             quote_style,
+            column_options,
         };
 
         let options = CsvWriterOptions {
@@ -833,7 +1014,10 @@ impl PyLazyFrame {
 
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "json")]
-    #[pyo3(signature = (target, compression, compression_level, check_extension, sink_options))]
+    #[pyo3(signature = (
+        target, compression, compression_level, check_extension, temporal_format,
+        non_finite_floats, sink_options
+    ))]
     fn sink_ndjson(
         &self,
         py: Python<'_>,
@@ -841,12 +1025,18 @@ impl PyLazyFrame {
         compression: &str,
         compression_level: Option<u32>,
         check_extension: bool,
+        temporal_format: Wrap<JsonTemporalFormat>,
+        non_finite_floats: Wrap<NonFiniteFloats>,
         sink_options: PySinkOptions,
     ) -> PyResult<PyLazyFrame> {
         let options = NDJsonWriterOptions {
             compression: ExternalCompression::try_from(compression, compression_level)
                 .map_err(PyPolarsErr::from)?,
             check_extension,
+            serialize_options: JsonSerializeOptions {
+                temporal_format: temporal_format.0,
+                non_finite_floats: non_finite_floats.0,
+            },
         };
 
         let target = target.extract_file_sink_destination()?;
@@ -887,6 +1077,22 @@ impl PyLazyFrame {
         self.ldf.read().clone().filter(predicate.inner).into()
     }
 
+    fn time_series_folds(
+        &self,
+        time_col: &str,
+        n_folds: usize,
+        gap: usize,
+    ) -> PyResult<Vec<(Self, Self)>> {
+        let ldf = self.ldf.read().clone();
+        let folds = ldf
+            .time_series_folds(time_col, n_folds, gap)
+            .map_err(PyPolarsErr::from)?;
+        Ok(folds
+            .into_iter()
+            .map(|(train, test)| (train.into(), test.into()))
+            .collect())
+    }
+
     fn remove(&self, predicate: PyExpr) -> Self {
         let ldf = self.ldf.read().clone();
         ldf.remove(predicate.inner).into()
@@ -916,6 +1122,38 @@ impl PyLazyFrame {
         PyLazyGroupBy { lgb: Some(lazy_gb) }
     }
 
+    fn group_by_hashed(
+        &self,
+        py: Python<'_>,
+        hash: PyExpr,
+        by: Vec<PyExpr>,
+        maintain_order: bool,
+    ) -> PyResult<PyLazyGroupBy> {
+        let ldf = self.ldf.read().clone();
+        let by = by.to_exprs();
+        let lazy_gb = py.enter_polars(|| ldf.group_by_hashed(hash.inner, by, maintain_order))?;
+        Ok(PyLazyGroupBy { lgb: Some(lazy_gb) })
+    }
+
+    fn join_hashed(
+        &self,
+        py: Python<'_>,
+        other: Self,
+        left_hash: PyExpr,
+        right_hash: PyExpr,
+        how: Wrap<JoinType>,
+        suffix: String,
+        maintain_order: Wrap<MaintainOrderJoin>,
+    ) -> PyResult<Self> {
+        let ldf = self.ldf.read().clone();
+        let other = other.ldf.into_inner();
+        let mut args = JoinArgs::new(how.0).with_suffix(Some(suffix.into()));
+        args.maintain_order = maintain_order.0;
+        let out =
+            py.enter_polars(|| ldf.join_hashed(other, left_hash.inner, right_hash.inner, args))?;
+        Ok(out.into())
+    }
+
     fn rolling(
         &self,
         index_column: PyExpr,
@@ -989,7 +1227,7 @@ impl PyLazyFrame {
     }
 
     #[cfg(feature = "asof_join")]
-    #[pyo3(signature = (other, left_on, right_on, left_by, right_by, allow_parallel, force_parallel, suffix, strategy, tolerance, tolerance_str, coalesce, allow_eq, check_sortedness))]
+    #[pyo3(signature = (other, left_on, right_on, left_by, right_by, allow_parallel, force_parallel, suffix, strategy, tolerance, tolerance_str, coalesce, allow_eq, check_sortedness, distance_column=None, indicator_column=None))]
     fn join_asof(
         &self,
         other: Self,
@@ -1006,6 +1244,8 @@ impl PyLazyFrame {
         coalesce: bool,
         allow_eq: bool,
         check_sortedness: bool,
+        distance_column: Option<String>,
+        indicator_column: Option<String>,
     ) -> PyResult<Self> {
         let coalesce = if coalesce {
             JoinCoalesce::CoalesceColumns
@@ -1036,6 +1276,8 @@ impl PyLazyFrame {
                 tolerance_str: tolerance_str.map(|s| s.into()),
                 allow_eq,
                 check_sortedness,
+                distance_column: distance_column.map(|s| s.into()),
+                indicator_column: indicator_column.map(|s| s.into()),
             })))
             .suffix(suffix)
             .finish()
@@ -1104,6 +1346,23 @@ impl PyLazyFrame {
             .into())
     }
 
+    fn interval_join(
+        &self,
+        py: Python<'_>,
+        other: Self,
+        left_on: PyExpr,
+        right_on: PyExpr,
+        suffix: String,
+    ) -> PyResult<Self> {
+        let ldf = self.ldf.read().clone();
+        let other = other.ldf.into_inner();
+
+        py.enter_polars(|| {
+            ldf.interval_join(other, left_on.inner, right_on.inner, Some(suffix.into()))
+        })
+        .map(Into::into)
+    }
+
     fn with_columns(&self, exprs: Vec<PyExpr>) -> Self {
         let ldf = self.ldf.read().clone();
         ldf.with_columns(exprs.to_exprs()).into()
@@ -1489,6 +1748,37 @@ impl PyLazyFrame {
         Ok(schema_dict)
     }
 
+    fn collect_field_metadata(
+        &self,
+        py: Python<'_>,
+    ) -> PyResult<HashMap<String, BTreeMap<String, String>>> {
+        let ldf = self.ldf.read().clone();
+        let field_metadata = py.enter_polars(|| ldf.collect_field_metadata())?;
+        Ok(field_metadata_to_strings(&field_metadata))
+    }
+
+    #[cfg(feature = "parquet")]
+    fn collect_metadata(&self, py: Python<'_>) -> PyResult<BTreeMap<String, String>> {
+        let ldf = self.ldf.read().clone();
+        let metadata = py.enter_polars(|| ldf.collect_metadata())?;
+        Ok(metadata_to_strings(&metadata))
+    }
+
+    fn scan_pruning_report(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        let ldf = self.ldf.read().clone();
+        py.enter_polars_df(|| ldf.scan_pruning_report())
+    }
+
+    #[cfg(feature = "ipc")]
+    fn collect_statistics_sidecar(
+        &self,
+        py: Python<'_>,
+        path: Wrap<PlRefPath>,
+    ) -> PyResult<PyDataFrame> {
+        let ldf = self.ldf.read().clone();
+        py.enter_polars_df(|| ldf.collect_statistics_sidecar(&path.0))
+    }
+
     fn unnest(&self, columns: PySelector, separator: Option<&str>) -> Self {
         self.ldf
             .read()
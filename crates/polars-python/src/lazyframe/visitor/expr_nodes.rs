@@ -790,6 +790,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
             predicate,
             truthy,
             falsy,
+            ..
         } => Ternary {
             predicate: predicate.0,
             truthy: truthy.0,
@@ -900,6 +901,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                         },
                     )
                         .into_py_any(py),
+                    IRStringFunction::RemoveDiacritics => {
+                        return Err(PyNotImplementedError::new_err("remove_diacritics"));
+                    },
+                    IRStringFunction::IsAscii => {
+                        return Err(PyNotImplementedError::new_err("is_ascii"));
+                    },
                     IRStringFunction::Reverse => (PyStringFunction::Reverse,).into_py_any(py),
                     IRStringFunction::PadStart { fill_char } => {
                         (PyStringFunction::PadStart, fill_char).into_py_any(py)
@@ -940,6 +947,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                         (PyStringFunction::SplitExact, n, inclusive).into_py_any(py)
                     },
                     IRStringFunction::SplitN(n) => (PyStringFunction::SplitN, n).into_py_any(py),
+                    IRStringFunction::Strptime(_, options) if options.locale.is_some() => {
+                        return Err(PyNotImplementedError::new_err("localized strptime"));
+                    },
                     IRStringFunction::Strptime(_, options) => (
                         PyStringFunction::Strptime,
                         options.format.as_ref().map(|s| s.as_str()),
@@ -948,6 +958,21 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                         options.cache,
                     )
                         .into_py_any(py),
+                    IRStringFunction::ToDuration { .. } => {
+                        return Err(PyNotImplementedError::new_err("to_duration"));
+                    },
+                    IRStringFunction::MaskEmail => {
+                        return Err(PyNotImplementedError::new_err("mask_email"));
+                    },
+                    IRStringFunction::MaskDigits { .. } => {
+                        return Err(PyNotImplementedError::new_err("mask_digits"));
+                    },
+                    IRStringFunction::CollationKey(_) => {
+                        return Err(PyNotImplementedError::new_err("collation_key"));
+                    },
+                    IRStringFunction::NaturalSortKey => {
+                        return Err(PyNotImplementedError::new_err("natural_sort_key"));
+                    },
                     IRStringFunction::Split(inclusive) => {
                         (PyStringFunction::Split, inclusive).into_py_any(py)
                     },
@@ -1067,7 +1092,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     IRTemporalFunction::TotalNanoseconds { fractional } => {
                         (PyTemporalFunction::TotalNanoseconds, fractional).into_py_any(py)
                     },
-                    IRTemporalFunction::ToString(format) => {
+                    IRTemporalFunction::ToString {
+                        locale: Some(_), ..
+                    } => return Err(PyNotImplementedError::new_err("localized to_string")),
+                    IRTemporalFunction::ToString { format, .. } => {
                         (PyTemporalFunction::ToString, format).into_py_any(py)
                     },
                     IRTemporalFunction::CastTimeUnit(time_unit) => {
@@ -1195,6 +1223,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     descending,
                 )
                     .into_py_any(py),
+                #[cfg(feature = "search_sorted")]
+                IRFunctionExpr::SearchSortedBy { .. } => {
+                    return Err(PyNotImplementedError::new_err("search_sorted_by"));
+                },
                 IRFunctionExpr::Range(_) => return Err(PyNotImplementedError::new_err("range")),
                 #[cfg(feature = "trigonometry")]
                 IRFunctionExpr::Trigonometry(trigfun) => {
@@ -1368,13 +1400,39 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::RLE => ("rle",).into_py_any(py),
                 #[cfg(feature = "rle")]
                 IRFunctionExpr::RLEID => ("rle_id",).into_py_any(py),
+                IRFunctionExpr::ToDummies { .. } => {
+                    return Err(PyNotImplementedError::new_err("to_dummies"));
+                },
+                IRFunctionExpr::Fft => ("fft",).into_py_any(py),
+                IRFunctionExpr::PowerSpectrum { window } => {
+                    ("power_spectrum", window).into_py_any(py)
+                },
+                IRFunctionExpr::Convolve { .. } => {
+                    return Err(PyNotImplementedError::new_err("convolve"));
+                },
+                IRFunctionExpr::Savgol { .. } => {
+                    return Err(PyNotImplementedError::new_err("savgol"));
+                },
+                IRFunctionExpr::BucketizeNumeric { .. } => {
+                    return Err(PyNotImplementedError::new_err("bucketize_numeric"));
+                },
                 IRFunctionExpr::ToPhysical => ("to_physical",).into_py_any(py),
                 IRFunctionExpr::Random { .. } => {
                     return Err(PyNotImplementedError::new_err("random"));
                 },
+                IRFunctionExpr::DpNoise { .. } => {
+                    return Err(PyNotImplementedError::new_err("dp_noise"));
+                },
                 IRFunctionExpr::SetSortedFlag(sorted) => {
                     ("set_sorted", sorted.descending, sorted.nulls_last).into_py_any(py)
                 },
+                IRFunctionExpr::WithFieldMetadata(_) => {
+                    return Err(PyNotImplementedError::new_err("with_field_metadata"));
+                },
+                IRFunctionExpr::SetUnit(unit) => ("set_unit", unit.as_str()).into_py_any(py),
+                IRFunctionExpr::ConvertUnit { .. } => {
+                    return Err(PyNotImplementedError::new_err("convert_unit"));
+                },
                 #[cfg(feature = "ffi_plugin")]
                 IRFunctionExpr::FfiPlugin { .. } => {
                     return Err(PyNotImplementedError::new_err("ffi plugin"));
@@ -1399,6 +1457,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     ("mean_horizontal", ignore_nulls).into_py_any(py)
                 },
                 IRFunctionExpr::MinHorizontal => ("min_horizontal",).into_py_any(py),
+                #[cfg(feature = "scan_fn")]
+                IRFunctionExpr::ScanFn { .. } => {
+                    return Err(PyNotImplementedError::new_err("scan_fn"));
+                },
                 IRFunctionExpr::EwmMean { options: _ } => {
                     return Err(PyNotImplementedError::new_err("ewm mean"));
                 },
@@ -1447,6 +1509,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::Business(_) => {
                     return Err(PyNotImplementedError::new_err("business"));
                 },
+                IRFunctionExpr::Interval(_) => {
+                    return Err(PyNotImplementedError::new_err("interval"));
+                },
                 #[cfg(feature = "top_k")]
                 IRFunctionExpr::TopKBy { descending } => ("top_k_by", descending).into_py_any(py),
                 IRFunctionExpr::EwmMeanBy { half_life: _ } => {
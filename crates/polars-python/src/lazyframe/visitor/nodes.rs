@@ -138,6 +138,7 @@ impl PyFileOptions {
     /// One of:
     /// * None
     /// * ("iceberg-position-delete", dict[int, list[str]])
+    /// * ("position-delete", dict[int, list[int]])
     #[getter]
     fn deletion_files(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         Ok(match &self.inner.deletion_files {
@@ -155,6 +156,19 @@ impl PyFileOptions {
                     .into_any()
                     .unbind()
             },
+
+            Some(DeletionFilesList::PositionDelete(positions)) => {
+                let out = PyDict::new(py);
+
+                for (k, v) in positions.iter() {
+                    out.set_item(*k, v.as_ref())?;
+                }
+
+                ("position-delete", out)
+                    .into_pyobject(py)?
+                    .into_any()
+                    .unbind()
+            },
         })
     }
 
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::series::IsSorted;
 use polars_core::utils::flatten::flatten_series;
@@ -8,6 +10,7 @@ use pyo3::types::PyBytes;
 use pyo3::{IntoPyObjectExt, Python};
 
 use super::PySeries;
+use crate::conversion::metadata_to_strings;
 use crate::dataframe::PyDataFrame;
 use crate::error::PyPolarsErr;
 use crate::prelude::*;
@@ -179,6 +182,11 @@ impl PySeries {
         Wrap(self.series.read().dtype().clone()).into_pyobject(py)
     }
 
+    fn field_metadata(&self) -> Option<BTreeMap<String, String>> {
+        let metadata = self.series.read().metadata()?;
+        Some(metadata_to_strings(&metadata))
+    }
+
     fn set_sorted_flag(&self, descending: bool) -> Self {
         let mut out = self.series.read().clone();
         if descending {
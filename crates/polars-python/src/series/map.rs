@@ -3,6 +3,8 @@ use pyo3::prelude::*;
 use pyo3::types::{PyNone, PyTuple};
 
 use super::PySeries;
+#[cfg(feature = "scan_fn")]
+use crate::conversion::any_value::{any_value_into_py_object, py_object_to_any_value};
 use crate::error::PyPolarsErr;
 use crate::map::series::ApplyLambdaGeneric;
 use crate::prelude::*;
@@ -65,6 +67,25 @@ impl PySeries {
             s.map(PySeries::from)
         })
     }
+
+    #[cfg(feature = "scan_fn")]
+    fn scan_fn(
+        &self,
+        py: Python<'_>,
+        init: Py<PyAny>,
+        function: &Bound<PyAny>,
+        dtype: Wrap<DataType>,
+    ) -> PyResult<PySeries> {
+        let series = self.series.read().clone(); // Clone so we don't deadlock on re-entrance.
+        let out = polars_ops::series::scan_fn(&series, init, &dtype.0, |state, value| {
+            let value = any_value_into_py_object(value, py)?;
+            let out = function.call1((state, value))?;
+            let (state, out) = out.extract::<(Py<PyAny>, Bound<PyAny>)>()?;
+            Ok((state, py_object_to_any_value(&out, true, false)?))
+        })
+        .map_err(PyPolarsErr::from)?;
+        Ok(out.into())
+    }
 }
 
 #[cfg(feature = "object")]
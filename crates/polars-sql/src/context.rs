@@ -144,11 +144,14 @@ fn disambiguate_projection_cols(
 #[derive(Clone)]
 pub struct SQLContext {
     pub(crate) table_map: Arc<RwLock<PlHashMap<String, LazyFrame>>>,
+    pub(crate) catalogs: Arc<RwLock<PlHashMap<PlSmallStr, Arc<dyn Catalog>>>>,
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
 
     cte_map: PlHashMap<String, LazyFrame>,
+    /// Tables of catalogs referred to by the current statement, by table name.
+    catalog_tables: PlHashMap<String, LazyFrame>,
     table_aliases: PlHashMap<String, String>,
     joined_aliases: PlHashMap<String, PlHashMap<String, String>>,
     pub(crate) named_windows: PlHashMap<String, WindowSpec>,
@@ -159,7 +162,9 @@ impl Default for SQLContext {
         Self {
             function_registry: Arc::new(DefaultFunctionRegistry {}),
             table_map: Default::default(),
+            catalogs: Default::default(),
             cte_map: Default::default(),
+            catalog_tables: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
             named_windows: Default::default(),
@@ -212,6 +217,18 @@ impl SQLContext {
         self.table_map.write().unwrap().remove(&name.to_owned());
     }
 
+    /// Register a [`Catalog`] in the SQLContext, whose tables can then be queried as
+    /// `name.namespace.table`. Catalogs registered with [`register_catalog`] can be queried
+    /// without registering them in the SQLContext.
+    pub fn register_catalog(&self, name: &str, catalog: Arc<dyn Catalog>) {
+        self.catalogs.write().unwrap().insert(name.into(), catalog);
+    }
+
+    /// Unregister a [`Catalog`] from the [`SQLContext`].
+    pub fn unregister_catalog(&self, name: &str) {
+        self.catalogs.write().unwrap().remove(name);
+    }
+
     /// Execute a SQL query, returning a [`LazyFrame`].
     /// ```rust
     /// # use polars_sql::SQLContext;
@@ -254,6 +271,7 @@ impl SQLContext {
 
         // Every execution should clear the statement-level maps.
         self.cte_map.clear();
+        self.catalog_tables.clear();
         self.table_aliases.clear();
         self.joined_aliases.clear();
         self.named_windows.clear();
@@ -284,6 +302,7 @@ impl SQLContext {
         Self {
             // Deep clone to isolate
             table_map: Arc::new(RwLock::new(self.table_map.read().unwrap().clone())),
+            catalogs: self.catalogs.clone(),
             named_windows: self.named_windows.clone(),
             cte_map: self.cte_map.clone(),
 
@@ -336,6 +355,7 @@ impl SQLContext {
             .get(name)
             .cloned()
             .or_else(|| self.cte_map.get(name).cloned())
+            .or_else(|| self.catalog_tables.get(name).cloned())
             .or_else(|| {
                 self.table_aliases.get(name).and_then(|alias| {
                     self.table_map
@@ -343,6 +363,7 @@ impl SQLContext {
                         .unwrap()
                         .get(alias.as_str())
                         .or_else(|| self.cte_map.get(alias.as_str()))
+                        .or_else(|| self.catalog_tables.get(alias.as_str()))
                         .cloned()
                 })
             })
@@ -1774,6 +1795,22 @@ impl SQLContext {
         }
     }
 
+    /// Resolve a table name of multiple parts with the catalog that is named by the first part.
+    fn get_table_from_catalog(&self, name: &ObjectName) -> PolarsResult<Option<LazyFrame>> {
+        if name.0.len() < 2 {
+            return Ok(None);
+        }
+        let Some(parts) = name
+            .0
+            .iter()
+            .map(|part| Some(PlSmallStr::from_str(&part.as_ident()?.value)))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Ok(None);
+        };
+        resolve_catalog_table(&parts, &self.catalogs.read().unwrap())
+    }
+
     fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
@@ -1782,8 +1819,19 @@ impl SQLContext {
                 if let Some(args) = args {
                     return self.execute_table_function(name, alias, &args.args);
                 }
-                let tbl_name = name.0.first().unwrap().as_ident().unwrap().value.as_str();
-                if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
+                let (tbl_name, lf) = match self.get_table_from_catalog(name)? {
+                    Some(lf) => {
+                        // Catalog tables can be referred to by their name without namespace.
+                        let tbl_name = name.0.last().unwrap().as_ident().unwrap().value.as_str();
+                        self.catalog_tables.insert(tbl_name.to_string(), lf.clone());
+                        (tbl_name, Some(lf))
+                    },
+                    None => {
+                        let tbl_name = name.0.first().unwrap().as_ident().unwrap().value.as_str();
+                        (tbl_name, self.get_table_from_current_scope(tbl_name))
+                    },
+                };
+                if let Some(lf) = lf {
                     match alias {
                         Some(alias) => {
                             self.table_aliases
@@ -593,3 +593,64 @@ fn test_compound_invalid_2() {
     let sql = "SELECT * FROM df1 INNER JOIN df2 ON df1.a = df2.a AND b";
     let _ = ctx.execute(sql).unwrap();
 }
+
+struct TestCatalog {
+    df: DataFrame,
+}
+
+impl Catalog for TestCatalog {
+    fn list_tables(&self, namespace: &[PlSmallStr]) -> PolarsResult<Vec<PlSmallStr>> {
+        Ok(match namespace {
+            [ns] if ns == "sales" => vec!["orders".into()],
+            _ => vec![],
+        })
+    }
+
+    fn resolve_table(&self, name: &[PlSmallStr]) -> PolarsResult<Option<LazyFrame>> {
+        Ok(match name {
+            [ns, tbl] if ns == "sales" && tbl == "orders" => Some(self.df.clone().lazy()),
+            _ => None,
+        })
+    }
+}
+
+#[test]
+fn test_catalog_tables() -> PolarsResult<()> {
+    let df = df! {
+        "id" => [1i64, 2, 3],
+        "amount" => [10i64, 20, 30],
+    }?;
+    let catalog: Arc<dyn Catalog> = Arc::new(TestCatalog { df: df.clone() });
+    assert_eq!(
+        catalog.table_schema(&["sales".into(), "orders".into()])?,
+        Some(df.schema().clone())
+    );
+
+    let mut ctx = create_ctx();
+    ctx.register_catalog("db", catalog.clone());
+    let actual = ctx
+        .execute("SELECT o.amount FROM db.sales.orders AS o WHERE o.id > 1")?
+        .collect()?;
+    assert!(actual.equals(&df! { "amount" => [20i64, 30] }?));
+
+    let actual = ctx
+        .execute("SELECT orders.amount FROM db.sales.orders ORDER BY id DESC LIMIT 1")?
+        .collect()?;
+    assert!(actual.equals(&df! { "amount" => [30i64] }?));
+
+    let actual = ctx.execute("SELECT * FROM db.sales.missing");
+    assert!(actual.is_err());
+
+    // Globally registered catalogs are resolved by scans and by SQL.
+    register_catalog("global_db", catalog)?;
+    let actual = LazyFrame::scan_table("global_db.sales.orders")?.collect()?;
+    assert!(actual.equals(&df));
+    let actual = SQLContext::new()
+        .execute("SELECT * FROM global_db.sales.orders")?
+        .collect()?;
+    assert!(actual.equals(&df));
+    assert!(LazyFrame::scan_table("other_db.sales.orders").is_err());
+    assert!(LazyFrame::scan_table("orders").is_err());
+    unregister_catalog("global_db");
+    Ok(())
+}
@@ -15,6 +15,7 @@ use tokio::task::JoinHandle;
 use crate::async_executor;
 use crate::graph::{Graph, GraphNode, GraphNodeKey, LogicalPipeKey, PortState};
 use crate::metrics::{GraphMetrics, MetricsBuilder};
+use crate::morsel::{MorselSizer, MorselSizing};
 use crate::pipe::PhysicalPipe;
 
 #[derive(Clone)]
@@ -25,6 +26,9 @@ pub struct StreamingExecutionState {
     /// The ExecutionState passed to any non-streaming operations.
     pub in_memory_exec_state: ExecutionState,
 
+    /// The ideal morsel size of the query.
+    pub morsel_sizer: Arc<MorselSizer>,
+
     query_tasks_send: Sender<JoinHandle<PolarsResult<()>>>,
     subphase_tasks_send: Sender<JoinHandle<PolarsResult<()>>>,
 }
@@ -304,6 +308,7 @@ fn run_subgraph(
 pub fn execute_graph(
     graph: &mut Graph,
     metrics: Option<Arc<Mutex<GraphMetrics>>>,
+    morsel_sizing: MorselSizing,
 ) -> PolarsResult<SparseSecondaryMap<GraphNodeKey, DataFrame>> {
     // Get the number of threads from the rayon thread-pool as that respects our config.
     let num_pipelines = POOL.current_num_threads();
@@ -315,6 +320,7 @@ pub fn execute_graph(
     let state = StreamingExecutionState {
         num_pipelines,
         in_memory_exec_state: ExecutionState::default(),
+        morsel_sizer: Arc::new(MorselSizer::new(morsel_sizing)),
         query_tasks_send,
         subphase_tasks_send,
     };
@@ -12,10 +12,11 @@ pub use dispatch::build_streaming_query_executor;
 pub(crate) mod expression;
 mod graph;
 pub use graph::{GraphNodeKey, LogicalPipe, LogicalPipeKey};
-pub use skeleton::StreamingQuery;
+pub use skeleton::{LoweredQuery, StreamingQuery};
 mod metrics;
 pub use metrics::{GraphMetrics, NodeMetrics};
 mod morsel;
+pub use morsel::MorselSizing;
 mod nodes;
 mod physical_plan;
 pub use physical_plan::{NodeStyle, PhysNode, PhysNodeKey, PhysNodeKind, ZipBehavior};
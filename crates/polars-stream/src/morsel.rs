@@ -1,16 +1,98 @@
 use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
+pub use polars_config::MorselSizing;
 use polars_core::frame::DataFrame;
 use polars_ooc::{AccessPattern, Token, mm};
 use polars_utils::relaxed_cell::RelaxedCell;
 
 use crate::async_primitives::wait_group::WaitToken;
 
+/// The ideal morsel size from the config. Nodes that have access to the
+/// [`StreamingExecutionState`](crate::execute::StreamingExecutionState) should use its
+/// [`MorselSizer`] instead, which respects the sizing of the query.
 pub fn get_ideal_morsel_size() -> usize {
     polars_config::config().ideal_morsel_size() as usize
 }
 
+/// Morsels of more bytes than this are shrunk by adaptive sizing.
+const TARGET_MORSEL_BYTES: usize = 16 * 1024 * 1024;
+/// Above this fraction of the memory budget adaptive sizing shrinks the morsels.
+const HIGH_MEMORY_PRESSURE: f64 = 0.8;
+/// Morsels consumed faster than this are grown by adaptive sizing.
+const FAST_MORSEL: Duration = Duration::from_millis(1);
+/// Morsels consumed slower than this are shrunk by adaptive sizing.
+const SLOW_MORSEL: Duration = Duration::from_millis(100);
+/// How far adaptive sizing may shrink or grow the morsels from the initial size.
+const MAX_ADAPTIVE_FACTOR: usize = 64;
+
+/// Tracks the ideal morsel size of a query, shared by all its nodes.
+#[derive(Debug)]
+pub struct MorselSizer {
+    sizing: MorselSizing,
+    size: RelaxedCell<usize>,
+}
+
+impl MorselSizer {
+    pub fn new(sizing: MorselSizing) -> Self {
+        Self {
+            sizing,
+            size: RelaxedCell::new_usize(sizing.initial_size()),
+        }
+    }
+
+    pub fn is_adaptive(&self) -> bool {
+        self.sizing.is_adaptive()
+    }
+
+    /// The number of rows the next morsel should have.
+    pub fn ideal_morsel_size(&self) -> usize {
+        self.size.load()
+    }
+
+    /// Report that a morsel of `rows` rows and `bytes` bytes took `elapsed` to be consumed,
+    /// adapting the ideal morsel size if the sizing is adaptive.
+    pub fn observe(&self, rows: usize, bytes: usize, elapsed: Duration) {
+        if !self.is_adaptive() || rows == 0 {
+            return;
+        }
+        let next = next_morsel_size(
+            self.sizing.initial_size(),
+            self.size.load(),
+            rows,
+            bytes,
+            elapsed,
+            mm().memory_pressure(),
+        );
+        self.size.store(next);
+    }
+}
+
+/// The morsel size after a morsel of `rows` rows and `bytes` bytes was consumed in `elapsed`.
+fn next_morsel_size(
+    initial: usize,
+    current: usize,
+    rows: usize,
+    bytes: usize,
+    elapsed: Duration,
+    memory_pressure: f64,
+) -> usize {
+    let min_size = (initial / MAX_ADAPTIVE_FACTOR).max(1);
+    let max_size = initial.saturating_mul(MAX_ADAPTIVE_FACTOR);
+    let bytes_per_row = bytes.div_ceil(rows).max(1);
+    let max_size_by_bytes = (TARGET_MORSEL_BYTES / bytes_per_row).max(1);
+
+    let next = if memory_pressure > HIGH_MEMORY_PRESSURE || elapsed > SLOW_MORSEL {
+        current / 2
+    } else if elapsed < FAST_MORSEL {
+        current.saturating_mul(2)
+    } else {
+        current
+    };
+    next.min(max_size_by_bytes).clamp(min_size, max_size)
+}
+
 /// A token indicating the order of morsels in a stream.
 ///
 /// The sequence tokens going through a pipe are monotonely non-decreasing and are allowed to be
@@ -187,3 +269,27 @@ impl Morsel {
         (seq, token)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_morsel_size() {
+        let next = |current, bytes, elapsed, memory_pressure| {
+            let elapsed = Duration::from_micros(elapsed);
+            next_morsel_size(1000, current, 100, bytes, elapsed, memory_pressure)
+        };
+        // Fast and small morsels grow, up to the maximum factor.
+        assert_eq!(next(1000, 800, 500, 0.0), 2000);
+        assert_eq!(next(64_000, 800, 500, 0.0), 64_000);
+        // Slow morsels shrink, down to the minimum factor.
+        assert_eq!(next(1000, 800, 200_000, 0.0), 500);
+        assert_eq!(next(16, 800, 200_000, 0.0), 15);
+        // Memory pressure shrinks the morsels.
+        assert_eq!(next(1000, 800, 10_000, 0.9), 500);
+        assert_eq!(next(1000, 800, 10_000, 0.5), 1000);
+        // Wide rows cap the morsel size.
+        assert_eq!(next(1000, 100 * 1024 * 1024, 500, 0.0), 16);
+    }
+}
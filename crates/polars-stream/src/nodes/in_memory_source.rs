@@ -1,15 +1,23 @@
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+
+use parking_lot::Mutex;
 
 use super::compute_node_prelude::*;
 use crate::async_primitives::wait_group::WaitGroup;
-use crate::morsel::{MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::morsel::{MorselSeq, SourceToken};
 
 #[derive(Debug)]
 pub struct InMemorySourceNode {
     source: Option<Arc<DataFrame>>,
     morsel_size: usize,
     seq: AtomicU64,
+    /// The sequence number and row offset of the next morsel if the morsel size is taken from
+    /// the adaptive sizer of the query. Morsels then differ in size, so the offset can't be
+    /// derived from the sequence number.
+    adaptive_cursor: Option<Mutex<(u64, usize)>>,
+    bytes_per_row: usize,
     seq_offset: MorselSeq,
 }
 
@@ -19,6 +27,8 @@ impl InMemorySourceNode {
             source: Some(source),
             morsel_size: 0,
             seq: AtomicU64::new(0),
+            adaptive_cursor: None,
+            bytes_per_row: 0,
             seq_offset,
         }
     }
@@ -30,6 +40,8 @@ impl InMemorySourceNode {
             source: Some(source),
             morsel_size,
             seq: AtomicU64::new(0),
+            adaptive_cursor: None,
+            bytes_per_row: 0,
             seq_offset,
         }
     }
@@ -50,11 +62,16 @@ impl ComputeNode for InMemorySourceNode {
         assert!(send.len() == 1);
 
         if self.morsel_size == 0 {
-            let len = self.source.as_ref().unwrap().height();
-            let ideal_morsel_count = (len / get_ideal_morsel_size()).max(1);
+            let source = self.source.as_ref().unwrap();
+            let len = source.height();
+            let ideal_morsel_size = state.morsel_sizer.ideal_morsel_size();
+            let ideal_morsel_count = (len / ideal_morsel_size).max(1);
             let morsel_count = ideal_morsel_count.next_multiple_of(state.num_pipelines);
             self.morsel_size = len.div_ceil(morsel_count).max(1);
-            self.seq = AtomicU64::new(0);
+            if state.morsel_sizer.is_adaptive() {
+                self.adaptive_cursor = Some(Mutex::new((0, 0)));
+                self.bytes_per_row = source.estimated_size() / len.max(1);
+            }
         }
 
         // As a temporary hack for some nodes (like the FunctionIR::FastCount)
@@ -62,8 +79,13 @@ impl ComputeNode for InMemorySourceNode {
         // morsel.
         // TODO: remove this hack.
         let exhausted = if let Some(src) = &self.source {
-            let seq = self.seq.load(Ordering::Relaxed);
-            seq > 0 && seq * self.morsel_size as u64 >= src.height() as u64
+            if let Some(cursor) = &self.adaptive_cursor {
+                let (seq, offset) = *cursor.lock();
+                seq > 0 && offset >= src.height()
+            } else {
+                let seq = self.seq.load(Ordering::Relaxed);
+                seq > 0 && seq * self.morsel_size as u64 >= src.height() as u64
+            }
         } else {
             true
         };
@@ -81,7 +103,7 @@ impl ComputeNode for InMemorySourceNode {
         scope: &'s TaskScope<'s, 'env>,
         recv_ports: &mut [Option<RecvPort<'_>>],
         send_ports: &mut [Option<SendPort<'_>>],
-        _state: &'s StreamingExecutionState,
+        state: &'s StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
         assert!(recv_ports.is_empty() && send_ports.len() == 1);
@@ -96,9 +118,18 @@ impl ComputeNode for InMemorySourceNode {
             join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
                 let wait_group = WaitGroup::default();
                 loop {
-                    let seq = slf.seq.fetch_add(1, Ordering::Relaxed);
-                    let offset = (seq as usize * slf.morsel_size) as i64;
-                    let df = source.slice(offset, slf.morsel_size);
+                    let (seq, df) = if let Some(cursor) = &slf.adaptive_cursor {
+                        let morsel_size = state.morsel_sizer.ideal_morsel_size();
+                        let mut cursor = cursor.lock();
+                        let (seq, offset) = *cursor;
+                        let df = source.slice(offset as i64, morsel_size);
+                        *cursor = (seq + 1, offset + df.height());
+                        (seq, df)
+                    } else {
+                        let seq = slf.seq.fetch_add(1, Ordering::Relaxed);
+                        let offset = (seq as usize * slf.morsel_size) as i64;
+                        (seq, source.slice(offset, slf.morsel_size))
+                    };
 
                     // TODO: remove this 'always sent at least one morsel'
                     // condition, see update_state.
@@ -106,14 +137,21 @@ impl ComputeNode for InMemorySourceNode {
                         break;
                     }
 
+                    let rows = df.height();
                     let morsel_seq = MorselSeq::new(seq).offset_by(slf.seq_offset);
                     let mut morsel = Morsel::new(df, morsel_seq, source_token.clone());
                     morsel.set_consume_token(wait_group.token());
+                    let start = Instant::now();
                     if send.send(morsel).await.is_err() {
                         break;
                     }
 
                     wait_group.wait().await;
+                    if slf.adaptive_cursor.is_some() {
+                        state
+                            .morsel_sizer
+                            .observe(rows, rows * slf.bytes_per_row, start.elapsed());
+                    }
                     if source_token.stop_requested() {
                         break;
                     }
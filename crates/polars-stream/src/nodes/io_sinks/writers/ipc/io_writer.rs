@@ -35,6 +35,15 @@ impl IOWriter {
 
         let (file, sync_on_close) = file.await?;
 
+        let schema = match &options.arrow_schema {
+            Some(arrow_schema) => Arc::clone(arrow_schema),
+            None => Arc::new(schema_to_arrow_checked(
+                &schema,
+                options.compat_level,
+                "ipc",
+            )?),
+        };
+
         match file {
             Writeable::Cloud(cloudwriter) => {
                 // The zero-copy implementation takes ownership of the encoded data and, after
@@ -53,7 +62,6 @@ impl IOWriter {
                 block_offsets += offset;
 
                 // Add schema message.
-                let schema = schema_to_arrow_checked(&schema, options.compat_level, "ipc")?;
                 let encoded_data = EncodedDataBytes {
                     ipc_message: Bytes::from(schema_to_bytes(&schema, &ipc_fields, None)),
                     arrow_data: Bytes::new(),
@@ -127,7 +135,7 @@ impl IOWriter {
                     .with_compression(options.compression)
                     .with_compat_level(options.compat_level)
                     .with_parallel(false)
-                    .batched(&schema, ipc_fields)?;
+                    .batched_arrow(schema, ipc_fields)?;
 
                 while let Some(batch) = ipc_batch_rx.recv().await {
                     match batch {
@@ -50,7 +50,7 @@ pub fn create_file_writer_starter(
         #[cfg(feature = "ipc")]
         FileWriteFormat::Ipc(options) => {
             Arc::new(crate::nodes::io_sinks::writers::ipc::IpcWriterStarter {
-                options: Arc::new(*options),
+                options: Arc::new(options.clone()),
                 schema: file_schema.clone(),
                 record_batch_size: options
                     .record_batch_size
@@ -125,6 +125,7 @@ impl FileWriterStarter for NDJsonWriterStarter {
                 reuse_serializer_rx,
                 max_serializers,
                 base_allocation_size,
+                serialize_options: self.options.serialize_options,
             }
             .run(),
         );
@@ -3,6 +3,7 @@ use arrow::datatypes::{ArrowDataType, Field as ArrowField};
 use polars_core::frame::DataFrame;
 use polars_core::prelude::CompatLevel;
 use polars_error::PolarsResult;
+use polars_io::json::JsonSerializeOptions;
 
 use crate::async_executor::{self, TaskPriority};
 use crate::async_primitives::connector;
@@ -18,6 +19,7 @@ pub struct MorselSerializerPipeline {
     pub reuse_serializer_rx: tokio::sync::mpsc::Receiver<MorselSerializer>,
     pub max_serializers: usize,
     pub base_allocation_size: usize,
+    pub serialize_options: JsonSerializeOptions,
 }
 
 impl MorselSerializerPipeline {
@@ -28,6 +30,7 @@ impl MorselSerializerPipeline {
             mut reuse_serializer_rx,
             max_serializers,
             base_allocation_size,
+            serialize_options,
         } = self;
 
         let mut num_created_serializers: usize = 0;
@@ -41,6 +44,7 @@ impl MorselSerializerPipeline {
                     MorselSerializer {
                         serialized_data: vec![],
                         allocation_size: base_allocation_size,
+                        serialize_options,
                     }
                 } else if let Some(serializer) = reuse_serializer_rx.recv().await {
                     serializer
@@ -69,6 +73,7 @@ impl MorselSerializerPipeline {
 pub struct MorselSerializer {
     pub serialized_data: Vec<u8>,
     allocation_size: usize,
+    serialize_options: JsonSerializeOptions,
 }
 
 impl MorselSerializer {
@@ -76,6 +81,7 @@ impl MorselSerializer {
         let MorselSerializer {
             serialized_data,
             allocation_size,
+            serialize_options,
         } = &mut self;
 
         rechunk_par(unsafe { df.columns_mut_retain_schema() }).await;
@@ -99,7 +105,8 @@ impl MorselSerializer {
             .unzip();
 
         let array = StructArray::new(ArrowDataType::Struct(fields), height, arrays, None);
-        let serializer = polars_json::json::write::new_serializer(&array, 0, usize::MAX);
+        let serializer =
+            polars_json::json::write::new_serializer(&array, 0, usize::MAX, *serialize_options);
 
         serializer.serialize_json_lines_to_vec(serialized_data, height);
         *allocation_size = usize::max(*allocation_size, serialized_data.capacity());
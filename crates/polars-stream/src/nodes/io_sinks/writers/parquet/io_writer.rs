@@ -5,7 +5,10 @@ use polars_buffer::Buffer;
 use polars_error::PolarsResult;
 use polars_io::parquet::write::BatchedWriter;
 use polars_io::prelude::KeyValueMetadata;
-use polars_parquet::write::{Encoding, FileWriter, SchemaDescriptor, WriteOptions};
+use polars_parquet::write::{
+    CompressionOptions, Encoding, FileEncryptionProperties, FileWriter, SchemaDescriptor,
+    WriteOptions,
+};
 
 use crate::async_executor::{self};
 use crate::nodes::io_sinks::writers::interface::FileOpenTaskHandle;
@@ -20,7 +23,9 @@ pub struct IOWriter {
     pub schema_descriptor: Arc<SchemaDescriptor>,
     pub write_options: WriteOptions,
     pub encodings: Buffer<Vec<Encoding>>,
+    pub compressions: Buffer<CompressionOptions>,
     pub key_value_metadata: Option<KeyValueMetadata>,
+    pub encryption: Option<Arc<FileEncryptionProperties>>,
     pub num_leaf_columns: usize,
 }
 
@@ -33,34 +38,50 @@ impl IOWriter {
             schema_descriptor,
             write_options,
             encodings,
+            compressions,
             key_value_metadata,
+            encryption,
             num_leaf_columns,
         } = self;
 
         let (mut file, sync_on_close) = file.await?;
         let mut buffered_file = file.as_buffered();
 
+        let mut file_writer = FileWriter::new_with_parquet_schema(
+            &mut *buffered_file,
+            Arc::unwrap_or_clone(arrow_schema),
+            Arc::unwrap_or_clone(schema_descriptor),
+            write_options,
+        );
+        if let Some(encryption) = encryption {
+            file_writer = file_writer.with_encryption(encryption)?;
+        }
+
         let mut parquet_writer = BatchedWriter::new(
-            std::sync::Mutex::new(FileWriter::new_with_parquet_schema(
-                &mut *buffered_file,
-                Arc::unwrap_or_clone(arrow_schema),
-                Arc::unwrap_or_clone(schema_descriptor),
-                write_options,
-            )),
+            std::sync::Mutex::new(file_writer),
             encodings,
+            compressions,
             write_options,
             false,
             key_value_metadata,
+            None,
         );
 
         while let Some(handle) = encoded_row_group_rx.recv().await {
             let EncodedRowGroup {
                 num_rows,
                 data,
+                bloom_filters,
+                sorting_columns,
                 morsel_permit,
             } = handle.await?;
             assert_eq!(data.len(), num_leaf_columns);
-            parquet_writer.write_row_group(num_rows as u64, &data)?;
+            parquet_writer.write_row_group(
+                num_rows as u64,
+                &data,
+                bloom_filters,
+                sorting_columns,
+            )?;
             drop(data);
             drop(morsel_permit);
         }
@@ -4,9 +4,12 @@ use arrow::datatypes::ArrowSchemaRef;
 use polars_buffer::Buffer;
 use polars_error::PolarsResult;
 use polars_io::pl_async;
-use polars_io::prelude::{ParquetWriteOptions, get_encodings};
+use polars_io::prelude::{
+    ParquetBloomFilters, ParquetWriteOptions, get_compressions, get_encodings, get_parquet_schema,
+};
 use polars_parquet::write::{
-    CompressedPage, Encoding, SchemaDescriptor, Version, WriteOptions, to_parquet_schema,
+    BloomFilterBitset, CompressedPage, CompressionOptions, Encoding, SchemaDescriptor,
+    SortingColumn, Version, WriteOptions,
 };
 use polars_utils::IdxSize;
 use polars_utils::index::NonZeroIdxSize;
@@ -35,12 +38,18 @@ pub struct ParquetWriterStarter {
 #[derive(Clone)]
 pub struct InitializedState {
     encodings: Buffer<Vec<Encoding>>,
+    compressions: Buffer<CompressionOptions>,
     schema_descriptor: Arc<SchemaDescriptor>,
+    bloom_filters: Option<Arc<ParquetBloomFilters>>,
 }
 
 struct EncodedRowGroup {
     num_rows: usize,
     data: Vec<Vec<CompressedPage>>,
+    /// Empty if no bloom filters are written.
+    bloom_filters: Vec<BloomFilterBitset>,
+    /// The columns the rows are known to be sorted by.
+    sorting_columns: Option<Vec<SortingColumn>>,
     morsel_permit: SinkMorselPermit,
 }
 
@@ -83,17 +92,41 @@ impl FileWriterStarter for ParquetWriterStarter {
     ) -> PolarsResult<async_executor::JoinHandle<PolarsResult<()>>> {
         let InitializedState {
             encodings,
+            compressions,
             schema_descriptor,
+            bloom_filters,
         } = {
             let mut initialized_state = self.initialized_state.lock().unwrap();
 
             if initialized_state.is_none() {
-                let schema_descriptor = Arc::new(to_parquet_schema(&self.arrow_schema)?);
-                let encodings = get_encodings(&self.arrow_schema);
+                let schema_descriptor = Arc::new(get_parquet_schema(
+                    &self.arrow_schema,
+                    &self.options.uuid_columns,
+                )?);
+                let encodings = get_encodings(
+                    &self.arrow_schema,
+                    &self.options.byte_stream_split,
+                    &self.options.uuid_columns,
+                    &self.options.column_options,
+                )?;
+                let compressions = get_compressions(
+                    &self.arrow_schema,
+                    self.options.compression.into(),
+                    &self.options.column_options,
+                )?;
+                let bloom_filters = self
+                    .options
+                    .bloom_filter
+                    .as_ref()
+                    .map(|options| ParquetBloomFilters::try_new(options, &self.arrow_schema))
+                    .transpose()?
+                    .map(Arc::new);
 
                 *initialized_state = Some(InitializedState {
                     encodings,
+                    compressions,
                     schema_descriptor,
+                    bloom_filters,
                 })
             };
 
@@ -110,6 +143,7 @@ impl FileWriterStarter for ParquetWriterStarter {
             compression: self.options.compression.into(),
             version: Version::V1,
             data_page_size: self.options.data_page_size,
+            data_page_row_limit: self.options.data_page_row_limit,
         };
 
         let arrow_schema = Arc::clone(&self.arrow_schema);
@@ -124,7 +158,9 @@ impl FileWriterStarter for ParquetWriterStarter {
                     schema_descriptor: Arc::clone(&schema_descriptor),
                     write_options,
                     encodings: Buffer::clone(&encodings),
+                    compressions: Buffer::clone(&compressions),
                     key_value_metadata,
+                    encryption: self.options.encryption.clone(),
                     num_leaf_columns,
                 }
                 .run(),
@@ -141,6 +177,8 @@ impl FileWriterStarter for ParquetWriterStarter {
                 schema_descriptor,
                 write_options,
                 encodings,
+                compressions,
+                bloom_filters,
                 num_leaf_columns,
             }
             .run(),
@@ -4,10 +4,13 @@ use std::sync::Arc;
 use arrow::datatypes::ArrowSchemaRef;
 use polars_buffer::Buffer;
 use polars_error::PolarsResult;
+use polars_io::parquet::write::sorting_columns;
+use polars_io::prelude::ParquetBloomFilters;
 use polars_parquet::parquet::error::ParquetResult;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
-    CompressedPage, Compressor, Encoding, SchemaDescriptor, WriteOptions, array_to_columns,
+    BloomFilterBitset, CompressedPage, CompressionOptions, Compressor, Encoding, SchemaDescriptor,
+    WriteOptions, array_to_columns,
 };
 use polars_utils::UnitVec;
 
@@ -26,6 +29,9 @@ pub struct RowGroupEncoder {
     pub schema_descriptor: Arc<SchemaDescriptor>,
     pub write_options: WriteOptions,
     pub encodings: Buffer<Vec<Encoding>>,
+    /// The compression of every top-level column, which overrides `write_options.compression`.
+    pub compressions: Buffer<CompressionOptions>,
+    pub bloom_filters: Option<Arc<ParquetBloomFilters>>,
     pub num_leaf_columns: usize,
 }
 
@@ -38,6 +44,8 @@ impl RowGroupEncoder {
             schema_descriptor,
             write_options,
             encodings,
+            compressions,
+            bloom_filters,
             num_leaf_columns,
         } = self;
 
@@ -45,13 +53,17 @@ impl RowGroupEncoder {
             let arrow_schema = Arc::clone(&arrow_schema);
             let schema_descriptor = Arc::clone(&schema_descriptor);
             let encodings = Buffer::clone(&encodings);
+            let compressions = Buffer::clone(&compressions);
+            let bloom_filters = bloom_filters.clone();
 
             let row_group_encode_handle = async_executor::AbortOnDropHandle::new(
                 async_executor::spawn(TaskPriority::High, async move {
                     let (df, morsel_permit) = morsel.into_inner();
                     let num_rows = df.height();
+                    let sorting_columns = sorting_columns(df.columns(), schema_descriptor.fields());
 
                     let mut data: Vec<Vec<CompressedPage>> = Vec::with_capacity(num_leaf_columns);
+                    let mut row_group_bloom_filters: Vec<BloomFilterBitset> = Vec::new();
 
                     for fut in parallelize_first_to_local(
                         TaskPriority::High,
@@ -59,6 +71,11 @@ impl RowGroupEncoder {
                             let arrow_schema = Arc::clone(&arrow_schema);
                             let schema_descriptor = Arc::clone(&schema_descriptor);
                             let encodings = Buffer::clone(&encodings);
+                            let bloom_filters = bloom_filters.clone();
+                            let write_options = WriteOptions {
+                                compression: compressions[i],
+                                ..write_options
+                            };
 
                             async move {
                                 let parquet_type = &schema_descriptor.fields()[i];
@@ -70,6 +87,17 @@ impl RowGroupEncoder {
                                         true,
                                     )?;
 
+                                let column_bloom_filters =
+                                    bloom_filters
+                                        .as_ref()
+                                        .map_or_else(Vec::new, |bloom_filters| {
+                                            bloom_filters.column_bloom_filters(
+                                                i,
+                                                array.as_ref(),
+                                                parquet_type,
+                                            )
+                                        });
+
                                 let mut data: UnitVec<Vec<CompressedPage>> =
                                     UnitVec::with_capacity(num_leaf_columns);
 
@@ -96,16 +124,20 @@ impl RowGroupEncoder {
                                     data.push(compressed_pages)
                                 }
 
-                                PolarsResult::Ok(data)
+                                PolarsResult::Ok((data, column_bloom_filters))
                             }
                         }),
                     ) {
-                        data.extend(fut.await?);
+                        let (column_data, column_bloom_filters) = fut.await?;
+                        data.extend(column_data);
+                        row_group_bloom_filters.extend(column_bloom_filters);
                     }
 
                     Ok(EncodedRowGroup {
                         num_rows,
                         data,
+                        bloom_filters: row_group_bloom_filters,
+                        sorting_columns,
                         morsel_permit,
                     })
                 }),
@@ -12,6 +12,7 @@ use super::{DynByteSourceBuilder, IpcFileReader};
 use crate::async_primitives::wait_group::WaitGroup;
 #[cfg(feature = "ipc")]
 use crate::metrics::IOMetrics;
+use crate::morsel::MorselSizer;
 use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
 use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
 use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
@@ -23,6 +24,8 @@ pub struct IpcReaderBuilder {
     pub prefetch_semaphore: std::sync::OnceLock<Arc<tokio::sync::Semaphore>>,
     pub shared_prefetch_wait_group_slot: Arc<std::sync::Mutex<Option<WaitGroup>>>,
     pub io_metrics: std::sync::OnceLock<Arc<IOMetrics>>,
+    /// Sizes the morsels of the files. Set from the execution state of the query.
+    pub morsel_sizer: std::sync::OnceLock<Arc<MorselSizer>>,
 }
 
 impl std::fmt::Debug for IpcReaderBuilder {
@@ -60,6 +63,9 @@ impl FileReaderBuilder for IpcReaderBuilder {
             .max(1);
 
         self.prefetch_limit.store(prefetch_limit);
+        let _ = self
+            .morsel_sizer
+            .set(execution_state.morsel_sizer.clone());
 
         if config::verbose() {
             eprintln!(
@@ -117,6 +123,7 @@ impl FileReaderBuilder for IpcReaderBuilder {
                 current_all_spawned: None,
             },
             io_metrics: OptIOMetrics(self.io_metrics.get().cloned()),
+            morsel_sizer: self.morsel_sizer.get().cloned(),
             verbose,
             init_data: None,
             checked: self.options.checked,
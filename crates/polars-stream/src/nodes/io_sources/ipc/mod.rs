@@ -2,6 +2,7 @@ use std::io::Cursor;
 use std::ops::Range;
 use std::sync::Arc;
 
+use arrow::datatypes::ArrowSchemaRef;
 use arrow::io::ipc::read::{Dictionaries, read_dictionary_block};
 use async_trait::async_trait;
 use polars_core::prelude::DataType;
@@ -30,7 +31,7 @@ use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
 use crate::async_executor::{self, JoinHandle, TaskPriority};
 use crate::async_primitives::wait_group::{WaitGroup, WaitToken};
 use crate::metrics::OptIOMetrics;
-use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::morsel::{Morsel, MorselSeq, MorselSizer, SourceToken, get_ideal_morsel_size};
 use crate::nodes::io_sources::ipc::metadata::read_ipc_metadata_bytes;
 use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
 use crate::nodes::io_sources::multi_scan::reader_interface::{
@@ -58,6 +59,8 @@ struct IpcFileReader {
     byte_source_builder: DynByteSourceBuilder,
     record_batch_prefetch_sync: RecordBatchPrefetchSync,
     io_metrics: OptIOMetrics,
+    /// Sizes the morsels. Falls back to the ideal morsel size of the config if unset.
+    morsel_sizer: Option<Arc<MorselSizer>>,
     verbose: bool,
     init_data: Option<InitializedState>,
     checked: UnsafeBool,
@@ -300,7 +303,10 @@ impl FileReader for IpcFileReader {
             .max(1);
 
         let io_runtime = polars_io::pl_async::get_runtime();
-        let ideal_morsel_size = get_ideal_morsel_size();
+        let ideal_morsel_size = self
+            .morsel_sizer
+            .as_ref()
+            .map_or_else(get_ideal_morsel_size, |sizer| sizer.ideal_morsel_size());
 
         if verbose {
             eprintln!(
@@ -495,6 +501,17 @@ impl FileReader for IpcFileReader {
             async_executor::spawn(TaskPriority::Low, async move { handle.await.unwrap() }),
         ))
     }
+
+    async fn file_arrow_schema(&mut self) -> PolarsResult<Option<ArrowSchemaRef>> {
+        Ok(Some(
+            self.init_data
+                .as_ref()
+                .unwrap()
+                .file_metadata
+                .schema
+                .clone(),
+        ))
+    }
 }
 
 async fn read_dictionaries(
@@ -2,14 +2,16 @@
 use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{AnyValue, Column, DataType};
+use polars_core::prelude::{AnyValue, Column, DataType, IdxCa, IntoColumn};
 use polars_core::scalar::Scalar;
 use polars_core::schema::SchemaRef;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, feature_gated};
 use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
-use polars_plan::dsl::{CastColumnsPolicy, MissingColumnsPolicy, ScanSource};
+use polars_plan::dsl::{CastColumnsPolicy, MissingColumnsPolicy, ProvenanceColumns, ScanSource};
 use polars_plan::plans::hive::HivePartitionsDf;
+use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
 use polars_utils::row_counter::RowCounter;
 use polars_utils::slice_enum::Slice;
 
@@ -39,6 +41,11 @@ pub enum ApplyExtraOps {
         hive_parts: Option<Arc<HivePartitionsDf>>,
         /// E.g. Iceberg deletion files.
         external_filter_mask: Option<ExternalFilterMask>,
+        /// Number of rows in each row group of the file. Only set if a row group index column is
+        /// requested.
+        row_group_row_counts: Option<Vec<IdxSize>>,
+        /// Only set if a file modification time column is requested.
+        file_modification_time_ms: Option<i64>,
     },
 
     /// Note: These fields are ordered according to the order in which they are applied.
@@ -50,6 +57,7 @@ pub enum ApplyExtraOps {
         row_index: Option<(RowIndex, usize)>,
         /// This will have include_file_paths, hive columns, missing columns.
         column_selectors: Option<Vec<ColumnSelector>>,
+        row_position_columns: Option<RowPositionColumns>,
         predicate: Option<ScanIOPredicate>,
     },
 
@@ -57,6 +65,16 @@ pub enum ApplyExtraOps {
     Noop,
 }
 
+/// Provenance columns that depend on the position of the rows within the file.
+#[derive(Debug)]
+pub struct RowPositionColumns {
+    /// `(name, insertion_position)`
+    row_index_in_file: Option<(PlSmallStr, usize)>,
+    /// `(name, insertion_position, row_group_ends)`, where `row_group_ends` holds the cumulative
+    /// row count at the end of each row group.
+    row_group_index: Option<(PlSmallStr, usize, Vec<IdxSize>)>,
+}
+
 impl ApplyExtraOps {
     pub fn variant_name(&self) -> &'static str {
         match self {
@@ -88,12 +106,15 @@ impl ApplyExtraOps {
                         pre_slice,
                         include_file_paths,
                         file_path_col_idx,
+                        provenance,
                         predicate,
                     },
                 scan_source,
                 scan_source_idx,
                 hive_parts,
                 external_filter_mask,
+                row_group_row_counts,
+                file_modification_time_ms,
             } => {
                 // Negative slice should have been resolved earlier.
                 if let Some(Slice::Negative { .. }) = pre_slice {
@@ -108,6 +129,11 @@ impl ApplyExtraOps {
                 // Tracks if the input already has all columns in the right order and type.
                 let mut is_input_passthrough = incoming_schema.len() == final_output_schema.len();
 
+                let mut row_position_columns = RowPositionColumns {
+                    row_index_in_file: None,
+                    row_group_index: None,
+                };
+
                 for (output_index, (output_name, output_dtype)) in
                     final_output_schema.iter().enumerate()
                 {
@@ -139,6 +165,39 @@ impl ApplyExtraOps {
 
                             ColumnSelector::Position(incoming_schema.index_of(output_name).unwrap())
                         }
+                    } else if provenance.file_modification_time.as_ref() == Some(output_name) {
+                        ColumnSelector::Constant(Box::new((
+                            output_name.clone(),
+                            file_modification_time_scalar(file_modification_time_ms),
+                        )))
+                    } else if provenance.row_index_in_file.as_ref() == Some(output_name) {
+                        row_position_columns.row_index_in_file =
+                            Some((output_name.clone(), output_index));
+
+                        // Filled in by us after the selectors are applied. Insert a placeholder column.
+                        ColumnSelector::Constant(Box::new((
+                            output_name.clone(),
+                            Scalar::null(DataType::Null),
+                        )))
+                    } else if provenance.row_group_index.as_ref() == Some(output_name) {
+                        let mut end: IdxSize = 0;
+                        let row_group_ends = row_group_row_counts
+                            .as_deref()
+                            .unwrap()
+                            .iter()
+                            .map(|n| {
+                                end = end.saturating_add(*n);
+                                end
+                            })
+                            .collect();
+
+                        row_position_columns.row_group_index =
+                            Some((output_name.clone(), output_index, row_group_ends));
+
+                        ColumnSelector::Constant(Box::new((
+                            output_name.clone(),
+                            Scalar::null(DataType::Null),
+                        )))
                     } else if let Some(hive_parts) = &hive_parts
                         && let Ok(hive_column) = hive_parts.df().column(output_name)
                     {
@@ -174,15 +233,20 @@ impl ApplyExtraOps {
                             )?
                         }
                     } else {
-                        match &selector_builder.missing_columns_policy {
-                            MissingColumnsPolicy::Insert => ColumnSelector::Constant(Box::new((
-                                output_name.clone(),
-                                projection
-                                    .get_default_value_by_output_name(output_name)
-                                    .cloned()
-                                    .unwrap_or_else(|| Scalar::null(output_dtype.clone())),
-                            ))),
-                            MissingColumnsPolicy::Raise => {
+                        let default_value =
+                            projection.get_default_value_by_output_name(output_name);
+
+                        match (&selector_builder.missing_columns_policy, default_value) {
+                            // A configured default value takes precedence over raising.
+                            (MissingColumnsPolicy::Insert, _) | (_, Some(_)) => {
+                                ColumnSelector::Constant(Box::new((
+                                    output_name.clone(),
+                                    default_value
+                                        .cloned()
+                                        .unwrap_or_else(|| Scalar::null(output_dtype.clone())),
+                                )))
+                            },
+                            (MissingColumnsPolicy::Raise, None) => {
                                 return Err(missing_column_err(output_name));
                             },
                         }
@@ -207,6 +271,9 @@ impl ApplyExtraOps {
                     external_filter_mask,
                     row_index: row_index.map(|ri| (ri, row_index_col_idx)),
                     column_selectors,
+                    row_position_columns: (row_position_columns.row_index_in_file.is_some()
+                        || row_position_columns.row_group_index.is_some())
+                    .then_some(row_position_columns),
                     predicate,
                 };
 
@@ -218,6 +285,7 @@ impl ApplyExtraOps {
                         external_filter_mask: None,
                         row_index: None,
                         column_selectors: None,
+                        row_position_columns: None,
                         predicate: None,
                     } => Self::Noop,
 
@@ -244,6 +312,7 @@ impl ApplyExtraOps {
             external_filter_mask,
             row_index,
             column_selectors,
+            row_position_columns,
             predicate,
         } = ({
             use ApplyExtraOps::*;
@@ -300,9 +369,8 @@ impl ApplyExtraOps {
             *df = unsafe { DataFrame::new_unchecked(df.height(), new_cols) }
         }
 
-        // Note: This branch is hit if we have negative slice or predicate + row index and the reader
-        // does not support them.
-        if let Some((ri, col_idx)) = row_index {
+        // Row position of the first row of `df` relative to the start of the current file.
+        let file_row_offset = || {
             // Adjustment needed for `current_row_position`.
             let local_offset_adjustment = RowCounter::new(
                 // Number of physical rows skipped in the current function
@@ -318,11 +386,15 @@ impl ApplyExtraOps {
                 }),
             );
 
-            let offset = ri.offset.saturating_add(
-                current_row_position
-                    .add(local_offset_adjustment)
-                    .num_rows_idxsize_saturating()?,
-            );
+            current_row_position
+                .add(local_offset_adjustment)
+                .num_rows_idxsize_saturating()
+        };
+
+        // Note: This branch is hit if we have negative slice or predicate + row index and the reader
+        // does not support them.
+        if let Some((ri, col_idx)) = row_index {
+            let offset = ri.offset.saturating_add(file_row_offset()?);
 
             let row_index_col = Column::new_row_index(ri.name.clone(), offset, df.height())?;
 
@@ -331,6 +403,39 @@ impl ApplyExtraOps {
             unsafe { *df.columns_mut().get_mut(*col_idx).unwrap() = row_index_col }
         }
 
+        if let Some(RowPositionColumns {
+            row_index_in_file,
+            row_group_index,
+        }) = row_position_columns
+        {
+            let offset = file_row_offset()?;
+            let height = df.height();
+
+            if let Some((name, col_idx)) = row_index_in_file {
+                let col = Column::new_row_index(name.clone(), offset, height)?;
+                unsafe { *df.columns_mut().get_mut(*col_idx).unwrap() = col }
+            }
+
+            if let Some((name, col_idx, row_group_ends)) = row_group_index {
+                let mut row_group_idx = row_group_ends.partition_point(|end| *end <= offset);
+                let mut values = Vec::with_capacity(height);
+
+                for row in offset..offset.saturating_add(height as IdxSize) {
+                    while row_group_ends
+                        .get(row_group_idx)
+                        .is_some_and(|end| *end <= row)
+                    {
+                        row_group_idx += 1;
+                    }
+
+                    values.push(row_group_idx as IdxSize);
+                }
+
+                let col = IdxCa::from_vec(name.clone(), values).into_column();
+                unsafe { *df.columns_mut().get_mut(*col_idx).unwrap() = col }
+            }
+        }
+
         if let Some(predicate) = predicate {
             let mask = predicate.predicate.evaluate_io(df)?;
             *df = df.filter_seq(mask.bool().expect("predicate not boolean"))?;
@@ -339,3 +444,20 @@ impl ApplyExtraOps {
         Ok(())
     }
 }
+
+fn file_modification_time_scalar(ms: Option<i64>) -> Scalar {
+    let dtype = ProvenanceColumns::file_modification_time_dtype();
+
+    let Some(ms) = ms else {
+        return Scalar::null(dtype);
+    };
+
+    feature_gated!("dtype-datetime", {
+        use polars_core::prelude::{TimeUnit, TimeZone};
+
+        Scalar::new(
+            dtype,
+            AnyValue::DatetimeOwned(ms, TimeUnit::Milliseconds, Some(Arc::new(TimeZone::UTC))),
+        )
+    })
+}
@@ -1,13 +1,16 @@
+use std::sync::Arc;
+
 use polars_core::schema::iceberg::{IcebergSchema, IcebergSchemaRef};
 use polars_core::schema::{Schema, SchemaRef};
 use polars_error::PolarsResult;
+use polars_plan::dsl::schema_evolution::SchemaEvolutionPolicy;
 use polars_plan::dsl::{ColumnMapping, ExtraColumnsPolicy};
 
 use crate::nodes::io_sources::multi_scan::components::errors::extra_column_err;
 
 #[derive(Debug, Clone)]
 pub enum ForbidExtraColumns {
-    /// Full file schema in the IR.
+    /// Full file schema in the IR, including former names from schema evolution.
     Plain(SchemaRef),
     /// Full iceberg file schema in the IR.
     Iceberg(IcebergSchemaRef),
@@ -18,6 +21,7 @@ impl ForbidExtraColumns {
         extra_columns_policy: &ExtraColumnsPolicy,
         full_file_schema: &SchemaRef,
         column_mapping: Option<&ColumnMapping>,
+        schema_evolution: Option<&SchemaEvolutionPolicy>,
     ) -> Option<Self> {
         if matches!(extra_columns_policy, ExtraColumnsPolicy::Ignore) {
             return None;
@@ -25,7 +29,22 @@ impl ForbidExtraColumns {
 
         Some(match column_mapping {
             Some(ColumnMapping::Iceberg(schema)) => Self::Iceberg(schema.clone()),
-            None => Self::Plain(full_file_schema.clone()),
+            None => {
+                let mut schema = full_file_schema.clone();
+
+                // Files written under an older schema may contain former names of columns.
+                if let Some(schema_evolution) = schema_evolution {
+                    for (former, current) in schema_evolution.renames.iter() {
+                        if let Some(dtype) = full_file_schema.get(current)
+                            && !schema.contains(former)
+                        {
+                            Arc::make_mut(&mut schema).insert(former.clone(), dtype.clone());
+                        }
+                    }
+                }
+
+                Self::Plain(schema)
+            },
         })
     }
 
@@ -7,6 +7,7 @@ use polars_core::schema::iceberg::{IcebergSchema, IcebergSchemaRef};
 use polars_core::schema::{Schema, SchemaRef};
 use polars_error::{PolarsResult, polars_err};
 use polars_plan::dsl::default_values::IcebergIdentityTransformedPartitionFields;
+use polars_plan::dsl::schema_evolution::SchemaEvolutionPolicy;
 use polars_plan::dsl::{CastColumnsPolicy, ColumnMapping, MissingColumnsPolicy};
 use polars_utils::pl_str::PlSmallStr;
 
@@ -23,6 +24,12 @@ use crate::nodes::io_sources::multi_scan::components::projection::{
 #[derive(Debug, Clone)]
 pub enum ProjectionBuilder {
     Plain(SchemaRef),
+    /// Plain projection where columns can be read from a former name, or filled with a default
+    /// value if they are missing from the file.
+    Evolved {
+        projected_schema: SchemaRef,
+        schema_evolution: Arc<SchemaEvolutionPolicy>,
+    },
     Iceberg {
         /// `(output_name, output_dtype)`
         projected_schema: SchemaRef,
@@ -38,6 +45,9 @@ impl ProjectionBuilder {
     fn projected_schema(&self) -> &SchemaRef {
         match self {
             ProjectionBuilder::Plain(schema) => schema,
+            ProjectionBuilder::Evolved {
+                projected_schema, ..
+            } => projected_schema,
             ProjectionBuilder::Iceberg {
                 projected_schema, ..
             } => projected_schema,
@@ -92,10 +102,24 @@ impl ProjectionBuilder {
         }
     }
 
+    pub fn with_schema_evolution(
+        self,
+        schema_evolution: Option<Arc<SchemaEvolutionPolicy>>,
+    ) -> Self {
+        match (self, schema_evolution) {
+            (Self::Plain(projected_schema), Some(schema_evolution)) => Self::Evolved {
+                projected_schema,
+                schema_evolution,
+            },
+            (this, _) => this,
+        }
+    }
+
     /// Builds a potentially mapped `Projection` (i.e. one containing renames/casting).
     ///
     /// # Returns
-    /// Returns a `Plain` variant if `self` is a `Plain` variant and the `file_schema` is `None`.
+    /// Returns a `Plain` variant if `self` is a `Plain` or `Evolved` variant and the `file_schema`
+    /// is `None`. For `Evolved`, this also contains the former names of the projected columns.
     ///
     /// # Panics
     /// * If `self` is the `Iceberg` variant and `file_iceberg_schema` is `None` or `scan_source_idx` is `usize::MAX`.
@@ -113,24 +137,70 @@ impl ProjectionBuilder {
         };
 
         Ok(match self {
-            Self::Plain(projected_schema) => {
+            Self::Plain(projected_schema)
+            | Self::Evolved {
+                projected_schema, ..
+            } => {
+                let schema_evolution = match self {
+                    Self::Evolved {
+                        schema_evolution, ..
+                    } => Some(schema_evolution.as_ref()),
+                    _ => None,
+                };
+
                 let Some(file_schema) = file_schema else {
-                    return Ok(Projection::Plain(projected_schema.clone()));
+                    let Some(schema_evolution) = schema_evolution else {
+                        return Ok(Projection::Plain(projected_schema.clone()));
+                    };
+
+                    // The file schema is not known yet, also request the former names so that
+                    // the projection can be resolved from the columns that were read.
+                    let mut read_schema = projected_schema.as_ref().clone();
+
+                    for (former, current) in schema_evolution.renames.iter() {
+                        if let Some(dtype) = projected_schema.get(current)
+                            && !read_schema.contains(former)
+                        {
+                            read_schema.insert(former.clone(), dtype.clone());
+                        }
+                    }
+
+                    return Ok(Projection::Plain(Arc::new(read_schema)));
                 };
 
                 let mut mapping: Option<PlHashMap<usize, ProjectionTransform>> = None;
                 let mut missing_columns_mask: Option<MutableBitmap> = None;
+                let mut missing_column_defaults: Option<PlHashMap<usize, Scalar>> = None;
 
                 for (index, (projected_name, projected_dtype)) in
                     projected_schema.iter().enumerate()
                 {
-                    let Some(incoming_dtype) = file_schema.get(projected_name) else {
+                    let source = file_schema
+                        .get(projected_name)
+                        .map(|dtype| (projected_name, dtype))
+                        .or_else(|| {
+                            schema_evolution?
+                                .former_names(projected_name)
+                                .find_map(|former| Some((former, file_schema.get(former)?)))
+                        });
+
+                    let Some((source_name, incoming_dtype)) = source else {
                         missing_columns_mask
                             .get_or_insert_with(|| {
                                 MutableBitmap::from_len_zeroed(projected_schema.len())
                             })
                             .set(index, true);
 
+                        if let Some(default) =
+                            schema_evolution.and_then(|x| x.missing_default(projected_name))
+                        {
+                            missing_column_defaults
+                                .get_or_insert_with(|| {
+                                    PlHashMap::with_capacity(projected_schema.len())
+                                })
+                                .insert(index, default.clone());
+                        }
+
                         continue;
                     };
 
@@ -140,7 +210,7 @@ impl ProjectionBuilder {
                         projected_dtype,
                         projected_name,
                     )? {
-                        ColumnSelector::Position(0) => {},
+                        ColumnSelector::Position(0) if source_name == projected_name => {},
                         selector => {
                             mapping
                                 .get_or_insert_with(|| {
@@ -149,7 +219,7 @@ impl ProjectionBuilder {
                                 .insert(
                                     index,
                                     ProjectionTransform {
-                                        source_name: projected_name.clone(),
+                                        source_name: source_name.clone(),
                                         source_dtype: incoming_dtype.clone(),
                                         transform: selector,
                                     },
@@ -162,7 +232,7 @@ impl ProjectionBuilder {
                     projected_schema: projected_schema.clone(),
                     mapping: mapping.map(Arc::new),
                     missing_columns_mask: missing_columns_mask.map(|x| x.freeze()),
-                    missing_column_defaults: None,
+                    missing_column_defaults: missing_column_defaults.map(Arc::new),
                 }
             },
 
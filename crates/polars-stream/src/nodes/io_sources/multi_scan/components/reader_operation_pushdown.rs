@@ -86,6 +86,7 @@ impl ReaderOperationPushdown<'_> {
             || unsupported_external_filter_mask
             || extra_ops_post.predicate.is_none()
             || (extra_ops_post.row_index.is_some() || extra_ops_post.pre_slice.is_some())
+            || extra_ops_post.provenance.needs_row_position()
             || !reader_capabilities.contains(RC::PARTIAL_FILTER));
 
         let mut predicate: Option<ScanIOPredicate> = None;
@@ -34,6 +34,10 @@ pub enum DeletionFilesProvider {
         reader_builder: ParquetReaderBuilder,
         projected_schema: SchemaRef,
     },
+
+    PositionDelete {
+        positions: Arc<PlIndexMap<usize, Arc<[u64]>>>,
+    },
 }
 
 impl DeletionFilesProvider {
@@ -59,11 +63,22 @@ impl DeletionFilesProvider {
                         parallel: polars_io::prelude::ParallelStrategy::Auto,
                         low_memory: false,
                         use_statistics: false,
+                        decryption: None,
+                        readahead: None,
+                        readahead_bytes: None,
+                        struct_fields: None,
+                        int96: Default::default(),
+                        int64_timestamp_overflow: None,
+                        uuid_as_string: false,
+                        categorical_columns: None,
+                        footers: None,
                     }),
                     prefetch_limit: RelaxedCell::new_usize(0),
                     prefetch_semaphore: std::sync::OnceLock::new(),
+                    readahead_bytes_semaphore: std::sync::OnceLock::new(),
                     shared_prefetch_wait_group_slot: Default::default(),
                     io_metrics: io_metrics.map(OnceLock::from).unwrap_or_default(),
+                    morsel_sizer: OnceLock::new(),
                 };
 
                 reader_builder.set_execution_state(execution_state);
@@ -77,6 +92,7 @@ impl DeletionFilesProvider {
                     ])),
                 }
             }),
+            DeletionFilesList::PositionDelete(positions) => Self::PositionDelete { positions },
         }
     }
 
@@ -222,23 +238,14 @@ impl DeletionFilesProvider {
                             position_columns.push(positions_col);
                         }
 
-                        let mut filter_mask = MutableBitmap::from_len_set(filter_mask_len);
-
-                        for c in position_columns {
-                            for idx in c.as_materialized_series_maintain_scalar().i64().unwrap() {
-                                let idx = usize::try_from(idx.unwrap()).unwrap();
-                                filter_mask.set(idx, false);
-                            }
-                        }
-
-                        let bitmap = filter_mask.freeze();
-
-                        // Also trigger the bitcount to reduce blocking later down.
-                        bitmap.unset_bits();
-                        debug_assert!(bitmap.lazy_unset_bits().is_some());
-
-                        let mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, bitmap);
-                        let mask = ExternalFilterMask::IcebergPositionDelete { mask };
+                        let positions = position_columns.iter().flat_map(|c| {
+                            c.as_materialized_series()
+                                .i64()
+                                .unwrap()
+                                .into_iter()
+                                .map(|idx| usize::try_from(idx.unwrap()).unwrap())
+                        });
+                        let mask = ExternalFilterMask::from_positions(filter_mask_len, positions);
 
                         if verbose {
                             let num_deleted_rows = mask.num_deleted_rows();
@@ -258,6 +265,31 @@ impl DeletionFilesProvider {
 
                 Some(RowDeletionsInit::Initializing(handle))
             },
+
+            Self::PositionDelete { positions } => {
+                let positions = positions.get(&scan_source_idx)?;
+                let filter_mask_len = positions
+                    .iter()
+                    .max()
+                    .map_or(0, |idx| usize::try_from(*idx).unwrap().saturating_add(1));
+                let mask = ExternalFilterMask::from_positions(
+                    filter_mask_len,
+                    positions.iter().map(|idx| usize::try_from(*idx).unwrap()),
+                );
+
+                if verbose {
+                    eprintln!(
+                        "[DeletionFilesProvider[PositionDelete]]: \
+                        scan_source_idx: {scan_source_idx}, \
+                        num_deleted_rows: {}, \
+                        max_index: {:?}",
+                        mask.num_deleted_rows(),
+                        mask.len().checked_sub(1),
+                    )
+                }
+
+                Some(RowDeletionsInit::Initialized(mask))
+            },
         }
     }
 }
@@ -283,15 +315,33 @@ impl RowDeletionsInit {
 
 #[derive(Debug, Clone)]
 pub enum ExternalFilterMask {
-    /// Note: Iceberg positional deletes can have a mask length shorter than the actual data.
-    IcebergPositionDelete { mask: BooleanChunked },
+    /// Note: Positional deletes can have a mask length shorter than the actual data.
+    PositionDelete { mask: BooleanChunked },
 }
 
 impl ExternalFilterMask {
+    /// Builds a mask of length `len` that is `false` at the positions of deleted rows.
+    pub fn from_positions(len: usize, positions: impl IntoIterator<Item = usize>) -> Self {
+        let mut filter_mask = MutableBitmap::from_len_set(len);
+
+        for idx in positions {
+            filter_mask.set(idx, false);
+        }
+
+        let bitmap = filter_mask.freeze();
+
+        // Also trigger the bitcount to reduce blocking later down.
+        bitmap.unset_bits();
+        debug_assert!(bitmap.lazy_unset_bits().is_some());
+
+        let mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, bitmap);
+        Self::PositionDelete { mask }
+    }
+
     pub fn variant_name(&self) -> &'static str {
         use ExternalFilterMask::*;
         match self {
-            IcebergPositionDelete { .. } => "IcebergPositionDelete",
+            PositionDelete { .. } => "PositionDelete",
         }
     }
 
@@ -310,7 +360,7 @@ impl ExternalFilterMask {
 
     pub fn filter_df(&self, df: &mut DataFrame) -> PolarsResult<()> {
         match self {
-            Self::IcebergPositionDelete { mask } => {
+            Self::PositionDelete { mask } => {
                 if !mask.is_empty() {
                     *df = if mask.len() < df.height() {
                         accumulate_dataframes_vertical_unchecked([
@@ -329,7 +379,7 @@ impl ExternalFilterMask {
 
     pub fn slice(&self, offset: usize, len: usize) -> Self {
         match self {
-            Self::IcebergPositionDelete { mask } => {
+            Self::PositionDelete { mask } => {
                 // This is not a valid offset, it's also a sentinel value from `RowCounter::MAX`.
                 assert_ne!(offset, usize::MAX);
                 let offset = offset.min(mask.len());
@@ -337,14 +387,14 @@ impl ExternalFilterMask {
 
                 let mask = mask.slice(i64::try_from(offset).unwrap(), len);
 
-                Self::IcebergPositionDelete { mask }
+                Self::PositionDelete { mask }
             },
         }
     }
 
     pub fn num_deleted_rows(&self) -> usize {
         match self {
-            Self::IcebergPositionDelete { mask } => mask
+            Self::PositionDelete { mask } => mask
                 .rechunk()
                 .downcast_get(0)
                 .unwrap()
@@ -401,7 +451,7 @@ impl ExternalFilterMask {
 
     fn get_mask(&self) -> Bitmap {
         match self {
-            Self::IcebergPositionDelete { mask } => {
+            Self::PositionDelete { mask } => {
                 mask.rechunk().downcast_get(0).unwrap().values().clone()
             },
         }
@@ -409,7 +459,7 @@ impl ExternalFilterMask {
 
     pub fn len(&self) -> usize {
         match self {
-            Self::IcebergPositionDelete { mask } => mask.len(),
+            Self::PositionDelete { mask } => mask.len(),
         }
     }
 }
@@ -433,7 +483,7 @@ mod tests {
     where
         I: IntoIterator<Item = bool>,
     {
-        let mask = ExternalFilterMask::IcebergPositionDelete {
+        let mask = ExternalFilterMask::PositionDelete {
             mask: mask.into_iter().collect(),
         };
 
@@ -6,7 +6,8 @@ use polars_io::cloud::CloudOptions;
 use polars_io::predicates::ScanIOPredicate;
 use polars_plan::dsl::deletion::DeletionFilesList;
 use polars_plan::dsl::{
-    CastColumnsPolicy, MissingColumnsPolicy, PredicateFileSkip, ScanSources, TableStatistics,
+    CastColumnsPolicy, MissingColumnsPolicy, PredicateFileSkip, ProvenanceColumns, ScanSources,
+    TableStatistics,
 };
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_utils::pl_str::PlSmallStr;
@@ -39,6 +40,7 @@ pub struct MultiScanConfig {
 
     pub hive_parts: Option<Arc<HivePartitionsDf>>,
     pub include_file_paths: Option<PlSmallStr>,
+    pub provenance: ProvenanceColumns,
     pub missing_columns_policy: MissingColumnsPolicy,
     pub cast_columns_policy: CastColumnsPolicy,
     pub forbid_extra_columns: Option<ForbidExtraColumns>,
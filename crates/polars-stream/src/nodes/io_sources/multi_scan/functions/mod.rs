@@ -1,7 +1,7 @@
 use std::num::NonZeroUsize;
 use std::sync::Arc;
 
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, feature_gated};
 use polars_io::cloud::CloudOptions;
 use polars_io::metrics::IOMetrics;
 use polars_io::utils::byte_source::{ByteSource, DynByteSourceBuilder};
@@ -74,3 +74,37 @@ pub async fn is_compressed_source(
 
     Ok(SupportedCompression::check(&first_4_bytes).is_some())
 }
+
+/// Returns the last modification time of the file in milliseconds since the UNIX epoch, or `None`
+/// for in-memory sources.
+pub async fn file_modification_time_ms(
+    scan_source: ScanSource,
+    #[cfg_attr(not(feature = "cloud"), allow(unused_variables))] cloud_options: Option<
+        Arc<CloudOptions>,
+    >,
+) -> PolarsResult<Option<i64>> {
+    let Some(path) = scan_source.as_scan_source_ref().as_path().cloned() else {
+        return Ok(None);
+    };
+
+    if path.has_scheme() {
+        feature_gated!("cloud", {
+            use polars_io::cloud::{CloudLocation, build_object_store, object_path_from_str};
+
+            let (CloudLocation { prefix, .. }, store) =
+                build_object_store(path, cloud_options.as_deref(), false).await?;
+            let object_meta = store.head(&object_path_from_str(&prefix)?).await?;
+
+            Ok(Some(object_meta.last_modified.timestamp_millis()))
+        })
+    } else {
+        let modified = std::fs::metadata(path.as_std_path())
+            .and_then(|metadata| metadata.modified())
+            .map_err(|err| polars_utils::_limit_path_len_io_err(path.as_std_path(), err))?;
+
+        Ok(Some(match modified.duration_since(std::time::UNIX_EPOCH) {
+            Ok(since_epoch) => since_epoch.as_millis() as i64,
+            Err(err) => -(err.duration().as_millis() as i64),
+        }))
+    }
+}
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use polars_core::schema::{Schema, SchemaRef};
+use polars_plan::dsl::ProvenanceColumns;
 use polars_plan::plans::hive::HivePartitionsDf;
 
 /// Returns the schema containing columns to project from the file.
@@ -22,6 +23,7 @@ pub fn resolve_projections(
     // TODO: One day update IR conversion to avoid attaching these to the file schema :')
     row_index_name: Option<&str>,
     include_file_paths: Option<&str>,
+    provenance: &ProvenanceColumns,
 ) -> (SchemaRef, SchemaRef) {
     if let Some(hive_parts) = hive_parts.as_mut() {
         *hive_parts = hive_parts.filter_columns(final_output_schema)
@@ -39,7 +41,8 @@ pub fn resolve_projections(
             let in_hive = hive_schema.is_some_and(|x| x.contains(name));
             let is_row_index_col = row_index_name.is_some_and(|x| name == x);
             let is_file_path_col = include_file_paths.is_some_and(|x| name == x);
-            (in_final && !(in_hive || is_file_path_col || is_row_index_col))
+            let is_provenance_col = provenance.contains(name);
+            (in_final && !(in_hive || is_file_path_col || is_row_index_col || is_provenance_col))
                 .then(|| (name.clone(), dtype.clone()))
         })
         .collect();
@@ -56,5 +59,11 @@ pub fn resolve_projections(
         Arc::make_mut(&mut full_file_schema).shift_remove(include_file_paths.unwrap());
     }
 
+    for (name, _) in provenance.iter() {
+        if full_file_schema.contains(name) {
+            Arc::make_mut(&mut full_file_schema).shift_remove(name);
+        }
+    }
+
     (Arc::new(projected_file_schema), full_file_schema)
 }
@@ -57,7 +57,10 @@ pub fn initialize_multi_scan_pipeline(
 
     let bridge_state = Arc::new(Mutex::new(BridgeState::NotYetStarted));
 
-    let (bridge_handle, bridge_recv_port_tx, phase_channel_tx) = spawn_bridge(bridge_state.clone());
+    let (bridge_handle, bridge_recv_port_tx, phase_channel_tx) = spawn_bridge(
+        bridge_state.clone(),
+        execution_state.morsel_sizer.clone(),
+    );
 
     let task_handle =
         AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
@@ -188,6 +191,7 @@ async fn finish_initialize_multi_scan_pipeline(
                 && reader_capabilities.contains(ReaderCapabilities::NEGATIVE_PRE_SLICE)
                 && (config.row_index.is_none()
                     || reader_capabilities.contains(ReaderCapabilities::ROW_INDEX))
+                && !config.provenance.needs_row_position()
                 && (config.deletion_files.is_none()
                     || reader_capabilities.contains(ReaderCapabilities::EXTERNAL_FILTER_MASK))
                 && !get_runtime()
@@ -239,6 +243,7 @@ async fn finish_initialize_multi_scan_pipeline(
         file_path_col_idx: config.include_file_paths.as_ref().map_or(usize::MAX, |x| {
             config.final_output_schema.index_of(x).unwrap()
         }),
+        provenance: config.provenance.clone(),
         predicate,
     };
 
@@ -408,6 +413,7 @@ async fn finish_initialize_multi_scan_pipeline(
             skip_files_mask,
             extra_ops,
             constant_args: StartReaderArgsConstant {
+                cloud_options: config.cloud_options.clone(),
                 hive_parts,
                 final_output_schema,
                 reader_capabilities,
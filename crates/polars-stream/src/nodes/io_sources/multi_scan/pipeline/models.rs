@@ -7,8 +7,9 @@ use polars_core::prelude::PlHashMap;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
 use polars_io::predicates::ScanIOPredicate;
-use polars_plan::dsl::{CastColumnsPolicy, MissingColumnsPolicy, ScanSource};
+use polars_plan::dsl::{CastColumnsPolicy, MissingColumnsPolicy, ProvenanceColumns, ScanSource};
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::row_counter::RowCounter;
@@ -45,6 +46,7 @@ pub struct ExtraOperations {
     pub include_file_paths: Option<PlSmallStr>,
     /// Index of the file path column in the final output.
     pub file_path_col_idx: usize,
+    pub provenance: ProvenanceColumns,
     pub predicate: Option<ScanIOPredicate>,
 }
 
@@ -75,6 +77,7 @@ pub struct ResolvedSliceInfo {
 /// Constant over the file list.
 #[derive(Clone)]
 pub(super) struct StartReaderArgsConstant {
+    pub(super) cloud_options: Option<Arc<CloudOptions>>,
     pub(super) hive_parts: Option<Arc<HivePartitionsDf>>,
     pub(super) final_output_schema: SchemaRef,
     pub(super) reader_capabilities: ReaderCapabilities,
@@ -1,10 +1,11 @@
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 use crate::async_executor;
 use crate::async_executor::{JoinHandle, TaskPriority};
 use crate::async_primitives::connector;
-use crate::async_primitives::wait_group::WaitToken;
-use crate::morsel::{MorselSeq, SourceToken};
+use crate::async_primitives::wait_group::{WaitGroup, WaitToken};
+use crate::morsel::{Morsel, MorselSeq, MorselSizer, SourceToken};
 use crate::nodes::io_sources::multi_scan::components::bridge::{
     BridgeRecvPort, BridgeState, StopReason,
 };
@@ -12,6 +13,7 @@ use crate::pipe::PortSender;
 
 pub fn spawn_bridge(
     bridge_state: Arc<Mutex<BridgeState>>,
+    morsel_sizer: Arc<MorselSizer>,
 ) -> (
     JoinHandle<()>,
     // For attaching file reader output port
@@ -29,6 +31,7 @@ pub fn spawn_bridge(
             outgoing,
             bridge_state,
             source_token: SourceToken::new(),
+            morsel_sizer,
         }
         .run(),
     );
@@ -43,6 +46,9 @@ struct Bridge {
     outgoing: connector::Receiver<(PortSender, WaitToken)>,
     bridge_state: Arc<Mutex<BridgeState>>,
     source_token: SourceToken,
+    /// Told how long the morsels take to be consumed if the morsel sizing is adaptive, so that
+    /// the readers size the morsels of the files they open next.
+    morsel_sizer: Arc<MorselSizer>,
 }
 
 impl Bridge {
@@ -104,6 +110,10 @@ impl Bridge {
 
             morsel_seq = morsel_seq.saturating_add(1);
 
+            if self.morsel_sizer.is_adaptive() {
+                self.observe_consume(&mut morsel);
+            }
+
             while let Err(v) = tx.send(morsel).await {
                 drop(tx);
                 drop(current_phase_wait_token);
@@ -129,4 +139,19 @@ impl Bridge {
             }
         }
     }
+
+    /// Reports the time until the morsel is consumed to the morsel sizer. This is done in a
+    /// separate task, so that the bridge keeps passing on morsels in the meantime.
+    fn observe_consume(&self, morsel: &mut Morsel) {
+        let wait_group = WaitGroup::default();
+        morsel.set_consume_token(wait_group.token());
+        let rows = morsel.df().height();
+        let bytes = morsel.df().estimated_size();
+        let morsel_sizer = self.morsel_sizer.clone();
+        let start = Instant::now();
+        drop(async_executor::spawn(TaskPriority::Low, async move {
+            wait_group.wait().await;
+            morsel_sizer.observe(rows, bytes, start.elapsed());
+        }));
+    }
 }
@@ -8,7 +8,8 @@ use polars_core::config::verbose_print_sensitive;
 use polars_core::prelude::{AnyValue, DataType};
 use polars_core::scalar::Scalar;
 use polars_core::schema::iceberg::IcebergSchema;
-use polars_error::PolarsResult;
+use polars_core::schema::{Schema, SchemaExt};
+use polars_error::{PolarsResult, polars_bail};
 use polars_mem_engine::scan_predicate::skip_files_mask::SkipFilesMask;
 use polars_plan::dsl::{MissingColumnsPolicy, ScanSource};
 use polars_utils::IdxSize;
@@ -24,6 +25,7 @@ use crate::nodes::io_sources::multi_scan::components::errors::missing_column_err
 use crate::nodes::io_sources::multi_scan::components::physical_slice::PhysicalSlice;
 use crate::nodes::io_sources::multi_scan::components::projection::builder::ProjectionBuilder;
 use crate::nodes::io_sources::multi_scan::components::reader_operation_pushdown::ReaderOperationPushdown;
+use crate::nodes::io_sources::multi_scan::functions::file_modification_time_ms;
 use crate::nodes::io_sources::multi_scan::pipeline::models::{
     ExtraOperations, StartReaderArgsConstant, StartReaderArgsPerFile, StartedReaderState,
 };
@@ -401,6 +403,7 @@ async fn start_reader_impl(
     args_this_file: StartReaderArgsPerFile,
 ) -> PolarsResult<StartedReaderState> {
     let StartReaderArgsConstant {
+        cloud_options,
         hive_parts,
         final_output_schema,
         reader_capabilities,
@@ -434,8 +437,20 @@ async fn start_reader_impl(
             None
         };
 
+    // Readers without an upfront file schema (e.g. CSV) have their schema evolution resolved
+    // against the first morsel instead.
+    let evolved_file_schema: Option<Schema> =
+        if matches!(&file_projection_builder, ProjectionBuilder::Evolved { .. }) {
+            reader
+                .file_arrow_schema()
+                .await?
+                .map(|x| Schema::from_arrow_schema(x.as_ref()))
+        } else {
+            None
+        };
+
     let file_projection = file_projection_builder.build_projection(
-        None,
+        evolved_file_schema.as_ref(),
         file_iceberg_schema.as_ref(),
         cast_columns_policy.clone(),
         scan_source_idx,
@@ -579,8 +594,8 @@ async fn start_reader_impl(
         for (missing_col_name, dtype, default_value) in
             file_projection.iter_missing_columns(Some(&reader_file_schema))?
         {
-            match &missing_columns_policy {
-                MissingColumnsPolicy::Insert => {
+            match (&missing_columns_policy, default_value) {
+                (MissingColumnsPolicy::Insert, _) | (_, Some(_)) => {
                     if predicate.live_columns.contains(missing_col_name) {
                         external_predicate_cols.push((
                             missing_col_name.clone(),
@@ -592,7 +607,9 @@ async fn start_reader_impl(
                         Arc::make_mut(&mut predicate.column_predicates).is_sumwise_complete = false;
                     }
                 },
-                MissingColumnsPolicy::Raise => return Err(missing_column_err(missing_col_name)),
+                (MissingColumnsPolicy::Raise, None) => {
+                    return Err(missing_column_err(missing_col_name));
+                },
             }
         }
 
@@ -610,6 +627,25 @@ async fn start_reader_impl(
         callbacks,
     };
 
+    let row_group_row_counts = if extra_ops_post.provenance.row_group_index.is_some() {
+        let Some(row_group_row_counts) = reader.row_group_row_counts().await? else {
+            polars_bail!(
+                InvalidOperation:
+                "row group index column is not supported for this scan type"
+            )
+        };
+
+        Some(row_group_row_counts)
+    } else {
+        None
+    };
+
+    let file_modification_time_ms = if extra_ops_post.provenance.file_modification_time.is_some() {
+        file_modification_time_ms(scan_source.clone(), cloud_options).await?
+    } else {
+        None
+    };
+
     let (mut reader_output_port, reader_handle) = reader.begin_read(begin_read_args)?;
 
     let reader_handle = AbortOnDropHandle::new(reader_handle);
@@ -641,6 +677,19 @@ async fn start_reader_impl(
             )
         }
 
+        let projection_to_post = if evolved_file_schema.is_none()
+            && matches!(&file_projection_builder, ProjectionBuilder::Evolved { .. })
+        {
+            file_projection_builder.build_projection(
+                Some(first_morsel.df().schema().as_ref()),
+                None,
+                cast_columns_policy.clone(),
+                scan_source_idx,
+            )?
+        } else {
+            projection_to_post
+        };
+
         ApplyExtraOps::Uninitialized {
             final_output_schema,
             projection: projection_to_post,
@@ -651,6 +700,8 @@ async fn start_reader_impl(
             scan_source_idx,
             hive_parts,
             external_filter_mask,
+            row_group_row_counts,
+            file_modification_time_ms,
         }
         .initialize(first_morsel.df().schema())?
     } else {
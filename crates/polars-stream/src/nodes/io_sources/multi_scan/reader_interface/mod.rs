@@ -54,6 +54,12 @@ pub trait FileReader: Send + Sync {
         Ok(None)
     }
 
+    /// Returns the number of rows in each row group for file formats that have row groups. Returns
+    /// None otherwise.
+    async fn row_group_row_counts(&mut self) -> PolarsResult<Option<Vec<IdxSize>>> {
+        Ok(None)
+    }
+
     /// This FileReader must be initialized before calling this.
     ///
     /// Note: The default implementation of this dispatches to `begin_read`, so should not be
@@ -0,0 +1,234 @@
+use std::ops::Range;
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_core::chunked_array::cast::CastOptions;
+use polars_core::prelude::*;
+use polars_io::predicates::{ScanIOPredicate, SpecializedColumnPredicate};
+use polars_io::prelude::FileMetadata;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::bloom_filter::read_header;
+use polars_parquet::read::{ColumnChunkMetadata, bloom_filter_may_contain};
+
+use super::struct_field_leaf;
+use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
+
+/// Upper bound of the size of a bloom filter header. This many bytes are fetched first for bloom
+/// filters whose length is not in the metadata.
+const BLOOM_FILTER_HEADER_SIZE_ESTIMATE: usize = 64;
+
+/// The number of row groups whose bloom filters are fetched at once.
+const ROW_GROUPS_PER_FETCH: usize = 16;
+
+/// A column whose values must be one of `values` for a row to pass the predicate.
+struct BloomFilterLookup {
+    leaf_idx: usize,
+    values: ArrayRef,
+}
+
+/// Extends `row_group_mask` with the row groups that can be skipped because the bloom filter of
+/// a column chunk contains none of the values that the predicate compares the column to.
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn calculate_row_group_bloom_filter_skip_mask(
+    row_group_slice: Range<usize>,
+    use_statistics: bool,
+    predicate: Option<&ScanIOPredicate>,
+    metadata: &FileMetadata,
+    projected_arrow_fields: &[ArrowFieldProjection],
+    byte_source: &DynByteSource,
+    row_group_mask: Option<Bitmap>,
+    verbose: bool,
+) -> PolarsResult<Option<Bitmap>> {
+    if !use_statistics || row_group_slice.is_empty() {
+        return Ok(row_group_mask);
+    }
+
+    let Some(predicate) = predicate else {
+        return Ok(row_group_mask);
+    };
+
+    let first_row_group = &metadata.row_groups[row_group_slice.start];
+    let lookups = projected_arrow_fields
+        .iter()
+        .filter_map(|projection| {
+            // The values of mapped columns are transformed after they are read, so they can't be
+            // looked up in the bloom filter.
+            let ArrowFieldProjection::Plain(field) = projection else {
+                return None;
+            };
+
+            let (_, specialized) = predicate.column_predicates.predicates.get(&field.name)?;
+            let scalars = match specialized.as_ref()? {
+                SpecializedColumnPredicate::Equal(scalar) => std::slice::from_ref(scalar),
+                SpecializedColumnPredicate::EqualOneOf(scalars) => scalars.as_ref(),
+                _ => return None,
+            };
+
+            // Predicates on a struct column are looked up in the bloom filter of the field they
+            // apply to.
+            let (leaf_field, leaf_offset) = match predicate
+                .column_predicates
+                .struct_field_paths
+                .get(&field.name)
+            {
+                Some(path) => struct_field_leaf(field, path)?,
+                None => (field, 0),
+            };
+
+            // Nested columns have no bloom filters, and those of encrypted columns are not read.
+            if leaf_field.dtype().is_nested() {
+                return None;
+            }
+            let leaf_idx = *first_row_group
+                .columns_idxs_under_root_iter(&field.name)?
+                .get(leaf_offset)?;
+            if first_row_group.parquet_columns()[leaf_idx].is_encrypted() {
+                return None;
+            }
+
+            Some(BloomFilterLookup {
+                leaf_idx,
+                values: lookup_values(scalars, leaf_field)?,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let has_bloom_filters = |rg_idx: usize| {
+        let columns = metadata.row_groups[rg_idx].parquet_columns();
+        lookups.iter().any(|lookup| {
+            columns[lookup.leaf_idx]
+                .metadata()
+                .bloom_filter_offset
+                .is_some()
+        })
+    };
+
+    if !row_group_slice.clone().any(has_bloom_filters) {
+        return Ok(row_group_mask);
+    }
+
+    let num_row_groups = row_group_slice.len();
+    let mut skip_mask = row_group_mask.map_or_else(
+        || MutableBitmap::from_len_zeroed(num_row_groups),
+        Bitmap::make_mut,
+    );
+    let num_skipped_by_statistics = skip_mask.set_bits();
+
+    // Only needed to bound the header fetches of bloom filters without a length.
+    let file_size = if row_group_slice.clone().any(|rg_idx| {
+        let columns = metadata.row_groups[rg_idx].parquet_columns();
+        lookups.iter().any(|lookup| {
+            let metadata = columns[lookup.leaf_idx].metadata();
+            metadata.bloom_filter_offset.is_some() && metadata.bloom_filter_length.is_none()
+        })
+    }) {
+        byte_source.get_size().await?
+    } else {
+        usize::MAX
+    };
+
+    for chunk_start in (0..num_row_groups).step_by(ROW_GROUPS_PER_FETCH) {
+        let chunk = chunk_start..num_row_groups.min(chunk_start + ROW_GROUPS_PER_FETCH);
+
+        // (row group, lookup, bloom filter range)
+        let mut requests = Vec::new();
+        for i in chunk {
+            if skip_mask.get(i) {
+                continue;
+            }
+            let columns = metadata.row_groups[row_group_slice.start + i].parquet_columns();
+            for (lookup_idx, lookup) in lookups.iter().enumerate() {
+                if let Some(range) = bloom_filter_range(&columns[lookup.leaf_idx], file_size) {
+                    requests.push((i, lookup_idx, range));
+                }
+            }
+        }
+
+        if requests.is_empty() {
+            continue;
+        }
+
+        let mut ranges = requests
+            .iter()
+            .map(|(_, _, range)| range.clone())
+            .collect::<Vec<_>>();
+        let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+        for (i, lookup_idx, range) in requests {
+            if skip_mask.get(i) {
+                continue;
+            }
+
+            let bytes = bytes_map.get(&range.start).unwrap();
+            let Some((header_len, num_bytes)) = read_header(bytes)? else {
+                continue;
+            };
+
+            let bitset_range = header_len..header_len + num_bytes;
+            let remaining;
+            let bitset = if bitset_range.end <= bytes.len() {
+                &bytes[bitset_range]
+            } else {
+                let start = range.start + header_len;
+                remaining = byte_source.get_range(start..start + num_bytes).await?;
+                &remaining[..]
+            };
+
+            let lookup = &lookups[lookup_idx];
+            let columns = metadata.row_groups[row_group_slice.start + i].parquet_columns();
+            let physical_type = columns[lookup.leaf_idx].physical_type();
+            if bloom_filter_may_contain(bitset, physical_type, lookup.values.as_ref())
+                == Some(false)
+            {
+                skip_mask.set(i, true);
+            }
+        }
+    }
+
+    let skip_mask: Bitmap = skip_mask.freeze();
+
+    if verbose {
+        eprintln!(
+            "[ParquetFileReader]: Bloom filter pushdown: \
+            skipped {} / {} row groups not skipped by statistics",
+            skip_mask.set_bits() - num_skipped_by_statistics,
+            num_row_groups - num_skipped_by_statistics,
+        );
+    }
+
+    Ok(Some(skip_mask))
+}
+
+/// Get `scalars` in the dtype of the column in the file, or `None` if they can't be looked up in
+/// its bloom filter.
+fn lookup_values(scalars: &[Scalar], field: &ArrowField) -> Option<ArrayRef> {
+    let dtype = DataType::from_arrow_field(field);
+    let values = scalars
+        .iter()
+        .map(|scalar| {
+            // Nulls are not in the bloom filters, and values that can't be cast to the dtype of
+            // the file are not looked up.
+            let value = scalar
+                .clone()
+                .cast_with_options(&dtype, CastOptions::Strict)
+                .ok()?;
+            (!value.is_null()).then(|| value.into_value())
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let values = Series::from_any_values_and_dtype(PlSmallStr::EMPTY, &values, &dtype, true)
+        .ok()?
+        .rechunk();
+    Some(values.to_arrow(0, CompatLevel::newest()))
+}
+
+/// The byte range to fetch for the bloom filter of a column chunk. This is only a prefix holding
+/// the header if the length of the bloom filter is not in the metadata.
+fn bloom_filter_range(column: &ColumnChunkMetadata, file_size: usize) -> Option<Range<usize>> {
+    let metadata = column.metadata();
+    let offset = usize::try_from(metadata.bloom_filter_offset?).ok()?;
+    let length = match metadata.bloom_filter_length {
+        Some(length) => usize::try_from(length).ok()?,
+        None => BLOOM_FILTER_HEADER_SIZE_ESTIMATE.min(file_size.checked_sub(offset)?),
+    };
+    Some(offset..offset + length)
+}
@@ -11,6 +11,7 @@ use polars_utils::relaxed_cell::RelaxedCell;
 use super::{FileReader, ParquetFileReader};
 use crate::async_primitives::wait_group::WaitGroup;
 use crate::metrics::{IOMetrics, OptIOMetrics};
+use crate::morsel::MorselSizer;
 use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
 use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
 
@@ -20,8 +21,13 @@ pub struct ParquetReaderBuilder {
     pub options: Arc<ParquetOptions>,
     pub prefetch_limit: RelaxedCell<usize>,
     pub prefetch_semaphore: std::sync::OnceLock<Arc<tokio::sync::Semaphore>>,
+    /// Limits the total size of the prefetched row groups. Only set if the options have a
+    /// `readahead_bytes` budget.
+    pub readahead_bytes_semaphore: std::sync::OnceLock<Arc<tokio::sync::Semaphore>>,
     pub shared_prefetch_wait_group_slot: Arc<std::sync::Mutex<Option<WaitGroup>>>,
     pub io_metrics: std::sync::OnceLock<Arc<IOMetrics>>,
+    /// Sizes the morsels of the files. Set from the execution state of the query.
+    pub morsel_sizer: std::sync::OnceLock<Arc<MorselSizer>>,
 }
 
 impl std::fmt::Debug for ParquetReaderBuilder {
@@ -30,6 +36,7 @@ impl std::fmt::Debug for ParquetReaderBuilder {
             .field("first_metadata", &self.first_metadata)
             .field("options", &self.options)
             .field("prefetch_semaphore", &self.prefetch_semaphore)
+            .field("readahead_bytes_semaphore", &self.readahead_bytes_semaphore)
             .finish()
     }
 }
@@ -58,13 +65,20 @@ impl FileReaderBuilder for ParquetReaderBuilder {
     }
 
     fn set_execution_state(&self, execution_state: &crate::execute::StreamingExecutionState) {
-        let prefetch_limit = std::env::var("POLARS_ROW_GROUP_PREFETCH_SIZE")
-            .map(|x| {
-                x.parse::<NonZeroUsize>()
-                    .unwrap_or_else(|_| {
-                        panic!("invalid value for POLARS_ROW_GROUP_PREFETCH_SIZE: {x}")
+        let prefetch_limit = self
+            .options
+            .readahead
+            .map(NonZeroUsize::get)
+            .or_else(|| {
+                std::env::var("POLARS_ROW_GROUP_PREFETCH_SIZE")
+                    .map(|x| {
+                        x.parse::<NonZeroUsize>()
+                            .unwrap_or_else(|_| {
+                                panic!("invalid value for POLARS_ROW_GROUP_PREFETCH_SIZE: {x}")
+                            })
+                            .get()
                     })
-                    .get()
+                    .ok()
             })
             .unwrap_or(
                 execution_state
@@ -75,17 +89,31 @@ impl FileReaderBuilder for ParquetReaderBuilder {
             .max(1);
 
         self.prefetch_limit.store(prefetch_limit);
+        let _ = self
+            .morsel_sizer
+            .set(execution_state.morsel_sizer.clone());
 
         if config::verbose() {
             eprintln!(
-                "[ParquetReaderBuilder]: prefetch_limit: {}",
-                self.prefetch_limit.load()
+                "[ParquetReaderBuilder]: prefetch_limit: {}, readahead_bytes: {:?}",
+                self.prefetch_limit.load(),
+                self.options.readahead_bytes
             );
         }
 
         self.prefetch_semaphore
             .set(Arc::new(tokio::sync::Semaphore::new(prefetch_limit)))
-            .unwrap()
+            .unwrap();
+
+        if let Some(readahead_bytes) = self.options.readahead_bytes {
+            self.readahead_bytes_semaphore
+                .set(Arc::new(tokio::sync::Semaphore::new(
+                    readahead_bytes
+                        .get()
+                        .min(tokio::sync::Semaphore::MAX_PERMITS),
+                )))
+                .unwrap()
+        }
     }
 
     fn set_io_metrics(&self, io_metrics: Arc<IOMetrics>) {
@@ -113,24 +141,34 @@ impl FileReaderBuilder for ParquetReaderBuilder {
 
         assert!(self.prefetch_limit.load() > 0);
 
+        let metadata = if scan_source_idx == 0 {
+            self.first_metadata.clone()
+        } else {
+            None
+        }
+        .or_else(|| {
+            let footers = config.footers.as_ref()?;
+            footers
+                .get(scan_source.as_scan_source_ref().as_path()?)
+                .cloned()
+        });
+
         let reader = ParquetFileReader {
             scan_source,
             cloud_options,
             config,
-            metadata: if scan_source_idx == 0 {
-                self.first_metadata.clone()
-            } else {
-                None
-            },
+            metadata,
             byte_source_builder,
             row_group_prefetch_sync: RowGroupPrefetchSync {
                 prefetch_limit: self.prefetch_limit.load(),
                 prefetch_semaphore: Arc::clone(self.prefetch_semaphore.get().unwrap()),
+                readahead_bytes_semaphore: self.readahead_bytes_semaphore.get().cloned(),
                 shared_prefetch_wait_group_slot: Arc::clone(&self.shared_prefetch_wait_group_slot),
                 prev_all_spawned: None,
                 current_all_spawned: None,
             },
             io_metrics: OptIOMetrics(self.io_metrics.get().cloned()),
+            morsel_sizer: self.morsel_sizer.get().cloned(),
             verbose,
 
             init_data: None,
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
+use polars_core::prelude::ArrowDataType;
 use polars_error::{PolarsResult, polars_ensure};
 use polars_io::prelude::_internal::PrefilterMaskSetting;
 use polars_io::prelude::ParallelStrategy;
@@ -10,8 +11,10 @@ use super::row_group_data_fetch::RowGroupDataFetcher;
 use super::row_group_decode::RowGroupDecoder;
 use super::{AsyncTaskData, ParquetReadImpl};
 use crate::async_executor;
-use crate::morsel::{Morsel, SourceToken, get_ideal_morsel_size};
+use crate::morsel::{Morsel, SourceToken};
 use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
+#[cfg(feature = "parquet_bloom_filter")]
+use crate::nodes::io_sources::parquet::bloom_filter::calculate_row_group_bloom_filter_skip_mask;
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
 use crate::nodes::io_sources::parquet::statistics::calculate_row_group_pred_pushdown_skip_mask;
 use crate::nodes::{MorselSeq, TaskPriority};
@@ -37,6 +40,7 @@ impl ParquetReadImpl {
 
         let projected_arrow_fields = self.projected_arrow_fields.clone();
         let is_full_projection = self.is_full_projection;
+        let struct_fields = self.options.struct_fields.clone();
 
         let row_group_prefetch_size = self.config.row_group_prefetch_size;
         let predicate = self.predicate.clone();
@@ -44,8 +48,9 @@ impl ParquetReadImpl {
 
         let row_group_decoder = self.init_row_group_decoder();
         let row_group_decoder = Arc::new(row_group_decoder);
+        let load_page_indexes = use_statistics && row_group_decoder.allow_column_predicates;
 
-        let ideal_morsel_size = get_ideal_morsel_size();
+        let ideal_morsel_size = self.config.ideal_morsel_size;
 
         if verbose {
             eprintln!("[ParquetFileReader]: ideal_morsel_size: {ideal_morsel_size}");
@@ -62,6 +67,11 @@ impl ParquetReadImpl {
         let row_index = self.row_index.clone();
 
         let rg_prefetch_semaphore = Arc::clone(&self.rg_prefetch_semaphore);
+        let readahead_bytes_semaphore = self.readahead_bytes_semaphore.clone();
+        let readahead_bytes_limit = self
+            .options
+            .readahead_bytes
+            .map_or(0, |x| x.get().min(tokio::sync::Semaphore::MAX_PERMITS));
         let rg_prefetch_prev_all_spawned = Option::take(&mut self.rg_prefetch_prev_all_spawned);
         let rg_prefetch_current_all_spawned =
             Option::take(&mut self.rg_prefetch_current_all_spawned);
@@ -132,14 +142,31 @@ impl ParquetReadImpl {
             )
             .await?;
 
+            #[cfg(feature = "parquet_bloom_filter")]
+            let row_group_mask = calculate_row_group_bloom_filter_skip_mask(
+                row_group_slice.clone(),
+                use_statistics,
+                predicate.as_ref(),
+                &metadata,
+                &projected_arrow_fields,
+                &byte_source,
+                row_group_mask,
+                verbose,
+            )
+            .await?;
+
             let mut row_group_data_fetcher = RowGroupDataFetcher {
                 projection: projected_arrow_fields.clone(),
                 is_full_projection,
+                struct_fields,
                 predicate,
+                load_page_indexes,
                 slice_range,
                 memory_prefetch_func,
                 metadata,
                 byte_source,
+                readahead_bytes_semaphore,
+                readahead_bytes_limit,
                 row_group_slice,
                 row_group_mask,
                 row_offset,
@@ -155,8 +182,13 @@ impl ParquetReadImpl {
                 let Some(prefetch) = row_group_data_fetcher.next().await else {
                     break;
                 };
+                let (prefetch, bytes_permit) = prefetch?;
 
-                if prefetch_send.send((prefetch?, fetch_permit)).await.is_err() {
+                if prefetch_send
+                    .send((prefetch, (fetch_permit, bytes_permit)))
+                    .await
+                    .is_err()
+                {
                     break;
                 }
             }
@@ -324,13 +356,17 @@ impl ParquetReadImpl {
             .is_some_and(|x| x.column_predicates.is_sumwise_complete)
             && row_index.is_none()
             && !projected_arrow_fields.iter().any(|x| {
-                x.arrow_field().dtype().is_nested()
+                // Struct columns can be decoded with a predicate on one of their fields.
+                let dtype = x.arrow_field().dtype();
+                (dtype.is_nested() && !matches!(dtype, ArrowDataType::Struct(_)))
                     || matches!(x, ArrowFieldProjection::Mapped { .. })
             });
 
         RowGroupDecoder {
             num_pipelines: self.config.num_pipelines,
             projected_arrow_fields,
+            struct_fields: self.options.struct_fields.clone(),
+            timestamp_overflow: self.options.timestamp_overflow(),
             row_index,
             predicate,
             allow_column_predicates,
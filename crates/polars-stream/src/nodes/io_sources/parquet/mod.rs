@@ -1,19 +1,26 @@
+use std::borrow::Cow;
 use std::sync::Arc;
 
 use arrow::datatypes::ArrowSchemaRef;
 use async_trait::async_trait;
-use polars_core::prelude::ArrowSchema;
+use polars_core::prelude::{ArrowDataType, ArrowField, ArrowSchema};
 use polars_core::schema::{Schema, SchemaExt, SchemaRef};
 use polars_error::{PolarsResult, polars_err};
 use polars_io::cloud::CloudOptions;
 use polars_io::predicates::ScanIOPredicate;
-use polars_io::prelude::{FileMetadata, ParquetOptions};
+use polars_io::prelude::{
+    FileMetadata, ParquetOptions, StructFieldSelection, infer_parquet_schema,
+    read_strings_as_categorical,
+};
 use polars_io::utils::byte_source::{BufferByteSource, DynByteSource, DynByteSourceBuilder};
 use polars_io::{RowIndex, pl_async};
-use polars_parquet::read::schema::infer_schema_with_options;
+use polars_parquet::read::{
+    ColumnChunkMetadata, RowGroupMetadata, n_columns, project_struct_fields,
+};
 use polars_plan::dsl::ScanSource;
 use polars_utils::IdxSize;
 use polars_utils::mem::prefetch::get_memory_prefetch_func;
+use polars_utils::pl_str::PlSmallStr;
 use polars_utils::slice_enum::Slice;
 
 use super::multi_scan::reader_interface::output::{FileReaderOutputRecv, FileReaderOutputSend};
@@ -23,7 +30,7 @@ use super::multi_scan::reader_interface::{
 use crate::async_executor::{self};
 use crate::async_primitives::wait_group::{WaitGroup, WaitToken};
 use crate::metrics::OptIOMetrics;
-use crate::morsel::SourceToken;
+use crate::morsel::{MorselSizer, SourceToken, get_ideal_morsel_size};
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::io_sources::parquet::projection::{
     ArrowFieldProjection, resolve_arrow_field_projections,
@@ -31,6 +38,8 @@ use crate::nodes::io_sources::parquet::projection::{
 use crate::nodes::{TaskPriority, io_sources};
 use crate::utils::tokio_handle_ext;
 
+#[cfg(feature = "parquet_bloom_filter")]
+mod bloom_filter;
 pub mod builder;
 pub mod init;
 mod metadata_utils;
@@ -48,6 +57,8 @@ pub struct ParquetFileReader {
     byte_source_builder: DynByteSourceBuilder,
     row_group_prefetch_sync: RowGroupPrefetchSync,
     io_metrics: OptIOMetrics,
+    /// Sizes the morsels. Falls back to the ideal morsel size of the config if unset.
+    morsel_sizer: Option<Arc<MorselSizer>>,
     verbose: bool,
 
     /// Set during initialize()
@@ -57,6 +68,7 @@ pub struct ParquetFileReader {
 struct RowGroupPrefetchSync {
     prefetch_limit: usize,
     prefetch_semaphore: Arc<tokio::sync::Semaphore>,
+    readahead_bytes_semaphore: Option<Arc<tokio::sync::Semaphore>>,
     shared_prefetch_wait_group_slot: Arc<std::sync::Mutex<Option<WaitGroup>>>,
 
     /// Waits for the previous reader to finish spawning prefetches.
@@ -121,13 +133,21 @@ impl FileReader for ParquetFileReader {
                 byte_source = Arc::new(DynByteSource::Buffer(BufferByteSource(full_bytes)));
             }
 
-            Arc::new(polars_parquet::parquet::read::deserialize_metadata(
+            Arc::new(polars_parquet::parquet::read::deserialize_footer(
                 metadata_bytes.as_ref(),
-                metadata_bytes.len() * 2 + 1024,
+                self.config.decryption.as_ref(),
             )?)
         };
 
-        let file_schema = Arc::new(infer_schema_with_options(&file_metadata, &None)?);
+        let mut file_schema = infer_parquet_schema(
+            &file_metadata,
+            &self.config.int96,
+            self.config.uuid_as_string,
+        )?;
+        if let Some(columns) = self.config.categorical_columns.as_deref() {
+            read_strings_as_categorical(&mut file_schema, columns)?;
+        }
+        let file_schema = Arc::new(file_schema);
 
         self.init_data = Some(InitializedState {
             file_metadata,
@@ -319,7 +339,8 @@ impl FileReader for ParquetFileReader {
                 .map(|x| x.parse::<usize>().expect("integer").max(1))
                 .unwrap_or(16_777_216);
 
-        let is_full_projection = projected_arrow_fields.len() == file_schema.len();
+        let is_full_projection = projected_arrow_fields.len() == file_schema.len()
+            && self.config.struct_fields.is_none();
 
         let (output_recv, handle) = ParquetReadImpl {
             projected_arrow_fields,
@@ -335,6 +356,10 @@ impl FileReader for ParquetFileReader {
             metadata: file_metadata,
             config: io_sources::parquet::Config {
                 num_pipelines,
+                ideal_morsel_size: self
+                    .morsel_sizer
+                    .as_ref()
+                    .map_or_else(get_ideal_morsel_size, |sizer| sizer.ideal_morsel_size()),
                 row_group_prefetch_size,
                 target_values_per_thread,
             },
@@ -342,6 +367,10 @@ impl FileReader for ParquetFileReader {
             memory_prefetch_func,
             row_index,
             rg_prefetch_semaphore: Arc::clone(&self.row_group_prefetch_sync.prefetch_semaphore),
+            readahead_bytes_semaphore: self
+                .row_group_prefetch_sync
+                .readahead_bytes_semaphore
+                .clone(),
             rg_prefetch_prev_all_spawned: Option::take(
                 &mut self.row_group_prefetch_sync.prev_all_spawned,
             ),
@@ -380,6 +409,22 @@ impl FileReader for ParquetFileReader {
     ) -> PolarsResult<IdxSize> {
         self._row_position_after_slice(pre_slice)
     }
+
+    async fn row_group_row_counts(&mut self) -> PolarsResult<Option<Vec<IdxSize>>> {
+        // The total row count fitting in IdxSize is checked in `_n_rows_in_file()`.
+        self._n_rows_in_file()?;
+
+        Ok(Some(
+            self.init_data
+                .as_ref()
+                .unwrap()
+                .file_metadata
+                .row_groups
+                .iter()
+                .map(|rg| rg.num_rows() as IdxSize)
+                .collect(),
+        ))
+    }
 }
 
 impl ParquetFileReader {
@@ -435,6 +480,8 @@ struct ParquetReadImpl {
     row_index: Option<RowIndex>,
 
     rg_prefetch_semaphore: Arc<tokio::sync::Semaphore>,
+    /// Limits the total number of bytes of the prefetched row groups.
+    readahead_bytes_semaphore: Option<Arc<tokio::sync::Semaphore>>,
     rg_prefetch_prev_all_spawned: Option<WaitGroup>,
     rg_prefetch_current_all_spawned: Option<WaitToken>,
     disable_morsel_split: bool,
@@ -443,6 +490,9 @@ struct ParquetReadImpl {
 #[derive(Debug)]
 struct Config {
     num_pipelines: usize,
+    /// The number of rows of the morsels, taken from the morsel sizer of the query when the
+    /// file is opened.
+    ideal_morsel_size: usize,
     /// Number of row groups to pre-fetch concurrently, this can be across files
     row_group_prefetch_size: usize,
     /// Minimum number of values for a parallel spawned task to process to amortize
@@ -459,3 +509,56 @@ impl ParquetReadImpl {
         self.init_morsel_distributor()
     }
 }
+
+/// The arrow field of the (nested) struct field at `path` of `field`, along with the index of its
+/// first leaf column among the leaf columns of `field`.
+fn struct_field_leaf<'a>(
+    field: &'a ArrowField,
+    path: &[PlSmallStr],
+) -> Option<(&'a ArrowField, usize)> {
+    path.iter()
+        .try_fold((field, 0), |(field, leaf_offset), name| {
+            let ArrowDataType::Struct(fields) = field.dtype() else {
+                return None;
+            };
+            let idx = fields.iter().position(|f| f.name == *name)?;
+            let leaf_offset = leaf_offset
+                + fields[..idx]
+                    .iter()
+                    .map(|f| n_columns(f.dtype()))
+                    .sum::<usize>();
+            Some((&fields[idx], leaf_offset))
+        })
+}
+
+/// The column chunks of `arrow_field` in the row group, along with the field they decode to. Of
+/// the struct columns in `struct_fields` (see [`ParquetOptions::struct_fields`]), only the leaf
+/// columns of the listed fields are returned and the field is restricted to those fields.
+///
+/// Returns `None` if the column is not in the row group.
+fn columns_to_decode<'a, 'b>(
+    row_group_metadata: &'a RowGroupMetadata,
+    arrow_field: &'b ArrowField,
+    struct_fields: Option<&StructFieldSelection>,
+) -> Option<(Cow<'b, ArrowField>, Vec<&'a ColumnChunkMetadata>)> {
+    let columns = row_group_metadata.columns_under_root_iter(&arrow_field.name)?;
+
+    let projected = struct_fields
+        .and_then(|struct_fields| {
+            struct_fields
+                .iter()
+                .find(|(name, _)| *name == arrow_field.name)
+        })
+        .and_then(|(_, fields)| project_struct_fields(arrow_field, fields));
+
+    Some(match projected {
+        None => (Cow::Borrowed(arrow_field), columns.collect()),
+        Some((field, leaf_idxs)) => {
+            let columns = columns.collect::<Vec<_>>();
+            (
+                Cow::Owned(field),
+                leaf_idxs.into_iter().map(|i| columns[i]).collect(),
+            )
+        },
+    })
+}
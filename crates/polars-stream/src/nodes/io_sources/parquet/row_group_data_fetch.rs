@@ -2,16 +2,18 @@ use std::ops::Range;
 use std::sync::Arc;
 
 use polars_buffer::Buffer;
-use polars_core::prelude::PlHashMap;
+use polars_core::prelude::{ArrowField, PlHashMap};
 use polars_core::series::IsSorted;
 use polars_core::utils::arrow::bitmap::Bitmap;
 use polars_error::PolarsResult;
 use polars_io::predicates::ScanIOPredicate;
-use polars_io::prelude::{FileMetadata, create_sorting_map};
+use polars_io::prelude::{FileMetadata, StructFieldSelection, create_sorting_map};
 use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::metadata::ColumnPageIndex;
+use polars_parquet::parquet::read::read_column_page_index;
 use polars_parquet::read::RowGroupMetadata;
-use polars_utils::pl_str::PlSmallStr;
 
+use super::columns_to_decode;
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
 use crate::utils::tokio_handle_ext;
 
@@ -27,12 +29,20 @@ pub(super) struct RowGroupData {
 pub(super) struct RowGroupDataFetcher {
     pub(super) projection: Arc<[ArrowFieldProjection]>,
     pub(super) is_full_projection: bool,
-    #[allow(unused)] // TODO: Fix!
+    /// See [`polars_io::prelude::ParquetOptions::struct_fields`].
+    pub(super) struct_fields: Option<StructFieldSelection>,
     pub(super) predicate: Option<ScanIOPredicate>,
+    /// Load the page indexes of the columns with a column predicate, so that the decoder can
+    /// skip their data pages.
+    pub(super) load_page_indexes: bool,
     pub(super) slice_range: Option<Range<usize>>,
     pub(super) memory_prefetch_func: fn(&[u8]) -> (),
     pub(super) metadata: Arc<FileMetadata>,
     pub(super) byte_source: Arc<DynByteSource>,
+    /// Limits the total number of bytes of the row groups that are fetched but not yet consumed.
+    pub(super) readahead_bytes_semaphore: Option<Arc<tokio::sync::Semaphore>>,
+    /// The number of permits of `readahead_bytes_semaphore`.
+    pub(super) readahead_bytes_limit: usize,
 
     pub(super) row_group_slice: Range<usize>,
     pub(super) row_group_mask: Option<Bitmap>,
@@ -41,9 +51,16 @@ pub(super) struct RowGroupDataFetcher {
 }
 
 impl RowGroupDataFetcher {
+    /// Returns the fetch of the next row group, along with the part of the readahead byte budget
+    /// it holds. The budget is released once the permit is dropped.
     pub(super) async fn next(
         &mut self,
-    ) -> Option<PolarsResult<tokio_handle_ext::AbortOnDropHandle<PolarsResult<RowGroupData>>>> {
+    ) -> Option<
+        PolarsResult<(
+            tokio_handle_ext::AbortOnDropHandle<PolarsResult<RowGroupData>>,
+            Option<tokio::sync::OwnedSemaphorePermit>,
+        )>,
+    > {
         while !self.row_group_slice.is_empty() {
             let idx = self.row_group_slice.start;
             self.row_group_slice.start += 1;
@@ -77,15 +94,56 @@ impl RowGroupDataFetcher {
                 }
             }
 
+            let bytes_permit = match self.readahead_bytes_semaphore.as_ref() {
+                // Mapped files are not downloaded, so they don't count towards the budget.
+                Some(semaphore)
+                    if !matches!(self.byte_source.as_ref(), DynByteSource::Buffer(_)) =>
+                {
+                    let num_bytes: usize = if self.is_full_projection {
+                        let range = row_group_metadata.full_byte_range();
+                        (range.end - range.start) as usize
+                    } else {
+                        get_row_group_byte_ranges_for_projection(
+                            row_group_metadata,
+                            &mut self.projection.iter().map(|x| x.arrow_field()),
+                            self.struct_fields.as_ref(),
+                        )
+                        .map(|range| range.len())
+                        .sum()
+                    };
+
+                    // A row group that is larger than the budget takes all of it.
+                    let num_permits = num_bytes
+                        .clamp(1, self.readahead_bytes_limit)
+                        .try_into()
+                        .unwrap_or(u32::MAX);
+
+                    Some(
+                        semaphore
+                            .clone()
+                            .acquire_many_owned(num_permits)
+                            .await
+                            .unwrap(),
+                    )
+                },
+                _ => None,
+            };
+
             let metadata = self.metadata.clone();
             let current_byte_source = self.byte_source.clone();
             let projection = self.projection.clone();
             let is_full_projection = self.is_full_projection;
+            let struct_fields = self.struct_fields.clone();
             let memory_prefetch_func = self.memory_prefetch_func;
+            let predicate = self.predicate.clone().filter(|_| self.load_page_indexes);
             let io_runtime = polars_io::pl_async::get_runtime();
 
             let handle = io_runtime.spawn(async move {
                 let row_group_metadata = &metadata.row_groups[idx];
+                if let Some(predicate) = predicate.as_ref() {
+                    load_page_indexes(row_group_metadata, predicate, &current_byte_source).await?;
+                }
+
                 let fetched_bytes =
                     if let DynByteSource::Buffer(mem_slice) = current_byte_source.as_ref() {
                         // Skip byte range calculation for `no_prefetch`.
@@ -97,7 +155,8 @@ impl RowGroupDataFetcher {
                             if !is_full_projection {
                                 for range in get_row_group_byte_ranges_for_projection(
                                     row_group_metadata,
-                                    &mut projection.iter().map(|x| &x.arrow_field().name),
+                                    &mut projection.iter().map(|x| x.arrow_field()),
+                                    struct_fields.as_ref(),
                                 ) {
                                     memory_prefetch_func(unsafe { slice.get_unchecked(range) })
                                 }
@@ -120,7 +179,8 @@ impl RowGroupDataFetcher {
                     } else if !is_full_projection {
                         let mut ranges = get_row_group_byte_ranges_for_projection(
                             row_group_metadata,
-                            &mut projection.iter().map(|x| &x.arrow_field().name),
+                            &mut projection.iter().map(|x| x.arrow_field()),
+                            struct_fields.as_ref(),
                         )
                         .collect::<Vec<_>>();
 
@@ -162,7 +222,7 @@ impl RowGroupDataFetcher {
             });
 
             let handle = tokio_handle_ext::AbortOnDropHandle(handle);
-            return Some(Ok(handle));
+            return Some(Ok((handle, bytes_permit)));
         }
 
         None
@@ -193,17 +253,84 @@ impl FetchedBytes {
     }
 }
 
+/// Loads the page indexes of the column chunks with a specialized column predicate, see
+/// [`ColumnChunkMetadata::page_index`].
+async fn load_page_indexes(
+    row_group_metadata: &RowGroupMetadata,
+    predicate: &ScanIOPredicate,
+    byte_source: &DynByteSource,
+) -> PolarsResult<()> {
+    let columns = predicate
+        .column_predicates
+        .predicates
+        .iter()
+        .filter(|(name, (_, specialized))| {
+            specialized.is_some()
+                && !predicate
+                    .column_predicates
+                    .struct_field_paths
+                    .contains_key(*name)
+        })
+        .filter_map(|(name, _)| {
+            // Nested columns are not decoded with the page indexes.
+            let mut iter = row_group_metadata.columns_under_root_iter(name)?;
+            let column = iter.next()?;
+            iter.next().is_none().then_some(column)
+        })
+        .filter(|column| {
+            column.page_index().is_none()
+                && column.offset_index_range().is_some()
+                && !column.is_encrypted()
+        })
+        .collect::<Vec<_>>();
+
+    if columns.is_empty() {
+        return Ok(());
+    }
+
+    if let DynByteSource::Buffer(mem_slice) = byte_source {
+        for column in columns {
+            read_column_page_index(column, mem_slice.0.as_ref())?;
+        }
+        return Ok(());
+    }
+
+    let to_usize_range = |range: Range<u64>| range.start as usize..range.end as usize;
+    let mut ranges = columns
+        .iter()
+        .flat_map(|column| {
+            let offset_index_range = column.offset_index_range().map(to_usize_range);
+            let column_index_range = column.column_index_range().map(to_usize_range);
+            offset_index_range.into_iter().chain(column_index_range)
+        })
+        .collect::<Vec<_>>();
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+    for column in columns {
+        let get = |range: Range<u64>| bytes_map.get(&(range.start as usize));
+        let Some(offset_index) = column.offset_index_range().and_then(get) else {
+            continue;
+        };
+        let column_index = column.column_index_range().and_then(get);
+        let page_index =
+            ColumnPageIndex::try_from_bytes(column_index.map(|b| b.as_ref()), offset_index)?;
+        column.set_page_index(Arc::new(page_index));
+    }
+
+    Ok(())
+}
+
 fn get_row_group_byte_ranges_for_projection<'a>(
     row_group_metadata: &'a RowGroupMetadata,
-    columns: &'a mut dyn Iterator<Item = &PlSmallStr>,
+    columns: &'a mut dyn Iterator<Item = &'a ArrowField>,
+    struct_fields: Option<&'a StructFieldSelection>,
 ) -> impl Iterator<Item = std::ops::Range<usize>> + 'a {
-    columns.flat_map(|col_name| {
-        row_group_metadata
-            .columns_under_root_iter(col_name)
+    columns.flat_map(move |arrow_field| {
+        columns_to_decode(row_group_metadata, arrow_field, struct_fields)
             // `Option::into_iter` so that we return an empty iterator for the
             // `allow_missing_columns` case
             .into_iter()
-            .flatten()
+            .flat_map(|(_, columns)| columns)
             .map(|col| {
                 let byte_range = col.byte_range();
                 byte_range.start as usize..byte_range.end as usize
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -5,18 +6,22 @@ use polars_core::frame::DataFrame;
 use polars_core::prelude::{ArrowField, BooleanChunked, ChunkFilter, Column, DataType, IntoColumn};
 use polars_core::series::Series;
 use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_bail};
 use polars_io::RowIndex;
 use polars_io::predicates::{
     ColumnPredicateExpr, ColumnPredicates, ScanIOPredicate, SpecializedColumnPredicate,
 };
 pub use polars_io::prelude::_internal::PrefilterMaskSetting;
-use polars_io::prelude::try_set_sorted_flag;
-use polars_parquet::read::{Filter, ParquetType, PredicateFilter, PrimitiveLogicalType};
+use polars_io::prelude::{StructFieldSelection, try_set_sorted_flag};
+use polars_parquet::read::{
+    Filter, ParquetType, PredicateFilter, PrimitiveLogicalType, TimestampOverflowOptions,
+    fill_projected_struct_fields,
+};
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::{IdxSize, UnitVec};
 
 use super::row_group_data_fetch::RowGroupData;
+use super::{columns_to_decode, struct_field_leaf};
 use crate::async_executor::TaskPriority;
 use crate::async_primitives::opt_spawned_future::parallelize_first_to_local;
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
@@ -25,6 +30,10 @@ use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
 pub(super) struct RowGroupDecoder {
     pub(super) num_pipelines: usize,
     pub(super) projected_arrow_fields: Arc<[ArrowFieldProjection]>,
+    /// See [`polars_io::prelude::ParquetOptions::struct_fields`].
+    pub(super) struct_fields: Option<StructFieldSelection>,
+    /// See [`polars_io::prelude::ParquetOptions::timestamp_overflow`].
+    pub(super) timestamp_overflow: TimestampOverflowOptions,
     pub(super) allow_column_predicates: bool,
     pub(super) row_index: Option<RowIndex>,
     pub(super) predicate: Option<ScanIOPredicate>,
@@ -41,16 +50,13 @@ impl RowGroupDecoder {
         &self,
         mut row_group_data: RowGroupData,
     ) -> PolarsResult<DataFrame> {
-        // If the slice consumes the entire row-group. Don't slice. This allows for prefiltering to
-        // happen more often until we properly support prefiltering with pre-slices.
+        // If the slice consumes the entire row-group. Don't slice. This allows column predicates to
+        // be used during the pre-filtered decode, as they always decode the entire row-group.
         row_group_data.slice.take_if(|slice| {
             slice.0 == 0 && slice.1 >= row_group_data.row_group_metadata.num_rows()
         });
 
-        if self.use_prefiltered.is_some()
-            && row_group_data.slice.is_none()
-            && !self.predicate_field_indices.is_empty()
-        {
+        if self.use_prefiltered.is_some() && !self.predicate_field_indices.is_empty() {
             self.row_group_data_to_df_prefiltered(row_group_data).await
         } else {
             self.row_group_data_to_df_impl(row_group_data).await
@@ -184,6 +190,8 @@ impl RowGroupDecoder {
 
         let task_handles = {
             let projected_arrow_fields = projected_arrow_fields.clone();
+            let struct_fields = self.struct_fields.clone();
+            let timestamp_overflow = self.timestamp_overflow;
             let filter = filter.clone();
 
             parallelize_first_to_local(
@@ -193,6 +201,7 @@ impl RowGroupDecoder {
                     .map(move |offset| {
                         let row_group_data = row_group_data_2.clone();
                         let projected_arrow_fields = projected_arrow_fields.clone();
+                        let struct_fields = struct_fields.clone();
                         let filter = filter.clone();
                         let get_projected_field_at_output_index =
                             get_projected_field_at_output_index.clone();
@@ -209,9 +218,11 @@ impl RowGroupDecoder {
 
                                     let (col, pred_true_mask) = decode_column(
                                         projection.arrow_field(),
+                                        struct_fields.as_ref(),
                                         &row_group_data,
                                         filter.clone(),
                                         expected_num_rows,
+                                        timestamp_overflow,
                                     )?;
 
                                     let col = projection.apply_transform(col)?;
@@ -234,14 +245,17 @@ impl RowGroupDecoder {
 
 fn decode_column(
     arrow_field: &ArrowField,
+    struct_fields: Option<&StructFieldSelection>,
     row_group_data: &RowGroupData,
     filter: Option<polars_parquet::read::Filter>,
     expected_num_rows: usize,
+    timestamp_overflow: TimestampOverflowOptions,
 ) -> PolarsResult<(Column, Bitmap)> {
-    let Some(iter) = row_group_data
-        .row_group_metadata
-        .columns_under_root_iter(&arrow_field.name)
-    else {
+    let Some((decoded_field, columns)) = columns_to_decode(
+        &row_group_data.row_group_metadata,
+        arrow_field,
+        struct_fields,
+    ) else {
         return Ok((
             Column::full_null(
                 arrow_field.name.clone(),
@@ -252,7 +266,8 @@ fn decode_column(
         ));
     };
 
-    let columns_to_deserialize = iter
+    let columns_to_deserialize = columns
+        .into_iter()
         .map(|col_md| {
             let byte_range = col_md.byte_range();
 
@@ -267,11 +282,18 @@ fn decode_column(
 
     let skip_num_rows_check = matches!(filter, Some(Filter::Predicate(_)));
 
-    let (arrays, pred_true_mask) = polars_io::prelude::_internal::to_deserializer(
+    let (mut arrays, pred_true_mask) = polars_io::prelude::_internal::to_deserializer(
         columns_to_deserialize,
-        arrow_field.clone(),
+        decoded_field.as_ref().clone(),
         filter,
+        timestamp_overflow,
     )?;
+    if let Cow::Owned(_) = decoded_field {
+        arrays = arrays
+            .into_iter()
+            .map(|array| fill_projected_struct_fields(array, arrow_field))
+            .collect();
+    }
 
     if !skip_num_rows_check {
         let num_rows = arrays.iter().map(|array| array.len()).sum::<usize>();
@@ -354,35 +376,62 @@ fn calc_cols_per_thread(n_rows_per_col: usize, target_n_rows_per_thread: usize)
 
 fn decode_column_in_filter(
     arrow_field: &ArrowField,
+    struct_fields: Option<&StructFieldSelection>,
     use_column_predicates: bool,
     column_predicates: &ColumnPredicates,
     row_group_data: &RowGroupData,
-    projection_height: usize,
+    slice_range: core::ops::Range<usize>,
+    timestamp_overflow: TimestampOverflowOptions,
 ) -> PolarsResult<(Column, Bitmap)> {
-    let mut filter = None;
+    let projection_height = slice_range.len();
+    let mut filter = Some(Filter::Range(slice_range));
     let mut constant = None;
     if use_column_predicates {
         if let Some((column_predicate, specialized)) =
             column_predicates.predicates.get(&arrow_field.name)
         {
-            constant = specialized.as_ref().and_then(|s| match s {
-                SpecializedColumnPredicate::Equal(sc) if !sc.is_null() => Some(sc),
-                _ => None,
-            });
+            // Predicates on a struct column apply to one of its fields.
+            let (predicate_field, struct_field_path) =
+                match column_predicates.struct_field_paths.get(&arrow_field.name) {
+                    Some(path) => {
+                        let Some((field, _)) = struct_field_leaf(arrow_field, path) else {
+                            polars_bail!(
+                                ColumnNotFound: "struct field {:?} of column '{}'",
+                                path, arrow_field.name
+                            );
+                        };
+                        (field, path.clone())
+                    },
+                    None => {
+                        constant = specialized.as_ref().and_then(|s| match s {
+                            SpecializedColumnPredicate::Equal(sc) if !sc.is_null() => Some(sc),
+                            _ => None,
+                        });
+                        (arrow_field, Vec::new())
+                    },
+                };
 
             let p = ColumnPredicateExpr::new(
                 arrow_field.name.clone(),
-                DataType::from_arrow_field(arrow_field),
+                DataType::from_arrow_field(predicate_field),
                 column_predicate.clone(),
                 specialized.clone(),
             );
             filter = Some(Filter::Predicate(PredicateFilter {
                 predicate: Arc::new(p) as _,
                 include_values: constant.is_none(),
+                struct_field_path,
             }));
         }
     }
-    let (mut c, m) = decode_column(arrow_field, row_group_data, filter, projection_height)?;
+    let (mut c, m) = decode_column(
+        arrow_field,
+        struct_fields,
+        row_group_data,
+        filter,
+        projection_height,
+        timestamp_overflow,
+    )?;
 
     if let Some(constant) = constant {
         c = Column::new_scalar(c.name().clone(), constant.clone(), m.set_bits());
@@ -396,11 +445,15 @@ impl RowGroupDecoder {
         &self,
         row_group_data: RowGroupData,
     ) -> PolarsResult<DataFrame> {
-        debug_assert!(row_group_data.slice.is_none()); // Invariant of the optimizer.
         assert!(self.predicate_field_indices.len() <= self.projected_arrow_fields.len());
 
         let row_group_data = Arc::new(row_group_data);
-        let projection_height = row_group_data.row_group_metadata.num_rows();
+        let num_rows = row_group_data.row_group_metadata.num_rows();
+        let slice_range = row_group_data
+            .slice
+            .map(|(offset, len)| offset..offset + len)
+            .unwrap_or(0..num_rows);
+        let projection_height = slice_range.len();
 
         let mut live_columns = Vec::with_capacity(
             self.row_index.is_some() as usize
@@ -411,16 +464,15 @@ impl RowGroupDecoder {
             self.row_index.is_some() as usize + self.predicate_field_indices.len(),
         );
 
-        if let Some(s) = self.materialize_row_index(
-            row_group_data.as_ref(),
-            0..row_group_data.row_group_metadata.num_rows(),
-        )? {
+        if let Some(s) = self.materialize_row_index(row_group_data.as_ref(), slice_range.clone())? {
             live_columns.push(s);
         }
 
         let scan_predicate = self.predicate.as_ref().unwrap();
 
+        // Column predicates are evaluated on the entire row-group.
         let use_column_predicates = self.allow_column_predicates
+            && row_group_data.slice.is_none()
             && !row_group_data
                 .row_group_metadata
                 .parquet_columns()
@@ -429,7 +481,10 @@ impl RowGroupDecoder {
                     let ParquetType::PrimitiveType(pt) = c.descriptor().base_type.deref() else {
                         return false;
                     };
-                    matches!(pt.logical_type, Some(PrimitiveLogicalType::Float16))
+                    matches!(
+                        pt.logical_type,
+                        Some(PrimitiveLogicalType::Float16 | PrimitiveLogicalType::Uuid)
+                    )
                 });
 
         let cols_per_thread = (self
@@ -440,7 +495,10 @@ impl RowGroupDecoder {
         let task_handles = {
             let predicate_field_indices = self.predicate_field_indices.clone();
             let projected_arrow_fields = self.projected_arrow_fields.clone();
+            let struct_fields = self.struct_fields.clone();
+            let timestamp_overflow = self.timestamp_overflow;
             let row_group_data = row_group_data.clone();
+            let slice_range = slice_range.clone();
 
             parallelize_first_to_local(
                 TaskPriority::Low,
@@ -450,7 +508,9 @@ impl RowGroupDecoder {
                         let row_group_data = row_group_data.clone();
                         let predicate_field_indices = predicate_field_indices.clone();
                         let projected_arrow_fields = projected_arrow_fields.clone();
+                        let struct_fields = struct_fields.clone();
                         let column_predicates = scan_predicate.column_predicates.clone();
+                        let slice_range = slice_range.clone();
 
                         async move {
                             (offset
@@ -470,10 +530,12 @@ impl RowGroupDecoder {
 
                                     let (col, pred_true_mask) = decode_column_in_filter(
                                         projection.arrow_field(),
+                                        struct_fields.as_ref(),
                                         use_column_predicates,
                                         column_predicates.as_ref(),
                                         row_group_data.as_ref(),
-                                        projection_height,
+                                        slice_range.clone(),
+                                        timestamp_overflow,
                                     )?;
 
                                     let col = projection.apply_transform(col)?;
@@ -526,9 +588,7 @@ impl RowGroupDecoder {
                 )
             }
         } else {
-            let mut live_df = unsafe {
-                DataFrame::new_unchecked(row_group_data.row_group_metadata.num_rows(), live_columns)
-            };
+            let mut live_df = unsafe { DataFrame::new_unchecked(projection_height, live_columns) };
 
             let mask = scan_predicate.predicate.evaluate_io(&live_df)?;
             let mask = mask.bool().unwrap();
@@ -570,6 +630,17 @@ impl RowGroupDecoder {
 
         let expected_num_rows = mask_bitmap.set_bits();
 
+        // The decoder takes a mask over the entire row-group.
+        let row_group_mask_bitmap = if projection_height == num_rows {
+            mask_bitmap
+        } else {
+            let mut row_group_mask = MutableBitmap::with_capacity(num_rows);
+            row_group_mask.extend_constant(slice_range.start, false);
+            row_group_mask.extend_from_bitmap(&mask_bitmap);
+            row_group_mask.extend_constant(num_rows - slice_range.end, false);
+            row_group_mask.freeze()
+        };
+
         let cols_per_thread = (self
             .predicate_field_indices
             .len()
@@ -580,6 +651,8 @@ impl RowGroupDecoder {
             let non_predicate_field_indices = self.non_predicate_field_indices.clone();
             let non_predicate_len = non_predicate_field_indices.len();
             let projected_arrow_fields = self.projected_arrow_fields.clone();
+            let struct_fields = self.struct_fields.clone();
+            let timestamp_overflow = self.timestamp_overflow;
             let row_group_data = row_group_data.clone();
 
             parallelize_first_to_local(
@@ -590,8 +663,10 @@ impl RowGroupDecoder {
                         let row_group_data = row_group_data.clone();
                         let non_predicate_field_indices = non_predicate_field_indices.clone();
                         let projected_arrow_fields = projected_arrow_fields.clone();
+                        let struct_fields = struct_fields.clone();
                         let mask = mask.clone();
-                        let mask_bitmap = mask_bitmap.clone();
+                        let row_group_mask_bitmap = row_group_mask_bitmap.clone();
+                        let slice_range = slice_range.clone();
 
                         async move {
                             (offset
@@ -604,10 +679,13 @@ impl RowGroupDecoder {
 
                                     let col = decode_column_prefiltered(
                                         projection.arrow_field(),
+                                        struct_fields.as_ref(),
                                         row_group_data.as_ref(),
+                                        slice_range.clone(),
                                         &mask,
-                                        &mask_bitmap,
+                                        &row_group_mask_bitmap,
                                         expected_num_rows,
+                                        timestamp_overflow,
                                     )?;
 
                                     projection.apply_transform(col)
@@ -634,17 +712,31 @@ impl RowGroupDecoder {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn decode_column_prefiltered(
     arrow_field: &ArrowField,
+    struct_fields: Option<&StructFieldSelection>,
     row_group_data: &RowGroupData,
+    slice_range: core::ops::Range<usize>,
     mask: &BooleanChunked,
-    mask_bitmap: &Bitmap,
+    row_group_mask_bitmap: &Bitmap,
     expected_num_rows: usize,
+    timestamp_overflow: TimestampOverflowOptions,
 ) -> PolarsResult<Column> {
-    let Some(iter) = row_group_data
-        .row_group_metadata
-        .columns_under_root_iter(&arrow_field.name)
-    else {
+    // The predicate did not select any rows, so there is nothing to decode.
+    if expected_num_rows == 0 {
+        return Ok(Column::full_null(
+            arrow_field.name.clone(),
+            0,
+            &DataType::from_arrow_field(arrow_field),
+        ));
+    }
+
+    let Some((decoded_field, columns)) = columns_to_decode(
+        &row_group_data.row_group_metadata,
+        arrow_field,
+        struct_fields,
+    ) else {
         return Ok(Column::full_null(
             arrow_field.name.clone(),
             expected_num_rows,
@@ -652,7 +744,8 @@ fn decode_column_prefiltered(
         ));
     };
 
-    let columns_to_deserialize = iter
+    let columns_to_deserialize = columns
+        .into_iter()
         .map(|col_md| {
             let byte_range = col_md.byte_range();
 
@@ -667,14 +760,25 @@ fn decode_column_prefiltered(
 
     let prefilter = !arrow_field.dtype.is_nested();
 
-    let deserialize_filter =
-        prefilter.then(|| polars_parquet::read::Filter::Mask(mask_bitmap.clone()));
+    // Nested columns are decoded for the entire slice and filtered afterwards.
+    let deserialize_filter = if prefilter {
+        polars_parquet::read::Filter::Mask(row_group_mask_bitmap.clone())
+    } else {
+        polars_parquet::read::Filter::Range(slice_range)
+    };
 
-    let (array, _) = polars_io::prelude::_internal::to_deserializer(
+    let (mut array, _) = polars_io::prelude::_internal::to_deserializer(
         columns_to_deserialize,
-        arrow_field.clone(),
-        deserialize_filter,
+        decoded_field.as_ref().clone(),
+        Some(deserialize_filter),
+        timestamp_overflow,
     )?;
+    if let Cow::Owned(_) = decoded_field {
+        array = array
+            .into_iter()
+            .map(|array| fill_projected_struct_fields(array, arrow_field))
+            .collect();
+    }
 
     let mut series = Series::try_from((arrow_field, array))?;
 
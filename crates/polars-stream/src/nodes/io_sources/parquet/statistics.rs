@@ -187,15 +187,10 @@ fn load_parquet_column_statistics(
         return null_statistics();
     };
 
-    // 0 is possible for possible for empty structs.
-    //
-    // 2+ is for structs. We don't support reading nested statistics for now. It does not
-    // really make any sense at the moment with how we structure statistics.
-    if idxs.is_empty() || idxs.len() > 1 {
+    // Empty is possible for empty structs. The leaves of structs are stored next to each other.
+    let Some(&idx) = idxs.first() else {
         return null_statistics();
-    }
-
-    let idx = idxs[0];
+    };
 
     let Some(statistics) = deserialize_all(arrow_field, row_groups, idx)? else {
         return null_statistics();
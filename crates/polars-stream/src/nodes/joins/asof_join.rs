@@ -358,6 +358,8 @@ async fn compute_and_emit_task(
             any_key_is_temporary_col || params.args.should_coalesce(),
             options.allow_eq,
             options.check_sortedness,
+            options.distance_column.clone(),
+            options.indicator_column.clone(),
         )?;
 
         // Drop any temporary key columns that were added
@@ -8,7 +8,6 @@ use polars_ops::frame::{JoinArgs, JoinBuildSide, MaintainOrderJoin};
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
 
-use crate::morsel::get_ideal_morsel_size;
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::in_memory_sink::InMemorySinkNode;
 
@@ -161,7 +160,7 @@ impl ComputeNode for CrossJoinNode {
                 assert!(recv_ports[build_idx].is_none());
                 let receivers = recv_ports[probe_idx].take().unwrap().parallel();
                 let senders = send_ports[0].take().unwrap().parallel();
-                let ideal_morsel_size = get_ideal_morsel_size();
+                let ideal_morsel_size = state.morsel_sizer.ideal_morsel_size();
 
                 for (mut recv, mut send) in receivers.into_iter().zip(senders) {
                     let left_is_build = self.left_is_build;
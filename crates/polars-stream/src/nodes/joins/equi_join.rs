@@ -29,7 +29,7 @@ use super::{BufferedStream, JOIN_SAMPLE_LIMIT, LOPSIDED_SAMPLE_FACTOR};
 use crate::async_executor;
 use crate::async_primitives::wait_group::WaitGroup;
 use crate::expression::StreamExpr;
-use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::morsel::SourceToken;
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::in_memory_source::InMemorySourceNode;
 
@@ -768,7 +768,7 @@ impl ProbeState {
         let mut probe_match = Vec::new();
         let mut max_seq = MorselSeq::default();
 
-        let probe_limit = get_ideal_morsel_size() as IdxSize;
+        let probe_limit = state.morsel_sizer.ideal_morsel_size() as IdxSize;
         let mark_matches = params.emit_unmatched_build();
         let emit_unmatched = params.emit_unmatched_probe();
 
@@ -1098,15 +1098,15 @@ impl EmitUnmatchedState {
         &mut self,
         mut send: PortSender,
         params: &EquiJoinParams,
-        num_pipelines: usize,
+        state: &StreamingExecutionState,
     ) -> PolarsResult<()> {
         let total_len: usize = self
             .partitions
             .iter()
             .map(|p| p.hash_table.num_keys() as usize)
             .sum();
-        let ideal_morsel_count = (total_len / get_ideal_morsel_size()).max(1);
-        let morsel_count = ideal_morsel_count.next_multiple_of(num_pipelines);
+        let ideal_morsel_count = (total_len / state.morsel_sizer.ideal_morsel_size()).max(1);
+        let morsel_count = ideal_morsel_count.next_multiple_of(state.num_pipelines);
         let morsel_size = total_len.div_ceil(morsel_count).max(1);
 
         let wait_group = WaitGroup::default();
@@ -1546,7 +1546,7 @@ impl ComputeNode for EquiJoinNode {
                 let send = send_ports[0].take().unwrap().serial();
                 join_handles.push(scope.spawn_task(
                     TaskPriority::Low,
-                    emit_state.emit_unmatched(send, &self.params, state.num_pipelines),
+                    emit_state.emit_unmatched(send, &self.params, state),
                 ));
             },
             EquiJoinState::EmitUnmatchedBuildInOrder(src_node) => {
@@ -4,7 +4,7 @@ use polars_core::schema::Schema;
 
 use super::compute_node_prelude::*;
 use crate::async_primitives::wait_group::WaitGroup;
-use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::morsel::SourceToken;
 use crate::nodes::in_memory_sink::InMemorySinkNode;
 pub enum RepeatNode {
     GatheringParams {
@@ -106,7 +106,8 @@ impl ComputeNode for RepeatNode {
 
                 let mut send = send_ports[0].take().unwrap().serial();
 
-                let ideal_morsel_count = (*repeats_left / get_ideal_morsel_size()).max(1);
+                let ideal_morsel_count =
+                    (*repeats_left / state.morsel_sizer.ideal_morsel_size()).max(1);
                 let morsel_count = ideal_morsel_count.next_multiple_of(state.num_pipelines);
                 let morsel_size = repeats_left.div_ceil(morsel_count).max(1);
 
@@ -65,10 +65,12 @@ impl RollingGroupBy {
         let (tu, tz) = match key_dtype {
             DT::Datetime(tu, tz) => (*tu, tz.clone()),
             DT::Date => (TimeUnit::Microseconds, None),
-            DT::UInt32 | DT::UInt64 | DT::Int64 | DT::Int32 => (TimeUnit::Nanoseconds, None),
+            dt if dt.is_integer() && !matches!(dt, DT::Int128 | DT::UInt128) => {
+                (TimeUnit::Nanoseconds, None)
+            },
             dt => polars_bail!(
                 ComputeError:
-                "expected any of the following dtypes: {{ Date, Datetime, Int32, Int64, UInt32, UInt64 }}, got {}",
+                "expected any of the following dtypes: {{ Date, Datetime, Int8, Int16, Int32, Int64, UInt8, UInt16, UInt32, UInt64 }}, got {}",
                 dt
             ),
         };
@@ -336,13 +338,14 @@ impl ComputeNode for RollingGroupBy {
                     DT::Date => {
                         morsel_index_column.cast(&DT::Datetime(TimeUnit::Microseconds, None))?
                     },
-                    DT::UInt32 | DT::UInt64 | DT::Int32 => morsel_index_column
-                        .cast(&DT::Int64)?
-                        .cast(&DT::Datetime(TimeUnit::Nanoseconds, None))?,
                     DT::Int64 => {
                         morsel_index_column.cast(&DT::Datetime(TimeUnit::Nanoseconds, None))?
                     },
-                    _ => unreachable!(),
+                    // Strict, so UInt64 values that don't fit an Int64 raise instead of
+                    // silently becoming null.
+                    _ => morsel_index_column
+                        .strict_cast(&DT::Int64)?
+                        .cast(&DT::Datetime(TimeUnit::Nanoseconds, None))?,
                 };
                 self.buf_index_column.append(&morsel_index_column)?;
                 self.buf_df.vstack_mut_owned(df)?;
@@ -8,7 +8,7 @@ use polars_ooc::mm;
 
 use super::compute_node_prelude::*;
 use crate::async_primitives::wait_group::WaitGroup;
-use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::morsel::SourceToken;
 use crate::nodes::in_memory_sink::InMemorySinkNode;
 use crate::pipe::PortReceiver;
 
@@ -126,7 +126,7 @@ impl ShiftState {
         let wait_group = WaitGroup::default();
 
         let total_len = self.rows_received - self.rows_sent;
-        let ideal_morsel_count = (total_len / get_ideal_morsel_size()).max(1);
+        let ideal_morsel_count = (total_len / state.morsel_sizer.ideal_morsel_size()).max(1);
         let morsel_count = ideal_morsel_count.next_multiple_of(state.num_pipelines);
         let morsel_size = total_len.div_ceil(morsel_count).max(1);
 
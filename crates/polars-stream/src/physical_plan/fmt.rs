@@ -470,6 +470,7 @@ fn visualize_plan_rec(
             predicate_file_skip_applied: _,
             hive_parts,
             include_file_paths,
+            provenance,
             cast_columns_policy: _,
             missing_columns_policy: _,
             forbid_extra_columns: _,
@@ -503,6 +504,18 @@ fn visualize_plan_rec(
                 write!(f, "\nfile path column: {col_name}").unwrap();
             }
 
+            if let Some(col_name) = &provenance.row_index_in_file {
+                write!(f, "\nrow index in file column: {col_name}").unwrap();
+            }
+
+            if let Some(col_name) = &provenance.row_group_index {
+                write!(f, "\nrow group index column: {col_name}").unwrap();
+            }
+
+            if let Some(col_name) = &provenance.file_modification_time {
+                write!(f, "\nfile modification time column: {col_name}").unwrap();
+            }
+
             if let Some(pre_slice) = pre_slice {
                 write!(f, "\nslice: offset: ").unwrap();
 
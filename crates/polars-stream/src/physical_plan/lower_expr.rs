@@ -194,6 +194,7 @@ pub fn is_input_independent_rec(
             predicate,
             truthy,
             falsy,
+            ..
         } => {
             is_input_independent_rec(*predicate, arena, cache)
                 && is_input_independent_rec(*truthy, arena, cache)
@@ -349,6 +350,7 @@ pub fn is_length_preserving_rec(
             predicate,
             truthy,
             falsy,
+            ..
         } => {
             is_length_preserving_rec(*predicate, arena, cache)
                 || is_length_preserving_rec(*truthy, arena, cache)
@@ -1716,6 +1718,7 @@ fn lower_exprs_with_ctx(
                 predicate,
                 truthy,
                 falsy,
+                lazy: false,
             } => {
                 let (trans_input, trans_exprs) =
                     lower_exprs_with_ctx(input, &[predicate, truthy, falsy], ctx)?;
@@ -1723,6 +1726,7 @@ fn lower_exprs_with_ctx(
                     predicate: trans_exprs[0],
                     truthy: trans_exprs[1],
                     falsy: trans_exprs[2],
+                    lazy: false,
                 };
                 input_streams.insert(trans_input);
                 transformed_exprs.push(ctx.expr_arena.add(tern_expr));
@@ -2240,10 +2244,13 @@ fn lower_exprs_with_ctx(
                 transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
             },
 
+            // The branches of a lazy ternary are evaluated on the rows they are selected
+            // for, which can't be done on the lowered inputs.
             AExpr::AnonymousFunction { .. }
             | AExpr::Function { .. }
             | AExpr::Over { .. }
-            | AExpr::Gather { .. } => {
+            | AExpr::Gather { .. }
+            | AExpr::Ternary { lazy: true, .. } => {
                 let out_name = unique_column_name();
                 fallback_subset.push(ExprIR::new(expr, OutputName::Alias(out_name.clone())));
                 transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
@@ -280,6 +280,9 @@ fn try_lower_elementwise_scalar_agg_expr(
         | AExpr::SortBy { .. }
         | AExpr::Gather { .. } => None,
 
+        // The branches of a lazy ternary are evaluated per group on the selected rows only.
+        AExpr::Ternary { lazy: true, .. } => None,
+
         // Explode and filter are row-separable and should thus in theory work
         // in a streaming fashion but they change the length of the input which
         // means the same filter/explode should also be applied to the key
@@ -334,6 +337,7 @@ fn try_lower_elementwise_scalar_agg_expr(
             predicate,
             truthy,
             falsy,
+            lazy: false,
         } => {
             let (predicate, truthy, falsy) = (*predicate, *truthy, *falsy);
             let predicate = lower_rec!(predicate)?;
@@ -343,6 +347,7 @@ fn try_lower_elementwise_scalar_agg_expr(
                 predicate,
                 truthy,
                 falsy,
+                lazy: false,
             }))
         },
 
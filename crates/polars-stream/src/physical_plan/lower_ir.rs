@@ -17,6 +17,7 @@ use polars_plan::constants::get_literal_name;
 use polars_plan::dsl::default_values::DefaultFieldValues;
 use polars_plan::dsl::deletion::DeletionFilesList;
 use polars_plan::dsl::{CallbackSinkType, ExtraColumnsPolicy, FileScanIR, SinkTypeIR};
+use polars_plan::plans::column_index::{index_bounds, index_candidates};
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
 use polars_plan::plans::{
     AExpr, FunctionIR, IR, IRAggExpr, LiteralValue, are_keys_sorted_any, is_sorted,
@@ -235,7 +236,28 @@ pub fn lower_ir(
 
         IR::Filter { input, predicate } => {
             let predicate = predicate.clone();
-            let phys_input = lower_ir!(*input)?;
+            let mut input = *input;
+            // Let the indexes of an in-memory frame select the rows to filter.
+            if let IR::DataFrameScan {
+                df, output_schema, ..
+            } = ir_arena.get(input)
+            {
+                let bounds = index_bounds(df, predicate.node(), expr_arena);
+                if let Some(idx) = index_candidates(df, &bounds)? {
+                    let df = match output_schema {
+                        Some(projection) => df.select(projection.iter_names_cloned())?,
+                        None => df.as_ref().clone(),
+                    };
+                    // SAFETY: the indexes give rows in bounds.
+                    let df = unsafe { df.take_unchecked(&idx) };
+                    input = ir_arena.add(IR::DataFrameScan {
+                        schema: df.schema().clone(),
+                        df: Arc::new(df),
+                        output_schema: None,
+                    });
+                }
+            }
+            let phys_input = lower_ir!(input)?;
             return build_filter_stream(
                 phys_input, predicate, expr_arena, phys_sm, expr_cache, ctx,
             );
@@ -541,7 +563,7 @@ pub fn lower_ir(
             return Ok(stream);
         },
         IR::Union { inputs, options } => {
-            let options = *options;
+            let options = options.clone();
 
             let inputs = inputs
                 .clone() // Needed to borrow ir_arena mutably.
@@ -649,6 +671,8 @@ pub fn lower_ir(
                     "pre_slice"
                 } else if unified_scan_args.include_file_paths.is_some() {
                     "include_file_paths"
+                } else if !unified_scan_args.provenance.is_empty() {
+                    "provenance"
                 } else if unified_scan_args.row_index.is_some() {
                     "row_index"
                 } else if predicate.is_some() {
@@ -708,12 +732,14 @@ pub fn lower_ir(
                         metadata: first_metadata,
                     } => Arc::new(
                         crate::nodes::io_sources::parquet::builder::ParquetReaderBuilder {
-                            options: Arc::new(options.clone()),
+                            options: options.clone(),
                             first_metadata: first_metadata.clone(),
                             prefetch_limit: RelaxedCell::new_usize(0),
                             prefetch_semaphore: std::sync::OnceLock::new(),
+                            readahead_bytes_semaphore: std::sync::OnceLock::new(),
                             shared_prefetch_wait_group_slot: Default::default(),
                             io_metrics: std::sync::OnceLock::new(),
+                            morsel_sizer: std::sync::OnceLock::new(),
                         },
                     ) as _,
 
@@ -728,6 +754,7 @@ pub fn lower_ir(
                         prefetch_semaphore: std::sync::OnceLock::new(),
                         shared_prefetch_wait_group_slot: Default::default(),
                         io_metrics: std::sync::OnceLock::new(),
+                        morsel_sizer: std::sync::OnceLock::new(),
                     }) as _,
 
                     #[cfg(feature = "csv")]
@@ -799,6 +826,7 @@ pub fn lower_ir(
                                 .include_file_paths
                                 .as_ref()
                                 .map(|x| x.as_str()),
+                            &unified_scan_args.provenance,
                         );
 
                     let file_projection_builder = ProjectionBuilder::new(
@@ -808,7 +836,8 @@ pub fn lower_ir(
                             .default_values
                             .filter(|DefaultFieldValues::Iceberg(v)| !v.is_empty())
                             .map(|DefaultFieldValues::Iceberg(v)| v),
-                    );
+                    )
+                    .with_schema_evolution(unified_scan_args.schema_evolution.clone());
 
                     // TODO: We ignore the parameter for some scan types to maintain old behavior,
                     // as they currently don't expose an API for it to be configured.
@@ -829,6 +858,20 @@ pub fn lower_ir(
                         &extra_columns_policy,
                         &file_schema,
                         unified_scan_args.column_mapping.as_ref(),
+                        unified_scan_args.schema_evolution.as_deref(),
+                    );
+
+                    // Set to None if empty for performance.
+                    let deletion_files =
+                        DeletionFilesList::filter_empty(unified_scan_args.deletion_files);
+
+                    // Row groups are located by physical row position, which is not tracked once
+                    // rows are deleted.
+                    polars_ensure!(
+                        unified_scan_args.provenance.row_group_index.is_none()
+                            || deletion_files.is_none(),
+                        InvalidOperation:
+                        "row group index column cannot be combined with deletion files"
                     );
 
                     let pre_slice = unified_scan_args.pre_slice.clone();
@@ -849,10 +892,8 @@ pub fn lower_ir(
                         missing_columns_policy: unified_scan_args.missing_columns_policy,
                         forbid_extra_columns,
                         include_file_paths: unified_scan_args.include_file_paths,
-                        // Set to None if empty for performance.
-                        deletion_files: DeletionFilesList::filter_empty(
-                            unified_scan_args.deletion_files,
-                        ),
+                        provenance: Box::new(unified_scan_args.provenance),
+                        deletion_files,
                         table_statistics: unified_scan_args.table_statistics,
                         file_schema,
                         disable_morsel_split,
@@ -11,7 +11,7 @@ use polars_ops::frame::JoinArgs;
 use polars_plan::dsl::deletion::DeletionFilesList;
 use polars_plan::dsl::{
     CastColumnsPolicy, FileSinkOptions, JoinTypeOptionsIR, MissingColumnsPolicy,
-    PartitionedSinkOptionsIR, PredicateFileSkip, ScanSources, TableStatistics,
+    PartitionedSinkOptionsIR, PredicateFileSkip, ProvenanceColumns, ScanSources, TableStatistics,
 };
 use polars_plan::plans::expr_ir::ExprIR;
 use polars_plan::plans::hive::HivePartitionsDf;
@@ -303,6 +303,7 @@ pub enum PhysNodeKind {
 
         hive_parts: Option<HivePartitionsDf>,
         include_file_paths: Option<PlSmallStr>,
+        provenance: Box<ProvenanceColumns>,
         cast_columns_policy: CastColumnsPolicy,
         missing_columns_policy: MissingColumnsPolicy,
         forbid_extra_columns: Option<ForbidExtraColumns>,
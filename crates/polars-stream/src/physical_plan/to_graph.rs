@@ -730,6 +730,7 @@ fn to_graph_rec<'a>(
             missing_columns_policy,
             cast_columns_policy,
             include_file_paths,
+            provenance,
             forbid_extra_columns,
             deletion_files,
             table_statistics,
@@ -768,6 +769,7 @@ fn to_graph_rec<'a>(
             let pre_slice = pre_slice.clone();
             let hive_parts = hive_parts.map(Arc::new);
             let include_file_paths = include_file_paths.clone();
+            let provenance = (**provenance).clone();
             let missing_columns_policy = *missing_columns_policy;
             let forbid_extra_columns = forbid_extra_columns.clone();
             let cast_columns_policy = cast_columns_policy.clone();
@@ -790,6 +792,7 @@ fn to_graph_rec<'a>(
                     predicate_file_skip_applied,
                     hive_parts,
                     include_file_paths,
+                    provenance,
                     missing_columns_policy,
                     forbid_extra_columns,
                     cast_columns_policy,
@@ -1406,7 +1409,7 @@ fn to_graph_rec<'a>(
                 },
             };
 
-            use polars_plan::dsl::{CastColumnsPolicy, MissingColumnsPolicy};
+            use polars_plan::dsl::{CastColumnsPolicy, MissingColumnsPolicy, ProvenanceColumns};
 
             use crate::nodes::io_sources::batch::builder::BatchFnReaderBuilder;
             use crate::nodes::io_sources::batch::{BatchFnReader, GetBatchState};
@@ -1440,6 +1443,7 @@ fn to_graph_rec<'a>(
             let predicate_file_skip_applied = None;
             let hive_parts = None;
             let include_file_paths = None;
+            let provenance = ProvenanceColumns::default();
             let missing_columns_policy = MissingColumnsPolicy::Raise;
             let forbid_extra_columns = None;
             let cast_columns_policy = CastColumnsPolicy::ERROR_ON_MISMATCH;
@@ -1461,6 +1465,7 @@ fn to_graph_rec<'a>(
                     predicate_file_skip_applied,
                     hive_parts,
                     include_file_paths,
+                    provenance,
                     missing_columns_policy,
                     forbid_extra_columns,
                     cast_columns_policy,
@@ -16,6 +16,7 @@ use slotmap::{SecondaryMap, SlotMap};
 
 use crate::graph::{Graph, GraphNodeKey};
 use crate::metrics::GraphMetrics;
+use crate::morsel::MorselSizing;
 use crate::physical_plan::{PhysNode, PhysNodeKey, PhysNodeKind, StreamingLowerIRContext};
 
 /// Executes the IR with the streaming engine.
@@ -63,6 +64,7 @@ pub struct StreamingQuery {
     pub phys_sm: SlotMap<PhysNodeKey, PhysNode>,
     pub phys_to_graph: SecondaryMap<PhysNodeKey, GraphNodeKey>,
     pub metrics: Option<Arc<Mutex<GraphMetrics>>>,
+    pub morsel_sizing: MorselSizing,
 }
 
 /// Configures if IR lowering creates the `format_str` for `InMemoryMap`.
@@ -83,7 +85,16 @@ fn cfg_prepare_visualization_data() -> bool {
     PREPARE_VISUALIZATION_DATA.load()
 }
 
-impl StreamingQuery {
+/// A query lowered to a physical plan, from which a [`StreamingQuery`] can be built any number of
+/// times, see [`StreamingQuery::from_lowered`].
+#[derive(Clone)]
+pub struct LoweredQuery {
+    top_ir: IR,
+    root_phys_node: PhysNodeKey,
+    phys_sm: SlotMap<PhysNodeKey, PhysNode>,
+}
+
+impl LoweredQuery {
     pub fn build(
         node: Node,
         ir_arena: &mut Arena<IR>,
@@ -115,11 +126,37 @@ impl StreamingQuery {
             std::fs::write(visual_path, visualization).unwrap();
         }
 
+        Ok(LoweredQuery {
+            top_ir: ir_arena.get(node).clone(),
+            root_phys_node,
+            phys_sm,
+        })
+    }
+}
+
+impl StreamingQuery {
+    pub fn build(
+        node: Node,
+        ir_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> PolarsResult<Self> {
+        let lowered = LoweredQuery::build(node, ir_arena, expr_arena)?;
+        Self::from_lowered(lowered, expr_arena)
+    }
+
+    /// Build the query from a physical plan that was lowered with `expr_arena`.
+    pub fn from_lowered(
+        lowered: LoweredQuery,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> PolarsResult<Self> {
+        let LoweredQuery {
+            top_ir,
+            root_phys_node,
+            phys_sm,
+        } = lowered;
         let (mut graph, phys_to_graph) =
             crate::physical_plan::physical_plan_to_graph(root_phys_node, &phys_sm, expr_arena)?;
 
-        let top_ir = ir_arena.get(node).clone();
-
         let metrics = if std::env::var("POLARS_TRACK_METRICS").as_deref() == Ok("1")
             || std::env::var("POLARS_LOG_METRICS").as_deref() == Ok("1")
         {
@@ -136,11 +173,18 @@ impl StreamingQuery {
             phys_sm,
             phys_to_graph,
             metrics,
+            morsel_sizing: MorselSizing::from_config(),
         };
 
         Ok(out)
     }
 
+    /// Set how the morsels of this query are sized, instead of the sizing from the config.
+    pub fn with_morsel_sizing(mut self, morsel_sizing: MorselSizing) -> Self {
+        self.morsel_sizing = morsel_sizing;
+        self
+    }
+
     pub fn execute(self) -> PolarsResult<QueryResult> {
         let StreamingQuery {
             top_ir,
@@ -149,10 +193,12 @@ impl StreamingQuery {
             phys_sm,
             phys_to_graph,
             metrics,
+            morsel_sizing,
         } = self;
 
         let query_start = Instant::now();
-        let mut results = crate::execute::execute_graph(&mut graph, metrics.clone())?;
+        let mut results =
+            crate::execute::execute_graph(&mut graph, metrics.clone(), morsel_sizing)?;
         let query_elapsed = query_start.elapsed();
 
         // Print metrics.
@@ -0,0 +1,247 @@
+use polars_core::prelude::*;
+
+const NS_SECOND: i128 = 1_000_000_000;
+const NS_MINUTE: i128 = 60 * NS_SECOND;
+const NS_HOUR: i128 = 60 * NS_MINUTE;
+const NS_DAY: i128 = 24 * NS_HOUR;
+const NS_WEEK: i128 = 7 * NS_DAY;
+
+const ISO_DATE_UNITS: [(char, i128); 2] = [('W', NS_WEEK), ('D', NS_DAY)];
+const ISO_TIME_UNITS: [(char, i128); 3] = [('H', NS_HOUR), ('M', NS_MINUTE), ('S', NS_SECOND)];
+
+fn human_unit_ns(unit: &str) -> Option<i128> {
+    let ns = match unit {
+        "w" | "week" | "weeks" => NS_WEEK,
+        "d" | "day" | "days" => NS_DAY,
+        "h" | "hr" | "hrs" | "hour" | "hours" => NS_HOUR,
+        "m" | "min" | "mins" | "minute" | "minutes" => NS_MINUTE,
+        "s" | "sec" | "secs" | "second" | "seconds" => NS_SECOND,
+        "ms" => 1_000_000,
+        "us" | "µs" => 1_000,
+        "ns" => 1,
+        _ => return None,
+    };
+    Some(ns)
+}
+
+/// Split off an optional leading sign, returning whether it was a minus sign.
+fn split_sign(s: &str) -> (Option<bool>, &str) {
+    if let Some(rest) = s.strip_prefix('-') {
+        (Some(true), rest)
+    } else if let Some(rest) = s.strip_prefix('+') {
+        (Some(false), rest)
+    } else {
+        (None, s)
+    }
+}
+
+/// Multiply the unsigned decimal number `num` (eg: "5.25") by `unit_ns`, truncating fractional
+/// nanoseconds.
+fn scale_number(num: &str, unit_ns: i128) -> Option<i128> {
+    let (whole, frac) = num.split_once(['.', ',']).unwrap_or((num, ""));
+    if (whole.is_empty() && frac.is_empty())
+        || !whole
+            .bytes()
+            .chain(frac.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let whole: i128 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().ok()?
+    };
+    // more digits than this can't change the number of nanoseconds.
+    let frac = &frac[..frac.len().min(18)];
+    let frac_ns = if frac.is_empty() {
+        0
+    } else {
+        frac.parse::<i128>().ok()? * unit_ns / 10i128.pow(frac.len() as u32)
+    };
+    whole.checked_mul(unit_ns)?.checked_add(frac_ns)
+}
+
+/// Parse ISO 8601 components such as "1DT" or "2H30M", where the units must follow the order of
+/// `units`.
+fn parse_iso_components(s: &str, units: &[(char, i128)]) -> Option<i128> {
+    let mut rest = s;
+    let mut next_unit = 0;
+    let mut total = 0i128;
+    while !rest.is_empty() {
+        let end = rest.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))?;
+        let (num, tail) = rest.split_at(end);
+        let unit = tail.chars().next()?;
+        let idx = next_unit + units[next_unit..].iter().position(|(u, _)| *u == unit)?;
+        total = total.checked_add(scale_number(num, units[idx].1)?)?;
+        next_unit = idx + 1;
+        rest = &tail[unit.len_utf8()..];
+    }
+    Some(total)
+}
+
+/// Parse an ISO 8601 duration such as "P1DT2H30M" or "PT0.5S". Years and months have no fixed
+/// length, so they are not supported.
+fn parse_iso_duration(s: &str) -> Option<i128> {
+    let s = s.strip_prefix('P')?;
+    let (date, time) = match s.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (s, None),
+    };
+    if s.is_empty() || time == Some("") {
+        return None;
+    }
+    let mut total = parse_iso_components(date, &ISO_DATE_UNITS)?;
+    if let Some(time) = time {
+        total = total.checked_add(parse_iso_components(time, &ISO_TIME_UNITS)?)?;
+    }
+    Some(total)
+}
+
+/// Parse a clock duration such as "26:03:04.5", "1:30" or, as written by Python's `timedelta`,
+/// "-1 day, 23:59:18".
+fn parse_clock_duration(s: &str) -> Option<i128> {
+    let (days, clock) = match s.split_once(',') {
+        Some((days, clock)) => {
+            let days = days
+                .trim()
+                .strip_suffix("days")
+                .or_else(|| days.trim().strip_suffix("day"))?
+                .trim_end();
+            let (negative, days) = split_sign(days);
+            let days = scale_number(days, NS_DAY)?;
+            (
+                if negative == Some(true) { -days } else { days },
+                clock.trim(),
+            )
+        },
+        None => (0, s),
+    };
+    let mut parts = clock.split(':');
+    let hours = parts.next()?;
+    let minutes = parts.next()?;
+    let seconds = parts.next().unwrap_or("0");
+    if parts.next().is_some()
+        || hours.contains(['.', ','])
+        || minutes.len() != 2
+        || minutes.contains(['.', ','])
+    {
+        return None;
+    }
+    let minutes = scale_number(minutes, NS_MINUTE)?;
+    let seconds = scale_number(seconds, NS_SECOND)?;
+    if minutes >= NS_HOUR || seconds >= NS_MINUTE {
+        return None;
+    }
+    days.checked_add(scale_number(hours, NS_HOUR)?)?
+        .checked_add(minutes)?
+        .checked_add(seconds)
+}
+
+/// Parse a human-readable duration such as "2d 3h 4m 5.25s", "1h30m" or "3 days". A leading
+/// sign applies to the whole duration, unless the other parts have their own sign (as in the
+/// Polars duration format "-1d -42s").
+fn parse_human_duration(s: &str) -> Option<i128> {
+    let mut parts = Vec::new();
+    let mut rest = s.trim_start();
+    while !rest.is_empty() {
+        let (sign, tail) = split_sign(rest);
+        let end = tail
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+            .unwrap_or(tail.len());
+        let (num, tail) = tail.split_at(end);
+        let tail = tail.trim_start();
+        let end = tail
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(end);
+        parts.push((sign, scale_number(num, human_unit_ns(unit)?)?));
+        rest = tail.trim_start();
+    }
+    let (first_sign, _) = parts.first()?;
+    let negate_all = *first_sign == Some(true) && parts[1..].iter().all(|(sign, _)| sign.is_none());
+    parts.iter().try_fold(0i128, |total, (sign, value)| {
+        let value = if negate_all || *sign == Some(true) {
+            -value
+        } else {
+            *value
+        };
+        total.checked_add(value)
+    })
+}
+
+/// Parse a duration string to a number of nanoseconds.
+fn parse_duration_ns(s: &str) -> Option<i128> {
+    let s = s.trim();
+    let (sign, unsigned) = split_sign(s);
+    if unsigned.starts_with('P') {
+        let ns = parse_iso_duration(unsigned)?;
+        Some(if sign == Some(true) { -ns } else { ns })
+    } else if unsigned.contains(':') {
+        if unsigned.contains(',') {
+            return parse_clock_duration(s);
+        }
+        let ns = parse_clock_duration(unsigned)?;
+        Some(if sign == Some(true) { -ns } else { ns })
+    } else {
+        parse_human_duration(s)
+    }
+}
+
+/// Parse a duration string to a number of `tu`, truncating the values that can't be
+/// represented exactly. Supported are ISO 8601 durations ("P1DT2H"), human-readable durations
+/// ("2d 3h 4m 5.25s", "1h30m") and clock durations ("26:03:04.5", "1 day, 2:03:04").
+pub fn parse_duration(s: &str, tu: TimeUnit) -> Option<i64> {
+    let ns = parse_duration_ns(s)?;
+    let v = match tu {
+        TimeUnit::Nanoseconds => ns,
+        TimeUnit::Microseconds => ns / 1_000,
+        TimeUnit::Milliseconds => ns / 1_000_000,
+    };
+    i64::try_from(v).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        let us = |s| parse_duration(s, TimeUnit::Microseconds);
+        let hms = |h: i64, m: i64, s: i64| ((h * 60 + m) * 60 + s) * 1_000_000;
+
+        // ISO 8601
+        assert_eq!(us("P1DT2H"), Some(hms(26, 0, 0)));
+        assert_eq!(us("-PT1M0.5S"), Some(-hms(0, 1, 0) - 500_000));
+        assert_eq!(us("P2W"), Some(hms(336, 0, 0)));
+        assert_eq!(us("PT0S"), Some(0));
+        assert_eq!(us("P1Y"), None);
+        assert_eq!(us("PT2S1M"), None);
+        assert_eq!(us("P1DT"), None);
+
+        // human-readable
+        assert_eq!(us("2d 3h 4m 5.25s"), Some(hms(51, 4, 5) + 250_000));
+        assert_eq!(us("1h30m"), Some(hms(1, 30, 0)));
+        assert_eq!(us("-1d 42s"), Some(-hms(24, 0, 42)));
+        assert_eq!(us("-1d -42s"), Some(-hms(24, 0, 42)));
+        assert_eq!(us("1d -42s"), Some(hms(23, 59, 18)));
+        assert_eq!(us("3 days 100µs"), Some(hms(72, 0, 0) + 100));
+        assert_eq!(us("1.5 hours"), Some(hms(1, 30, 0)));
+        assert_eq!(us("5"), None);
+        assert_eq!(us("5x"), None);
+        assert_eq!(us(""), None);
+
+        // clock
+        assert_eq!(us("26:03:04.5"), Some(hms(26, 3, 4) + 500_000));
+        assert_eq!(us("-01:30"), Some(-hms(1, 30, 0)));
+        assert_eq!(us("-1 day, 23:59:18"), Some(-hms(0, 0, 42)));
+        assert_eq!(us("2 days, 0:00:01"), Some(hms(48, 0, 1)));
+        assert_eq!(us("1:60:00"), None);
+
+        assert_eq!(
+            parse_duration("1.0000015s", TimeUnit::Milliseconds),
+            Some(1_000)
+        );
+        assert_eq!(parse_duration("1000000w", TimeUnit::Nanoseconds), None);
+    }
+}
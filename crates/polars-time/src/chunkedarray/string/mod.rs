@@ -1,5 +1,9 @@
+#[cfg(feature = "dtype-duration")]
+mod duration;
 pub mod infer;
 use chrono::DateTime;
+#[cfg(feature = "dtype-duration")]
+use duration::parse_duration;
 mod patterns;
 mod strptime;
 pub use patterns::Pattern;
@@ -101,6 +105,24 @@ pub trait StringMethods: AsString {
         Ok(ca.with_name(string_ca.name().clone()).into_time())
     }
 
+    #[cfg(feature = "dtype-duration")]
+    /// Parsing string values and return a [`DurationChunked`]
+    /// Supported are ISO 8601 durations ("P1DT2H"), human-readable durations
+    /// ("2d 3h 4m 5.25s") and clock durations ("26:03:04.5").
+    fn as_duration(&self, tu: TimeUnit, strict: bool) -> PolarsResult<DurationChunked> {
+        let string_ca = self.as_string();
+        let ca: Int64Chunked = unary_elementwise(string_ca, |opt_s| parse_duration(opt_s?, tu));
+        let out = ca.with_name(string_ca.name().clone()).into_duration(tu);
+
+        if strict && string_ca.null_count() != out.null_count() {
+            polars_core::utils::handle_casting_failures(
+                &string_ca.clone().into_series(),
+                &out.clone().into_series(),
+            )?;
+        }
+        Ok(out)
+    }
+
     #[cfg(feature = "dtype-date")]
     /// Parsing string values and return a [`DateChunked`]
     /// Different from `as_date` this function allows matches that not contain the whole string
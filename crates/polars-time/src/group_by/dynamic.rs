@@ -145,9 +145,11 @@ impl Wrap<&DataFrame> {
                 TimeUnit::Microseconds,
                 None,
             ),
-            UInt32 | UInt64 | Int32 => {
+            Int8 | Int16 | Int32 | UInt8 | UInt16 | UInt32 | UInt64 => {
                 let time_type_dt = Datetime(TimeUnit::Nanoseconds, None);
-                let dt = time.cast(&Int64).unwrap().cast(&time_type_dt).unwrap();
+                // Strict, so UInt64 values that don't fit an Int64 raise instead of silently
+                // becoming null.
+                let dt = time.strict_cast(&Int64)?.cast(&time_type_dt).unwrap();
                 let (out, gt) = self.impl_rolling(
                     dt,
                     group_by,
@@ -175,7 +177,7 @@ impl Wrap<&DataFrame> {
             },
             dt => polars_bail!(
                 ComputeError:
-                "expected any of the following dtypes: {{ Date, Datetime, Int32, Int64, UInt32, UInt64 }}, got {}",
+                "expected any of the following dtypes: {{ Date, Datetime, Int8, Int16, Int32, Int64, UInt8, UInt16, UInt32, UInt64 }}, got {}",
                 dt
             ),
         };
@@ -545,6 +547,35 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_rolling_group_by_integer_index() -> PolarsResult<()> {
+        let mut idx = Column::new("idx".into(), [0i16, 1, 2, 5, 6, 10]);
+        idx.set_sorted_flag(IsSorted::Ascending);
+        let a = Column::new("a".into(), [3, 7, 5, 9, 2, 1]);
+        let df = DataFrame::new_infer_height(vec![idx, a.clone()])?;
+
+        let options = RollingGroupOptions {
+            index_column: "idx".into(),
+            period: Duration::parse("3i"),
+            offset: Duration::parse("-3i"),
+            closed_window: ClosedWindow::Right,
+        };
+        let (keys, groups) = df.rolling(None, &options)?;
+        assert_eq!(keys.dtype(), &DataType::Int16);
+
+        let sum = unsafe { a.agg_sum(&groups) };
+        let expected = Column::new("".into(), [3, 10, 15, 9, 11, 1]);
+        assert_eq!(sum, expected);
+
+        // UInt64 indices that don't fit an Int64 can't be represented.
+        let mut idx = Column::new("idx".into(), [0u64, u64::MAX]);
+        idx.set_sorted_flag(IsSorted::Ascending);
+        let df = DataFrame::new_infer_height(vec![idx])?;
+        assert!(df.rolling(None, &options).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_rolling_group_by_aggs() -> PolarsResult<()> {
         let mut date = StringChunked::new(
@@ -12,6 +12,8 @@ mod date_range;
 #[cfg(feature = "timezones")]
 mod dst_offset;
 mod group_by;
+#[cfg(feature = "locales")]
+mod locale;
 #[cfg(feature = "month_end")]
 mod month_end;
 #[cfg(feature = "month_start")]
@@ -36,6 +38,8 @@ pub use date_range::*;
 pub use dst_offset::*;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 pub use group_by::dynamic::*;
+#[cfg(feature = "locales")]
+pub use locale::*;
 #[cfg(feature = "month_end")]
 pub use month_end::*;
 #[cfg(feature = "month_start")]
@@ -0,0 +1,285 @@
+//! Locale-aware month and weekday names, using the locale data of the `pure-rust-locales` crate.
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use arrow::temporal_conversions::*;
+use chrono::Locale;
+#[cfg(feature = "timezones")]
+use chrono::TimeZone as _;
+#[cfg(feature = "timezones")]
+use chrono_tz::Tz;
+use polars_core::prelude::*;
+use pure_rust_locales::locale_match;
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+// Weekdays start on Sunday, as in the locale data.
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// Parse a locale name such as "fr_FR" or "de_DE".
+pub fn parse_locale(locale: &str) -> PolarsResult<Locale> {
+    Locale::try_from(locale).map_err(|_| {
+        polars_err!(
+            InvalidOperation: "unknown locale '{}', expected a name such as 'fr_FR'", locale
+        )
+    })
+}
+
+/// Localized names, lowercased and paired with their English name.
+type Names = Vec<(String, &'static str)>;
+
+fn sort_longest_first(names: &mut Names) {
+    names.sort_by_key(|(name, _)| std::cmp::Reverse(name.chars().count()));
+}
+
+fn name_pairs(full: &[&str], abbreviated: &[&str], english: &[&'static str]) -> Names {
+    let mut names: Names = full
+        .iter()
+        .zip(english)
+        .chain(abbreviated.iter().zip(english))
+        .filter(|(name, _)| !name.is_empty())
+        .map(|(name, english)| (name.to_lowercase(), *english))
+        .collect();
+    sort_longest_first(&mut names);
+    names
+}
+
+/// The localized month names and weekday names, longest name first.
+fn localized_names(locale: Locale) -> (Names, Names) {
+    let months = name_pairs(
+        locale_match!(locale => LC_TIME::MON),
+        locale_match!(locale => LC_TIME::ABMON),
+        &MONTHS,
+    );
+    let weekdays = name_pairs(
+        locale_match!(locale => LC_TIME::DAY),
+        locale_match!(locale => LC_TIME::ABDAY),
+        &WEEKDAYS,
+    );
+    (months, weekdays)
+}
+
+/// Get the names matching the name specifiers of `format`, in the order in which they appear.
+fn format_names<'a>(format: &str, months: &'a Names, weekdays: &'a Names) -> Vec<&'a Names> {
+    let mut out = Vec::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            continue;
+        }
+        // Skip the padding modifiers, as in "%-d" or "%_m".
+        match chars.find(|c| !matches!(c, '-' | '_' | '0' | '#' | '^')) {
+            Some('a' | 'A') => out.push(weekdays),
+            Some('b' | 'B' | 'h' | 'v') => out.push(months),
+            Some('c') => out.extend([weekdays, months]),
+            _ => {},
+        }
+    }
+    out
+}
+
+/// Get the length in bytes of the prefix of `s` that case-insensitively matches the lowercase
+/// `prefix`.
+fn match_prefix_ignore_case(s: &str, prefix: &str) -> Option<usize> {
+    let mut chars = s.char_indices();
+    let mut lowercase = String::new();
+    for expected in prefix.chars() {
+        let (_, c) = chars.next()?;
+        if c == expected {
+            continue;
+        }
+        lowercase.clear();
+        lowercase.extend(c.to_lowercase());
+        if lowercase.chars().ne(std::iter::once(expected)) {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(s.len(), |(i, _)| i))
+}
+
+/// Replace the localized names in `s` that are whole words by their English name. The n-th
+/// replaced name is looked up in the n-th table of `tables`, as the same abbreviation can be
+/// both a month and a weekday (eg: "mar" in Spanish).
+fn translate_names<'a, 'n>(
+    s: &'a str,
+    mut tables: impl Iterator<Item = &'n Names>,
+) -> Cow<'a, str> {
+    let mut out = String::new();
+    let mut copied = 0;
+    let mut prev_alphabetic = false;
+    let mut idx = 0;
+    let mut names = tables.next();
+    while let Some(current) = names
+        && idx < s.len()
+    {
+        let rest = &s[idx..];
+        let c = rest.chars().next().unwrap();
+        if !prev_alphabetic && c.is_alphabetic() {
+            let found = current.iter().find_map(|(name, english)| {
+                let len = match_prefix_ignore_case(rest, name)?;
+                let next_alphabetic = rest[len..].chars().next().is_some_and(char::is_alphabetic);
+                (!next_alphabetic).then_some((len, *english))
+            });
+            if let Some((len, english)) = found {
+                out.push_str(&s[copied..idx]);
+                out.push_str(english);
+                idx += len;
+                copied = idx;
+                prev_alphabetic = false;
+                names = tables.next();
+                continue;
+            }
+        }
+        prev_alphabetic = c.is_alphabetic();
+        idx += c.len_utf8();
+    }
+
+    if copied == 0 {
+        Cow::Borrowed(s)
+    } else {
+        out.push_str(&s[copied..]);
+        Cow::Owned(out)
+    }
+}
+
+/// Replace the month and weekday names of `locale` by their English name, so that the strings
+/// can be parsed with the (English) `%b`, `%B`, `%a` and `%A` specifiers. Only the names of
+/// the specifiers used in `format` are replaced, or all names if `format` is not given.
+pub fn delocalize_names(
+    ca: &StringChunked,
+    format: Option<&str>,
+    locale: &str,
+) -> PolarsResult<StringChunked> {
+    let locale = parse_locale(locale)?;
+    let (months, weekdays) = localized_names(locale);
+    let out = match format {
+        Some(format) => {
+            let tables = format_names(format, &months, &weekdays);
+            if tables.is_empty() {
+                return Ok(ca.clone());
+            }
+            ca.apply_values(|s| translate_names(s, tables.iter().copied()))
+        },
+        None => {
+            let mut names = months;
+            names.extend(weekdays);
+            sort_longest_first(&mut names);
+            ca.apply_values(|s| translate_names(s, std::iter::repeat(&names)))
+        },
+    };
+    Ok(out)
+}
+
+/// Convert a Date/Datetime/Time column into a String column with the given format, writing
+/// month and weekday names in the language of `locale`.
+pub fn to_string_localized(s: &Series, format: &str, locale: &str) -> PolarsResult<Series> {
+    let locale = parse_locale(locale)?;
+    let out = match s.dtype() {
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(tu, tz) => {
+            let format = get_strftime_format(format, s.dtype())?;
+            let conversion_f = match tu {
+                TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
+                TimeUnit::Microseconds => timestamp_us_to_datetime,
+                TimeUnit::Milliseconds => timestamp_ms_to_datetime,
+            };
+            let ca = s.datetime()?.physical();
+            match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => {
+                    let tz = tz.parse::<Tz>().expect("already validated");
+                    ca.try_apply_into_string_amortized(|val, buf| {
+                        let dt = tz.from_utc_datetime(&conversion_f(val));
+                        write!(buf, "{}", dt.format_localized(&format, locale))
+                    })
+                },
+                _ => ca.try_apply_into_string_amortized(|val, buf| {
+                    let dt = conversion_f(val).and_utc();
+                    write!(buf, "{}", dt.format_localized(&format, locale))
+                }),
+            }
+            .map_err(
+                |_| polars_err!(ComputeError: "cannot format Datetime with format '{}'", format),
+            )?
+        },
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => {
+            let format = get_strftime_format(format, s.dtype())?;
+            s.date()?
+                .physical()
+                .try_apply_into_string_amortized(|val, buf| {
+                    write!(
+                        buf,
+                        "{}",
+                        date32_to_date(val).format_localized(&format, locale)
+                    )
+                })
+                .map_err(
+                    |_| polars_err!(ComputeError: "cannot format Date with format '{}'", format),
+                )?
+        },
+        // Times have no month and weekday names.
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => {
+            let format = get_strftime_format(format, s.dtype())?;
+            s.time()?.to_string(&format)
+        },
+        dt => polars_bail!(
+            InvalidOperation: "formatting with a locale is not supported for dtype {}", dt
+        ),
+    };
+    Ok(out.with_name(s.name().clone()).into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_translate_names() {
+        let locale = parse_locale("fr_FR").unwrap();
+        let (months, weekdays) = localized_names(locale);
+        let mut all = months.clone();
+        all.extend(weekdays.clone());
+        sort_longest_first(&mut all);
+        let translate = |s| translate_names(s, std::iter::repeat(&all));
+
+        assert_eq!(translate("mardi 5 mars 2024"), "Tuesday 5 March 2024");
+        assert_eq!(translate("1 Juil. 2024"), "1 July 2024");
+        assert_eq!(translate("Février"), "February");
+        // Only whole words are replaced.
+        assert_eq!(translate("marsupial"), "marsupial");
+        assert!(matches!(translate("2024-01-01"), Cow::Borrowed(_)));
+
+        // The names are looked up in the order of the specifiers of the format.
+        let locale = parse_locale("es_ES").unwrap();
+        let (months, weekdays) = localized_names(locale);
+        let tables = format_names("%a %-d %b", &months, &weekdays);
+        assert_eq!(
+            translate_names("mar 5 mar", tables.into_iter()),
+            "Tuesday 5 March"
+        );
+
+        assert!(parse_locale("xx_XX").is_err());
+    }
+}
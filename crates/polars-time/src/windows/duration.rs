@@ -1109,16 +1109,35 @@ pub fn ensure_duration_matches_dtype(
     variable_name: &str,
 ) -> PolarsResult<()> {
     match dtype {
-        DataType::Int64 | DataType::UInt64 | DataType::Int32 | DataType::UInt32 => {
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64 => {
             polars_ensure!(duration.parsed_int || duration.is_zero(),
                 InvalidOperation: "`{}` duration must be a parsed integer (i.e. use '2i', not '2d') when working with a numeric column", variable_name);
+            // The window bounds are given in the dtype of the index column. Offsets are negative
+            // by default, so unsigned indexes accept negative durations down to `-max`.
+            let max = dtype.max()?.value().extract::<i128>().unwrap();
+            let min = if dtype.is_signed_integer() {
+                dtype.min()?.value().extract::<i128>().unwrap()
+            } else {
+                -max
+            };
+            let value = duration.nanoseconds() as i128;
+            let value = if duration.negative() { -value } else { value };
+            polars_ensure!((min..=max).contains(&value),
+                InvalidOperation: "`{}` duration of {}i is out of range for an index column of type {}", variable_name, value, dtype);
         },
         DataType::Datetime(_, _) | DataType::Date | DataType::Duration(_) | DataType::Time => {
             polars_ensure!(!duration.parsed_int,
                 InvalidOperation: "`{}` duration may not be a parsed integer (i.e. use '2d', not '2i') when working with a temporal column", variable_name);
         },
         _ => {
-            polars_bail!(InvalidOperation: "unsupported data type: {} for temporal/index column, expected UInt64, UInt32, Int64, Int32, Datetime, Date, Duration, or Time", dtype)
+            polars_bail!(InvalidOperation: "unsupported data type: {} for temporal/index column, expected an integer (up to 64 bits), Datetime, Date, Duration, or Time", dtype)
         },
     }
     Ok(())
@@ -1200,4 +1219,22 @@ mod test {
         let expected = "4w";
         assert_eq!(format!("{duration}"), expected);
     }
+
+    #[test]
+    fn test_duration_matches_integer_dtype() {
+        let dtype = DataType::Int8;
+        assert!(ensure_duration_matches_dtype(Duration::parse("127i"), &dtype, "period").is_ok());
+        assert!(ensure_duration_matches_dtype(Duration::parse("-128i"), &dtype, "offset").is_ok());
+        assert!(ensure_duration_matches_dtype(Duration::parse("128i"), &dtype, "period").is_err());
+        assert!(ensure_duration_matches_dtype(Duration::parse("-129i"), &dtype, "offset").is_err());
+
+        let dtype = DataType::UInt8;
+        assert!(ensure_duration_matches_dtype(Duration::parse("255i"), &dtype, "period").is_ok());
+        assert!(ensure_duration_matches_dtype(Duration::parse("-255i"), &dtype, "offset").is_ok());
+        assert!(ensure_duration_matches_dtype(Duration::parse("256i"), &dtype, "period").is_err());
+        assert!(ensure_duration_matches_dtype(Duration::parse("-256i"), &dtype, "offset").is_err());
+
+        let dtype = DataType::UInt64;
+        assert!(ensure_duration_matches_dtype(Duration::parse("-5i"), &dtype, "offset").is_ok());
+    }
 }
@@ -182,6 +182,7 @@
 //! * `ndarray`- Convert from [`DataFrame`] to [ndarray](https://docs.rs/ndarray/)
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
 //! * `timezones` - Activate timezone support.
+//! * `locales` - Locale-aware month and weekday names when formatting and parsing temporal data.
 //! * `strings` - Extra string utilities for [`StringChunked`]
 //!     - `string_pad` - `zfill`, `ljust`, `rjust`
 //!     - `string_to_integer` - `parse_int`
@@ -195,12 +196,15 @@
 //!       Polars will be a bit slower with this feature activated as many data structures
 //!       are less cache efficient.
 //!     - `cse` - Activate common subplan elimination optimization
+//!     - `plan_cache` - Cache optimized plans of queries that only differ in their literals
 //! * IO related:
 //!     - `serde` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!       Can be used for JSON and more serde supported serialization formats.
 //!     - `serde-lazy` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!       Can be used for JSON and more serde supported serialization formats.
 //!     - `parquet` - Read Apache Parquet format
+//!     - `parquet_encryption` - Read and write Parquet files with modular encryption
+//!     - `parquet_bloom_filter` - Write Parquet bloom filters and skip row groups based on them
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
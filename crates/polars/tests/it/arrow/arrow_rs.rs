@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use arrow::array::*;
+use arrow::arrow_rs::{self, arrow_array};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, Field};
+use arrow::record_batch::RecordBatch;
+use arrow_array::Array as _;
+use polars_error::PolarsResult;
+
+fn test_round_trip(expected: impl Array + Clone + 'static) -> PolarsResult<()> {
+    let expected = Box::new(expected) as Box<dyn Array>;
+    for array in [expected.clone(), expected.sliced(1, 2)] {
+        let exported = arrow_rs::array_to_arrow_rs(array.as_ref())?;
+        exported.to_data().validate_full().unwrap();
+        assert_eq!(exported.len(), array.len());
+        assert_eq!(exported.null_count(), array.null_count());
+
+        let imported = arrow_rs::array_from_arrow_rs(exported.as_ref())?;
+        assert_eq!(&imported, &array);
+    }
+    Ok(())
+}
+
+#[test]
+fn bool_nullable() -> PolarsResult<()> {
+    test_round_trip(BooleanArray::from(&[Some(true), None, Some(false), None]))
+}
+
+#[test]
+fn primitive_nullable() -> PolarsResult<()> {
+    test_round_trip(Int64Array::from(&[Some(1), None, Some(3), Some(4)]))
+}
+
+#[test]
+fn utf8_nullable() -> PolarsResult<()> {
+    test_round_trip(Utf8Array::<i64>::from([
+        Some("a"),
+        None,
+        Some("bb"),
+        Some("ccc"),
+    ]))
+}
+
+#[test]
+fn binview_nullable_buffered() -> PolarsResult<()> {
+    test_round_trip(Utf8ViewArray::from_slice([
+        Some("foobaroiwalksdfjoiei"),
+        None,
+        Some("barbar"),
+        None,
+        Some("aoisejiofjfoiewjjwfoiwejfo"),
+    ]))
+}
+
+#[test]
+fn list_nullable() -> PolarsResult<()> {
+    let mut array = MutableListArray::<i64, MutablePrimitiveArray<i32>>::new();
+    array.try_extend([
+        Some(vec![Some(1), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(4)]),
+    ])?;
+    test_round_trip(ListArray::<i64>::from(array))
+}
+
+#[test]
+fn struct_nullable() -> PolarsResult<()> {
+    let fields = vec![
+        Field::new("a".into(), ArrowDataType::Int32, true),
+        Field::new("b".into(), ArrowDataType::Utf8View, true),
+    ];
+    let values = vec![
+        Int32Array::from(&[Some(1), None, Some(3)]).boxed(),
+        Utf8ViewArray::from_slice([Some("a"), Some("b"), None]).boxed(),
+    ];
+    test_round_trip(StructArray::new(
+        ArrowDataType::Struct(fields),
+        3,
+        values,
+        Some([true, false, true].into()),
+    ))
+}
+
+#[test]
+fn shares_buffers() -> PolarsResult<()> {
+    let array = Int64Array::from_vec(vec![1, 2, 3]);
+    let exported = arrow_rs::array_to_arrow_rs(&array)?;
+    assert_eq!(
+        exported.to_data().buffers()[0].as_ptr(),
+        array.values().as_ptr().cast()
+    );
+
+    let imported = arrow_rs::array_from_arrow_rs(exported.as_ref())?;
+    let imported = imported.as_any().downcast_ref::<Int64Array>().unwrap();
+    assert_eq!(imported.values().as_ptr(), array.values().as_ptr());
+    Ok(())
+}
+
+#[test]
+fn record_batch() -> PolarsResult<()> {
+    let schema = ArrowSchema::from_iter([
+        Field::new("a".into(), ArrowDataType::Int32, true),
+        Field::new("b".into(), ArrowDataType::Utf8View, true),
+    ]);
+    let batch = RecordBatch::try_new(
+        3,
+        Arc::new(schema),
+        vec![
+            Int32Array::from(&[Some(1), None, Some(3)]).boxed(),
+            Utf8ViewArray::from_slice([Some("a"), Some("b"), None]).boxed(),
+        ],
+    )?;
+
+    let exported = arrow_rs::record_batch_to_arrow_rs(&batch)?;
+    assert_eq!(exported.num_rows(), 3);
+    assert_eq!(
+        exported.schema().field(1).data_type(),
+        &arrow_rs::arrow_schema::DataType::Utf8View
+    );
+    assert!(exported.column(0).as_any().is::<arrow_array::Int32Array>());
+
+    let imported = arrow_rs::record_batch_from_arrow_rs(&exported)?;
+    assert_eq!(imported.schema(), batch.schema());
+    assert_eq!(imported.arrays(), batch.arrays());
+    Ok(())
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "arrow_rs")]
+mod arrow_rs;
 mod ffi;
 #[cfg(feature = "ipc")]
 mod io;
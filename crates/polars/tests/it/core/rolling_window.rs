@@ -195,6 +195,85 @@ fn test_rolling_map() {
     );
 }
 
+#[test]
+fn test_rolling_map_reducer() {
+    struct Sum {
+        sum: f64,
+    }
+
+    impl RollingReducer<f64> for Sum {
+        type Output = Float64Type;
+
+        fn init(&mut self) {
+            self.sum = 0.0;
+        }
+
+        fn update(&mut self, value: f64) {
+            self.sum += value;
+        }
+
+        fn remove(&mut self, value: f64) {
+            self.sum -= value;
+        }
+
+        fn emit(&mut self) -> Option<f64> {
+            Some(self.sum)
+        }
+    }
+
+    let ca = Float64Chunked::new(
+        "foo".into(),
+        &[
+            Some(0.0),
+            Some(1.0),
+            Some(2.0),
+            None,
+            None,
+            Some(5.0),
+            Some(6.0),
+        ],
+    );
+
+    let out = ca
+        .rolling_map_reducer(
+            Sum { sum: 0.0 },
+            RollingOptionsFixedWindow {
+                window_size: 3,
+                min_periods: 3,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        Vec::from(&out),
+        &[None, None, Some(3.0), None, None, None, None]
+    );
+
+    let out = ca
+        .rolling_map_reducer(
+            Sum { sum: 0.0 },
+            RollingOptionsFixedWindow {
+                window_size: 3,
+                min_periods: 1,
+                center: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        Vec::from(&out),
+        &[
+            Some(1.0),
+            Some(3.0),
+            Some(3.0),
+            Some(2.0),
+            Some(5.0),
+            Some(11.0),
+            Some(11.0)
+        ]
+    );
+}
+
 #[test]
 fn test_rolling_var() {
     let s = Float64Chunked::new(
@@ -38,6 +38,45 @@ fn write_csv() {
     assert_eq!("0,22.1\r\n1,19.9\r\n2,7.0\r\n3,2.0\r\n4,3.0\r\n", csv);
 }
 
+#[test]
+fn write_csv_column_options() {
+    let mut buf: Vec<u8> = Vec::new();
+    let mut df = create_df();
+
+    CsvWriter::new(&mut buf)
+        .with_float_scientific(Some(true))
+        .with_column_options(vec![
+            (
+                "days".into(),
+                CsvColumnOptions {
+                    quote_style: Some(QuoteStyle::Always),
+                    ..Default::default()
+                },
+            ),
+            (
+                "temp".into(),
+                CsvColumnOptions {
+                    float_scientific: Some(false),
+                    float_precision: Some(2),
+                    ..Default::default()
+                },
+            ),
+        ])
+        .finish(&mut df)
+        .expect("csv written");
+    let csv = std::str::from_utf8(&buf).unwrap();
+    assert_eq!(
+        "days,temp\n\"0\",22.10\n\"1\",19.90\n\"2\",7.00\n\"3\",2.00\n\"4\",3.00\n",
+        csv
+    );
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .with_column_options(vec![("missing".into(), CsvColumnOptions::default())])
+        .finish(&mut df)
+        .expect_err("unknown column should err");
+}
+
 #[test]
 #[cfg(feature = "timezones")]
 fn write_dates() {
@@ -1053,6 +1092,32 @@ fn test_null_values_infer_schema() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_null_values_per_column() -> PolarsResult<()> {
+    let csv = r#"price,name,qty
+NA,NA,-
+-,x,1
+1.5,-,NA"#;
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options.with_null_values(Some(NullValues::NamedMultiple(vec![
+                ("price".into(), vec!["NA".into(), "-".into()]),
+                ("name".into(), vec![]),
+                ("qty".into(), vec!["-".into()]),
+            ])))
+        })
+        .into_reader_with_file_handle(file)
+        .finish()?;
+    let expected = df![
+        "price" => [None, None, Some(1.5)],
+        "name" => ["NA", "x", "-"],
+        "qty" => [None, Some("1"), Some("NA")],
+    ]?;
+    assert!(df.equals_missing(&expected));
+    Ok(())
+}
+
 #[test]
 fn test_comma_separated_field_in_tsv() -> PolarsResult<()> {
     let csv = "first\tsecond\n1\t2.3,2.4\n3\t4.5,4.6\n";
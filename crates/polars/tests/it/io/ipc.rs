@@ -46,6 +46,31 @@ fn write_and_read_ipc() {
     assert!(df.equals(&df_read));
 }
 
+#[test]
+fn write_and_read_ipc_field_metadata() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = create_df();
+    let temp = df
+        .column("temp")
+        .unwrap()
+        .clone()
+        .with_metadata(Some(Arc::new(
+            [("unit".into(), "C".into())].into_iter().collect(),
+        )));
+    df.with_column(temp).unwrap();
+
+    IpcWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("ipc writer");
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish().unwrap();
+    let field_metadata = df_read.field_metadata();
+    assert_eq!(field_metadata.len(), 1);
+    assert_eq!(field_metadata["temp"]["unit"].as_str(), "C");
+    assert!(df.equals(&df_read));
+}
+
 #[test]
 fn test_read_ipc_with_projection() {
     let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
@@ -155,3 +180,53 @@ fn write_and_read_ipc_empty_series() {
     let df_read = IpcReader::new(buf).finish().unwrap();
     assert!(df.equals(&df_read));
 }
+
+#[cfg(feature = "dtype-extension")]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Celsius(i64);
+
+#[cfg(feature = "dtype-extension")]
+impl polars::datatypes::extension::PolarsOpaque for Celsius {
+    fn type_name() -> &'static str {
+        "test.celsius"
+    }
+
+    fn serialize(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.0.to_le_bytes())
+    }
+
+    fn deserialize(bytes: &[u8]) -> PolarsResult<Self> {
+        let bytes = bytes
+            .try_into()
+            .map_err(|_| polars_err!(ComputeError: "invalid celsius"))?;
+        Ok(Celsius(i64::from_le_bytes(bytes)))
+    }
+}
+
+#[test]
+#[cfg(feature = "dtype-extension")]
+fn write_and_read_ipc_opaque() -> PolarsResult<()> {
+    use polars::datatypes::extension::{
+        OpaqueType, opaque_series, opaque_values, register_opaque_type,
+    };
+
+    // Registering the type lets the reader restore the opaque dtype from the extension name.
+    register_opaque_type::<Celsius>()?;
+    let values = [Celsius(-4), Celsius(21)];
+    let mut df = DataFrame::new_infer_height(vec![
+        opaque_series("t".into(), [Some(&values[0]), None, Some(&values[1])]).into_column(),
+    ])?;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(&mut df)?;
+    buf.set_position(0);
+    let df_read = IpcReader::new(buf).finish()?;
+
+    let t = df_read.column("t")?.as_materialized_series();
+    assert_eq!(t.dtype(), &OpaqueType::dtype::<Celsius>());
+    assert_eq!(
+        opaque_values::<Celsius>(t)?,
+        [Some(Celsius(-4)), None, Some(Celsius(21))]
+    );
+    Ok(())
+}
@@ -102,3 +102,46 @@ fn read_ndjson_with_trailing_newline() {
     .unwrap();
     assert!(expected.equals(&df));
 }
+
+#[test]
+fn write_json_serialize_options() {
+    let mut df = df! {
+        "b" => [f64::NAN, f64::NEG_INFINITY],
+        "a" => [0i32, 1],
+    }
+    .unwrap();
+    df.try_apply("a", |s| s.cast(&DataType::Date)).unwrap();
+
+    let mut buf = vec![];
+    JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::Json)
+        .finish(&mut df)
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        r#"[{"b":null,"a":"1970-01-01"},{"b":null,"a":"1970-01-02"}]"#
+    );
+
+    let mut buf = vec![];
+    JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::JsonLines)
+        .with_temporal_format(JsonTemporalFormat::Epoch)
+        .with_non_finite_floats(NonFiniteFloats::String)
+        .finish(&mut df)
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "{\"b\":\"NaN\",\"a\":0}\n{\"b\":\"-Infinity\",\"a\":1}\n"
+    );
+
+    let mut buf = vec![];
+    JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::Json)
+        .with_pretty(true)
+        .finish(&mut df.head(Some(1)))
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "[\n  {\n    \"b\": null,\n    \"a\": \"1970-01-01\"\n  }\n]"
+    );
+}
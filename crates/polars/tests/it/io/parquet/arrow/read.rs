@@ -4,6 +4,7 @@ use polars_parquet::arrow::read::*;
 
 use super::*;
 use crate::io::parquet::read::file::FileReader;
+use crate::io::parquet::read::row_group::{read_columns, to_deserializer};
 #[cfg(feature = "parquet")]
 #[test]
 fn all_types() -> PolarsResult<()> {
@@ -145,5 +146,110 @@ fn read_int96_timestamps() -> PolarsResult<()> {
     std::panic::catch_unwind(|| parse(arrow::datatypes::TimeUnit::Nanosecond))
         .expect_err("Should be a panic error");
 
+    let parse_ns = |int96_overflow: TimestampOverflow| {
+        let mut reader = Cursor::new(timestamp_data);
+        let metadata = read_metadata(&mut reader)?;
+        let columns = read_columns(&mut reader, &metadata.row_groups[0], "timestamps")?;
+        let field = arrow::datatypes::Field::new(
+            "timestamps".into(),
+            arrow::datatypes::ArrowDataType::Timestamp(
+                arrow::datatypes::TimeUnit::Nanosecond,
+                None,
+            ),
+            false,
+        );
+        let overflow = TimestampOverflowOptions {
+            int96: Some(int96_overflow),
+            ..Default::default()
+        };
+        let mut arrays = to_deserializer(columns, field, None, overflow)?;
+        assert_eq!(arrays.len(), 1);
+        let array = arrays.pop().unwrap();
+        PolarsResult::Ok(
+            array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap()
+                .clone(),
+        )
+    };
+    let micros = parse(arrow::datatypes::TimeUnit::Microsecond)?;
+    let micros = micros[0].columns()[0]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i64>>()
+        .unwrap();
+
+    parse_ns(TimestampOverflow::Raise).expect_err("Should be out of range");
+    let saturated = parse_ns(TimestampOverflow::Saturate)?;
+    let nulled = parse_ns(TimestampOverflow::Null)?;
+    assert_eq!(saturated.null_count(), 0);
+    assert!(nulled.null_count() > 0);
+    for ((us, saturated), nulled) in micros
+        .values_iter()
+        .zip(saturated.iter())
+        .zip(nulled.iter())
+    {
+        let ns = (*us as i128) * 1000;
+        let in_range = i64::try_from(ns).ok();
+        assert_eq!(nulled.copied(), in_range);
+        assert_eq!(
+            saturated.copied(),
+            Some(in_range.unwrap_or(if ns < 0 { i64::MIN } else { i64::MAX }))
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn read_int64_timestamp_overflow() -> PolarsResult<()> {
+    let ms = arrow::datatypes::ArrowDataType::Timestamp(TimeUnit::Millisecond, None);
+    let ns = arrow::datatypes::ArrowDataType::Timestamp(TimeUnit::Nanosecond, None);
+    let values = [
+        Some(1),
+        None,
+        Some(i64::MAX / 1_000),
+        Some(i64::MIN / 1_000),
+    ];
+
+    let array = PrimitiveArray::<i64>::from(values).to(ms.clone());
+    let schema = arrow::datatypes::ArrowSchema::from_iter([arrow::datatypes::Field::new(
+        "t".into(),
+        ms,
+        true,
+    )]);
+    let batch = RecordBatchT::new(array.len(), Arc::new(schema.clone()), vec![array.boxed()]);
+    let data = integration_write(&schema, &[batch])?;
+
+    let parse_ns = |int64: TimestampOverflow| {
+        let mut reader = Cursor::new(&data);
+        let metadata = read_metadata(&mut reader)?;
+        let columns = read_columns(&mut reader, &metadata.row_groups[0], "t")?;
+        let field = arrow::datatypes::Field::new("t".into(), ns.clone(), true);
+        let overflow = TimestampOverflowOptions {
+            int64: Some(int64),
+            ..Default::default()
+        };
+        let mut arrays = to_deserializer(columns, field, None, overflow)?;
+        let array = arrays.pop().unwrap();
+        PolarsResult::Ok(
+            array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap()
+                .clone(),
+        )
+    };
+
+    assert!(parse_ns(TimestampOverflow::Raise).is_err());
+    assert_eq!(
+        parse_ns(TimestampOverflow::Null)?,
+        PrimitiveArray::<i64>::from([Some(1_000_000), None, None, None]).to(ns.clone())
+    );
+    assert_eq!(
+        parse_ns(TimestampOverflow::Saturate)?,
+        PrimitiveArray::<i64>::from([Some(1_000_000), None, Some(i64::MAX), Some(i64::MIN)])
+            .to(ns.clone())
+    );
     Ok(())
 }
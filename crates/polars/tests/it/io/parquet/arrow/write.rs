@@ -37,6 +37,7 @@ fn round_trip_opt_stats(
         compression,
         version,
         data_page_size: None,
+        data_page_row_limit: None,
     };
 
     let iter = vec![RecordBatchT::try_new(
@@ -404,6 +405,50 @@ fn utf8_required_v2_delta() -> PolarsResult<()> {
     )
 }
 
+#[test]
+fn utf8_optional_v2_delta_byte_array() -> PolarsResult<()> {
+    round_trip(
+        "string",
+        "nullable",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::DeltaByteArray],
+    )
+}
+
+#[test]
+fn utf8_required_v1_delta_byte_array() -> PolarsResult<()> {
+    round_trip(
+        "string",
+        "required",
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::DeltaByteArray],
+    )
+}
+
+#[test]
+fn f64_optional_v1_byte_stream_split() -> PolarsResult<()> {
+    round_trip(
+        "float64",
+        "nullable",
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::ByteStreamSplit],
+    )
+}
+
+#[test]
+fn f64_optional_v2_byte_stream_split_compressed() -> PolarsResult<()> {
+    round_trip(
+        "float64",
+        "nullable",
+        Version::V2,
+        CompressionOptions::Zstd(None),
+        vec![Encoding::ByteStreamSplit],
+    )
+}
+
 #[test]
 fn struct_v1() -> PolarsResult<()> {
     round_trip(
@@ -6,7 +6,7 @@ use arrow::record_batch::RecordBatchT;
 use polars::prelude::ArrowSchema;
 use polars_buffer::Buffer;
 use polars_error::PolarsResult;
-use polars_parquet::arrow::read::{Filter, column_iter_to_arrays};
+use polars_parquet::arrow::read::{Filter, TimestampOverflowOptions, column_iter_to_arrays};
 use polars_parquet::parquet::metadata::ColumnChunkMetadata;
 use polars_parquet::parquet::read::{BasicDecompressor, PageReader};
 use polars_parquet::read::RowGroupMetadata;
@@ -115,6 +115,7 @@ pub fn to_deserializer(
     columns: Vec<(&ColumnChunkMetadata, Vec<u8>)>,
     field: Field,
     filter: Option<Filter>,
+    timestamp_overflow: TimestampOverflowOptions,
 ) -> PolarsResult<Vec<Box<dyn Array>>> {
     let (columns, types): (Vec<_>, Vec<_>) = columns
         .into_iter()
@@ -133,7 +134,7 @@ pub fn to_deserializer(
         })
         .unzip();
 
-    column_iter_to_arrays(columns, types, field, filter).map(|v| v.0)
+    column_iter_to_arrays(columns, types, field, filter, timestamp_overflow).map(|v| v.0)
 }
 
 /// Returns a vector of iterators of [`Array`] ([`ArrayIter`]) corresponding to the top
@@ -162,6 +163,13 @@ pub fn read_columns_many<R: Read + Seek>(
     field_columns
         .into_iter()
         .zip(fields.iter_values().cloned())
-        .map(|(columns, field)| to_deserializer(columns.clone(), field, filter.clone()))
+        .map(|(columns, field)| {
+            to_deserializer(
+                columns.clone(),
+                field,
+                filter.clone(),
+                TimestampOverflowOptions::default(),
+            )
+        })
         .collect()
 }
@@ -28,6 +28,7 @@ fn round_trip(
         compression,
         version,
         data_page_size: None,
+        data_page_row_limit: None,
     };
 
     let iter = vec![RecordBatchT::try_new(
@@ -4,21 +4,37 @@ mod sidecar;
 
 use std::io::{Cursor, Read, Seek};
 
+#[cfg(feature = "parquet_bloom_filter")]
+use arrow::array::{Int32Array, Int64Array};
 use polars::io::SerReader;
-use polars::io::parquet::read::ParquetReader;
-use polars::io::parquet::write::ParquetWriter;
+#[cfg(feature = "parquet_encryption")]
+use polars::io::parquet::read::FileDecryptionProperties;
+use polars::io::parquet::read::{
+    ParquetReader, column_chunk_statistics_df, row_group_statistics_df,
+};
+#[cfg(feature = "parquet_encryption")]
+use polars::io::parquet::write::FileEncryptionProperties;
+use polars::io::parquet::write::{
+    ParquetBloomFilterOptions, ParquetColumnOptions, ParquetCompression, ParquetWriter,
+};
 use polars_buffer::Buffer;
 use polars_core::df;
 use polars_core::prelude::*;
-use polars_parquet::parquet::compression::{BrotliLevel, CompressionOptions};
+#[cfg(feature = "parquet_bloom_filter")]
+use polars_parquet::parquet::bloom_filter;
+use polars_parquet::parquet::compression::{BrotliLevel, Compression, CompressionOptions};
+use polars_parquet::parquet::encoding::Encoding;
 use polars_parquet::parquet::error::ParquetResult;
 use polars_parquet::parquet::metadata::{Descriptor, SchemaDescriptor};
 use polars_parquet::parquet::page::Page;
+use polars_parquet::parquet::read::read_page_indexes;
 use polars_parquet::parquet::schema::types::{ParquetType, PhysicalType};
 use polars_parquet::parquet::statistics::Statistics;
 use polars_parquet::parquet::write::{
     Compressor, DynIter, DynStreamingIterator, FileWriter, Version, WriteOptions,
 };
+#[cfg(feature = "parquet_bloom_filter")]
+use polars_parquet::read::bloom_filter_may_contain;
 use polars_parquet::read::read_metadata;
 use primitive::array_to_page_v1;
 
@@ -299,3 +315,475 @@ fn test_read_parquet_with_columns() {
     assert_eq!(df_read.shape(), (3, 2));
     df_read.equals(&expected);
 }
+
+#[test]
+#[cfg(feature = "parquet_bloom_filter")]
+fn test_write_bloom_filter() -> PolarsResult<()> {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!(
+        "a" => [1i64, 2, 3],
+        "b" => ["x", "y", "z"],
+        "c" => [3, 4, 5]
+    )?;
+
+    ParquetWriter::new(&mut buf)
+        .with_bloom_filter(Some(ParquetBloomFilterOptions::new(vec![
+            "a".into(),
+            "b".into(),
+        ])))
+        .finish(&mut df)?;
+    buf.set_position(0);
+
+    let metadata = read_metadata(&mut buf)?;
+    let columns = metadata.row_groups[0].parquet_columns();
+    let mut bitset = vec![];
+
+    bloom_filter::read(&columns[0], &mut buf, &mut bitset)?;
+    assert!(!bitset.is_empty());
+    for v in [1i64, 2, 3] {
+        assert!(bloom_filter::is_in_set(
+            &bitset,
+            bloom_filter::hash_native(v)
+        ));
+    }
+    let physical_type = columns[0].physical_type();
+    let values = Int64Array::from_slice([2, 5]);
+    assert_eq!(
+        bloom_filter_may_contain(&bitset, physical_type, &values),
+        Some(true)
+    );
+    let values = Int64Array::from_slice([4, 5]);
+    assert_eq!(
+        bloom_filter_may_contain(&bitset, physical_type, &values),
+        Some(false)
+    );
+    let values = Int32Array::from_slice([2]);
+    assert_eq!(
+        bloom_filter_may_contain(&bitset, physical_type, &values),
+        None
+    );
+
+    bloom_filter::read(&columns[1], &mut buf, &mut bitset)?;
+    assert!(!bitset.is_empty());
+    for v in ["x", "y", "z"] {
+        assert!(bloom_filter::is_in_set(&bitset, bloom_filter::hash_byte(v)));
+    }
+
+    bloom_filter::read(&columns[2], &mut buf, &mut bitset)?;
+    assert!(bitset.is_empty());
+
+    buf.set_position(0);
+    let read = ParquetReader::new(buf).finish()?;
+    assert!(read.equals(&df));
+    Ok(())
+}
+
+#[test]
+fn test_write_bloom_filter_unknown_column() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!("a" => [1, 2, 3]).unwrap();
+
+    let result = ParquetWriter::new(&mut buf)
+        .with_bloom_filter(Some(ParquetBloomFilterOptions::new(vec!["b".into()])))
+        .finish(&mut df);
+    assert!(matches!(result, Err(PolarsError::ColumnNotFound(_))));
+}
+
+#[test]
+fn test_read_page_indexes() -> PolarsResult<()> {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!(
+        "a" => [1i64, 2, 3],
+        "b" => [Some("x"), None, Some("z")]
+    )?;
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    buf.set_position(0);
+
+    let metadata = read_metadata(&mut buf)?;
+    let columns = metadata.row_groups[0].parquet_columns();
+    assert!(columns[0].page_index().is_none());
+
+    read_page_indexes(&mut buf, &metadata)?;
+    for column in columns {
+        let page_index = column.page_index().unwrap();
+        assert_eq!(page_index.num_pages(), 1);
+        let column_index = page_index.column_index.as_ref().unwrap();
+        assert_eq!(column_index.null_pages, [false]);
+    }
+
+    let column_index = columns[0].page_index().unwrap().column_index.as_ref();
+    assert_eq!(column_index.unwrap().min_values, [1i64.to_le_bytes()]);
+    assert_eq!(column_index.unwrap().max_values, [3i64.to_le_bytes()]);
+    Ok(())
+}
+
+#[test]
+fn test_write_data_page_row_limit() -> PolarsResult<()> {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!(
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("{}", i % 10)).collect::<Vec<_>>()
+    )?;
+    ParquetWriter::new(&mut buf)
+        .with_data_page_row_limit(Some(300))
+        .finish(&mut df)?;
+    buf.set_position(0);
+
+    let metadata = read_metadata(&mut buf)?;
+    read_page_indexes(&mut buf, &metadata)?;
+    for column in metadata.row_groups[0].parquet_columns() {
+        let page_index = column.page_index().unwrap();
+        let first_rows = page_index
+            .offset_index
+            .page_locations
+            .iter()
+            .map(|location| location.first_row_index)
+            .collect::<Vec<_>>();
+        assert_eq!(first_rows, [0, 300, 600, 900]);
+    }
+
+    let df_read = ParquetReader::new(buf).finish()?;
+    assert!(df.equals(&df_read));
+    Ok(())
+}
+
+#[test]
+fn test_write_delta_byte_array() -> PolarsResult<()> {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let urls = (0..1000)
+        .map(|i| format!("https://example.com/some/long/path/{i}"))
+        .collect::<Vec<_>>();
+    let hashes = (0..1000u64)
+        .map(|i| format!("{:016x}", i.wrapping_mul(0x9E3779B97F4A7C15)))
+        .collect::<Vec<_>>();
+    let mut df = df!(
+        "url" => urls,
+        "hash" => hashes
+    )?;
+
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    buf.set_position(0);
+
+    let metadata = read_metadata(&mut buf)?;
+    let columns = metadata.row_groups[0].parquet_columns();
+    let has_encoding = |column: usize, encoding: Encoding| {
+        columns[column]
+            .column_encoding()
+            .iter()
+            .any(|e| Encoding::try_from(*e).ok() == Some(encoding))
+    };
+    // The values of "url" share long prefixes, those of "hash" don't.
+    assert!(has_encoding(0, Encoding::DeltaByteArray));
+    assert!(has_encoding(1, Encoding::Plain));
+    assert!(!has_encoding(1, Encoding::DeltaByteArray));
+
+    let df_read = ParquetReader::new(buf).finish()?;
+    assert!(df.equals(&df_read));
+    Ok(())
+}
+
+#[test]
+fn test_write_byte_stream_split() -> PolarsResult<()> {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!(
+        "a" => (0..1000).map(|i| (i as f64).sin()).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| Some(i as f32 / 7.0).filter(|_| i % 3 != 0)).collect::<Vec<_>>(),
+        "c" => (0..1000).map(|i| i as f64).collect::<Vec<_>>()
+    )?;
+
+    ParquetWriter::new(&mut buf)
+        .with_byte_stream_split(vec!["a".into(), "b".into()])
+        .finish(&mut df)?;
+    buf.set_position(0);
+
+    let metadata = read_metadata(&mut buf)?;
+    let columns = metadata.row_groups[0].parquet_columns();
+    let has_encoding = |column: usize, encoding: Encoding| {
+        columns[column]
+            .column_encoding()
+            .iter()
+            .any(|e| Encoding::try_from(*e).ok() == Some(encoding))
+    };
+    assert!(has_encoding(0, Encoding::ByteStreamSplit));
+    assert!(has_encoding(1, Encoding::ByteStreamSplit));
+    assert!(!has_encoding(2, Encoding::ByteStreamSplit));
+
+    let df_read = ParquetReader::new(buf).finish()?;
+    assert!(df.equals_missing(&df_read));
+
+    let mut df = df!("a" => [1i64, 2, 3])?;
+    let result = ParquetWriter::new(Cursor::new(Vec::new()))
+        .with_byte_stream_split(vec!["a".into()])
+        .finish(&mut df);
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_write_column_options() -> PolarsResult<()> {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!(
+        "a" => (0..1000).map(|i| format!("{}", i % 10)).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| (i % 5) as f64 / 7.0).collect::<Vec<_>>(),
+        "c" => (0..1000).map(|i| i % 5).collect::<Vec<_>>()
+    )?;
+
+    ParquetWriter::new(&mut buf)
+        .with_compression(ParquetCompression::Snappy)
+        .with_column_options(vec![
+            (
+                "a".into(),
+                ParquetColumnOptions {
+                    compression: Some(ParquetCompression::Zstd(None)),
+                    ..Default::default()
+                },
+            ),
+            (
+                "b".into(),
+                ParquetColumnOptions {
+                    compression: Some(ParquetCompression::Uncompressed),
+                    dictionary: false,
+                },
+            ),
+        ])
+        .finish(&mut df)?;
+    buf.set_position(0);
+
+    let metadata = read_metadata(&mut buf)?;
+    let columns = metadata.row_groups[0].parquet_columns();
+    let compressions = columns.iter().map(|c| c.compression()).collect::<Vec<_>>();
+    assert_eq!(
+        compressions,
+        [
+            Compression::Zstd,
+            Compression::Uncompressed,
+            Compression::Snappy
+        ]
+    );
+    let has_dictionary = columns
+        .iter()
+        .map(|c| c.dictionary_page_offset().is_some())
+        .collect::<Vec<_>>();
+    assert_eq!(has_dictionary, [true, false, true]);
+
+    let df_read = ParquetReader::new(buf).finish()?;
+    assert!(df.equals(&df_read));
+
+    let result = ParquetWriter::new(Cursor::new(Vec::new()))
+        .with_column_options(vec![("x".into(), ParquetColumnOptions::default())])
+        .finish(&mut df);
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_write_nested_statistics() -> PolarsResult<()> {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let x = Series::new("x".into(), [0i64, 1, 2, 3, 4, 5]);
+    let y = Series::new(
+        "y".into(),
+        [Some("a"), None, Some("c"), Some("d"), Some("e"), Some("f")],
+    );
+    let s = StructChunked::from_series("s".into(), 6, [x, y].iter())?.into_series();
+    let l = Series::new(
+        "l".into(),
+        [
+            Series::new("".into(), [1i64, 2]),
+            Series::new("".into(), [3i64]),
+            Series::new("".into(), [4i64]),
+            Series::new("".into(), [5i64, 6]),
+            Series::new("".into(), [7i64]),
+            Series::new("".into(), [8i64]),
+        ],
+    );
+    let mut df = DataFrame::new(6, vec![s.into_column(), l.into_column()])?;
+
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(3))
+        .finish(&mut df)?;
+    buf.set_position(0);
+
+    let metadata = read_metadata(&mut buf)?;
+    assert_eq!(metadata.row_groups.len(), 2);
+
+    let columns = metadata.row_groups[1].parquet_columns();
+    let x_stats = columns[0].statistics().unwrap()?;
+    let x_stats = x_stats.expect_as_int64();
+    assert_eq!((x_stats.min_value, x_stats.max_value), (Some(3), Some(5)));
+    assert_eq!(x_stats.null_count, Some(0));
+
+    let y_stats = metadata.row_groups[0].parquet_columns()[1]
+        .statistics()
+        .unwrap()?;
+    let y_stats = y_stats.expect_as_binary();
+    assert_eq!(y_stats.min_value.as_deref(), Some(&b"a"[..]));
+    assert_eq!(y_stats.max_value.as_deref(), Some(&b"c"[..]));
+    assert_eq!(y_stats.null_count, Some(1));
+
+    let l_stats = columns[2].statistics().unwrap()?;
+    let l_stats = l_stats.expect_as_int64();
+    assert_eq!((l_stats.min_value, l_stats.max_value), (Some(5), Some(8)));
+
+    let live_columns = PlIndexSet::from_iter([PlSmallStr::from_static("s")]);
+    let statistics = row_group_statistics_df(&metadata, df.schema(), &live_columns)?;
+    let s_min = statistics.column("s_min")?.struct_()?.field_by_name("x")?;
+    let s_max = statistics.column("s_max")?.struct_()?.field_by_name("x")?;
+    assert_eq!(Vec::from(s_min.i64()?), [Some(0), Some(3)]);
+    assert_eq!(Vec::from(s_max.i64()?), [Some(2), Some(5)]);
+    Ok(())
+}
+
+#[test]
+fn test_column_chunk_statistics_df() -> PolarsResult<()> {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let x = Series::new("x".into(), [0i64, 1, 2, 3, 4, 5]);
+    let y = Series::new(
+        "y".into(),
+        [Some("a"), None, Some("c"), Some("d"), Some("e"), Some("f")],
+    );
+    let s = StructChunked::from_series("s".into(), 6, [x, y].iter())?.into_series();
+    let a = Series::new("a".into(), [1.5f64, 2.5, 0.5, 4.0, 5.0, 6.0]);
+    let mut df = DataFrame::new(6, vec![a.into_column(), s.into_column()])?;
+
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(3))
+        .finish(&mut df)?;
+    buf.set_position(0);
+
+    let metadata = read_metadata(&mut buf)?;
+    let statistics = column_chunk_statistics_df(&metadata)?;
+    assert_eq!(statistics.height(), 6);
+
+    let row_group = statistics.column("row_group")?.idx()?;
+    assert_eq!(Vec::from(row_group), [0, 0, 0, 1, 1, 1].map(Some));
+    let column = statistics.column("column")?.str()?;
+    assert_eq!(
+        Vec::from(column),
+        ["a", "s.x", "s.y", "a", "s.x", "s.y"].map(Some)
+    );
+    let num_rows = statistics.column("num_rows")?.u64()?;
+    assert_eq!(Vec::from(num_rows), [Some(3); 6]);
+    let null_count = statistics.column("null_count")?.u64()?;
+    assert_eq!(Vec::from(null_count), [0, 0, 1, 0, 0, 0].map(Some));
+    let min = statistics.column("min")?.str()?;
+    assert_eq!(Vec::from(min), ["0.5", "0", "a", "4.0", "3", "d"].map(Some));
+    let max = statistics.column("max")?.str()?;
+    assert_eq!(Vec::from(max), ["2.5", "2", "c", "6.0", "5", "f"].map(Some));
+    let compressed_size = statistics.column("compressed_size")?.i64()?;
+    assert!(compressed_size.into_no_null_iter().all(|size| size > 0));
+    Ok(())
+}
+
+#[test]
+fn test_write_memory_budget() -> PolarsResult<()> {
+    let columns = (0..50)
+        .map(|i| {
+            Column::new(
+                format!("c{i}").into(),
+                (0..1000).map(|j| i * j).collect::<Vec<i64>>(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let mut df = DataFrame::new(1000, columns)?;
+
+    let mut expected = Vec::new();
+    ParquetWriter::new(&mut expected)
+        .with_row_group_size(Some(300))
+        .finish(&mut df)?;
+
+    // Budgets that are smaller than a column, fit several columns and fit all columns.
+    for memory_budget in [1, 20_000, usize::MAX] {
+        let mut buf = Vec::new();
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(300))
+            .with_memory_budget(Some(memory_budget))
+            .finish(&mut df)?;
+        assert_eq!(buf, expected);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet_encryption")]
+fn test_write_encrypted() -> PolarsResult<()> {
+    let footer_key = b"0123456789012345".to_vec();
+    let column_key = b"1234567890123450".to_vec();
+    let mut df = df!(
+        "a" => [1i64, 2, 3],
+        "b" => ["x", "y", "z"]
+    )?;
+
+    let cases = [
+        // All columns encrypted with the footer key.
+        (
+            FileEncryptionProperties::new(footer_key.clone()),
+            FileDecryptionProperties::new(footer_key.clone()),
+        ),
+        // Only "a" encrypted, with its own key.
+        (
+            FileEncryptionProperties::new(footer_key.clone())
+                .with_column_key("a", column_key.clone())
+                .with_aad_prefix(b"file".to_vec()),
+            FileDecryptionProperties::new(footer_key.clone()).with_column_key("a", column_key),
+        ),
+    ];
+    for (encryption, decryption) in cases {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        ParquetWriter::new(&mut buf)
+            .with_encryption(Some(Arc::new(encryption)))
+            .finish(&mut df)?;
+        assert!(buf.get_ref().ends_with(b"PARE"));
+
+        let df_read = ParquetReader::new(buf.clone())
+            .with_decryption(Some(Arc::new(decryption)))
+            .finish()?;
+        assert!(df.equals(&df_read));
+
+        assert!(ParquetReader::new(buf).finish().is_err());
+    }
+
+    let result = ParquetWriter::new(Cursor::new(Vec::new()))
+        .with_encryption(Some(Arc::new(
+            FileEncryptionProperties::new(footer_key).with_column_key("c", vec![0; 16]),
+        )))
+        .finish(&mut df);
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_append_row_groups() -> PolarsResult<()> {
+    let mut df = df!("a" => [1i32, 2, 3])?;
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+
+    let options = WriteOptions {
+        write_statistics: true,
+        version: Version::V1,
+    };
+    let mut writer = FileWriter::try_append(buf, options, None)?;
+    let page = array_to_page_v1(
+        &[Some(4i32), None],
+        &options,
+        &writer.schema().columns()[0].descriptor,
+    );
+    let pages = DynStreamingIterator::new(Compressor::new_from_vec(
+        DynIter::new(std::iter::once(page)),
+        CompressionOptions::Uncompressed,
+        vec![],
+    ));
+    writer.write(2, DynIter::new(std::iter::once(Ok(pages))))?;
+    // Without new key-value metadata, the Arrow schema of the file is kept.
+    let len = writer.end(None)?;
+
+    let mut data = writer.into_inner().into_inner();
+    data.truncate(len as usize);
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    assert_eq!(metadata.row_groups.len(), 2);
+    assert_eq!(metadata.num_rows, 5);
+
+    let out = ParquetReader::new(Cursor::new(data)).finish()?;
+    let expected = df!("a" => [Some(1i32), Some(2), Some(3), Some(4), None])?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
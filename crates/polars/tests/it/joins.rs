@@ -56,3 +56,104 @@ fn join_empty_datasets() -> PolarsResult<()> {
 
     Ok(())
 }
+
+/// A label that is equal to the labels that only differ in case.
+#[cfg(feature = "dtype-extension")]
+#[derive(Clone, Debug)]
+struct Label(String);
+
+#[cfg(feature = "dtype-extension")]
+impl Label {
+    fn key(&self) -> String {
+        self.0.to_lowercase()
+    }
+}
+
+#[cfg(feature = "dtype-extension")]
+impl PartialEq for Label {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+#[cfg(feature = "dtype-extension")]
+impl Eq for Label {}
+
+#[cfg(feature = "dtype-extension")]
+impl PartialOrd for Label {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "dtype-extension")]
+impl Ord for Label {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+#[cfg(feature = "dtype-extension")]
+impl std::hash::Hash for Label {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.key().hash(state)
+    }
+}
+
+#[cfg(feature = "dtype-extension")]
+impl polars::datatypes::extension::PolarsOpaque for Label {
+    fn type_name() -> &'static str {
+        "test.label"
+    }
+
+    fn serialize(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.0.as_bytes())
+    }
+
+    fn deserialize(bytes: &[u8]) -> PolarsResult<Self> {
+        String::from_utf8(bytes.to_vec())
+            .map(Label)
+            .map_err(|e| polars_err!(ComputeError: "invalid label: {e}"))
+    }
+}
+
+#[test]
+#[cfg(feature = "dtype-extension")]
+fn join_opaque_keys() -> PolarsResult<()> {
+    use polars::datatypes::extension::{opaque_series, opaque_values};
+
+    let labels = |name: &str, values: &[&str]| {
+        let values = values
+            .iter()
+            .map(|v| Label(v.to_string()))
+            .collect::<Vec<_>>();
+        opaque_series(name.into(), values.iter().map(Some)).into_column()
+    };
+    let left = DataFrame::new_infer_height(vec![
+        labels("k", &["a", "B", "c"]),
+        Column::new("v".into(), [1, 2, 3]),
+    ])?;
+    let right = DataFrame::new_infer_height(vec![
+        labels("k", &["b", "A", "d"]),
+        Column::new("w".into(), [10, 20, 30]),
+    ])?;
+
+    // The keys are matched by the equality of the labels, not their serializations.
+    let out = left
+        .join(&right, ["k"], ["k"], JoinArgs::new(JoinType::Inner), None)?
+        .sort(["v"], Default::default())?;
+    assert_eq!(out.column("v")?.i32()?.to_vec(), [Some(1), Some(2)]);
+    assert_eq!(out.column("w")?.i32()?.to_vec(), [Some(20), Some(10)]);
+    // The output keeps the left labels.
+    let keys = opaque_values::<Label>(out.column("k")?.as_materialized_series())?;
+    assert_eq!(
+        keys.into_iter().map(|k| k.unwrap().0).collect::<Vec<_>>(),
+        ["a", "B"]
+    );
+
+    let out = left
+        .join(&right, ["k"], ["k"], JoinArgs::new(JoinType::Left), None)?
+        .sort(["v"], Default::default())?;
+    assert_eq!(out.column("w")?.i32()?.to_vec(), [Some(20), Some(10), None]);
+    Ok(())
+}
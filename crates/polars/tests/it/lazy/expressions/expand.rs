@@ -46,3 +46,103 @@ fn test_expand_datetimes_3042() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_cols_matching() -> PolarsResult<()> {
+    let df = df![
+        "key" => ["a", "b", "a"],
+        "x_1" => [1i32, 2, 3],
+        "x_2" => [1.5f64, 2.5, 3.5],
+        "y" => [10i64, 20, 30],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([cols_matching(Selector::by_name_regex("^x_"))])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["x_1", "x_2"]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .with_columns([cols_matching(DataTypeSelector::Integer) * lit(2)])
+        .collect()?;
+    assert_eq!(
+        out.column("y")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[20, 40, 60]
+    );
+    assert_eq!(
+        out.column("x_2")?
+            .f64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[1.5, 2.5, 3.5]
+    );
+
+    let out = df
+        .lazy()
+        .group_by_stable([cols_matching(dtype_col(&DataType::String))])
+        .agg([cols_matching(Selector::by_dtype(DataTypeSelector::Float)).sum()])
+        .collect()?;
+    let expected = df![
+        "key" => ["a", "b"],
+        "x_2" => [5.0, 2.5],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_cols_matching_metadata() -> PolarsResult<()> {
+    let with_unit = |name: &str, unit: &str| {
+        col(name).with_field_metadata([("unit".into(), unit.into())].into_iter().collect())
+    };
+    let lf = df![
+        "key" => ["a", "b", "a"],
+        "speed" => [1.0, 2.0, 3.0],
+        "distance" => [10.0, 20.0, 30.0],
+        "time" => [4.0, 5.0, 6.0],
+    ]?
+    .lazy()
+    .with_columns([
+        with_unit("speed", "m/s"),
+        with_unit("distance", "m"),
+        with_unit("time", "s"),
+    ]);
+    let by_unit = |unit: &str| cols_matching(Selector::by_metadata("unit", unit));
+
+    let out = lf
+        .clone()
+        .select([cols_matching(
+            Selector::by_metadata("unit", "m") | Selector::by_metadata("unit", "s"),
+        )])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["distance", "time"]);
+
+    let out = lf
+        .clone()
+        .with_columns([by_unit("m") * lit(2.0)])
+        .collect()?;
+    assert_eq!(
+        out.column("distance")?
+            .f64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[20.0, 40.0, 60.0]
+    );
+
+    let out = lf
+        .group_by_stable([col("key")])
+        .agg([by_unit("m/s").sum()])
+        .collect()?;
+    let expected = df![
+        "key" => ["a", "b"],
+        "speed" => [4.0, 2.0],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
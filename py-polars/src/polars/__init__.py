@@ -160,6 +160,7 @@ from polars.functions import (
     explain_all,
     field,
     first,
+    first_non_null_horizontal,
     fold,
     format,
     from_epoch,
@@ -168,6 +169,7 @@ from polars.functions import (
     implode,
     int_range,
     int_ranges,
+    interval,
     last,
     len,
     linear_space,
@@ -185,14 +187,18 @@ from polars.functions import (
     n_unique,
     nth,
     ones,
+    param,
+    privacy_budget_remaining,
     quantile,
     reduce,
     repeat,
     rolling_corr,
     rolling_cov,
     row_index,
+    search_sorted_by,
     select,
     self_dtype,
+    set_privacy_budget,
     set_random_seed,
     sql_expr,
     std,
@@ -207,13 +213,16 @@ from polars.functions import (
     union,
     var,
     when,
+    when_lazy,
     zeros,
 )
 from polars.interchange import CompatLevel
 from polars.io import (
+    FileListingOptions,
     FileProviderArgs,
     PartitionBy,
     ScanCastOptions,
+    SchemaEvolutionPolicy,
     defer,
     read_avro,
     read_clipboard,
@@ -233,6 +242,7 @@ from polars.io import (
     read_parquet,
     read_parquet_metadata,
     read_parquet_schema,
+    read_parquet_statistics,
     scan_csv,
     scan_delta,
     scan_iceberg,
@@ -250,13 +260,20 @@ from polars.io.cloud import (
     CredentialProviderFunctionReturn,
     CredentialProviderGCP,
 )
-from polars.lazyframe import GPUEngine, LazyFrame, QueryOptFlags
+from polars.lazyframe import GPUEngine, LazyFrame, QueryOptFlags, StreamingEngine
 from polars.meta import (
+    available_time_zones,
     build_info,
     get_index_type,
     show_versions,
     thread_pool_size,
     threadpool_size,
+    tzdb_version,
+)
+from polars.plan_cache import (
+    clear_plan_cache,
+    plan_cache_stats,
+    set_plan_cache_capacity,
 )
 from polars.schema import Schema
 from polars.series import Series
@@ -284,6 +301,7 @@ __all__ = [
     "Series",
     # Engine configuration
     "GPUEngine",
+    "StreamingEngine",
     # schema
     "Schema",
     # datatype_expr
@@ -330,9 +348,11 @@ __all__ = [
     "get_extension_type",
     # polars.io
     "defer",
+    "FileListingOptions",
     "FileProviderArgs",
     "PartitionBy",
     "ScanCastOptions",
+    "SchemaEvolutionPolicy",
     "read_avro",
     "read_clipboard",
     "read_csv",
@@ -351,6 +371,7 @@ __all__ = [
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
+    "read_parquet_statistics",
     "scan_csv",
     "scan_delta",
     "scan_iceberg",
@@ -372,10 +393,15 @@ __all__ = [
     "disable_string_cache",
     "enable_string_cache",
     "using_string_cache",
+    # polars.plan_cache
+    "clear_plan_cache",
+    "plan_cache_stats",
+    "set_plan_cache_capacity",
     # polars.config
     "Config",
     # polars.functions.whenthen
     "when",
+    "when_lazy",
     # polars.functions
     "align_frames",
     "arg_where",
@@ -403,6 +429,7 @@ __all__ = [
     "any_horizontal",
     "cum_sum",
     "cum_sum_horizontal",
+    "first_non_null_horizontal",
     "max",
     "max_horizontal",
     "mean_horizontal",
@@ -444,6 +471,7 @@ __all__ = [
     "implode",
     "int_range",
     "int_ranges",
+    "interval",
     "last",
     "linear_space",
     "linear_spaces",
@@ -454,11 +482,13 @@ __all__ = [
     "median",
     "n_unique",
     "nth",
+    "param",
     "quantile",
     "reduce",
     "rolling_corr",
     "rolling_cov",
     "row_index",
+    "search_sorted_by",
     "select",
     "std",
     "struct",
@@ -469,6 +499,9 @@ __all__ = [
     "len",
     # polars.functions.random
     "set_random_seed",
+    # polars.functions.privacy
+    "privacy_budget_remaining",
+    "set_privacy_budget",
     # polars.convert
     "from_arrow",
     "from_dataframe",
@@ -481,11 +514,13 @@ __all__ = [
     "from_torch",
     "json_normalize",
     # polars.meta
+    "available_time_zones",
     "build_info",
     "get_index_type",
     "show_versions",
     "thread_pool_size",
     "threadpool_size",
+    "tzdb_version",
     # polars.sql
     "SQLContext",
     "sql",
@@ -82,6 +82,9 @@ SetOperation: TypeAlias = Literal[
     "union", "difference", "intersection", "symmetric_difference"
 ]
 FloatFmt: TypeAlias = Literal["full", "mixed"]
+NearestMetric: TypeAlias = Literal["euclidean", "cosine"]
+ConvolveMode: TypeAlias = Literal["full", "same", "valid"]
+ConvolveNullBehavior: TypeAlias = Literal["propagate", "zero", "renormalize"]
 NDArray1D: TypeAlias = NDArray[Any]
 StatisticsOptions: TypeAlias = Any
 EngineType: TypeAlias = Literal["auto", "in-memory", "streaming", "gpu"]
@@ -113,6 +116,7 @@ class PySeries:
     def map_elements(
         self, function: Any, return_dtype: Any | None, skip_nulls: bool
     ) -> PySeries: ...
+    def scan_fn(self, init: Any, function: Any, dtype: Any) -> PySeries: ...
 
     # general
     def struct_unnest(self) -> PyDataFrame: ...
@@ -137,6 +141,7 @@ class PySeries:
     def name(self) -> str: ...
     def rename(self, name: str) -> None: ...
     def dtype(self) -> Any: ...
+    def field_metadata(self) -> dict[str, str] | None: ...
     def set_sorted_flag(self, descending: bool) -> PySeries: ...
     def n_chunks(self) -> int: ...
     def append(self, other: PySeries) -> None: ...
@@ -620,9 +625,17 @@ class PyDataFrame:
         shuffle: bool,
         seed: int | None,
     ) -> PyDataFrame: ...
+    def split(
+        self,
+        fractions: Sequence[float],
+        shuffle: bool,
+        seed: int | None,
+        stratify_by: Sequence[str] | None,
+    ) -> list[PyDataFrame]: ...
     def rechunk(self) -> PyDataFrame: ...
     def as_str(self) -> str: ...
     def get_columns(self) -> list[PySeries]: ...
+    def field_metadata(self) -> dict[str, dict[str, str]]: ...
     def columns(self) -> list[str]: ...
     def set_column_names(self, names: Sequence[str]) -> None: ...
     def dtypes(self) -> list[Any]: ...
@@ -684,6 +697,8 @@ class PyDataFrame:
         inference_size: int,
     ) -> tuple[Any, bool]: ...
     def shrink_to_fit(self) -> None: ...
+    def create_index(self, column: str, kind: str, block_size: int) -> None: ...
+    def drop_indexes(self) -> None: ...
     def hash_rows(self, k0: int, k1: int, k2: int, k3: int) -> PySeries: ...
     def transpose(
         self, keep_names_as: str | None, column_names: None | str | Sequence[str]
@@ -697,6 +712,23 @@ class PyDataFrame:
     ) -> PyDataFrame: ...
     def to_struct(self, name: str, invalid_indices: Sequence[int]) -> PySeries: ...
     def clear(self) -> PyDataFrame: ...
+    def pca(self, k: int) -> tuple[PyDataFrame, PyDataFrame]: ...
+    def kmeans(
+        self, columns: Sequence[str], k: int, max_iter: int, seed: int | None
+    ) -> PySeries: ...
+    def dbscan(self, columns: Sequence[str], eps: float, min_pts: int) -> PySeries: ...
+    def cdc(self, other: PyDataFrame, keys: Sequence[str]) -> PyDataFrame: ...
+    def join_nearest(
+        self,
+        other: PyDataFrame,
+        left_on: Sequence[str],
+        right_on: Sequence[str],
+        k: int,
+        metric: NearestMetric,
+        max_distance: float | None,
+        suffix: str,
+        distance_column: str,
+    ) -> PyDataFrame: ...
     def _export_columns(self, location: int) -> None: ...
     @classmethod
     def _import_columns(cls, location: int, width: int) -> PyDataFrame: ...
@@ -796,7 +828,13 @@ class PyDataFrame:
         projection: Sequence[int] | None,
         n_rows: int | None,
     ) -> PyDataFrame: ...
-    def write_json(self, py_f: Any) -> None: ...
+    def write_json(
+        self,
+        py_f: Any,
+        temporal_format: Literal["iso", "epoch"],
+        non_finite_floats: Literal["null", "string"],
+        pretty: bool,
+    ) -> None: ...
     def write_ipc_stream(
         self, py_f: Any, compression: Any, compat_level: Any
     ) -> None: ...
@@ -835,6 +873,11 @@ class PyLazyFrame:
         include_file_paths: str | None,
         cloud_options: dict[str, Any] | None,
         credential_provider: Any | None,
+        schema_evolution: Any | None,
+        include_row_index_in_file: str | None,
+        include_file_modification_time: str | None,
+        listing_options: Any | None,
+        statistics_sidecar: str | None,
     ) -> PyLazyFrame: ...
     @staticmethod
     def new_from_csv(
@@ -870,6 +913,11 @@ class PyLazyFrame:
         credential_provider: Any | None,
         include_file_paths: str | None,
         missing_columns: str | None,
+        schema_evolution: Any | None,
+        include_row_index_in_file: str | None,
+        include_file_modification_time: str | None,
+        listing_options: Any | None,
+        statistics_sidecar: str | None,
     ) -> PyLazyFrame: ...
     @staticmethod
     def new_from_parquet(
@@ -879,6 +927,14 @@ class PyLazyFrame:
         parallel: Any,
         low_memory: bool,
         use_statistics: bool,
+        int96_time_unit: TimeUnit | None,
+        int96_time_zone: str | None,
+        int96_overflow: Any | None,
+        int64_timestamp_overflow: Any | None,
+        uuid_as_string: bool,
+        footer_summary: str | None = None,
+        footers: list[tuple[str, bytes]] | None = None,
+        categorical_columns: Sequence[str] | None = None,
     ) -> PyLazyFrame: ...
     @staticmethod
     def new_from_ipc(
@@ -925,6 +981,7 @@ class PyLazyFrame:
     def describe_optimized_plan(self) -> str: ...
     def describe_plan_tree(self) -> str: ...
     def describe_optimized_plan_tree(self) -> str: ...
+    def explain_analyze(self) -> str: ...
     def to_dot(self, optimized: bool) -> str: ...
     def to_dot_streaming_phys(self, optimized: bool) -> str: ...
     def sort(
@@ -950,11 +1007,18 @@ class PyLazyFrame:
         self, k: int, by: Sequence[PyExpr], reverse: Sequence[bool]
     ) -> PyLazyFrame: ...
     def cache(self) -> PyLazyFrame: ...
+    def bind_parameters(self, params: list[tuple[str, PyExpr]]) -> PyLazyFrame: ...
     def with_optimizations(self, optflags: PyOptFlags) -> PyLazyFrame: ...
     def profile(
         self, lambda_post_opt: Any | None
     ) -> tuple[PyDataFrame, PyDataFrame]: ...
-    def collect(self, engine: Any, lambda_post_opt: Any | None) -> PyDataFrame: ...
+    def collect(
+        self,
+        engine: Any,
+        lambda_post_opt: Any | None,
+        morsel_size: int | None = None,
+        adaptive_morsel_size: bool | None = None,
+    ) -> PyDataFrame: ...
     def collect_with_callback(self, engine: Any, lambda_func: Any) -> None: ...
     def collect_batches(
         self, engine: Any, maintain_order: bool, chunk_size: int | None, lazy: bool
@@ -968,8 +1032,11 @@ class PyLazyFrame:
         statistics: StatisticsOptions,
         row_group_size: int | None,
         data_page_size: int | None,
+        data_page_row_limit: int | None,
         metadata: KeyValueMetadata | None,
         arrow_schema: ArrowSchemaExportable | None = None,
+        uuid_columns: Sequence[str] = (),
+        column_options: Sequence[tuple[str, str | None, int | None, bool]] = (),
     ) -> PyLazyFrame: ...
     def sink_ipc(
         self,
@@ -992,6 +1059,7 @@ class PyLazyFrame:
         separator: int,
         line_terminator: str,
         quote_char: int,
+        escape_char: int | None,
         batch_size: int,
         datetime_format: str | None,
         date_format: str | None,
@@ -1001,6 +1069,17 @@ class PyLazyFrame:
         decimal_comma: bool,
         null_value: str | None,
         quote_style: QuoteStyle | None,
+        column_options: Sequence[
+            tuple[
+                str,
+                QuoteStyle | None,
+                bool | None,
+                int | None,
+                str | None,
+                str | None,
+                str | None,
+            ]
+        ],
     ) -> PyLazyFrame: ...
     def sink_ndjson(
         self,
@@ -1008,6 +1087,8 @@ class PyLazyFrame:
         compression: Literal["uncompressed", "gzip", "zstd"],
         compression_level: int | None,
         check_extension: bool,
+        temporal_format: Literal["iso", "epoch"],
+        non_finite_floats: Literal["null", "string"],
         sink_options: Any,
     ) -> PyLazyFrame: ...
     def sink_batches(
@@ -1017,10 +1098,16 @@ class PyLazyFrame:
         chunk_size: int | None,
     ) -> PyLazyFrame: ...
     def filter(self, predicate: PyExpr) -> PyLazyFrame: ...
+    def time_series_folds(
+        self, time_col: str, n_folds: int, gap: int
+    ) -> list[tuple[PyLazyFrame, PyLazyFrame]]: ...
     def remove(self, predicate: PyExpr) -> PyLazyFrame: ...
     def select(self, exprs: Sequence[PyExpr]) -> PyLazyFrame: ...
     def select_seq(self, exprs: Sequence[PyExpr]) -> PyLazyFrame: ...
     def group_by(self, by: Sequence[PyExpr], maintain_order: bool) -> PyLazyGroupBy: ...
+    def group_by_hashed(
+        self, hash: PyExpr, by: Sequence[PyExpr], maintain_order: bool
+    ) -> PyLazyGroupBy: ...
     def rolling(
         self,
         index_column: PyExpr,
@@ -1058,6 +1145,8 @@ class PyLazyFrame:
         coalesce: bool,
         allow_eq: bool,
         check_sortedness: bool,
+        distance_column: str | None = None,
+        indicator_column: str | None = None,
     ) -> PyLazyFrame: ...
     def join(
         self,
@@ -1076,6 +1165,18 @@ class PyLazyFrame:
     def join_where(
         self, other: PyLazyFrame, predicates: Sequence[PyExpr], suffix: str
     ) -> PyLazyFrame: ...
+    def interval_join(
+        self, other: PyLazyFrame, left_on: PyExpr, right_on: PyExpr, suffix: str
+    ) -> PyLazyFrame: ...
+    def join_hashed(
+        self,
+        other: PyLazyFrame,
+        left_hash: PyExpr,
+        right_hash: PyExpr,
+        how: JoinType,
+        suffix: str,
+        maintain_order: MaintainOrderJoin,
+    ) -> PyLazyFrame: ...
     def with_columns(self, exprs: Sequence[PyExpr]) -> PyLazyFrame: ...
     def with_columns_seq(self, exprs: Sequence[PyExpr]) -> PyLazyFrame: ...
     def match_to_schema(
@@ -1152,6 +1253,10 @@ class PyLazyFrame:
     def cast_all(self, dtype: PyDataTypeExpr, strict: bool) -> PyLazyFrame: ...
     def clone(self) -> PyLazyFrame: ...
     def collect_schema(self) -> dict[str, Any]: ...
+    def collect_field_metadata(self) -> dict[str, dict[str, str]]: ...
+    def collect_metadata(self) -> dict[str, str]: ...
+    def scan_pruning_report(self) -> PyDataFrame: ...
+    def collect_statistics_sidecar(self, path: str) -> PyDataFrame: ...
     def unnest(self, columns: PySelector, separator: str | None) -> PyLazyFrame: ...
     def count(self) -> PyLazyFrame: ...
     def merge_sorted(self, other: PyLazyFrame, key: str) -> PyLazyFrame: ...
@@ -1230,6 +1335,12 @@ class PyExpr:
         left_closed: bool,
         include_breaks: bool,
     ) -> PyExpr: ...
+    def cut_bounds(
+        self,
+        breaks: Sequence[float],
+        labels: Sequence[str] | None,
+        left_closed: bool,
+    ) -> PyExpr: ...
     def qcut(
         self,
         probs: Sequence[float],
@@ -1238,6 +1349,13 @@ class PyExpr:
         allow_duplicates: bool,
         include_breaks: bool,
     ) -> PyExpr: ...
+    def qcut_bounds(
+        self,
+        probs: Sequence[float],
+        labels: Sequence[str] | None,
+        left_closed: bool,
+        allow_duplicates: bool,
+    ) -> PyExpr: ...
     def qcut_uniform(
         self,
         n_bins: int,
@@ -1248,6 +1366,31 @@ class PyExpr:
     ) -> PyExpr: ...
     def rle(self) -> PyExpr: ...
     def rle_id(self) -> PyExpr: ...
+    def to_dummies(
+        self,
+        categories: Sequence[str] | None,
+        separator: str | None,
+        drop_first: bool,
+    ) -> PyExpr: ...
+    def target_encode(
+        self, target: PyExpr, smoothing: float, leave_one_out: bool
+    ) -> PyExpr: ...
+    def freq_encode(self) -> PyExpr: ...
+    def zscore(self, ddof: int, return_params: bool) -> PyExpr: ...
+    def min_max_scale(self, min: float, max: float, return_params: bool) -> PyExpr: ...
+    def robust_scale(self, return_params: bool) -> PyExpr: ...
+    def fft(self) -> PyExpr: ...
+    def power_spectrum(self, window: int) -> PyExpr: ...
+    def convolve(
+        self,
+        kernel: Sequence[float],
+        mode: ConvolveMode,
+        null_behavior: ConvolveNullBehavior,
+    ) -> PyExpr: ...
+    def savgol(
+        self, window: int, polyorder: int, null_behavior: ConvolveNullBehavior
+    ) -> PyExpr: ...
+    def bucketize_numeric(self, k_anonymity: int) -> PyExpr: ...
     def agg_groups(self) -> PyExpr: ...
     def count(self) -> PyExpr: ...
     def len(self) -> PyExpr: ...
@@ -1363,6 +1506,12 @@ class PyExpr:
     def cum_prod(self, reverse: bool) -> PyExpr: ...
     def cum_count(self, reverse: bool) -> PyExpr: ...
     def cumulative_eval(self, expr: PyExpr, min_samples: int) -> PyExpr: ...
+    def scan_fn(
+        self, function: Any, return_dtype: PyDataTypeExpr | None
+    ) -> PyExpr: ...
+    def scan_fn_registered(
+        self, name: str, init: Any, return_dtype: PyDataTypeExpr | None
+    ) -> PyExpr: ...
     def product(self) -> PyExpr: ...
     def shrink_dtype(self) -> PyExpr: ...
     def dot(self, other: PyExpr) -> PyExpr: ...
@@ -1386,6 +1535,9 @@ class PyExpr:
     def sample_frac(
         self, frac: PyExpr, with_replacement: bool, shuffle: bool, seed: int | None
     ) -> PyExpr: ...
+    def dp_sum(self, epsilon: float, lower: float, upper: float) -> PyExpr: ...
+    def dp_count(self, epsilon: float) -> PyExpr: ...
+    def dp_mean(self, epsilon: float, lower: float, upper: float) -> PyExpr: ...
     def ewm_mean(
         self, alpha: float, adjust: bool, min_periods: int, ignore_nulls: bool
     ) -> PyExpr: ...
@@ -1415,6 +1567,9 @@ class PyExpr:
     def entropy(self, base: float, normalize: bool) -> PyExpr: ...
     def hash(self, seed: int, seed_1: int, seed_2: int, seed_3: int) -> PyExpr: ...
     def set_sorted_flag(self, descending: bool, nulls_last: bool | None) -> PyExpr: ...
+    def with_field_metadata(self, metadata: dict[str, str]) -> PyExpr: ...
+    def set_unit(self, unit: str) -> PyExpr: ...
+    def convert_unit(self, unit: str) -> PyExpr: ...
     def replace(self, old: PyExpr, new: PyExpr) -> PyExpr: ...
     def replace_strict(
         self,
@@ -1523,7 +1678,7 @@ class PyExpr:
     def dt_add_business_days(
         self, n: PyExpr, week_mask: Sequence[bool], holidays: PyExpr, roll: Roll
     ) -> PyExpr: ...
-    def dt_to_string(self, format: str) -> PyExpr: ...
+    def dt_to_string(self, format: str, locale: str | None = None) -> PyExpr: ...
     def dt_offset_by(self, by: PyExpr) -> PyExpr: ...
     def dt_with_time_unit(self, time_unit: TimeUnit) -> PyExpr: ...
     def dt_convert_time_zone(self, time_zone: str) -> PyExpr: ...
@@ -1585,6 +1740,12 @@ class PyExpr:
     def dt_total_microseconds(self, fractional: bool) -> PyExpr: ...
     def dt_total_nanoseconds(self, fractional: bool) -> PyExpr: ...
 
+    # interval
+    def interval_overlaps(self, other: PyExpr) -> PyExpr: ...
+    def interval_contains(self, other: PyExpr) -> PyExpr: ...
+    def interval_intersect(self, other: PyExpr) -> PyExpr: ...
+    def interval_union_merge(self) -> PyExpr: ...
+
     # list
     def list_all(self) -> PyExpr: ...
     def list_any(self) -> PyExpr: ...
@@ -1841,6 +2002,7 @@ class PyExpr:
         strict: bool = True,
         exact: bool = True,
         cache: bool = True,
+        locale: str | None = None,
     ) -> PyExpr: ...
     def str_to_datetime(
         self,
@@ -1851,13 +2013,16 @@ class PyExpr:
         exact: bool,
         cache: bool,
         ambiguous: PyExpr,
+        locale: str | None = None,
     ) -> PyExpr: ...
     def str_to_time(
         self,
         format: str | None = None,
         strict: bool = True,
         cache: bool = True,
+        locale: str | None = None,
     ) -> PyExpr: ...
+    def str_to_duration(self, time_unit: TimeUnit, strict: bool) -> PyExpr: ...
     def str_strip_chars(self, matches: PyExpr) -> PyExpr: ...
     def str_strip_chars_start(self, matches: PyExpr) -> PyExpr: ...
     def str_strip_chars_end(self, matches: PyExpr) -> PyExpr: ...
@@ -1868,6 +2033,10 @@ class PyExpr:
     def str_tail(self, n: PyExpr) -> PyExpr: ...
     def str_to_uppercase(self) -> PyExpr: ...
     def str_to_lowercase(self) -> PyExpr: ...
+    def str_mask_email(self) -> PyExpr: ...
+    def str_mask_digits(self, keep_last: int) -> PyExpr: ...
+    def str_collation_key(self, locale: str, strength: Any) -> PyExpr: ...
+    def str_natural_sort_key(self) -> PyExpr: ...
     def str_to_titlecase(self) -> PyExpr: ...
     def str_len_bytes(self) -> PyExpr: ...
     def str_len_chars(self) -> PyExpr: ...
@@ -1876,6 +2045,8 @@ class PyExpr:
     ) -> PyExpr: ...
     def str_replace_all(self, pat: PyExpr, val: PyExpr, literal: bool) -> PyExpr: ...
     def str_normalize(self, form: UnicodeForm) -> PyExpr: ...
+    def str_remove_diacritics(self) -> PyExpr: ...
+    def str_is_ascii(self) -> PyExpr: ...
     def str_reverse(self) -> PyExpr: ...
     def str_pad_start(self, length: PyExpr, fill_char: str) -> PyExpr: ...
     def str_pad_end(self, length: PyExpr, fill_char: str) -> PyExpr: ...
@@ -2012,6 +2183,8 @@ class PySelector:
     @staticmethod
     def matches(pattern: str) -> PySelector: ...
     @staticmethod
+    def by_metadata(key: str, value: str) -> PySelector: ...
+    @staticmethod
     def enum_() -> PySelector: ...
     @staticmethod
     def categorical() -> PySelector: ...
@@ -2124,14 +2297,28 @@ def arg_sort_by(
     multithreaded: bool,
     maintain_order: bool,
 ) -> PyExpr: ...
+def search_sorted_by(
+    by: Sequence[PyExpr],
+    search_values: Sequence[PyExpr],
+    side: Any,
+    descending: Sequence[bool],
+    nulls_last: Sequence[bool],
+) -> PyExpr: ...
 def arg_where(condition: PyExpr) -> PyExpr: ...
 def as_struct(exprs: Sequence[PyExpr]) -> PyExpr: ...
 def field(names: Sequence[str]) -> PyExpr: ...
+def param(name: str) -> PyExpr: ...
 def coalesce(exprs: Sequence[PyExpr]) -> PyExpr: ...
 def col(name: str) -> PyExpr: ...
 def element() -> PyExpr: ...
 def collect_all(
-    lfs: Sequence[PyLazyFrame], engine: Any, optflags: PyOptFlags
+    lfs: Sequence[PyLazyFrame],
+    engine: Any,
+    optflags: PyOptFlags,
+    priorities: Sequence[int] | None = None,
+    max_concurrent_queries: int | None = None,
+    memory_limit: int | None = None,
+    max_concurrent_requests: int | None = None,
 ) -> list[PyDataFrame]: ...
 def explain_all(lfs: Sequence[PyLazyFrame], optflags: PyOptFlags) -> str: ...
 def collect_all_lazy(
@@ -2228,6 +2415,7 @@ def max_horizontal(exprs: Sequence[PyExpr]) -> PyExpr: ...
 def min_horizontal(exprs: Sequence[PyExpr]) -> PyExpr: ...
 def sum_horizontal(exprs: Sequence[PyExpr], ignore_nulls: bool) -> PyExpr: ...
 def mean_horizontal(exprs: Sequence[PyExpr], ignore_nulls: bool) -> PyExpr: ...
+def first_non_null_horizontal(selector: PySelector) -> PyExpr: ...
 
 # functions.business
 def business_day_count(
@@ -2237,6 +2425,9 @@ def business_day_count(
     holidays: PyExpr,
 ) -> PyExpr: ...
 
+# functions.interval
+def interval(start: PyExpr, end: PyExpr) -> PyExpr: ...
+
 # functions.eager
 def concat_df(dfs: Any) -> PyDataFrame: ...
 def concat_series(series: Any) -> PySeries: ...
@@ -2248,6 +2439,9 @@ def read_ipc_schema(py_f: Any) -> dict[str, Any]: ...
 def read_parquet_metadata(
     py_f: Any, storage_options: Any, credential_provider: Any
 ) -> dict[str, str]: ...
+def read_parquet_statistics(
+    py_f: Any, storage_options: Any, credential_provider: Any
+) -> PyDataFrame: ...
 def read_clipboard_string() -> str: ...
 def write_clipboard_string(s: str) -> None: ...
 
@@ -2281,9 +2475,18 @@ def register_plugin_function(
 ) -> PyExpr: ...
 def __register_startup_deps() -> None: ...
 
+# functions.plan_cache
+def set_plan_cache_capacity(capacity: int) -> None: ...
+def plan_cache_stats() -> tuple[int, int, int, int]: ...
+def clear_plan_cache() -> None: ...
+
 # functions.random
 def set_random_seed(seed: int) -> None: ...
 
+# functions.privacy
+def set_privacy_budget(epsilon: float | None) -> None: ...
+def privacy_budget_remaining() -> float | None: ...
+
 # functions.range
 def int_range(
     start: PyExpr, end: PyExpr, step: int, dtype: PyDataTypeExpr
@@ -2365,6 +2568,7 @@ class PyChainedThen:
     def otherwise(self, statement: PyExpr) -> PyExpr: ...
 
 def when(condition: PyExpr) -> PyWhen: ...
+def when_lazy(condition: PyExpr) -> PyWhen: ...
 
 # functions: schema
 def init_polars_schema_from_arrow_c_schema(
@@ -2382,6 +2586,19 @@ class PyLazyGroupBy:
         self, lambda_function: Any, schema: Schema | None
     ) -> PyLazyFrame: ...
 
+class PyPartitionedFrame:
+    def __init__(
+        self, df: PyDataFrame, keys: Sequence[str], n_partitions: int | None
+    ) -> None: ...
+    def keys(self) -> list[str]: ...
+    def n_partitions(self) -> int: ...
+    def height(self) -> int: ...
+    def partitions(self) -> list[PyDataFrame]: ...
+    def to_df(self) -> PyDataFrame: ...
+    def lazy(self) -> PyLazyFrame: ...
+    def group_by_agg(self, aggs: Sequence[PyExpr]) -> PyLazyFrame: ...
+    def join(self, other: PyPartitionedFrame, how: JoinType) -> PyLazyFrame: ...
+
 # categorical
 class PyCategories:
     def __init__(self, name: str, namespace: str, physical: str) -> None: ...
@@ -2510,6 +2727,7 @@ def assert_schema_equal_py(
 def _get_dtype_max(dt: DataType) -> PyExpr: ...
 def _get_dtype_min(dt: DataType) -> PyExpr: ...
 def _known_timezones() -> list[str]: ...
+def _tzdb_version() -> str: ...
 
 # extension
 def _register_extension_type(name: str, cls: Any | None) -> None: ...
@@ -103,6 +103,10 @@ Ambiguous: TypeAlias = Literal["earliest", "latest", "raise", "null"]
 AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
+ConvolveMode: TypeAlias = Literal["full", "same", "valid"]
+CollationStrength: TypeAlias = Literal[
+    "primary", "secondary", "tertiary", "quaternary", "identical"
+]
 CsvCompression: TypeAlias = Literal["uncompressed", "gzip", "zstd"]
 CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
 ColumnMapping: TypeAlias = tuple[
@@ -114,8 +118,10 @@ ColumnMapping: TypeAlias = tuple[
 DefaultFieldValues: TypeAlias = tuple[
     Literal["iceberg"], dict[int, Union["Series", str]]
 ]
-DeletionFiles: TypeAlias = tuple[
-    Literal["iceberg-position-delete"], dict[int, list[str]]
+DeletionFiles: TypeAlias = Union[
+    tuple[Literal["iceberg-position-delete"], dict[int, list[str]]],
+    # Positions of deleted rows, e.g. a list, a Series or a roaring bitmap.
+    tuple[Literal["position-delete"], dict[int, Iterable[int]]],
 ]
 FillNullStrategy: TypeAlias = Literal[
     "forward", "backward", "min", "max", "mean", "zero", "one"
@@ -124,12 +130,15 @@ FloatFmt: TypeAlias = Literal["full", "mixed"]
 IndexOrder: TypeAlias = Literal["c", "fortran"]
 IpcCompression: TypeAlias = Literal["uncompressed", "lz4", "zstd"]
 JoinValidation: TypeAlias = Literal["m:m", "m:1", "1:m", "1:1"]
+JsonTemporalFormat: TypeAlias = Literal["iso", "epoch"]
 Label: TypeAlias = Literal["left", "right", "datapoint"]
 MaintainOrderJoin: TypeAlias = Literal[
     "none", "left", "right", "left_right", "right_left"
 ]
 NdjsonCompression: TypeAlias = Literal["uncompressed", "gzip", "zstd"]
+NearestMetric: TypeAlias = Literal["euclidean", "cosine"]
 NonExistent: TypeAlias = Literal["raise", "null"]
+NonFiniteFloats: TypeAlias = Literal["null", "string"]
 NullBehavior: TypeAlias = Literal["ignore", "drop"]
 ParallelStrategy: TypeAlias = Literal[
     "auto", "columns", "row_groups", "prefiltered", "none"
@@ -137,6 +146,7 @@ ParallelStrategy: TypeAlias = Literal[
 ParquetCompression: TypeAlias = Literal[
     "lz4", "uncompressed", "snappy", "gzip", "brotli", "zstd"
 ]
+ParquetTimestampOverflow: TypeAlias = Literal["raise", "saturate", "null"]
 PivotAgg: TypeAlias = Literal[
     "min", "max", "first", "last", "sum", "mean", "median", "len", "item"
 ]
@@ -181,6 +191,9 @@ MapElementsStrategy: TypeAlias = Literal["thread_local", "threading"]
 # The following have a Rust enum equivalent with a different name
 AsofJoinStrategy: TypeAlias = Literal["backward", "forward", "nearest"]  # AsofStrategy
 ClosedInterval: TypeAlias = Literal["left", "right", "both", "none"]  # ClosedWindow
+ConvolveNullBehavior: TypeAlias = Literal[
+    "propagate", "zero", "renormalize"
+]  # ConvolveNulls
 InterpolationMethod: TypeAlias = Literal["linear", "nearest"]
 JoinStrategy: TypeAlias = Literal[
     "inner", "left", "right", "full", "semi", "anti", "cross", "outer"
@@ -193,6 +206,7 @@ ConcatMethod = Literal[
     "vertical_relaxed",
     "diagonal",
     "diagonal_relaxed",
+    "diagonal_relaxed_by_name",
     "horizontal",
     "align",
     "align_full",
@@ -201,6 +215,7 @@ ConcatMethod = Literal[
     "align_right",
 ]
 CorrelationMethod: TypeAlias = Literal["pearson", "spearman"]
+DateGranularity: TypeAlias = Literal["year", "quarter", "month", "week", "day"]
 DbReadEngine: TypeAlias = Literal["adbc", "connectorx"]
 DbWriteEngine: TypeAlias = Literal["sqlalchemy", "adbc"]
 DbWriteMode: TypeAlias = Literal["replace", "append", "fail"]
@@ -360,6 +375,7 @@ __all__ = [
     "BufferInfo",
     "CategoricalOrdering",
     "ClosedInterval",
+    "CollationStrength",
     "ColumnFormatDict",
     "ColumnNameOrSelector",
     "ColumnTotalsDefinition",
@@ -368,10 +384,13 @@ __all__ = [
     "ConcatMethod",
     "ConditionalFormatDict",
     "ConnectionOrCursor",
+    "ConvolveMode",
+    "ConvolveNullBehavior",
     "CorrelationMethod",
     "CsvEncoding",
     "CsvQuoteStyle",
     "Cursor",
+    "DateGranularity",
     "DbReadEngine",
     "DbWriteEngine",
     "DbWriteMode",
@@ -395,6 +414,7 @@ __all__ = [
     "JaxExportType",
     "JoinStrategy",
     "JoinValidation",
+    "JsonTemporalFormat",
     "Label",
     "ListToStructWidthStrategy",
     "MaintainOrderJoin",
@@ -403,7 +423,9 @@ __all__ = [
     "MultiIndexSelector",
     "MultiNameSelector",
     "NdjsonCompression",
+    "NearestMetric",
     "NonExistent",
+    "NonFiniteFloats",
     "NonNestedLiteral",
     "NullBehavior",
     "NumericLiteral",
@@ -412,6 +434,7 @@ __all__ = [
     "ParallelStrategy",
     "ParametricProfileNames",
     "ParquetCompression",
+    "ParquetTimestampOverflow",
     "PivotAgg",
     "PolarsDataType",
     "PolarsIntegerType",
@@ -464,4 +487,24 @@ class ParquetMetadataContext:
 ParquetMetadataFn: TypeAlias = Callable[[ParquetMetadataContext], dict[str, str]]
 ParquetMetadata: TypeAlias = dict[str, str] | ParquetMetadataFn
 
+
+class ParquetColumnOptions(TypedDict, total=False):
+    """Options that override the file-level Parquet options for a column."""
+
+    compression: ParquetCompression
+    compression_level: int
+    dictionary: bool
+
+
+class CsvColumnOptions(TypedDict, total=False):
+    """Options that override the file-level CSV options for a column."""
+
+    quote_style: CsvQuoteStyle
+    float_scientific: bool
+    float_precision: int
+    date_format: str
+    time_format: str
+    datetime_format: str
+
+
 StorageOptionsDict: TypeAlias = dict[str, Any]
@@ -73,10 +73,21 @@ if sys.version_info >= (3, 11):
 
 
 def _process_null_values(
-    null_values: None | str | Sequence[str] | dict[str, str] = None,
-) -> None | str | Sequence[str] | list[tuple[str, str]]:
+    null_values: None
+    | str
+    | Sequence[str]
+    | dict[str, str]
+    | dict[str, Sequence[str]] = None,
+) -> (
+    None | str | Sequence[str] | list[tuple[str, str]] | list[tuple[str, Sequence[str]]]
+):
     if isinstance(null_values, dict):
-        return list(null_values.items())
+        if all(isinstance(v, str) for v in null_values.values()):
+            return list(null_values.items())
+        return [
+            (name, [v] if isinstance(v, str) else list(v))
+            for name, v in null_values.items()
+        ]
     else:
         return null_values
 
@@ -55,6 +55,7 @@ TableFormatNames: TypeAlias = Literal[
 # which 'POLARS_' environment variables are recognized, as there are other lower-level
 # and/or unstable settings that should not be saved or reset with the Config vars.
 _POLARS_CFG_ENV_VARS: Final[set[str]] = {
+    "POLARS_ADAPTIVE_MORSEL_SIZE",
     "POLARS_WARN_UNSTABLE",
     "POLARS_FMT_MAX_COLS",
     "POLARS_FMT_MAX_ROWS",
@@ -78,6 +79,8 @@ _POLARS_CFG_ENV_VARS: Final[set[str]] = {
     "POLARS_VERBOSE",
     "POLARS_MAX_EXPR_DEPTH",
     "POLARS_ENGINE_AFFINITY",
+    "POLARS_TZDB_VERSION",
+    "POLARS_JOIN_VALIDATION_MAX_REPORTED",
 }
 
 # vars that set the rust env directly should declare themselves here as the Config
@@ -98,6 +101,7 @@ with contextlib.suppress(ImportError, NameError):
 class ConfigParameters(TypedDict, total=False):
     """Parameters supported by the polars Config."""
 
+    adaptive_morsel_size: bool | None
     ascii_tables: bool | None
     auto_structify: bool | None
     decimal_separator: str | None
@@ -106,6 +110,7 @@ class ConfigParameters(TypedDict, total=False):
     fmt_float: FloatFmt | None
     fmt_str_lengths: int | None
     fmt_table_cell_list_len: int | None
+    join_validation_max_reported: int | None
     streaming_chunk_size: int | None
     tbl_cell_alignment: Alignment | None
     tbl_cell_numeric_alignment: Alignment | None
@@ -120,9 +125,11 @@ class ConfigParameters(TypedDict, total=False):
     tbl_rows: int | None
     tbl_width_chars: int | None
     trim_decimal_zeros: bool | None
+    tzdb_version: str | None
     verbose: bool | None
     expr_depth_warning: int
 
+    set_adaptive_morsel_size: bool | None
     set_ascii_tables: bool | None
     set_auto_structify: bool | None
     set_decimal_separator: str | None
@@ -131,6 +138,7 @@ class ConfigParameters(TypedDict, total=False):
     set_fmt_float: FloatFmt | None
     set_fmt_str_lengths: int | None
     set_fmt_table_cell_list_len: int | None
+    set_join_validation_max_reported: int | None
     set_streaming_chunk_size: int | None
     set_tbl_cell_alignment: Alignment | None
     set_tbl_cell_numeric_alignment: Alignment | None
@@ -145,6 +153,7 @@ class ConfigParameters(TypedDict, total=False):
     set_tbl_rows: int | None
     set_tbl_width_chars: int | None
     set_trim_decimal_zeros: bool | None
+    set_tzdb_version: str | None
     set_verbose: bool | None
     set_expr_depth_warning: int
     set_engine_affinity: EngineType | None
@@ -493,6 +502,34 @@ class Config(contextlib.ContextDecorator):
 
         return config_state
 
+    @classmethod
+    def set_adaptive_morsel_size(cls, active: bool | None = True) -> type[Config]:
+        """
+        Adapt the size of the morsels of the `streaming` engine while a query runs.
+
+        The morsels start at the size set with :meth:`set_streaming_chunk_size`.
+        They are grown while they are processed quickly, and shrunk when they are
+        processed slowly, hold many bytes (wide rows), or memory is running low.
+
+        Parameters
+        ----------
+        active
+            Adapt the morsel size. Set to `None` to restore the default (fixed size).
+
+        Examples
+        --------
+        Only adapt the morsel size for one query:
+
+        >>> with pl.Config(adaptive_morsel_size=True):  # doctest: +SKIP
+        ...     lf.collect(engine="streaming")
+        """
+        if active is None:
+            os.environ.pop("POLARS_ADAPTIVE_MORSEL_SIZE", None)
+        else:
+            os.environ["POLARS_ADAPTIVE_MORSEL_SIZE"] = str(int(active))
+        plr.config_reload_env_var("POLARS_ADAPTIVE_MORSEL_SIZE")
+        return cls
+
     @classmethod
     def set_ascii_tables(cls, active: bool | None = True) -> type[Config]:
         """
@@ -887,6 +924,37 @@ class Config(contextlib.ContextDecorator):
         plr.config_reload_env_var("POLARS_FMT_TABLE_CELL_LIST_LEN")
         return cls
 
+    @classmethod
+    def set_join_validation_max_reported(cls, n: int | None) -> type[Config]:
+        """
+        Set the number of violating keys listed when a join validation fails.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        n : int
+            Number of duplicate keys (with their number of occurrences) listed in
+            the error raised by a join with `validate`. Set to None to restore the
+            default (10).
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"k": [1, 1, 2, 2]})
+        >>> with pl.Config(join_validation_max_reported=1):
+        ...     df.join(df, on="k", validate="1:1")  # doctest: +SKIP
+        """
+        if n is None:
+            os.environ.pop("POLARS_JOIN_VALIDATION_MAX_REPORTED", None)
+        else:
+            if n < 0:
+                msg = "number of reported keys must be >= 0"
+                raise ValueError(msg)
+
+            os.environ["POLARS_JOIN_VALIDATION_MAX_REPORTED"] = str(n)
+        plr.config_reload_env_var("POLARS_JOIN_VALIDATION_MAX_REPORTED")
+        return cls
+
     @classmethod
     def set_streaming_chunk_size(cls, size: int | None) -> type[Config]:
         """
@@ -1437,6 +1505,37 @@ class Config(contextlib.ContextDecorator):
         plr.set_trim_decimal_zeros(active)
         return cls
 
+    @classmethod
+    def set_tzdb_version(cls, version: str | None) -> type[Config]:
+        """
+        Pin the version of the IANA time zone database bundled with Polars.
+
+        .. versionadded:: 1.40.0
+
+        Time zone rules change over time, so a different version of the database
+        can convert the same datetime differently. Once pinned, using a time zone
+        raises a `ComputeError` if the bundled database has another version,
+        instead of silently giving different results. The version can also be
+        pinned for the whole process with the `POLARS_TZDB_VERSION` environment
+        variable.
+
+        Parameters
+        ----------
+        version
+            Version of the time zone database, such as `"2025b"`, as returned by
+            :func:`tzdb_version`. Set to None to unpin the version.
+
+        Examples
+        --------
+        >>> pl.Config.set_tzdb_version(pl.tzdb_version())  # doctest: +SKIP
+        """
+        if version is None:
+            os.environ.pop("POLARS_TZDB_VERSION", None)
+        else:
+            os.environ["POLARS_TZDB_VERSION"] = version
+        plr.config_reload_env_var("POLARS_TZDB_VERSION")
+        return cls
+
     @classmethod
     def set_verbose(cls, active: bool | None = True) -> type[Config]:
         """
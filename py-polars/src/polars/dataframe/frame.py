@@ -84,6 +84,7 @@ from polars._utils.wrap import wrap_expr, wrap_ldf, wrap_s
 from polars.config import Config
 from polars.dataframe._html import NotebookFormatter
 from polars.dataframe.group_by import DynamicGroupBy, GroupBy, RollingGroupBy
+from polars.dataframe.partitioned import PartitionedFrame
 from polars.dataframe.plotting import DataFramePlot
 from polars.datatypes import (
     N_INFER_DEFAULT,
@@ -116,7 +117,7 @@ from polars.schema import Schema
 from polars.selectors import _expand_selector_dicts, _expand_selectors
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
-    from polars._plr import PyDataFrame
+    from polars._plr import PyDataFrame, PyPartitionedFrame
     from polars._plr import dtype_str_repr as _dtype_str_repr
     from polars._plr import write_clipboard_string as _write_clipboard_string
 
@@ -154,6 +155,7 @@ if TYPE_CHECKING:
         ComparisonOperator,
         ConditionalFormatDict,
         ConnectionOrCursor,
+        CsvColumnOptions,
         CsvQuoteStyle,
         DbWriteEngine,
         EngineType,
@@ -166,12 +168,16 @@ if TYPE_CHECKING:
         IpcCompression,
         JoinStrategy,
         JoinValidation,
+        JsonTemporalFormat,
         Label,
         MaintainOrderJoin,
+        NearestMetric,
         MultiColSelector,
         MultiIndexSelector,
+        NonFiniteFloats,
         OneOrMoreDataTypes,
         Orientation,
+        ParquetColumnOptions,
         ParquetCompression,
         ParquetMetadata,
         PivotAgg,
@@ -972,6 +978,35 @@ class DataFrame:
         """
         return {name: self[name].flags for name in self.columns}
 
+    @property
+    @unstable()
+    def field_metadata(self) -> dict[str, dict[str, str]]:
+        """
+        Get the key/value metadata of the columns that have any.
+
+        The metadata is set with :meth:`Expr.with_field_metadata` and is written to
+        and read from Parquet and IPC files.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Returns
+        -------
+        dict
+            Mapping from column names to column metadata.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2], "b": [3, 4]})
+        >>> df = df.with_columns(pl.col("a").with_field_metadata({"unit": "m"}))
+        >>> df.field_metadata
+        {'a': {'unit': 'm'}}
+        """
+        return self._df.field_metadata()
+
     @property
     def schema(self) -> Schema:
         """
@@ -2841,12 +2876,33 @@ class DataFrame:
         return serialize_polars_object(serializer, file, format)
 
     @overload
-    def write_json(self, file: None = ...) -> str: ...
+    def write_json(
+        self,
+        file: None = ...,
+        *,
+        temporal_format: JsonTemporalFormat = ...,
+        non_finite_floats: NonFiniteFloats = ...,
+        pretty: bool = ...,
+    ) -> str: ...
 
     @overload
-    def write_json(self, file: IOBase | str | Path) -> None: ...
+    def write_json(
+        self,
+        file: IOBase | str | Path,
+        *,
+        temporal_format: JsonTemporalFormat = ...,
+        non_finite_floats: NonFiniteFloats = ...,
+        pretty: bool = ...,
+    ) -> None: ...
 
-    def write_json(self, file: IOBase | str | Path | None = None) -> str | None:
+    def write_json(
+        self,
+        file: IOBase | str | Path | None = None,
+        *,
+        temporal_format: JsonTemporalFormat = "iso",
+        non_finite_floats: NonFiniteFloats = "null",
+        pretty: bool = False,
+    ) -> str | None:
         """
         Serialize to JSON representation.
 
@@ -2855,6 +2911,29 @@ class DataFrame:
         file
             File path or writable file-like object to which the result will be written.
             If set to `None` (default), the output is returned as a string instead.
+        temporal_format : {'iso', 'epoch'}
+            How to write temporal values.
+
+            - iso: write dates, times and datetimes as ISO 8601 strings and
+              durations as ISO 8601 duration strings.
+            - epoch: write the underlying integer, i.e. the days since the epoch
+              for dates and the number of time units (since the epoch, midnight or
+              in total) for datetimes, times and durations.
+
+            .. versionadded:: 1.40.0
+        non_finite_floats : {'null', 'string'}
+            How to write NaN and infinite floats, which have no JSON representation.
+
+            - null: write them as `null`.
+            - string: write them as the strings `"NaN"`, `"Infinity"` and
+              `"-Infinity"`.
+
+            .. versionadded:: 1.40.0
+        pretty
+            Indent the output by two spaces, with every value of an object or array on
+            its own line.
+
+            .. versionadded:: 1.40.0
 
         See Also
         --------
@@ -2870,11 +2949,22 @@ class DataFrame:
         ... )
         >>> df.write_json()
         '[{"foo":1,"bar":6},{"foo":2,"bar":7},{"foo":3,"bar":8}]'
+
+        Write datetimes as the number of time units since the epoch.
+
+        >>> from datetime import datetime
+        >>> pl.DataFrame({"dt": [datetime(2020, 1, 1)]}).write_json(
+        ...     temporal_format="epoch"
+        ... )
+        '[{"dt":1577836800000000}]'
         """
 
+        def write_json_to_target(target: IOBase | str | Path) -> None:
+            self._df.write_json(target, temporal_format, non_finite_floats, pretty)
+
         def write_json_to_string() -> str:
             with BytesIO() as buf:
-                self._df.write_json(buf)
+                write_json_to_target(buf)
                 json_bytes = buf.getvalue()
             return json_bytes.decode("utf8")
 
@@ -2886,10 +2976,10 @@ class DataFrame:
             return None
         elif isinstance(file, (str, Path)):
             file = normalize_filepath(file)
-            self._df.write_json(file)
+            write_json_to_target(file)
             return None
         else:
-            self._df.write_json(file)
+            write_json_to_target(file)
             return None
 
     @overload
@@ -2900,6 +2990,8 @@ class DataFrame:
         compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
+        temporal_format: JsonTemporalFormat = "iso",
+        non_finite_floats: NonFiniteFloats = "null",
     ) -> str: ...
 
     @overload
@@ -2910,6 +3002,8 @@ class DataFrame:
         compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
+        temporal_format: JsonTemporalFormat = "iso",
+        non_finite_floats: NonFiniteFloats = "null",
     ) -> None: ...
 
     def write_ndjson(
@@ -2919,6 +3013,8 @@ class DataFrame:
         compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
+        temporal_format: JsonTemporalFormat = "iso",
+        non_finite_floats: NonFiniteFloats = "null",
     ) -> str | None:
         r"""
         Serialize to newline delimited JSON representation.
@@ -2951,6 +3047,24 @@ class DataFrame:
             .. warning::
                 This functionality is considered **unstable**. It may be changed at any
                 point without it being considered a breaking change.
+        temporal_format : {'iso', 'epoch'}
+            How to write temporal values.
+
+            - iso: write dates, times and datetimes as ISO 8601 strings and
+              durations as ISO 8601 duration strings.
+            - epoch: write the underlying integer, i.e. the days since the epoch
+              for dates and the number of time units (since the epoch, midnight or
+              in total) for datetimes, times and durations.
+
+            .. versionadded:: 1.40.0
+        non_finite_floats : {'null', 'string'}
+            How to write NaN and infinite floats, which have no JSON representation.
+
+            - null: write them as `null`.
+            - string: write them as the strings `"NaN"`, `"Infinity"` and
+              `"-Infinity"`.
+
+            .. versionadded:: 1.40.0
 
         Examples
         --------
@@ -2982,6 +3096,8 @@ class DataFrame:
             compression=compression,
             compression_level=compression_level,
             check_extension=check_extension,
+            temporal_format=temporal_format,
+            non_finite_floats=non_finite_floats,
             optimizations=QueryOptFlags._eager(),
             engine=engine,
         )
@@ -3004,6 +3120,7 @@ class DataFrame:
         separator: str = ...,
         line_terminator: str = ...,
         quote_char: str = ...,
+        escape_char: str | None = ...,
         batch_size: int = ...,
         datetime_format: str | None = ...,
         date_format: str | None = ...,
@@ -3013,6 +3130,7 @@ class DataFrame:
         decimal_comma: bool = ...,
         null_value: str | None = ...,
         quote_style: CsvQuoteStyle | None = ...,
+        column_options: dict[str, CsvColumnOptions] | None = ...,
         storage_options: StorageOptionsDict | None = ...,
         credential_provider: CredentialProviderFunction | Literal["auto"] | None = ...,
         retries: int | None = ...,
@@ -3031,6 +3149,7 @@ class DataFrame:
         separator: str = ...,
         line_terminator: str = ...,
         quote_char: str = ...,
+        escape_char: str | None = ...,
         batch_size: int = ...,
         datetime_format: str | None = ...,
         date_format: str | None = ...,
@@ -3040,6 +3159,7 @@ class DataFrame:
         decimal_comma: bool = ...,
         null_value: str | None = ...,
         quote_style: CsvQuoteStyle | None = ...,
+        column_options: dict[str, CsvColumnOptions] | None = ...,
         storage_options: StorageOptionsDict | None = ...,
         credential_provider: CredentialProviderFunction | Literal["auto"] | None = ...,
         retries: int | None = ...,
@@ -3057,6 +3177,7 @@ class DataFrame:
         separator: str = ",",
         line_terminator: str = "\n",
         quote_char: str = '"',
+        escape_char: str | None = None,
         batch_size: int = 1024,
         datetime_format: str | None = None,
         date_format: str | None = None,
@@ -3066,6 +3187,7 @@ class DataFrame:
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        column_options: dict[str, CsvColumnOptions] | None = None,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: (
             CredentialProviderFunction | Literal["auto"] | None
@@ -3113,6 +3235,12 @@ class DataFrame:
             String used to end each row.
         quote_char
             Byte to use as quoting character.
+        escape_char
+            Byte to use to escape the quoting character and itself in quoted fields,
+            e.g. `"\\"` to write `"a\\"b"` instead of `"a""b"`. If `None`
+            (default), the quoting character is escaped by doubling it.
+
+            .. versionadded:: 1.40.0
         batch_size
             Number of rows that will be processed per thread.
         datetime_format
@@ -3157,6 +3285,17 @@ class DataFrame:
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        column_options
+            Options that override the file-level options for some columns, as a
+            dictionary mapping column names to dictionaries with the keys
+            "quote_style", "float_scientific", "float_precision", "date_format",
+            "time_format" and "datetime_format". These have the same meaning as the
+            parameters of the same name.
+
+            This allows e.g. writing prices with 2 decimals and measurements in
+            scientific notation, or quoting only the text columns.
+
+            .. versionadded:: 1.40.0
         storage_options
             Options that indicate how to connect to a cloud provider.
 
@@ -3230,6 +3369,7 @@ class DataFrame:
             separator=separator,
             line_terminator=line_terminator,
             quote_char=quote_char,
+            escape_char=escape_char,
             batch_size=batch_size,
             datetime_format=datetime_format,
             date_format=date_format,
@@ -3239,6 +3379,7 @@ class DataFrame:
             decimal_comma=decimal_comma,
             null_value=null_value,
             quote_style=quote_style,
+            column_options=column_options,
             storage_options=storage_options,
             credential_provider=credential_provider,
             retries=retries,
@@ -4114,6 +4255,7 @@ class DataFrame:
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        data_page_row_limit: int | None = None,
         use_pyarrow: bool = False,
         pyarrow_options: dict[str, Any] | None = None,
         partition_by: str | Sequence[str] | None = None,
@@ -4125,6 +4267,8 @@ class DataFrame:
         retries: int | None = None,
         metadata: ParquetMetadata | None = None,
         arrow_schema: ArrowSchemaExportable | None = None,
+        uuid_columns: Sequence[str] | None = None,
+        column_options: dict[str, ParquetColumnOptions] | None = None,
         mkdir: bool = False,
     ) -> None:
         """
@@ -4163,12 +4307,20 @@ class DataFrame:
 
               - "min": column minimum value (default: `True`)
               - "max": column maximum value (default: `True`)
-              - "distinct_count": number of unique column values (default: `False`)
+              - "distinct_count": number of unique non-null column values. This
+                is exact for dictionary-encoded columns and estimated with
+                HyperLogLog otherwise (default: `False`)
               - "null_count": number of null values in column (default: `True`)
         row_group_size
             Size of the row groups in number of rows. Defaults to 512^2 rows.
         data_page_size
             Size of the data page in bytes. Defaults to 1024^2 bytes.
+        data_page_row_limit
+            Maximum number of rows in a data page, on top of the size limit. Smaller
+            pages let readers that use the page index skip more rows, and readers need
+            less memory to decode a page. Not limited by default.
+
+            .. versionadded:: 1.40.0
         use_pyarrow
             Use C++ parquet implementation vs Rust parquet implementation.
             At the moment C++ supports more features.
@@ -4230,6 +4382,29 @@ class DataFrame:
             .. warning::
                 This functionality is considered **unstable**. It may be changed at any
                 point without it being considered a breaking change.
+        uuid_columns
+            String columns holding UUIDs in their canonical hyphenated form, e.g.
+            `"123e4567-e89b-12d3-a456-426614174000"`, to write as 16-byte values with
+            the Parquet UUID logical type. Other readers then recognize the values as
+            UUIDs. Use `uuid_as_string=True` in :func:`read_parquet` to read them back
+            as strings.
+
+            .. versionadded:: 1.40.0
+        column_options
+            Options that override the file-level options for some columns, as a
+            dictionary mapping column names to dictionaries with the keys:
+
+              - "compression": the compression of the column.
+              - "compression_level": the compression level of the column. Without
+                "compression", this is a level of the file-level compression.
+              - "dictionary": whether to use dictionary encoding for the column
+                (default: `True`). Disabling it avoids building a dictionary for
+                columns with mostly unique values.
+
+            This allows e.g. compressing string columns with zstd while writing float
+            columns uncompressed.
+
+            .. versionadded:: 1.40.0
         mkdir: bool
             Recursively create all the directories in the path.
 
@@ -4284,6 +4459,15 @@ class DataFrame:
             if mkdir:
                 msg = "write_parquet with `use_pyarrow=True` cannot be combined with `mkdir`"
                 raise ValueError(msg)
+            if uuid_columns:
+                msg = "write_parquet with `use_pyarrow=True` cannot be combined with `uuid_columns`"
+                raise ValueError(msg)
+            if data_page_row_limit is not None:
+                msg = "write_parquet with `use_pyarrow=True` cannot be combined with `data_page_row_limit`"
+                raise ValueError(msg)
+            if column_options:
+                msg = "write_parquet with `use_pyarrow=True` cannot be combined with `column_options`"
+                raise ValueError(msg)
 
             tbl = self.to_arrow()
             data = {}
@@ -4349,11 +4533,14 @@ class DataFrame:
             statistics=statistics,
             row_group_size=row_group_size,
             data_page_size=data_page_size,
+            data_page_row_limit=data_page_row_limit,
             storage_options=storage_options,
             credential_provider=credential_provider,
             retries=retries,
             metadata=metadata,
             arrow_schema=arrow_schema,
+            uuid_columns=uuid_columns,
+            column_options=column_options,
             engine=engine,
             mkdir=mkdir,
             optimizations=QueryOptFlags._eager(),
@@ -6021,6 +6208,8 @@ class DataFrame:
         nulls_last: bool | Sequence[bool] = False,
         multithreaded: bool = True,
         maintain_order: bool = False,
+        collation: str | None = None,
+        natural: bool | Sequence[bool] = False,
     ) -> DataFrame:
         """
         Sort the dataframe by the given columns.
@@ -6042,6 +6231,23 @@ class DataFrame:
             Sort using multiple threads.
         maintain_order
             Whether the order should be maintained if elements are equal.
+        collation
+            Sort strings with the locale-aware collation of this BCP 47 language
+            tag, such as `"de"` or `"sv"`, instead of byte-wise. All columns to sort
+            by must be of type String. See :meth:`Expr.str.collation_key`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        natural
+            Sort strings in natural order, comparing runs of digits by their numeric
+            value so that `"file2"` sorts before `"file10"`. Can be specified per
+            column by passing a sequence of booleans; the columns sorted naturally
+            must be of type String. See :meth:`Expr.str.natural_sort_key`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Examples
         --------
@@ -6119,6 +6325,8 @@ class DataFrame:
                 nulls_last=nulls_last,
                 multithreaded=multithreaded,
                 maintain_order=maintain_order,
+                collation=collation,
+                natural=natural,
             )
             .collect(optimizations=QueryOptFlags._eager())
         )
@@ -7847,6 +8055,8 @@ class DataFrame:
         coalesce: bool = True,
         allow_exact_matches: bool = True,
         check_sortedness: bool = True,
+        distance_column: str | None = None,
+        indicator_column: str | None = None,
     ) -> DataFrame:
         """
         Perform an asof join.
@@ -7944,6 +8154,18 @@ class DataFrame:
             Check the sortedness of the asof keys. If the keys are not sorted Polars
             will error. Currently, sortedness cannot be checked if 'by' groups are
             provided.
+        distance_column
+            Name of an output column holding the distance between the left key and
+            the matched right key (`left - right`), null if there was no match.
+            Only supported for numeric and temporal keys. The distance of integer
+            keys is an `Int64`; `UInt64` keys beyond its range raise an error.
+
+            .. versionadded:: 1.40.0
+        indicator_column
+            Name of an output boolean column indicating whether a match within the
+            `tolerance` was found.
+
+            .. versionadded:: 1.40.0
 
         Examples
         --------
@@ -8179,6 +8401,8 @@ class DataFrame:
                 coalesce=coalesce,
                 allow_exact_matches=allow_exact_matches,
                 check_sortedness=check_sortedness,
+                distance_column=distance_column,
+                indicator_column=indicator_column,
             )
             .collect(optimizations=QueryOptFlags._eager())
         )
@@ -8536,6 +8760,189 @@ class DataFrame:
             .collect(optimizations=QueryOptFlags._eager())
         )
 
+    @unstable()
+    def interval_join(
+        self,
+        other: DataFrame,
+        left_on: IntoExpr,
+        right_on: IntoExpr,
+        *,
+        suffix: str = "_right",
+    ) -> DataFrame:
+        """
+        Join the rows of which the intervals overlap.
+
+        Both `left_on` and `right_on` evaluate to intervals, see
+        :func:`polars.interval`. This performs an inner join, so a row from either
+        frame may be included multiple times in the result. Rows with a null interval
+        are never joined.
+
+        .. note::
+            The row order of the input frames is not preserved.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        other
+            DataFrame to join with.
+        left_on
+            The intervals of this frame. Accepts expression input. Strings are parsed
+            as column names.
+        right_on
+            The intervals of `other`. Accepts expression input. Strings are parsed as
+            column names.
+        suffix
+            Suffix to append to columns with a duplicate name.
+
+        See Also
+        --------
+        join_where
+
+        Examples
+        --------
+        >>> bookings = pl.DataFrame({"id": [1, 2], "start": [0, 10], "end": [5, 20]})
+        >>> events = pl.DataFrame(
+        ...     {"event": ["a", "b", "c"], "start": [4, 5, 15], "end": [6, 10, 25]}
+        ... )
+        >>> bookings.interval_join(
+        ...     events,
+        ...     pl.interval("start", "end"),
+        ...     pl.interval("start", "end"),
+        ... ).sort("id")
+        shape: (2, 6)
+        ┌─────┬───────┬─────┬───────┬─────────────┬───────────┐
+        │ id  ┆ start ┆ end ┆ event ┆ start_right ┆ end_right │
+        │ --- ┆ ---   ┆ --- ┆ ---   ┆ ---         ┆ ---       │
+        │ i64 ┆ i64   ┆ i64 ┆ str   ┆ i64         ┆ i64       │
+        ╞═════╪═══════╪═════╪═══════╪═════════════╪═══════════╡
+        │ 1   ┆ 0     ┆ 5   ┆ a     ┆ 4           ┆ 6         │
+        │ 2   ┆ 10    ┆ 20  ┆ c     ┆ 15          ┆ 25        │
+        └─────┴───────┴─────┴───────┴─────────────┴───────────┘
+        """
+        require_same_type(self, other)
+
+        from polars.lazyframe.opt_flags import QueryOptFlags
+
+        return (
+            self.lazy()
+            .interval_join(other.lazy(), left_on, right_on, suffix=suffix)
+            .collect(optimizations=QueryOptFlags._eager())
+        )
+
+    @unstable()
+    def join_nearest(
+        self,
+        other: DataFrame,
+        on: str | Sequence[str] | None = None,
+        *,
+        left_on: str | Sequence[str] | None = None,
+        right_on: str | Sequence[str] | None = None,
+        k: int = 1,
+        metric: NearestMetric = "euclidean",
+        max_distance: float | None = None,
+        suffix: str = "_right",
+        distance_column: str = "distance",
+    ) -> DataFrame:
+        """
+        Attach the `k` nearest rows of another DataFrame to every row.
+
+        Every row is a vector with the values of the join columns as coordinates. A
+        KD-tree is built on the rows of `other` and queried for all rows of this
+        DataFrame in parallel.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        other
+            DataFrame to join with.
+        on
+            Name(s) of the numeric vector columns in both DataFrames.
+        left_on
+            Name(s) of the vector columns in this DataFrame.
+        right_on
+            Name(s) of the vector columns in `other`.
+        k
+            The maximum number of rows of `other` to attach to every row.
+        metric : {'euclidean', 'cosine'}
+            The distance between two vectors.
+
+            * *euclidean*: the euclidean distance.
+            * *cosine*: one minus the cosine similarity.
+        max_distance
+            Rows of `other` further away than this are never matched. Rows without
+            any match are dropped from the result.
+        suffix
+            Suffix to append to columns with a duplicate name.
+        distance_column
+            Name of the column holding the distance of every match. Must not be the
+            name of a column in either DataFrame.
+
+        Returns
+        -------
+        DataFrame
+            One row per match, ordered by the rows of this DataFrame and then by
+            increasing distance.
+
+        See Also
+        --------
+        join_asof
+
+        Examples
+        --------
+        >>> points = pl.DataFrame({"x": [0.0, 5.0], "y": [0.0, 5.0]})
+        >>> stations = pl.DataFrame(
+        ...     {"name": ["a", "b", "c"], "x": [1.0, 4.0, 9.0], "y": [0.0, 5.0, 9.0]}
+        ... )
+        >>> points.join_nearest(stations, on=["x", "y"])
+        shape: (2, 6)
+        ┌─────┬─────┬──────┬─────────┬─────────┬──────────┐
+        │ x   ┆ y   ┆ name ┆ x_right ┆ y_right ┆ distance │
+        │ --- ┆ --- ┆ ---  ┆ ---     ┆ ---     ┆ ---      │
+        │ f64 ┆ f64 ┆ str  ┆ f64     ┆ f64     ┆ f64      │
+        ╞═════╪═════╪══════╪═════════╪═════════╪══════════╡
+        │ 0.0 ┆ 0.0 ┆ a    ┆ 1.0     ┆ 0.0     ┆ 1.0      │
+        │ 5.0 ┆ 5.0 ┆ b    ┆ 4.0     ┆ 5.0     ┆ 1.0      │
+        └─────┴─────┴──────┴─────────┴─────────┴──────────┘
+        """
+        require_same_type(self, other)
+
+        if on is not None:
+            if left_on is not None or right_on is not None:
+                msg = "cannot use 'on' in conjunction with 'left_on' or 'right_on'"
+                raise ValueError(msg)
+            left_on = right_on = on
+        elif left_on is None or right_on is None:
+            msg = "either 'on' or both 'left_on' and 'right_on' should be passed"
+            raise ValueError(msg)
+
+        if isinstance(left_on, str):
+            left_on = [left_on]
+        if isinstance(right_on, str):
+            right_on = [right_on]
+
+        return self._from_pydf(
+            self._df.join_nearest(
+                other._df,
+                list(left_on),
+                list(right_on),
+                k,
+                metric,
+                max_distance,
+                suffix,
+                distance_column,
+            )
+        )
+
     def map_rows(
         self,
         function: Callable[[tuple[Any, ...]], Any],
@@ -10061,6 +10468,64 @@ class DataFrame:
 
         return partitions
 
+    @unstable()
+    def partition_by_hash(
+        self,
+        by: str | Sequence[str],
+        *more_by: str,
+        n_partitions: int | None = None,
+    ) -> PartitionedFrame:
+        """
+        Keep the rows partitioned by the hash of the given columns.
+
+        All the rows with the same keys are put in the same partition, so that a
+        group by on the keys, or a join on the keys with a frame that is partitioned in
+        the same way, is done per partition and in parallel, without shuffling the rows
+        again. The keys are hashed with a fixed seed, so two frames with the same
+        number of partitions and keys of the same data types are partitioned in the
+        same way.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        by
+            Name(s) of the key columns.
+        *more_by
+            Additional names of key columns, specified as positional arguments.
+        n_partitions
+            The number of partitions. Defaults to the number of threads.
+
+        See Also
+        --------
+        partition_by
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["x", "y", "x", "z"], "b": [1, 2, 3, 4]})
+        >>> pf = df.partition_by_hash("a", n_partitions=2)
+        >>> pf.n_partitions
+        2
+        >>> pf.group_by_agg(pl.col("b").sum()).sort("a").collect()
+        shape: (3, 2)
+        ┌─────┬─────┐
+        │ a   ┆ b   │
+        │ --- ┆ --- │
+        │ str ┆ i64 │
+        ╞═════╪═════╡
+        │ x   ┆ 4   │
+        │ y   ┆ 2   │
+        │ z   ┆ 4   │
+        └─────┴─────┘
+        """
+        keys = [by] if isinstance(by, str) else list(by)
+        keys.extend(more_by)
+        return PartitionedFrame(PyPartitionedFrame(self._df, keys, n_partitions))
+
     def shift(self, n: int = 1, *, fill_value: IntoExpr | None = None) -> DataFrame:
         """
         Shift values by the given number of indices.
@@ -11459,6 +11924,87 @@ class DataFrame:
 
         return self._from_pydf(self._df.sample_n(n._s, with_replacement, shuffle, seed))
 
+    @unstable()
+    def split(
+        self,
+        fractions: Sequence[float],
+        *,
+        shuffle: bool = False,
+        seed: int | None = None,
+        stratify_by: str | Sequence[str] | None = None,
+    ) -> list[DataFrame]:
+        """
+        Split the rows of this DataFrame into parts, e.g. for a train/test split.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        fractions
+            The fraction of the rows in every part. Must be positive and sum to 1.
+            Rounding remainders go to the last part.
+        shuffle
+            Randomly assign the rows to the parts. If set to False (default), every
+            part is a contiguous slice of the rows and the row order is maintained.
+        seed
+            Seed for the random number generator. If set to None (default), a
+            random seed is generated for each split.
+        stratify_by
+            Column(s) whose distinct values are split separately, so that every part
+            holds the same fraction of each of them.
+
+        Returns
+        -------
+        list of DataFrame
+            One DataFrame per fraction.
+
+        See Also
+        --------
+        sample
+        LazyFrame.time_series_folds
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": range(5), "label": ["x", "x", "x", "y", "y"]})
+        >>> train, test = df.split([0.6, 0.4], stratify_by="label")
+        >>> train
+        shape: (3, 2)
+        ┌─────┬───────┐
+        │ a   ┆ label │
+        │ --- ┆ ---   │
+        │ i64 ┆ str   │
+        ╞═════╪═══════╡
+        │ 0   ┆ x     │
+        │ 1   ┆ x     │
+        │ 3   ┆ y     │
+        └─────┴───────┘
+        >>> test
+        shape: (2, 2)
+        ┌─────┬───────┐
+        │ a   ┆ label │
+        │ --- ┆ ---   │
+        │ i64 ┆ str   │
+        ╞═════╪═══════╡
+        │ 2   ┆ x     │
+        │ 4   ┆ y     │
+        └─────┴───────┘
+        """
+        if seed is None:
+            seed = random.randint(0, 10000)
+        if isinstance(stratify_by, str):
+            stratify_by = [stratify_by]
+        elif stratify_by is not None:
+            stratify_by = list(stratify_by)
+
+        return [
+            self._from_pydf(df)
+            for df in self._df.split(list(fractions), shuffle, seed, stratify_by)
+        ]
+
     def fold(self, operation: Callable[[Series, Series], Series]) -> Series:
         """
         Apply a horizontal reduction on a DataFrame.
@@ -12127,6 +12673,91 @@ class DataFrame:
             df._df.shrink_to_fit()
             return df
 
+    @unstable()
+    def create_index(
+        self,
+        column: str,
+        *,
+        kind: Literal["sorted", "zone_map"] = "sorted",
+        block_size: int = 4096,
+    ) -> DataFrame:
+        """
+        Build an index on a column, in place.
+
+        The indexes of a DataFrame speed up filters that compare an indexed column
+        with a literal, and, on the in-memory engine, asof joins on an indexed column
+        of the right DataFrame. This pays off when the same DataFrame is queried
+        repeatedly.
+
+        An index belongs to the data of its column, so it is shared with the
+        DataFrames created from this one that hold the same column, such as a
+        selection of columns. An index is dropped when its column is replaced or
+        modified.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        column
+            Name of the column to index.
+        kind : {'sorted', 'zone_map'}
+            The kind of index.
+
+            * 'sorted': A sorted permutation of the rows. Finds the rows within a
+              range of values with a binary search, and gives an asof join the rows
+              in sorted order without sorting them.
+            * 'zone_map': The minimum and maximum value of every block of
+              `block_size` rows. Skips the blocks that cannot contain a range of
+              values, which works best if the column is (nearly) sorted.
+        block_size
+            The number of rows per block of a zone map.
+
+        See Also
+        --------
+        drop_indexes
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [3, 1, 4, 2], "b": ["w", "x", "y", "z"]})
+        >>> df = df.create_index("a")
+        >>> df.lazy().filter(pl.col("a") >= 3).collect()
+        shape: (2, 2)
+        ┌─────┬─────┐
+        │ a   ┆ b   │
+        │ --- ┆ --- │
+        │ i64 ┆ str │
+        ╞═════╪═════╡
+        │ 3   ┆ w   │
+        │ 4   ┆ y   │
+        └─────┴─────┘
+        """
+        self._df.create_index(column, kind, block_size)
+        return self
+
+    @unstable()
+    def drop_indexes(self) -> DataFrame:
+        """
+        Drop all the indexes on the columns, in place.
+
+        This also drops the indexes for the other DataFrames that share these columns.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        See Also
+        --------
+        create_index
+        """
+        self._df.drop_indexes()
+        return self
+
     def gather_every(self, n: int, offset: int = 0) -> DataFrame:
         """
         Take every nth row in the DataFrame and return as a new DataFrame.
@@ -12434,6 +13065,237 @@ class DataFrame:
             df.insert_column(0, cols)
         return df
 
+    @unstable()
+    def pca(self, k: int) -> tuple[DataFrame, DataFrame]:
+        """
+        Principal component analysis over the columns of this DataFrame.
+
+        The columns are centered and decomposed with a randomized SVD, without
+        converting the data to numpy.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        k
+            The number of components. Must not exceed the number of rows or columns.
+
+        Returns
+        -------
+        tuple of (DataFrame, DataFrame)
+            The loadings, with one row per component holding its name, the
+            (ratio of) explained variance and the weight of every column, and the
+            data projected on the components.
+
+        Notes
+        -----
+        All columns must be numeric and may not contain null values. Select a subset
+        of the columns first to run the analysis on only those.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4, 5], "b": [2, 4, 6, 8, 10]})
+        >>> loadings, transformed = df.pca(1)
+        >>> loadings
+        shape: (1, 5)
+        ┌───────────┬────────────────────┬──────────────────────────┬──────────┬──────────┐
+        │ component ┆ explained_variance ┆ explained_variance_ratio ┆ a        ┆ b        │
+        │ ---       ┆ ---                ┆ ---                      ┆ ---      ┆ ---      │
+        │ str       ┆ f64                ┆ f64                      ┆ f64      ┆ f64      │
+        ╞═══════════╪════════════════════╪══════════════════════════╪══════════╪══════════╡
+        │ pc1       ┆ 12.5               ┆ 1.0                      ┆ 0.447214 ┆ 0.894427 │
+        └───────────┴────────────────────┴──────────────────────────┴──────────┴──────────┘
+        >>> transformed
+        shape: (5, 1)
+        ┌───────────┐
+        │ pc1       │
+        │ ---       │
+        │ f64       │
+        ╞═══════════╡
+        │ -4.472136 │
+        │ -2.236068 │
+        │ 0.0       │
+        │ 2.236068  │
+        │ 4.472136  │
+        └───────────┘
+        """
+        loadings, transformed = self._df.pca(k)
+        return self._from_pydf(loadings), self._from_pydf(transformed)
+
+    @unstable()
+    def kmeans(
+        self,
+        columns: str | Sequence[str],
+        k: int,
+        *,
+        max_iter: int = 300,
+        seed: int | None = None,
+    ) -> Series:
+        """
+        Assign every row to one of `k` clusters with k-means.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        columns
+            The numeric column(s) holding the coordinates of every row. They may not
+            contain null values.
+        k
+            The number of clusters.
+        max_iter
+            The maximum number of iterations. The iterations stop earlier once the
+            cluster assignment no longer changes.
+        seed
+            Seed for the k-means++ initialization of the centroids. If set to None
+            (default), a random seed is drawn from the global random number
+            generator, see :func:`set_random_seed`.
+
+        Returns
+        -------
+        Series
+            The cluster label of every row, named `cluster`.
+
+        See Also
+        --------
+        dbscan
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"x": [0.0, 0.1, 5.0, 5.1], "y": [0.0, 0.2, 5.0, 4.9]})
+        >>> labels = df.kmeans(["x", "y"], 2, seed=0)
+        >>> labels[0] == labels[1], labels[1] == labels[2]
+        (True, False)
+        """
+        if isinstance(columns, str):
+            columns = [columns]
+        return wrap_s(self._df.kmeans(list(columns), k, max_iter, seed))
+
+    @unstable()
+    def dbscan(
+        self, columns: str | Sequence[str], eps: float, *, min_pts: int = 5
+    ) -> Series:
+        """
+        Cluster the rows with DBSCAN.
+
+        Rows with at least `min_pts` rows (including themselves) within euclidean
+        distance `eps` are core points. A cluster consists of core points that reach
+        each other and all rows within `eps` of them. The remaining rows are noise.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        columns
+            The numeric column(s) holding the coordinates of every row. They may not
+            contain null values.
+        eps
+            The maximum distance between two neighbouring rows.
+        min_pts
+            The minimum number of neighbours of a core point.
+
+        Returns
+        -------
+        Series
+            The cluster label of every row, named `cluster`. Noise is labelled null.
+
+        Notes
+        -----
+        The neighbours are found by brute force, which is quadratic in the number of
+        rows.
+
+        See Also
+        --------
+        kmeans
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"x": [0.0, 0.5, 1.0, 10.0, 10.5, 50.0]})
+        >>> df.with_columns(df.dbscan("x", 0.6, min_pts=2))
+        shape: (6, 2)
+        ┌──────┬─────────┐
+        │ x    ┆ cluster │
+        │ ---  ┆ ---     │
+        │ f64  ┆ u32     │
+        ╞══════╪═════════╡
+        │ 0.0  ┆ 0       │
+        │ 0.5  ┆ 0       │
+        │ 1.0  ┆ 0       │
+        │ 10.0 ┆ 1       │
+        │ 10.5 ┆ 1       │
+        │ 50.0 ┆ null    │
+        └──────┴─────────┘
+        """
+        if isinstance(columns, str):
+            columns = [columns]
+        return wrap_s(self._df.dbscan(list(columns), eps, min_pts))
+
+    @unstable()
+    def cdc(self, other: DataFrame, on: str | Sequence[str]) -> DataFrame:
+        """
+        Get the row-level changes from this version of a frame to a newer version.
+
+        Rows are matched on the `on` columns with a single full outer join, after
+        which the remaining columns of the matched rows are compared. The result can
+        be used to build change feeds, such as slowly changing dimensions or deltas.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        other
+            The new version of the frame. It must have the same columns and data types
+            as this frame.
+        on
+            Name(s) of the key column(s). The keys must be unique in both frames.
+
+        Returns
+        -------
+        DataFrame
+            The changed rows, with an additional `__change_type` column:
+
+            - `"insert"`: the row from `other` whose key is not in this frame.
+            - `"delete"`: the row from this frame whose key is not in `other`.
+            - `"update_pre"` / `"update_post"`: the row from this frame and the row
+              from `other` for a key whose values differ, directly after each other.
+
+        Examples
+        --------
+        >>> old = pl.DataFrame({"id": [1, 2, 3], "value": ["a", "b", "c"]})
+        >>> new = pl.DataFrame({"id": [2, 3, 4], "value": ["b", "x", "d"]})
+        >>> old.cdc(new, on="id")
+        shape: (4, 3)
+        ┌─────┬───────┬───────────────┐
+        │ id  ┆ value ┆ __change_type │
+        │ --- ┆ ---   ┆ ---           │
+        │ i64 ┆ str   ┆ str           │
+        ╞═════╪═══════╪═══════════════╡
+        │ 1   ┆ a     ┆ delete        │
+        │ 3   ┆ c     ┆ update_pre    │
+        │ 3   ┆ x     ┆ update_post   │
+        │ 4   ┆ d     ┆ insert        │
+        └─────┴───────┴───────────────┘
+        """
+        if isinstance(on, str):
+            on = [on]
+        return self._from_pydf(self._df.cdc(other._df, list(on)))
+
     def merge_sorted(self, other: DataFrame, key: str) -> DataFrame:
         """
         Take two sorted DataFrames and merge them by the sorted key.
@@ -0,0 +1,115 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars import functions as F
+from polars._utils.parse import parse_into_list_of_expressions
+from polars._utils.wrap import wrap_df, wrap_ldf
+
+if TYPE_CHECKING:
+    from collections.abc import Callable, Iterable
+
+    from polars import DataFrame, LazyFrame
+    from polars._plr import PyPartitionedFrame
+    from polars._typing import IntoExpr, JoinStrategy
+
+
+class PartitionedFrame:
+    """
+    A DataFrame that is kept partitioned by the hash of its key columns.
+
+    Generated by calling `df.partition_by_hash(...)`.
+
+    All the rows with the same keys are in the same partition, so a group by on the
+    keys, or a join on the keys with a frame that is partitioned in the same way, is
+    done per partition and in parallel, without shuffling the rows again.
+
+    The LazyFrame of a partitioned frame carries its partitioning, so the optimizer
+    only reads the partition selected by an equality filter on the keys, and runs
+    group bys on the keys and joins with a frame partitioned in the same way per
+    partition.
+    """
+
+    def __init__(self, pf: PyPartitionedFrame) -> None:
+        self._pf = pf
+
+    @property
+    def keys(self) -> list[str]:
+        """The names of the key columns."""
+        return self._pf.keys()
+
+    @property
+    def n_partitions(self) -> int:
+        """The number of partitions."""
+        return self._pf.n_partitions()
+
+    @property
+    def height(self) -> int:
+        """The number of rows of all the partitions."""
+        return self._pf.height()
+
+    def partitions(self) -> list[DataFrame]:
+        """Get the partitions as separate DataFrames."""
+        return [wrap_df(df) for df in self._pf.partitions()]
+
+    def to_frame(self) -> DataFrame:
+        """Concatenate the partitions into a single DataFrame, ordered by partition."""
+        return wrap_df(self._pf.to_df())
+
+    def lazy(self) -> LazyFrame:
+        """Get a LazyFrame of the union of the partitions."""
+        return wrap_ldf(self._pf.lazy())
+
+    def map_partitions(self, function: Callable[[LazyFrame], LazyFrame]) -> LazyFrame:
+        """
+        Apply a function to the LazyFrame of every partition.
+
+        As the partitions are processed separately, `function` must only combine rows
+        with the same keys, like a group by on (a superset of) the keys does. The
+        result is not partitioned, as `function` may change the keys.
+
+        Parameters
+        ----------
+        function
+            Function that takes the LazyFrame of a partition and returns a LazyFrame.
+        """
+        return F.concat([function(df.lazy()) for df in self.partitions()])
+
+    def group_by_agg(
+        self,
+        *aggs: IntoExpr | Iterable[IntoExpr],
+        **named_aggs: IntoExpr,
+    ) -> LazyFrame:
+        """
+        Group by the keys and aggregate, per partition.
+
+        Parameters
+        ----------
+        *aggs
+            Aggregations to compute for each group of the group by operation,
+            specified as positional arguments.
+            Accepts expression input. Strings are parsed as column names.
+        **named_aggs
+            Additional aggregations, specified as keyword arguments.
+            The resulting columns will be renamed to the keyword used.
+        """
+        pyexprs = parse_into_list_of_expressions(*aggs, **named_aggs)
+        return wrap_ldf(self._pf.group_by_agg(pyexprs))
+
+    def join(
+        self, other: PartitionedFrame, *, how: JoinStrategy = "inner"
+    ) -> LazyFrame:
+        """
+        Join with another PartitionedFrame on the keys of both frames, per partition.
+
+        The frames must have the same number of partitions and keys of the same data
+        types. The key columns are coalesced.
+
+        Parameters
+        ----------
+        other
+            PartitionedFrame to join with.
+        how : {'inner', 'left', 'right', 'full', 'semi', 'anti'}
+            Join strategy, see :meth:`DataFrame.join`.
+        """
+        return wrap_ldf(self._pf.join(other._pf, how))
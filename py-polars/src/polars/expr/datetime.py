@@ -21,6 +21,7 @@ if TYPE_CHECKING:
     from polars import Expr
     from polars._typing import (
         Ambiguous,
+        DateGranularity,
         EpochTimeUnit,
         IntoExpr,
         IntoExprColumn,
@@ -295,6 +296,61 @@ class ExprDateTimeNameSpace:
         every_pyexpr = parse_into_expression(every, str_as_lit=True)
         return wrap_expr(self._pyexpr.dt_truncate(every_pyexpr))
 
+    @unstable()
+    def generalize_date(self, granularity: DateGranularity) -> Expr:
+        """
+        Generalize dates/datetimes to the start of a coarser period.
+
+        This reduces the precision of the values, for example to anonymize them.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        granularity : {'year', 'quarter', 'month', 'week', 'day'}
+            The period the values are generalized to. Weeks start on Monday.
+
+        See Also
+        --------
+        truncate
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame({"birth": [date(1985, 5, 17), date(1990, 11, 3)]})
+        >>> df.with_columns(
+        ...     year=pl.col("birth").dt.generalize_date("year"),
+        ...     quarter=pl.col("birth").dt.generalize_date("quarter"),
+        ... )
+        shape: (2, 3)
+        ┌────────────┬────────────┬────────────┐
+        │ birth      ┆ year       ┆ quarter    │
+        │ ---        ┆ ---        ┆ ---        │
+        │ date       ┆ date       ┆ date       │
+        ╞════════════╪════════════╪════════════╡
+        │ 1985-05-17 ┆ 1985-01-01 ┆ 1985-04-01 │
+        │ 1990-11-03 ┆ 1990-01-01 ┆ 1990-10-01 │
+        └────────────┴────────────┴────────────┘
+        """
+        every = {
+            "year": "1y",
+            "quarter": "1q",
+            "month": "1mo",
+            "week": "1w",
+            "day": "1d",
+        }.get(granularity)
+        if every is None:
+            msg = (
+                "`granularity` must be one of {'year', 'quarter', 'month', 'week',"
+                f" 'day'}}, got {granularity!r}"
+            )
+            raise ValueError(msg)
+        return self.truncate(every)
+
     def round(self, every: str | dt.timedelta | IntoExprColumn) -> Expr:
         """
         Divide the date/datetime range into buckets.
@@ -558,10 +614,15 @@ class ExprDateTimeNameSpace:
         time_pyexpr = parse_into_expression(time)
         return wrap_expr(self._pyexpr.dt_combine(time_pyexpr, time_unit))
 
-    def to_string(self, format: str | None = None) -> Expr:
+    def to_string(
+        self, format: str | None = None, *, locale: str | None = None
+    ) -> Expr:
         """
         Convert a Date/Time/Datetime column into a String column with the given format.
 
+        .. versionchanged:: 1.40.0
+            Added support for "human" and strftime-like format strings for the
+            `Duration` dtype.
         .. versionchanged:: 1.15.0
             Added support for the use of "iso:strict" as a format string.
         .. versionchanged:: 1.14.0
@@ -577,6 +638,11 @@ class ExprDateTimeNameSpace:
             * If no format is provided, the appropriate ISO format for the underlying
               data type is used. This can be made explicit by passing `"iso"` or
               `"iso:strict"` as the format string (see notes below for details).
+        locale
+            Locale used to write the month and weekday names, such as `"fr_FR"` or
+            `"de_DE"`. If set to None (default), the names are written in English.
+
+            .. versionadded:: 1.40.0
 
         Notes
         -----
@@ -591,10 +657,14 @@ class ExprDateTimeNameSpace:
           the same components separated with a "T"). All other temporal types
           return the same value for both format strings.
 
-        * Duration dtype expressions cannot be formatted with `strftime`. Instead,
-          only "iso" and "polars" are supported as format strings. The "iso" format
-          string results in ISO8601 duration string output, and "polars" results
-          in the same form seen in the frame `repr`.
+        * Duration dtype expressions support "iso", "polars" and "human" as format
+          strings. The "iso" format string results in ISO8601 duration string
+          output, "polars" results in the same form seen in the frame `repr`, and
+          "human" results in a readable form with a single leading sign, such as
+          "-2d 3h 4m 5.25s". Durations also support a subset of the `strftime`
+          specifiers: `%d`, `%H`, `%M`, `%S`, `%f`, `%.f`, `%.3f`, `%.6f`, `%.9f`
+          and `%%`. The largest unit in the format holds the total of that unit,
+          so `"%H:%M:%S"` formats 26 hours as "26:00:00".
 
         Examples
         --------
@@ -658,7 +728,7 @@ class ExprDateTimeNameSpace:
         │ 2040-12-30 16:40:50.000000 ┆ 2040-12-30T16:40:50.000000 │
         └────────────────────────────┴────────────────────────────┘
 
-        All temporal types (aside from `Duration`) support strftime formatting:
+        All temporal types support strftime formatting:
 
         >>> df.select(
         ...     pl.col("dtm"),
@@ -692,6 +762,25 @@ class ExprDateTimeNameSpace:
         │ 0µs           ┆ 0µs           │
         └───────────────┴───────────────┘
 
+        Durations can also be formatted in a human-readable form, or with the
+        subset of strftime specifiers that apply to durations:
+
+        >>> df.select(
+        ...     pl.col("td"),
+        ...     s_human=pl.col("td").dt.to_string("human"),
+        ...     s_clock=pl.col("td").dt.to_string("%H:%M:%S%.3f"),
+        ... )
+        shape: (3, 3)
+        ┌───────────────┬─────────────────┬───────────────┐
+        │ td            ┆ s_human         ┆ s_clock       │
+        │ ---           ┆ ---             ┆ ---           │
+        │ duration[μs]  ┆ str             ┆ str           │
+        ╞═══════════════╪═════════════════╪═══════════════╡
+        │ -1d -42s      ┆ -1d 42s         ┆ -24:00:42.000 │
+        │ 13d 14h 100µs ┆ 13d 14h 0.0001s ┆ 326:00:00.000 │
+        │ 0µs           ┆ 0s              ┆ 00:00:00.000  │
+        └───────────────┴─────────────────┴───────────────┘
+
         If you're interested in extracting the day or month names, you can use
         the `'%A'` and `'%B'` strftime specifiers:
 
@@ -710,12 +799,29 @@ class ExprDateTimeNameSpace:
         │ 2020-05-03 ┆ Wednesday ┆ October    │
         │ 2077-07-05 ┆ Sunday    ┆ December   │
         └────────────┴───────────┴────────────┘
+
+        Month and weekday names can be written in the language of a locale:
+
+        >>> df.select(
+        ...     pl.col("dtm"),
+        ...     s_dtm=pl.col("dtm").dt.to_string("%A %-d %B %Y", locale="de_DE"),
+        ... )
+        shape: (3, 2)
+        ┌─────────────────────┬──────────────────────────┐
+        │ dtm                 ┆ s_dtm                    │
+        │ ---                 ┆ ---                      │
+        │ datetime[μs]        ┆ str                      │
+        ╞═════════════════════╪══════════════════════════╡
+        │ 1980-08-10 00:10:20 ┆ Sonntag 10 August 1980   │
+        │ 2010-10-20 08:25:35 ┆ Mittwoch 20 Oktober 2010 │
+        │ 2040-12-30 16:40:50 ┆ Sonntag 30 Dezember 2040 │
+        └─────────────────────┴──────────────────────────┘
         """
         if format is None:
             format = "iso"
-        return wrap_expr(self._pyexpr.dt_to_string(format))
+        return wrap_expr(self._pyexpr.dt_to_string(format, locale))
 
-    def strftime(self, format: str) -> Expr:
+    def strftime(self, format: str, *, locale: str | None = None) -> Expr:
         """
         Convert a Date/Time/Datetime column into a String column with the given format.
 
@@ -730,6 +836,11 @@ class ExprDateTimeNameSpace:
             Format to use, refer to the `chrono strftime documentation
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for specification. Example: `"%y-%m-%d"`.
+        locale
+            Locale used to write the month and weekday names, such as `"fr_FR"` or
+            `"de_DE"`. If set to None (default), the names are written in English.
+
+            .. versionadded:: 1.40.0
 
         See Also
         --------
@@ -781,7 +892,7 @@ class ExprDateTimeNameSpace:
         │ 2020-05-01 00:00:00 ┆ Friday    ┆ May        │
         └─────────────────────┴───────────┴────────────┘
         """
-        return self.to_string(format)
+        return self.to_string(format, locale=locale)
 
     def millennium(self) -> Expr:
         """
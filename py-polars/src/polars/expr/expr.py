@@ -51,6 +51,7 @@ from polars.datatypes import (
 from polars.datatypes import (
     Int64,
     parse_into_datatype_expr,
+    parse_into_dtype,
 )
 from polars.exceptions import (
     CustomUFuncWarning,
@@ -62,6 +63,7 @@ from polars.expr.binary import ExprBinaryNameSpace
 from polars.expr.categorical import ExprCatNameSpace
 from polars.expr.datetime import ExprDateTimeNameSpace
 from polars.expr.ext import ExprExtensionNameSpace
+from polars.expr.interval import ExprIntervalNameSpace
 from polars.expr.list import ExprListNameSpace
 from polars.expr.meta import ExprMetaNameSpace
 from polars.expr.name import ExprNameNameSpace
@@ -91,6 +93,8 @@ if TYPE_CHECKING:
     from polars import DataFrame, LazyFrame, Series
     from polars._typing import (
         ClosedInterval,
+        ConvolveMode,
+        ConvolveNullBehavior,
         FillNullStrategy,
         InterpolationMethod,
         IntoExpr,
@@ -143,6 +147,7 @@ class Expr:
         "cat",
         "dt",
         "ext",
+        "interval",
         "list",
         "meta",
         "name",
@@ -189,6 +194,15 @@ class Expr:
         """Create an object namespace of all datetime related methods."""
         return ExprDateTimeNameSpace(self)
 
+    @property
+    def interval(self) -> ExprIntervalNameSpace:
+        """
+        Create an object namespace of all interval related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprIntervalNameSpace(self)
+
     @property
     def list(self) -> ExprListNameSpace:
         """
@@ -2111,7 +2125,14 @@ class Expr:
             self._pyexpr.cast(dtype._pydatatype_expr, strict, wrap_numerical)
         )
 
-    def sort(self, *, descending: bool = False, nulls_last: bool = False) -> Expr:
+    def sort(
+        self,
+        *,
+        descending: bool = False,
+        nulls_last: bool = False,
+        collation: str | None = None,
+        natural: bool = False,
+    ) -> Expr:
         """
         Sort this column.
 
@@ -2124,6 +2145,22 @@ class Expr:
             Sort in descending order.
         nulls_last
             Place null values last.
+        collation
+            Sort strings with the locale-aware collation of this BCP 47 language
+            tag, such as `"de"` or `"sv"`, instead of byte-wise. The column must
+            be of type String. See :meth:`Expr.str.collation_key`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        natural
+            Sort strings in natural order, comparing runs of digits by their numeric
+            value so that `"file2"` sorts before `"file10"`. The column must be of
+            type String. See :meth:`Expr.str.natural_sort_key`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Examples
         --------
@@ -2188,6 +2225,21 @@ class Expr:
         │ one   ┆ [1, 2, 98] │
         └───────┴────────────┘
         """
+        if collation is not None and natural:
+            msg = "cannot combine `collation` with `natural` sorting"
+            raise ValueError(msg)
+        if collation is not None:
+            return self.sort_by(
+                self.str.collation_key(collation),
+                descending=descending,
+                nulls_last=nulls_last,
+            )
+        if natural:
+            return self.sort_by(
+                self.str.natural_sort_key(),
+                descending=descending,
+                nulls_last=nulls_last,
+            )
         return wrap_expr(self._pyexpr.sort_with(descending, nulls_last))
 
     def top_k(self, k: int | IntoExprColumn = 5) -> Expr:
@@ -4315,6 +4367,269 @@ class Expr:
         """
         return wrap_expr(self._pyexpr.is_duplicated())
 
+    @unstable()
+    def fft(self) -> Expr:
+        """
+        Compute the discrete Fourier transform of the whole column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with fields `re` and `im`, holding
+            the real and imaginary part of every frequency bin.
+
+        Notes
+        -----
+        The column may not contain null values. Inputs of any length are supported
+        in `O(n log n)`.
+
+        See Also
+        --------
+        power_spectrum
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"signal": [1.0, 0.0, -1.0, 0.0]})
+        >>> df.select(pl.col("signal").fft()).unnest("signal")
+        shape: (4, 2)
+        ┌─────┬─────┐
+        │ re  ┆ im  │
+        │ --- ┆ --- │
+        │ f64 ┆ f64 │
+        ╞═════╪═════╡
+        │ 0.0 ┆ 0.0 │
+        │ 2.0 ┆ 0.0 │
+        │ 0.0 ┆ 0.0 │
+        │ 2.0 ┆ 0.0 │
+        └─────┴─────┘
+        """
+        return wrap_expr(self._pyexpr.fft())
+
+    @unstable()
+    def power_spectrum(self, window: int) -> Expr:
+        """
+        Compute the power spectrum of the trailing `window` values at every row.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        window
+            The number of values in every window.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`List` of :class:`Float64`, holding the
+            `window // 2 + 1` one-sided periodogram bins `|X_k|^2 / window` of the
+            window ending at every row. Rows without a full window, or with a null
+            value in it, are null.
+
+        See Also
+        --------
+        fft
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"signal": [1.0, -1.0, 1.0, -1.0]})
+        >>> df.with_columns(spectrum=pl.col("signal").power_spectrum(2))
+        shape: (4, 2)
+        ┌────────┬────────────┐
+        │ signal ┆ spectrum   │
+        │ ---    ┆ ---        │
+        │ f64    ┆ list[f64]  │
+        ╞════════╪════════════╡
+        │ 1.0    ┆ null       │
+        │ -1.0   ┆ [0.0, 2.0] │
+        │ 1.0    ┆ [0.0, 2.0] │
+        │ -1.0   ┆ [0.0, 2.0] │
+        └────────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.power_spectrum(window))
+
+    @unstable()
+    def convolve(
+        self,
+        kernel: Sequence[float],
+        mode: ConvolveMode = "same",
+        *,
+        null_behavior: ConvolveNullBehavior = "propagate",
+    ) -> Expr:
+        """
+        Convolve the values with a kernel.
+
+        As in the mathematical definition the kernel is flipped, so that
+        `out[k] = sum(x[k - j] * kernel[j])`. Positions outside of the column count
+        as zero.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        kernel
+            The weights to convolve with.
+        mode : {'same', 'full', 'valid'}
+            The part of the full convolution to return.
+
+            * *same*: the center of the full convolution, with the same length as the
+              column.
+            * *full*: every position where the kernel overlaps the column, `n + m - 1`
+              values.
+            * *valid*: only the positions where the kernel fully overlaps the column,
+              `n - m + 1` values.
+        null_behavior : {'propagate', 'zero', 'renormalize'}
+            How null values are handled.
+
+            * *propagate*: an output value is null if any of the values it is
+              computed from is null.
+            * *zero*: null values count as zero.
+            * *renormalize*: null values are skipped and the remaining weights are
+              rescaled to the sum of all weights. An output value is null only if all
+              values it is computed from are null.
+
+        See Also
+        --------
+        savgol
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1.0, 2.0, None, 4.0, 5.0]})
+        >>> df.with_columns(
+        ...     propagate=pl.col("a").convolve([1 / 3, 1 / 3, 1 / 3]),
+        ...     renormalize=pl.col("a").convolve(
+        ...         [1 / 3, 1 / 3, 1 / 3], null_behavior="renormalize"
+        ...     ),
+        ... )
+        shape: (5, 3)
+        ┌──────┬───────────┬─────────────┐
+        │ a    ┆ propagate ┆ renormalize │
+        │ ---  ┆ ---       ┆ ---         │
+        │ f64  ┆ f64       ┆ f64         │
+        ╞══════╪═══════════╪═════════════╡
+        │ 1.0  ┆ 1.0       ┆ 1.0         │
+        │ 2.0  ┆ null      ┆ 1.5         │
+        │ null ┆ null      ┆ 3.0         │
+        │ 4.0  ┆ null      ┆ 4.5         │
+        │ 5.0  ┆ 3.0       ┆ 3.0         │
+        └──────┴───────────┴─────────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.convolve([float(w) for w in kernel], mode, null_behavior)
+        )
+
+    @unstable()
+    def savgol(
+        self,
+        window: int,
+        polyorder: int,
+        *,
+        null_behavior: ConvolveNullBehavior = "propagate",
+    ) -> Expr:
+        """
+        Smooth the values with a Savitzky-Golay filter.
+
+        Every value is replaced by the value of a polynomial of degree `polyorder`
+        fitted with least squares to the `window` values centered on it. At the
+        edges, the polynomial fitted to the first or last full window is used.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        window
+            The number of values every polynomial is fitted to. Must be odd. If the
+            column is shorter than this, the result is null.
+        polyorder
+            The degree of the polynomial. Must be less than `window`.
+        null_behavior : {'propagate', 'zero', 'renormalize'}
+            How null values are handled, see :meth:`convolve`.
+
+        See Also
+        --------
+        convolve
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [2.0, 1.0, 2.0, 5.0, 10.0, 17.0, 26.0]})
+        >>> df.with_columns(smooth=pl.col("a").savgol(5, 2).round(6))
+        shape: (7, 2)
+        ┌──────┬────────┐
+        │ a    ┆ smooth │
+        │ ---  ┆ ---    │
+        │ f64  ┆ f64    │
+        ╞══════╪════════╡
+        │ 2.0  ┆ 2.0    │
+        │ 1.0  ┆ 1.0    │
+        │ 2.0  ┆ 2.0    │
+        │ 5.0  ┆ 5.0    │
+        │ 10.0 ┆ 10.0   │
+        │ 17.0 ┆ 17.0   │
+        │ 26.0 ┆ 26.0   │
+        └──────┴────────┘
+        """
+        return wrap_expr(self._pyexpr.savgol(window, polyorder, null_behavior))
+
+    @unstable()
+    def bucketize_numeric(self, k_anonymity: int) -> Expr:
+        """
+        Generalize numeric values into ranges that each contain at least `k` values.
+
+        The values are sorted and split into consecutive buckets of at least
+        `k_anonymity` values, so that every range is shared by at least that many
+        rows. Equal values are always put in the same bucket, and a remainder of
+        fewer than `k_anonymity` values is added to the last bucket. Every value is
+        replaced by a struct with the `lower` and `upper` bound of its bucket.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        k_anonymity
+            The minimum number of non-null values in every bucket.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"age": [23, 35, 31, 52, 25, 40, 35]})
+        >>> df.with_columns(range=pl.col("age").bucketize_numeric(3))
+        shape: (7, 2)
+        ┌─────┬───────────┐
+        │ age ┆ range     │
+        │ --- ┆ ---       │
+        │ i64 ┆ struct[2] │
+        ╞═════╪═══════════╡
+        │ 23  ┆ {23,31}   │
+        │ 35  ┆ {35,52}   │
+        │ 31  ┆ {23,31}   │
+        │ 52  ┆ {35,52}   │
+        │ 25  ┆ {23,31}   │
+        │ 40  ┆ {35,52}   │
+        │ 35  ┆ {35,52}   │
+        └─────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.bucketize_numeric(k_anonymity))
+
     def peak_max(self) -> Expr:
         """
         Get a boolean mask of the local maximum peaks.
@@ -4448,6 +4763,7 @@ class Expr:
         labels: Sequence[str_] | None = None,
         left_closed: bool = False,
         include_breaks: bool = False,
+        include_bounds: bool = False,
     ) -> Expr:
         """
         Bin continuous values into discrete categories.
@@ -4469,12 +4785,19 @@ class Expr:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        include_bounds
+            Return a :class:`Struct` with the `category` and the `lower` and `upper`
+            bounds of the bin each observation falls in. Cannot be combined with
+            `include_breaks`.
+
+            .. versionadded:: 1.40.0
 
         Returns
         -------
         Expr
-            Expression of data type :class:`Categorical` if `include_breaks` is set to
-            `False` (default), otherwise an expression of data type :class:`Struct`.
+            Expression of data type :class:`Categorical` if `include_breaks` and
+            `include_bounds` are set to `False` (default), otherwise an expression of
+            data type :class:`Struct`.
 
         See Also
         --------
@@ -4519,6 +4842,11 @@ class Expr:
         │ 2   ┆ inf        ┆ (1, inf]   │
         └─────┴────────────┴────────────┘
         """
+        if include_bounds:
+            if include_breaks:
+                msg = "`include_breaks` and `include_bounds` cannot both be set"
+                raise ValueError(msg)
+            return wrap_expr(self._pyexpr.cut_bounds(breaks, labels, left_closed))
         return wrap_expr(self._pyexpr.cut(breaks, labels, left_closed, include_breaks))
 
     @unstable()
@@ -4530,6 +4858,7 @@ class Expr:
         left_closed: bool = False,
         allow_duplicates: bool = False,
         include_breaks: bool = False,
+        include_bounds: bool = False,
     ) -> Expr:
         """
         Bin continuous values into discrete categories based on their quantiles.
@@ -4556,12 +4885,24 @@ class Expr:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        include_bounds
+            Return a :class:`Struct` with the `category` and the `lower` and `upper`
+            bounds of the bin each observation falls in. Cannot be combined with
+            `include_breaks`.
+
+            .. versionadded:: 1.40.0
 
         Returns
         -------
         Expr
-            Expression of data type :class:`Categorical` if `include_breaks` is set to
-            `False` (default), otherwise an expression of data type :class:`Struct`.
+            Expression of data type :class:`Categorical` if `include_breaks` and
+            `include_bounds` are set to `False` (default), otherwise an expression of
+            data type :class:`Struct`.
+
+        Notes
+        -----
+        Inside a group-by or window context (e.g. `over`), the quantiles are computed
+        per group.
 
         See Also
         --------
@@ -4627,7 +4968,16 @@ class Expr:
         │ 2   ┆ inf        ┆ (1, inf]   │
         └─────┴────────────┴────────────┘
         """
-        if isinstance(quantiles, int):
+        if include_bounds:
+            if include_breaks:
+                msg = "`include_breaks` and `include_bounds` cannot both be set"
+                raise ValueError(msg)
+            if isinstance(quantiles, int):
+                quantiles = [i / quantiles for i in range(1, quantiles)]
+            pyexpr = self._pyexpr.qcut_bounds(
+                quantiles, labels, left_closed, allow_duplicates
+            )
+        elif isinstance(quantiles, int):
             pyexpr = self._pyexpr.qcut_uniform(
                 quantiles, labels, left_closed, allow_duplicates, include_breaks
             )
@@ -4642,86 +4992,447 @@ class Expr:
         """
         Compress the column data using run-length encoding.
 
-        Run-length encoding (RLE) encodes data by storing each *run* of identical values
-        as a single value and its length.
+        Run-length encoding (RLE) encodes data by storing each *run* of identical values
+        as a single value and its length.
+
+        Returns
+        -------
+        Expr
+            Expression of data type `Struct` with fields `len` of data type `UInt32`
+            and `value` of the original data type.
+
+        See Also
+        --------
+        rle_id
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 1, 2, 1, None, 1, 3, 3]})
+        >>> df.select(pl.col("a").rle()).unnest("a")
+        shape: (6, 2)
+        ┌─────┬───────┐
+        │ len ┆ value │
+        │ --- ┆ ---   │
+        │ u32 ┆ i64   │
+        ╞═════╪═══════╡
+        │ 2   ┆ 1     │
+        │ 1   ┆ 2     │
+        │ 1   ┆ 1     │
+        │ 1   ┆ null  │
+        │ 1   ┆ 1     │
+        │ 2   ┆ 3     │
+        └─────┴───────┘
+        """
+        return wrap_expr(self._pyexpr.rle())
+
+    def rle_id(self) -> Expr:
+        """
+        Get a distinct integer ID for each run of identical values.
+
+        The ID starts at 0 and increases by one each time the value of the column
+        changes.
+
+        Returns
+        -------
+        Expr
+            Expression of data type `UInt32`.
+
+        See Also
+        --------
+        rle
+
+        Notes
+        -----
+        This functionality is especially useful for defining a new group for every time
+        a column's value changes, rather than for every distinct value of that column.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [1, 2, 1, 1, 1],
+        ...         "b": ["x", "x", None, "y", "y"],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     rle_id_a=pl.col("a").rle_id(),
+        ...     rle_id_ab=pl.struct("a", "b").rle_id(),
+        ... )
+        shape: (5, 4)
+        ┌─────┬──────┬──────────┬───────────┐
+        │ a   ┆ b    ┆ rle_id_a ┆ rle_id_ab │
+        │ --- ┆ ---  ┆ ---      ┆ ---       │
+        │ i64 ┆ str  ┆ u32      ┆ u32       │
+        ╞═════╪══════╪══════════╪═══════════╡
+        │ 1   ┆ x    ┆ 0        ┆ 0         │
+        │ 2   ┆ x    ┆ 1        ┆ 1         │
+        │ 1   ┆ null ┆ 2        ┆ 2         │
+        │ 1   ┆ y    ┆ 2        ┆ 3         │
+        │ 1   ┆ y    ┆ 2        ┆ 3         │
+        └─────┴──────┴──────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.rle_id())
+
+    @unstable()
+    def to_dummies(
+        self,
+        categories: Sequence[str_] | None = None,
+        *,
+        separator: str_ = "_",
+        drop_first: bool = False,
+    ) -> Expr:
+        """
+        Convert the column into dummy/indicator variables.
+
+        The result is a :class:`Struct` with one field per category, named
+        `{name}{separator}{category}`. Use :meth:`Expr.struct.unnest` to turn the
+        fields into columns.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        categories
+            The categories to encode. This determines the fields of the output, so
+            that its schema is known before the query runs. If not given, the column
+            must be of data type :class:`Enum` and its categories are used.
+        separator
+            Separator/delimiter used when generating the field names.
+        drop_first
+            Remove the first category from the variable being encoded.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct`. Null values and values that are
+            not one of the categories are represented by zero vectors.
+
+        See Also
+        --------
+        DataFrame.to_dummies
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["x", "y", None, "x"]})
+        >>> df.select(pl.col("a").to_dummies(["x", "y"])).unnest("a")
+        shape: (4, 2)
+        ┌─────┬─────┐
+        │ a_x ┆ a_y │
+        │ --- ┆ --- │
+        │ u8  ┆ u8  │
+        ╞═════╪═════╡
+        │ 1   ┆ 0   │
+        │ 0   ┆ 1   │
+        │ 0   ┆ 0   │
+        │ 1   ┆ 0   │
+        └─────┴─────┘
+
+        The categories of an :class:`Enum` are used if none are given.
+
+        >>> df = pl.DataFrame(
+        ...     {"a": ["low", "high"]}, schema={"a": pl.Enum(["low", "mid", "high"])}
+        ... )
+        >>> df.select(pl.col("a").to_dummies(drop_first=True)).unnest("a")
+        shape: (2, 2)
+        ┌───────┬────────┐
+        │ a_mid ┆ a_high │
+        │ ---   ┆ ---    │
+        │ u8    ┆ u8     │
+        ╞═══════╪════════╡
+        │ 0     ┆ 0      │
+        │ 0     ┆ 1      │
+        └───────┴────────┘
+        """
+        if categories is not None:
+            categories = list(categories)
+        return wrap_expr(self._pyexpr.to_dummies(categories, separator, drop_first))
+
+    @unstable()
+    def target_encode(
+        self,
+        target: IntoExpr,
+        *,
+        smoothing: float = 0.0,
+        leave_one_out: bool = False,
+    ) -> Expr:
+        """
+        Encode every category by the mean of a target over the rows of that category.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        target
+            The target to average. Accepts expression input. Strings are parsed as
+            column names. Null values are ignored.
+        smoothing
+            Shrink the mean of every category towards the mean of the target over all
+            rows, as if `smoothing` rows with that mean were added to every category.
+            Must be non-negative and finite.
+        leave_one_out
+            Exclude the target value of each row from its own encoding, including
+            from the mean over all rows that it falls back to. This avoids leaking
+            the target into the feature when encoding training data.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float64`. Rows whose encoding would be
+            based on no target values get the mean of the target over all rows.
+
+        See Also
+        --------
+        freq_encode
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "city": ["a", "a", "a", "b", "b"],
+        ...         "price": [1.0, 2.0, 3.0, 10.0, 20.0],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     mean=pl.col("city").target_encode("price"),
+        ...     loo=pl.col("city").target_encode("price", leave_one_out=True),
+        ... )
+        shape: (5, 4)
+        ┌──────┬───────┬──────┬──────┐
+        │ city ┆ price ┆ mean ┆ loo  │
+        │ ---  ┆ ---   ┆ ---  ┆ ---  │
+        │ str  ┆ f64   ┆ f64  ┆ f64  │
+        ╞══════╪═══════╪══════╪══════╡
+        │ a    ┆ 1.0   ┆ 2.0  ┆ 2.5  │
+        │ a    ┆ 2.0   ┆ 2.0  ┆ 2.0  │
+        │ a    ┆ 3.0   ┆ 2.0  ┆ 1.5  │
+        │ b    ┆ 10.0  ┆ 15.0 ┆ 20.0 │
+        │ b    ┆ 20.0  ┆ 15.0 ┆ 10.0 │
+        └──────┴───────┴──────┴──────┘
+        """
+        if not (math.isfinite(smoothing) and smoothing >= 0):
+            msg = f"`smoothing` must be non-negative and finite, got {smoothing}"
+            raise ValueError(msg)
+        target_pyexpr = parse_into_expression(target)
+        return wrap_expr(
+            self._pyexpr.target_encode(target_pyexpr, smoothing, leave_one_out)
+        )
+
+    @unstable()
+    def freq_encode(self) -> Expr:
+        """
+        Encode every category by the fraction of rows that belong to it.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float64`.
+
+        See Also
+        --------
+        target_encode
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"city": ["a", "a", "b", "a"]})
+        >>> df.with_columns(freq=pl.col("city").freq_encode())
+        shape: (4, 2)
+        ┌──────┬──────┐
+        │ city ┆ freq │
+        │ ---  ┆ ---  │
+        │ str  ┆ f64  │
+        ╞══════╪══════╡
+        │ a    ┆ 0.75 │
+        │ a    ┆ 0.75 │
+        │ b    ┆ 0.25 │
+        │ a    ┆ 0.75 │
+        └──────┴──────┘
+        """
+        return wrap_expr(self._pyexpr.freq_encode())
+
+    @unstable()
+    def zscore(self, *, ddof: int = 1, return_params: bool = False) -> Expr:
+        """
+        Standardize the values to a mean of zero and a standard deviation of one.
+
+        Use :meth:`over` to fit the mean and standard deviation per group.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        ddof
+            "Delta Degrees of Freedom" of the standard deviation.
+        return_params
+            Return the fitted parameters as a :class:`Struct` with the fields `mean`
+            and `std` instead, so that they can be reused on new data.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float64`. Values with a standard deviation
+            of zero are only centered.
+
+        See Also
+        --------
+        min_max_scale
+        robust_scale
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"g": ["a", "a", "b", "b"], "x": [1, 3, 10, 20]})
+        >>> df.with_columns(z=pl.col("x").zscore().over("g"))
+        shape: (4, 3)
+        ┌─────┬─────┬───────────┐
+        │ g   ┆ x   ┆ z         │
+        │ --- ┆ --- ┆ ---       │
+        │ str ┆ i64 ┆ f64       │
+        ╞═════╪═════╪═══════════╡
+        │ a   ┆ 1   ┆ -0.707107 │
+        │ a   ┆ 3   ┆ 0.707107  │
+        │ b   ┆ 10  ┆ -0.707107 │
+        │ b   ┆ 20  ┆ 0.707107  │
+        └─────┴─────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.zscore(ddof, return_params))
+
+    @unstable()
+    def min_max_scale(
+        self,
+        feature_range: tuple[float, float] = (0.0, 1.0),
+        *,
+        return_params: bool = False,
+    ) -> Expr:
+        """
+        Scale the values linearly so that they span `feature_range`.
+
+        Use :meth:`over` to fit the minimum and maximum per group.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        feature_range
+            The values the minimum and the maximum are mapped to.
+        return_params
+            Return the fitted parameters as a :class:`Struct` with the fields `min`
+            and `max` instead, so that they can be reused on new data.
 
         Returns
         -------
         Expr
-            Expression of data type `Struct` with fields `len` of data type `UInt32`
-            and `value` of the original data type.
+            Expression of data type :class:`Float64`. If all values are equal they
+            are mapped to the lower end of `feature_range`.
 
         See Also
         --------
-        rle_id
+        zscore
+        robust_scale
 
         Examples
         --------
-        >>> df = pl.DataFrame({"a": [1, 1, 2, 1, None, 1, 3, 3]})
-        >>> df.select(pl.col("a").rle()).unnest("a")
-        shape: (6, 2)
-        ┌─────┬───────┐
-        │ len ┆ value │
-        │ --- ┆ ---   │
-        │ u32 ┆ i64   │
-        ╞═════╪═══════╡
-        │ 2   ┆ 1     │
-        │ 1   ┆ 2     │
-        │ 1   ┆ 1     │
-        │ 1   ┆ null  │
-        │ 1   ┆ 1     │
-        │ 2   ┆ 3     │
-        └─────┴───────┘
+        >>> df = pl.DataFrame({"x": [1, 3, 5]})
+        >>> df.with_columns(
+        ...     scaled=pl.col("x").min_max_scale(),
+        ...     symmetric=pl.col("x").min_max_scale((-1, 1)),
+        ... )
+        shape: (3, 3)
+        ┌─────┬────────┬───────────┐
+        │ x   ┆ scaled ┆ symmetric │
+        │ --- ┆ ---    ┆ ---       │
+        │ i64 ┆ f64    ┆ f64       │
+        ╞═════╪════════╪═══════════╡
+        │ 1   ┆ 0.0    ┆ -1.0      │
+        │ 3   ┆ 0.5    ┆ 0.0       │
+        │ 5   ┆ 1.0    ┆ 1.0       │
+        └─────┴────────┴───────────┘
+
+        The fitted parameters can be returned to scale new data the same way.
+
+        >>> df.select(pl.col("x").min_max_scale(return_params=True)).unnest("x")
+        shape: (1, 2)
+        ┌─────┬─────┐
+        │ min ┆ max │
+        │ --- ┆ --- │
+        │ f64 ┆ f64 │
+        ╞═════╪═════╡
+        │ 1.0 ┆ 5.0 │
+        └─────┴─────┘
         """
-        return wrap_expr(self._pyexpr.rle())
+        low, high = feature_range
+        return wrap_expr(self._pyexpr.min_max_scale(low, high, return_params))
 
-    def rle_id(self) -> Expr:
+    @unstable()
+    def robust_scale(self, *, return_params: bool = False) -> Expr:
         """
-        Get a distinct integer ID for each run of identical values.
+        Scale the values by subtracting the median and dividing by the IQR.
 
-        The ID starts at 0 and increases by one each time the value of the column
-        changes.
+        The interquartile range (IQR) is the difference between the 75th and the 25th
+        percentile, which makes the scaling robust to outliers. Use :meth:`over` to fit
+        the median and the IQR per group.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        return_params
+            Return the fitted parameters as a :class:`Struct` with the fields `median`
+            and `iqr` instead, so that they can be reused on new data.
 
         Returns
         -------
         Expr
-            Expression of data type `UInt32`.
+            Expression of data type :class:`Float64`. Values with an IQR of zero are
+            only centered.
 
         See Also
         --------
-        rle
-
-        Notes
-        -----
-        This functionality is especially useful for defining a new group for every time
-        a column's value changes, rather than for every distinct value of that column.
+        zscore
+        min_max_scale
 
         Examples
         --------
-        >>> df = pl.DataFrame(
-        ...     {
-        ...         "a": [1, 2, 1, 1, 1],
-        ...         "b": ["x", "x", None, "y", "y"],
-        ...     }
-        ... )
-        >>> df.with_columns(
-        ...     rle_id_a=pl.col("a").rle_id(),
-        ...     rle_id_ab=pl.struct("a", "b").rle_id(),
-        ... )
-        shape: (5, 4)
-        ┌─────┬──────┬──────────┬───────────┐
-        │ a   ┆ b    ┆ rle_id_a ┆ rle_id_ab │
-        │ --- ┆ ---  ┆ ---      ┆ ---       │
-        │ i64 ┆ str  ┆ u32      ┆ u32       │
-        ╞═════╪══════╪══════════╪═══════════╡
-        │ 1   ┆ x    ┆ 0        ┆ 0         │
-        │ 2   ┆ x    ┆ 1        ┆ 1         │
-        │ 1   ┆ null ┆ 2        ┆ 2         │
-        │ 1   ┆ y    ┆ 2        ┆ 3         │
-        │ 1   ┆ y    ┆ 2        ┆ 3         │
-        └─────┴──────┴──────────┴───────────┘
+        >>> df = pl.DataFrame({"x": [1, 2, 3, 4, 100]})
+        >>> df.with_columns(scaled=pl.col("x").robust_scale())
+        shape: (5, 2)
+        ┌─────┬────────┐
+        │ x   ┆ scaled │
+        │ --- ┆ ---    │
+        │ i64 ┆ f64    │
+        ╞═════╪════════╡
+        │ 1   ┆ -1.0   │
+        │ 2   ┆ -0.5   │
+        │ 3   ┆ 0.0    │
+        │ 4   ┆ 0.5    │
+        │ 100 ┆ 48.5   │
+        └─────┴────────┘
         """
-        return wrap_expr(self._pyexpr.rle_id())
+        return wrap_expr(self._pyexpr.robust_scale(return_params))
 
     def filter(
         self,
@@ -10388,6 +11099,131 @@ Consider using {self}.implode() instead"""
             self._pyexpr.sample_n(n_pyexpr, with_replacement, shuffle, seed)
         )
 
+    @unstable()
+    def dp_sum(self, epsilon: float, bounds: tuple[float, float]) -> Expr:
+        """
+        Get the differentially private sum of the values.
+
+        The values are clipped to `bounds`, summed, and Laplace noise calibrated to
+        the bounds and `epsilon` is added to the sum. The result is a float.
+
+        Queries with this expression spend `epsilon` of the budget set with
+        :func:`set_privacy_budget` and fail without one. It is not supported in
+        group-by aggregations and window expressions, as their groups would be
+        released without noise.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        epsilon
+            The part of the privacy budget spent by the sum. Smaller values add more
+            noise.
+        bounds
+            The lower and upper bound of the values.
+
+        See Also
+        --------
+        dp_count
+        dp_mean
+
+        Examples
+        --------
+        >>> pl.set_privacy_budget(1.0)
+        >>> df = pl.DataFrame({"salary": [40_000, 55_000, 1_000_000]})
+        >>> df.select(
+        ...     pl.col("salary").dp_sum(1.0, (0, 100_000))
+        ... )  # doctest: +IGNORE_RESULT
+        >>> pl.set_privacy_budget(None)
+        """
+        lower, upper = bounds
+        return wrap_expr(self._pyexpr.dp_sum(epsilon, lower, upper))
+
+    @unstable()
+    def dp_count(self, epsilon: float) -> Expr:
+        """
+        Get the differentially private number of non-null values.
+
+        Laplace noise calibrated to `epsilon` is added to the count. The result is a
+        float.
+
+        Queries with this expression spend `epsilon` of the budget set with
+        :func:`set_privacy_budget` and fail without one. It is not supported in
+        group-by aggregations and window expressions, as their groups would be
+        released without noise.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        epsilon
+            The part of the privacy budget spent by the count. Smaller values add
+            more noise.
+
+        See Also
+        --------
+        dp_sum
+        dp_mean
+
+        Examples
+        --------
+        >>> pl.set_privacy_budget(1.0)
+        >>> df = pl.DataFrame({"id": [1, 2, 3]})
+        >>> df.select(pl.col("id").dp_count(0.5))  # doctest: +IGNORE_RESULT
+        >>> pl.set_privacy_budget(None)
+        """
+        return wrap_expr(self._pyexpr.dp_count(epsilon))
+
+    @unstable()
+    def dp_mean(self, epsilon: float, bounds: tuple[float, float]) -> Expr:
+        """
+        Get the differentially private mean of the values.
+
+        The mean is the ratio of a differentially private sum and count, which each
+        spend half of `epsilon`, clipped to `bounds`.
+
+        Queries with this expression spend `epsilon` of the budget set with
+        :func:`set_privacy_budget` and fail without one. It is not supported in
+        group-by aggregations and window expressions, as their groups would be
+        released without noise.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        epsilon
+            The part of the privacy budget spent by the mean. Smaller values add more
+            noise.
+        bounds
+            The lower and upper bound of the values.
+
+        See Also
+        --------
+        dp_sum
+        dp_count
+
+        Examples
+        --------
+        >>> pl.set_privacy_budget(1.0)
+        >>> df = pl.DataFrame({"age": [23, 35, 41, 67]})
+        >>> df.select(pl.col("age").dp_mean(1.0, (0, 100)))  # doctest: +IGNORE_RESULT
+        >>> pl.set_privacy_budget(None)
+        """
+        lower, upper = bounds
+        return wrap_expr(self._pyexpr.dp_mean(epsilon, lower, upper))
+
     @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
     def ewm_mean(
         self,
@@ -11140,6 +11976,89 @@ Consider using {self}.implode() instead"""
         """
         return wrap_expr(self._pyexpr.cumulative_eval(expr._pyexpr, min_samples))
 
+    @unstable()
+    def scan_fn(
+        self,
+        init: Any,
+        function: Callable[[Any, Any], tuple[Any, Any]] | str,
+        *,
+        return_dtype: PolarsDataType | None = None,
+    ) -> Expr:
+        """
+        Run a state machine over the values, in order.
+
+        For every value, `function` is called with the current state and the value,
+        and returns the next state and the output value. The state starts at `init`,
+        for every group when used in a group context.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        init
+            The initial state.
+        function
+            Function taking the state and a value, returning a tuple of the next state
+            and the output value. This can also be the name of a step function
+            registered from Rust with `polars_ops::series::register_scan_fn`, which
+            runs without calling into Python and keeps the expression serializable
+            without pickling. The state of a registered step must be a value that
+            Polars can represent.
+        return_dtype
+            Data type of the output. Defaults to the data type of the input.
+
+        Notes
+        -----
+        Unlike :meth:`cumulative_eval`, which evaluates an expression on every
+        prefix of the column, this visits every value once. Prefer the built-in
+        cumulative and `ewm` functions where they apply, as a Python `function` is
+        called for every value.
+
+        See Also
+        --------
+        cumulative_eval
+
+        Examples
+        --------
+        Hold a position from entry and exit signals.
+
+        >>> def step(position: int, signal: int | None) -> tuple[int, int]:
+        ...     if signal:
+        ...         position = signal
+        ...     return position, position
+        >>> df = pl.DataFrame({"signal": [1, None, 0, -1, None]})
+        >>> df.with_columns(position=pl.col("signal").scan_fn(0, step))
+        shape: (5, 2)
+        ┌────────┬──────────┐
+        │ signal ┆ position │
+        │ ---    ┆ ---      │
+        │ i64    ┆ i64      │
+        ╞════════╪══════════╡
+        │ 1      ┆ 1        │
+        │ null   ┆ 1        │
+        │ 0      ┆ 1        │
+        │ -1     ┆ -1       │
+        │ null   ┆ -1       │
+        └────────┴──────────┘
+        """
+        rt: plr.PyDataTypeExpr | None = None
+        if return_dtype is not None:
+            rt = parse_into_datatype_expr(return_dtype)._pydatatype_expr
+
+        if isinstance(function, str):
+            return wrap_expr(self._pyexpr.scan_fn_registered(function, init, rt))
+
+        dtype = None if return_dtype is None else parse_into_dtype(return_dtype)
+
+        def scan(s: plr.PySeries) -> plr.PySeries:
+            return s.scan_fn(init, function, s.dtype() if dtype is None else dtype)
+
+        return wrap_expr(self._pyexpr.scan_fn(scan, rt))
+
     def set_sorted(self, *, descending: bool = False, nulls_last: bool = False) -> Expr:
         """
         Flags the expression as 'sorted'.
@@ -11175,6 +12094,138 @@ Consider using {self}.implode() instead"""
         """
         return wrap_expr(self._pyexpr.set_sorted_flag(descending, nulls_last))
 
+    @unstable()
+    def with_field_metadata(self, metadata: dict[str, str]) -> Expr:
+        """
+        Replace the key/value metadata of the output column.
+
+        The metadata survives projections and renames, and is written to and read
+        from Parquet and IPC files. Casts, filters, sorts, slices and aggregations
+        that stay in the domain of their input (e.g. `min`, `max`, `mean` and `sum`)
+        keep the metadata of their input. Arithmetic only keeps the `"polars:unit"` entry
+        (see :meth:`set_unit`), other expressions drop the metadata, and
+        `when/then/otherwise` keeps it only if both branches have the same metadata.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        metadata
+            The metadata of the column. An empty dictionary removes the metadata.
+
+        See Also
+        --------
+        DataFrame.field_metadata
+        LazyFrame.collect_field_metadata
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"speed": [1.0, 2.5]})
+        >>> df = df.select(
+        ...     pl.col("speed").with_field_metadata({"unit": "m/s"}).alias("v")
+        ... )
+        >>> df.field_metadata
+        {'v': {'unit': 'm/s'}}
+        >>> df.select(pl.col("v").max()).field_metadata
+        {'v': {'unit': 'm/s'}}
+        """
+        return wrap_expr(self._pyexpr.with_field_metadata(metadata))
+
+    @unstable()
+    def set_unit(self, unit: str) -> Expr:
+        """
+        Set the physical unit of the output column, like `"m/s"` or `"kg*m/s^2"`.
+
+        The unit is stored in the `"polars:unit"` entry of the field metadata. When the
+        query is optimized, arithmetic between columns with a unit is checked and
+        derives the unit of its result: adding, subtracting and comparing values
+        requires the same unit on both sides, while multiplying and dividing
+        combines the units. Arithmetic with a literal keeps the unit, and arithmetic
+        with a column without a unit gives a result without a unit.
+
+        Units are products of symbols with integer exponents. SI units with a prefix
+        (e.g. `km`, `ms`, `kWh`) and common other units (e.g. `h`, `min`, `ft`,
+        `lb`) are known, so that `N*m` and `J` are the same unit. Unknown symbols
+        are only compatible with themselves.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        unit
+            The unit of the column. An empty string removes the unit.
+
+        See Also
+        --------
+        convert_unit
+        with_field_metadata
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"distance": [100.0, 300.0], "time": [10.0, 20.0]})
+        >>> lf = lf.with_columns(
+        ...     pl.col("distance").set_unit("m"), pl.col("time").set_unit("s")
+        ... )
+        >>> lf.select(
+        ...     speed=pl.col("distance") / pl.col("time")
+        ... ).collect_field_metadata()
+        {'speed': {'polars:unit': 'm/s'}}
+        >>> lf.select(pl.col("distance") + pl.col("time")).collect()
+        Traceback (most recent call last):
+        ...
+        polars.exceptions.InvalidOperationError: incompatible units in `+`: `m` and `s` have different dimensions
+        """  # noqa: W505
+        return wrap_expr(self._pyexpr.set_unit(unit))
+
+    @unstable()
+    def convert_unit(self, unit: str) -> Expr:
+        """
+        Convert the values to another unit with the same dimension.
+
+        The input must have a unit (see :meth:`set_unit`). The scaling factor is
+        resolved from it when the query is optimized. The result is a float column
+        with unit `unit`. Units with an offset, like degrees Celsius, are not
+        supported.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        unit
+            The unit to convert to.
+
+        See Also
+        --------
+        set_unit
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"speed": [1.0, 2.5]})
+        >>> df.select(pl.col("speed").set_unit("m/s").convert_unit("km/h"))
+        shape: (2, 1)
+        ┌───────┐
+        │ speed │
+        │ ---   │
+        │ f64   │
+        ╞═══════╡
+        │ 3.6   │
+        │ 9.0   │
+        └───────┘
+        """
+        return wrap_expr(self._pyexpr.convert_unit(unit))
+
     @deprecated(
         "`Expr.shrink_dtype` is deprecated and is a no-op; use `Series.shrink_dtype` instead."
     )
@@ -11293,8 +12344,8 @@ Consider using {self}.implode() instead"""
 
     def replace(
         self,
-        old: IntoExpr | Sequence[Any] | Mapping[Any, Any],
-        new: IntoExpr | Sequence[Any] | NoDefault = no_default,
+        old: IntoExpr | Sequence[Any] | Mapping[Any, Any] | DataFrame,
+        new: IntoExpr | Sequence[Any] | DataFrame | NoDefault = no_default,
         *,
         default: IntoExpr | NoDefault = no_default,
         return_dtype: PolarsDataType | None = None,
@@ -11310,10 +12361,14 @@ Consider using {self}.implode() instead"""
             other non-expression inputs are parsed as literals.
             Also accepts a mapping of values to their replacement as syntactic sugar for
             `replace(old=Series(mapping.keys()), new=Series(mapping.values()))`.
+            A DataFrame is parsed as a Series of structs, one per row, to replace
+            struct values such as `pl.struct("a", "b")` by the values of multiple
+            columns at once.
         new
             Value or sequence of values to replace by.
             Accepts expression input. Sequences are parsed as Series,
             other non-expression inputs are parsed as literals.
+            A DataFrame is parsed as a Series of structs, one per row.
             Length must match the length of `old` or have length 1.
 
         default
@@ -11460,6 +12515,10 @@ Consider using {self}.implode() instead"""
                 old = pl.Series(old)
             if isinstance(new, Sequence) and not isinstance(new, (str, pl.Series)):
                 new = pl.Series(new)
+        if isinstance(old, pl.DataFrame):
+            old = old.to_struct()
+        if isinstance(new, pl.DataFrame):
+            new = new.to_struct()
 
         old_pyexpr = parse_into_expression(old, str_as_lit=True)  # type: ignore[arg-type]
         new_pyexpr = parse_into_expression(new, str_as_lit=True)
@@ -11473,8 +12532,8 @@ Consider using {self}.implode() instead"""
 
     def replace_strict(
         self,
-        old: IntoExpr | Sequence[Any] | Mapping[Any, Any],
-        new: IntoExpr | Sequence[Any] | NoDefault = no_default,
+        old: IntoExpr | Sequence[Any] | Mapping[Any, Any] | DataFrame,
+        new: IntoExpr | Sequence[Any] | DataFrame | NoDefault = no_default,
         *,
         default: IntoExpr | NoDefault = no_default,
         return_dtype: PolarsDataType | pl.DataTypeExpr | None = None,
@@ -11490,10 +12549,14 @@ Consider using {self}.implode() instead"""
             other non-expression inputs are parsed as literals.
             Also accepts a mapping of values to their replacement as syntactic sugar for
             `replace_strict(old=Series(mapping.keys()), new=Series(mapping.values()))`.
+            A DataFrame is parsed as a Series of structs, one per row, to look up
+            struct values such as `pl.struct("a", "b")` by multiple key columns.
         new
             Value or sequence of values to replace by.
             Accepts expression input. Sequences are parsed as Series,
             other non-expression inputs are parsed as literals.
+            A DataFrame is parsed as a Series of structs, one per row, to replace
+            by the values of multiple output columns.
             Length must match the length of `old` or have length 1.
         default
             Set values that were not replaced to this value. If no default is specified,
@@ -11621,6 +12684,34 @@ Consider using {self}.implode() instead"""
         │ z   ┆ 3        │
         └─────┴──────────┘
 
+        Look up multiple key columns at once by replacing struct values with the
+        rows of DataFrames. Unnest the result to get the output columns.
+
+        >>> df = pl.DataFrame({"a": [1, 1, 2], "b": ["x", "y", "x"]})
+        >>> mapping = pl.DataFrame(
+        ...     {
+        ...         "a": [1, 1, 2],
+        ...         "b": ["x", "y", "x"],
+        ...         "c": [10, 20, 30],
+        ...         "d": [True, False, True],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     pl.struct("a", "b")
+        ...     .replace_strict(mapping.select("a", "b"), mapping.select("c", "d"))
+        ...     .struct.unnest()
+        ... )
+        shape: (3, 4)
+        ┌─────┬─────┬─────┬───────┐
+        │ a   ┆ b   ┆ c   ┆ d     │
+        │ --- ┆ --- ┆ --- ┆ ---   │
+        │ i64 ┆ str ┆ i64 ┆ bool  │
+        ╞═════╪═════╪═════╪═══════╡
+        │ 1   ┆ x   ┆ 10  ┆ true  │
+        │ 1   ┆ y   ┆ 20  ┆ false │
+        │ 2   ┆ x   ┆ 30  ┆ true  │
+        └─────┴─────┴─────┴───────┘
+
         Expression input is supported for all parameters.
 
         >>> df = pl.DataFrame({"a": [1, 2, 2, 3], "b": [1.5, 2.5, 5.0, 1.0]})
@@ -11651,6 +12742,10 @@ Consider using {self}.implode() instead"""
                 raise TypeError(msg)
             new = list(old.values())
             old = list(old.keys())
+        if isinstance(old, pl.DataFrame):
+            old = old.to_struct()
+        if isinstance(new, pl.DataFrame):
+            new = new.to_struct()
 
         old_pyexpr = parse_into_expression(old, str_as_lit=True)  # type: ignore[arg-type]
         new_pyexpr = parse_into_expression(new, str_as_lit=True)  # type: ignore[arg-type]
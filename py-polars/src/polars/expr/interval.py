@@ -0,0 +1,180 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExpr
+
+
+class ExprIntervalNameSpace:
+    """
+    Namespace for interval related expressions.
+
+    Intervals are structs with a `start` and an `end` field of the same numeric or
+    temporal data type, covering the half-open range `[start, end)`. They are
+    always closed on the left and open on the right. They are created with
+    :func:`polars.interval`, but any struct of this shape is accepted; every
+    method raises a :class:`ComputeError` if the start of an interval is after
+    its end.
+    """
+
+    _accessor = "interval"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    @unstable()
+    def overlaps(self, other: IntoExpr) -> Expr:
+        """
+        Check whether the intervals overlap the intervals of `other`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        other
+            The intervals to compare with. Accepts expression input. Strings are parsed
+            as column names.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [{"start": 0, "end": 5}, {"start": 0, "end": 10}],
+        ...         "b": [{"start": 5, "end": 8}, {"start": 2, "end": 4}],
+        ...     }
+        ... )
+        >>> df.with_columns(overlaps=pl.col("a").interval.overlaps("b"))
+        shape: (2, 3)
+        ┌───────────┬───────────┬──────────┐
+        │ a         ┆ b         ┆ overlaps │
+        │ ---       ┆ ---       ┆ ---      │
+        │ struct[2] ┆ struct[2] ┆ bool     │
+        ╞═══════════╪═══════════╪══════════╡
+        │ {0,5}     ┆ {5,8}     ┆ false    │
+        │ {0,10}    ┆ {2,4}     ┆ true     │
+        └───────────┴───────────┴──────────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.interval_overlaps(other_pyexpr))
+
+    @unstable()
+    def contains(self, other: IntoExpr) -> Expr:
+        """
+        Check whether the intervals contain `other`, which are values or intervals.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        other
+            The values or intervals to check. Accepts expression input. Strings are
+            parsed as column names, other non-expression inputs are parsed as literals.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [{"start": 0, "end": 5}, {"start": 0, "end": 10}],
+        ...         "b": [{"start": 5, "end": 8}, {"start": 2, "end": 4}],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     contains_b=pl.col("a").interval.contains("b"),
+        ...     contains_5=pl.col("a").interval.contains(5),
+        ... )
+        shape: (2, 4)
+        ┌───────────┬───────────┬────────────┬────────────┐
+        │ a         ┆ b         ┆ contains_b ┆ contains_5 │
+        │ ---       ┆ ---       ┆ ---        ┆ ---        │
+        │ struct[2] ┆ struct[2] ┆ bool       ┆ bool       │
+        ╞═══════════╪═══════════╪════════════╪════════════╡
+        │ {0,5}     ┆ {5,8}     ┆ false      ┆ false      │
+        │ {0,10}    ┆ {2,4}     ┆ true       ┆ true       │
+        └───────────┴───────────┴────────────┴────────────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.interval_contains(other_pyexpr))
+
+    @unstable()
+    def intersect(self, other: IntoExpr) -> Expr:
+        """
+        Get the overlapping part of the intervals and the intervals of `other`.
+
+        The result is null where the intervals don't overlap.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        other
+            The intervals to intersect with. Accepts expression input. Strings are
+            parsed as column names.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [{"start": 0, "end": 5}, {"start": 0, "end": 10}],
+        ...         "b": [{"start": 5, "end": 8}, {"start": 2, "end": 4}],
+        ...     }
+        ... )
+        >>> df.with_columns(intersection=pl.col("a").interval.intersect("b"))
+        shape: (2, 3)
+        ┌───────────┬───────────┬──────────────┐
+        │ a         ┆ b         ┆ intersection │
+        │ ---       ┆ ---       ┆ ---          │
+        │ struct[2] ┆ struct[2] ┆ struct[2]    │
+        ╞═══════════╪═══════════╪══════════════╡
+        │ {0,5}     ┆ {5,8}     ┆ null         │
+        │ {0,10}    ┆ {2,4}     ┆ {2,4}        │
+        └───────────┴───────────┴──────────────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.interval_intersect(other_pyexpr))
+
+    @unstable()
+    def union_merge(self) -> Expr:
+        """
+        Merge the overlapping and adjacent intervals into sorted, disjoint intervals.
+
+        Null intervals are ignored.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"start": [7, 0, 3], "end": [10, 3, 5]})
+        >>> df.select(pl.interval("start", "end").interval.union_merge())
+        shape: (2, 1)
+        ┌───────────┐
+        │ start     │
+        │ ---       │
+        │ struct[2] │
+        ╞═══════════╡
+        │ {0,5}     │
+        │ {7,10}    │
+        └───────────┘
+        """
+        return wrap_expr(self._pyexpr.interval_union_merge())
@@ -25,6 +25,7 @@ if TYPE_CHECKING:
     from polars import Expr
     from polars._typing import (
         Ambiguous,
+        CollationStrength,
         IntoExpr,
         IntoExprColumn,
         PolarsDataType,
@@ -57,6 +58,7 @@ class ExprStringNameSpace:
         strict: bool = True,
         exact: bool = True,
         cache: bool = True,
+        locale: str | None = None,
     ) -> Expr:
         """
         Convert a String column into a Date column.
@@ -79,6 +81,11 @@ class ExprStringNameSpace:
                 data beforehand will almost certainly be more performant.
         cache
             Use a cache of unique, converted dates to apply the conversion.
+        locale
+            Locale of the month and weekday names in the strings, such as `"fr_FR"`
+            or `"de_DE"`. If set to None (default), the names must be in English.
+
+            .. versionadded:: 1.40.0
 
         Examples
         --------
@@ -91,9 +98,20 @@ class ExprStringNameSpace:
                 2020-02-01
                 2020-03-01
         ]
+
+        Parse month names in another language:
+
+        >>> s = pl.Series(["5 mars 2024", "1 juillet 2024"])
+        >>> s.str.to_date("%d %B %Y", locale="fr_FR")
+        shape: (2,)
+        Series: '' [date]
+        [
+                2024-03-05
+                2024-07-01
+        ]
         """
         _validate_format_argument(format)
-        return wrap_expr(self._pyexpr.str_to_date(format, strict, exact, cache))
+        return wrap_expr(self._pyexpr.str_to_date(format, strict, exact, cache, locale))
 
     def to_datetime(
         self,
@@ -105,6 +123,7 @@ class ExprStringNameSpace:
         exact: bool = True,
         cache: bool = True,
         ambiguous: Ambiguous | Expr = "raise",
+        locale: str | None = None,
     ) -> Expr:
         """
         Convert a String column into a Datetime column.
@@ -151,6 +170,11 @@ class ExprStringNameSpace:
             - `'earliest'`: use the earliest datetime
             - `'latest'`: use the latest datetime
             - `'null'`: set to null
+        locale
+            Locale of the month and weekday names in the strings, such as `"fr_FR"`
+            or `"de_DE"`. If set to None (default), the names must be in English.
+
+            .. versionadded:: 1.40.0
 
         Examples
         --------
@@ -175,6 +199,7 @@ class ExprStringNameSpace:
                 exact,
                 cache,
                 ambiguous._pyexpr,
+                locale,
             )
         )
 
@@ -184,6 +209,7 @@ class ExprStringNameSpace:
         *,
         strict: bool = True,
         cache: bool = True,
+        locale: str | None = None,
     ) -> Expr:
         """
         Convert a String column into a Time column.
@@ -199,6 +225,11 @@ class ExprStringNameSpace:
             Raise an error if any conversion fails.
         cache
             Use a cache of unique, converted times to apply the conversion.
+        locale
+            Locale of the month and weekday names in the strings, such as `"fr_FR"`
+            or `"de_DE"`. If set to None (default), the names must be in English.
+
+            .. versionadded:: 1.40.0
 
         Examples
         --------
@@ -213,7 +244,56 @@ class ExprStringNameSpace:
         ]
         """
         _validate_format_argument(format)
-        return wrap_expr(self._pyexpr.str_to_time(format, strict, cache))
+        return wrap_expr(self._pyexpr.str_to_time(format, strict, cache, locale))
+
+    @unstable()
+    def to_duration(
+        self,
+        time_unit: TimeUnit = "us",
+        *,
+        strict: bool = True,
+    ) -> Expr:
+        """
+        Convert a String column into a Duration column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        The following formats are supported:
+
+        * ISO 8601 durations, such as `"P1DT2H"` or `"-PT0.5S"`. Years and months
+          have no fixed length, so they are not supported.
+        * Human-readable durations, such as `"2d 3h 4m 5.25s"`, `"1h30m"` or
+          `"3 days"`. This includes the Polars duration format `"-1d -42s"`.
+        * Clock durations, such as `"26:03:04.5"` or, as written by Python's
+          `timedelta`, `"-1 day, 23:59:18"`.
+
+        Parameters
+        ----------
+        time_unit : {'us', 'ns', 'ms'}
+            Time unit of the resulting Duration column. Values are truncated to
+            this time unit.
+        strict
+            Raise an error if any conversion fails. If set to False, values that
+            cannot be parsed are set to null.
+
+        Examples
+        --------
+        >>> s = pl.Series(["P1DT2H", "2d 3h 4m 5.25s", "01:30:00", "-1 day, 23:59:18"])
+        >>> s.str.to_duration()
+        shape: (4,)
+        Series: '' [duration[μs]]
+        [
+                1d 2h
+                2d 3h 4m 5s 250ms
+                1h 30m
+                -42s
+        ]
+        """
+        return wrap_expr(self._pyexpr.str_to_duration(time_unit, strict))
 
     def strptime(
         self,
@@ -224,6 +304,7 @@ class ExprStringNameSpace:
         exact: bool = True,
         cache: bool = True,
         ambiguous: Ambiguous | Expr = "raise",
+        locale: str | None = None,
     ) -> Expr:
         """
         Convert a String column into a Date/Datetime/Time column.
@@ -255,6 +336,11 @@ class ExprStringNameSpace:
             - `'earliest'`: use the earliest datetime
             - `'latest'`: use the latest datetime
             - `'null'`: set to null
+        locale
+            Locale of the month and weekday names in the strings, such as `"fr_FR"`
+            or `"de_DE"`. If set to None (default), the names must be in English.
+
+            .. versionadded:: 1.40.0
 
         Notes
         -----
@@ -304,7 +390,9 @@ class ExprStringNameSpace:
         ]
         """
         if dtype == Date:
-            return self.to_date(format, strict=strict, exact=exact, cache=cache)
+            return self.to_date(
+                format, strict=strict, exact=exact, cache=cache, locale=locale
+            )
         elif dtype == Datetime:
             time_unit = getattr(dtype, "time_unit", None)
             time_zone = getattr(dtype, "time_zone", None)
@@ -316,9 +404,10 @@ class ExprStringNameSpace:
                 exact=exact,
                 cache=cache,
                 ambiguous=ambiguous,
+                locale=locale,
             )
         elif dtype == Time:
-            return self.to_time(format, strict=strict, cache=cache)
+            return self.to_time(format, strict=strict, cache=cache, locale=locale)
         else:
             msg = "`dtype` must be of type {Date, Datetime, Time}"
             raise ValueError(msg)
@@ -504,6 +593,193 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_to_lowercase())
 
+    @unstable()
+    def mask_email(self) -> Expr:
+        """
+        Mask the local part of email addresses.
+
+        The first character of the local part and the domain are kept, for example
+        `"john.doe@example.com"` becomes `"j***@example.com"`. Values without an
+        `@` are masked completely.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        See Also
+        --------
+        mask_digits
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"email": ["john.doe@example.com", "jane@corp.org"]})
+        >>> df.with_columns(masked=pl.col("email").str.mask_email())
+        shape: (2, 2)
+        ┌──────────────────────┬──────────────────┐
+        │ email                ┆ masked           │
+        │ ---                  ┆ ---              │
+        │ str                  ┆ str              │
+        ╞══════════════════════╪══════════════════╡
+        │ john.doe@example.com ┆ j***@example.com │
+        │ jane@corp.org        ┆ j***@corp.org    │
+        └──────────────────────┴──────────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_mask_email())
+
+    @unstable()
+    def mask_digits(self, keep_last: int = 0) -> Expr:
+        """
+        Mask the digits of strings with `*`.
+
+        Other characters, such as separators, are kept.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        keep_last
+            The number of trailing digits that are not masked.
+
+        See Also
+        --------
+        mask_email
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"card": ["4111-1111-1111-1234", "5500 0000 0000 0004"]})
+        >>> df.with_columns(masked=pl.col("card").str.mask_digits(keep_last=4))
+        shape: (2, 2)
+        ┌─────────────────────┬─────────────────────┐
+        │ card                ┆ masked              │
+        │ ---                 ┆ ---                 │
+        │ str                 ┆ str                 │
+        ╞═════════════════════╪═════════════════════╡
+        │ 4111-1111-1111-1234 ┆ ****-****-****-1234 │
+        │ 5500 0000 0000 0004 ┆ **** **** **** 0004 │
+        └─────────────────────┴─────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_mask_digits(keep_last))
+
+    def collation_key(
+        self, locale: str, *, strength: CollationStrength = "tertiary"
+    ) -> Expr:
+        """
+        Compute binary sort keys that follow a locale-aware collation.
+
+        Strings are compared by the Unicode Collation Algorithm with the
+        conventions of the given locale, instead of byte-wise. Sorting, comparing
+        or grouping by the keys therefore treats the strings as the locale does;
+        for example, "ä" sorts next to "a" in German but after "z" in Swedish.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        locale
+            BCP 47 language tag of the collation, such as `"de"`, `"sv"` or
+            `"de-u-co-phonebk"`. The `-u-kn` extension enables numeric ordering of
+            digits.
+        strength : {'primary', 'secondary', 'tertiary', 'quaternary', 'identical'}
+            Which differences are significant. `"primary"` only compares base
+            letters, `"secondary"` also compares accents and `"tertiary"` also
+            compares case. `"quaternary"` and `"identical"` break the remaining
+            ties.
+
+        See Also
+        --------
+        Expr.sort
+        DataFrame.sort
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"word": ["zebra", "Öl", "apfel", "Äpfel"]})
+        >>> df.sort(pl.col("word").str.collation_key("sv"))
+        shape: (4, 1)
+        ┌───────┐
+        │ word  │
+        │ ---   │
+        │ str   │
+        ╞═══════╡
+        │ apfel │
+        │ zebra │
+        │ Äpfel │
+        │ Öl    │
+        └───────┘
+
+        Group strings that only differ in accents or case.
+
+        >>> df = pl.DataFrame(
+        ...     {"city": ["Malmö", "malmo", "Lund", "MALMO"], "visits": [1, 2, 3, 4]}
+        ... )
+        >>> df.group_by(
+        ...     pl.col("city").str.collation_key("de", strength="primary").alias("key"),
+        ...     maintain_order=True,
+        ... ).agg(pl.col("city").first(), pl.col("visits").sum()).drop("key")
+        shape: (2, 2)
+        ┌───────┬────────┐
+        │ city  ┆ visits │
+        │ ---   ┆ ---    │
+        │ str   ┆ i64    │
+        ╞═══════╪════════╡
+        │ Malmö ┆ 7      │
+        │ Lund  ┆ 3      │
+        └───────┴────────┘
+        """
+        return wrap_expr(self._pyexpr.str_collation_key(locale, strength))
+
+    def natural_sort_key(self) -> Expr:
+        """
+        Compute binary sort keys that order strings naturally.
+
+        Runs of digits are compared by their numeric value instead of character by
+        character, so that `"file2"` sorts before `"file10"`. Leading zeros are
+        ignored. As the keys are plain binary values, they can be combined with other
+        columns in a multi-column sort.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        See Also
+        --------
+        Expr.sort
+        DataFrame.sort
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "file": ["img12.png", "img10.png", "img2.png", "img1.png"],
+        ...         "dir": ["b", "a", "b", "a"],
+        ...     }
+        ... )
+        >>> df.sort("dir", pl.col("file").str.natural_sort_key())
+        shape: (4, 2)
+        ┌───────────┬─────┐
+        │ file      ┆ dir │
+        │ ---       ┆ --- │
+        │ str       ┆ str │
+        ╞═══════════╪═════╡
+        │ img1.png  ┆ a   │
+        │ img10.png ┆ a   │
+        │ img2.png  ┆ b   │
+        │ img12.png ┆ b   │
+        └───────────┴─────┘
+        """
+        return wrap_expr(self._pyexpr.str_natural_sort_key())
+
     def to_titlecase(self) -> Expr:
         """
         Modify strings to their titlecase equivalent.
@@ -3187,6 +3463,77 @@ class ExprStringNameSpace:
         """  # noqa: RUF002
         return wrap_expr(self._pyexpr.str_normalize(form))
 
+    @unstable()
+    def remove_diacritics(self) -> Expr:
+        """
+        Remove the diacritics (accents) of the string values.
+
+        The strings are decomposed into base characters and combining marks (NFD),
+        the combining marks are dropped and the result is composed again (NFC).
+        Letters with a stroke, such as `ł` and `ø`, are replaced by their base
+        letter. This is useful before comparing or joining on human-entered names.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        See Also
+        --------
+        normalize
+        is_ascii
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"name": ["Zoë Saldaña", "Łukasz Żółw", "Søren"]})
+        >>> df.with_columns(plain=pl.col("name").str.remove_diacritics())
+        shape: (3, 2)
+        ┌─────────────┬─────────────┐
+        │ name        ┆ plain       │
+        │ ---         ┆ ---         │
+        │ str         ┆ str         │
+        ╞═════════════╪═════════════╡
+        │ Zoë Saldaña ┆ Zoe Saldana │
+        │ Łukasz Żółw ┆ Lukasz Zolw │
+        │ Søren       ┆ Soren       │
+        └─────────────┴─────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_remove_diacritics())
+
+    @unstable()
+    def is_ascii(self) -> Expr:
+        """
+        Check whether the string values only consist of ASCII characters.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        See Also
+        --------
+        remove_diacritics
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"name": ["Zoe", "Zoë", "", None]})
+        >>> df.with_columns(ascii=pl.col("name").str.is_ascii())
+        shape: (4, 2)
+        ┌──────┬───────┐
+        │ name ┆ ascii │
+        │ ---  ┆ ---   │
+        │ str  ┆ bool  │
+        ╞══════╪═══════╡
+        │ Zoe  ┆ true  │
+        │ Zoë  ┆ false │
+        │      ┆ true  │
+        │ null ┆ null  │
+        └──────┴───────┘
+        """
+        return wrap_expr(self._pyexpr.str_is_ascii())
+
 
 def _validate_format_argument(format: str | None) -> None:
     if format is not None and ".%f" in format:
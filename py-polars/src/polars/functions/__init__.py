@@ -5,6 +5,7 @@ from polars.functions.aggregation import (
     any_horizontal,
     cum_sum,
     cum_sum_horizontal,
+    first_non_null_horizontal,
     max,
     max_horizontal,
     mean_horizontal,
@@ -29,6 +30,7 @@ from polars.functions.col import col
 from polars.functions.datatype import dtype_of, self_dtype, struct_with_fields
 from polars.functions.eager import align_frames, concat, union
 from polars.functions.escape_regex import escape_regex
+from polars.functions.interval import interval
 from polars.functions.lazy import (
     _row_encode,
     approx_n_unique,
@@ -62,11 +64,13 @@ from polars.functions.lazy import (
     median,
     n_unique,
     nth,
+    param,
     quantile,
     reduce,
     rolling_corr,
     rolling_cov,
     row_index,
+    search_sorted_by,
     select,
     sql_expr,
     std,
@@ -75,6 +79,7 @@ from polars.functions.lazy import (
 )
 from polars.functions.len import len
 from polars.functions.lit import lit
+from polars.functions.privacy import privacy_budget_remaining, set_privacy_budget
 from polars.functions.random import set_random_seed
 from polars.functions.range import (
     arange,
@@ -90,7 +95,7 @@ from polars.functions.range import (
     time_ranges,
 )
 from polars.functions.repeat import ones, repeat, zeros
-from polars.functions.whenthen import when
+from polars.functions.whenthen import when, when_lazy
 
 __all__ = [
     # polars.functions.aggregation
@@ -103,6 +108,7 @@ __all__ = [
     "all_horizontal",
     "any_horizontal",
     "cum_sum_horizontal",
+    "first_non_null_horizontal",
     "max_horizontal",
     "min_horizontal",
     "sum_horizontal",
@@ -161,6 +167,7 @@ __all__ = [
     "implode",
     "int_range",
     "int_ranges",
+    "interval",
     "last",
     "linear_space",
     "linear_spaces",
@@ -172,11 +179,13 @@ __all__ = [
     "median",
     "n_unique",
     "nth",
+    "param",
     "quantile",
     "reduce",
     "rolling_corr",
     "rolling_cov",
     "row_index",
+    "search_sorted_by",
     "select",
     "set_random_seed",
     "std",
@@ -186,8 +195,12 @@ __all__ = [
     "var",
     # polars.functions.len
     "len",
+    # polars.functions.privacy
+    "privacy_budget_remaining",
+    "set_privacy_budget",
     # polars.functions.whenthen
     "when",
+    "when_lazy",
     "sql_expr",
     # polars.functions.escape_regex
     "escape_regex",
@@ -2,6 +2,7 @@ from polars.functions.aggregation.horizontal import (
     all_horizontal,
     any_horizontal,
     cum_sum_horizontal,
+    first_non_null_horizontal,
     max_horizontal,
     mean_horizontal,
     min_horizontal,
@@ -23,6 +24,7 @@ __all__ = [
     "any_horizontal",
     "cum_sum",
     "cum_sum_horizontal",
+    "first_non_null_horizontal",
     "max",
     "max_horizontal",
     "mean_horizontal",
@@ -5,6 +5,8 @@ from typing import TYPE_CHECKING
 
 import polars.functions as F
 from polars._utils.parse import parse_into_list_of_expressions
+from polars._utils.parse.expr import parse_list_into_selector
+from polars._utils.unstable import unstable
 from polars._utils.wrap import wrap_expr
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -14,7 +16,7 @@ if TYPE_CHECKING:
     from collections.abc import Iterable
 
     from polars import Expr
-    from polars._typing import IntoExpr
+    from polars._typing import ColumnNameOrSelector, IntoExpr
 
 
 def all_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
@@ -257,6 +259,70 @@ def mean_horizontal(
     return wrap_expr(plr.mean_horizontal(pyexprs, ignore_nulls))
 
 
+@unstable()
+def first_non_null_horizontal(*columns: ColumnNameOrSelector) -> Expr:
+    """
+    Keep the first non-null value of every row, over the selected columns.
+
+    This is a :func:`coalesce` over a selector, which is convenient for long
+    fallback chains over many columns. Columns further down the chain are only
+    evaluated as long as some rows are still null.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    .. versionadded:: 1.40.0
+
+    Parameters
+    ----------
+    *columns
+        Names of the columns to coalesce, or selectors matching them. Columns
+        given by name are coalesced in the given order, all others in the order
+        of the schema.
+
+    See Also
+    --------
+    coalesce
+
+    Examples
+    --------
+    >>> import polars.selectors as cs
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "a": [1, None, None],
+    ...         "b": [None, 2, None],
+    ...         "c": [None, 5, 3],
+    ...         "d": ["x", "y", "z"],
+    ...     }
+    ... )
+    >>> df.select(first=pl.first_non_null_horizontal(cs.numeric()))
+    shape: (3, 1)
+    ┌───────┐
+    │ first │
+    │ ---   │
+    │ i64   │
+    ╞═══════╡
+    │ 1     │
+    │ 2     │
+    │ 3     │
+    └───────┘
+    >>> df.select(first=pl.first_non_null_horizontal("c", "b", "a"))
+    shape: (3, 1)
+    ┌───────┐
+    │ first │
+    │ ---   │
+    │ i64   │
+    ╞═══════╡
+    │ 1     │
+    │ 5     │
+    │ 3     │
+    └───────┘
+    """
+    selector = parse_list_into_selector(columns)
+    return wrap_expr(plr.first_non_null_horizontal(selector._pyselector))
+
+
 def cum_sum_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     """
     Cumulatively sum all values horizontally across columns.
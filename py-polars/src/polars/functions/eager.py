@@ -38,7 +38,7 @@ def concat(
     ----------
     items
         DataFrames, LazyFrames, or Series to concatenate.
-    how : {'vertical', 'vertical_relaxed', 'diagonal', 'diagonal_relaxed', 'horizontal', 'align', 'align_full', 'align_inner', 'align_left', 'align_right'}
+    how : {'vertical', 'vertical_relaxed', 'diagonal', 'diagonal_relaxed', 'diagonal_relaxed_by_name', 'horizontal', 'align', 'align_full', 'align_inner', 'align_left', 'align_right'}
         Note that `Series` only support the `vertical` strategy.
 
         * vertical: Applies multiple `vstack` operations.
@@ -48,6 +48,13 @@ def concat(
           values with `null`.
         * diagonal_relaxed: Same as `diagonal`, but additionally coerces columns to
           their common supertype *if* they are mismatched (eg: Int32 → Int64).
+        * diagonal_relaxed_by_name: Aligns the columns of all frames by name,
+          regardless of their order, coerces every column to the common supertype
+          of its inputs and fills columns that are missing in a frame with `null`.
+          The unified schema can be inspected up front with
+          `pl.concat(lfs, how="diagonal_relaxed_by_name").collect_schema()`.
+
+          .. versionadded:: 1.40.0
         * horizontal: Stacks Series from DataFrames horizontally and fills with `null`
           if the lengths don't match.
         * align, align_full, align_left, align_right: Combines frames horizontally,
@@ -248,7 +255,7 @@ def concat(
 
         elif how == "diagonal":
             out = wrap_df(plr.concat_df_diagonal(elems))
-        elif how == "diagonal_relaxed":
+        elif how in ("diagonal_relaxed", "diagonal_relaxed_by_name"):
             out = wrap_ldf(
                 plr.concat_lf_diagonal(
                     [df.lazy() for df in elems],
@@ -276,13 +283,13 @@ def concat(
                     maintain_order=True,
                 )
             )
-        elif how in ("diagonal", "diagonal_relaxed"):
+        elif how in ("diagonal", "diagonal_relaxed", "diagonal_relaxed_by_name"):
             return wrap_ldf(
                 plr.concat_lf_diagonal(
                     elems,
                     rechunk=rechunk,
                     parallel=parallel,
-                    to_supertypes=how.endswith("relaxed"),
+                    to_supertypes=how != "diagonal",
                     maintain_order=True,
                 )
             )
@@ -334,7 +341,7 @@ def union(
     ----------
     items
         DataFrames, LazyFrames, or Series to concatenate.
-    how : {'vertical', 'vertical_relaxed', 'diagonal', 'diagonal_relaxed', 'horizontal', 'align', 'align_full', 'align_inner', 'align_left', 'align_right'}
+    how : {'vertical', 'vertical_relaxed', 'diagonal', 'diagonal_relaxed', 'diagonal_relaxed_by_name', 'horizontal', 'align', 'align_full', 'align_inner', 'align_left', 'align_right'}
         Note that `Series` only support the `vertical` strategy.
 
         * vertical: Applies multiple `vstack` operations.
@@ -344,6 +351,13 @@ def union(
           values with `null`.
         * diagonal_relaxed: Same as `diagonal`, but additionally coerces columns to
           their common supertype *if* they are mismatched (eg: Int32 → Int64).
+        * diagonal_relaxed_by_name: Aligns the columns of all frames by name,
+          regardless of their order, coerces every column to the common supertype
+          of its inputs and fills columns that are missing in a frame with `null`.
+          The unified schema can be inspected up front with
+          `pl.concat(lfs, how="diagonal_relaxed_by_name").collect_schema()`.
+
+          .. versionadded:: 1.40.0
         * horizontal: Stacks Series from DataFrames horizontally and fills with `null`
           if the lengths don't match.
         * align, align_full, align_left, align_right: Combines frames horizontally,
@@ -534,13 +548,13 @@ def union(
                     maintain_order=False,
                 )
             ).collect(optimizations=QueryOptFlags._eager())
-        elif how in ("diagonal", "diagonal_relaxed"):
+        elif how in ("diagonal", "diagonal_relaxed", "diagonal_relaxed_by_name"):
             out = wrap_ldf(
                 plr.concat_lf_diagonal(
                     [df.lazy() for df in elems],
                     rechunk=False,
                     parallel=True,
-                    to_supertypes=how.endswith("relaxed"),
+                    to_supertypes=how != "diagonal",
                     maintain_order=False,
                 )
             ).collect(optimizations=QueryOptFlags._eager())
@@ -562,13 +576,13 @@ def union(
                     maintain_order=False,
                 )
             )
-        elif how in ("diagonal", "diagonal_relaxed"):
+        elif how in ("diagonal", "diagonal_relaxed", "diagonal_relaxed_by_name"):
             return wrap_ldf(
                 plr.concat_lf_diagonal(
                     elems,
                     rechunk=False,
                     parallel=True,
-                    to_supertypes=how.endswith("relaxed"),
+                    to_supertypes=how != "diagonal",
                     maintain_order=False,
                 )
             )
@@ -0,0 +1,73 @@
+from __future__ import annotations
+
+import contextlib
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars._plr as plr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExpr
+
+
+@unstable()
+def interval(start: IntoExpr, end: IntoExpr) -> Expr:
+    """
+    Create half-open intervals `[start, end)` from their bounds.
+
+    Intervals are structs with a `start` and an `end` field of the same numeric or
+    temporal data type, the supertype of the bounds. They can be manipulated with
+    the methods of the :attr:`Expr.interval` namespace.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    .. versionadded:: 1.40.0
+
+    Parameters
+    ----------
+    start
+        The (inclusive) start of the intervals. Accepts expression input. Strings
+        are parsed as column names.
+    end
+        The (exclusive) end of the intervals. Accepts expression input. Strings are
+        parsed as column names.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`Struct`, which is null where either bound is
+        null.
+
+    Raises
+    ------
+    ComputeError
+        If the start of an interval is after its end.
+
+    See Also
+    --------
+    LazyFrame.interval_join
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"start": [0, 3], "end": [5, 4]})
+    >>> df.with_columns(interval=pl.interval("start", "end"))
+    shape: (2, 3)
+    ┌───────┬─────┬───────────┐
+    │ start ┆ end ┆ interval  │
+    │ ---   ┆ --- ┆ ---       │
+    │ i64   ┆ i64 ┆ struct[2] │
+    ╞═══════╪═════╪═══════════╡
+    │ 0     ┆ 5   ┆ {0,5}     │
+    │ 3     ┆ 4   ┆ {3,4}     │
+    └───────┴─────┴───────────┘
+    """
+    start_pyexpr = parse_into_expression(start)
+    end_pyexpr = parse_into_expression(end)
+    return wrap_expr(plr.interval(start_pyexpr, end_pyexpr))
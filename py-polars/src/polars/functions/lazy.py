@@ -47,6 +47,7 @@ if TYPE_CHECKING:
         IntoExpr,
         PolarsDataType,
         QuantileMethod,
+        SearchSortedSide,
     )
     from polars.lazyframe.opt_flags import (
         QueryOptFlags,
@@ -86,6 +87,49 @@ def field(name: str | list[str]) -> Expr:
     return wrap_expr(plr.field(name))
 
 
+@unstable()
+def param(name: str) -> Expr:
+    """
+    A literal whose value is bound later with :meth:`LazyFrame.bind_parameters`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    .. versionadded:: 1.40.0
+
+    This allows a query to be built once, and run many times with different values.
+    A query with unbound parameters cannot be collected, and its schema cannot be
+    resolved.
+
+    Parameters
+    ----------
+    name
+        Name of the parameter.
+
+    See Also
+    --------
+    LazyFrame.bind_parameters
+    set_plan_cache_capacity
+
+    Examples
+    --------
+    >>> lf = pl.LazyFrame({"a": [1, 2, 3, 4]})
+    >>> query = lf.filter(pl.col("a") > pl.param("min"))
+    >>> query.bind_parameters({"min": 2}).collect()
+    shape: (2, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ i64 │
+    ╞═════╡
+    │ 3   │
+    │ 4   │
+    └─────┘
+    """
+    return wrap_expr(plr.param(name))
+
+
 def element() -> Expr:
     """
     Alias for an element being evaluated in an `eval` or `filter` expression.
@@ -2013,6 +2057,79 @@ def arg_sort_by(
     )
 
 
+@unstable()
+def search_sorted_by(
+    by: IntoExpr | Iterable[IntoExpr],
+    search_values: IntoExpr | Iterable[IntoExpr],
+    *,
+    side: SearchSortedSide = "any",
+    descending: bool | Sequence[bool] = False,
+    nulls_last: bool | Sequence[bool] = False,
+) -> Expr:
+    """
+    Find the indices where rows should be inserted to maintain the order.
+
+    The frame has to be sorted by all columns in `by`, as with :meth:`DataFrame.sort`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    .. versionadded:: 1.40.0
+
+    Parameters
+    ----------
+    by
+        Column(s) the frame is sorted by. Accepts expression input. Strings are
+        parsed as column names.
+    search_values
+        Values to search for, one per column in `by`. Accepts expression input.
+        Strings are parsed as column names.
+    side : {'any', 'left', 'right'}
+        If 'any', the index of the first suitable location found is given.
+        If 'left', the index of the leftmost suitable location found is given.
+        If 'right', return the rightmost suitable location found is given.
+    descending
+        Whether the columns are sorted in descending order. Can be specified per
+        column by passing a sequence of booleans.
+    nulls_last
+        Whether nulls are placed last. Can be specified per column by passing a
+        sequence of booleans.
+
+    See Also
+    --------
+    Expr.search_sorted: Search a single sorted column.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, 1, 2, 2], "b": [4, 3, 2, 1]})
+    >>> df.select(
+    ...     pl.search_sorted_by(
+    ...         ["a", "b"],
+    ...         [pl.lit(pl.Series([1, 2])), pl.lit(pl.Series([3, 0]))],
+    ...         side="left",
+    ...         descending=[False, True],
+    ...     )
+    ... )
+    shape: (2, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ u32 │
+    ╞═════╡
+    │ 1   │
+    │ 4   │
+    └─────┘
+    """
+    by = parse_into_list_of_expressions(by)
+    search_values = parse_into_list_of_expressions(search_values)
+    descending = extend_bool(descending, len(by), "descending", "by")
+    nulls_last = extend_bool(nulls_last, len(by), "nulls_last", "by")
+    return wrap_expr(
+        plr.search_sorted_by(by, search_values, side, descending, nulls_last)
+    )
+
+
 @overload
 def collect_all(
     lazy_frames: Iterable[LazyFrame],
@@ -2030,6 +2147,10 @@ def collect_all(
     optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
     engine: EngineType = "auto",
     lazy: Literal[False] = False,
+    priorities: Sequence[int] | None = None,
+    max_concurrent_queries: int | None = None,
+    memory_limit: int | None = None,
+    max_concurrent_requests: int | None = None,
 ) -> list[DataFrame]: ...
 
 
@@ -2071,6 +2192,10 @@ def collect_all(
     optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
     engine: EngineType = "auto",
     lazy: bool = False,
+    priorities: Sequence[int] | None = None,
+    max_concurrent_queries: int | None = None,
+    memory_limit: int | None = None,
+    max_concurrent_requests: int | None = None,
 ) -> list[DataFrame] | LazyFrame:
     """
     Collect multiple LazyFrames at the same time.
@@ -2080,6 +2205,10 @@ def collect_all(
     Common Subplan Elimination is applied on the combined plan, meaning
     that diverging queries will run only once.
 
+    If any of `priorities`, `max_concurrent_queries`, `memory_limit` or
+    `max_concurrent_requests` is set, the queries are instead collected
+    independently by a scheduler that shares these limits between them.
+
     Parameters
     ----------
     lazy_frames
@@ -2159,6 +2288,22 @@ def collect_all(
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
+    priorities
+        The priority of every LazyFrame. LazyFrames with a higher priority are
+        started first, those with the same priority in the given order.
+    max_concurrent_queries
+        The maximum number of LazyFrames that are collected at the same time.
+    memory_limit
+        The memory in bytes that the running queries may use together. A query
+        is only started while the memory used since the start of `collect_all`
+        is below the limit, or if no other query runs.
+    max_concurrent_requests
+        The maximum number of concurrent object store requests of all queries
+        together.
+
+    .. warning::
+        The scheduling parameters are considered **unstable**. They may be
+        changed at any point without it being considered a breaking change.
 
     Returns
     -------
@@ -2167,7 +2312,20 @@ def collect_all(
 
     """
     lfs = [lf._ldf for lf in lazy_frames]
+    is_scheduled = (
+        priorities is not None
+        or max_concurrent_queries is not None
+        or memory_limit is not None
+        or max_concurrent_requests is not None
+    )
+    if is_scheduled:
+        msg = "the scheduling parameters of `collect_all` are considered unstable."
+        issue_unstable_warning(msg)
     if lazy:
+        if is_scheduled:
+            msg = "the scheduling parameters of `collect_all` require `lazy=False`"
+            raise ValueError(msg)
+
         msg = "the `lazy` parameter of `collect_all` is considered unstable."
         issue_unstable_warning(msg)
 
@@ -2180,7 +2338,15 @@ def collect_all(
     from polars.lazyframe.frame import _select_engine
 
     engine = _select_engine(engine)
-    out = plr.collect_all(lfs, engine, optimizations._pyoptflags)
+    out = plr.collect_all(
+        lfs,
+        engine,
+        optimizations._pyoptflags,
+        priorities=None if priorities is None else list(priorities),
+        max_concurrent_queries=max_concurrent_queries,
+        memory_limit=memory_limit,
+        max_concurrent_requests=max_concurrent_requests,
+    )
 
     # wrap the pydataframes into dataframe
     result = [wrap_df(pydf) for pydf in out]
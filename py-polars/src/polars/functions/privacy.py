@@ -0,0 +1,62 @@
+from __future__ import annotations
+
+import contextlib
+
+from polars._utils.unstable import unstable
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars._plr as plr
+
+
+@unstable()
+def set_privacy_budget(epsilon: float | None) -> None:
+    """
+    Set the differential privacy budget spent by queries.
+
+    Every query that evaluates differentially private aggregations, such as
+    :meth:`Expr.dp_sum`, spends the sum of their `epsilon` from this budget before
+    it runs. A query fails if it would exceed the remaining budget, or if no budget
+    is set.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    .. versionadded:: 1.40.0
+
+    Parameters
+    ----------
+    epsilon
+        The total budget. Setting a budget replaces the previous budget, including
+        what was spent of it. `None` removes the budget.
+
+    See Also
+    --------
+    privacy_budget_remaining
+
+    Examples
+    --------
+    >>> pl.set_privacy_budget(1.0)
+    >>> df = pl.DataFrame({"age": [23, 35, 41, 67]})
+    >>> df.select(pl.col("age").dp_mean(0.5, (0, 100)))  # doctest: +IGNORE_RESULT
+    >>> pl.privacy_budget_remaining()
+    0.5
+    >>> pl.set_privacy_budget(None)
+    """
+    plr.set_privacy_budget(epsilon)
+
+
+@unstable()
+def privacy_budget_remaining() -> float | None:
+    """
+    Get the remaining differential privacy budget, see :func:`set_privacy_budget`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    .. versionadded:: 1.40.0
+
+    Returns `None` if no budget is set.
+    """
+    return plr.privacy_budget_remaining()
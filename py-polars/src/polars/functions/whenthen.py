@@ -5,6 +5,7 @@ from typing import TYPE_CHECKING, Any
 
 import polars._reexport as pl
 from polars._utils.parse import parse_predicates_constraints_into_expression
+from polars._utils.unstable import unstable
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
     import polars._plr as plr
@@ -48,7 +49,8 @@ def when(
     --------
     Polars computes all expressions passed to `when-then-otherwise` in parallel and
     filters afterwards. This means each expression must be valid on its own, regardless
-    of the conditions in the `when-then-otherwise` chain.
+    of the conditions in the `when-then-otherwise` chain. Use :func:`when_lazy` to
+    evaluate every statement only on the rows it is selected for.
 
     Notes
     -----
@@ -353,3 +355,84 @@ def when(
     """  # fmt: skip
     condition = parse_predicates_constraints_into_expression(*predicates, **constraints)
     return pl.When(plr.when(condition))
+
+
+@unstable()
+def when_lazy(
+    *predicates: IntoExprColumn | Iterable[IntoExprColumn] | bool,
+    **constraints: Any,
+) -> pl.When:
+    """
+    Start a `when-then-otherwise` expression that evaluates every statement on its rows.
+
+    This works like :func:`when`, except that every `then` and `otherwise` statement is
+    evaluated only on the rows it is selected for, as if these rows were the whole
+    column. This also holds for statements that depend on other rows, such as
+    cumulative functions, which are then computed over the selected rows only.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    .. versionadded:: 1.40.0
+
+    Parameters
+    ----------
+    predicates
+        Condition(s) that must be met in order to apply the subsequent statement.
+        Accepts one or more boolean expressions, which are implicitly combined with
+        `&`.
+    constraints
+        Apply conditions as `col_name = value` keyword arguments that are treated as
+        equality matches, such as `x = 123`. As with the predicates parameter, multiple
+        conditions are implicitly combined using `&`.
+
+    See Also
+    --------
+    when
+
+    Examples
+    --------
+    Number the rows of both categories separately.
+
+    >>> df = pl.DataFrame({"kind": ["a", "b", "a", "b", "a"]})
+    >>> df.with_columns(
+    ...     n=pl.when_lazy(pl.col("kind") == "a")
+    ...     .then(pl.int_range(pl.len()))
+    ...     .otherwise(pl.int_range(pl.len()))
+    ... )
+    shape: (5, 2)
+    ┌──────┬─────┐
+    │ kind ┆ n   │
+    │ ---  ┆ --- │
+    │ str  ┆ i64 │
+    ╞══════╪═════╡
+    │ a    ┆ 0   │
+    │ b    ┆ 0   │
+    │ a    ┆ 1   │
+    │ b    ┆ 1   │
+    │ a    ┆ 2   │
+    └──────┴─────┘
+
+    With :func:`when`, the statements are evaluated on the whole column.
+
+    >>> df.with_columns(
+    ...     n=pl.when(pl.col("kind") == "a")
+    ...     .then(pl.int_range(pl.len()))
+    ...     .otherwise(pl.int_range(pl.len()))
+    ... )
+    shape: (5, 2)
+    ┌──────┬─────┐
+    │ kind ┆ n   │
+    │ ---  ┆ --- │
+    │ str  ┆ i64 │
+    ╞══════╪═════╡
+    │ a    ┆ 0   │
+    │ b    ┆ 1   │
+    │ a    ┆ 2   │
+    │ b    ┆ 3   │
+    │ a    ┆ 4   │
+    └──────┴─────┘
+    """
+    condition = parse_predicates_constraints_into_expression(*predicates, **constraints)
+    return pl.When(plr.when_lazy(condition))
@@ -14,6 +14,7 @@ from polars.io.parquet import (
     read_parquet,
     read_parquet_metadata,
     read_parquet_schema,
+    read_parquet_statistics,
     scan_parquet,
 )
 from polars.io.partition import (
@@ -22,11 +23,16 @@ from polars.io.partition import (
 )
 from polars.io.plugins import _defer as defer
 from polars.io.pyarrow_dataset import scan_pyarrow_dataset
-from polars.io.scan_options import ScanCastOptions
+from polars.io.scan_options import (
+    FileListingOptions,
+    ScanCastOptions,
+    SchemaEvolutionPolicy,
+)
 from polars.io.spreadsheet import read_excel, read_ods
 
 __all__ = [
     "defer",
+    "FileListingOptions",
     "FileProviderArgs",
     "PartitionBy",
     "read_avro",
@@ -47,6 +53,7 @@ __all__ = [
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
+    "read_parquet_statistics",
     "scan_csv",
     "scan_delta",
     "scan_iceberg",
@@ -56,4 +63,5 @@ __all__ = [
     "scan_parquet",
     "scan_pyarrow_dataset",
     "ScanCastOptions",
+    "SchemaEvolutionPolicy",
 ]
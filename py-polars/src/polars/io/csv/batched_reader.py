@@ -28,7 +28,11 @@ class BatchedCsvReader:
         skip_rows: int = 0,
         skip_lines: int = 0,
         schema_overrides: SchemaDict | Sequence[PolarsDataType] | None = None,
-        null_values: str | Sequence[str] | dict[str, str] | None = None,
+        null_values: str
+        | Sequence[str]
+        | dict[str, str]
+        | dict[str, Sequence[str]]
+        | None = None,
         missing_utf8_is_empty_string: bool = False,
         ignore_errors: bool = False,
         try_parse_dates: bool = False,
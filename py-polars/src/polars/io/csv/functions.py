@@ -51,6 +51,7 @@ if TYPE_CHECKING:
     )
     from polars.io.cloud import CredentialProviderFunction
     from polars.io.cloud.credential_provider._builder import CredentialProviderBuilder
+    from polars.io.scan_options import FileListingOptions, SchemaEvolutionPolicy
 
 
 @deprecate_renamed_parameter("dtypes", "schema_overrides", version="0.20.31")
@@ -71,7 +72,11 @@ def read_csv(
     schema_overrides: (
         Mapping[str, PolarsDataType] | Sequence[PolarsDataType] | None
     ) = None,
-    null_values: str | Sequence[str] | dict[str, str] | None = None,
+    null_values: str
+    | Sequence[str]
+    | dict[str, str]
+    | dict[str, Sequence[str]]
+    | None = None,
     missing_utf8_is_empty_string: bool = False,
     ignore_errors: bool = False,
     try_parse_dates: bool = False,
@@ -156,6 +161,11 @@ def read_csv(
         - `List[str]`: All values equal to any string in this list will be null.
         - `Dict[str, str]`: A dictionary that maps column name to a
           null value string.
+        - `Dict[str, List[str]]`: A dictionary that maps column name to a
+          list of null value strings. Columns that are not in the dictionary,
+          or that map to an empty list, have no null value strings.
+
+          .. versionadded:: 1.40.0
 
     missing_utf8_is_empty_string
         By default a missing value is considered to be null; if you would prefer missing
@@ -611,7 +621,11 @@ def _read_csv_impl(
     skip_lines: int = 0,
     schema: None | SchemaDict = None,
     schema_overrides: None | (SchemaDict | Sequence[PolarsDataType]) = None,
-    null_values: str | Sequence[str] | dict[str, str] | None = None,
+    null_values: str
+    | Sequence[str]
+    | dict[str, str]
+    | dict[str, Sequence[str]]
+    | None = None,
     missing_utf8_is_empty_string: bool = False,
     ignore_errors: bool = False,
     try_parse_dates: bool = False,
@@ -769,7 +783,11 @@ def read_csv_batched(
     schema_overrides: (
         Mapping[str, PolarsDataType] | Sequence[PolarsDataType] | None
     ) = None,
-    null_values: str | Sequence[str] | dict[str, str] | None = None,
+    null_values: str
+    | Sequence[str]
+    | dict[str, str]
+    | dict[str, Sequence[str]]
+    | None = None,
     missing_utf8_is_empty_string: bool = False,
     ignore_errors: bool = False,
     try_parse_dates: bool = False,
@@ -849,6 +867,11 @@ def read_csv_batched(
         - `List[str]`: All values equal to any string in this list will be null.
         - `Dict[str, str]`: A dictionary that maps column name to a
           null value string.
+        - `Dict[str, List[str]]`: A dictionary that maps column name to a
+          list of null value strings. Columns that are not in the dictionary,
+          or that map to an empty list, have no null value strings.
+
+          .. versionadded:: 1.40.0
 
     missing_utf8_is_empty_string
         By default a missing value is considered to be null; if you would prefer missing
@@ -1105,7 +1128,11 @@ def scan_csv(
     skip_lines: int = 0,
     schema: SchemaDict | None = None,
     schema_overrides: SchemaDict | Sequence[PolarsDataType] | None = None,
-    null_values: str | Sequence[str] | dict[str, str] | None = None,
+    null_values: str
+    | Sequence[str]
+    | dict[str, str]
+    | dict[str, Sequence[str]]
+    | None = None,
     missing_utf8_is_empty_string: bool = False,
     ignore_errors: bool = False,
     cache: bool | None = None,
@@ -1131,7 +1158,12 @@ def scan_csv(
     retries: int | None = None,
     file_cache_ttl: int | None = None,
     include_file_paths: str | None = None,
+    include_row_index_in_file: str | None = None,
+    include_file_modification_time: str | None = None,
     missing_columns: Literal["insert", "raise"] | None = None,
+    schema_evolution: SchemaEvolutionPolicy | None = None,
+    listing_options: FileListingOptions | None = None,
+    statistics_sidecar: str | Path | None = None,
 ) -> LazyFrame:
     r"""
     Lazily read from a CSV file or multiple files via glob patterns.
@@ -1188,6 +1220,11 @@ def scan_csv(
         - `List[str]`: All values equal to any string in this list will be null.
         - `Dict[str, str]`: A dictionary that maps column name to a
           null value string.
+        - `Dict[str, List[str]]`: A dictionary that maps column name to a
+          list of null value strings. Columns that are not in the dictionary,
+          or that map to an empty list, have no null value strings.
+
+          .. versionadded:: 1.40.0
 
     missing_utf8_is_empty_string
         By default a missing value is considered to be null; if you would prefer missing
@@ -1288,6 +1325,12 @@ def scan_csv(
             File cache is no longer supported.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    include_row_index_in_file
+        Include the position of each row within its source file as a column with
+        this name. Unlike the row index, the numbering starts at 0 for every file.
+    include_file_modification_time
+        Include the last modification time of the source file(s) as a column with
+        this name. The column has dtype `Datetime("ms", "UTC")`.
     missing_columns
         Configuration for behavior when columns defined in the schema are
         missing from the data:
@@ -1295,6 +1338,30 @@ def scan_csv(
         * ``"insert"``: Insert the missing columns with NULL values.
         * ``"raise"``: Raise an error.
 
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    schema_evolution
+        Policy used to reconcile files written under older schemas, such as renamed
+        columns, widened types and new columns with default values. See
+        :class:`SchemaEvolutionPolicy`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    listing_options
+        Options that control how the paths are expanded into the files to scan,
+        such as a limit on the number of files or a function that supplies the
+        files from a manifest. See :class:`FileListingOptions`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    statistics_sidecar
+        Path of a statistics sidecar file, written with
+        :meth:`LazyFrame.write_statistics_sidecar`. The per-file statistics in it
+        are used to skip files based on the predicates of the query.
+
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
@@ -1417,6 +1484,9 @@ def scan_csv(
         msg = "The `missing_columns` parameter of `scan_csv` is considered unstable."
         issue_unstable_warning(msg)
 
+    if statistics_sidecar is not None:
+        statistics_sidecar = normalize_filepath(statistics_sidecar)
+
     credential_provider_builder = _init_credential_provider_builder(
         credential_provider, source, storage_options, "scan_csv"
     )
@@ -1455,6 +1525,11 @@ def scan_csv(
         credential_provider=credential_provider_builder,
         include_file_paths=include_file_paths,
         missing_columns=missing_columns,
+        schema_evolution=schema_evolution,
+        listing_options=listing_options,
+        statistics_sidecar=statistics_sidecar,
+        include_row_index_in_file=include_row_index_in_file,
+        include_file_modification_time=include_file_modification_time,
     )
 
 
@@ -1477,7 +1552,11 @@ def _scan_csv_impl(
     skip_lines: int = 0,
     schema: SchemaDict | None = None,
     schema_overrides: SchemaDict | None = None,
-    null_values: str | Sequence[str] | dict[str, str] | None = None,
+    null_values: str
+    | Sequence[str]
+    | dict[str, str]
+    | dict[str, Sequence[str]]
+    | None = None,
     missing_utf8_is_empty_string: bool = False,
     ignore_errors: bool = False,
     cache: bool = True,
@@ -1499,7 +1578,12 @@ def _scan_csv_impl(
     storage_options: StorageOptionsDict | None = None,
     credential_provider: CredentialProviderBuilder | None = None,
     include_file_paths: str | None = None,
+    include_row_index_in_file: str | None = None,
+    include_file_modification_time: str | None = None,
     missing_columns: Literal["insert", "raise"] | None = None,
+    schema_evolution: SchemaEvolutionPolicy | None = None,
+    listing_options: FileListingOptions | None = None,
+    statistics_sidecar: str | Path | None = None,
 ) -> LazyFrame:
     dtype_list: list[tuple[str, PolarsDataType]] | None = None
     if schema_overrides is not None:
@@ -1556,5 +1640,10 @@ def _scan_csv_impl(
         credential_provider=credential_provider,
         include_file_paths=include_file_paths,
         missing_columns=missing_columns,
+        schema_evolution=schema_evolution,
+        listing_options=listing_options,
+        statistics_sidecar=statistics_sidecar,
+        include_row_index_in_file=include_row_index_in_file,
+        include_file_modification_time=include_file_modification_time,
     )
     return wrap_ldf(pylf)
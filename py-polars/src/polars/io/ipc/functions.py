@@ -40,6 +40,7 @@ if TYPE_CHECKING:
     from polars import DataFrame, DataType, LazyFrame
     from polars._typing import SchemaDict, StorageOptionsDict
     from polars.io.cloud import CredentialProviderFunction
+    from polars.io.scan_options import FileListingOptions, SchemaEvolutionPolicy
 
 
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
@@ -394,6 +395,11 @@ def scan_ipc(
     hive_schema: SchemaDict | None = None,
     try_parse_hive_dates: bool = True,
     include_file_paths: str | None = None,
+    include_row_index_in_file: str | None = None,
+    include_file_modification_time: str | None = None,
+    schema_evolution: SchemaEvolutionPolicy | None = None,
+    listing_options: FileListingOptions | None = None,
+    statistics_sidecar: str | Path | None = None,
     _record_batch_statistics: bool = False,
 ) -> LazyFrame:
     """
@@ -480,6 +486,36 @@ def scan_ipc(
         Whether to try parsing hive values as date/datetime types.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    include_row_index_in_file
+        Include the position of each row within its source file as a column with
+        this name. Unlike the row index, the numbering starts at 0 for every file.
+    include_file_modification_time
+        Include the last modification time of the source file(s) as a column with
+        this name. The column has dtype `Datetime("ms", "UTC")`.
+    schema_evolution
+        Policy used to reconcile files written under older schemas, such as renamed
+        columns, widened types and new columns with default values. See
+        :class:`SchemaEvolutionPolicy`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    listing_options
+        Options that control how the paths are expanded into the files to scan,
+        such as a limit on the number of files or a function that supplies the
+        files from a manifest. See :class:`FileListingOptions`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    statistics_sidecar
+        Path of a statistics sidecar file, written with
+        :meth:`LazyFrame.write_statistics_sidecar`. The per-file statistics in it
+        are used to skip files based on the predicates of the query.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     """
     # Memory Mapping is now a no-op
     _ = memory_map
@@ -498,6 +534,9 @@ def scan_ipc(
         storage_options = storage_options or {}
         storage_options["file_cache_ttl"] = file_cache_ttl
 
+    if statistics_sidecar is not None:
+        statistics_sidecar = normalize_filepath(statistics_sidecar)
+
     credential_provider_builder = _init_credential_provider_builder(
         credential_provider, sources, storage_options, "scan_parquet"
     )
@@ -514,6 +553,11 @@ def scan_ipc(
             ),
             pre_slice=(0, n_rows) if n_rows is not None else None,
             include_file_paths=include_file_paths,
+            include_row_index_in_file=include_row_index_in_file,
+            include_file_modification_time=include_file_modification_time,
+            schema_evolution=schema_evolution,
+            listing_options=listing_options,
+            statistics_sidecar=statistics_sidecar,
             glob=glob,
             hive_partitioning=hive_partitioning,
             hive_schema=hive_schema,
@@ -23,6 +23,7 @@ if TYPE_CHECKING:
     from polars import DataFrame, LazyFrame
     from polars._typing import SchemaDefinition, StorageOptionsDict
     from polars.io.cloud import CredentialProviderFunction
+    from polars.io.scan_options import FileListingOptions, SchemaEvolutionPolicy
 
 
 def read_ndjson(
@@ -217,6 +218,11 @@ def scan_ndjson(
     retries: int | None = None,
     file_cache_ttl: int | None = None,
     include_file_paths: str | None = None,
+    include_row_index_in_file: str | None = None,
+    include_file_modification_time: str | None = None,
+    schema_evolution: SchemaEvolutionPolicy | None = None,
+    listing_options: FileListingOptions | None = None,
+    statistics_sidecar: str | Path | None = None,
 ) -> LazyFrame:
     """
     Lazily read from a newline delimited JSON file or multiple files via glob patterns.
@@ -299,6 +305,36 @@ def scan_ndjson(
             File cache is no longer supported.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    include_row_index_in_file
+        Include the position of each row within its source file as a column with
+        this name. Unlike the row index, the numbering starts at 0 for every file.
+    include_file_modification_time
+        Include the last modification time of the source file(s) as a column with
+        this name. The column has dtype `Datetime("ms", "UTC")`.
+    schema_evolution
+        Policy used to reconcile files written under older schemas. Only type
+        widening and dtype overrides are supported for NDJSON. See
+        :class:`SchemaEvolutionPolicy`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    listing_options
+        Options that control how the paths are expanded into the files to scan,
+        such as a limit on the number of files or a function that supplies the
+        files from a manifest. See :class:`FileListingOptions`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    statistics_sidecar
+        Path of a statistics sidecar file, written with
+        :meth:`LazyFrame.write_statistics_sidecar`. The per-file statistics in it
+        are used to skip files based on the predicates of the query.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     """
     sources: list[str] | list[Path] | list[IO[str]] | list[IO[bytes]] = []
     if isinstance(source, (str, Path)):
@@ -328,6 +364,9 @@ def scan_ndjson(
         msg = "file cache is no longer supported as of 1.39.0."
         issue_deprecation_warning(msg)
 
+    if statistics_sidecar is not None:
+        statistics_sidecar = normalize_filepath(statistics_sidecar)
+
     credential_provider_builder = _init_credential_provider_builder(
         credential_provider, source, storage_options, "scan_ndjson"
     )
@@ -349,5 +388,10 @@ def scan_ndjson(
         include_file_paths=include_file_paths,
         cloud_options=storage_options,
         credential_provider=credential_provider_builder,
+        schema_evolution=schema_evolution,
+        listing_options=listing_options,
+        statistics_sidecar=statistics_sidecar,
+        include_row_index_in_file=include_row_index_in_file,
+        include_file_modification_time=include_file_modification_time,
     )
     return wrap_ldf(pylf)
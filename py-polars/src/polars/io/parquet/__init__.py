@@ -2,6 +2,7 @@ from polars.io.parquet.functions import (
     read_parquet,
     read_parquet_metadata,
     read_parquet_schema,
+    read_parquet_statistics,
     scan_parquet,
 )
 
@@ -9,5 +10,6 @@ __all__ = [
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
+    "read_parquet_statistics",
     "scan_parquet",
 ]
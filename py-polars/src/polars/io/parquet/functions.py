@@ -17,8 +17,9 @@ from polars._utils.various import (
     is_int_sequence,
     normalize_filepath,
 )
-from polars._utils.wrap import wrap_ldf
+from polars._utils.wrap import wrap_df, wrap_ldf
 from polars.convert import from_arrow
+from polars.datatypes import String
 from polars.io._utils import (
     get_sources,
     prepare_file_arg,
@@ -31,9 +32,10 @@ from polars.io.scan_options._options import ScanOptions
 with contextlib.suppress(ImportError):
     from polars._plr import PyLazyFrame
     from polars._plr import read_parquet_metadata as _read_parquet_metadata
+    from polars._plr import read_parquet_statistics as _read_parquet_statistics
 
 if TYPE_CHECKING:
-    from collections.abc import Sequence
+    from collections.abc import Mapping, Sequence
     from typing import Literal
 
     from polars import DataFrame, DataType, LazyFrame
@@ -43,11 +45,17 @@ if TYPE_CHECKING:
         DeletionFiles,
         FileSource,
         ParallelStrategy,
+        ParquetTimestampOverflow,
         SchemaDict,
         StorageOptionsDict,
+        TimeUnit,
     )
     from polars.io.cloud import CredentialProviderFunction
-    from polars.io.scan_options import ScanCastOptions
+    from polars.io.scan_options import (
+        FileListingOptions,
+        ScanCastOptions,
+        SchemaEvolutionPolicy,
+    )
 
 
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
@@ -77,6 +85,12 @@ def read_parquet(
     include_file_paths: str | None = None,
     missing_columns: Literal["insert", "raise"] = "raise",
     allow_missing_columns: bool | None = None,
+    int96_time_unit: TimeUnit = "ns",
+    int96_time_zone: str | None = None,
+    int96_overflow: ParquetTimestampOverflow | None = None,
+    int64_timestamp_overflow: ParquetTimestampOverflow | None = None,
+    uuid_as_string: bool = False,
+    categorical_columns: Sequence[str] | None = None,
 ) -> DataFrame:
     """
     Read into a DataFrame from a parquet file.
@@ -199,6 +213,45 @@ def read_parquet(
         .. deprecated:: 1.30.0
             Use the parameter `missing_columns` instead and pass one of
             `('insert', 'raise')`.
+    int96_time_unit : {'ns', 'us', 'ms'}
+        Time unit of the Datetime columns that INT96 timestamps are read as. INT96
+        timestamps are written by legacy Spark and Hive writers. Dates before 1677 or
+        after 2262 do not fit in a nanosecond resolution and need a coarser time unit.
+    int96_time_zone
+        Time zone of the Datetime columns that INT96 timestamps are read as. INT96
+        timestamps are instants in UTC, so this only sets the zone they are
+        displayed in, as Spark and DataFusion do when coercing them. Defaults to no
+        time zone.
+    int96_overflow : {None, 'raise', 'saturate', 'null'}
+        What to do with INT96 timestamps that do not fit in `int96_time_unit`:
+
+        * `None`: Does not check the range, so these timestamps overflow.
+        * `raise`: Raises an error.
+        * `saturate`: Clamps them to the smallest or largest representable datetime.
+        * `null`: Replaces them by null.
+    int64_timestamp_overflow : {None, 'raise', 'saturate', 'null'}
+        What to do with INT64 timestamps that do not fit in the time unit they are
+        read as, which happens when they are read with a finer time unit than they
+        were written with, e.g. when the stored Arrow schema has a nanosecond
+        resolution but the values were written as milliseconds. The options are the
+        same as for `int96_overflow`.
+    uuid_as_string
+        Read columns with the Parquet UUID logical type as String columns holding
+        the canonical hyphenated form, e.g. `"123e4567-e89b-12d3-a456-426614174000"`,
+        instead of as 16-byte Binary columns.
+
+        .. versionadded:: 1.40.0
+    categorical_columns
+        String columns that are read as Categorical columns. Their
+        dictionary-encoded pages are decoded into categories directly, without
+        materializing every string, which takes much less memory for columns with
+        few distinct values.
+
+        .. versionadded:: 1.40.0
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
     Returns
     -------
@@ -282,6 +335,12 @@ def read_parquet(
         glob=glob,
         include_file_paths=include_file_paths,
         missing_columns=missing_columns,
+        int96_time_unit=int96_time_unit,
+        int96_time_zone=int96_time_zone,
+        int96_overflow=int96_overflow,
+        int64_timestamp_overflow=int64_timestamp_overflow,
+        uuid_as_string=uuid_as_string,
+        categorical_columns=categorical_columns,
     )
 
     if columns is not None:
@@ -449,6 +508,95 @@ def read_parquet_metadata(
     )
 
 
+def read_parquet_statistics(
+    source: str | Path | IO[bytes] | bytes | Sequence[str | Path],
+    storage_options: StorageOptionsDict | None = None,
+    credential_provider: CredentialProviderFunction | Literal["auto"] | None = "auto",
+) -> DataFrame:
+    """
+    Get the statistics of every column chunk of Parquet files without reading data.
+
+    This shows how the data of the files is laid out, e.g. to check whether the row
+    groups can be skipped when filtering on a column.
+
+    .. warning::
+        This functionality is considered **experimental**. It may be removed or
+        changed at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    source
+        Path to a file, a list of paths or a file-like object (by "file-like object"
+        we refer to objects that have a `read()` method, such as a file handler like
+        the builtin `open` function, or a `BytesIO` instance). For file-like objects,
+        the stream position may not be updated accordingly after reading.
+    storage_options
+        Options that indicate how to connect to a cloud provider.
+
+        The cloud providers currently supported are AWS, GCP, and Azure.
+        See supported keys here:
+
+        * `aws <https://docs.rs/object_store/latest/object_store/aws/enum.AmazonS3ConfigKey.html>`_
+        * `gcp <https://docs.rs/object_store/latest/object_store/gcp/enum.GoogleConfigKey.html>`_
+        * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
+        * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
+          `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+
+        If `storage_options` is not provided, Polars will try to infer the information
+        from environment variables.
+    credential_provider
+        Provide a function that can be called to provide cloud storage
+        credentials. The function is expected to return a dictionary of
+        credential keys along with an optional credential expiry time.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+    Returns
+    -------
+    DataFrame
+        A row for every column chunk of every row group with the columns:
+
+        - "file": the path of the file, or null for file-like objects.
+        - "row_group": the index of the row group in the file.
+        - "column": the path of the (leaf) column, with the fields of nested
+          columns separated by dots.
+        - "num_rows": the number of rows in the row group.
+        - "null_count", "distinct_count", "min" and "max": the statistics of the
+          column chunk, with the min and max formatted as strings. These are null
+          if they are not in the file.
+        - "compressed_size" and "uncompressed_size": the size of the column chunk in
+          bytes.
+
+    Examples
+    --------
+    >>> pl.read_parquet_statistics("data.parquet")  # doctest: +SKIP
+    """
+    sources = list(source) if isinstance(source, (list, tuple)) else [source]
+
+    dfs = []
+    for src in sources:
+        file = None
+        if isinstance(src, (str, Path)):
+            src = file = normalize_filepath(src, check_not_directory=False)
+
+        credential_provider_builder = _init_credential_provider_builder(
+            credential_provider, src, storage_options, "scan_parquet"
+        )
+
+        df = wrap_df(
+            _read_parquet_statistics(
+                src,
+                storage_options=storage_options,
+                credential_provider=credential_provider_builder,
+            )
+        )
+        dfs.append(df.select(F.lit(file, dtype=String).alias("file"), F.all()))
+
+    return plconcat(dfs)
+
+
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
 @deprecate_renamed_parameter("row_count_offset", "row_index_offset", version="0.20.4")
 def scan_parquet(
@@ -472,10 +620,23 @@ def scan_parquet(
     credential_provider: CredentialProviderFunction | Literal["auto"] | None = "auto",
     retries: int | None = None,
     include_file_paths: str | None = None,
+    include_row_index_in_file: str | None = None,
+    include_row_group_index: str | None = None,
+    include_file_modification_time: str | None = None,
     missing_columns: Literal["insert", "raise"] = "raise",
     allow_missing_columns: bool | None = None,
     extra_columns: Literal["ignore", "raise"] = "raise",
     cast_options: ScanCastOptions | None = None,
+    schema_evolution: SchemaEvolutionPolicy | None = None,
+    listing_options: FileListingOptions | None = None,
+    statistics_sidecar: str | Path | None = None,
+    int96_time_unit: TimeUnit = "ns",
+    int96_time_zone: str | None = None,
+    int96_overflow: ParquetTimestampOverflow | None = None,
+    int64_timestamp_overflow: ParquetTimestampOverflow | None = None,
+    uuid_as_string: bool = False,
+    footers: str | Path | Mapping[str, bytes] | None = None,
+    categorical_columns: Sequence[str] | None = None,
     _column_mapping: ColumnMapping | None = None,
     _default_values: DefaultFieldValues | None = None,
     _deletion_files: DeletionFiles | None = None,
@@ -594,6 +755,15 @@ def scan_parquet(
             Pass {"max_retries": n} via `storage_options` instead.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    include_row_index_in_file
+        Include the position of each row within its source file as a column with
+        this name. Unlike the row index, the numbering starts at 0 for every file.
+    include_row_group_index
+        Include the index of the row group each row was read from as a column with
+        this name.
+    include_file_modification_time
+        Include the last modification time of the source file(s) as a column with
+        this name. The column has dtype `Datetime("ms", "UTC")`.
     missing_columns
         Configuration for behavior when columns defined in the schema
         are missing from the data:
@@ -622,6 +792,84 @@ def scan_parquet(
         Configuration for column type-casting during scans. Useful for datasets
         containing files that have differing schemas.
 
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    schema_evolution
+        Policy used to reconcile files written under older schemas, such as renamed
+        columns, widened types and new columns with default values. See
+        :class:`SchemaEvolutionPolicy`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    listing_options
+        Options that control how the paths are expanded into the files to scan,
+        such as a limit on the number of files or a function that supplies the
+        files from a manifest. See :class:`FileListingOptions`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    statistics_sidecar
+        Path of a statistics sidecar file, written with
+        :meth:`LazyFrame.write_statistics_sidecar`. The per-file statistics in it
+        are used to skip files based on the predicates of the query.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    int96_time_unit : {'ns', 'us', 'ms'}
+        Time unit of the Datetime columns that INT96 timestamps are read as. INT96
+        timestamps are written by legacy Spark and Hive writers. Dates before 1677 or
+        after 2262 do not fit in a nanosecond resolution and need a coarser time unit.
+    int96_time_zone
+        Time zone of the Datetime columns that INT96 timestamps are read as. INT96
+        timestamps are instants in UTC, so this only sets the zone they are
+        displayed in, as Spark and DataFusion do when coercing them. Defaults to no
+        time zone.
+    int96_overflow : {None, 'raise', 'saturate', 'null'}
+        What to do with INT96 timestamps that do not fit in `int96_time_unit`:
+
+        * `None`: Does not check the range, so these timestamps overflow.
+        * `raise`: Raises an error.
+        * `saturate`: Clamps them to the smallest or largest representable datetime.
+        * `null`: Replaces them by null.
+    int64_timestamp_overflow : {None, 'raise', 'saturate', 'null'}
+        What to do with INT64 timestamps that do not fit in the time unit they are
+        read as, which happens when they are read with a finer time unit than they
+        were written with, e.g. when the stored Arrow schema has a nanosecond
+        resolution but the values were written as milliseconds. The options are the
+        same as for `int96_overflow`.
+    uuid_as_string
+        Read columns with the Parquet UUID logical type as String columns holding
+        the canonical hyphenated form, e.g. `"123e4567-e89b-12d3-a456-426614174000"`,
+        instead of as 16-byte Binary columns.
+
+        .. versionadded:: 1.40.0
+    footers
+        Footers of the Parquet files that were read beforehand, which are used
+        instead of fetching the footers of these files. Either:
+
+        * The path of a `_metadata` summary file, as written by Spark, Dask or
+          PyArrow. The file paths in it are relative to its directory.
+        * A mapping from the path of a file to the bytes of its footer, i.e. the
+          end of the file starting at (or before) the serialized metadata.
+
+        The paths must match the paths of the scanned files after expansion.
+        Files without a footer are read as usual.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    categorical_columns
+        String columns that are read as Categorical columns. Their
+        dictionary-encoded pages are decoded into categories directly, without
+        materializing every string, which takes much less memory for columns with
+        few distinct values.
+
+        .. versionadded:: 1.40.0
+
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
@@ -680,6 +928,33 @@ def scan_parquet(
         storage_options = storage_options or {}
         storage_options["max_retries"] = retries
 
+    if statistics_sidecar is not None:
+        statistics_sidecar = normalize_filepath(statistics_sidecar)
+
+    footer_summary = None
+    footer_bytes = None
+    if footers is not None:
+        msg = "The `footers` parameter of `scan_parquet` is considered unstable."
+        issue_unstable_warning(msg)
+
+        if isinstance(footers, (str, Path)):
+            footer_summary = normalize_filepath(footers)
+        else:
+            footer_bytes = [
+                (normalize_filepath(path, check_not_directory=False), footer)
+                for path, footer in footers.items()
+            ]
+
+    if categorical_columns is not None:
+        msg = (
+            "The `categorical_columns` parameter of `scan_parquet` is considered "
+            "unstable."
+        )
+        issue_unstable_warning(msg)
+        if isinstance(categorical_columns, str):
+            categorical_columns = [categorical_columns]
+        categorical_columns = list(categorical_columns)
+
     sources = get_sources(source)
 
     credential_provider_builder = _init_credential_provider_builder(
@@ -694,6 +969,14 @@ def scan_parquet(
         parallel=parallel,
         low_memory=low_memory,
         use_statistics=use_statistics,
+        int96_time_unit=int96_time_unit,
+        int96_time_zone=int96_time_zone,
+        int96_overflow=int96_overflow,
+        int64_timestamp_overflow=int64_timestamp_overflow,
+        uuid_as_string=uuid_as_string,
+        footer_summary=footer_summary,
+        footers=footer_bytes,
+        categorical_columns=categorical_columns,
         scan_options=ScanOptions(
             row_index=(
                 (row_index_name, row_index_offset)
@@ -705,6 +988,12 @@ def scan_parquet(
             extra_columns=extra_columns,
             missing_columns=missing_columns,
             include_file_paths=include_file_paths,
+            include_row_index_in_file=include_row_index_in_file,
+            include_row_group_index=include_row_group_index,
+            include_file_modification_time=include_file_modification_time,
+            schema_evolution=schema_evolution,
+            listing_options=listing_options,
+            statistics_sidecar=statistics_sidecar,
             glob=glob,
             hidden_file_prefix=(
                 [hidden_file_prefix]
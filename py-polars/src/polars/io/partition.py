@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import os
 from collections.abc import Mapping
 from dataclasses import dataclass
 from typing import TYPE_CHECKING, ClassVar, Literal
@@ -148,6 +149,40 @@ class FileProviderArgs:
     partition_keys: DataFrame
 
 
+@dataclass(kw_only=True)
+class _IndexedFilePathProvider:
+    """
+    Provides the file paths of a sink that rotates files by size.
+
+    For internal use. The index of the file is appended to the file name, e.g.
+    `data.csv` becomes `data_0.csv`, `data_1.csv`, etc.
+    """
+
+    stem: str
+    suffix: str
+
+    def __call__(self, args: FileProviderArgs) -> str:
+        return f"{self.stem}_{args.index_in_partition}{self.suffix}"
+
+
+def _rotating_file_target(
+    path: str,
+    *,
+    max_rows_per_file: int | None,
+    approximate_bytes_per_file: int | None,
+) -> PartitionBy:
+    """Write to files next to `path` that are rotated when they reach a size."""
+    base_path, file_name = os.path.split(path)
+    stem, suffix = os.path.splitext(file_name)  # noqa: PTH122
+
+    return PartitionBy(
+        base_path or ".",
+        file_path_provider=_IndexedFilePathProvider(stem=stem, suffix=suffix),
+        max_rows_per_file=max_rows_per_file,
+        approximate_bytes_per_file=approximate_bytes_per_file,
+    )
+
+
 @dataclass(kw_only=True)
 class _PartitionByInner:
     """
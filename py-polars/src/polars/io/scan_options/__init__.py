@@ -1,5 +1,9 @@
 from polars.io.scan_options.cast_options import ScanCastOptions
+from polars.io.scan_options.listing import FileListingOptions
+from polars.io.scan_options.schema_evolution import SchemaEvolutionPolicy
 
 __all__ = [
+    "FileListingOptions",
     "ScanCastOptions",
+    "SchemaEvolutionPolicy",
 ]
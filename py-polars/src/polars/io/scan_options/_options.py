@@ -4,6 +4,7 @@ from typing import TYPE_CHECKING, Literal
 
 if TYPE_CHECKING:
     from collections.abc import Sequence
+    from pathlib import Path
 
     from polars._typing import (
         ColumnMapping,
@@ -15,6 +16,8 @@ if TYPE_CHECKING:
     from polars.dataframe.frame import DataFrame
     from polars.io.cloud.credential_provider._builder import CredentialProviderBuilder
     from polars.io.scan_options.cast_options import ScanCastOptions
+    from polars.io.scan_options.listing import FileListingOptions
+    from polars.io.scan_options.schema_evolution import SchemaEvolutionPolicy
 
 from dataclasses import dataclass
 
@@ -34,10 +37,15 @@ class ScanOptions:
     extra_columns: Literal["ignore", "raise"] = "raise"
     missing_columns: Literal["insert", "raise"] = "raise"
     include_file_paths: str | None = None
+    include_row_index_in_file: str | None = None
+    include_row_group_index: str | None = None
+    include_file_modification_time: str | None = None
+    schema_evolution: SchemaEvolutionPolicy | None = None
 
     # For path expansion
     glob: bool = True
     hidden_file_prefix: Sequence[str] | None = None
+    listing_options: FileListingOptions | None = None
 
     # Hive
     # Note: `None` means auto.
@@ -56,5 +64,6 @@ class ScanOptions:
     default_values: DefaultFieldValues | None = None
     deletion_files: DeletionFiles | None = None
     table_statistics: DataFrame | None = None
+    statistics_sidecar: str | Path | None = None
     # (physical, deleted)
     row_count: tuple[int, int] | None = None
@@ -0,0 +1,76 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import issue_unstable_warning
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+
+class FileListingOptions:
+    """Controls how the paths of a scan are expanded into the files to scan."""
+
+    def __init__(
+        self,
+        *,
+        max_files: int | None = None,
+        listing_concurrency: int | None = None,
+        file_name_filter: Callable[[str], bool] | None = None,
+        lister: Callable[[list[str]], list[str]] | None = None,
+    ) -> None:
+        """
+        Control the expansion of directories and glob patterns into files.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        max_files
+            Raise an error if the paths expand to more files than this.
+        listing_concurrency
+            Maximum number of paths that are listed concurrently on an object
+            store. Paths are listed one at a time by default.
+        file_name_filter
+            Function that is called with the file name of every listed file. Files
+            for which it returns `False` are not scanned.
+        lister
+            Function that is called with the paths given to the scan and returns
+            the files to scan. This is used in place of listing the file system or
+            object store, e.g. to scan the files of a manifest from a catalog.
+            Glob patterns are not expanded and hidden files are not skipped for the
+            returned files.
+
+        Examples
+        --------
+        >>> def lister(paths: list[str]) -> list[str]:
+        ...     return [f"s3://bucket/data/{i}.parquet" for i in range(10)]
+        >>> pl.scan_parquet(
+        ...     "s3://bucket/data/",
+        ...     listing_options=pl.FileListingOptions(lister=lister),
+        ... )  # doctest: +SKIP
+        """
+        issue_unstable_warning("FileListingOptions is considered unstable.")
+
+        if max_files is not None and max_files < 0:
+            msg = f"`max_files` must be non-negative, got {max_files}"
+            raise ValueError(msg)
+
+        if listing_concurrency is not None and listing_concurrency < 1:
+            msg = f"`listing_concurrency` must be positive, got {listing_concurrency}"
+            raise ValueError(msg)
+
+        self.max_files = max_files
+        self.listing_concurrency = listing_concurrency
+        self.file_name_filter = file_name_filter
+        self.lister = lister
+
+    def __repr__(self) -> str:
+        return (
+            f"FileListingOptions(max_files={self.max_files!r}, "
+            f"listing_concurrency={self.listing_concurrency!r}, "
+            f"file_name_filter={self.file_name_filter!r}, "
+            f"lister={self.lister!r})"
+        )
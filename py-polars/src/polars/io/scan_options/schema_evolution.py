@@ -0,0 +1,100 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any, Literal
+
+from polars._utils.unstable import issue_unstable_warning
+
+if TYPE_CHECKING:
+    from collections.abc import Collection, Mapping
+    from typing import TypeAlias
+
+    from polars._typing import SchemaDict
+    from polars.dataframe.frame import DataFrame
+
+
+TypeWideningOption: TypeAlias = Literal[
+    "integer", "integer-to-float", "float", "categorical-to-string"
+]
+
+
+class SchemaEvolutionPolicy:
+    """Describes how the schema of a collection of files changed over time."""
+
+    def __init__(
+        self,
+        *,
+        renames: Mapping[str, str] | None = None,
+        type_widening: Literal["forbid", "all"]
+        | TypeWideningOption
+        | Collection[TypeWideningOption] = "forbid",
+        dtypes: SchemaDict | None = None,
+        defaults: Mapping[str, Any] | None = None,
+    ) -> None:
+        """
+        Reconcile files written under different schemas when scanning them together.
+
+        The target schema is the schema passed to the scan function, or otherwise
+        the schema of the first file. The policy is applied on top of that schema,
+        and to every file that is read.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        renames
+            Mapping of former column names to their current names. A file that
+            does not contain the current name is read from the first former name
+            it does contain.
+        type_widening
+            Lossless casts that are allowed from the type in the file to the target
+            type:
+
+            * `integer`: Cast to wider integer types.
+            * `integer-to-float`: Cast integers to float types.
+            * `float`: Cast to higher precision float types.
+            * `categorical-to-string`: Cast categoricals to strings.
+            * `all`: All of the above.
+            * `forbid`: Raises an error if types do not match.
+        dtypes
+            Target types for columns, overriding the type in the schema. This can be
+            used to widen a column that is narrower in the first file than in the
+            later ones.
+        defaults
+            Values used for columns that are missing from a file, instead of
+            NULLs. Columns that are not in the schema are added to it.
+
+        Notes
+        -----
+        Renames and defaults are not supported for NDJSON scans, as missing fields
+        cannot be distinguished from NULLs there.
+
+        Examples
+        --------
+        >>> policy = pl.SchemaEvolutionPolicy(
+        ...     renames={"temp": "temperature"},
+        ...     type_widening="integer",
+        ...     defaults={"unit": "celsius"},
+        ... )  # doctest: +SKIP
+        >>> pl.scan_parquet("data/*.parquet", schema_evolution=policy)  # doctest: +SKIP
+        """
+        issue_unstable_warning("SchemaEvolutionPolicy is considered unstable.")
+
+        self.renames = dict(renames) if renames is not None else {}
+        self.type_widening = type_widening
+        self.dtypes = dict(dtypes) if dtypes is not None else {}
+        self.defaults = dict(defaults) if defaults is not None else {}
+
+    def _defaults_frame(self) -> DataFrame:
+        """Single-row frame holding the default values, used by the Rust side."""
+        from polars.dataframe.frame import DataFrame
+
+        return DataFrame({name: [value] for name, value in self.defaults.items()})
+
+    def __repr__(self) -> str:
+        return (
+            f"SchemaEvolutionPolicy(renames={self.renames!r}, "
+            f"type_widening={self.type_widening!r}, "
+            f"dtypes={self.dtypes!r}, defaults={self.defaults!r})"
+        )
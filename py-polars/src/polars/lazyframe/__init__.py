@@ -1,4 +1,4 @@
-from polars.lazyframe.engine_config import GPUEngine
+from polars.lazyframe.engine_config import GPUEngine, StreamingEngine
 from polars.lazyframe.frame import LazyFrame
 from polars.lazyframe.opt_flags import QueryOptFlags
 
@@ -6,4 +6,5 @@ __all__ = [
     "GPUEngine",
     "LazyFrame",
     "QueryOptFlags",
+    "StreamingEngine",
 ]
@@ -59,3 +59,45 @@ class GPUEngine:
         # Avoids need for changes in cudf-polars
         kwargs["raise_on_fail"] = raise_on_fail
         self.config = kwargs
+
+
+class StreamingEngine:
+    """
+    Configuration options for the streaming engine.
+
+    Use this if you want control over how the streaming engine runs a query.
+    Options that are not provided are taken from the environment variables
+    `POLARS_IDEAL_MORSEL_SIZE` and `POLARS_ADAPTIVE_MORSEL_SIZE`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    morsel_size : int, default None
+        The number of rows of the morsels, the chunks of data the streaming
+        engine processes at a time. With adaptive sizing this is the initial
+        number of rows.
+    adaptive_morsel_size : bool, default None
+        Grow the morsels while they are processed quickly, and shrink them
+        when they are processed slowly, hold many bytes, or memory is running
+        low.
+    """
+
+    morsel_size: int | None
+    """Number of rows of the morsels."""
+    adaptive_morsel_size: bool | None
+    """Whether the size of the morsels adapts while the query runs."""
+
+    def __init__(
+        self,
+        *,
+        morsel_size: int | None = None,
+        adaptive_morsel_size: bool | None = None,
+    ) -> None:
+        if morsel_size is not None and morsel_size <= 0:
+            msg = f"`morsel_size` must be positive, got {morsel_size}"
+            raise ValueError(msg)
+        self.morsel_size = morsel_size
+        self.adaptive_morsel_size = adaptive_morsel_size
@@ -96,7 +96,7 @@ from polars.datatypes import (
 from polars.datatypes.group import DataTypeGroup
 from polars.exceptions import InvalidOperationError, PerformanceWarning
 from polars.interchange.protocol import CompatLevel
-from polars.lazyframe.engine_config import GPUEngine
+from polars.lazyframe.engine_config import GPUEngine, StreamingEngine
 from polars.lazyframe.group_by import LazyGroupBy
 from polars.lazyframe.in_process import InProcessQuery
 from polars.lazyframe.opt_flags import DEFAULT_QUERY_OPT_FLAGS, forward_old_opt_flags
@@ -135,6 +135,7 @@ if TYPE_CHECKING:
         AsofJoinStrategy,
         ClosedInterval,
         ColumnNameOrSelector,
+        CsvColumnOptions,
         CsvQuoteStyle,
         EngineType,
         ExplainFormat,
@@ -146,9 +147,12 @@ if TYPE_CHECKING:
         IpcCompression,
         JoinStrategy,
         JoinValidation,
+        JsonTemporalFormat,
         Label,
         MaintainOrderJoin,
+        NonFiniteFloats,
         Orientation,
+        ParquetColumnOptions,
         ParquetMetadata,
         PivotAgg,
         PlanStage,
@@ -1430,6 +1434,38 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         else:
             return self._ldf.describe_plan()
 
+    @unstable()
+    def explain_analyze(
+        self, *, optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS
+    ) -> str:
+        """
+        Run the query and show the optimized query plan with the rows of every node.
+
+        The plan is shown as a tree, like `explain(format="tree")`, with the actual
+        number of rows that every node output next to its estimated number of rows.
+        Nodes whose number of rows differs from the estimate by more than a factor 10
+        are flagged with `(!)`, which points at misestimates. The query is run on
+        the in-memory engine and its result is discarded.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        optimizations
+            The optimization passes done during query optimization.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3, 4], "b": [5, 6, 7, 8]})
+        >>> print(lf.filter(pl.col("a") > 3).explain_analyze())  # doctest: +SKIP
+        """
+        ldf = self._ldf.with_optimizations(optimizations._pyoptflags)
+        return ldf.explain_analyze()
+
     @deprecate_streaming_parameter()
     @forward_old_opt_flags()
     def show_graph(
@@ -1624,6 +1660,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         nulls_last: bool | Sequence[bool] = False,
         maintain_order: bool = False,
         multithreaded: bool = True,
+        collation: str | None = None,
+        natural: bool | Sequence[bool] = False,
     ) -> LazyFrame:
         """
         Sort the LazyFrame by the given columns.
@@ -1647,6 +1685,23 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             worse since this requires a stable search.
         multithreaded
             Sort using multiple threads.
+        collation
+            Sort strings with the locale-aware collation of this BCP 47 language
+            tag, such as `"de"` or `"sv"`, instead of byte-wise. All columns to sort
+            by must be of type String. See :meth:`Expr.str.collation_key`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        natural
+            Sort strings in natural order, comparing runs of digits by their numeric
+            value so that `"file2"` sorts before `"file10"`. Can be specified per
+            column by passing a sequence of booleans; the columns sorted naturally
+            must be of type String. See :meth:`Expr.str.natural_sort_key`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Examples
         --------
@@ -1719,6 +1774,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             and not more_by
             and isinstance(descending, bool)
             and isinstance(nulls_last, bool)
+            and collation is None
+            and not natural
         ):
             return self._from_pyldf(
                 self._ldf.sort(
@@ -1727,6 +1784,13 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             )
 
         by = parse_into_list_of_expressions(by, *more_by)
+        natural = extend_bool(natural, len(by), "natural", "by")
+        if collation is not None:
+            if any(natural):
+                msg = "cannot combine `collation` with `natural` sorting"
+                raise ValueError(msg)
+            by = [e.str_collation_key(collation, "tertiary") for e in by]
+        by = [e.str_natural_sort_key() if nat else e for e, nat in zip(by, natural)]
         descending = extend_bool(descending, len(by), "descending", "by")
         nulls_last = extend_bool(nulls_last, len(by), "nulls_last", "by")
 
@@ -2206,7 +2270,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         cluster_with_columns: bool = True,
         collapse_joins: bool = True,
         no_optimization: bool = False,
-        engine: EngineType = "auto",
+        engine: EngineType | StreamingEngine = "auto",
         background: Literal[True],
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
     ) -> InProcessQuery: ...
@@ -2225,7 +2289,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         cluster_with_columns: bool = True,
         collapse_joins: bool = True,
         no_optimization: bool = False,
-        engine: EngineType = "auto",
+        engine: EngineType | StreamingEngine = "auto",
         background: Literal[False] = False,
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
     ) -> DataFrame: ...
@@ -2245,7 +2309,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         cluster_with_columns: bool = True,  # noqa: ARG002
         collapse_joins: bool = True,  # noqa: ARG002
         no_optimization: bool = False,  # noqa: ARG002
-        engine: EngineType = "auto",
+        engine: EngineType | StreamingEngine = "auto",
         background: bool = False,
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
         **_kwargs: Any,
@@ -2331,6 +2395,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             .. note::
                The GPU engine does not support streaming, or running in the
                background. If either are enabled, then GPU execution is switched off.
+
+            A :class:`~.StreamingEngine` object runs the query on the streaming
+            engine with the given configuration options, such as the size of
+            the morsels.
         background
             Run the query in the background and get a handle to the query.
             This handle can be used to fetch the result or cancel the query.
@@ -2431,6 +2499,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 error_msg = f"collect() got an unexpected keyword argument '{k}'"
                 raise TypeError(error_msg)
 
+        streaming_engine = None
+        if isinstance(engine, StreamingEngine):
+            streaming_engine, engine = engine, "streaming"
+
         engine = _select_engine(engine)
 
         new_streaming = (
@@ -2461,6 +2533,15 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         # Only for testing purposes
         callback = _kwargs.get("post_opt_callback", callback)
+        if streaming_engine is not None and engine == "streaming":
+            return wrap_df(
+                ldf.collect(
+                    engine,
+                    callback,
+                    morsel_size=streaming_engine.morsel_size,
+                    adaptive_morsel_size=streaming_engine.adaptive_morsel_size,
+                )
+            )
         return wrap_df(ldf.collect(engine, callback))
 
     @overload
@@ -2621,6 +2702,186 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         """
         return Schema(self._ldf.collect_schema(), check_dtypes=False)
 
+    @unstable()
+    def collect_field_metadata(self) -> dict[str, dict[str, str]]:
+        """
+        Resolve the key/value metadata of the output columns without running the query.
+
+        The metadata of the columns is resolved from the optimized query plan. A
+        column reference keeps the metadata of its input column whatever its output
+        name is, and :meth:`Expr.with_field_metadata` replaces it. Casts, filters,
+        sorts, slices and aggregations that stay in the domain of their input keep
+        the metadata, other expressions drop it. Joins keep the metadata of both
+        sides and vertical concatenations keep the metadata that all inputs agree on.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Returns
+        -------
+        dict
+            Mapping from the names of the columns that have metadata to their
+            metadata.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"speed": [1.0, 2.5], "n": [1, 2]})
+        >>> lf = lf.with_columns(pl.col("speed").with_field_metadata({"unit": "m/s"}))
+        >>> lf.select(pl.col("speed").mean(), "n").collect_field_metadata()
+        {'speed': {'unit': 'm/s'}}
+        >>> lf.select(pl.col("speed").count()).collect_field_metadata()
+        {}
+        """
+        return self._ldf.collect_field_metadata()
+
+    @unstable()
+    def collect_metadata(self) -> dict[str, str]:
+        """
+        Read the key-value metadata of the Parquet files the query scans.
+
+        The metadata is read from the footer of the first file of the first Parquet
+        scan of the query, without running the query. It holds all key-value pairs
+        of the footer, including the Arrow and pandas schemas that writers store in
+        it. Use the `metadata` parameter of :meth:`DataFrame.write_parquet` to write
+        custom key-value pairs.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Returns
+        -------
+        dict
+            Mapping from the keys of the metadata to their values.
+
+        See Also
+        --------
+        polars.read_parquet_metadata
+
+        Examples
+        --------
+        >>> import io
+        >>> f = io.BytesIO()
+        >>> pl.DataFrame({"a": [1, 2, 3]}).write_parquet(f, metadata={"source": "crm"})
+        >>> _ = f.seek(0)
+        >>> pl.scan_parquet(f).collect_metadata()["source"]
+        'crm'
+        """
+        return self._ldf.collect_metadata()
+
+    @unstable()
+    def scan_pruning_report(self) -> DataFrame:
+        """
+        Report which files and row groups are skipped by the scans of the query.
+
+        The query is optimized but not run. For every file of every scan, the report
+        shows whether the predicate pushed into the scan allows skipping the whole
+        file and why, and for Parquet files which row groups can be skipped based
+        on their statistics. This helps to verify that a partitioning scheme
+        actually benefits the queries that are run against it.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Returns
+        -------
+        DataFrame
+            A row per file, with the columns:
+
+            * `scan`: Index of the scan in the query plan.
+            * `path`: Path of the file.
+            * `pruned`: Whether the whole file is skipped.
+            * `reason`: Why the file is skipped, `"hive partitions"` or
+              `"table statistics"`.
+            * `row_groups`: Number of row groups, for Parquet files that are not
+              skipped.
+            * `pruned_row_groups`: Indices of the row groups that are skipped.
+            * `predicate`: The predicate pushed into the scan.
+
+        Examples
+        --------
+        >>> lf = pl.scan_parquet("data/")  # doctest: +SKIP
+        >>> lf.filter(pl.col("year") == 2024).scan_pruning_report().select(
+        ...     "path", "pruned", "reason"
+        ... )  # doctest: +SKIP
+        shape: (2, 3)
+        ┌──────────────────────────┬────────┬─────────────────┐
+        │ path                     ┆ pruned ┆ reason          │
+        │ ---                      ┆ ---    ┆ ---             │
+        │ str                      ┆ bool   ┆ str             │
+        ╞══════════════════════════╪════════╪═════════════════╡
+        │ data/year=2023/0.parquet ┆ true   ┆ hive partitions │
+        │ data/year=2024/0.parquet ┆ false  ┆ null            │
+        └──────────────────────────┴────────┴─────────────────┘
+        """
+        return wrap_df(self._ldf.scan_pruning_report())
+
+    @unstable()
+    def write_statistics_sidecar(
+        self,
+        path: str | Path,
+        *,
+        storage_options: StorageOptionsDict | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+    ) -> None:
+        """
+        Write a statistics sidecar file for the files of this scan.
+
+        The sidecar holds, for every file of the scan, its number of rows and the
+        minimum, maximum, null count and number of distinct values of every column.
+        Passing it as `statistics_sidecar` to a later scan of the same files allows
+        skipping files based on the predicates of the query, which is useful for
+        formats that do not store statistics themselves, such as CSV and NDJSON.
+
+        The files are read in full to compute the statistics. The paths of the files
+        under the directory of the sidecar are stored relative to it. Conventionally,
+        the sidecar is named `.polars_stats` and placed in the directory of the
+        dataset, in which case the dataset should be scanned with a glob pattern
+        (e.g. `data/*.csv`) so that the sidecar is not read as one of its files.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        path
+            Path to write the sidecar to.
+        storage_options
+            Options that indicate how to connect to a cloud provider.
+        credential_provider
+            Provide a function that can be called to provide cloud storage
+            credentials. The function is expected to return a dictionary of
+            credential keys along with an optional credential expiry time.
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("data/*.csv")  # doctest: +SKIP
+        >>> lf.write_statistics_sidecar("data/.polars_stats")  # doctest: +SKIP
+        >>> pl.scan_csv(
+        ...     "data/*.csv", statistics_sidecar="data/.polars_stats"
+        ... ).filter(pl.col("a") > 10).collect()  # doctest: +SKIP
+        """
+        path = normalize_filepath(path)
+
+        wrap_df(self._ldf.collect_statistics_sidecar(path)).write_ipc(
+            path,
+            storage_options=storage_options,
+            credential_provider=credential_provider,
+        )
+
     @overload
     def sink_parquet(
         self,
@@ -2631,6 +2892,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        data_page_row_limit: int | None = None,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -2643,6 +2905,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         engine: EngineType = "auto",
         metadata: ParquetMetadata | None = None,
         arrow_schema: ArrowSchemaExportable | None = None,
+        uuid_columns: Sequence[str] | None = None,
+        column_options: dict[str, ParquetColumnOptions] | None = None,
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
     ) -> None: ...
 
@@ -2656,6 +2920,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        data_page_row_limit: int | None = None,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -2668,6 +2933,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         engine: EngineType = "auto",
         metadata: ParquetMetadata | None = None,
         arrow_schema: ArrowSchemaExportable | None = None,
+        uuid_columns: Sequence[str] | None = None,
+        column_options: dict[str, ParquetColumnOptions] | None = None,
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
     ) -> LazyFrame: ...
 
@@ -2680,6 +2947,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        data_page_row_limit: int | None = None,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -2689,6 +2957,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         sync_on_close: SyncOnCloseMethod | None = None,
         metadata: ParquetMetadata | None = None,
         arrow_schema: ArrowSchemaExportable | None = None,
+        uuid_columns: Sequence[str] | None = None,
+        column_options: dict[str, ParquetColumnOptions] | None = None,
         mkdir: bool = False,
         lazy: bool = False,
         engine: EngineType = "auto",
@@ -2728,7 +2998,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
               - "min": column minimum value (default: `True`)
               - "max": column maximum value (default: `True`)
-              - "distinct_count": number of unique column values (default: `False`)
+              - "distinct_count": number of unique non-null column values. This
+                is exact for dictionary-encoded columns and estimated with
+                HyperLogLog otherwise (default: `False`)
               - "null_count": number of null values in column (default: `True`)
         row_group_size
             Size of the row groups in number of rows.
@@ -2738,6 +3010,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         data_page_size
             Size limit of individual data pages.
             If not set defaults to 1024 * 1024 bytes
+        data_page_row_limit
+            Maximum number of rows in individual data pages, on top of the size
+            limit. Smaller pages let readers that use the page index skip more rows,
+            and readers need less memory to decode a page. Not limited if not set.
+
+            .. versionadded:: 1.40.0
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -2797,6 +3075,29 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             .. warning::
                 This functionality is considered **unstable**. It may be changed at any
                 point without it being considered a breaking change.
+        uuid_columns
+            String columns holding UUIDs in their canonical hyphenated form, e.g.
+            `"123e4567-e89b-12d3-a456-426614174000"`, to write as 16-byte values with
+            the Parquet UUID logical type. Other readers then recognize the values as
+            UUIDs. Use `uuid_as_string=True` in :func:`read_parquet` to read them back
+            as strings.
+
+            .. versionadded:: 1.40.0
+        column_options
+            Options that override the file-level options for some columns, as a
+            dictionary mapping column names to dictionaries with the keys:
+
+              - "compression": the compression of the column.
+              - "compression_level": the compression level of the column. Without
+                "compression", this is a level of the file-level compression.
+              - "dictionary": whether to use dictionary encoding for the column
+                (default: `True`). Disabling it avoids building a dictionary for
+                columns with mostly unique values.
+
+            This allows e.g. compressing string columns with zstd while writing float
+            columns uncompressed.
+
+            .. versionadded:: 1.40.0
         mkdir: bool
             Recursively create all the directories in the path.
 
@@ -2922,8 +3223,19 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             statistics=statistics,
             row_group_size=row_group_size,
             data_page_size=data_page_size,
+            data_page_row_limit=data_page_row_limit,
             metadata=metadata,
             arrow_schema=arrow_schema,
+            uuid_columns=[] if uuid_columns is None else list(uuid_columns),
+            column_options=[
+                (
+                    name,
+                    options.get("compression"),
+                    options.get("compression_level"),
+                    options.get("dictionary", True),
+                )
+                for name, options in (column_options or {}).items()
+            ],
         )
 
         if not lazy:
@@ -3547,6 +3859,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         separator: str = ",",
         line_terminator: str = "\n",
         quote_char: str = '"',
+        escape_char: str | None = None,
         batch_size: int = 1024,
         datetime_format: str | None = None,
         date_format: str | None = None,
@@ -3556,6 +3869,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        column_options: dict[str, CsvColumnOptions] | None = None,
+        max_rows_per_file: int | None = None,
+        approximate_bytes_per_file: int | None = None,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -3582,6 +3898,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         separator: str = ",",
         line_terminator: str = "\n",
         quote_char: str = '"',
+        escape_char: str | None = None,
         batch_size: int = 1024,
         datetime_format: str | None = None,
         date_format: str | None = None,
@@ -3591,6 +3908,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        column_options: dict[str, CsvColumnOptions] | None = None,
+        max_rows_per_file: int | None = None,
+        approximate_bytes_per_file: int | None = None,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -3616,6 +3936,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         separator: str = ",",
         line_terminator: str = "\n",
         quote_char: str = '"',
+        escape_char: str | None = None,
         batch_size: int = 1024,
         datetime_format: str | None = None,
         date_format: str | None = None,
@@ -3625,6 +3946,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        column_options: dict[str, CsvColumnOptions] | None = None,
+        max_rows_per_file: int | None = None,
+        approximate_bytes_per_file: int | None = None,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -3679,6 +4003,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             String used to end each row.
         quote_char
             Byte to use as quoting character.
+        escape_char
+            Byte to use to escape the quoting character and itself in quoted fields,
+            e.g. `"\\"` to write `"a\\"b"` instead of `"a""b"`. If `None`
+            (default), the quoting character is escaped by doubling it.
+
+            .. versionadded:: 1.40.0
         batch_size
             Number of rows that will be processed per thread.
         datetime_format
@@ -3723,6 +4053,39 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        column_options
+            Options that override the file-level options for some columns, as a
+            dictionary mapping column names to dictionaries with the keys
+            "quote_style", "float_scientific", "float_precision", "date_format",
+            "time_format" and "datetime_format". These have the same meaning as the
+            parameters of the same name.
+
+            This allows e.g. writing prices with 2 decimals and measurements in
+            scientific notation, or quoting only the text columns.
+
+            .. versionadded:: 1.40.0
+        max_rows_per_file
+            Start a new file after this many rows. The files are written next to
+            `path`, with the index of the file appended to the file name, e.g.
+            `data.csv` is written as `data_0.csv`, `data_1.csv`, etc. Every file
+            starts with a header if `include_header` is set. Requires `path` to be a
+            file path.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+
+            .. versionadded:: 1.40.0
+        approximate_bytes_per_file
+            Start a new file after approximately this many bytes, like
+            `max_rows_per_file`. This is measured as the estimated size of the data
+            in memory, so files can be larger or smaller than this.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+
+            .. versionadded:: 1.40.0
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -3811,6 +4174,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         >>> buf = io.BytesIO()  # doctest: +SKIP
         >>> pl.LazyFrame({"x": [1, 2, 1]}).sink_csv(buf)  # doctest: +SKIP
 
+        Split into files of at most 1 million rows, written as `out_0.csv`,
+        `out_1.csv`, etc.:
+
+        >>> lf.sink_csv("out.csv", max_rows_per_file=1_000_000)  # doctest: +SKIP
+
         Split into a hive-partitioning style partition:
 
         >>> pl.LazyFrame({"x": [1, 2, 1], "y": [3, 4, 5]}).sink_csv(
@@ -3826,10 +4194,27 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         _check_arg_is_1byte("separator", separator, can_be_empty=False)
         _check_arg_is_1byte("quote_char", quote_char, can_be_empty=False)
+        _check_arg_is_1byte("escape_char", escape_char, can_be_empty=False)
         if not null_value:
             null_value = None
         engine = _select_engine(engine)
 
+        if max_rows_per_file is not None or approximate_bytes_per_file is not None:
+            if not isinstance(path, (str, Path)):
+                msg = (
+                    "`max_rows_per_file` and `approximate_bytes_per_file` require "
+                    f"`path` to be a file path, got {qualified_type_name(path)!r}"
+                )
+                raise TypeError(msg)
+
+            from polars.io.partition import _rotating_file_target
+
+            path = _rotating_file_target(
+                normalize_filepath(path),
+                max_rows_per_file=max_rows_per_file,
+                approximate_bytes_per_file=approximate_bytes_per_file,
+            )
+
         from polars.io.cloud.credential_provider._builder import (
             _init_credential_provider_builder,
         )
@@ -3868,6 +4253,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             separator=ord(separator),
             line_terminator=line_terminator,
             quote_char=ord(quote_char),
+            escape_char=None if escape_char is None else ord(escape_char),
             batch_size=batch_size,
             datetime_format=datetime_format,
             date_format=date_format,
@@ -3877,6 +4263,18 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             decimal_comma=decimal_comma,
             null_value=null_value,
             quote_style=quote_style,
+            column_options=[
+                (
+                    name,
+                    options.get("quote_style"),
+                    options.get("float_scientific"),
+                    options.get("float_precision"),
+                    options.get("date_format"),
+                    options.get("time_format"),
+                    options.get("datetime_format"),
+                )
+                for name, options in (column_options or {}).items()
+            ],
         )
 
         if not lazy:
@@ -3894,6 +4292,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
+        temporal_format: JsonTemporalFormat = "iso",
+        non_finite_floats: NonFiniteFloats = "null",
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -3915,6 +4315,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
+        temporal_format: JsonTemporalFormat = "iso",
+        non_finite_floats: NonFiniteFloats = "null",
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -3935,6 +4337,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
+        temporal_format: JsonTemporalFormat = "iso",
+        non_finite_floats: NonFiniteFloats = "null",
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -3979,6 +4383,24 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             .. warning::
                 This functionality is considered **unstable**. It may be changed at any
                 point without it being considered a breaking change.
+        temporal_format : {'iso', 'epoch'}
+            How to write temporal values.
+
+            - iso: write dates, times and datetimes as ISO 8601 strings and
+              durations as ISO 8601 duration strings.
+            - epoch: write the underlying integer, i.e. the days since the epoch
+              for dates and the number of time units (since the epoch, midnight or
+              in total) for datetimes, times and durations.
+
+            .. versionadded:: 1.40.0
+        non_finite_floats : {'null', 'string'}
+            How to write NaN and infinite floats, which have no JSON representation.
+
+            - null: write them as `null`.
+            - string: write them as the strings `"NaN"`, `"Infinity"` and
+              `"-Infinity"`.
+
+            .. versionadded:: 1.40.0
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -4112,6 +4534,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             compression=compression,
             compression_level=compression_level,
             check_extension=check_extension,
+            temporal_format=temporal_format,
+            non_finite_floats=non_finite_floats,
             sink_options=sink_options,
         )
 
@@ -4297,6 +4721,94 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         )
         return CollectBatches(inner)
 
+    @unstable()
+    def collect_to_disk(
+        self,
+        path: str | Path,
+        *,
+        compression: IpcCompression | None = "uncompressed",
+        maintain_order: bool = True,
+        storage_options: StorageOptionsDict | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame:
+        """
+        Evaluate the query in streaming mode and spill the result to an IPC file.
+
+        This allows collecting results that are larger than RAM. The returned
+        LazyFrame scans the written file, so the result is only read back into
+        memory when (a part of) it is collected, e.g. with :meth:`collect_batches`
+        or after a :meth:`slice`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        path
+            File path to which the result should be written. The file is not
+            removed afterwards.
+        compression : {'uncompressed', 'lz4', 'zstd'}
+            Choose "zstd" for good compression performance.
+            Choose "lz4" for fast compression/decompression.
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will be slightly faster.
+        storage_options
+            Options that indicate how to connect to a cloud provider, see
+            :meth:`sink_ipc`.
+        credential_provider
+            Provide a function that can be called to provide cloud storage
+            credentials, see :meth:`sink_ipc`.
+        engine
+            Select the engine used to process the query, optional.
+            At the moment, if set to `"auto"` (default), the query is run
+            using the polars streaming engine. Polars will also
+            attempt to use the engine set by the `POLARS_ENGINE_AFFINITY`
+            environment variable. If it cannot run the query using the
+            selected engine, the query is run using the polars streaming
+            engine.
+        optimizations
+            The optimization passes done during query optimization.
+
+        Returns
+        -------
+        LazyFrame
+            A LazyFrame that scans the result.
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> result = lf.group_by("key").len().collect_to_disk(
+        ...     "result.arrow"
+        ... )  # doctest: +SKIP
+        >>> for df in result.collect_batches():
+        ...     print(df)  # doctest: +SKIP
+        """
+        self.sink_ipc(
+            path,
+            compression=compression,
+            maintain_order=maintain_order,
+            storage_options=storage_options,
+            credential_provider=credential_provider,
+            engine=engine,
+            optimizations=optimizations,
+        )
+
+        from polars.io.ipc import scan_ipc
+
+        return scan_ipc(
+            path,
+            storage_options=storage_options,
+            credential_provider=credential_provider,
+        )
+
     @deprecated(
         "`LazyFrame.fetch` is deprecated; use `LazyFrame.collect` "
         "instead, in conjunction with a call to `head`."
@@ -4361,6 +4873,51 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         """
         return self._from_pyldf(self._ldf.cache())
 
+    @unstable()
+    def bind_parameters(self, params: Mapping[str, Any]) -> LazyFrame:
+        """
+        Bind the parameters of the query to literal values.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters are created with :func:`polars.param`. This allows a query to be
+        built once, and bound to different values for every run. With the plan cache
+        enabled (see :func:`polars.set_plan_cache_capacity`), the bound queries reuse
+        the same optimized plan.
+
+        Parameters
+        ----------
+        params
+            A mapping of parameter names to values. The values are converted with
+            :func:`polars.lit`. Parameters that are not in `params` stay unbound.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3, 4], "b": ["x", "y", "x", "y"]})
+        >>> query = lf.filter(
+        ...     pl.col("a") > pl.param("min"),
+        ...     pl.col("b") == pl.param("b"),
+        ... )
+        >>> query.bind_parameters({"min": 1, "b": "x"}).collect()
+        shape: (1, 2)
+        ┌─────┬─────┐
+        │ a   ┆ b   │
+        │ --- ┆ --- │
+        │ i64 ┆ str │
+        ╞═════╪═════╡
+        │ 3   ┆ x   │
+        └─────┴─────┘
+        """
+        params_py = [
+            (name, parse_into_expression(value, str_as_lit=True))
+            for name, value in params.items()
+        ]
+        return self._from_pyldf(self._ldf.bind_parameters(params_py))
+
     def cast(
         self,
         dtypes: (
@@ -4959,6 +5516,60 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             invert=True,
         )
 
+    @unstable()
+    def time_series_folds(
+        self, time_column: str, n_folds: int, *, gap: int = 0
+    ) -> list[tuple[LazyFrame, LazyFrame]]:
+        """
+        Create expanding-window train/test splits over the values of a time column.
+
+        The distinct non-null values of `time_column` are cut into `n_folds + 1`
+        consecutive blocks. Fold `i` tests on block `i + 1` and trains on all earlier
+        blocks. Every split is a filter on this LazyFrame, so the folds can be
+        collected together with :func:`collect_all`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        time_column
+            The column to order the rows by. Rows with equal values always end up in
+            the same block.
+        n_folds
+            The number of folds.
+        gap
+            The number of distinct values before the test block that are excluded
+            from the training data, to avoid leakage between neighbouring rows.
+
+        Returns
+        -------
+        list of tuple of (LazyFrame, LazyFrame)
+            The train and test set of every fold.
+
+        See Also
+        --------
+        DataFrame.split
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"t": [1, 2, 3, 4, 5, 6], "v": range(6)})
+        >>> for train, test in lf.time_series_folds("t", 2):
+        ...     print(
+        ...         train.collect()["t"].to_list(),
+        ...         test.collect()["t"].to_list(),
+        ...     )
+        [1, 2] [3, 4]
+        [1, 2, 3, 4] [5, 6]
+        """
+        return [
+            (self._from_pyldf(train), self._from_pyldf(test))
+            for train, test in self._ldf.time_series_folds(time_column, n_folds, gap)
+        ]
+
     def select(
         self, *exprs: IntoExpr | Iterable[IntoExpr], **named_exprs: IntoExpr
     ) -> LazyFrame:
@@ -5188,6 +5799,75 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         lgb = self._ldf.group_by(exprs, maintain_order)
         return LazyGroupBy(lgb)
 
+    @unstable()
+    def group_by_hashed(
+        self,
+        hash: IntoExpr,
+        *by: IntoExpr | Iterable[IntoExpr],
+        maintain_order: bool = False,
+    ) -> LazyGroupBy:
+        """
+        Start a group by operation on a precomputed hash of the keys.
+
+        The rows are grouped on the values of `hash`, which are trusted to identify
+        the keys `by`: rows with the same hash are put in the same group without
+        comparing their keys, and the keys of a group are taken from its first row.
+        This is faster than grouping on the keys themselves when the same expensive
+        composite key is grouped on repeatedly, as the keys are not encoded and
+        hashed again.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        hash
+            The UInt64 hash of the keys, such as the output of :func:`Expr.hash`.
+            Accepts expression input. Strings are parsed as column names. The hash is
+            not part of the output, so it must not have the name of a key.
+        *by
+            Column(s) identified by `hash`. Accepts expression input. Strings are
+            parsed as column names. Note that these columns are not excluded from the
+            columns selected by `pl.all()` in the aggregations.
+        maintain_order
+            Ensure that the order of the groups is consistent with the input data.
+            This is slower than a default group by.
+
+        See Also
+        --------
+        group_by
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "a": ["x", "y", "x", "y"],
+        ...         "b": [1, 2, 1, 3],
+        ...         "c": [5, 4, 3, 2],
+        ...     }
+        ... ).with_columns(key_hash=pl.struct("a", "b").hash())
+        >>> lf.group_by_hashed("key_hash", "a", "b", maintain_order=True).agg(
+        ...     pl.col("c").sum()
+        ... ).collect()
+        shape: (3, 3)
+        ┌─────┬─────┬─────┐
+        │ a   ┆ b   ┆ c   │
+        │ --- ┆ --- ┆ --- │
+        │ str ┆ i64 ┆ i64 │
+        ╞═════╪═════╪═════╡
+        │ x   ┆ 1   ┆ 8   │
+        │ y   ┆ 2   ┆ 4   │
+        │ y   ┆ 3   ┆ 2   │
+        └─────┴─────┴─────┘
+        """
+        hash_py = parse_into_expression(hash)
+        exprs = parse_into_list_of_expressions(*by)
+        lgb = self._ldf.group_by_hashed(hash_py, exprs, maintain_order)
+        return LazyGroupBy(lgb)
+
     @deprecate_renamed_parameter("by", "group_by", version="0.20.14")
     def rolling(
         self,
@@ -5683,6 +6363,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         coalesce: bool = True,
         allow_exact_matches: bool = True,
         check_sortedness: bool = True,
+        distance_column: str | None = None,
+        indicator_column: str | None = None,
     ) -> LazyFrame:
         """
         Perform an asof join.
@@ -5780,6 +6462,18 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             Check the sortedness of the asof keys. If the keys are not sorted Polars
             will error. Currently, sortedness cannot be checked if 'by' groups are
             provided.
+        distance_column
+            Name of an output column holding the distance between the left key and
+            the matched right key (`left - right`), null if there was no match.
+            Only supported for numeric and temporal keys. The distance of integer
+            keys is an `Int64`; `UInt64` keys beyond its range raise an error.
+
+            .. versionadded:: 1.40.0
+        indicator_column
+            Name of an output boolean column indicating whether a match within the
+            `tolerance` was found.
+
+            .. versionadded:: 1.40.0
 
 
         Examples
@@ -6034,6 +6728,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 coalesce=coalesce,
                 allow_eq=allow_exact_matches,
                 check_sortedness=check_sortedness,
+                distance_column=distance_column,
+                indicator_column=indicator_column,
             )
         )
 
@@ -6427,6 +7123,164 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             )
         )
 
+    @unstable()
+    def interval_join(
+        self,
+        other: LazyFrame,
+        left_on: IntoExpr,
+        right_on: IntoExpr,
+        *,
+        suffix: str = "_right",
+    ) -> LazyFrame:
+        """
+        Join the rows of which the intervals overlap.
+
+        Both `left_on` and `right_on` evaluate to intervals, see
+        :func:`polars.interval`. This performs an inner join, so a row from either
+        frame may be included multiple times in the result. Rows with a null interval
+        are never joined.
+
+        .. note::
+            The row order of the input frames is not preserved.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        other
+            LazyFrame to join with.
+        left_on
+            The intervals of this frame. Accepts expression input. Strings are parsed
+            as column names.
+        right_on
+            The intervals of `other`. Accepts expression input. Strings are parsed as
+            column names.
+        suffix
+            Suffix to append to columns with a duplicate name.
+
+        See Also
+        --------
+        join_where
+
+        Examples
+        --------
+        >>> bookings = pl.LazyFrame({"id": [1, 2], "start": [0, 10], "end": [5, 20]})
+        >>> events = pl.LazyFrame(
+        ...     {"event": ["a", "b", "c"], "start": [4, 5, 15], "end": [6, 10, 25]}
+        ... )
+        >>> bookings.interval_join(
+        ...     events,
+        ...     pl.interval("start", "end"),
+        ...     pl.interval("start", "end"),
+        ... ).sort("id").collect()
+        shape: (2, 6)
+        ┌─────┬───────┬─────┬───────┬─────────────┬───────────┐
+        │ id  ┆ start ┆ end ┆ event ┆ start_right ┆ end_right │
+        │ --- ┆ ---   ┆ --- ┆ ---   ┆ ---         ┆ ---       │
+        │ i64 ┆ i64   ┆ i64 ┆ str   ┆ i64         ┆ i64       │
+        ╞═════╪═══════╪═════╪═══════╪═════════════╪═══════════╡
+        │ 1   ┆ 0     ┆ 5   ┆ a     ┆ 4           ┆ 6         │
+        │ 2   ┆ 10    ┆ 20  ┆ c     ┆ 15          ┆ 25        │
+        └─────┴───────┴─────┴───────┴─────────────┴───────────┘
+        """
+        require_same_type(self, other)
+
+        return self._from_pyldf(
+            self._ldf.interval_join(
+                other._ldf,
+                parse_into_expression(left_on),
+                parse_into_expression(right_on),
+                suffix,
+            )
+        )
+
+    @unstable()
+    def join_hashed(
+        self,
+        other: LazyFrame,
+        left_hash: IntoExpr,
+        right_hash: IntoExpr,
+        *,
+        how: JoinStrategy = "inner",
+        suffix: str = "_right",
+        maintain_order: MaintainOrderJoin | None = None,
+    ) -> LazyFrame:
+        """
+        Join on a precomputed hash of the join keys.
+
+        The rows are joined on the values of `left_hash` and `right_hash`, which are
+        trusted to identify the join keys: rows with equal hashes are joined without
+        comparing their keys. The hashes are only used for matching, so the key
+        columns of both frames are kept in the output.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        other
+            LazyFrame to join with.
+        left_hash
+            The UInt64 hash of the keys of this frame, such as the output of
+            :func:`Expr.hash`. Accepts expression input. Strings are parsed as column
+            names.
+        right_hash
+            The UInt64 hash of the keys of `other`. Accepts expression input. Strings
+            are parsed as column names.
+        how : {'inner', 'left', 'right', 'full', 'semi', 'anti'}
+            Join strategy, see :func:`join`.
+        suffix
+            Suffix to append to columns with a duplicate name.
+        maintain_order : {'none', 'left', 'right', 'left_right', 'right_left'}
+            Which DataFrame row order to preserve, see :func:`join`.
+
+        See Also
+        --------
+        join
+        group_by_hashed
+
+        Examples
+        --------
+        >>> left = pl.LazyFrame({"a": ["x", "y", "z"], "b": [1, 2, 3]})
+        >>> right = pl.LazyFrame({"a": ["x", "z"], "b": [1, 3], "c": [10.0, 30.0]})
+        >>> key_hash = pl.struct("a", "b").hash()
+        >>> left.join_hashed(
+        ...     right, key_hash, key_hash, how="left", maintain_order="left"
+        ... ).collect()
+        shape: (3, 5)
+        ┌─────┬─────┬─────────┬─────────┬──────┐
+        │ a   ┆ b   ┆ a_right ┆ b_right ┆ c    │
+        │ --- ┆ --- ┆ ---     ┆ ---     ┆ ---  │
+        │ str ┆ i64 ┆ str     ┆ i64     ┆ f64  │
+        ╞═════╪═════╪═════════╪═════════╪══════╡
+        │ x   ┆ 1   ┆ x       ┆ 1       ┆ 10.0 │
+        │ y   ┆ 2   ┆ null    ┆ null    ┆ null │
+        │ z   ┆ 3   ┆ z       ┆ 3       ┆ 30.0 │
+        └─────┴─────┴─────────┴─────────┴──────┘
+        """
+        require_same_type(self, other)
+
+        if maintain_order is None:
+            maintain_order = "none"
+
+        return self._from_pyldf(
+            self._ldf.join_hashed(
+                other._ldf,
+                parse_into_expression(left_hash),
+                parse_into_expression(right_hash),
+                how,
+                suffix,
+                maintain_order,
+            )
+        )
+
     def with_columns(
         self,
         *exprs: IntoExpr | Iterable[IntoExpr],
@@ -3,12 +3,15 @@
 from polars.meta.build import build_info
 from polars.meta.index_type import get_index_type
 from polars.meta.thread_pool import thread_pool_size, threadpool_size
+from polars.meta.time_zones import available_time_zones, tzdb_version
 from polars.meta.versions import show_versions
 
 __all__ = [
+    "available_time_zones",
     "build_info",
     "get_index_type",
     "show_versions",
     "thread_pool_size",
     "threadpool_size",
+    "tzdb_version",
 ]
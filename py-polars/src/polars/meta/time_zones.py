@@ -0,0 +1,59 @@
+from __future__ import annotations
+
+import contextlib
+
+from polars._utils.unstable import unstable
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars._plr as plr
+
+
+@unstable()
+def available_time_zones() -> list[str]:
+    """
+    Return the names of the time zones known to Polars, sorted alphabetically.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    .. versionadded:: 1.40.0
+
+    Polars computes with the IANA time zone database that is bundled with it at
+    build time (see :func:`tzdb_version`), so these are available regardless of
+    the time zone data installed on the system. Fixed offsets such as `"+01:00"`
+    are accepted as well, and are converted to the matching `"Etc/GMT"` time zone.
+
+    See Also
+    --------
+    tzdb_version : The version of the bundled time zone database.
+
+    Examples
+    --------
+    >>> "Europe/Amsterdam" in pl.available_time_zones()
+    True
+    """
+    return sorted(plr._known_timezones())
+
+
+@unstable()
+def tzdb_version() -> str:
+    """
+    Return the version of the IANA time zone database bundled with Polars.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    .. versionadded:: 1.40.0
+
+    The version can be pinned with :meth:`Config.set_tzdb_version`, so that
+    processes running with a different version of Polars fail instead of
+    silently converting time zones differently.
+
+    Examples
+    --------
+    >>> pl.tzdb_version()  # doctest: +SKIP
+    '2025b'
+    """
+    return plr._tzdb_version()
@@ -0,0 +1,122 @@
+from __future__ import annotations
+
+import contextlib
+
+from polars._utils.unstable import unstable
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars._plr as plr
+
+__all__ = [
+    "clear_plan_cache",
+    "plan_cache_stats",
+    "set_plan_cache_capacity",
+]
+
+
+@unstable()
+def set_plan_cache_capacity(capacity: int) -> None:
+    """
+    Set the maximum number of optimized query plans to cache.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    .. versionadded:: 1.40.0
+
+    With the cache enabled, a query that has the same shape as a cached query (it
+    only differs in the values of its literals) skips the query optimizer. The
+    cached plan is reused, with the new values of the literals substituted in
+    where that is safe. This speeds up running the same query many times with
+    different parameters.
+
+    The cache is disabled with a capacity of 0, which is the default.
+
+    Parameters
+    ----------
+    capacity
+        The maximum number of plans to cache. The least recently used plans are
+        evicted first.
+
+    Notes
+    -----
+    The streaming engine also reuses the physical plan of a query whose literals are
+    the same as those of the cached plan. The in-memory engine still builds its
+    physical plan for every query. Cached plans keep the DataFrames they scan alive
+    until they are evicted, or the cache is cleared with :func:`clear_plan_cache`.
+
+    See Also
+    --------
+    plan_cache_stats
+    clear_plan_cache
+
+    Examples
+    --------
+    >>> pl.set_plan_cache_capacity(128)
+    >>> df = pl.DataFrame({"a": [1, 2, 3]})
+    >>> for threshold in [1, 2]:
+    ...     _ = df.lazy().filter(pl.col("a") > threshold).collect()
+    >>> pl.plan_cache_stats()["len"]  # doctest: +SKIP
+    1
+    >>> pl.set_plan_cache_capacity(0)
+    """
+    if capacity < 0:
+        msg = f"`capacity` must be non-negative, got {capacity}"
+        raise ValueError(msg)
+    plr.set_plan_cache_capacity(capacity)
+
+
+@unstable()
+def plan_cache_stats() -> dict[str, int]:
+    """
+    Return the statistics of the plan cache.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    .. versionadded:: 1.40.0
+
+    Returns
+    -------
+    dict
+        The number of queries that reused a cached plan (`"hits"`), the number of
+        queries that had to be optimized (`"misses"`), the number of cached plans
+        (`"len"`) and the maximum number of cached plans (`"capacity"`).
+
+    See Also
+    --------
+    set_plan_cache_capacity
+
+    Examples
+    --------
+    >>> pl.plan_cache_stats()  # doctest: +SKIP
+    {'hits': 0, 'misses': 0, 'len': 0, 'capacity': 0}
+    """
+    hits, misses, length, capacity = plr.plan_cache_stats()
+    return {"hits": hits, "misses": misses, "len": length, "capacity": capacity}
+
+
+@unstable()
+def clear_plan_cache() -> None:
+    """
+    Remove all plans from the plan cache and reset its statistics.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    .. versionadded:: 1.40.0
+
+    The capacity of the cache is kept.
+
+    See Also
+    --------
+    set_plan_cache_capacity
+
+    Examples
+    --------
+    >>> pl.clear_plan_cache()
+    """
+    plr.clear_plan_cache()
@@ -54,6 +54,7 @@ __all__ = [
     "boolean",
     "by_dtype",
     "by_index",
+    "by_metadata",
     "by_name",
     "categorical",
     "contains",
@@ -1186,6 +1187,57 @@ def by_index(
     return Selector._from_pyselector(PySelector.by_index(all_indices, require_all))
 
 
+@unstable()
+def by_metadata(key: str, value: str) -> Selector:
+    """
+    Select all columns of which the field metadata has the given value for a key.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    .. versionadded:: 1.40.0
+
+    Parameters
+    ----------
+    key
+        The key to look up in the field metadata of every column.
+    value
+        The value that the key must have.
+
+    Notes
+    -----
+    The field metadata is resolved from the input when the query is planned, which
+    is supported in `select`, `with_columns` and `group_by`.
+
+    See Also
+    --------
+    by_name : Select all columns matching the given names.
+    Expr.with_field_metadata : Set the field metadata of a column.
+
+    Examples
+    --------
+    >>> import polars.selectors as cs
+    >>> df = pl.DataFrame(
+    ...     {"dist": [1.0, 2.0], "time": [4.0, 5.0], "label": ["a", "b"]}
+    ... ).with_columns(
+    ...     pl.col("dist").with_field_metadata({"unit": "m"}),
+    ...     pl.col("time").with_field_metadata({"unit": "s"}),
+    ... )
+    >>> df.select(cs.by_metadata("unit", "m"))
+    shape: (2, 1)
+    ┌──────┐
+    │ dist │
+    │ ---  │
+    │ f64  │
+    ╞══════╡
+    │ 1.0  │
+    │ 2.0  │
+    └──────┘
+    """
+    return Selector._from_pyselector(PySelector.by_metadata(key, value))
+
+
 def by_name(*names: str | Collection[str], require_all: bool = True) -> Selector:
     """
     Select all columns matching the given names.
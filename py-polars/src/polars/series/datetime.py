@@ -16,6 +16,7 @@ if TYPE_CHECKING:
     from polars._plr import PySeries
     from polars._typing import (
         Ambiguous,
+        DateGranularity,
         EpochTimeUnit,
         IntoExpr,
         IntoExprColumn,
@@ -222,10 +223,15 @@ class DateTimeNameSpace:
         """
         return self._s.mean()
 
-    def to_string(self, format: str | None = None) -> Series:
+    def to_string(
+        self, format: str | None = None, *, locale: str | None = None
+    ) -> Series:
         """
         Convert a Date/Time/Datetime column into a String column with the given format.
 
+        .. versionchanged:: 1.40.0
+            Added support for "human" and strftime-like format strings for the
+            `Duration` dtype.
         .. versionchanged:: 1.15.0
             Added support for the use of "iso:strict" as a format string.
         .. versionchanged:: 1.14.0
@@ -241,6 +247,11 @@ class DateTimeNameSpace:
             * If no format is provided, the appropriate ISO format for the underlying
               data type is used. This can be made explicit by passing `"iso"` or
               `"iso:strict"` as the format string (see notes below for details).
+        locale
+            Locale used to write the month and weekday names, such as `"fr_FR"` or
+            `"de_DE"`. If set to None (default), the names are written in English.
+
+            .. versionadded:: 1.40.0
 
         Notes
         -----
@@ -255,10 +266,14 @@ class DateTimeNameSpace:
           the same components separated with a "T"). All other temporal types
           return the same value for both format strings.
 
-        * Duration dtype expressions cannot be formatted with `strftime`. Instead,
-          only "iso" and "polars" are supported as format strings. The "iso" format
-          string results in ISO8601 duration string output, and "polars" results
-          in the same form seen in the frame `repr`.
+        * Duration dtype expressions support "iso", "polars" and "human" as format
+          strings. The "iso" format string results in ISO8601 duration string
+          output, "polars" results in the same form seen in the frame `repr`, and
+          "human" results in a readable form with a single leading sign, such as
+          "-2d 3h 4m 5.25s". Durations also support a subset of the `strftime`
+          specifiers: `%d`, `%H`, `%M`, `%S`, `%f`, `%.f`, `%.3f`, `%.6f`, `%.9f`
+          and `%%`. The largest unit in the format holds the total of that unit,
+          so `"%H:%M:%S"` formats 26 hours as "26:00:00".
 
         Examples
         --------
@@ -327,9 +342,20 @@ class DateTimeNameSpace:
             "July"
             "October"
         ]
+
+        Month and weekday names can be written in the language of a locale:
+
+        >>> s.dt.to_string("%A %-d %B", locale="fr_FR")
+        shape: (3,)
+        Series: 'dtm' [str]
+        [
+            "vendredi 31 décembre"
+            "dimanche 5 juillet"
+            "mercredi 20 octobre"
+        ]
         """
 
-    def strftime(self, format: str) -> Series:
+    def strftime(self, format: str, *, locale: str | None = None) -> Series:
         """
         Convert a Date/Time/Datetime column into a String column with the given format.
 
@@ -344,6 +370,11 @@ class DateTimeNameSpace:
             Format to use, refer to the `chrono strftime documentation
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for specification. Example: `"%y-%m-%d"`.
+        locale
+            Locale used to write the month and weekday names, such as `"fr_FR"` or
+            `"de_DE"`. If set to None (default), the names are written in English.
+
+            .. versionadded:: 1.40.0
 
         See Also
         --------
@@ -386,7 +417,7 @@ class DateTimeNameSpace:
                 "May"
         ]
         """
-        return self.to_string(format)
+        return self.to_string(format, locale=locale)
 
     def millennium(self) -> Series:
         """
@@ -1984,6 +2015,41 @@ class DateTimeNameSpace:
         ]
         """
 
+    @unstable()
+    def generalize_date(self, granularity: DateGranularity) -> Series:
+        """
+        Generalize dates/datetimes to the start of a coarser period.
+
+        This reduces the precision of the values, for example to anonymize them.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        granularity : {'year', 'quarter', 'month', 'week', 'day'}
+            The period the values are generalized to. Weeks start on Monday.
+
+        See Also
+        --------
+        truncate
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series("birth", [date(1985, 5, 17), date(1990, 11, 3)])
+        >>> s.dt.generalize_date("month")
+        shape: (2,)
+        Series: 'birth' [date]
+        [
+            1985-05-01
+            1990-11-01
+        ]
+        """
+
     def round(self, every: str | dt.timedelta | IntoExprColumn) -> Series:
         """
         Divide the date/ datetime range into buckets.
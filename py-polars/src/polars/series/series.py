@@ -709,6 +709,33 @@ class Series:
         """
         return self._s.dtype()
 
+    @property
+    @unstable()
+    def field_metadata(self) -> dict[str_, str_] | None:
+        """
+        Get the key/value metadata of this Series, if any.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        See Also
+        --------
+        Expr.with_field_metadata
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, 3])
+        >>> s.field_metadata is None
+        True
+        >>> s = s.to_frame().select(pl.col("a").with_field_metadata({"unit": "m"}))
+        >>> s.to_series().field_metadata
+        {'unit': 'm'}
+        """
+        return self._s.field_metadata()
+
     @property
     def flags(self) -> dict[str_, bool]:
         """
@@ -2528,6 +2555,7 @@ class Series:
         labels: Sequence[str_] | None = None,
         left_closed: bool = False,
         include_breaks: bool = False,
+        include_bounds: bool = False,
     ) -> Series:
         """
         Bin continuous values into discrete categories.
@@ -2549,12 +2577,19 @@ class Series:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        include_bounds
+            Return a :class:`Struct` with the `category` and the `lower` and `upper`
+            bounds of the bin each observation falls in. Cannot be combined with
+            `include_breaks`.
+
+            .. versionadded:: 1.40.0
 
         Returns
         -------
         Series
-            Series of data type :class:`Categorical` if `include_breaks` is set to
-            `False` (default), otherwise a Series of data type :class:`Struct`.
+            Series of data type :class:`Categorical` if `include_breaks` and
+            `include_bounds` are set to `False` (default), otherwise a Series of data
+            type :class:`Struct`.
 
         See Also
         --------
@@ -2603,6 +2638,7 @@ class Series:
         left_closed: bool = False,
         allow_duplicates: bool = False,
         include_breaks: bool = False,
+        include_bounds: bool = False,
     ) -> Series:
         """
         Bin continuous values into discrete categories based on their quantiles.
@@ -2629,12 +2665,19 @@ class Series:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        include_bounds
+            Return a :class:`Struct` with the `category` and the `lower` and `upper`
+            bounds of the bin each observation falls in. Cannot be combined with
+            `include_breaks`.
+
+            .. versionadded:: 1.40.0
 
         Returns
         -------
         Series
-            Series of data type :class:`Categorical` if `include_breaks` is set to
-            `False` (default), otherwise a Series of data type :class:`Struct`.
+            Series of data type :class:`Categorical` if `include_breaks` and
+            `include_bounds` are set to `False` (default), otherwise a Series of data
+            type :class:`Struct`.
 
         See Also
         --------
@@ -8721,8 +8764,8 @@ class Series:
 
     def replace(
         self,
-        old: IntoExpr | Sequence[Any] | Mapping[Any, Any],
-        new: IntoExpr | Sequence[Any] | NoDefault = no_default,
+        old: IntoExpr | Sequence[Any] | Mapping[Any, Any] | DataFrame,
+        new: IntoExpr | Sequence[Any] | DataFrame | NoDefault = no_default,
         *,
         default: IntoExpr | NoDefault = no_default,
         return_dtype: PolarsDataType | None = None,
@@ -8736,8 +8779,10 @@ class Series:
             Value or sequence of values to replace.
             Also accepts a mapping of values to their replacement as syntactic sugar for
             `replace(old=Series(mapping.keys()), new=Series(mapping.values()))`.
+            A DataFrame is parsed as a Series of structs, one per row.
         new
             Value or sequence of values to replace by.
+            A DataFrame is parsed as a Series of structs, one per row.
             Length must match the length of `old` or have length 1.
 
         default
@@ -8826,8 +8871,8 @@ class Series:
 
     def replace_strict(
         self,
-        old: IntoExpr | Sequence[Any] | Mapping[Any, Any],
-        new: IntoExpr | Sequence[Any] | NoDefault = no_default,
+        old: IntoExpr | Sequence[Any] | Mapping[Any, Any] | DataFrame,
+        new: IntoExpr | Sequence[Any] | DataFrame | NoDefault = no_default,
         *,
         default: IntoExpr | NoDefault = no_default,
         return_dtype: PolarsDataType | None = None,
@@ -8841,8 +8886,11 @@ class Series:
             Value or sequence of values to replace.
             Also accepts a mapping of values to their replacement as syntactic sugar for
             `replace_strict(old=Series(mapping.keys()), new=Series(mapping.values()))`.
+            A DataFrame is parsed as a Series of structs, one per row, to look up
+            struct values by multiple key columns.
         new
             Value or sequence of values to replace by.
+            A DataFrame is parsed as a Series of structs, one per row.
             Length must match the length of `old` or have length 1.
         default
             Set values that were not replaced to this value. If no default is specified,
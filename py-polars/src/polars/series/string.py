@@ -21,6 +21,7 @@ if TYPE_CHECKING:
     from polars._plr import PySeries
     from polars._typing import (
         Ambiguous,
+        CollationStrength,
         IntoExpr,
         IntoExprColumn,
         PolarsDataType,
@@ -54,6 +55,7 @@ class StringNameSpace:
         strict: bool = True,
         exact: bool = True,
         cache: bool = True,
+        locale: str | None = None,
     ) -> Series:
         """
         Convert a String column into a Date column.
@@ -76,6 +78,11 @@ class StringNameSpace:
                 data beforehand will almost certainly be more performant.
         cache
             Use a cache of unique, converted dates to apply the conversion.
+        locale
+            Locale of the month and weekday names in the strings, such as `"fr_FR"`
+            or `"de_DE"`. If set to None (default), the names must be in English.
+
+            .. versionadded:: 1.40.0
 
         Examples
         --------
@@ -88,6 +95,17 @@ class StringNameSpace:
                 2020-02-01
                 2020-03-01
         ]
+
+        Parse month names in another language:
+
+        >>> s = pl.Series(["5 mars 2024", "1 juillet 2024"])
+        >>> s.str.to_date("%d %B %Y", locale="fr_FR")
+        shape: (2,)
+        Series: '' [date]
+        [
+                2024-03-05
+                2024-07-01
+        ]
         """
 
     def to_datetime(
@@ -100,6 +118,7 @@ class StringNameSpace:
         exact: bool = True,
         cache: bool = True,
         ambiguous: Ambiguous | pl.Series = "raise",
+        locale: str | None = None,
     ) -> pl.Series:
         """
         Convert a String column into a Datetime column.
@@ -146,6 +165,11 @@ class StringNameSpace:
             - `'earliest'`: use the earliest datetime
             - `'latest'`: use the latest datetime
             - `'null'`: set to null
+        locale
+            Locale of the month and weekday names in the strings, such as `"fr_FR"`
+            or `"de_DE"`. If set to None (default), the names must be in English.
+
+            .. versionadded:: 1.40.0
 
         Examples
         --------
@@ -158,7 +182,7 @@ class StringNameSpace:
                 2020-01-01 02:00:00 UTC
         ]
         """
-        if format is None and time_zone is None:
+        if format is None and time_zone is None and locale is None:
             if isinstance(ambiguous, str):
                 ambiguous_s = pl.Series([ambiguous])
             else:
@@ -186,6 +210,7 @@ class StringNameSpace:
                         exact=exact,
                         cache=cache,
                         ambiguous=ambiguous_expr,
+                        locale=locale,
                     )
                 )
                 .to_series()
@@ -197,6 +222,7 @@ class StringNameSpace:
         *,
         strict: bool = True,
         cache: bool = True,
+        locale: str | None = None,
     ) -> Series:
         """
         Convert a String column into a Time column.
@@ -212,6 +238,11 @@ class StringNameSpace:
             Raise an error if any conversion fails.
         cache
             Use a cache of unique, converted times to apply the conversion.
+        locale
+            Locale of the month and weekday names in the strings, such as `"fr_FR"`
+            or `"de_DE"`. If set to None (default), the names must be in English.
+
+            .. versionadded:: 1.40.0
 
         Examples
         --------
@@ -226,6 +257,54 @@ class StringNameSpace:
         ]
         """
 
+    @unstable()
+    def to_duration(
+        self,
+        time_unit: TimeUnit = "us",
+        *,
+        strict: bool = True,
+    ) -> Series:
+        """
+        Convert a String column into a Duration column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        The following formats are supported:
+
+        * ISO 8601 durations, such as `"P1DT2H"` or `"-PT0.5S"`. Years and months
+          have no fixed length, so they are not supported.
+        * Human-readable durations, such as `"2d 3h 4m 5.25s"`, `"1h30m"` or
+          `"3 days"`. This includes the Polars duration format `"-1d -42s"`.
+        * Clock durations, such as `"26:03:04.5"` or, as written by Python's
+          `timedelta`, `"-1 day, 23:59:18"`.
+
+        Parameters
+        ----------
+        time_unit : {'us', 'ns', 'ms'}
+            Time unit of the resulting Duration column. Values are truncated to
+            this time unit.
+        strict
+            Raise an error if any conversion fails. If set to False, values that
+            cannot be parsed are set to null.
+
+        Examples
+        --------
+        >>> s = pl.Series(["P1DT2H", "2d 3h 4m 5.25s", "01:30:00", "-1 day, 23:59:18"])
+        >>> s.str.to_duration()
+        shape: (4,)
+        Series: '' [duration[μs]]
+        [
+                1d 2h
+                2d 3h 4m 5s 250ms
+                1h 30m
+                -42s
+        ]
+        """
+
     def strptime(
         self,
         dtype: PolarsTemporalType,
@@ -235,6 +314,7 @@ class StringNameSpace:
         exact: bool = True,
         cache: bool = True,
         ambiguous: Ambiguous | Series = "raise",
+        locale: str | None = None,
     ) -> Series:
         """
         Convert a String column into a Date/Datetime/Time column.
@@ -266,6 +346,11 @@ class StringNameSpace:
             - `'earliest'`: use the earliest datetime
             - `'latest'`: use the latest datetime
             - `'null'`: set to null
+        locale
+            Locale of the month and weekday names in the strings, such as `"fr_FR"`
+            or `"de_DE"`. If set to None (default), the names must be in English.
+
+            .. versionadded:: 1.40.0
 
         Notes
         -----
@@ -328,6 +413,7 @@ class StringNameSpace:
                 exact=exact,
                 cache=cache,
                 ambiguous=ambiguous,
+                locale=locale,
             )
         else:
             ambiguous_expr = F.lit(ambiguous)
@@ -342,6 +428,7 @@ class StringNameSpace:
                         exact=exact,
                         cache=cache,
                         ambiguous=ambiguous_expr,
+                        locale=locale,
                     )
                 )
                 .to_series()
@@ -1656,6 +1743,152 @@ class StringNameSpace:
         ]
         """
 
+    @unstable()
+    def mask_email(self) -> Series:
+        """
+        Mask the local part of email addresses.
+
+        The first character of the local part and the domain are kept, for example
+        `"john.doe@example.com"` becomes `"j***@example.com"`. Values without an
+        `@` are masked completely.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        See Also
+        --------
+        mask_digits
+
+        Examples
+        --------
+        >>> s = pl.Series("email", ["john.doe@example.com", "not an email"])
+        >>> s.str.mask_email()
+        shape: (2,)
+        Series: 'email' [str]
+        [
+            "j***@example.com"
+            "***"
+        ]
+        """
+
+    @unstable()
+    def mask_digits(self, keep_last: int = 0) -> Series:
+        """
+        Mask the digits of strings with `*`.
+
+        Other characters, such as separators, are kept.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        keep_last
+            The number of trailing digits that are not masked.
+
+        See Also
+        --------
+        mask_email
+
+        Examples
+        --------
+        >>> s = pl.Series("phone", ["+1 (555) 010-9999", "555-0100"])
+        >>> s.str.mask_digits(keep_last=2)
+        shape: (2,)
+        Series: 'phone' [str]
+        [
+            "+* (***) ***-**99"
+            "***-**00"
+        ]
+        """
+
+    def collation_key(
+        self, locale: str, *, strength: CollationStrength = "tertiary"
+    ) -> Series:
+        """
+        Compute binary sort keys that follow a locale-aware collation.
+
+        Strings are compared by the Unicode Collation Algorithm with the
+        conventions of the given locale, instead of byte-wise. Sorting, comparing
+        or grouping by the keys therefore treats the strings as the locale does;
+        for example, "ä" sorts next to "a" in German but after "z" in Swedish.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Parameters
+        ----------
+        locale
+            BCP 47 language tag of the collation, such as `"de"`, `"sv"` or
+            `"de-u-co-phonebk"`. The `-u-kn` extension enables numeric ordering of
+            digits.
+        strength : {'primary', 'secondary', 'tertiary', 'quaternary', 'identical'}
+            Which differences are significant. `"primary"` only compares base
+            letters, `"secondary"` also compares accents and `"tertiary"` also
+            compares case. `"quaternary"` and `"identical"` break the remaining
+            ties.
+
+        Examples
+        --------
+        >>> s = pl.Series(["Müller", "Mueller", "Muller"])
+        >>> s.gather(s.str.collation_key("de").arg_sort())
+        shape: (3,)
+        Series: '' [str]
+        [
+            "Mueller"
+            "Muller"
+            "Müller"
+        ]
+
+        Strings that only differ in accents or case have the same primary key.
+
+        >>> s.str.collation_key("de", strength="primary").is_duplicated()
+        shape: (3,)
+        Series: '' [bool]
+        [
+            true
+            false
+            true
+        ]
+        """
+
+    def natural_sort_key(self) -> Series:
+        """
+        Compute binary sort keys that order strings naturally.
+
+        Runs of digits are compared by their numeric value instead of character by
+        character, so that `"file2"` sorts before `"file10"`. Leading zeros are
+        ignored.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        Examples
+        --------
+        >>> s = pl.Series(["v1.10", "v1.9", "v1.2", "v10.0"])
+        >>> s.gather(s.str.natural_sort_key().arg_sort())
+        shape: (4,)
+        Series: '' [str]
+        [
+            "v1.2"
+            "v1.9"
+            "v1.10"
+            "v10.0"
+        ]
+        """
+
     def to_uppercase(self) -> Series:
         """
         Modify strings to their uppercase equivalent.
@@ -2419,3 +2652,65 @@ class StringNameSpace:
                 "KADOKAWA"
         ]
         """  # noqa: RUF002
+
+    @unstable()
+    def remove_diacritics(self) -> Series:
+        """
+        Remove the diacritics (accents) of the string values.
+
+        The strings are decomposed into base characters and combining marks (NFD),
+        the combining marks are dropped and the result is composed again (NFC).
+        Letters with a stroke, such as `ł` and `ø`, are replaced by their base
+        letter. This is useful before comparing or joining on human-entered names.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        See Also
+        --------
+        normalize
+        is_ascii
+
+        Examples
+        --------
+        >>> s = pl.Series(["Zoë Saldaña", "Łukasz Żółw", "Søren"])
+        >>> s.str.remove_diacritics()
+        shape: (3,)
+        Series: '' [str]
+        [
+            "Zoe Saldana"
+            "Lukasz Zolw"
+            "Soren"
+        ]
+        """
+
+    @unstable()
+    def is_ascii(self) -> Series:
+        """
+        Check whether the string values only consist of ASCII characters.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        .. versionadded:: 1.40.0
+
+        See Also
+        --------
+        remove_diacritics
+
+        Examples
+        --------
+        >>> s = pl.Series(["Zoe", "Zoë", None])
+        >>> s.str.is_ascii()
+        shape: (3,)
+        Series: '' [bool]
+        [
+            true
+            false
+            null
+        ]
+        """
@@ -0,0 +1,64 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Literal
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from polars._typing import EngineType
+
+
+@pytest.mark.parametrize("kind", ["sorted", "zone_map"])
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_create_index_filter(
+    kind: Literal["sorted", "zone_map"], engine: EngineType
+) -> None:
+    data = {"a": [5, None, 1, 3, 3, 9], "b": range(6)}
+    df = pl.DataFrame(data).create_index("a", kind=kind, block_size=2)
+
+    for predicate in [
+        pl.col("a") >= 3,
+        (pl.col("a") > 1) & (pl.col("a") < 5),
+        pl.col("a") == 9,
+        pl.lit(3) < pl.col("a"),
+        (pl.col("a") < 4) & (pl.col("b") > 2),
+    ]:
+        expected = pl.DataFrame(data).filter(predicate)
+        out = df.lazy().filter(predicate).collect(engine=engine)
+        assert_frame_equal(out, expected)
+
+    # The index is shared with the frames that hold the same column.
+    assert_frame_equal(
+        df.select("a", "b").lazy().filter(pl.col("a") == 9).collect(engine=engine),
+        pl.DataFrame(data).filter(pl.col("a") == 9),
+    )
+
+    # The index is dropped when its column is replaced.
+    df = df.with_columns(pl.col("a") * 2)
+    out = df.lazy().filter(pl.col("a") >= 6).collect(engine=engine)
+    assert out["b"].to_list() == [0, 3, 4, 5]
+
+
+def test_create_index_asof_join() -> None:
+    left = pl.DataFrame({"t": [2, 5, 9]})
+    right = pl.DataFrame({"t": [8, 1, 4], "v": ["c", "a", "b"]})
+    right.create_index("t")
+
+    out = left.lazy().join_asof(right.lazy(), on="t").collect(engine="in-memory")
+    expected = pl.DataFrame({"t": [2, 5, 9], "v": ["a", "b", "c"]})
+    assert_frame_equal(out, expected)
+
+    right.drop_indexes()
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="not sorted"):
+        left.lazy().join_asof(right.lazy(), on="t").collect(engine="in-memory")
+
+
+def test_create_index_invalid() -> None:
+    df = pl.DataFrame({"a": [[1], [2]]})
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="cannot index"):
+        df.create_index("a")
+    with pytest.raises(ValueError, match="`kind` must be one of"):
+        df.create_index("a", kind="hash")  # type: ignore[arg-type]
@@ -0,0 +1,61 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+def test_partition_by_hash() -> None:
+    df = pl.DataFrame({"a": ["x", "y", "x", "z", "y"], "b": [1, 2, 3, 4, 5]})
+    pf = df.partition_by_hash("a", n_partitions=3)
+
+    assert pf.keys == ["a"]
+    assert pf.n_partitions == 3
+    assert pf.height == 5
+    # Every key is in a single partition.
+    assert sum(p["a"].n_unique() for p in pf.partitions()) == 3
+    assert_frame_equal(pf.to_frame(), df, check_row_order=False)
+    assert_frame_equal(pf.lazy().collect(), df, check_row_order=False)
+
+    out = pf.group_by_agg(pl.col("b").sum()).collect()
+    expected = df.group_by("a").agg(pl.col("b").sum())
+    assert_frame_equal(out, expected, check_row_order=False)
+
+    out = pf.map_partitions(lambda lf: lf.group_by("a").len()).collect()
+    assert_frame_equal(out, df.group_by("a").len(), check_row_order=False)
+
+
+def test_partition_by_hash_join() -> None:
+    left = pl.DataFrame({"a": [1, 2, 3, 4], "b": ["p", "q", "r", "s"]})
+    right = pl.DataFrame({"a": [4, 2, 5], "c": [1.0, 2.0, 3.0]})
+
+    out = (
+        left.partition_by_hash("a", n_partitions=2)
+        .join(right.partition_by_hash("a", n_partitions=2), how="left")
+        .collect()
+    )
+    expected = left.join(right, on="a", how="left")
+    assert_frame_equal(out, expected, check_row_order=False)
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="partitions"):
+        left.partition_by_hash("a", n_partitions=2).join(
+            right.partition_by_hash("a", n_partitions=3)
+        )
+
+
+def test_partition_by_hash_plan() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, 1, 2, 1], "b": [1, 2, 3, 4, 5, 6]})
+    pf = df.partition_by_hash("a", n_partitions=3)
+
+    plan = pf.group_by_agg(pl.col("b").sum()).explain()
+    assert "PARTITIONED UNION" in plan
+    assert plan.count("AGGREGATE") == 3
+
+    plan = pf.join(pf).explain()
+    assert plan.count("INNER JOIN:") == 3
+
+    # An equality filter on the keys only reads the partition holding them.
+    q = pf.lazy().filter(pl.col("a") == 1)
+    assert q.explain().count("DF [") == 1
+    assert_frame_equal(q.collect(), df.filter(pl.col("a") == 1), check_row_order=False)
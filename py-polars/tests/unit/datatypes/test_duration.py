@@ -9,6 +9,7 @@ from hypothesis import assume, given
 
 import polars as pl
 from polars._utils.constants import I64_MAX, I64_MIN
+from polars.exceptions import InvalidOperationError
 from polars.series.datetime import DateTimeNameSpace
 from polars.testing import assert_frame_equal, assert_series_equal
 from polars.testing.parametric import series
@@ -145,6 +146,109 @@ def test_duration_to_string() -> None:
     ]
 
 
+def test_duration_to_string_human_and_strftime() -> None:
+    s = pl.Series(
+        "td",
+        [
+            timedelta(days=2, hours=3, minutes=4, seconds=5, milliseconds=250),
+            timedelta(days=-1, seconds=-42),
+            timedelta(microseconds=5),
+            timedelta(0),
+            None,
+        ],
+        dtype=pl.Duration("us"),
+    )
+    assert s.dt.to_string("human").to_list() == [
+        "2d 3h 4m 5.25s",
+        "-1d 42s",
+        "0.000005s",
+        "0s",
+        None,
+    ]
+    assert s.dt.to_string("%H:%M:%S%.3f").to_list() == [
+        "51:04:05.250",
+        "-24:00:42.000",
+        "00:00:00.000",
+        "00:00:00.000",
+        None,
+    ]
+    assert s.dt.to_string("%d days, %H:%M:%S%.f (100%%)").to_list() == [
+        "2 days, 03:04:05.250000 (100%)",
+        "-1 days, 00:00:42.000000 (100%)",
+        "0 days, 00:00:00.000005 (100%)",
+        "0 days, 00:00:00.000000 (100%)",
+        None,
+    ]
+
+    with pytest.raises(InvalidOperationError, match="invalid specifier"):
+        s.dt.to_string("%Y")
+    with pytest.raises(InvalidOperationError, match="not supported for Duration"):
+        s.dt.to_string("hours")
+
+
+@pytest.mark.parametrize("format", ["iso", "polars", "human"])
+@pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
+def test_str_to_duration_roundtrip(format: str, time_unit: TimeUnit) -> None:
+    s = pl.Series(
+        "td",
+        [
+            timedelta(days=180, seconds=56789, microseconds=987654),
+            timedelta(hours=1, seconds=1, milliseconds=1, microseconds=1),
+            timedelta(seconds=-42, milliseconds=-42),
+            timedelta(days=-1, seconds=42),
+            timedelta(0),
+            None,
+        ],
+        dtype=pl.Duration(time_unit),
+    )
+    out = s.dt.to_string(format).str.to_duration(time_unit)
+    assert_series_equal(out, s)
+
+
+def test_str_to_duration() -> None:
+    s = pl.Series(
+        [
+            "P1DT2H",
+            "-PT1M0.5S",
+            "P2W",
+            "1h30m",
+            "3 days 100µs",
+            "1.5 hours",
+            "26:03:04.5",
+            "-1 day, 23:59:18",
+            None,
+        ]
+    )
+    expected = pl.Series(
+        [
+            timedelta(days=1, hours=2),
+            timedelta(minutes=-1, milliseconds=-500),
+            timedelta(weeks=2),
+            timedelta(hours=1, minutes=30),
+            timedelta(days=3, microseconds=100),
+            timedelta(hours=1, minutes=30),
+            timedelta(hours=26, minutes=3, seconds=4, milliseconds=500),
+            timedelta(seconds=-42),
+            None,
+        ],
+        dtype=pl.Duration("us"),
+    )
+    assert_series_equal(s.str.to_duration(), expected)
+
+    out = pl.select(pl.lit("1.0000015s").str.to_duration("ms")).to_series()
+    assert out.dtype == pl.Duration("ms")
+    assert out.item() == timedelta(seconds=1)
+
+
+def test_str_to_duration_invalid() -> None:
+    s = pl.Series("td", ["P1Y", "1h", "5", "1:60:00", None])
+    with pytest.raises(InvalidOperationError, match="for 3 out of 5 values"):
+        s.str.to_duration()
+
+    out = s.str.to_duration(strict=False)
+    assert out.to_list() == [None, timedelta(hours=1), None, None, None]
+
+
 def test_duration_std_var() -> None:
     df = pl.DataFrame(
         {"duration": [1000, 5000, 3000]}, schema={"duration": pl.Duration}
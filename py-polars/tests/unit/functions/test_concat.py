@@ -290,6 +290,37 @@ def test_concat_diagonal_relaxed() -> None:
     assert_frame_equal(result, expected)
 
 
+def test_concat_diagonal_relaxed_by_name() -> None:
+    df1 = pl.DataFrame(
+        {"a": [1, 2], "b": ["x", "y"]}, schema={"a": pl.Int32, "b": pl.String}
+    )
+    df2 = pl.DataFrame({"b": ["z"], "a": [3.5]})
+    df3 = pl.DataFrame({"c": [1]}, schema={"c": pl.UInt8})
+
+    lf = pl.concat([df1.lazy(), df2.lazy(), df3.lazy()], how="diagonal_relaxed_by_name")
+    assert lf.collect_schema() == pl.Schema(
+        {"a": pl.Float64, "b": pl.String, "c": pl.UInt8}
+    )
+
+    expected = pl.DataFrame(
+        {
+            "a": [1.0, 2.0, 3.5, None],
+            "b": ["x", "y", "z", None],
+            "c": [None, None, None, 1],
+        },
+        schema={"a": pl.Float64, "b": pl.String, "c": pl.UInt8},
+    )
+    assert_frame_equal(lf.collect(), expected)
+    assert_frame_equal(
+        pl.concat([df1, df2, df3], how="diagonal_relaxed_by_name"), expected
+    )
+    assert_frame_equal(
+        pl.union([df1, df2, df3], how="diagonal_relaxed_by_name"),
+        expected,
+        check_row_order=False,
+    )
+
+
 def test_concat_horizontal() -> None:
     df1 = pl.DataFrame({"a": [1, 2, 3]})
     df2 = pl.DataFrame({"b": [4, 5]})
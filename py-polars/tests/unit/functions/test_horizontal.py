@@ -1,6 +1,7 @@
 import pytest
 
 import polars as pl
+import polars.selectors as cs
 
 
 @pytest.mark.parametrize(
@@ -40,3 +41,38 @@ def test_fold_reduce_output_dtype_24011() -> None:
     )
 
     assert q.collect_schema() == q.collect().schema
+
+
+@pytest.mark.may_fail_auto_streaming
+def test_coalesce_skips_unneeded_inputs() -> None:
+    df = pl.DataFrame({"a": [1, None], "b": [10, 20], "s": ["x", "y"]})
+
+    # The last input can't be parsed, but no rows are left to fill by then.
+    result = df.select(pl.coalesce("a", "b", pl.col("s").str.to_integer()))
+    assert result.to_series().to_list() == [1, 20]
+
+    result = df.select(pl.coalesce(pl.lit(0), "a"))
+    assert result.to_series().to_list() == [0, 0]
+
+
+def test_first_non_null_horizontal() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, None, None, None],
+            "b": [None, 2, None, None],
+            "c": [5, 6, 3, None],
+            "s": ["p", "q", "r", "s"],
+        }
+    )
+
+    result = df.select(pl.first_non_null_horizontal(cs.numeric()))
+    assert result.to_dict(as_series=False) == {"a": [1, 2, 3, None]}
+
+    result = df.select(pl.first_non_null_horizontal("c", "a"))
+    assert result.to_dict(as_series=False) == {"c": [5, 6, 3, None]}
+
+    result = df.select(pl.first_non_null_horizontal(cs.starts_with("b", "a")))
+    assert result.to_dict(as_series=False) == {"a": [1, 2, None, None]}
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        df.select(pl.first_non_null_horizontal(cs.temporal()))
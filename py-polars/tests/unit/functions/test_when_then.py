@@ -840,3 +840,63 @@ def test_when_then_in_group_by_aggregated_22922() -> None:
     )
     expected = pl.DataFrame({"group": ["x", "y"], "expr": [3, None]})
     assert_frame_equal(out, expected)
+
+
+def test_when_lazy_short_circuits_branches() -> None:
+    df = pl.DataFrame({"s": ["1", "abc", None, "20"]})
+    is_digit = pl.col("s").str.contains(r"^\d+$")
+
+    result = df.select(
+        pl.when_lazy(is_digit).then(pl.col("s").str.to_integer()).otherwise(-1)
+    )
+    assert result.to_series().to_list() == [1, -1, -1, 20]
+
+    result = df.select(
+        pl.when_lazy(~is_digit).then(-1).otherwise(pl.col("s").str.to_integer())
+    )
+    assert result.to_series().to_list() == [1, -1, None, 20]
+
+    # Branches that are never selected are not evaluated at all.
+    result = df.select(
+        pl.when_lazy(pl.col("s") == "x")
+        .then(pl.col("s").str.to_integer())
+        .otherwise(0)
+    )
+    assert result.to_series().to_list() == [0, 0, 0, 0]
+
+
+def test_when_lazy() -> None:
+    df = pl.DataFrame({"kind": ["a", "b", "a", None, "a"], "x": [1, 2, 3, 4, 5]})
+
+    result = df.select(
+        pl.when_lazy(pl.col("kind") == "a")
+        .then(pl.col("x").cum_sum())
+        .otherwise(pl.col("x").cum_sum() * 10)
+    )
+    assert result.to_series().to_list() == [1, 20, 4, 60, 9]
+
+    # Chained statements only see the rows of their own condition.
+    result = df.select(
+        pl.when_lazy(pl.col("kind") == "a")
+        .then(pl.col("x").cum_count())
+        .when(pl.col("kind") == "b")
+        .then(pl.lit(100))
+        .otherwise(pl.col("x").cum_count() * 10)
+    )
+    assert result.to_series().to_list() == [1, 100, 2, 10, 3]
+
+
+def test_when_lazy_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2],
+            "flag": [True, False, True, True, True],
+            "x": [1, 2, 3, 4, 5],
+        }
+    )
+    result = (
+        df.group_by("g", maintain_order=True)
+        .agg(pl.when_lazy("flag").then(pl.col("x").cum_sum()).otherwise(0))
+        .sort("g")
+    )
+    assert result["x"].to_list() == [[1, 0, 4], [4, 9]]
@@ -10,6 +10,8 @@ import polars as pl
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
+    from pathlib import Path
+
     from polars._typing import EngineType
 
 
@@ -109,3 +111,17 @@ print("OK", end="")
     )
 
     assert out == b"OK"
+
+
+def test_collect_to_disk(tmp_path: Path) -> None:
+    lf = pl.LazyFrame({"a": range(1000), "b": [str(i % 7) for i in range(1000)]})
+    q = lf.with_columns(c=pl.col("a") * 2).filter(pl.col("b") != "3")
+    path = tmp_path / "result.arrow"
+
+    result = q.collect_to_disk(path, compression="zstd")
+
+    assert isinstance(result, pl.LazyFrame)
+    assert path.exists()
+    assert_frame_equal(result.collect(), q.collect())
+    assert_frame_equal(pl.concat(result.collect_batches()), q.collect())
+    assert_frame_equal(result.slice(10, 5).collect(), q.collect().slice(10, 5))
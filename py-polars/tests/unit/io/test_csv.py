@@ -18,7 +18,12 @@ import zstandard
 
 import polars as pl
 from polars._utils.various import normalize_filepath
-from polars.exceptions import ComputeError, InvalidOperationError, NoDataError
+from polars.exceptions import (
+    ColumnNotFoundError,
+    ComputeError,
+    InvalidOperationError,
+    NoDataError,
+)
 from polars.io.csv import BatchedCsvReader
 from polars.testing import assert_frame_equal, assert_series_equal
 from tests.conftest import PlMonkeyPatch
@@ -195,6 +200,33 @@ def test_csv_null_values(chunk_override: None) -> None:
     assert df.rows() == [(None, "b", "c"), ("a", None, "c"), (None, "b", None)]
 
 
+def test_csv_null_values_per_column() -> None:
+    csv = textwrap.dedent(
+        """\
+        price,name,qty
+        NA,NA,-
+        -,x,1
+        1.5,-,NA
+        """
+    )
+    null_values = {"price": ["NA", "-"], "name": [], "qty": "-"}
+    df = pl.read_csv(io.StringIO(csv), null_values=null_values)
+    expected = pl.DataFrame(
+        {
+            "price": [None, None, 1.5],
+            "name": ["NA", "x", "-"],
+            "qty": [None, "1", "NA"],
+        }
+    )
+    assert_frame_equal(df, expected)
+    assert_frame_equal(
+        pl.scan_csv(io.StringIO(csv), null_values=null_values).collect(), expected
+    )
+
+    with pytest.raises(ColumnNotFoundError):
+        pl.read_csv(io.StringIO(csv), null_values={"missing": ["NA"]})
+
+
 def test_csv_missing_utf8_is_empty_string(chunk_override: None) -> None:
     # validate 'missing_utf8_is_empty_string' for missing fields that are...
     # >> ...leading
@@ -1933,6 +1965,66 @@ def test_csv_quote_styles(chunk_override: None) -> None:
     )
 
 
+def test_csv_column_options(chunk_override: None) -> None:
+    dtm = datetime(2077, 7, 5, 3, 1, 0)
+    df = pl.DataFrame(
+        {
+            "price": [1.5, 20.0],
+            "measurement": [1234.56, 0.25],
+            "name": ["a", "b"],
+            "datetime": [dtm, dtm],
+        }
+    )
+
+    assert df.write_csv(
+        float_precision=1,
+        datetime_format="%Y-%m-%d",
+        column_options={
+            "price": {"float_precision": 2},
+            "measurement": {"float_scientific": True, "float_precision": 3},
+            "name": {"quote_style": "always"},
+            "datetime": {"datetime_format": "%H:%M"},
+        },
+    ) == (
+        "price,measurement,name,datetime\n"
+        '1.50,1.235e3,"a",03:01\n'
+        '20.00,2.500e-1,"b",03:01\n'
+    )
+
+    f = io.BytesIO()
+    df.lazy().sink_csv(f, column_options={"name": {"quote_style": "always"}})
+    assert f.getvalue().decode() == (
+        "price,measurement,name,datetime\n"
+        '1.5,1234.56,"a",2077-07-05T03:01:00.000000\n'
+        '20.0,0.25,"b",2077-07-05T03:01:00.000000\n'
+    )
+
+    with pytest.raises(pl.exceptions.ColumnNotFoundError):
+        df.write_csv(column_options={"missing": {"quote_style": "always"}})
+
+
+def test_write_csv_escape_char() -> None:
+    df = pl.DataFrame({"a": ['say "hi"', "back\\slash", "plain"], "b": [1, 2, 3]})
+
+    assert df.write_csv(escape_char="\\") == (
+        "a,b\n"
+        '"say \\"hi\\"",1\n'
+        '"back\\\\slash",2\n'
+        "plain,3\n"
+    )
+    assert df.write_csv(escape_char="\\", quote_style="always") == (
+        '"a","b"\n'
+        '"say \\"hi\\"","1"\n'
+        '"back\\\\slash","2"\n'
+        '"plain","3"\n'
+    )
+    # Without an escape char, quotes are doubled.
+    assert df.write_csv() == 'a,b\n"say ""hi""",1\nback\\slash,2\nplain,3\n'
+
+    with pytest.raises(ValueError, match="single byte character"):
+        df.write_csv(escape_char="ab")
+
+
 def test_ignore_errors_casting_dtypes(chunk_override: None) -> None:
     csv = """inventory
 10
@@ -7,7 +7,7 @@ import math
 import re
 import zlib
 from collections import OrderedDict
-from datetime import datetime
+from datetime import date, datetime
 from decimal import Decimal as D
 from io import BytesIO
 from typing import TYPE_CHECKING
@@ -159,6 +159,66 @@ def test_write_json_decimal() -> None:
     assert value == """[{"a":"1.00"},{"a":"2.00"},{"a":null}]"""
 
 
+def test_write_json_temporal_format() -> None:
+    df = pl.DataFrame(
+        {
+            "date": [date(1970, 1, 2)],
+            "datetime": [datetime(1970, 1, 1, 0, 0, 1)],
+            "duration": pl.Series([1_000], dtype=pl.Duration("ms")),
+        }
+    )
+    assert df.write_json() == (
+        '[{"date":"1970-01-02","datetime":"1970-01-01 00:00:01",'
+        '"duration":"PT1S"}]'
+    )
+    assert df.write_json(temporal_format="epoch") == (
+        '[{"date":1,"datetime":1000000,"duration":1000}]'
+    )
+    assert df.write_ndjson(temporal_format="epoch") == (
+        '{"date":1,"datetime":1000000,"duration":1000}\n'
+    )
+
+    with pytest.raises(ValueError, match="`temporal_format` must be one of"):
+        df.write_json(temporal_format="unix")  # type: ignore[arg-type]
+
+
+def test_write_json_non_finite_floats() -> None:
+    df = pl.DataFrame({"a": [1.5, float("nan"), float("inf"), float("-inf"), None]})
+    assert df.write_ndjson() == (
+        '{"a":1.5}\n{"a":null}\n{"a":null}\n{"a":null}\n{"a":null}\n'
+    )
+    assert df.write_json(non_finite_floats="string") == (
+        '[{"a":1.5},{"a":"NaN"},{"a":"Infinity"},{"a":"-Infinity"},{"a":null}]'
+    )
+
+    df = pl.DataFrame({"s": [{"x": [float("nan")]}]})
+    assert df.write_ndjson(non_finite_floats="string") == '{"s":{"x":["NaN"]}}\n'
+
+
+def test_write_json_pretty() -> None:
+    df = pl.DataFrame(
+        {"s": [{"z": 1, "a": [], "m": ["x, y"]}], "b": ['{"not": "nested"}']}
+    )
+    out = df.write_json(pretty=True)
+    assert out == (
+        "[\n"
+        "  {\n"
+        '    "s": {\n'
+        '      "z": 1,\n'
+        '      "a": [],\n'
+        '      "m": [\n'
+        '        "x, y"\n'
+        "      ]\n"
+        "    },\n"
+        '    "b": "{\\"not\\": \\"nested\\"}"\n'
+        "  }\n"
+        "]"
+    )
+    assert json.loads(out) == json.loads(df.write_json())
+    # Struct fields keep their order.
+    assert list(json.loads(out)[0]["s"]) == ["z", "a", "m"]
+
+
 def test_json_infer_schema_length_11148() -> None:
     response = [{"col1": 1}] * 2 + [{"col1": 1, "col2": 2}] * 1
     with pytest.raises(
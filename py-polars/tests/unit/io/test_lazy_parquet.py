@@ -1889,3 +1889,46 @@ def test_sink_parquet_lazy_and_collect() -> None:
     assert q.collect().shape == (0, 0)
 
     assert_frame_equal(pl.scan_parquet(f).collect(), df)
+
+
+def test_scan_parquet_footers(tmp_path: Path) -> None:
+    dfs = [
+        pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]}),
+        pl.DataFrame({"a": [4, 5], "b": ["u", None]}),
+    ]
+    paths = [tmp_path / f"{i}.parquet" for i in range(len(dfs))]
+    for df, path in zip(dfs, paths):
+        df.write_parquet(path, row_group_size=2)
+
+    expected = pl.concat(dfs)
+    source = tmp_path / "*.parquet"
+
+    # The end of the file, starting at the serialized metadata.
+    footers = {}
+    for path in paths:
+        data = path.read_bytes()
+        metadata_len = int.from_bytes(data[-8:-4], "little")
+        footers[str(path)] = data[-(metadata_len + 8) :]
+
+    assert_frame_equal(pl.scan_parquet(source, footers=footers).collect(), expected)
+    assert_frame_equal(
+        pl.scan_parquet(source, footers=footers).filter(pl.col("a") > 3).collect(),
+        expected.filter(pl.col("a") > 3),
+    )
+
+    # A `_metadata` summary file, as written by PyArrow.
+    collector = []
+    for path in paths:
+        metadata = pq.read_metadata(path)
+        metadata.set_file_path(path.name)
+        collector.append(metadata)
+    pq.write_metadata(
+        pq.read_schema(paths[0]), tmp_path / "_metadata", metadata_collector=collector
+    )
+
+    assert_frame_equal(
+        pl.scan_parquet(source, footers=tmp_path / "_metadata").collect(), expected
+    )
+
+    with pytest.raises(ComputeError, match="invalid Parquet footer"):
+        pl.scan_parquet(source, footers={str(paths[0]): b"not a footer"})
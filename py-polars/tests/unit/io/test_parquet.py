@@ -6,6 +6,7 @@ import io
 import math
 import subprocess
 import sys
+import uuid
 import warnings
 from datetime import date, datetime, time, timezone
 from decimal import Decimal
@@ -1113,6 +1114,44 @@ def test_parquet_statistics_uint64_16683() -> None:
     assert statistics.max == u64_max
 
 
+def test_parquet_distinct_count_statistics(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "cat": pl.Series(["x", "y", None, "x"] * 250, dtype=pl.Categorical),
+            "int": [1, 2, None, 3] * 250,
+            "float": [float(i) for i in range(1000)],
+        }
+    )
+    path = tmp_path / "a.parquet"
+    df.write_parquet(path, statistics="full")
+
+    row_group = pq.read_metadata(path).row_group(0)
+    statistics = {
+        row_group.column(i).path_in_schema: row_group.column(i).statistics
+        for i in range(row_group.num_columns)
+    }
+
+    # Exact for dictionary-encoded columns.
+    assert statistics["cat"].distinct_count == 2
+    assert statistics["int"].distinct_count == 3
+    # Estimated otherwise.
+    assert statistics["float"].distinct_count == pytest.approx(1000, rel=0.05)
+
+    df.write_parquet(path)
+    statistics = pq.read_metadata(path).row_group(0).column(0).statistics
+    assert not statistics.has_distinct_count
+
+
+def test_parquet_distinct_count_group_by_estimate(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, 4] * 250, "b": range(1000)})
+    path = tmp_path / "a.parquet"
+    df.write_parquet(path, statistics="full")
+
+    q = pl.scan_parquet(path).group_by("a").agg(pl.col("b").sum())
+    # The 4 distinct values of the key, and the null group.
+    assert "est. rows: 5" in q.explain(format="tree")
+
+
 @pytest.mark.slow
 @pytest.mark.parametrize("nullable", [True, False])
 def test_read_byte_stream_split(nullable: bool) -> None:
@@ -1686,6 +1725,106 @@ def test_struct_prefiltered() -> None:
     )
 
 
+@pytest.mark.parametrize("parallel", ["auto", "prefiltered"])
+@pytest.mark.parametrize("n_rows", [None, 1, 7, 23, 100])
+def test_prefiltered_with_slice(parallel: ParallelStrategy, n_rows: int | None) -> None:
+    df = pl.DataFrame(
+        {
+            "a": range(30),
+            "b": [f"s{i}" for i in range(30)],
+            "c": [[i, i + 1] for i in range(30)],
+            "d": [{"x": i} for i in range(30)],
+        }
+    )
+    f = io.BytesIO()
+    df.write_parquet(f, row_group_size=10, data_page_size=16)
+
+    expected = df.head(n_rows) if n_rows is not None else df
+    for expr in [pl.col("a") % 3 == 0, pl.col("a") > 100, pl.col("a") < 100]:
+        f.seek(0)
+        result = (
+            pl.scan_parquet(f, n_rows=n_rows, parallel=parallel)
+            .with_row_index()
+            .filter(expr)
+            .collect()
+        )
+        assert_frame_equal(result, expected.with_row_index().filter(expr))
+
+
+@pytest.mark.parametrize(
+    "expr",
+    [
+        pl.col("a").struct.field("x") > 2,
+        pl.col("a").struct.field("x").is_between(1, 3),
+        pl.col("a").struct.field("x").is_null(),
+        pl.col("a").struct.field("y").struct.field("z") == "b",
+        (pl.col("a").struct.field("x") < 4) & (pl.col("b") != 2),
+    ],
+)
+def test_struct_field_column_predicates(expr: pl.Expr) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [
+                {"x": 1, "y": {"z": "a"}},
+                None,
+                {"x": None, "y": {"z": "b"}},
+                {"x": 3, "y": None},
+                {"x": 4, "y": {"z": "b"}},
+            ],
+            "b": [1, 2, 3, 4, 5],
+        }
+    )
+    f = io.BytesIO()
+    df.write_parquet(f, row_group_size=2)
+
+    f.seek(0)
+    result = pl.scan_parquet(f, parallel="prefiltered").filter(expr).collect()
+    assert_frame_equal(result, df.filter(expr))
+
+
+@pytest.mark.parametrize("parallel", ["auto", "prefiltered"])
+@pytest.mark.parametrize(
+    "query",
+    [
+        lambda lf: lf.select(pl.col("a").struct.field("x")),
+        lambda lf: lf.select(pl.col("a").struct.field("y").struct.field("z")),
+        lambda lf: lf.select(pl.col("a").struct.field("x") + pl.col("b")),
+        lambda lf: lf.filter(pl.col("a").struct.field("x") > 1).select(
+            pl.col("a").struct.field("y")
+        ),
+        lambda lf: lf.filter(pl.col("a").struct.field("x") > 1).select("a"),
+        lambda lf: lf.with_columns(c=pl.col("a").struct.field("x")),
+        lambda lf: lf.group_by("b", maintain_order=True).agg(
+            pl.col("a").struct.field("x").sum()
+        ),
+        lambda lf: lf.join(lf, on="b", maintain_order="left").select(
+            pl.col("a_right").struct.field("x")
+        ),
+    ],
+)
+def test_struct_field_projection_pushdown(
+    query: Callable[[pl.LazyFrame], pl.LazyFrame], parallel: ParallelStrategy
+) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [
+                {"x": 1, "y": {"z": "a"}, "w": [1.0]},
+                None,
+                {"x": None, "y": {"z": "b"}, "w": None},
+                {"x": 3, "y": None, "w": [2.0, 3.0]},
+                {"x": 4, "y": {"z": "b"}, "w": []},
+            ],
+            "b": [1, 2, 3, 4, 5],
+        }
+    )
+    f = io.BytesIO()
+    df.write_parquet(f, row_group_size=2)
+
+    f.seek(0)
+    result = query(pl.scan_parquet(f, parallel=parallel)).collect()
+    assert_frame_equal(result, query(df.lazy()).collect())
+
+
 @pytest.mark.parametrize(
     "data",
     [
@@ -2184,8 +2323,7 @@ def test_conserve_sortedness(
 
         captured = capfd.readouterr().err
 
-        # @NOTE: We don't conserve sortedness for anything except integers at the
-        # moment.
+        # @NOTE: We don't conserve sortedness for floats at the moment.
         assert (
             captured.count("Parquet conserved SortingColumn for column chunk of") == 1
         )
@@ -2295,6 +2433,165 @@ def test_decode_f16() -> None:
     )
 
 
+def test_decode_f16_without_arrow_schema() -> None:
+    values = [1.5, None, -0.25, 65504.0]
+    array = pa.array(
+        np.array([1.5, 0.0, -0.25, 65504.0], dtype=np.float16),
+        mask=np.array([False, True, False, False]),
+    )
+    table = pa.table({"x": array})
+
+    # The FLOAT16 logical type is then the only indication of the dtype.
+    f = io.BytesIO()
+    pq.write_table(table, f, store_schema=False)
+
+    f.seek(0)
+    assert_frame_equal(
+        pl.read_parquet(f), pl.Series("x", values, pl.Float16).to_frame()
+    )
+
+    f.seek(0)
+    assert pl.scan_parquet(f).filter(pl.col.x > 1.0).collect()["x"].to_list() == [
+        1.5,
+        65504.0,
+    ]
+
+
+def test_parquet_uuid() -> None:
+    values = [
+        "123e4567-e89b-12d3-a456-426614174000",
+        None,
+        "00000000-0000-0000-0000-00000000000a",
+    ]
+    uuids = [None if v is None else uuid.UUID(v).bytes for v in values]
+    table = pa.table({"id": pa.array(uuids, pa.uuid())})
+
+    # The UUID logical type is then the only indication of the dtype.
+    f = io.BytesIO()
+    pq.write_table(table, f, store_schema=False)
+
+    f.seek(0)
+    assert pl.read_parquet(f)["id"].to_list() == uuids
+
+    f.seek(0)
+    expected = pl.Series("id", values, pl.String).to_frame()
+    assert_frame_equal(pl.read_parquet(f, uuid_as_string=True), expected)
+
+    f.seek(0)
+    out = pl.scan_parquet(f, uuid_as_string=True).filter(pl.col.id > "1").collect()
+    assert out["id"].to_list() == [values[0]]
+
+    # Strings are written back with the UUID logical type.
+    f = io.BytesIO()
+    expected.write_parquet(f, uuid_columns=["id"])
+    f.seek(0)
+    assert pq.read_metadata(f).schema.column(0).logical_type.type == "UUID"
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), expected)
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="Parquet UUID"):
+        pl.DataFrame({"id": ["not a uuid"]}).write_parquet(
+            io.BytesIO(), uuid_columns=["id"]
+        )
+
+
+@pytest.mark.parametrize("use_pyarrow", [False, True])
+def test_parquet_categorical_columns(use_pyarrow: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "s": [f"value_{i % 7}" if i % 5 else None for i in range(10_000)],
+            "t": [f"{i}" * 30 for i in range(10_000)],
+            "i": range(10_000),
+        }
+    )
+
+    f = io.BytesIO()
+    if use_pyarrow:
+        # A small dictionary page limit makes pyarrow fall back to plain pages for
+        # "t", which are not dictionary-encoded.
+        pq.write_table(
+            df.to_arrow(), f, dictionary_pagesize_limit=1024, data_page_size=1024
+        )
+    else:
+        df.write_parquet(f, row_group_size=3_000)
+
+    f.seek(0)
+    out = pl.read_parquet(f, categorical_columns=["s", "t", "missing"])
+    assert out.schema == pl.Schema(
+        {"s": pl.Categorical, "t": pl.Categorical, "i": pl.Int64}
+    )
+    assert_frame_equal(out.cast({"s": pl.String, "t": pl.String}), df)
+
+    f.seek(0)
+    out = (
+        pl.scan_parquet(f, categorical_columns=["s"])
+        .filter(pl.col.s == "value_3", pl.col.i > 100)
+        .collect()
+    )
+    expected = df.filter(pl.col.s == "value_3", pl.col.i > 100)
+    assert_frame_equal(out.cast({"s": pl.String}), expected)
+
+    f.seek(0)
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="only string"):
+        pl.read_parquet(f, categorical_columns=["i"])
+
+
+def test_write_parquet_data_page_row_limit() -> None:
+    df = pl.DataFrame({"a": range(1_000), "b": [f"{i % 10}" for i in range(1_000)]})
+
+    f = io.BytesIO()
+    df.write_parquet(f, data_page_row_limit=100)
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+    f.seek(0)
+    assert_frame_equal(pl.from_arrow(pq.read_table(f)), df)  # type: ignore[arg-type]
+
+    with pytest.raises(ValueError, match="data_page_row_limit"):
+        df.write_parquet(io.BytesIO(), data_page_row_limit=100, use_pyarrow=True)
+
+
+def test_write_parquet_column_options() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [f"{i % 10}" for i in range(1_000)],
+            "b": [i % 5 / 7 for i in range(1_000)],
+            "c": pl.Series([f"{i % 3}" for i in range(1_000)], dtype=pl.Categorical),
+            "d": [i % 5 for i in range(1_000)],
+        }
+    )
+
+    f = io.BytesIO()
+    df.write_parquet(
+        f,
+        compression="snappy",
+        column_options={
+            "a": {"compression": "zstd", "compression_level": 10},
+            "b": {"compression": "uncompressed", "dictionary": False},
+            "c": {"dictionary": False},
+        },
+    )
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    f.seek(0)
+    row_group = pq.ParquetFile(f).metadata.row_group(0)
+    columns = [row_group.column(i) for i in range(4)]
+    assert [c.compression for c in columns] == [
+        "ZSTD",
+        "UNCOMPRESSED",
+        "SNAPPY",
+        "SNAPPY",
+    ]
+    assert [c.has_dictionary_page for c in columns] == [True, False, False, True]
+
+    with pytest.raises(pl.exceptions.ColumnNotFoundError):
+        df.write_parquet(io.BytesIO(), column_options={"x": {"dictionary": False}})
+    with pytest.raises(ValueError, match="column_options"):
+        df.write_parquet(
+            io.BytesIO(), column_options={"a": {"dictionary": False}}, use_pyarrow=True
+        )
+
+
 def test_invalid_utf8_binary() -> None:
     a = pl.Series("a", [b"\x80"], pl.Binary).to_frame()
     f = io.BytesIO()
@@ -2956,6 +3253,64 @@ def test_nested_deprecated_int96_timestamps_21332() -> None:
     )
 
 
+def test_int96_timestamps_out_of_range() -> None:
+    f = io.BytesIO()
+    dates = [datetime(1000, 1, 1), datetime(2025, 1, 1), None, datetime(3000, 1, 1)]
+    table = pa.table({"t": pa.array(dates, pa.timestamp("us"))})
+    # Without the Arrow schema, the time unit of INT96 columns is not known.
+    pq.write_table(table, f, use_deprecated_int96_timestamps=True, store_schema=False)
+
+    f.seek(0)
+    with pytest.raises(pl.exceptions.ComputeError, match="INT96 timestamp"):
+        pl.read_parquet(f, int96_overflow="raise")
+
+    f.seek(0)
+    out = pl.read_parquet(f, int96_time_unit="us")
+    assert out.schema == {"t": pl.Datetime("us")}
+    assert out["t"].to_list() == dates
+
+    f.seek(0)
+    out = pl.read_parquet(f, int96_overflow="null")
+    assert out.schema == {"t": pl.Datetime("ns")}
+    assert out["t"].to_list() == [None, datetime(2025, 1, 1), None, None]
+
+    f.seek(0)
+    out = pl.read_parquet(f, int96_overflow="saturate")
+    assert out["t"].dt.epoch("ns").to_list() == [
+        -(2**63),
+        1735689600 * 10**9,
+        None,
+        2**63 - 1,
+    ]
+
+    f.seek(0)
+    with pytest.raises(ValueError, match="`int96_overflow` must be one of"):
+        pl.read_parquet(f, int96_overflow="wrap")  # type: ignore[arg-type]
+    f.seek(0)
+    with pytest.raises(ValueError, match="`int64_timestamp_overflow` must be one of"):
+        pl.read_parquet(f, int64_timestamp_overflow="wrap")  # type: ignore[arg-type]
+
+
+def test_int96_time_zone() -> None:
+    f = io.BytesIO()
+    dates = [datetime(2025, 1, 1, 12), None]
+    table = pa.table({"t": pa.array(dates, pa.timestamp("us"))})
+    pq.write_table(table, f, use_deprecated_int96_timestamps=True, store_schema=False)
+
+    f.seek(0)
+    out = pl.read_parquet(f, int96_time_unit="us", int96_time_zone="Europe/Amsterdam")
+    assert out.schema == {"t": pl.Datetime("us", "Europe/Amsterdam")}
+    assert out["t"].dt.replace_time_zone(None).to_list() == [
+        datetime(2025, 1, 1, 13),
+        None,
+    ]
+    assert out["t"].dt.epoch("us").to_list() == table["t"].cast(pa.int64()).to_pylist()
+
+    f.seek(0)
+    out = pl.scan_parquet(f, int96_time_zone="UTC").collect()
+    assert out.schema == {"t": pl.Datetime("ns", "UTC")}
+
+
 def test_final_masked_optional_iteration_21378() -> None:
     # fmt: off
     values = [
@@ -3302,6 +3657,70 @@ def test_read_write_metadata(static: bool, lazy: bool) -> None:
     assert metadata == {k: v for k, v in actual.items() if k != "ARROW:schema"}
 
 
+def test_read_parquet_statistics(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, None, 4],
+            "s": [{"x": "p", "y": 1.5}, {"x": "q", "y": 2.5}] * 2,
+        }
+    )
+    path = tmp_path / "data.parquet"
+    df.write_parquet(path, row_group_size=2)
+
+    out = pl.read_parquet_statistics([path, path])
+    assert out.columns == [
+        "file",
+        "row_group",
+        "column",
+        "num_rows",
+        "null_count",
+        "distinct_count",
+        "min",
+        "max",
+        "compressed_size",
+        "uncompressed_size",
+    ]
+    assert out.height == 12
+    assert out["file"].unique().to_list() == [str(path)]
+    columns = ["row_group", "column", "null_count", "min", "max"]
+    assert out.head(6).select(columns).rows() == [
+        (0, "a", 0, "1", "2"),
+        (0, "s.x", 0, "p", "q"),
+        (0, "s.y", 0, "1.5", "2.5"),
+        (1, "a", 1, "4", "4"),
+        (1, "s.x", 0, "p", "q"),
+        (1, "s.y", 0, "1.5", "2.5"),
+    ]
+
+    f = io.BytesIO()
+    df.write_parquet(f)
+    f.seek(0)
+    out = pl.read_parquet_statistics(f)
+    assert out["file"].to_list() == [None, None, None]
+    assert out["num_rows"].to_list() == [4, 4, 4]
+
+
+def test_scan_parquet_collect_metadata() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    f = io.BytesIO()
+    df.write_parquet(f, metadata={"lineage": "job-42"})
+
+    f.seek(0)
+    q = pl.scan_parquet(f).filter(pl.col("a") > 1).join(df.lazy(), on="a")
+    metadata = q.collect_metadata()
+    assert metadata["lineage"] == "job-42"
+    assert "ARROW:schema" in metadata
+
+    f.seek(0)
+    lf = pl.scan_parquet(f, schema={"a": pl.Int64})
+    assert lf.collect_metadata()["lineage"] == "job-42"
+
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError, match="does not scan Parquet files"
+    ):
+        df.lazy().collect_metadata()
+
+
 @pytest.mark.write_disk
 def test_metadata_callback_info(tmp_path: Path) -> None:
     df = pl.DataFrame({"a": [1, 2, 3]})
@@ -3339,6 +3758,46 @@ def test_multiple_sorting_columns() -> None:
     assert_frame_equal(roundtrip.sort("b"), df.sort("b"))
 
 
+@pytest.mark.may_fail_cloud  # reason: sortedness flag
+def test_write_sorting_columns() -> None:
+    df = pl.DataFrame(
+        {
+            "nested": [[1], [2], [3], [4]],
+            "s": ["d", "c", "b", None],
+            "i": [2, 1, 4, 3],
+            "t": [date(2020, 1, day) for day in range(1, 5)],
+        }
+    ).with_columns(pl.col("s").set_sorted(descending=True), pl.col("t").set_sorted())
+
+    # The indices are those of the leaf columns.
+    expected = [pq.SortingColumn(1, descending=True), pq.SortingColumn(3)]
+
+    f = io.BytesIO()
+    df.write_parquet(f)
+    f.seek(0)
+    assert list(pq.ParquetFile(f).metadata.row_group(0).sorting_columns) == expected
+
+    f.seek(0)
+    out = pl.read_parquet(f)
+    assert_frame_equal(out, df)
+    assert out["s"].flags["SORTED_DESC"]
+    assert not out["i"].flags["SORTED_ASC"]
+
+    f = io.BytesIO()
+    df.lazy().sink_parquet(f, row_group_size=2)
+    f.seek(0)
+    metadata = pq.ParquetFile(f).metadata
+    assert metadata.num_row_groups == 2
+    for i in range(metadata.num_row_groups):
+        assert list(metadata.row_group(i).sorting_columns) == expected
+
+    # Without known sortedness, no sorting columns are written.
+    f = io.BytesIO()
+    df.select(pl.all().shuffle(seed=0)).write_parquet(f)
+    f.seek(0)
+    assert not pq.ParquetFile(f).metadata.row_group(0).sorting_columns
+
+
 @pytest.mark.write_disk
 def test_read_parquet_duplicate_range_start_fetch_23139(tmp_path: Path) -> None:
     tmp_path.mkdir(exist_ok=True)
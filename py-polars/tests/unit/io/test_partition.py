@@ -151,6 +151,21 @@ def test_partition_by_max_rows_per_file() -> None:
     ] == [10, 10, 10, 10, 10, 10, 10, 10, 10, 10]
 
 
+@pytest.mark.write_disk
+def test_sink_csv_max_rows_per_file(tmp_path: Path) -> None:
+    df = pl.select(x=pl.int_range(0, 25))
+    df.lazy().sink_csv(tmp_path / "data.csv", max_rows_per_file=10)
+
+    files = [tmp_path / f"data_{i}.csv" for i in range(3)]
+    assert sorted(tmp_path.iterdir()) == files
+    heights = [pl.scan_csv(f).select(pl.len()).collect().item() for f in files]
+    assert heights == [10, 10, 5]
+    assert_frame_equal(pl.scan_csv(files).collect(), df)
+
+    with pytest.raises(TypeError, match="require `path` to be a file path"):
+        df.lazy().sink_csv(io.BytesIO(), max_rows_per_file=10)
+
+
 @pytest.mark.parametrize("io_type", io_types)
 @pytest.mark.parametrize("engine", engines)
 def test_max_size_partition_lambda(
@@ -0,0 +1,105 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+    from pathlib import Path
+
+
+@pytest.mark.parametrize(
+    ("scan_func", "write_func", "ext"),
+    [
+        (pl.scan_parquet, pl.DataFrame.write_parquet, "parquet"),
+        (pl.scan_ipc, pl.DataFrame.write_ipc, "ipc"),
+        (pl.scan_csv, pl.DataFrame.write_csv, "csv"),
+        (pl.scan_ndjson, pl.DataFrame.write_ndjson, "jsonl"),
+    ],
+)
+def test_scan_listing_max_files(
+    scan_func: Callable[..., pl.LazyFrame],
+    write_func: Callable[[pl.DataFrame, Path], None],
+    ext: str,
+    tmp_path: Path,
+) -> None:
+    for i in range(3):
+        write_func(pl.DataFrame({"a": [i]}), tmp_path / f"{i}.{ext}")
+
+    lf = scan_func(
+        tmp_path / f"*.{ext}", listing_options=pl.FileListingOptions(max_files=3)
+    )
+    assert_frame_equal(lf.collect(), pl.DataFrame({"a": [0, 1, 2]}))
+
+    with pytest.raises(pl.exceptions.ComputeError, match="maximum of 2"):
+        scan_func(
+            tmp_path / f"*.{ext}", listing_options=pl.FileListingOptions(max_files=2)
+        ).collect()
+
+
+def test_scan_listing_file_name_filter(tmp_path: Path) -> None:
+    for i in range(4):
+        pl.DataFrame({"a": [i]}).write_parquet(tmp_path / f"{i}.parquet")
+
+    listing_options = pl.FileListingOptions(
+        file_name_filter=lambda name: int(name.split(".")[0]) % 2 == 0
+    )
+
+    assert_frame_equal(
+        pl.scan_parquet(tmp_path, listing_options=listing_options).collect(),
+        pl.DataFrame({"a": [0, 2]}),
+    )
+    assert_frame_equal(
+        pl.scan_parquet(
+            tmp_path / "*.parquet", listing_options=listing_options
+        ).collect(),
+        pl.DataFrame({"a": [0, 2]}),
+    )
+
+
+def test_scan_listing_lister(tmp_path: Path) -> None:
+    for i in range(3):
+        pl.DataFrame({"a": [i]}).write_parquet(tmp_path / f"{i}.parquet")
+
+    listed_paths: list[str] = []
+
+    def lister(paths: list[str]) -> list[str]:
+        listed_paths.extend(paths)
+        return [str(tmp_path / "2.parquet"), str(tmp_path / "0.parquet")]
+
+    lf = pl.scan_parquet(
+        tmp_path / "*.parquet", listing_options=pl.FileListingOptions(lister=lister)
+    )
+
+    assert_frame_equal(lf.collect(), pl.DataFrame({"a": [2, 0]}))
+    assert listed_paths
+    assert all(p.endswith("*.parquet") for p in listed_paths)
+
+
+def test_scan_listing_lister_hive(tmp_path: Path) -> None:
+    for i in range(3):
+        (tmp_path / f"p={i}").mkdir()
+        pl.DataFrame({"a": [i]}).write_parquet(tmp_path / f"p={i}/0.parquet")
+
+    listing_options = pl.FileListingOptions(
+        lister=lambda _: [str(tmp_path / f"p={i}/0.parquet") for i in (0, 2)],
+        file_name_filter=lambda name: name.endswith(".parquet"),
+        listing_concurrency=4,
+    )
+
+    assert_frame_equal(
+        pl.scan_parquet(tmp_path, listing_options=listing_options).collect(),
+        pl.DataFrame({"a": [0, 2], "p": [0, 2]}),
+    )
+
+
+def test_scan_listing_options_invalid() -> None:
+    with pytest.raises(ValueError, match="listing_concurrency"):
+        pl.FileListingOptions(listing_concurrency=0)
+
+    with pytest.raises(ValueError, match="max_files"):
+        pl.FileListingOptions(max_files=-1)
@@ -0,0 +1,138 @@
+from __future__ import annotations
+
+import os
+from datetime import datetime, timezone
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+    from pathlib import Path
+
+
+@pytest.mark.parametrize(
+    ("scan_func", "write_func", "ext"),
+    [
+        (pl.scan_parquet, pl.DataFrame.write_parquet, "parquet"),
+        (pl.scan_ipc, pl.DataFrame.write_ipc, "ipc"),
+        (pl.scan_csv, pl.DataFrame.write_csv, "csv"),
+        (pl.scan_ndjson, pl.DataFrame.write_ndjson, "jsonl"),
+    ],
+)
+def test_scan_row_index_in_file(
+    scan_func: Callable[..., pl.LazyFrame],
+    write_func: Callable[[pl.DataFrame, Path], None],
+    ext: str,
+    tmp_path: Path,
+) -> None:
+    write_func(pl.DataFrame({"a": [1, 2, 3]}), tmp_path / f"1.{ext}")
+    write_func(pl.DataFrame({"a": [4, 5]}), tmp_path / f"2.{ext}")
+
+    lf = scan_func(
+        [tmp_path / f"1.{ext}", tmp_path / f"2.{ext}"],
+        include_row_index_in_file="row_in_file",
+    ).with_row_index()
+
+    expected = pl.DataFrame(
+        {
+            "index": [0, 1, 2, 3, 4],
+            "a": [1, 2, 3, 4, 5],
+            "row_in_file": [0, 1, 2, 0, 1],
+        },
+        schema_overrides={
+            "index": pl.get_index_type(),
+            "row_in_file": pl.get_index_type(),
+        },
+    )
+
+    assert_frame_equal(lf.collect(), expected)
+    assert_frame_equal(lf.slice(2, 2).collect(), expected.slice(2, 2))
+    assert_frame_equal(lf.tail(1).collect(), expected.tail(1))
+    assert_frame_equal(
+        lf.filter(pl.col("a") % 2 == 0).collect(),
+        expected.filter(pl.col("a") % 2 == 0),
+    )
+    assert_frame_equal(
+        lf.filter(pl.col("row_in_file") == 1).select("a").collect(),
+        pl.DataFrame({"a": [2, 5]}),
+    )
+    assert_frame_equal(
+        lf.select("row_in_file").collect(), expected.select("row_in_file")
+    )
+
+
+def test_scan_parquet_row_group_index(tmp_path: Path) -> None:
+    pl.DataFrame({"a": range(5)}).write_parquet(tmp_path / "1.pq", row_group_size=2)
+    pl.DataFrame({"a": range(5, 8)}).write_parquet(tmp_path / "2.pq", row_group_size=2)
+
+    lf = pl.scan_parquet(
+        [tmp_path / "1.pq", tmp_path / "2.pq"],
+        include_row_index_in_file="row_in_file",
+        include_row_group_index="row_group",
+    )
+
+    expected = pl.DataFrame(
+        {
+            "a": range(8),
+            "row_in_file": [0, 1, 2, 3, 4, 0, 1, 2],
+            "row_group": [0, 0, 1, 1, 2, 0, 0, 1],
+        },
+        schema_overrides={
+            "row_in_file": pl.get_index_type(),
+            "row_group": pl.get_index_type(),
+        },
+    )
+
+    assert_frame_equal(lf.collect(), expected)
+    assert_frame_equal(lf.slice(3, 4).collect(), expected.slice(3, 4))
+    assert_frame_equal(
+        lf.filter(pl.col("a") > 3).collect(), expected.filter(pl.col("a") > 3)
+    )
+    assert_frame_equal(lf.select("row_group").collect(), expected.select("row_group"))
+
+
+def test_scan_row_group_index_unsupported(tmp_path: Path) -> None:
+    pl.DataFrame({"a": [1]}).write_ipc(tmp_path / "1.ipc")
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="Parquet"):
+        pl.scan_ipc(
+            tmp_path / "1.ipc", include_row_group_index="row_group"
+        ).collect_schema()
+
+
+def test_scan_file_modification_time(tmp_path: Path) -> None:
+    path = tmp_path / "1.parquet"
+    pl.DataFrame({"a": [1, 2]}).write_parquet(path)
+
+    mtime = datetime(2024, 1, 2, 3, 4, 5, tzinfo=timezone.utc)
+    os.utime(path, (mtime.timestamp(), mtime.timestamp()))
+
+    lf = pl.scan_parquet(path, include_file_modification_time="mtime")
+
+    assert lf.collect_schema() == pl.Schema(
+        {"a": pl.Int64, "mtime": pl.Datetime("ms", "UTC")}
+    )
+    assert_frame_equal(
+        lf.collect(),
+        pl.DataFrame(
+            {"a": [1, 2], "mtime": [mtime, mtime]},
+            schema_overrides={"mtime": pl.Datetime("ms", "UTC")},
+        ),
+    )
+    assert_frame_equal(
+        lf.filter(pl.col("mtime") == mtime).select("a").collect(),
+        pl.DataFrame({"a": [1, 2]}),
+    )
+
+
+def test_scan_provenance_name_conflict(tmp_path: Path) -> None:
+    pl.DataFrame({"a": [1]}).write_parquet(tmp_path / "1.pq")
+
+    with pytest.raises(pl.exceptions.DuplicateError, match="provenance"):
+        pl.scan_parquet(
+            tmp_path / "1.pq", include_row_index_in_file="a"
+        ).collect_schema()
@@ -0,0 +1,96 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+def test_scan_pruning_report_hive(tmp_path: Path) -> None:
+    for year in (2023, 2024):
+        (tmp_path / f"year={year}").mkdir()
+        pl.DataFrame({"a": [1, 2]}).write_parquet(tmp_path / f"year={year}/0.parquet")
+
+    report = (
+        pl.scan_parquet(tmp_path)
+        .filter(pl.col("year") == 2024)
+        .scan_pruning_report()
+    )
+
+    assert report.columns == [
+        "scan",
+        "path",
+        "pruned",
+        "reason",
+        "row_groups",
+        "pruned_row_groups",
+        "predicate",
+    ]
+    assert report["path"].str.contains("year=2023").to_list() == [True, False]
+    assert_frame_equal(
+        report.select("pruned", "reason", "row_groups"),
+        pl.DataFrame(
+            {
+                "pruned": [True, False],
+                "reason": ["hive partitions", None],
+                "row_groups": [None, 1],
+            },
+            schema_overrides={"row_groups": pl.get_index_type()},
+        ),
+    )
+    assert report["predicate"].str.contains("year").all()
+
+
+def test_scan_pruning_report_row_groups(tmp_path: Path) -> None:
+    path = tmp_path / "1.parquet"
+    pl.DataFrame({"a": range(6)}).write_parquet(path, row_group_size=2)
+
+    lf = pl.scan_parquet(path)
+
+    report = lf.filter(pl.col("a") >= 3).scan_pruning_report()
+    assert report["pruned"].to_list() == [False]
+    assert report["row_groups"].to_list() == [3]
+    assert report["pruned_row_groups"].to_list() == [[0]]
+
+    report = lf.scan_pruning_report()
+    assert report["pruned_row_groups"].to_list() == [[]]
+    assert report["predicate"].to_list() == [None]
+
+
+
+def test_scan_pruning_report_struct_field(tmp_path: Path) -> None:
+    path = tmp_path / "1.parquet"
+    df = pl.DataFrame({"s": [{"a": i, "b": str(i)} for i in range(6)]})
+    df.write_parquet(path, row_group_size=2)
+
+    lf = pl.scan_parquet(path)
+
+    q = lf.filter(pl.col("s").struct.field("a") >= 3)
+    assert q.scan_pruning_report()["pruned_row_groups"].to_list() == [[0]]
+    assert_frame_equal(q.collect(), df.filter(pl.col("s").struct.field("a") >= 3))
+
+    q = lf.filter(pl.col("s").struct.field("b") == "4")
+    assert q.scan_pruning_report()["pruned_row_groups"].to_list() == [[0, 1]]
+
+    # The min and max of a struct do not bound the struct itself.
+    q = lf.filter(pl.col("s") == {"a": 0, "b": "0"})
+    assert q.scan_pruning_report()["pruned_row_groups"].to_list() == [[]]
+
+def test_scan_pruning_report_multiple_scans(tmp_path: Path) -> None:
+    pl.DataFrame({"a": [1, 2]}).write_csv(tmp_path / "1.csv")
+    pl.DataFrame({"a": [1, 2]}).write_parquet(tmp_path / "1.parquet")
+
+    q = pl.concat(
+        [pl.scan_csv(tmp_path / "1.csv"), pl.scan_parquet(tmp_path / "1.parquet")]
+    )
+    report = q.scan_pruning_report()
+
+    assert report.height == 2
+    assert sorted(report["scan"].to_list()) == [0, 1]
+    assert not report["pruned"].any()
+
+    csv_report = report.filter(pl.col("path").str.ends_with(".csv"))
+    assert csv_report["row_groups"].to_list() == [None]
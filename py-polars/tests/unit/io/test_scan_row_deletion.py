@@ -256,6 +256,55 @@ def test_scan_row_deletions(
     )
 
 
+@pytest.mark.parametrize("row_index_offset", [0, 27])
+def test_scan_row_deletions_positions(
+    data_files_path: Path,
+    write_position_deletes: WritePositionDeletes,
+    row_index_offset: int,
+) -> None:
+    positions = {0: [1, 2], 1: [0, 1, 2], 4: [2, 3]}
+
+    q = pl.scan_parquet(
+        data_files_path,
+        _deletion_files=("position-delete", positions),
+        hive_partitioning=False,
+    ).with_row_index(offset=row_index_offset)
+
+    expect = pl.scan_parquet(
+        data_files_path,
+        _deletion_files=(
+            "iceberg-position-delete",
+            {
+                k: [write_position_deletes(pl.Series(v))]
+                for k, v in positions.items()
+            },
+        ),
+        hive_partitioning=False,
+    ).with_row_index(offset=row_index_offset)
+
+    assert q.select(pl.len()).collect().item() == 18
+    assert_frame_equal(q.collect(), expect.collect())
+    assert_frame_equal(q.head(10).collect(), expect.head(10).collect())
+    assert_frame_equal(q.tail(10).collect(), expect.tail(10).collect())
+    assert_frame_equal(q.slice(5, 10).collect(), expect.slice(5, 10).collect())
+    assert_frame_equal(
+        q.filter(pl.col("physical_index") > 10).collect(),
+        expect.filter(pl.col("physical_index") > 10).collect(),
+    )
+
+    # Positions may be given by any iterable of integers, e.g. a Series.
+    q = pl.scan_parquet(
+        data_files_path,
+        _deletion_files=("position-delete", {1: pl.Series([4, 0, 2])}),
+        hive_partitioning=False,
+    )
+    assert q.collect()["physical_index"].to_list() == [
+        0, 1, 2, 3, 4,
+        6, 8,
+        *range(10, 25),
+    ]  # fmt: skip
+
+
 @pytest.mark.slow
 @pytest.mark.write_disk
 @pytest.mark.parametrize("ideal_morsel_size", [999, 50, 33])
@@ -0,0 +1,176 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+    from pathlib import Path
+
+
+@pytest.mark.parametrize(
+    ("scan_func", "write_func", "ext"),
+    [
+        (pl.scan_parquet, pl.DataFrame.write_parquet, "parquet"),
+        (pl.scan_ipc, pl.DataFrame.write_ipc, "ipc"),
+        (pl.scan_csv, pl.DataFrame.write_csv, "csv"),
+    ],
+)
+def test_scan_schema_evolution_rename_and_default(
+    scan_func: Callable[..., pl.LazyFrame],
+    write_func: Callable[[pl.DataFrame, Path], None],
+    ext: str,
+    tmp_path: Path,
+) -> None:
+    write_func(
+        pl.DataFrame({"id": [1], "temperature": [20.5], "unit": ["kelvin"]}),
+        tmp_path / f"1.{ext}",
+    )
+    write_func(pl.DataFrame({"id": [2], "temp": [21.5]}), tmp_path / f"2.{ext}")
+
+    sources = [tmp_path / f"1.{ext}", tmp_path / f"2.{ext}"]
+    policy = pl.SchemaEvolutionPolicy(
+        renames={"temp": "temperature"},
+        defaults={"unit": "celsius"},
+    )
+
+    expected = pl.DataFrame(
+        {
+            "id": [1, 2],
+            "temperature": [20.5, 21.5],
+            "unit": ["kelvin", "celsius"],
+        }
+    )
+
+    lf = scan_func(sources, schema_evolution=policy)
+    assert lf.collect_schema() == expected.schema
+    assert_frame_equal(lf.collect(), expected)
+    assert_frame_equal(
+        lf.select("temperature").collect(), expected.select("temperature")
+    )
+    assert_frame_equal(
+        lf.filter(pl.col("temperature") > 21).collect(), expected.slice(1)
+    )
+
+    # The rename is resolved on the schema of the first file as well.
+    lf = scan_func(sources[::-1], schema_evolution=policy)
+    assert_frame_equal(lf.collect(), expected.reverse())
+
+
+def test_scan_schema_evolution_type_widening(tmp_path: Path) -> None:
+    pl.DataFrame({"a": [1]}, schema={"a": pl.Int32}).write_parquet(tmp_path / "1.pq")
+    pl.DataFrame({"a": [2]}, schema={"a": pl.Int64}).write_parquet(tmp_path / "2.pq")
+    pl.DataFrame({"a": [3.5]}).write_parquet(tmp_path / "3.pq")
+
+    sources = [tmp_path / "1.pq", tmp_path / "2.pq"]
+
+    with pytest.raises(pl.exceptions.SchemaError):
+        pl.scan_parquet(sources).collect()
+
+    # The first file is narrower than the later ones, so widening alone is not enough.
+    with pytest.raises(pl.exceptions.SchemaError):
+        pl.scan_parquet(
+            sources, schema_evolution=pl.SchemaEvolutionPolicy(type_widening="integer")
+        ).collect()
+
+    policy = pl.SchemaEvolutionPolicy(type_widening="integer", dtypes={"a": pl.Int64})
+    assert_frame_equal(
+        pl.scan_parquet(sources, schema_evolution=policy).collect(),
+        pl.DataFrame({"a": [1, 2]}, schema={"a": pl.Int64}),
+    )
+
+    policy = pl.SchemaEvolutionPolicy(
+        type_widening=["integer", "integer-to-float"], dtypes={"a": pl.Float64}
+    )
+    assert_frame_equal(
+        pl.scan_parquet(
+            [*sources, tmp_path / "3.pq"], schema_evolution=policy
+        ).collect(),
+        pl.DataFrame({"a": [1.0, 2.0, 3.5]}),
+    )
+
+
+def test_scan_schema_evolution_extra_columns(tmp_path: Path) -> None:
+    pl.DataFrame({"a": [1], "b": [1]}).write_parquet(tmp_path / "1.pq")
+    pl.DataFrame({"a": [2], "b_old": [2], "c": [2]}).write_parquet(tmp_path / "2.pq")
+
+    sources = [tmp_path / "1.pq", tmp_path / "2.pq"]
+    policy = pl.SchemaEvolutionPolicy(renames={"b_old": "b"})
+
+    # Former names are not extra columns, but unrelated new columns still are.
+    with pytest.raises(
+        pl.exceptions.SchemaError,
+        match=r"extra column in file outside of expected schema: c",
+    ):
+        pl.scan_parquet(sources, schema_evolution=policy).collect()
+
+    assert_frame_equal(
+        pl.scan_parquet(
+            sources, schema_evolution=policy, extra_columns="ignore"
+        ).collect(),
+        pl.DataFrame({"a": [1, 2], "b": [1, 2]}),
+    )
+
+
+def test_scan_schema_evolution_missing_column_raises(tmp_path: Path) -> None:
+    pl.DataFrame({"a": [1], "b": [1]}).write_parquet(tmp_path / "1.pq")
+    pl.DataFrame({"a": [2]}).write_parquet(tmp_path / "2.pq")
+
+    sources = [tmp_path / "1.pq", tmp_path / "2.pq"]
+
+    # Columns without a default still follow `missing_columns`.
+    with pytest.raises(pl.exceptions.ColumnNotFoundError):
+        pl.scan_parquet(
+            sources, schema_evolution=pl.SchemaEvolutionPolicy(defaults={"c": 0})
+        ).collect()
+
+    assert_frame_equal(
+        pl.scan_parquet(
+            sources, schema_evolution=pl.SchemaEvolutionPolicy(defaults={"b": 0})
+        ).collect(),
+        pl.DataFrame({"a": [1, 2], "b": [1, 0]}),
+    )
+
+
+def test_scan_schema_evolution_invalid_default(tmp_path: Path) -> None:
+    pl.DataFrame({"a": [1]}).write_parquet(tmp_path / "1.pq")
+
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError,
+        match="invalid default value for column 'a'",
+    ):
+        pl.scan_parquet(
+            tmp_path / "1.pq",
+            schema_evolution=pl.SchemaEvolutionPolicy(defaults={"a": "x"}),
+        ).collect_schema()
+
+
+@pytest.mark.parametrize(
+    "kwargs",
+    [
+        {"renames": {"a_old": "a"}},
+        {"defaults": {"a": 1}},
+    ],
+)
+def test_scan_schema_evolution_ndjson_unsupported(
+    kwargs: dict[str, Any], tmp_path: Path
+) -> None:
+    pl.DataFrame({"a": [1]}).write_ndjson(tmp_path / "1.jsonl")
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="NDJSON"):
+        pl.scan_ndjson(
+            tmp_path / "1.jsonl",
+            schema_evolution=pl.SchemaEvolutionPolicy(**kwargs),
+        ).collect()
+
+    assert_frame_equal(
+        pl.scan_ndjson(
+            tmp_path / "1.jsonl",
+            schema_evolution=pl.SchemaEvolutionPolicy(dtypes={"a": pl.Float64}),
+        ).collect(),
+        pl.DataFrame({"a": [1.0]}),
+    )
@@ -0,0 +1,110 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+    from pathlib import Path
+
+
+def test_write_statistics_sidecar(tmp_path: Path) -> None:
+    pl.DataFrame({"a": [1, 2, 2], "b": ["x", None, "y"]}).write_csv(
+        tmp_path / "0.csv"
+    )
+    pl.DataFrame({"a": [5, 6, 7], "b": ["z", "z", "z"]}).write_csv(
+        tmp_path / "1.csv"
+    )
+
+    sidecar_path = tmp_path / ".polars_stats"
+    pl.scan_csv(tmp_path / "*.csv").write_statistics_sidecar(sidecar_path)
+
+    assert_frame_equal(
+        pl.read_ipc(sidecar_path),
+        pl.DataFrame(
+            {
+                "path": ["0.csv", "1.csv"],
+                "len": [3, 3],
+                "a_min": [1, 5],
+                "a_max": [2, 7],
+                "a_nc": [0, 0],
+                "a_ndv": [2, 3],
+                "b_min": ["x", "z"],
+                "b_max": ["y", "z"],
+                "b_nc": [1, 0],
+                "b_ndv": [2, 1],
+            },
+            schema_overrides={
+                k: pl.get_index_type()
+                for k in ("len", "a_nc", "a_ndv", "b_nc", "b_ndv")
+            },
+        ),
+    )
+
+
+@pytest.mark.parametrize(
+    ("scan_func", "write_func", "ext"),
+    [
+        (pl.scan_csv, pl.DataFrame.write_csv, "csv"),
+        (pl.scan_ndjson, pl.DataFrame.write_ndjson, "jsonl"),
+        (pl.scan_parquet, pl.DataFrame.write_parquet, "parquet"),
+    ],
+)
+def test_scan_statistics_sidecar_skips_files(
+    scan_func: Callable[..., pl.LazyFrame],
+    write_func: Callable[[pl.DataFrame, Path], None],
+    ext: str,
+    tmp_path: Path,
+) -> None:
+    for i in range(3):
+        write_func(pl.DataFrame({"a": [10 * i, 10 * i + 1]}), tmp_path / f"{i}.{ext}")
+
+    sidecar_path = tmp_path / ".polars_stats"
+    scan_func(tmp_path / f"*.{ext}").write_statistics_sidecar(sidecar_path)
+
+    q = scan_func(tmp_path / f"*.{ext}", statistics_sidecar=sidecar_path).filter(
+        pl.col("a") >= 20
+    )
+
+    report = q.scan_pruning_report()
+    assert report["pruned"].to_list() == [True, True, False]
+    assert report["reason"].to_list() == ["table statistics"] * 2 + [None]
+
+    assert_frame_equal(q.collect(), pl.DataFrame({"a": [20, 21]}))
+
+
+def test_scan_statistics_sidecar_missing_file(tmp_path: Path) -> None:
+    pl.DataFrame({"a": [1]}).write_csv(tmp_path / "0.csv")
+
+    sidecar_path = tmp_path / ".polars_stats"
+    pl.scan_csv(tmp_path / "*.csv").write_statistics_sidecar(sidecar_path)
+
+    # Files without statistics in the sidecar are never skipped.
+    pl.DataFrame({"a": [2]}).write_csv(tmp_path / "1.csv")
+
+    q = pl.scan_csv(tmp_path / "*.csv", statistics_sidecar=sidecar_path).filter(
+        pl.col("a") > 1
+    )
+
+    assert q.scan_pruning_report()["pruned"].to_list() == [True, False]
+    assert_frame_equal(q.collect(), pl.DataFrame({"a": [2]}))
+
+
+def test_scan_statistics_sidecar_row_estimation(tmp_path: Path) -> None:
+    for i in range(2):
+        pl.DataFrame({"a": range(5)}).write_csv(tmp_path / f"{i}.csv")
+
+    sidecar_path = tmp_path / ".polars_stats"
+    pl.scan_csv(tmp_path / "*.csv").write_statistics_sidecar(sidecar_path)
+
+    lf = pl.scan_csv(tmp_path / "*.csv", statistics_sidecar=sidecar_path)
+    assert "ESTIMATED ROWS: 10" in lf.explain()
+
+
+def test_write_statistics_sidecar_requires_scan() -> None:
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="file scan"):
+        pl.LazyFrame({"a": [1]}).write_statistics_sidecar("stats")
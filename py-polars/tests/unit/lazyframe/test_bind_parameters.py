@@ -0,0 +1,53 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+def test_bind_parameters() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, 4, 5], "b": ["x", "y", "x", "y", "x"]})
+    query = df.lazy().filter(
+        pl.col("a").is_between(pl.param("low"), pl.param("high")),
+        pl.col("b") == pl.param("b"),
+    )
+
+    for low, high, b in [(1, 3, "x"), (2, 5, "y"), (4, 4, "x")]:
+        out = query.bind_parameters({"low": low, "high": high, "b": b}).collect()
+        expected = df.filter(pl.col("a").is_between(low, high), pl.col("b") == b)
+        assert_frame_equal(out, expected)
+
+
+def test_bind_parameters_partial() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+    query = lf.select(pl.col("a") * pl.param("scale") + pl.param("offset"))
+
+    partial = query.bind_parameters({"scale": 2})
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError, match="parameter 'offset' is not bound"
+    ):
+        partial.collect()
+
+    out = partial.bind_parameters({"offset": 1}).collect()
+    assert out["a"].to_list() == [3, 5, 7]
+
+
+def test_bind_parameters_in_join_and_group_by() -> None:
+    left = pl.LazyFrame({"k": [1, 1, 2], "v": [1, 2, 3]})
+    right = pl.LazyFrame({"k": [1, 2], "w": [10, 20]})
+    query = (
+        left.join(right, on="k")
+        .group_by("k")
+        .agg((pl.col("v") * pl.param("f")).sum())
+        .sort("k")
+    )
+
+    out = query.bind_parameters({"f": 10}).collect()
+    assert out.to_dict(as_series=False) == {"k": [1, 2], "v": [30, 30]}
+
+
+def test_bind_parameters_non_literal() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+    with pytest.raises(ValueError, match="must be bound to a literal"):
+        lf.select(pl.param("x")).bind_parameters({"x": pl.col("a")})
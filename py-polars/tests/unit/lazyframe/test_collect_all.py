@@ -1,5 +1,5 @@
 from pathlib import Path
-from typing import cast
+from typing import Any, cast
 
 import pytest
 
@@ -54,3 +54,35 @@ def test_collect_all_groupby_lazy_sink_issue_26296(tmp_path: Path) -> None:
 
     expected_sink = pl.DataFrame({"g": ["A"], "v": [1]})
     assert_frame_equal(pl.read_parquet(tmp_file), expected_sink)
+
+
+@pytest.mark.parametrize(
+    "kwargs",
+    [
+        {"priorities": [0, 2, 1]},
+        {"max_concurrent_queries": 1},
+        {"priorities": [1, 1, 2], "max_concurrent_queries": 2},
+        {"memory_limit": 1},
+        {"max_concurrent_requests": 1},
+    ],
+)
+def test_collect_all_scheduled(kwargs: dict[str, Any]) -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3], "b": [4, 5, 6]})
+    lfs = [lf.select(pl.col.a.sum()), lf.select(pl.col.b.max()), lf.head(1)]
+
+    out = pl.collect_all(lfs, **kwargs)
+    for result, expected in zip(out, pl.collect_all(lfs), strict=True):
+        assert_frame_equal(result, expected)
+
+
+def test_collect_all_scheduled_errors() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="2 priorities"):
+        pl.collect_all([lf], priorities=[1, 2])
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="must be positive"):
+        pl.collect_all([lf], max_concurrent_queries=0)
+    with pytest.raises(pl.exceptions.ColumnNotFoundError):
+        pl.collect_all([lf, lf.select("b")], max_concurrent_queries=1)
+    with pytest.raises(ValueError, match="lazy=False"):
+        pl.collect_all([lf], priorities=[1], lazy=True)  # type: ignore[call-overload]
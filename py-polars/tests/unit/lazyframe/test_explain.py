@@ -15,23 +15,34 @@ def test_lf_explain_format_tree() -> None:
                 0                            1
    ┌─────────────────────────────────────────────────────────
    │
-   │        ╭────────╮
- 0 │        │ SELECT │
-   │        ╰───┬┬───╯
+   │     ╭──────────────╮
+   │     │ SELECT       │
+ 0 │     │ est. rows: 1 │
+   │     ╰──────┬┬──────╯
    │            ││
    │            │╰───────────────────────────╮
    │            │                            │
-   │  ╭─────────┴──────────╮                 │
-   │  │ expression:        │  ╭──────────────┴──────────────╮
-   │  │ [(col("a")         │  │ FROM:                       │
- 1 │  │   .sum()) + (len() │  │ DF ["a", "b"]               │
-   │  │   .cast(Int64))]   │  │ PROJECT: ["a"]; 1/2 COLUMNS │
+   │  ╭─────────┴──────────╮  ╭──────────────┴──────────────╮
+   │  │ expression:        │  │ FROM:                       │
+   │  │ [(col("a")         │  │ DF ["a", "b"]               │
+ 1 │  │   .sum()) + (len() │  │ PROJECT: ["a"]; 1/2 COLUMNS │
+   │  │   .cast(Int64))]   │  │ est. rows: 4                │
    │  ╰────────────────────╯  ╰─────────────────────────────╯
 \
 """
     assert result == expected
 
 
+def test_lf_explain_analyze() -> None:
+    lf = pl.LazyFrame({"a": range(100)})
+
+    result = lf.filter(pl.col("a") > 97).explain_analyze()
+
+    assert "rows: 100, est. 100" in result
+    # The filter is estimated to keep half of the rows.
+    assert "rows: 2, est. 50 (!)" in result
+
+
 def test_lf_explain_tree_format_deprecated() -> None:
     lf = pl.LazyFrame({"a": [1, 2, 3, 4], "b": [5, 6, 7, 8]})
 
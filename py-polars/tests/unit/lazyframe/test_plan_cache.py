@@ -0,0 +1,85 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+
+@pytest.fixture
+def plan_cache() -> Iterator[None]:
+    pl.clear_plan_cache()
+    pl.set_plan_cache_capacity(16)
+    yield
+    pl.set_plan_cache_capacity(0)
+    pl.clear_plan_cache()
+
+
+@pytest.mark.usefixtures("plan_cache")
+def test_plan_cache_substitutes_literals() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, 4, 5], "b": ["x", "y", "x", "y", "x"]})
+
+    def query(threshold: int, b: str) -> pl.LazyFrame:
+        return df.lazy().filter(pl.col("a") > threshold, pl.col("b") == b)
+
+    for threshold, b in [(1, "x"), (1, "x"), (0, "y"), (3, "x")]:
+        expected = df.filter(pl.col("a") > threshold, pl.col("b") == b)
+        assert_frame_equal(query(threshold, b).collect(), expected)
+
+    stats = pl.plan_cache_stats()
+    assert stats["hits"] >= 3
+    assert stats["capacity"] == 16
+
+
+@pytest.mark.usefixtures("plan_cache")
+def test_plan_cache_null_literal() -> None:
+    df = pl.DataFrame({"a": [1, None, 3]})
+
+    def query(value: int | None) -> pl.DataFrame:
+        fill_value = pl.lit(value, pl.Int64)
+        return df.lazy().select(pl.col("a").fill_null(fill_value)).collect()
+
+    assert query(0)["a"].to_list() == [1, 0, 3]
+    # A null is never substituted for another value.
+    assert query(None)["a"].to_list() == [1, None, 3]
+    assert query(2)["a"].to_list() == [1, 2, 3]
+
+
+@pytest.mark.usefixtures("plan_cache")
+def test_plan_cache_streaming() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, 4, 5]})
+
+    def query(threshold: int) -> pl.DataFrame:
+        q = df.lazy().filter(pl.col("a") > threshold).select(pl.col("a").sum())
+        return q.collect(engine="streaming")
+
+    # The second query reuses the physical plan of the first one.
+    for threshold, expected in [(2, 12), (2, 12), (0, 15), (0, 15)]:
+        assert query(threshold).item() == expected
+
+
+def test_plan_cache_capacity() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    pl.set_plan_cache_capacity(1)
+    try:
+        df.lazy().select(pl.col("a") + 1).collect()
+        df.lazy().select(pl.col("a").sum()).collect()
+        assert pl.plan_cache_stats()["len"] == 1
+
+        pl.clear_plan_cache()
+        assert pl.plan_cache_stats() == {
+            "hits": 0,
+            "misses": 0,
+            "len": 0,
+            "capacity": 1,
+        }
+    finally:
+        pl.set_plan_cache_capacity(0)
+
+    with pytest.raises(ValueError, match="non-negative"):
+        pl.set_plan_cache_capacity(-1)
@@ -0,0 +1,30 @@
+from __future__ import annotations
+
+from datetime import datetime
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError
+
+
+def test_available_time_zones() -> None:
+    time_zones = pl.available_time_zones()
+    assert time_zones == sorted(time_zones)
+    assert {"UTC", "Europe/Amsterdam", "Etc/GMT-1"} <= set(time_zones)
+
+
+def test_tzdb_version() -> None:
+    version = pl.tzdb_version()
+    assert isinstance(version, str)
+
+    with pl.Config(tzdb_version=version):
+        s = pl.Series([datetime(2024, 1, 1)]).dt.replace_time_zone("Europe/Amsterdam")
+        assert s.dtype == pl.Datetime("us", "Europe/Amsterdam")
+
+    with (
+        pl.Config(tzdb_version="1900a"),
+        pytest.raises(ComputeError, match="version '1900a' was pinned"),
+    ):
+        pl.Series([datetime(2024, 1, 1)], dtype=pl.Datetime(time_zone="Asia/Tokyo"))
+
@@ -2082,6 +2082,110 @@ def test_string_normalize_wrong_input() -> None:
         pl.Series(["01²"], dtype=pl.String).str.normalize("foobar")  # type: ignore[arg-type]
 
 
+def test_remove_diacritics() -> None:
+    s = pl.Series(["Crème Brûlée", "Łódź", "Ørsted Ångström", "e\u0301", "日本", None])
+    expected = pl.Series(["Creme Brulee", "Lodz", "Orsted Angstrom", "e", "日本", None])
+    assert_series_equal(s.str.remove_diacritics(), expected)
+
+    # Joining on names that are written with and without diacritics.
+    left = pl.DataFrame({"name": ["José Núñez", "Zoë"], "x": [1, 2]})
+    right = pl.DataFrame({"name": ["Jose Nunez", "Zoe"], "y": [3, 4]})
+    out = left.join(
+        right,
+        left_on=pl.col("name").str.remove_diacritics(),
+        right_on="name",
+    )
+    assert out["y"].to_list() == [3, 4]
+
+
+def test_collation_key() -> None:
+    words = ["zebra", "Öl", "apfel", "Äpfel", "Ober", None]
+    df = pl.DataFrame({"word": words})
+
+    # Byte-wise, the umlauts sort after all ASCII letters.
+    out = df.sort("word", nulls_last=True)
+    assert out["word"].to_list() == ["Ober", "apfel", "zebra", "Äpfel", "Öl", None]
+
+    out = df.sort("word", collation="de", nulls_last=True)
+    assert out["word"].to_list() == ["apfel", "Äpfel", "Ober", "Öl", "zebra", None]
+
+    out = df.lazy().sort("word", collation="sv").collect()
+    assert out["word"].to_list() == [None, "apfel", "Ober", "zebra", "Äpfel", "Öl"]
+
+    out = df.select(pl.col("word").sort(collation="de", descending=True))
+    assert out["word"].to_list() == [None, "zebra", "Öl", "Ober", "Äpfel", "apfel"]
+
+    key = pl.col("word").str.collation_key("de")
+    out = df.select(key, lt=key < key.shift(-1))
+    assert out.schema["word"] == pl.Binary
+    assert out["lt"].to_list() == [False, False, True, True, None, None]
+
+    # Primary strength ignores accents and case, so these form one group.
+    df = pl.DataFrame({"city": ["Malmö", "malmo", "Lund", "MALMO"], "n": [1, 2, 3, 4]})
+    out = df.group_by(
+        pl.col("city").str.collation_key("de", strength="primary").alias("key"),
+        maintain_order=True,
+    ).agg(pl.col("city").first(), pl.col("n").sum())
+    assert out.drop("key").rows() == [("Malmö", 7), ("Lund", 3)]
+
+    with pytest.raises(InvalidOperationError, match="invalid collation locale"):
+        pl.Series(["a"]).str.collation_key("not a locale")
+    with pytest.raises(ValueError, match="`strength` must be one of"):
+        pl.col("a").str.collation_key("de", strength="weak")  # type: ignore[arg-type]
+
+
+def test_natural_sort_key() -> None:
+    files = ["file10", "file2", None, "file1.txt", "file02b", "file", "File3"]
+    df = pl.DataFrame({"file": files, "n": [1, 2, 3, 4, 5, 6, 7]})
+
+    out = df.sort("file", natural=True, nulls_last=True)
+    assert out["file"].to_list() == [
+        "File3",
+        "file",
+        "file1.txt",
+        "file2",
+        "file02b",
+        "file10",
+        None,
+    ]
+
+    out = df.select(pl.col("file").sort(natural=True, descending=True))
+    assert out["file"].to_list() == [
+        None,
+        "file10",
+        "file02b",
+        "file2",
+        "file1.txt",
+        "file",
+        "File3",
+    ]
+
+    # Natural ordering can be enabled per column in a multi-column sort.
+    df = pl.DataFrame(
+        {"a": ["x10", "x9", "x10", "x9"], "b": ["y10", "y9", "y9", "y10"]}
+    )
+    out = df.lazy().sort("a", "b", natural=[True, False]).collect()
+    assert out.rows() == [("x9", "y10"), ("x9", "y9"), ("x10", "y10"), ("x10", "y9")]
+
+    out = df.select(pl.col("a").str.natural_sort_key())
+    assert out.schema["a"] == pl.Binary
+
+    with pytest.raises(ValueError, match="cannot combine"):
+        df.sort("a", natural=True, collation="de")
+
+
+def test_is_ascii() -> None:
+    s = pl.Series(["abc", "Zoë", "", "tab\t", "\x7f", "日本", None])
+    expected = pl.Series([True, False, True, True, True, False, None])
+    assert_series_equal(s.str.is_ascii(), expected)
+    assert pl.Series(["ab"]).str.remove_diacritics().str.is_ascii().all()
+
+
+def test_titlecase_unicode() -> None:
+    s = pl.Series(["ǆemal", "ﬁsh ﬂour", "straße", "ᾳ ᾀ", "Ǉubljana"])
+    assert s.str.to_titlecase().to_list() == [v.title() for v in s]
+
+
 def test_to_integer_unequal_lengths_22034() -> None:
     s = pl.Series("a", ["1", "2", "3"], pl.String)
     with pytest.raises(pl.exceptions.ShapeError):
@@ -42,6 +42,29 @@ def test_dt_to_string(series_of_int_dates: pl.Series) -> None:
     assert_series_equal(series_of_int_dates.dt.strftime("%F"), expected_str_dates)
 
 
+def test_dt_to_string_locale() -> None:
+    s = pl.Series([date(2024, 3, 5), date(2024, 7, 1)])
+
+    assert s.dt.to_string("%A %-d %B %Y", locale="fr_FR").to_list() == [
+        "mardi 5 mars 2024",
+        "lundi 1 juillet 2024",
+    ]
+    assert s.dt.strftime("%B", locale="de_DE").to_list() == ["März", "Juli"]
+
+    s_dtm = s.cast(pl.Datetime("ms")).dt.replace_time_zone("Europe/Paris")
+    assert s_dtm.dt.to_string("%B %H:%M %Z", locale="fr_FR").to_list() == [
+        "mars 00:00 CET",
+        "juillet 00:00 CEST",
+    ]
+
+    # Times have no month or weekday names.
+    s_tm = pl.Series([time(1, 2, 3)])
+    assert s_tm.dt.to_string("%H:%M", locale="fr_FR").to_list() == ["01:02"]
+
+    with pytest.raises(InvalidOperationError, match="unknown locale 'xx_XX'"):
+        s.dt.to_string("%B", locale="xx_XX")
+
+
 @pytest.mark.parametrize(
     ("unit_attr", "expected"),
     [
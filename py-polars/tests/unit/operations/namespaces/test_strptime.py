@@ -956,3 +956,33 @@ def test_strptime_inexact_datetime_date_only_26713() -> None:
         pl.col("date_str").str.strptime(pl.Datetime(time_unit="ns"), "%Y-%m-%d")
     )
     assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize("locale", ["fr_FR", "de_DE", "es_ES"])
+@pytest.mark.parametrize("format", ["%A %d %B %Y", "%a %d %b %Y"])
+def test_strptime_locale_roundtrip(locale: str, format: str) -> None:
+    s = pl.date_range(date(2024, 1, 1), date(2024, 12, 31), "9d", eager=True)
+
+    s_str = s.dt.to_string(format, locale=locale)
+    assert_series_equal(s_str.str.to_date(format, locale=locale), s)
+    assert_series_equal(s_str.str.strptime(pl.Date, format, locale=locale), s)
+
+
+def test_strptime_locale() -> None:
+    s = pl.Series(["Mardi 5 MARS 2024 10:30", "lundi 1 juil. 2024 23:59", "x"])
+
+    result = s.str.to_datetime(
+        "%A %d %B %Y %H:%M", time_unit="ms", strict=False, locale="fr_FR"
+    )
+    expected = pl.Series(
+        [datetime(2024, 3, 5, 10, 30), datetime(2024, 7, 1, 23, 59), None],
+        dtype=pl.Datetime("ms"),
+    )
+    assert_series_equal(result, expected)
+
+    # English names are still accepted.
+    result = pl.Series(["5 March 2024"]).str.to_date("%d %B %Y", locale="fr_FR")
+    assert result.to_list() == [date(2024, 3, 5)]
+
+    with pytest.raises(InvalidOperationError, match="unknown locale 'xx_XX'"):
+        s.str.to_date("%d %B %Y", locale="xx_XX")
@@ -0,0 +1,85 @@
+from __future__ import annotations
+
+from datetime import date, datetime
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_mask_email() -> None:
+    s = pl.Series(
+        "email", ["john.doe@example.com", "@example.com", "no email", "", None]
+    )
+    expected = pl.Series(
+        "email", ["j***@example.com", "***@example.com", "***", "***", None]
+    )
+    assert_series_equal(s.str.mask_email(), expected)
+
+
+def test_mask_digits() -> None:
+    df = pl.DataFrame({"a": ["4111-1111-1111-1234", "+1 (555) 010", "12", None]})
+    result = df.select(
+        all=pl.col("a").str.mask_digits(),
+        keep=pl.col("a").str.mask_digits(keep_last=4),
+    )
+    expected = pl.DataFrame(
+        {
+            "all": ["****-****-****-****", "+* (***) ***", "**", None],
+            "keep": ["****-****-****-1234", "+* (**5) 010", "12", None],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize(
+    ("granularity", "expected"),
+    [
+        ("year", date(2024, 1, 1)),
+        ("quarter", date(2024, 4, 1)),
+        ("month", date(2024, 5, 1)),
+        ("week", date(2024, 5, 13)),
+        ("day", date(2024, 5, 17)),
+    ],
+)
+def test_generalize_date(granularity: str, expected: date) -> None:
+    s = pl.Series([date(2024, 5, 17), None])
+    result = s.dt.generalize_date(granularity)  # type: ignore[arg-type]
+    assert result.to_list() == [expected, None]
+
+    s = pl.Series([datetime(2024, 5, 17, 13, 45)])
+    result = s.dt.generalize_date(granularity)  # type: ignore[arg-type]
+    assert result.to_list() == [datetime.combine(expected, datetime.min.time())]
+
+
+def test_generalize_date_invalid() -> None:
+    with pytest.raises(ValueError, match="`granularity` must be one of"):
+        pl.col("a").dt.generalize_date("hour")  # type: ignore[arg-type]
+
+
+def test_bucketize_numeric() -> None:
+    df = pl.DataFrame({"a": [5, 1, None, 3, 3, 9, 2]})
+    result = df.select(pl.col("a").bucketize_numeric(2)).unnest("a")
+    expected = pl.DataFrame(
+        {
+            "lower": [5, 1, None, 3, 3, 5, 1],
+            "upper": [9, 2, None, 3, 3, 9, 2],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_bucketize_numeric_k_anonymity() -> None:
+    df = pl.DataFrame({"a": [1.5, 1.5, 1.5, 2.0, 3.0, 4.0, 4.5, 8.0]})
+    result = df.select(pl.col("a").bucketize_numeric(3)).unnest("a")
+    assert result.schema == {"lower": pl.Float64, "upper": pl.Float64}
+    assert result.rows() == [(1.5, 1.5)] * 3 + [(2.0, 8.0)] * 5
+
+
+def test_bucketize_numeric_errors() -> None:
+    with pytest.raises(InvalidOperationError, match="must be positive"):
+        pl.select(pl.lit(1).bucketize_numeric(0))
+    with pytest.raises(InvalidOperationError):
+        pl.select(pl.lit("a").bucketize_numeric(1))
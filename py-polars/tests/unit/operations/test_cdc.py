@@ -0,0 +1,66 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, SchemaError
+from polars.testing import assert_frame_equal
+
+
+def test_cdc() -> None:
+    old = pl.DataFrame({"id": [1, 2, 3, 4], "value": ["a", "b", "c", None]})
+    new = pl.DataFrame({"id": [5, 4, 3, 2], "value": ["e", None, "x", "b"]})
+
+    assert_frame_equal(
+        old.cdc(new, on="id"),
+        pl.DataFrame(
+            {
+                "id": [1, 3, 3, 5],
+                "value": ["a", "c", "x", "e"],
+                "__change_type": ["delete", "update_pre", "update_post", "insert"],
+            }
+        ),
+    )
+
+
+def test_cdc_multiple_keys_and_null_keys() -> None:
+    old = pl.DataFrame(
+        {"a": [1, 1, None], "b": ["x", "y", "z"], "value": [1.0, 2.0, 3.0]}
+    )
+    new = pl.DataFrame(
+        {"b": ["z", "y", "x"], "a": [None, 1, 2], "value": [3.5, 2.0, 1.0]}
+    )
+
+    assert_frame_equal(
+        old.cdc(new, on=["a", "b"]),
+        pl.DataFrame(
+            {
+                "a": [1, None, None, 2],
+                "b": ["x", "z", "z", "x"],
+                "value": [1.0, 3.0, 3.5, 1.0],
+                "__change_type": ["delete", "update_pre", "update_post", "insert"],
+            }
+        ),
+    )
+
+
+def test_cdc_no_changes() -> None:
+    df = pl.DataFrame({"id": [1, 2], "value": [[1], [2, None]]})
+
+    out = df.cdc(df, on="id")
+    assert out.height == 0
+    assert out.schema == pl.Schema(
+        {"id": pl.Int64, "value": pl.List(pl.Int64), "__change_type": pl.String}
+    )
+
+
+def test_cdc_invalid() -> None:
+    df = pl.DataFrame({"id": [1, 1], "value": [1, 2]})
+    with pytest.raises(ComputeError, match="duplicate keys"):
+        df.cdc(df, on="id")
+
+    df = pl.DataFrame({"id": [1, 2], "value": [1, 2]})
+    with pytest.raises(SchemaError, match="dtypes of column 'value'"):
+        df.cdc(df.with_columns(pl.col("value").cast(pl.Float64)), on="id")
+    with pytest.raises(SchemaError, match="same columns"):
+        df.cdc(df.with_columns(other=1), on="id")
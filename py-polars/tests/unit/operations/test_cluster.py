@@ -0,0 +1,50 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ColumnNotFoundError, InvalidOperationError
+from polars.testing import assert_series_equal
+
+
+def test_kmeans() -> None:
+    df = pl.DataFrame(
+        {
+            "x": [0.0, 0.1, 0.2, 10.0, 10.1, 10.2, 20.0, 20.1],
+            "y": [0, 1, 0, 10, 11, 10, 0, 1],
+        }
+    )
+    labels = df.kmeans(["x", "y"], 3, seed=0)
+    assert labels.name == "cluster"
+    assert labels.dtype == pl.get_index_type()
+
+    groups = df.with_columns(labels).group_by("cluster").agg(pl.col("x").min())
+    assert sorted(groups["x"].to_list()) == [0.0, 10.0, 20.0]
+    assert labels.n_unique() == 3
+
+    assert_series_equal(labels, df.kmeans(["x", "y"], 3, seed=0))
+
+
+def test_kmeans_invalid() -> None:
+    df = pl.DataFrame({"x": [1.0, None], "s": ["a", "b"]})
+    with pytest.raises(InvalidOperationError, match="null"):
+        df.kmeans("x", 1)
+    with pytest.raises(InvalidOperationError, match="numeric"):
+        df.kmeans("s", 1)
+    with pytest.raises(ColumnNotFoundError):
+        df.kmeans("z", 1)
+
+
+def test_dbscan() -> None:
+    df = pl.DataFrame(
+        {
+            "x": [0.0, 0.5, 1.0, 10.0, 10.5, 11.0, 50.0],
+            "y": [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+        }
+    )
+    result = df.dbscan(["x", "y"], 0.6, min_pts=2)
+    expected = pl.Series("cluster", [0, 0, 0, 1, 1, 1, None], pl.get_index_type())
+    assert_series_equal(result, expected)
+
+    # With a larger minimum, no row is a core point.
+    assert df.dbscan("x", 0.6, min_pts=4).null_count() == df.height
@@ -0,0 +1,83 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError
+
+
+def test_convolve_modes() -> None:
+    df = pl.DataFrame({"a": [1.0, 2.0, 3.0]})
+    kernel = [0.0, 1.0, 0.5]
+
+    result = df.select(
+        full=pl.col("a").convolve(kernel, "full").implode(),
+        same=pl.col("a").convolve(kernel).implode(),
+        valid=pl.col("a").convolve(kernel, "valid").implode(),
+    )
+    assert result.row(0) == (
+        [0.0, 1.0, 2.5, 4.0, 1.5],
+        [1.0, 2.5, 4.0],
+        [2.5],
+    )
+
+
+def test_convolve_integers() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    result = df.select(pl.col("a").convolve([1, 1]))
+    assert result.schema == {"a": pl.Float64}
+    assert result["a"].to_list() == [1.0, 3.0, 5.0]
+
+
+@pytest.mark.parametrize(
+    ("null_behavior", "expected"),
+    [
+        ("propagate", [None, None]),
+        ("zero", [1.0, 3.0]),
+        ("renormalize", [2.0, 6.0]),
+    ],
+)
+def test_convolve_nulls(null_behavior: str, expected: list[float | None]) -> None:
+    df = pl.DataFrame({"a": [1.0, None, 3.0]})
+    result = df.select(
+        pl.col("a").convolve(
+            [1.0, 1.0],
+            "valid",
+            null_behavior=null_behavior,  # type: ignore[arg-type]
+        )
+    )
+    assert result["a"].to_list() == expected
+
+
+def test_convolve_over() -> None:
+    df = pl.DataFrame({"g": [1, 1, 1, 2, 2], "a": [1.0, 2.0, 3.0, 4.0, 5.0]})
+    result = df.select(pl.col("a").convolve([1.0, 1.0, 1.0]).over("g"))
+    assert result["a"].to_list() == [3.0, 6.0, 5.0, 9.0, 9.0]
+
+
+def test_savgol_reproduces_polynomial() -> None:
+    x = [float(i * i - 3 * i) for i in range(10)]
+    df = pl.DataFrame({"a": x})
+    result = df.select(pl.col("a").savgol(5, 2))
+    assert result["a"].to_list() == pytest.approx(x)
+
+
+def test_savgol_smooths() -> None:
+    df = pl.DataFrame({"a": [0.0, 0.0, 35.0, 0.0, 0.0]})
+    result = df.select(pl.col("a").savgol(5, 2))
+    # The center value is the classic 5-point quadratic weight `17 / 35`.
+    assert result["a"][2] == pytest.approx(17.0)
+
+
+def test_savgol_short_input() -> None:
+    df = pl.DataFrame({"a": [1.0, 2.0]})
+    result = df.select(pl.col("a").savgol(5, 2))
+    assert result["a"].to_list() == [None, None]
+
+
+def test_savgol_invalid() -> None:
+    df = pl.DataFrame({"a": [1.0, 2.0, 3.0, 4.0, 5.0]})
+    with pytest.raises(ComputeError, match="must be odd"):
+        df.select(pl.col("a").savgol(4, 2))
+    with pytest.raises(ComputeError, match="less than `window`"):
+        df.select(pl.col("a").savgol(3, 3))
@@ -72,6 +72,27 @@ def test_cut_include_breaks_lazy_schema() -> None:
     assert_frame_equal(result, expected, categorical_as_str=True)
 
 
+def test_cut_include_bounds() -> None:
+    lf = pl.LazyFrame({"a": [-2, -1, 0, 1, 2]})
+
+    result = lf.select(
+        pl.col("a").cut([-1, 1], left_closed=True, include_bounds=True).alias("cut")
+    ).unnest("cut")
+
+    expected = pl.LazyFrame(
+        {
+            "category": ["[-inf, -1)", "[-1, 1)", "[-1, 1)", "[1, inf)", "[1, inf)"],
+            "lower": [-inf, -1.0, -1.0, 1.0, 1.0],
+            "upper": [-1.0, 1.0, 1.0, inf, inf],
+        },
+        schema_overrides={"category": pl.Categorical},
+    )
+    assert_frame_equal(result, expected, categorical_as_str=True)
+
+    with pytest.raises(ValueError, match="cannot both be set"):
+        pl.col("a").cut([0], include_breaks=True, include_bounds=True)
+
+
 def test_cut_null_values() -> None:
     s = pl.Series([-1.0, None, 1.0, 2.0, None, 8.0, 4.0])
 
@@ -0,0 +1,79 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+
+@pytest.fixture
+def privacy_budget() -> Iterator[None]:
+    pl.set_privacy_budget(10.0)
+    yield
+    pl.set_privacy_budget(None)
+
+
+@pytest.mark.usefixtures("privacy_budget")
+def test_dp_aggregations() -> None:
+    df = pl.DataFrame({"x": [1, 5, 1000]})
+    out = df.select(
+        pl.col("x").dp_sum(1.0, (0, 10)).alias("sum"),
+        pl.col("x").dp_count(1.0).alias("count"),
+        pl.col("x").dp_mean(1.0, (0, 10)).alias("mean"),
+    )
+    assert out.schema == pl.Schema(
+        {"sum": pl.Float64, "count": pl.Float64, "mean": pl.Float64}
+    )
+    # The mean is clipped to the bounds.
+    assert out["mean"].is_between(0, 10).all()
+    assert pl.privacy_budget_remaining() == 7.0
+
+
+def test_dp_requires_budget() -> None:
+    df = pl.DataFrame({"x": [1, 5, 1000]})
+    with pytest.raises(InvalidOperationError, match="requires a privacy budget"):
+        df.select(pl.col("x").dp_count(1.0))
+
+    pl.set_privacy_budget(1.5)
+    try:
+        df.select(pl.col("x").dp_count(1.0))
+        with pytest.raises(InvalidOperationError, match="exceeds the remaining"):
+            df.lazy().select(pl.col("x").dp_count(1.0)).collect(engine="streaming")
+        assert pl.privacy_budget_remaining() == 0.5
+    finally:
+        pl.set_privacy_budget(None)
+
+
+@pytest.mark.usefixtures("privacy_budget")
+def test_dp_data_derived_groups() -> None:
+    lf = pl.LazyFrame({"g": ["a", "a", "b"], "x": [1, 5, 1000]})
+    for q in [
+        lf.group_by("g").agg(pl.col("x").dp_count(1.0)),
+        lf.select(pl.col("x").dp_count(1.0).over("g")),
+        lf.select(pl.col("x").implode().list.eval(pl.element().dp_count(1.0))),
+    ]:
+        with pytest.raises(InvalidOperationError, match="not supported in"):
+            q.collect()
+    assert pl.privacy_budget_remaining() == 10.0
+
+
+@pytest.mark.parametrize(
+    "bounds", [(10, 0), (0, float("inf")), (float("-inf"), 0), (float("nan"), 1)]
+)
+def test_dp_invalid_bounds(bounds: tuple[float, float]) -> None:
+    with pytest.raises(InvalidOperationError, match="bounds"):
+        pl.col("x").dp_sum(1.0, bounds)
+    with pytest.raises(InvalidOperationError, match="bounds"):
+        pl.col("x").dp_mean(1.0, bounds)
+
+
+@pytest.mark.parametrize("epsilon", [0.0, -1.0, float("inf"), float("nan")])
+def test_dp_invalid_epsilon(epsilon: float) -> None:
+    df = pl.DataFrame({"x": [1, 2, 3]})
+    with pytest.raises(InvalidOperationError, match="epsilon"):
+        df.lazy().select(pl.col("x").dp_count(epsilon)).collect_schema()
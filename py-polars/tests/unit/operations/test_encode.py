@@ -0,0 +1,86 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_target_encode() -> None:
+    df = pl.DataFrame(
+        {
+            "city": ["a", "a", "a", "b", "b", "c"],
+            "price": [1.0, 2.0, None, 10.0, 20.0, 4.0],
+        }
+    )
+
+    result = df.select(
+        pl.col("city").target_encode("price"),
+        pl.col("city").target_encode("price", smoothing=2.0).alias("smooth"),
+        pl.col("city").target_encode("price", leave_one_out=True).alias("loo"),
+    )
+
+    # The overall mean of the target is 37 / 5.
+    prior = 7.4
+    expected = pl.DataFrame(
+        {
+            "city": [1.5, 1.5, 1.5, 15.0, 15.0, 4.0],
+            "smooth": [
+                (3.0 + 2 * prior) / 4,
+                (3.0 + 2 * prior) / 4,
+                (3.0 + 2 * prior) / 4,
+                (30.0 + 2 * prior) / 4,
+                (30.0 + 2 * prior) / 4,
+                (4.0 + 2 * prior) / 3,
+            ],
+            # A category with a single value falls back to the mean of the other rows.
+            "loo": [2.0, 1.0, 1.5, 20.0, 10.0, 33.0 / 4],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_target_encode_leave_one_out_smoothing() -> None:
+    df = pl.DataFrame({"city": ["a", "a", "b"], "price": [1.0, 3.0, 8.0]})
+
+    result = df.select(
+        pl.col("city").target_encode("price", smoothing=1.0, leave_one_out=True)
+    )
+
+    # Both the category mean and the overall mean exclude the row itself.
+    expected = pl.DataFrame(
+        {
+            "city": [
+                (3.0 + (3.0 + 8.0) / 2) / 2,
+                (1.0 + (1.0 + 8.0) / 2) / 2,
+                (1.0 + 3.0) / 2,
+            ]
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_target_encode_lazy_schema() -> None:
+    lf = pl.LazyFrame({"city": ["a", "b"], "sold": [True, False]})
+
+    result = lf.select(pl.col("city").target_encode("sold"))
+
+    assert result.collect_schema() == {"city": pl.Float64}
+    assert result.collect()["city"].to_list() == [1.0, 0.0]
+
+
+def test_target_encode_invalid_smoothing() -> None:
+    with pytest.raises(ValueError, match="non-negative"):
+        pl.col("city").target_encode("price", smoothing=-1.0)
+    for smoothing in [float("nan"), float("inf")]:
+        with pytest.raises(ValueError, match="finite"):
+            pl.col("city").target_encode("price", smoothing=smoothing)
+
+
+def test_freq_encode() -> None:
+    s = pl.Series("city", ["a", "b", None, "a"])
+
+    result = s.to_frame().select(pl.col("city").freq_encode()).to_series()
+
+    expected = pl.Series("city", [0.5, 0.25, 0.25, 0.5])
+    assert_series_equal(result, expected)
@@ -0,0 +1,60 @@
+from __future__ import annotations
+
+import cmath
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError
+
+
+def naive_dft(x: list[float]) -> list[complex]:
+    n = len(x)
+    return [
+        sum(v * cmath.exp(-2j * cmath.pi * k * t / n) for t, v in enumerate(x))
+        for k in range(n)
+    ]
+
+
+@pytest.mark.parametrize("n", [1, 2, 7, 8, 12, 100])
+def test_fft(n: int) -> None:
+    x = [float((i * 7) % 5) - 1.5 for i in range(n)]
+    df = pl.DataFrame({"x": x})
+
+    result = df.select(pl.col("x").fft()).unnest("x")
+    assert result.columns == ["re", "im"]
+
+    expected = naive_dft(x)
+    assert result["re"].to_list() == pytest.approx([c.real for c in expected])
+    assert result["im"].to_list() == pytest.approx([c.imag for c in expected])
+
+
+def test_fft_schema() -> None:
+    lf = pl.LazyFrame({"x": [1, 2, 3]})
+    assert lf.select(pl.col("x").fft()).collect_schema() == pl.Schema(
+        {"x": pl.Struct({"re": pl.Float64, "im": pl.Float64})}
+    )
+
+
+def test_fft_nulls() -> None:
+    with pytest.raises(ComputeError, match="null"):
+        pl.DataFrame({"x": [1.0, None]}).select(pl.col("x").fft())
+
+
+def test_power_spectrum() -> None:
+    x = [0.5, 1.0, None, 2.0, -1.0, 3.0, 0.0, 1.5]
+    df = pl.DataFrame({"x": x})
+
+    result = df.select(pl.col("x").power_spectrum(3))["x"]
+    assert result.dtype == pl.List(pl.Float64)
+    assert result.null_count() == 5
+
+    for i in range(5, 8):
+        expected = [abs(c) ** 2 / 3 for c in naive_dft(x[i - 2 : i + 1])][:2]  # type: ignore[arg-type]
+        assert result[i].to_list() == pytest.approx(expected)
+
+
+def test_power_spectrum_over() -> None:
+    df = pl.DataFrame({"g": [1, 1, 2, 2, 2], "x": [1.0, -1.0, 1.0, 1.0, 1.0]})
+    result = df.select(pl.col("x").power_spectrum(2).over("g"))["x"]
+    assert result.to_list() == [None, [0.0, 2.0], None, [2.0, 0.0], [2.0, 0.0]]
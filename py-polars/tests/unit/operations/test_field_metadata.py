@@ -0,0 +1,123 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+import polars.selectors as cs
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {"speed": [1.0, 2.5, 4.0], "g": ["a", "b", "a"], "n": [1, 2, 3]}
+    ).with_columns(
+        pl.col("speed").with_field_metadata({"unit": "m/s"}),
+        pl.col("g").with_field_metadata({"description": "group"}),
+    )
+
+
+def test_field_metadata_set(df: pl.DataFrame) -> None:
+    assert df.field_metadata == {
+        "speed": {"unit": "m/s"},
+        "g": {"description": "group"},
+    }
+    assert df["speed"].field_metadata == {"unit": "m/s"}
+    assert df["n"].field_metadata is None
+
+    out = df.with_columns(pl.col("speed").with_field_metadata({}))
+    assert out.field_metadata == {"g": {"description": "group"}}
+
+
+def test_field_metadata_projection_rename(df: pl.DataFrame) -> None:
+    out = df.select(pl.col("speed").alias("v"), "n")
+    assert out.field_metadata == {"v": {"unit": "m/s"}}
+
+    out = df.rename({"speed": "velocity"}).drop("g")
+    assert out.field_metadata == {"velocity": {"unit": "m/s"}}
+
+    out = df.lazy().filter(pl.col("n") > 1).sort("speed").head(1).collect()
+    assert out.field_metadata == df.field_metadata
+
+
+def test_field_metadata_expressions(df: pl.DataFrame) -> None:
+    lf = df.lazy()
+    assert lf.select(
+        pl.col("speed").cast(pl.Float32),
+        pl.col("n").cast(pl.Float64),
+    ).collect_field_metadata() == {"speed": {"unit": "m/s"}}
+    assert lf.select(pl.col("speed") * 2).collect_field_metadata() == {}
+    assert lf.select(pl.col("speed") * pl.col("n")).collect_field_metadata() == {}
+    assert lf.select(
+        pl.col("speed").max().alias("max"),
+        pl.col("speed").count().alias("count"),
+    ).collect_field_metadata() == {"max": {"unit": "m/s"}}
+    assert lf.select(
+        pl.when(pl.col("n") > 1).then("speed").otherwise("speed")
+    ).collect_field_metadata() == {"speed": {"unit": "m/s"}}
+    assert (
+        lf.select(pl.when(pl.col("n") > 1).then("speed").otherwise(0.0))
+        .collect_field_metadata()
+        == {}
+    )
+
+    out = df.group_by("g", maintain_order=True).agg(pl.col("speed").mean())
+    assert out.field_metadata == df.field_metadata
+
+
+def test_field_metadata_join_concat(df: pl.DataFrame) -> None:
+    other = pl.DataFrame({"g": ["a", "b"], "speed": [0.5, 1.5]}).with_columns(
+        pl.col("speed").with_field_metadata({"unit": "km/h"})
+    )
+    out = df.join(other, on="g")
+    assert out.field_metadata == {
+        "speed": {"unit": "m/s"},
+        "g": {"description": "group"},
+        "speed_right": {"unit": "km/h"},
+    }
+
+    lf = df.lazy()
+    assert pl.concat([lf, lf]).collect().field_metadata == df.field_metadata
+    out = pl.concat([lf, lf.with_columns(pl.col("speed").with_field_metadata({}))])
+    assert out.collect_field_metadata() == {"g": {"description": "group"}}
+
+
+def test_field_metadata_opaque_udf(df: pl.DataFrame) -> None:
+    out = df.lazy().map_batches(lambda df: df).collect()
+    assert out.field_metadata == {}
+
+
+@pytest.mark.parametrize("format", ["parquet", "ipc"])
+def test_field_metadata_roundtrip(
+    df: pl.DataFrame, format: str, tmp_path: Path
+) -> None:
+    path = tmp_path / f"data.{format}"
+    if format == "parquet":
+        df.write_parquet(path)
+        lf = pl.scan_parquet(path)
+        out = pl.read_parquet(path)
+    else:
+        df.write_ipc(path)
+        lf = pl.scan_ipc(path)
+        out = pl.read_ipc(path)
+
+    assert out.field_metadata == df.field_metadata
+    assert lf.select("speed").collect_field_metadata() == {"speed": {"unit": "m/s"}}
+    assert lf.select("speed").collect().field_metadata == {"speed": {"unit": "m/s"}}
+
+
+def test_field_metadata_selector(df: pl.DataFrame) -> None:
+    assert df.select(cs.by_metadata("unit", "m/s")).columns == ["speed"]
+    assert df.select(cs.by_metadata("unit", "m")).columns == []
+    assert df.select(
+        cs.by_metadata("unit", "m/s") | cs.by_metadata("description", "group")
+    ).columns == ["speed", "g"]
+
+    out = df.lazy().with_columns(cs.by_metadata("unit", "m/s") * 2).collect()
+    assert out["speed"].to_list() == [2.0, 5.0, 8.0]
+
+    assert repr(cs.by_metadata("unit", "m/s")) == "cs.by_metadata('unit', 'm/s')"
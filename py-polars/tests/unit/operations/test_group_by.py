@@ -17,6 +17,7 @@ from polars.exceptions import (
     ColumnNotFoundError,
     ComputeError,
     InvalidOperationError,
+    SchemaError,
 )
 from polars.meta import get_index_type
 from polars.testing import assert_frame_equal, assert_series_equal
@@ -2973,3 +2974,46 @@ def test_group_by_agg_get_oob_error_26747() -> None:
 
     with pytest.raises(ComputeError, match="get index is out of bounds"):
         df.group_by("x").agg(y=pl.col.x.get(100))
+
+
+def test_group_by_hashed() -> None:
+    lf = pl.LazyFrame(
+        {
+            "a": ["x", "y", "x", "y", "x"],
+            "b": [1, 2, 1, 3, 1],
+            "c": [5, 4, 3, 2, 1],
+        }
+    ).with_columns(key_hash=pl.struct("a", "b").hash())
+
+    out = (
+        lf.group_by_hashed("key_hash", "a", "b", maintain_order=True)
+        .agg(pl.col("c").sum(), n=pl.len())
+        .collect()
+    )
+    expected = lf.group_by("a", "b", maintain_order=True).agg(
+        pl.col("c").sum(), n=pl.len()
+    )
+    assert_frame_equal(out, expected.collect())
+
+    out = (
+        lf.group_by_hashed("key_hash", ["a", "b"])
+        .having(pl.len() > 1)
+        .head(2)
+        .collect()
+    )
+    assert_frame_equal(
+        out,
+        pl.DataFrame({"a": ["x", "x"], "b": [1, 1], "c": [5, 3]}),
+        check_row_order=False,
+    )
+
+
+def test_group_by_hashed_trusts_hash() -> None:
+    lf = pl.LazyFrame(
+        {"a": [1, 2, 3], "h": [7, 7, 8]}, schema_overrides={"h": pl.UInt64}
+    )
+    out = lf.group_by_hashed("h", "a", maintain_order=True).agg(pl.len()).collect()
+    assert out.to_dict(as_series=False) == {"a": [1, 3], "len": [2, 1]}
+
+    with pytest.raises(SchemaError, match="must be of dtype UInt64"):
+        lf.group_by_hashed(pl.col("h").cast(pl.Int64), "a").agg(pl.len())
@@ -0,0 +1,164 @@
+from __future__ import annotations
+
+from datetime import date, datetime
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_interval() -> None:
+    df = pl.DataFrame({"start": [1, None, 3], "end": [2.5, 4.0, None]})
+
+    out = df.select(pl.interval("start", "end"))
+    assert out.schema == pl.Schema(
+        {"start": pl.Struct({"start": pl.Float64, "end": pl.Float64})}
+    )
+    assert out["start"].to_list() == [{"start": 1.0, "end": 2.5}, None, None]
+
+
+def test_interval_invalid() -> None:
+    df = pl.DataFrame({"start": [2], "end": [1]})
+    with pytest.raises(ComputeError, match="must not be after its end"):
+        df.select(pl.interval("start", "end"))
+
+    df = pl.DataFrame({"start": ["a"], "end": ["b"]})
+    with pytest.raises(InvalidOperationError, match="numeric or temporal"):
+        df.select(pl.interval("start", "end"))
+
+    df = pl.DataFrame({"a": [{"x": 1, "y": 2}]})
+    with pytest.raises(InvalidOperationError, match="expected an interval"):
+        df.select(pl.col("a").interval.overlaps("a"))
+
+    # Structs that were not created with `pl.interval` are checked when used.
+    df = pl.DataFrame({"a": [{"start": 2, "end": 1}]})
+    with pytest.raises(ComputeError, match="must not be after its end"):
+        df.select(pl.col("a").interval.overlaps("a"))
+    with pytest.raises(ComputeError, match="must not be after its end"):
+        df.lazy().interval_join(df.lazy(), "a", "a").collect()
+
+
+def test_interval_algebra() -> None:
+    df = pl.DataFrame(
+        {
+            "a_start": [0, 0, 5, None],
+            "a_end": [5, 10, 5, 1],
+            "b_start": [5, 2, 0, 0],
+            "b_end": [8, 4, 10, 1],
+        }
+    ).select(
+        a=pl.interval("a_start", "a_end"),
+        b=pl.interval("b_start", "b_end"),
+    )
+
+    out = df.select(
+        overlaps=pl.col("a").interval.overlaps("b"),
+        contains=pl.col("a").interval.contains("b"),
+        contains_3=pl.col("a").interval.contains(3),
+        intersect=pl.col("a").interval.intersect("b"),
+    )
+
+    assert_frame_equal(
+        out,
+        pl.DataFrame(
+            {
+                "overlaps": [False, True, True, None],
+                "contains": [False, True, False, None],
+                "contains_3": [True, True, False, None],
+                "intersect": [
+                    None,
+                    {"start": 2, "end": 4},
+                    {"start": 5, "end": 5},
+                    None,
+                ],
+            }
+        ),
+    )
+
+
+def test_interval_temporal() -> None:
+    df = pl.DataFrame(
+        {
+            "start": [datetime(2024, 1, 1), datetime(2024, 3, 1)],
+            "end": [date(2024, 2, 1), date(2024, 4, 1)],
+        }
+    )
+
+    out = df.select(
+        pl.interval("start", "end").interval.contains(pl.datetime(2024, 1, 15))
+    )
+    assert out.to_series().to_list() == [True, False]
+
+
+def test_interval_union_merge() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2],
+            "start": [7, 0, 3, 1, None],
+            "end": [10, 3, 5, 2, 5],
+        }
+    )
+
+    out = df.select(pl.interval("start", "end").interval.union_merge())
+    assert out.to_series().to_list() == [
+        {"start": 0, "end": 5},
+        {"start": 7, "end": 10},
+    ]
+
+    out = (
+        df.group_by("g", maintain_order=True)
+        .agg(pl.interval("start", "end").interval.union_merge())
+        .explode("start")
+    )
+    assert_series_equal(
+        out["start"].struct.field("start"),
+        pl.Series("start", [0, 7, 1]),
+    )
+
+
+@pytest.mark.parametrize("streaming", [False, True])
+def test_interval_join(streaming: bool) -> None:
+    bookings = pl.LazyFrame(
+        {"id": [1, 2, 3], "start": [0, 10, None], "end": [5, 20, 30]}
+    )
+    events = pl.LazyFrame(
+        {
+            "event": ["a", "b", "c", "d"],
+            "start": [4, 5, 15, 0],
+            "end": [6, 10, 25, 30],
+        }
+    )
+
+    out = (
+        bookings.interval_join(
+            events,
+            pl.interval("start", "end"),
+            pl.interval("start", "end"),
+        )
+        .select("id", "event", "start_right")
+        .sort("id", "event")
+        .collect(engine="streaming" if streaming else "in-memory")
+    )
+    assert_frame_equal(
+        out,
+        pl.DataFrame(
+            {
+                "id": [1, 1, 2, 2],
+                "event": ["a", "d", "c", "d"],
+                "start_right": [4, 0, 15, 0],
+            }
+        ),
+    )
+
+    eager = bookings.collect().interval_join(
+        events.collect(),
+        pl.interval("start", "end"),
+        pl.interval("start", "end"),
+        suffix="_event",
+    )
+    assert_frame_equal(
+        eager.select("id", "event", "start_event").sort("id", "event"),
+        out.rename({"start_right": "start_event"}),
+    )
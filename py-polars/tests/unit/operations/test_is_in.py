@@ -360,6 +360,24 @@ def test_is_in_list_rhs() -> None:
     )
 
 
+@pytest.mark.parametrize("nulls_equal", [False, True])
+def test_is_in_list_column_multiple_chunks(nulls_equal: bool) -> None:
+    # Sliced chunks have offsets that don't start at zero.
+    df = pl.concat(
+        [
+            pl.DataFrame({"x": [9, 1], "allowed": [[9], [0, 1]]}).slice(1),
+            pl.DataFrame({"x": [8, 2, None], "allowed": [[8], [3], [None]]}).slice(1),
+            pl.DataFrame({"x": [4, 5], "allowed": [[4, 5, 6], []]}),
+        ],
+        rechunk=False,
+    )
+    assert df.n_chunks() == 3
+
+    out = df.select(pl.col("x").is_in(pl.col("allowed"), nulls_equal=nulls_equal))
+    expected = [True, False, True if nulls_equal else None, True, False]
+    assert out.to_series().to_list() == expected
+
+
 @pytest.mark.parametrize("dtype", [pl.Float32, pl.Float64])
 def test_is_in_float(dtype: PolarsDataType) -> None:
     s = pl.Series([float("nan"), 0.0], dtype=dtype)
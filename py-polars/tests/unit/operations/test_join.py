@@ -848,6 +848,34 @@ def test_join_validation_many_keys() -> None:
                 df1.join(df2, on=["val1", "val2"], how=join_type, validate=val)
 
 
+def test_join_validation_reports_violating_keys() -> None:
+    dim = pl.DataFrame({"k": [1, 2, 2, 3, 3, 3, None, None], "v": range(8)})
+    fact = pl.DataFrame({"k": [1, 2, 3]})
+
+    with pytest.raises(
+        ComputeError, match=r"m:1 validation; duplicate keys in the right dataset"
+    ) as exc:
+        fact.join(dim, on="k", validate="m:1")
+
+    msg = str(exc.value)
+    assert "shape: (2, 2)" in msg
+    # Null keys don't match, so they are not violations.
+    assert "null" not in msg
+
+    # Nulls are duplicates if they are considered equal.
+    with pytest.raises(ComputeError, match=r"shape: \(3, 2\)"):
+        fact.join(dim, on="k", validate="m:1", nulls_equal=True)
+
+    with (
+        pl.Config(join_validation_max_reported=1),
+        pytest.raises(ComputeError, match=r"shape: \(1, 2\)"),
+    ):
+        fact.join(dim, on="k", validate="m:1")
+
+    # Valid joins still go through the regular validation.
+    assert fact.join(dim.unique("k"), on="k", validate="m:1").height == 3
+
+
 def test_full_outer_join_bool() -> None:
     df1 = pl.DataFrame({"id": [True, False], "val": [1, 2]})
     df2 = pl.DataFrame({"id": [True, False], "val": [0, -1]})
@@ -4024,3 +4052,23 @@ def test_full_join_rewrite_to_right_with_cast() -> None:
         },
     )
     assert_frame_equal(out, ret, check_column_order=True, check_row_order=False)
+
+
+def test_join_hashed() -> None:
+    left = pl.LazyFrame({"a": [1, 2, 3], "h": [7, 8, 9]}).cast({"h": pl.UInt64})
+    right = pl.LazyFrame({"b": [4, 5], "h": [9, 7]}).cast({"h": pl.UInt64})
+
+    # Only the hashes are compared.
+    out = left.join_hashed(right, "h", "h", how="left", maintain_order="left")
+    expected = pl.DataFrame(
+        {"a": [1, 2, 3], "h": [7, 8, 9], "b": [5, None, 4], "h_right": [7, None, 9]}
+    )
+    assert_frame_equal(
+        out.collect(), expected.cast({"h": pl.UInt64, "h_right": pl.UInt64})
+    )
+
+    out = left.join_hashed(right, "h", "h", how="anti")
+    assert out.collect().to_dict(as_series=False) == {"a": [2], "h": [8]}
+
+    with pytest.raises(SchemaError, match="must be of dtype UInt64"):
+        left.join_hashed(right, "a", "h")
@@ -1667,3 +1667,74 @@ def test_join_asof_nans(strategy: AsofJoinStrategy) -> None:
         }
     )
     assert_frame_equal(actual, expected)
+
+
+def test_join_asof_distance_and_indicator() -> None:
+    left = pl.DataFrame(
+        {"t": [1, 5, 10], "a": ["x", "y", "z"]}, schema_overrides={"t": pl.UInt32}
+    )
+    right = pl.DataFrame(
+        {"t": [0, 4, 9], "b": [10, 40, 90]}, schema_overrides={"t": pl.UInt32}
+    )
+
+    result = left.join_asof(
+        right,
+        on="t",
+        tolerance=1,
+        distance_column="dist",
+        indicator_column="matched",
+    )
+    expected = pl.DataFrame(
+        {
+            "t": pl.Series([1, 5, 10], dtype=pl.UInt32),
+            "a": ["x", "y", "z"],
+            "b": [10, 40, 90],
+            "dist": pl.Series([1, 1, 1], dtype=pl.Int64),
+            "matched": [True, True, True],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    result = left.lazy().join_asof(
+        right.lazy(),
+        on="t",
+        strategy="forward",
+        distance_column="dist",
+        indicator_column="matched",
+    )
+    expected = pl.DataFrame(
+        {
+            "t": pl.Series([1, 5, 10], dtype=pl.UInt32),
+            "a": ["x", "y", "z"],
+            "b": [40, 90, None],
+            "dist": pl.Series([-3, -4, None], dtype=pl.Int64),
+            "matched": [True, True, False],
+        }
+    )
+    assert result.collect_schema() == expected.schema
+    assert_frame_equal(result.collect(), expected)
+
+
+def test_join_asof_distance_uint64_out_of_range() -> None:
+    big = 2**64 - 10
+    left = pl.DataFrame({"t": [big, 2**64 - 1]}, schema={"t": pl.UInt64})
+    right = pl.DataFrame({"t": [big], "v": [1]}, schema={"t": pl.UInt64, "v": pl.Int64})
+    with pytest.raises(InvalidOperationError, match="conversion from `u64` to `i64`"):
+        left.join_asof(right, on="t", distance_column="dist")
+
+    # Without a distance column, keys near `u64::MAX` still join.
+    result = left.join_asof(right, on="t", indicator_column="matched")
+    assert result["v"].to_list() == [1, 1]
+
+    left = pl.DataFrame({"t": [2**63 - 1]}, schema={"t": pl.UInt64})
+    right = pl.DataFrame({"t": [0], "v": [1]}, schema={"t": pl.UInt64, "v": pl.Int64})
+    result = left.join_asof(right, on="t", distance_column="dist")
+    assert result["dist"].to_list() == [2**63 - 1]
+
+
+def test_join_asof_distance_temporal() -> None:
+    left = pl.DataFrame({"d": [date(2020, 1, 3)]})
+    right = pl.DataFrame({"d": [date(2020, 1, 1)], "v": [1]})
+    result = left.join_asof(right, on="d", distance_column="dist")
+    assert result["dist"].to_list() == [timedelta(days=2)]
+    assert result.schema["dist"] == pl.Duration("us")
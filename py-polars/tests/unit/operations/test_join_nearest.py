@@ -0,0 +1,89 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+def test_join_nearest() -> None:
+    left = pl.DataFrame({"id": [1, 2, 3], "x": [0.0, 5.0, 100.0], "y": [0, 5, 100]})
+    right = pl.DataFrame(
+        {
+            "name": ["a", "b", "c", "d"],
+            "px": [1.0, 4.0, 6.0, 0.0],
+            "py": [0.0, 5.0, 5.0, 2.0],
+        }
+    )
+
+    result = left.join_nearest(
+        right, left_on=["x", "y"], right_on=["px", "py"], k=2, max_distance=3.0
+    )
+    expected = pl.DataFrame(
+        {
+            "id": [1, 1, 2, 2],
+            "x": [0.0, 0.0, 5.0, 5.0],
+            "y": [0, 0, 5, 5],
+            "name": ["a", "d", "b", "c"],
+            "px": [1.0, 0.0, 4.0, 6.0],
+            "py": [0.0, 2.0, 5.0, 5.0],
+            "distance": [1.0, 2.0, 1.0, 1.0],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_join_nearest_matches_brute_force() -> None:
+    left = pl.DataFrame({"x": [0.3, 2.7, -1.2, 8.8], "y": [1.1, -0.4, 3.3, 2.0]})
+    right = pl.DataFrame(
+        {
+            "x": [float(i % 7) - 2.5 for i in range(50)],
+            "y": [float(i % 11) / 3 - 1.0 for i in range(50)],
+        }
+    ).with_row_index()
+
+    result = left.join_nearest(right, on=["x", "y"], k=3)
+    expected = (
+        left.with_row_index("left")
+        .join(right, how="cross", suffix="_right")
+        .with_columns(
+            distance=(
+                (pl.col("x") - pl.col("x_right")) ** 2
+                + (pl.col("y") - pl.col("y_right")) ** 2
+            ).sqrt()
+        )
+        .sort("left", "distance", "index")
+        .group_by("left", maintain_order=True)
+        .head(3)
+    )
+    assert result["distance"].to_list() == pytest.approx(
+        expected["distance"].to_list()
+    )
+
+
+def test_join_nearest_cosine() -> None:
+    left = pl.DataFrame({"a": [1.0], "b": [0.0]})
+    right = pl.DataFrame({"a": [-1.0, 10.0, 1.0], "b": [0.0, 1.0, 1.0]})
+
+    result = left.join_nearest(right, on=["a", "b"], k=3, metric="cosine")
+    assert result["a_right"].to_list() == [10.0, 1.0, -1.0]
+    assert result["distance"][-1] == pytest.approx(2.0)
+
+
+def test_join_nearest_invalid() -> None:
+    df = pl.DataFrame({"x": [1.0]})
+    with pytest.raises(ValueError, match="either 'on' or both"):
+        df.join_nearest(df, left_on="x")
+    with pytest.raises(ValueError, match="`metric` must be one of"):
+        df.join_nearest(df, on="x", metric="manhattan")  # type: ignore[arg-type]
+
+
+def test_join_nearest_distance_column_exists() -> None:
+    left = pl.DataFrame({"x": [0.0]})
+    right = pl.DataFrame({"x": [1.0], "distance": [5.0]})
+
+    with pytest.raises(pl.exceptions.DuplicateError, match="distance"):
+        left.join_nearest(right, on="x")
+
+    result = left.join_nearest(right, on="x", distance_column="dist")
+    assert result.columns == ["x", "x_right", "distance", "dist"]
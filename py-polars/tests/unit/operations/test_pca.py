@@ -0,0 +1,58 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_series_equal
+
+
+def test_pca() -> None:
+    df = pl.DataFrame({"a": [1.0, 2.0, 3.0, 4.0, 5.0], "b": [2, 4, 6, 8, 10]})
+
+    loadings, transformed = df.pca(2)
+    assert loadings.columns == [
+        "component",
+        "explained_variance",
+        "explained_variance_ratio",
+        "a",
+        "b",
+    ]
+    assert loadings["component"].to_list() == ["pc1", "pc2"]
+    assert loadings["explained_variance_ratio"].to_list() == pytest.approx([1.0, 0.0])
+    assert loadings.row(0)[3:] == pytest.approx((5**-0.5, 2 * 5**-0.5))
+
+    assert transformed.columns == ["pc1", "pc2"]
+    assert_series_equal(
+        transformed["pc1"],
+        pl.Series("pc1", [-2.0, -1.0, 0.0, 1.0, 2.0]) * 5**0.5,
+        abs_tol=1e-9,
+    )
+
+
+def test_pca_matches_covariance_eigenvalues() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [2.5, 0.5, 2.2, 1.9, 3.1, 2.3, 2.0, 1.0, 1.5, 1.1],
+            "b": [2.4, 0.7, 2.9, 2.2, 3.0, 2.7, 1.6, 1.1, 1.6, 0.9],
+            "c": [1.0, 0.0, 1.0, 1.0, 2.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+        }
+    )
+    loadings, _ = df.pca(3)
+
+    variances = loadings["explained_variance"]
+    assert variances.is_sorted(descending=True)
+    assert variances.sum() == pytest.approx(
+        sum(df[c].var() for c in df.columns)  # type: ignore[misc]
+    )
+    assert loadings["explained_variance_ratio"].sum() == pytest.approx(1.0)
+
+
+def test_pca_invalid() -> None:
+    df = pl.DataFrame({"a": [1, 2, None], "b": [1, 2, 3], "c": ["x", "y", "z"]})
+    with pytest.raises(ComputeError, match="`k` must be between"):
+        df.select("b").pca(2)
+    with pytest.raises(InvalidOperationError, match="null"):
+        df.select("a", "b").pca(1)
+    with pytest.raises(InvalidOperationError, match="numeric"):
+        df.select("b", "c").pca(1)
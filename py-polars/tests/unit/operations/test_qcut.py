@@ -82,6 +82,54 @@ def test_qcut_include_breaks_lazy_schema() -> None:
     assert_frame_equal(result, expected, categorical_as_str=True)
 
 
+def test_qcut_include_bounds() -> None:
+    lf = pl.LazyFrame({"a": [-2, -1, 0, 1, 2]})
+
+    result = lf.select(
+        pl.col("a").qcut([0.25, 0.75], include_bounds=True).alias("qcut")
+    ).unnest("qcut")
+
+    expected = pl.LazyFrame(
+        {
+            "category": ["(-inf, -1]", "(-inf, -1]", "(-1, 1]", "(-1, 1]", "(1, inf]"],
+            "lower": [-inf, -inf, -1.0, -1.0, 1.0],
+            "upper": [-1.0, -1.0, 1.0, 1.0, inf],
+        },
+        schema_overrides={"category": pl.Categorical},
+    )
+    assert_frame_equal(result, expected, categorical_as_str=True)
+
+    with pytest.raises(ValueError, match="cannot both be set"):
+        pl.col("a").qcut(2, include_breaks=True, include_bounds=True)
+
+
+def test_qcut_include_bounds_over() -> None:
+    df = pl.DataFrame(
+        {
+            "group": ["a"] * 4 + ["b"] * 4,
+            "value": [0, 1, 2, 3, 10, 11, 12, 13],
+        }
+    )
+
+    out = df.select(
+        pl.col("value").qcut(2, include_bounds=True).over("group").struct.unnest()
+    )
+
+    assert out["lower"].to_list() == [-inf, -inf, 1.5, 1.5, -inf, -inf, 11.5, 11.5]
+    assert out["upper"].to_list() == [1.5, 1.5, inf, inf, 11.5, 11.5, inf, inf]
+
+
+def test_qcut_include_bounds_full_null() -> None:
+    s = pl.Series("a", [None, None], dtype=pl.Float64)
+
+    result = s.qcut([0.5], include_bounds=True)
+
+    assert result.dtype == pl.Struct(
+        {"category": pl.Categorical, "lower": pl.Float64, "upper": pl.Float64}
+    )
+    assert result.null_count() == 2
+
+
 def test_qcut_null_values() -> None:
     s = pl.Series([-1.0, None, 1.0, 2.0, None, 8.0, 4.0])
 
@@ -428,3 +428,67 @@ def test_replace_strict_incompatible_types_26329() -> None:
         pl.exceptions.InvalidOperationError, match="cannot use values of type"
     ):
         df.with_columns(pl.col("x").replace_strict({"a": 1}))
+
+
+def test_replace_strict_struct_dataframe_mapping() -> None:
+    df = pl.DataFrame({"a": [1, 1, 2, None], "b": ["x", "y", "x", "x"]})
+    mapping = pl.DataFrame(
+        {
+            "a": [1, 1, 2],
+            "b": ["x", "y", "x"],
+            "c": [10, 20, 30],
+            "d": [True, False, True],
+        }
+    )
+
+    result = df.select(
+        pl.struct("a", "b")
+        .replace_strict(
+            mapping.select("a", "b"), mapping.select("c", "d"), default=None
+        )
+        .struct.unnest()
+    )
+    expected = pl.DataFrame({"c": [10, 20, 30, None], "d": [True, False, True, None]})
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(InvalidOperationError, match="incomplete mapping"):
+        df.select(
+            pl.struct("a", "b").replace_strict(
+                mapping.select("a", "b"), mapping.select("c", "d")
+            )
+        )
+
+
+@pytest.mark.parametrize("strict", [True, False])
+def test_replace_large_mapping_as_join(strict: bool) -> None:
+    n = 20_000
+    old = pl.Series("old", range(n))
+    new = pl.Series("new", range(n, 2 * n))
+    df = pl.DataFrame({"a": [5, None, 3, n - 1, 7] * 100})
+
+    if strict:
+        expr = pl.col("a").replace_strict(old, new)
+    else:
+        expr = pl.col("a").replace(old.head(n - 1), new.head(n - 1))
+
+    lf = df.lazy().with_columns(b=expr)
+    assert "LEFT JOIN" in lf.explain()
+    assert_frame_equal(lf.collect(), df.with_columns(b=expr))
+    assert_frame_equal(lf.collect(engine="streaming"), df.with_columns(b=expr))
+
+
+def test_replace_strict_large_mapping_as_join_incomplete() -> None:
+    n = 20_000
+    old = pl.Series("old", range(1, n))
+    lf = pl.LazyFrame({"a": [1, None, 0]})
+
+    result = lf.select(pl.col("a").replace_strict(old, old * 2, default=-1))
+    assert "LEFT JOIN" in result.explain()
+    assert result.collect().to_series().to_list() == [2, -1, -1]
+
+    with pytest.raises(InvalidOperationError, match="incomplete mapping"):
+        lf.select(pl.col("a").replace_strict(old, old * 2)).collect()
+    assert_frame_equal(
+        lf.head(2).select(pl.col("a").replace_strict(old, old * 2)).collect(),
+        pl.DataFrame({"a": [2, None]}),
+    )
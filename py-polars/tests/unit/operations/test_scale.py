@@ -0,0 +1,83 @@
+from __future__ import annotations
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+def test_zscore() -> None:
+    df = pl.DataFrame({"g": ["a", "a", "a", "b", "b"], "x": [1, 2, 3, 5, 5]})
+
+    result = df.select(
+        pl.col("x").zscore(),
+        pl.col("x").zscore().over("g").alias("per_group"),
+    )
+
+    mean = 16 / 5
+    std = 1.7888543819998317
+    expected = pl.DataFrame(
+        {
+            "x": [(v - mean) / std for v in [1, 2, 3, 5, 5]],
+            # Group "b" has a standard deviation of zero.
+            "per_group": [-1.0, 0.0, 1.0, 0.0, 0.0],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_min_max_scale() -> None:
+    df = pl.DataFrame({"g": ["a", "a", "a", "b"], "x": [2, 4, 6, 7]})
+
+    result = df.select(
+        pl.col("x").min_max_scale(),
+        pl.col("x").min_max_scale((-1, 1)).over("g").alias("per_group"),
+    )
+
+    expected = pl.DataFrame(
+        {"x": [0.0, 0.4, 0.8, 1.0], "per_group": [-1.0, 0.0, 1.0, -1.0]}
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_robust_scale() -> None:
+    df = pl.DataFrame({"x": [1, 2, 3, 4, 100]})
+
+    result = df.select(pl.col("x").robust_scale())
+
+    expected = pl.DataFrame({"x": [-1.0, -0.5, 0.0, 0.5, 48.5]})
+    assert_frame_equal(result, expected)
+
+
+def test_scale_return_params() -> None:
+    lf = pl.LazyFrame({"g": ["a", "a", "b", "b"], "x": [1, 3, 10, 20]})
+
+    result = lf.group_by("g", maintain_order=True).agg(
+        pl.col("x").zscore(return_params=True).alias("zscore"),
+        pl.col("x").min_max_scale(return_params=True).alias("min_max"),
+        pl.col("x").robust_scale(return_params=True).alias("robust"),
+    )
+
+    assert result.collect_schema() == {
+        "g": pl.String,
+        "zscore": pl.Struct({"mean": pl.Float64, "std": pl.Float64}),
+        "min_max": pl.Struct({"min": pl.Float64, "max": pl.Float64}),
+        "robust": pl.Struct({"median": pl.Float64, "iqr": pl.Float64}),
+    }
+    expected = pl.DataFrame(
+        {
+            "g": ["a", "b"],
+            "zscore": [
+                {"mean": 2.0, "std": 2**0.5},
+                {"mean": 15.0, "std": 50**0.5},
+            ],
+            "min_max": [{"min": 1.0, "max": 3.0}, {"min": 10.0, "max": 20.0}],
+            "robust": [{"median": 2.0, "iqr": 1.0}, {"median": 15.0, "iqr": 5.0}],
+        }
+    )
+    assert_frame_equal(result.collect(), expected)
+
+    # The parameters can be reused on new data.
+    params = result.collect().row(0, named=True)["zscore"]
+    new = pl.DataFrame({"x": [2.0, 4.0]}).select(
+        (pl.col("x") - params["mean"]) / params["std"]
+    )
+    assert new["x"].to_list() == [0.0, 2 / 2**0.5]
@@ -0,0 +1,93 @@
+from __future__ import annotations
+
+import io
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, SchemaError
+from polars.testing import assert_frame_equal
+
+
+def step(position: int, signal: int | None) -> tuple[int, int]:
+    if signal:
+        position = signal
+    return position, position
+
+
+def test_scan_fn() -> None:
+    df = pl.DataFrame({"signal": [1, None, 0, -1, None]})
+    result = df.select(pl.col("signal").scan_fn(0, step, return_dtype=pl.Int64))
+    expected = pl.DataFrame({"signal": [1, 1, 1, -1, -1]})
+    assert_frame_equal(result, expected)
+
+
+def test_scan_fn_ewma() -> None:
+    def ewma(
+        mean: float | None, value: float | None
+    ) -> tuple[float | None, float | None]:
+        if value is not None:
+            mean = value if mean is None else 0.5 * mean + 0.5 * value
+        return mean, mean
+
+    df = pl.DataFrame({"a": [1.0, 3.0, None, 5.0]})
+    result = df.select(pl.col("a").scan_fn(None, ewma, return_dtype=pl.Float64))
+    assert result["a"].to_list() == [1.0, 2.0, 2.0, 3.5]
+
+
+def test_scan_fn_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "b", "b", "b"],
+            "signal": [1, None, None, -1, 0],
+        }
+    )
+    result = (
+        df.group_by("g", maintain_order=True)
+        .agg(pl.col("signal").scan_fn(0, step, return_dtype=pl.Int64))
+        .sort("g")
+    )
+    expected = pl.DataFrame(
+        {"g": ["a", "b"], "signal": [[1, 1], [0, -1, -1]]},
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_scan_fn_preserves_dtype() -> None:
+    lf = pl.LazyFrame({"signal": [1, None, 0, -1, None]}, schema={"signal": pl.Int8})
+    result = lf.select(pl.col("signal").scan_fn(0, step))
+    assert result.collect_schema() == {"signal": pl.Int8}
+    expected = pl.DataFrame({"signal": [1, 1, 1, -1, -1]}, schema={"signal": pl.Int8})
+    assert_frame_equal(result.collect(), expected)
+
+
+def test_scan_fn_return_dtype() -> None:
+    def count(n: int, value: str | None) -> tuple[int, int]:
+        n += value is not None
+        return n, n
+
+    lf = pl.LazyFrame({"s": ["a", None, "b"]})
+    result = lf.select(pl.col("s").scan_fn(0, count, return_dtype=pl.UInt32))
+    assert result.collect_schema() == {"s": pl.UInt32}
+    expected = pl.DataFrame({"s": [1, 1, 2]}, schema={"s": pl.UInt32})
+    assert_frame_equal(result.collect(), expected)
+
+
+def test_scan_fn_invalid_output() -> None:
+    df = pl.DataFrame({"a": [1, 2]})
+    with pytest.raises(SchemaError, match="unexpected value"):
+        df.select(pl.col("a").scan_fn(None, lambda state, value: (state, "x")))
+
+
+def test_scan_fn_serde() -> None:
+    expr = pl.col("signal").scan_fn(0, step)
+    expr = pl.Expr.deserialize(io.BytesIO(expr.meta.serialize()), format="binary")
+    df = pl.DataFrame({"signal": [1, None, 0, -1, None]})
+    expected = pl.DataFrame({"signal": [1, 1, 1, -1, -1]})
+    assert_frame_equal(df.select(expr), expected)
+
+
+def test_scan_fn_unregistered() -> None:
+    df = pl.DataFrame({"a": [1, 2]})
+    with pytest.raises(ComputeError, match="no scan function named 'unknown'"):
+        df.select(pl.col("a").scan_fn(0, "unknown"))
@@ -94,3 +94,28 @@ def test_raise_literal_numeric_search_sorted_18096() -> None:
 
     with pytest.raises(pl.exceptions.InvalidOperationError):
         df.with_columns(idx=pl.col("foo").search_sorted("bar"))
+
+
+def test_search_sorted_by() -> None:
+    df = pl.DataFrame({"a": [1, 1, 1, 2, 2], "b": ["z", "y", "x", "b", "a"]})
+    values = pl.DataFrame({"a": [0, 1, 1, 2, 3], "b": ["a", "y", "w", "b", "a"]})
+
+    out = df.select(
+        left=pl.search_sorted_by(
+            ["a", "b"],
+            [pl.lit(values["a"]), pl.lit(values["b"])],
+            side="left",
+            descending=[False, True],
+        ),
+        right=pl.search_sorted_by(
+            ["a", "b"],
+            [pl.lit(values["a"]), pl.lit(values["b"])],
+            side="right",
+            descending=[False, True],
+        ),
+    )
+    assert out["left"].to_list() == [0, 1, 3, 3, 5]
+    assert out["right"].to_list() == [0, 2, 3, 4, 5]
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        df.select(pl.search_sorted_by(["a", "b"], [pl.lit(1)]))
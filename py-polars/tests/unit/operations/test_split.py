@@ -0,0 +1,64 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError
+from polars.testing import assert_frame_equal
+
+
+def test_split() -> None:
+    df = pl.DataFrame({"a": range(10)})
+
+    train, test = df.split([0.8, 0.2])
+    assert_frame_equal(train, df.head(8))
+    assert_frame_equal(test, df.tail(2))
+
+
+def test_split_shuffle_seed() -> None:
+    df = pl.DataFrame({"a": range(100)})
+
+    parts = df.split([0.5, 0.3, 0.2], shuffle=True, seed=1)
+    assert [p.height for p in parts] == [50, 30, 20]
+    assert_frame_equal(pl.concat(parts).sort("a"), df)
+
+    again = df.split([0.5, 0.3, 0.2], shuffle=True, seed=1)
+    for left, right in zip(parts, again):
+        assert_frame_equal(left, right)
+
+
+def test_split_stratify_by() -> None:
+    df = pl.DataFrame({"a": range(10), "label": ["x"] * 6 + ["y"] * 4})
+
+    train, test = df.split([0.5, 0.5], shuffle=True, seed=0, stratify_by="label")
+    assert train["label"].value_counts(sort=True).to_dict(as_series=False) == {
+        "label": ["x", "y"],
+        "count": [3, 2],
+    }
+    assert_frame_equal(pl.concat([train, test]).sort("a"), df)
+
+
+def test_split_invalid_fractions() -> None:
+    df = pl.DataFrame({"a": range(10)})
+    with pytest.raises(ComputeError, match="sum to 1"):
+        df.split([0.5, 0.4])
+    with pytest.raises(ComputeError, match="positive"):
+        df.split([1.5, -0.5])
+
+
+def test_time_series_folds() -> None:
+    lf = pl.LazyFrame({"t": [1, 1, 2, 3, 4, 5, None, 6], "v": range(8)})
+
+    folds = lf.time_series_folds("t", 2, gap=1)
+    assert len(folds) == 2
+
+    result = [
+        (train.collect()["v"].to_list(), test.collect()["v"].to_list())
+        for train, test in folds
+    ]
+    assert result == [([0, 1], [3, 4]), ([0, 1, 2, 3], [5, 7])]
+
+
+def test_time_series_folds_invalid() -> None:
+    with pytest.raises(ComputeError, match="n_folds"):
+        pl.LazyFrame({"t": [1, 2]}).time_series_folds("t", 0)
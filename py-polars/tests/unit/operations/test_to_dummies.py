@@ -0,0 +1,54 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import DuplicateError, InvalidOperationError
+from polars.testing import assert_frame_equal
+
+
+def test_to_dummies_expr() -> None:
+    lf = pl.LazyFrame({"a": ["x", "z", None, "y"], "b": [1, 2, 3, 4]})
+
+    result = lf.select(
+        pl.col("a").to_dummies(["x", "y"], separator=":"),
+        pl.col("b").to_dummies(["1", "4"], drop_first=True),
+    ).unnest("a", "b")
+
+    expected = pl.LazyFrame(
+        {"a:x": [1, 0, 0, 0], "a:y": [0, 0, 0, 1], "b_4": [0, 0, 0, 1]},
+        schema_overrides={"a:x": pl.UInt8, "a:y": pl.UInt8, "b_4": pl.UInt8},
+    )
+    assert result.collect_schema() == expected.collect_schema()
+    assert_frame_equal(result, expected)
+
+
+def test_to_dummies_expr_enum() -> None:
+    dtype = pl.Enum(["low", "mid", "high"])
+    df = pl.DataFrame({"a": ["high", "low", None]}, schema={"a": dtype})
+
+    result = df.select(pl.col("a").to_dummies()).unnest("a")
+
+    expected = pl.DataFrame(
+        {"a_low": [0, 1, 0], "a_mid": [0, 0, 0], "a_high": [1, 0, 0]},
+        schema_overrides={"a_low": pl.UInt8, "a_mid": pl.UInt8, "a_high": pl.UInt8},
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_to_dummies_expr_matches_eager() -> None:
+    df = pl.DataFrame({"a": ["x", "y", "x", "z"]})
+
+    result = df.select(pl.col("a").to_dummies(["x", "y", "z"])).unnest("a")
+
+    assert_frame_equal(result, df.to_dummies())
+
+
+def test_to_dummies_expr_invalid() -> None:
+    lf = pl.LazyFrame({"a": ["x", "y"]})
+
+    with pytest.raises(InvalidOperationError, match="requires the `categories`"):
+        lf.select(pl.col("a").to_dummies()).collect_schema()
+
+    with pytest.raises(DuplicateError):
+        lf.select(pl.col("a").to_dummies(["x", "x"])).collect()
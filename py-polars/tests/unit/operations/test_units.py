@@ -0,0 +1,131 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_series_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+@pytest.fixture
+def lf() -> pl.LazyFrame:
+    return pl.LazyFrame(
+        {
+            "distance": [100, 300, 900],
+            "time": [10.0, 20.0, 30.0],
+            "offset": [0.5, 1.0, 1.5],
+            "n": [1, 2, 3],
+        }
+    ).with_columns(
+        pl.col("distance").set_unit("m"),
+        pl.col("time").set_unit("s"),
+        pl.col("offset").set_unit("km"),
+    )
+
+
+def test_set_unit(lf: pl.LazyFrame) -> None:
+    assert lf.collect_field_metadata() == {
+        "distance": {"polars:unit": "m"},
+        "time": {"polars:unit": "s"},
+        "offset": {"polars:unit": "km"},
+    }
+    df = pl.DataFrame({"a": [1]}).with_columns(
+        pl.col("a").with_field_metadata({"description": "x"}).set_unit("m")
+    )
+    assert df.field_metadata == {"a": {"description": "x", "polars:unit": "m"}}
+    assert df.with_columns(pl.col("a").set_unit("")).field_metadata == {
+        "a": {"description": "x"}
+    }
+
+
+def test_unit_arithmetic(lf: pl.LazyFrame) -> None:
+    out = lf.select(
+        speed=pl.col("distance") / pl.col("time"),
+        acceleration=pl.col("distance") / pl.col("time") ** 2,
+        area=pl.col("distance") * pl.col("distance"),
+        side=(pl.col("distance") * pl.col("distance")).sqrt(),
+        doubled=pl.col("distance") * 2 + 1,
+        frequency=1 / pl.col("time"),
+        ratio=pl.col("distance") / pl.col("distance"),
+        mixed=pl.col("distance") * pl.col("n"),
+        total=pl.col("time").sum(),
+        spread=pl.col("time").var(),
+    )
+    assert out.collect_field_metadata() == {
+        "speed": {"polars:unit": "m/s"},
+        "acceleration": {"polars:unit": "m/s^2"},
+        "area": {"polars:unit": "m^2"},
+        "side": {"polars:unit": "m"},
+        "doubled": {"polars:unit": "m"},
+        "frequency": {"polars:unit": "1/s"},
+        "total": {"polars:unit": "s"},
+        "spread": {"polars:unit": "s^2"},
+    }
+    # Equivalent units can be combined.
+    assert lf.select(
+        pl.col("time").set_unit("N*m") + pl.col("time").set_unit("J")
+    ).collect_field_metadata() == {"time": {"polars:unit": "N*m"}}
+
+
+def test_unit_errors(lf: pl.LazyFrame) -> None:
+    with pytest.raises(InvalidOperationError, match="different dimensions"):
+        lf.select(pl.col("distance") + pl.col("time")).collect()
+    with pytest.raises(InvalidOperationError, match="use `convert_unit`"):
+        lf.select(pl.col("distance") - pl.col("offset")).collect()
+    with pytest.raises(InvalidOperationError, match="use `convert_unit`"):
+        lf.filter(pl.col("distance") > pl.col("offset")).collect()
+    with pytest.raises(InvalidOperationError, match="when/then/otherwise"):
+        lf.select(
+            pl.when(pl.col("n") > 1).then("distance").otherwise("time")
+        ).collect()
+    with pytest.raises(InvalidOperationError, match="has no unit"):
+        lf.select(pl.col("n").convert_unit("m")).collect()
+    with pytest.raises(InvalidOperationError, match="cannot convert unit"):
+        lf.select(pl.col("time").convert_unit("m")).collect()
+
+
+def test_convert_unit(lf: pl.LazyFrame) -> None:
+    q = lf.select(
+        pl.col("offset").convert_unit("m"),
+        speed=(pl.col("distance") / pl.col("time")).convert_unit("km/h"),
+        total=pl.col("distance") + pl.col("offset").convert_unit("m"),
+    )
+    assert q.collect_field_metadata() == {
+        "offset": {"polars:unit": "m"},
+        "speed": {"polars:unit": "km/h"},
+        "total": {"polars:unit": "m"},
+    }
+    out = q.collect()
+    assert_series_equal(out["offset"], pl.Series("offset", [500.0, 1000.0, 1500.0]))
+    assert_series_equal(out["speed"], pl.Series("speed", [36.0, 54.0, 108.0]))
+    assert_series_equal(out["total"], pl.Series("total", [600.0, 1300.0, 2400.0]))
+    assert out.field_metadata["speed"] == {"polars:unit": "km/h"}
+
+    s = pl.Series("a", [1.0], dtype=pl.Float32)
+    out = s.to_frame().select(pl.col("a").set_unit("h").convert_unit("min"))
+    assert_series_equal(out["a"], pl.Series("a", [60.0], dtype=pl.Float32))
+
+
+def test_units_roundtrip(lf: pl.LazyFrame, tmp_path: Path) -> None:
+    path = tmp_path / "data.parquet"
+    lf.collect().write_parquet(path)
+    with pytest.raises(InvalidOperationError, match="different dimensions"):
+        pl.scan_parquet(path).select(pl.col("distance") + pl.col("time")).collect()
+    out = pl.scan_parquet(path).select(pl.col("offset").convert_unit("m")).collect()
+    assert out.field_metadata == {"offset": {"polars:unit": "m"}}
+
+
+def test_plain_unit_metadata_is_not_checked() -> None:
+    lf = pl.LazyFrame({"distance": [1.0], "time": [2.0]}).with_columns(
+        pl.col("distance").with_field_metadata({"unit": "m"}),
+        pl.col("time").with_field_metadata({"unit": "s"}),
+    )
+
+    q = lf.select(pl.col("distance") + pl.col("time"))
+    assert q.collect_field_metadata() == {}
+    assert q.collect().item() == 3.0
@@ -1061,3 +1061,28 @@ def test_over_literal_cum_sum_26800() -> None:
     out = q.collect()
 
     assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize("cse", [True, False])
+def test_over_duplicate_sub_expressions(cse: bool) -> None:
+    lf = pl.LazyFrame(
+        {"g": [1, 1, 2, 2, 2], "h": [1, 2, 1, 2, 2], "a": [1, 2, 3, 4, 5]}
+    )
+    a = pl.col.a * 2 + 1
+    exprs = [
+        a.sum().over("g").alias("sum_g"),
+        a.mean().over("g").alias("mean_g"),
+        a.sum().over("h").alias("sum_h"),
+        pl.when(a > 5).then(a.max().over("g")).otherwise(a).alias("when"),
+        a.cum_sum().alias("cum_sum"),
+    ]
+    opts = pl.QueryOptFlags(comm_subexpr_elim=cse)
+
+    expected = pl.concat(
+        [lf.select(e).collect(optimizations=opts) for e in exprs], how="horizontal"
+    )
+    assert_frame_equal(lf.select(exprs).collect(optimizations=opts), expected)
+    assert_frame_equal(
+        lf.with_columns(exprs).collect(optimizations=opts),
+        pl.concat([lf.collect(), expected], how="horizontal"),
+    )
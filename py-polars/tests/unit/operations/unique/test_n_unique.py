@@ -77,3 +77,17 @@ def test_n_unique_array() -> None:
     assert df["arr"].dtype == pl.Array
     assert df.select(pl.col("arr")).n_unique() == 3
     assert df.select(pl.col("arr").n_unique()).item() == 3
+
+
+def test_n_unique_string_views() -> None:
+    # Long strings with the same prefix and length are only compared in full.
+    values = [f"a long string with suffix {i % 1_000:04}" for i in range(100_000)]
+    s = pl.Series("s", [*values, "short", None, "short", None])
+    assert s.n_unique() == 1_002
+    assert s.unique().len() == 1_002
+    assert s.approx_n_unique() == pytest.approx(1_002, rel=0.05)
+
+    df = pl.DataFrame({"s": s}).with_row_index()
+    out = df.group_by("s").agg(pl.len())
+    assert out.height == 1_002
+    assert out["len"].sum() == s.len()
@@ -412,3 +412,43 @@ def test_streaming_boolean_multiply_dtype_24609() -> None:
     assert (
         lf.collect(engine="streaming").schema == lf.collect(engine="in-memory").schema
     )
+
+
+@pytest.mark.parametrize("chunk_size", [1, 7, 100_000])
+def test_streaming_adaptive_morsel_size(chunk_size: int) -> None:
+    lf = (
+        pl.LazyFrame({"a": range(10_000), "b": ["x" * 100] * 10_000})
+        .with_columns(c=pl.col("a") * 2)
+        .join(pl.LazyFrame({"a": range(0, 10_000, 3)}), on="a", maintain_order="left")
+    )
+    expected = lf.collect(engine="in-memory")
+
+    with pl.Config(streaming_chunk_size=chunk_size, adaptive_morsel_size=True):
+        result = lf.collect(engine="streaming")
+    assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize("morsel_size", [1, 7, 100_000])
+@pytest.mark.parametrize("adaptive_morsel_size", [False, True])
+def test_streaming_engine_morsel_size(
+    morsel_size: int, adaptive_morsel_size: bool, tmp_path: Path
+) -> None:
+    df = pl.DataFrame({"a": range(10_000), "b": ["x" * 100] * 10_000})
+    df.write_parquet(tmp_path / "a.parquet", row_group_size=1_000)
+
+    lf = (
+        pl.concat([df.lazy(), pl.scan_parquet(tmp_path / "a.parquet")])
+        .with_columns(c=pl.col("a") * 2)
+        .join(pl.LazyFrame({"a": range(0, 10_000, 3)}), on="a", maintain_order="left")
+    )
+    expected = lf.collect(engine="in-memory")
+
+    engine = pl.StreamingEngine(
+        morsel_size=morsel_size, adaptive_morsel_size=adaptive_morsel_size
+    )
+    assert_frame_equal(lf.collect(engine=engine), expected)
+
+
+def test_streaming_engine_invalid_morsel_size() -> None:
+    with pytest.raises(ValueError, match="`morsel_size` must be positive"):
+        pl.StreamingEngine(morsel_size=0)
@@ -7,7 +7,7 @@ import numpy as np
 import pytest
 
 import polars as pl
-from polars.exceptions import DuplicateError
+from polars.exceptions import DuplicateError, InvalidOperationError
 from polars.testing import assert_frame_equal
 from tests.unit.conftest import INTEGER_DTYPES
 
@@ -530,3 +530,32 @@ def test_streaming_group_by_nested_agg_fallback() -> None:
     )
     expected = {("aaa", n // 3), ("bbb", n - n // 3)}
     assert expected == set(res.rows())
+
+
+@pytest.mark.parametrize(
+    ("dtype", "out_of_range_period"),
+    [
+        (pl.Int8, "200i"),
+        (pl.Int16, "40000i"),
+        (pl.UInt32, "5000000000i"),
+    ],
+)
+def test_streaming_rolling_integer_index(
+    dtype: pl.DataType, out_of_range_period: str
+) -> None:
+    lf = pl.LazyFrame(
+        {"idx": [0, 1, 2, 5, 6, 10], "a": [3, 7, 5, 9, 2, 1]},
+        schema={"idx": dtype, "a": pl.Int64},
+    ).set_sorted("idx")
+
+    q = lf.rolling("idx", period="3i").agg(pl.col("a").sum())
+    expected = pl.DataFrame(
+        {"idx": [0, 1, 2, 5, 6, 10], "a": [3, 10, 15, 9, 11, 1]},
+        schema={"idx": dtype, "a": pl.Int64},
+    )
+    assert_frame_equal(q.collect(engine="streaming"), expected)
+    assert_frame_equal(q.collect(engine="in-memory"), expected)
+
+    q = lf.rolling("idx", period=out_of_range_period).agg(pl.col("a").sum())
+    with pytest.raises(InvalidOperationError, match="out of range"):
+        q.collect(engine="streaming")